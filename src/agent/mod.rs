@@ -0,0 +1,203 @@
+//! A module implementing the ES [agent](https://tc39.es/ecma262/multipage/executable-code-and-execution-contexts.html#sec-agents), owning the state shared by every realm of a thread.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::core::{StringRep, SymbolRegistry, SymbolRep};
+use crate::realm::Realm;
+
+thread_local! {
+    static AGENT: Agent = Agent::default();
+}
+
+/// An [agent](https://tc39.es/ecma262/multipage/executable-code-and-execution-contexts.html#sec-agents), i.e. the owner of the execution context stack.
+///
+/// There is a single agent per thread, reachable via the `p262_*` functions of this module.
+#[derive(Default)]
+struct Agent {
+    realms: RefCell<Vec<Rc<Realm>>>,
+    symbols: SymbolRegistry,
+    /// How many identities were allocated, which reseeding leaves alone.
+    ids: Cell<u64>,
+    entropy: RefCell<Entropy>,
+}
+
+/// A virtual clock supplied by the embedder, returning the current [time value](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-time-values-and-time-range) in milliseconds.
+pub type Clock = Rc<dyn Fn() -> f64>;
+
+/// Every source of nondeterminism of an agent: random numbers and the current time.
+///
+/// The generator is ChaCha8 rather than [`rand::rngs::StdRng`], whose algorithm may change between versions of `rand`, so a seed yields the same stream everywhere.
+struct Entropy {
+    rng: ChaCha8Rng,
+    /// How many times the generator was reseeded, so generators derived from it can tell when to derive again.
+    generation: u64,
+    clock: Option<Clock>,
+}
+
+impl Default for Entropy {
+    fn default() -> Self {
+        Entropy {
+            rng: ChaCha8Rng::from_entropy(),
+            generation: 0,
+            clock: None,
+        }
+    }
+}
+
+/// Returns the [current Realm Record](https://tc39.es/ecma262/multipage/executable-code-and-execution-contexts.html#current-realm).
+///
+/// If no realm was entered, a default realm is created and entered.
+pub fn e262_current_realm() -> Rc<Realm> {
+    let current = AGENT.with(|agent| agent.realms.borrow().last().cloned());
+    match current {
+        Some(realm) => realm,
+        None => {
+            let realm = Realm::new();
+            p262_enter_realm(realm.clone());
+            realm
+        }
+    }
+}
+
+/// Makes `realm` the current realm, until a matching call to [`p262_exit_realm`].
+pub fn p262_enter_realm(realm: Rc<Realm>) {
+    AGENT.with(|agent| agent.realms.borrow_mut().push(realm));
+}
+
+/// Restores the realm that was current before the last call to [`p262_enter_realm`], returning the exited realm.
+pub fn p262_exit_realm() -> Option<Rc<Realm>> {
+    AGENT.with(|agent| agent.realms.borrow_mut().pop())
+}
+
+/// Returns the symbol registered in the agent-wide registry for `key`, implementing the lookup of [`Symbol.for`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-symbol.for).
+pub fn p262_symbol_for(key: impl Into<StringRep>) -> SymbolRep {
+    AGENT.with(|agent| agent.symbols.get(key))
+}
+
+/// Returns the key a symbol was registered with, implementing [KeyForSymbol](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-keyforsymbol).
+pub fn e262_key_for_symbol(symbol: &SymbolRep) -> Option<StringRep> {
+    AGENT.with(|agent| agent.symbols.key_for(symbol))
+}
+
+/// Enters or leaves deterministic mode.
+///
+/// With a seed, every random number of the agent is drawn from a generator seeded with it, including the generators behind `Math.random` in existing realms, so two runs with the same seed produce the same random numbers. Without, the generator is seeded from the operating system. Identities do not depend on the seed: they are allocated in order.
+pub fn p262_set_seed(seed: Option<u64>) {
+    let rng = match seed {
+        Some(seed) => ChaCha8Rng::seed_from_u64(seed),
+        None => ChaCha8Rng::from_entropy(),
+    };
+    AGENT.with(|agent| {
+        let mut entropy = agent.entropy.borrow_mut();
+        entropy.rng = rng;
+        entropy.generation += 1;
+    });
+}
+
+/// Replaces the system clock with a virtual clock, or restores the system clock if [`None`].
+pub fn p262_set_clock(clock: Option<Clock>) {
+    AGENT.with(|agent| agent.entropy.borrow_mut().clock = clock);
+}
+
+/// Returns 64 random bits from the agent's generator.
+pub fn p262_random_bits() -> u64 {
+    AGENT.with(|agent| agent.entropy.borrow_mut().rng.gen())
+}
+
+/// Returns the agent's next identity, counting every identity allocated before it.
+pub(crate) fn p262_next_id() -> u64 {
+    AGENT.with(|agent| {
+        let id = agent.ids.get();
+        agent.ids.set(id + 1);
+        id
+    })
+}
+
+/// Returns how many times the agent's generator was reseeded by [`p262_set_seed`].
+pub(crate) fn p262_seed_generation() -> u64 {
+    AGENT.with(|agent| agent.entropy.borrow().generation)
+}
+
+/// Returns the current [time value](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-time-values-and-time-range), i.e. the number of milliseconds since the epoch, from the virtual clock if one is set.
+pub fn p262_now() -> f64 {
+    let clock = AGENT.with(|agent| agent.entropy.borrow().clock.clone());
+    match clock {
+        Some(clock) => clock(),
+        None => SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0.0, |elapsed| elapsed.as_millis() as f64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symbols_are_shared_across_realms() {
+        p262_enter_realm(Realm::new());
+        let a = p262_symbol_for("shared");
+        p262_exit_realm();
+        p262_enter_realm(Realm::new());
+        let b = p262_symbol_for("shared");
+        p262_exit_realm();
+
+        assert_eq!(a, b);
+        assert_eq!(e262_key_for_symbol(&a), Some("shared".into()));
+        assert_eq!(e262_key_for_symbol(&SymbolRep::named("shared")), None);
+    }
+
+    #[test]
+    fn seeds_make_runs_reproducible() {
+        let run = || {
+            let realm = e262_current_realm();
+            p262_set_seed(Some(42));
+            let symbol = SymbolRep::anon();
+            let obj = crate::core::e262_ordinary_object_create(None);
+            let run = (format!("{obj:?}"), realm.random(), p262_random_bits());
+            p262_set_seed(None);
+            (symbol, run)
+        };
+        let in_fresh_agent = || std::thread::spawn(move || run().1).join().unwrap();
+
+        let (a_obj, a_random, a_bits) = in_fresh_agent();
+        let (b_obj, b_random, b_bits) = in_fresh_agent();
+        assert_eq!(a_obj, b_obj);
+        assert_eq!(a_random, b_random);
+        assert_eq!(a_bits, b_bits);
+        assert!((0.0..1.0).contains(&a_random));
+
+        // Reseeding the same agent repeats the random numbers but not the identities.
+        let (a_symbol, (a_obj, a_random, _)) = run();
+        let (b_symbol, (b_obj, b_random, _)) = run();
+        assert_eq!(a_random, b_random);
+        assert_ne!(a_symbol, b_symbol);
+        assert_ne!(a_obj, b_obj);
+    }
+
+    #[test]
+    fn seeds_pin_the_stream() {
+        p262_set_seed(Some(0));
+        let bits = p262_random_bits();
+        p262_set_seed(None);
+        assert_eq!(bits, 0xB585_F767_A79A_3B6C);
+    }
+
+    #[test]
+    fn clock_can_be_virtual() {
+        let time = Rc::new(std::cell::Cell::new(1000.0));
+        let clock = time.clone();
+        p262_set_clock(Some(Rc::new(move || clock.get())));
+        assert_eq!(p262_now(), 1000.0);
+        time.set(2000.0);
+        assert_eq!(p262_now(), 2000.0);
+
+        p262_set_clock(None);
+        assert!(p262_now() > 1e12);
+    }
+}
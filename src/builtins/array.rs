@@ -0,0 +1,1807 @@
+use std::cell::{Cell, RefCell};
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use super::{
+    p262_argument, p262_define_getter, p262_define_method, p262_define_value,
+    p262_link_constructor, p262_relative_index,
+};
+use crate::agent::e262_current_realm;
+use crate::core::cast::{
+    e262_to_boolean, e262_to_integer_or_infinity, e262_to_number, e262_to_object, e262_to_string,
+    e262_to_uint32,
+};
+use crate::core::function::{e262_call, e262_construct};
+use crate::core::numbers::MAX_SAFE_INTEGER;
+use crate::core::test::{
+    e262_is_array, e262_is_callable, e262_is_constructor, e262_is_strictly_equal,
+    e262_same_value_zero,
+};
+use crate::core::{
+    e262_array_create, e262_create_array_from_list, e262_create_data_property_or_throw,
+    e262_create_iter_result_object, e262_define_property_or_throw, e262_delete_property_or_throw,
+    e262_get, e262_get_iterator_from_method, e262_get_method, e262_get_prototype_from_constructor,
+    e262_has_property, e262_invoke, e262_iterator_close, e262_iterator_step_value,
+    e262_length_of_array_like, e262_ordinary_object_create, e262_set,
+    p262_create_builtin_self_constructor, p262_get_slot, p262_has_slot, Descriptor, ObjectRep,
+    PropertyKey, PropertyKind, StringRep, SymbolRep, Value, WellKnownSymbol,
+};
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::{Intrinsic, Realm};
+
+/// Creates `%Array%`, the methods of `%Array.prototype%` and `%ArrayIteratorPrototype%`.
+pub(crate) fn p262_create_intrinsics(realm: &Realm) {
+    let prototype = realm.intrinsic(Intrinsic::ArrayPrototype);
+    let constructor = p262_create_builtin_self_constructor(
+        Rc::new(construct),
+        1,
+        "Array",
+        Some(realm.intrinsic(Intrinsic::FunctionPrototype)),
+    );
+    p262_link_constructor(&constructor, &prototype);
+    // Functions carry no [[Realm]], so this marks `%Array%` for ArraySpeciesCreate to recognise
+    // another realm's.
+    constructor
+        .0
+        .clone()
+        .set_slot("IntrinsicArray".to_string(), Rc::new(()));
+
+    p262_define_method(realm, &constructor, "from", 1, from);
+    p262_define_method(realm, &constructor, "isArray", 1, |_, args| {
+        Ok(Value::Boolean(e262_is_array(&p262_argument(args, 0))?))
+    });
+    p262_define_method(realm, &constructor, "of", 0, of);
+    let species = SymbolRep::well_known(WellKnownSymbol::Species);
+    // Implements [`get Array[@@species]`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-get-array-@@species).
+    p262_define_getter(realm, &constructor, species, |this, _| Ok(this.clone()));
+
+    p262_define_method(realm, &prototype, "at", 1, at);
+    p262_define_method(realm, &prototype, "concat", 1, concat);
+    p262_define_method(realm, &prototype, "copyWithin", 2, copy_within);
+    p262_define_method(realm, &prototype, "entries", 0, |this, _| {
+        Ok(Value::Object(create_array_iterator(
+            e262_to_object(this)?,
+            PropertyKind::KeyValue,
+        )))
+    });
+    p262_define_method(realm, &prototype, "every", 1, every);
+    p262_define_method(realm, &prototype, "fill", 1, fill);
+    p262_define_method(realm, &prototype, "filter", 1, filter);
+    p262_define_method(realm, &prototype, "find", 1, |this, args| {
+        Ok(find_via_predicate(this, args, Direction::Ascending)?.1)
+    });
+    p262_define_method(realm, &prototype, "findIndex", 1, |this, args| {
+        Ok(Value::Number(
+            find_via_predicate(this, args, Direction::Ascending)?.0,
+        ))
+    });
+    p262_define_method(realm, &prototype, "findLast", 1, |this, args| {
+        Ok(find_via_predicate(this, args, Direction::Descending)?.1)
+    });
+    p262_define_method(realm, &prototype, "findLastIndex", 1, |this, args| {
+        Ok(Value::Number(
+            find_via_predicate(this, args, Direction::Descending)?.0,
+        ))
+    });
+    p262_define_method(realm, &prototype, "flat", 0, flat);
+    p262_define_method(realm, &prototype, "flatMap", 1, flat_map);
+    p262_define_method(realm, &prototype, "forEach", 1, for_each);
+    p262_define_method(realm, &prototype, "includes", 1, includes);
+    p262_define_method(realm, &prototype, "indexOf", 1, index_of);
+    p262_define_method(realm, &prototype, "join", 1, join);
+    p262_define_method(realm, &prototype, "keys", 0, |this, _| {
+        Ok(Value::Object(create_array_iterator(
+            e262_to_object(this)?,
+            PropertyKind::Key,
+        )))
+    });
+    p262_define_method(realm, &prototype, "lastIndexOf", 1, last_index_of);
+    p262_define_method(realm, &prototype, "map", 1, map);
+    p262_define_method(realm, &prototype, "pop", 0, pop);
+    p262_define_method(realm, &prototype, "push", 1, push);
+    p262_define_method(realm, &prototype, "reduce", 1, |this, args| {
+        reduce(this, args, Direction::Ascending)
+    });
+    p262_define_method(realm, &prototype, "reduceRight", 1, |this, args| {
+        reduce(this, args, Direction::Descending)
+    });
+    p262_define_method(realm, &prototype, "reverse", 0, reverse);
+    p262_define_method(realm, &prototype, "shift", 0, shift);
+    p262_define_method(realm, &prototype, "slice", 2, slice);
+    p262_define_method(realm, &prototype, "some", 1, some);
+    p262_define_method(realm, &prototype, "sort", 1, sort);
+    p262_define_method(realm, &prototype, "splice", 2, splice);
+    p262_define_method(realm, &prototype, "toLocaleString", 0, to_locale_string);
+    p262_define_method(realm, &prototype, "toReversed", 0, to_reversed);
+    p262_define_method(realm, &prototype, "toSorted", 1, to_sorted);
+    p262_define_method(realm, &prototype, "toSpliced", 2, to_spliced);
+    p262_define_method(realm, &prototype, "toString", 0, to_string);
+    p262_define_method(realm, &prototype, "unshift", 1, unshift);
+    let values = p262_define_method(realm, &prototype, "values", 0, |this, _| {
+        Ok(Value::Object(create_array_iterator(
+            e262_to_object(this)?,
+            PropertyKind::Value,
+        )))
+    });
+    p262_define_method(realm, &prototype, "with", 2, with);
+    let iterator = SymbolRep::well_known(WellKnownSymbol::Iterator);
+    p262_define_value(&prototype, iterator, Value::Object(values));
+    let unscopables = SymbolRep::well_known(WellKnownSymbol::Unscopables);
+    e262_define_property_or_throw(
+        &prototype,
+        PropertyKey::from(unscopables),
+        Descriptor {
+            value: Some(Rc::new(Value::Object(unscopable_list()))),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Built-in properties can always be defined");
+
+    let iterator_prototype =
+        e262_ordinary_object_create(Some(realm.intrinsic(Intrinsic::IteratorPrototype)));
+    p262_define_method(realm, &iterator_prototype, "next", 0, array_iterator_next);
+    let to_string_tag = SymbolRep::well_known(WellKnownSymbol::ToStringTag);
+    e262_define_property_or_throw(
+        &iterator_prototype,
+        PropertyKey::from(to_string_tag),
+        Descriptor {
+            value: Some(Rc::new(Value::String("Array Iterator".into()))),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Built-in properties can always be defined");
+
+    realm.set_intrinsic(Intrinsic::Array, constructor);
+    realm.set_intrinsic(Intrinsic::ArrayIteratorPrototype, iterator_prototype);
+}
+
+/// The order in which the elements of an array-like are visited.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Direction {
+    Ascending,
+    Descending,
+}
+
+/// Converts `this` to an object and returns it along with its [length](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-lengthofarraylike).
+fn this_array_like(this: &Value) -> CoreResult<(ObjectRep, f64)> {
+    let obj = e262_to_object(this)?;
+    let len = e262_length_of_array_like(&obj)?;
+    Ok((obj, len))
+}
+
+/// Resolves the end of a range like [`p262_relative_index`], where undefined stands for `len`.
+fn relative_end(argument: &Value, len: f64) -> CoreResult<f64> {
+    match argument {
+        Value::Undefined => Ok(len),
+        argument => p262_relative_index(argument, len),
+    }
+}
+
+/// Returns the callback passed as first argument, or throws a TypeError if it is not callable.
+fn callback_argument(arguments: &[Value]) -> CoreResult<Value> {
+    let callback = p262_argument(arguments, 0);
+    if !e262_is_callable(&callback) {
+        return Err(CoreError::TypeError(
+            "Callback is not a function".to_string(),
+        ));
+    }
+    Ok(callback)
+}
+
+/// Throws a TypeError if an array-like would grow beyond 2<sup>53</sup> - 1 elements.
+fn check_length(len: f64) -> CoreResult<()> {
+    if len > MAX_SAFE_INTEGER {
+        return Err(CoreError::TypeError(
+            "Array length exceeds the maximum safe integer".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Copies the element at `from` to `to` if present, and deletes `to` otherwise.
+fn move_element(obj: &ObjectRep, from: f64, to: f64) -> CoreResult<()> {
+    let from = PropertyKey::from(from);
+    let to = PropertyKey::from(to);
+    if e262_has_property(obj, &from)? {
+        let value = e262_get(obj, &from)?;
+        e262_set(obj, &to, value, true)
+    } else {
+        e262_delete_property_or_throw(obj, &to)
+    }
+}
+
+fn set_length(obj: &ObjectRep, len: f64) -> CoreResult<()> {
+    e262_set(obj, &PropertyKey::from("length"), Value::Number(len), true)
+}
+
+/// Creates a new array with `C`, or an ordinary array if `C` is not a constructor, as done by `Array.from` and `Array.of`.
+fn construct_this(this: &Value, arguments: &[Value]) -> CoreResult<ObjectRep> {
+    match this {
+        Value::Object(constructor) if e262_is_constructor(this) => {
+            e262_construct(constructor, arguments, None)
+        }
+        _ => match arguments {
+            [Value::Number(len)] => e262_array_create(*len, None),
+            _ => e262_array_create(0f64, None),
+        },
+    }
+}
+
+/// Implements [`Array`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array) when called as a constructor.
+fn construct(arguments: &[Value], new_target: &ObjectRep) -> CoreResult<ObjectRep> {
+    let proto = e262_get_prototype_from_constructor(new_target, Intrinsic::ArrayPrototype)?;
+    match arguments {
+        [] => e262_array_create(0f64, Some(proto)),
+        [len] => {
+            let array = e262_array_create(0f64, Some(proto))?;
+            let int_len = match len {
+                Value::Number(number) => {
+                    let int_len = e262_to_uint32(len)? as f64;
+                    if int_len != *number {
+                        return Err(CoreError::RangeError("Invalid array length".to_string()));
+                    }
+                    int_len
+                }
+                _ => {
+                    e262_create_data_property_or_throw(&array, PropertyKey::from(0), len.clone())?;
+                    1f64
+                }
+            };
+            set_length(&array, int_len)?;
+            Ok(array)
+        }
+        values => {
+            let array = e262_array_create(values.len() as f64, Some(proto))?;
+            for (k, value) in values.iter().enumerate() {
+                e262_create_data_property_or_throw(
+                    &array,
+                    PropertyKey::from(k as u32),
+                    value.clone(),
+                )?;
+            }
+            Ok(array)
+        }
+    }
+}
+
+/// Implements [ArraySpeciesCreate](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-arrayspeciescreate).
+fn array_species_create(original_array: &ObjectRep, length: f64) -> CoreResult<ObjectRep> {
+    if !e262_is_array(&Value::Object(original_array.clone()))? {
+        return e262_array_create(length, None);
+    }
+    let mut c = e262_get(original_array, &PropertyKey::from("constructor"))?;
+    if let Value::Object(constructor) = &c {
+        if p262_has_slot(constructor.0.clone(), "IntrinsicArray".to_string())
+            && *constructor != e262_current_realm().intrinsic(Intrinsic::Array)
+        {
+            c = Value::Undefined;
+        }
+    }
+    if let Value::Object(constructor) = &c {
+        let species = SymbolRep::well_known(WellKnownSymbol::Species);
+        c = match e262_get(constructor, &PropertyKey::from(species))? {
+            Value::Null => Value::Undefined,
+            species => species,
+        };
+    }
+    match c {
+        Value::Undefined => e262_array_create(length, None),
+        Value::Object(constructor) if e262_is_constructor(&Value::Object(constructor.clone())) => {
+            e262_construct(&constructor, &[Value::Number(length)], None)
+        }
+        _ => Err(CoreError::TypeError(
+            "Array species is not a constructor".to_string(),
+        )),
+    }
+}
+
+/// Implements [`Array.from`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.from).
+fn from(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let items = p262_argument(arguments, 0);
+    let mapfn = p262_argument(arguments, 1);
+    let this_arg = p262_argument(arguments, 2);
+    let mapping = match mapfn {
+        Value::Undefined => false,
+        _ if e262_is_callable(&mapfn) => true,
+        _ => {
+            return Err(CoreError::TypeError(
+                "Array.from mapper is not a function".to_string(),
+            ))
+        }
+    };
+    let map = |value: Value, k: f64| match mapping {
+        true => e262_call(&mapfn, &this_arg, &[value, Value::Number(k)]),
+        false => Ok(value),
+    };
+    let iterator = SymbolRep::well_known(WellKnownSymbol::Iterator);
+    if let Some(using_iterator) = e262_get_method(&items, &PropertyKey::from(iterator))? {
+        let array = construct_this(this, &[])?;
+        let mut record = e262_get_iterator_from_method(&items, &using_iterator)?;
+        let mut k = 0f64;
+        loop {
+            if k >= MAX_SAFE_INTEGER {
+                let error = check_length(k + 1f64);
+                return e262_iterator_close(&record, error).map(|_| Value::Undefined);
+            }
+            let Some(next) = e262_iterator_step_value(&mut record)? else {
+                set_length(&array, k)?;
+                return Ok(Value::Object(array));
+            };
+            let result = map(next, k).and_then(|value| {
+                e262_create_data_property_or_throw(&array, PropertyKey::from(k), value)
+            });
+            if result.is_err() {
+                return e262_iterator_close(&record, result).map(|_| Value::Undefined);
+            }
+            k += 1f64;
+        }
+    }
+    let array_like = e262_to_object(&items)?;
+    let len = e262_length_of_array_like(&array_like)?;
+    let array = construct_this(this, &[Value::Number(len)])?;
+    let mut k = 0f64;
+    while k < len {
+        let key = PropertyKey::from(k);
+        let value = map(e262_get(&array_like, &key)?, k)?;
+        e262_create_data_property_or_throw(&array, key, value)?;
+        k += 1f64;
+    }
+    set_length(&array, len)?;
+    Ok(Value::Object(array))
+}
+
+/// Implements [`Array.of`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.of).
+fn of(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let len = arguments.len() as f64;
+    let array = construct_this(this, &[Value::Number(len)])?;
+    for (k, item) in arguments.iter().enumerate() {
+        e262_create_data_property_or_throw(&array, PropertyKey::from(k as u32), item.clone())?;
+    }
+    set_length(&array, len)?;
+    Ok(Value::Object(array))
+}
+
+/// Implements [`Array.prototype.at`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.at).
+fn at(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let relative = e262_to_integer_or_infinity(&p262_argument(arguments, 0))?.to_f64();
+    let k = if relative >= 0f64 {
+        relative
+    } else {
+        len + relative
+    };
+    if k < 0f64 || k >= len {
+        return Ok(Value::Undefined);
+    }
+    e262_get(&obj, &PropertyKey::from(k))
+}
+
+/// Implements [IsConcatSpreadable](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-isconcatspreadable).
+fn is_concat_spreadable(value: &Value) -> CoreResult<bool> {
+    let Value::Object(obj) = value else {
+        return Ok(false);
+    };
+    let key = SymbolRep::well_known(WellKnownSymbol::IsConcatSpreadable);
+    match e262_get(obj, &PropertyKey::from(key))? {
+        Value::Undefined => e262_is_array(value),
+        spreadable => Ok(e262_to_boolean(&spreadable)),
+    }
+}
+
+/// Implements [`Array.prototype.concat`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.concat).
+fn concat(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let obj = e262_to_object(this)?;
+    let array = array_species_create(&obj, 0f64)?;
+    let mut n = 0f64;
+    let items = std::iter::once(Value::Object(obj)).chain(arguments.iter().cloned());
+    for item in items {
+        if is_concat_spreadable(&item)? {
+            let Value::Object(item) = item else {
+                unreachable!("Only objects are spreadable");
+            };
+            let len = e262_length_of_array_like(&item)?;
+            check_length(n + len)?;
+            let mut k = 0f64;
+            while k < len {
+                let key = PropertyKey::from(k);
+                if e262_has_property(&item, &key)? {
+                    let value = e262_get(&item, &key)?;
+                    e262_create_data_property_or_throw(&array, PropertyKey::from(n), value)?;
+                }
+                n += 1f64;
+                k += 1f64;
+            }
+        } else {
+            check_length(n + 1f64)?;
+            e262_create_data_property_or_throw(&array, PropertyKey::from(n), item)?;
+            n += 1f64;
+        }
+    }
+    set_length(&array, n)?;
+    Ok(Value::Object(array))
+}
+
+/// Implements [`Array.prototype.copyWithin`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.copywithin).
+fn copy_within(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let mut to = p262_relative_index(&p262_argument(arguments, 0), len)?;
+    let mut from = p262_relative_index(&p262_argument(arguments, 1), len)?;
+    let end = relative_end(&p262_argument(arguments, 2), len)?;
+    let mut count = (end - from).min(len - to);
+    let direction = if from < to && to < from + count {
+        from += count - 1f64;
+        to += count - 1f64;
+        -1f64
+    } else {
+        1f64
+    };
+    while count > 0f64 {
+        move_element(&obj, from, to)?;
+        from += direction;
+        to += direction;
+        count -= 1f64;
+    }
+    Ok(Value::Object(obj))
+}
+
+/// Calls `f` with the index and value of every present element of `obj`, in ascending order, until it returns `false`.
+fn each_present(
+    obj: &ObjectRep,
+    len: f64,
+    mut f: impl FnMut(f64, Value) -> CoreResult<bool>,
+) -> CoreResult<()> {
+    let mut k = 0f64;
+    while k < len {
+        let key = PropertyKey::from(k);
+        if e262_has_property(obj, &key)? && !f(k, e262_get(obj, &key)?)? {
+            break;
+        }
+        k += 1f64;
+    }
+    Ok(())
+}
+
+/// Calls `callback` with `thisArg` as `this` on an element, as done by most iteration methods.
+fn call_back(
+    callback: &Value,
+    this_arg: &Value,
+    value: Value,
+    k: f64,
+    obj: &ObjectRep,
+) -> CoreResult<Value> {
+    e262_call(
+        callback,
+        this_arg,
+        &[value, Value::Number(k), Value::Object(obj.clone())],
+    )
+}
+
+/// Implements [`Array.prototype.every`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.every).
+fn every(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let callback = callback_argument(arguments)?;
+    let this_arg = p262_argument(arguments, 1);
+    let mut result = true;
+    each_present(&obj, len, |k, value| {
+        result = e262_to_boolean(&call_back(&callback, &this_arg, value, k, &obj)?);
+        Ok(result)
+    })?;
+    Ok(Value::Boolean(result))
+}
+
+/// Implements [`Array.prototype.fill`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.fill).
+fn fill(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let value = p262_argument(arguments, 0);
+    let mut k = p262_relative_index(&p262_argument(arguments, 1), len)?;
+    let end = relative_end(&p262_argument(arguments, 2), len)?;
+    while k < end {
+        e262_set(&obj, &PropertyKey::from(k), value.clone(), true)?;
+        k += 1f64;
+    }
+    Ok(Value::Object(obj))
+}
+
+/// Implements [`Array.prototype.filter`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.filter).
+fn filter(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let callback = callback_argument(arguments)?;
+    let this_arg = p262_argument(arguments, 1);
+    let array = array_species_create(&obj, 0f64)?;
+    let mut to = 0f64;
+    each_present(&obj, len, |k, value| {
+        let selected = call_back(&callback, &this_arg, value.clone(), k, &obj)?;
+        if e262_to_boolean(&selected) {
+            e262_create_data_property_or_throw(&array, PropertyKey::from(to), value)?;
+            to += 1f64;
+        }
+        Ok(true)
+    })?;
+    Ok(Value::Object(array))
+}
+
+/// Implements [FindViaPredicate](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-findviapredicate), returning the index and value of the first match.
+fn find_via_predicate(
+    this: &Value,
+    arguments: &[Value],
+    direction: Direction,
+) -> CoreResult<(f64, Value)> {
+    let (obj, len) = this_array_like(this)?;
+    let predicate = callback_argument(arguments)?;
+    let this_arg = p262_argument(arguments, 1);
+    let mut k = match direction {
+        Direction::Ascending => 0f64,
+        Direction::Descending => len - 1f64,
+    };
+    while 0f64 <= k && k < len {
+        let value = e262_get(&obj, &PropertyKey::from(k))?;
+        let result = call_back(&predicate, &this_arg, value.clone(), k, &obj)?;
+        if e262_to_boolean(&result) {
+            return Ok((k, value));
+        }
+        match direction {
+            Direction::Ascending => k += 1f64,
+            Direction::Descending => k -= 1f64,
+        }
+    }
+    Ok((-1f64, Value::Undefined))
+}
+
+/// The deepest nesting [`flatten_into_array`] descends into, which also stops it on cyclic arrays.
+const MAX_FLATTEN_DEPTH: usize = 1 << 16;
+
+/// Implements [FlattenIntoArray](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-flattenintoarray), where `mapper` holds the callback and `thisArg` of `flatMap`.
+///
+/// Nested arrays are flattened from an explicit stack rather than recursively, and nesting deeper
+/// than [`MAX_FLATTEN_DEPTH`] throws a RangeError.
+fn flatten_into_array(
+    target: &ObjectRep,
+    source: &ObjectRep,
+    source_len: f64,
+    start: f64,
+    depth: f64,
+    mapper: Option<(&Value, &Value)>,
+) -> CoreResult<f64> {
+    let mut target_index = start;
+    // Each source being flattened, with its length, its next index and the depth left below it.
+    let mut sources = vec![(source.clone(), source_len, 0f64, depth)];
+    while let Some((source, source_len, next_index, depth)) = sources.last_mut() {
+        if *next_index >= *source_len {
+            sources.pop();
+            continue;
+        }
+        let source_index = *next_index;
+        *next_index += 1f64;
+        let (source, depth) = (source.clone(), *depth);
+        let key = PropertyKey::from(source_index);
+        if !e262_has_property(&source, &key)? {
+            continue;
+        }
+        let mut element = e262_get(&source, &key)?;
+        if let (Some((mapper, this_arg)), 1) = (mapper, sources.len()) {
+            element = call_back(mapper, this_arg, element, source_index, &source)?;
+        }
+        match &element {
+            Value::Object(array) if depth > 0f64 && e262_is_array(&element)? => {
+                if sources.len() > MAX_FLATTEN_DEPTH {
+                    return Err(CoreError::RangeError(
+                        "Array is nested too deeply to flatten".to_string(),
+                    ));
+                }
+                let element_len = e262_length_of_array_like(array)?;
+                sources.push((array.clone(), element_len, 0f64, depth - 1f64));
+            }
+            _ => {
+                check_length(target_index + 1f64)?;
+                e262_create_data_property_or_throw(
+                    target,
+                    PropertyKey::from(target_index),
+                    element,
+                )?;
+                target_index += 1f64;
+            }
+        }
+    }
+    Ok(target_index)
+}
+
+/// Implements [`Array.prototype.flat`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.flat).
+fn flat(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, source_len) = this_array_like(this)?;
+    let depth = match p262_argument(arguments, 0) {
+        Value::Undefined => 1f64,
+        depth => e262_to_integer_or_infinity(&depth)?.to_f64().max(0f64),
+    };
+    let array = array_species_create(&obj, 0f64)?;
+    flatten_into_array(&array, &obj, source_len, 0f64, depth, None)?;
+    Ok(Value::Object(array))
+}
+
+/// Implements [`Array.prototype.flatMap`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.flatmap).
+fn flat_map(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, source_len) = this_array_like(this)?;
+    let mapper = callback_argument(arguments)?;
+    let this_arg = p262_argument(arguments, 1);
+    let array = array_species_create(&obj, 0f64)?;
+    flatten_into_array(
+        &array,
+        &obj,
+        source_len,
+        0f64,
+        1f64,
+        Some((&mapper, &this_arg)),
+    )?;
+    Ok(Value::Object(array))
+}
+
+/// Implements [`Array.prototype.forEach`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.foreach).
+fn for_each(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let callback = callback_argument(arguments)?;
+    let this_arg = p262_argument(arguments, 1);
+    each_present(&obj, len, |k, value| {
+        call_back(&callback, &this_arg, value, k, &obj)?;
+        Ok(true)
+    })?;
+    Ok(Value::Undefined)
+}
+
+/// Resolves the `fromIndex` argument of `includes` and `indexOf`, returning [`None`] when the search is empty.
+fn from_index(from_index: &Value, len: f64) -> CoreResult<Option<f64>> {
+    if len == 0f64 {
+        return Ok(None);
+    }
+    let n = e262_to_integer_or_infinity(from_index)?.to_f64();
+    if n == f64::INFINITY {
+        return Ok(None);
+    }
+    if n >= 0f64 {
+        Ok(Some(n))
+    } else {
+        Ok(Some((len + n).max(0f64)))
+    }
+}
+
+/// Implements [`Array.prototype.includes`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.includes).
+fn includes(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let search_element = p262_argument(arguments, 0);
+    let Some(mut k) = from_index(&p262_argument(arguments, 1), len)? else {
+        return Ok(Value::Boolean(false));
+    };
+    while k < len {
+        let element = e262_get(&obj, &PropertyKey::from(k))?;
+        if e262_same_value_zero(&search_element, &element) {
+            return Ok(Value::Boolean(true));
+        }
+        k += 1f64;
+    }
+    Ok(Value::Boolean(false))
+}
+
+/// Implements [`Array.prototype.indexOf`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.indexof).
+fn index_of(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let search_element = p262_argument(arguments, 0);
+    let Some(mut k) = from_index(&p262_argument(arguments, 1), len)? else {
+        return Ok(Value::Number(-1f64));
+    };
+    while k < len {
+        let key = PropertyKey::from(k);
+        if e262_has_property(&obj, &key)?
+            && e262_is_strictly_equal(&search_element, &e262_get(&obj, &key)?)
+        {
+            return Ok(Value::Number(k));
+        }
+        k += 1f64;
+    }
+    Ok(Value::Number(-1f64))
+}
+
+/// Implements [`Array.prototype.join`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.join).
+fn join(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let separator = match p262_argument(arguments, 0) {
+        Value::Undefined => StringRep::from(","),
+        separator => e262_to_string(&separator)?,
+    };
+    let mut result = StringRep::empty();
+    let mut k = 0f64;
+    while k < len {
+        if k > 0f64 {
+            result = result.concat(&separator);
+        }
+        match e262_get(&obj, &PropertyKey::from(k))? {
+            Value::Undefined | Value::Null => {}
+            element => result = result.concat(&e262_to_string(&element)?),
+        }
+        k += 1f64;
+    }
+    Ok(Value::String(result))
+}
+
+/// Implements [`Array.prototype.lastIndexOf`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.lastindexof).
+fn last_index_of(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    if len == 0f64 {
+        return Ok(Value::Number(-1f64));
+    }
+    let search_element = p262_argument(arguments, 0);
+    let n = match arguments.get(1) {
+        Some(from_index) => e262_to_integer_or_infinity(from_index)?.to_f64(),
+        None => len - 1f64,
+    };
+    let mut k = if n >= 0f64 {
+        n.min(len - 1f64)
+    } else {
+        len + n
+    };
+    while k >= 0f64 {
+        let key = PropertyKey::from(k);
+        if e262_has_property(&obj, &key)?
+            && e262_is_strictly_equal(&search_element, &e262_get(&obj, &key)?)
+        {
+            return Ok(Value::Number(k));
+        }
+        k -= 1f64;
+    }
+    Ok(Value::Number(-1f64))
+}
+
+/// Implements [`Array.prototype.map`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.map).
+fn map(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let callback = callback_argument(arguments)?;
+    let this_arg = p262_argument(arguments, 1);
+    let array = array_species_create(&obj, len)?;
+    each_present(&obj, len, |k, value| {
+        let mapped = call_back(&callback, &this_arg, value, k, &obj)?;
+        e262_create_data_property_or_throw(&array, PropertyKey::from(k), mapped)?;
+        Ok(true)
+    })?;
+    Ok(Value::Object(array))
+}
+
+/// Implements [`Array.prototype.pop`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.pop).
+fn pop(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    if len == 0f64 {
+        set_length(&obj, 0f64)?;
+        return Ok(Value::Undefined);
+    }
+    let new_len = len - 1f64;
+    let index = PropertyKey::from(new_len);
+    let element = e262_get(&obj, &index)?;
+    e262_delete_property_or_throw(&obj, &index)?;
+    set_length(&obj, new_len)?;
+    Ok(element)
+}
+
+/// Implements [`Array.prototype.push`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.push).
+fn push(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, mut len) = this_array_like(this)?;
+    check_length(len + arguments.len() as f64)?;
+    for item in arguments {
+        e262_set(&obj, &PropertyKey::from(len), item.clone(), true)?;
+        len += 1f64;
+    }
+    set_length(&obj, len)?;
+    Ok(Value::Number(len))
+}
+
+/// Implements [`Array.prototype.reduce`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.reduce) and [`Array.prototype.reduceRight`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.reduceright).
+fn reduce(this: &Value, arguments: &[Value], direction: Direction) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let callback = callback_argument(arguments)?;
+    let (mut k, step) = match direction {
+        Direction::Ascending => (0f64, 1f64),
+        Direction::Descending => (len - 1f64, -1f64),
+    };
+    let in_range = |k: f64| 0f64 <= k && k < len;
+    let mut accumulator = match arguments.get(1) {
+        Some(initial_value) => initial_value.clone(),
+        None => loop {
+            if !in_range(k) {
+                return Err(CoreError::TypeError(
+                    "Reduce of empty array with no initial value".to_string(),
+                ));
+            }
+            let key = PropertyKey::from(k);
+            k += step;
+            if e262_has_property(&obj, &key)? {
+                break e262_get(&obj, &key)?;
+            }
+        },
+    };
+    while in_range(k) {
+        let key = PropertyKey::from(k);
+        if e262_has_property(&obj, &key)? {
+            let value = e262_get(&obj, &key)?;
+            accumulator = e262_call(
+                &callback,
+                &Value::Undefined,
+                &[
+                    accumulator,
+                    value,
+                    Value::Number(k),
+                    Value::Object(obj.clone()),
+                ],
+            )?;
+        }
+        k += step;
+    }
+    Ok(accumulator)
+}
+
+/// Implements [`Array.prototype.reverse`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.reverse).
+fn reverse(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let middle = (len / 2f64).floor();
+    let mut lower = 0f64;
+    while lower != middle {
+        let lower_key = PropertyKey::from(lower);
+        let upper_key = PropertyKey::from(len - lower - 1f64);
+        let lower_value = match e262_has_property(&obj, &lower_key)? {
+            true => Some(e262_get(&obj, &lower_key)?),
+            false => None,
+        };
+        let upper_value = match e262_has_property(&obj, &upper_key)? {
+            true => Some(e262_get(&obj, &upper_key)?),
+            false => None,
+        };
+        match upper_value {
+            Some(value) => e262_set(&obj, &lower_key, value, true)?,
+            None => e262_delete_property_or_throw(&obj, &lower_key)?,
+        }
+        match lower_value {
+            Some(value) => e262_set(&obj, &upper_key, value, true)?,
+            None => e262_delete_property_or_throw(&obj, &upper_key)?,
+        }
+        lower += 1f64;
+    }
+    Ok(Value::Object(obj))
+}
+
+/// Implements [`Array.prototype.shift`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.shift).
+fn shift(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    if len == 0f64 {
+        set_length(&obj, 0f64)?;
+        return Ok(Value::Undefined);
+    }
+    let first = e262_get(&obj, &PropertyKey::from(0))?;
+    let mut k = 1f64;
+    while k < len {
+        move_element(&obj, k, k - 1f64)?;
+        k += 1f64;
+    }
+    e262_delete_property_or_throw(&obj, &PropertyKey::from(len - 1f64))?;
+    set_length(&obj, len - 1f64)?;
+    Ok(first)
+}
+
+/// Implements [`Array.prototype.slice`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.slice).
+fn slice(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let mut k = p262_relative_index(&p262_argument(arguments, 0), len)?;
+    let end = relative_end(&p262_argument(arguments, 1), len)?;
+    let array = array_species_create(&obj, (end - k).max(0f64))?;
+    let mut n = 0f64;
+    while k < end {
+        let key = PropertyKey::from(k);
+        if e262_has_property(&obj, &key)? {
+            let value = e262_get(&obj, &key)?;
+            e262_create_data_property_or_throw(&array, PropertyKey::from(n), value)?;
+        }
+        k += 1f64;
+        n += 1f64;
+    }
+    set_length(&array, n)?;
+    Ok(Value::Object(array))
+}
+
+/// Implements [`Array.prototype.some`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.some).
+fn some(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let callback = callback_argument(arguments)?;
+    let this_arg = p262_argument(arguments, 1);
+    let mut result = false;
+    each_present(&obj, len, |k, value| {
+        result = e262_to_boolean(&call_back(&callback, &this_arg, value, k, &obj)?);
+        Ok(!result)
+    })?;
+    Ok(Value::Boolean(result))
+}
+
+/// Returns the comparator passed to `sort` or `toSorted`, or throws a TypeError if it is neither undefined nor callable.
+fn comparator_argument(arguments: &[Value]) -> CoreResult<Value> {
+    let comparefn = p262_argument(arguments, 0);
+    if comparefn != Value::Undefined && !e262_is_callable(&comparefn) {
+        return Err(CoreError::TypeError(
+            "Comparator is not a function".to_string(),
+        ));
+    }
+    Ok(comparefn)
+}
+
+/// Implements [CompareArrayElements](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-comparearrayelements).
+fn compare_array_elements(x: &Value, y: &Value, comparefn: &Value) -> CoreResult<Ordering> {
+    match (x, y) {
+        (Value::Undefined, Value::Undefined) => return Ok(Ordering::Equal),
+        (Value::Undefined, _) => return Ok(Ordering::Greater),
+        (_, Value::Undefined) => return Ok(Ordering::Less),
+        _ => {}
+    }
+    if *comparefn != Value::Undefined {
+        let v = e262_to_number(&e262_call(
+            comparefn,
+            &Value::Undefined,
+            &[x.clone(), y.clone()],
+        )?)?;
+        return Ok(v.partial_cmp(&0f64).unwrap_or(Ordering::Equal));
+    }
+    Ok(e262_to_string(x)?.cmp(&e262_to_string(y)?))
+}
+
+/// Sorts `items` with a stable merge sort, stopping at the first abrupt completion of `compare`.
+fn merge_sort(
+    items: Vec<Value>,
+    compare: &impl Fn(&Value, &Value) -> CoreResult<Ordering>,
+) -> CoreResult<Vec<Value>> {
+    if items.len() <= 1 {
+        return Ok(items);
+    }
+    let mut left = items;
+    let right = left.split_off(left.len() / 2);
+    let left = merge_sort(left, compare)?;
+    let right = merge_sort(right, compare)?;
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(x), Some(y)) = (left.peek(), right.peek()) {
+        if compare(y, x)? == Ordering::Less {
+            merged.extend(right.next());
+        } else {
+            merged.extend(left.next());
+        }
+    }
+    merged.extend(left);
+    merged.extend(right);
+    Ok(merged)
+}
+
+/// Implements [SortIndexedProperties](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-sortindexedproperties), where `skip_holes` selects between skip-holes and read-through-holes.
+fn sort_indexed_properties(
+    obj: &ObjectRep,
+    len: f64,
+    comparefn: &Value,
+    skip_holes: bool,
+) -> CoreResult<Vec<Value>> {
+    let mut items = Vec::new();
+    let mut k = 0f64;
+    while k < len {
+        let key = PropertyKey::from(k);
+        if !skip_holes || e262_has_property(obj, &key)? {
+            items.push(e262_get(obj, &key)?);
+        }
+        k += 1f64;
+    }
+    merge_sort(items, &|x, y| compare_array_elements(x, y, comparefn))
+}
+
+/// Implements [`Array.prototype.sort`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.sort).
+fn sort(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let comparefn = comparator_argument(arguments)?;
+    let (obj, len) = this_array_like(this)?;
+    let sorted = sort_indexed_properties(&obj, len, &comparefn, true)?;
+    let item_count = sorted.len() as f64;
+    for (j, item) in sorted.into_iter().enumerate() {
+        e262_set(&obj, &PropertyKey::from(j as f64), item, true)?;
+    }
+    let mut j = item_count;
+    while j < len {
+        e262_delete_property_or_throw(&obj, &PropertyKey::from(j))?;
+        j += 1f64;
+    }
+    Ok(Value::Object(obj))
+}
+
+/// Resolves the `start` and `deleteCount` arguments of `splice` and `toSpliced`.
+fn splice_range(arguments: &[Value], len: f64) -> CoreResult<(f64, f64)> {
+    let start = p262_relative_index(&p262_argument(arguments, 0), len)?;
+    let count = match arguments {
+        [] => 0f64,
+        [_] => len - start,
+        [_, count, ..] => e262_to_integer_or_infinity(count)?
+            .to_f64()
+            .clamp(0f64, len - start),
+    };
+    Ok((start, count))
+}
+
+/// Implements [`Array.prototype.splice`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.splice).
+fn splice(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let (start, delete_count) = splice_range(arguments, len)?;
+    let items = arguments.get(2..).unwrap_or_default();
+    let item_count = items.len() as f64;
+    check_length(len + item_count - delete_count)?;
+    let array = array_species_create(&obj, delete_count)?;
+    let mut k = 0f64;
+    while k < delete_count {
+        let from = PropertyKey::from(start + k);
+        if e262_has_property(&obj, &from)? {
+            let value = e262_get(&obj, &from)?;
+            e262_create_data_property_or_throw(&array, PropertyKey::from(k), value)?;
+        }
+        k += 1f64;
+    }
+    set_length(&array, delete_count)?;
+    if item_count < delete_count {
+        k = start;
+        while k < len - delete_count {
+            move_element(&obj, k + delete_count, k + item_count)?;
+            k += 1f64;
+        }
+        k = len;
+        while k > len - delete_count + item_count {
+            e262_delete_property_or_throw(&obj, &PropertyKey::from(k - 1f64))?;
+            k -= 1f64;
+        }
+    } else if item_count > delete_count {
+        k = len - delete_count;
+        while k > start {
+            move_element(&obj, k + delete_count - 1f64, k + item_count - 1f64)?;
+            k -= 1f64;
+        }
+    }
+    for (offset, item) in items.iter().enumerate() {
+        e262_set(
+            &obj,
+            &PropertyKey::from(start + offset as f64),
+            item.clone(),
+            true,
+        )?;
+    }
+    set_length(&obj, len - delete_count + item_count)?;
+    Ok(Value::Object(array))
+}
+
+/// Implements [`Array.prototype.toLocaleString`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.tolocalestring), using a comma as list separator.
+fn to_locale_string(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let separator = StringRep::from(",");
+    let mut result = StringRep::empty();
+    let mut k = 0f64;
+    while k < len {
+        if k > 0f64 {
+            result = result.concat(&separator);
+        }
+        match e262_get(&obj, &PropertyKey::from(k))? {
+            Value::Undefined | Value::Null => {}
+            element => {
+                let string = e262_invoke(&element, &PropertyKey::from("toLocaleString"), &[])?;
+                result = result.concat(&e262_to_string(&string)?);
+            }
+        }
+        k += 1f64;
+    }
+    Ok(Value::String(result))
+}
+
+/// Implements [`Array.prototype.toReversed`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.toreversed).
+fn to_reversed(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let array = e262_array_create(len, None)?;
+    let mut k = 0f64;
+    while k < len {
+        let value = e262_get(&obj, &PropertyKey::from(len - k - 1f64))?;
+        e262_create_data_property_or_throw(&array, PropertyKey::from(k), value)?;
+        k += 1f64;
+    }
+    Ok(Value::Object(array))
+}
+
+/// Implements [`Array.prototype.toSorted`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.tosorted).
+fn to_sorted(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let comparefn = comparator_argument(arguments)?;
+    let (obj, len) = this_array_like(this)?;
+    let array = e262_array_create(len, None)?;
+    let sorted = sort_indexed_properties(&obj, len, &comparefn, false)?;
+    for (j, item) in sorted.into_iter().enumerate() {
+        e262_create_data_property_or_throw(&array, PropertyKey::from(j as f64), item)?;
+    }
+    Ok(Value::Object(array))
+}
+
+/// Implements [`Array.prototype.toSpliced`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.tospliced).
+fn to_spliced(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let (start, skip_count) = splice_range(arguments, len)?;
+    let items = arguments.get(2..).unwrap_or_default();
+    let new_len = len + items.len() as f64 - skip_count;
+    check_length(new_len)?;
+    let array = e262_array_create(new_len, None)?;
+    let mut i = 0f64;
+    while i < start {
+        let key = PropertyKey::from(i);
+        e262_create_data_property_or_throw(&array, key.clone(), e262_get(&obj, &key)?)?;
+        i += 1f64;
+    }
+    for item in items {
+        e262_create_data_property_or_throw(&array, PropertyKey::from(i), item.clone())?;
+        i += 1f64;
+    }
+    let mut r = start + skip_count;
+    while i < new_len {
+        let value = e262_get(&obj, &PropertyKey::from(r))?;
+        e262_create_data_property_or_throw(&array, PropertyKey::from(i), value)?;
+        i += 1f64;
+        r += 1f64;
+    }
+    Ok(Value::Object(array))
+}
+
+/// Implements [`Array.prototype.toString`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.tostring).
+fn to_string(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let array = e262_to_object(this)?;
+    let mut func = e262_get(&array, &PropertyKey::from("join"))?;
+    if !e262_is_callable(&func) {
+        func = Value::Object(e262_current_realm().intrinsic(Intrinsic::ObjectPrototypeToString));
+    }
+    e262_call(&func, &Value::Object(array), &[])
+}
+
+/// Implements [`Array.prototype.unshift`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.unshift).
+fn unshift(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let arg_count = arguments.len() as f64;
+    if !arguments.is_empty() {
+        check_length(len + arg_count)?;
+        let mut k = len;
+        while k > 0f64 {
+            move_element(&obj, k - 1f64, k + arg_count - 1f64)?;
+            k -= 1f64;
+        }
+        for (j, item) in arguments.iter().enumerate() {
+            e262_set(&obj, &PropertyKey::from(j as f64), item.clone(), true)?;
+        }
+    }
+    set_length(&obj, len + arg_count)?;
+    Ok(Value::Number(len + arg_count))
+}
+
+/// Implements [`Array.prototype.with`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.with).
+fn with(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let (obj, len) = this_array_like(this)?;
+    let relative = e262_to_integer_or_infinity(&p262_argument(arguments, 0))?.to_f64();
+    let actual_index = if relative >= 0f64 {
+        relative
+    } else {
+        len + relative
+    };
+    if actual_index >= len || actual_index < 0f64 {
+        return Err(CoreError::RangeError("Invalid array index".to_string()));
+    }
+    let array = e262_array_create(len, None)?;
+    let mut k = 0f64;
+    while k < len {
+        let key = PropertyKey::from(k);
+        let value = match k == actual_index {
+            true => p262_argument(arguments, 1),
+            false => e262_get(&obj, &key)?,
+        };
+        e262_create_data_property_or_throw(&array, key, value)?;
+        k += 1f64;
+    }
+    Ok(Value::Object(array))
+}
+
+/// Creates the null-prototype object stored in [`Array.prototype[@@unscopables]`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype-@@unscopables).
+fn unscopable_list() -> ObjectRep {
+    let list = e262_ordinary_object_create(None);
+    for name in [
+        "at",
+        "copyWithin",
+        "entries",
+        "fill",
+        "find",
+        "findIndex",
+        "findLast",
+        "findLastIndex",
+        "flat",
+        "flatMap",
+        "includes",
+        "keys",
+        "toReversed",
+        "toSorted",
+        "toSpliced",
+        "values",
+    ] {
+        e262_create_data_property_or_throw(&list, PropertyKey::from(name), Value::Boolean(true))
+            .expect("Fresh ordinary objects accept new properties");
+    }
+    list
+}
+
+/// Implements [CreateArrayIterator](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-createarrayiterator).
+fn create_array_iterator(array: ObjectRep, kind: PropertyKind) -> ObjectRep {
+    let prototype = e262_current_realm().intrinsic(Intrinsic::ArrayIteratorPrototype);
+    let iterator = e262_ordinary_object_create(Some(prototype));
+    let slots = iterator.0.clone();
+    slots.clone().set_slot(
+        "IteratedArrayLike".to_string(),
+        Rc::new(RefCell::new(Some(array))),
+    );
+    slots
+        .clone()
+        .set_slot("ArrayLikeNextIndex".to_string(), Rc::new(Cell::new(0f64)));
+    slots.set_slot("ArrayLikeIterationKind".to_string(), Rc::new(kind));
+    iterator
+}
+
+/// Implements [`%ArrayIteratorPrototype%.next`](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%arrayiteratorprototype%.next).
+fn array_iterator_next(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let slots = match this {
+        Value::Object(obj) => {
+            let array = p262_get_slot::<RefCell<Option<ObjectRep>>>(
+                obj.0.clone(),
+                "IteratedArrayLike".to_string(),
+            );
+            let index = p262_get_slot::<Cell<f64>>(obj.0.clone(), "ArrayLikeNextIndex".to_string());
+            let kind =
+                p262_get_slot::<PropertyKind>(obj.0.clone(), "ArrayLikeIterationKind".to_string());
+            array.zip(index).zip(kind)
+        }
+        _ => None,
+    };
+    let Some(((array, index), kind)) = slots else {
+        return Err(CoreError::TypeError(
+            "Receiver is not an Array Iterator".to_string(),
+        ));
+    };
+    let Some(obj) = array.borrow().clone() else {
+        return Ok(Value::Object(e262_create_iter_result_object(
+            Value::Undefined,
+            true,
+        )));
+    };
+    let k = index.get();
+    if k >= e262_length_of_array_like(&obj)? {
+        array.replace(None);
+        return Ok(Value::Object(e262_create_iter_result_object(
+            Value::Undefined,
+            true,
+        )));
+    }
+    index.set(k + 1f64);
+    let result = match *kind {
+        PropertyKind::Key => Value::Number(k),
+        PropertyKind::Value => e262_get(&obj, &PropertyKey::from(k))?,
+        PropertyKind::KeyValue => {
+            let value = e262_get(&obj, &PropertyKey::from(k))?;
+            Value::Object(e262_create_array_from_list(&[Value::Number(k), value]))
+        }
+    };
+    Ok(Value::Object(e262_create_iter_result_object(result, false)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::e262_create_builtin_function;
+
+    fn array<const N: usize>(values: [f64; N]) -> Value {
+        let values: Vec<Value> = values.into_iter().map(Value::Number).collect();
+        Value::Object(e262_create_array_from_list(&values))
+    }
+
+    fn invoke<const N: usize>(
+        this: &Value,
+        method: &str,
+        arguments: [Value; N],
+    ) -> CoreResult<Value> {
+        e262_invoke(this, &PropertyKey::from(method), &arguments)
+    }
+
+    fn call<const N: usize>(method: &str, arguments: [Value; N]) -> CoreResult<Value> {
+        let constructor = Value::Object(e262_current_realm().intrinsic(Intrinsic::Array));
+        invoke(&constructor, method, arguments)
+    }
+
+    fn function(behaviour: impl Fn(&Value, &[Value]) -> CoreResult<Value> + 'static) -> Value {
+        Value::Object(e262_create_builtin_function(
+            Rc::new(behaviour),
+            0,
+            "",
+            None,
+        ))
+    }
+
+    /// Joins the elements of an array-like with commas, so that holes show up as empty strings.
+    fn contents(value: &Value) -> String {
+        let Value::String(joined) = invoke(value, "join", []).unwrap() else {
+            unreachable!("join returns a string");
+        };
+        joined.to_std_string_lossy()
+    }
+
+    fn get(value: &Value, key: impl Into<PropertyKey>) -> Value {
+        let Value::Object(obj) = value else {
+            panic!("{value:?} is not an object");
+        };
+        e262_get(obj, &key.into()).unwrap()
+    }
+
+    #[test]
+    fn constructor_handles_lengths_and_elements() {
+        let constructor = e262_current_realm().intrinsic(Intrinsic::Array);
+        let sparse = e262_construct(&constructor, &[Value::Number(3f64)], None).unwrap();
+        assert_eq!(contents(&Value::Object(sparse)), ",,");
+        let list = e262_construct(&constructor, &[Value::Number(1f64), Value::Null], None);
+        assert_eq!(
+            get(&Value::Object(list.unwrap()), "length"),
+            Value::Number(2f64)
+        );
+        assert!(matches!(
+            e262_construct(&constructor, &[Value::Number(1.5f64)], None),
+            Err(CoreError::RangeError(_))
+        ));
+        let called = e262_call(
+            &Value::Object(constructor),
+            &Value::Undefined,
+            &[Value::Null],
+        );
+        assert_eq!(contents(&called.unwrap()), "");
+    }
+
+    #[test]
+    fn calls_construct_in_the_function_realm() {
+        let other = Realm::new();
+        let called = e262_call(
+            &Value::Object(other.intrinsic(Intrinsic::Array)),
+            &Value::Undefined,
+            &[],
+        );
+        let Value::Object(called) = called.unwrap() else {
+            panic!("Array returns an object when called")
+        };
+        let proto = called.0.clone().get_prototype_of().unwrap();
+        assert_eq!(proto, Some(other.intrinsic(Intrinsic::ArrayPrototype)));
+    }
+
+    #[test]
+    fn from_of_and_is_array() {
+        let from_iterable = call("from", [array([1f64, 2f64])]).unwrap();
+        assert_eq!(contents(&from_iterable), "1,2");
+
+        let array_like = Value::Object(e262_ordinary_object_create(None));
+        let Value::Object(obj) = &array_like else {
+            unreachable!()
+        };
+        e262_set(obj, &PropertyKey::from("length"), Value::Number(2f64), true).unwrap();
+        e262_set(obj, &PropertyKey::from(1), Value::Number(7f64), true).unwrap();
+        let double = function(|_, args| match p262_argument(args, 0) {
+            Value::Number(n) => Ok(Value::Number(n * 2f64)),
+            _ => Ok(Value::Number(f64::NAN)),
+        });
+        assert_eq!(
+            contents(&call("from", [array_like, double]).unwrap()),
+            "NaN,14"
+        );
+        assert!(call("from", [array([]), Value::Null]).is_err());
+
+        let of = call("of", [Value::Number(3f64)]).unwrap();
+        assert_eq!(contents(&of), "3");
+        assert_eq!(call("isArray", [of]).unwrap(), Value::Boolean(true));
+        assert_eq!(
+            call(
+                "isArray",
+                [Value::Object(e262_ordinary_object_create(None))]
+            )
+            .unwrap(),
+            Value::Boolean(false)
+        );
+    }
+
+    #[test]
+    fn concat_spreads_arrays_and_spreadable_objects() {
+        let spreadable = e262_ordinary_object_create(None);
+        let key = SymbolRep::well_known(WellKnownSymbol::IsConcatSpreadable);
+        e262_set(
+            &spreadable,
+            &PropertyKey::from(key),
+            Value::Boolean(true),
+            true,
+        )
+        .unwrap();
+        e262_set(
+            &spreadable,
+            &PropertyKey::from("length"),
+            Value::Number(1f64),
+            true,
+        )
+        .unwrap();
+        e262_set(
+            &spreadable,
+            &PropertyKey::from(0),
+            Value::Number(4f64),
+            true,
+        )
+        .unwrap();
+        let result = invoke(
+            &array([1f64]),
+            "concat",
+            [
+                array([2f64, 3f64]),
+                Value::Object(spreadable),
+                Value::Number(5f64),
+            ],
+        )
+        .unwrap();
+        assert_eq!(contents(&result), "1,2,3,4,5");
+    }
+
+    #[test]
+    fn index_helpers_resolve_relative_positions() {
+        let values = array([1f64, 2f64, 3f64, 4f64, 5f64]);
+        assert_eq!(
+            invoke(&values, "at", [Value::Number(-1f64)]).unwrap(),
+            Value::Number(5f64)
+        );
+        assert_eq!(
+            invoke(&values, "at", [Value::Number(5f64)]).unwrap(),
+            Value::Undefined
+        );
+        let sliced = invoke(
+            &values,
+            "slice",
+            [Value::Number(-3f64), Value::Number(-1f64)],
+        );
+        assert_eq!(contents(&sliced.unwrap()), "3,4");
+        let copied = invoke(
+            &values,
+            "copyWithin",
+            [Value::Number(1f64), Value::Number(0f64)],
+        );
+        assert_eq!(contents(&copied.unwrap()), "1,1,2,3,4");
+        let filled = invoke(&values, "fill", [Value::Number(0f64), Value::Number(3f64)]);
+        assert_eq!(contents(&filled.unwrap()), "1,1,2,0,0");
+        let replaced = invoke(&values, "with", [Value::Number(-5f64), Value::Number(9f64)]);
+        assert_eq!(contents(&replaced.unwrap()), "9,1,2,0,0");
+        assert!(matches!(
+            invoke(&values, "with", [Value::Number(5f64), Value::Number(9f64)]),
+            Err(CoreError::RangeError(_))
+        ));
+    }
+
+    #[test]
+    fn searches_use_the_right_equality() {
+        let values = array([f64::NAN, 0f64, 1f64, 0f64]);
+        assert_eq!(
+            invoke(&values, "includes", [Value::Number(f64::NAN)]).unwrap(),
+            Value::Boolean(true)
+        );
+        assert_eq!(
+            invoke(&values, "indexOf", [Value::Number(f64::NAN)]).unwrap(),
+            Value::Number(-1f64)
+        );
+        assert_eq!(
+            invoke(&values, "indexOf", [Value::Number(-0f64)]).unwrap(),
+            Value::Number(1f64)
+        );
+        assert_eq!(
+            invoke(&values, "lastIndexOf", [Value::Number(0f64)]).unwrap(),
+            Value::Number(3f64)
+        );
+        assert_eq!(
+            invoke(
+                &values,
+                "lastIndexOf",
+                [Value::Number(0f64), Value::Number(-2f64)]
+            )
+            .unwrap(),
+            Value::Number(1f64)
+        );
+        let is_one = function(|_, args| {
+            Ok(Value::Boolean(
+                p262_argument(args, 0) == Value::Number(1f64),
+            ))
+        });
+        assert_eq!(
+            invoke(&values, "findIndex", [is_one.clone()]).unwrap(),
+            Value::Number(2f64)
+        );
+        assert_eq!(
+            invoke(&values, "findLast", [is_one.clone()]).unwrap(),
+            Value::Number(1f64)
+        );
+        assert_eq!(
+            invoke(&values, "some", [is_one.clone()]).unwrap(),
+            Value::Boolean(true)
+        );
+        assert_eq!(
+            invoke(&values, "every", [is_one]).unwrap(),
+            Value::Boolean(false)
+        );
+    }
+
+    #[test]
+    fn callbacks_skip_holes() {
+        let constructor = e262_current_realm().intrinsic(Intrinsic::Array);
+        let sparse =
+            Value::Object(e262_construct(&constructor, &[Value::Number(3f64)], None).unwrap());
+        invoke(
+            &sparse,
+            "fill",
+            [
+                Value::Number(1f64),
+                Value::Number(1f64),
+                Value::Number(2f64),
+            ],
+        )
+        .unwrap();
+        let count = Rc::new(Cell::new(0));
+        let counter = count.clone();
+        let callback = function(move |_, _| {
+            counter.set(counter.get() + 1);
+            Ok(Value::Boolean(true))
+        });
+        let filtered = invoke(&sparse, "filter", [callback.clone()]).unwrap();
+        assert_eq!(contents(&filtered), "1");
+        let mapped = invoke(&sparse, "map", [callback]).unwrap();
+        assert_eq!(contents(&mapped), ",true,");
+        assert_eq!(count.get(), 2);
+
+        let add = function(|_, args| {
+            let (Value::Number(x), Value::Number(y)) =
+                (p262_argument(args, 0), p262_argument(args, 1))
+            else {
+                unreachable!()
+            };
+            Ok(Value::Number(x + y))
+        });
+        let values = array([1f64, 2f64, 3f64]);
+        assert_eq!(
+            invoke(&values, "reduce", [add.clone()]).unwrap(),
+            Value::Number(6f64)
+        );
+        assert_eq!(
+            invoke(&values, "reduceRight", [add.clone(), Value::Number(4f64)]).unwrap(),
+            Value::Number(10f64)
+        );
+        assert!(matches!(
+            invoke(&array([]), "reduce", [add]),
+            Err(CoreError::TypeError(_))
+        ));
+    }
+
+    #[test]
+    fn flat_and_flat_map_respect_depth() {
+        let nested = Value::Object(e262_create_array_from_list(&[
+            Value::Number(1f64),
+            Value::Object(e262_create_array_from_list(&[
+                Value::Number(2f64),
+                array([3f64]),
+            ])),
+        ]));
+        let flat = invoke(&nested, "flat", []).unwrap();
+        assert_eq!(get(&flat, "length"), Value::Number(3f64));
+        let deep = invoke(&nested, "flat", [Value::Number(f64::INFINITY)]).unwrap();
+        assert_eq!(get(&deep, "length"), Value::Number(3f64));
+        assert_eq!(get(&deep, 2), Value::Number(3f64));
+        let pair = function(|_, args| {
+            let value = p262_argument(args, 0);
+            Ok(Value::Object(e262_create_array_from_list(&[
+                value.clone(),
+                value,
+            ])))
+        });
+        let mapped = invoke(&array([1f64, 2f64]), "flatMap", [pair]).unwrap();
+        assert_eq!(contents(&mapped), "1,1,2,2");
+
+        let cyclic = array([1f64]);
+        let Value::Object(obj) = &cyclic else {
+            unreachable!()
+        };
+        e262_set(obj, &PropertyKey::from(1), cyclic.clone(), true).unwrap();
+        let deep = invoke(&cyclic, "flat", [Value::Number(20_000f64)]).unwrap();
+        assert_eq!(get(&deep, "length"), Value::Number(20_002f64));
+        assert_eq!(get(&deep, 20_001), cyclic);
+        assert!(matches!(
+            invoke(&cyclic, "flat", [Value::Number(f64::INFINITY)]),
+            Err(CoreError::RangeError(_))
+        ));
+        e262_set(obj, &PropertyKey::from(1), Value::Null, true).unwrap();
+    }
+
+    #[test]
+    fn mutators_shift_elements() {
+        let values = array([1f64, 2f64, 3f64]);
+        assert_eq!(
+            invoke(&values, "push", [Value::Number(4f64)]).unwrap(),
+            Value::Number(4f64)
+        );
+        assert_eq!(invoke(&values, "pop", []).unwrap(), Value::Number(4f64));
+        assert_eq!(invoke(&values, "shift", []).unwrap(), Value::Number(1f64));
+        assert_eq!(
+            invoke(
+                &values,
+                "unshift",
+                [Value::Number(0f64), Value::Number(1f64)]
+            )
+            .unwrap(),
+            Value::Number(4f64)
+        );
+        assert_eq!(
+            contents(&invoke(&values, "reverse", []).unwrap()),
+            "3,2,1,0"
+        );
+        let removed = invoke(
+            &values,
+            "splice",
+            [
+                Value::Number(1f64),
+                Value::Number(2f64),
+                Value::Number(9f64),
+            ],
+        )
+        .unwrap();
+        assert_eq!(contents(&removed), "2,1");
+        assert_eq!(contents(&values), "3,9,0");
+        let spliced = invoke(
+            &values,
+            "toSpliced",
+            [
+                Value::Number(-1f64),
+                Value::Number(0f64),
+                Value::Number(8f64),
+            ],
+        )
+        .unwrap();
+        assert_eq!(contents(&spliced), "3,9,8,0");
+        assert_eq!(
+            contents(&invoke(&values, "toReversed", []).unwrap()),
+            "0,9,3"
+        );
+        assert_eq!(contents(&values), "3,9,0");
+    }
+
+    #[test]
+    fn sort_is_stable_and_propagates_comparator_errors() {
+        let values = array([10f64, 9f64, 1f64, 2f64]);
+        assert_eq!(
+            contents(&invoke(&values, "toSorted", []).unwrap()),
+            "1,10,2,9"
+        );
+        let pairs = array([3f64, 1f64, 4f64, 2f64, 5f64]);
+        let by_parity =
+            function(
+                |_, args| match (p262_argument(args, 0), p262_argument(args, 1)) {
+                    (Value::Number(x), Value::Number(y)) => Ok(Value::Number(x % 2f64 - y % 2f64)),
+                    _ => unreachable!(),
+                },
+            );
+        let sorted = invoke(&pairs, "sort", [by_parity]).unwrap();
+        assert_eq!(contents(&sorted), "4,2,3,1,5");
+        let failing = function(|_, _| Err(CoreError::RangeError("comparator".to_string())));
+        assert!(matches!(
+            invoke(&pairs, "sort", [failing]),
+            Err(CoreError::RangeError(_))
+        ));
+        assert_eq!(contents(&pairs), "4,2,3,1,5");
+        assert!(invoke(&pairs, "sort", [Value::Null]).is_err());
+
+        let constructor = e262_current_realm().intrinsic(Intrinsic::Array);
+        let holes =
+            Value::Object(e262_construct(&constructor, &[Value::Number(3f64)], None).unwrap());
+        invoke(&holes, "fill", [Value::Undefined, Value::Number(2f64)]).unwrap();
+        invoke(
+            &holes,
+            "fill",
+            [
+                Value::Number(1f64),
+                Value::Number(1f64),
+                Value::Number(2f64),
+            ],
+        )
+        .unwrap();
+        invoke(&holes, "sort", []).unwrap();
+        assert_eq!(get(&holes, 0), Value::Number(1f64));
+        let Value::Object(holes) = holes else {
+            unreachable!()
+        };
+        assert!(!e262_has_property(&holes, &PropertyKey::from(2)).unwrap());
+    }
+
+    #[test]
+    fn iterators_walk_keys_values_and_entries() {
+        let values = array([5f64, 6f64]);
+        let entries = invoke(&values, "entries", []).unwrap();
+        let first = invoke(&entries, "next", []).unwrap();
+        assert_eq!(get(&get(&first, "value"), 1), Value::Number(5f64));
+        let keys = call("from", [invoke(&values, "keys", []).unwrap()]).unwrap();
+        assert_eq!(contents(&keys), "0,1");
+        let key = SymbolRep::well_known(WellKnownSymbol::Iterator);
+        let iterator = invoke(&values, "values", []).unwrap();
+        assert_eq!(
+            e262_invoke(&iterator, &PropertyKey::from(key), &[]).unwrap(),
+            iterator
+        );
+        invoke(&iterator, "next", []).unwrap();
+        invoke(&iterator, "next", []).unwrap();
+        let done = invoke(&iterator, "next", []).unwrap();
+        assert_eq!(get(&done, "done"), Value::Boolean(true));
+        assert!(invoke(&iterator, "next", []).is_ok());
+        let next = get(&iterator, "next");
+        assert!(e262_call(&next, &values, &[]).is_err());
+    }
+
+    #[test]
+    fn species_and_to_string() {
+        let values = array([1f64, 2f64]);
+        assert_eq!(
+            invoke(&values, "toString", []).unwrap(),
+            Value::String("1,2".into())
+        );
+        let Value::Object(obj) = &values else {
+            unreachable!()
+        };
+        e262_set(
+            obj,
+            &PropertyKey::from("constructor"),
+            Value::Number(0f64),
+            true,
+        )
+        .unwrap();
+        assert!(matches!(
+            invoke(&values, "map", [function(|_, _| Ok(Value::Null))]),
+            Err(CoreError::TypeError(_))
+        ));
+        e262_set(
+            obj,
+            &PropertyKey::from("constructor"),
+            Value::Undefined,
+            true,
+        )
+        .unwrap();
+        assert!(invoke(&values, "slice", []).is_ok());
+        let join = get(&values, "join");
+        e262_set(obj, &PropertyKey::from("join"), Value::Null, true).unwrap();
+        let Value::String(tag) = invoke(&values, "toString", []).unwrap() else {
+            unreachable!("toString returns a string");
+        };
+        assert_eq!(tag.to_std_string_lossy(), "[object Array]");
+        e262_set(obj, &PropertyKey::from("join"), join, true).unwrap();
+        assert_eq!(contents(&values), "1,2");
+    }
+
+    #[test]
+    fn species_ignores_another_realms_array() {
+        let other = Realm::new();
+        let foreign = e262_construct(&other.intrinsic(Intrinsic::Array), &[], None).unwrap();
+        let current = e262_current_realm();
+        let slice = get(
+            &Value::Object(current.intrinsic(Intrinsic::ArrayPrototype)),
+            "slice",
+        );
+        let Value::Object(copy) = e262_call(&slice, &Value::Object(foreign), &[]).unwrap() else {
+            panic!("slice returns an array")
+        };
+        let proto = copy.0.clone().get_prototype_of().unwrap();
+        assert_eq!(proto, Some(current.intrinsic(Intrinsic::ArrayPrototype)));
+
+        let local = array([]);
+        let Value::Object(copy) = e262_call(&slice, &local, &[]).unwrap() else {
+            panic!("slice returns an array")
+        };
+        let proto = copy.0.clone().get_prototype_of().unwrap();
+        assert_eq!(proto, Some(current.intrinsic(Intrinsic::ArrayPrototype)));
+    }
+}
@@ -0,0 +1,192 @@
+use std::rc::Rc;
+
+use num_bigint::BigInt;
+
+use super::{p262_argument, p262_define_method, p262_link_constructor};
+use crate::core::bigint::{e262_as_int_n, e262_as_uint_n, e262_number_to_big_int};
+use crate::core::cast::{
+    e262_to_big_int, e262_to_index, e262_to_integer_or_infinity, e262_to_primitive, PreferredType,
+};
+use crate::core::{
+    bigint, e262_create_builtin_function, e262_define_property_or_throw, p262_get_slot, Descriptor,
+    PropertyKey, SymbolRep, Value, WellKnownSymbol,
+};
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::{Intrinsic, Realm};
+
+/// Creates `%BigInt%` and the methods of `%BigInt.prototype%`.
+///
+/// `%BigInt%` has no `[[Construct]]`, so `new BigInt()` throws a TypeError.
+pub(crate) fn p262_create_intrinsics(realm: &Realm) {
+    let prototype = realm.intrinsic(Intrinsic::BigIntPrototype);
+    let constructor = e262_create_builtin_function(
+        Rc::new(big_int),
+        1,
+        "BigInt",
+        Some(realm.intrinsic(Intrinsic::FunctionPrototype)),
+    );
+    p262_link_constructor(&constructor, &prototype);
+    realm.set_intrinsic(Intrinsic::BigInt, constructor.clone());
+
+    p262_define_method(realm, &constructor, "asIntN", 2, |_, arguments| {
+        let bits = e262_to_index(&p262_argument(arguments, 0))?;
+        let bigint = e262_to_big_int(&p262_argument(arguments, 1))?;
+        Ok(Value::BigInt(e262_as_int_n(bits as u64, &bigint)))
+    });
+    p262_define_method(realm, &constructor, "asUintN", 2, |_, arguments| {
+        let bits = e262_to_index(&p262_argument(arguments, 0))?;
+        let bigint = e262_to_big_int(&p262_argument(arguments, 1))?;
+        Ok(Value::BigInt(e262_as_uint_n(bits as u64, &bigint)?))
+    });
+
+    p262_define_method(realm, &prototype, "toLocaleString", 0, |this, _| {
+        to_string(this, &[])
+    });
+    p262_define_method(realm, &prototype, "toString", 0, to_string);
+    p262_define_method(realm, &prototype, "valueOf", 0, |this, _| {
+        Ok(Value::BigInt(this_big_int_value(this)?))
+    });
+    let to_string_tag = SymbolRep::well_known(WellKnownSymbol::ToStringTag);
+    e262_define_property_or_throw(
+        &prototype,
+        PropertyKey::from(to_string_tag),
+        Descriptor {
+            value: Some(Rc::new(Value::String("BigInt".into()))),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Built-in properties can always be defined");
+}
+
+/// Implements [`BigInt`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-bigint-constructor-number-value).
+fn big_int(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let value = p262_argument(arguments, 0);
+    let prim = e262_to_primitive(&value, Some(PreferredType::Number))?;
+    match prim {
+        Value::Number(number) => Ok(Value::BigInt(e262_number_to_big_int(number)?)),
+        _ => Ok(Value::BigInt(e262_to_big_int(&prim)?)),
+    }
+}
+
+/// Implements [thisBigIntValue](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-thisbigintvalue).
+fn this_big_int_value(value: &Value) -> CoreResult<BigInt> {
+    match value {
+        Value::BigInt(bigint) => Ok(bigint.clone()),
+        Value::Object(obj) => p262_get_slot::<BigInt>(obj.0.clone(), "BigIntData".to_string())
+            .map(|bigint| (*bigint).clone())
+            .ok_or_else(|| CoreError::TypeError("Receiver is not a BigInt".to_string())),
+        _ => Err(CoreError::TypeError("Receiver is not a BigInt".to_string())),
+    }
+}
+
+/// Implements [`BigInt.prototype.toString`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-bigint.prototype.tostring).
+fn to_string(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let x = this_big_int_value(this)?;
+    let radix = match p262_argument(arguments, 0) {
+        Value::Undefined => 10f64,
+        radix => e262_to_integer_or_infinity(&radix)?.to_f64(),
+    };
+    if !(2f64..=36f64).contains(&radix) {
+        return Err(CoreError::RangeError(
+            "toString() radix must be between 2 and 36".to_string(),
+        ));
+    }
+    Ok(Value::String(
+        bigint::e262_to_string(&x, radix as u32).into(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::e262_current_realm;
+    use crate::core::function::{e262_call, e262_construct};
+    use crate::core::{e262_get, e262_invoke};
+
+    fn big(value: i64) -> Value {
+        Value::BigInt(BigInt::from(value))
+    }
+
+    fn call<const N: usize>(arguments: [Value; N]) -> CoreResult<Value> {
+        let constructor = e262_current_realm().intrinsic(Intrinsic::BigInt);
+        e262_call(&Value::Object(constructor), &Value::Undefined, &arguments)
+    }
+
+    fn invoke<const N: usize>(this: Value, name: &str, arguments: [Value; N]) -> CoreResult<Value> {
+        e262_invoke(&this, &PropertyKey::from(name), &arguments)
+    }
+
+    #[test]
+    fn converts_but_does_not_construct() {
+        assert_eq!(call([Value::Number(-12f64)]).unwrap(), big(-12));
+        assert_eq!(call([Value::String(" 0x1f ".into())]).unwrap(), big(31));
+        assert_eq!(call([Value::Boolean(true)]).unwrap(), big(1));
+        assert!(matches!(
+            call([Value::Number(1.5)]),
+            Err(CoreError::RangeError(_))
+        ));
+        assert!(matches!(
+            call([Value::Number(f64::NAN)]),
+            Err(CoreError::RangeError(_))
+        ));
+        assert!(matches!(
+            call([Value::String("1.5".into())]),
+            Err(CoreError::SyntaxError(_))
+        ));
+        assert!(matches!(call([]), Err(CoreError::TypeError(_))));
+        let constructor = e262_current_realm().intrinsic(Intrinsic::BigInt);
+        assert!(matches!(
+            e262_construct(&constructor, &[big(1)], None),
+            Err(CoreError::TypeError(_))
+        ));
+    }
+
+    #[test]
+    fn wraps_to_bit_widths() {
+        let constructor = Value::Object(e262_current_realm().intrinsic(Intrinsic::BigInt));
+        let bits = |n: f64| Value::Number(n);
+        assert_eq!(
+            invoke(constructor.clone(), "asIntN", [bits(8f64), big(255)]).unwrap(),
+            big(-1)
+        );
+        assert_eq!(
+            invoke(constructor.clone(), "asUintN", [bits(8f64), big(-1)]).unwrap(),
+            big(255)
+        );
+        assert_eq!(
+            invoke(constructor.clone(), "asIntN", [bits(0f64), big(5)]).unwrap(),
+            big(0)
+        );
+        assert!(matches!(
+            invoke(constructor, "asIntN", [bits(-1f64), big(5)]),
+            Err(CoreError::RangeError(_))
+        ));
+    }
+
+    #[test]
+    fn prototype_methods_use_the_big_int_data() {
+        assert_eq!(
+            invoke(big(255), "toString", [Value::Number(16f64)]).unwrap(),
+            Value::String("ff".into())
+        );
+        assert_eq!(
+            invoke(big(-10), "toLocaleString", []).unwrap(),
+            Value::String("-10".into())
+        );
+        assert!(matches!(
+            invoke(big(1), "toString", [Value::Number(1f64)]),
+            Err(CoreError::RangeError(_))
+        ));
+        let wrapper = Value::Object(crate::core::cast::e262_to_object(&big(7)).unwrap());
+        assert_eq!(invoke(wrapper, "valueOf", []).unwrap(), big(7));
+        let value_of = e262_get(
+            &e262_current_realm().intrinsic(Intrinsic::BigIntPrototype),
+            &PropertyKey::from("valueOf"),
+        )
+        .unwrap();
+        assert!(e262_call(&value_of, &Value::Number(7f64), &[]).is_err());
+    }
+}
@@ -0,0 +1,117 @@
+use std::rc::Rc;
+
+use super::{p262_argument, p262_define_method, p262_link_constructor};
+use crate::core::cast::e262_to_boolean;
+use crate::core::{
+    e262_ordinary_create_from_constructor, p262_create_builtin_constructor, p262_get_slot,
+    ObjectRep, Value,
+};
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::{Intrinsic, Realm};
+
+/// Creates `%Boolean%` and the methods of `%Boolean.prototype%`.
+pub(crate) fn p262_create_intrinsics(realm: &Realm) {
+    let prototype = realm.intrinsic(Intrinsic::BooleanPrototype);
+    let constructor = p262_create_builtin_constructor(
+        Rc::new(|_, arguments| {
+            Ok(Value::Boolean(e262_to_boolean(&p262_argument(
+                arguments, 0,
+            ))))
+        }),
+        Rc::new(construct),
+        1,
+        "Boolean",
+        Some(realm.intrinsic(Intrinsic::FunctionPrototype)),
+    );
+    p262_link_constructor(&constructor, &prototype);
+    realm.set_intrinsic(Intrinsic::Boolean, constructor);
+
+    p262_define_method(realm, &prototype, "toString", 0, |this, _| {
+        let b = this_boolean_value(this)?;
+        Ok(Value::String(if b { "true" } else { "false" }.into()))
+    });
+    p262_define_method(realm, &prototype, "valueOf", 0, |this, _| {
+        Ok(Value::Boolean(this_boolean_value(this)?))
+    });
+}
+
+/// Implements [`Boolean`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-boolean-constructor-boolean-value) when called as part of a `new` expression.
+fn construct(arguments: &[Value], new_target: &ObjectRep) -> CoreResult<ObjectRep> {
+    let b = e262_to_boolean(&p262_argument(arguments, 0));
+    let obj = e262_ordinary_create_from_constructor(new_target, Intrinsic::BooleanPrototype)?;
+    obj.0
+        .clone()
+        .set_slot("BooleanData".to_string(), Rc::new(b));
+    Ok(obj)
+}
+
+/// Implements [thisBooleanValue](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-thisbooleanvalue).
+fn this_boolean_value(value: &Value) -> CoreResult<bool> {
+    match value {
+        Value::Boolean(b) => Ok(*b),
+        Value::Object(obj) => p262_get_slot::<bool>(obj.0.clone(), "BooleanData".to_string())
+            .map(|b| *b)
+            .ok_or_else(|| CoreError::TypeError("Receiver is not a Boolean".to_string())),
+        _ => Err(CoreError::TypeError(
+            "Receiver is not a Boolean".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::e262_current_realm;
+    use crate::core::function::{e262_call, e262_construct};
+    use crate::core::{e262_invoke, PropertyKey};
+
+    #[test]
+    fn converts_and_wraps() {
+        let constructor = e262_current_realm().intrinsic(Intrinsic::Boolean);
+        let call = |arguments: &[Value]| {
+            e262_call(
+                &Value::Object(constructor.clone()),
+                &Value::Undefined,
+                arguments,
+            )
+        };
+        assert_eq!(call(&[]).unwrap(), Value::Boolean(false));
+        assert_eq!(
+            call(&[Value::String("false".into())]).unwrap(),
+            Value::Boolean(true)
+        );
+        assert_eq!(
+            call(&[Value::Number(f64::NAN)]).unwrap(),
+            Value::Boolean(false)
+        );
+
+        let wrapper = e262_construct(&constructor, &[Value::Number(1f64)], None).unwrap();
+        assert_eq!(
+            e262_invoke(&Value::Object(wrapper), &PropertyKey::from("valueOf"), &[]).unwrap(),
+            Value::Boolean(true)
+        );
+    }
+
+    #[test]
+    fn prototype_methods_use_the_boolean_data() {
+        let prototype = Value::Object(e262_current_realm().intrinsic(Intrinsic::BooleanPrototype));
+        assert_eq!(
+            e262_invoke(&prototype, &PropertyKey::from("toString"), &[]).unwrap(),
+            Value::String("false".into())
+        );
+        assert_eq!(
+            e262_invoke(&Value::Boolean(true), &PropertyKey::from("toString"), &[]).unwrap(),
+            Value::String("true".into())
+        );
+        let object = Value::Object(e262_current_realm().intrinsic(Intrinsic::ObjectPrototype));
+        let to_string = crate::core::e262_get(
+            &e262_current_realm().intrinsic(Intrinsic::BooleanPrototype),
+            &PropertyKey::from("toString"),
+        )
+        .unwrap();
+        assert!(matches!(
+            e262_call(&to_string, &object, &[]),
+            Err(CoreError::TypeError(_))
+        ));
+    }
+}
@@ -0,0 +1,339 @@
+use std::rc::Rc;
+
+use super::{p262_argument, p262_define_method, p262_define_value, p262_link_constructor};
+use crate::agent::e262_current_realm;
+use crate::core::cast::e262_to_string;
+use crate::core::{
+    e262_create_array_from_list, e262_get, e262_has_property, e262_iterable_to_list,
+    e262_ordinary_create_from_constructor, e262_ordinary_object_create,
+    p262_create_builtin_self_constructor, ObjectRep, PropertyKey, StringRep, Value,
+};
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::{Intrinsic, Realm};
+
+/// The [NativeError](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-nativeerror-object-structure) constructors, with their prototypes.
+const NATIVE_ERRORS: [(&str, Intrinsic, Intrinsic); 6] = [
+    (
+        "EvalError",
+        Intrinsic::EvalError,
+        Intrinsic::EvalErrorPrototype,
+    ),
+    (
+        "RangeError",
+        Intrinsic::RangeError,
+        Intrinsic::RangeErrorPrototype,
+    ),
+    (
+        "ReferenceError",
+        Intrinsic::ReferenceError,
+        Intrinsic::ReferenceErrorPrototype,
+    ),
+    (
+        "SyntaxError",
+        Intrinsic::SyntaxError,
+        Intrinsic::SyntaxErrorPrototype,
+    ),
+    (
+        "TypeError",
+        Intrinsic::TypeError,
+        Intrinsic::TypeErrorPrototype,
+    ),
+    (
+        "URIError",
+        Intrinsic::URIError,
+        Intrinsic::URIErrorPrototype,
+    ),
+];
+
+/// Creates `%Error%`, the NativeError constructors, `%AggregateError%` and their prototypes.
+pub(crate) fn p262_create_intrinsics(realm: &Realm) {
+    let prototype = e262_ordinary_object_create(Some(realm.intrinsic(Intrinsic::ObjectPrototype)));
+    realm.set_intrinsic(Intrinsic::ErrorPrototype, prototype.clone());
+    let constructor = create_constructor(
+        realm,
+        "Error",
+        Intrinsic::Error,
+        Intrinsic::ErrorPrototype,
+        realm.intrinsic(Intrinsic::FunctionPrototype),
+    );
+    p262_define_method(realm, &prototype, "toString", 0, to_string);
+
+    for (name, intrinsic, prototype_intrinsic) in NATIVE_ERRORS {
+        let native_prototype = e262_ordinary_object_create(Some(prototype.clone()));
+        realm.set_intrinsic(prototype_intrinsic, native_prototype);
+        create_constructor(
+            realm,
+            name,
+            intrinsic,
+            prototype_intrinsic,
+            constructor.clone(),
+        );
+    }
+
+    let aggregate_prototype = e262_ordinary_object_create(Some(prototype));
+    realm.set_intrinsic(
+        Intrinsic::AggregateErrorPrototype,
+        aggregate_prototype.clone(),
+    );
+    let aggregate = p262_create_builtin_self_constructor(
+        Rc::new(construct_aggregate),
+        2,
+        "AggregateError",
+        Some(constructor),
+    );
+    p262_link_constructor(&aggregate, &aggregate_prototype);
+    define_name_and_message(&aggregate_prototype, "AggregateError");
+    realm.set_intrinsic(Intrinsic::AggregateError, aggregate);
+}
+
+/// Creates `%Error%` or a NativeError constructor, which creates an error object both when called and when constructed.
+fn create_constructor(
+    realm: &Realm,
+    name: &'static str,
+    intrinsic: Intrinsic,
+    prototype_intrinsic: Intrinsic,
+    parent: ObjectRep,
+) -> ObjectRep {
+    let constructor = p262_create_builtin_self_constructor(
+        Rc::new(move |arguments, new_target| construct(arguments, new_target, prototype_intrinsic)),
+        1,
+        name,
+        Some(parent),
+    );
+    let prototype = realm.intrinsic(prototype_intrinsic);
+    p262_link_constructor(&constructor, &prototype);
+    define_name_and_message(&prototype, name);
+    realm.set_intrinsic(intrinsic, constructor.clone());
+    constructor
+}
+
+/// Defines the `name` and empty `message` properties of an error prototype.
+fn define_name_and_message(prototype: &ObjectRep, name: &str) {
+    p262_define_value(prototype, "message", Value::String("".into()));
+    p262_define_value(prototype, "name", Value::String(name.into()));
+}
+
+/// Implements [`Error`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-error-message) and [NativeError](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-nativeerror) when called as part of a `new` expression.
+fn construct(
+    arguments: &[Value],
+    new_target: &ObjectRep,
+    prototype: Intrinsic,
+) -> CoreResult<ObjectRep> {
+    let obj = e262_ordinary_create_from_constructor(new_target, prototype)?;
+    initialize(
+        &obj,
+        &p262_argument(arguments, 0),
+        &p262_argument(arguments, 1),
+    )?;
+    Ok(obj)
+}
+
+/// Implements [`AggregateError`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-aggregate-error) when called as part of a `new` expression.
+fn construct_aggregate(arguments: &[Value], new_target: &ObjectRep) -> CoreResult<ObjectRep> {
+    let obj =
+        e262_ordinary_create_from_constructor(new_target, Intrinsic::AggregateErrorPrototype)?;
+    initialize(
+        &obj,
+        &p262_argument(arguments, 1),
+        &p262_argument(arguments, 2),
+    )?;
+    let errors = e262_iterable_to_list(&p262_argument(arguments, 0))?;
+    p262_define_value(
+        &obj,
+        "errors",
+        Value::Object(e262_create_array_from_list(&errors)),
+    );
+    Ok(obj)
+}
+
+/// Sets the `[[ErrorData]]` slot of a fresh error object, and installs its own `message` and `cause`.
+fn initialize(obj: &ObjectRep, message: &Value, options: &Value) -> CoreResult<()> {
+    obj.0.clone().set_slot("ErrorData".to_string(), Rc::new(()));
+    if *message != Value::Undefined {
+        let message = e262_to_string(message)?;
+        p262_define_value(obj, "message", Value::String(message));
+    }
+    install_error_cause(obj, options)
+}
+
+/// Implements [InstallErrorCause](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-installerrorcause).
+fn install_error_cause(obj: &ObjectRep, options: &Value) -> CoreResult<()> {
+    let Value::Object(options) = options else {
+        return Ok(());
+    };
+    let key = PropertyKey::from("cause");
+    if e262_has_property(options, &key)? {
+        let cause = e262_get(options, &key)?;
+        p262_define_value(obj, key, cause);
+    }
+    Ok(())
+}
+
+/// Creates an instance of the error whose prototype is the `prototype` intrinsic of the current realm, as the spec does when it throws one of its own errors.
+pub(crate) fn p262_create_error_object(prototype: Intrinsic, message: &str) -> ObjectRep {
+    let prototype = e262_current_realm().intrinsic(prototype);
+    let obj = e262_ordinary_object_create(Some(prototype));
+    obj.0.clone().set_slot("ErrorData".to_string(), Rc::new(()));
+    p262_define_value(&obj, "message", Value::String(message.into()));
+    obj
+}
+
+/// Implements [`Error.prototype.toString`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-error.prototype.tostring).
+fn to_string(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let Value::Object(obj) = this else {
+        return Err(CoreError::TypeError(
+            "Error.prototype.toString receiver is not an object".to_string(),
+        ));
+    };
+    let name = match e262_get(obj, &PropertyKey::from("name"))? {
+        Value::Undefined => StringRep::from("Error"),
+        name => e262_to_string(&name)?,
+    };
+    let msg = match e262_get(obj, &PropertyKey::from("message"))? {
+        Value::Undefined => StringRep::default(),
+        msg => e262_to_string(&msg)?,
+    };
+    if name.is_empty() {
+        return Ok(Value::String(msg));
+    }
+    if msg.is_empty() {
+        return Ok(Value::String(name));
+    }
+    Ok(Value::String(name.concat(&": ".into()).concat(&msg)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::function::{e262_call, e262_construct};
+    use crate::core::{
+        e262_create_data_property_or_throw, e262_get_v, e262_has_own_property, e262_invoke,
+    };
+
+    fn invoke_to_string(obj: &ObjectRep) -> Value {
+        e262_invoke(
+            &Value::Object(obj.clone()),
+            &PropertyKey::from("toString"),
+            &[],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn constructs_with_message_and_cause() {
+        let constructor = e262_current_realm().intrinsic(Intrinsic::RangeError);
+        let options = e262_ordinary_object_create(None);
+        e262_create_data_property_or_throw(&options, "cause".into(), Value::Number(1f64)).unwrap();
+        let error = e262_construct(
+            &constructor,
+            &[Value::String("bad".into()), Value::Object(options)],
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            invoke_to_string(&error),
+            Value::String("RangeError: bad".into())
+        );
+        assert_eq!(
+            e262_get(&error, &"cause".into()).unwrap(),
+            Value::Number(1f64)
+        );
+
+        let Value::Object(called) = e262_call(
+            &Value::Object(e262_current_realm().intrinsic(Intrinsic::Error)),
+            &Value::Undefined,
+            &[],
+        )
+        .unwrap() else {
+            panic!("Error() returns an object")
+        };
+        assert_eq!(invoke_to_string(&called), Value::String("Error".into()));
+        assert!(!e262_has_own_property(&called, &"message".into()).unwrap());
+        assert!(!e262_has_own_property(&called, &"cause".into()).unwrap());
+    }
+
+    #[test]
+    fn aggregates_errors() {
+        let constructor = e262_current_realm().intrinsic(Intrinsic::AggregateError);
+        let errors = e262_create_array_from_list(&[Value::Number(1f64), Value::Null]);
+        let error = e262_construct(
+            &constructor,
+            &[Value::Object(errors), Value::String("many".into())],
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            invoke_to_string(&error),
+            Value::String("AggregateError: many".into())
+        );
+        let Value::Object(list) = e262_get(&error, &"errors".into()).unwrap() else {
+            panic!("errors is an array")
+        };
+        assert_eq!(
+            e262_get(&list, &"length".into()).unwrap(),
+            Value::Number(2f64)
+        );
+        assert!(e262_construct(&constructor, &[Value::Number(1f64)], None).is_err());
+    }
+
+    #[test]
+    fn calls_construct_in_the_function_realm() {
+        let other = Realm::new();
+        let current = e262_current_realm();
+        for (constructor, prototype) in [
+            (Intrinsic::TypeError, Intrinsic::TypeErrorPrototype),
+            (
+                Intrinsic::AggregateError,
+                Intrinsic::AggregateErrorPrototype,
+            ),
+        ] {
+            let errors = Value::Object(e262_create_array_from_list(&[]));
+            let Value::Object(called) = e262_call(
+                &Value::Object(other.intrinsic(constructor)),
+                &Value::Undefined,
+                &[errors],
+            )
+            .unwrap() else {
+                panic!("error constructors return objects when called")
+            };
+            let proto = called.0.clone().get_prototype_of().unwrap();
+            assert_eq!(proto, Some(other.intrinsic(prototype)));
+            assert_ne!(proto, Some(current.intrinsic(prototype)));
+        }
+    }
+
+    #[test]
+    fn converts_core_errors() {
+        let Value::Object(error) = CoreError::TypeError("not a function".to_string()).into_value()
+        else {
+            panic!("native errors are converted into objects")
+        };
+        assert_eq!(
+            invoke_to_string(&error),
+            Value::String("TypeError: not a function".into())
+        );
+        let to_string = e262_current_realm().intrinsic(Intrinsic::ObjectPrototypeToString);
+        assert_eq!(
+            e262_call(&Value::Object(to_string), &Value::Object(error), &[]).unwrap(),
+            Value::String("[object Error]".into())
+        );
+        let error = CoreError::URIError("malformed".to_string()).into_value();
+        let constructor = e262_get_v(&error, &"constructor".into()).unwrap();
+        assert_eq!(
+            constructor,
+            Value::Object(e262_current_realm().intrinsic(Intrinsic::URIError))
+        );
+    }
+
+    #[test]
+    fn formats_names_and_messages() {
+        let obj = e262_ordinary_object_create(None);
+        let to_string = |obj: &ObjectRep| to_string(&Value::Object(obj.clone()), &[]).unwrap();
+        assert_eq!(to_string(&obj), Value::String("Error".into()));
+        e262_create_data_property_or_throw(&obj, "name".into(), Value::String("".into())).unwrap();
+        e262_create_data_property_or_throw(&obj, "message".into(), Value::String("m".into()))
+            .unwrap();
+        assert_eq!(to_string(&obj), Value::String("m".into()));
+        assert!(super::to_string(&Value::Number(1f64), &[]).is_err());
+    }
+}
@@ -0,0 +1,267 @@
+use std::rc::Rc;
+
+use super::{p262_argument, p262_define_constant, p262_define_method};
+use crate::core::cast::{e262_to_integer_or_infinity, IntegerOrInfinity};
+use crate::core::function::{e262_call, e262_set_function_length, e262_set_function_name};
+use crate::core::test::e262_is_callable;
+use crate::core::{
+    e262_bound_function_create, e262_create_builtin_function, e262_create_list_from_array_like,
+    e262_get, e262_has_own_property, e262_ordinary_has_instance, p262_get_slot, ObjectRep,
+    PropertyKey, StringRep, SymbolRep, Value, WellKnownSymbol,
+};
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::{Intrinsic, Realm};
+
+/// Creates the methods of `%Function.prototype%`.
+pub(crate) fn p262_create_intrinsics(realm: &Realm) {
+    let prototype = realm.intrinsic(Intrinsic::FunctionPrototype);
+
+    p262_define_method(realm, &prototype, "apply", 2, apply);
+    p262_define_method(realm, &prototype, "bind", 1, bind);
+    p262_define_method(realm, &prototype, "call", 1, call);
+    p262_define_method(realm, &prototype, "toString", 0, to_string);
+
+    // Unlike other methods, @@hasInstance cannot be overwritten, so that bound functions cannot be tampered with.
+    let has_instance = e262_create_builtin_function(
+        Rc::new(has_instance),
+        1,
+        "[Symbol.hasInstance]",
+        Some(prototype.clone()),
+    );
+    p262_define_constant(
+        &prototype,
+        SymbolRep::well_known(WellKnownSymbol::HasInstance),
+        Value::Object(has_instance),
+    );
+}
+
+fn this_callable(this: &Value, method: &str) -> CoreResult<ObjectRep> {
+    match this {
+        Value::Object(func) if e262_is_callable(this) => Ok(func.clone()),
+        _ => Err(CoreError::TypeError(format!(
+            "Function.prototype.{method} called on non-callable value"
+        ))),
+    }
+}
+
+/// Implements [`Function.prototype.apply`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-function.prototype.apply).
+fn apply(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    this_callable(this, "apply")?;
+    let this_arg = p262_argument(arguments, 0);
+    match p262_argument(arguments, 1) {
+        Value::Undefined | Value::Null => e262_call(this, &this_arg, &[]),
+        arg_array => {
+            let arg_list = e262_create_list_from_array_like(&arg_array)?;
+            e262_call(this, &this_arg, &arg_list)
+        }
+    }
+}
+
+/// Implements [`Function.prototype.bind`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-function.prototype.bind).
+fn bind(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let target = this_callable(this, "bind")?;
+    let bound_args = arguments.iter().skip(1).cloned().collect::<Vec<_>>();
+    let arg_count = bound_args.len() as f64;
+    let func = e262_bound_function_create(target.clone(), p262_argument(arguments, 0), bound_args)?;
+
+    let mut length = 0f64;
+    if e262_has_own_property(&target, &PropertyKey::from("length"))? {
+        if let Value::Number(target_len) = e262_get(&target, &PropertyKey::from("length"))? {
+            length = match e262_to_integer_or_infinity(&Value::Number(target_len))? {
+                IntegerOrInfinity::PositiveInfinity => f64::INFINITY,
+                IntegerOrInfinity::NegativeInfinity => 0f64,
+                IntegerOrInfinity::Integer(target_len) => (target_len - arg_count).max(0f64),
+            };
+        }
+    }
+    e262_set_function_length(&func, length);
+
+    let name = match e262_get(&target, &PropertyKey::from("name"))? {
+        Value::String(name) => name,
+        _ => StringRep::empty(),
+    };
+    e262_set_function_name(&func, name, Some("bound"));
+    Ok(Value::Object(func))
+}
+
+/// Implements [`Function.prototype.call`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-function.prototype.call).
+fn call(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    this_callable(this, "call")?;
+    let args = arguments.get(1..).unwrap_or_default();
+    e262_call(this, &p262_argument(arguments, 0), args)
+}
+
+/// Implements [`Function.prototype.toString`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-function.prototype.tostring).
+///
+/// Functions with a `[[SourceText]]` slot return the exact source text they were created from. Other functions return a [NativeFunction](https://tc39.es/ecma262/multipage/fundamental-objects.html#prod-NativeFunction) string using their `[[InitialName]]`, e.g. `function push() { [native code] }`.
+///
+/// Nothing sets `[[SourceText]]` yet: without a parser there are no ECMAScript function objects, which [OrdinaryFunctionCreate](https://tc39.es/ecma262/multipage/ordinary-and-exotic-objects-behaviours.html#sec-ordinaryfunctioncreate) would give the slot, so every function is currently printed as native.
+fn to_string(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let func = this_callable(this, "toString")?;
+    if let Some(source_text) = p262_get_slot::<StringRep>(func.0.clone(), "SourceText".to_string())
+    {
+        return Ok(Value::String(source_text.as_ref().clone()));
+    }
+    let name = p262_get_slot::<StringRep>(func.0, "InitialName".to_string())
+        .map(|name| name.as_ref().clone())
+        .unwrap_or_default();
+    let source_text = StringRep::from("function ")
+        .concat(&name)
+        .concat(&"() { [native code] }".into());
+    Ok(Value::String(source_text))
+}
+
+/// Implements [`Function.prototype[@@hasInstance]`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-function.prototype-@@hasinstance).
+fn has_instance(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let result = e262_ordinary_has_instance(this, &p262_argument(arguments, 0))?;
+    Ok(Value::Boolean(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::e262_current_realm;
+    use crate::builtins::p262_define_value;
+    use crate::core::{
+        e262_create_array_from_list, e262_instanceof_operator, e262_invoke,
+        e262_ordinary_object_create,
+    };
+
+    fn function_prototype() -> ObjectRep {
+        e262_current_realm().intrinsic(Intrinsic::FunctionPrototype)
+    }
+
+    /// Returns a function of two parameters returning `[this, ...arguments]`.
+    fn echo(name: &str) -> Value {
+        let func = e262_create_builtin_function(
+            Rc::new(|this, args| {
+                let list: Vec<Value> = std::iter::once(this.clone())
+                    .chain(args.iter().cloned())
+                    .collect();
+                Ok(Value::Object(e262_create_array_from_list(&list)))
+            }),
+            2,
+            name,
+            Some(function_prototype()),
+        );
+        Value::Object(func)
+    }
+
+    fn invoke<const N: usize>(
+        func: &Value,
+        method: &str,
+        arguments: [Value; N],
+    ) -> CoreResult<Value> {
+        e262_invoke(func, &PropertyKey::from(method), &arguments)
+    }
+
+    fn get(value: &Value, key: impl Into<PropertyKey>) -> Value {
+        let Value::Object(obj) = value else {
+            panic!("{value:?} is not an object");
+        };
+        e262_get(obj, &key.into()).unwrap()
+    }
+
+    fn elements(value: &Value) -> Vec<Value> {
+        let Value::Number(len) = get(value, "length") else {
+            unreachable!()
+        };
+        (0..len as u32).map(|index| get(value, index)).collect()
+    }
+
+    fn num(value: f64) -> Value {
+        Value::Number(value)
+    }
+
+    #[test]
+    fn call_and_apply_forward_arguments() {
+        let func = echo("f");
+        let result = invoke(&func, "call", [num(0f64), num(1f64), num(2f64)]).unwrap();
+        assert_eq!(elements(&result), vec![num(0f64), num(1f64), num(2f64)]);
+
+        let args = Value::Object(e262_create_array_from_list(&[num(3f64)]));
+        let result = invoke(&func, "apply", [num(0f64), args]).unwrap();
+        assert_eq!(elements(&result), vec![num(0f64), num(3f64)]);
+        let result = invoke(&func, "apply", [num(0f64), Value::Null]).unwrap();
+        assert_eq!(elements(&result), vec![num(0f64)]);
+        assert!(invoke(&func, "apply", [num(0f64), num(1f64)]).is_err());
+
+        let call = get(&func, "call");
+        assert!(e262_call(&call, &num(1f64), &[]).is_err());
+    }
+
+    #[test]
+    fn bind_prepends_arguments() {
+        let func = echo("f");
+        let bound = invoke(&func, "bind", [num(0f64), num(1f64)]).unwrap();
+        let result = e262_call(&bound, &num(9f64), &[num(2f64)]).unwrap();
+        assert_eq!(elements(&result), vec![num(0f64), num(1f64), num(2f64)]);
+
+        assert_eq!(get(&bound, "length"), num(1f64));
+        assert_eq!(get(&bound, "name"), Value::String("bound f".into()));
+        let twice = invoke(&bound, "bind", [num(0f64), num(1f64), num(2f64)]).unwrap();
+        assert_eq!(get(&twice, "length"), num(0f64));
+        assert_eq!(get(&twice, "name"), Value::String("bound bound f".into()));
+    }
+
+    #[test]
+    fn to_string_prints_source_text() {
+        let func = echo("push");
+        assert_eq!(
+            invoke(&func, "toString", []).unwrap(),
+            Value::String("function push() { [native code] }".into())
+        );
+        let bound = invoke(&func, "bind", [Value::Undefined]).unwrap();
+        assert_eq!(
+            invoke(&bound, "toString", []).unwrap(),
+            Value::String("function () { [native code] }".into())
+        );
+
+        let Value::Object(obj) = &func else {
+            unreachable!()
+        };
+        // Stands in for OrdinaryFunctionCreate, until ECMAScript function objects exist.
+        let source = StringRep::from("function f(a, b) { /* \u{1F600} */ }");
+        obj.0
+            .clone()
+            .set_slot("SourceText".to_string(), Rc::new(source.clone()));
+        assert_eq!(
+            invoke(&func, "toString", []).unwrap(),
+            Value::String(source)
+        );
+
+        let to_string = get(&func, "toString");
+        let obj = Value::Object(e262_ordinary_object_create(None));
+        assert!(e262_call(&to_string, &obj, &[]).is_err());
+    }
+
+    #[test]
+    fn has_instance_walks_prototypes() {
+        let func = echo("F");
+        let Value::Object(func_obj) = &func else {
+            unreachable!()
+        };
+        let prototype = e262_ordinary_object_create(None);
+        p262_define_value(func_obj, "prototype", Value::Object(prototype.clone()));
+        let instance = Value::Object(e262_ordinary_object_create(Some(prototype)));
+
+        assert!(e262_instanceof_operator(&instance, &func).unwrap());
+        assert!(!e262_instanceof_operator(&num(1f64), &func).unwrap());
+        let bound = invoke(&func, "bind", [Value::Undefined]).unwrap();
+        assert!(e262_instanceof_operator(&instance, &bound).unwrap());
+        assert!(e262_instanceof_operator(&instance, &num(1f64)).is_err());
+
+        let key = PropertyKey::from(SymbolRep::well_known(WellKnownSymbol::HasInstance));
+        let has_instance = e262_get(&function_prototype(), &key).unwrap();
+        assert_eq!(
+            get(&has_instance, "name"),
+            Value::String("[Symbol.hasInstance]".into())
+        );
+        assert!(!function_prototype()
+            .0
+            .get_own_property(&key)
+            .unwrap()
+            .unwrap()
+            .is_configurable());
+    }
+}
@@ -0,0 +1,205 @@
+use std::rc::Rc;
+
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+
+use super::p262_argument;
+use crate::core::cast::{e262_to_int32, e262_to_string, is_str_whitespace};
+use crate::core::{e262_create_builtin_function, Value};
+use crate::errors::CoreResult;
+use crate::realm::{Intrinsic, Realm};
+
+/// Creates the function properties of the global object that other built-ins share, such as `%parseFloat%`.
+pub(crate) fn p262_create_intrinsics(realm: &Realm) {
+    let function_prototype = realm.intrinsic(Intrinsic::FunctionPrototype);
+    let parse_float = e262_create_builtin_function(
+        Rc::new(parse_float),
+        1,
+        "parseFloat",
+        Some(function_prototype.clone()),
+    );
+    realm.set_intrinsic(Intrinsic::ParseFloat, parse_float);
+    let parse_int =
+        e262_create_builtin_function(Rc::new(parse_int), 2, "parseInt", Some(function_prototype));
+    realm.set_intrinsic(Intrinsic::ParseInt, parse_int);
+}
+
+/// Converts `argument` to a string without its leading white space, as `parseFloat` and `parseInt` start with.
+fn trimmed_start(argument: &Value) -> CoreResult<String> {
+    let input = e262_to_string(argument)?.to_std_string_lossy();
+    Ok(input.trim_start_matches(is_str_whitespace).to_string())
+}
+
+/// Returns the length of the longest prefix of `text` that is a [StrDecimalLiteral](https://tc39.es/ecma262/multipage/abstract-operations.html#prod-StrDecimalLiteral), if any.
+fn str_decimal_literal_prefix(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut end = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    if text[end..].starts_with("Infinity") {
+        return Some(end + "Infinity".len());
+    }
+    let digits_from = |start: usize| {
+        bytes[start..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count()
+    };
+    let int_digits = digits_from(end);
+    end += int_digits;
+    let mut frac_digits = 0;
+    if bytes.get(end) == Some(&b'.') {
+        frac_digits = digits_from(end + 1);
+        if int_digits + frac_digits > 0 {
+            end += 1 + frac_digits;
+        }
+    }
+    if int_digits + frac_digits == 0 {
+        return None;
+    }
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(end + 1), Some(b'+' | b'-')));
+        let exponent_digits = digits_from(end + 1 + sign);
+        if exponent_digits > 0 {
+            end += 1 + sign + exponent_digits;
+        }
+    }
+    Some(end)
+}
+
+/// Implements [`parseFloat`](https://tc39.es/ecma262/multipage/global-object.html#sec-parsefloat-string).
+fn parse_float(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let trimmed = trimmed_start(&p262_argument(arguments, 0))?;
+    let Some(end) = str_decimal_literal_prefix(&trimmed) else {
+        return Ok(Value::Number(f64::NAN));
+    };
+    let literal = &trimmed[..end];
+    let value = match literal.trim_start_matches(['+', '-']) {
+        "Infinity" => f64::INFINITY,
+        unsigned => unsigned
+            .parse::<f64>()
+            .expect("StrDecimalLiteral is valid Rust float syntax"),
+    };
+    Ok(Value::Number(match literal.starts_with('-') {
+        true => -value,
+        false => value,
+    }))
+}
+
+/// Implements [`parseInt`](https://tc39.es/ecma262/multipage/global-object.html#sec-parseint-string-radix).
+fn parse_int(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let trimmed = trimmed_start(&p262_argument(arguments, 0))?;
+    let mut s = trimmed.as_str();
+    let sign = match s.strip_prefix('-') {
+        Some(rest) => {
+            s = rest;
+            -1f64
+        }
+        None => {
+            s = s.strip_prefix('+').unwrap_or(s);
+            1f64
+        }
+    };
+    let mut radix = e262_to_int32(&p262_argument(arguments, 1))?;
+    let mut strip_prefix = true;
+    if radix != 0 {
+        if !(2..=36).contains(&radix) {
+            return Ok(Value::Number(f64::NAN));
+        }
+        strip_prefix = radix == 16;
+    } else {
+        radix = 10;
+    }
+    if strip_prefix {
+        if let Some(rest) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            s = rest;
+            radix = 16;
+        }
+    }
+    let radix = radix as u32;
+    let end = s.find(|c: char| !c.is_digit(radix)).unwrap_or(s.len());
+    let z = &s[..end];
+    if z.is_empty() {
+        return Ok(Value::Number(f64::NAN));
+    }
+    let math_int = match radix {
+        10 => z
+            .parse::<f64>()
+            .expect("Decimal digits are valid Rust float syntax"),
+        _ => BigUint::parse_bytes(z.as_bytes(), radix)
+            .expect("The digits were checked against the radix")
+            .to_f64()
+            .unwrap_or(f64::INFINITY),
+    };
+    Ok(Value::Number(sign * math_int))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::e262_current_realm;
+    use crate::core::function::e262_call;
+
+    fn call<const N: usize>(intrinsic: Intrinsic, arguments: [Value; N]) -> Value {
+        let func = Value::Object(e262_current_realm().intrinsic(intrinsic));
+        e262_call(&func, &Value::Undefined, &arguments).unwrap()
+    }
+
+    fn str(value: &str) -> Value {
+        Value::String(value.into())
+    }
+
+    #[test]
+    fn parse_float_reads_the_longest_prefix() {
+        assert_eq!(
+            call(Intrinsic::ParseFloat, [str("  3.25e2xyz")]),
+            Value::Number(325f64)
+        );
+        assert_eq!(
+            call(Intrinsic::ParseFloat, [str(".5.")]),
+            Value::Number(0.5f64)
+        );
+        assert_eq!(
+            call(Intrinsic::ParseFloat, [str("1e")]),
+            Value::Number(1f64)
+        );
+        assert_eq!(
+            call(Intrinsic::ParseFloat, [str("-Infinityx")]),
+            Value::Number(f64::NEG_INFINITY)
+        );
+        assert_eq!(
+            call(Intrinsic::ParseFloat, [str("-0")]),
+            Value::Number(-0f64)
+        );
+        assert!(
+            matches!(call(Intrinsic::ParseFloat, [str("0x10")]), Value::Number(n) if n == 0f64)
+        );
+        assert!(matches!(call(Intrinsic::ParseFloat, [str(".")]), Value::Number(n) if n.is_nan()));
+    }
+
+    #[test]
+    fn parse_int_handles_radices_and_prefixes() {
+        assert_eq!(
+            call(Intrinsic::ParseInt, [str(" -0x1F")]),
+            Value::Number(-31f64)
+        );
+        assert_eq!(
+            call(Intrinsic::ParseInt, [str("0x1F"), Value::Number(10f64)]),
+            Value::Number(0f64)
+        );
+        assert_eq!(
+            call(Intrinsic::ParseInt, [str("z"), Value::Number(36f64)]),
+            Value::Number(35f64)
+        );
+        assert_eq!(
+            call(Intrinsic::ParseInt, [str("12.9")]),
+            Value::Number(12f64)
+        );
+        assert!(
+            matches!(call(Intrinsic::ParseInt, [str("1"), Value::Number(37f64)]), Value::Number(n) if n.is_nan())
+        );
+        assert!(matches!(call(Intrinsic::ParseInt, [str("-")]), Value::Number(n) if n.is_nan()));
+        let Value::Number(zero) = call(Intrinsic::ParseInt, [str("-0")]) else {
+            unreachable!()
+        };
+        assert!(zero == 0f64 && zero.is_sign_negative());
+    }
+}
@@ -0,0 +1,26 @@
+use super::p262_define_method;
+use crate::core::{e262_ordinary_object_create, SymbolRep, WellKnownSymbol};
+use crate::realm::{Intrinsic, Realm};
+
+/// Creates `%Iterator.prototype%`, the prototype of every built-in iterator.
+pub(crate) fn p262_create_intrinsics(realm: &Realm) {
+    let prototype = e262_ordinary_object_create(Some(realm.intrinsic(Intrinsic::ObjectPrototype)));
+    let key = SymbolRep::well_known(WellKnownSymbol::Iterator);
+    // Implements [`%Iterator.prototype%[@@iterator]`](https://tc39.es/ecma262/multipage/control-abstraction-objects.html#sec-%iteratorprototype%-@@iterator).
+    p262_define_method(realm, &prototype, key, 0, |this, _| Ok(this.clone()));
+    realm.set_intrinsic(Intrinsic::IteratorPrototype, prototype);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::e262_current_realm;
+    use crate::core::{e262_invoke, PropertyKey, Value};
+
+    #[test]
+    fn iterators_are_iterable() {
+        let prototype = Value::Object(e262_current_realm().intrinsic(Intrinsic::IteratorPrototype));
+        let key = PropertyKey::from(SymbolRep::well_known(WellKnownSymbol::Iterator));
+        assert_eq!(e262_invoke(&prototype, &key, &[]).unwrap(), prototype);
+    }
+}
@@ -0,0 +1,920 @@
+use std::rc::Rc;
+
+use super::{p262_argument, p262_define_method};
+use crate::agent::e262_current_realm;
+use crate::core::cast::{
+    e262_string_to_number, e262_to_integer_or_infinity, e262_to_number, e262_to_string,
+};
+use crate::core::function::e262_call;
+use crate::core::test::{e262_is_array, e262_is_callable, e262_same_value};
+use crate::core::{
+    e262_create_array_from_list, e262_create_data_property, e262_define_property_or_throw,
+    e262_enumerable_own_properties, e262_get, e262_get_v, e262_length_of_array_like,
+    e262_ordinary_object_create, numbers, p262_get_slot, p262_has_slot,
+    p262_utf16_encode_code_point, Descriptor, ObjectRep, PropertyKey, PropertyKind, StringRep,
+    SymbolRep, Value, WellKnownSymbol,
+};
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::{Intrinsic, Realm};
+
+/// The deepest nesting of arrays and objects, which bounds the recursion of parsing, reviving and serializing.
+///
+/// Deeper values are valid JSON, but parsing, reviving and serializing recurse once per level, as does dropping the values they create, so the limit keeps all of them within the stack of a thread.
+const MAX_NESTING: usize = 256;
+
+/// Returns the RangeError thrown for values nested deeper than [`MAX_NESTING`].
+fn too_deep<T>() -> CoreResult<T> {
+    Err(CoreError::RangeError(
+        "JSON value is too deeply nested".to_string(),
+    ))
+}
+
+/// Creates `%JSON%`.
+pub(crate) fn p262_create_intrinsics(realm: &Realm) {
+    let json = e262_ordinary_object_create(Some(realm.intrinsic(Intrinsic::ObjectPrototype)));
+    realm.set_intrinsic(Intrinsic::JSON, json.clone());
+
+    p262_define_method(realm, &json, "parse", 2, parse);
+    p262_define_method(realm, &json, "stringify", 3, stringify);
+    let to_string_tag = SymbolRep::well_known(WellKnownSymbol::ToStringTag);
+    e262_define_property_or_throw(
+        &json,
+        PropertyKey::from(to_string_tag),
+        Descriptor {
+            value: Some(Rc::new(Value::String("JSON".into()))),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Built-in properties can always be defined");
+}
+
+/// A parsed JSON value, remembering the source text of its primitives for the reviver's context argument.
+enum Node {
+    Primitive(Value, StringRep),
+    Array(Vec<Node>),
+    Object(Vec<(StringRep, Node)>),
+}
+
+impl Node {
+    /// Creates the ES value of the node, as [ParseJSON](https://tc39.es/ecma262/multipage/structured-data.html#sec-ParseJSON) evaluates it.
+    fn to_value(&self) -> Value {
+        match self {
+            Node::Primitive(value, _) => value.clone(),
+            Node::Array(elements) => {
+                let elements: Vec<Value> = elements.iter().map(Node::to_value).collect();
+                Value::Object(e262_create_array_from_list(&elements))
+            }
+            Node::Object(members) => {
+                let prototype = e262_current_realm().intrinsic(Intrinsic::ObjectPrototype);
+                let obj = e262_ordinary_object_create(Some(prototype));
+                for (key, node) in members {
+                    e262_create_data_property(
+                        &obj,
+                        PropertyKey::from(key.clone()),
+                        node.to_value(),
+                    )
+                    .expect("Fresh ordinary objects accept new properties");
+                }
+                Value::Object(obj)
+            }
+        }
+    }
+
+    /// Returns the node of the `key` property of an object or array node, if any.
+    fn child(&self, key: &PropertyKey) -> Option<&Node> {
+        match (self, key) {
+            (Node::Array(elements), PropertyKey::Index(index)) => elements.get(*index as usize),
+            (Node::Object(members), key) => {
+                let Value::String(key) = key.to_value() else {
+                    return None;
+                };
+                // Later duplicates win, as they overwrite earlier ones in the parsed object.
+                members
+                    .iter()
+                    .rev()
+                    .find(|(name, _)| *name == key)
+                    .map(|(_, node)| node)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A recursive descent parser for the [JSON grammar](https://tc39.es/ecma262/multipage/structured-data.html#sec-json.parse), working on code units.
+struct Parser {
+    text: Vec<u16>,
+    position: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn error<T>(&self, message: &str) -> CoreResult<T> {
+        Err(CoreError::SyntaxError(format!(
+            "JSON.parse: {message} at position {}",
+            self.position
+        )))
+    }
+
+    fn peek(&self) -> Option<u16> {
+        self.text.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(0x09 | 0x0A | 0x0D | 0x20)) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, unit: u8) -> CoreResult<()> {
+        self.skip_whitespace();
+        if self.peek() != Some(unit as u16) {
+            return self.error(&format!("expected '{}'", unit as char));
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    fn parse_text(&mut self) -> CoreResult<Node> {
+        let node = self.parse_value()?;
+        self.skip_whitespace();
+        if self.position < self.text.len() {
+            return self.error("unexpected trailing characters");
+        }
+        Ok(node)
+    }
+
+    fn parse_value(&mut self) -> CoreResult<Node> {
+        self.skip_whitespace();
+        let start = self.position;
+        let value = match self.peek() {
+            Some(0x7B | 0x5B) => {
+                if self.depth == MAX_NESTING {
+                    return too_deep();
+                }
+                self.depth += 1;
+                let node = if self.peek() == Some(0x7B) {
+                    self.parse_object()
+                } else {
+                    self.parse_array()
+                };
+                self.depth -= 1;
+                return node;
+            }
+            Some(0x22) => Value::String(self.parse_string()?),
+            Some(0x2D | 0x30..=0x39) => Value::Number(self.parse_number()?),
+            Some(0x74) => self.parse_literal("true", Value::Boolean(true))?,
+            Some(0x66) => self.parse_literal("false", Value::Boolean(false))?,
+            Some(0x6E) => self.parse_literal("null", Value::Null)?,
+            Some(_) => return self.error("unexpected character"),
+            None => return self.error("unexpected end of input"),
+        };
+        let source = StringRep::from_utf16(&self.text[start..self.position]);
+        Ok(Node::Primitive(value, source))
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: Value) -> CoreResult<Value> {
+        for byte in literal.bytes() {
+            if self.peek() != Some(byte as u16) {
+                return self.error(&format!("invalid literal, expected '{literal}'"));
+            }
+            self.position += 1;
+        }
+        Ok(value)
+    }
+
+    fn parse_digits(&mut self) -> CoreResult<()> {
+        if !matches!(self.peek(), Some(0x30..=0x39)) {
+            return self.error("expected a digit");
+        }
+        while matches!(self.peek(), Some(0x30..=0x39)) {
+            self.position += 1;
+        }
+        Ok(())
+    }
+
+    fn parse_number(&mut self) -> CoreResult<f64> {
+        let start = self.position;
+        if self.peek() == Some(0x2D) {
+            self.position += 1;
+        }
+        if self.peek() == Some(0x30) {
+            self.position += 1;
+        } else {
+            self.parse_digits()?;
+        }
+        if self.peek() == Some(0x2E) {
+            self.position += 1;
+            self.parse_digits()?;
+        }
+        if matches!(self.peek(), Some(0x45 | 0x65)) {
+            self.position += 1;
+            if matches!(self.peek(), Some(0x2B | 0x2D)) {
+                self.position += 1;
+            }
+            self.parse_digits()?;
+        }
+        let source = String::from_utf16_lossy(&self.text[start..self.position]);
+        Ok(e262_string_to_number(&source))
+    }
+
+    fn parse_hex4(&mut self) -> CoreResult<u16> {
+        let mut unit = 0u16;
+        for _ in 0..4 {
+            let digit = self
+                .peek()
+                .and_then(|unit| char::from_u32(unit as u32))
+                .and_then(|c| c.to_digit(16));
+            let Some(digit) = digit else {
+                return self.error("invalid unicode escape");
+            };
+            unit = unit * 16 + digit as u16;
+            self.position += 1;
+        }
+        Ok(unit)
+    }
+
+    fn parse_string(&mut self) -> CoreResult<StringRep> {
+        self.position += 1;
+        let mut units = Vec::new();
+        loop {
+            let Some(unit) = self.peek() else {
+                return self.error("unterminated string");
+            };
+            self.position += 1;
+            match unit {
+                0x22 => return Ok(StringRep::from_utf16(&units)),
+                0x00..=0x1F => {
+                    self.position -= 1;
+                    return self.error("control character in string");
+                }
+                0x5C => {
+                    let escaped = match self.peek() {
+                        Some(0x22) => 0x22,
+                        Some(0x5C) => 0x5C,
+                        Some(0x2F) => 0x2F,
+                        Some(0x62) => 0x08,
+                        Some(0x66) => 0x0C,
+                        Some(0x6E) => 0x0A,
+                        Some(0x72) => 0x0D,
+                        Some(0x74) => 0x09,
+                        Some(0x75) => {
+                            self.position += 1;
+                            units.push(self.parse_hex4()?);
+                            continue;
+                        }
+                        _ => return self.error("invalid escape"),
+                    };
+                    self.position += 1;
+                    units.push(escaped);
+                }
+                unit => units.push(unit),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> CoreResult<Node> {
+        self.position += 1;
+        let mut elements = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(0x5D) {
+            self.position += 1;
+            return Ok(Node::Array(elements));
+        }
+        loop {
+            elements.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(0x2C) => self.position += 1,
+                Some(0x5D) => {
+                    self.position += 1;
+                    return Ok(Node::Array(elements));
+                }
+                _ => return self.error("expected ',' or ']'"),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> CoreResult<Node> {
+        self.position += 1;
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(0x7D) {
+            self.position += 1;
+            return Ok(Node::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(0x22) {
+                return self.error("expected a property name");
+            }
+            let key = self.parse_string()?;
+            self.expect(b':')?;
+            members.push((key, self.parse_value()?));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(0x2C) => self.position += 1,
+                Some(0x7D) => {
+                    self.position += 1;
+                    return Ok(Node::Object(members));
+                }
+                _ => return self.error("expected ',' or '}'"),
+            }
+        }
+    }
+}
+
+/// Implements [`JSON.parse`](https://tc39.es/ecma262/multipage/structured-data.html#sec-json.parse), including the `context` argument of the reviver from the [JSON.parse source text access](https://tc39.es/proposal-json-parse-with-source/) proposal.
+///
+/// Unlike the spec, it throws a RangeError for text with arrays and objects nested deeper than [`MAX_NESTING`] (256) levels, even if valid, and when the reviver meets values nested that deep.
+fn parse(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let json_string = e262_to_string(&p262_argument(arguments, 0))?;
+    let mut parser = Parser {
+        text: json_string.to_utf16(),
+        position: 0,
+        depth: 0,
+    };
+    let node = parser.parse_text()?;
+    let unfiltered = node.to_value();
+    let reviver = p262_argument(arguments, 1);
+    if !e262_is_callable(&reviver) {
+        return Ok(unfiltered);
+    }
+    let prototype = e262_current_realm().intrinsic(Intrinsic::ObjectPrototype);
+    let root = e262_ordinary_object_create(Some(prototype));
+    e262_create_data_property(&root, PropertyKey::from(""), unfiltered)
+        .expect("Fresh ordinary objects accept new properties");
+    internalize_json_property(&root, PropertyKey::from(""), &reviver, Some(&node), 0)
+}
+
+/// Implements [InternalizeJSONProperty](https://tc39.es/ecma262/multipage/structured-data.html#sec-internalizejsonproperty), where `node` is the parsed value the property still holds, if known, and `depth` the number of enclosing objects.
+///
+/// The reviver can replace properties that are yet to be visited with deeper objects, so the depth is checked again here.
+fn internalize_json_property(
+    holder: &ObjectRep,
+    name: PropertyKey,
+    reviver: &Value,
+    node: Option<&Node>,
+    depth: usize,
+) -> CoreResult<Value> {
+    let val = e262_get(holder, &name)?;
+    let prototype = e262_current_realm().intrinsic(Intrinsic::ObjectPrototype);
+    let context = e262_ordinary_object_create(Some(prototype));
+    match &val {
+        Value::Object(obj) => {
+            if depth == MAX_NESTING {
+                return too_deep();
+            }
+            let keys = if e262_is_array(&val)? {
+                let len = e262_length_of_array_like(obj)?;
+                let mut keys = Vec::new();
+                let mut index = 0f64;
+                while index < len {
+                    keys.push(PropertyKey::from(index));
+                    index += 1f64;
+                }
+                keys
+            } else {
+                e262_enumerable_own_properties(obj, PropertyKind::Key)?
+                    .into_iter()
+                    .map(|key| match key {
+                        Value::String(key) => PropertyKey::from(key),
+                        _ => unreachable!("Enumerable own keys are strings"),
+                    })
+                    .collect()
+            };
+            for key in keys {
+                let child = node.and_then(|node| node.child(&key));
+                let new_element =
+                    internalize_json_property(obj, key.clone(), reviver, child, depth + 1)?;
+                if new_element == Value::Undefined {
+                    obj.0.clone().delete(&key)?;
+                } else {
+                    e262_create_data_property(obj, key, new_element)?;
+                }
+            }
+        }
+        val => {
+            if let Some(Node::Primitive(value, source)) = node {
+                if e262_same_value(value, val) {
+                    e262_create_data_property(
+                        &context,
+                        PropertyKey::from("source"),
+                        Value::String(source.clone()),
+                    )
+                    .expect("Fresh ordinary objects accept new properties");
+                }
+            }
+        }
+    }
+    e262_call(
+        reviver,
+        &Value::Object(holder.clone()),
+        &[name.to_value(), val, Value::Object(context)],
+    )
+}
+
+/// The state of a [JSON Serialization Record](https://tc39.es/ecma262/multipage/structured-data.html#sec-json-serialization-record).
+struct Serializer {
+    replacer_function: Option<Value>,
+    property_list: Option<Vec<StringRep>>,
+    stack: Vec<ObjectRep>,
+    indent: Vec<u16>,
+    gap: Vec<u16>,
+}
+
+/// Implements [`JSON.stringify`](https://tc39.es/ecma262/multipage/structured-data.html#sec-json.stringify).
+///
+/// Unlike the spec, it throws a RangeError for values with arrays and objects nested deeper than [`MAX_NESTING`] (256) levels.
+fn stringify(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let value = p262_argument(arguments, 0);
+    let replacer = p262_argument(arguments, 1);
+    let mut space = p262_argument(arguments, 2);
+    let mut serializer = Serializer {
+        replacer_function: None,
+        property_list: None,
+        stack: Vec::new(),
+        indent: Vec::new(),
+        gap: Vec::new(),
+    };
+    if e262_is_callable(&replacer) {
+        serializer.replacer_function = Some(replacer);
+    } else if e262_is_array(&replacer)? {
+        let Value::Object(replacer) = &replacer else {
+            unreachable!("Arrays are objects")
+        };
+        let len = e262_length_of_array_like(replacer)?;
+        let mut property_list: Vec<StringRep> = Vec::new();
+        let mut k = 0f64;
+        while k < len {
+            let v = e262_get(replacer, &PropertyKey::from(k))?;
+            let item = match &v {
+                Value::String(item) => Some(item.clone()),
+                Value::Number(_) => Some(e262_to_string(&v)?),
+                Value::Object(obj)
+                    if has_slot(obj, "StringData") || has_slot(obj, "NumberData") =>
+                {
+                    Some(e262_to_string(&v)?)
+                }
+                _ => None,
+            };
+            if let Some(item) = item {
+                if !property_list.contains(&item) {
+                    property_list.push(item);
+                }
+            }
+            k += 1f64;
+        }
+        serializer.property_list = Some(property_list);
+    }
+    if let Value::Object(obj) = &space {
+        if has_slot(obj, "NumberData") {
+            space = Value::Number(e262_to_number(&space)?);
+        } else if has_slot(obj, "StringData") {
+            space = Value::String(e262_to_string(&space)?);
+        }
+    }
+    serializer.gap = match &space {
+        Value::Number(_) => {
+            let spaces = e262_to_integer_or_infinity(&space)?
+                .to_f64()
+                .clamp(0f64, 10f64);
+            vec![0x20; spaces as usize]
+        }
+        Value::String(space) => space.code_units().take(10).collect(),
+        _ => Vec::new(),
+    };
+    let prototype = e262_current_realm().intrinsic(Intrinsic::ObjectPrototype);
+    let wrapper = e262_ordinary_object_create(Some(prototype));
+    e262_create_data_property(&wrapper, PropertyKey::from(""), value)
+        .expect("Fresh ordinary objects accept new properties");
+    match serializer.serialize_json_property(PropertyKey::from(""), &wrapper)? {
+        Some(result) => Ok(Value::String(StringRep::from_utf16(&result))),
+        None => Ok(Value::Undefined),
+    }
+}
+
+/// Returns whether `obj` has the internal slot `slot`.
+fn has_slot(obj: &ObjectRep, slot: &str) -> bool {
+    p262_has_slot(obj.0.clone(), slot.to_string())
+}
+
+impl Serializer {
+    /// Implements [SerializeJSONProperty](https://tc39.es/ecma262/multipage/structured-data.html#sec-serializejsonproperty), returning [`None`] for undefined.
+    fn serialize_json_property(
+        &mut self,
+        key: PropertyKey,
+        holder: &ObjectRep,
+    ) -> CoreResult<Option<Vec<u16>>> {
+        let mut value = e262_get(holder, &key)?;
+        if matches!(value, Value::Object(_) | Value::BigInt(_)) {
+            let to_json = e262_get_v(&value, &PropertyKey::from("toJSON"))?;
+            if e262_is_callable(&to_json) {
+                value = e262_call(&to_json, &value, &[key.to_value()])?;
+            }
+        }
+        if let Some(replacer) = &self.replacer_function {
+            value = e262_call(
+                replacer,
+                &Value::Object(holder.clone()),
+                &[key.to_value(), value],
+            )?;
+        }
+        if let Value::Object(obj) = &value {
+            if has_slot(obj, "NumberData") {
+                value = Value::Number(e262_to_number(&value)?);
+            } else if has_slot(obj, "StringData") {
+                value = Value::String(e262_to_string(&value)?);
+            } else if let Some(b) = p262_get_slot::<bool>(obj.0.clone(), "BooleanData".to_string())
+            {
+                value = Value::Boolean(*b);
+            } else if let Some(n) =
+                p262_get_slot::<num_bigint::BigInt>(obj.0.clone(), "BigIntData".to_string())
+            {
+                value = Value::BigInt((*n).clone());
+            }
+        }
+        let units = match &value {
+            Value::Null => "null".encode_utf16().collect(),
+            Value::Boolean(true) => "true".encode_utf16().collect(),
+            Value::Boolean(false) => "false".encode_utf16().collect(),
+            Value::String(string) => quote_json_string(string),
+            Value::Number(n) if n.is_finite() => {
+                numbers::e262_to_string(*n, 10).encode_utf16().collect()
+            }
+            Value::Number(_) => "null".encode_utf16().collect(),
+            Value::BigInt(_) => {
+                return Err(CoreError::TypeError(
+                    "Do not know how to serialize a BigInt".to_string(),
+                ))
+            }
+            Value::Object(obj) if !e262_is_callable(&value) => {
+                if e262_is_array(&value)? {
+                    self.serialize_json_array(obj)?
+                } else {
+                    self.serialize_json_object(obj)?
+                }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(units))
+    }
+
+    /// Pushes `value` on the stack, throwing a TypeError on a cyclic structure and a RangeError on one nested too deeply.
+    fn enter(&mut self, value: &ObjectRep) -> CoreResult<Vec<u16>> {
+        if self.stack.contains(value) {
+            return Err(CoreError::TypeError(
+                "Converting circular structure to JSON".to_string(),
+            ));
+        }
+        if self.stack.len() == MAX_NESTING {
+            return too_deep();
+        }
+        self.stack.push(value.clone());
+        let stepback = self.indent.clone();
+        self.indent.extend_from_slice(&self.gap);
+        Ok(stepback)
+    }
+
+    /// Pops the stack, and joins the serialized members between the `open` and `close` brackets.
+    fn leave(
+        &mut self,
+        stepback: Vec<u16>,
+        partial: Vec<Vec<u16>>,
+        open: u16,
+        close: u16,
+    ) -> Vec<u16> {
+        let mut result = vec![open];
+        if !partial.is_empty() {
+            let separator: Vec<u16> = if self.gap.is_empty() {
+                vec![0x2C]
+            } else {
+                [&[0x2C, 0x0A], &self.indent[..]].concat()
+            };
+            if !self.gap.is_empty() {
+                result.push(0x0A);
+                result.extend_from_slice(&self.indent);
+            }
+            result.extend(partial.join(&separator[..]));
+            if !self.gap.is_empty() {
+                result.push(0x0A);
+                result.extend_from_slice(&stepback);
+            }
+        }
+        result.push(close);
+        self.stack.pop();
+        self.indent = stepback;
+        result
+    }
+
+    /// Implements [SerializeJSONObject](https://tc39.es/ecma262/multipage/structured-data.html#sec-serializejsonobject).
+    fn serialize_json_object(&mut self, value: &ObjectRep) -> CoreResult<Vec<u16>> {
+        let stepback = self.enter(value)?;
+        let keys = match &self.property_list {
+            Some(property_list) => property_list.clone(),
+            None => e262_enumerable_own_properties(value, PropertyKind::Key)?
+                .into_iter()
+                .map(|key| match key {
+                    Value::String(key) => key,
+                    _ => unreachable!("Enumerable own keys are strings"),
+                })
+                .collect(),
+        };
+        let mut partial = Vec::new();
+        for p in keys {
+            let Some(str_p) = self.serialize_json_property(PropertyKey::from(p.clone()), value)?
+            else {
+                continue;
+            };
+            let mut member = quote_json_string(&p);
+            member.push(0x3A);
+            if !self.gap.is_empty() {
+                member.push(0x20);
+            }
+            member.extend(str_p);
+            partial.push(member);
+        }
+        Ok(self.leave(stepback, partial, 0x7B, 0x7D))
+    }
+
+    /// Implements [SerializeJSONArray](https://tc39.es/ecma262/multipage/structured-data.html#sec-serializejsonarray).
+    fn serialize_json_array(&mut self, value: &ObjectRep) -> CoreResult<Vec<u16>> {
+        let stepback = self.enter(value)?;
+        let len = e262_length_of_array_like(value)?;
+        let mut partial = Vec::new();
+        let mut index = 0f64;
+        while index < len {
+            let str_p = self.serialize_json_property(PropertyKey::from(index), value)?;
+            partial.push(str_p.unwrap_or_else(|| "null".encode_utf16().collect()));
+            index += 1f64;
+        }
+        Ok(self.leave(stepback, partial, 0x5B, 0x5D))
+    }
+}
+
+/// Implements [QuoteJSONString](https://tc39.es/ecma262/multipage/structured-data.html#sec-quotejsonstring), escaping lone surrogates so that the result is well-formed.
+fn quote_json_string(value: &StringRep) -> Vec<u16> {
+    let mut product = vec![0x22];
+    let mut buffer = [0u16; 2];
+    for c in value.code_points() {
+        let escape = match c {
+            0x08 => "\\b".to_string(),
+            0x09 => "\\t".to_string(),
+            0x0A => "\\n".to_string(),
+            0x0C => "\\f".to_string(),
+            0x0D => "\\r".to_string(),
+            0x22 => "\\\"".to_string(),
+            0x5C => "\\\\".to_string(),
+            0x00..=0x1F | 0xD800..=0xDFFF => format!("\\u{c:04x}"),
+            _ => {
+                product.extend_from_slice(p262_utf16_encode_code_point(c, &mut buffer));
+                continue;
+            }
+        };
+        product.extend(escape.encode_utf16());
+    }
+    product.push(0x22);
+    product
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::core::e262_create_builtin_function;
+
+    fn json(name: &str, arguments: &[Value]) -> CoreResult<Value> {
+        let json = e262_current_realm().intrinsic(Intrinsic::JSON);
+        let method = e262_get(&json, &PropertyKey::from(name)).unwrap();
+        e262_call(&method, &Value::Object(json), arguments)
+    }
+
+    fn string(value: &str) -> Value {
+        Value::String(value.into())
+    }
+
+    fn round_trip(text: &str, space: Value) -> Value {
+        let parsed = json("parse", &[string(text)]).unwrap();
+        json("stringify", &[parsed, Value::Undefined, space]).unwrap()
+    }
+
+    #[test]
+    fn parses_and_stringifies() {
+        assert_eq!(
+            round_trip(
+                r#" {"a": [1, -0.5e1, true, null, "x\ty"], "b": {}} "#,
+                Value::Undefined
+            ),
+            string(r#"{"a":[1,-5,true,null,"x\ty"],"b":{}}"#)
+        );
+        assert_eq!(
+            round_trip(r#"{"a":[1,{"b":2}],"c":[]}"#, Value::Number(2f64)),
+            string("{\n  \"a\": [\n    1,\n    {\n      \"b\": 2\n    }\n  ],\n  \"c\": []\n}")
+        );
+        assert_eq!(
+            round_trip(r#"[1]"#, string("--------------")),
+            string("[\n----------1\n]")
+        );
+        assert_eq!(
+            round_trip(r#"{"a":1,"a":2}"#, Value::Undefined),
+            string(r#"{"a":2}"#)
+        );
+        for text in [
+            "",
+            "01",
+            "[1,]",
+            "{'a':1}",
+            "\"\u{1}\"",
+            "tru",
+            "1 2",
+            "\"\\x\"",
+        ] {
+            assert!(
+                matches!(
+                    json("parse", &[string(text)]),
+                    Err(CoreError::SyntaxError(_))
+                ),
+                "{text:?} is not JSON"
+            );
+        }
+    }
+
+    #[test]
+    fn escapes_strings() {
+        let lone = Value::String(StringRep::from_utf16(&[0xD800, 0x61, 0xD83D, 0xDE00]));
+        assert_eq!(
+            json("stringify", &[lone]).unwrap(),
+            Value::String(StringRep::from_utf16(
+                &"\"\\ud800a\u{1F600}\"".encode_utf16().collect::<Vec<_>>()
+            ))
+        );
+        assert_eq!(
+            json("stringify", &[string("\u{8}\"\\\u{1f}")]).unwrap(),
+            string(r#""\b\"\\\u001f""#)
+        );
+        assert_eq!(
+            json("parse", &[string(r#""\ud800\u0041""#)]).unwrap(),
+            Value::String(StringRep::from_utf16(&[0xD800, 0x41]))
+        );
+    }
+
+    #[test]
+    fn rejects_big_ints_and_cycles() {
+        let big = Value::BigInt(1.into());
+        assert!(matches!(
+            json("stringify", &[big]),
+            Err(CoreError::TypeError(_))
+        ));
+        let array = e262_create_array_from_list(&[]);
+        e262_create_data_property(
+            &array,
+            PropertyKey::from(0u32),
+            Value::Object(array.clone()),
+        )
+        .unwrap();
+        assert!(matches!(
+            json("stringify", &[Value::Object(array)]),
+            Err(CoreError::TypeError(_))
+        ));
+        assert_eq!(
+            json("stringify", &[Value::Undefined]).unwrap(),
+            Value::Undefined
+        );
+    }
+
+    fn nested(depth: usize) -> Value {
+        let mut value = Value::Null;
+        for _ in 0..depth {
+            value = Value::Object(e262_create_array_from_list(&[value]));
+        }
+        value
+    }
+
+    #[test]
+    fn bounds_nesting() {
+        let text = |depth| string(&("[".repeat(depth) + &"]".repeat(depth)));
+        assert!(json("parse", &[text(MAX_NESTING)]).is_ok());
+        for depth in [MAX_NESTING + 1, 100_000] {
+            assert!(matches!(
+                json("parse", &[text(depth)]),
+                Err(CoreError::RangeError(_))
+            ));
+        }
+
+        assert!(json("stringify", &[nested(MAX_NESTING)]).is_ok());
+        assert!(matches!(
+            json("stringify", &[nested(MAX_NESTING + 1)]),
+            Err(CoreError::RangeError(_))
+        ));
+
+        // The reviver replaces the second element before it is visited.
+        let function_prototype = e262_current_realm().intrinsic(Intrinsic::FunctionPrototype);
+        let reviver = e262_create_builtin_function(
+            Rc::new(|this, arguments| {
+                if let (Value::Object(holder), Value::String(key)) = (this, &arguments[0]) {
+                    if *key == "0" {
+                        e262_create_data_property(
+                            holder,
+                            PropertyKey::from(1u32),
+                            nested(MAX_NESTING),
+                        )?;
+                    }
+                }
+                Ok(arguments[1].clone())
+            }),
+            3,
+            "",
+            Some(function_prototype),
+        );
+        assert!(matches!(
+            json("parse", &[string("[0, 0]"), Value::Object(reviver)]),
+            Err(CoreError::RangeError(_))
+        ));
+    }
+
+    #[test]
+    fn uses_replacers_and_to_json() {
+        let parsed = json("parse", &[string(r#"{"a":1,"b":2,"c":{"a":3,"d":4}}"#)]).unwrap();
+        let list = e262_create_array_from_list(&[string("a"), Value::Number(1f64), string("c")]);
+        assert_eq!(
+            json("stringify", &[parsed.clone(), Value::Object(list)]).unwrap(),
+            string(r#"{"a":1,"c":{"a":3}}"#)
+        );
+
+        let function_prototype = e262_current_realm().intrinsic(Intrinsic::FunctionPrototype);
+        let replacer = e262_create_builtin_function(
+            Rc::new(|_, arguments| match &arguments[1] {
+                Value::Number(n) => Ok(Value::Number(n * 10f64)),
+                value => Ok(value.clone()),
+            }),
+            2,
+            "",
+            Some(function_prototype.clone()),
+        );
+        assert_eq!(
+            json("stringify", &[parsed.clone(), Value::Object(replacer)]).unwrap(),
+            string(r#"{"a":10,"b":20,"c":{"a":30,"d":40}}"#)
+        );
+
+        let to_json = e262_create_builtin_function(
+            Rc::new(|_, arguments| Ok(arguments[0].clone())),
+            1,
+            "toJSON",
+            Some(function_prototype),
+        );
+        let Value::Object(obj) = &parsed else {
+            unreachable!()
+        };
+        e262_create_data_property(obj, PropertyKey::from("toJSON"), Value::Object(to_json))
+            .unwrap();
+        assert_eq!(json("stringify", &[parsed]).unwrap(), string(r#""""#));
+    }
+
+    #[test]
+    fn revives_with_source_text() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let log = seen.clone();
+        let function_prototype = e262_current_realm().intrinsic(Intrinsic::FunctionPrototype);
+        let reviver = e262_create_builtin_function(
+            Rc::new(move |_, arguments| {
+                let Value::Object(context) = &arguments[2] else {
+                    unreachable!()
+                };
+                let source = e262_get(context, &PropertyKey::from("source"))?;
+                log.borrow_mut().push((arguments[0].clone(), source));
+                match &arguments[0] {
+                    Value::String(key) if *key == "drop" => Ok(Value::Undefined),
+                    _ => Ok(arguments[1].clone()),
+                }
+            }),
+            3,
+            "",
+            Some(function_prototype),
+        );
+        let revived = json(
+            "parse",
+            &[
+                string(r#"{"n": 1.0e0, "drop": true, "l": [ "x" ]}"#),
+                Value::Object(reviver),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            json("stringify", &[revived]).unwrap(),
+            string(r#"{"n":1,"l":["x"]}"#)
+        );
+        assert_eq!(
+            *seen.borrow(),
+            vec![
+                (string("n"), string("1.0e0")),
+                (string("drop"), string("true")),
+                (string("0"), string(r#""x""#)),
+                (string("l"), Value::Undefined),
+                (string(""), Value::Undefined),
+            ]
+        );
+    }
+}
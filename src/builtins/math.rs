@@ -0,0 +1,439 @@
+use std::rc::Rc;
+
+use num_bigint::{BigInt, BigUint};
+use num_traits::{Signed, ToPrimitive, Zero};
+
+use super::{p262_argument, p262_define_constant, p262_define_method};
+use crate::agent::e262_current_realm;
+use crate::core::cast::{e262_to_number, e262_to_uint32};
+use crate::core::fdlibm;
+use crate::core::numbers::{e262_exponentiate, MAX_SAFE_INTEGER};
+use crate::core::test::e262_require_object_coercible;
+use crate::core::{
+    e262_define_property_or_throw, e262_get_iterator, e262_iterator_close,
+    e262_iterator_step_value, e262_ordinary_object_create, Descriptor, ObjectRep, PropertyKey,
+    SymbolRep, Value, WellKnownSymbol,
+};
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::{Intrinsic, Realm};
+
+/// Creates `%Math%`.
+///
+/// The transcendental functions are computed by [`fdlibm`], so they return the same bits on every platform.
+pub(crate) fn p262_create_intrinsics(realm: &Realm) {
+    let math = e262_ordinary_object_create(Some(realm.intrinsic(Intrinsic::ObjectPrototype)));
+    realm.set_intrinsic(Intrinsic::Math, math.clone());
+
+    p262_define_constant(&math, "E", Value::Number(std::f64::consts::E));
+    p262_define_constant(&math, "LN10", Value::Number(std::f64::consts::LN_10));
+    p262_define_constant(&math, "LN2", Value::Number(std::f64::consts::LN_2));
+    p262_define_constant(&math, "LOG10E", Value::Number(std::f64::consts::LOG10_E));
+    p262_define_constant(&math, "LOG2E", Value::Number(std::f64::consts::LOG2_E));
+    p262_define_constant(&math, "PI", Value::Number(std::f64::consts::PI));
+    p262_define_constant(
+        &math,
+        "SQRT1_2",
+        Value::Number(std::f64::consts::FRAC_1_SQRT_2),
+    );
+    p262_define_constant(&math, "SQRT2", Value::Number(std::f64::consts::SQRT_2));
+    let to_string_tag = SymbolRep::well_known(WellKnownSymbol::ToStringTag);
+    e262_define_property_or_throw(
+        &math,
+        PropertyKey::from(to_string_tag),
+        Descriptor {
+            value: Some(Rc::new(Value::String("Math".into()))),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Built-in properties can always be defined");
+
+    define_unary(realm, &math, "abs", f64::abs);
+    define_unary(realm, &math, "acos", fdlibm::acos);
+    define_unary(realm, &math, "acosh", fdlibm::acosh);
+    define_unary(realm, &math, "asin", fdlibm::asin);
+    define_unary(realm, &math, "asinh", fdlibm::asinh);
+    define_unary(realm, &math, "atan", fdlibm::atan);
+    define_unary(realm, &math, "atanh", fdlibm::atanh);
+    p262_define_method(realm, &math, "atan2", 2, |_, arguments| {
+        let ny = e262_to_number(&p262_argument(arguments, 0))?;
+        let nx = e262_to_number(&p262_argument(arguments, 1))?;
+        Ok(Value::Number(fdlibm::atan2(ny, nx)))
+    });
+    define_unary(realm, &math, "cbrt", fdlibm::cbrt);
+    define_unary(realm, &math, "ceil", f64::ceil);
+    p262_define_method(realm, &math, "clz32", 1, |_, arguments| {
+        let n = e262_to_uint32(&p262_argument(arguments, 0))?;
+        Ok(Value::Number(n.leading_zeros() as f64))
+    });
+    define_unary(realm, &math, "cos", fdlibm::cos);
+    define_unary(realm, &math, "cosh", fdlibm::cosh);
+    define_unary(realm, &math, "exp", fdlibm::exp);
+    define_unary(realm, &math, "expm1", fdlibm::expm1);
+    define_unary(realm, &math, "floor", f64::floor);
+    define_unary(realm, &math, "fround", |n| n as f32 as f64);
+    define_unary(realm, &math, "f16round", f16_round);
+    p262_define_method(realm, &math, "hypot", 2, hypot);
+    p262_define_method(realm, &math, "imul", 2, |_, arguments| {
+        let a = e262_to_uint32(&p262_argument(arguments, 0))?;
+        let b = e262_to_uint32(&p262_argument(arguments, 1))?;
+        Ok(Value::Number(a.wrapping_mul(b) as i32 as f64))
+    });
+    define_unary(realm, &math, "log", fdlibm::log);
+    define_unary(realm, &math, "log1p", fdlibm::log1p);
+    define_unary(realm, &math, "log10", fdlibm::log10);
+    define_unary(realm, &math, "log2", fdlibm::log2);
+    p262_define_method(realm, &math, "max", 2, |_, arguments| {
+        extremum(arguments, f64::NEG_INFINITY, |n, best| {
+            n > best || (n == 0f64 && best == 0f64 && best.is_sign_negative())
+        })
+    });
+    p262_define_method(realm, &math, "min", 2, |_, arguments| {
+        extremum(arguments, f64::INFINITY, |n, best| {
+            n < best || (n == 0f64 && best == 0f64 && n.is_sign_negative())
+        })
+    });
+    p262_define_method(realm, &math, "pow", 2, |_, arguments| {
+        let base = e262_to_number(&p262_argument(arguments, 0))?;
+        let exponent = e262_to_number(&p262_argument(arguments, 1))?;
+        Ok(Value::Number(e262_exponentiate(base, exponent)))
+    });
+    p262_define_method(realm, &math, "random", 0, |_, _| {
+        Ok(Value::Number(e262_current_realm().random()))
+    });
+    define_unary(realm, &math, "round", round);
+    define_unary(realm, &math, "sign", |n| {
+        if n.is_nan() || n == 0f64 {
+            n
+        } else {
+            n.signum()
+        }
+    });
+    define_unary(realm, &math, "sin", fdlibm::sin);
+    define_unary(realm, &math, "sinh", fdlibm::sinh);
+    define_unary(realm, &math, "sqrt", f64::sqrt);
+    p262_define_method(realm, &math, "sumPrecise", 1, sum_precise);
+    define_unary(realm, &math, "tan", fdlibm::tan);
+    define_unary(realm, &math, "tanh", fdlibm::tanh);
+    define_unary(realm, &math, "trunc", f64::trunc);
+}
+
+/// Defines a function of one argument that applies `op` to the argument converted to a Number.
+fn define_unary(realm: &Realm, math: &ObjectRep, name: &str, op: fn(f64) -> f64) {
+    p262_define_method(realm, math, name, 1, move |_, arguments| {
+        let n = e262_to_number(&p262_argument(arguments, 0))?;
+        Ok(Value::Number(op(n)))
+    });
+}
+
+/// Implements [`Math.f16round`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-math.f16round), rounding to the nearest IEEE 754 binary16 value, ties to even.
+fn f16_round(n: f64) -> f64 {
+    // The smallest magnitude that rounds to infinity, halfway between 65504 and 2^16.
+    const OVERFLOW: f64 = 65520f64;
+    // The smallest normal binary16 magnitude, 2^-14.
+    const MIN_NORMAL: f64 = 6.103515625e-05;
+    if !n.is_finite() || n == 0f64 {
+        return n;
+    }
+    let magnitude = n.abs();
+    if magnitude >= OVERFLOW {
+        return f64::INFINITY.copysign(n);
+    }
+    // The distance between binary16 values around n, i.e. 2^-24 for subnormals.
+    let exponent = if magnitude < MIN_NORMAL {
+        -14
+    } else {
+        ((magnitude.to_bits() >> 52) as i32) - 1023
+    };
+    let quantum = f64::from_bits(((exponent - 10 + 1023) as u64) << 52);
+    ((magnitude / quantum).round_ties_even() * quantum).copysign(n)
+}
+
+/// Implements [`Math.hypot`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-math.hypot).
+fn hypot(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let coerced = arguments
+        .iter()
+        .map(e262_to_number)
+        .collect::<CoreResult<Vec<_>>>()?;
+    if coerced.iter().any(|n| n.is_infinite()) {
+        return Ok(Value::Number(f64::INFINITY));
+    }
+    if coerced.iter().any(|n| n.is_nan()) {
+        return Ok(Value::Number(f64::NAN));
+    }
+    Ok(Value::Number(coerced.into_iter().fold(0f64, fdlibm::hypot)))
+}
+
+/// Implements [`Math.max`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-math.max) and [`Math.min`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-math.min), where `better(n, best)` tells whether `n` replaces the current result.
+fn extremum(
+    arguments: &[Value],
+    initial: f64,
+    better: impl Fn(f64, f64) -> bool,
+) -> CoreResult<Value> {
+    let coerced = arguments
+        .iter()
+        .map(e262_to_number)
+        .collect::<CoreResult<Vec<_>>>()?;
+    let mut best = initial;
+    for n in coerced {
+        if n.is_nan() {
+            return Ok(Value::Number(f64::NAN));
+        }
+        if better(n, best) {
+            best = n;
+        }
+    }
+    Ok(Value::Number(best))
+}
+
+/// Implements [`Math.round`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-math.round), rounding halfway cases towards +∞.
+fn round(n: f64) -> f64 {
+    if !n.is_finite() || n == 0f64 {
+        return n;
+    }
+    if 0f64 < n && n < 0.5 {
+        return 0f64;
+    }
+    if (-0.5..0f64).contains(&n) {
+        return -0f64;
+    }
+    // Adding 0.5 could round, so the fraction is compared instead.
+    let floor = n.floor();
+    if n - floor >= 0.5 {
+        floor + 1f64
+    } else {
+        floor
+    }
+}
+
+/// The state of [`Math.sumPrecise`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-math.sumprecise) while iterating.
+#[derive(Clone, Copy, PartialEq)]
+enum SumState {
+    MinusZero,
+    Finite,
+    PlusInfinity,
+    MinusInfinity,
+    NotANumber,
+}
+
+/// The number of bits below the unit of the smallest subnormal, so that every finite Number is an integer multiple of `2^-SCALE`.
+const SCALE: i32 = 1074;
+
+/// Returns the finite `n` as an exact multiple of `2^-SCALE`.
+fn to_scaled_integer(n: f64) -> BigInt {
+    let bits = n.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as usize;
+    let fraction = bits & ((1 << 52) - 1);
+    let magnitude = match biased_exponent {
+        0 => BigUint::from(fraction),
+        _ => BigUint::from(fraction | 1 << 52) << (biased_exponent - 1),
+    };
+    let scaled = BigInt::from(magnitude);
+    if n.is_sign_negative() {
+        -scaled
+    } else {
+        scaled
+    }
+}
+
+/// Returns the Number closest to `sum × 2^-SCALE`, ties to even.
+fn from_scaled_integer(sum: &BigInt) -> f64 {
+    let magnitude = sum.magnitude();
+    let shift = magnitude.bits().saturating_sub(53);
+    let mut significand = (magnitude >> shift)
+        .to_u64()
+        .expect("The significand has at most 53 bits");
+    if shift > 0 {
+        let round_bit = magnitude.bit(shift - 1);
+        let sticky = magnitude
+            .trailing_zeros()
+            .is_some_and(|zeros| zeros < shift - 1);
+        if round_bit && (sticky || significand & 1 == 1) {
+            significand += 1;
+        }
+    }
+    let result = fdlibm::scalbn(significand as f64, shift as i32 - SCALE);
+    if sum.is_negative() {
+        -result
+    } else {
+        result
+    }
+}
+
+/// Implements [`Math.sumPrecise`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-math.sumprecise), summing exactly before rounding once.
+fn sum_precise(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let items = e262_require_object_coercible(p262_argument(arguments, 0))?;
+    let mut record = e262_get_iterator(&items)?;
+    let mut state = SumState::MinusZero;
+    let mut sum = BigInt::zero();
+    let mut count = 0f64;
+    while let Some(next) = e262_iterator_step_value(&mut record)? {
+        count += 1f64;
+        if count > MAX_SAFE_INTEGER {
+            let error = CoreError::RangeError("Too many values to sum".to_string());
+            return e262_iterator_close(&record, Err(error));
+        }
+        let Value::Number(n) = next else {
+            let error = CoreError::TypeError("Only Numbers can be summed".to_string());
+            return e262_iterator_close(&record, Err(error));
+        };
+        if state == SumState::NotANumber {
+            continue;
+        }
+        state = if n.is_nan() {
+            SumState::NotANumber
+        } else if n == f64::INFINITY {
+            match state {
+                SumState::MinusInfinity => SumState::NotANumber,
+                _ => SumState::PlusInfinity,
+            }
+        } else if n == f64::NEG_INFINITY {
+            match state {
+                SumState::PlusInfinity => SumState::NotANumber,
+                _ => SumState::MinusInfinity,
+            }
+        } else if (n != 0f64 || n.is_sign_positive())
+            && matches!(state, SumState::MinusZero | SumState::Finite)
+        {
+            sum += to_scaled_integer(n);
+            SumState::Finite
+        } else {
+            state
+        };
+    }
+    Ok(Value::Number(match state {
+        SumState::NotANumber => f64::NAN,
+        SumState::PlusInfinity => f64::INFINITY,
+        SumState::MinusInfinity => f64::NEG_INFINITY,
+        SumState::MinusZero => -0f64,
+        SumState::Finite => from_scaled_integer(&sum),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::p262_set_seed;
+    use crate::core::{e262_create_array_from_list, e262_invoke};
+
+    fn call<const N: usize>(name: &str, arguments: [Value; N]) -> CoreResult<Value> {
+        let math = Value::Object(e262_current_realm().intrinsic(Intrinsic::Math));
+        e262_invoke(&math, &PropertyKey::from(name), &arguments)
+    }
+
+    fn number<const N: usize>(name: &str, arguments: [f64; N]) -> f64 {
+        match call(name, arguments.map(Value::Number)).unwrap() {
+            Value::Number(n) => n,
+            value => panic!("{name} returned {value:?}"),
+        }
+    }
+
+    fn is_neg_zero(n: f64) -> bool {
+        n == 0f64 && n.is_sign_negative()
+    }
+
+    #[test]
+    fn handles_signed_zeros_and_nan() {
+        assert!(is_neg_zero(number("round", [-0.25])));
+        assert_eq!(number("round", [0.49999999999999994]), 0f64);
+        assert_eq!(number("round", [-2.5]), -2f64);
+        assert_eq!(number("round", [2.5]), 3f64);
+        assert!(is_neg_zero(number("sign", [-0f64])));
+        assert!(is_neg_zero(number("atan2", [-0f64, 1f64])));
+        assert_eq!(number("atan2", [0f64, -0f64]), std::f64::consts::PI);
+        assert!(is_neg_zero(number("max", [-0f64, -0f64])));
+        assert_eq!(number("max", [-0f64, 0f64]), 0f64);
+        assert!(!is_neg_zero(number("max", [-0f64, 0f64])));
+        assert!(is_neg_zero(number("min", [0f64, -0f64])));
+        assert_eq!(number("max", []), f64::NEG_INFINITY);
+        assert!(number("min", [1f64, f64::NAN]).is_nan());
+        assert_eq!(
+            number("hypot", [f64::NAN, f64::NEG_INFINITY]),
+            f64::INFINITY
+        );
+        assert!(!is_neg_zero(number("hypot", [-0f64])));
+        assert_eq!(number("hypot", [3f64, 4f64, 12f64]), 13f64);
+        assert!(number("pow", [1f64, f64::INFINITY]).is_nan());
+        assert!(is_neg_zero(number("cbrt", [-0f64])));
+    }
+
+    #[test]
+    fn integer_and_rounding_functions() {
+        assert_eq!(number("clz32", [1f64]), 31f64);
+        assert_eq!(number("clz32", [0f64]), 32f64);
+        assert_eq!(number("imul", [0xffffffffu32 as f64, 5f64]), -5f64);
+        assert_eq!(number("fround", [5.5]), 5.5);
+        assert_eq!(number("fround", [5.05]), 5.050000190734863);
+        assert_eq!(number("f16round", [5.05]), 5.05078125);
+        assert_eq!(number("f16round", [65519.99]), 65504f64);
+        assert_eq!(number("f16round", [65520f64]), f64::INFINITY);
+        // Halfway between the two smallest subnormals, rounding to the even one.
+        assert_eq!(number("f16round", [2f64.powi(-25)]), 0f64);
+        assert_eq!(number("f16round", [3f64 * 2f64.powi(-25)]), 2f64.powi(-23));
+        assert!(is_neg_zero(number("f16round", [-1e-10])));
+    }
+
+    #[test]
+    fn transcendental_results_are_portable() {
+        assert_eq!(number("exp", [1f64]), 2.7182818284590455);
+        assert_eq!(number("sin", [1e22]), -0.8522008497671888);
+        assert_eq!(number("pow", [2f64, 0.5]), std::f64::consts::SQRT_2);
+        assert_eq!(number("log10", [1e15]), 15f64);
+    }
+
+    #[test]
+    fn sum_precise_is_exact() {
+        let sum = |values: &[f64]| {
+            let list: Vec<_> = values.iter().copied().map(Value::Number).collect();
+            let array = Value::Object(e262_create_array_from_list(&list));
+            number_of(call("sumPrecise", [array]))
+        };
+        fn number_of(result: CoreResult<Value>) -> f64 {
+            match result.unwrap() {
+                Value::Number(n) => n,
+                value => panic!("sumPrecise returned {value:?}"),
+            }
+        }
+        assert!(is_neg_zero(sum(&[])));
+        assert!(is_neg_zero(sum(&[-0f64, -0f64])));
+        assert_eq!(sum(&[1e20, 0.1, -1e20]), 0.1);
+        assert_eq!(sum(&[0.1, 0.2]), 0.30000000000000004);
+        assert_eq!(sum(&[f64::MAX, f64::MAX, -f64::MAX]), f64::MAX);
+        assert_eq!(sum(&[f64::MAX, f64::MAX]), f64::INFINITY);
+        assert_eq!(sum(&[5e-324, 5e-324]), 1e-323);
+        assert_eq!(sum(&[1f64, 2f64.powi(-53)]), 1f64);
+        assert_eq!(
+            sum(&[1f64, 2f64.powi(-53), 2f64.powi(-80)]),
+            1f64 + f64::EPSILON
+        );
+        assert!(sum(&[f64::INFINITY, 1f64, f64::NEG_INFINITY]).is_nan());
+        assert!(sum(&[f64::NAN, 1f64]).is_nan());
+        let mixed = e262_create_array_from_list(&[Value::Number(1f64), Value::String("2".into())]);
+        assert!(matches!(
+            call("sumPrecise", [Value::Object(mixed)]),
+            Err(CoreError::TypeError(_))
+        ));
+        assert!(call("sumPrecise", [Value::Undefined]).is_err());
+    }
+
+    #[test]
+    fn random_is_seeded_per_realm() {
+        let realm = e262_current_realm();
+        realm.set_random_seed(7);
+        let first = [number("random", []), number("random", [])];
+        realm.set_random_seed(7);
+        let second = [number("random", []), number("random", [])];
+        assert_eq!(first, second);
+        assert!(first.iter().all(|n| (0f64..1f64).contains(n)));
+
+        // Seeding the agent reseeds the realms that already exist.
+        p262_set_seed(Some(7));
+        let first = [number("random", []), number("random", [])];
+        p262_set_seed(Some(7));
+        let second = [number("random", []), number("random", [])];
+        p262_set_seed(None);
+        assert_eq!(first, second);
+    }
+}
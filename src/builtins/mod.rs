@@ -0,0 +1,140 @@
+//! A module implementing the ES [built-in objects](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-built-in-object) of a realm.
+//!
+//! Each submodule creates the intrinsics of one constructor and its prototype, and is called while [creating the intrinsics](crate::realm::Realm::new) of a realm.
+
+pub(crate) mod array;
+pub(crate) mod bigint;
+pub(crate) mod boolean;
+pub(crate) mod error;
+pub(crate) mod function;
+pub(crate) mod global;
+pub(crate) mod iterator;
+pub(crate) mod json;
+pub(crate) mod math;
+pub(crate) mod number;
+pub(crate) mod object;
+pub(crate) mod regexp;
+pub(crate) mod string;
+pub(crate) mod symbol;
+
+use std::rc::Rc;
+
+use crate::core::cast::e262_to_integer_or_infinity;
+use crate::core::{
+    e262_create_builtin_function, e262_define_property_or_throw, Descriptor, ObjectRep,
+    PropertyKey, Value,
+};
+use crate::errors::CoreResult;
+use crate::realm::{Intrinsic, Realm};
+
+/// Returns the argument at `index`, or undefined if it was not passed.
+pub(crate) fn p262_argument(arguments: &[Value], index: usize) -> Value {
+    arguments.get(index).cloned().unwrap_or(Value::Undefined)
+}
+
+/// Resolves an index argument relative to the end of a sequence of `len` elements when negative, clamped to `0..=len`.
+pub(crate) fn p262_relative_index(argument: &Value, len: f64) -> CoreResult<f64> {
+    let relative = e262_to_integer_or_infinity(argument)?.to_f64();
+    if relative < 0f64 {
+        Ok((len + relative).max(0f64))
+    } else {
+        Ok(relative.min(len))
+    }
+}
+
+/// Defines a built-in function as a writable, non-enumerable and configurable property of `obj`, as is the default for [built-in properties](https://tc39.es/ecma262/multipage/ecmascript-standard-built-in-objects.html#sec-ecmascript-standard-built-in-objects).
+pub(crate) fn p262_define_method(
+    realm: &Realm,
+    obj: &ObjectRep,
+    key: impl Into<PropertyKey>,
+    length: usize,
+    behaviour: impl Fn(&Value, &[Value]) -> CoreResult<Value> + 'static,
+) -> ObjectRep {
+    let key = key.into();
+    let func = e262_create_builtin_function(
+        Rc::new(behaviour),
+        length,
+        &function_name(&key),
+        Some(realm.intrinsic(Intrinsic::FunctionPrototype)),
+    );
+    p262_define_value(obj, key, Value::Object(func.clone()));
+    func
+}
+
+/// Defines a built-in getter as a non-enumerable and configurable accessor property of `obj`.
+pub(crate) fn p262_define_getter(
+    realm: &Realm,
+    obj: &ObjectRep,
+    key: impl Into<PropertyKey>,
+    behaviour: impl Fn(&Value, &[Value]) -> CoreResult<Value> + 'static,
+) {
+    let key = key.into();
+    let getter = e262_create_builtin_function(
+        Rc::new(behaviour),
+        0,
+        &format!("get {}", function_name(&key)),
+        Some(realm.intrinsic(Intrinsic::FunctionPrototype)),
+    );
+    e262_define_property_or_throw(
+        obj,
+        key,
+        Descriptor {
+            get: Some(Rc::new(Value::Object(getter))),
+            set: Some(Rc::new(Value::Undefined)),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Built-in properties can always be defined");
+}
+
+/// Returns the name of a function stored in the `key` property, as [SetFunctionName](https://tc39.es/ecma262/multipage/ordinary-and-exotic-objects-behaviours.html#sec-setfunctionname) computes it.
+fn function_name(key: &PropertyKey) -> String {
+    match key {
+        PropertyKey::Symbol(symbol) => match symbol.description() {
+            Some(description) => format!("[{description}]"),
+            None => String::new(),
+        },
+        PropertyKey::String(atom) => atom.as_string().to_std_string_lossy(),
+        PropertyKey::Index(index) => index.to_string(),
+    }
+}
+
+/// Defines a writable, non-enumerable and configurable data property of `obj`.
+pub(crate) fn p262_define_value(obj: &ObjectRep, key: impl Into<PropertyKey>, value: Value) {
+    e262_define_property_or_throw(
+        obj,
+        key.into(),
+        Descriptor {
+            value: Some(Rc::new(value)),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Built-in properties can always be defined");
+}
+
+/// Defines a non-writable, non-enumerable and non-configurable data property of `obj`, e.g. the `prototype` property of constructors.
+pub(crate) fn p262_define_constant(obj: &ObjectRep, key: impl Into<PropertyKey>, value: Value) {
+    e262_define_property_or_throw(
+        obj,
+        key.into(),
+        Descriptor {
+            value: Some(Rc::new(value)),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(false),
+            ..Default::default()
+        },
+    )
+    .expect("Built-in properties can always be defined");
+}
+
+/// Links a constructor and its prototype via their `prototype` and `constructor` properties.
+pub(crate) fn p262_link_constructor(constructor: &ObjectRep, prototype: &ObjectRep) {
+    p262_define_constant(constructor, "prototype", Value::Object(prototype.clone()));
+    p262_define_value(prototype, "constructor", Value::Object(constructor.clone()));
+}
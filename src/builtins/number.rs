@@ -0,0 +1,316 @@
+use std::rc::Rc;
+
+use super::{
+    p262_argument, p262_define_constant, p262_define_method, p262_define_value,
+    p262_link_constructor,
+};
+use crate::core::cast::{e262_to_integer_or_infinity, e262_to_numeric, Numeric};
+use crate::core::numbers::{
+    p262_to_exponential, p262_to_fixed, p262_to_precision, MAX_SAFE_INTEGER,
+};
+use crate::core::test::e262_is_integral_number;
+use crate::core::{
+    bigint, e262_ordinary_create_from_constructor, numbers, p262_create_builtin_constructor,
+    p262_get_slot, ObjectRep, Value,
+};
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::{Intrinsic, Realm};
+
+/// Creates `%Number%` and the methods of `%Number.prototype%`.
+pub(crate) fn p262_create_intrinsics(realm: &Realm) {
+    let prototype = realm.intrinsic(Intrinsic::NumberPrototype);
+    let constructor = p262_create_builtin_constructor(
+        Rc::new(|_, arguments| Ok(Value::Number(to_number_value(arguments)?))),
+        Rc::new(construct),
+        1,
+        "Number",
+        Some(realm.intrinsic(Intrinsic::FunctionPrototype)),
+    );
+    p262_link_constructor(&constructor, &prototype);
+    realm.set_intrinsic(Intrinsic::Number, constructor.clone());
+
+    p262_define_constant(&constructor, "EPSILON", Value::Number(f64::EPSILON));
+    p262_define_constant(
+        &constructor,
+        "MAX_SAFE_INTEGER",
+        Value::Number(MAX_SAFE_INTEGER),
+    );
+    p262_define_constant(&constructor, "MAX_VALUE", Value::Number(f64::MAX));
+    p262_define_constant(
+        &constructor,
+        "MIN_SAFE_INTEGER",
+        Value::Number(-MAX_SAFE_INTEGER),
+    );
+    // The smallest positive denormalized value, 5 × 10^-324.
+    p262_define_constant(&constructor, "MIN_VALUE", Value::Number(f64::from_bits(1)));
+    p262_define_constant(&constructor, "NaN", Value::Number(f64::NAN));
+    p262_define_constant(
+        &constructor,
+        "NEGATIVE_INFINITY",
+        Value::Number(f64::NEG_INFINITY),
+    );
+    p262_define_constant(
+        &constructor,
+        "POSITIVE_INFINITY",
+        Value::Number(f64::INFINITY),
+    );
+
+    p262_define_method(realm, &constructor, "isFinite", 1, |_, arguments| {
+        let number = p262_argument(arguments, 0);
+        Ok(Value::Boolean(
+            matches!(number, Value::Number(n) if n.is_finite()),
+        ))
+    });
+    p262_define_method(realm, &constructor, "isInteger", 1, |_, arguments| {
+        Ok(Value::Boolean(e262_is_integral_number(&p262_argument(
+            arguments, 0,
+        ))))
+    });
+    p262_define_method(realm, &constructor, "isNaN", 1, |_, arguments| {
+        let number = p262_argument(arguments, 0);
+        Ok(Value::Boolean(
+            matches!(number, Value::Number(n) if n.is_nan()),
+        ))
+    });
+    p262_define_method(realm, &constructor, "isSafeInteger", 1, is_safe_integer);
+    p262_define_value(
+        &constructor,
+        "parseFloat",
+        Value::Object(realm.intrinsic(Intrinsic::ParseFloat)),
+    );
+    p262_define_value(
+        &constructor,
+        "parseInt",
+        Value::Object(realm.intrinsic(Intrinsic::ParseInt)),
+    );
+
+    p262_define_method(realm, &prototype, "toExponential", 1, to_exponential);
+    p262_define_method(realm, &prototype, "toFixed", 1, to_fixed);
+    p262_define_method(realm, &prototype, "toLocaleString", 0, |this, _| {
+        to_string(this, &[])
+    });
+    p262_define_method(realm, &prototype, "toPrecision", 1, to_precision);
+    p262_define_method(realm, &prototype, "toString", 1, to_string);
+    p262_define_method(realm, &prototype, "valueOf", 0, |this, _| {
+        Ok(Value::Number(this_number_value(this)?))
+    });
+}
+
+/// Converts the first argument of the [Number constructor](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-number-constructor-number-value) to a Number, converting BigInts to the closest Number.
+fn to_number_value(arguments: &[Value]) -> CoreResult<f64> {
+    match arguments.first() {
+        None => Ok(0f64),
+        Some(value) => match e262_to_numeric(value)? {
+            Numeric::BigInt(prim) => Ok(bigint::p262_to_number(&prim)),
+            Numeric::Number(prim) => Ok(prim),
+        },
+    }
+}
+
+/// Implements [`Number`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-number-constructor-number-value) when called as part of a `new` expression.
+fn construct(arguments: &[Value], new_target: &ObjectRep) -> CoreResult<ObjectRep> {
+    let n = to_number_value(arguments)?;
+    let obj = e262_ordinary_create_from_constructor(new_target, Intrinsic::NumberPrototype)?;
+    obj.0.clone().set_slot("NumberData".to_string(), Rc::new(n));
+    Ok(obj)
+}
+
+/// Implements [thisNumberValue](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-thisnumbervalue).
+fn this_number_value(value: &Value) -> CoreResult<f64> {
+    match value {
+        Value::Number(n) => Ok(*n),
+        Value::Object(obj) => p262_get_slot::<f64>(obj.0.clone(), "NumberData".to_string())
+            .map(|n| *n)
+            .ok_or_else(|| CoreError::TypeError("Receiver is not a Number".to_string())),
+        _ => Err(CoreError::TypeError("Receiver is not a Number".to_string())),
+    }
+}
+
+/// Checks a digit count converted by ToIntegerOrInfinity, throwing a RangeError outside `min..=100`.
+fn digit_count(digits: f64, min: f64, name: &str) -> CoreResult<usize> {
+    if !(min..=100f64).contains(&digits) {
+        return Err(CoreError::RangeError(format!(
+            "{name} argument must be between {min} and 100"
+        )));
+    }
+    Ok(digits as usize)
+}
+
+/// Implements [`Number.isSafeInteger`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-number.issafeinteger).
+fn is_safe_integer(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let number = p262_argument(arguments, 0);
+    Ok(Value::Boolean(match number {
+        Value::Number(n) => e262_is_integral_number(&number) && n.abs() <= MAX_SAFE_INTEGER,
+        _ => false,
+    }))
+}
+
+/// Implements [`Number.prototype.toExponential`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-number.prototype.toexponential).
+fn to_exponential(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let x = this_number_value(this)?;
+    let fraction_digits = p262_argument(arguments, 0);
+    let f = e262_to_integer_or_infinity(&fraction_digits)?.to_f64();
+    if !x.is_finite() {
+        return Ok(Value::String(numbers::e262_to_string(x, 10).into()));
+    }
+    let f = digit_count(f, 0f64, "toExponential()")?;
+    let f = match fraction_digits {
+        Value::Undefined => None,
+        _ => Some(f),
+    };
+    Ok(Value::String(p262_to_exponential(x, f).into()))
+}
+
+/// Implements [`Number.prototype.toFixed`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-number.prototype.tofixed).
+fn to_fixed(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let x = this_number_value(this)?;
+    let f = e262_to_integer_or_infinity(&p262_argument(arguments, 0))?.to_f64();
+    let f = digit_count(f, 0f64, "toFixed()")?;
+    Ok(Value::String(p262_to_fixed(x, f).into()))
+}
+
+/// Implements [`Number.prototype.toPrecision`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-number.prototype.toprecision).
+fn to_precision(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let x = this_number_value(this)?;
+    let precision = p262_argument(arguments, 0);
+    if precision == Value::Undefined {
+        return Ok(Value::String(numbers::e262_to_string(x, 10).into()));
+    }
+    let p = e262_to_integer_or_infinity(&precision)?.to_f64();
+    if !x.is_finite() {
+        return Ok(Value::String(numbers::e262_to_string(x, 10).into()));
+    }
+    let p = digit_count(p, 1f64, "toPrecision()")?;
+    Ok(Value::String(p262_to_precision(x, p).into()))
+}
+
+/// Implements [`Number.prototype.toString`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-number.prototype.tostring).
+fn to_string(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let x = this_number_value(this)?;
+    let radix = match p262_argument(arguments, 0) {
+        Value::Undefined => 10f64,
+        radix => e262_to_integer_or_infinity(&radix)?.to_f64(),
+    };
+    if !(2f64..=36f64).contains(&radix) {
+        return Err(CoreError::RangeError(
+            "toString() radix must be between 2 and 36".to_string(),
+        ));
+    }
+    Ok(Value::String(
+        numbers::e262_to_string(x, radix as u32).into(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+
+    use super::*;
+    use crate::agent::e262_current_realm;
+    use crate::core::function::{e262_call, e262_construct};
+    use crate::core::{e262_get, e262_invoke, PropertyKey};
+
+    fn invoke<const N: usize>(this: Value, name: &str, arguments: [Value; N]) -> CoreResult<Value> {
+        e262_invoke(&this, &PropertyKey::from(name), &arguments)
+    }
+
+    fn constant(name: &str) -> Value {
+        let constructor = e262_current_realm().intrinsic(Intrinsic::Number);
+        e262_get(&constructor, &PropertyKey::from(name)).unwrap()
+    }
+
+    fn str(value: &str) -> Value {
+        Value::String(value.into())
+    }
+
+    #[test]
+    fn constructor_converts_and_wraps() {
+        let constructor = e262_current_realm().intrinsic(Intrinsic::Number);
+        let call = |arguments: &[Value]| {
+            e262_call(
+                &Value::Object(constructor.clone()),
+                &Value::Undefined,
+                arguments,
+            )
+            .unwrap()
+        };
+        assert_eq!(call(&[]), Value::Number(0f64));
+        assert_eq!(call(&[str(" 0x10 ")]), Value::Number(16f64));
+        let big = BigInt::from(1u8) << 1100;
+        assert_eq!(call(&[Value::BigInt(big)]), Value::Number(f64::INFINITY));
+        assert_eq!(
+            call(&[Value::BigInt(BigInt::from(-7))]),
+            Value::Number(-7f64)
+        );
+        let wrapper = e262_construct(&constructor, &[Value::Number(4.5f64)], None).unwrap();
+        assert_eq!(
+            invoke(Value::Object(wrapper), "valueOf", []).unwrap(),
+            Value::Number(4.5f64)
+        );
+        let value_of = e262_get(
+            &e262_current_realm().intrinsic(Intrinsic::NumberPrototype),
+            &PropertyKey::from("valueOf"),
+        )
+        .unwrap();
+        assert!(e262_call(&value_of, &str("1"), &[]).is_err());
+    }
+
+    #[test]
+    fn constants_and_predicates() {
+        assert_eq!(
+            constant("MAX_SAFE_INTEGER"),
+            Value::Number(MAX_SAFE_INTEGER)
+        );
+        assert_eq!(constant("MIN_VALUE"), Value::Number(5e-324f64));
+        assert_eq!(constant("EPSILON"), Value::Number(2f64.powi(-52)));
+        let constructor = Value::Object(e262_current_realm().intrinsic(Intrinsic::Number));
+        let is = |name: &str, value: Value| invoke(constructor.clone(), name, [value]).unwrap();
+        assert_eq!(is("isFinite", str("1")), Value::Boolean(false));
+        assert_eq!(is("isInteger", Value::Number(-0f64)), Value::Boolean(true));
+        assert_eq!(is("isNaN", Value::Number(f64::NAN)), Value::Boolean(true));
+        assert_eq!(is("isNaN", str("x")), Value::Boolean(false));
+        assert_eq!(
+            is("isSafeInteger", Value::Number(MAX_SAFE_INTEGER + 1f64)),
+            Value::Boolean(false)
+        );
+        assert_eq!(
+            constant("parseInt"),
+            Value::Object(e262_current_realm().intrinsic(Intrinsic::ParseInt))
+        );
+    }
+
+    #[test]
+    fn formats_digits() {
+        let n = |value: f64| Value::Number(value);
+        assert_eq!(invoke(n(1.005), "toFixed", [n(2f64)]).unwrap(), str("1.00"));
+        assert_eq!(invoke(n(1e21), "toFixed", [n(2f64)]).unwrap(), str("1e+21"));
+        assert_eq!(
+            invoke(n(123.456), "toExponential", []).unwrap(),
+            str("1.23456e+2")
+        );
+        assert_eq!(
+            invoke(n(123.456), "toExponential", [n(1f64)]).unwrap(),
+            str("1.2e+2")
+        );
+        assert_eq!(
+            invoke(n(f64::INFINITY), "toExponential", [n(-1f64)]).unwrap(),
+            str("Infinity")
+        );
+        assert_eq!(
+            invoke(n(0.000123), "toPrecision", [n(2f64)]).unwrap(),
+            str("0.00012")
+        );
+        assert_eq!(
+            invoke(n(255f64), "toString", [n(16f64)]).unwrap(),
+            str("ff")
+        );
+        assert_eq!(invoke(n(-0f64), "toString", []).unwrap(), str("0"));
+        let range_error =
+            |result: CoreResult<Value>| matches!(result, Err(CoreError::RangeError(_)));
+        assert!(range_error(invoke(n(1f64), "toFixed", [n(101f64)])));
+        assert!(range_error(invoke(n(1f64), "toExponential", [n(-1f64)])));
+        assert!(range_error(invoke(n(1f64), "toPrecision", [n(0f64)])));
+        assert!(range_error(invoke(n(1f64), "toString", [n(37f64)])));
+        assert!(range_error(invoke(n(1f64), "toString", [n(f64::NAN)])));
+    }
+}
@@ -0,0 +1,804 @@
+use std::rc::Rc;
+
+use super::{p262_argument, p262_define_method, p262_link_constructor};
+use crate::agent::e262_current_realm;
+use crate::core::cast::{e262_to_object, e262_to_property_key};
+use crate::core::function::e262_call;
+use crate::core::numbers::MAX_SAFE_INTEGER;
+use crate::core::test::{
+    e262_is_array, e262_is_callable, e262_require_object_coercible, e262_same_value,
+};
+use crate::core::{
+    e262_create_array_from_list, e262_create_data_property_or_throw, e262_define_property_or_throw,
+    e262_enumerable_own_properties, e262_from_property_descriptor, e262_get, e262_get_iterator,
+    e262_has_own_property, e262_invoke, e262_iterator_close, e262_iterator_step_value,
+    e262_ordinary_create_from_constructor, e262_ordinary_object_create, e262_set,
+    e262_set_integrity_level, e262_test_integrity_level, e262_to_property_descriptor,
+    p262_create_builtin_constructor, p262_has_slot, IntegrityLevel, ObjectRep, PropertyKey,
+    PropertyKind, StringRep, SymbolRep, Value, WellKnownSymbol,
+};
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::{Intrinsic, Realm};
+
+/// Creates `%Object%` and the methods of `%Object.prototype%`.
+pub(crate) fn p262_create_intrinsics(realm: &Realm) {
+    let prototype = realm.intrinsic(Intrinsic::ObjectPrototype);
+    let constructor = p262_create_builtin_constructor(
+        Rc::new(|_, arguments| Ok(Value::Object(object(arguments)?))),
+        Rc::new(construct),
+        1,
+        "Object",
+        Some(realm.intrinsic(Intrinsic::FunctionPrototype)),
+    );
+    p262_link_constructor(&constructor, &prototype);
+
+    p262_define_method(realm, &constructor, "assign", 2, assign);
+    p262_define_method(realm, &constructor, "create", 2, create);
+    p262_define_method(
+        realm,
+        &constructor,
+        "defineProperties",
+        2,
+        define_properties,
+    );
+    p262_define_method(realm, &constructor, "defineProperty", 3, define_property);
+    p262_define_method(realm, &constructor, "entries", 1, entries);
+    p262_define_method(realm, &constructor, "freeze", 1, freeze);
+    p262_define_method(realm, &constructor, "fromEntries", 1, from_entries);
+    p262_define_method(
+        realm,
+        &constructor,
+        "getOwnPropertyDescriptor",
+        2,
+        get_own_property_descriptor,
+    );
+    p262_define_method(
+        realm,
+        &constructor,
+        "getOwnPropertyDescriptors",
+        1,
+        get_own_property_descriptors,
+    );
+    p262_define_method(realm, &constructor, "getOwnPropertyNames", 1, |_, args| {
+        get_own_property_keys(&p262_argument(args, 0), KeyType::String)
+    });
+    p262_define_method(
+        realm,
+        &constructor,
+        "getOwnPropertySymbols",
+        1,
+        |_, args| get_own_property_keys(&p262_argument(args, 0), KeyType::Symbol),
+    );
+    p262_define_method(realm, &constructor, "getPrototypeOf", 1, get_prototype_of);
+    p262_define_method(realm, &constructor, "groupBy", 2, group_by);
+    p262_define_method(realm, &constructor, "hasOwn", 2, has_own);
+    p262_define_method(realm, &constructor, "is", 2, is);
+    p262_define_method(realm, &constructor, "isExtensible", 1, is_extensible);
+    p262_define_method(realm, &constructor, "isFrozen", 1, |_, args| {
+        test_integrity_level(&p262_argument(args, 0), IntegrityLevel::Frozen)
+    });
+    p262_define_method(realm, &constructor, "isSealed", 1, |_, args| {
+        test_integrity_level(&p262_argument(args, 0), IntegrityLevel::Sealed)
+    });
+    p262_define_method(realm, &constructor, "keys", 1, |_, args| {
+        enumerable_own_properties(&p262_argument(args, 0), PropertyKind::Key)
+    });
+    p262_define_method(
+        realm,
+        &constructor,
+        "preventExtensions",
+        1,
+        prevent_extensions,
+    );
+    p262_define_method(realm, &constructor, "seal", 1, |_, args| {
+        set_integrity_level(&p262_argument(args, 0), IntegrityLevel::Sealed)
+    });
+    p262_define_method(realm, &constructor, "setPrototypeOf", 2, set_prototype_of);
+    p262_define_method(realm, &constructor, "values", 1, |_, args| {
+        enumerable_own_properties(&p262_argument(args, 0), PropertyKind::Value)
+    });
+
+    p262_define_method(realm, &prototype, "hasOwnProperty", 1, has_own_property);
+    p262_define_method(realm, &prototype, "isPrototypeOf", 1, is_prototype_of);
+    p262_define_method(
+        realm,
+        &prototype,
+        "propertyIsEnumerable",
+        1,
+        property_is_enumerable,
+    );
+    p262_define_method(realm, &prototype, "toLocaleString", 0, to_locale_string);
+    let to_string = p262_define_method(realm, &prototype, "toString", 0, to_string);
+    realm.set_intrinsic(Intrinsic::ObjectPrototypeToString, to_string);
+    p262_define_method(realm, &prototype, "valueOf", 0, value_of);
+
+    realm.set_intrinsic(Intrinsic::Object, constructor);
+}
+
+fn object_prototype() -> ObjectRep {
+    e262_current_realm().intrinsic(Intrinsic::ObjectPrototype)
+}
+
+/// Implements [`Object`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object-value) when `NewTarget` is undefined or `Object` itself.
+fn object(arguments: &[Value]) -> CoreResult<ObjectRep> {
+    match p262_argument(arguments, 0) {
+        Value::Undefined | Value::Null => Ok(e262_ordinary_object_create(Some(object_prototype()))),
+        value => e262_to_object(&value),
+    }
+}
+
+/// Implements [`Object`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object-value) when called as a constructor, e.g. by a derived class.
+fn construct(arguments: &[Value], new_target: &ObjectRep) -> CoreResult<ObjectRep> {
+    if *new_target != e262_current_realm().intrinsic(Intrinsic::Object) {
+        return e262_ordinary_create_from_constructor(new_target, Intrinsic::ObjectPrototype);
+    }
+    object(arguments)
+}
+
+/// Implements [`Object.assign`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.assign).
+fn assign(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let to = e262_to_object(&p262_argument(arguments, 0))?;
+    for source in arguments.iter().skip(1) {
+        if matches!(source, Value::Undefined | Value::Null) {
+            continue;
+        }
+        let from = e262_to_object(source)?;
+        for key in from.0.clone().own_property_keys()? {
+            let desc = from.0.clone().get_own_property(&key)?;
+            if desc.is_some_and(|desc| desc.is_enumerable()) {
+                let value = e262_get(&from, &key)?;
+                e262_set(&to, &key, value, true)?;
+            }
+        }
+    }
+    Ok(Value::Object(to))
+}
+
+/// Implements [`Object.create`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.create).
+fn create(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let prototype = match p262_argument(arguments, 0) {
+        Value::Object(prototype) => Some(prototype),
+        Value::Null => None,
+        _ => {
+            return Err(CoreError::TypeError(
+                "Object prototype may only be an Object or null".to_string(),
+            ))
+        }
+    };
+    let obj = e262_ordinary_object_create(prototype);
+    match p262_argument(arguments, 1) {
+        Value::Undefined => Ok(Value::Object(obj)),
+        properties => object_define_properties(obj, &properties),
+    }
+}
+
+/// Implements [`Object.defineProperties`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.defineproperties).
+fn define_properties(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    match p262_argument(arguments, 0) {
+        Value::Object(obj) => object_define_properties(obj, &p262_argument(arguments, 1)),
+        _ => Err(CoreError::TypeError(
+            "Object.defineProperties called on non-object".to_string(),
+        )),
+    }
+}
+
+/// Implements [ObjectDefineProperties](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-objectdefineproperties).
+fn object_define_properties(obj: ObjectRep, properties: &Value) -> CoreResult<Value> {
+    let props = e262_to_object(properties)?;
+    let mut descriptors = Vec::new();
+    for key in props.0.clone().own_property_keys()? {
+        let prop_desc = props.0.clone().get_own_property(&key)?;
+        if prop_desc.is_some_and(|desc| desc.is_enumerable()) {
+            let desc_obj = e262_get(&props, &key)?;
+            descriptors.push((key, e262_to_property_descriptor(&desc_obj)?));
+        }
+    }
+    for (key, desc) in descriptors {
+        e262_define_property_or_throw(&obj, key, desc)?;
+    }
+    Ok(Value::Object(obj))
+}
+
+/// Implements [`Object.defineProperty`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.defineproperty).
+fn define_property(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let Value::Object(obj) = p262_argument(arguments, 0) else {
+        return Err(CoreError::TypeError(
+            "Object.defineProperty called on non-object".to_string(),
+        ));
+    };
+    let key = e262_to_property_key(&p262_argument(arguments, 1))?;
+    let desc = e262_to_property_descriptor(&p262_argument(arguments, 2))?;
+    e262_define_property_or_throw(&obj, key, desc)?;
+    Ok(Value::Object(obj))
+}
+
+/// Implements [`Object.entries`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.entries).
+fn entries(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    enumerable_own_properties(&p262_argument(arguments, 0), PropertyKind::KeyValue)
+}
+
+/// Implements `Object.entries`, `Object.keys` and `Object.values`.
+fn enumerable_own_properties(value: &Value, kind: PropertyKind) -> CoreResult<Value> {
+    let obj = e262_to_object(value)?;
+    let properties = e262_enumerable_own_properties(&obj, kind)?;
+    Ok(Value::Object(e262_create_array_from_list(&properties)))
+}
+
+/// Implements [`Object.freeze`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.freeze).
+fn freeze(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    set_integrity_level(&p262_argument(arguments, 0), IntegrityLevel::Frozen)
+}
+
+/// Implements `Object.freeze` and `Object.seal`.
+fn set_integrity_level(value: &Value, level: IntegrityLevel) -> CoreResult<Value> {
+    if let Value::Object(obj) = value {
+        if !e262_set_integrity_level(obj, level)? {
+            return Err(CoreError::TypeError(
+                "Cannot change the integrity level of the object".to_string(),
+            ));
+        }
+    }
+    Ok(value.clone())
+}
+
+/// Implements [`Object.fromEntries`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.fromentries).
+fn from_entries(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let iterable = e262_require_object_coercible(p262_argument(arguments, 0))?;
+    let obj = e262_ordinary_object_create(Some(object_prototype()));
+    let mut record = e262_get_iterator(&iterable)?;
+    while let Some(next) = e262_iterator_step_value(&mut record)? {
+        let result = add_entry(&obj, &next);
+        if result.is_err() {
+            return e262_iterator_close(&record, result).map(|_| Value::Undefined);
+        }
+    }
+    Ok(Value::Object(obj))
+}
+
+/// Implements the steps of [AddEntriesFromIterable](https://tc39.es/ecma262/multipage/keyed-collections.html#sec-add-entries-from-iterable) for a single entry, with `Object.fromEntries` as adder.
+fn add_entry(obj: &ObjectRep, entry: &Value) -> CoreResult<()> {
+    let Value::Object(entry) = entry else {
+        return Err(CoreError::TypeError(
+            "Iterator value is not an entry object".to_string(),
+        ));
+    };
+    let key = e262_get(entry, &PropertyKey::from(0))?;
+    let value = e262_get(entry, &PropertyKey::from(1))?;
+    let key = e262_to_property_key(&key)?;
+    e262_create_data_property_or_throw(obj, key, value)
+}
+
+/// Implements [`Object.getOwnPropertyDescriptor`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.getownpropertydescriptor).
+fn get_own_property_descriptor(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let obj = e262_to_object(&p262_argument(arguments, 0))?;
+    let key = e262_to_property_key(&p262_argument(arguments, 1))?;
+    let desc = obj.0.get_own_property(&key)?;
+    Ok(e262_from_property_descriptor(desc))
+}
+
+/// Implements [`Object.getOwnPropertyDescriptors`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.getownpropertydescriptors).
+fn get_own_property_descriptors(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let obj = e262_to_object(&p262_argument(arguments, 0))?;
+    let descriptors = e262_ordinary_object_create(Some(object_prototype()));
+    for key in obj.0.clone().own_property_keys()? {
+        let desc = obj.0.clone().get_own_property(&key)?;
+        let descriptor = e262_from_property_descriptor(desc);
+        if descriptor != Value::Undefined {
+            e262_create_data_property_or_throw(&descriptors, key, descriptor)?;
+        }
+    }
+    Ok(Value::Object(descriptors))
+}
+
+/// The type of keys returned by [GetOwnPropertyKeys](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-getownpropertykeys).
+#[derive(Clone, Copy)]
+enum KeyType {
+    String,
+    Symbol,
+}
+
+/// Implements [GetOwnPropertyKeys](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-getownpropertykeys).
+fn get_own_property_keys(value: &Value, key_type: KeyType) -> CoreResult<Value> {
+    let obj = e262_to_object(value)?;
+    let keys: Vec<Value> = obj
+        .0
+        .own_property_keys()?
+        .into_iter()
+        .filter(|key| matches!(key, PropertyKey::Symbol(_)) == matches!(key_type, KeyType::Symbol))
+        .map(|key| key.to_value())
+        .collect();
+    Ok(Value::Object(e262_create_array_from_list(&keys)))
+}
+
+/// Implements [`Object.getPrototypeOf`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.getprototypeof).
+fn get_prototype_of(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let obj = e262_to_object(&p262_argument(arguments, 0))?;
+    Ok(obj.0.get_prototype_of()?.map_or(Value::Null, Value::Object))
+}
+
+/// Implements [`Object.groupBy`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.groupby).
+fn group_by(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let items = e262_require_object_coercible(p262_argument(arguments, 0))?;
+    let callback = p262_argument(arguments, 1);
+    if !e262_is_callable(&callback) {
+        return Err(CoreError::TypeError(
+            "Object.groupBy callback is not a function".to_string(),
+        ));
+    }
+    // Keys are compared linearly, as groups are usually few.
+    let mut groups: Vec<(PropertyKey, Vec<Value>)> = Vec::new();
+    let mut record = e262_get_iterator(&items)?;
+    let mut k = 0f64;
+    loop {
+        if k >= MAX_SAFE_INTEGER {
+            let err = CoreError::TypeError("Object.groupBy has too many elements".to_string());
+            return e262_iterator_close(&record, Err(err));
+        }
+        let Some(value) = e262_iterator_step_value(&mut record)? else {
+            break;
+        };
+        let key = e262_call(
+            &callback,
+            &Value::Undefined,
+            &[value.clone(), Value::Number(k)],
+        )
+        .and_then(|key| e262_to_property_key(&key));
+        let key = match key {
+            Ok(key) => key,
+            Err(err) => return e262_iterator_close(&record, Err(err)),
+        };
+        match groups.iter_mut().find(|(group_key, _)| *group_key == key) {
+            Some((_, elements)) => elements.push(value),
+            None => groups.push((key, vec![value])),
+        }
+        k += 1f64;
+    }
+    let obj = e262_ordinary_object_create(None);
+    for (key, elements) in groups {
+        let elements = e262_create_array_from_list(&elements);
+        e262_create_data_property_or_throw(&obj, key, Value::Object(elements))?;
+    }
+    Ok(Value::Object(obj))
+}
+
+/// Implements [`Object.hasOwn`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.hasown).
+fn has_own(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let obj = e262_to_object(&p262_argument(arguments, 0))?;
+    let key = e262_to_property_key(&p262_argument(arguments, 1))?;
+    Ok(Value::Boolean(e262_has_own_property(&obj, &key)?))
+}
+
+/// Implements [`Object.is`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.is).
+fn is(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    Ok(Value::Boolean(e262_same_value(
+        &p262_argument(arguments, 0),
+        &p262_argument(arguments, 1),
+    )))
+}
+
+/// Implements [`Object.isExtensible`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.isextensible).
+fn is_extensible(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    match p262_argument(arguments, 0) {
+        Value::Object(obj) => Ok(Value::Boolean(obj.0.is_extensible()?)),
+        _ => Ok(Value::Boolean(false)),
+    }
+}
+
+/// Implements `Object.isFrozen` and `Object.isSealed`.
+fn test_integrity_level(value: &Value, level: IntegrityLevel) -> CoreResult<Value> {
+    match value {
+        Value::Object(obj) => Ok(Value::Boolean(e262_test_integrity_level(obj, level)?)),
+        _ => Ok(Value::Boolean(true)),
+    }
+}
+
+/// Implements [`Object.preventExtensions`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.preventextensions).
+fn prevent_extensions(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let value = p262_argument(arguments, 0);
+    if let Value::Object(obj) = &value {
+        if !obj.0.clone().prevent_extensions()? {
+            return Err(CoreError::TypeError(
+                "Cannot prevent extensions of the object".to_string(),
+            ));
+        }
+    }
+    Ok(value)
+}
+
+/// Implements [`Object.setPrototypeOf`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.setprototypeof).
+fn set_prototype_of(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let value = e262_require_object_coercible(p262_argument(arguments, 0))?;
+    let prototype = match p262_argument(arguments, 1) {
+        Value::Object(prototype) => Some(prototype),
+        Value::Null => None,
+        _ => {
+            return Err(CoreError::TypeError(
+                "Object prototype may only be an Object or null".to_string(),
+            ))
+        }
+    };
+    if let Value::Object(obj) = &value {
+        if !obj.0.clone().set_prototype_of(prototype) {
+            return Err(CoreError::TypeError(
+                "Cannot set the prototype of the object".to_string(),
+            ));
+        }
+    }
+    Ok(value)
+}
+
+/// Implements [`Object.prototype.hasOwnProperty`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.prototype.hasownproperty).
+fn has_own_property(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let key = e262_to_property_key(&p262_argument(arguments, 0))?;
+    let obj = e262_to_object(this)?;
+    Ok(Value::Boolean(e262_has_own_property(&obj, &key)?))
+}
+
+/// Implements [`Object.prototype.isPrototypeOf`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.prototype.isprototypeof).
+fn is_prototype_of(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let Value::Object(mut value) = p262_argument(arguments, 0) else {
+        return Ok(Value::Boolean(false));
+    };
+    let obj = e262_to_object(this)?;
+    loop {
+        match value.0.get_prototype_of()? {
+            None => return Ok(Value::Boolean(false)),
+            Some(prototype) if prototype == obj => return Ok(Value::Boolean(true)),
+            Some(prototype) => value = prototype,
+        }
+    }
+}
+
+/// Implements [`Object.prototype.propertyIsEnumerable`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.prototype.propertyisenumerable).
+fn property_is_enumerable(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let key = e262_to_property_key(&p262_argument(arguments, 0))?;
+    let obj = e262_to_object(this)?;
+    let desc = obj.0.get_own_property(&key)?;
+    Ok(Value::Boolean(
+        desc.is_some_and(|desc| desc.is_enumerable()),
+    ))
+}
+
+/// Implements [`Object.prototype.toLocaleString`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.prototype.tolocalestring).
+fn to_locale_string(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    e262_invoke(this, &PropertyKey::from("toString"), &[])
+}
+
+/// Implements [`Object.prototype.toString`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.prototype.tostring).
+fn to_string(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let obj = match this {
+        Value::Undefined => return Ok(Value::String("[object Undefined]".into())),
+        Value::Null => return Ok(Value::String("[object Null]".into())),
+        value => e262_to_object(value)?,
+    };
+    let has_slot = |slot: &str| p262_has_slot(obj.0.clone(), slot.to_string());
+    let builtin_tag = if e262_is_array(&Value::Object(obj.clone()))? {
+        "Array"
+    } else if has_slot("ParameterMap") {
+        "Arguments"
+    } else if e262_is_callable(&Value::Object(obj.clone())) {
+        "Function"
+    } else if has_slot("ErrorData") {
+        "Error"
+    } else if has_slot("BooleanData") {
+        "Boolean"
+    } else if has_slot("NumberData") {
+        "Number"
+    } else if has_slot("StringData") {
+        "String"
+    } else if has_slot("DateValue") {
+        "Date"
+    } else if has_slot("RegExpMatcher") {
+        "RegExp"
+    } else {
+        "Object"
+    };
+    let key = PropertyKey::from(SymbolRep::well_known(WellKnownSymbol::ToStringTag));
+    let tag = match e262_get(&obj, &key)? {
+        Value::String(tag) => tag,
+        _ => builtin_tag.into(),
+    };
+    let result = StringRep::from("[object ").concat(&tag).concat(&"]".into());
+    Ok(Value::String(result))
+}
+
+/// Implements [`Object.prototype.valueOf`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-object.prototype.valueof).
+fn value_of(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    Ok(Value::Object(e262_to_object(this)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::core::{
+        e262_create_array_from_list, e262_create_builtin_function, e262_create_iter_result_object,
+    };
+
+    fn call<const N: usize>(method: &str, arguments: [Value; N]) -> CoreResult<Value> {
+        let constructor = e262_current_realm().intrinsic(Intrinsic::Object);
+        e262_invoke(
+            &Value::Object(constructor),
+            &PropertyKey::from(method),
+            &arguments,
+        )
+    }
+
+    fn new_object() -> ObjectRep {
+        e262_ordinary_object_create(Some(object_prototype()))
+    }
+
+    fn get(value: &Value, key: impl Into<PropertyKey>) -> Value {
+        let Value::Object(obj) = value else {
+            panic!("{value:?} is not an object");
+        };
+        e262_get(obj, &key.into()).unwrap()
+    }
+
+    fn str(value: &str) -> Value {
+        Value::String(value.into())
+    }
+
+    /// Returns an iterable yielding `values`, counting its calls to `return`.
+    fn iterable(values: Vec<Value>, closed: Rc<Cell<usize>>) -> Value {
+        let obj = new_object();
+        let key = SymbolRep::well_known(WellKnownSymbol::Iterator);
+        p262_define_method(&e262_current_realm(), &obj, key, 0, move |_, _| {
+            let iterator = new_object();
+            let position = Cell::new(0);
+            let values = values.clone();
+            p262_define_method(&e262_current_realm(), &iterator, "next", 0, move |_, _| {
+                let index = position.get();
+                position.set(index + 1);
+                Ok(Value::Object(match values.get(index) {
+                    Some(value) => e262_create_iter_result_object(value.clone(), false),
+                    None => e262_create_iter_result_object(Value::Undefined, true),
+                }))
+            });
+            let closed = closed.clone();
+            p262_define_method(
+                &e262_current_realm(),
+                &iterator,
+                "return",
+                0,
+                move |_, _| {
+                    closed.set(closed.get() + 1);
+                    Ok(Value::Object(new_object()))
+                },
+            );
+            Ok(Value::Object(iterator))
+        });
+        Value::Object(obj)
+    }
+
+    #[test]
+    fn object_wraps_values() {
+        let constructor = Value::Object(e262_current_realm().intrinsic(Intrinsic::Object));
+        let wrapped = e262_call(&constructor, &Value::Undefined, &[str("ab")]).unwrap();
+        assert_eq!(get(&wrapped, "length"), Value::Number(2f64));
+
+        let obj = new_object();
+        assert_eq!(
+            e262_call(
+                &constructor,
+                &Value::Undefined,
+                &[Value::Object(obj.clone())]
+            )
+            .unwrap(),
+            Value::Object(obj)
+        );
+        assert_eq!(get(&constructor, "length"), Value::Number(1f64));
+        assert_eq!(
+            get(&get(&constructor, "prototype"), "constructor"),
+            constructor
+        );
+    }
+
+    #[test]
+    fn assign_copies_enumerable_properties() {
+        let source = new_object();
+        e262_create_data_property_or_throw(&source, "a".into(), Value::Number(1f64)).unwrap();
+        call(
+            "defineProperty",
+            [
+                Value::Object(source.clone()),
+                str("hidden"),
+                Value::Object(new_object()),
+            ],
+        )
+        .unwrap();
+        let target = Value::Object(new_object());
+
+        call(
+            "assign",
+            [target.clone(), Value::Null, Value::Object(source)],
+        )
+        .unwrap();
+        assert_eq!(get(&target, "a"), Value::Number(1f64));
+        assert_eq!(
+            call("hasOwn", [target, str("hidden")]).unwrap(),
+            Value::Boolean(false)
+        );
+    }
+
+    #[test]
+    fn define_property_keeps_absent_attributes() {
+        let obj = Value::Object(new_object());
+        let attributes = new_object();
+        for (key, value) in [
+            ("value", Value::Number(1f64)),
+            ("writable", Value::Boolean(true)),
+            ("enumerable", Value::Boolean(true)),
+        ] {
+            e262_create_data_property_or_throw(&attributes, key.into(), value).unwrap();
+        }
+        call(
+            "defineProperty",
+            [obj.clone(), str("x"), Value::Object(attributes)],
+        )
+        .unwrap();
+
+        let only_value = new_object();
+        e262_create_data_property_or_throw(&only_value, "value".into(), Value::Number(2f64))
+            .unwrap();
+        call(
+            "defineProperty",
+            [obj.clone(), str("x"), Value::Object(only_value)],
+        )
+        .unwrap();
+
+        let desc = call("getOwnPropertyDescriptor", [obj, str("x")]).unwrap();
+        assert_eq!(get(&desc, "value"), Value::Number(2f64));
+        assert_eq!(get(&desc, "writable"), Value::Boolean(true));
+        assert_eq!(get(&desc, "enumerable"), Value::Boolean(true));
+        assert_eq!(get(&desc, "configurable"), Value::Boolean(false));
+    }
+
+    #[test]
+    fn freeze_and_seal_work() {
+        let obj = new_object();
+        e262_create_data_property_or_throw(&obj, "a".into(), Value::Number(1f64)).unwrap();
+        let value = Value::Object(obj.clone());
+
+        assert_eq!(
+            call("isSealed", [Value::Object(obj.clone())]).unwrap(),
+            Value::Boolean(false)
+        );
+        call("seal", [Value::Object(obj.clone())]).unwrap();
+        assert_eq!(
+            call("isSealed", [Value::Object(obj.clone())]).unwrap(),
+            Value::Boolean(true)
+        );
+        assert_eq!(
+            call("isFrozen", [Value::Object(obj.clone())]).unwrap(),
+            Value::Boolean(false)
+        );
+        e262_set(&obj, &"a".into(), Value::Number(2f64), true).unwrap();
+
+        call("freeze", [Value::Object(obj.clone())]).unwrap();
+        assert_eq!(
+            call("isFrozen", [Value::Object(obj.clone())]).unwrap(),
+            Value::Boolean(true)
+        );
+        assert!(e262_set(&obj, &"a".into(), Value::Number(3f64), true).is_err());
+        assert_eq!(get(&value, "a"), Value::Number(2f64));
+        assert_eq!(
+            call("isFrozen", [Value::Number(1f64)]).unwrap(),
+            Value::Boolean(true)
+        );
+    }
+
+    #[test]
+    fn keys_values_and_entries_are_ordered() {
+        let obj = new_object();
+        for (key, value) in [("b", 1f64), ("1", 2f64), ("a", 3f64), ("0", 4f64)] {
+            e262_create_data_property_or_throw(&obj, key.into(), Value::Number(value)).unwrap();
+        }
+        let obj = Value::Object(obj);
+
+        let keys = call("keys", [obj.clone()]).unwrap();
+        let keys: Vec<Value> = (0..4).map(|i| get(&keys, i)).collect();
+        assert_eq!(keys, vec![str("0"), str("1"), str("b"), str("a")]);
+        let values = call("values", [obj.clone()]).unwrap();
+        assert_eq!(get(&values, 0), Value::Number(4f64));
+        let entries = call("entries", [obj]).unwrap();
+        assert_eq!(get(&get(&entries, 2), 0), str("b"));
+        assert_eq!(get(&get(&entries, 2), 1), Value::Number(1f64));
+        assert_eq!(get(&entries, "length"), Value::Number(4f64));
+    }
+
+    #[test]
+    fn from_entries_and_group_by_iterate() {
+        let entries = vec![
+            Value::Object(e262_create_array_from_list(&[
+                str("a"),
+                Value::Number(1f64),
+            ])),
+            Value::Object(e262_create_array_from_list(&[
+                str("b"),
+                Value::Number(2f64),
+            ])),
+        ];
+        let obj = call("fromEntries", [iterable(entries, Rc::default())]).unwrap();
+        assert_eq!(get(&obj, "b"), Value::Number(2f64));
+
+        let closed = Rc::new(Cell::new(0));
+        let invalid = iterable(vec![Value::Number(1f64)], closed.clone());
+        assert!(call("fromEntries", [invalid]).is_err());
+        assert_eq!(closed.get(), 1);
+
+        let numbers = (1..=5).map(|n| Value::Number(n as f64)).collect();
+        let parity = e262_create_builtin_function(
+            Rc::new(|_, args| match p262_argument(args, 0) {
+                Value::Number(n) if n % 2f64 == 0f64 => Ok(str("even")),
+                _ => Ok(str("odd")),
+            }),
+            1,
+            "",
+            None,
+        );
+        let groups = call(
+            "groupBy",
+            [iterable(numbers, Rc::default()), Value::Object(parity)],
+        )
+        .unwrap();
+        assert_eq!(get(&get(&groups, "odd"), "length"), Value::Number(3f64));
+        assert_eq!(get(&get(&groups, "even"), 1), Value::Number(4f64));
+        let Value::Object(groups) = groups else {
+            unreachable!()
+        };
+        assert_eq!(groups.0.get_prototype_of().unwrap(), None);
+    }
+
+    #[test]
+    fn prototypes_can_be_changed() {
+        let a = Value::Object(new_object());
+        let b = call("create", [a.clone()]).unwrap();
+        assert_eq!(call("getPrototypeOf", [b.clone()]).unwrap(), a);
+        assert!(call("setPrototypeOf", [a.clone(), b.clone()]).is_err());
+
+        call("setPrototypeOf", [b.clone(), Value::Null]).unwrap();
+        assert_eq!(call("getPrototypeOf", [b]).unwrap(), Value::Null);
+
+        let is_prototype_of = get(&Value::Object(object_prototype()), "isPrototypeOf");
+        assert_eq!(
+            e262_call(&is_prototype_of, &Value::Object(object_prototype()), &[a]).unwrap(),
+            Value::Boolean(true)
+        );
+    }
+
+    #[test]
+    fn is_uses_same_value() {
+        let nan = Value::Number(f64::NAN);
+        assert_eq!(
+            call("is", [nan.clone(), nan]).unwrap(),
+            Value::Boolean(true)
+        );
+        let (zero, minus_zero) = (Value::Number(0f64), Value::Number(-0f64));
+        assert_eq!(
+            call("is", [zero, minus_zero]).unwrap(),
+            Value::Boolean(false)
+        );
+    }
+
+    #[test]
+    fn to_string_uses_tags() {
+        let to_string = get(&Value::Object(object_prototype()), "toString");
+        let tag = |this: Value| e262_call(&to_string, &this, &[]).unwrap();
+
+        assert_eq!(tag(Value::Undefined), str("[object Undefined]"));
+        assert_eq!(tag(Value::Null), str("[object Null]"));
+        assert_eq!(tag(Value::Number(1f64)), str("[object Number]"));
+        assert_eq!(
+            tag(Value::Object(e262_create_array_from_list(&[]))),
+            str("[object Array]")
+        );
+        assert_eq!(tag(to_string.clone()), str("[object Function]"));
+
+        let obj = new_object();
+        let key = SymbolRep::well_known(WellKnownSymbol::ToStringTag);
+        e262_create_data_property_or_throw(&obj, key.into(), str("Custom")).unwrap();
+        assert_eq!(tag(Value::Object(obj)), str("[object Custom]"));
+    }
+}
@@ -0,0 +1,1170 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use super::{p262_argument, p262_define_getter, p262_define_method, p262_link_constructor};
+use crate::agent::e262_current_realm;
+use crate::builtins::string::p262_get_substitution;
+use crate::core::cast::{
+    e262_to_boolean, e262_to_integer_or_infinity, e262_to_length, e262_to_object, e262_to_string,
+    e262_to_uint32,
+};
+use crate::core::function::{e262_call, e262_construct};
+use crate::core::regexp::{RegExpFlags, RegExpMatcher};
+use crate::core::test::{e262_is_callable, e262_is_reg_exp, e262_same_value};
+use crate::core::{
+    e262_array_create, e262_create_array_from_list, e262_create_data_property_or_throw,
+    e262_create_iter_result_object, e262_define_property_or_throw, e262_get,
+    e262_length_of_array_like, e262_ordinary_create_from_constructor, e262_ordinary_object_create,
+    e262_set, e262_species_constructor, p262_create_builtin_self_constructor_with_call,
+    p262_get_slot, Descriptor, ObjectRep, Property, PropertyKey, StringRep, SymbolRep, Value,
+    WellKnownSymbol,
+};
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::{Intrinsic, Realm};
+
+/// The flags of regular expressions with the getter reporting each, in the order `flags` lists them.
+const FLAG_GETTERS: [(char, &str); 8] = [
+    ('d', "hasIndices"),
+    ('g', "global"),
+    ('i', "ignoreCase"),
+    ('m', "multiline"),
+    ('s', "dotAll"),
+    ('u', "unicode"),
+    ('v', "unicodeSets"),
+    ('y', "sticky"),
+];
+
+/// Creates `%RegExp%`, the methods of `%RegExp.prototype%` and `%RegExpStringIteratorPrototype%`.
+pub(crate) fn p262_create_intrinsics(realm: &Realm) {
+    let prototype = e262_ordinary_object_create(Some(realm.intrinsic(Intrinsic::ObjectPrototype)));
+    realm.set_intrinsic(Intrinsic::RegExpPrototype, prototype.clone());
+    let constructor = p262_create_builtin_self_constructor_with_call(
+        Rc::new(|arguments, new_target| reg_exp(arguments, new_target, true)),
+        Rc::new(|arguments, new_target| reg_exp(arguments, new_target, false)),
+        2,
+        "RegExp",
+        Some(realm.intrinsic(Intrinsic::FunctionPrototype)),
+    );
+    p262_link_constructor(&constructor, &prototype);
+    realm.set_intrinsic(Intrinsic::RegExp, constructor.clone());
+    let species = SymbolRep::well_known(WellKnownSymbol::Species);
+    // Implements [`get RegExp[@@species]`](https://tc39.es/ecma262/multipage/text-processing.html#sec-get-regexp-@@species).
+    p262_define_getter(realm, &constructor, species, |this, _| Ok(this.clone()));
+
+    let exec = p262_define_method(realm, &prototype, "exec", 1, |this, arguments| {
+        let r = this_reg_exp(this)?;
+        let s = e262_to_string(&p262_argument(arguments, 0))?;
+        reg_exp_builtin_exec(&r, &s)
+    });
+    realm.set_intrinsic(Intrinsic::RegExpPrototypeExec, exec);
+    p262_define_getter(realm, &prototype, "flags", flags);
+    for (flag, name) in FLAG_GETTERS {
+        p262_define_getter(realm, &prototype, name, move |this, _| {
+            reg_exp_has_flag(this, flag)
+        });
+    }
+    p262_define_getter(realm, &prototype, "source", source);
+    p262_define_method(realm, &prototype, "test", 1, |this, arguments| {
+        let r = this_object(this)?;
+        let s = e262_to_string(&p262_argument(arguments, 0))?;
+        Ok(Value::Boolean(reg_exp_exec(&r, &s)? != Value::Null))
+    });
+    p262_define_method(realm, &prototype, "toString", 0, to_string);
+    let symbol = |symbol| SymbolRep::well_known(symbol);
+    p262_define_method(
+        realm,
+        &prototype,
+        symbol(WellKnownSymbol::Match),
+        1,
+        r#match,
+    );
+    p262_define_method(
+        realm,
+        &prototype,
+        symbol(WellKnownSymbol::MatchAll),
+        1,
+        match_all,
+    );
+    p262_define_method(
+        realm,
+        &prototype,
+        symbol(WellKnownSymbol::Replace),
+        2,
+        replace,
+    );
+    p262_define_method(
+        realm,
+        &prototype,
+        symbol(WellKnownSymbol::Search),
+        1,
+        search,
+    );
+    p262_define_method(realm, &prototype, symbol(WellKnownSymbol::Split), 2, split);
+
+    #[cfg(feature = "annex-b")]
+    p262_define_method(realm, &prototype, "compile", 2, annex_b::compile);
+
+    let iterator_prototype =
+        e262_ordinary_object_create(Some(realm.intrinsic(Intrinsic::IteratorPrototype)));
+    p262_define_method(
+        realm,
+        &iterator_prototype,
+        "next",
+        0,
+        reg_exp_string_iterator_next,
+    );
+    let to_string_tag = SymbolRep::well_known(WellKnownSymbol::ToStringTag);
+    e262_define_property_or_throw(
+        &iterator_prototype,
+        PropertyKey::from(to_string_tag),
+        Descriptor {
+            value: Some(Rc::new(Value::String("RegExp String Iterator".into()))),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Built-in properties can always be defined");
+    realm.set_intrinsic(Intrinsic::RegExpStringIteratorPrototype, iterator_prototype);
+}
+
+/// Implements [`RegExp`](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexp-pattern-flags), where `called` tells if NewTarget is undefined.
+fn reg_exp(arguments: &[Value], new_target: &ObjectRep, called: bool) -> CoreResult<ObjectRep> {
+    let pattern = p262_argument(arguments, 0);
+    let flags = p262_argument(arguments, 1);
+    let pattern_is_reg_exp = e262_is_reg_exp(&pattern)?;
+    if let (true, true, Value::Object(pattern), Value::Undefined) =
+        (called, pattern_is_reg_exp, &pattern, &flags)
+    {
+        let pattern_constructor = e262_get(pattern, &PropertyKey::from("constructor"))?;
+        if e262_same_value(&Value::Object(new_target.clone()), &pattern_constructor) {
+            return Ok(pattern.clone());
+        }
+    }
+    let (p, f) = match &pattern {
+        Value::Object(obj) if this_reg_exp(&pattern).is_ok() => {
+            let source = original_string(obj, "OriginalSource");
+            let f = match flags {
+                Value::Undefined => Value::String(original_string(obj, "OriginalFlags")),
+                flags => flags,
+            };
+            (Value::String(source), f)
+        }
+        Value::Object(obj) if pattern_is_reg_exp => {
+            let source = e262_get(obj, &PropertyKey::from("source"))?;
+            let f = match flags {
+                Value::Undefined => e262_get(obj, &PropertyKey::from("flags"))?,
+                flags => flags,
+            };
+            (source, f)
+        }
+        _ => (pattern, flags),
+    };
+    let o = reg_exp_alloc(new_target)?;
+    reg_exp_initialize(&o, &p, &f)
+}
+
+/// Implements [RegExpAlloc](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexpalloc).
+fn reg_exp_alloc(new_target: &ObjectRep) -> CoreResult<ObjectRep> {
+    let obj = e262_ordinary_create_from_constructor(new_target, Intrinsic::RegExpPrototype)?;
+    obj.0
+        .clone()
+        .set_slot("RegExpMatcher".to_string(), Rc::new(None::<RegExpMatcher>));
+    e262_define_property_or_throw(
+        &obj,
+        PropertyKey::from("lastIndex"),
+        Descriptor {
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(false),
+            ..Default::default()
+        },
+    )?;
+    Ok(obj)
+}
+
+/// Implements [RegExpInitialize](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexpinitialize).
+fn reg_exp_initialize(obj: &ObjectRep, pattern: &Value, flags: &Value) -> CoreResult<ObjectRep> {
+    let p = match pattern {
+        Value::Undefined => StringRep::empty(),
+        pattern => e262_to_string(pattern)?,
+    };
+    let f = match flags {
+        Value::Undefined => StringRep::empty(),
+        flags => e262_to_string(flags)?,
+    };
+    let parsed_flags = RegExpFlags::parse(&f.to_utf16()).ok_or_else(|| {
+        CoreError::SyntaxError(format!(
+            "Invalid regular expression flags '{}'",
+            f.to_std_string_lossy()
+        ))
+    })?;
+    let matcher = RegExpMatcher::new(&p.to_utf16(), parsed_flags)?;
+    let slots = obj.0.clone();
+    slots
+        .clone()
+        .set_slot("OriginalSource".to_string(), Rc::new(p));
+    slots
+        .clone()
+        .set_slot("OriginalFlags".to_string(), Rc::new(f));
+    slots.set_slot("RegExpMatcher".to_string(), Rc::new(Some(matcher)));
+    e262_set(
+        obj,
+        &PropertyKey::from("lastIndex"),
+        Value::Number(0f64),
+        true,
+    )?;
+    Ok(obj.clone())
+}
+
+/// Implements [RegExpCreate](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexpcreate).
+pub(crate) fn p262_reg_exp_create(pattern: &Value, flags: &str) -> CoreResult<Value> {
+    let obj = reg_exp_alloc(&e262_current_realm().intrinsic(Intrinsic::RegExp))?;
+    reg_exp_initialize(&obj, pattern, &Value::String(flags.into())).map(Value::Object)
+}
+
+/// Returns the [[OriginalSource]] or [[OriginalFlags]] of an initialized regular expression.
+fn original_string(obj: &ObjectRep, slot: &str) -> StringRep {
+    p262_get_slot::<StringRep>(obj.0.clone(), slot.to_string())
+        .map(|s| (*s).clone())
+        .expect("Initialized regular expressions have their original source and flags")
+}
+
+/// Returns the [[RegExpMatcher]] of `value`, throwing a TypeError if it is not an initialized regular expression.
+fn reg_exp_matcher(value: &Value) -> CoreResult<(ObjectRep, Rc<Option<RegExpMatcher>>)> {
+    if let Value::Object(obj) = value {
+        if let Some(matcher) =
+            p262_get_slot::<Option<RegExpMatcher>>(obj.0.clone(), "RegExpMatcher".to_string())
+        {
+            if matcher.is_some() {
+                return Ok((obj.clone(), matcher));
+            }
+        }
+    }
+    Err(CoreError::TypeError("Receiver is not a RegExp".to_string()))
+}
+
+/// Requires the [[RegExpMatcher]] internal slot of `value`.
+fn this_reg_exp(value: &Value) -> CoreResult<ObjectRep> {
+    reg_exp_matcher(value).map(|(obj, _)| obj)
+}
+
+fn this_object(value: &Value) -> CoreResult<ObjectRep> {
+    match value {
+        Value::Object(obj) => Ok(obj.clone()),
+        _ => Err(CoreError::TypeError(
+            "Receiver is not an object".to_string(),
+        )),
+    }
+}
+
+/// Implements [RegExpHasFlag](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexphasflag).
+fn reg_exp_has_flag(this: &Value, flag: char) -> CoreResult<Value> {
+    let r = this_object(this)?;
+    match p262_get_slot::<StringRep>(r.0.clone(), "OriginalFlags".to_string()) {
+        Some(flags) => Ok(Value::Boolean(
+            flags
+                .index_of(&flag.to_string().as_str().into(), 0)
+                .is_some(),
+        )),
+        None if is_reg_exp_prototype(&r) => Ok(Value::Undefined),
+        None => Err(CoreError::TypeError("Receiver is not a RegExp".to_string())),
+    }
+}
+
+fn is_reg_exp_prototype(obj: &ObjectRep) -> bool {
+    let prototype = e262_current_realm().intrinsic(Intrinsic::RegExpPrototype);
+    e262_same_value(&Value::Object(obj.clone()), &Value::Object(prototype))
+}
+
+/// Implements [`get RegExp.prototype.flags`](https://tc39.es/ecma262/multipage/text-processing.html#sec-get-regexp.prototype.flags).
+fn flags(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let r = this_object(this)?;
+    let mut result = String::new();
+    for (flag, name) in FLAG_GETTERS {
+        if e262_to_boolean(&e262_get(&r, &PropertyKey::from(name))?) {
+            result.push(flag);
+        }
+    }
+    Ok(Value::String(result.as_str().into()))
+}
+
+/// Implements [`get RegExp.prototype.source`](https://tc39.es/ecma262/multipage/text-processing.html#sec-get-regexp.prototype.source).
+fn source(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let r = this_object(this)?;
+    match p262_get_slot::<StringRep>(r.0.clone(), "OriginalSource".to_string()) {
+        Some(src) => Ok(Value::String(escape_reg_exp_pattern(&src))),
+        None if is_reg_exp_prototype(&r) => Ok(Value::String("(?:)".into())),
+        None => Err(CoreError::TypeError("Receiver is not a RegExp".to_string())),
+    }
+}
+
+/// Implements [EscapeRegExpPattern](https://tc39.es/ecma262/multipage/text-processing.html#sec-escaperegexppattern), escaping the slashes outside of classes and the line terminators.
+fn escape_reg_exp_pattern(src: &StringRep) -> StringRep {
+    if src.is_empty() {
+        return "(?:)".into();
+    }
+    let mut result = Vec::with_capacity(src.len());
+    let mut in_class = false;
+    let mut escaped = false;
+    for unit in src.code_units() {
+        let line_terminator = match unit {
+            0x0A => Some("n"),
+            0x0D => Some("r"),
+            0x2028 => Some("u2028"),
+            0x2029 => Some("u2029"),
+            _ => None,
+        };
+        if let Some(escape) = line_terminator {
+            if !escaped {
+                result.push(b'\\' as u16);
+            }
+            result.extend(escape.encode_utf16());
+            escaped = false;
+            continue;
+        }
+        if !escaped {
+            match unit {
+                0x2F if !in_class => result.push(b'\\' as u16),
+                0x5B => in_class = true,
+                0x5D => in_class = false,
+                _ => {}
+            }
+        }
+        result.push(unit);
+        escaped = !escaped && unit == b'\\' as u16;
+    }
+    StringRep::from_utf16(&result)
+}
+
+/// Implements [`RegExp.prototype.toString`](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexp.prototype.tostring).
+fn to_string(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let r = this_object(this)?;
+    let pattern = e262_to_string(&e262_get(&r, &PropertyKey::from("source"))?)?;
+    let flags = e262_to_string(&e262_get(&r, &PropertyKey::from("flags"))?)?;
+    let slash = StringRep::from("/");
+    Ok(Value::String(
+        slash.concat(&pattern).concat(&slash).concat(&flags),
+    ))
+}
+
+/// Implements [RegExpExec](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexpexec).
+fn reg_exp_exec(r: &ObjectRep, s: &StringRep) -> CoreResult<Value> {
+    let exec = e262_get(r, &PropertyKey::from("exec"))?;
+    if e262_is_callable(&exec) {
+        let result = e262_call(
+            &exec,
+            &Value::Object(r.clone()),
+            &[Value::String(s.clone())],
+        )?;
+        return match result {
+            Value::Object(_) | Value::Null => Ok(result),
+            _ => Err(CoreError::TypeError(
+                "RegExp exec method returned something other than an Object or null".to_string(),
+            )),
+        };
+    }
+    this_reg_exp(&Value::Object(r.clone()))?;
+    reg_exp_builtin_exec(r, s)
+}
+
+/// Implements [RegExpBuiltinExec](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexpbuiltinexec).
+fn reg_exp_builtin_exec(r: &ObjectRep, s: &StringRep) -> CoreResult<Value> {
+    let last_index_key = PropertyKey::from("lastIndex");
+    let length = s.len();
+    let mut last_index = e262_to_length(&e262_get(r, &last_index_key)?)?;
+    let (_, matcher) = reg_exp_matcher(&Value::Object(r.clone()))?;
+    let matcher = Option::as_ref(&matcher).expect("The matcher was checked");
+    let flags = matcher.flags();
+    if !flags.global && !flags.sticky {
+        last_index = 0f64;
+    }
+    let input = matcher.units(s);
+    let captures = if last_index > length as f64 {
+        None
+    } else {
+        matcher.find(&input, last_index as usize)?
+    };
+    let Some(captures) = captures else {
+        if flags.global || flags.sticky {
+            e262_set(r, &last_index_key, Value::Number(0f64), true)?;
+        }
+        return Ok(Value::Null);
+    };
+    let (start, e) = captures[0].expect("The whole match is always captured");
+    if flags.global || flags.sticky {
+        e262_set(r, &last_index_key, Value::Number(e as f64), true)?;
+    }
+    let a = e262_array_create((captures.len()) as f64, None)?;
+    let define = |obj: &ObjectRep, key: PropertyKey, value: Value| {
+        e262_create_data_property_or_throw(obj, key, value)
+            .expect("Properties can always be created on fresh objects")
+    };
+    define(&a, PropertyKey::from("index"), Value::Number(start as f64));
+    define(&a, PropertyKey::from("input"), Value::String(s.clone()));
+    define(
+        &a,
+        PropertyKey::from(0u32),
+        Value::String(s.substring(start, e)),
+    );
+    let has_groups = matcher.has_group_names();
+    let groups = has_groups.then(|| e262_ordinary_object_create(None));
+    let groups_value = |groups: &Option<ObjectRep>| match groups {
+        Some(groups) => Value::Object(groups.clone()),
+        None => Value::Undefined,
+    };
+    define(&a, PropertyKey::from("groups"), groups_value(&groups));
+    let mut group_names = Vec::new();
+    let mut matched_group_names: Vec<&Vec<u16>> = Vec::new();
+    for (i, (capture, name)) in captures[1..].iter().zip(matcher.group_names()).enumerate() {
+        let captured_value = match capture {
+            Some((start, end)) => Value::String(s.substring(*start, *end)),
+            None => Value::Undefined,
+        };
+        define(&a, PropertyKey::from(i as u32 + 1), captured_value.clone());
+        match (name, &groups) {
+            (Some(name), Some(groups)) if !matched_group_names.contains(&name) => {
+                if capture.is_some() {
+                    matched_group_names.push(name);
+                }
+                define(
+                    groups,
+                    PropertyKey::from(StringRep::from_utf16(name)),
+                    captured_value,
+                );
+                group_names.push(Some(name));
+            }
+            _ => group_names.push(None),
+        }
+    }
+    if flags.has_indices {
+        let indices = e262_array_create(captures.len() as f64, None)?;
+        let index_groups = has_groups.then(|| e262_ordinary_object_create(None));
+        define(
+            &indices,
+            PropertyKey::from("groups"),
+            groups_value(&index_groups),
+        );
+        for (i, capture) in captures.iter().enumerate() {
+            let pair = match capture {
+                Some((start, end)) => Value::Object(e262_create_array_from_list(&[
+                    Value::Number(*start as f64),
+                    Value::Number(*end as f64),
+                ])),
+                None => Value::Undefined,
+            };
+            define(&indices, PropertyKey::from(i as u32), pair.clone());
+            if let (Some(Some(name)), Some(index_groups)) =
+                (i.checked_sub(1).map(|i| group_names[i]), &index_groups)
+            {
+                define(
+                    index_groups,
+                    PropertyKey::from(StringRep::from_utf16(name)),
+                    pair,
+                );
+            }
+        }
+        define(&a, PropertyKey::from("indices"), Value::Object(indices));
+    }
+    Ok(Value::Object(a))
+}
+
+/// Implements [AdvanceStringIndex](https://tc39.es/ecma262/multipage/text-processing.html#sec-advancestringindex).
+fn advance_string_index(s: &StringRep, index: f64, unicode: bool) -> f64 {
+    if !unicode || index + 1f64 >= s.len() as f64 {
+        return index + 1f64;
+    }
+    let index = index as usize;
+    let is_pair = matches!(s.code_unit_at(index), Some(0xD800..=0xDBFF))
+        && matches!(s.code_unit_at(index + 1), Some(0xDC00..=0xDFFF));
+    (index + if is_pair { 2 } else { 1 }) as f64
+}
+
+/// Advances `lastIndex` past an empty match, as the global algorithms do to avoid matching forever at the same position.
+fn advance_after_empty_match(
+    rx: &ObjectRep,
+    s: &StringRep,
+    result: &Value,
+    full_unicode: bool,
+) -> CoreResult<()> {
+    let Value::Object(result) = result else {
+        unreachable!("RegExpExec returns an object when it does not return null");
+    };
+    let match_str = e262_to_string(&e262_get(result, &PropertyKey::from(0u32))?)?;
+    if match_str.is_empty() {
+        let key = PropertyKey::from("lastIndex");
+        let this_index = e262_to_length(&e262_get(rx, &key)?)?;
+        let next_index = advance_string_index(s, this_index, full_unicode);
+        e262_set(rx, &key, Value::Number(next_index), true)?;
+    }
+    Ok(())
+}
+
+/// Returns the `flags` of a regular expression, with whether they hold `g` and either `u` or `v`.
+fn get_flags(rx: &ObjectRep) -> CoreResult<(StringRep, bool, bool)> {
+    let flags = e262_to_string(&e262_get(rx, &PropertyKey::from("flags"))?)?;
+    let has = |flag: &str| flags.index_of(&flag.into(), 0).is_some();
+    let (global, full_unicode) = (has("g"), has("u") || has("v"));
+    Ok((flags, global, full_unicode))
+}
+
+/// Implements [`RegExp.prototype[@@match]`](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexp.prototype-@@match).
+fn r#match(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let rx = this_object(this)?;
+    let s = e262_to_string(&p262_argument(arguments, 0))?;
+    let (_, global, full_unicode) = get_flags(&rx)?;
+    if !global {
+        return reg_exp_exec(&rx, &s);
+    }
+    e262_set(
+        &rx,
+        &PropertyKey::from("lastIndex"),
+        Value::Number(0f64),
+        true,
+    )?;
+    let mut matches = Vec::new();
+    loop {
+        let result = reg_exp_exec(&rx, &s)?;
+        let Value::Object(obj) = &result else {
+            break;
+        };
+        let match_str = e262_to_string(&e262_get(obj, &PropertyKey::from(0u32))?)?;
+        matches.push(Value::String(match_str));
+        advance_after_empty_match(&rx, &s, &result, full_unicode)?;
+    }
+    Ok(match matches.is_empty() {
+        true => Value::Null,
+        false => Value::Object(e262_create_array_from_list(&matches)),
+    })
+}
+
+/// Implements [`RegExp.prototype[@@matchAll]`](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexp-prototype-matchall).
+fn match_all(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let r = this_object(this)?;
+    let s = e262_to_string(&p262_argument(arguments, 0))?;
+    let c = e262_species_constructor(&r, e262_current_realm().intrinsic(Intrinsic::RegExp))?;
+    let (flags, global, full_unicode) = get_flags(&r)?;
+    let matcher = e262_construct(&c, &[Value::Object(r.clone()), Value::String(flags)], None)?;
+    let key = PropertyKey::from("lastIndex");
+    let last_index = e262_to_length(&e262_get(&r, &key)?)?;
+    e262_set(&matcher, &key, Value::Number(last_index), true)?;
+    let prototype = e262_current_realm().intrinsic(Intrinsic::RegExpStringIteratorPrototype);
+    let iterator = e262_ordinary_object_create(Some(prototype));
+    let slots = iterator.0.clone();
+    slots
+        .clone()
+        .set_slot("IteratingRegExp".to_string(), Rc::new(matcher));
+    slots
+        .clone()
+        .set_slot("IteratedString".to_string(), Rc::new(s));
+    slots
+        .clone()
+        .set_slot("Global".to_string(), Rc::new(global));
+    slots
+        .clone()
+        .set_slot("Unicode".to_string(), Rc::new(full_unicode));
+    slots.set_slot("Done".to_string(), Rc::new(Cell::new(false)));
+    Ok(Value::Object(iterator))
+}
+
+/// Implements [`%RegExpStringIteratorPrototype%.next`](https://tc39.es/ecma262/multipage/text-processing.html#sec-%regexpstringiteratorprototype%.next).
+fn reg_exp_string_iterator_next(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let slots = match this {
+        Value::Object(obj) => {
+            let slot = |name: &str| obj.0.clone().get_slot(name.to_string());
+            slot("IteratingRegExp").and_then(|r| {
+                let r = r.downcast::<ObjectRep>().ok()?;
+                let s = slot("IteratedString")?.downcast::<StringRep>().ok()?;
+                let global = slot("Global")?.downcast::<bool>().ok()?;
+                let unicode = slot("Unicode")?.downcast::<bool>().ok()?;
+                let done = slot("Done")?.downcast::<Cell<bool>>().ok()?;
+                Some((r, s, *global, *unicode, done))
+            })
+        }
+        _ => None,
+    };
+    let Some((r, s, global, full_unicode, done)) = slots else {
+        return Err(CoreError::TypeError(
+            "Receiver is not a RegExp String Iterator".to_string(),
+        ));
+    };
+    if done.get() {
+        return Ok(Value::Object(e262_create_iter_result_object(
+            Value::Undefined,
+            true,
+        )));
+    }
+    let result = reg_exp_exec(&r, &s)?;
+    if result == Value::Null {
+        done.set(true);
+        return Ok(Value::Object(e262_create_iter_result_object(
+            Value::Undefined,
+            true,
+        )));
+    }
+    if global {
+        advance_after_empty_match(&r, &s, &result, full_unicode)?;
+    } else {
+        done.set(true);
+    }
+    Ok(Value::Object(e262_create_iter_result_object(result, false)))
+}
+
+/// Implements [`RegExp.prototype[@@replace]`](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexp.prototype-@@replace).
+fn replace(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let rx = this_object(this)?;
+    let s = e262_to_string(&p262_argument(arguments, 0))?;
+    let length_s = s.len();
+    let replace_value = p262_argument(arguments, 1);
+    let functional_replace = e262_is_callable(&replace_value);
+    let replace_value = match functional_replace {
+        true => replace_value,
+        false => Value::String(e262_to_string(&replace_value)?),
+    };
+    let (_, global, full_unicode) = get_flags(&rx)?;
+    if global {
+        e262_set(
+            &rx,
+            &PropertyKey::from("lastIndex"),
+            Value::Number(0f64),
+            true,
+        )?;
+    }
+    let mut results = Vec::new();
+    loop {
+        let result = reg_exp_exec(&rx, &s)?;
+        if result == Value::Null {
+            break;
+        }
+        results.push(result.clone());
+        if !global {
+            break;
+        }
+        advance_after_empty_match(&rx, &s, &result, full_unicode)?;
+    }
+    let mut accumulated_result = StringRep::empty();
+    let mut next_source_position = 0;
+    for result in results {
+        let Value::Object(result) = result else {
+            unreachable!("Only match objects are collected");
+        };
+        let n_captures = (e262_length_of_array_like(&result)? - 1f64).max(0f64) as u32;
+        let matched = e262_to_string(&e262_get(&result, &PropertyKey::from(0u32))?)?;
+        let position =
+            e262_to_integer_or_infinity(&e262_get(&result, &PropertyKey::from("index"))?)?
+                .to_f64()
+                .clamp(0f64, length_s as f64) as usize;
+        let mut captures = Vec::with_capacity(n_captures as usize);
+        for n in 1..=n_captures {
+            let cap_n = e262_get(&result, &PropertyKey::from(n))?;
+            captures.push(match cap_n {
+                Value::Undefined => Value::Undefined,
+                cap_n => Value::String(e262_to_string(&cap_n)?),
+            });
+        }
+        let named_captures = e262_get(&result, &PropertyKey::from("groups"))?;
+        let replacement = if functional_replace {
+            let mut replacer_args = vec![Value::String(matched.clone())];
+            replacer_args.extend(captures);
+            replacer_args.push(Value::Number(position as f64));
+            replacer_args.push(Value::String(s.clone()));
+            if named_captures != Value::Undefined {
+                replacer_args.push(named_captures);
+            }
+            e262_to_string(&e262_call(
+                &replace_value,
+                &Value::Undefined,
+                &replacer_args,
+            )?)?
+        } else {
+            let named_captures = match named_captures {
+                Value::Undefined => Value::Undefined,
+                named_captures => Value::Object(e262_to_object(&named_captures)?),
+            };
+            let Value::String(template) = &replace_value else {
+                unreachable!("Non-functional replace values are converted to strings");
+            };
+            p262_get_substitution(&matched, &s, position, &captures, &named_captures, template)?
+        };
+        if position >= next_source_position {
+            accumulated_result = accumulated_result
+                .concat(&s.substring(next_source_position, position))
+                .concat(&replacement);
+            next_source_position = position + matched.len();
+        }
+    }
+    if next_source_position >= length_s {
+        return Ok(Value::String(accumulated_result));
+    }
+    Ok(Value::String(
+        accumulated_result.concat(&s.substring(next_source_position, length_s)),
+    ))
+}
+
+/// Implements [`RegExp.prototype[@@search]`](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexp.prototype-@@search).
+fn search(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let rx = this_object(this)?;
+    let s = e262_to_string(&p262_argument(arguments, 0))?;
+    let key = PropertyKey::from("lastIndex");
+    let previous_last_index = e262_get(&rx, &key)?;
+    if !e262_same_value(&previous_last_index, &Value::Number(0f64)) {
+        e262_set(&rx, &key, Value::Number(0f64), true)?;
+    }
+    let result = reg_exp_exec(&rx, &s)?;
+    let current_last_index = e262_get(&rx, &key)?;
+    if !e262_same_value(&current_last_index, &previous_last_index) {
+        e262_set(&rx, &key, previous_last_index, true)?;
+    }
+    match result {
+        Value::Object(result) => e262_get(&result, &PropertyKey::from("index")),
+        _ => Ok(Value::Number(-1f64)),
+    }
+}
+
+/// Implements [`RegExp.prototype[@@split]`](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexp.prototype-@@split).
+fn split(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let rx = this_object(this)?;
+    let s = e262_to_string(&p262_argument(arguments, 0))?;
+    let c = e262_species_constructor(&rx, e262_current_realm().intrinsic(Intrinsic::RegExp))?;
+    let (flags, _, unicode_matching) = get_flags(&rx)?;
+    let new_flags = match flags.index_of(&"y".into(), 0) {
+        Some(_) => flags,
+        None => flags.concat(&"y".into()),
+    };
+    let splitter = e262_construct(
+        &c,
+        &[Value::Object(rx.clone()), Value::String(new_flags)],
+        None,
+    )?;
+    let lim = match p262_argument(arguments, 1) {
+        Value::Undefined => u32::MAX,
+        limit => e262_to_uint32(&limit)?,
+    } as usize;
+    let mut substrings = Vec::new();
+    if lim == 0 {
+        return Ok(Value::Object(e262_create_array_from_list(&substrings)));
+    }
+    if s.is_empty() {
+        if reg_exp_exec(&splitter, &s)? == Value::Null {
+            substrings.push(Value::String(s));
+        }
+        return Ok(Value::Object(e262_create_array_from_list(&substrings)));
+    }
+    if let Some(matcher) = unobserved_matcher(&c, &splitter)? {
+        let matcher = Option::as_ref(&matcher).expect("The splitter is initialized");
+        let substrings = split_forward(&s, matcher, lim)?;
+        return Ok(Value::Object(e262_create_array_from_list(&substrings)));
+    }
+    let size = s.len();
+    let key = PropertyKey::from("lastIndex");
+    let mut p = 0;
+    let mut q = p;
+    while q < size {
+        e262_set(&splitter, &key, Value::Number(q as f64), true)?;
+        let z = reg_exp_exec(&splitter, &s)?;
+        let Value::Object(z) = z else {
+            q = advance_string_index(&s, q as f64, unicode_matching) as usize;
+            continue;
+        };
+        let e = e262_to_length(&e262_get(&splitter, &key)?)?.min(size as f64) as usize;
+        if e == p {
+            q = advance_string_index(&s, q as f64, unicode_matching) as usize;
+            continue;
+        }
+        substrings.push(Value::String(s.substring(p, q)));
+        if substrings.len() == lim {
+            return Ok(Value::Object(e262_create_array_from_list(&substrings)));
+        }
+        p = e;
+        let number_of_captures = (e262_length_of_array_like(&z)? - 1f64).max(0f64) as u32;
+        for i in 1..=number_of_captures {
+            substrings.push(e262_get(&z, &PropertyKey::from(i))?);
+            if substrings.len() == lim {
+                return Ok(Value::Object(e262_create_array_from_list(&substrings)));
+            }
+        }
+        q = p;
+    }
+    substrings.push(Value::String(s.substring(p, size)));
+    Ok(Value::Object(e262_create_array_from_list(&substrings)))
+}
+
+/// Returns the matcher of the splitter of `@@split` if nothing can observe how it is run, i.e. if `c` is `%RegExp%`, so that no code but `@@split` can reach the splitter, and its `exec` method is `%RegExp.prototype.exec%`.
+fn unobserved_matcher(
+    c: &ObjectRep,
+    splitter: &ObjectRep,
+) -> CoreResult<Option<Rc<Option<RegExpMatcher>>>> {
+    let realm = e262_current_realm();
+    if *c != realm.intrinsic(Intrinsic::RegExp) {
+        return Ok(None);
+    }
+    let prototype = realm.intrinsic(Intrinsic::RegExpPrototype);
+    let exec = prototype.0.get_own_property(&PropertyKey::from("exec"))?;
+    let original = Value::Object(realm.intrinsic(Intrinsic::RegExpPrototypeExec));
+    match exec {
+        Some(Property::Data { value, .. }) if *value == original => {
+            reg_exp_matcher(&Value::Object(splitter.clone())).map(|(_, matcher)| Some(matcher))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Runs the loop of `@@split` on a non-empty `s`, searching forward for the next match instead of
+/// trying a sticky match at each position, as [`unobserved_matcher`] makes equivalent.
+fn split_forward(s: &StringRep, matcher: &RegExpMatcher, lim: usize) -> CoreResult<Vec<Value>> {
+    let input = matcher.units(s);
+    let size = input.len();
+    let unicode_matching = matcher.flags().full_unicode();
+    let mut substrings = Vec::new();
+    let mut p = 0;
+    let mut q = p;
+    while q < size {
+        let Some(captures) = matcher.find_forward(&input, q)? else {
+            break;
+        };
+        let (start, e) = captures[0].expect("The whole match is always captured");
+        if start >= size {
+            break;
+        }
+        if e == p {
+            q = advance_string_index(s, start as f64, unicode_matching) as usize;
+            continue;
+        }
+        substrings.push(Value::String(s.substring(p, start)));
+        if substrings.len() == lim {
+            return Ok(substrings);
+        }
+        p = e;
+        for capture in &captures[1..] {
+            substrings.push(match capture {
+                Some((start, end)) => Value::String(s.substring(*start, *end)),
+                None => Value::Undefined,
+            });
+            if substrings.len() == lim {
+                return Ok(substrings);
+            }
+        }
+        q = p;
+    }
+    substrings.push(Value::String(s.substring(p, size)));
+    Ok(substrings)
+}
+
+/// The [additional properties of `RegExp.prototype`](https://tc39.es/ecma262/multipage/additional-ecmascript-features-for-web-browsers.html#sec-additional-properties-of-the-regexp.prototype-object) for web browsers.
+#[cfg(feature = "annex-b")]
+mod annex_b {
+    use super::*;
+
+    /// Implements [`RegExp.prototype.compile`](https://tc39.es/ecma262/multipage/additional-ecmascript-features-for-web-browsers.html#sec-regexp.prototype.compile).
+    pub(super) fn compile(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+        let o = this_reg_exp(this)?;
+        let pattern = p262_argument(arguments, 0);
+        let flags = p262_argument(arguments, 1);
+        let (p, f) = match &pattern {
+            Value::Object(obj) if this_reg_exp(&pattern).is_ok() => {
+                if flags != Value::Undefined {
+                    return Err(CoreError::TypeError(
+                        "Cannot supply flags when constructing one RegExp from another".to_string(),
+                    ));
+                }
+                (
+                    Value::String(original_string(obj, "OriginalSource")),
+                    Value::String(original_string(obj, "OriginalFlags")),
+                )
+            }
+            _ => (pattern, flags),
+        };
+        reg_exp_initialize(&o, &p, &f).map(Value::Object)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        e262_create_builtin_function, e262_get_iterator, e262_invoke, e262_iterator_step_value,
+    };
+
+    fn str(value: &str) -> Value {
+        Value::String(value.into())
+    }
+
+    fn reg_exp(pattern: &str, flags: &str) -> Value {
+        let constructor = e262_current_realm().intrinsic(Intrinsic::RegExp);
+        Value::Object(e262_construct(&constructor, &[str(pattern), str(flags)], None).unwrap())
+    }
+
+    fn get(value: &Value, key: impl Into<PropertyKey>) -> Value {
+        let Value::Object(obj) = value else {
+            panic!("{value:?} is not an object");
+        };
+        e262_get(obj, &key.into()).unwrap()
+    }
+
+    fn invoke<const N: usize>(
+        this: &Value,
+        method: impl Into<PropertyKey>,
+        arguments: [Value; N],
+    ) -> CoreResult<Value> {
+        e262_invoke(this, &method.into(), &arguments)
+    }
+
+    fn symbol(symbol: WellKnownSymbol) -> PropertyKey {
+        PropertyKey::from(SymbolRep::well_known(symbol))
+    }
+
+    #[test]
+    fn constructs_and_describes() {
+        let rx = reg_exp("a/b\n", "gimsuyd");
+        assert_eq!(get(&rx, "source"), str("a\\/b\\n"));
+        assert_eq!(get(&rx, "flags"), str("dgimsuy"));
+        assert_eq!(get(&rx, "global"), Value::Boolean(true));
+        assert_eq!(get(&rx, "unicodeSets"), Value::Boolean(false));
+        assert_eq!(get(&rx, "lastIndex"), Value::Number(0f64));
+        assert_eq!(
+            invoke(&rx, "toString", []).unwrap(),
+            str("/a\\/b\\n/dgimsuy")
+        );
+        assert_eq!(get(&reg_exp("[/]", ""), "source"), str("[/]"));
+        assert_eq!(get(&reg_exp("", ""), "source"), str("(?:)"));
+        let prototype = Value::Object(e262_current_realm().intrinsic(Intrinsic::RegExpPrototype));
+        assert_eq!(get(&prototype, "source"), str("(?:)"));
+        assert_eq!(get(&prototype, "global"), Value::Undefined);
+        assert_eq!(get(&prototype, "flags"), str(""));
+
+        let constructor = Value::Object(e262_current_realm().intrinsic(Intrinsic::RegExp));
+        assert_eq!(
+            e262_call(&constructor, &Value::Undefined, std::slice::from_ref(&rx)).unwrap(),
+            rx
+        );
+        let copy = e262_call(&constructor, &Value::Undefined, &[rx.clone(), str("i")]).unwrap();
+        assert_eq!(get(&copy, "source"), str("a\\/b\\n"));
+        assert_eq!(get(&copy, "flags"), str("i"));
+        assert_eq!(
+            get(&constructor, symbol(WellKnownSymbol::Species)),
+            constructor
+        );
+
+        let construct = |pattern: &str, flags: &str| {
+            let constructor = e262_current_realm().intrinsic(Intrinsic::RegExp);
+            e262_construct(&constructor, &[str(pattern), str(flags)], None)
+        };
+        assert!(matches!(construct("(", ""), Err(CoreError::SyntaxError(_))));
+        assert!(matches!(
+            construct("a", "gg"),
+            Err(CoreError::SyntaxError(_))
+        ));
+        assert!(matches!(
+            construct("a", "uv"),
+            Err(CoreError::SyntaxError(_))
+        ));
+    }
+
+    #[test]
+    fn calls_in_the_function_realm() {
+        let other = Realm::new();
+        let constructor = Value::Object(other.intrinsic(Intrinsic::RegExp));
+        let called = e262_call(&constructor, &Value::Undefined, &[str("a")]).unwrap();
+        let Value::Object(obj) = &called else {
+            panic!("RegExp returns an object when called")
+        };
+        let proto = obj.0.clone().get_prototype_of().unwrap();
+        assert_eq!(proto, Some(other.intrinsic(Intrinsic::RegExpPrototype)));
+        assert_eq!(
+            e262_call(
+                &constructor,
+                &Value::Undefined,
+                std::slice::from_ref(&called)
+            )
+            .unwrap(),
+            called
+        );
+        let rx = reg_exp("a", "");
+        assert_ne!(
+            e262_call(&constructor, &Value::Undefined, std::slice::from_ref(&rx)).unwrap(),
+            rx
+        );
+    }
+
+    #[test]
+    fn executes() {
+        let rx = reg_exp("(?<word>[a-z]+)(\\d)?", "g");
+        let result = invoke(&rx, "exec", [str("12 ab3 cd")]).unwrap();
+        assert_eq!(get(&result, "index"), Value::Number(3f64));
+        assert_eq!(get(&result, 0u32), str("ab3"));
+        assert_eq!(get(&result, 2u32), str("3"));
+        assert_eq!(get(&get(&result, "groups"), "word"), str("ab"));
+        assert_eq!(get(&rx, "lastIndex"), Value::Number(6f64));
+        let result = invoke(&rx, "exec", [str("12 ab3 cd")]).unwrap();
+        assert_eq!(get(&result, 2u32), Value::Undefined);
+        assert_eq!(
+            invoke(&rx, "exec", [str("12 ab3 cd")]).unwrap(),
+            Value::Null
+        );
+        assert_eq!(get(&rx, "lastIndex"), Value::Number(0f64));
+
+        let sticky = reg_exp("b", "y");
+        assert_eq!(
+            invoke(&sticky, "test", [str("ab")]).unwrap(),
+            Value::Boolean(false)
+        );
+        invoke(&sticky, "exec", []).unwrap();
+        let Value::Object(obj) = &sticky else {
+            unreachable!()
+        };
+        e262_set(
+            obj,
+            &PropertyKey::from("lastIndex"),
+            Value::Number(1f64),
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            invoke(&sticky, "test", [str("ab")]).unwrap(),
+            Value::Boolean(true)
+        );
+
+        let indices = invoke(&reg_exp("(?<x>a)|(?<x>b)", "d"), "exec", [str("cb")]).unwrap();
+        let indices = get(&indices, "indices");
+        assert_eq!(get(&get(&indices, 0u32), 0u32), Value::Number(1f64));
+        assert_eq!(get(&indices, 1u32), Value::Undefined);
+        assert_eq!(
+            get(&get(&get(&indices, "groups"), "x"), 1u32),
+            Value::Number(2f64)
+        );
+
+        let unicode = invoke(
+            &reg_exp("", "gu"),
+            symbol(WellKnownSymbol::Match),
+            [str("😀")],
+        )
+        .unwrap();
+        assert_eq!(get(&unicode, "length"), Value::Number(2f64));
+        let legacy = invoke(
+            &reg_exp("", "g"),
+            symbol(WellKnownSymbol::Match),
+            [str("😀")],
+        )
+        .unwrap();
+        assert_eq!(get(&legacy, "length"), Value::Number(3f64));
+    }
+
+    #[test]
+    fn serves_string_methods() {
+        let s = str("a1b22c333");
+        let matched = invoke(&s, "match", [reg_exp("\\d+", "g")]).unwrap();
+        assert_eq!(get(&matched, 2u32), str("333"));
+        assert_eq!(
+            invoke(&s, "search", [str("b")]).unwrap(),
+            Value::Number(2f64)
+        );
+        assert_eq!(
+            invoke(&s, "replace", [reg_exp("(\\d)+", "g"), str("<$1>")]).unwrap(),
+            str("a<1>b<2>c<3>")
+        );
+        assert_eq!(
+            invoke(
+                &s,
+                "replaceAll",
+                [reg_exp("(?<d>\\d)", "g"), str("$<d>$<d>")]
+            )
+            .unwrap(),
+            str("a11b2222c333333")
+        );
+        let split = invoke(&s, "split", [reg_exp("(\\d)+", ""), Value::Number(4f64)]).unwrap();
+        assert_eq!(get(&split, "length"), Value::Number(4f64));
+        assert_eq!(get(&split, 1u32), str("1"));
+        assert_eq!(get(&split, 3u32), str("2"));
+        let split = invoke(&str("ab"), "split", [reg_exp("", "")]).unwrap();
+        assert_eq!(get(&split, "length"), Value::Number(2f64));
+
+        let iterator = invoke(&s, "matchAll", [str("\\d")]).unwrap();
+        let mut record = e262_get_iterator(&iterator).unwrap();
+        let mut count = 0;
+        while let Some(result) = e262_iterator_step_value(&mut record).unwrap() {
+            assert!(matches!(get(&result, 0u32), Value::String(_)));
+            count += 1;
+        }
+        assert_eq!(count, 6);
+        assert!(invoke(&s, "matchAll", [reg_exp("\\d", "")]).is_err());
+        let match_all = get(
+            &Value::Object(e262_current_realm().intrinsic(Intrinsic::StringPrototype)),
+            "matchAll",
+        );
+        let err = e262_call(&match_all, &Value::Null, &[reg_exp("\\d", "")]).unwrap_err();
+        assert!(matches!(err, CoreError::TypeError(message) if message.contains("null")));
+    }
+
+    #[test]
+    fn splits_forward_like_the_generic_loop() {
+        let cases = [
+            ("(\\d)+", "", "a1b22c333", 10f64),
+            ("(\\d)+", "", "a1b22c333", 3f64),
+            ("", "", "abc", 10f64),
+            ("", "u", "a😀b", 10f64),
+            ("", "", "a😀b", 10f64),
+            ("x*", "", "axxbx", 10f64),
+            ("(?:)|b", "", "abc", 10f64),
+            ("(a)|(b)", "", "xaybz", 10f64),
+            ("$", "m", "a\nb\n", 10f64),
+            ("\\b", "", "ab cd", 10f64),
+            ("(?<=a)", "y", "aaa", 10f64),
+            ("c", "i", "abCabc", 10f64),
+        ];
+        let split = |(pattern, flags, input, limit): (&str, &str, &str, f64)| {
+            let parts = invoke(
+                &reg_exp(pattern, flags),
+                symbol(WellKnownSymbol::Split),
+                [str(input), Value::Number(limit)],
+            )
+            .unwrap();
+            let Value::Number(len) = get(&parts, "length") else {
+                unreachable!("arrays have a length")
+            };
+            (0..len as u32)
+                .map(|i| get(&parts, i))
+                .collect::<Vec<Value>>()
+        };
+        let forward: Vec<Vec<Value>> = cases.into_iter().map(split).collect();
+
+        let prototype = e262_current_realm().intrinsic(Intrinsic::RegExpPrototype);
+        let original = e262_get(&prototype, &PropertyKey::from("exec")).unwrap();
+        let wrapper = e262_create_builtin_function(
+            Rc::new(move |this, arguments| e262_call(&original, this, arguments)),
+            1,
+            "exec",
+            None,
+        );
+        let exec = PropertyKey::from("exec");
+        e262_set(&prototype, &exec, Value::Object(wrapper), true).unwrap();
+        let generic: Vec<Vec<Value>> = cases.into_iter().map(split).collect();
+        let original =
+            Value::Object(e262_current_realm().intrinsic(Intrinsic::RegExpPrototypeExec));
+        e262_set(&prototype, &exec, original, true).unwrap();
+        assert_eq!(forward, generic);
+
+        let long = str(&"ab".repeat(100_000));
+        let parts = invoke(&long, "split", [reg_exp("b", "")]).unwrap();
+        assert_eq!(get(&parts, "length"), Value::Number(100_001f64));
+    }
+
+    #[test]
+    fn limits_backtracking() {
+        let rx = reg_exp("^(a|a)*$", "");
+        let input = format!("{}b", "a".repeat(64));
+        assert!(matches!(
+            invoke(&rx, "test", [str(&input)]),
+            Err(CoreError::RangeError(_))
+        ));
+    }
+
+    #[cfg(feature = "annex-b")]
+    #[test]
+    fn recompiles() {
+        let rx = reg_exp("a", "g");
+        invoke(&rx, "compile", [str("b"), str("i")]).unwrap();
+        assert_eq!(invoke(&rx, "toString", []).unwrap(), str("/b/i"));
+        assert!(invoke(&rx, "compile", [reg_exp("c", ""), str("g")]).is_err());
+    }
+}
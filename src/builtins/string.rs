@@ -0,0 +1,1319 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use super::regexp::p262_reg_exp_create;
+use super::{p262_argument, p262_define_method, p262_link_constructor, p262_relative_index};
+use crate::agent::e262_current_realm;
+use crate::core::cast::{
+    e262_to_integer_or_infinity, e262_to_length, e262_to_number, e262_to_object, e262_to_string,
+    e262_to_uint16, e262_to_uint32, is_str_whitespace,
+};
+use crate::core::function::e262_call;
+use crate::core::test::{
+    e262_is_callable, e262_is_integral_number, e262_is_reg_exp, e262_require_object_coercible,
+};
+use crate::core::unicode::{
+    p262_normalize, p262_to_lowercase, p262_to_uppercase, NormalizationForm,
+};
+use crate::core::{
+    e262_create_array_from_list, e262_create_iter_result_object, e262_define_property_or_throw,
+    e262_get, e262_get_method, e262_get_prototype_from_constructor, e262_invoke,
+    e262_length_of_array_like, e262_ordinary_object_create, e262_string_create,
+    p262_create_builtin_constructor, p262_get_slot, p262_utf16_encode_code_point, Descriptor,
+    ObjectRep, PropertyKey, StringRep, SymbolRep, Value, WellKnownSymbol,
+};
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::{Intrinsic, Realm};
+
+/// The length of the longest string the built-ins will create, beyond which they throw a RangeError.
+const MAX_STRING_LENGTH: f64 = ((1u32 << 30) - 1) as f64;
+
+/// Creates `%String%`, the methods of `%String.prototype%` and `%StringIteratorPrototype%`.
+pub(crate) fn p262_create_intrinsics(realm: &Realm) {
+    let prototype = realm.intrinsic(Intrinsic::StringPrototype);
+    let constructor = p262_create_builtin_constructor(
+        Rc::new(|_, arguments| match arguments.first() {
+            None => Ok(Value::String(StringRep::empty())),
+            Some(Value::Symbol(symbol)) => Ok(Value::String(symbol.descriptive_string())),
+            Some(value) => Ok(Value::String(e262_to_string(value)?)),
+        }),
+        Rc::new(construct),
+        1,
+        "String",
+        Some(realm.intrinsic(Intrinsic::FunctionPrototype)),
+    );
+    p262_link_constructor(&constructor, &prototype);
+
+    p262_define_method(realm, &constructor, "fromCharCode", 1, from_char_code);
+    p262_define_method(realm, &constructor, "fromCodePoint", 1, from_code_point);
+    p262_define_method(realm, &constructor, "raw", 1, raw);
+
+    p262_define_method(realm, &prototype, "at", 1, at);
+    p262_define_method(realm, &prototype, "charAt", 1, char_at);
+    p262_define_method(realm, &prototype, "charCodeAt", 1, char_code_at);
+    p262_define_method(realm, &prototype, "codePointAt", 1, code_point_at);
+    p262_define_method(realm, &prototype, "concat", 1, concat);
+    p262_define_method(realm, &prototype, "endsWith", 1, ends_with);
+    p262_define_method(realm, &prototype, "includes", 1, includes);
+    p262_define_method(realm, &prototype, "indexOf", 1, index_of);
+    p262_define_method(realm, &prototype, "isWellFormed", 0, |this, _| {
+        let s = this_string(this)?;
+        Ok(Value::Boolean(is_well_formed(&s)))
+    });
+    p262_define_method(realm, &prototype, "lastIndexOf", 1, last_index_of);
+    p262_define_method(realm, &prototype, "localeCompare", 1, locale_compare);
+    p262_define_method(realm, &prototype, "match", 1, |this, args| {
+        match_with(this, args, WellKnownSymbol::Match, "")
+    });
+    p262_define_method(realm, &prototype, "matchAll", 1, match_all);
+    p262_define_method(realm, &prototype, "normalize", 0, normalize);
+    p262_define_method(realm, &prototype, "padEnd", 1, |this, args| {
+        pad(this, args, Placement::End)
+    });
+    p262_define_method(realm, &prototype, "padStart", 1, |this, args| {
+        pad(this, args, Placement::Start)
+    });
+    p262_define_method(realm, &prototype, "repeat", 1, repeat);
+    p262_define_method(realm, &prototype, "replace", 2, replace);
+    p262_define_method(realm, &prototype, "replaceAll", 2, replace_all);
+    p262_define_method(realm, &prototype, "search", 1, |this, args| {
+        match_with(this, args, WellKnownSymbol::Search, "")
+    });
+    p262_define_method(realm, &prototype, "slice", 2, slice);
+    p262_define_method(realm, &prototype, "split", 2, split);
+    p262_define_method(realm, &prototype, "startsWith", 1, starts_with);
+    p262_define_method(realm, &prototype, "substring", 2, substring);
+    p262_define_method(realm, &prototype, "toLocaleLowerCase", 0, |this, _| {
+        Ok(Value::String(to_case(&this_string(this)?, Case::Lower)))
+    });
+    p262_define_method(realm, &prototype, "toLocaleUpperCase", 0, |this, _| {
+        Ok(Value::String(to_case(&this_string(this)?, Case::Upper)))
+    });
+    p262_define_method(realm, &prototype, "toLowerCase", 0, |this, _| {
+        Ok(Value::String(to_case(&this_string(this)?, Case::Lower)))
+    });
+    p262_define_method(realm, &prototype, "toString", 0, |this, _| {
+        Ok(Value::String(this_string_value(this)?))
+    });
+    p262_define_method(realm, &prototype, "toUpperCase", 0, |this, _| {
+        Ok(Value::String(to_case(&this_string(this)?, Case::Upper)))
+    });
+    p262_define_method(realm, &prototype, "toWellFormed", 0, to_well_formed);
+    p262_define_method(realm, &prototype, "trim", 0, |this, _| {
+        trim(this, Placement::Both)
+    });
+    let trim_end = p262_define_method(realm, &prototype, "trimEnd", 0, |this, _| {
+        trim(this, Placement::End)
+    });
+    let trim_start = p262_define_method(realm, &prototype, "trimStart", 0, |this, _| {
+        trim(this, Placement::Start)
+    });
+    p262_define_method(realm, &prototype, "valueOf", 0, |this, _| {
+        Ok(Value::String(this_string_value(this)?))
+    });
+    let iterator = SymbolRep::well_known(WellKnownSymbol::Iterator);
+    p262_define_method(realm, &prototype, iterator, 0, |this, _| {
+        let s = this_string(this)?;
+        Ok(Value::Object(create_string_iterator(s)))
+    });
+
+    #[cfg(feature = "annex-b")]
+    annex_b::p262_create_intrinsics(realm, &prototype, trim_start, trim_end);
+    #[cfg(not(feature = "annex-b"))]
+    let _ = (trim_start, trim_end);
+
+    let iterator_prototype =
+        e262_ordinary_object_create(Some(realm.intrinsic(Intrinsic::IteratorPrototype)));
+    p262_define_method(realm, &iterator_prototype, "next", 0, string_iterator_next);
+    let to_string_tag = SymbolRep::well_known(WellKnownSymbol::ToStringTag);
+    e262_define_property_or_throw(
+        &iterator_prototype,
+        PropertyKey::from(to_string_tag),
+        Descriptor {
+            value: Some(Rc::new(Value::String("String Iterator".into()))),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Built-in properties can always be defined");
+
+    realm.set_intrinsic(Intrinsic::String, constructor);
+    realm.set_intrinsic(Intrinsic::StringIteratorPrototype, iterator_prototype);
+}
+
+/// Which ends of a string an operation applies to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Placement {
+    Start,
+    End,
+    Both,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Case {
+    Lower,
+    Upper,
+}
+
+/// Implements [`String`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string-constructor-string-value) when called as a constructor.
+fn construct(arguments: &[Value], new_target: &ObjectRep) -> CoreResult<ObjectRep> {
+    let s = match arguments.first() {
+        None => StringRep::empty(),
+        Some(value) => e262_to_string(value)?,
+    };
+    let proto = e262_get_prototype_from_constructor(new_target, Intrinsic::StringPrototype)?;
+    Ok(e262_string_create(s, Some(proto)))
+}
+
+/// Implements [thisStringValue](https://tc39.es/ecma262/multipage/text-processing.html#sec-thisstringvalue).
+fn this_string_value(value: &Value) -> CoreResult<StringRep> {
+    match value {
+        Value::String(s) => Ok(s.clone()),
+        Value::Object(obj) => p262_get_slot::<StringRep>(obj.0.clone(), "StringData".to_string())
+            .map(|s| (*s).clone())
+            .ok_or_else(|| CoreError::TypeError("Receiver is not a String".to_string())),
+        _ => Err(CoreError::TypeError("Receiver is not a String".to_string())),
+    }
+}
+
+/// Converts `this` to a string after checking it is neither undefined nor null, as most methods of `String.prototype` start with.
+fn this_string(this: &Value) -> CoreResult<StringRep> {
+    e262_to_string(&e262_require_object_coercible(this.clone())?)
+}
+
+/// Converts a position argument to an integer clamped to `0..=len`.
+fn clamped_position(argument: &Value, len: usize) -> CoreResult<usize> {
+    let position = e262_to_integer_or_infinity(argument)?.to_f64();
+    Ok(position.clamp(0f64, len as f64) as usize)
+}
+
+/// Throws a TypeError if `argument` is a RegExp, which `endsWith`, `includes` and `startsWith` refuse as search string.
+fn search_string(argument: &Value) -> CoreResult<StringRep> {
+    if e262_is_reg_exp(argument)? {
+        return Err(CoreError::TypeError(
+            "First argument must not be a regular expression".to_string(),
+        ));
+    }
+    e262_to_string(argument)
+}
+
+/// Throws a RangeError if a string of `len` code units would be too long to create.
+fn check_string_length(len: f64) -> CoreResult<()> {
+    if len > MAX_STRING_LENGTH {
+        return Err(CoreError::RangeError("Invalid string length".to_string()));
+    }
+    Ok(())
+}
+
+/// Implements [`String.fromCharCode`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.fromcharcode).
+fn from_char_code(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let code_units = arguments
+        .iter()
+        .map(e262_to_uint16)
+        .collect::<CoreResult<Vec<u16>>>()?;
+    Ok(Value::String(StringRep::from_utf16(&code_units)))
+}
+
+/// Implements [`String.fromCodePoint`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.fromcodepoint).
+fn from_code_point(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let mut code_units = Vec::with_capacity(arguments.len());
+    for next in arguments {
+        let next_cp = e262_to_number(next)?;
+        if !e262_is_integral_number(&Value::Number(next_cp))
+            || !(0f64..=1114111f64).contains(&next_cp)
+        {
+            return Err(CoreError::RangeError(format!(
+                "Invalid code point {next_cp}"
+            )));
+        }
+        let mut buffer = [0; 2];
+        code_units.extend_from_slice(p262_utf16_encode_code_point(next_cp as u32, &mut buffer));
+    }
+    Ok(Value::String(StringRep::from_utf16(&code_units)))
+}
+
+/// Implements [`String.raw`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.raw).
+fn raw(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let cooked = e262_to_object(&p262_argument(arguments, 0))?;
+    let literals = e262_to_object(&e262_get(&cooked, &PropertyKey::from("raw"))?)?;
+    let literal_count = e262_length_of_array_like(&literals)?;
+    let substitutions = arguments.get(1..).unwrap_or_default();
+    let mut result = StringRep::empty();
+    let mut next_index = 0f64;
+    while next_index < literal_count {
+        let next_literal = e262_get(&literals, &PropertyKey::from(next_index))?;
+        result = result.concat(&e262_to_string(&next_literal)?);
+        if next_index + 1f64 == literal_count {
+            break;
+        }
+        if let Some(next_sub) = substitutions.get(next_index as usize) {
+            result = result.concat(&e262_to_string(next_sub)?);
+        }
+        next_index += 1f64;
+    }
+    Ok(Value::String(result))
+}
+
+/// Implements [`String.prototype.at`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.at).
+fn at(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    let len = s.len() as f64;
+    let relative = e262_to_integer_or_infinity(&p262_argument(arguments, 0))?.to_f64();
+    let k = if relative >= 0f64 {
+        relative
+    } else {
+        len + relative
+    };
+    if k < 0f64 || k >= len {
+        return Ok(Value::Undefined);
+    }
+    Ok(Value::String(s.substring(k as usize, k as usize + 1)))
+}
+
+/// Resolves the position argument of `charAt`, `charCodeAt` and `codePointAt`, returning [`None`] when out of bounds.
+fn position_in(s: &StringRep, argument: &Value) -> CoreResult<Option<usize>> {
+    let position = e262_to_integer_or_infinity(argument)?.to_f64();
+    if position < 0f64 || position >= s.len() as f64 {
+        return Ok(None);
+    }
+    Ok(Some(position as usize))
+}
+
+/// Implements [`String.prototype.charAt`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.charat).
+fn char_at(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    Ok(Value::String(
+        match position_in(&s, &p262_argument(arguments, 0))? {
+            Some(position) => s.substring(position, position + 1),
+            None => StringRep::empty(),
+        },
+    ))
+}
+
+/// Implements [`String.prototype.charCodeAt`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.charcodeat).
+fn char_code_at(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    Ok(Value::Number(
+        match position_in(&s, &p262_argument(arguments, 0))? {
+            Some(position) => s.code_unit_at(position).map_or(f64::NAN, f64::from),
+            None => f64::NAN,
+        },
+    ))
+}
+
+/// Implements [CodePointAt](https://tc39.es/ecma262/multipage/ecmascript-language-source-code.html#sec-codepointat), returning the code point and its number of code units.
+fn code_point_at_index(s: &StringRep, position: usize) -> (u32, usize) {
+    let first = s
+        .code_unit_at(position)
+        .expect("position must be in bounds");
+    if (0xD800..=0xDBFF).contains(&first) {
+        if let Some(second @ 0xDC00..=0xDFFF) = s.code_unit_at(position + 1) {
+            let cp = 0x10000 + ((first as u32 - 0xD800) << 10) + (second as u32 - 0xDC00);
+            return (cp, 2);
+        }
+    }
+    (first as u32, 1)
+}
+
+/// Implements [`String.prototype.codePointAt`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.codepointat).
+fn code_point_at(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    match position_in(&s, &p262_argument(arguments, 0))? {
+        Some(position) => Ok(Value::Number(code_point_at_index(&s, position).0 as f64)),
+        None => Ok(Value::Undefined),
+    }
+}
+
+/// Implements [`String.prototype.concat`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.concat).
+fn concat(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let mut result = this_string(this)?;
+    for argument in arguments {
+        result = result.concat(&e262_to_string(argument)?);
+    }
+    Ok(Value::String(result))
+}
+
+/// Returns if `s` contains `search` at `position`.
+fn matches_at(s: &StringRep, search: &StringRep, position: usize) -> bool {
+    position + search.len() <= s.len()
+        && search
+            .code_units()
+            .enumerate()
+            .all(|(i, unit)| s.code_unit_at(position + i) == Some(unit))
+}
+
+/// Implements [`String.prototype.endsWith`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.endswith).
+fn ends_with(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    let search = search_string(&p262_argument(arguments, 0))?;
+    let end = match p262_argument(arguments, 1) {
+        Value::Undefined => s.len(),
+        end_position => clamped_position(&end_position, s.len())?,
+    };
+    Ok(Value::Boolean(
+        search.len() <= end && matches_at(&s, &search, end - search.len()),
+    ))
+}
+
+/// Implements [`String.prototype.includes`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.includes).
+fn includes(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    let search = search_string(&p262_argument(arguments, 0))?;
+    let start = clamped_position(&p262_argument(arguments, 1), s.len())?;
+    Ok(Value::Boolean(s.index_of(&search, start).is_some()))
+}
+
+/// Implements [`String.prototype.indexOf`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.indexof).
+fn index_of(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    let search = e262_to_string(&p262_argument(arguments, 0))?;
+    let start = clamped_position(&p262_argument(arguments, 1), s.len())?;
+    Ok(Value::Number(
+        s.index_of(&search, start)
+            .map_or(-1f64, |index| index as f64),
+    ))
+}
+
+/// Implements [IsStringWellFormedUnicode](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-isstringwellformedunicode).
+fn is_well_formed(s: &StringRep) -> bool {
+    char::decode_utf16(s.code_units()).all(|result| result.is_ok())
+}
+
+/// Implements [`String.prototype.lastIndexOf`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.lastindexof).
+fn last_index_of(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    let search = e262_to_string(&p262_argument(arguments, 0))?;
+    let num_pos = e262_to_number(&p262_argument(arguments, 1))?;
+    let start = match num_pos.is_nan() {
+        true => s.len(),
+        false => clamped_position(&Value::Number(num_pos), s.len())?,
+    };
+    if search.len() > s.len() {
+        return Ok(Value::Number(-1f64));
+    }
+    let last = start.min(s.len() - search.len());
+    Ok(Value::Number(
+        (0..=last)
+            .rev()
+            .find(|&n| matches_at(&s, &search, n))
+            .map_or(-1f64, |n| n as f64),
+    ))
+}
+
+/// Implements [`String.prototype.localeCompare`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.localecompare) without locale data, by comparing the NFC forms of both strings code point by code point.
+fn locale_compare(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    let that = e262_to_string(&p262_argument(arguments, 0))?;
+    let s = p262_normalize(s.code_points(), NormalizationForm::Nfc);
+    let that = p262_normalize(that.code_points(), NormalizationForm::Nfc);
+    Ok(Value::Number(match s.cmp(&that) {
+        std::cmp::Ordering::Less => -1f64,
+        std::cmp::Ordering::Equal => 0f64,
+        std::cmp::Ordering::Greater => 1f64,
+    }))
+}
+
+/// Implements `match` and `search`, which delegate to the method `symbol` of the regular expression argument.
+fn match_with(
+    this: &Value,
+    arguments: &[Value],
+    symbol: WellKnownSymbol,
+    flags: &str,
+) -> CoreResult<Value> {
+    let o = e262_require_object_coercible(this.clone())?;
+    let regexp = p262_argument(arguments, 0);
+    let key = PropertyKey::from(SymbolRep::well_known(symbol));
+    if !matches!(regexp, Value::Undefined | Value::Null) {
+        if let Some(matcher) = e262_get_method(&regexp, &key)? {
+            return e262_call(&matcher, &regexp, &[o]);
+        }
+    }
+    let s = e262_to_string(&o)?;
+    let rx = p262_reg_exp_create(&regexp, flags)?;
+    e262_invoke(&rx, &key, &[Value::String(s)])
+}
+
+/// Throws a TypeError if `value` is a RegExp without the `g` flag, as `matchAll` and `replaceAll` require.
+fn require_global(value: &Value) -> CoreResult<()> {
+    if let Value::Object(obj) = value {
+        if e262_is_reg_exp(value)? {
+            let flags = e262_require_object_coercible(e262_get(obj, &PropertyKey::from("flags"))?)?;
+            if e262_to_string(&flags)?.index_of(&"g".into(), 0).is_none() {
+                return Err(CoreError::TypeError(
+                    "Regular expression must have the global flag".to_string(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Implements [`String.prototype.matchAll`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.matchall).
+fn match_all(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    e262_require_object_coercible(this.clone())?;
+    require_global(&p262_argument(arguments, 0))?;
+    match_with(this, arguments, WellKnownSymbol::MatchAll, "g")
+}
+
+/// Implements [`String.prototype.normalize`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.normalize).
+fn normalize(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    let form = match p262_argument(arguments, 0) {
+        Value::Undefined => NormalizationForm::Nfc,
+        form => {
+            let form = e262_to_string(&form)?;
+            NormalizationForm::from_name(&form.to_std_string_lossy()).ok_or_else(|| {
+                CoreError::RangeError(
+                    "The normalization form must be one of NFC, NFD, NFKC or NFKD".to_string(),
+                )
+            })?
+        }
+    };
+    let mut code_units = Vec::with_capacity(s.len());
+    for code_point in p262_normalize(s.code_points(), form) {
+        let mut buffer = [0; 2];
+        code_units.extend_from_slice(p262_utf16_encode_code_point(code_point, &mut buffer));
+    }
+    Ok(Value::String(StringRep::from_utf16(&code_units)))
+}
+
+/// Implements [StringPaddingBuiltinsImpl](https://tc39.es/ecma262/multipage/text-processing.html#sec-stringpaddingbuiltinsimpl) for `padStart` and `padEnd`.
+fn pad(this: &Value, arguments: &[Value], placement: Placement) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    let int_max_length = e262_to_length(&p262_argument(arguments, 0))?;
+    let string_length = s.len() as f64;
+    if int_max_length <= string_length {
+        return Ok(Value::String(s));
+    }
+    let fill_string = match p262_argument(arguments, 1) {
+        Value::Undefined => StringRep::from(" "),
+        fill_string => e262_to_string(&fill_string)?,
+    };
+    if fill_string.is_empty() {
+        return Ok(Value::String(s));
+    }
+    check_string_length(int_max_length)?;
+    let fill_len = (int_max_length - string_length) as usize;
+    let filler: Vec<u16> = fill_string.code_units().cycle().take(fill_len).collect();
+    let filler = StringRep::from_utf16(&filler);
+    Ok(Value::String(match placement {
+        Placement::Start => filler.concat(&s),
+        _ => s.concat(&filler),
+    }))
+}
+
+/// Implements [`String.prototype.repeat`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.repeat).
+fn repeat(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    let n = e262_to_integer_or_infinity(&p262_argument(arguments, 0))?.to_f64();
+    if n < 0f64 || n == f64::INFINITY {
+        return Err(CoreError::RangeError("Invalid count value".to_string()));
+    }
+    if n == 0f64 || s.is_empty() {
+        return Ok(Value::String(StringRep::empty()));
+    }
+    check_string_length(n * s.len() as f64)?;
+    let code_units: Vec<u16> = s.code_units().cycle().take(n as usize * s.len()).collect();
+    Ok(Value::String(StringRep::from_utf16(&code_units)))
+}
+
+/// Implements [GetSubstitution](https://tc39.es/ecma262/multipage/text-processing.html#sec-getsubstitution), where `captures` holds undefined or String values.
+pub(crate) fn p262_get_substitution(
+    matched: &StringRep,
+    s: &StringRep,
+    position: usize,
+    captures: &[Value],
+    named_captures: &Value,
+    replacement_template: &StringRep,
+) -> CoreResult<StringRep> {
+    let template = replacement_template.to_utf16();
+    let digit = |index: usize| {
+        template
+            .get(index)
+            .filter(|unit| (b'0' as u16..=b'9' as u16).contains(unit))
+            .map(|unit| (unit - b'0' as u16) as usize)
+    };
+    let mut result: Vec<u16> = Vec::with_capacity(template.len());
+    let mut i = 0;
+    while i < template.len() {
+        if template[i] != b'$' as u16 {
+            result.push(template[i]);
+            i += 1;
+            continue;
+        }
+        match template
+            .get(i + 1)
+            .and_then(|&unit| char::from_u32(unit as u32))
+        {
+            Some('$') => {
+                result.push(b'$' as u16);
+                i += 2;
+            }
+            Some('`') => {
+                result.extend(s.substring(0, position).code_units());
+                i += 2;
+            }
+            Some('&') => {
+                result.extend(matched.code_units());
+                i += 2;
+            }
+            Some('\'') => {
+                let tail_pos = (position + matched.len()).min(s.len());
+                result.extend(s.substring(tail_pos, s.len()).code_units());
+                i += 2;
+            }
+            Some('0'..='9') => {
+                let first = digit(i + 1).expect("The first digit was just matched");
+                let (mut index, mut digit_count) = match digit(i + 2) {
+                    Some(second) => (first * 10 + second, 2),
+                    None => (first, 1),
+                };
+                if index > captures.len() && digit_count == 2 {
+                    index = first;
+                    digit_count = 1;
+                }
+                if (1..=captures.len()).contains(&index) {
+                    if let Value::String(capture) = &captures[index - 1] {
+                        result.extend(capture.code_units());
+                    }
+                } else {
+                    result.extend_from_slice(&template[i..i + 1 + digit_count]);
+                }
+                i += 1 + digit_count;
+            }
+            Some('<') => {
+                let gt_pos = template[i..].iter().position(|&unit| unit == b'>' as u16);
+                match (gt_pos, named_captures) {
+                    (Some(gt_pos), Value::Object(named_captures)) => {
+                        let group_name = StringRep::from_utf16(&template[i + 2..i + gt_pos]);
+                        let capture = e262_get(named_captures, &PropertyKey::from(group_name))?;
+                        if capture != Value::Undefined {
+                            result.extend(e262_to_string(&capture)?.code_units());
+                        }
+                        i += gt_pos + 1;
+                    }
+                    _ => {
+                        result.extend_from_slice(&template[i..i + 2]);
+                        i += 2;
+                    }
+                }
+            }
+            _ => {
+                result.push(b'$' as u16);
+                i += 1;
+            }
+        }
+    }
+    Ok(StringRep::from_utf16(&result))
+}
+
+/// Computes the replacement of `search` found at `position`, calling `replace_value` if it is a function.
+fn replacement(
+    search: &StringRep,
+    s: &StringRep,
+    position: usize,
+    replace_value: &Value,
+) -> CoreResult<StringRep> {
+    match replace_value {
+        Value::String(template) => {
+            p262_get_substitution(search, s, position, &[], &Value::Undefined, template)
+        }
+        replacer => e262_to_string(&e262_call(
+            replacer,
+            &Value::Undefined,
+            &[
+                Value::String(search.clone()),
+                Value::Number(position as f64),
+                Value::String(s.clone()),
+            ],
+        )?),
+    }
+}
+
+/// Implements the steps shared by `replace` and `replaceAll`, which delegate to `@@replace` of the search value and otherwise replace the given matches.
+fn replace_with(
+    this: &Value,
+    arguments: &[Value],
+    find: impl Fn(&StringRep, &StringRep) -> Vec<usize>,
+) -> CoreResult<Value> {
+    let o = e262_require_object_coercible(this.clone())?;
+    let search_value = p262_argument(arguments, 0);
+    let replace_value = p262_argument(arguments, 1);
+    if !matches!(search_value, Value::Undefined | Value::Null) {
+        let key = PropertyKey::from(SymbolRep::well_known(WellKnownSymbol::Replace));
+        if let Some(replacer) = e262_get_method(&search_value, &key)? {
+            return e262_call(&replacer, &search_value, &[o, replace_value]);
+        }
+    }
+    let s = e262_to_string(&o)?;
+    let search = e262_to_string(&search_value)?;
+    let replace_value = match e262_is_callable(&replace_value) {
+        true => replace_value,
+        false => Value::String(e262_to_string(&replace_value)?),
+    };
+    let mut result = StringRep::empty();
+    let mut end_of_last_match = 0;
+    for position in find(&s, &search) {
+        let preserved = s.substring(end_of_last_match, position);
+        let replacement = replacement(&search, &s, position, &replace_value)?;
+        result = result.concat(&preserved).concat(&replacement);
+        end_of_last_match = position + search.len();
+    }
+    let rest = s.substring(end_of_last_match.min(s.len()), s.len());
+    Ok(Value::String(result.concat(&rest)))
+}
+
+/// Implements [`String.prototype.replace`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.replace).
+fn replace(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    replace_with(this, arguments, |s, search| {
+        s.index_of(search, 0).into_iter().collect()
+    })
+}
+
+/// Implements [`String.prototype.replaceAll`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.replaceall).
+fn replace_all(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let search_value = p262_argument(arguments, 0);
+    if !matches!(search_value, Value::Undefined | Value::Null) {
+        e262_require_object_coercible(this.clone())?;
+        require_global(&search_value)?;
+    }
+    replace_with(this, arguments, |s, search| {
+        let advance_by = search.len().max(1);
+        let mut positions = Vec::new();
+        let mut position = s.index_of(search, 0);
+        while let Some(found) = position {
+            positions.push(found);
+            position = s.index_of(search, found + advance_by);
+        }
+        positions
+    })
+}
+
+/// Implements [`String.prototype.slice`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.slice).
+fn slice(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    let len = s.len() as f64;
+    let from = p262_relative_index(&p262_argument(arguments, 0), len)?;
+    let to = match p262_argument(arguments, 1) {
+        Value::Undefined => len,
+        end => p262_relative_index(&end, len)?,
+    };
+    if from >= to {
+        return Ok(Value::String(StringRep::empty()));
+    }
+    Ok(Value::String(s.substring(from as usize, to as usize)))
+}
+
+/// Implements [`String.prototype.split`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.split).
+fn split(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let o = e262_require_object_coercible(this.clone())?;
+    let separator = p262_argument(arguments, 0);
+    let limit = p262_argument(arguments, 1);
+    if !matches!(separator, Value::Undefined | Value::Null) {
+        let key = PropertyKey::from(SymbolRep::well_known(WellKnownSymbol::Split));
+        if let Some(splitter) = e262_get_method(&separator, &key)? {
+            return e262_call(&splitter, &separator, &[o, limit]);
+        }
+    }
+    let s = e262_to_string(&o)?;
+    let lim = match limit {
+        Value::Undefined => u32::MAX,
+        limit => e262_to_uint32(&limit)?,
+    } as usize;
+    let r = e262_to_string(&separator)?;
+    if lim == 0 {
+        return Ok(Value::Object(e262_create_array_from_list(&[])));
+    }
+    if separator == Value::Undefined {
+        return Ok(Value::Object(e262_create_array_from_list(&[
+            Value::String(s),
+        ])));
+    }
+    let mut substrings = Vec::new();
+    if r.is_empty() {
+        for i in 0..s.len().min(lim) {
+            substrings.push(Value::String(s.substring(i, i + 1)));
+        }
+        return Ok(Value::Object(e262_create_array_from_list(&substrings)));
+    }
+    if s.is_empty() {
+        return Ok(Value::Object(e262_create_array_from_list(&[
+            Value::String(s),
+        ])));
+    }
+    let mut i = 0;
+    let mut j = s.index_of(&r, 0);
+    while let Some(found) = j {
+        substrings.push(Value::String(s.substring(i, found)));
+        if substrings.len() == lim {
+            return Ok(Value::Object(e262_create_array_from_list(&substrings)));
+        }
+        i = found + r.len();
+        j = s.index_of(&r, i);
+    }
+    substrings.push(Value::String(s.substring(i, s.len())));
+    Ok(Value::Object(e262_create_array_from_list(&substrings)))
+}
+
+/// Implements [`String.prototype.startsWith`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.startswith).
+fn starts_with(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    let search = search_string(&p262_argument(arguments, 0))?;
+    let start = clamped_position(&p262_argument(arguments, 1), s.len())?;
+    Ok(Value::Boolean(matches_at(&s, &search, start)))
+}
+
+/// Implements [`String.prototype.substring`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.substring).
+fn substring(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    let start = clamped_position(&p262_argument(arguments, 0), s.len())?;
+    let end = match p262_argument(arguments, 1) {
+        Value::Undefined => s.len(),
+        end => clamped_position(&end, s.len())?,
+    };
+    Ok(Value::String(s.substring(start.min(end), start.max(end))))
+}
+
+/// Maps the code points of `s` to their full Unicode default case conversion, leaving lone surrogates untouched.
+fn to_case(s: &StringRep, case: Case) -> StringRep {
+    let mapped = match case {
+        Case::Lower => p262_to_lowercase(s.code_points()),
+        Case::Upper => p262_to_uppercase(s.code_points()),
+    };
+    let mut code_units = Vec::with_capacity(mapped.len());
+    for code_point in mapped {
+        let mut buffer = [0; 2];
+        code_units.extend_from_slice(p262_utf16_encode_code_point(code_point, &mut buffer));
+    }
+    StringRep::from_utf16(&code_units)
+}
+
+/// Implements [`String.prototype.toWellFormed`](https://tc39.es/ecma262/multipage/text-processing.html#sec-string.prototype.towellformed).
+fn to_well_formed(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    let mut code_units = Vec::with_capacity(s.len());
+    for result in char::decode_utf16(s.code_units()) {
+        let c = result.unwrap_or(char::REPLACEMENT_CHARACTER);
+        code_units.extend_from_slice(c.encode_utf16(&mut [0; 2]));
+    }
+    Ok(Value::String(StringRep::from_utf16(&code_units)))
+}
+
+/// Implements [TrimString](https://tc39.es/ecma262/multipage/text-processing.html#sec-trimstring).
+fn trim(this: &Value, placement: Placement) -> CoreResult<Value> {
+    let s = this_string(this)?;
+    let is_whitespace = |unit: &u16| char::from_u32(*unit as u32).is_some_and(is_str_whitespace);
+    let mut start = 0;
+    let mut end = s.len();
+    if placement != Placement::End {
+        start = s.code_units().take_while(is_whitespace).count();
+    }
+    if placement != Placement::Start {
+        end -= s
+            .code_units()
+            .skip(start)
+            .rev()
+            .take_while(is_whitespace)
+            .count();
+    }
+    Ok(Value::String(s.substring(start, end)))
+}
+
+/// Implements [CreateStringIterator](https://tc39.es/ecma262/multipage/text-processing.html#sec-createstringiterator), keeping the iteration state in slots instead of a generator.
+fn create_string_iterator(s: StringRep) -> ObjectRep {
+    let prototype = e262_current_realm().intrinsic(Intrinsic::StringIteratorPrototype);
+    let iterator = e262_ordinary_object_create(Some(prototype));
+    let slots = iterator.0.clone();
+    slots
+        .clone()
+        .set_slot("IteratedString".to_string(), Rc::new(RefCell::new(Some(s))));
+    slots.set_slot("StringNextIndex".to_string(), Rc::new(Cell::new(0usize)));
+    iterator
+}
+
+/// Implements [`%StringIteratorPrototype%.next`](https://tc39.es/ecma262/multipage/text-processing.html#sec-%stringiteratorprototype%.next).
+fn string_iterator_next(this: &Value, _: &[Value]) -> CoreResult<Value> {
+    let slots = match this {
+        Value::Object(obj) => {
+            let s = p262_get_slot::<RefCell<Option<StringRep>>>(
+                obj.0.clone(),
+                "IteratedString".to_string(),
+            );
+            let index = p262_get_slot::<Cell<usize>>(obj.0.clone(), "StringNextIndex".to_string());
+            s.zip(index)
+        }
+        _ => None,
+    };
+    let Some((s, index)) = slots else {
+        return Err(CoreError::TypeError(
+            "Receiver is not a String Iterator".to_string(),
+        ));
+    };
+    let position = index.get();
+    let result = match s.borrow().as_ref() {
+        Some(s) if position < s.len() => {
+            let (_, code_unit_count) = code_point_at_index(s, position);
+            index.set(position + code_unit_count);
+            Some(s.substring(position, position + code_unit_count))
+        }
+        _ => None,
+    };
+    Ok(Value::Object(match result {
+        Some(result) => e262_create_iter_result_object(Value::String(result), false),
+        None => {
+            s.replace(None);
+            e262_create_iter_result_object(Value::Undefined, true)
+        }
+    }))
+}
+
+/// The [additional properties of `String.prototype`](https://tc39.es/ecma262/multipage/additional-ecmascript-features-for-web-browsers.html#sec-additional-properties-of-the-string.prototype-object) for web browsers.
+#[cfg(feature = "annex-b")]
+mod annex_b {
+    use super::*;
+    use crate::builtins::p262_define_value;
+
+    pub(super) fn p262_create_intrinsics(
+        realm: &Realm,
+        prototype: &ObjectRep,
+        trim_start: ObjectRep,
+        trim_end: ObjectRep,
+    ) {
+        p262_define_method(realm, prototype, "substr", 2, substr);
+        p262_define_value(prototype, "trimLeft", Value::Object(trim_start));
+        p262_define_value(prototype, "trimRight", Value::Object(trim_end));
+        for (name, tag, attribute) in [
+            ("anchor", "a", "name"),
+            ("big", "big", ""),
+            ("blink", "blink", ""),
+            ("bold", "b", ""),
+            ("fixed", "tt", ""),
+            ("fontcolor", "font", "color"),
+            ("fontsize", "font", "size"),
+            ("italics", "i", ""),
+            ("link", "a", "href"),
+            ("small", "small", ""),
+            ("strike", "strike", ""),
+            ("sub", "sub", ""),
+            ("sup", "sup", ""),
+        ] {
+            let length = if attribute.is_empty() { 0 } else { 1 };
+            p262_define_method(realm, prototype, name, length, move |this, args| {
+                create_html(this, tag, attribute, &p262_argument(args, 0))
+            });
+        }
+    }
+
+    /// Implements [`String.prototype.substr`](https://tc39.es/ecma262/multipage/additional-ecmascript-features-for-web-browsers.html#sec-string.prototype.substr).
+    fn substr(this: &Value, arguments: &[Value]) -> CoreResult<Value> {
+        let s = this_string(this)?;
+        let size = s.len() as f64;
+        let start = p262_relative_index(&p262_argument(arguments, 0), size)?;
+        let length = match p262_argument(arguments, 1) {
+            Value::Undefined => size,
+            length => e262_to_integer_or_infinity(&length)?
+                .to_f64()
+                .clamp(0f64, size),
+        };
+        let end = (start + length).min(size);
+        if start >= end {
+            return Ok(Value::String(StringRep::empty()));
+        }
+        Ok(Value::String(s.substring(start as usize, end as usize)))
+    }
+
+    /// Implements [CreateHTML](https://tc39.es/ecma262/multipage/additional-ecmascript-features-for-web-browsers.html#sec-createhtml).
+    fn create_html(string: &Value, tag: &str, attribute: &str, value: &Value) -> CoreResult<Value> {
+        let s = this_string(string)?;
+        let mut p1 = StringRep::from(format!("<{tag}"));
+        if !attribute.is_empty() {
+            let v = e262_to_string(value)?;
+            let mut escaped_v = Vec::with_capacity(v.len());
+            for unit in v.code_units() {
+                match unit == b'"' as u16 {
+                    true => escaped_v.extend("&quot;".encode_utf16()),
+                    false => escaped_v.push(unit),
+                }
+            }
+            p1 = p1
+                .concat(&StringRep::from(format!(" {attribute}=\"")))
+                .concat(&StringRep::from_utf16(&escaped_v))
+                .concat(&StringRep::from("\""));
+        }
+        let result = p1
+            .concat(&StringRep::from(">"))
+            .concat(&s)
+            .concat(&StringRep::from(format!("</{tag}>")));
+        Ok(Value::String(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::function::e262_construct;
+    use crate::core::{e262_create_builtin_function, e262_get_iterator, e262_iterator_step_value};
+
+    fn str(value: &str) -> Value {
+        Value::String(value.into())
+    }
+
+    fn invoke<const N: usize>(
+        this: &str,
+        method: &str,
+        arguments: [Value; N],
+    ) -> CoreResult<Value> {
+        e262_invoke(&str(this), &PropertyKey::from(method), &arguments)
+    }
+
+    fn call<const N: usize>(method: &str, arguments: [Value; N]) -> CoreResult<Value> {
+        let constructor = Value::Object(e262_current_realm().intrinsic(Intrinsic::String));
+        e262_invoke(&constructor, &PropertyKey::from(method), &arguments)
+    }
+
+    #[test]
+    fn constructor_converts_and_wraps() {
+        let constructor = e262_current_realm().intrinsic(Intrinsic::String);
+        let symbol = Value::Symbol(SymbolRep::named("tag"));
+        let called = e262_call(
+            &Value::Object(constructor.clone()),
+            &Value::Undefined,
+            std::slice::from_ref(&symbol),
+        );
+        assert_eq!(called.unwrap(), str("Symbol(tag)"));
+        assert!(e262_construct(&constructor, &[symbol], None).is_err());
+        let wrapper = e262_construct(&constructor, &[Value::Number(12f64)], None).unwrap();
+        assert_eq!(
+            e262_get(&wrapper, &PropertyKey::from("length")).unwrap(),
+            Value::Number(2f64)
+        );
+        let value_of = e262_invoke(&Value::Object(wrapper), &PropertyKey::from("valueOf"), &[]);
+        assert_eq!(value_of.unwrap(), str("12"));
+        let to_string = e262_get(
+            &e262_current_realm().intrinsic(Intrinsic::StringPrototype),
+            &PropertyKey::from("toString"),
+        )
+        .unwrap();
+        assert!(e262_call(&to_string, &Value::Number(1f64), &[]).is_err());
+    }
+
+    #[test]
+    fn static_methods_build_strings() {
+        let from_char_code = call(
+            "fromCharCode",
+            [
+                Value::Number(65601f64),
+                Value::Number(0xD83D as f64),
+                Value::Number(0xDE00 as f64),
+            ],
+        );
+        assert_eq!(from_char_code.unwrap(), str("A\u{1F600}"));
+        assert_eq!(
+            call("fromCodePoint", [Value::Number(0x1F600 as f64)]).unwrap(),
+            str("\u{1F600}")
+        );
+        assert!(matches!(
+            call("fromCodePoint", [Value::Number(1.5f64)]),
+            Err(CoreError::RangeError(_))
+        ));
+        assert!(matches!(
+            call("fromCodePoint", [Value::Number(0x110000 as f64)]),
+            Err(CoreError::RangeError(_))
+        ));
+
+        let template = e262_ordinary_object_create(None);
+        let raw = e262_create_array_from_list(&[str("a"), str("b"), str("c")]);
+        crate::core::e262_set(
+            &template,
+            &PropertyKey::from("raw"),
+            Value::Object(raw),
+            true,
+        )
+        .unwrap();
+        let result = call(
+            "raw",
+            [
+                Value::Object(template),
+                Value::Number(1f64),
+                Value::Number(2f64),
+                Value::Number(3f64),
+            ],
+        );
+        assert_eq!(result.unwrap(), str("a1b2c"));
+    }
+
+    #[test]
+    fn accessors_work_on_code_units() {
+        assert_eq!(
+            invoke("abc", "at", [Value::Number(-1f64)]).unwrap(),
+            str("c")
+        );
+        assert_eq!(
+            invoke("abc", "charAt", [Value::Number(3f64)]).unwrap(),
+            str("")
+        );
+        assert_eq!(
+            invoke("\u{1F600}", "charCodeAt", [Value::Number(1f64)]).unwrap(),
+            Value::Number(0xDE00 as f64)
+        );
+        assert_eq!(
+            invoke("\u{1F600}", "codePointAt", [Value::Number(0f64)]).unwrap(),
+            Value::Number(0x1F600 as f64)
+        );
+        assert_eq!(
+            invoke("\u{1F600}", "codePointAt", [Value::Number(1f64)]).unwrap(),
+            Value::Number(0xDE00 as f64)
+        );
+        assert_eq!(
+            invoke("abc", "codePointAt", [Value::Number(5f64)]).unwrap(),
+            Value::Undefined
+        );
+        assert_eq!(
+            invoke("ab", "concat", [Value::Number(1f64), Value::Null]).unwrap(),
+            str("ab1null")
+        );
+    }
+
+    #[test]
+    fn searches_respect_positions() {
+        assert_eq!(
+            invoke("abcabc", "indexOf", [str("c"), Value::Number(3f64)]).unwrap(),
+            Value::Number(5f64)
+        );
+        assert_eq!(
+            invoke("abcabc", "lastIndexOf", [str("a")]).unwrap(),
+            Value::Number(3f64)
+        );
+        assert_eq!(
+            invoke("abcabc", "lastIndexOf", [str("a"), Value::Number(2f64)]).unwrap(),
+            Value::Number(0f64)
+        );
+        assert_eq!(
+            invoke("abc", "lastIndexOf", [str("")]).unwrap(),
+            Value::Number(3f64)
+        );
+        assert_eq!(
+            invoke("abc", "includes", [str("bc"), Value::Number(2f64)]).unwrap(),
+            Value::Boolean(false)
+        );
+        assert_eq!(
+            invoke("abc", "startsWith", [str("bc"), Value::Number(1f64)]).unwrap(),
+            Value::Boolean(true)
+        );
+        assert_eq!(
+            invoke("abc", "endsWith", [str("ab"), Value::Number(2f64)]).unwrap(),
+            Value::Boolean(true)
+        );
+        assert_eq!(
+            invoke("abc", "endsWith", [str("abcd")]).unwrap(),
+            Value::Boolean(false)
+        );
+
+        let regexp_like = e262_ordinary_object_create(None);
+        let key = PropertyKey::from(SymbolRep::well_known(WellKnownSymbol::Match));
+        crate::core::e262_set(&regexp_like, &key, Value::Boolean(true), true).unwrap();
+        assert!(matches!(
+            invoke("abc", "startsWith", [Value::Object(regexp_like)]),
+            Err(CoreError::TypeError(_))
+        ));
+    }
+
+    #[test]
+    fn slicing_and_padding() {
+        assert_eq!(
+            invoke(
+                "abcdef",
+                "slice",
+                [Value::Number(-3f64), Value::Number(-1f64)]
+            )
+            .unwrap(),
+            str("de")
+        );
+        assert_eq!(
+            invoke(
+                "abcdef",
+                "substring",
+                [Value::Number(4f64), Value::Number(1f64)]
+            )
+            .unwrap(),
+            str("bcd")
+        );
+        assert_eq!(
+            invoke("abc", "padStart", [Value::Number(7f64), str("12")]).unwrap(),
+            str("1212abc")
+        );
+        assert_eq!(
+            invoke("abc", "padEnd", [Value::Number(5f64)]).unwrap(),
+            str("abc  ")
+        );
+        assert_eq!(
+            invoke("ab", "repeat", [Value::Number(3f64)]).unwrap(),
+            str("ababab")
+        );
+        assert!(matches!(
+            invoke("ab", "repeat", [Value::Number(-1f64)]),
+            Err(CoreError::RangeError(_))
+        ));
+        assert!(matches!(
+            invoke("ab", "repeat", [Value::Number(f64::INFINITY)]),
+            Err(CoreError::RangeError(_))
+        ));
+        assert!(matches!(
+            invoke("ab", "repeat", [Value::Number(1e10f64)]),
+            Err(CoreError::RangeError(_))
+        ));
+        assert_eq!(
+            invoke("", "repeat", [Value::Number(1e10f64)]).unwrap(),
+            str("")
+        );
+        assert_eq!(invoke(" \u{FEFF}a b\n", "trim", []).unwrap(), str("a b"));
+        assert_eq!(invoke(" a ", "trimStart", []).unwrap(), str("a "));
+        assert_eq!(invoke(" a ", "trimEnd", []).unwrap(), str(" a"));
+    }
+
+    #[test]
+    fn replace_and_split_with_strings() {
+        assert_eq!(
+            invoke("aXbXc", "replace", [str("X"), str("[$&$`$'$$$1]")]).unwrap(),
+            str("a[XabXc$$1]bXc")
+        );
+        assert_eq!(
+            invoke("aXbXc", "replaceAll", [str("X"), str("-")]).unwrap(),
+            str("a-b-c")
+        );
+        assert_eq!(
+            invoke("abc", "replaceAll", [str(""), str("-")]).unwrap(),
+            str("-a-b-c-")
+        );
+        let upper = Value::Object(e262_create_builtin_function(
+            Rc::new(|_, args| {
+                Ok(Value::String(to_case(
+                    &e262_to_string(&p262_argument(args, 0))?,
+                    Case::Upper,
+                )))
+            }),
+            1,
+            "",
+            None,
+        ));
+        assert_eq!(
+            invoke("abc", "replace", [str("b"), upper]).unwrap(),
+            str("aBc")
+        );
+
+        let parts = invoke("a,b,,c", "split", [str(","), Value::Number(3f64)]).unwrap();
+        let Value::Object(parts) = parts else {
+            unreachable!()
+        };
+        assert_eq!(e262_length_of_array_like(&parts).unwrap(), 3f64);
+        assert_eq!(e262_get(&parts, &PropertyKey::from(2)).unwrap(), str(""));
+        let chars = invoke("abc", "split", [str("")]).unwrap();
+        let Value::Object(chars) = chars else {
+            unreachable!()
+        };
+        assert_eq!(e262_length_of_array_like(&chars).unwrap(), 3f64);
+    }
+
+    #[test]
+    fn substitution_patterns() {
+        let captures = [str("1"), Value::Undefined];
+        let substitute = |template: &str| {
+            p262_get_substitution(
+                &"bc".into(),
+                &"abcd".into(),
+                1,
+                &captures,
+                &Value::Undefined,
+                &template.into(),
+            )
+            .unwrap()
+            .to_std_string_lossy()
+        };
+        assert_eq!(substitute("$1$2$3"), "1$3");
+        assert_eq!(substitute("$01$10"), "110");
+        assert_eq!(substitute("$<name>"), "$<name>");
+        assert_eq!(substitute("$"), "$");
+    }
+
+    #[test]
+    fn case_mapping_and_normalization() {
+        assert_eq!(
+            invoke("stra\u{DF}e", "toUpperCase", []).unwrap(),
+            str("STRASSE")
+        );
+        assert_eq!(
+            invoke("\u{130}", "toLowerCase", []).unwrap(),
+            str("i\u{307}")
+        );
+        assert_eq!(
+            invoke("\u{39F}\u{3A3}", "toLowerCase", []).unwrap(),
+            str("\u{3BF}\u{3C2}")
+        );
+        assert_eq!(
+            invoke("\u{A7CB}", "toLowerCase", []).unwrap(),
+            str("\u{264}")
+        );
+        let lone = Value::String(StringRep::from_utf16(&[0x61, 0xD800]));
+        let upper = e262_invoke(&lone, &PropertyKey::from("toUpperCase"), &[]).unwrap();
+        assert_eq!(upper, Value::String(StringRep::from_utf16(&[0x41, 0xD800])));
+        let well_formed = e262_invoke(&lone, &PropertyKey::from("toWellFormed"), &[]).unwrap();
+        assert_eq!(well_formed, str("a\u{FFFD}"));
+        assert_eq!(
+            e262_invoke(&lone, &PropertyKey::from("isWellFormed"), &[]).unwrap(),
+            Value::Boolean(false)
+        );
+
+        assert_eq!(invoke("e\u{301}", "normalize", []).unwrap(), str("\u{E9}"));
+        assert_eq!(
+            invoke("\u{E9}", "normalize", [str("NFD")]).unwrap(),
+            str("e\u{301}")
+        );
+        assert!(matches!(
+            invoke("a", "normalize", [str("nfc")]),
+            Err(CoreError::RangeError(_))
+        ));
+        assert_eq!(
+            invoke("e\u{301}", "localeCompare", [str("\u{E9}")]).unwrap(),
+            Value::Number(0f64)
+        );
+        assert_eq!(
+            invoke("a", "localeCompare", [str("b")]).unwrap(),
+            Value::Number(-1f64)
+        );
+    }
+
+    #[test]
+    fn iterates_by_code_point() {
+        let mut record = e262_get_iterator(&str("a\u{1F600}b")).unwrap();
+        let mut values = Vec::new();
+        while let Some(value) = e262_iterator_step_value(&mut record).unwrap() {
+            values.push(value);
+        }
+        assert_eq!(values, vec![str("a"), str("\u{1F600}"), str("b")]);
+    }
+
+    #[cfg(feature = "annex-b")]
+    #[test]
+    fn annex_b_methods() {
+        assert_eq!(
+            invoke(
+                "abcdef",
+                "substr",
+                [Value::Number(-3f64), Value::Number(2f64)]
+            )
+            .unwrap(),
+            str("de")
+        );
+        assert_eq!(
+            invoke("a\"b", "anchor", [str("x\"y")]).unwrap(),
+            str("<a name=\"x&quot;y\">a\"b</a>")
+        );
+        assert_eq!(invoke("a", "bold", []).unwrap(), str("<b>a</b>"));
+        let prototype = e262_current_realm().intrinsic(Intrinsic::StringPrototype);
+        let trim_left = e262_get(&prototype, &PropertyKey::from("trimLeft")).unwrap();
+        let trim_start = e262_get(&prototype, &PropertyKey::from("trimStart")).unwrap();
+        assert_eq!(trim_left, trim_start);
+    }
+}
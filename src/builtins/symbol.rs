@@ -0,0 +1,220 @@
+use std::rc::Rc;
+
+use super::{
+    p262_argument, p262_define_constant, p262_define_getter, p262_define_method,
+    p262_link_constructor,
+};
+use crate::agent::{e262_key_for_symbol, p262_symbol_for};
+use crate::core::cast::e262_to_string;
+use crate::core::{
+    e262_create_builtin_function, e262_define_property_or_throw, p262_get_slot, Descriptor,
+    PropertyKey, SymbolRep, Value, WellKnownSymbol,
+};
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::{Intrinsic, Realm};
+
+/// Creates `%Symbol%` and the methods of `%Symbol.prototype%`.
+///
+/// `%Symbol%` has no `[[Construct]]`, so `new Symbol()` throws a TypeError.
+pub(crate) fn p262_create_intrinsics(realm: &Realm) {
+    let prototype = realm.intrinsic(Intrinsic::SymbolPrototype);
+    let constructor = e262_create_builtin_function(
+        Rc::new(symbol),
+        0,
+        "Symbol",
+        Some(realm.intrinsic(Intrinsic::FunctionPrototype)),
+    );
+    p262_link_constructor(&constructor, &prototype);
+    realm.set_intrinsic(Intrinsic::Symbol, constructor.clone());
+
+    for well_known in WellKnownSymbol::ALL {
+        p262_define_constant(
+            &constructor,
+            well_known.name(),
+            Value::Symbol(SymbolRep::well_known(well_known)),
+        );
+    }
+    p262_define_method(realm, &constructor, "for", 1, |_, arguments| {
+        let key = e262_to_string(&p262_argument(arguments, 0))?;
+        Ok(Value::Symbol(p262_symbol_for(key)))
+    });
+    p262_define_method(
+        realm,
+        &constructor,
+        "keyFor",
+        1,
+        |_, arguments| match p262_argument(arguments, 0) {
+            Value::Symbol(sym) => Ok(e262_key_for_symbol(&sym)
+                .map(Value::String)
+                .unwrap_or(Value::Undefined)),
+            _ => Err(CoreError::TypeError(
+                "Symbol.keyFor argument is not a symbol".to_string(),
+            )),
+        },
+    );
+
+    p262_define_getter(realm, &prototype, "description", |this, _| {
+        let sym = this_symbol_value(this)?;
+        Ok(sym
+            .description()
+            .cloned()
+            .map(Value::String)
+            .unwrap_or(Value::Undefined))
+    });
+    p262_define_method(realm, &prototype, "toString", 0, |this, _| {
+        Ok(Value::String(this_symbol_value(this)?.descriptive_string()))
+    });
+    p262_define_method(realm, &prototype, "valueOf", 0, |this, _| {
+        Ok(Value::Symbol(this_symbol_value(this)?))
+    });
+    let to_primitive = SymbolRep::well_known(WellKnownSymbol::ToPrimitive);
+    p262_define_method(realm, &prototype, to_primitive.clone(), 1, |this, _| {
+        Ok(Value::Symbol(this_symbol_value(this)?))
+    });
+    // Unlike other methods, @@toPrimitive is not writable.
+    e262_define_property_or_throw(
+        &prototype,
+        PropertyKey::from(to_primitive),
+        Descriptor {
+            writable: Some(false),
+            ..Default::default()
+        },
+    )
+    .expect("Built-in properties can always be defined");
+    let to_string_tag = SymbolRep::well_known(WellKnownSymbol::ToStringTag);
+    e262_define_property_or_throw(
+        &prototype,
+        PropertyKey::from(to_string_tag),
+        Descriptor {
+            value: Some(Rc::new(Value::String("Symbol".into()))),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Built-in properties can always be defined");
+}
+
+/// Implements [`Symbol`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-symbol-description).
+fn symbol(_: &Value, arguments: &[Value]) -> CoreResult<Value> {
+    let description = match p262_argument(arguments, 0) {
+        Value::Undefined => None,
+        description => Some(e262_to_string(&description)?),
+    };
+    Ok(Value::Symbol(SymbolRep::new(description)))
+}
+
+/// Implements [thisSymbolValue](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-thissymbolvalue).
+fn this_symbol_value(value: &Value) -> CoreResult<SymbolRep> {
+    match value {
+        Value::Symbol(sym) => Ok(sym.clone()),
+        Value::Object(obj) => p262_get_slot::<SymbolRep>(obj.0.clone(), "SymbolData".to_string())
+            .map(|sym| (*sym).clone())
+            .ok_or_else(|| CoreError::TypeError("Receiver is not a Symbol".to_string())),
+        _ => Err(CoreError::TypeError("Receiver is not a Symbol".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::e262_current_realm;
+    use crate::core::cast::e262_to_object;
+    use crate::core::function::{e262_call, e262_construct};
+    use crate::core::{e262_get, e262_invoke};
+
+    fn constructor() -> Value {
+        Value::Object(e262_current_realm().intrinsic(Intrinsic::Symbol))
+    }
+
+    fn invoke<const N: usize>(
+        this: &Value,
+        name: &str,
+        arguments: [Value; N],
+    ) -> CoreResult<Value> {
+        e262_invoke(this, &PropertyKey::from(name), &arguments)
+    }
+
+    #[test]
+    fn creates_unique_symbols() {
+        let a = e262_call(
+            &constructor(),
+            &Value::Undefined,
+            &[Value::String("a".into())],
+        )
+        .unwrap();
+        let b = e262_call(
+            &constructor(),
+            &Value::Undefined,
+            &[Value::String("a".into())],
+        )
+        .unwrap();
+        assert_ne!(a, b);
+        assert_eq!(
+            invoke(&a, "toString", []).unwrap(),
+            Value::String("Symbol(a)".into())
+        );
+        assert_eq!(
+            e262_get(
+                &e262_to_object(&a).unwrap(),
+                &PropertyKey::from("description")
+            )
+            .unwrap(),
+            Value::String("a".into())
+        );
+        let anon = e262_call(&constructor(), &Value::Undefined, &[]).unwrap();
+        assert_eq!(
+            e262_get(
+                &e262_to_object(&anon).unwrap(),
+                &PropertyKey::from("description")
+            )
+            .unwrap(),
+            Value::Undefined
+        );
+        let Value::Object(ctor) = constructor() else {
+            unreachable!()
+        };
+        assert!(matches!(
+            e262_construct(&ctor, &[], None),
+            Err(CoreError::TypeError(_))
+        ));
+    }
+
+    #[test]
+    fn uses_the_registry() {
+        let key = Value::String("app.key".into());
+        let a = invoke(&constructor(), "for", [key.clone()]).unwrap();
+        let b = invoke(&constructor(), "for", [key.clone()]).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(invoke(&constructor(), "keyFor", [a]).unwrap(), key);
+        let unregistered = e262_call(&constructor(), &Value::Undefined, &[key]).unwrap();
+        assert_eq!(
+            invoke(&constructor(), "keyFor", [unregistered]).unwrap(),
+            Value::Undefined
+        );
+        assert!(matches!(
+            invoke(&constructor(), "keyFor", [Value::String("x".into())]),
+            Err(CoreError::TypeError(_))
+        ));
+    }
+
+    #[test]
+    fn exposes_well_known_symbols() {
+        let Value::Object(ctor) = constructor() else {
+            unreachable!()
+        };
+        assert_eq!(
+            e262_get(&ctor, &PropertyKey::from("iterator")).unwrap(),
+            Value::Symbol(SymbolRep::well_known(WellKnownSymbol::Iterator))
+        );
+        let sym = Value::Symbol(SymbolRep::named("x"));
+        let wrapper = Value::Object(e262_to_object(&sym).unwrap());
+        assert_eq!(invoke(&wrapper, "valueOf", []).unwrap(), sym);
+        let to_primitive = SymbolRep::well_known(WellKnownSymbol::ToPrimitive);
+        assert_eq!(
+            e262_invoke(&wrapper, &PropertyKey::from(to_primitive), &[]).unwrap(),
+            sym
+        );
+    }
+}
@@ -1,16 +1,22 @@
 //! A module implementing ES [completion records](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-completion-record-specification-type).
 //!
 //! Native code typically return [`CoreResult`], that can be converted into a [`Completion`] via the [`From`] trait: [`Ok`] are converted into [`Normal`](Completion::Normal) completions, while [`Err`] are converted into [`Throw`](Completion::Throw) completions.
+//!
+//! [`Completion`] implements the [`Try`] trait, so that `?` is the spec's [ReturnIfAbrupt](https://tc39.es/ecma262/multipage/notational-conventions.html#sec-returnifabrupt) shorthand: it unwraps normal completions and returns abrupt ones. It can be applied to both [`Completion`] and [`CoreResult`] in a function returning a [`Completion`], including a completion of another type whose value converts into the returned one (e.g. a `Completion<Value>` in a function returning a `Completion<Option<Value>>`).
+
+use std::convert::Infallible;
+use std::ops::{ControlFlow, FromResidual, Residual, Try};
 
 use crate::errors::{CoreError, CoreResult};
 
 /// A [completion](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-completion-record-specification-type) record.
 ///
 /// It can be created from a [`CoreResult`] via the [`From`] trait: [`Ok`] are converted into [`Normal`](Self::Normal) completions, while [`Err`] are converted into [`Throw`](Self::Throw) completions.
+#[derive(Debug)]
 pub enum Completion<T> {
     /// A normal completion.
     Normal(T),
-    /// A throw completion, related to the `throw` keyword.
+    /// A throw completion, related to the `throw` keyword, carrying the thrown value as a [`CoreError`].
     Throw(CoreError),
     /// A return completion, related to the `return` keyword.
     Return(T),
@@ -20,6 +26,65 @@ pub enum Completion<T> {
     Continue(Option<String>),
 }
 
+/// An [abrupt completion](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-completion-record-specification-type), i.e. any [`Completion`] but a normal one.
+///
+/// It is the residual of applying `?` to a [`Completion`], and converts into any [`Completion`] whose value type its return value converts into.
+#[derive(Debug)]
+pub enum Abrupt<T> {
+    /// A throw completion.
+    Throw(CoreError),
+    /// A return completion.
+    Return(T),
+    /// A break completion.
+    Break(Option<String>),
+    /// A continue completion.
+    Continue(Option<String>),
+}
+
+/// Implements [NormalCompletion](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-normalcompletion).
+pub fn e262_normal_completion<T>(value: T) -> Completion<T> {
+    Completion::Normal(value)
+}
+
+/// Implements [ThrowCompletion](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-throwcompletion).
+pub fn e262_throw_completion<T>(error: impl Into<CoreError>) -> Completion<T> {
+    Completion::Throw(error.into())
+}
+
+impl<T> Completion<T> {
+    /// Returns if the completion is abrupt, i.e. not [`Normal`](Self::Normal).
+    pub fn is_abrupt(&self) -> bool {
+        !matches!(self, Self::Normal(_))
+    }
+
+    /// Returns the value of a normal completion, implementing the `!` prefix of the spec's [shorthands](https://tc39.es/ecma262/multipage/notational-conventions.html#sec-returnifabrupt-shorthands).
+    ///
+    /// # Panics
+    ///
+    /// Panics with the given spec `step` if the completion is abrupt, as the spec asserts it cannot be.
+    #[track_caller]
+    pub fn assert_normal(self, step: &str) -> T {
+        match self {
+            Self::Normal(value) => value,
+            Self::Throw(err) => panic!("{step}: unexpected throw completion {err:?}"),
+            _ => panic!("{step}: unexpected abrupt completion"),
+        }
+    }
+}
+
+impl<T> Completion<Option<T>> {
+    /// Implements [UpdateEmpty](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-updateempty), where [`None`] is the empty value.
+    ///
+    /// Break and continue completions carry no value in this implementation, so they are returned unchanged.
+    pub fn update_empty(self, value: Option<T>) -> Self {
+        match self {
+            Self::Normal(None) => Self::Normal(value),
+            Self::Return(None) => Self::Return(value),
+            completion => completion,
+        }
+    }
+}
+
 impl<T> From<CoreResult<T>> for Completion<T> {
     fn from(result: CoreResult<T>) -> Self {
         match result {
@@ -28,3 +93,135 @@ impl<T> From<CoreResult<T>> for Completion<T> {
         }
     }
 }
+
+impl<T> Try for Completion<T> {
+    type Output = T;
+    type Residual = Abrupt<T>;
+
+    fn from_output(output: T) -> Self {
+        Self::Normal(output)
+    }
+
+    fn branch(self) -> ControlFlow<Abrupt<T>, T> {
+        match self {
+            Self::Normal(value) => ControlFlow::Continue(value),
+            Self::Throw(err) => ControlFlow::Break(Abrupt::Throw(err)),
+            Self::Return(value) => ControlFlow::Break(Abrupt::Return(value)),
+            Self::Break(label) => ControlFlow::Break(Abrupt::Break(label)),
+            Self::Continue(label) => ControlFlow::Break(Abrupt::Continue(label)),
+        }
+    }
+}
+
+impl<T> Residual<T> for Abrupt<T> {
+    type TryType = Completion<T>;
+}
+
+impl<T, U: Into<T>> FromResidual<Abrupt<U>> for Completion<T> {
+    fn from_residual(residual: Abrupt<U>) -> Self {
+        match residual {
+            Abrupt::Throw(err) => Self::Throw(err),
+            Abrupt::Return(value) => Self::Return(value.into()),
+            Abrupt::Break(label) => Self::Break(label),
+            Abrupt::Continue(label) => Self::Continue(label),
+        }
+    }
+}
+
+impl<T> FromResidual<Result<Infallible, CoreError>> for Completion<T> {
+    fn from_residual(residual: Result<Infallible, CoreError>) -> Self {
+        match residual {
+            Err(err) => Self::Throw(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Value;
+
+    fn add_one(completion: Completion<f64>) -> Completion<f64> {
+        let value = completion?;
+        Completion::Normal(value + 1f64)
+    }
+
+    fn check(result: CoreResult<f64>) -> Completion<f64> {
+        let value = result?;
+        e262_normal_completion(value)
+    }
+
+    fn statement(expression: Completion<Value>) -> Completion<Option<Value>> {
+        let value = expression?;
+        Completion::Normal(Some(value))
+    }
+
+    #[test]
+    fn returns_if_abrupt() {
+        assert!(matches!(
+            add_one(Completion::Normal(1f64)),
+            Completion::Normal(2f64)
+        ));
+        assert!(matches!(
+            add_one(Completion::Return(1f64)),
+            Completion::Return(1f64)
+        ));
+        assert!(matches!(
+            add_one(Completion::Break(Some("outer".to_string()))),
+            Completion::Break(Some(label)) if label == "outer"
+        ));
+        assert!(matches!(
+            add_one(e262_throw_completion(Value::Number(1f64))),
+            Completion::Throw(CoreError::Throw(Value::Number(_)))
+        ));
+        assert!(matches!(check(Ok(3f64)), Completion::Normal(3f64)));
+        assert!(matches!(
+            check(Err(CoreError::TypeError("x".to_string()))),
+            Completion::Throw(CoreError::TypeError(_))
+        ));
+    }
+
+    #[test]
+    fn returns_if_abrupt_across_completion_types() {
+        assert!(matches!(
+            statement(Completion::Normal(Value::Null)),
+            Completion::Normal(Some(Value::Null))
+        ));
+        assert!(matches!(
+            statement(Completion::Return(Value::Number(1f64))),
+            Completion::Return(Some(Value::Number(_)))
+        ));
+        assert!(matches!(
+            statement(Completion::Continue(None)),
+            Completion::Continue(None)
+        ));
+        assert!(matches!(
+            statement(e262_throw_completion(Value::Null)),
+            Completion::Throw(CoreError::Throw(Value::Null))
+        ));
+    }
+
+    #[test]
+    fn updates_empty_values() {
+        let value = Some(Value::Number(1f64));
+        assert!(matches!(
+            Completion::Normal(None).update_empty(value.clone()),
+            Completion::Normal(Some(Value::Number(_)))
+        ));
+        assert!(matches!(
+            Completion::Normal(Some(Value::Null)).update_empty(value.clone()),
+            Completion::Normal(Some(Value::Null))
+        ));
+        assert!(matches!(
+            Completion::Continue(None).update_empty(value),
+            Completion::Continue(None)
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "step 3")]
+    fn asserts_normal_completions() {
+        assert_eq!(Completion::Normal(1).assert_normal("step 2"), 1);
+        Completion::<i32>::Throw(CoreError::RangeError("x".to_string())).assert_normal("step 3");
+    }
+}
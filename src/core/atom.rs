@@ -0,0 +1,140 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::{Rc, Weak};
+
+use super::StringRep;
+
+thread_local! {
+    static ATOMS: RefCell<HashMap<StringRep, Weak<AtomData>>> = RefCell::new(HashMap::new());
+}
+
+struct AtomData(StringRep);
+
+impl Drop for AtomData {
+    fn drop(&mut self) {
+        // The table may already be gone if the thread is exiting.
+        let _ = ATOMS.try_with(|atoms| {
+            atoms.borrow_mut().remove(&self.0);
+        });
+    }
+}
+
+/// An interned string, used as a [property key](super::PropertyKey).
+///
+/// Two atoms with the same contents are the same allocation, so comparing and hashing atoms are pointer operations. The atom table only keeps weak references, so an atom is reclaimed as soon as no key refers to it anymore.
+#[derive(Clone)]
+pub struct Atom(Rc<AtomData>);
+
+impl Atom {
+    /// Returns the atom for `string`, interning it if needed.
+    pub(crate) fn new(string: &StringRep) -> Self {
+        ATOMS.with(|atoms| {
+            let mut atoms = atoms.borrow_mut();
+            if let Some(atom) = atoms.get(string).and_then(Weak::upgrade) {
+                return Atom(atom);
+            }
+            let atom = Rc::new(AtomData(string.clone()));
+            atoms.insert(string.clone(), Rc::downgrade(&atom));
+            Atom(atom)
+        })
+    }
+
+    /// Returns the interned string.
+    pub fn as_string(&self) -> &StringRep {
+        &self.0 .0
+    }
+}
+
+impl PartialEq for Atom {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Atom {}
+
+impl Hash for Atom {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(Rc::as_ptr(&self.0) as usize);
+    }
+}
+
+impl fmt::Debug for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_string(), f)
+    }
+}
+
+impl fmt::Display for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_string(), f)
+    }
+}
+
+/// Returns the number of live atoms.
+pub(crate) fn p262_atom_count() -> usize {
+    ATOMS.with(|atoms| atoms.borrow().len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn are_interned() {
+        let a = Atom::new(&"atom_are_interned".into());
+        let b = Atom::new(&"atom_are_interned".into());
+        let c = Atom::new(&"atom_are_interned_too".into());
+
+        assert_eq!(a, b);
+        assert!(Rc::ptr_eq(&a.0, &b.0));
+        assert_ne!(a, c);
+        assert_eq!(*a.as_string(), "atom_are_interned");
+    }
+
+    #[test]
+    fn are_reclaimed() {
+        let before = p262_atom_count();
+        let atom = Atom::new(&"atom_are_reclaimed".into());
+        let copy = atom.clone();
+        assert_eq!(p262_atom_count(), before + 1);
+
+        drop(atom);
+        assert_eq!(p262_atom_count(), before + 1);
+        drop(copy);
+        assert_eq!(p262_atom_count(), before);
+    }
+
+    #[test]
+    fn keys_use_indices_or_atoms() {
+        use crate::core::PropertyKey;
+
+        assert_eq!(PropertyKey::from("42"), PropertyKey::Index(42));
+        assert_eq!(PropertyKey::from(42), PropertyKey::Index(42));
+        assert!(matches!(PropertyKey::from("042"), PropertyKey::String(_)));
+        assert!(matches!(
+            PropertyKey::from("4294967295"),
+            PropertyKey::String(_)
+        ));
+        assert_eq!(PropertyKey::from(u32::MAX), PropertyKey::from("4294967295"));
+        assert_eq!(
+            PropertyKey::from("4294967294"),
+            PropertyKey::Index(u32::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn are_reclaimed_with_dead_objects() {
+        use crate::core::{e262_create_data_property, e262_ordinary_object_create, Value};
+
+        let before = p262_atom_count();
+        let obj = e262_ordinary_object_create(None);
+        e262_create_data_property(&obj, "atom_dead_object".into(), Value::Null).unwrap();
+        assert_eq!(p262_atom_count(), before + 1);
+
+        drop(obj);
+        assert_eq!(p262_atom_count(), before);
+    }
+}
@@ -1,10 +1,321 @@
+//! The [BigInt type](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint) operations.
+//!
+//! Operations that can throw return a [`CoreResult`], so embedders get the same errors as scripts.
+
 use num_bigint::BigInt;
+use num_traits::{FromPrimitive, One, Signed, ToPrimitive, Zero};
+
+use crate::errors::{CoreError, CoreResult};
+
+/// The maximum size, in bits, of a BigInt created by an operation.
+///
+/// Operations whose result would be larger throw a RangeError instead of exhausting the memory.
+pub const MAX_BIGINT_BITS: u64 = 1 << 30;
+
+fn too_large() -> CoreError {
+    CoreError::RangeError("Maximum BigInt size exceeded".to_string())
+}
+
+/// Implements [BigInt::unaryMinus](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-unaryMinus).
+pub fn e262_unary_minus(x: &BigInt) -> BigInt {
+    -x
+}
+
+/// Implements [BigInt::bitwiseNOT](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-bitwiseNOT).
+pub fn e262_bitwise_not(x: &BigInt) -> BigInt {
+    -x - 1
+}
+
+/// Implements [BigInt::exponentiate](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-exponentiate).
+pub fn e262_exponentiate(base: &BigInt, exponent: &BigInt) -> CoreResult<BigInt> {
+    if exponent.is_negative() {
+        return Err(CoreError::RangeError(
+            "Exponent must be non-negative".to_string(),
+        ));
+    }
+    if exponent.is_zero() {
+        return Ok(BigInt::one());
+    }
+    if base.is_zero() || base.is_one() {
+        return Ok(base.clone());
+    }
+    if *base == BigInt::from(-1) {
+        let odd = exponent.bit(0);
+        return Ok(if odd { base.clone() } else { BigInt::one() });
+    }
+    match exponent.to_u32() {
+        Some(exponent) if base.bits().saturating_mul(exponent as u64) <= MAX_BIGINT_BITS => {
+            Ok(base.pow(exponent))
+        }
+        _ => Err(too_large()),
+    }
+}
+
+/// Implements [BigInt::multiply](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-multiply).
+pub fn e262_multiply(x: &BigInt, y: &BigInt) -> BigInt {
+    x * y
+}
+
+/// Implements [BigInt::divide](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-divide), rounding towards zero.
+pub fn e262_divide(x: &BigInt, y: &BigInt) -> CoreResult<BigInt> {
+    if y.is_zero() {
+        Err(CoreError::RangeError("Division by zero".to_string()))
+    } else {
+        Ok(x / y)
+    }
+}
+
+/// Implements [BigInt::remainder](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-remainder), whose result has the sign of the dividend.
+pub fn e262_remainder(n: &BigInt, d: &BigInt) -> CoreResult<BigInt> {
+    if d.is_zero() {
+        Err(CoreError::RangeError("Division by zero".to_string()))
+    } else {
+        Ok(n % d)
+    }
+}
+
+/// Implements [BigInt::add](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-add).
+pub fn e262_add(x: &BigInt, y: &BigInt) -> BigInt {
+    x + y
+}
+
+/// Implements [BigInt::subtract](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-subtract).
+pub fn e262_subtract(x: &BigInt, y: &BigInt) -> BigInt {
+    x - y
+}
+
+/// Implements [BigInt::leftShift](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-leftShift).
+///
+/// Negative shift counts shift to the right, rounding towards negative infinity.
+pub fn e262_left_shift(x: &BigInt, y: &BigInt) -> CoreResult<BigInt> {
+    if x.is_zero() {
+        return Ok(BigInt::zero());
+    }
+    if y.is_negative() {
+        return Ok(match (-y).to_u64() {
+            Some(shift) if shift < x.bits() => x >> shift,
+            _ if x.is_negative() => BigInt::from(-1),
+            _ => BigInt::zero(),
+        });
+    }
+    match y.to_u64() {
+        Some(shift) if x.bits().saturating_add(shift) <= MAX_BIGINT_BITS => Ok(x << shift),
+        _ => Err(too_large()),
+    }
+}
+
+/// Implements [BigInt::signedRightShift](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-signedRightShift).
+pub fn e262_signed_right_shift(x: &BigInt, y: &BigInt) -> CoreResult<BigInt> {
+    e262_left_shift(x, &-y)
+}
+
+/// Implements [BigInt::unsignedRightShift](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-unsignedRightShift), which always throws.
+pub fn e262_unsigned_right_shift(_x: &BigInt, _y: &BigInt) -> CoreResult<BigInt> {
+    Err(CoreError::TypeError(
+        "BigInts have no unsigned right shift, use >> instead".to_string(),
+    ))
+}
+
+/// Implements [BigInt::lessThan](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-lessThan).
+pub fn e262_less_than(x: &BigInt, y: &BigInt) -> bool {
+    x < y
+}
 
-pub(crate) fn e262_equal(x: &BigInt, y: &BigInt) -> bool {
+/// Implements [BigInt::equal](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-equal).
+pub fn e262_equal(x: &BigInt, y: &BigInt) -> bool {
     x.eq(y)
 }
 
+/// Implements [BigInt::bitwiseAND](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-bitwiseAND), with two's complement semantics.
+pub fn e262_bitwise_and(x: &BigInt, y: &BigInt) -> BigInt {
+    x & y
+}
+
+/// Implements [BigInt::bitwiseXOR](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-bitwiseXOR), with two's complement semantics.
+pub fn e262_bitwise_xor(x: &BigInt, y: &BigInt) -> BigInt {
+    x ^ y
+}
+
+/// Implements [BigInt::bitwiseOR](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-bitwiseOR), with two's complement semantics.
+pub fn e262_bitwise_or(x: &BigInt, y: &BigInt) -> BigInt {
+    x | y
+}
+
+/// Implements [BigInt::toString](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-bigint-tostring), for a `radix` in the range 2–36.
+pub fn e262_to_string(x: &BigInt, radix: u32) -> String {
+    x.to_str_radix(radix)
+}
+
+/// Returns the Number closest to `x`, i.e. 𝔽(ℝ(x)), rounding to infinity beyond the finite range.
+pub fn p262_to_number(x: &BigInt) -> f64 {
+    x.to_f64().unwrap_or(if x.is_negative() {
+        f64::NEG_INFINITY
+    } else {
+        f64::INFINITY
+    })
+}
+
+/// Implements [NumberToBigInt](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-numbertobigint), throwing a RangeError for NaN, infinities and fractions.
+pub fn e262_number_to_big_int(number: f64) -> CoreResult<BigInt> {
+    if !number.is_finite() || number.trunc() != number {
+        return Err(CoreError::RangeError(format!(
+            "Cannot convert {} into BigInt, as it is not an integer",
+            super::numbers::e262_to_string(number, 10)
+        )));
+    }
+    Ok(BigInt::from_f64(number).expect("Integral Numbers are exactly representable"))
+}
+
+/// Implements the core steps of [BigInt.asIntN](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-bigint.asintn), wrapping `bigint` into a signed integer of `bits` bits.
+pub fn e262_as_int_n(bits: u64, bigint: &BigInt) -> BigInt {
+    if bits == 0 {
+        return BigInt::zero();
+    }
+    if bits > bigint.bits() {
+        // The value already fits, including its sign bit.
+        return bigint.clone();
+    }
+    let modulo = BigInt::one() << bits;
+    let wrapped = modulo_pow2(bigint, bits);
+    if wrapped >= BigInt::one() << (bits - 1) {
+        wrapped - modulo
+    } else {
+        wrapped
+    }
+}
+
+/// Implements the core steps of [BigInt.asUintN](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-bigint.asuintn), wrapping `bigint` into an unsigned integer of `bits` bits.
+pub fn e262_as_uint_n(bits: u64, bigint: &BigInt) -> CoreResult<BigInt> {
+    if !bigint.is_negative() && bits >= bigint.bits() {
+        return Ok(bigint.clone());
+    }
+    if bits > MAX_BIGINT_BITS {
+        return Err(too_large());
+    }
+    Ok(modulo_pow2(bigint, bits))
+}
+
+/// Returns `value` modulo 2^`bits`, as a non-negative BigInt.
+fn modulo_pow2(value: &BigInt, bits: u64) -> BigInt {
+    let modulo = BigInt::one() << bits;
+    let remainder = value % &modulo;
+    if remainder.is_negative() {
+        remainder + modulo
+    } else {
+        remainder
+    }
+}
+
 #[inline(always)]
-pub(crate) fn is_zero(value: BigInt) -> bool {
-    value == BigInt::ZERO
+pub(crate) fn is_zero(value: &BigInt) -> bool {
+    *value == BigInt::ZERO
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(value: i64) -> BigInt {
+        BigInt::from(value)
+    }
+
+    #[test]
+    fn exponentiate_works() {
+        assert_eq!(e262_exponentiate(&big(2), &big(10)).unwrap(), big(1024));
+        assert_eq!(e262_exponentiate(&big(0), &big(0)).unwrap(), big(1));
+        assert_eq!(e262_exponentiate(&big(-1), &big(1 << 40)).unwrap(), big(1));
+        assert_eq!(
+            e262_exponentiate(&big(-1), &(big(1 << 40) + 1)).unwrap(),
+            big(-1)
+        );
+        assert!(matches!(
+            e262_exponentiate(&big(2), &big(-1)),
+            Err(CoreError::RangeError(_))
+        ));
+        assert!(matches!(
+            e262_exponentiate(&big(2), &big(1 << 40)),
+            Err(CoreError::RangeError(_))
+        ));
+    }
+
+    #[test]
+    fn division_truncates() {
+        assert_eq!(e262_divide(&big(7), &big(2)).unwrap(), big(3));
+        assert_eq!(e262_divide(&big(-7), &big(2)).unwrap(), big(-3));
+        assert_eq!(e262_remainder(&big(-7), &big(2)).unwrap(), big(-1));
+        assert_eq!(e262_remainder(&big(7), &big(-2)).unwrap(), big(1));
+        assert!(matches!(
+            e262_divide(&big(1), &big(0)),
+            Err(CoreError::RangeError(_))
+        ));
+        assert!(matches!(
+            e262_remainder(&big(1), &big(0)),
+            Err(CoreError::RangeError(_))
+        ));
+    }
+
+    #[test]
+    fn bitwise_uses_twos_complement() {
+        assert_eq!(e262_bitwise_not(&big(5)), big(-6));
+        assert_eq!(e262_bitwise_not(&big(-1)), big(0));
+        assert_eq!(e262_bitwise_and(&big(-1), &big(255)), big(255));
+        assert_eq!(e262_bitwise_and(&big(-8), &big(-3)), big(-8));
+        assert_eq!(e262_bitwise_or(&big(-8), &big(3)), big(-5));
+        assert_eq!(e262_bitwise_xor(&big(-1), &big(5)), big(-6));
+    }
+
+    #[test]
+    fn shifts_work() {
+        assert_eq!(e262_left_shift(&big(1), &big(64)).unwrap(), big(1) << 64);
+        assert_eq!(e262_left_shift(&big(-5), &big(-1)).unwrap(), big(-3));
+        assert_eq!(e262_signed_right_shift(&big(-5), &big(1)).unwrap(), big(-3));
+        assert_eq!(e262_signed_right_shift(&big(5), &big(1)).unwrap(), big(2));
+        assert_eq!(
+            e262_signed_right_shift(&big(-5), &big(1 << 40)).unwrap(),
+            big(-1)
+        );
+        assert_eq!(
+            e262_signed_right_shift(&big(5), &big(1 << 40)).unwrap(),
+            big(0)
+        );
+        assert!(matches!(
+            e262_left_shift(&big(1), &big(1 << 40)),
+            Err(CoreError::RangeError(_))
+        ));
+        assert!(matches!(
+            e262_unsigned_right_shift(&big(1), &big(1)),
+            Err(CoreError::TypeError(_))
+        ));
+    }
+
+    #[test]
+    fn as_int_n_works() {
+        assert_eq!(e262_as_int_n(8, &big(255)), big(-1));
+        assert_eq!(e262_as_int_n(8, &big(127)), big(127));
+        assert_eq!(e262_as_int_n(8, &big(-129)), big(127));
+        assert_eq!(e262_as_int_n(0, &big(5)), big(0));
+        assert_eq!(e262_as_int_n(64, &(big(1) << 63)), big(i64::MIN));
+        assert_eq!(e262_as_int_n(1 << 50, &big(-5)), big(-5));
+    }
+
+    #[test]
+    fn as_uint_n_works() {
+        assert_eq!(e262_as_uint_n(8, &big(-1)).unwrap(), big(255));
+        assert_eq!(e262_as_uint_n(8, &big(256)).unwrap(), big(0));
+        assert_eq!(
+            e262_as_uint_n(64, &big(-1)).unwrap(),
+            BigInt::from(u64::MAX)
+        );
+        assert_eq!(e262_as_uint_n(1 << 50, &big(5)).unwrap(), big(5));
+        assert!(matches!(
+            e262_as_uint_n(1 << 50, &big(-5)),
+            Err(CoreError::RangeError(_))
+        ));
+    }
+
+    #[test]
+    fn to_string_works() {
+        assert_eq!(e262_to_string(&big(-255), 16), "-ff");
+        assert_eq!(e262_to_string(&big(35), 36), "z");
+    }
 }
@@ -1,21 +1,55 @@
-use super::p262_has_slot;
-use super::Value;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::ToPrimitive;
+use std::rc::Rc;
+
+use super::function::e262_call;
+use super::test::e262_is_callable;
 use super::{bigint, numbers};
+use super::{e262_get, e262_get_method, e262_ordinary_object_create, e262_string_create};
+use super::{p262_has_slot, ObjectRep, PropertyKey, StringRep, SymbolRep, Value, WellKnownSymbol};
+use crate::agent::e262_current_realm;
 use crate::errors::{CoreError, CoreResult};
+use crate::realm::Intrinsic;
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub(crate) enum IntegerOrInfinity {
     NegativeInfinity,
     Integer(f64),
     PositiveInfinity,
 }
 
+impl IntegerOrInfinity {
+    /// Returns the mathematical value as a float, where infinities are represented by themselves.
+    pub(crate) fn to_f64(self) -> f64 {
+        match self {
+            Self::NegativeInfinity => f64::NEG_INFINITY,
+            Self::Integer(value) => value,
+            Self::PositiveInfinity => f64::INFINITY,
+        }
+    }
+}
+
+/// A [Numeric](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types) value, as returned by [ToNumeric](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-tonumeric).
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Numeric {
+    BigInt(BigInt),
+    Number(f64),
+}
+
+/// The `preferredType` hint of [ToPrimitive](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-toprimitive).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum PreferredType {
+    Number,
+    String,
+}
+
 pub(crate) fn e262_to_boolean(argument: &Value) -> bool {
     match argument {
         Value::Boolean(value) => *value,
         Value::Null | Value::Undefined => false,
         Value::Number(value) => !numbers::is_zero(*value) && !numbers::is_nan(*value),
-        Value::BigInt(value) => !bigint::is_zero(value.clone()),
-        Value::String(value) => value.len() > 0,
+        Value::BigInt(value) => !bigint::is_zero(value),
+        Value::String(value) => !value.is_empty(),
         Value::Symbol(_) => true,
         Value::Object(value) => {
             if cfg!(feature = "annex-b") {
@@ -61,7 +95,689 @@ pub(crate) fn e262_to_number(argument: &Value) -> CoreResult<f64> {
         Value::Undefined => Ok(f64::NAN),
         Value::Null | Value::Boolean(false) => Ok(0f64),
         Value::Boolean(true) => Ok(1f64),
-        Value::String(value) => Ok(value.parse::<f64>().unwrap_or(f64::NAN)), // @TODO
-        Value::Object(_) => Ok(1f64),                                         // @TODO
+        Value::String(value) => Ok(e262_string_to_number(&value.to_std_string_lossy())),
+        Value::Object(_) => {
+            let prim_value = e262_to_primitive(argument, Some(PreferredType::Number))?;
+            e262_to_number(&prim_value)
+        }
+    }
+}
+
+/// Returns if `c` is a [WhiteSpace](https://tc39.es/ecma262/multipage/ecmascript-language-lexical-grammar.html#prod-WhiteSpace) or a [LineTerminator](https://tc39.es/ecma262/multipage/ecmascript-language-lexical-grammar.html#prod-LineTerminator).
+pub(crate) fn is_str_whitespace(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0009}'
+            | '\u{000A}'
+            | '\u{000B}'
+            | '\u{000C}'
+            | '\u{000D}'
+            | '\u{0020}'
+            | '\u{00A0}'
+            | '\u{1680}'
+            | '\u{2000}'
+            ..='\u{200A}'
+                | '\u{2028}'
+                | '\u{2029}'
+                | '\u{202F}'
+                | '\u{205F}'
+                | '\u{3000}'
+                | '\u{FEFF}'
+    )
+}
+
+/// Parses a [NonDecimalIntegerLiteral](https://tc39.es/ecma262/multipage/ecmascript-language-lexical-grammar.html#prod-NonDecimalIntegerLiteral) without numeric separators.
+fn parse_non_decimal_integer(text: &str) -> Option<BigUint> {
+    let radix = match text.get(0..2)? {
+        "0x" | "0X" => 16,
+        "0o" | "0O" => 8,
+        "0b" | "0B" => 2,
+        _ => return None,
+    };
+    let digits = &text[2..];
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+    BigUint::parse_bytes(digits.as_bytes(), radix)
+}
+
+fn is_str_unsigned_decimal_literal(text: &str) -> bool {
+    if text == "Infinity" {
+        return true;
+    }
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(pos) => (&text[..pos], Some(&text[pos + 1..])),
+        None => (text, None),
+    };
+    let (int, frac) = match mantissa.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (mantissa, None),
+    };
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    let valid_mantissa = match frac {
+        None => !int.is_empty() && is_digits(int),
+        Some(frac) => is_digits(int) && is_digits(frac) && !(int.is_empty() && frac.is_empty()),
+    };
+    let valid_exponent = match exponent {
+        None => true,
+        Some(exponent) => {
+            let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+            !digits.is_empty() && is_digits(digits)
+        }
+    };
+    valid_mantissa && valid_exponent
+}
+
+pub(crate) fn e262_string_to_number(argument: &str) -> f64 {
+    let text = argument.trim_matches(is_str_whitespace);
+    if text.is_empty() {
+        return 0f64;
+    }
+    if let Some(value) = parse_non_decimal_integer(text) {
+        return value.to_f64().unwrap_or(f64::INFINITY);
+    }
+    let (negative, unsigned) = match text.as_bytes()[0] {
+        b'-' => (true, &text[1..]),
+        b'+' => (false, &text[1..]),
+        _ => (false, text),
+    };
+    if !is_str_unsigned_decimal_literal(unsigned) {
+        return f64::NAN;
+    }
+    let value = if unsigned == "Infinity" {
+        f64::INFINITY
+    } else {
+        unsigned.parse::<f64>().unwrap_or(f64::NAN)
+    };
+    if negative {
+        -value
+    } else {
+        value
+    }
+}
+
+pub(crate) fn e262_string_to_big_int(argument: &str) -> Option<BigInt> {
+    let text = argument.trim_matches(is_str_whitespace);
+    if text.is_empty() {
+        return Some(BigInt::ZERO);
+    }
+    if let Some(value) = parse_non_decimal_integer(text) {
+        return Some(BigInt::from_biguint(Sign::Plus, value));
+    }
+    let (sign, digits) = match text.as_bytes()[0] {
+        b'-' => (Sign::Minus, &text[1..]),
+        b'+' => (Sign::Plus, &text[1..]),
+        _ => (Sign::Plus, text),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    BigUint::parse_bytes(digits.as_bytes(), 10).map(|value| BigInt::from_biguint(sign, value))
+}
+
+pub(crate) fn e262_to_numeric(value: &Value) -> CoreResult<Numeric> {
+    let prim_value = e262_to_primitive(value, Some(PreferredType::Number))?;
+    match prim_value {
+        Value::BigInt(value) => Ok(Numeric::BigInt(value)),
+        _ => e262_to_number(&prim_value).map(Numeric::Number),
+    }
+}
+
+pub(crate) fn e262_to_big_int(argument: &Value) -> CoreResult<BigInt> {
+    let prim = e262_to_primitive(argument, Some(PreferredType::Number))?;
+    match prim {
+        Value::Undefined => Err(CoreError::TypeError(
+            "Cannot convert undefined value into BigInt".to_string(),
+        )),
+        Value::Null => Err(CoreError::TypeError(
+            "Cannot convert null value into BigInt".to_string(),
+        )),
+        Value::Boolean(value) => Ok(BigInt::from(value as u8)),
+        Value::BigInt(value) => Ok(value),
+        Value::Number(_) => Err(CoreError::TypeError(
+            "Cannot convert Number value into BigInt".to_string(),
+        )),
+        Value::String(value) => {
+            e262_string_to_big_int(&value.to_std_string_lossy()).ok_or_else(|| {
+                CoreError::SyntaxError(format!("Cannot convert \"{value}\" into BigInt"))
+            })
+        }
+        Value::Symbol(_) => Err(CoreError::TypeError(
+            "Cannot convert Symbol value into BigInt".to_string(),
+        )),
+        Value::Object(_) => unreachable!(),
+    }
+}
+
+pub(crate) fn e262_to_big_int64(argument: &Value) -> CoreResult<i64> {
+    let n = e262_to_big_int(argument)?;
+    Ok(e262_to_big_uint64(&Value::BigInt(n))? as i64)
+}
+
+pub(crate) fn e262_to_big_uint64(argument: &Value) -> CoreResult<u64> {
+    let n = e262_to_big_int(argument)?;
+    let int64bit = bigint::e262_as_uint_n(64, &n)?;
+    Ok(int64bit.to_u64().expect("Value was reduced modulo 2^64"))
+}
+
+/// Reduces a Number modulo 2^`bits`, implementing the common steps of ToInt32 and friends after ToNumber.
+pub(crate) fn p262_number_to_uint_modulo(number: f64, bits: i32) -> u64 {
+    if !number.is_finite() || numbers::is_zero(number) {
+        return 0;
+    }
+    let int = number.trunc();
+    int.rem_euclid(2f64.powi(bits)) as u64
+}
+
+/// Reduces `argument` modulo 2^`bits`, implementing the common steps of ToInt32 and friends.
+fn to_uint_modulo(argument: &Value, bits: i32) -> CoreResult<u64> {
+    Ok(p262_number_to_uint_modulo(e262_to_number(argument)?, bits))
+}
+
+pub(crate) fn e262_to_int32(argument: &Value) -> CoreResult<i32> {
+    to_uint_modulo(argument, 32).map(|int| int as u32 as i32)
+}
+
+pub(crate) fn e262_to_uint32(argument: &Value) -> CoreResult<u32> {
+    to_uint_modulo(argument, 32).map(|int| int as u32)
+}
+
+pub(crate) fn e262_to_int16(argument: &Value) -> CoreResult<i16> {
+    to_uint_modulo(argument, 16).map(|int| int as u16 as i16)
+}
+
+pub(crate) fn e262_to_uint16(argument: &Value) -> CoreResult<u16> {
+    to_uint_modulo(argument, 16).map(|int| int as u16)
+}
+
+pub(crate) fn e262_to_int8(argument: &Value) -> CoreResult<i8> {
+    to_uint_modulo(argument, 8).map(|int| int as u8 as i8)
+}
+
+pub(crate) fn e262_to_uint8(argument: &Value) -> CoreResult<u8> {
+    to_uint_modulo(argument, 8).map(|int| int as u8)
+}
+
+#[allow(clippy::if_same_then_else)]
+pub(crate) fn e262_to_uint8_clamp(argument: &Value) -> CoreResult<u8> {
+    let number = e262_to_number(argument)?;
+    if numbers::is_nan(number) || number <= 0f64 {
+        return Ok(0);
+    }
+    if number >= 255f64 {
+        return Ok(255);
+    }
+    let f = number.floor();
+    if f + 0.5 < number {
+        Ok(f as u8 + 1)
+    } else if number < f + 0.5 {
+        Ok(f as u8)
+    } else if f % 2f64 == 0f64 {
+        Ok(f as u8)
+    } else {
+        Ok(f as u8 + 1)
+    }
+}
+
+pub(crate) fn e262_to_string(argument: &Value) -> CoreResult<StringRep> {
+    match argument {
+        Value::String(value) => Ok(value.clone()),
+        Value::Symbol(_) => Err(CoreError::TypeError(
+            "Cannot convert Symbol value into String".to_string(),
+        )),
+        Value::Undefined => Ok("undefined".into()),
+        Value::Null => Ok("null".into()),
+        Value::Boolean(true) => Ok("true".into()),
+        Value::Boolean(false) => Ok("false".into()),
+        Value::Number(value) => Ok(numbers::e262_to_string(*value, 10).into()),
+        Value::BigInt(value) => Ok(value.to_string().into()),
+        Value::Object(_) => {
+            let prim_value = e262_to_primitive(argument, Some(PreferredType::String))?;
+            e262_to_string(&prim_value)
+        }
+    }
+}
+
+pub(crate) fn e262_to_object(argument: &Value) -> CoreResult<ObjectRep> {
+    let wrap = |intrinsic: Intrinsic, slot: &str, value: Rc<dyn std::any::Any>| {
+        let prototype = e262_current_realm().intrinsic(intrinsic);
+        let obj = e262_ordinary_object_create(Some(prototype));
+        obj.0.clone().set_slot(slot.to_string(), value);
+        Ok(obj)
+    };
+    match argument {
+        Value::Undefined => Err(CoreError::TypeError(
+            "Cannot convert undefined value into Object".to_string(),
+        )),
+        Value::Null => Err(CoreError::TypeError(
+            "Cannot convert null value into Object".to_string(),
+        )),
+        Value::Boolean(value) => wrap(Intrinsic::BooleanPrototype, "BooleanData", Rc::new(*value)),
+        Value::Number(value) => wrap(Intrinsic::NumberPrototype, "NumberData", Rc::new(*value)),
+        Value::String(value) => {
+            let prototype = e262_current_realm().intrinsic(Intrinsic::StringPrototype);
+            Ok(e262_string_create(value.clone(), Some(prototype)))
+        }
+        Value::Symbol(value) => wrap(
+            Intrinsic::SymbolPrototype,
+            "SymbolData",
+            Rc::new(value.clone()),
+        ),
+        Value::BigInt(value) => wrap(
+            Intrinsic::BigIntPrototype,
+            "BigIntData",
+            Rc::new(value.clone()),
+        ),
+        Value::Object(obj) => Ok(obj.clone()),
+    }
+}
+
+pub(crate) fn e262_to_property_key(argument: &Value) -> CoreResult<PropertyKey> {
+    let key = e262_to_primitive(argument, Some(PreferredType::String))?;
+    match key {
+        Value::Symbol(symbol) => Ok(PropertyKey::Symbol(symbol)),
+        // Fast path for array indices, skipping the string conversion.
+        Value::Number(number)
+            if number.fract() == 0f64 && (0f64..u32::MAX as f64).contains(&number) =>
+        {
+            Ok(PropertyKey::Index(number as u32))
+        }
+        _ => Ok(PropertyKey::from(e262_to_string(&key)?)),
+    }
+}
+
+pub(crate) fn e262_to_index(value: &Value) -> CoreResult<f64> {
+    let integer = e262_to_integer_or_infinity(value)?;
+    match integer {
+        IntegerOrInfinity::Integer(integer)
+            if (0f64..=numbers::MAX_SAFE_INTEGER).contains(&integer) =>
+        {
+            Ok(integer)
+        }
+        _ => Err(CoreError::RangeError("Invalid index".to_string())),
+    }
+}
+
+pub(crate) fn e262_canonical_numeric_index_string(argument: &StringRep) -> Option<f64> {
+    if *argument == "-0" {
+        return Some(-0f64);
+    }
+    let n = e262_string_to_number(&argument.to_std_string_lossy());
+    if *argument == *numbers::e262_to_string(n, 10) {
+        Some(n)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn e262_ordinary_to_primitive(
+    obj: &ObjectRep,
+    hint: PreferredType,
+) -> CoreResult<Value> {
+    let method_names = match hint {
+        PreferredType::String => ["toString", "valueOf"],
+        PreferredType::Number => ["valueOf", "toString"],
+    };
+    for name in method_names {
+        let method = e262_get(obj, &PropertyKey::from(name))?;
+        if e262_is_callable(&method) {
+            let result = e262_call(&method, &Value::Object(obj.clone()), &[])?;
+            if !matches!(result, Value::Object(_)) {
+                return Ok(result);
+            }
+        }
+    }
+    Err(CoreError::TypeError(
+        "Cannot convert object to primitive value".to_string(),
+    ))
+}
+
+pub(crate) fn e262_to_primitive(
+    input: &Value,
+    preferred_type: Option<PreferredType>,
+) -> CoreResult<Value> {
+    match input {
+        Value::Object(obj) => {
+            let key = PropertyKey::from(SymbolRep::well_known(WellKnownSymbol::ToPrimitive));
+            match e262_get_method(input, &key)? {
+                Some(exotic_to_prim) => {
+                    let hint = match preferred_type {
+                        None => "default",
+                        Some(PreferredType::String) => "string",
+                        Some(PreferredType::Number) => "number",
+                    };
+                    let result = e262_call(&exotic_to_prim, input, &[Value::String(hint.into())])?;
+                    match result {
+                        Value::Object(_) => Err(CoreError::TypeError(
+                            "Cannot convert object to primitive value".to_string(),
+                        )),
+                        _ => Ok(result),
+                    }
+                }
+                None => {
+                    e262_ordinary_to_primitive(obj, preferred_type.unwrap_or(PreferredType::Number))
+                }
+            }
+        }
+        _ => Ok(input.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::core::function::e262_create_builtin_function;
+    use crate::core::{e262_create_data_property, e262_ordinary_object_create, NativeBehaviour};
+
+    fn define_method(obj: &ObjectRep, key: PropertyKey, behaviour: NativeBehaviour) {
+        let func = e262_create_builtin_function(behaviour, 0, "", None);
+        e262_create_data_property(obj, key, Value::Object(func)).unwrap();
+    }
+
+    #[test]
+    fn to_primitive_returns_primitives() {
+        assert_eq!(
+            e262_to_primitive(&Value::Number(1.0), None).unwrap(),
+            Value::Number(1.0)
+        );
+        assert_eq!(
+            e262_to_primitive(&Value::String("foo".into()), Some(PreferredType::Number)).unwrap(),
+            Value::String("foo".into())
+        );
+    }
+
+    #[test]
+    fn ordinary_to_primitive_respects_hint() {
+        let obj = e262_ordinary_object_create(None);
+        define_method(
+            &obj,
+            "valueOf".into(),
+            Rc::new(|_, _| Ok(Value::Number(42.0))),
+        );
+        define_method(
+            &obj,
+            "toString".into(),
+            Rc::new(|_, _| Ok(Value::String("foo".into()))),
+        );
+        let value = Value::Object(obj);
+
+        assert_eq!(
+            e262_to_primitive(&value, None).unwrap(),
+            Value::Number(42.0)
+        );
+        assert_eq!(
+            e262_to_primitive(&value, Some(PreferredType::String)).unwrap(),
+            Value::String("foo".into())
+        );
+        assert_eq!(e262_to_number(&value).unwrap(), 42.0);
+    }
+
+    #[test]
+    fn ordinary_to_primitive_skips_object_results() {
+        let obj = e262_ordinary_object_create(None);
+        let other = Value::Object(e262_ordinary_object_create(None));
+        define_method(
+            &obj,
+            "valueOf".into(),
+            Rc::new(move |_, _| Ok(other.clone())),
+        );
+        define_method(
+            &obj,
+            "toString".into(),
+            Rc::new(|_, _| Ok(Value::String("7".into()))),
+        );
+
+        assert_eq!(
+            e262_to_primitive(&Value::Object(obj), Some(PreferredType::Number)).unwrap(),
+            Value::String("7".into())
+        );
+    }
+
+    #[test]
+    fn ordinary_to_primitive_throws_without_methods() {
+        let obj = Value::Object(e262_ordinary_object_create(None));
+
+        assert!(matches!(
+            e262_to_primitive(&obj, None),
+            Err(CoreError::TypeError(_))
+        ));
+        assert!(matches!(e262_to_number(&obj), Err(CoreError::TypeError(_))));
+    }
+
+    #[test]
+    fn to_primitive_calls_exotic_method_with_hint() {
+        let obj = e262_ordinary_object_create(None);
+        let key = PropertyKey::from(SymbolRep::well_known(WellKnownSymbol::ToPrimitive));
+        define_method(&obj, key, Rc::new(|_, args| Ok(args[0].clone())));
+        let value = Value::Object(obj);
+
+        assert_eq!(
+            e262_to_primitive(&value, None).unwrap(),
+            Value::String("default".into())
+        );
+        assert_eq!(
+            e262_to_primitive(&value, Some(PreferredType::String)).unwrap(),
+            Value::String("string".into())
+        );
+        assert_eq!(
+            e262_to_primitive(&value, Some(PreferredType::Number)).unwrap(),
+            Value::String("number".into())
+        );
+    }
+
+    #[test]
+    fn to_primitive_rejects_exotic_object_results() {
+        let obj = e262_ordinary_object_create(None);
+        let key = PropertyKey::from(SymbolRep::well_known(WellKnownSymbol::ToPrimitive));
+        define_method(&obj, key, Rc::new(|this, _| Ok(this.clone())));
+
+        assert!(matches!(
+            e262_to_primitive(&Value::Object(obj), None),
+            Err(CoreError::TypeError(_))
+        ));
+    }
+
+    #[test]
+    fn to_primitive_rejects_non_callable_exotic() {
+        let obj = e262_ordinary_object_create(None);
+        let key = PropertyKey::from(SymbolRep::well_known(WellKnownSymbol::ToPrimitive));
+        e262_create_data_property(&obj, key, Value::Number(1.0)).unwrap();
+
+        assert!(matches!(
+            e262_to_primitive(&Value::Object(obj), None),
+            Err(CoreError::TypeError(_))
+        ));
+    }
+
+    #[test]
+    fn string_to_number_works() {
+        assert_eq!(e262_string_to_number(""), 0.0);
+        assert_eq!(e262_string_to_number(" \n\t\u{FEFF}42\u{3000}"), 42.0);
+        assert_eq!(e262_string_to_number("-1.5e3"), -1500.0);
+        assert_eq!(e262_string_to_number(".5"), 0.5);
+        assert_eq!(e262_string_to_number("5."), 5.0);
+        assert_eq!(e262_string_to_number("0x1F"), 31.0);
+        assert_eq!(e262_string_to_number("0b101"), 5.0);
+        assert_eq!(e262_string_to_number("0O17"), 15.0);
+        assert_eq!(
+            e262_string_to_number("0x20000000000001"),
+            9007199254740992.0
+        );
+        assert_eq!(e262_string_to_number("-Infinity"), f64::NEG_INFINITY);
+        assert!(e262_string_to_number("-0").is_sign_negative());
+        assert!(e262_string_to_number("-0x10").is_nan());
+        assert!(e262_string_to_number("1_000").is_nan());
+        assert!(e262_string_to_number("inf").is_nan());
+        assert!(e262_string_to_number("NaN").is_nan());
+        assert!(e262_string_to_number(".").is_nan());
+        assert!(e262_string_to_number("1e").is_nan());
+        assert!(e262_string_to_number("\u{0085}1").is_nan());
+    }
+
+    #[test]
+    fn string_to_big_int_works() {
+        assert_eq!(e262_string_to_big_int("  "), Some(BigInt::ZERO));
+        assert_eq!(e262_string_to_big_int("-12"), Some(BigInt::from(-12)));
+        assert_eq!(e262_string_to_big_int("0xff"), Some(BigInt::from(255)));
+        assert_eq!(e262_string_to_big_int("1.5"), None);
+        assert_eq!(e262_string_to_big_int("1e3"), None);
+        assert_eq!(e262_string_to_big_int("-0x1"), None);
+        assert_eq!(e262_string_to_big_int("Infinity"), None);
+    }
+
+    #[test]
+    fn to_big_int_works() {
+        assert_eq!(
+            e262_to_big_int(&Value::Boolean(true)).unwrap(),
+            BigInt::from(1)
+        );
+        assert_eq!(
+            e262_to_big_int(&Value::String("42".into())).unwrap(),
+            BigInt::from(42)
+        );
+        assert!(matches!(
+            e262_to_big_int(&Value::String("4.2".into())),
+            Err(CoreError::SyntaxError(_))
+        ));
+        assert!(matches!(
+            e262_to_big_int(&Value::Number(1.0)),
+            Err(CoreError::TypeError(_))
+        ));
+        assert_eq!(
+            e262_to_big_int64(&Value::BigInt(BigInt::from(u64::MAX))).unwrap(),
+            -1
+        );
+        assert_eq!(
+            e262_to_big_uint64(&Value::BigInt(BigInt::from(-1))).unwrap(),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn to_numeric_works() {
+        assert_eq!(
+            e262_to_numeric(&Value::BigInt(BigInt::from(1))).unwrap(),
+            Numeric::BigInt(BigInt::from(1))
+        );
+        assert_eq!(
+            e262_to_numeric(&Value::String("7".into())).unwrap(),
+            Numeric::Number(7.0)
+        );
+    }
+
+    #[test]
+    fn sized_integer_conversions_work() {
+        assert_eq!(
+            e262_to_int32(&Value::Number(2147483648.0)).unwrap(),
+            -2147483648
+        );
+        assert_eq!(e262_to_int32(&Value::Number(-1.9)).unwrap(), -1);
+        assert_eq!(e262_to_int32(&Value::Number(f64::INFINITY)).unwrap(), 0);
+        assert_eq!(e262_to_uint32(&Value::Number(-1.0)).unwrap(), u32::MAX);
+        assert_eq!(e262_to_int16(&Value::Number(32768.0)).unwrap(), -32768);
+        assert_eq!(e262_to_uint16(&Value::Number(65537.0)).unwrap(), 1);
+        assert_eq!(e262_to_int8(&Value::Number(255.0)).unwrap(), -1);
+        assert_eq!(e262_to_uint8(&Value::Number(-1.0)).unwrap(), 255);
+        assert_eq!(e262_to_uint8_clamp(&Value::Number(300.0)).unwrap(), 255);
+        assert_eq!(e262_to_uint8_clamp(&Value::Number(-3.0)).unwrap(), 0);
+        assert_eq!(e262_to_uint8_clamp(&Value::Number(1.5)).unwrap(), 2);
+        assert_eq!(e262_to_uint8_clamp(&Value::Number(2.5)).unwrap(), 2);
+        assert_eq!(e262_to_uint8_clamp(&Value::Number(2.6)).unwrap(), 3);
+    }
+
+    #[test]
+    fn to_string_works() {
+        assert_eq!(e262_to_string(&Value::Undefined).unwrap(), "undefined");
+        assert_eq!(e262_to_string(&Value::Boolean(false)).unwrap(), "false");
+        assert_eq!(e262_to_string(&Value::Number(-0.0)).unwrap(), "0");
+        assert_eq!(e262_to_string(&Value::Number(1e21)).unwrap(), "1e+21");
+        assert_eq!(
+            e262_to_string(&Value::BigInt(BigInt::from(-5))).unwrap(),
+            "-5"
+        );
+        assert!(matches!(
+            e262_to_string(&Value::Symbol(SymbolRep::anon())),
+            Err(CoreError::TypeError(_))
+        ));
+    }
+
+    #[test]
+    fn to_property_key_works() {
+        let symbol = SymbolRep::anon();
+        assert_eq!(
+            e262_to_property_key(&Value::Symbol(symbol.clone())).unwrap(),
+            PropertyKey::Symbol(symbol)
+        );
+        assert_eq!(
+            e262_to_property_key(&Value::Number(1.5)).unwrap(),
+            PropertyKey::from("1.5")
+        );
+    }
+
+    #[test]
+    fn to_object_wraps_primitives() {
+        let realm = e262_current_realm();
+
+        let obj = e262_to_object(&Value::Number(42.0)).unwrap();
+        assert_eq!(
+            obj.0.clone().get_prototype_of().unwrap(),
+            Some(realm.intrinsic(Intrinsic::NumberPrototype))
+        );
+        assert_eq!(
+            crate::core::p262_get_slot::<f64>(obj.0, "NumberData".to_string()).as_deref(),
+            Some(&42.0)
+        );
+
+        let obj = e262_to_object(&Value::String("ab".into())).unwrap();
+        assert_eq!(
+            e262_get(&obj, &PropertyKey::from("length")).unwrap(),
+            Value::Number(2.0)
+        );
+        assert_eq!(
+            e262_get(&obj, &PropertyKey::from("1")).unwrap(),
+            Value::String("b".into())
+        );
+        assert_eq!(
+            obj.0.clone().own_property_keys().unwrap(),
+            vec!["0".into(), "1".into(), "length".into()]
+        );
+
+        assert!(matches!(
+            e262_to_object(&Value::Null),
+            Err(CoreError::TypeError(_))
+        ));
+    }
+
+    #[test]
+    fn to_index_works() {
+        assert_eq!(e262_to_index(&Value::Undefined).unwrap(), 0.0);
+        assert_eq!(e262_to_index(&Value::Number(3.7)).unwrap(), 3.0);
+        assert!(matches!(
+            e262_to_index(&Value::Number(-1.0)),
+            Err(CoreError::RangeError(_))
+        ));
+        assert!(matches!(
+            e262_to_index(&Value::Number(f64::INFINITY)),
+            Err(CoreError::RangeError(_))
+        ));
+    }
+
+    #[test]
+    fn canonical_numeric_index_string_works() {
+        assert_eq!(e262_canonical_numeric_index_string(&"1".into()), Some(1.0));
+        assert_eq!(
+            e262_canonical_numeric_index_string(&"1.5".into()),
+            Some(1.5)
+        );
+        assert!(e262_canonical_numeric_index_string(&"-0".into())
+            .unwrap()
+            .is_sign_negative());
+        assert!(e262_canonical_numeric_index_string(&"NaN".into())
+            .unwrap()
+            .is_nan());
+        assert_eq!(e262_canonical_numeric_index_string(&"01".into()), None);
+        assert_eq!(e262_canonical_numeric_index_string(&"1e3".into()), None);
     }
 }
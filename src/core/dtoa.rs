@@ -0,0 +1,368 @@
+use num_bigint::BigUint;
+
+const DIGIT_CHARS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// The decimal digits of a positive finite Number, such that the number equals `0.digits × 10^exponent`.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Digits {
+    /// The significant digits, without leading or trailing zeros (unless the number is zero).
+    pub digits: String,
+    /// The spec's `n`, i.e. the position of the decimal point relative to the first digit.
+    pub exponent: i32,
+}
+
+/// Splits a positive finite `f64` into an integer mantissa and a binary exponent, so that `value = mantissa × 2^exponent`.
+fn decompose(value: f64) -> (u64, i32) {
+    let bits = value.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & 0x000f_ffff_ffff_ffff;
+    if biased == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased - 1075)
+    }
+}
+
+/// Returns `value` as an exact rational `numerator / denominator`.
+fn to_rational(value: f64) -> (BigUint, BigUint) {
+    let (mantissa, exponent) = decompose(value);
+    let mantissa = BigUint::from(mantissa);
+    if exponent >= 0 {
+        (mantissa << exponent as usize, BigUint::from(1u8))
+    } else {
+        (mantissa, BigUint::from(1u8) << (-exponent) as usize)
+    }
+}
+
+/// Divides rationally, rounding half-way cases up.
+fn div_round_half_up(numerator: &BigUint, denominator: &BigUint) -> BigUint {
+    ((numerator << 1usize) + denominator) / (denominator << 1usize)
+}
+
+/// Generates the shortest digit string that round-trips to `value`, which must be positive and finite.
+pub(crate) fn shortest_digits(value: f64) -> Digits {
+    let formatted = format!("{:e}", value);
+    let (mantissa, exponent) = formatted
+        .split_once('e')
+        .expect("Exponential formatting always has an exponent");
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let exponent: i32 = exponent.parse().expect("Exponent must be an integer");
+    Digits {
+        digits,
+        exponent: exponent + 1,
+    }
+}
+
+/// Generates exactly `precision` significant digits of `value`, which must be positive and finite.
+///
+/// When two digit strings are equally close to `value`, the larger one is chosen.
+pub(crate) fn precise_digits(value: f64, precision: usize) -> Digits {
+    let (numerator, denominator) = to_rational(value);
+    let mut exponent = value.log10().floor() as i32 + 1;
+    loop {
+        let scale = exponent - precision as i32;
+        let ten = BigUint::from(10u8);
+        let digits = if scale >= 0 {
+            div_round_half_up(&numerator, &(&denominator * ten.pow(scale as u32)))
+        } else {
+            div_round_half_up(&(&numerator * ten.pow((-scale) as u32)), &denominator)
+        };
+        let digits = digits.to_string();
+        if digits.len() > precision {
+            // The estimate was too low, or rounding carried into a new digit.
+            if digits.len() == precision + 1 && digits.bytes().skip(1).all(|b| b == b'0') {
+                let mut digits = digits;
+                digits.truncate(precision);
+                return Digits {
+                    digits,
+                    exponent: exponent + 1,
+                };
+            }
+            exponent += 1;
+        } else if digits.len() < precision {
+            exponent -= 1;
+        } else {
+            return Digits { digits, exponent };
+        }
+    }
+}
+
+/// Formats `value` in radix 10, following [Number::toString](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-tostring).
+fn to_decimal_string(value: f64) -> String {
+    let Digits {
+        digits,
+        exponent: n,
+    } = shortest_digits(value);
+    let k = digits.len() as i32;
+    if k <= n && n <= 21 {
+        digits + &"0".repeat((n - k) as usize)
+    } else if 0 < n && n <= 21 {
+        let (int, frac) = digits.split_at(n as usize);
+        format!("{int}.{frac}")
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{digits}", "0".repeat((-n) as usize))
+    } else {
+        let e = n - 1;
+        let sign = if e < 0 { '-' } else { '+' };
+        if k == 1 {
+            format!("{digits}e{sign}{}", e.abs())
+        } else {
+            let (first, rest) = digits.split_at(1);
+            format!("{first}.{rest}e{sign}{}", e.abs())
+        }
+    }
+}
+
+/// Formats `value` in a radix other than 10.
+///
+/// The integer part is converted exactly; the fractional part is generated until the digits uniquely identify `value`, as the spec leaves this implementation-approximated.
+fn to_radix_string(value: f64, radix: u32) -> String {
+    let mut integer = value.floor();
+    let mut fraction = value - integer;
+    let mut delta = 0.5 * (f64::from_bits(value.to_bits() + 1) - value);
+    delta = delta.max(f64::from_bits(1));
+
+    let mut fraction_digits: Vec<u8> = Vec::new();
+    if fraction >= delta {
+        loop {
+            fraction *= radix as f64;
+            delta *= radix as f64;
+            let digit = fraction as usize;
+            fraction_digits.push(digit as u8);
+            fraction -= digit as f64;
+            if (fraction > 0.5 || (fraction == 0.5 && (digit & 1) == 1)) && fraction + delta > 1.0 {
+                // Round up, propagating carries to the previous digits.
+                loop {
+                    match fraction_digits.pop() {
+                        None => {
+                            integer += 1.0;
+                            break;
+                        }
+                        Some(last) if (last as u32) + 1 < radix => {
+                            fraction_digits.push(last + 1);
+                            break;
+                        }
+                        Some(_) => {}
+                    }
+                }
+                break;
+            }
+            if fraction < delta {
+                break;
+            }
+        }
+    }
+
+    let (numerator, denominator) = to_rational(integer);
+    let mut result = (numerator / denominator).to_str_radix(radix);
+    if !fraction_digits.is_empty() {
+        result.push('.');
+        result.extend(
+            fraction_digits
+                .iter()
+                .map(|digit| DIGIT_CHARS[*digit as usize] as char),
+        );
+    }
+    result
+}
+
+/// Implements [Number::toString](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-tostring), with `radix` in the range 2–36.
+pub(crate) fn to_string_radix(value: f64, radix: u32) -> String {
+    debug_assert!((2..=36).contains(&radix));
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value == 0.0 {
+        "0".to_string()
+    } else if value < 0.0 {
+        format!("-{}", to_string_radix(-value, radix))
+    } else if value.is_infinite() {
+        "Infinity".to_string()
+    } else if radix == 10 {
+        to_decimal_string(value)
+    } else {
+        to_radix_string(value, radix)
+    }
+}
+
+/// Implements the formatting steps of [Number.prototype.toFixed](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-number.prototype.tofixed), for `fraction_digits` in the range 0–100.
+pub(crate) fn to_fixed(value: f64, fraction_digits: usize) -> String {
+    if !value.is_finite() || value.abs() >= 1e21 {
+        return to_string_radix(value, 10);
+    }
+    let sign = if value < 0.0 { "-" } else { "" };
+    let (numerator, denominator) = to_rational(value.abs());
+    let scaled = numerator * BigUint::from(10u8).pow(fraction_digits as u32);
+    let mut m = div_round_half_up(&scaled, &denominator).to_string();
+    if fraction_digits != 0 {
+        if m.len() <= fraction_digits {
+            m = "0".repeat(fraction_digits + 1 - m.len()) + &m;
+        }
+        m.insert(m.len() - fraction_digits, '.');
+    }
+    format!("{sign}{m}")
+}
+
+/// Implements the formatting steps of [Number.prototype.toExponential](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-number.prototype.toexponential), for `fraction_digits` in the range 0–100, or the shortest representation if absent.
+pub(crate) fn to_exponential(value: f64, fraction_digits: Option<usize>) -> String {
+    if !value.is_finite() {
+        return to_string_radix(value, 10);
+    }
+    let sign = if value < 0.0 { "-" } else { "" };
+    let (digits, e) = if value == 0.0 {
+        ("0".repeat(fraction_digits.unwrap_or(0) + 1), 0)
+    } else {
+        let Digits { digits, exponent } = match fraction_digits {
+            Some(f) => precise_digits(value.abs(), f + 1),
+            None => shortest_digits(value.abs()),
+        };
+        (digits, exponent - 1)
+    };
+    let mantissa = if digits.len() == 1 {
+        digits
+    } else {
+        let (first, rest) = digits.split_at(1);
+        format!("{first}.{rest}")
+    };
+    let exp_sign = if e < 0 { '-' } else { '+' };
+    format!("{sign}{mantissa}e{exp_sign}{}", e.abs())
+}
+
+/// Implements the formatting steps of [Number.prototype.toPrecision](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-number.prototype.toprecision), for `precision` in the range 1–100.
+pub(crate) fn to_precision(value: f64, precision: usize) -> String {
+    if !value.is_finite() {
+        return to_string_radix(value, 10);
+    }
+    let sign = if value < 0.0 { "-" } else { "" };
+    let (digits, e) = if value == 0.0 {
+        ("0".repeat(precision), 0)
+    } else {
+        let Digits { digits, exponent } = precise_digits(value.abs(), precision);
+        (digits, exponent - 1)
+    };
+    let p = precision as i32;
+    let m = if e < -6 || e >= p {
+        let mantissa = if precision == 1 {
+            digits
+        } else {
+            let (first, rest) = digits.split_at(1);
+            format!("{first}.{rest}")
+        };
+        let exp_sign = if e < 0 { '-' } else { '+' };
+        format!("{mantissa}e{exp_sign}{}", e.abs())
+    } else if e == p - 1 {
+        digits
+    } else if e >= 0 {
+        let (int, frac) = digits.split_at((e + 1) as usize);
+        format!("{int}.{frac}")
+    } else {
+        format!("0.{}{digits}", "0".repeat((-(e + 1)) as usize))
+    };
+    format!("{sign}{m}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string_special_values() {
+        assert_eq!(to_string_radix(f64::NAN, 10), "NaN");
+        assert_eq!(to_string_radix(0.0, 10), "0");
+        assert_eq!(to_string_radix(-0.0, 10), "0");
+        assert_eq!(to_string_radix(f64::INFINITY, 10), "Infinity");
+        assert_eq!(to_string_radix(f64::NEG_INFINITY, 2), "-Infinity");
+    }
+
+    #[test]
+    fn to_string_is_shortest_round_trip() {
+        assert_eq!(to_string_radix(0.1 + 0.2, 10), "0.30000000000000004");
+        assert_eq!(to_string_radix(0.1, 10), "0.1");
+        assert_eq!(to_string_radix(123.456, 10), "123.456");
+        assert_eq!(to_string_radix(-42.0, 10), "-42");
+        assert_eq!(to_string_radix(5e-324, 10), "5e-324");
+        assert_eq!(to_string_radix(f64::MAX, 10), "1.7976931348623157e+308");
+    }
+
+    #[test]
+    fn to_string_exponent_thresholds() {
+        assert_eq!(to_string_radix(1e20, 10), "100000000000000000000");
+        assert_eq!(to_string_radix(1e21, 10), "1e+21");
+        assert_eq!(to_string_radix(1.5e21, 10), "1.5e+21");
+        assert_eq!(to_string_radix(1e-6, 10), "0.000001");
+        assert_eq!(to_string_radix(1e-7, 10), "1e-7");
+        assert_eq!(to_string_radix(1.25e-7, 10), "1.25e-7");
+    }
+
+    #[test]
+    fn to_string_other_radixes() {
+        assert_eq!(to_string_radix(255.0, 16), "ff");
+        assert_eq!(to_string_radix(-255.0, 2), "-11111111");
+        assert_eq!(to_string_radix(0.5, 2), "0.1");
+        assert_eq!(
+            to_string_radix(0.1, 3),
+            "0.0022002200220022002200220022002201"
+        );
+        assert_eq!(to_string_radix(3.75, 16), "3.c");
+        assert_eq!(to_string_radix(1e21, 36), "5v1j4f4ds79m9s");
+        assert_eq!(
+            to_string_radix(2f64.powi(60), 2),
+            format!("1{}", "0".repeat(60))
+        );
+    }
+
+    #[test]
+    fn to_fixed_works() {
+        assert_eq!(to_fixed(1.005, 2), "1.00");
+        assert_eq!(to_fixed(1.45, 1), "1.4");
+        assert_eq!(to_fixed(0.5, 0), "1");
+        assert_eq!(to_fixed(2.5, 0), "3");
+        assert_eq!(to_fixed(-2.5, 0), "-3");
+        assert_eq!(to_fixed(0.000001, 2), "0.00");
+        assert_eq!(to_fixed(-0.0000001, 2), "-0.00");
+        assert_eq!(to_fixed(-0.0, 2), "0.00");
+        assert_eq!(to_fixed(123.456, 10), "123.4560000000");
+        assert_eq!(to_fixed(1e21, 2), "1e+21");
+        assert_eq!(to_fixed(0.1, 20), "0.10000000000000000555");
+    }
+
+    #[test]
+    fn to_exponential_works() {
+        assert_eq!(to_exponential(123456.0, Some(2)), "1.23e+5");
+        assert_eq!(to_exponential(123456.0, None), "1.23456e+5");
+        assert_eq!(to_exponential(0.00015, Some(1)), "1.5e-4");
+        assert_eq!(to_exponential(0.0, Some(2)), "0.00e+0");
+        assert_eq!(to_exponential(-1.0, None), "-1e+0");
+        assert_eq!(to_exponential(9.99, Some(1)), "1.0e+1");
+        assert_eq!(to_exponential(f64::NEG_INFINITY, Some(1)), "-Infinity");
+    }
+
+    #[test]
+    fn to_precision_works() {
+        assert_eq!(to_precision(123.456, 4), "123.5");
+        assert_eq!(to_precision(0.000123, 2), "0.00012");
+        assert_eq!(to_precision(123456.0, 2), "1.2e+5");
+        assert_eq!(to_precision(1e-7, 1), "1e-7");
+        assert_eq!(to_precision(0.0, 3), "0.00");
+        assert_eq!(to_precision(-99.95, 3), "-100");
+        assert_eq!(to_precision(5.0, 1), "5");
+        assert_eq!(to_precision(1e21, 3), "1.00e+21");
+    }
+
+    #[test]
+    fn precise_digits_carries() {
+        assert_eq!(
+            precise_digits(9.99, 2),
+            Digits {
+                digits: "10".to_string(),
+                exponent: 2
+            }
+        );
+        assert_eq!(
+            precise_digits(0.1, 1),
+            Digits {
+                digits: "1".to_string(),
+                exponent: 0
+            }
+        );
+    }
+}
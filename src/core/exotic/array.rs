@@ -0,0 +1,284 @@
+use std::any::Any;
+use std::rc::Rc;
+
+use crate::agent::e262_current_realm;
+use crate::core::cast::{e262_to_number, e262_to_uint32};
+use crate::core::property::Descriptor;
+use crate::core::{
+    e262_create_data_property_or_throw, e262_ordinary_define_own_property, e262_ordinary_delete,
+    e262_ordinary_get, e262_ordinary_get_own_property, e262_ordinary_get_prototype_of,
+    e262_ordinary_has_property, e262_ordinary_is_extensible, e262_ordinary_own_property_keys,
+    e262_ordinary_prevent_extensions, e262_ordinary_set, e262_ordinary_set_prototype_of,
+    BaseObject, HasBaseObject, Object, ObjectRep, Property, PropertyKey, Value,
+};
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::Intrinsic;
+
+/// The internal implementation of an [Array exotic object](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-array-exotic-objects).
+///
+/// Its `length` property is kept greater than every array index property.
+#[derive(Debug)]
+pub struct ArrayObject {
+    base: Rc<BaseObject>,
+}
+
+impl ArrayObject {
+    fn length(self: Rc<Self>) -> (u32, bool) {
+        match e262_ordinary_get_own_property(self, &PropertyKey::from("length")) {
+            Some(Property::Data {
+                value, writable, ..
+            }) => match *value {
+                Value::Number(length) => (length as u32, writable),
+                _ => unreachable!("Array lengths are numbers"),
+            },
+            _ => unreachable!("Arrays must have a length"),
+        }
+    }
+}
+
+impl Object for ArrayObject {
+    fn get_slot(self: Rc<Self>, key: String) -> Option<Rc<dyn Any>> {
+        self.base.clone().get_slot(key)
+    }
+
+    fn set_slot(self: Rc<Self>, key: String, value: Rc<dyn Any>) -> bool {
+        self.base.clone().set_slot(key, value)
+    }
+
+    fn get_prototype_of(self: Rc<Self>) -> CoreResult<Option<ObjectRep>> {
+        Ok(e262_ordinary_get_prototype_of(self))
+    }
+
+    fn set_prototype_of(self: Rc<Self>, proto: Option<ObjectRep>) -> bool {
+        e262_ordinary_set_prototype_of(self, proto)
+    }
+
+    fn is_extensible(self: Rc<Self>) -> CoreResult<bool> {
+        Ok(e262_ordinary_is_extensible(self))
+    }
+
+    fn prevent_extensions(self: Rc<Self>) -> CoreResult<bool> {
+        Ok(e262_ordinary_prevent_extensions(self))
+    }
+
+    fn get_own_property(self: Rc<Self>, key: &PropertyKey) -> CoreResult<Option<Property>> {
+        Ok(e262_ordinary_get_own_property(self, key))
+    }
+
+    fn define_own_property(self: Rc<Self>, key: PropertyKey, desc: Descriptor) -> CoreResult<bool> {
+        match key {
+            PropertyKey::Index(index) => {
+                let (length, writable) = self.clone().length();
+                if index >= length && !writable {
+                    return Ok(false);
+                }
+                if !e262_ordinary_define_own_property(self.clone(), &key, desc)? {
+                    return Ok(false);
+                }
+                if index >= length {
+                    e262_ordinary_define_own_property(
+                        self,
+                        &PropertyKey::from("length"),
+                        Descriptor {
+                            value: Some(Rc::new(Value::Number(index as f64 + 1f64))),
+                            ..Default::default()
+                        },
+                    )?;
+                }
+                Ok(true)
+            }
+            _ if key == PropertyKey::from("length") => e262_array_set_length(self, desc),
+            _ => e262_ordinary_define_own_property(self, &key, desc),
+        }
+    }
+
+    fn has_property(self: Rc<Self>, key: &PropertyKey) -> CoreResult<bool> {
+        e262_ordinary_has_property(self, key)
+    }
+
+    fn get(self: Rc<Self>, key: &PropertyKey, receiver: &Value) -> CoreResult<Value> {
+        e262_ordinary_get(self, key, receiver)
+    }
+
+    fn set(self: Rc<Self>, key: &PropertyKey, value: Value, receiver: &Value) -> CoreResult<bool> {
+        e262_ordinary_set(self, key, value, receiver)
+    }
+
+    fn delete(self: Rc<Self>, key: &PropertyKey) -> CoreResult<bool> {
+        e262_ordinary_delete(self, key)
+    }
+
+    fn own_property_keys(self: Rc<Self>) -> CoreResult<Vec<PropertyKey>> {
+        Ok(e262_ordinary_own_property_keys(self))
+    }
+
+    fn is_array_exotic(self: Rc<Self>) -> bool {
+        true
+    }
+}
+
+impl HasBaseObject for ArrayObject {
+    fn get_object(self: Rc<Self>) -> Rc<BaseObject> {
+        self.base.clone()
+    }
+}
+
+/// Creates an Array exotic object, using `%Array.prototype%` of the current realm if no prototype is provided.
+pub(crate) fn e262_array_create(
+    length: f64,
+    prototype: Option<ObjectRep>,
+) -> CoreResult<ObjectRep> {
+    if length > u32::MAX as f64 {
+        return Err(CoreError::RangeError("Invalid array length".to_string()));
+    }
+    let prototype =
+        prototype.unwrap_or_else(|| e262_current_realm().intrinsic(Intrinsic::ArrayPrototype));
+    let array = Rc::new(ArrayObject {
+        base: Rc::new(BaseObject::new(&Some(prototype))),
+    });
+    e262_ordinary_define_own_property(
+        array.clone(),
+        &PropertyKey::from("length"),
+        Descriptor {
+            value: Some(Rc::new(Value::Number(length))),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(false),
+            ..Default::default()
+        },
+    )?;
+    Ok(ObjectRep::new(array))
+}
+
+fn e262_array_set_length(array: Rc<ArrayObject>, desc: Descriptor) -> CoreResult<bool> {
+    let length_key = PropertyKey::from("length");
+    let Some(value) = &desc.value else {
+        return e262_ordinary_define_own_property(array, &length_key, desc);
+    };
+    let new_len = e262_to_uint32(value)?;
+    let number_len = e262_to_number(value)?;
+    if new_len as f64 != number_len {
+        return Err(CoreError::RangeError("Invalid array length".to_string()));
+    }
+    let mut new_len_desc = Descriptor {
+        value: Some(Rc::new(Value::Number(new_len as f64))),
+        ..desc
+    };
+    let (old_len, old_writable) = array.clone().length();
+    if new_len >= old_len {
+        return e262_ordinary_define_own_property(array, &length_key, new_len_desc);
+    }
+    if !old_writable {
+        return Ok(false);
+    }
+    let new_writable = new_len_desc.writable != Some(false);
+    new_len_desc.writable = Some(true);
+    if !e262_ordinary_define_own_property(array.clone(), &length_key, new_len_desc.clone())? {
+        return Ok(false);
+    }
+    let mut indices: Vec<u32> = e262_ordinary_own_property_keys(array.clone())
+        .into_iter()
+        .filter_map(|key| match key {
+            PropertyKey::Index(index) if index >= new_len => Some(index),
+            _ => None,
+        })
+        .collect();
+    indices.sort_unstable_by(|a, b| b.cmp(a));
+    for index in indices {
+        if !array.clone().delete(&PropertyKey::Index(index))? {
+            new_len_desc.value = Some(Rc::new(Value::Number(index as f64 + 1f64)));
+            if !new_writable {
+                new_len_desc.writable = Some(false);
+            }
+            e262_ordinary_define_own_property(array, &length_key, new_len_desc)?;
+            return Ok(false);
+        }
+    }
+    if !new_writable {
+        e262_ordinary_define_own_property(
+            array,
+            &length_key,
+            Descriptor {
+                writable: Some(false),
+                ..Default::default()
+            },
+        )?;
+    }
+    Ok(true)
+}
+
+pub(crate) fn e262_create_array_from_list(elements: &[Value]) -> ObjectRep {
+    let array = e262_array_create(0f64, None).expect("Empty arrays can always be created");
+    for (index, element) in elements.iter().enumerate() {
+        e262_create_data_property_or_throw(
+            &array,
+            PropertyKey::from(index as u32),
+            element.clone(),
+        )
+        .expect("Fresh arrays accept new elements");
+    }
+    array
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{e262_get, e262_set};
+
+    fn length(array: &ObjectRep) -> Value {
+        e262_get(array, &PropertyKey::from("length")).unwrap()
+    }
+
+    #[test]
+    fn length_follows_indices() {
+        let array = e262_create_array_from_list(&[Value::Null, Value::Undefined]);
+        assert_eq!(length(&array), Value::Number(2f64));
+
+        e262_set(&array, &PropertyKey::from(9), Value::Null, true).unwrap();
+        assert_eq!(length(&array), Value::Number(10f64));
+    }
+
+    #[test]
+    fn shrinking_deletes_elements() {
+        let array = e262_create_array_from_list(&[Value::Null, Value::Null, Value::Null]);
+        e262_set(
+            &array,
+            &PropertyKey::from("length"),
+            Value::Number(1f64),
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(length(&array), Value::Number(1f64));
+        assert_eq!(
+            array.0.clone().own_property_keys().unwrap(),
+            vec![PropertyKey::from(0), PropertyKey::from("length")]
+        );
+        assert!(e262_set(
+            &array,
+            &PropertyKey::from("length"),
+            Value::Number(1.5),
+            true
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn frozen_length_rejects_elements() {
+        let array = e262_create_array_from_list(&[]);
+        let frozen = array
+            .0
+            .clone()
+            .define_own_property(
+                PropertyKey::from("length"),
+                Descriptor {
+                    writable: Some(false),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert!(frozen);
+        assert!(e262_set(&array, &PropertyKey::from(0), Value::Null, true).is_err());
+        assert!(e262_array_create(u32::MAX as f64 + 1f64, None).is_err());
+    }
+}
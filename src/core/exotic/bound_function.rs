@@ -0,0 +1,150 @@
+use std::any::Any;
+use std::rc::Rc;
+
+use crate::core::function::{e262_call, e262_construct, Callable, Constructor};
+use crate::core::property::Descriptor;
+use crate::core::{
+    e262_ordinary_define_own_property, e262_ordinary_delete, e262_ordinary_get,
+    e262_ordinary_get_own_property, e262_ordinary_get_prototype_of, e262_ordinary_has_property,
+    e262_ordinary_is_extensible, e262_ordinary_own_property_keys, e262_ordinary_prevent_extensions,
+    e262_ordinary_set, e262_ordinary_set_prototype_of, p262_get_slot, BaseObject, HasBaseObject,
+    Object, ObjectRep, Property, PropertyKey, Value,
+};
+use crate::errors::CoreResult;
+
+/// The internal implementation of a [bound function exotic object](https://tc39.es/ecma262/multipage/ordinary-and-exotic-objects-behaviours.html#sec-bound-function-exotic-objects).
+///
+/// The wrapped function, `this` value and arguments live in the `[[BoundTargetFunction]]`, `[[BoundThis]]` and `[[BoundArguments]]` slots.
+#[derive(Debug)]
+pub struct BoundFunction {
+    base: Rc<BaseObject>,
+}
+
+impl BoundFunction {
+    fn target(self: Rc<Self>) -> ObjectRep {
+        p262_get_slot::<ObjectRep>(self, "BoundTargetFunction".to_string())
+            .expect("Bound functions must have a [[BoundTargetFunction]] slot")
+            .as_ref()
+            .clone()
+    }
+
+    fn arguments(self: Rc<Self>, arguments_list: &[Value]) -> Vec<Value> {
+        let bound_args = p262_get_slot::<Vec<Value>>(self, "BoundArguments".to_string())
+            .expect("Bound functions must have a [[BoundArguments]] slot");
+        bound_args.iter().chain(arguments_list).cloned().collect()
+    }
+}
+
+impl Object for BoundFunction {
+    fn get_slot(self: Rc<Self>, key: String) -> Option<Rc<dyn Any>> {
+        self.base.clone().get_slot(key)
+    }
+
+    fn set_slot(self: Rc<Self>, key: String, value: Rc<dyn Any>) -> bool {
+        self.base.clone().set_slot(key, value)
+    }
+
+    fn get_prototype_of(self: Rc<Self>) -> CoreResult<Option<ObjectRep>> {
+        Ok(e262_ordinary_get_prototype_of(self))
+    }
+
+    fn set_prototype_of(self: Rc<Self>, proto: Option<ObjectRep>) -> bool {
+        e262_ordinary_set_prototype_of(self, proto)
+    }
+
+    fn is_extensible(self: Rc<Self>) -> CoreResult<bool> {
+        Ok(e262_ordinary_is_extensible(self))
+    }
+
+    fn prevent_extensions(self: Rc<Self>) -> CoreResult<bool> {
+        Ok(e262_ordinary_prevent_extensions(self))
+    }
+
+    fn get_own_property(self: Rc<Self>, key: &PropertyKey) -> CoreResult<Option<Property>> {
+        Ok(e262_ordinary_get_own_property(self, key))
+    }
+
+    fn define_own_property(self: Rc<Self>, key: PropertyKey, desc: Descriptor) -> CoreResult<bool> {
+        e262_ordinary_define_own_property(self, &key, desc)
+    }
+
+    fn has_property(self: Rc<Self>, key: &PropertyKey) -> CoreResult<bool> {
+        e262_ordinary_has_property(self, key)
+    }
+
+    fn get(self: Rc<Self>, key: &PropertyKey, receiver: &Value) -> CoreResult<Value> {
+        e262_ordinary_get(self, key, receiver)
+    }
+
+    fn set(self: Rc<Self>, key: &PropertyKey, value: Value, receiver: &Value) -> CoreResult<bool> {
+        e262_ordinary_set(self, key, value, receiver)
+    }
+
+    fn delete(self: Rc<Self>, key: &PropertyKey) -> CoreResult<bool> {
+        e262_ordinary_delete(self, key)
+    }
+
+    fn own_property_keys(self: Rc<Self>) -> CoreResult<Vec<PropertyKey>> {
+        Ok(e262_ordinary_own_property_keys(self))
+    }
+
+    fn as_callable(self: Rc<Self>) -> Option<Rc<dyn Callable>> {
+        Some(self)
+    }
+
+    fn as_constructor(self: Rc<Self>) -> Option<Rc<dyn Constructor>> {
+        let target = self.clone().target();
+        match target.0.clone().as_constructor() {
+            Some(_) => Some(self),
+            None => None,
+        }
+    }
+}
+
+impl HasBaseObject for BoundFunction {
+    fn get_object(self: Rc<Self>) -> Rc<BaseObject> {
+        self.base.clone()
+    }
+}
+
+impl Callable for BoundFunction {
+    fn call(self: Rc<Self>, _: &Value, arguments_list: &[Value]) -> CoreResult<Value> {
+        let bound_this = p262_get_slot::<Value>(self.clone(), "BoundThis".to_string())
+            .expect("Bound functions must have a [[BoundThis]] slot");
+        let args = self.clone().arguments(arguments_list);
+        e262_call(&Value::Object(self.target()), &bound_this, &args)
+    }
+}
+
+impl Constructor for BoundFunction {
+    fn construct(
+        self: Rc<Self>,
+        arguments_list: &[Value],
+        new_target: &ObjectRep,
+    ) -> CoreResult<ObjectRep> {
+        let target = self.clone().target();
+        let args = self.clone().arguments(arguments_list);
+        let new_target = if *new_target == ObjectRep::new(self) {
+            &target
+        } else {
+            new_target
+        };
+        e262_construct(&target, &args, Some(new_target))
+    }
+}
+
+pub(crate) fn e262_bound_function_create(
+    target_function: ObjectRep,
+    bound_this: Value,
+    bound_args: Vec<Value>,
+) -> CoreResult<ObjectRep> {
+    let prototype = target_function.0.clone().get_prototype_of()?;
+    let base = Rc::new(BaseObject::new(&prototype));
+    base.clone()
+        .set_slot("BoundTargetFunction".to_string(), Rc::new(target_function));
+    base.clone()
+        .set_slot("BoundThis".to_string(), Rc::new(bound_this));
+    base.clone()
+        .set_slot("BoundArguments".to_string(), Rc::new(bound_args));
+    Ok(ObjectRep::new(Rc::new(BoundFunction { base })))
+}
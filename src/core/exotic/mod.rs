@@ -0,0 +1,9 @@
+//! Implementations of [exotic objects](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#exotic-object).
+
+mod array;
+mod bound_function;
+mod string;
+
+pub use self::array::*;
+pub use self::bound_function::*;
+pub use self::string::*;
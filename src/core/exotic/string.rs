@@ -0,0 +1,151 @@
+use std::any::Any;
+use std::rc::Rc;
+
+use crate::core::property::Descriptor;
+use crate::core::{
+    e262_define_property_or_throw, e262_is_compatible_property_descriptor,
+    e262_ordinary_define_own_property, e262_ordinary_delete, e262_ordinary_get,
+    e262_ordinary_get_own_property, e262_ordinary_get_prototype_of, e262_ordinary_has_property,
+    e262_ordinary_is_extensible, e262_ordinary_own_property_keys, e262_ordinary_prevent_extensions,
+    e262_ordinary_set, e262_ordinary_set_prototype_of, p262_get_slot, BaseObject, HasBaseObject,
+    Object, ObjectRep, Property, PropertyKey, StringRep, Value,
+};
+use crate::errors::CoreResult;
+
+/// The internal implementation of a [String exotic object](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-string-exotic-objects).
+///
+/// The wrapped string lives in the `[[StringData]]` slot.
+#[derive(Debug)]
+pub struct StringObject {
+    base: Rc<BaseObject>,
+}
+
+impl StringObject {
+    fn string_data(self: Rc<Self>) -> Rc<StringRep> {
+        p262_get_slot::<StringRep>(self, "StringData".to_string())
+            .expect("String exotic objects must have a [[StringData]] slot")
+    }
+}
+
+impl Object for StringObject {
+    fn get_slot(self: Rc<Self>, key: String) -> Option<Rc<dyn Any>> {
+        self.base.clone().get_slot(key)
+    }
+
+    fn set_slot(self: Rc<Self>, key: String, value: Rc<dyn Any>) -> bool {
+        self.base.clone().set_slot(key, value)
+    }
+
+    fn get_prototype_of(self: Rc<Self>) -> CoreResult<Option<ObjectRep>> {
+        Ok(e262_ordinary_get_prototype_of(self))
+    }
+
+    fn set_prototype_of(self: Rc<Self>, proto: Option<ObjectRep>) -> bool {
+        e262_ordinary_set_prototype_of(self, proto)
+    }
+
+    fn is_extensible(self: Rc<Self>) -> CoreResult<bool> {
+        Ok(e262_ordinary_is_extensible(self))
+    }
+
+    fn prevent_extensions(self: Rc<Self>) -> CoreResult<bool> {
+        Ok(e262_ordinary_prevent_extensions(self))
+    }
+
+    fn get_own_property(self: Rc<Self>, key: &PropertyKey) -> CoreResult<Option<Property>> {
+        let desc = e262_ordinary_get_own_property(self.clone(), key);
+        match desc {
+            Some(desc) => Ok(Some(desc)),
+            None => Ok(e262_string_get_own_property(self, key)),
+        }
+    }
+
+    fn define_own_property(self: Rc<Self>, key: PropertyKey, desc: Descriptor) -> CoreResult<bool> {
+        let string_desc = e262_string_get_own_property(self.clone(), &key);
+        match string_desc {
+            Some(string_desc) => {
+                let extensible = e262_ordinary_is_extensible(self);
+                Ok(e262_is_compatible_property_descriptor(
+                    extensible,
+                    &desc,
+                    Some(string_desc),
+                ))
+            }
+            None => e262_ordinary_define_own_property(self, &key, desc),
+        }
+    }
+
+    fn has_property(self: Rc<Self>, key: &PropertyKey) -> CoreResult<bool> {
+        e262_ordinary_has_property(self, key)
+    }
+
+    fn get(self: Rc<Self>, key: &PropertyKey, receiver: &Value) -> CoreResult<Value> {
+        e262_ordinary_get(self, key, receiver)
+    }
+
+    fn set(self: Rc<Self>, key: &PropertyKey, value: Value, receiver: &Value) -> CoreResult<bool> {
+        e262_ordinary_set(self, key, value, receiver)
+    }
+
+    fn delete(self: Rc<Self>, key: &PropertyKey) -> CoreResult<bool> {
+        e262_ordinary_delete(self, key)
+    }
+
+    fn own_property_keys(self: Rc<Self>) -> CoreResult<Vec<PropertyKey>> {
+        let len = self.clone().string_data().len();
+        let mut keys: Vec<PropertyKey> = (0..len)
+            .map(|index| PropertyKey::from(index as u32))
+            .collect();
+        keys.extend(e262_ordinary_own_property_keys(self));
+        Ok(keys)
+    }
+}
+
+impl HasBaseObject for StringObject {
+    fn get_object(self: Rc<Self>) -> Rc<BaseObject> {
+        self.base.clone()
+    }
+}
+
+pub(crate) fn e262_string_create(value: StringRep, prototype: Option<ObjectRep>) -> ObjectRep {
+    let length = value.len();
+    let base = Rc::new(BaseObject::new(&prototype));
+    base.clone()
+        .set_slot("StringData".to_string(), Rc::new(value));
+    let obj = ObjectRep::new(Rc::new(StringObject { base }));
+    e262_define_property_or_throw(
+        &obj,
+        PropertyKey::from("length"),
+        Descriptor {
+            value: Some(Rc::new(Value::Number(length as f64))),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(false),
+            ..Default::default()
+        },
+    )
+    .expect("String exotic objects must have a length");
+    obj
+}
+
+pub(crate) fn e262_string_get_own_property(
+    obj: Rc<StringObject>,
+    key: &PropertyKey,
+) -> Option<Property> {
+    // Only array indices can be in range, as String lengths are below 2³² − 1.
+    let PropertyKey::Index(index) = key else {
+        return None;
+    };
+    let string_data = obj.string_data();
+    let index = *index as usize;
+    if index >= string_data.len() {
+        return None;
+    }
+    let result_str = string_data.substring(index, index + 1);
+    Some(Property::Data {
+        value: Rc::new(Value::String(result_str)),
+        writable: false,
+        enumerable: true,
+        configurable: false,
+    })
+}
@@ -0,0 +1,1939 @@
+//! A pure-Rust port of the [fdlibm](https://www.netlib.org/fdlibm/) algorithms behind the `Math` functions.
+//!
+//! Only IEEE 754 basic operations are used, so every function returns the same bits on every platform, unlike the platform libm behind [`f64`]'s methods.
+//!
+//! Copyright (C) 1993-2004 by Sun Microsystems, Inc. All rights reserved.
+//!
+//! Permission to use, copy, modify, and distribute this software is freely granted, provided that this notice is preserved.
+
+// The constants are kept exactly as fdlibm spells them.
+#![allow(clippy::excessive_precision, clippy::approx_constant)]
+
+/// Returns the high 32 bits of `x`.
+fn high_word(x: f64) -> u32 {
+    (x.to_bits() >> 32) as u32
+}
+
+/// Returns the low 32 bits of `x`.
+fn low_word(x: f64) -> u32 {
+    x.to_bits() as u32
+}
+
+/// Builds a double from its high and low 32 bits.
+fn from_words(high: u32, low: u32) -> f64 {
+    f64::from_bits((high as u64) << 32 | low as u64)
+}
+
+/// Replaces the high 32 bits of `x`.
+fn with_high_word(x: f64, high: u32) -> f64 {
+    from_words(high, low_word(x))
+}
+
+/// Replaces the low 32 bits of `x`.
+fn with_low_word(x: f64, low: u32) -> f64 {
+    from_words(high_word(x), low)
+}
+
+/// Returns `x × 2^n`, rounding once even when the result is subnormal.
+pub(crate) fn scalbn(x: f64, mut n: i32) -> f64 {
+    let mut y = x;
+    if n > 1023 {
+        y *= f64::from_bits(0x7FE0_0000_0000_0000);
+        n -= 1023;
+        if n > 1023 {
+            y *= f64::from_bits(0x7FE0_0000_0000_0000);
+            n -= 1023;
+            n = n.min(1023);
+        }
+    } else if n < -1022 {
+        // Makes sure the final n < -53 to avoid double rounding in the subnormal range.
+        y *= f64::from_bits(0x0010_0000_0000_0000) * f64::from_bits(0x4340_0000_0000_0000);
+        n += 1022 - 53;
+        if n < -1022 {
+            y *= f64::from_bits(0x0010_0000_0000_0000) * f64::from_bits(0x4340_0000_0000_0000);
+            n += 1022 - 53;
+            n = n.max(-1022);
+        }
+    }
+    y * f64::from_bits(((0x3FF + n) as u64) << 52)
+}
+
+const TWO24: f64 = 16777216.0;
+const TWO_M24: f64 = 5.9604644775390625e-08;
+
+/// The 24-bit chunks of the fraction of 2/π.
+const IPIO2: [i32; 66] = [
+    0xA2F983, 0x6E4E44, 0x1529FC, 0x2757D1, 0xF534DD, 0xC0DB62, 0x95993C, 0x439041, 0xFE5163,
+    0xABDEBB, 0xC561B7, 0x246E3A, 0x424DD2, 0xE00649, 0x2EEA09, 0xD1921C, 0xFE1DEB, 0x1CB129,
+    0xA73EE8, 0x8235F5, 0x2EBB44, 0x84E99C, 0x7026B4, 0x5F7E41, 0x3991D6, 0x398353, 0x39F49C,
+    0x845F8B, 0xBDF928, 0x3B1FF8, 0x97FFDE, 0x05980F, 0xEF2F11, 0x8B5A0A, 0x6D1F6D, 0x367ECF,
+    0x27CB09, 0xB74F46, 0x3F669E, 0x5FEA2D, 0x7527BA, 0xC7EBE5, 0xF17B3D, 0x0739F7, 0x8A5292,
+    0xEA6BFB, 0x5FB11F, 0x8D5D08, 0x560330, 0x46FC7B, 0x6BABF0, 0xCFBC20, 0x9AF436, 0x1DA9E3,
+    0x91615E, 0xE61B08, 0x659985, 0x5F14A0, 0x68408D, 0xFFD880, 0x4D7327, 0x310606, 0x1556CA,
+    0x73A8C9, 0x60E27B, 0xC08C6B,
+];
+
+/// π/2 split into 24-bit chunks.
+const PIO2: [f64; 8] = [
+    1.57079625129699707031e+00,
+    7.54978941586159635335e-08,
+    5.39030252995776476554e-15,
+    3.28200341580791294123e-22,
+    1.27065575308067607349e-29,
+    1.22933308981111328932e-36,
+    2.73370053816464559624e-44,
+    2.16741683877804819444e-51,
+];
+
+/// Reduces a large `x`, given as 24-bit chunks scaled by `2^-e0`, modulo π/2 with double-double precision.
+///
+/// Returns the last three bits of the quotient and the remainder `y[0] + y[1]`.
+fn rem_pio2_large(x: &[f64], e0: i32) -> (i32, [f64; 2]) {
+    // The number of terms of 2/π needed for double precision.
+    const JK: usize = 4;
+    let jp = JK;
+    let mut iq = [0i32; 20];
+    let mut f = [0f64; 20];
+    let mut fq = [0f64; 20];
+    let mut q = [0f64; 20];
+
+    let jx = x.len() - 1;
+    let jv = ((e0 - 3) / 24).max(0) as usize;
+    let mut q0 = e0 - 24 * (jv as i32 + 1);
+
+    // Sets up f[0] to f[jx+jk] where f[jx+jk] = IPIO2[jv+jk].
+    for (j, value) in (jv as i32 - jx as i32..).zip(f.iter_mut().take(jx + JK + 1)) {
+        *value = if j < 0 { 0.0 } else { IPIO2[j as usize] as f64 };
+    }
+    for i in 0..=JK {
+        let mut fw = 0.0;
+        for j in 0..=jx {
+            fw += x[j] * f[jx + i - j];
+        }
+        q[i] = fw;
+    }
+
+    let mut jz = JK;
+    loop {
+        // Distills q[] into iq[] reversingly.
+        let mut z = q[jz];
+        let mut i = 0;
+        let mut j = jz;
+        while j > 0 {
+            let fw = ((TWO_M24 * z) as i32) as f64;
+            iq[i] = (z - TWO24 * fw) as i32;
+            z = q[j - 1] + fw;
+            i += 1;
+            j -= 1;
+        }
+
+        // Computes n.
+        z = scalbn(z, q0);
+        z -= 8.0 * (z * 0.125).floor();
+        let mut n = z as i32;
+        z -= n as f64;
+        let mut ih = 0;
+        if q0 > 0 {
+            let i = iq[jz - 1] >> (24 - q0);
+            n += i;
+            iq[jz - 1] -= i << (24 - q0);
+            ih = iq[jz - 1] >> (23 - q0);
+        } else if q0 == 0 {
+            ih = iq[jz - 1] >> 23;
+        } else if z >= 0.5 {
+            ih = 2;
+        }
+
+        if ih > 0 {
+            // q > 0.5, so computes 1 - q.
+            n += 1;
+            let mut carry = 0;
+            for digit in iq.iter_mut().take(jz) {
+                let j = *digit;
+                if carry == 0 {
+                    if j != 0 {
+                        carry = 1;
+                        *digit = 0x1000000 - j;
+                    }
+                } else {
+                    *digit = 0xffffff - j;
+                }
+            }
+            match q0 {
+                1 => iq[jz - 1] &= 0x7fffff,
+                2 => iq[jz - 1] &= 0x3fffff,
+                _ => {}
+            }
+            if ih == 2 {
+                z = 1.0 - z;
+                if carry != 0 {
+                    z -= scalbn(1.0, q0);
+                }
+            }
+        }
+
+        // Checks whether recomputation is needed.
+        if z == 0.0 && iq[JK..jz].iter().all(|&digit| digit == 0) {
+            let mut k = 1;
+            while iq[JK - k] == 0 {
+                k += 1;
+            }
+            for i in jz + 1..=jz + k {
+                f[jx + i] = IPIO2[jv + i] as f64;
+                let mut fw = 0.0;
+                for j in 0..=jx {
+                    fw += x[j] * f[jx + i - j];
+                }
+                q[i] = fw;
+            }
+            jz += k;
+            continue;
+        }
+
+        // Chops off zero terms.
+        if z == 0.0 {
+            jz -= 1;
+            q0 -= 24;
+            while iq[jz] == 0 {
+                jz -= 1;
+                q0 -= 24;
+            }
+        } else {
+            // Breaks z into 24-bit chunks if necessary.
+            z = scalbn(z, -q0);
+            if z >= TWO24 {
+                let fw = ((TWO_M24 * z) as i32) as f64;
+                iq[jz] = (z - TWO24 * fw) as i32;
+                jz += 1;
+                q0 += 24;
+                iq[jz] = fw as i32;
+            } else {
+                iq[jz] = z as i32;
+            }
+        }
+
+        // Converts the integer chunks to floating-point values.
+        let mut fw = scalbn(1.0, q0);
+        for i in (0..=jz).rev() {
+            q[i] = fw * iq[i] as f64;
+            fw *= TWO_M24;
+        }
+
+        // Computes PIO2[0..=jp] * q[jz..=0].
+        for i in (0..=jz).rev() {
+            let mut fw = 0.0;
+            let mut k = 0;
+            while k <= jp && k <= jz - i {
+                fw += PIO2[k] * q[i + k];
+                k += 1;
+            }
+            fq[jz - i] = fw;
+        }
+
+        // Compresses fq[] into y[].
+        let mut fw = 0.0;
+        for i in (0..=jz).rev() {
+            fw += fq[i];
+        }
+        let y0 = if ih == 0 { fw } else { -fw };
+        fw = fq[0] - fw;
+        for value in fq.iter().take(jz + 1).skip(1) {
+            fw += value;
+        }
+        let y1 = if ih == 0 { fw } else { -fw };
+        return (n & 7, [y0, y1]);
+    }
+}
+
+/// Reduces `x` modulo π/2, returning the quotient `n` and the remainder `y[0] + y[1]` in `[-π/4, π/4]`.
+fn rem_pio2(x: f64) -> (i32, [f64; 2]) {
+    const TOINT: f64 = 6755399441055744.0;
+    const PIO4: f64 = 7.85398163397448278999e-01;
+    const INVPIO2: f64 = 6.36619772367581382433e-01;
+    const PIO2_1: f64 = 1.57079632673412561417e+00;
+    const PIO2_1T: f64 = 6.07710050650619224932e-11;
+    const PIO2_2: f64 = 6.07710050630396597660e-11;
+    const PIO2_2T: f64 = 2.02226624879595063154e-21;
+    const PIO2_3: f64 = 2.02226624871116645580e-21;
+    const PIO2_3T: f64 = 8.47842766036889956997e-32;
+
+    let sign = x.is_sign_negative();
+    let ix = high_word(x) & 0x7fffffff;
+
+    // Subtracts k·π/2 in one round, good to 85 bits.
+    let small = |k: f64| {
+        if !sign {
+            let z = x - k * PIO2_1;
+            let y0 = z - k * PIO2_1T;
+            (k as i32, [y0, (z - y0) - k * PIO2_1T])
+        } else {
+            let z = x + k * PIO2_1;
+            let y0 = z + k * PIO2_1T;
+            (-k as i32, [y0, (z - y0) + k * PIO2_1T])
+        }
+    };
+
+    // Close to a multiple of π/2, one round cancels, so the medium case is used.
+    let medium = if ix <= 0x400f6a7a {
+        // |x| ~<= 5π/4.
+        if ix & 0xfffff != 0x921fb {
+            return small(if ix <= 0x4002d97c { 1.0 } else { 2.0 });
+        }
+        true
+    } else if ix <= 0x401c463b {
+        // |x| ~<= 9π/4.
+        if ix <= 0x4015fdbc && ix != 0x4012d97c {
+            return small(3.0);
+        }
+        if ix > 0x4015fdbc && ix != 0x401921fb {
+            return small(4.0);
+        }
+        true
+    } else {
+        ix < 0x413921fb
+    };
+    if medium {
+        // |x| ~< 2^20·π/2, or close to a multiple of π/2 where one round cancels.
+        let mut f_n = x * INVPIO2 + TOINT - TOINT;
+        let mut n = f_n as i32;
+        let mut r = x - f_n * PIO2_1;
+        let mut w = f_n * PIO2_1T;
+        if r - w < -PIO4 {
+            n -= 1;
+            f_n -= 1.0;
+            r = x - f_n * PIO2_1;
+            w = f_n * PIO2_1T;
+        } else if r - w > PIO4 {
+            n += 1;
+            f_n += 1.0;
+            r = x - f_n * PIO2_1;
+            w = f_n * PIO2_1T;
+        }
+        let mut y0 = r - w;
+        let ex = (ix >> 20) as i32;
+        let ey = ((y0.to_bits() >> 52) & 0x7ff) as i32;
+        if ex - ey > 16 {
+            // A second round, good to 118 bits.
+            let t = r;
+            w = f_n * PIO2_2;
+            r = t - w;
+            w = f_n * PIO2_2T - ((t - r) - w);
+            y0 = r - w;
+            let ey = ((y0.to_bits() >> 52) & 0x7ff) as i32;
+            if ex - ey > 49 {
+                // A third round, good to 151 bits, covering all cases.
+                let t = r;
+                w = f_n * PIO2_3;
+                r = t - w;
+                w = f_n * PIO2_3T - ((t - r) - w);
+                y0 = r - w;
+            }
+        }
+        return (n, [y0, (r - y0) - w]);
+    }
+    if ix >= 0x7ff00000 {
+        // x is infinite or NaN.
+        return (0, [f64::NAN, f64::NAN]);
+    }
+
+    // Sets z = scalbn(|x|, -ilogb(x) + 23) and splits it into 24-bit chunks.
+    let mut z = f64::from_bits((x.to_bits() & (u64::MAX >> 12)) | ((0x3ff + 23) << 52));
+    let mut tx = [0f64; 3];
+    for value in tx.iter_mut().take(2) {
+        *value = (z as i32) as f64;
+        z = (z - *value) * TWO24;
+    }
+    tx[2] = z;
+    let mut nx = 3;
+    while tx[nx - 1] == 0.0 {
+        nx -= 1;
+    }
+    let (n, y) = rem_pio2_large(&tx[..nx], (ix >> 20) as i32 - (0x3ff + 23));
+    if sign {
+        (-n, [-y[0], -y[1]])
+    } else {
+        (n, y)
+    }
+}
+
+/// Computes cos(x + y) on `[-π/4, π/4]`, where `y` is the tail of `x`.
+fn k_cos(x: f64, y: f64) -> f64 {
+    const C1: f64 = 4.16666666666666019037e-02;
+    const C2: f64 = -1.38888888888741095749e-03;
+    const C3: f64 = 2.48015872894767294178e-05;
+    const C4: f64 = -2.75573143513906633035e-07;
+    const C5: f64 = 2.08757232129817482790e-09;
+    const C6: f64 = -1.13596475577881948265e-11;
+
+    let ix = high_word(x) & 0x7fffffff;
+    // |x| < 2^-27.
+    if ix < 0x3e400000 {
+        return 1.0;
+    }
+    let z = x * x;
+    let r = z * (C1 + z * (C2 + z * (C3 + z * (C4 + z * (C5 + z * C6)))));
+    // |x| < 0.3.
+    if ix < 0x3fd33333 {
+        return 1.0 - (0.5 * z - (z * r - x * y));
+    }
+    // 1 - x²/2 is computed as (1 - qx) - (x²/2 - qx), with qx close to x²/2, for accuracy.
+    let qx = if ix > 0x3fe90000 {
+        0.28125
+    } else {
+        from_words(ix - 0x00200000, 0)
+    };
+    let hz = 0.5 * z - qx;
+    let a = 1.0 - qx;
+    a - (hz - (z * r - x * y))
+}
+
+/// Computes sin(x + y) on `[-π/4, π/4]`, where `y` is the tail of `x`, ignored unless `has_tail`.
+fn k_sin(x: f64, y: f64, has_tail: bool) -> f64 {
+    const S1: f64 = -1.66666666666666324348e-01;
+    const S2: f64 = 8.33333333332248946124e-03;
+    const S3: f64 = -1.98412698298579493134e-04;
+    const S4: f64 = 2.75573137070700676789e-06;
+    const S5: f64 = -2.50507602534068634195e-08;
+    const S6: f64 = 1.58969099521155010221e-10;
+
+    // |x| < 2^-27.
+    if high_word(x) & 0x7fffffff < 0x3e400000 {
+        return x;
+    }
+    let z = x * x;
+    let v = z * x;
+    let r = S2 + z * (S3 + z * (S4 + z * (S5 + z * S6)));
+    if !has_tail {
+        x + v * (S1 + z * r)
+    } else {
+        x - ((z * (0.5 * y - v * r) - y) - v * S1)
+    }
+}
+
+/// Computes tan(x + y) on `[-π/4, π/4]`, or -1/tan(x + y) if `odd`.
+fn k_tan(mut x: f64, mut y: f64, odd: bool) -> f64 {
+    const T: [f64; 13] = [
+        3.33333333333334091986e-01,
+        1.33333333333201242699e-01,
+        5.39682539762260521377e-02,
+        2.18694882948595424599e-02,
+        8.86323982359930005737e-03,
+        3.59207910759131235356e-03,
+        1.45620945432529025516e-03,
+        5.88041240820264096874e-04,
+        2.46463134818469906812e-04,
+        7.81794442939557092300e-05,
+        7.14072491382608190305e-05,
+        -1.85586374855275456654e-05,
+        2.59073051863633712884e-05,
+    ];
+    const PIO4: f64 = 7.85398163397448278999e-01;
+    const PIO4LO: f64 = 3.06161699786838301793e-17;
+
+    let hx = high_word(x);
+    // |x| >= 0.6744.
+    let big = hx & 0x7fffffff >= 0x3FE59428;
+    let negative = hx >> 31 != 0;
+    if big {
+        if negative {
+            x = -x;
+            y = -y;
+        }
+        x = (PIO4 - x) + (PIO4LO - y);
+        y = 0.0;
+    }
+    let z = x * x;
+    let w = z * z;
+    let r = T[1] + w * (T[3] + w * (T[5] + w * (T[7] + w * (T[9] + w * T[11]))));
+    let v = z * (T[2] + w * (T[4] + w * (T[6] + w * (T[8] + w * (T[10] + w * T[12])))));
+    let s = z * x;
+    let r = y + z * (s * (r + v) + y) + s * T[0];
+    let w = x + r;
+    if big {
+        let s = if odd { -1.0 } else { 1.0 };
+        let v = s - 2.0 * (x + (r - w * w / (w + s)));
+        return if negative { -v } else { v };
+    }
+    if !odd {
+        return w;
+    }
+    // -1/(x + r) has up to 2ulp error, so it is computed accurately.
+    let w0 = with_low_word(w, 0);
+    let v = r - (w0 - x);
+    let a = -1.0 / w;
+    let a0 = with_low_word(a, 0);
+    a0 + a * (1.0 + a0 * w0 + a0 * v)
+}
+
+/// Computes the sine of `x`.
+pub(crate) fn sin(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+    if ix <= 0x3fe921fb {
+        return k_sin(x, 0.0, false);
+    }
+    if ix >= 0x7ff00000 {
+        return f64::NAN;
+    }
+    let (n, y) = rem_pio2(x);
+    match n & 3 {
+        0 => k_sin(y[0], y[1], true),
+        1 => k_cos(y[0], y[1]),
+        2 => -k_sin(y[0], y[1], true),
+        _ => -k_cos(y[0], y[1]),
+    }
+}
+
+/// Computes the cosine of `x`.
+pub(crate) fn cos(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+    if ix <= 0x3fe921fb {
+        return k_cos(x, 0.0);
+    }
+    if ix >= 0x7ff00000 {
+        return f64::NAN;
+    }
+    let (n, y) = rem_pio2(x);
+    match n & 3 {
+        0 => k_cos(y[0], y[1]),
+        1 => -k_sin(y[0], y[1], true),
+        2 => -k_cos(y[0], y[1]),
+        _ => k_sin(y[0], y[1], true),
+    }
+}
+
+/// Computes the tangent of `x`.
+pub(crate) fn tan(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+    if ix <= 0x3fe921fb {
+        // |x| < 2^-27.
+        if ix < 0x3e400000 {
+            return x;
+        }
+        return k_tan(x, 0.0, false);
+    }
+    if ix >= 0x7ff00000 {
+        return f64::NAN;
+    }
+    let (n, y) = rem_pio2(x);
+    k_tan(y[0], y[1], n & 1 != 0)
+}
+
+const PIO2_HI: f64 = 1.57079632679489655800e+00;
+const PIO2_LO: f64 = 6.12323399573676603587e-17;
+
+/// The rational approximation of (asin(x) - x)/x³ used by [`asin`] and [`acos`], evaluated at `z = x²`.
+fn asin_r(z: f64) -> f64 {
+    const PS0: f64 = 1.66666666666666657415e-01;
+    const PS1: f64 = -3.25565818622400915405e-01;
+    const PS2: f64 = 2.01212532134862925881e-01;
+    const PS3: f64 = -4.00555345006794114027e-02;
+    const PS4: f64 = 7.91534994289814532176e-04;
+    const PS5: f64 = 3.47933107596021167570e-05;
+    const QS1: f64 = -2.40339491173441421878e+00;
+    const QS2: f64 = 2.02094576023350569471e+00;
+    const QS3: f64 = -6.88283971605453293030e-01;
+    const QS4: f64 = 7.70381505559019352791e-02;
+
+    let p = z * (PS0 + z * (PS1 + z * (PS2 + z * (PS3 + z * (PS4 + z * PS5)))));
+    let q = 1.0 + z * (QS1 + z * (QS2 + z * (QS3 + z * QS4)));
+    p / q
+}
+
+/// Computes the arcsine of `x`.
+pub(crate) fn asin(x: f64) -> f64 {
+    let hx = high_word(x);
+    let ix = hx & 0x7fffffff;
+    if ix >= 0x3ff00000 {
+        // |x| >= 1 or NaN.
+        if (ix - 0x3ff00000) | low_word(x) == 0 {
+            return x * PIO2_HI;
+        }
+        return f64::NAN;
+    }
+    if ix < 0x3fe00000 {
+        // |x| < 0.5.
+        if ix < 0x3e500000 {
+            return x;
+        }
+        return x + x * asin_r(x * x);
+    }
+    // 1 > |x| >= 0.5.
+    let z = (1.0 - x.abs()) * 0.5;
+    let s = z.sqrt();
+    let r = asin_r(z);
+    let y = if ix >= 0x3fef3333 {
+        // |x| > 0.975.
+        PIO2_HI - (2.0 * (s + s * r) - PIO2_LO)
+    } else {
+        // f + c = sqrt(z).
+        let f = with_low_word(s, 0);
+        let c = (z - f * f) / (s + f);
+        0.5 * PIO2_HI - (2.0 * s * r - (PIO2_LO - 2.0 * c) - (0.5 * PIO2_HI - 2.0 * f))
+    };
+    if hx >> 31 != 0 {
+        -y
+    } else {
+        y
+    }
+}
+
+/// Computes the arccosine of `x`.
+pub(crate) fn acos(x: f64) -> f64 {
+    let hx = high_word(x);
+    let ix = hx & 0x7fffffff;
+    if ix >= 0x3ff00000 {
+        // |x| >= 1 or NaN.
+        if (ix - 0x3ff00000) | low_word(x) == 0 {
+            return if hx >> 31 != 0 { 2.0 * PIO2_HI } else { 0.0 };
+        }
+        return f64::NAN;
+    }
+    if ix < 0x3fe00000 {
+        // |x| < 0.5.
+        if ix <= 0x3c600000 {
+            return PIO2_HI;
+        }
+        return PIO2_HI - (x - (PIO2_LO - x * asin_r(x * x)));
+    }
+    if hx >> 31 != 0 {
+        // x < -0.5.
+        let z = (1.0 + x) * 0.5;
+        let s = z.sqrt();
+        let w = asin_r(z) * s - PIO2_LO;
+        return 2.0 * (PIO2_HI - (s + w));
+    }
+    // x > 0.5.
+    let z = (1.0 - x) * 0.5;
+    let s = z.sqrt();
+    let df = with_low_word(s, 0);
+    let c = (z - df * df) / (s + df);
+    let w = asin_r(z) * s + c;
+    2.0 * (df + w)
+}
+
+/// Computes the arctangent of `x`.
+pub(crate) fn atan(x: f64) -> f64 {
+    const ATANHI: [f64; 4] = [
+        4.63647609000806093515e-01,
+        7.85398163397448278999e-01,
+        9.82793723247329054082e-01,
+        1.57079632679489655800e+00,
+    ];
+    const ATANLO: [f64; 4] = [
+        2.26987774529616870924e-17,
+        3.06161699786838301793e-17,
+        1.39033110312309984516e-17,
+        6.12323399573676603587e-17,
+    ];
+    const AT: [f64; 11] = [
+        3.33333333333329318027e-01,
+        -1.99999999998764832476e-01,
+        1.42857142725034663711e-01,
+        -1.11111104054623557880e-01,
+        9.09088713343650656196e-02,
+        -7.69187620504482999495e-02,
+        6.66107313738753120669e-02,
+        -5.83357013379057348645e-02,
+        4.97687799461593236017e-02,
+        -3.65315727442169155270e-02,
+        1.62858201153657823623e-02,
+    ];
+
+    let hx = high_word(x);
+    let negative = hx >> 31 != 0;
+    let ix = hx & 0x7fffffff;
+    if ix >= 0x44100000 {
+        // |x| >= 2^66.
+        if x.is_nan() {
+            return x;
+        }
+        return if negative { -ATANHI[3] } else { ATANHI[3] };
+    }
+    let (id, x) = if ix < 0x3fdc0000 {
+        // |x| < 0.4375.
+        if ix < 0x3e400000 {
+            return x;
+        }
+        (None, x)
+    } else {
+        let x = x.abs();
+        if ix < 0x3ff30000 {
+            if ix < 0x3fe60000 {
+                // 7/16 <= |x| < 11/16.
+                (Some(0), (2.0 * x - 1.0) / (2.0 + x))
+            } else {
+                // 11/16 <= |x| < 19/16.
+                (Some(1), (x - 1.0) / (x + 1.0))
+            }
+        } else if ix < 0x40038000 {
+            // |x| < 2.4375.
+            (Some(2), (x - 1.5) / (1.0 + 1.5 * x))
+        } else {
+            // 2.4375 <= |x| < 2^66.
+            (Some(3), -1.0 / x)
+        }
+    };
+    let z = x * x;
+    let w = z * z;
+    // The sum of AT[i]·z^(i+1), split into odd and even polynomials.
+    let s1 = z * (AT[0] + w * (AT[2] + w * (AT[4] + w * (AT[6] + w * (AT[8] + w * AT[10])))));
+    let s2 = w * (AT[1] + w * (AT[3] + w * (AT[5] + w * (AT[7] + w * AT[9]))));
+    let Some(id) = id else {
+        return x - x * (s1 + s2);
+    };
+    let z = ATANHI[id] - (x * (s1 + s2) - ATANLO[id] - x);
+    if negative {
+        -z
+    } else {
+        z
+    }
+}
+
+/// Computes the angle of the point `(x, y)`.
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    const PI: f64 = 3.1415926535897931160E+00;
+    const PI_LO: f64 = 1.2246467991473531772E-16;
+
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    let (ix, lx) = (high_word(x), low_word(x));
+    let (iy, ly) = (high_word(y), low_word(y));
+    if (ix.wrapping_sub(0x3ff00000)) | lx == 0 {
+        // x = 1.0.
+        return atan(y);
+    }
+    // 2·sign(x) + sign(y).
+    let m = ((iy >> 31) & 1) | ((ix >> 30) & 2);
+    let ix = ix & 0x7fffffff;
+    let iy = iy & 0x7fffffff;
+
+    if iy | ly == 0 {
+        return match m {
+            0 | 1 => y,
+            2 => PI,
+            _ => -PI,
+        };
+    }
+    if ix | lx == 0 {
+        return if m & 1 != 0 { -PI / 2.0 } else { PI / 2.0 };
+    }
+    if ix == 0x7ff00000 {
+        if iy == 0x7ff00000 {
+            return match m {
+                0 => PI / 4.0,
+                1 => -PI / 4.0,
+                2 => 3.0 * PI / 4.0,
+                _ => -3.0 * PI / 4.0,
+            };
+        }
+        return match m {
+            0 => 0.0,
+            1 => -0.0,
+            2 => PI,
+            _ => -PI,
+        };
+    }
+    if iy == 0x7ff00000 {
+        return if m & 1 != 0 { -PI / 2.0 } else { PI / 2.0 };
+    }
+    let k = (iy as i32 - ix as i32) >> 20;
+    let z = if k > 60 {
+        // |y/x| > 2^60.
+        PI / 2.0 + 0.5 * PI_LO
+    } else if m & 2 != 0 && k < -60 {
+        // |y|/x < -2^60.
+        0.0
+    } else {
+        atan((y / x).abs())
+    };
+    match m {
+        0 => z,
+        1 => -z,
+        2 => PI - (z - PI_LO),
+        _ => (z - PI_LO) - PI,
+    }
+}
+
+const LN2_HI: f64 = 6.93147180369123816490e-01;
+const LN2_LO: f64 = 1.90821492927058770002e-10;
+const INVLN2: f64 = 1.44269504088896338700e+00;
+
+/// Computes e raised to the power of `x`.
+pub(crate) fn exp(mut x: f64) -> f64 {
+    const P1: f64 = 1.66666666666666019037e-01;
+    const P2: f64 = -2.77777777770155933842e-03;
+    const P3: f64 = 6.61375632143793436117e-05;
+    const P4: f64 = -1.65339022054652515390e-06;
+    const P5: f64 = 4.13813679705723846039e-08;
+
+    let hx = high_word(x);
+    let negative = hx >> 31 != 0;
+    let hx = hx & 0x7fffffff;
+
+    if hx >= 0x4086232b {
+        // |x| >= 708.39.
+        if x.is_nan() {
+            return x;
+        }
+        if x > 709.782712893383973096 {
+            return x * f64::from_bits(0x7FE0_0000_0000_0000);
+        }
+        if x < -745.13321910194110842 {
+            return 0.0;
+        }
+    }
+
+    let (k, hi, lo) = if hx > 0x3fd62e42 {
+        // |x| > 0.5·ln2.
+        let k = if hx >= 0x3ff0a2b2 {
+            // |x| >= 1.5·ln2.
+            (INVLN2 * x + if negative { -0.5 } else { 0.5 }) as i32
+        } else if negative {
+            -1
+        } else {
+            1
+        };
+        let hi = x - k as f64 * LN2_HI;
+        let lo = k as f64 * LN2_LO;
+        x = hi - lo;
+        (k, hi, lo)
+    } else if hx > 0x3e300000 {
+        // |x| > 2^-28.
+        (0, x, 0.0)
+    } else {
+        return 1.0 + x;
+    };
+
+    // x is now in the primary range.
+    let xx = x * x;
+    let c = x - xx * (P1 + xx * (P2 + xx * (P3 + xx * (P4 + xx * P5))));
+    let y = 1.0 + (x * c / (2.0 - c) - lo + hi);
+    if k == 0 {
+        y
+    } else {
+        scalbn(y, k)
+    }
+}
+
+/// Computes e raised to the power of `x`, minus 1, accurately for `x` near 0.
+pub(crate) fn expm1(mut x: f64) -> f64 {
+    const O_THRESHOLD: f64 = 7.09782712893383973096e+02;
+    const Q1: f64 = -3.33333333333331316428e-02;
+    const Q2: f64 = 1.58730158725481460165e-03;
+    const Q3: f64 = -7.93650757867487942473e-05;
+    const Q4: f64 = 4.00821782732936239552e-06;
+    const Q5: f64 = -2.01099218183624371326e-07;
+
+    let hx = high_word(x) & 0x7fffffff;
+    let negative = x.is_sign_negative();
+
+    if hx >= 0x4043687A {
+        // |x| >= 56·ln2.
+        if x.is_nan() {
+            return x;
+        }
+        if negative {
+            return -1.0;
+        }
+        if x > O_THRESHOLD {
+            return x * f64::from_bits(0x7FE0_0000_0000_0000);
+        }
+    }
+
+    let mut c = 0.0;
+    let k = if hx > 0x3fd62e42 {
+        // |x| > 0.5·ln2.
+        let (k, hi, lo) = if hx < 0x3FF0A2B2 {
+            // |x| < 1.5·ln2.
+            if !negative {
+                (1, x - LN2_HI, LN2_LO)
+            } else {
+                (-1, x + LN2_HI, -LN2_LO)
+            }
+        } else {
+            let k = (INVLN2 * x + if negative { -0.5 } else { 0.5 }) as i32;
+            let t = k as f64;
+            (k, x - t * LN2_HI, t * LN2_LO)
+        };
+        x = hi - lo;
+        c = (hi - x) - lo;
+        k
+    } else if hx < 0x3c900000 {
+        // |x| < 2^-54.
+        return x;
+    } else {
+        0
+    };
+
+    // x is now in the primary range.
+    let hfx = 0.5 * x;
+    let hxs = x * hfx;
+    let r1 = 1.0 + hxs * (Q1 + hxs * (Q2 + hxs * (Q3 + hxs * (Q4 + hxs * Q5))));
+    let t = 3.0 - r1 * hfx;
+    let mut e = hxs * ((r1 - t) / (6.0 - x * t));
+    if k == 0 {
+        return x - (x * e - hxs);
+    }
+    e = x * (e - c) - c;
+    e -= hxs;
+    // exp(x) ~ 2^k·(x_reduced - e + 1).
+    if k == -1 {
+        return 0.5 * (x - e) - 0.5;
+    }
+    if k == 1 {
+        if x < -0.25 {
+            return -2.0 * (e - (x + 0.5));
+        }
+        return 1.0 + 2.0 * (x - e);
+    }
+    let twopk = f64::from_bits(((0x3ff + k) as u64) << 52);
+    if !(0..=56).contains(&k) {
+        let y = x - e + 1.0;
+        let y = if k == 1024 {
+            y * 2.0 * f64::from_bits(0x7FE0_0000_0000_0000)
+        } else {
+            y * twopk
+        };
+        return y - 1.0;
+    }
+    let uf = f64::from_bits(((0x3ff - k) as u64) << 52);
+    if k < 20 {
+        (x - e + (1.0 - uf)) * twopk
+    } else {
+        (x - (e + uf) + 1.0) * twopk
+    }
+}
+
+/// The reduced form of a positive finite argument of a logarithm, `x = 2^k·(1 + f)` with `1 + f` in `[√2/2, √2]`.
+struct LogReduction {
+    k: i32,
+    f: f64,
+    /// `f²/2`.
+    hfsq: f64,
+    /// `f/(2 + f)`.
+    s: f64,
+    /// The approximation of `log(1 + f) - f + f²/2 - s·f²/2`, divided by `s`.
+    r: f64,
+}
+
+/// Evaluates the polynomial approximation of [`LogReduction::r`] for `1 + f`.
+fn log_reduction(k: i32, f: f64) -> LogReduction {
+    const LG1: f64 = 6.666666666666735130e-01;
+    const LG2: f64 = 3.999999999940941908e-01;
+    const LG3: f64 = 2.857142874366239149e-01;
+    const LG4: f64 = 2.222219843214978396e-01;
+    const LG5: f64 = 1.818357216161805012e-01;
+    const LG6: f64 = 1.531383769920937332e-01;
+    const LG7: f64 = 1.479819860511658591e-01;
+
+    let hfsq = 0.5 * f * f;
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let t1 = w * (LG2 + w * (LG4 + w * LG6));
+    let t2 = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+    LogReduction {
+        k,
+        f,
+        hfsq,
+        s,
+        r: t2 + t1,
+    }
+}
+
+/// Reduces the argument of [`log2`], or returns the result directly for zeros, negative numbers, infinities, NaN and 1.
+fn reduce_log(mut x: f64) -> Result<LogReduction, f64> {
+    let mut bits = x.to_bits();
+    let mut hx = (bits >> 32) as u32;
+    let mut k = 0;
+    if hx < 0x00100000 || hx >> 31 != 0 {
+        if bits << 1 == 0 {
+            return Err(f64::NEG_INFINITY);
+        }
+        if hx >> 31 != 0 {
+            return Err(f64::NAN);
+        }
+        // Scales a subnormal x up.
+        k -= 54;
+        x *= 18014398509481984.0;
+        bits = x.to_bits();
+        hx = (bits >> 32) as u32;
+    } else if hx >= 0x7ff00000 {
+        return Err(x);
+    } else if hx == 0x3ff00000 && bits << 32 == 0 {
+        return Err(0.0);
+    }
+
+    // Reduces x into [√2/2, √2].
+    hx += 0x3ff00000 - 0x3fe6a09e;
+    k += (hx >> 20) as i32 - 0x3ff;
+    hx = (hx & 0x000fffff) + 0x3fe6a09e;
+    let x = from_words(hx, bits as u32);
+    Ok(log_reduction(k, x - 1.0))
+}
+
+/// Computes the natural logarithm of `x`.
+pub(crate) fn log(x: f64) -> f64 {
+    let mut x = x;
+    let mut hx = high_word(x) as i32;
+    let mut k = 0;
+    if hx < 0x00100000 {
+        // x < 2^-1022.
+        if x == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        if hx < 0 {
+            return f64::NAN;
+        }
+        // Subnormal, scales x up.
+        k -= 54;
+        x *= 18014398509481984.0;
+        hx = high_word(x) as i32;
+    }
+    if hx >= 0x7ff00000 {
+        return x + x;
+    }
+    k += (hx >> 20) - 1023;
+    hx &= 0x000fffff;
+    // Normalizes x or x/2 into [√2/2, √2].
+    let i = (hx + 0x95f64) & 0x100000;
+    let x = with_high_word(x, (hx | (i ^ 0x3ff00000)) as u32);
+    k += i >> 20;
+    let f = x - 1.0;
+    let dk = k as f64;
+    if (0x000fffff & (2 + hx)) < 3 {
+        // |f| < 2^-20.
+        if f == 0.0 {
+            return if k == 0 {
+                0.0
+            } else {
+                dk * LN2_HI + dk * LN2_LO
+            };
+        }
+        let r = f * f * (0.5 - 0.33333333333333333 * f);
+        return if k == 0 {
+            f - r
+        } else {
+            dk * LN2_HI - ((r - dk * LN2_LO) - f)
+        };
+    }
+    let LogReduction { hfsq, s, r, .. } = log_reduction(k, f);
+    if (hx - 0x6147a) | (0x6b851 - hx) > 0 {
+        if k == 0 {
+            f - (hfsq - s * (hfsq + r))
+        } else {
+            dk * LN2_HI - ((hfsq - (s * (hfsq + r) + dk * LN2_LO)) - f)
+        }
+    } else if k == 0 {
+        f - s * (f - r)
+    } else {
+        dk * LN2_HI - ((s * (f - r) - dk * LN2_LO) - f)
+    }
+}
+
+/// Splits `log(1 + f)` into `hi + lo`, where `hi` has its low word cleared.
+fn log_hi_lo(reduction: &LogReduction) -> (f64, f64) {
+    let LogReduction { f, hfsq, s, r, .. } = *reduction;
+    let hi = with_low_word(f - hfsq, 0);
+    let lo = f - hi - hfsq + s * (hfsq + r);
+    (hi, lo)
+}
+
+/// Computes the base 2 logarithm of `x`.
+pub(crate) fn log2(x: f64) -> f64 {
+    const IVLN2HI: f64 = 1.44269504072144627571e+00;
+    const IVLN2LO: f64 = 1.67517131648865118353e-10;
+
+    let reduction = match reduce_log(x) {
+        Ok(reduction) => reduction,
+        Err(result) => return result,
+    };
+    let (hi, lo) = log_hi_lo(&reduction);
+    let val_hi = hi * IVLN2HI;
+    let mut val_lo = (lo + hi) * IVLN2LO + lo * IVLN2HI;
+    let y = reduction.k as f64;
+    let w = y + val_hi;
+    val_lo += (y - w) + val_hi;
+    val_lo + w
+}
+
+/// Computes the base 10 logarithm of `x`.
+pub(crate) fn log10(x: f64) -> f64 {
+    const TWO54: f64 = 1.80143985094819840000e+16;
+    const IVLN10: f64 = 4.34294481903251816668e-01;
+    const LOG10_2HI: f64 = 3.01029995663611771306e-01;
+    const LOG10_2LO: f64 = 3.69423907715893078616e-13;
+
+    let mut x = x;
+    let mut hx = high_word(x) as i32;
+    let mut k = 0;
+    if hx < 0x00100000 {
+        // x < 2^-1022.
+        if x == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        if hx < 0 {
+            return f64::NAN;
+        }
+        // Subnormal, scales x up.
+        k -= 54;
+        x *= TWO54;
+        hx = high_word(x) as i32;
+    }
+    if hx >= 0x7ff00000 {
+        return x + x;
+    }
+    k += (hx >> 20) - 1023;
+    // Keeps the reduced x in [1, 2) for k >= 0 and in [0.5, 1) otherwise, so y·log10(2) and log10(x) have the same sign.
+    let i = ((k as u32) >> 31) as i32;
+    let y = (k + i) as f64;
+    let x = with_high_word(x, ((hx & 0x000fffff) | ((0x3ff - i) << 20)) as u32);
+    let z = y * LOG10_2LO + IVLN10 * log(x);
+    z + y * LOG10_2HI
+}
+
+/// Computes the natural logarithm of `1 + x`, accurately for `x` near 0.
+pub(crate) fn log1p(x: f64) -> f64 {
+    const LP: [f64; 7] = [
+        6.666666666666735130e-01,
+        3.999999999940941908e-01,
+        2.857142874366239149e-01,
+        2.222219843214978396e-01,
+        1.818357216161805012e-01,
+        1.531383769920937332e-01,
+        1.479819860511658591e-01,
+    ];
+
+    let hx = high_word(x) as i32;
+    let ax = hx & 0x7fffffff;
+    let mut k = 1;
+    let mut f = 0.0;
+    let mut hu = 0;
+    let mut c = 0.0;
+    if hx < 0x3fda827a {
+        // x < 0.41422.
+        if ax >= 0x3ff00000 {
+            // x <= -1.0.
+            return if x == -1.0 {
+                f64::NEG_INFINITY
+            } else {
+                f64::NAN
+            };
+        }
+        if ax < 0x3e200000 {
+            // |x| < 2^-29.
+            return if ax < 0x3c900000 { x } else { x - x * x * 0.5 };
+        }
+        if hx > 0 || hx <= 0xbfd2bec3u32 as i32 {
+            // -0.2929 < x < 0.41422.
+            k = 0;
+            f = x;
+            hu = 1;
+        }
+    }
+    if hx >= 0x7ff00000 {
+        return x + x;
+    }
+    if k != 0 {
+        let mut u;
+        if hx < 0x43400000 {
+            u = 1.0 + x;
+            hu = high_word(u) as i32;
+            k = (hu >> 20) - 1023;
+            // A correction term for the rounding of 1 + x.
+            c = if k > 0 { 1.0 - (u - x) } else { x - (u - 1.0) };
+            c /= u;
+        } else {
+            u = x;
+            hu = high_word(u) as i32;
+            k = (hu >> 20) - 1023;
+        }
+        hu &= 0x000fffff;
+        if hu < 0x6a09e {
+            // Normalizes u.
+            u = with_high_word(u, (hu | 0x3ff00000) as u32);
+        } else {
+            // Normalizes u/2.
+            k += 1;
+            u = with_high_word(u, (hu | 0x3fe00000) as u32);
+            hu = (0x00100000 - hu) >> 2;
+        }
+        f = u - 1.0;
+    }
+    let hfsq = 0.5 * f * f;
+    let dk = k as f64;
+    if hu == 0 {
+        // |f| < 2^-20.
+        if f == 0.0 {
+            return if k == 0 {
+                0.0
+            } else {
+                dk * LN2_HI + (c + dk * LN2_LO)
+            };
+        }
+        let r = hfsq * (1.0 - 0.66666666666666666 * f);
+        return if k == 0 {
+            f - r
+        } else {
+            dk * LN2_HI - ((r - (dk * LN2_LO + c)) - f)
+        };
+    }
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let r = z
+        * (LP[0] + z * (LP[1] + z * (LP[2] + z * (LP[3] + z * (LP[4] + z * (LP[5] + z * LP[6]))))));
+    if k == 0 {
+        f - (hfsq - s * (hfsq + r))
+    } else {
+        dk * LN2_HI - ((hfsq - (s * (hfsq + r) + (dk * LN2_LO + c))) - f)
+    }
+}
+
+/// The largest `|x|` for which sinh(x) and cosh(x) do not overflow.
+const HYPERBOLIC_OVERFLOW: f64 = f64::from_bits(0x408633CE8FB9F87D);
+
+/// Computes the hyperbolic sine of `x`.
+pub(crate) fn sinh(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+    if ix >= 0x7ff00000 {
+        return x + x;
+    }
+    let h = if x.is_sign_negative() { -0.5 } else { 0.5 };
+    let absx = x.abs();
+    if ix < 0x40360000 {
+        // |x| < 22.
+        if ix < 0x3e300000 {
+            // |x| < 2^-28, sinh(x) = x.
+            return x;
+        }
+        let t = expm1(absx);
+        if ix < 0x3ff00000 {
+            return h * (2.0 * t - t * t / (t + 1.0));
+        }
+        return h * (t + t / (t + 1.0));
+    }
+    if ix < 0x40862e42 {
+        // |x| < log(f64::MAX).
+        return h * exp(absx);
+    }
+    if absx <= HYPERBOLIC_OVERFLOW {
+        // exp(|x|) overflows but exp(|x|)/2 does not.
+        let w = exp(0.5 * absx);
+        let t = h * w;
+        return t * w;
+    }
+    x * f64::INFINITY
+}
+
+/// Computes the hyperbolic cosine of `x`.
+pub(crate) fn cosh(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+    if ix >= 0x7ff00000 {
+        return x * x;
+    }
+    let x = x.abs();
+    if ix < 0x3fd62e43 {
+        // |x| < log(2)/2, returns 1 + expm1(|x|)²/(2·exp(|x|)).
+        let t = expm1(x);
+        let w = 1.0 + t;
+        // |x| < 2^-55.
+        if ix < 0x3c800000 {
+            return w;
+        }
+        return 1.0 + (t * t) / (w + w);
+    }
+    if ix < 0x40360000 {
+        // |x| < 22.
+        let t = exp(x);
+        return 0.5 * t + 0.5 / t;
+    }
+    if ix < 0x40862e42 {
+        // |x| < log(f64::MAX).
+        return 0.5 * exp(x);
+    }
+    if x <= HYPERBOLIC_OVERFLOW {
+        // exp(|x|) overflows but exp(|x|)/2 does not.
+        let w = exp(0.5 * x);
+        let t = 0.5 * w;
+        return t * w;
+    }
+    f64::INFINITY
+}
+
+/// Computes the hyperbolic tangent of `x`.
+pub(crate) fn tanh(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+    if ix >= 0x7ff00000 {
+        // tanh(±∞) = ±1 and tanh(NaN) = NaN.
+        return if x.is_sign_negative() {
+            1.0 / x - 1.0
+        } else {
+            1.0 / x + 1.0
+        };
+    }
+    let z = if ix < 0x40360000 {
+        // |x| < 22.
+        if ix < 0x3c800000 {
+            // |x| < 2^-55, tanh(x) = x.
+            return x * (1.0 + x);
+        }
+        if ix >= 0x3ff00000 {
+            // |x| >= 1.
+            let t = expm1(2.0 * x.abs());
+            1.0 - 2.0 / (t + 2.0)
+        } else {
+            let t = expm1(-2.0 * x.abs());
+            -t / (t + 2.0)
+        }
+    } else {
+        1.0
+    };
+    if x.is_sign_negative() {
+        -z
+    } else {
+        z
+    }
+}
+
+const LN2: f64 = 0.693147180559945309417232121458176568;
+
+/// Computes the inverse hyperbolic sine of `x`.
+pub(crate) fn asinh(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+    if ix >= 0x7ff00000 {
+        return x + x;
+    }
+    if ix < 0x3e300000 {
+        // |x| < 2^-28.
+        return x;
+    }
+    let w = if ix > 0x41b00000 {
+        // |x| > 2^28.
+        log(x.abs()) + LN2
+    } else if ix > 0x40000000 {
+        // 2 < |x| <= 2^28.
+        let t = x.abs();
+        log(2.0 * t + 1.0 / ((x * x + 1.0).sqrt() + t))
+    } else {
+        let t = x * x;
+        log1p(x.abs() + t / (1.0 + (1.0 + t).sqrt()))
+    };
+    if x.is_sign_negative() {
+        -w
+    } else {
+        w
+    }
+}
+
+/// Computes the inverse hyperbolic cosine of `x`.
+pub(crate) fn acosh(x: f64) -> f64 {
+    let hx = high_word(x) as i32;
+    if hx < 0x3ff00000 {
+        // x < 1.
+        return f64::NAN;
+    }
+    if hx >= 0x41b00000 {
+        // x >= 2^28, infinite or NaN.
+        if hx >= 0x7ff00000 {
+            return x + x;
+        }
+        return log(x) + LN2;
+    }
+    if x == 1.0 {
+        return 0.0;
+    }
+    if hx > 0x40000000 {
+        // 2 < x < 2^28.
+        let t = x * x;
+        return log(2.0 * x - 1.0 / (x + (t - 1.0).sqrt()));
+    }
+    let t = x - 1.0;
+    log1p(t + (2.0 * t + t * t).sqrt())
+}
+
+/// Computes the inverse hyperbolic tangent of `x`.
+pub(crate) fn atanh(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+    let absx = x.abs();
+    if absx > 1.0 || x.is_nan() {
+        return f64::NAN;
+    }
+    if absx == 1.0 {
+        return x / 0.0;
+    }
+    if ix < 0x3e300000 {
+        // |x| < 2^-28.
+        return x;
+    }
+    let t = if ix < 0x3fe00000 {
+        // |x| < 0.5.
+        let t = absx + absx;
+        0.5 * log1p(t + t * absx / (1.0 - absx))
+    } else {
+        0.5 * log1p((absx + absx) / (1.0 - absx))
+    };
+    if x.is_sign_negative() {
+        -t
+    } else {
+        t
+    }
+}
+
+/// Computes the cube root of `x`.
+pub(crate) fn cbrt(x: f64) -> f64 {
+    // (682 - 0.03306235651)·2^20.
+    const B1: u32 = 715094163;
+    // (664 - 0.03306235651)·2^20.
+    const B2: u32 = 696219795;
+    const C: f64 = 5.42857142857142815906e-01;
+    const D: f64 = -7.05306122448979611050e-01;
+    const E: f64 = 1.41428571428571436819e+00;
+    const F: f64 = 1.60714285714285720630e+00;
+    const G: f64 = 3.57142857142857150787e-01;
+
+    let sign = high_word(x) & 0x80000000;
+    let hx = high_word(x) ^ sign;
+    if hx >= 0x7ff00000 {
+        return x + x;
+    }
+    if x == 0.0 {
+        return x;
+    }
+    let x = with_high_word(x, hx);
+
+    // A rough cube root to 5 bits.
+    let mut t = if hx < 0x00100000 {
+        // Subnormal, scales x up by 2^54.
+        let t = from_words(0x43500000, 0) * x;
+        with_high_word(t, high_word(t) / 3 + B2)
+    } else {
+        from_words(hx / 3 + B1, 0)
+    };
+
+    // A new cube root to 23 bits.
+    let r = t * t / x;
+    let s = C + r * t;
+    t *= G + F / (s + E + D / s);
+
+    // Chops t to 20 bits, making it larger than the cube root.
+    t = from_words(high_word(t) + 1, 0);
+
+    // One step of Newton's method to 53 bits, with an error below 0.667ulp.
+    let s = t * t;
+    let r = x / s;
+    let w = t + t;
+    let r = (r - t) / (w + r);
+    let t = t + t * r;
+    with_high_word(t, high_word(t) | sign)
+}
+
+/// Computes `sqrt(x² + y²)` without undue overflow or underflow.
+pub(crate) fn hypot(x: f64, y: f64) -> f64 {
+    let mut ha = (high_word(x) & 0x7fffffff) as i32;
+    let mut hb = (high_word(y) & 0x7fffffff) as i32;
+    let (mut a, mut b) = if hb > ha {
+        std::mem::swap(&mut ha, &mut hb);
+        (y, x)
+    } else {
+        (x, y)
+    };
+    a = with_high_word(a, ha as u32);
+    b = with_high_word(b, hb as u32);
+    if ha - hb > 0x3c00000 {
+        // a/b > 2^60.
+        return a + b;
+    }
+    let mut k = 0;
+    if ha > 0x5f300000 {
+        // a > 2^500.
+        if ha >= 0x7ff00000 {
+            // Infinite or NaN, where infinity wins.
+            let mut w = a + b;
+            if (ha as u32 & 0xfffff) | low_word(a) == 0 {
+                w = a;
+            }
+            if (hb as u32 ^ 0x7ff00000) | low_word(b) == 0 {
+                w = b;
+            }
+            return w;
+        }
+        // Scales a and b by 2^-600.
+        ha -= 0x25800000;
+        hb -= 0x25800000;
+        k += 600;
+        a = with_high_word(a, ha as u32);
+        b = with_high_word(b, hb as u32);
+    }
+    if hb < 0x20b00000 {
+        // b < 2^-500.
+        if hb <= 0x000fffff {
+            // Subnormal b or 0.
+            if hb as u32 | low_word(b) == 0 {
+                return a;
+            }
+            // Scales a and b by 2^1022.
+            let t1 = from_words(0x7fd00000, 0);
+            b *= t1;
+            a *= t1;
+            k -= 1022;
+        } else {
+            // Scales a and b by 2^600.
+            ha += 0x25800000;
+            hb += 0x25800000;
+            k -= 600;
+            a = with_high_word(a, ha as u32);
+            b = with_high_word(b, hb as u32);
+        }
+    }
+    // Medium size a and b.
+    let mut w = a - b;
+    if w > b {
+        let t1 = from_words(ha as u32, 0);
+        let t2 = a - t1;
+        w = (t1 * t1 - (b * (-b) - t2 * (a + t1))).sqrt();
+    } else {
+        a += a;
+        let y1 = from_words(hb as u32, 0);
+        let y2 = b - y1;
+        let t1 = from_words(ha as u32 + 0x00100000, 0);
+        let t2 = a - t1;
+        w = (t1 * y1 - (w * (-w) - (t1 * y2 + t2 * b))).sqrt();
+    }
+    if k != 0 {
+        from_words((0x3ff00000 + (k << 20)) as u32, 0) * w
+    } else {
+        w
+    }
+}
+
+/// Computes `x` raised to the power of `y`.
+pub(crate) fn pow(x: f64, y: f64) -> f64 {
+    const BP: [f64; 2] = [1.0, 1.5];
+    const DP_H: [f64; 2] = [0.0, 5.84962487220764160156e-01];
+    const DP_L: [f64; 2] = [0.0, 1.35003920212974897128e-08];
+    const TWO53: f64 = 9007199254740992.0;
+    const HUGE: f64 = 1.0e300;
+    const TINY: f64 = 1.0e-300;
+    const L1: f64 = 5.99999999999994648725e-01;
+    const L2: f64 = 4.28571428578550184252e-01;
+    const L3: f64 = 3.33333329818377432918e-01;
+    const L4: f64 = 2.72728123808534006489e-01;
+    const L5: f64 = 2.30660745775561754067e-01;
+    const L6: f64 = 2.06975017800338417784e-01;
+    const P1: f64 = 1.66666666666666019037e-01;
+    const P2: f64 = -2.77777777770155933842e-03;
+    const P3: f64 = 6.61375632143793436117e-05;
+    const P4: f64 = -1.65339022054652515390e-06;
+    const P5: f64 = 4.13813679705723846039e-08;
+    const LG2: f64 = 6.93147180559945286227e-01;
+    const LG2_H: f64 = 6.93147182464599609375e-01;
+    const LG2_L: f64 = -1.90465429995776804525e-09;
+    // -(1024 - log2(overflow + 0.5ulp)).
+    const OVT: f64 = 8.0085662595372944372e-017;
+    // 2/(3·ln2), split into a 24-bit head and a tail.
+    const CP: f64 = 9.61796693925975554329e-01;
+    const CP_H: f64 = 9.61796700954437255859e-01;
+    const CP_L: f64 = -7.02846165095275826516e-09;
+    // 1/ln2, split into a 24-bit head and a tail.
+    const IVLN2: f64 = 1.44269504088896338700e+00;
+    const IVLN2_H: f64 = 1.44269502162933349609e+00;
+    const IVLN2_L: f64 = 1.92596299112661746887e-08;
+
+    let hx = high_word(x) as i32;
+    let lx = low_word(x);
+    let hy = high_word(y) as i32;
+    let ly = low_word(y);
+    let mut ix = hx & 0x7fffffff;
+    let iy = hy & 0x7fffffff;
+
+    // x^0 = 1, even if x is NaN.
+    if iy as u32 | ly == 0 {
+        return 1.0;
+    }
+    // 1^y = 1, even if y is NaN.
+    if hx == 0x3ff00000 && lx == 0 {
+        return 1.0;
+    }
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    // Determines whether y is an odd (1) or even (2) integer when x < 0, or not an integer (0).
+    let mut yisint = 0;
+    if hx < 0 {
+        if iy >= 0x43400000 {
+            yisint = 2;
+        } else if iy >= 0x3ff00000 {
+            let k = (iy >> 20) - 0x3ff;
+            if k > 20 {
+                let j = ly >> (52 - k);
+                if j << (52 - k) == ly {
+                    yisint = 2 - (j & 1) as i32;
+                }
+            } else if ly == 0 {
+                let j = iy >> (20 - k);
+                if j << (20 - k) == iy {
+                    yisint = 2 - (j & 1);
+                }
+            }
+        }
+    }
+
+    // Special values of y.
+    if ly == 0 {
+        if iy == 0x7ff00000 {
+            // y is ±∞.
+            return if (ix - 0x3ff00000) as u32 | lx == 0 {
+                1.0
+            } else if ix >= 0x3ff00000 {
+                if hy >= 0 {
+                    y
+                } else {
+                    0.0
+                }
+            } else if hy >= 0 {
+                0.0
+            } else {
+                -y
+            };
+        }
+        if iy == 0x3ff00000 {
+            // y is ±1.
+            return if hy >= 0 { x } else { 1.0 / x };
+        }
+        if hy == 0x40000000 {
+            return x * x;
+        }
+        if hy == 0x3fe00000 && hx >= 0 {
+            // y is 0.5 and x >= +0.
+            return x.sqrt();
+        }
+    }
+
+    let mut ax = x.abs();
+    // Special values of x: ±0, ±∞ and ±1.
+    if lx == 0 && (ix == 0x7ff00000 || ix == 0 || ix == 0x3ff00000) {
+        let mut z = ax;
+        if hy < 0 {
+            z = 1.0 / z;
+        }
+        if hx < 0 {
+            if (ix - 0x3ff00000) | yisint == 0 {
+                // (-1)^non-integer is NaN.
+                z = f64::NAN;
+            } else if yisint == 1 {
+                z = -z;
+            }
+        }
+        return z;
+    }
+
+    let mut s = 1.0;
+    if hx < 0 {
+        if yisint == 0 {
+            // (x < 0)^non-integer is NaN.
+            return f64::NAN;
+        }
+        if yisint == 1 {
+            s = -1.0;
+        }
+    }
+
+    let (t1, t2);
+    if iy > 0x41e00000 {
+        // |y| > 2^31.
+        if iy > 0x43f00000 {
+            // |y| > 2^64, so the result must overflow or underflow.
+            if ix <= 0x3fefffff {
+                return if hy < 0 { HUGE * HUGE } else { TINY * TINY };
+            }
+            if ix >= 0x3ff00000 {
+                return if hy > 0 { HUGE * HUGE } else { TINY * TINY };
+            }
+        }
+        // Overflows or underflows if x is not close to one.
+        if ix < 0x3fefffff {
+            return if hy < 0 {
+                s * HUGE * HUGE
+            } else {
+                s * TINY * TINY
+            };
+        }
+        if ix > 0x3ff00000 {
+            return if hy > 0 {
+                s * HUGE * HUGE
+            } else {
+                s * TINY * TINY
+            };
+        }
+        // Now |1 - x| <= 2^-20, so log(x) is x - x²/2 + x³/3 - x⁴/4.
+        let t = ax - 1.0;
+        let w = (t * t) * (0.5 - t * (0.3333333333333333333333 - t * 0.25));
+        let u = IVLN2_H * t;
+        let v = t * IVLN2_L - w * IVLN2;
+        t1 = with_low_word(u + v, 0);
+        t2 = v - (t1 - u);
+    } else {
+        let mut n = 0;
+        // Takes care of subnormal numbers.
+        if ix < 0x00100000 {
+            ax *= TWO53;
+            n -= 53;
+            ix = high_word(ax) as i32;
+        }
+        n += (ix >> 20) - 0x3ff;
+        let j = ix & 0x000fffff;
+        // Determines the interval.
+        ix = j | 0x3ff00000;
+        let k = if j <= 0x3988E {
+            // |x| < √(3/2).
+            0
+        } else if j < 0xBB67A {
+            // |x| < √3.
+            1
+        } else {
+            n += 1;
+            ix -= 0x00100000;
+            0
+        };
+        ax = with_high_word(ax, ix as u32);
+
+        // Computes ss = s_h + s_l = (x - 1)/(x + 1) or (x - 1.5)/(x + 1.5).
+        let u = ax - BP[k];
+        let v = 1.0 / (ax + BP[k]);
+        let ss = u * v;
+        let s_h = with_low_word(ss, 0);
+        // t_h = ax + BP[k], high part.
+        let t_h = from_words(
+            (((ix >> 1) | 0x20000000) + 0x00080000 + ((k as i32) << 18)) as u32,
+            0,
+        );
+        let t_l = ax - (t_h - BP[k]);
+        let s_l = v * ((u - s_h * t_h) - s_h * t_l);
+        // Computes log(ax).
+        let mut s2 = ss * ss;
+        let mut r = s2 * s2 * (L1 + s2 * (L2 + s2 * (L3 + s2 * (L4 + s2 * (L5 + s2 * L6)))));
+        r += s_l * (s_h + ss);
+        s2 = s_h * s_h;
+        let t_h = with_low_word(3.0 + s2 + r, 0);
+        let t_l = r - ((t_h - 3.0) - s2);
+        // u + v = ss·(1 + ...).
+        let u = s_h * t_h;
+        let v = s_l * t_h + t_l * ss;
+        // 2/(3·ln2)·(ss + ...).
+        let p_h = with_low_word(u + v, 0);
+        let p_l = v - (p_h - u);
+        let z_h = CP_H * p_h;
+        let z_l = CP_L * p_h + p_l * CP + DP_L[k];
+        // log2(ax) = (ss + ...)·2/(3·ln2) = n + DP_H + z_h + z_l.
+        let t = n as f64;
+        t1 = with_low_word(((z_h + z_l) + DP_H[k]) + t, 0);
+        t2 = z_l - (((t1 - t) - DP_H[k]) - z_h);
+    }
+
+    // Splits y into y1 + y2 and computes (y1 + y2)·(t1 + t2).
+    let y1 = with_low_word(y, 0);
+    let p_l = (y - y1) * t1 + y * t2;
+    let mut p_h = y1 * t1;
+    let z = p_l + p_h;
+    let j = high_word(z) as i32;
+    let i = low_word(z);
+    if j >= 0x40900000 {
+        // z >= 1024.
+        if (j - 0x40900000) as u32 | i != 0 || p_l + OVT > z - p_h {
+            return s * HUGE * HUGE;
+        }
+    } else if (j & 0x7fffffff) >= 0x4090cc00 {
+        // z <= -1075.
+        if (j as u32).wrapping_sub(0xc090cc00) | i != 0 || p_l <= z - p_h {
+            return s * TINY * TINY;
+        }
+    }
+
+    // Computes 2^(p_h + p_l).
+    let i = j & 0x7fffffff;
+    let mut k = (i >> 20) - 0x3ff;
+    let mut n = 0;
+    if i > 0x3fe00000 {
+        // |z| > 0.5, so sets n = [z + 0.5].
+        n = j + (0x00100000 >> (k + 1));
+        k = ((n & 0x7fffffff) >> 20) - 0x3ff;
+        let t = from_words((n & !(0x000fffff >> k)) as u32, 0);
+        n = ((n & 0x000fffff) | 0x00100000) >> (20 - k);
+        if j < 0 {
+            n = -n;
+        }
+        p_h -= t;
+    }
+    let t = with_low_word(p_l + p_h, 0);
+    let u = t * LG2_H;
+    let v = (p_l - (t - p_h)) * LG2 + t * LG2_L;
+    let z = u + v;
+    let w = v - (z - u);
+    let t = z * z;
+    let t1 = z - t * (P1 + t * (P2 + t * (P3 + t * (P4 + t * P5))));
+    let r = (z * t1) / (t1 - 2.0) - (w + z * w);
+    let z = 1.0 - (r - z);
+    let j = (high_word(z) as i32).wrapping_add(n << 20);
+    let z = if (j >> 20) <= 0 {
+        // A subnormal output.
+        scalbn(z, n)
+    } else {
+        with_high_word(z, j as u32)
+    };
+    s * z
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_known_values() {
+        assert_eq!(sin(0.5), 0.479425538604203);
+        assert_eq!(cos(1.0), 0.5403023058681398);
+        assert_eq!(tan(1.0), 1.5574077246549023);
+        assert_eq!(sin(1e22), -0.8522008497671888);
+        assert_eq!(cos(1e300), -0.5753861119575491);
+        assert_eq!(atan2(1.0, -1.0), 2.356194490192345);
+        // fdlibm is 1ulp off here, as is every port of it, e.g. Java's StrictMath.
+        assert_eq!(exp(1.0), 2.7182818284590455);
+        assert_eq!(log(10.0), std::f64::consts::LN_10);
+        assert_eq!(log2(8.0), 3.0);
+        assert_eq!(log10(1000.0), 3.0);
+        assert_eq!(cbrt(27.0), 3.0);
+        assert_eq!(hypot(3.0, 4.0), 5.0);
+        assert_eq!(pow(2.0, 0.5), std::f64::consts::SQRT_2);
+        assert_eq!(pow(10.0, -2.0), 0.01);
+    }
+
+    #[test]
+    fn preserves_signed_zeros() {
+        for f in [
+            sin, tan, asin, atan, expm1, log1p, sinh, tanh, asinh, atanh, cbrt,
+        ] {
+            assert!(f(-0.0) == 0.0 && f(-0.0).is_sign_negative());
+            assert!(f(0.0) == 0.0 && f(0.0).is_sign_positive());
+        }
+        assert_eq!(cos(-0.0), 1.0);
+        assert_eq!(log(-0.0), f64::NEG_INFINITY);
+        assert!(atan2(-0.0, -1.0) == -std::f64::consts::PI);
+    }
+
+    #[test]
+    fn matches_reference_bits() {
+        // The bits fdlibm 5.3 returns, as shipped in Java's StrictMath, and V8's for log2, asinh, acosh and atanh, which StrictMath lacks.
+        type Unary = fn(f64) -> f64;
+        type Binary = fn(f64, f64) -> f64;
+        let unary: [(Unary, f64, u64); 69] = [
+            (sin, 0.5, 0x3FDEAEE8744B05F0),
+            (sin, -2.0, 0xBFED18F6EAD1B446),
+            (sin, 8.507322470496105, 0x3FE968EB56C42460),
+            (sin, 6.2493199099084264e16, 0xBFE2380CD6B2DA09),
+            (sin, 1.1712231651673889e19, 0xBFEDF66A8B357FC2),
+            (sin, 1e300, 0xBFEA2C16B010E385),
+            (cos, 0.5, 0x3FEC1528065B7D50),
+            (cos, -2.0, 0xBFDAA22657537205),
+            (cos, 8.507322470496105, 0xBFE373725804B1CA),
+            (cos, 6.2493199099084264e16, 0x3FEA4EA633A202A0),
+            (cos, 1.1712231651673889e19, 0xBFD678C420CB7DD6),
+            (cos, 1e300, 0xBFE2699022ADC4C1),
+            (tan, 0.5, 0x3FE17B4F5BF3474A),
+            (tan, -2.0, 0x40017AF62E0950F8),
+            (tan, 1.5707963267948966, 0x434D02967C31CDB5),
+            (tan, 6.2493199099084264e16, 0xBFE62954E94E4CF2),
+            (tan, 1e300, 0x3FF6BE411F37AC77),
+            (asin, 0.3, 0x3FD380159E14F6FF),
+            (asin, -0.7, 0xBFE8D00E692AFD95),
+            (asin, 0.99, 0x3FF6DE3C6F33D51D),
+            (acos, 0.3, 0x3FF441F5ECBEEF59),
+            (acos, -0.7, 0x4002C501446CD5F2),
+            (acos, 0.99, 0x3FC21DF72882BFD8),
+            (atan, 0.3, 0x3FD2A73A661EAF06),
+            (atan, -5.0, 0xBFF5F97315254857),
+            (atan, 1e10, 0x3FF921FB543D4DE0),
+            (exp, 0.5, 0x3FFA61298E1E069C),
+            (exp, -20.0, 0x3E21B48655F37267),
+            (exp, 700.0, 0x7F0D945DF4F8EC8E),
+            (expm1, 1e-5, 0x3EE4F8BC681CDFB6),
+            (expm1, 0.3, 0x3FD6641632306A56),
+            (expm1, -2.0, 0xBFEBAB5557101F8D),
+            (log, 1e-7, 0xC0301E3B843EAA73),
+            (log, 0.9, 0xBFBAF8E8210A415C),
+            (log, 3.0, 0x3FF193EA7AAD030A),
+            (log, 1e300, 0x4085963447F87FB5),
+            (log1p, 0.270994592931274, 0x3FCEB1C2002DCA4A),
+            (log1p, -0.23998527893554494, 0xBFD1900E514A5852),
+            (log1p, 1e-10, 0x3DDB7CDFD9D1D693),
+            (log1p, 100.0, 0x401275E2271BBA31),
+            (log10, 0.6855329877550511, 0xBFC4FD05D45107E4),
+            (log10, 3.522753610000001e-7, 0xC019CFFE1835F66B),
+            (log10, 2.0, 0x3FD34413509F79FF),
+            (sinh, -168.057987938891, 0xCF05F4472BD5EE26),
+            (sinh, 1.3722320463719963e-8, 0x3E4D77ECEA9DD5B4),
+            (sinh, 0.5, 0x3FE0ACD00FE63B97),
+            (sinh, 710.0, 0x7FE3E21A464507FA),
+            (cosh, 0.36524747602698654, 0x3FF114440CCC1072),
+            (cosh, 3.0, 0x402422A497D6185E),
+            (cosh, 710.0, 0x7FE3E21A464507FA),
+            (tanh, 0.26660399709999016, 0x3FD0AB68EDD69E79),
+            (tanh, 0.5003890421569716, 0x3FDD9856E7BCCDE8),
+            (tanh, 3.3108132830128397e-9, 0x3E2C708E2EF726E6),
+            (tanh, 5.0, 0x3FEFFF419668DF11),
+            (cbrt, 2.601693740197302e-7, 0x3F7A25FE67D5BD7A),
+            (cbrt, 0.359868303742444, 0x3FE6C2E73D41A1F4),
+            (cbrt, -1000.5, 0xC02400DA6ABC3915),
+            (log2, 3.0, 0x3FF95C01A39FBD68),
+            (log2, 1e-7, 0xC03740E5284A6F03),
+            (log2, 0.75, 0xBFDA8FF971810A5E),
+            (asinh, 1.4572657550849604e-8, 0x3E4F4B6738ED2AB5),
+            (asinh, 0.5, 0x3FDECC2CAEC5160A),
+            (asinh, 1e10, 0x4037B810429A7C2A),
+            (acosh, 1.0000001, 0x3F3D4EFFC065CAE4),
+            (acosh, 1.5, 0x3FEECC2CAEC5160A),
+            (acosh, 141568085.65321514, 0x40337620D5900A14),
+            (atanh, 2.162799386509841e-9, 0x3E22940BD05B7235),
+            (atanh, 0.3, 0x3FD3CF2B50617C96),
+            (atanh, 0.9, 0x3FF78E360604B32D),
+        ];
+        for (f, x, bits) in unary {
+            assert_eq!(f(x).to_bits(), bits, "{x}");
+        }
+        let binary: [(Binary, f64, f64, u64); 8] = [
+            (
+                pow,
+                0.00002292285362220699,
+                -23.01401601706909,
+                0x561A33FD4B81FA0D,
+            ),
+            (pow, 2.5, 1.7, 0x4012FDCF53F3E6F3),
+            (
+                pow,
+                0.06288638570963814,
+                -19.367639312171114,
+                0x44C3AD003A25915E,
+            ),
+            (
+                hypot,
+                -235684.9654551953,
+                10.286163817908038,
+                0x410CC527B9B641BB,
+            ),
+            (hypot, 3.0, 4.5, 0x4015A22073490377),
+            (
+                atan2,
+                7.156463989389836e-166,
+                -1.0627757164491073e-204,
+                0x3FF921FB54442D19,
+            ),
+            (atan2, 1.0, 0.3, 0x3FF4782CBABC8157),
+            (atan2, -2.0, -3.0, 0xC0046DC09EC29433),
+        ];
+        for (f, x, y, bits) in binary {
+            assert_eq!(f(x, y).to_bits(), bits, "{x}, {y}");
+        }
+        assert!(acosh(0.5).is_nan() && acosh(-2.0).is_nan());
+        assert!(atanh(1.5).is_nan());
+    }
+}
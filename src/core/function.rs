@@ -1,5 +1,303 @@
-use super::Object;
+use std::any::Any;
+use std::fmt;
+use std::rc::Rc;
 
-trait Callable: Object {}
+use super::property::Descriptor;
+use super::{
+    e262_define_property_or_throw, e262_ordinary_define_own_property, e262_ordinary_delete,
+    e262_ordinary_get, e262_ordinary_get_own_property, e262_ordinary_get_prototype_of,
+    e262_ordinary_has_property, e262_ordinary_is_extensible, e262_ordinary_own_property_keys,
+    e262_ordinary_prevent_extensions, e262_ordinary_set, e262_ordinary_set_prototype_of,
+    BaseObject, HasBaseObject, Object, ObjectRep, PropertyKey,
+};
+use super::{Property, StringRep, Value};
+use crate::errors::{CoreError, CoreResult};
 
-trait Constructor: Callable {}
+/// The Rust closure backing a [built-in function object](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-built-in-function-objects).
+///
+/// It receives the `this` value and the arguments list.
+pub type NativeBehaviour = Rc<dyn Fn(&Value, &[Value]) -> CoreResult<Value>>;
+
+/// Implements the internal methods of a [function object](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#function-object).
+pub trait Callable: HasBaseObject {
+    /// Implements the [`[[Call]]`](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#table-additional-essential-internal-methods-of-function-objects) internal method.
+    fn call(self: Rc<Self>, this_argument: &Value, arguments_list: &[Value]) -> CoreResult<Value>;
+}
+
+/// The Rust closure backing the `[[Construct]]` internal method of a built-in constructor.
+///
+/// It receives the arguments list and the `NewTarget` object.
+pub type NativeConstructor = Rc<dyn Fn(&[Value], &ObjectRep) -> CoreResult<ObjectRep>>;
+
+/// Implements the internal methods of a [constructor](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#constructor).
+pub trait Constructor: Callable {
+    /// Implements the [`[[Construct]]`](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#table-additional-essential-internal-methods-of-function-objects) internal method.
+    fn construct(
+        self: Rc<Self>,
+        arguments_list: &[Value],
+        new_target: &ObjectRep,
+    ) -> CoreResult<ObjectRep>;
+}
+
+/// The internal implementation of a [built-in function object](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-built-in-function-objects).
+///
+/// Built-in functions are constructors only if they have a [`NativeConstructor`].
+pub struct BuiltinFunction {
+    base: Rc<BaseObject>,
+    behaviour: CallBehaviour,
+    constructor: Option<NativeConstructor>,
+}
+
+/// What the `[[Call]]` internal method of a [`BuiltinFunction`] runs.
+enum CallBehaviour {
+    /// A closure receiving the `this` value and the arguments list.
+    Native(NativeBehaviour),
+    /// A closure receiving the arguments list and the function itself as `NewTarget`, for
+    /// constructors that treat an undefined `NewTarget` as the active function object.
+    SelfConstruct(NativeConstructor),
+}
+
+impl fmt::Debug for BuiltinFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BuiltinFunction")
+            .field("base", &self.base)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Object for BuiltinFunction {
+    fn get_slot(self: Rc<Self>, key: String) -> Option<Rc<dyn Any>> {
+        self.base.clone().get_slot(key)
+    }
+
+    fn set_slot(self: Rc<Self>, key: String, value: Rc<dyn Any>) -> bool {
+        self.base.clone().set_slot(key, value)
+    }
+
+    fn get_prototype_of(self: Rc<Self>) -> CoreResult<Option<ObjectRep>> {
+        Ok(e262_ordinary_get_prototype_of(self))
+    }
+
+    fn set_prototype_of(self: Rc<Self>, proto: Option<ObjectRep>) -> bool {
+        e262_ordinary_set_prototype_of(self, proto)
+    }
+
+    fn is_extensible(self: Rc<Self>) -> CoreResult<bool> {
+        Ok(e262_ordinary_is_extensible(self))
+    }
+
+    fn prevent_extensions(self: Rc<Self>) -> CoreResult<bool> {
+        Ok(e262_ordinary_prevent_extensions(self))
+    }
+
+    fn get_own_property(self: Rc<Self>, key: &PropertyKey) -> CoreResult<Option<Property>> {
+        Ok(e262_ordinary_get_own_property(self, key))
+    }
+
+    fn define_own_property(self: Rc<Self>, key: PropertyKey, desc: Descriptor) -> CoreResult<bool> {
+        e262_ordinary_define_own_property(self, &key, desc)
+    }
+
+    fn has_property(self: Rc<Self>, key: &PropertyKey) -> CoreResult<bool> {
+        e262_ordinary_has_property(self, key)
+    }
+
+    fn get(self: Rc<Self>, key: &PropertyKey, receiver: &Value) -> CoreResult<Value> {
+        e262_ordinary_get(self, key, receiver)
+    }
+
+    fn set(self: Rc<Self>, key: &PropertyKey, value: Value, receiver: &Value) -> CoreResult<bool> {
+        e262_ordinary_set(self, key, value, receiver)
+    }
+
+    fn delete(self: Rc<Self>, key: &PropertyKey) -> CoreResult<bool> {
+        e262_ordinary_delete(self, key)
+    }
+
+    fn own_property_keys(self: Rc<Self>) -> CoreResult<Vec<PropertyKey>> {
+        Ok(e262_ordinary_own_property_keys(self))
+    }
+
+    fn as_callable(self: Rc<Self>) -> Option<Rc<dyn Callable>> {
+        Some(self)
+    }
+
+    fn as_constructor(self: Rc<Self>) -> Option<Rc<dyn Constructor>> {
+        match self.constructor {
+            Some(_) => Some(self),
+            None => None,
+        }
+    }
+}
+
+impl HasBaseObject for BuiltinFunction {
+    fn get_object(self: Rc<Self>) -> Rc<BaseObject> {
+        self.base.clone()
+    }
+}
+
+impl Callable for BuiltinFunction {
+    fn call(self: Rc<Self>, this_argument: &Value, arguments_list: &[Value]) -> CoreResult<Value> {
+        match &self.behaviour {
+            CallBehaviour::Native(behaviour) => behaviour(this_argument, arguments_list),
+            CallBehaviour::SelfConstruct(behaviour) => {
+                let new_target = ObjectRep::new(self.clone());
+                Ok(Value::Object(behaviour(arguments_list, &new_target)?))
+            }
+        }
+    }
+}
+
+impl Constructor for BuiltinFunction {
+    fn construct(
+        self: Rc<Self>,
+        arguments_list: &[Value],
+        new_target: &ObjectRep,
+    ) -> CoreResult<ObjectRep> {
+        let constructor = self
+            .constructor
+            .as_ref()
+            .expect("Only built-in functions with a constructor are constructors");
+        constructor(arguments_list, new_target)
+    }
+}
+
+pub(crate) fn e262_call(
+    func: &Value,
+    this_argument: &Value,
+    arguments_list: &[Value],
+) -> CoreResult<Value> {
+    match func {
+        Value::Object(obj) => match obj.0.clone().as_callable() {
+            Some(callable) => callable.call(this_argument, arguments_list),
+            None => Err(CoreError::TypeError("Value is not a function".to_string())),
+        },
+        _ => Err(CoreError::TypeError("Value is not a function".to_string())),
+    }
+}
+
+pub(crate) fn e262_construct(
+    func: &ObjectRep,
+    arguments_list: &[Value],
+    new_target: Option<&ObjectRep>,
+) -> CoreResult<ObjectRep> {
+    match func.0.clone().as_constructor() {
+        Some(constructor) => constructor.construct(arguments_list, new_target.unwrap_or(func)),
+        None => Err(CoreError::TypeError(
+            "Value is not a constructor".to_string(),
+        )),
+    }
+}
+
+pub(crate) fn e262_create_builtin_function(
+    behaviour: NativeBehaviour,
+    length: usize,
+    name: &str,
+    prototype: Option<ObjectRep>,
+) -> ObjectRep {
+    create_builtin_function(
+        CallBehaviour::Native(behaviour),
+        None,
+        length,
+        name,
+        prototype,
+    )
+}
+
+/// Creates a built-in function that is also a constructor, where `behaviour` implements `[[Call]]` and `constructor` implements `[[Construct]]`.
+pub(crate) fn p262_create_builtin_constructor(
+    behaviour: NativeBehaviour,
+    constructor: NativeConstructor,
+    length: usize,
+    name: &str,
+    prototype: Option<ObjectRep>,
+) -> ObjectRep {
+    create_builtin_function(
+        CallBehaviour::Native(behaviour),
+        Some(constructor),
+        length,
+        name,
+        prototype,
+    )
+}
+
+/// Creates a built-in constructor whose `[[Call]]` behaves like `[[Construct]]` with the active
+/// function object as `NewTarget`, as the Array and Error constructors do.
+pub(crate) fn p262_create_builtin_self_constructor(
+    constructor: NativeConstructor,
+    length: usize,
+    name: &str,
+    prototype: Option<ObjectRep>,
+) -> ObjectRep {
+    let behaviour = CallBehaviour::SelfConstruct(constructor.clone());
+    create_builtin_function(behaviour, Some(constructor), length, name, prototype)
+}
+
+/// Creates a built-in constructor whose `[[Call]]` runs `behaviour` with the active function object
+/// as `NewTarget`, for constructors whose call steps differ from their construct steps, as
+/// `%RegExp%`'s do.
+pub(crate) fn p262_create_builtin_self_constructor_with_call(
+    behaviour: NativeConstructor,
+    constructor: NativeConstructor,
+    length: usize,
+    name: &str,
+    prototype: Option<ObjectRep>,
+) -> ObjectRep {
+    let behaviour = CallBehaviour::SelfConstruct(behaviour);
+    create_builtin_function(behaviour, Some(constructor), length, name, prototype)
+}
+
+fn create_builtin_function(
+    behaviour: CallBehaviour,
+    constructor: Option<NativeConstructor>,
+    length: usize,
+    name: &str,
+    prototype: Option<ObjectRep>,
+) -> ObjectRep {
+    let func = ObjectRep::new(Rc::new(BuiltinFunction {
+        base: Rc::new(BaseObject::new(&prototype)),
+        behaviour,
+        constructor,
+    }));
+    let name = StringRep::from(name);
+    func.0
+        .clone()
+        .set_slot("InitialName".to_string(), Rc::new(name.clone()));
+    e262_set_function_length(&func, length as f64);
+    e262_set_function_name(&func, name, None);
+    func
+}
+
+pub(crate) fn e262_set_function_length(func: &ObjectRep, length: f64) {
+    e262_define_property_or_throw(
+        func,
+        PropertyKey::from("length"),
+        Descriptor {
+            value: Some(Rc::new(Value::Number(length))),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Functions must have a length");
+}
+
+pub(crate) fn e262_set_function_name(func: &ObjectRep, name: StringRep, prefix: Option<&str>) {
+    let name = match prefix {
+        Some(prefix) => StringRep::from(format!("{prefix} ")).concat(&name),
+        None => name,
+    };
+    e262_define_property_or_throw(
+        func,
+        PropertyKey::from("name"),
+        Descriptor {
+            value: Some(Rc::new(Value::String(name))),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+    )
+    .expect("Functions must have a name");
+}
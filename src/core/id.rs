@@ -1,12 +1,24 @@
-use rand::random;
 use std::fmt;
 
+use crate::agent::p262_next_id;
+
+/// How many ids are kept for [reserved](MagicId::reserved) identities, below the allocated ones.
+const RESERVED_IDS: u64 = 1 << 16;
+
+/// An identity, allocated in order by the agent so that every run allocates the same identities.
+///
+/// Ids are never drawn from the agent's generator, so reseeding it cannot hand out an id that a live value already has.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct MagicId(u64);
 
 impl MagicId {
     pub(crate) fn new() -> Self {
-        MagicId(random::<u64>())
+        MagicId(RESERVED_IDS + p262_next_id())
+    }
+
+    /// Returns a fixed id, for values whose identity must be the same everywhere (e.g. well-known symbols).
+    pub(crate) const fn reserved(index: u64) -> Self {
+        MagicId(index)
     }
 }
 
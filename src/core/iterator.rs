@@ -0,0 +1,137 @@
+use super::cast::e262_to_boolean;
+use super::function::e262_call;
+use super::{
+    e262_create_data_property_or_throw, e262_get, e262_get_method, e262_ordinary_object_create,
+    ObjectRep, PropertyKey, SymbolRep, Value, WellKnownSymbol,
+};
+use crate::agent::e262_current_realm;
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::Intrinsic;
+
+/// An [Iterator Record](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-iterator-records).
+#[derive(Clone, Debug)]
+pub(crate) struct IteratorRecord {
+    pub iterator: ObjectRep,
+    pub next_method: Value,
+    pub done: bool,
+}
+
+/// Implements [GetIterator](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-getiterator) for the sync kind.
+pub(crate) fn e262_get_iterator(obj: &Value) -> CoreResult<IteratorRecord> {
+    let key = PropertyKey::from(SymbolRep::well_known(WellKnownSymbol::Iterator));
+    match e262_get_method(obj, &key)? {
+        Some(method) => e262_get_iterator_from_method(obj, &method),
+        None => Err(CoreError::TypeError("Value is not iterable".to_string())),
+    }
+}
+
+pub(crate) fn e262_get_iterator_from_method(
+    obj: &Value,
+    method: &Value,
+) -> CoreResult<IteratorRecord> {
+    let Value::Object(iterator) = e262_call(method, obj, &[])? else {
+        return Err(CoreError::TypeError(
+            "Result of the @@iterator method is not an object".to_string(),
+        ));
+    };
+    let next_method = e262_get(&iterator, &PropertyKey::from("next"))?;
+    Ok(IteratorRecord {
+        iterator,
+        next_method,
+        done: false,
+    })
+}
+
+pub(crate) fn e262_iterator_next(
+    record: &mut IteratorRecord,
+    value: Option<Value>,
+) -> CoreResult<ObjectRep> {
+    let iterator = Value::Object(record.iterator.clone());
+    let result = match value {
+        None => e262_call(&record.next_method, &iterator, &[]),
+        Some(value) => e262_call(&record.next_method, &iterator, &[value]),
+    };
+    match result {
+        Ok(Value::Object(result)) => Ok(result),
+        Ok(_) => {
+            record.done = true;
+            Err(CoreError::TypeError(
+                "Iterator result is not an object".to_string(),
+            ))
+        }
+        Err(err) => {
+            record.done = true;
+            Err(err)
+        }
+    }
+}
+
+pub(crate) fn e262_iterator_complete(iter_result: &ObjectRep) -> CoreResult<bool> {
+    Ok(e262_to_boolean(&e262_get(
+        iter_result,
+        &PropertyKey::from("done"),
+    )?))
+}
+
+pub(crate) fn e262_iterator_value(iter_result: &ObjectRep) -> CoreResult<Value> {
+    e262_get(iter_result, &PropertyKey::from("value"))
+}
+
+/// Implements [IteratorStepValue](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-iteratorstepvalue), returning [`None`] once the iterator is done.
+pub(crate) fn e262_iterator_step_value(record: &mut IteratorRecord) -> CoreResult<Option<Value>> {
+    let result = e262_iterator_next(record, None)?;
+    let done = e262_iterator_complete(&result).inspect_err(|_| record.done = true)?;
+    if done {
+        record.done = true;
+        return Ok(None);
+    }
+    let value = e262_iterator_value(&result).inspect_err(|_| record.done = true)?;
+    Ok(Some(value))
+}
+
+/// Implements [IteratorClose](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-iteratorclose), where `completion` is the completion the iteration ends with.
+pub(crate) fn e262_iterator_close<T>(
+    record: &IteratorRecord,
+    completion: CoreResult<T>,
+) -> CoreResult<T> {
+    let iterator = Value::Object(record.iterator.clone());
+    let inner_result =
+        e262_get_method(&iterator, &PropertyKey::from("return")).and_then(|return_method| {
+            match return_method {
+                Some(return_method) => e262_call(&return_method, &iterator, &[]).map(Some),
+                None => Ok(None),
+            }
+        });
+    let completion = completion?;
+    match inner_result? {
+        None | Some(Value::Object(_)) => Ok(completion),
+        Some(_) => Err(CoreError::TypeError(
+            "Iterator result is not an object".to_string(),
+        )),
+    }
+}
+
+/// Implements [IteratorToList](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-iteratortolist).
+pub(crate) fn e262_iterator_to_list(record: &mut IteratorRecord) -> CoreResult<Vec<Value>> {
+    let mut values = Vec::new();
+    while let Some(next) = e262_iterator_step_value(record)? {
+        values.push(next);
+    }
+    Ok(values)
+}
+
+/// Implements [IterableToList](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-iterabletolist) when no method is supplied.
+pub(crate) fn e262_iterable_to_list(items: &Value) -> CoreResult<Vec<Value>> {
+    let mut record = e262_get_iterator(items)?;
+    e262_iterator_to_list(&mut record)
+}
+
+pub(crate) fn e262_create_iter_result_object(value: Value, done: bool) -> ObjectRep {
+    let prototype = e262_current_realm().intrinsic(Intrinsic::ObjectPrototype);
+    let obj = e262_ordinary_object_create(Some(prototype));
+    e262_create_data_property_or_throw(&obj, PropertyKey::from("value"), value)
+        .expect("Fresh ordinary objects accept new properties");
+    e262_create_data_property_or_throw(&obj, PropertyKey::from("done"), Value::Boolean(done))
+        .expect("Fresh ordinary objects accept new properties");
+    obj
+}
@@ -1,20 +1,38 @@
 //! The core module, implementing the basic language values and functions.
 
 mod annex_b;
-mod bigint;
-mod cast;
-mod function;
+mod atom;
+pub mod bigint;
+pub(crate) mod cast;
+mod dtoa;
+mod exotic;
+pub(crate) mod fdlibm;
+pub(crate) mod function;
 mod id;
-mod numbers;
+mod iterator;
+pub mod numbers;
 mod objects;
 mod property;
+pub(crate) mod regexp;
 mod string;
 mod symbol;
-mod test;
+pub(crate) mod test;
+pub mod unicode;
 mod value;
 
+pub use self::atom::Atom;
+pub use self::exotic::*;
+pub(crate) use self::function::{
+    e262_create_builtin_function, p262_create_builtin_constructor,
+    p262_create_builtin_self_constructor, p262_create_builtin_self_constructor_with_call,
+};
+pub use self::function::{
+    BuiltinFunction, Callable, Constructor, NativeBehaviour, NativeConstructor,
+};
+pub(crate) use self::iterator::*;
 pub use self::objects::*;
 pub use self::property::*;
-pub use self::string::StringRep;
-pub use self::symbol::SymbolRep;
+pub use self::string::{p262_utf16_encode_code_point, CodeUnits, StringRep};
+pub(crate) use self::symbol::SymbolRegistry;
+pub use self::symbol::{SymbolRep, WellKnownSymbol};
 pub use self::value::*;
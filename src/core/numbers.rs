@@ -1,6 +1,159 @@
+//! The [Number type](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number) operations.
+//!
+//! Every operation is a pure function over [`f64`], so embedders can get spec-exact arithmetic without going through an interpreter.
+
+use super::cast::p262_number_to_uint_modulo;
+use super::{dtoa, fdlibm};
+
 pub(crate) const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991f64;
 
-pub(crate) fn e262_equal(x: f64, y: f64) -> bool {
+/// Implements [Number::unaryMinus](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-unaryMinus).
+pub fn e262_unary_minus(x: f64) -> f64 {
+    -x
+}
+
+/// Implements [Number::bitwiseNOT](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-bitwiseNOT).
+pub fn e262_bitwise_not(x: f64) -> f64 {
+    !to_int32(x) as f64
+}
+
+/// Implements [Number::exponentiate](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-exponentiate).
+pub fn e262_exponentiate(base: f64, exponent: f64) -> f64 {
+    if is_nan(exponent) {
+        return f64::NAN;
+    }
+    if is_zero(exponent) {
+        return 1f64;
+    }
+    if is_nan(base) {
+        return f64::NAN;
+    }
+    if is_pos_inf(base) {
+        return if exponent > 0f64 { f64::INFINITY } else { 0f64 };
+    }
+    if is_neg_inf(base) {
+        return if exponent > 0f64 {
+            if is_odd_integral(exponent) {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            }
+        } else if is_odd_integral(exponent) {
+            -0f64
+        } else {
+            0f64
+        };
+    }
+    if is_pos_zero(base) {
+        return if exponent > 0f64 { 0f64 } else { f64::INFINITY };
+    }
+    if is_neg_zero(base) {
+        return if exponent > 0f64 {
+            if is_odd_integral(exponent) {
+                -0f64
+            } else {
+                0f64
+            }
+        } else if is_odd_integral(exponent) {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        };
+    }
+    if is_inf(exponent) {
+        let abs = base.abs();
+        return if abs == 1f64 {
+            f64::NAN
+        } else if (abs > 1f64) == is_pos_inf(exponent) {
+            f64::INFINITY
+        } else {
+            0f64
+        };
+    }
+    if base < 0f64 && exponent.fract() != 0f64 {
+        return f64::NAN;
+    }
+    fdlibm::pow(base, exponent)
+}
+
+/// Implements [Number::multiply](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-multiply).
+pub fn e262_multiply(x: f64, y: f64) -> f64 {
+    x * y
+}
+
+/// Implements [Number::divide](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-divide).
+pub fn e262_divide(x: f64, y: f64) -> f64 {
+    x / y
+}
+
+/// Implements [Number::remainder](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-remainder).
+pub fn e262_remainder(n: f64, d: f64) -> f64 {
+    if is_nan(n) || is_nan(d) || is_inf(n) || is_zero(d) {
+        f64::NAN
+    } else if is_inf(d) || is_zero(n) {
+        n
+    } else {
+        // `%` is an exact IEEE 754 fmod, whose result keeps the sign of the dividend.
+        n % d
+    }
+}
+
+/// Implements [Number::add](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-add).
+pub fn e262_add(x: f64, y: f64) -> f64 {
+    x + y
+}
+
+/// Implements [Number::subtract](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-subtract).
+pub fn e262_subtract(x: f64, y: f64) -> f64 {
+    e262_add(x, e262_unary_minus(y))
+}
+
+/// Implements [Number::leftShift](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-leftShift).
+pub fn e262_left_shift(x: f64, y: f64) -> f64 {
+    let shift_count = to_uint32(y) % 32;
+    to_int32(x).wrapping_shl(shift_count) as f64
+}
+
+/// Implements [Number::signedRightShift](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-signedRightShift).
+pub fn e262_signed_right_shift(x: f64, y: f64) -> f64 {
+    let shift_count = to_uint32(y) % 32;
+    (to_int32(x) >> shift_count) as f64
+}
+
+/// Implements [Number::unsignedRightShift](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-unsignedRightShift).
+pub fn e262_unsigned_right_shift(x: f64, y: f64) -> f64 {
+    let shift_count = to_uint32(y) % 32;
+    (to_uint32(x) >> shift_count) as f64
+}
+
+/// Implements [Number::lessThan](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-lessThan).
+///
+/// Returns [`None`] (i.e. undefined) if either operand is NaN.
+pub fn e262_less_than(x: f64, y: f64) -> Option<bool> {
+    if is_nan(x) || is_nan(y) {
+        None
+    } else {
+        Some(x < y)
+    }
+}
+
+/// Implements [Number::bitwiseAND](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-bitwiseAND).
+pub fn e262_bitwise_and(x: f64, y: f64) -> f64 {
+    (to_int32(x) & to_int32(y)) as f64
+}
+
+/// Implements [Number::bitwiseXOR](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-bitwiseXOR).
+pub fn e262_bitwise_xor(x: f64, y: f64) -> f64 {
+    (to_int32(x) ^ to_int32(y)) as f64
+}
+
+/// Implements [Number::bitwiseOR](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-bitwiseOR).
+pub fn e262_bitwise_or(x: f64, y: f64) -> f64 {
+    (to_int32(x) | to_int32(y)) as f64
+}
+
+/// Implements [Number::equal](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-equal).
+pub fn e262_equal(x: f64, y: f64) -> bool {
     if is_nan(x) || is_nan(y) {
         false
     } else if is_zero(x) && is_zero(y) {
@@ -10,7 +163,8 @@ pub(crate) fn e262_equal(x: f64, y: f64) -> bool {
     }
 }
 
-pub(crate) fn e262_same_value(x: f64, y: f64) -> bool {
+/// Implements [Number::sameValue](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-sameValue).
+pub fn e262_same_value(x: f64, y: f64) -> bool {
     if is_nan(x) && is_nan(y) {
         true
     } else if is_zero(x) && is_zero(y) {
@@ -20,8 +174,9 @@ pub(crate) fn e262_same_value(x: f64, y: f64) -> bool {
     }
 }
 
+/// Implements [Number::sameValueZero](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-sameValueZero).
 #[allow(clippy::if_same_then_else)]
-pub(crate) fn e262_same_value_zero(x: f64, y: f64) -> bool {
+pub fn e262_same_value_zero(x: f64, y: f64) -> bool {
     if is_nan(x) && is_nan(y) {
         true
     } else if is_zero(x) && is_zero(y) {
@@ -31,6 +186,41 @@ pub(crate) fn e262_same_value_zero(x: f64, y: f64) -> bool {
     }
 }
 
+/// Implements [Number::toString](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-numeric-types-number-tostring), for a `radix` in the range 2–36.
+pub fn e262_to_string(x: f64, radix: u32) -> String {
+    dtoa::to_string_radix(x, radix)
+}
+
+/// Formats `x` with `fraction_digits` digits after the decimal point, as [`Number.prototype.toFixed`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-number.prototype.tofixed) does for `fraction_digits` in the range 0–100.
+pub fn p262_to_fixed(x: f64, fraction_digits: usize) -> String {
+    dtoa::to_fixed(x, fraction_digits)
+}
+
+/// Formats `x` in exponential notation, as [`Number.prototype.toExponential`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-number.prototype.toexponential) does for `fraction_digits` in the range 0–100, or with as many digits as necessary if absent.
+pub fn p262_to_exponential(x: f64, fraction_digits: Option<usize>) -> String {
+    dtoa::to_exponential(x, fraction_digits)
+}
+
+/// Formats `x` with `precision` significant digits, as [`Number.prototype.toPrecision`](https://tc39.es/ecma262/multipage/numbers-and-dates.html#sec-number.prototype.toprecision) does for `precision` in the range 1–100.
+pub fn p262_to_precision(x: f64, precision: usize) -> String {
+    dtoa::to_precision(x, precision)
+}
+
+#[inline(always)]
+fn is_odd_integral(value: f64) -> bool {
+    value.is_finite() && (value % 2f64).abs() == 1f64
+}
+
+/// Implements [ToInt32](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-toint32) over a Number.
+fn to_int32(value: f64) -> i32 {
+    to_uint32(value) as i32
+}
+
+/// Implements [ToUint32](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-touint32) over a Number.
+fn to_uint32(value: f64) -> u32 {
+    p262_number_to_uint_modulo(value, 32) as u32
+}
+
 #[inline(always)]
 pub(crate) fn is_inf(value: f64) -> bool {
     value.is_infinite()
@@ -70,6 +260,77 @@ pub(crate) fn is_zero(value: f64) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn exponentiate_works() {
+        assert_eq!(e262_exponentiate(2.0, 10.0), 1024.0);
+        assert_eq!(e262_exponentiate(f64::NAN, 0.0), 1.0);
+        assert!(is_nan(e262_exponentiate(1.0, f64::NAN)));
+        assert!(is_nan(e262_exponentiate(1.0, f64::INFINITY)));
+        assert!(is_nan(e262_exponentiate(-1.0, f64::NEG_INFINITY)));
+        assert_eq!(e262_exponentiate(0.5, f64::NEG_INFINITY), f64::INFINITY);
+        assert!(is_pos_zero(e262_exponentiate(2.0, f64::NEG_INFINITY)));
+        assert_eq!(e262_exponentiate(f64::NEG_INFINITY, 3.0), f64::NEG_INFINITY);
+        assert_eq!(e262_exponentiate(f64::NEG_INFINITY, 2.0), f64::INFINITY);
+        assert!(is_neg_zero(e262_exponentiate(f64::NEG_INFINITY, -3.0)));
+        assert!(is_neg_zero(e262_exponentiate(-0.0, 3.0)));
+        assert!(is_pos_zero(e262_exponentiate(-0.0, 2.0)));
+        assert_eq!(e262_exponentiate(-0.0, -3.0), f64::NEG_INFINITY);
+        assert_eq!(e262_exponentiate(0.0, -1.0), f64::INFINITY);
+        assert!(is_nan(e262_exponentiate(-8.0, 1.0 / 3.0)));
+        assert_eq!(e262_exponentiate(-2.0, 3.0), -8.0);
+    }
+
+    #[test]
+    fn remainder_works() {
+        assert_eq!(e262_remainder(5.5, 2.0), 1.5);
+        assert_eq!(e262_remainder(-5.5, 2.0), -1.5);
+        assert!(is_neg_zero(e262_remainder(-4.0, 2.0)));
+        assert!(is_nan(e262_remainder(f64::INFINITY, 2.0)));
+        assert!(is_nan(e262_remainder(1.0, 0.0)));
+        assert_eq!(e262_remainder(1.0, f64::NEG_INFINITY), 1.0);
+        assert!(is_neg_zero(e262_remainder(-0.0, 5.0)));
+        assert_eq!(e262_remainder(1e300, 7.0), 1.0);
+    }
+
+    #[test]
+    fn arithmetic_works() {
+        assert_eq!(e262_add(0.1, 0.2), 0.30000000000000004);
+        assert!(is_neg_zero(e262_add(-0.0, -0.0)));
+        assert!(is_pos_zero(e262_subtract(0.0, 0.0)));
+        assert!(is_nan(e262_add(f64::INFINITY, f64::NEG_INFINITY)));
+        assert!(is_neg_zero(e262_multiply(-1.0, 0.0)));
+        assert_eq!(e262_divide(1.0, -0.0), f64::NEG_INFINITY);
+        assert!(is_neg_zero(e262_unary_minus(0.0)));
+    }
+
+    #[test]
+    fn bitwise_works() {
+        assert_eq!(e262_bitwise_not(0.0), -1.0);
+        assert_eq!(e262_bitwise_not(4294967295.0), 0.0);
+        assert_eq!(e262_bitwise_and(5.0, 3.0), 1.0);
+        assert_eq!(e262_bitwise_or(5.0, 3.0), 7.0);
+        assert_eq!(e262_bitwise_xor(5.0, 3.0), 6.0);
+        assert_eq!(e262_bitwise_or(f64::NAN, 2147483648.0), -2147483648.0);
+    }
+
+    #[test]
+    fn shifts_work() {
+        assert_eq!(e262_left_shift(1.0, 31.0), -2147483648.0);
+        assert_eq!(e262_left_shift(1.0, 32.0), 1.0);
+        assert_eq!(e262_signed_right_shift(-8.0, 1.0), -4.0);
+        assert_eq!(e262_unsigned_right_shift(-1.0, 0.0), 4294967295.0);
+        assert_eq!(e262_unsigned_right_shift(-8.0, 1.0), 2147483644.0);
+        assert_eq!(e262_signed_right_shift(16.0, -1.0), 0.0);
+    }
+
+    #[test]
+    fn less_than_works() {
+        assert_eq!(e262_less_than(1.0, 2.0), Some(true));
+        assert_eq!(e262_less_than(-0.0, 0.0), Some(false));
+        assert_eq!(e262_less_than(f64::NEG_INFINITY, 0.0), Some(true));
+        assert_eq!(e262_less_than(f64::NAN, 0.0), None);
+    }
+
     #[test]
     fn is_nan_works() {
         assert!(is_nan(f64::NAN));
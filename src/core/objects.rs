@@ -1,26 +1,90 @@
 use ordermap::OrderMap;
 use std::any::Any;
 use std::cell::{Cell, RefCell};
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::rc::Rc;
 
+use super::atom::Atom;
+use super::cast::{e262_to_boolean, e262_to_length, e262_to_object};
+use super::exotic::e262_create_array_from_list;
+use super::function::{e262_call, Callable, Constructor};
 use super::id::MagicId;
 use super::property::Descriptor;
-use super::test::e262_same_value;
-use super::{Property, SymbolRep, Value};
-use crate::errors::CoreResult;
+use super::test::{e262_is_callable, e262_is_constructor, e262_same_value};
+use super::{numbers, Property, StringRep, SymbolRep, Value, WellKnownSymbol};
+use crate::agent::e262_current_realm;
+use crate::errors::{CoreError, CoreResult};
+use crate::realm::Intrinsic;
 
 /// An [Object](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-object-type) property key.
+///
+/// String keys are either [array indices](Self::Index) or [atoms](Self::String), so comparing and hashing keys never touches the string contents. Keys should be built via the [`From`] trait, which picks the right variant.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum PropertyKey {
-    /// A [String](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-ecmascript-language-types-string-type) key.
-    String(String),
+    /// A [String](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-ecmascript-language-types-string-type) key that is an [array index](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#array-index).
+    Index(u32),
+    /// Any other [String](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-ecmascript-language-types-string-type) key.
+    String(Atom),
     /// A [Symbol](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-ecmascript-language-types-symbol-type) key.
     Symbol(SymbolRep),
 }
 
+impl PropertyKey {
+    /// Returns the key as an ES value, i.e. a String or a Symbol.
+    pub fn to_value(&self) -> Value {
+        match self {
+            Self::Index(index) => Value::String(index.to_string().into()),
+            Self::String(atom) => Value::String(atom.as_string().clone()),
+            Self::Symbol(symbol) => Value::Symbol(symbol.clone()),
+        }
+    }
+}
+
+impl From<&str> for PropertyKey {
+    fn from(value: &str) -> Self {
+        PropertyKey::from(StringRep::from(value))
+    }
+}
+
+impl From<StringRep> for PropertyKey {
+    fn from(value: StringRep) -> Self {
+        match p262_array_index(&value) {
+            Some(index) => PropertyKey::Index(index),
+            None => PropertyKey::String(Atom::new(&value)),
+        }
+    }
+}
+
+impl From<u32> for PropertyKey {
+    /// Creates a key from an integer, which is an array index unless it is 2³² − 1.
+    fn from(value: u32) -> Self {
+        if value == u32::MAX {
+            PropertyKey::String(Atom::new(&value.to_string().into()))
+        } else {
+            PropertyKey::Index(value)
+        }
+    }
+}
+
+impl From<f64> for PropertyKey {
+    /// Creates a key from a Number, i.e. from its [ToString](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-tostring).
+    fn from(value: f64) -> Self {
+        if value.fract() == 0f64 && (0f64..u32::MAX as f64).contains(&value) {
+            PropertyKey::Index(value as u32)
+        } else {
+            PropertyKey::from(StringRep::from(numbers::e262_to_string(value, 10)))
+        }
+    }
+}
+
+impl From<SymbolRep> for PropertyKey {
+    fn from(value: SymbolRep) -> Self {
+        PropertyKey::Symbol(value)
+    }
+}
+
 /// Implements the internal methods of an [Object](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-object-type).
 ///
 /// The default implementation of those methods are defined by the [`BaseObject`] struct, and other structs can leverage them via the [`HasBaseObject`] trait, but one or more internal methods can be overriden by [exotic objects](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#exotic-object).
@@ -55,14 +119,32 @@ pub trait Object: Debug {
     ///Implements the [`[[HasProperty]]`](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-invariants-of-the-essential-internal-methods) internal method.
     fn has_property(self: Rc<Self>, key: &PropertyKey) -> CoreResult<bool>;
 
-    // fn get(self: Rc<Self>, key: &PropertyKey, receiver: Value) -> Value;
+    ///Implements the [`[[Get]]`](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-invariants-of-the-essential-internal-methods) internal method.
+    fn get(self: Rc<Self>, key: &PropertyKey, receiver: &Value) -> CoreResult<Value>;
 
-    // fn set(self: Rc<Self>, key: &PropertyKey, value: Value, receiver: Value) -> bool;
+    ///Implements the [`[[Set]]`](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-invariants-of-the-essential-internal-methods) internal method.
+    fn set(self: Rc<Self>, key: &PropertyKey, value: Value, receiver: &Value) -> CoreResult<bool>;
 
     /// Implements the [`[[Delete]]`](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-invariants-of-the-essential-internal-methods) internal method.
     fn delete(self: Rc<Self>, key: &PropertyKey) -> CoreResult<bool>;
 
-    // fn own_property_keys(self: Rc<Self>) -> Vec<&PropertyKey>;
+    /// Implements the [`[[OwnPropertyKeys]]`](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-invariants-of-the-essential-internal-methods) internal method.
+    fn own_property_keys(self: Rc<Self>) -> CoreResult<Vec<PropertyKey>>;
+
+    /// Returns the object as a [function object](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#function-object), if it implements the `[[Call]]` internal method.
+    fn as_callable(self: Rc<Self>) -> Option<Rc<dyn Callable>> {
+        None
+    }
+
+    /// Returns the object as a [constructor](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#constructor), if it implements the `[[Construct]]` internal method.
+    fn as_constructor(self: Rc<Self>) -> Option<Rc<dyn Constructor>> {
+        None
+    }
+
+    /// Returns if the object is an [Array exotic object](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-array-exotic-objects).
+    fn is_array_exotic(self: Rc<Self>) -> bool {
+        false
+    }
 }
 
 /// The internal implementation for an ES [ordinary object](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#ordinary-object).
@@ -70,17 +152,18 @@ pub trait Object: Debug {
 pub struct BaseObject {
     id: MagicId,
     props: RefCell<OrderMap<PropertyKey, Property>>,
-    slots: RefCell<HashMap<String, Rc<dyn 'static + Any>>>,
+    /// Ordered, so that debug output does not depend on hashing.
+    slots: RefCell<OrderMap<String, Rc<dyn 'static + Any>>>,
     prototype: RefCell<Option<ObjectRep>>,
     extensible: Cell<bool>,
 }
 
 impl BaseObject {
-    fn new(prototype: &Option<ObjectRep>) -> Self {
+    pub(crate) fn new(prototype: &Option<ObjectRep>) -> Self {
         BaseObject {
             id: MagicId::new(),
             props: RefCell::new(OrderMap::new()),
-            slots: RefCell::new(HashMap::new()),
+            slots: RefCell::new(OrderMap::new()),
             prototype: RefCell::new(prototype.clone()),
             extensible: Cell::new(true),
         }
@@ -127,9 +210,21 @@ impl Object for BaseObject {
         e262_ordinary_has_property(self, key)
     }
 
+    fn get(self: Rc<Self>, key: &PropertyKey, receiver: &Value) -> CoreResult<Value> {
+        e262_ordinary_get(self, key, receiver)
+    }
+
+    fn set(self: Rc<Self>, key: &PropertyKey, value: Value, receiver: &Value) -> CoreResult<bool> {
+        e262_ordinary_set(self, key, value, receiver)
+    }
+
     fn delete(self: Rc<Self>, key: &PropertyKey) -> CoreResult<bool> {
         e262_ordinary_delete(self, key)
     }
+
+    fn own_property_keys(self: Rc<Self>) -> CoreResult<Vec<PropertyKey>> {
+        Ok(e262_ordinary_own_property_keys(self))
+    }
 }
 
 /// Gets a [`BaseObject`] from an [ordinary](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#ordinary-object) or [exotic](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#exotic-object) Object implementation.
@@ -147,10 +242,352 @@ impl HasBaseObject for BaseObject {
     }
 }
 
+pub(crate) fn e262_create_data_property(
+    obj: &ObjectRep,
+    key: PropertyKey,
+    value: Value,
+) -> CoreResult<bool> {
+    let desc = Descriptor {
+        value: Some(Rc::new(value)),
+        writable: Some(true),
+        enumerable: Some(true),
+        configurable: Some(true),
+        ..Default::default()
+    };
+    obj.0.clone().define_own_property(key, desc)
+}
+
+pub(crate) fn e262_create_list_from_array_like(value: &Value) -> CoreResult<Vec<Value>> {
+    let Value::Object(obj) = value else {
+        return Err(CoreError::TypeError(
+            "Value is not an array-like object".to_string(),
+        ));
+    };
+    let len = e262_length_of_array_like(obj)?;
+    let mut list = Vec::new();
+    let mut index = 0f64;
+    while index < len {
+        list.push(e262_get(obj, &PropertyKey::from(index))?);
+        index += 1f64;
+    }
+    Ok(list)
+}
+
+pub(crate) fn e262_create_data_property_or_throw(
+    obj: &ObjectRep,
+    key: PropertyKey,
+    value: Value,
+) -> CoreResult<()> {
+    if e262_create_data_property(obj, key, value)? {
+        Ok(())
+    } else {
+        Err(CoreError::TypeError("Cannot define property".to_string()))
+    }
+}
+
+pub(crate) fn e262_define_property_or_throw(
+    obj: &ObjectRep,
+    key: PropertyKey,
+    desc: Descriptor,
+) -> CoreResult<()> {
+    let success = obj.0.clone().define_own_property(key, desc)?;
+    if success {
+        Ok(())
+    } else {
+        Err(CoreError::TypeError("Cannot define property".to_string()))
+    }
+}
+
+pub(crate) fn e262_get(obj: &ObjectRep, key: &PropertyKey) -> CoreResult<Value> {
+    obj.0.clone().get(key, &Value::Object(obj.clone()))
+}
+
+pub(crate) fn e262_delete_property_or_throw(obj: &ObjectRep, key: &PropertyKey) -> CoreResult<()> {
+    if obj.0.clone().delete(key)? {
+        Ok(())
+    } else {
+        Err(CoreError::TypeError("Cannot delete property".to_string()))
+    }
+}
+
+/// The kind of results returned by [`e262_enumerable_own_properties`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum PropertyKind {
+    Key,
+    Value,
+    KeyValue,
+}
+
+pub(crate) fn e262_enumerable_own_properties(
+    obj: &ObjectRep,
+    kind: PropertyKind,
+) -> CoreResult<Vec<Value>> {
+    let own_keys = obj.0.clone().own_property_keys()?;
+    let mut results = Vec::new();
+    for key in own_keys {
+        if let PropertyKey::Symbol(_) = key {
+            continue;
+        }
+        let desc = obj.0.clone().get_own_property(&key)?;
+        if !desc.is_some_and(|desc| desc.is_enumerable()) {
+            continue;
+        }
+        if kind == PropertyKind::Key {
+            results.push(key.to_value());
+            continue;
+        }
+        let value = e262_get(obj, &key)?;
+        if kind == PropertyKind::Value {
+            results.push(value);
+        } else {
+            results.push(Value::Object(e262_create_array_from_list(&[
+                key.to_value(),
+                value,
+            ])));
+        }
+    }
+    Ok(results)
+}
+
+pub(crate) fn e262_from_property_descriptor(desc: Option<Property>) -> Value {
+    let Some(desc) = desc else {
+        return Value::Undefined;
+    };
+    let prototype = e262_current_realm().intrinsic(Intrinsic::ObjectPrototype);
+    let obj = e262_ordinary_object_create(Some(prototype));
+    let fields: Vec<(&str, Value)> = match desc {
+        Property::Data {
+            value,
+            writable,
+            enumerable,
+            configurable,
+        } => vec![
+            ("value", value.as_ref().clone()),
+            ("writable", Value::Boolean(writable)),
+            ("enumerable", Value::Boolean(enumerable)),
+            ("configurable", Value::Boolean(configurable)),
+        ],
+        Property::Accessor {
+            get,
+            set,
+            enumerable,
+            configurable,
+        } => vec![
+            (
+                "get",
+                get.map_or(Value::Undefined, |get| get.as_ref().clone()),
+            ),
+            (
+                "set",
+                set.map_or(Value::Undefined, |set| set.as_ref().clone()),
+            ),
+            ("enumerable", Value::Boolean(enumerable)),
+            ("configurable", Value::Boolean(configurable)),
+        ],
+    };
+    for (key, value) in fields {
+        e262_create_data_property_or_throw(&obj, PropertyKey::from(key), value)
+            .expect("Fresh ordinary objects accept new properties");
+    }
+    Value::Object(obj)
+}
+
+pub(crate) fn e262_get_method(value: &Value, key: &PropertyKey) -> CoreResult<Option<Value>> {
+    let func = e262_get_v(value, key)?;
+    match func {
+        Value::Undefined | Value::Null => Ok(None),
+        _ if e262_is_callable(&func) => Ok(Some(func)),
+        _ => Err(CoreError::TypeError("Value is not a function".to_string())),
+    }
+}
+
+pub(crate) fn e262_get_v(value: &Value, key: &PropertyKey) -> CoreResult<Value> {
+    let obj = e262_to_object(value)?;
+    obj.0.get(key, value)
+}
+
+pub(crate) fn e262_has_own_property(obj: &ObjectRep, key: &PropertyKey) -> CoreResult<bool> {
+    Ok(obj.0.clone().get_own_property(key)?.is_some())
+}
+
+pub(crate) fn e262_has_property(obj: &ObjectRep, key: &PropertyKey) -> CoreResult<bool> {
+    obj.0.clone().has_property(key)
+}
+
+pub(crate) fn e262_instanceof_operator(value: &Value, target: &Value) -> CoreResult<bool> {
+    if !matches!(target, Value::Object(_)) {
+        return Err(CoreError::TypeError(
+            "Right-hand side of instanceof is not an object".to_string(),
+        ));
+    }
+    let key = PropertyKey::from(SymbolRep::well_known(WellKnownSymbol::HasInstance));
+    if let Some(handler) = e262_get_method(target, &key)? {
+        return Ok(e262_to_boolean(&e262_call(
+            &handler,
+            target,
+            std::slice::from_ref(value),
+        )?));
+    }
+    if !e262_is_callable(target) {
+        return Err(CoreError::TypeError(
+            "Right-hand side of instanceof is not callable".to_string(),
+        ));
+    }
+    e262_ordinary_has_instance(target, value)
+}
+
+pub(crate) fn e262_invoke(
+    value: &Value,
+    key: &PropertyKey,
+    arguments_list: &[Value],
+) -> CoreResult<Value> {
+    let func = e262_get_v(value, key)?;
+    e262_call(&func, value, arguments_list)
+}
+
 pub(crate) fn e262_is_extensible(obj: Rc<dyn Object>) -> CoreResult<bool> {
     Object::is_extensible(obj.clone())
 }
 
+pub(crate) fn e262_length_of_array_like(obj: &ObjectRep) -> CoreResult<f64> {
+    e262_to_length(&e262_get(obj, &PropertyKey::from("length"))?)
+}
+
+pub(crate) fn e262_set(
+    obj: &ObjectRep,
+    key: &PropertyKey,
+    value: Value,
+    throw: bool,
+) -> CoreResult<()> {
+    let success = obj.0.clone().set(key, value, &Value::Object(obj.clone()))?;
+    if !success && throw {
+        Err(CoreError::TypeError(
+            "Cannot assign to property".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Implements [SpeciesConstructor](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-speciesconstructor).
+pub(crate) fn e262_species_constructor(
+    obj: &ObjectRep,
+    default_constructor: ObjectRep,
+) -> CoreResult<ObjectRep> {
+    let c = e262_get(obj, &PropertyKey::from("constructor"))?;
+    let c = match c {
+        Value::Undefined => return Ok(default_constructor),
+        Value::Object(c) => c,
+        _ => {
+            return Err(CoreError::TypeError(
+                "Object constructor is not an object".to_string(),
+            ))
+        }
+    };
+    let species = PropertyKey::from(SymbolRep::well_known(WellKnownSymbol::Species));
+    match e262_get(&c, &species)? {
+        Value::Undefined | Value::Null => Ok(default_constructor),
+        Value::Object(s) if e262_is_constructor(&Value::Object(s.clone())) => Ok(s),
+        _ => Err(CoreError::TypeError(
+            "Object species is not a constructor".to_string(),
+        )),
+    }
+}
+
+/// An [integrity level](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-setintegritylevel) of an object.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum IntegrityLevel {
+    Sealed,
+    Frozen,
+}
+
+pub(crate) fn e262_set_integrity_level(obj: &ObjectRep, level: IntegrityLevel) -> CoreResult<bool> {
+    if !obj.0.clone().prevent_extensions()? {
+        return Ok(false);
+    }
+    let keys = obj.0.clone().own_property_keys()?;
+    for key in keys {
+        let desc = match level {
+            IntegrityLevel::Sealed => Descriptor {
+                configurable: Some(false),
+                ..Default::default()
+            },
+            IntegrityLevel::Frozen => match obj.0.clone().get_own_property(&key)? {
+                None => continue,
+                Some(current) if current.is_accessor() => Descriptor {
+                    configurable: Some(false),
+                    ..Default::default()
+                },
+                Some(_) => Descriptor {
+                    configurable: Some(false),
+                    writable: Some(false),
+                    ..Default::default()
+                },
+            },
+        };
+        e262_define_property_or_throw(obj, key, desc)?;
+    }
+    Ok(true)
+}
+
+pub(crate) fn e262_test_integrity_level(
+    obj: &ObjectRep,
+    level: IntegrityLevel,
+) -> CoreResult<bool> {
+    if obj.0.clone().is_extensible()? {
+        return Ok(false);
+    }
+    for key in obj.0.clone().own_property_keys()? {
+        match obj.0.clone().get_own_property(&key)? {
+            Some(current) if current.is_configurable() => return Ok(false),
+            Some(Property::Data { writable: true, .. }) if level == IntegrityLevel::Frozen => {
+                return Ok(false)
+            }
+            _ => {}
+        }
+    }
+    Ok(true)
+}
+
+pub(crate) fn e262_to_property_descriptor(value: &Value) -> CoreResult<Descriptor> {
+    let Value::Object(obj) = value else {
+        return Err(CoreError::TypeError(
+            "Property description must be an object".to_string(),
+        ));
+    };
+    let field = |name: &str| -> CoreResult<Option<Value>> {
+        let key = PropertyKey::from(name);
+        if e262_has_property(obj, &key)? {
+            Ok(Some(e262_get(obj, &key)?))
+        } else {
+            Ok(None)
+        }
+    };
+    let accessor = |name: &str| -> CoreResult<Option<Rc<Value>>> {
+        match field(name)? {
+            Some(func) if !e262_is_callable(&func) && func != Value::Undefined => Err(
+                CoreError::TypeError(format!("Property {name}ter must be a function")),
+            ),
+            func => Ok(func.map(Rc::new)),
+        }
+    };
+    let desc = Descriptor {
+        enumerable: field("enumerable")?.map(|value| e262_to_boolean(&value)),
+        configurable: field("configurable")?.map(|value| e262_to_boolean(&value)),
+        value: field("value")?.map(Rc::new),
+        writable: field("writable")?.map(|value| e262_to_boolean(&value)),
+        get: accessor("get")?,
+        set: accessor("set")?,
+    };
+    if desc.is_accessor() && desc.is_data() {
+        return Err(CoreError::TypeError(
+            "Property descriptors cannot both specify accessors and a value or writable attribute"
+                .to_string(),
+        ));
+    }
+    Ok(desc)
+}
+
 pub(crate) fn e262_ordinary_define_own_property(
     obj: Rc<dyn HasBaseObject>,
     key: &PropertyKey,
@@ -187,6 +624,140 @@ pub(crate) fn e262_ordinary_delete(
     }
 }
 
+pub(crate) fn e262_ordinary_get(
+    obj: Rc<dyn HasBaseObject>,
+    key: &PropertyKey,
+    receiver: &Value,
+) -> CoreResult<Value> {
+    let desc = Object::get_own_property(obj.clone(), key)?;
+    match desc {
+        None => {
+            let parent = Object::get_prototype_of(obj)?;
+            match parent {
+                Some(parent) => parent.0.clone().get(key, receiver),
+                None => Ok(Value::Undefined),
+            }
+        }
+        Some(Property::Data { value, .. }) => Ok(value.as_ref().clone()),
+        Some(Property::Accessor { get, .. }) => match get {
+            Some(getter) if !matches!(*getter, Value::Undefined) => {
+                e262_call(&getter, receiver, &[])
+            }
+            _ => Ok(Value::Undefined),
+        },
+    }
+}
+
+pub(crate) fn e262_ordinary_has_instance(c: &Value, o: &Value) -> CoreResult<bool> {
+    let Value::Object(c) = c else {
+        return Ok(false);
+    };
+    if c.0.clone().as_callable().is_none() {
+        return Ok(false);
+    }
+    if let Some(target) = p262_get_slot::<ObjectRep>(c.0.clone(), "BoundTargetFunction".to_string())
+    {
+        return e262_instanceof_operator(o, &Value::Object(target.as_ref().clone()));
+    }
+    let Value::Object(o) = o else {
+        return Ok(false);
+    };
+    let Value::Object(p) = e262_get(c, &PropertyKey::from("prototype"))? else {
+        return Err(CoreError::TypeError(
+            "Function has non-object prototype in instanceof check".to_string(),
+        ));
+    };
+    let mut o = o.clone();
+    loop {
+        match o.0.get_prototype_of()? {
+            None => return Ok(false),
+            Some(proto) if proto == p => return Ok(true),
+            Some(proto) => o = proto,
+        }
+    }
+}
+
+pub(crate) fn e262_ordinary_create_from_constructor(
+    constructor: &ObjectRep,
+    intrinsic_default_proto: Intrinsic,
+) -> CoreResult<ObjectRep> {
+    let proto = e262_get_prototype_from_constructor(constructor, intrinsic_default_proto)?;
+    Ok(e262_ordinary_object_create(Some(proto)))
+}
+
+/// Implements [GetPrototypeFromConstructor](https://tc39.es/ecma262/multipage/ordinary-and-exotic-objects-behaviours.html#sec-getprototypefromconstructor), falling back to the intrinsic of the current realm.
+pub(crate) fn e262_get_prototype_from_constructor(
+    constructor: &ObjectRep,
+    intrinsic_default_proto: Intrinsic,
+) -> CoreResult<ObjectRep> {
+    match e262_get(constructor, &PropertyKey::from("prototype"))? {
+        Value::Object(proto) => Ok(proto),
+        _ => Ok(e262_current_realm().intrinsic(intrinsic_default_proto)),
+    }
+}
+
+pub(crate) fn e262_ordinary_set(
+    obj: Rc<dyn HasBaseObject>,
+    key: &PropertyKey,
+    value: Value,
+    receiver: &Value,
+) -> CoreResult<bool> {
+    let own_desc = Object::get_own_property(obj.clone(), key)?;
+    e262_ordinary_set_with_own_descriptor(obj, key, value, receiver, own_desc)
+}
+
+pub(crate) fn e262_ordinary_set_with_own_descriptor(
+    obj: Rc<dyn HasBaseObject>,
+    key: &PropertyKey,
+    value: Value,
+    receiver: &Value,
+    own_desc: Option<Property>,
+) -> CoreResult<bool> {
+    let own_desc = match own_desc {
+        Some(own_desc) => own_desc,
+        None => match Object::get_prototype_of(obj)? {
+            Some(parent) => return parent.0.set(key, value, receiver),
+            None => Property::Data {
+                value: Rc::new(Value::Undefined),
+                writable: true,
+                enumerable: true,
+                configurable: true,
+            },
+        },
+    };
+    match own_desc {
+        Property::Data { writable, .. } => {
+            if !writable {
+                return Ok(false);
+            }
+            let Value::Object(receiver) = receiver else {
+                return Ok(false);
+            };
+            match receiver.0.clone().get_own_property(key)? {
+                Some(Property::Accessor { .. })
+                | Some(Property::Data {
+                    writable: false, ..
+                }) => Ok(false),
+                Some(Property::Data { .. }) => receiver.0.clone().define_own_property(
+                    key.clone(),
+                    Descriptor {
+                        value: Some(Rc::new(value)),
+                        ..Default::default()
+                    },
+                ),
+                None => e262_create_data_property(receiver, key.clone(), value),
+            }
+        }
+        Property::Accessor { set, .. } => match set {
+            Some(setter) if !matches!(*setter, Value::Undefined) => {
+                e262_call(&setter, receiver, &[value])?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        },
+    }
+}
+
 pub(crate) fn e262_ordinary_get_own_property(
     obj: Rc<dyn HasBaseObject>,
     key: &PropertyKey,
@@ -206,12 +777,11 @@ pub(crate) fn e262_ordinary_has_property(
     obj: Rc<dyn HasBaseObject>,
     key: &PropertyKey,
 ) -> CoreResult<bool> {
-    let base = obj.get_object();
-    let has_own = base.clone().get_own_property(key)?;
+    let has_own = Object::get_own_property(obj.clone(), key)?;
     match has_own {
         Some(_) => Ok(true),
         None => {
-            let parent = base.get_prototype_of()?;
+            let parent = Object::get_prototype_of(obj)?;
             match parent {
                 Some(parent) => parent.0.clone().has_property(key),
                 None => Ok(false),
@@ -220,6 +790,34 @@ pub(crate) fn e262_ordinary_has_property(
     }
 }
 
+pub(crate) fn e262_ordinary_own_property_keys(obj: Rc<dyn HasBaseObject>) -> Vec<PropertyKey> {
+    let base = obj.get_object();
+    let props = base.props.borrow();
+    let mut indices: Vec<u32> = props
+        .keys()
+        .filter_map(|key| match key {
+            PropertyKey::Index(index) => Some(*index),
+            _ => None,
+        })
+        .collect();
+    indices.sort_unstable();
+    let strings = props
+        .keys()
+        .filter(|key| matches!(key, PropertyKey::String(_)));
+    let symbols = props
+        .keys()
+        .filter(|key| matches!(key, PropertyKey::Symbol(_)));
+    indices
+        .into_iter()
+        .map(PropertyKey::Index)
+        .chain(strings.chain(symbols).cloned())
+        .collect()
+}
+
+pub(crate) fn e262_ordinary_object_create(proto: Option<ObjectRep>) -> ObjectRep {
+    ObjectRep::new(Rc::new(BaseObject::new(&proto)))
+}
+
 pub(crate) fn e262_ordinary_is_extensible(obj: Rc<dyn HasBaseObject>) -> bool {
     let base = obj.get_object();
     base.extensible.get()
@@ -238,7 +836,7 @@ pub(crate) fn e262_is_compatible_property_descriptor(
 ) -> bool {
     e262_validate_and_apply_property_descriptor(
         None,
-        &PropertyKey::String("".into()),
+        &PropertyKey::from(""),
         extensible,
         desc,
         current,
@@ -250,41 +848,27 @@ pub(crate) fn e262_ordinary_set_prototype_of(
     proto: Option<ObjectRep>,
 ) -> bool {
     let base = obj.get_object();
-    let base_id = base.id;
-    let current = base.prototype.borrow_mut();
-    if *current == proto {
-        true
-    } else {
-        let mut found_protos: HashSet<MagicId> = HashSet::new();
-
-        if !base.extensible.get() {
+    if *base.prototype.borrow() == proto {
+        return true;
+    }
+    if !base.extensible.get() {
+        return false;
+    }
+    // Walk up from the new prototype, stopping at a cycle that does not include the object itself.
+    let mut found_protos: HashSet<MagicId> = HashSet::new();
+    let mut p = proto.clone();
+    while let Some(rep) = p {
+        let proto_base = rep.0.get_object();
+        if Rc::ptr_eq(&proto_base, &base) {
             return false;
         }
-
-        let mut p: Option<ObjectRep> = proto;
-        let mut done = false;
-        while !done {
-            match &p {
-                None => {
-                    done = true;
-                }
-                Some(rep) => {
-                    let curr_id = rep.clone().0.get_object().id;
-
-                    if curr_id == base_id {
-                        return false;
-                    } else if found_protos.contains(&curr_id) {
-                        done = true; // @TODO
-                    } else {
-                        found_protos.insert(curr_id);
-                        p = rep.clone().0.get_object().prototype.borrow().clone();
-                    }
-                }
-            }
+        if !found_protos.insert(proto_base.id) {
+            break;
         }
-        base.prototype.replace(p);
-        true
+        p = proto_base.prototype.borrow().clone();
     }
+    base.prototype.replace(proto);
+    true
 }
 
 #[allow(clippy::if_same_then_else)]
@@ -318,7 +902,10 @@ pub(crate) fn e262_validate_and_apply_property_descriptor(
             } else if !current.is_configurable() {
                 if desc.configurable == Some(true) {
                     return false;
-                } else if desc.enumerable == Some(current.is_enumerable()) {
+                } else if desc
+                    .enumerable
+                    .is_some_and(|e| e != current.is_enumerable())
+                {
                     return false;
                 } else if !desc.is_generic() && (desc.is_accessor() != current.is_accessor()) {
                     return false;
@@ -366,49 +953,36 @@ pub(crate) fn e262_validate_and_apply_property_descriptor(
                 }
             }
             if let Some(obj) = obj {
-                let prop = match &current {
-                    Property::Accessor {
+                // Attributes absent from the descriptor keep their current value, unless the property changes kind.
+                let enumerable = desc.enumerable.unwrap_or(current.is_enumerable());
+                let configurable = desc.configurable.unwrap_or(current.is_configurable());
+                let prop = match current {
+                    Property::Data {
+                        value, writable, ..
+                    } if !desc.is_accessor() => Property::Data {
+                        value: desc.value.unwrap_or(value),
+                        writable: desc.writable.unwrap_or(writable),
                         enumerable,
                         configurable,
-                        ..
-                    } => {
-                        if desc.is_accessor() {
-                            Property::Accessor {
-                                get: desc.get,
-                                set: desc.set,
-                                enumerable: desc.enumerable.unwrap_or(false),
-                                configurable: desc.configurable.unwrap_or(false),
-                            }
-                        } else {
-                            Property::Data {
-                                value: desc.value.unwrap_or_else(|| Rc::new(Value::Undefined)),
-                                writable: desc.writable.unwrap_or(false),
-                                enumerable: desc.enumerable.unwrap_or(*enumerable),
-                                configurable: desc.configurable.unwrap_or(*configurable),
-                            }
-                        }
-                    }
-                    Property::Data {
+                    },
+                    Property::Accessor { get, set, .. } if !desc.is_data() => Property::Accessor {
+                        get: desc.get.or(get),
+                        set: desc.set.or(set),
                         enumerable,
                         configurable,
-                        ..
-                    } => {
-                        if desc.is_data() {
-                            Property::Data {
-                                value: desc.value.unwrap_or_else(|| Rc::new(Value::Undefined)),
-                                writable: desc.writable.unwrap_or(false),
-                                enumerable: desc.enumerable.unwrap_or(false),
-                                configurable: desc.configurable.unwrap_or(false),
-                            }
-                        } else {
-                            Property::Accessor {
-                                get: desc.get,
-                                set: desc.set,
-                                enumerable: desc.enumerable.unwrap_or(*enumerable),
-                                configurable: desc.configurable.unwrap_or(*configurable),
-                            }
-                        }
-                    }
+                    },
+                    Property::Data { .. } => Property::Accessor {
+                        get: desc.get,
+                        set: desc.set,
+                        enumerable,
+                        configurable,
+                    },
+                    Property::Accessor { .. } => Property::Data {
+                        value: desc.value.unwrap_or_else(|| Rc::new(Value::Undefined)),
+                        writable: desc.writable.unwrap_or(false),
+                        enumerable,
+                        configurable,
+                    },
                 };
                 let base = obj.get_object();
                 let mut props = base.props.borrow_mut();
@@ -419,6 +993,25 @@ pub(crate) fn e262_validate_and_apply_property_descriptor(
     true
 }
 
+/// Returns the numeric value of an [array index](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#array-index), i.e. a canonical numeric string in the range 0 to 2³² − 2.
+pub fn p262_array_index(key: &StringRep) -> Option<u32> {
+    if key.is_empty() || key.len() > 10 || (key.len() > 1 && key.code_unit_at(0) == Some(0x30)) {
+        return None;
+    }
+    let mut index = 0u64;
+    for unit in key.code_units() {
+        if !(0x30..=0x39).contains(&unit) {
+            return None;
+        }
+        index = index * 10 + (unit - 0x30) as u64;
+    }
+    if index >= u32::MAX as u64 {
+        None
+    } else {
+        Some(index as u32)
+    }
+}
+
 /// Retrieves a slot from the [`Object`], if it exists and matches the provided type.
 ///
 /// Prefer it over the lower-level [`Object::get_slot`] because of the typed return value.
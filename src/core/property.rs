@@ -203,7 +203,7 @@ pub(crate) fn e262_complete_property_descriptor(desc: Descriptor) -> Property {
             value: desc.value.unwrap_or_else(|| Rc::new(Value::Undefined)),
             writable: desc.writable.unwrap_or(false),
             enumerable: desc.enumerable.unwrap_or(false),
-            configurable: desc.enumerable.unwrap_or(false),
+            configurable: desc.configurable.unwrap_or(false),
         }
     }
 }
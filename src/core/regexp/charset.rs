@@ -0,0 +1,221 @@
+//! Sets of characters and the case canonicalization of regular expressions.
+
+use crate::core::unicode::{
+    p262_simple_case_folding, p262_simple_case_foldings, p262_uppercase_mapping,
+    p262_uppercase_mappings,
+};
+
+/// The largest code point.
+pub(super) const MAX_CODE_POINT: u32 = 0x10FFFF;
+
+/// The largest code unit, the largest character outside of Unicode mode.
+pub(super) const MAX_CODE_UNIT: u32 = 0xFFFF;
+
+/// A set of characters, i.e. code points in Unicode mode and code units otherwise, stored as sorted, disjoint and non-adjacent inclusive ranges.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(super) struct CharSet {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CharSet {
+    /// Creates a set from arbitrary inclusive ranges.
+    pub(super) fn from_ranges(ranges: impl IntoIterator<Item = (u32, u32)>) -> Self {
+        let mut ranges: Vec<(u32, u32)> = ranges.into_iter().collect();
+        ranges.sort_unstable();
+        let mut result: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (first, last) in ranges {
+            match result.last_mut() {
+                Some(previous) if first <= previous.1.saturating_add(1) => {
+                    previous.1 = previous.1.max(last);
+                }
+                _ => result.push((first, last)),
+            }
+        }
+        Self { ranges: result }
+    }
+
+    /// Creates a set holding a single character.
+    pub(super) fn single(c: u32) -> Self {
+        Self {
+            ranges: vec![(c, c)],
+        }
+    }
+
+    /// Creates a set holding the characters from `first` to `last` included.
+    pub(super) fn range(first: u32, last: u32) -> Self {
+        Self {
+            ranges: vec![(first, last)],
+        }
+    }
+
+    /// Returns the ranges of the set.
+    pub(super) fn ranges(&self) -> &[(u32, u32)] {
+        &self.ranges
+    }
+
+    /// Returns if the set is empty.
+    pub(super) fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns if the set holds `c`.
+    pub(super) fn contains(&self, c: u32) -> bool {
+        self.ranges
+            .binary_search_by(|&(first, last)| {
+                if last < c {
+                    std::cmp::Ordering::Less
+                } else if first > c {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// Returns the characters in either set.
+    pub(super) fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.ranges.iter().chain(&other.ranges).copied())
+    }
+
+    /// Returns the characters up to `max` that are not in the set.
+    pub(super) fn complement(&self, max: u32) -> Self {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        let mut next = 0;
+        for &(first, last) in &self.ranges {
+            if first > max {
+                break;
+            }
+            if first > next {
+                ranges.push((next, first - 1));
+            }
+            next = last.saturating_add(1);
+        }
+        if next <= max {
+            ranges.push((next, max));
+        }
+        Self { ranges }
+    }
+
+    /// Returns the characters in both sets.
+    pub(super) fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a_first, a_last) = self.ranges[i];
+            let (b_first, b_last) = other.ranges[j];
+            let first = a_first.max(b_first);
+            let last = a_last.min(b_last);
+            if first <= last {
+                ranges.push((first, last));
+            }
+            if a_last < b_last {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Returns the characters of the set that are not in `other`.
+    pub(super) fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement(MAX_CODE_POINT))
+    }
+}
+
+/// Implements [Canonicalize](https://tc39.es/ecma262/multipage/text-processing.html#sec-runtime-semantics-canonicalize-ch) for a regular expression that ignores case, using simple case folding in Unicode mode and the uppercase mapping otherwise.
+pub(super) fn canonicalize(c: u32, unicode: bool) -> u32 {
+    if unicode {
+        return p262_simple_case_folding(c);
+    }
+    match p262_uppercase_mapping(c) {
+        Some(&[upper]) if legacy_mapping_applies(c, upper) => upper,
+        _ => c,
+    }
+}
+
+/// Returns if the uppercase mapping of `c` to a single `upper` is kept by the non-Unicode [Canonicalize](https://tc39.es/ecma262/multipage/text-processing.html#sec-runtime-semantics-canonicalize-ch), which never maps a code unit outside of ASCII into ASCII.
+fn legacy_mapping_applies(c: u32, upper: u32) -> bool {
+    c <= MAX_CODE_UNIT && upper <= MAX_CODE_UNIT && (c < 128 || upper >= 128)
+}
+
+/// Returns the characters that do not canonicalize to themselves, with their canonicalization.
+fn canonical_mappings(unicode: bool) -> Vec<(u32, u32)> {
+    if unicode {
+        return p262_simple_case_foldings().collect();
+    }
+    p262_uppercase_mappings()
+        .filter_map(|(c, mapping)| match *mapping {
+            [upper] if legacy_mapping_applies(c, upper) => Some((c, upper)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the canonicalization of every character of `set`.
+pub(super) fn canonicalize_set(set: &CharSet, unicode: bool) -> CharSet {
+    let mappings = canonical_mappings(unicode);
+    let changed = CharSet::from_ranges(mappings.iter().map(|&(c, _)| (c, c)));
+    let images = mappings
+        .iter()
+        .filter(|&&(c, _)| set.contains(c))
+        .map(|&(_, image)| (image, image));
+    let unchanged = set.difference(&changed);
+    CharSet::from_ranges(unchanged.ranges().iter().copied().chain(images))
+}
+
+/// Returns the characters that are their own simple case folding, which are all the characters of a case-insensitive `v` regular expression.
+pub(super) fn case_folded_characters() -> CharSet {
+    CharSet::from_ranges(p262_simple_case_foldings().map(|(c, _)| (c, c)))
+        .complement(MAX_CODE_POINT)
+}
+
+/// Returns the characters of `\d`.
+pub(super) fn digit_characters() -> CharSet {
+    CharSet::range('0' as u32, '9' as u32)
+}
+
+/// Returns the characters of `\s`, i.e. WhiteSpace and LineTerminator.
+pub(super) fn white_space_characters() -> CharSet {
+    CharSet::from_ranges([
+        (0x9, 0xD),
+        (0x20, 0x20),
+        (0xA0, 0xA0),
+        (0x1680, 0x1680),
+        (0x2000, 0x200A),
+        (0x2028, 0x2029),
+        (0x202F, 0x202F),
+        (0x205F, 0x205F),
+        (0x3000, 0x3000),
+        (0xFEFF, 0xFEFF),
+    ])
+}
+
+/// Implements [WordCharacters](https://tc39.es/ecma262/multipage/text-processing.html#sec-wordcharacters), the characters of `\w`.
+pub(super) fn word_characters(unicode: bool, ignore_case: bool) -> CharSet {
+    let basic = CharSet::from_ranges([
+        ('0' as u32, '9' as u32),
+        ('A' as u32, 'Z' as u32),
+        ('_' as u32, '_' as u32),
+        ('a' as u32, 'z' as u32),
+    ]);
+    if !(unicode && ignore_case) {
+        return basic;
+    }
+    let extra = canonical_mappings(true)
+        .into_iter()
+        .filter(|&(c, image)| basic.contains(image) && !basic.contains(c))
+        .map(|(c, _)| (c, c));
+    CharSet::from_ranges(basic.ranges().iter().copied().chain(extra))
+}
+
+/// Returns the characters of `.` without the `s` flag, i.e. all but the line terminators.
+pub(super) fn non_line_terminators(max: u32) -> CharSet {
+    CharSet::from_ranges([(0xA, 0xA), (0xD, 0xD), (0x2028, 0x2029)]).complement(max)
+}
+
+/// Returns if a code unit is a LineTerminator.
+pub(super) fn is_line_terminator(c: u32) -> bool {
+    matches!(c, 0xA | 0xD | 0x2028 | 0x2029)
+}
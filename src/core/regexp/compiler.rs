@@ -0,0 +1,332 @@
+//! The compiler of parsed patterns into programs for the backtracking matcher.
+
+use super::charset::{canonicalize, canonicalize_set, word_characters, CharSet};
+use super::parser::Node;
+
+/// What a single character is matched against.
+#[derive(Debug)]
+pub(super) enum CharMatcher {
+    /// The character itself.
+    Char(u32),
+    /// Any character with the given canonicalization.
+    Fold(u32),
+    /// A character of the set with the given index, or not of it when `invert` is set. When `fold` is set the set is canonicalized, and so is the matched character.
+    Set {
+        set: usize,
+        invert: bool,
+        fold: bool,
+    },
+}
+
+/// An instruction of a [`Program`].
+#[derive(Debug)]
+pub(super) enum Inst {
+    /// Consumes a character, going towards the start of the input when `backward` is set.
+    Consume {
+        matcher: CharMatcher,
+        backward: bool,
+    },
+    /// Consumes between `min` and `max` characters, the fast path for quantified single characters.
+    Star {
+        matcher: CharMatcher,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+        backward: bool,
+    },
+    /// Continues with the next instruction, then with the given one on failure.
+    Split(usize),
+    /// Continues with the given instruction.
+    Jump(usize),
+    /// Stores the position in a capture slot.
+    Save(usize),
+    LineStart {
+        multiline: bool,
+    },
+    LineEnd {
+        multiline: bool,
+    },
+    /// Checks for a word boundary, using the word characters of the set with the given index.
+    WordBoundary {
+        negate: bool,
+        set: usize,
+    },
+    /// Matches the text captured by the first of `groups` that participates in the match.
+    BackRef {
+        groups: Vec<usize>,
+        fold: bool,
+        backward: bool,
+    },
+    /// Matches the lookaround whose body follows, up to its [`Match`](Inst::Match), then continues at `end`.
+    Look {
+        negate: bool,
+        end: usize,
+    },
+    /// Resets the iteration counter of a general quantifier.
+    RepeatStart {
+        counter: usize,
+    },
+    /// Decides whether a general quantifier runs its body, which follows, or continues at `exit`.
+    RepeatHead {
+        counter: usize,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+        exit: usize,
+    },
+    /// Starts an iteration of a general quantifier: records the position and clears the captures of the body.
+    RepeatEnter {
+        position: usize,
+        slots: (usize, usize),
+    },
+    /// Ends an iteration of a general quantifier, rejecting empty iterations once the minimum is reached.
+    RepeatTail {
+        head: usize,
+        counter: usize,
+        position: usize,
+        min: u32,
+    },
+    /// Ends a successful match.
+    Match,
+}
+
+/// A compiled pattern.
+#[derive(Debug)]
+pub(super) struct Program {
+    pub(super) insts: Vec<Inst>,
+    pub(super) sets: Vec<CharSet>,
+    /// The number of registers, holding the counters and positions of general quantifiers.
+    pub(super) registers: usize,
+    /// The number of capture slots, two for each group including the whole match.
+    pub(super) slots: usize,
+    /// Whether characters are code points rather than code units.
+    pub(super) unicode: bool,
+}
+
+/// Compiles a parsed pattern with the given number of capturing groups.
+pub(super) fn compile(node: &Node, group_count: usize, unicode: bool) -> Program {
+    let mut compiler = Compiler {
+        program: Program {
+            insts: Vec::new(),
+            sets: Vec::new(),
+            registers: 0,
+            slots: 2 * (group_count + 1),
+            unicode,
+        },
+    };
+    compiler.emit(Inst::Save(0));
+    compiler.compile(node, false);
+    compiler.emit(Inst::Save(1));
+    compiler.emit(Inst::Match);
+    compiler.program
+}
+
+struct Compiler {
+    program: Program,
+}
+
+impl Compiler {
+    fn emit(&mut self, inst: Inst) -> usize {
+        self.program.insts.push(inst);
+        self.program.insts.len() - 1
+    }
+
+    fn next_pc(&self) -> usize {
+        self.program.insts.len()
+    }
+
+    fn add_set(&mut self, set: CharSet) -> usize {
+        self.program.sets.push(set);
+        self.program.sets.len() - 1
+    }
+
+    fn add_register(&mut self) -> usize {
+        self.program.registers += 1;
+        self.program.registers - 1
+    }
+
+    /// Returns the matcher of a node matching a single character, if it is one.
+    fn char_matcher(&mut self, node: &Node) -> Option<CharMatcher> {
+        let unicode = self.program.unicode;
+        match node {
+            Node::Char {
+                c,
+                ignore_case: false,
+            } => Some(CharMatcher::Char(*c)),
+            Node::Char {
+                c,
+                ignore_case: true,
+            } => Some(CharMatcher::Fold(canonicalize(*c, unicode))),
+            Node::Set {
+                set,
+                invert,
+                ignore_case,
+            } => {
+                let set = if *ignore_case {
+                    canonicalize_set(set, unicode)
+                } else {
+                    set.clone()
+                };
+                Some(CharMatcher::Set {
+                    set: self.add_set(set),
+                    invert: *invert,
+                    fold: *ignore_case,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Compiles a node matching towards the end of the input, or towards its start in lookbehinds.
+    fn compile(&mut self, node: &Node, backward: bool) {
+        match node {
+            Node::Empty => {}
+            Node::Char { .. } | Node::Set { .. } => {
+                let matcher = self.char_matcher(node).expect("The node is a character");
+                self.emit(Inst::Consume { matcher, backward });
+            }
+            Node::Seq(nodes) => {
+                if backward {
+                    nodes.iter().rev().for_each(|node| self.compile(node, true));
+                } else {
+                    nodes.iter().for_each(|node| self.compile(node, false));
+                }
+            }
+            Node::Alt(nodes) => {
+                let mut jumps = Vec::new();
+                for (i, node) in nodes.iter().enumerate() {
+                    if i + 1 == nodes.len() {
+                        self.compile(node, backward);
+                        break;
+                    }
+                    let split = self.emit(Inst::Split(0));
+                    self.compile(node, backward);
+                    jumps.push(self.emit(Inst::Jump(0)));
+                    self.program.insts[split] = Inst::Split(self.next_pc());
+                }
+                let end = self.next_pc();
+                for jump in jumps {
+                    self.program.insts[jump] = Inst::Jump(end);
+                }
+            }
+            Node::Group { index, node } => {
+                let (first, second) = if backward {
+                    (2 * index + 1, 2 * index)
+                } else {
+                    (2 * index, 2 * index + 1)
+                };
+                self.emit(Inst::Save(first));
+                self.compile(node, backward);
+                self.emit(Inst::Save(second));
+            }
+            Node::Look {
+                behind,
+                negate,
+                node,
+            } => {
+                let look = self.emit(Inst::Look {
+                    negate: *negate,
+                    end: 0,
+                });
+                self.compile(node, *behind);
+                self.emit(Inst::Match);
+                let end = self.next_pc();
+                self.program.insts[look] = Inst::Look {
+                    negate: *negate,
+                    end,
+                };
+            }
+            Node::Repeat {
+                node,
+                min,
+                max,
+                greedy,
+                groups,
+            } => self.compile_repeat(node, *min, *max, *greedy, *groups, backward),
+            Node::LineStart { multiline } => {
+                self.emit(Inst::LineStart {
+                    multiline: *multiline,
+                });
+            }
+            Node::LineEnd { multiline } => {
+                self.emit(Inst::LineEnd {
+                    multiline: *multiline,
+                });
+            }
+            Node::WordBoundary {
+                negate,
+                ignore_case,
+            } => {
+                let set = self.add_set(word_characters(self.program.unicode, *ignore_case));
+                self.emit(Inst::WordBoundary {
+                    negate: *negate,
+                    set,
+                });
+            }
+            Node::BackRef {
+                groups,
+                ignore_case,
+            } => {
+                self.emit(Inst::BackRef {
+                    groups: groups.clone(),
+                    fold: *ignore_case,
+                    backward,
+                });
+            }
+        }
+    }
+
+    /// Compiles a quantifier following [RepeatMatcher](https://tc39.es/ecma262/multipage/text-processing.html#sec-runtime-semantics-repeatmatcher-abstract-operation).
+    fn compile_repeat(
+        &mut self,
+        node: &Node,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+        groups: (usize, usize),
+        backward: bool,
+    ) {
+        if max == Some(0) {
+            return;
+        }
+        if let Some(matcher) = self.char_matcher(node) {
+            self.emit(Inst::Star {
+                matcher,
+                min,
+                max,
+                greedy,
+                backward,
+            });
+            return;
+        }
+        let counter = self.add_register();
+        let position = self.add_register();
+        self.emit(Inst::RepeatStart { counter });
+        let head = self.emit(Inst::RepeatHead {
+            counter,
+            min,
+            max,
+            greedy,
+            exit: 0,
+        });
+        self.emit(Inst::RepeatEnter {
+            position,
+            slots: (2 * groups.0, 2 * groups.1),
+        });
+        self.compile(node, backward);
+        self.emit(Inst::RepeatTail {
+            head,
+            counter,
+            position,
+            min,
+        });
+        let exit = self.next_pc();
+        self.program.insts[head] = Inst::RepeatHead {
+            counter,
+            min,
+            max,
+            greedy,
+            exit,
+        };
+    }
+}
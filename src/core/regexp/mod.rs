@@ -0,0 +1,430 @@
+//! A backtracking regular expression engine implementing the [pattern semantics](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexp-regular-expression-objects) of ES.
+//!
+//! Patterns are parsed into a tree, compiled into a program, then run by a backtracking matcher with a step budget growing with the input, so that catastrophic backtracking throws a RangeError instead of hanging.
+
+mod charset;
+mod compiler;
+mod parser;
+mod vm;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use self::compiler::Program;
+use self::vm::Vm;
+use super::StringRep;
+use crate::errors::{CoreError, CoreResult};
+
+/// The number of steps a search may take before giving up, on top of [`STEPS_PER_UNIT`].
+const STEP_BUDGET: u64 = 1 << 26;
+
+/// The number of steps a search may take for each code unit of its input, so that trying every position of a long input stays within budget.
+const STEPS_PER_UNIT: u64 = 1 << 6;
+
+/// The flags of a regular expression.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct RegExpFlags {
+    /// `d`
+    pub(crate) has_indices: bool,
+    /// `g`
+    pub(crate) global: bool,
+    /// `i`
+    pub(crate) ignore_case: bool,
+    /// `m`
+    pub(crate) multiline: bool,
+    /// `s`
+    pub(crate) dot_all: bool,
+    /// `u`
+    pub(crate) unicode: bool,
+    /// `v`
+    pub(crate) unicode_sets: bool,
+    /// `y`
+    pub(crate) sticky: bool,
+}
+
+impl RegExpFlags {
+    /// Parses flags, returning [`None`] if one is unknown or repeated, or if both `u` and `v` are set.
+    pub(crate) fn parse(flags: &[u16]) -> Option<Self> {
+        let mut result = Self::default();
+        for &unit in flags {
+            let flag = match char::from_u32(unit as u32)? {
+                'd' => &mut result.has_indices,
+                'g' => &mut result.global,
+                'i' => &mut result.ignore_case,
+                'm' => &mut result.multiline,
+                's' => &mut result.dot_all,
+                'u' => &mut result.unicode,
+                'v' => &mut result.unicode_sets,
+                'y' => &mut result.sticky,
+                _ => return None,
+            };
+            if *flag {
+                return None;
+            }
+            *flag = true;
+        }
+        (!(result.unicode && result.unicode_sets)).then_some(result)
+    }
+
+    /// Returns if matching works on code points rather than code units.
+    pub(crate) fn full_unicode(&self) -> bool {
+        self.unicode || self.unicode_sets
+    }
+}
+
+/// A compiled regular expression, the value of the [[RegExpMatcher]] internal slot.
+#[derive(Debug)]
+pub(crate) struct RegExpMatcher {
+    program: Program,
+    flags: RegExpFlags,
+    group_names: Vec<Option<Vec<u16>>>,
+    /// The last subject with its code units, as global and sticky searches run once per match on the same subject.
+    subject: RefCell<Option<(StringRep, Rc<[u16]>)>>,
+}
+
+/// The captures of a match: the range of code units of the whole match, then of each capturing group.
+pub(crate) type RegExpCaptures = Vec<Option<(usize, usize)>>;
+
+impl RegExpMatcher {
+    /// Parses and compiles a pattern, throwing a SyntaxError if it is invalid.
+    pub(crate) fn new(pattern: &[u16], flags: RegExpFlags) -> CoreResult<Self> {
+        let parsed = parser::parse_pattern(pattern, &flags).map_err(|message| {
+            CoreError::SyntaxError(format!(
+                "Invalid regular expression: /{}/: {message}",
+                String::from_utf16_lossy(pattern)
+            ))
+        })?;
+        let program =
+            compiler::compile(&parsed.node, parsed.group_names.len(), flags.full_unicode());
+        Ok(Self {
+            program,
+            flags,
+            group_names: parsed.group_names,
+            subject: RefCell::new(None),
+        })
+    }
+
+    /// Returns the flags the expression was compiled with.
+    pub(crate) fn flags(&self) -> RegExpFlags {
+        self.flags
+    }
+
+    /// Returns the number of capturing groups.
+    pub(crate) fn group_count(&self) -> usize {
+        self.group_names.len()
+    }
+
+    /// Returns the name of each capturing group, from the first one.
+    pub(crate) fn group_names(&self) -> &[Option<Vec<u16>>] {
+        &self.group_names
+    }
+
+    /// Returns if some capturing group is named.
+    pub(crate) fn has_group_names(&self) -> bool {
+        self.group_names.iter().any(Option::is_some)
+    }
+
+    /// Returns the code units of `s`, reusing those of the last subject if `s` is a clone of it.
+    pub(crate) fn units(&self, s: &StringRep) -> Rc<[u16]> {
+        let mut subject = self.subject.borrow_mut();
+        match &*subject {
+            Some((last, units)) if last.ptr_eq(s) => units.clone(),
+            _ => {
+                let units: Rc<[u16]> = s.to_utf16().into();
+                *subject = Some((s.clone(), units.clone()));
+                units
+            }
+        }
+    }
+
+    /// Searches `input` from the code unit `start`, only there if the expression is sticky, following the loop of [RegExpBuiltinExec](https://tc39.es/ecma262/multipage/text-processing.html#sec-regexpbuiltinexec).
+    ///
+    /// Throws a RangeError if the search exceeds the step budget.
+    pub(crate) fn find(&self, input: &[u16], start: usize) -> CoreResult<Option<RegExpCaptures>> {
+        self.find_with_budget(input, start, self.flags.sticky, budget(input))
+    }
+
+    /// Searches `input` from the code unit `start` onwards even if the expression is sticky, finding the first position where a sticky search would match.
+    pub(crate) fn find_forward(
+        &self,
+        input: &[u16],
+        start: usize,
+    ) -> CoreResult<Option<RegExpCaptures>> {
+        self.find_with_budget(input, start, false, budget(input))
+    }
+
+    fn find_with_budget(
+        &self,
+        input: &[u16],
+        mut start: usize,
+        sticky: bool,
+        budget: u64,
+    ) -> CoreResult<Option<RegExpCaptures>> {
+        let mut vm = Vm::new(&self.program, input, budget);
+        let exceeded = |_| {
+            CoreError::RangeError("Maximum regular expression backtracking exceeded".to_string())
+        };
+        // A position within a surrogate pair denotes the code point of the pair.
+        if self.flags.full_unicode()
+            && start > 0
+            && start < input.len()
+            && (0xDC00..=0xDFFF).contains(&input[start])
+            && (0xD800..=0xDBFF).contains(&input[start - 1])
+        {
+            start -= 1;
+        }
+        while start <= input.len() {
+            if let Some(slots) = vm.run_at(start).map_err(exceeded)? {
+                return Ok(Some(
+                    slots
+                        .chunks(2)
+                        .map(|pair| Some((pair[0]?, pair[1]?)))
+                        .collect(),
+                ));
+            }
+            if sticky {
+                break;
+            }
+            start = match vm.read(start, false) {
+                Some((_, next)) => next,
+                None => break,
+            };
+        }
+        Ok(None)
+    }
+}
+
+/// Returns the step budget of a search of `input`.
+fn budget(input: &[u16]) -> u64 {
+    STEP_BUDGET.saturating_add(STEPS_PER_UNIT.saturating_mul(input.len() as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compile(pattern: &str, flags: &str) -> CoreResult<RegExpMatcher> {
+        let flags: Vec<u16> = flags.encode_utf16().collect();
+        let pattern: Vec<u16> = pattern.encode_utf16().collect();
+        RegExpMatcher::new(&pattern, RegExpFlags::parse(&flags).expect("Valid flags"))
+    }
+
+    /// Returns the captured strings of the first match of `pattern` in `input`.
+    fn exec(pattern: &str, flags: &str, input: &str) -> Option<Vec<Option<String>>> {
+        let input: Vec<u16> = input.encode_utf16().collect();
+        let captures = compile(pattern, flags)
+            .expect("Valid pattern")
+            .find(&input, 0)
+            .expect("Within budget")?;
+        Some(
+            captures
+                .into_iter()
+                .map(|range| range.map(|(start, end)| String::from_utf16_lossy(&input[start..end])))
+                .collect(),
+        )
+    }
+
+    fn matched(pattern: &str, flags: &str, input: &str) -> Option<String> {
+        exec(pattern, flags, input).and_then(|captures| captures[0].clone())
+    }
+
+    fn strings(captures: &[Option<&str>]) -> Option<Vec<Option<String>>> {
+        Some(captures.iter().map(|c| c.map(str::to_string)).collect())
+    }
+
+    #[test]
+    fn parses_flags() {
+        let parse = |flags: &str| RegExpFlags::parse(&flags.encode_utf16().collect::<Vec<_>>());
+        assert!(parse("dgimsuy").is_some_and(|flags| flags.sticky && !flags.unicode_sets));
+        assert!(parse("gg").is_none());
+        assert!(parse("uv").is_none());
+        assert!(parse("x").is_none());
+    }
+
+    #[test]
+    fn matches_basics() {
+        assert_eq!(matched("b+", "", "abbbc"), Some("bbb".to_string()));
+        assert_eq!(matched("b+?", "", "abbbc"), Some("b".to_string()));
+        assert_eq!(matched("a.c", "", "a\nc abc"), Some("abc".to_string()));
+        assert_eq!(matched("a.c", "s", "a\nc"), Some("a\nc".to_string()));
+        assert_eq!(matched("^b", "m", "a\nb"), Some("b".to_string()));
+        assert_eq!(matched("^b", "", "a\nb"), None);
+        assert_eq!(
+            matched("\\bfoo\\b", "", "afoo foo"),
+            Some("foo".to_string())
+        );
+        assert_eq!(matched("[^a-c]+", "", "abcdef"), Some("def".to_string()));
+        assert_eq!(matched("a{2,3}", "", "aaaa"), Some("aaa".to_string()));
+        assert_eq!(matched("ABC", "i", "xabc"), Some("abc".to_string()));
+        assert_eq!(matched("\\u{1F600}", "u", "x😀"), Some("😀".to_string()));
+        assert_eq!(matched("^.$", "u", "😀"), Some("😀".to_string()));
+        assert_eq!(matched("^.$", "", "😀"), None);
+        assert_eq!(
+            exec("(a)|(b)", "", "b"),
+            strings(&[Some("b"), None, Some("b")])
+        );
+        assert_eq!(
+            exec("(z)((a+)?(b+)?(c))*", "", "zaacbbbcac"),
+            strings(&[
+                Some("zaacbbbcac"),
+                Some("z"),
+                Some("ac"),
+                Some("a"),
+                None,
+                Some("c")
+            ])
+        );
+        assert_eq!(exec("(a*)*", "", "b"), strings(&[Some(""), None]));
+        assert_eq!(
+            exec("(a*)b\\1+", "", "baaaac"),
+            strings(&[Some("b"), Some("")])
+        );
+        assert_eq!(
+            matched("(.*?)a(?!(a+)b\\2c)\\2(.*)", "", "baaabaac"),
+            Some("baaabaac".to_string())
+        );
+    }
+
+    #[test]
+    fn matches_lookarounds_and_names() {
+        assert_eq!(
+            matched("(?<=\\$)\\d+", "", "a1 $42"),
+            Some("42".to_string())
+        );
+        assert_eq!(
+            matched("(?<!\\$)\\b\\d+", "", "$4 2"),
+            Some("2".to_string())
+        );
+        assert_eq!(
+            exec("(?<=(\\d+)(\\d+))$", "", "1053"),
+            strings(&[Some(""), Some("1"), Some("053")])
+        );
+        assert_eq!(
+            exec("(?<=\\1(a))b", "", "aab"),
+            strings(&[Some("b"), Some("a")])
+        );
+        assert_eq!(
+            exec("(?<year>\\d{4})-\\k<year>", "", "1999-1999"),
+            strings(&[Some("1999-1999"), Some("1999")])
+        );
+        let matcher = compile("(?<a>x)|(?<a>y)\\k<a>", "").expect("Valid pattern");
+        assert_eq!(matcher.group_count(), 2);
+        assert!(matcher.has_group_names());
+        assert_eq!(
+            matched("(?<a>x)|(?<a>y)\\k<a>", "", "yy"),
+            Some("yy".to_string())
+        );
+        assert!(compile("(?<a>x)(?<a>y)", "").is_err());
+        assert!(compile("(?<a>x)|(?:(?<a>y)|(?<a>z))", "").is_ok());
+        assert!(compile("\\k<b>(?<a>x)", "u").is_err());
+        assert_eq!(matched("(?i:a)b", "", "AB Ab"), Some("Ab".to_string()));
+    }
+
+    #[test]
+    fn matches_unicode_sets() {
+        assert_eq!(
+            matched("\\p{Script=Greek}+", "u", "abγδε"),
+            Some("γδε".to_string())
+        );
+        assert_eq!(matched("\\p{Lu}", "u", "abC"), Some("C".to_string()));
+        assert_eq!(
+            matched("[\\p{L}--[a-z]]", "v", "abC"),
+            Some("C".to_string())
+        );
+        assert_eq!(matched("[\\w&&\\d]", "v", "ab1"), Some("1".to_string()));
+        assert_eq!(
+            matched("[\\q{abc|d}x]", "v", "zabc"),
+            Some("abc".to_string())
+        );
+        assert_eq!(
+            matched("^\\p{Emoji_Keycap_Sequence}$", "v", "#\u{FE0F}\u{20E3}"),
+            Some("#\u{FE0F}\u{20E3}".to_string())
+        );
+        for emoji in [
+            "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+            "\u{1F1EB}\u{1F1F7}",
+            "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+            "\u{231A}",
+        ] {
+            assert_eq!(
+                matched("^\\p{RGI_Emoji}$", "v", emoji),
+                Some(emoji.to_string())
+            );
+        }
+        assert_eq!(
+            matched("\\p{RGI_Emoji}", "v", "x\u{1F44D}\u{1F3FD}"),
+            Some("\u{1F44D}\u{1F3FD}".to_string())
+        );
+        assert_eq!(matched("[^\\P{Ll}]", "vi", "1A"), Some("A".to_string()));
+        assert!(compile("[^\\q{ab}]", "v").is_err());
+        assert!(compile("\\P{Emoji_Keycap_Sequence}", "v").is_err());
+        assert!(compile("\\p{Emoji_Keycap_Sequence}", "u").is_err());
+        assert!(compile("[a&&&b]", "v").is_err());
+        assert!(compile("\\p{Unknown}", "u").is_err());
+        assert_eq!(matched("\\u017F", "ui", "s"), Some("s".to_string()));
+        assert_eq!(matched("\\u017F", "i", "s"), None);
+    }
+
+    #[test]
+    fn follows_the_grammar() {
+        assert!(compile("(", "").is_err());
+        assert!(compile("a**", "").is_err());
+        assert!(compile("a{2,1}", "").is_err());
+        assert!(compile("\\c", "u").is_err());
+        assert!(compile("[b-a]", "").is_err());
+        assert!(compile("(?<=a)*", "").is_err());
+        if cfg!(feature = "annex-b") {
+            assert_eq!(matched("\\1(a)", "", "a"), Some("a".to_string()));
+            assert_eq!(matched("a{", "", "a{"), Some("a{".to_string()));
+            assert_eq!(matched("\\101", "", "A"), Some("A".to_string()));
+            assert_eq!(matched("[\\d-z]+", "", "1-z"), Some("1-z".to_string()));
+            assert_eq!(matched("\\k", "", "k"), Some("k".to_string()));
+            assert!(compile("\\k(?<a>)", "").is_err());
+            assert_eq!(matched("(?=a)*a", "", "a"), Some("a".to_string()));
+        } else {
+            assert!(compile("a{", "").is_err());
+            assert!(compile("\\k", "").is_err());
+        }
+        assert!(compile("]", "u").is_err());
+        assert!(compile("\\-", "u").is_err());
+        assert!(compile(&"(".repeat(1000), "").is_err());
+    }
+
+    #[test]
+    fn reuses_the_units_of_the_last_subject() {
+        let matcher = compile("b", "y").expect("Valid pattern");
+        let s = StringRep::from("ab\u{20ac}");
+        let units = matcher.units(&s);
+        assert_eq!(*units, [0x61, 0x62, 0x20AC]);
+        assert!(Rc::ptr_eq(&units, &matcher.units(&s.clone())));
+        assert!(!Rc::ptr_eq(&units, &matcher.units(&"ab\u{20ac}".into())));
+        assert_eq!(matcher.find(&units, 0).expect("Within budget"), None);
+        assert_eq!(
+            matcher.find_forward(&units, 0).expect("Within budget"),
+            Some(vec![Some((1, 2))])
+        );
+    }
+
+    #[test]
+    fn limits_backtracking() {
+        let input: Vec<u16> = "a".repeat(40).encode_utf16().collect();
+        let matcher = compile("^(a+)+$", "").expect("Valid pattern");
+        assert!(matches!(
+            matcher.find_with_budget(&[input.as_slice(), &[0x62]].concat(), 0, false, 100_000),
+            Err(CoreError::RangeError(_))
+        ));
+        assert!(matcher
+            .find(&input, 0)
+            .is_ok_and(|captures| captures.is_some()));
+
+        // The allowance per code unit alone covers failing at every position.
+        let input = vec![0x61; 100_000];
+        let matcher = compile("(?:b|c|d|e)z", "").expect("Valid pattern");
+        let per_unit = STEPS_PER_UNIT * input.len() as u64;
+        assert!(matches!(
+            matcher.find_with_budget(&input, 0, false, per_unit),
+            Ok(None)
+        ));
+        assert_eq!(budget(&input), STEP_BUDGET + per_unit);
+    }
+}
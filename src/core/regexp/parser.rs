@@ -0,0 +1,1302 @@
+//! The parser of [patterns](https://tc39.es/ecma262/multipage/text-processing.html#sec-patterns), including the [web compatibility grammar](https://tc39.es/ecma262/multipage/additional-ecmascript-features-for-web-browsers.html#sec-regular-expressions-patterns) of Annex B outside of Unicode mode.
+
+use std::collections::BTreeSet;
+
+use super::charset::{
+    canonicalize_set, case_folded_characters, digit_characters, non_line_terminators,
+    white_space_characters, word_characters, CharSet, MAX_CODE_POINT, MAX_CODE_UNIT,
+};
+use super::RegExpFlags;
+use crate::core::unicode::{
+    p262_binary_property, p262_general_category, p262_is_id_continue, p262_is_id_start,
+    p262_script, p262_simple_case_folding, p262_string_property,
+};
+
+/// The deepest nesting of groups and classes, which bounds the recursion of the parser and the compiler.
+const MAX_NESTING: usize = 256;
+
+/// A node of a parsed pattern, with the modifiers in effect where it appears already applied.
+#[derive(Debug)]
+pub(super) enum Node {
+    /// Matches the empty string.
+    Empty,
+    /// Matches a character.
+    Char { c: u32, ignore_case: bool },
+    /// Matches a character of a set, or not of it when `invert` is set.
+    Set {
+        set: CharSet,
+        invert: bool,
+        ignore_case: bool,
+    },
+    /// Matches each node in turn.
+    Seq(Vec<Node>),
+    /// Matches the first node that leads to a match.
+    Alt(Vec<Node>),
+    /// A capturing group, numbered from 1.
+    Group { index: usize, node: Box<Node> },
+    /// A lookahead or lookbehind assertion.
+    Look {
+        behind: bool,
+        negate: bool,
+        node: Box<Node>,
+    },
+    /// A quantified atom, containing the capturing groups `groups.0..groups.1`.
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+        groups: (usize, usize),
+    },
+    /// The `^` assertion.
+    LineStart { multiline: bool },
+    /// The `$` assertion.
+    LineEnd { multiline: bool },
+    /// The `\b` assertion, or `\B` when `negate` is set.
+    WordBoundary { negate: bool, ignore_case: bool },
+    /// A backreference to the capturing groups with the given numbers, only one of which can participate in a match.
+    BackRef {
+        groups: Vec<usize>,
+        ignore_case: bool,
+    },
+}
+
+/// A parsed pattern.
+#[derive(Debug)]
+pub(super) struct Pattern {
+    /// The root of the pattern.
+    pub(super) node: Node,
+    /// The name of each capturing group, from the first one.
+    pub(super) group_names: Vec<Option<Vec<u16>>>,
+}
+
+/// The characters matched by a class of a `v` regular expression, which may include strings.
+#[derive(Clone, Debug, Default)]
+struct ClassSet {
+    chars: CharSet,
+    strings: BTreeSet<Vec<u32>>,
+    /// Implements [MayContainStrings](https://tc39.es/ecma262/multipage/text-processing.html#sec-static-semantics-maycontainstrings), which only depends on the syntax.
+    may_contain_strings: bool,
+}
+
+impl ClassSet {
+    fn from_chars(chars: CharSet) -> Self {
+        Self {
+            chars,
+            ..Default::default()
+        }
+    }
+
+    /// Creates the set of the given sequences, the ones of a single character being plain characters.
+    fn from_sequences<'a>(sequences: impl IntoIterator<Item = &'a [u32]>) -> Self {
+        let mut result = Self {
+            may_contain_strings: true,
+            ..Default::default()
+        };
+        let mut chars = Vec::new();
+        for sequence in sequences {
+            match *sequence {
+                [c] => chars.push((c, c)),
+                _ => {
+                    result.strings.insert(sequence.to_vec());
+                }
+            }
+        }
+        result.chars = CharSet::from_ranges(chars);
+        result
+    }
+
+    fn union(mut self, other: Self) -> Self {
+        self.chars = self.chars.union(&other.chars);
+        self.strings.extend(other.strings);
+        self.may_contain_strings |= other.may_contain_strings;
+        self
+    }
+
+    fn intersection(self, other: Self) -> Self {
+        Self {
+            chars: self.chars.intersection(&other.chars),
+            strings: self.strings.intersection(&other.strings).cloned().collect(),
+            may_contain_strings: self.may_contain_strings && other.may_contain_strings,
+        }
+    }
+
+    fn difference(self, other: Self) -> Self {
+        Self {
+            chars: self.chars.difference(&other.chars),
+            strings: self.strings.difference(&other.strings).cloned().collect(),
+            may_contain_strings: self.may_contain_strings,
+        }
+    }
+}
+
+/// What a class atom outside of `v` regular expressions denotes.
+enum ClassAtom {
+    Char(u32),
+    Set(CharSet),
+}
+
+type ParseResult<T> = Result<T, String>;
+
+/// The alternative taken in each enclosing disjunction, as `(disjunction, alternative)`.
+type AlternativePath = Vec<(usize, usize)>;
+
+fn is_syntax_character(c: u32) -> bool {
+    matches!(
+        char::from_u32(c),
+        Some('^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|')
+    )
+}
+
+fn is_class_set_syntax_character(c: u32) -> bool {
+    matches!(
+        char::from_u32(c),
+        Some('(' | ')' | '[' | ']' | '{' | '}' | '/' | '-' | '\\' | '|')
+    )
+}
+
+fn is_class_set_reserved_punctuator(c: u32) -> bool {
+    matches!(
+        char::from_u32(c),
+        Some('&' | '-' | '!' | '#' | '%' | ',' | ':' | ';' | '<' | '=' | '>' | '@' | '`' | '~')
+    )
+}
+
+/// Returns if `c` doubled is a ClassSetReservedDoublePunctuator.
+fn is_class_set_reserved_double_punctuator(c: u32) -> bool {
+    matches!(
+        char::from_u32(c),
+        Some(
+            '&' | '!'
+                | '#'
+                | '$'
+                | '%'
+                | '*'
+                | '+'
+                | ','
+                | '.'
+                | ':'
+                | ';'
+                | '<'
+                | '='
+                | '>'
+                | '?'
+                | '@'
+                | '^'
+                | '`'
+                | '~'
+        )
+    )
+}
+
+fn hex_value(c: u32) -> Option<u32> {
+    char::from_u32(c).and_then(|c| c.to_digit(16))
+}
+
+fn is_lead_surrogate(c: u32) -> bool {
+    (0xD800..=0xDBFF).contains(&c)
+}
+
+fn is_trail_surrogate(c: u32) -> bool {
+    (0xDC00..=0xDFFF).contains(&c)
+}
+
+fn combine_surrogates(lead: u32, trail: u32) -> u32 {
+    0x10000 + ((lead - 0xD800) << 10) + (trail - 0xDC00)
+}
+
+/// Implements [CountLeftCapturingParensWithin](https://tc39.es/ecma262/multipage/text-processing.html#sec-countleftcapturingparenswithin) for the whole pattern, also returning if any group is named.
+fn scan_groups(chars: &[u32], unicode_sets: bool) -> (usize, bool) {
+    let at = |i: usize| chars.get(i).and_then(|&c| char::from_u32(c));
+    let (mut count, mut named) = (0, false);
+    let mut class_depth = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        match at(i) {
+            Some('\\') => i += 1,
+            Some(']') if class_depth > 0 => class_depth -= 1,
+            Some('[') if class_depth == 0 || unicode_sets => class_depth += 1,
+            Some('(') if class_depth == 0 => {
+                if at(i + 1) != Some('?') {
+                    count += 1;
+                } else if at(i + 2) == Some('<') && !matches!(at(i + 3), Some('=' | '!')) {
+                    count += 1;
+                    named = true;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    (count, named)
+}
+
+/// Parses a pattern, given as UTF-16 code units.
+///
+/// Returns the message of the SyntaxError to throw if the pattern is invalid.
+pub(super) fn parse_pattern(source: &[u16], flags: &RegExpFlags) -> ParseResult<Pattern> {
+    let unicode = flags.unicode || flags.unicode_sets;
+    let chars: Vec<u32> = if unicode {
+        char::decode_utf16(source.iter().copied())
+            .map(|c| c.map_or_else(|e| e.unpaired_surrogate() as u32, |c| c as u32))
+            .collect()
+    } else {
+        source.iter().map(|&unit| unit as u32).collect()
+    };
+    let legacy = cfg!(feature = "annex-b") && !unicode;
+    let (total_groups, named) = scan_groups(&chars, flags.unicode_sets);
+    let mut parser = Parser {
+        chars,
+        pos: 0,
+        unicode,
+        unicode_sets: flags.unicode_sets,
+        named_groups: !legacy || named,
+        legacy,
+        ignore_case: flags.ignore_case,
+        multiline: flags.multiline,
+        dot_all: flags.dot_all,
+        total_groups,
+        group_names: Vec::new(),
+        named_paths: Vec::new(),
+        path: Vec::new(),
+        disjunctions: 0,
+        references: Vec::new(),
+        depth: 0,
+    };
+    let node = parser.parse_disjunction()?;
+    if parser.pos < parser.chars.len() {
+        return Err(if parser.peek_is(')') {
+            "Unmatched ')'".to_string()
+        } else {
+            "Unexpected character".to_string()
+        });
+    }
+    let node = parser.resolve_names(node)?;
+    Ok(Pattern {
+        node,
+        group_names: parser.group_names,
+    })
+}
+
+struct Parser {
+    /// The characters of the pattern: code points in Unicode mode and code units otherwise.
+    chars: Vec<u32>,
+    pos: usize,
+    /// Whether the `u` or `v` flag is set.
+    unicode: bool,
+    unicode_sets: bool,
+    /// The NamedCaptureGroups parameter of the grammar.
+    named_groups: bool,
+    /// Whether the Annex B grammar applies.
+    legacy: bool,
+    ignore_case: bool,
+    multiline: bool,
+    dot_all: bool,
+    total_groups: usize,
+    group_names: Vec<Option<Vec<u16>>>,
+    /// The named groups, with the alternatives of each enclosing disjunction they appear in.
+    named_paths: Vec<(Vec<u16>, AlternativePath)>,
+    path: AlternativePath,
+    disjunctions: usize,
+    /// The backreferences by name, resolved once all group names are known.
+    references: Vec<Vec<u16>>,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<u32> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<u32> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn peek_is(&self, c: char) -> bool {
+        self.peek() == Some(c as u32)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek_is(c);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn starts_with(&self, text: &str) -> bool {
+        text.chars()
+            .enumerate()
+            .all(|(i, c)| self.peek_at(i) == Some(c as u32))
+    }
+
+    fn eat_str(&mut self, text: &str) -> bool {
+        let found = self.starts_with(text);
+        if found {
+            self.pos += text.len();
+        }
+        found
+    }
+
+    fn max_char(&self) -> u32 {
+        if self.unicode {
+            MAX_CODE_POINT
+        } else {
+            MAX_CODE_UNIT
+        }
+    }
+
+    fn nest(&mut self) -> ParseResult<()> {
+        self.depth += 1;
+        if self.depth > MAX_NESTING {
+            return Err("Regular expression is too deeply nested".to_string());
+        }
+        Ok(())
+    }
+
+    fn parse_disjunction(&mut self) -> ParseResult<Node> {
+        self.nest()?;
+        let disjunction = self.disjunctions;
+        self.disjunctions += 1;
+        let mut alternatives = Vec::new();
+        loop {
+            self.path.push((disjunction, alternatives.len()));
+            let alternative = self.parse_alternative();
+            self.path.pop();
+            alternatives.push(alternative?);
+            if !self.eat('|') {
+                break;
+            }
+        }
+        self.depth -= 1;
+        Ok(if alternatives.len() == 1 {
+            alternatives.pop().expect("There is one alternative")
+        } else {
+            Node::Alt(alternatives)
+        })
+    }
+
+    fn parse_alternative(&mut self) -> ParseResult<Node> {
+        let mut terms = Vec::new();
+        while self.peek().is_some() && !self.peek_is('|') && !self.peek_is(')') {
+            terms.push(self.parse_term()?);
+        }
+        Ok(match terms.len() {
+            0 => Node::Empty,
+            1 => terms.pop().expect("There is one term"),
+            _ => Node::Seq(terms),
+        })
+    }
+
+    fn parse_term(&mut self) -> ParseResult<Node> {
+        let assertion = if self.eat('^') {
+            Some(Node::LineStart {
+                multiline: self.multiline,
+            })
+        } else if self.eat('$') {
+            Some(Node::LineEnd {
+                multiline: self.multiline,
+            })
+        } else if self.eat_str("\\b") || self.eat_str("\\B") {
+            Some(Node::WordBoundary {
+                negate: self.chars[self.pos - 1] == 'B' as u32,
+                ignore_case: self.ignore_case,
+            })
+        } else if self.starts_with("(?=")
+            || self.starts_with("(?!")
+            || self.starts_with("(?<=")
+            || self.starts_with("(?<!")
+        {
+            let behind = self.peek_at(2) == Some('<' as u32);
+            self.pos += if behind { 3 } else { 2 };
+            let negate = self.chars[self.pos] == '!' as u32;
+            self.pos += 1;
+            let first_group = self.group_names.len() + 1;
+            let node = self.parse_disjunction()?;
+            if !self.eat(')') {
+                return Err("Unterminated group".to_string());
+            }
+            let look = Node::Look {
+                behind,
+                negate,
+                node: Box::new(node),
+            };
+            // Lookaheads remain quantifiable in the web compatibility grammar.
+            if self.legacy && !behind {
+                return self.parse_quantifier(look, first_group);
+            }
+            Some(look)
+        } else {
+            None
+        };
+        if let Some(assertion) = assertion {
+            if self.at_quantifier() {
+                return Err("Nothing to repeat".to_string());
+            }
+            return Ok(assertion);
+        }
+        let first_group = self.group_names.len() + 1;
+        let atom = self.parse_atom()?;
+        self.parse_quantifier(atom, first_group)
+    }
+
+    /// Returns if a quantifier starts at the current position.
+    fn at_quantifier(&mut self) -> bool {
+        match self.peek().and_then(char::from_u32) {
+            Some('*' | '+' | '?') => true,
+            Some('{') => {
+                let start = self.pos;
+                let found = self.parse_braced_quantifier().is_some();
+                self.pos = start;
+                found || !self.legacy
+            }
+            _ => false,
+        }
+    }
+
+    /// Parses `{n}`, `{n,}` or `{n,m}`, leaving the position unchanged if there is none.
+    fn parse_braced_quantifier(&mut self) -> Option<(u64, Option<u64>)> {
+        let start = self.pos;
+        self.pos += 1;
+        let result = (|| {
+            let min = self.parse_decimal()?;
+            let max = if self.eat(',') {
+                if self.peek_is('}') {
+                    None
+                } else {
+                    Some(self.parse_decimal()?)
+                }
+            } else {
+                Some(min)
+            };
+            self.eat('}').then_some((min, max))
+        })();
+        if result.is_none() {
+            self.pos = start;
+        }
+        result
+    }
+
+    /// Parses DecimalDigits, saturating instead of overflowing.
+    fn parse_decimal(&mut self) -> Option<u64> {
+        let start = self.pos;
+        let mut value: u64 = 0;
+        while let Some(digit) = self
+            .peek()
+            .and_then(char::from_u32)
+            .and_then(|c| c.to_digit(10))
+        {
+            value = value.saturating_mul(10).saturating_add(digit as u64);
+            self.pos += 1;
+        }
+        (self.pos > start).then_some(value)
+    }
+
+    fn parse_quantifier(&mut self, atom: Node, first_group: usize) -> ParseResult<Node> {
+        let (min, max) = match self.peek().and_then(char::from_u32) {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => match self.parse_braced_quantifier() {
+                Some((min, max)) => {
+                    if max.is_some_and(|max| max < min) {
+                        return Err("numbers out of order in {} quantifier".to_string());
+                    }
+                    // The counters of the matcher are 32-bit: larger bounds cannot be reached anyway.
+                    let clamp = |n: u64| n.min(u32::MAX as u64) as u32;
+                    self.pos -= 1;
+                    (clamp(min), max.map(clamp))
+                }
+                None if self.legacy => return Ok(atom),
+                None => return Err("Incomplete quantifier".to_string()),
+            },
+            _ => return Ok(atom),
+        };
+        self.pos += 1;
+        let greedy = !self.eat('?');
+        Ok(Node::Repeat {
+            node: Box::new(atom),
+            min,
+            max,
+            greedy,
+            groups: (first_group, self.group_names.len() + 1),
+        })
+    }
+
+    fn parse_atom(&mut self) -> ParseResult<Node> {
+        let c = self.peek().expect("Atoms are only parsed before the end");
+        match char::from_u32(c) {
+            Some('.') => {
+                self.pos += 1;
+                let set = if self.dot_all {
+                    CharSet::range(0, self.max_char())
+                } else {
+                    non_line_terminators(self.max_char())
+                };
+                Ok(Node::Set {
+                    set,
+                    invert: false,
+                    ignore_case: false,
+                })
+            }
+            Some('(') => self.parse_group(),
+            Some('[') => {
+                self.pos += 1;
+                self.parse_class()
+            }
+            Some('\\') => {
+                self.pos += 1;
+                self.parse_atom_escape()
+            }
+            Some('*' | '+' | '?') => Err("Nothing to repeat".to_string()),
+            Some('{') if self.at_quantifier() => Err(if self.legacy {
+                "Nothing to repeat".to_string()
+            } else {
+                "Lone quantifier brackets".to_string()
+            }),
+            Some(']' | '}') if !self.legacy => Err("Lone quantifier brackets".to_string()),
+            _ => {
+                self.pos += 1;
+                Ok(self.char_node(c))
+            }
+        }
+    }
+
+    fn char_node(&self, c: u32) -> Node {
+        Node::Char {
+            c,
+            ignore_case: self.ignore_case,
+        }
+    }
+
+    fn set_node(&self, set: CharSet, invert: bool) -> Node {
+        Node::Set {
+            set,
+            invert,
+            ignore_case: self.ignore_case,
+        }
+    }
+
+    fn parse_group(&mut self) -> ParseResult<Node> {
+        self.pos += 1;
+        let saved_flags = (self.ignore_case, self.multiline, self.dot_all);
+        let index = if self.eat_str("?<") {
+            let name = self.parse_group_name()?;
+            let index = self.group_names.len() + 1;
+            self.add_group_name(name)?;
+            Some(index)
+        } else if self.eat('?') {
+            self.parse_modifiers()?;
+            None
+        } else {
+            self.group_names.push(None);
+            Some(self.group_names.len())
+        };
+        let node = self.parse_disjunction()?;
+        (self.ignore_case, self.multiline, self.dot_all) = saved_flags;
+        if !self.eat(')') {
+            return Err("Unterminated group".to_string());
+        }
+        Ok(match index {
+            Some(index) => Node::Group {
+                index,
+                node: Box::new(node),
+            },
+            None => node,
+        })
+    }
+
+    /// Parses the modifiers of a non-capturing group up to the colon, e.g. `i-m:`, and applies them.
+    fn parse_modifiers(&mut self) -> ParseResult<()> {
+        let mut seen = Vec::new();
+        let mut remove = false;
+        let mut removed_any = false;
+        loop {
+            let Some(c) = self.peek().and_then(char::from_u32) else {
+                return Err("Invalid group".to_string());
+            };
+            self.pos += 1;
+            match c {
+                ':' => break,
+                '-' if !remove => remove = true,
+                'i' | 'm' | 's' if !seen.contains(&c) => {
+                    seen.push(c);
+                    removed_any |= remove;
+                    let flag = match c {
+                        'i' => &mut self.ignore_case,
+                        'm' => &mut self.multiline,
+                        _ => &mut self.dot_all,
+                    };
+                    *flag = !remove;
+                }
+                _ => return Err("Invalid group".to_string()),
+            }
+        }
+        if remove && !removed_any && seen.is_empty() {
+            return Err("Invalid group".to_string());
+        }
+        Ok(())
+    }
+
+    /// Records the name of a new capturing group, which may only be shared with groups that can never participate in the same match.
+    fn add_group_name(&mut self, name: Vec<u16>) -> ParseResult<()> {
+        for (other, path) in &self.named_paths {
+            let exclusive = path.iter().any(|&(disjunction, alternative)| {
+                self.path
+                    .iter()
+                    .any(|&(d, a)| d == disjunction && a != alternative)
+            });
+            if *other == name && !exclusive {
+                return Err("Duplicate capture group name".to_string());
+            }
+        }
+        self.named_paths.push((name.clone(), self.path.clone()));
+        self.group_names.push(Some(name));
+        Ok(())
+    }
+
+    /// Parses a RegExpIdentifierName followed by `>`, returning its UTF-16 code units.
+    fn parse_group_name(&mut self) -> ParseResult<Vec<u16>> {
+        let mut name = Vec::new();
+        loop {
+            let Some(mut c) = self.peek() else {
+                return Err("Invalid capture group name".to_string());
+            };
+            self.pos += 1;
+            if c == '>' as u32 && !name.is_empty() {
+                break;
+            }
+            if c == '\\' as u32 {
+                if !self.eat('u') {
+                    return Err("Invalid capture group name".to_string());
+                }
+                c = self
+                    .parse_unicode_escape(true)
+                    .ok_or("Invalid Unicode escape")?;
+            } else if is_lead_surrogate(c) && self.peek().is_some_and(is_trail_surrogate) {
+                c = combine_surrogates(c, self.peek().expect("A trail surrogate follows"));
+                self.pos += 1;
+            }
+            let valid = if name.is_empty() {
+                c == '$' as u32 || c == '_' as u32 || p262_is_id_start(c)
+            } else {
+                c == '$' as u32 || c == 0x200C || c == 0x200D || p262_is_id_continue(c)
+            };
+            if !valid {
+                return Err("Invalid capture group name".to_string());
+            }
+            let mut units = [0; 2];
+            name.extend_from_slice(
+                char::from_u32(c)
+                    .ok_or("Invalid capture group name")?
+                    .encode_utf16(&mut units),
+            );
+        }
+        Ok(name)
+    }
+
+    /// Parses the RegExpUnicodeEscapeSequence following `\u`, which may be a surrogate pair or use braces in Unicode mode.
+    fn parse_unicode_escape(&mut self, unicode: bool) -> Option<u32> {
+        let start = self.pos;
+        let result = (|| {
+            if unicode && self.eat('{') {
+                let mut value: u32 = 0;
+                let digits_start = self.pos;
+                while let Some(digit) = self.peek().and_then(hex_value) {
+                    value = value.checked_mul(16)? + digit;
+                    if value > MAX_CODE_POINT {
+                        return None;
+                    }
+                    self.pos += 1;
+                }
+                return (self.pos > digits_start && self.eat('}')).then_some(value);
+            }
+            let value = self.parse_hex_digits(4)?;
+            if unicode && is_lead_surrogate(value) && self.starts_with("\\u") {
+                let lead_end = self.pos;
+                self.pos += 2;
+                match self.parse_hex_digits(4) {
+                    Some(trail) if is_trail_surrogate(trail) => {
+                        return Some(combine_surrogates(value, trail));
+                    }
+                    _ => self.pos = lead_end,
+                }
+            }
+            Some(value)
+        })();
+        if result.is_none() {
+            self.pos = start;
+        }
+        result
+    }
+
+    fn parse_hex_digits(&mut self, count: usize) -> Option<u32> {
+        let mut value = 0;
+        for i in 0..count {
+            value = value * 16 + self.peek_at(i).and_then(hex_value)?;
+        }
+        self.pos += count;
+        Some(value)
+    }
+
+    /// Parses a LegacyOctalEscapeSequence.
+    fn parse_legacy_octal(&mut self) -> u32 {
+        let octal = |c: Option<u32>| c.and_then(char::from_u32).and_then(|c| c.to_digit(8));
+        let first = octal(self.peek()).expect("Octal escapes start with an octal digit");
+        self.pos += 1;
+        let mut value = first;
+        if let Some(second) = octal(self.peek()) {
+            value = value * 8 + second;
+            self.pos += 1;
+            if first <= 3 {
+                if let Some(third) = octal(self.peek()) {
+                    value = value * 8 + third;
+                    self.pos += 1;
+                }
+            }
+        }
+        value
+    }
+
+    /// Parses the AtomEscape following a backslash.
+    fn parse_atom_escape(&mut self) -> ParseResult<Node> {
+        let Some(c) = self.peek() else {
+            return Err("\\ at end of pattern".to_string());
+        };
+        match char::from_u32(c) {
+            Some('1'..='9') => {
+                let start = self.pos;
+                let index = self.parse_decimal().expect("A digit was just matched");
+                if index <= self.total_groups as u64 {
+                    return Ok(Node::BackRef {
+                        groups: vec![index as usize],
+                        ignore_case: self.ignore_case,
+                    });
+                }
+                if !self.legacy {
+                    return Err("Invalid escape".to_string());
+                }
+                self.pos = start;
+            }
+            Some('k') if self.named_groups => {
+                self.pos += 1;
+                if !self.eat('<') {
+                    return Err("Invalid named reference".to_string());
+                }
+                let name = self.parse_group_name()?;
+                self.references.push(name);
+                return Ok(Node::BackRef {
+                    groups: vec![usize::MAX - (self.references.len() - 1)],
+                    ignore_case: self.ignore_case,
+                });
+            }
+            Some('d' | 'D' | 's' | 'S' | 'w' | 'W') => {
+                self.pos += 1;
+                let set = self.parse_class_escape(c)?;
+                return Ok(self.class_node(set, false));
+            }
+            Some('p' | 'P') if self.unicode => {
+                self.pos += 1;
+                let set = self.parse_property_escape(c == 'P' as u32)?;
+                return Ok(self.class_node(set, false));
+            }
+            _ => {}
+        }
+        let c = self.parse_character_escape(false)?;
+        Ok(self.char_node(c))
+    }
+
+    /// Parses a CharacterEscape, or an IdentityEscape, following a backslash.
+    fn parse_character_escape(&mut self, in_class: bool) -> ParseResult<u32> {
+        let Some(c) = self.peek() else {
+            return Err("\\ at end of pattern".to_string());
+        };
+        self.pos += 1;
+        let is_digit = |c: Option<u32>| {
+            c.and_then(char::from_u32)
+                .is_some_and(|c| c.is_ascii_digit())
+        };
+        match char::from_u32(c) {
+            Some('f') => Ok(0xC),
+            Some('n') => Ok(0xA),
+            Some('r') => Ok(0xD),
+            Some('t') => Ok(0x9),
+            Some('v') => Ok(0xB),
+            Some('c') => match self.peek().and_then(char::from_u32) {
+                Some(letter) if letter.is_ascii_alphabetic() => {
+                    self.pos += 1;
+                    Ok(letter as u32 % 32)
+                }
+                Some(letter)
+                    if self.legacy && in_class && (letter.is_ascii_digit() || letter == '_') =>
+                {
+                    self.pos += 1;
+                    Ok(letter as u32 % 32)
+                }
+                // The backslash matches itself, and the `c` is parsed again as a pattern character.
+                _ if self.legacy => {
+                    self.pos -= 1;
+                    Ok('\\' as u32)
+                }
+                _ => Err("Invalid unicode escape".to_string()),
+            },
+            Some('0') if !is_digit(self.peek()) => Ok(0),
+            Some('0'..='7') if self.legacy => {
+                self.pos -= 1;
+                Ok(self.parse_legacy_octal())
+            }
+            Some('x') => match self.parse_hex_digits(2) {
+                Some(value) => Ok(value),
+                None if self.legacy => Ok(c),
+                None => Err("Invalid escape".to_string()),
+            },
+            Some('u') => match self.parse_unicode_escape(self.unicode) {
+                Some(value) => Ok(value),
+                None if self.legacy => Ok(c),
+                None => Err("Invalid Unicode escape".to_string()),
+            },
+            _ if self.unicode => {
+                if is_syntax_character(c) || c == '/' as u32 {
+                    Ok(c)
+                } else {
+                    Err("Invalid escape".to_string())
+                }
+            }
+            Some('k') if self.named_groups => Err("Invalid named reference".to_string()),
+            _ if self.legacy => Ok(c),
+            _ if !p262_is_id_continue(c) => Ok(c),
+            _ => Err("Invalid escape".to_string()),
+        }
+    }
+
+    /// Returns the set of a `\d`, `\s` or `\w` escape, or of their complements.
+    fn parse_class_escape(&mut self, c: u32) -> ParseResult<ClassSet> {
+        let set = match char::from_u32(c).expect("Class escapes are ASCII") {
+            'd' | 'D' => digit_characters(),
+            's' | 'S' => white_space_characters(),
+            _ => self.maybe_fold(word_characters(self.unicode, self.ignore_case)),
+        };
+        let set = if char::from_u32(c).is_some_and(|c| c.is_ascii_uppercase()) {
+            self.character_complement(&set)
+        } else {
+            set
+        };
+        Ok(ClassSet::from_chars(set))
+    }
+
+    /// Parses the body of a `\p{…}` or `\P{…}` escape.
+    fn parse_property_escape(&mut self, negate: bool) -> ParseResult<ClassSet> {
+        if !self.eat('{') {
+            return Err("Invalid property name".to_string());
+        }
+        let start = self.pos;
+        while self
+            .peek()
+            .and_then(char::from_u32)
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_' || c == '=')
+        {
+            self.pos += 1;
+        }
+        let text: String = self.chars[start..self.pos]
+            .iter()
+            .filter_map(|&c| char::from_u32(c))
+            .collect();
+        if !self.eat('}') {
+            return Err("Invalid property name".to_string());
+        }
+        let ranges = match text.split_once('=') {
+            Some((name, value)) => match name {
+                "General_Category" | "gc" => p262_general_category(value),
+                "Script" | "sc" => p262_script(value, false),
+                "Script_Extensions" | "scx" => p262_script(value, true),
+                _ => None,
+            },
+            None => p262_general_category(&text)
+                .or_else(|| p262_binary_property(&text).map(<[_]>::to_vec)),
+        };
+        if let Some(ranges) = ranges {
+            let set = self.maybe_fold(CharSet::from_ranges(ranges));
+            return Ok(ClassSet::from_chars(if negate {
+                self.character_complement(&set)
+            } else {
+                set
+            }));
+        }
+        match p262_string_property(&text) {
+            Some(sequences) if self.unicode_sets && !negate => {
+                Ok(self.maybe_fold_strings(ClassSet::from_sequences(sequences)))
+            }
+            _ => Err("Invalid property name".to_string()),
+        }
+    }
+
+    /// Implements [MaybeSimpleCaseFolding](https://tc39.es/ecma262/multipage/text-processing.html#sec-maybesimplecasefolding) for characters.
+    fn maybe_fold(&self, set: CharSet) -> CharSet {
+        if self.unicode_sets && self.ignore_case {
+            canonicalize_set(&set, true)
+        } else {
+            set
+        }
+    }
+
+    /// Implements [MaybeSimpleCaseFolding](https://tc39.es/ecma262/multipage/text-processing.html#sec-maybesimplecasefolding) for a set that may hold strings.
+    fn maybe_fold_strings(&self, set: ClassSet) -> ClassSet {
+        if !(self.unicode_sets && self.ignore_case) {
+            return set;
+        }
+        let strings = set.strings.iter().map(|string| {
+            string
+                .iter()
+                .map(|&c| p262_simple_case_folding(c))
+                .collect::<Vec<_>>()
+        });
+        let folded =
+            ClassSet::from_sequences(strings.collect::<Vec<_>>().iter().map(Vec::as_slice));
+        ClassSet {
+            chars: self.maybe_fold(set.chars).union(&folded.chars),
+            strings: folded.strings,
+            may_contain_strings: set.may_contain_strings,
+        }
+    }
+
+    /// Implements [CharacterComplement](https://tc39.es/ecma262/multipage/text-processing.html#sec-charactercomplement).
+    fn character_complement(&self, set: &CharSet) -> CharSet {
+        if self.unicode_sets && self.ignore_case {
+            case_folded_characters().difference(set)
+        } else {
+            set.complement(self.max_char())
+        }
+    }
+
+    /// Returns the node matching a class, which alternates between its strings, longest first, and its characters.
+    fn class_node(&self, set: ClassSet, invert: bool) -> Node {
+        if set.strings.is_empty() {
+            return self.set_node(set.chars, invert);
+        }
+        let mut strings: Vec<&Vec<u32>> = set.strings.iter().collect();
+        strings.sort_by_key(|string| std::cmp::Reverse(string.len()));
+        let mut alternatives: Vec<Node> = strings
+            .iter()
+            .filter(|string| !string.is_empty())
+            .map(|string| Node::Seq(string.iter().map(|&c| self.char_node(c)).collect()))
+            .collect();
+        alternatives.push(self.set_node(set.chars, false));
+        if set.strings.contains(&Vec::new()) {
+            alternatives.push(Node::Empty);
+        }
+        Node::Alt(alternatives)
+    }
+
+    /// Parses a CharacterClass following its opening bracket.
+    fn parse_class(&mut self) -> ParseResult<Node> {
+        self.nest()?;
+        let invert = self.eat('^');
+        let node = if self.unicode_sets {
+            let set = self.parse_class_set_expression()?;
+            if !invert {
+                self.class_node(set, false)
+            } else if set.may_contain_strings {
+                return Err("Negated character class may contain strings".to_string());
+            } else {
+                self.set_node(self.character_complement(&set.chars), false)
+            }
+        } else {
+            let set = self.parse_class_ranges()?;
+            self.set_node(set, invert)
+        };
+        self.depth -= 1;
+        Ok(node)
+    }
+
+    /// Parses the NonemptyClassRanges of a class outside of `v` regular expressions, and the closing bracket.
+    fn parse_class_ranges(&mut self) -> ParseResult<CharSet> {
+        let mut set = CharSet::default();
+        loop {
+            match self.peek() {
+                None => return Err("Unterminated character class".to_string()),
+                Some(c) if c == ']' as u32 => {
+                    self.pos += 1;
+                    return Ok(set);
+                }
+                _ => {}
+            }
+            let first = self.parse_class_atom()?;
+            if !self.peek_is('-') || matches!(self.peek_at(1), None | Some(0x5D)) {
+                set = match first {
+                    ClassAtom::Char(c) => set.union(&CharSet::single(c)),
+                    ClassAtom::Set(atom) => set.union(&atom),
+                };
+                continue;
+            }
+            self.pos += 1;
+            let last = self.parse_class_atom()?;
+            set = match (first, last) {
+                (ClassAtom::Char(first), ClassAtom::Char(last)) => {
+                    if first > last {
+                        return Err("Range out of order in character class".to_string());
+                    }
+                    set.union(&CharSet::range(first, last))
+                }
+                // The web compatibility grammar matches both atoms and the dash.
+                (first, last) if self.legacy => [first, last, ClassAtom::Char('-' as u32)]
+                    .into_iter()
+                    .fold(set, |set, atom| match atom {
+                        ClassAtom::Char(c) => set.union(&CharSet::single(c)),
+                        ClassAtom::Set(atom) => set.union(&atom),
+                    }),
+                _ => return Err("Invalid character class".to_string()),
+            };
+        }
+    }
+
+    fn parse_class_atom(&mut self) -> ParseResult<ClassAtom> {
+        let c = self
+            .peek()
+            .expect("Class atoms are only parsed before the end");
+        self.pos += 1;
+        if c != '\\' as u32 {
+            return Ok(ClassAtom::Char(c));
+        }
+        let Some(c) = self.peek() else {
+            return Err("\\ at end of pattern".to_string());
+        };
+        match char::from_u32(c) {
+            Some('b') => {
+                self.pos += 1;
+                Ok(ClassAtom::Char(0x8))
+            }
+            Some('-') if self.unicode => {
+                self.pos += 1;
+                Ok(ClassAtom::Char(c))
+            }
+            Some('d' | 'D' | 's' | 'S' | 'w' | 'W') => {
+                self.pos += 1;
+                Ok(ClassAtom::Set(self.parse_class_escape(c)?.chars))
+            }
+            Some('p' | 'P') if self.unicode => {
+                self.pos += 1;
+                Ok(ClassAtom::Set(
+                    self.parse_property_escape(c == 'P' as u32)?.chars,
+                ))
+            }
+            Some('1'..='9') if !self.legacy => Err("Invalid class escape".to_string()),
+            _ => Ok(ClassAtom::Char(self.parse_character_escape(true)?)),
+        }
+    }
+
+    /// Parses the ClassSetExpression of a `v` class, and the closing bracket.
+    fn parse_class_set_expression(&mut self) -> ParseResult<ClassSet> {
+        if self.eat(']') {
+            return Ok(ClassSet::default());
+        }
+        let (first, first_char) = self.parse_class_set_operand()?;
+        for (operator, intersect) in [("&&", true), ("--", false)] {
+            if !self.starts_with(operator) {
+                continue;
+            }
+            let mut result = first;
+            while self.eat_str(operator) {
+                if intersect && self.peek_is('&') {
+                    return Err("Invalid set operation in character class".to_string());
+                }
+                let (operand, _) = self.parse_class_set_operand()?;
+                result = if intersect {
+                    result.intersection(operand)
+                } else {
+                    result.difference(operand)
+                };
+            }
+            if !self.eat(']') {
+                return Err("Invalid set operation in character class".to_string());
+            }
+            return Ok(result);
+        }
+        let mut result = ClassSet::default();
+        let (mut operand, mut single) = (first, first_char);
+        loop {
+            match single {
+                Some(first) if self.peek_is('-') && !self.starts_with("--") => {
+                    self.pos += 1;
+                    let Some(last) = self.parse_class_set_operand()?.1 else {
+                        return Err("Invalid character class".to_string());
+                    };
+                    if first > last {
+                        return Err("Range out of order in character class".to_string());
+                    }
+                    let range = self.maybe_fold(CharSet::range(first, last));
+                    result = result.union(ClassSet::from_chars(range));
+                }
+                _ => result = result.union(operand),
+            }
+            if self.eat(']') {
+                return Ok(result);
+            }
+            if self.starts_with("&&") || self.starts_with("--") {
+                return Err("Invalid set operation in character class".to_string());
+            }
+            (operand, single) = self.parse_class_set_operand()?;
+        }
+    }
+
+    /// Parses a ClassSetOperand, also returning its character when it is a single ClassSetCharacter that may start a range.
+    fn parse_class_set_operand(&mut self) -> ParseResult<(ClassSet, Option<u32>)> {
+        let Some(c) = self.peek() else {
+            return Err("Unterminated character class".to_string());
+        };
+        if c == '[' as u32 {
+            self.pos += 1;
+            return Ok((self.parse_nested_class()?, None));
+        }
+        if c == '\\' as u32 {
+            match self.peek_at(1).and_then(char::from_u32) {
+                Some('q') if self.peek_at(2) == Some('{' as u32) => {
+                    self.pos += 3;
+                    return Ok((self.parse_class_string_disjunction()?, None));
+                }
+                Some(escape @ ('d' | 'D' | 's' | 'S' | 'w' | 'W')) => {
+                    self.pos += 2;
+                    return Ok((self.parse_class_escape(escape as u32)?, None));
+                }
+                Some(escape @ ('p' | 'P')) => {
+                    self.pos += 2;
+                    return Ok((self.parse_property_escape(escape == 'P')?, None));
+                }
+                _ => {}
+            }
+        }
+        let c = self.parse_class_set_character()?;
+        let set = ClassSet::from_chars(self.maybe_fold(CharSet::single(c)));
+        Ok((set, Some(c)))
+    }
+
+    fn parse_nested_class(&mut self) -> ParseResult<ClassSet> {
+        self.nest()?;
+        let invert = self.eat('^');
+        let set = self.parse_class_set_expression()?;
+        self.depth -= 1;
+        if !invert {
+            return Ok(set);
+        }
+        if set.may_contain_strings {
+            return Err("Negated character class may contain strings".to_string());
+        }
+        Ok(ClassSet::from_chars(self.character_complement(&set.chars)))
+    }
+
+    /// Parses the ClassStringDisjunctionContents of a `\q{…}` escape, and the closing brace.
+    fn parse_class_string_disjunction(&mut self) -> ParseResult<ClassSet> {
+        let mut strings = vec![Vec::new()];
+        loop {
+            match self.peek().and_then(char::from_u32) {
+                None => return Err("Unterminated character class".to_string()),
+                Some('}') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some('|') => {
+                    self.pos += 1;
+                    strings.push(Vec::new());
+                }
+                _ => {
+                    let c = self.parse_class_set_character()?;
+                    strings.last_mut().expect("There is a string").push(c);
+                }
+            }
+        }
+        let mut set = ClassSet::from_sequences(strings.iter().map(Vec::as_slice));
+        set.may_contain_strings = strings.iter().any(|string| string.len() != 1);
+        Ok(self.maybe_fold_strings(set))
+    }
+
+    fn parse_class_set_character(&mut self) -> ParseResult<u32> {
+        let Some(c) = self.peek() else {
+            return Err("Unterminated character class".to_string());
+        };
+        if c == '\\' as u32 {
+            self.pos += 1;
+            return match self.peek() {
+                Some(escaped) if is_class_set_reserved_punctuator(escaped) => {
+                    self.pos += 1;
+                    Ok(escaped)
+                }
+                Some(escaped) if escaped == 'b' as u32 => {
+                    self.pos += 1;
+                    Ok(0x8)
+                }
+                _ => self.parse_character_escape(true),
+            };
+        }
+        if is_class_set_syntax_character(c)
+            || (is_class_set_reserved_double_punctuator(c) && self.peek_at(1) == Some(c))
+        {
+            return Err("Invalid character in character class".to_string());
+        }
+        self.pos += 1;
+        Ok(c)
+    }
+
+    /// Replaces the backreferences by name with references to the groups of that name.
+    fn resolve_names(&self, node: Node) -> ParseResult<Node> {
+        Ok(match node {
+            Node::BackRef {
+                groups,
+                ignore_case,
+            } if groups[0] > self.total_groups => {
+                let name = &self.references[usize::MAX - groups[0]];
+                let groups: Vec<usize> = self
+                    .group_names
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, group)| group.as_ref() == Some(name))
+                    .map(|(index, _)| index + 1)
+                    .collect();
+                if groups.is_empty() {
+                    return Err("Invalid named capture referenced".to_string());
+                }
+                Node::BackRef {
+                    groups,
+                    ignore_case,
+                }
+            }
+            Node::Seq(nodes) => Node::Seq(
+                nodes
+                    .into_iter()
+                    .map(|node| self.resolve_names(node))
+                    .collect::<ParseResult<_>>()?,
+            ),
+            Node::Alt(nodes) => Node::Alt(
+                nodes
+                    .into_iter()
+                    .map(|node| self.resolve_names(node))
+                    .collect::<ParseResult<_>>()?,
+            ),
+            Node::Group { index, node } => Node::Group {
+                index,
+                node: Box::new(self.resolve_names(*node)?),
+            },
+            Node::Look {
+                behind,
+                negate,
+                node,
+            } => Node::Look {
+                behind,
+                negate,
+                node: Box::new(self.resolve_names(*node)?),
+            },
+            Node::Repeat {
+                node,
+                min,
+                max,
+                greedy,
+                groups,
+            } => Node::Repeat {
+                node: Box::new(self.resolve_names(*node)?),
+                min,
+                max,
+                greedy,
+                groups,
+            },
+            node => node,
+        })
+    }
+}
@@ -0,0 +1,452 @@
+//! The backtracking matcher running compiled programs.
+//!
+//! Choices and the previous values of the captures and registers are recorded on a trail, which is unwound on failure. Lookarounds are atomic, so they run on a trail of their own.
+
+use super::charset::{canonicalize, is_line_terminator};
+use super::compiler::{CharMatcher, Inst, Program};
+
+/// The error of a match that took more steps than its budget.
+#[derive(Debug)]
+pub(super) struct BudgetExceeded;
+
+/// An entry of the trail.
+enum Frame {
+    /// Resumes at `pc` and `pos`.
+    Choice { pc: usize, pos: usize },
+    /// Restores a capture slot.
+    Slot { slot: usize, old: Option<usize> },
+    /// Restores a register.
+    Register { register: usize, old: usize },
+    /// Gives back the last character consumed by the greedy [`Star`](Inst::Star) at `star`, which cannot go back further than `min_pos`.
+    Greedy {
+        star: usize,
+        min_pos: usize,
+        pos: usize,
+    },
+    /// Consumes one more character for the lazy [`Star`](Inst::Star) at `star`, which consumed `count` ones.
+    Lazy { star: usize, pos: usize, count: u32 },
+}
+
+/// The matcher of a program against an input.
+pub(super) struct Vm<'a> {
+    program: &'a Program,
+    input: &'a [u16],
+    steps: u64,
+    budget: u64,
+}
+
+impl<'a> Vm<'a> {
+    /// Creates a matcher allowed to run `budget` steps across all its matches.
+    pub(super) fn new(program: &'a Program, input: &'a [u16], budget: u64) -> Self {
+        Self {
+            program,
+            input,
+            steps: 0,
+            budget,
+        }
+    }
+
+    fn tick(&mut self) -> Result<(), BudgetExceeded> {
+        self.steps += 1;
+        if self.steps > self.budget {
+            Err(BudgetExceeded)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the character at `pos`, or before it when `backward` is set, with the position past it.
+    pub(super) fn read(&self, pos: usize, backward: bool) -> Option<(u32, usize)> {
+        let input = self.input;
+        let is_lead = |unit: u16| (0xD800..=0xDBFF).contains(&unit);
+        let is_trail = |unit: u16| (0xDC00..=0xDFFF).contains(&unit);
+        if backward {
+            let unit = *input.get(pos.checked_sub(1)?)?;
+            if self.program.unicode && is_trail(unit) && pos >= 2 && is_lead(input[pos - 2]) {
+                let c = char::decode_utf16([input[pos - 2], unit])
+                    .next()
+                    .and_then(Result::ok)
+                    .expect("The units form a surrogate pair");
+                return Some((c as u32, pos - 2));
+            }
+            Some((unit as u32, pos - 1))
+        } else {
+            let unit = *input.get(pos)?;
+            if self.program.unicode
+                && is_lead(unit)
+                && input.get(pos + 1).is_some_and(|&u| is_trail(u))
+            {
+                let c = char::decode_utf16([unit, input[pos + 1]])
+                    .next()
+                    .and_then(Result::ok)
+                    .expect("The units form a surrogate pair");
+                return Some((c as u32, pos + 2));
+            }
+            Some((unit as u32, pos + 1))
+        }
+    }
+
+    fn matches(&self, matcher: &CharMatcher, c: u32) -> bool {
+        match *matcher {
+            CharMatcher::Char(expected) => c == expected,
+            CharMatcher::Fold(expected) => canonicalize(c, self.program.unicode) == expected,
+            CharMatcher::Set { set, invert, fold } => {
+                let c = if fold {
+                    canonicalize(c, self.program.unicode)
+                } else {
+                    c
+                };
+                self.program.sets[set].contains(c) != invert
+            }
+        }
+    }
+
+    /// Consumes a character matching `matcher`, returning the position past it.
+    fn consume(&self, matcher: &CharMatcher, pos: usize, backward: bool) -> Option<usize> {
+        self.read(pos, backward)
+            .filter(|&(c, _)| self.matches(matcher, c))
+            .map(|(_, next)| next)
+    }
+
+    /// Returns the characters of some input units.
+    fn chars(&self, units: &[u16]) -> Vec<u32> {
+        if !self.program.unicode {
+            return units.iter().map(|&unit| unit as u32).collect();
+        }
+        char::decode_utf16(units.iter().copied())
+            .map(|c| c.map_or_else(|e| e.unpaired_surrogate() as u32, |c| c as u32))
+            .collect()
+    }
+
+    /// Matches the text captured by the first participating group of `groups`, returning the position past it.
+    fn backreference(
+        &self,
+        groups: &[usize],
+        fold: bool,
+        backward: bool,
+        pos: usize,
+        slots: &[Option<usize>],
+    ) -> Option<usize> {
+        let Some((start, end)) = groups
+            .iter()
+            .find_map(|&group| Some((slots[2 * group]?, slots[2 * group + 1]?)))
+        else {
+            return Some(pos);
+        };
+        let len = end - start;
+        let (from, to) = if backward {
+            (pos.checked_sub(len)?, pos)
+        } else {
+            (pos, pos + len)
+        };
+        if to > self.input.len() {
+            return None;
+        }
+        let captured = &self.input[start..end];
+        let candidate = &self.input[from..to];
+        let equal = if fold {
+            let canonicalize = |c| canonicalize(c, self.program.unicode);
+            let (captured, candidate) = (self.chars(captured), self.chars(candidate));
+            captured.len() == candidate.len()
+                && captured
+                    .iter()
+                    .zip(&candidate)
+                    .all(|(&a, &b)| canonicalize(a) == canonicalize(b))
+        } else {
+            captured == candidate
+        };
+        equal.then_some(if backward { from } else { to })
+    }
+
+    fn is_word_char(&self, pos: Option<usize>, set: usize) -> bool {
+        pos.and_then(|pos| self.input.get(pos))
+            .is_some_and(|&unit| self.program.sets[set].contains(unit as u32))
+    }
+
+    /// Matches the program from `start`, returning the capture slots of the match.
+    pub(super) fn run_at(
+        &mut self,
+        start: usize,
+    ) -> Result<Option<Vec<Option<usize>>>, BudgetExceeded> {
+        let mut slots = vec![None; self.program.slots];
+        let mut registers = vec![0; self.program.registers];
+        Ok(self
+            .run(0, start, &mut slots, &mut registers)?
+            .then_some(slots))
+    }
+
+    /// Runs the program from `pc` at `pos` until a [`Match`](Inst::Match) or the exhaustion of all choices.
+    fn run(
+        &mut self,
+        mut pc: usize,
+        mut pos: usize,
+        slots: &mut Vec<Option<usize>>,
+        registers: &mut [usize],
+    ) -> Result<bool, BudgetExceeded> {
+        let program = self.program;
+        let mut trail: Vec<Frame> = Vec::new();
+        loop {
+            self.tick()?;
+            let success = match &program.insts[pc] {
+                Inst::Match => return Ok(true),
+                Inst::Consume { matcher, backward } => {
+                    match self.consume(matcher, pos, *backward) {
+                        Some(next) => {
+                            pos = next;
+                            pc += 1;
+                            true
+                        }
+                        None => false,
+                    }
+                }
+                Inst::Star {
+                    matcher,
+                    min,
+                    max,
+                    greedy,
+                    backward,
+                } => {
+                    let mut count = 0;
+                    let mut success = true;
+                    while count < *min {
+                        self.tick()?;
+                        match self.consume(matcher, pos, *backward) {
+                            Some(next) => pos = next,
+                            None => {
+                                success = false;
+                                break;
+                            }
+                        }
+                        count += 1;
+                    }
+                    if success {
+                        let below_max = |count: u32| max.is_none_or(|max| count < max);
+                        if *greedy {
+                            let min_pos = pos;
+                            while below_max(count) {
+                                self.tick()?;
+                                match self.consume(matcher, pos, *backward) {
+                                    Some(next) => pos = next,
+                                    None => break,
+                                }
+                                count += 1;
+                            }
+                            if pos != min_pos {
+                                trail.push(Frame::Greedy {
+                                    star: pc,
+                                    min_pos,
+                                    pos,
+                                });
+                            }
+                        } else if below_max(count) {
+                            trail.push(Frame::Lazy {
+                                star: pc,
+                                pos,
+                                count,
+                            });
+                        }
+                        pc += 1;
+                    }
+                    success
+                }
+                Inst::Split(alternative) => {
+                    trail.push(Frame::Choice {
+                        pc: *alternative,
+                        pos,
+                    });
+                    pc += 1;
+                    true
+                }
+                Inst::Jump(target) => {
+                    pc = *target;
+                    true
+                }
+                Inst::Save(slot) => {
+                    trail.push(Frame::Slot {
+                        slot: *slot,
+                        old: slots[*slot],
+                    });
+                    slots[*slot] = Some(pos);
+                    pc += 1;
+                    true
+                }
+                Inst::LineStart { multiline } => {
+                    pc += 1;
+                    pos == 0 || (*multiline && is_line_terminator(self.input[pos - 1] as u32))
+                }
+                Inst::LineEnd { multiline } => {
+                    pc += 1;
+                    pos == self.input.len()
+                        || (*multiline && is_line_terminator(self.input[pos] as u32))
+                }
+                Inst::WordBoundary { negate, set } => {
+                    pc += 1;
+                    let before = self.is_word_char(pos.checked_sub(1), *set);
+                    let after = self.is_word_char(Some(pos), *set);
+                    (before != after) != *negate
+                }
+                Inst::BackRef {
+                    groups,
+                    fold,
+                    backward,
+                } => match self.backreference(groups, *fold, *backward, pos, slots) {
+                    Some(next) => {
+                        pos = next;
+                        pc += 1;
+                        true
+                    }
+                    None => false,
+                },
+                Inst::Look { negate, end } => {
+                    let snapshot = slots.clone();
+                    let matched = self.run(pc + 1, pos, slots, registers)?;
+                    if *negate {
+                        *slots = snapshot;
+                    } else if matched {
+                        for (slot, old) in snapshot.into_iter().enumerate() {
+                            if slots[slot] != old {
+                                trail.push(Frame::Slot { slot, old });
+                            }
+                        }
+                    }
+                    pc = *end;
+                    matched != *negate
+                }
+                Inst::RepeatStart { counter } => {
+                    trail.push(Frame::Register {
+                        register: *counter,
+                        old: registers[*counter],
+                    });
+                    registers[*counter] = 0;
+                    pc += 1;
+                    true
+                }
+                Inst::RepeatHead {
+                    counter,
+                    min,
+                    max,
+                    greedy,
+                    exit,
+                } => {
+                    let count = registers[*counter];
+                    if count < *min as usize {
+                        pc += 1;
+                    } else if max.is_some_and(|max| count >= max as usize) {
+                        pc = *exit;
+                    } else if *greedy {
+                        trail.push(Frame::Choice { pc: *exit, pos });
+                        pc += 1;
+                    } else {
+                        trail.push(Frame::Choice { pc: pc + 1, pos });
+                        pc = *exit;
+                    }
+                    true
+                }
+                Inst::RepeatEnter {
+                    position,
+                    slots: (first, last),
+                } => {
+                    trail.push(Frame::Register {
+                        register: *position,
+                        old: registers[*position],
+                    });
+                    registers[*position] = pos;
+                    for (slot, value) in slots.iter_mut().enumerate().take(*last).skip(*first) {
+                        if let Some(old) = value.take() {
+                            trail.push(Frame::Slot {
+                                slot,
+                                old: Some(old),
+                            });
+                        }
+                    }
+                    pc += 1;
+                    true
+                }
+                Inst::RepeatTail {
+                    head,
+                    counter,
+                    position,
+                    min,
+                } => {
+                    let count = registers[*counter];
+                    if count >= *min as usize && pos == registers[*position] {
+                        false
+                    } else {
+                        trail.push(Frame::Register {
+                            register: *counter,
+                            old: count,
+                        });
+                        registers[*counter] = count + 1;
+                        pc = *head;
+                        true
+                    }
+                }
+            };
+            if success {
+                continue;
+            }
+            loop {
+                self.tick()?;
+                match trail.pop() {
+                    None => return Ok(false),
+                    Some(Frame::Choice {
+                        pc: resume_pc,
+                        pos: resume_pos,
+                    }) => {
+                        (pc, pos) = (resume_pc, resume_pos);
+                        break;
+                    }
+                    Some(Frame::Slot { slot, old }) => slots[slot] = old,
+                    Some(Frame::Register { register, old }) => registers[register] = old,
+                    Some(Frame::Greedy {
+                        star,
+                        min_pos,
+                        pos: star_pos,
+                    }) => {
+                        let Inst::Star { backward, .. } = program.insts[star] else {
+                            unreachable!("Greedy frames come from stars");
+                        };
+                        let (_, previous) = self
+                            .read(star_pos, !backward)
+                            .expect("A consumed character can be given back");
+                        if previous != min_pos {
+                            trail.push(Frame::Greedy {
+                                star,
+                                min_pos,
+                                pos: previous,
+                            });
+                        }
+                        (pc, pos) = (star + 1, previous);
+                        break;
+                    }
+                    Some(Frame::Lazy {
+                        star,
+                        pos: star_pos,
+                        count,
+                    }) => {
+                        let Inst::Star {
+                            matcher,
+                            max,
+                            backward,
+                            ..
+                        } = &program.insts[star]
+                        else {
+                            unreachable!("Lazy frames come from stars");
+                        };
+                        if let Some(next) = self.consume(matcher, star_pos, *backward) {
+                            if max.is_none_or(|max| count + 1 < max) {
+                                trail.push(Frame::Lazy {
+                                    star,
+                                    pos: next,
+                                    count: count + 1,
+                                });
+                            }
+                            (pc, pos) = (star + 1, next);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,543 @@
-use std::borrow::Cow;
+use std::cell::{OnceCell, RefCell};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+use std::string::FromUtf16Error;
 
-/// A [copy-on-write](Cow) string.
-pub type StringRep = Cow<'static, str>;
+/// The internal implementation of an ES [String](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-ecmascript-language-types-string-type) value, i.e. an immutable sequence of UTF-16 code units.
+///
+/// Strings whose code units all fit in a byte are stored as Latin-1, halving their memory; other strings are stored as UTF-16. The representation is canonical, so a string is stored as UTF-16 only if it has a code unit above `0xFF`.
+///
+/// Concatenating long strings builds a rope instead of copying both operands. The rope is flattened the first time its code units are accessed, so building a string with repeated concatenation takes linear time.
+///
+/// Converting from a Rust [`str`] is lossless. Converting back is either fallible ([`to_std_string`](Self::to_std_string)) or explicitly lossy ([`to_std_string_lossy`](Self::to_std_string_lossy)), as ES strings may contain lone surrogates.
+#[derive(Clone)]
+pub struct StringRep(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Latin1(Rc<[u8]>),
+    Utf16(Rc<[u16]>),
+    Rope(Rc<Rope>),
+}
+
+/// Concatenations shorter than this are copied eagerly rather than building a rope.
+const MIN_ROPE_LEN: usize = 16;
+
+/// A lazy concatenation of two strings.
+struct Rope {
+    len: usize,
+    latin1: bool,
+    /// The operands, released once the rope is flattened.
+    parts: RefCell<Option<(StringRep, StringRep)>>,
+    /// The flattened string, which is never itself a rope.
+    flat: OnceCell<StringRep>,
+}
+
+impl Rope {
+    fn flatten(&self) -> &StringRep {
+        self.flat.get_or_init(|| {
+            let (left, right) = self.parts.take().expect("Unflattened ropes have parts");
+            // Walk the tree with an explicit stack, as ropes built in a loop are as deep as they are long.
+            let mut stack = vec![right, left];
+            if self.latin1 {
+                let mut bytes = Vec::with_capacity(self.len);
+                while let Some(part) = stack.pop() {
+                    match part.pending_parts() {
+                        Some((left, right)) => stack.extend([right, left]),
+                        None => match &part.flat().0 {
+                            Repr::Latin1(part) => bytes.extend_from_slice(part),
+                            _ => unreachable!("Latin-1 ropes only have Latin-1 parts"),
+                        },
+                    }
+                }
+                StringRep(Repr::Latin1(bytes.into()))
+            } else {
+                let mut units = Vec::with_capacity(self.len);
+                while let Some(part) = stack.pop() {
+                    match part.pending_parts() {
+                        Some((left, right)) => stack.extend([right, left]),
+                        None => units.extend(part.code_units()),
+                    }
+                }
+                StringRep(Repr::Utf16(units.into()))
+            }
+        })
+    }
+}
+
+/// Ropes are released iteratively, as dropping a deep rope recursively would overflow the stack.
+impl Drop for Rope {
+    fn drop(&mut self) {
+        let mut stack: Vec<StringRep> = self
+            .parts
+            .take()
+            .into_iter()
+            .flat_map(|(l, r)| [l, r])
+            .collect();
+        while let Some(part) = stack.pop() {
+            if let Repr::Rope(rope) = part.0 {
+                if let Some(rope) = Rc::into_inner(rope) {
+                    stack.extend(rope.parts.take().into_iter().flat_map(|(l, r)| [l, r]));
+                }
+            }
+        }
+    }
+}
+
+/// An iterator over the code units of a [`StringRep`].
+#[derive(Clone)]
+pub enum CodeUnits<'a> {
+    /// Iterates a Latin-1 string.
+    Latin1(std::slice::Iter<'a, u8>),
+    /// Iterates an UTF-16 string.
+    Utf16(std::slice::Iter<'a, u16>),
+}
+
+impl Iterator for CodeUnits<'_> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        match self {
+            Self::Latin1(iter) => iter.next().map(|unit| *unit as u16),
+            Self::Utf16(iter) => iter.next().copied(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Latin1(iter) => iter.size_hint(),
+            Self::Utf16(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl DoubleEndedIterator for CodeUnits<'_> {
+    fn next_back(&mut self) -> Option<u16> {
+        match self {
+            Self::Latin1(iter) => iter.next_back().map(|unit| *unit as u16),
+            Self::Utf16(iter) => iter.next_back().copied(),
+        }
+    }
+}
+
+impl ExactSizeIterator for CodeUnits<'_> {}
+
+impl StringRep {
+    /// Returns the flat representation, flattening the rope if needed.
+    fn flat(&self) -> &StringRep {
+        match &self.0 {
+            Repr::Rope(rope) => rope.flatten(),
+            _ => self,
+        }
+    }
+
+    /// Returns the operands of a rope that has not been flattened yet.
+    fn pending_parts(&self) -> Option<(StringRep, StringRep)> {
+        match &self.0 {
+            Repr::Rope(rope) if rope.flat.get().is_none() => rope.parts.borrow().clone(),
+            _ => None,
+        }
+    }
+
+    /// Creates an empty string.
+    pub fn empty() -> Self {
+        StringRep(Repr::Latin1(Rc::from([])))
+    }
+
+    /// Creates a string from a sequence of UTF-16 code units, which may contain lone surrogates.
+    pub fn from_utf16(code_units: &[u16]) -> Self {
+        if code_units.iter().all(|unit| *unit <= 0xFF) {
+            let bytes: Vec<u8> = code_units.iter().map(|unit| *unit as u8).collect();
+            StringRep(Repr::Latin1(bytes.into()))
+        } else {
+            StringRep(Repr::Utf16(code_units.into()))
+        }
+    }
+
+    /// Creates a string from Latin-1 (ISO-8859-1) bytes, i.e. code units in the range `0x00`–`0xFF`.
+    pub fn from_latin1(bytes: &[u8]) -> Self {
+        StringRep(Repr::Latin1(bytes.into()))
+    }
+
+    /// Creates a string holding a single code point.
+    pub fn from_code_point(code_point: u32) -> Self {
+        let mut buffer = [0u16; 2];
+        StringRep::from_utf16(p262_utf16_encode_code_point(code_point, &mut buffer))
+    }
+
+    /// Returns the length of the string, in code units.
+    pub fn len(&self) -> usize {
+        match &self.0 {
+            Repr::Latin1(bytes) => bytes.len(),
+            Repr::Utf16(units) => units.len(),
+            Repr::Rope(rope) => rope.len,
+        }
+    }
+
+    /// Returns if the string has no code units.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns if the string is stored as Latin-1.
+    pub fn is_latin1(&self) -> bool {
+        match &self.0 {
+            Repr::Latin1(_) => true,
+            Repr::Utf16(_) => false,
+            Repr::Rope(rope) => rope.latin1,
+        }
+    }
+
+    /// Returns if both strings share their code units, as clones of a string do, in which case they are equal.
+    pub(crate) fn ptr_eq(&self, other: &StringRep) -> bool {
+        match (&self.flat().0, &other.flat().0) {
+            (Repr::Latin1(a), Repr::Latin1(b)) => Rc::ptr_eq(a, b),
+            (Repr::Utf16(a), Repr::Utf16(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+
+    /// Returns the code unit at `index`, if in bounds.
+    pub fn code_unit_at(&self, index: usize) -> Option<u16> {
+        match &self.flat().0 {
+            Repr::Latin1(bytes) => bytes.get(index).map(|unit| *unit as u16),
+            Repr::Utf16(units) => units.get(index).copied(),
+            Repr::Rope(_) => unreachable!(),
+        }
+    }
+
+    /// Returns an iterator over the code units.
+    pub fn code_units(&self) -> CodeUnits<'_> {
+        match &self.flat().0 {
+            Repr::Latin1(bytes) => CodeUnits::Latin1(bytes.iter()),
+            Repr::Utf16(units) => CodeUnits::Utf16(units.iter()),
+            Repr::Rope(_) => unreachable!(),
+        }
+    }
+
+    /// Returns the code units as an owned UTF-16 vector.
+    pub fn to_utf16(&self) -> Vec<u16> {
+        self.code_units().collect()
+    }
+
+    /// Returns an iterator over the code points, implementing [CodePointAt](https://tc39.es/ecma262/multipage/ecmascript-language-source-code.html#sec-codepointat) repeatedly.
+    ///
+    /// Lone surrogates are returned as their own code point.
+    pub fn code_points(&self) -> impl Iterator<Item = u32> + '_ {
+        char::decode_utf16(self.code_units())
+            .map(|result| result.map_or_else(|err| err.unpaired_surrogate() as u32, |c| c as u32))
+    }
+
+    /// Returns the code units in the range `from..to`, implementing the [substring](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#substring) notation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn substring(&self, from: usize, to: usize) -> Self {
+        match &self.flat().0 {
+            Repr::Latin1(bytes) => StringRep::from_latin1(&bytes[from..to]),
+            Repr::Utf16(units) => StringRep::from_utf16(&units[from..to]),
+            Repr::Rope(_) => unreachable!(),
+        }
+    }
+
+    /// Returns the [string-concatenation](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#string-concatenation) of two strings.
+    ///
+    /// Long results are built as ropes, which do not copy the operands until their code units are accessed.
+    pub fn concat(&self, other: &StringRep) -> Self {
+        if other.is_empty() {
+            return self.clone();
+        }
+        if self.is_empty() {
+            return other.clone();
+        }
+        let len = self.len() + other.len();
+        if len >= MIN_ROPE_LEN {
+            return StringRep(Repr::Rope(Rc::new(Rope {
+                len,
+                latin1: self.is_latin1() && other.is_latin1(),
+                parts: RefCell::new(Some((self.clone(), other.clone()))),
+                flat: OnceCell::new(),
+            })));
+        }
+        match (&self.flat().0, &other.flat().0) {
+            (Repr::Latin1(a), Repr::Latin1(b)) => {
+                let mut bytes = Vec::with_capacity(a.len() + b.len());
+                bytes.extend_from_slice(a);
+                bytes.extend_from_slice(b);
+                StringRep(Repr::Latin1(bytes.into()))
+            }
+            _ => {
+                let mut units = Vec::with_capacity(len);
+                units.extend(self.code_units());
+                units.extend(other.code_units());
+                StringRep(Repr::Utf16(units.into()))
+            }
+        }
+    }
+
+    /// Returns the index of the first occurrence of `search` at or after `from`, implementing [StringIndexOf](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-stringindexof).
+    pub fn index_of(&self, search: &StringRep, from: usize) -> Option<usize> {
+        let len = self.len();
+        let search_len = search.len();
+        if search_len == 0 && from <= len {
+            return Some(from);
+        }
+        if search_len > len {
+            return None;
+        }
+        (from..=len - search_len)
+            .find(|&i| (0..search_len).all(|j| self.code_unit_at(i + j) == search.code_unit_at(j)))
+    }
+
+    /// Converts to a Rust [`String`], failing if the string contains lone surrogates.
+    pub fn to_std_string(&self) -> Result<String, FromUtf16Error> {
+        match &self.flat().0 {
+            Repr::Latin1(bytes) => Ok(bytes.iter().map(|byte| *byte as char).collect()),
+            Repr::Utf16(units) => String::from_utf16(units),
+            Repr::Rope(_) => unreachable!(),
+        }
+    }
+
+    /// Converts to a Rust [`String`], replacing lone surrogates with U+FFFD REPLACEMENT CHARACTER.
+    pub fn to_std_string_lossy(&self) -> String {
+        match &self.flat().0 {
+            Repr::Latin1(bytes) => bytes.iter().map(|byte| *byte as char).collect(),
+            Repr::Utf16(units) => String::from_utf16_lossy(units),
+            Repr::Rope(_) => unreachable!(),
+        }
+    }
+}
+
+/// Encodes a code point into one or two UTF-16 code units, implementing [UTF16EncodeCodePoint](https://tc39.es/ecma262/multipage/ecmascript-language-source-code.html#sec-utf16encodecodepoint).
+///
+/// Surrogate code points are encoded as themselves.
+pub fn p262_utf16_encode_code_point(code_point: u32, buffer: &mut [u16; 2]) -> &[u16] {
+    debug_assert!(code_point <= 0x10FFFF);
+    if code_point <= 0xFFFF {
+        buffer[0] = code_point as u16;
+        &buffer[..1]
+    } else {
+        let offset = code_point - 0x10000;
+        buffer[0] = (0xD800 + (offset >> 10)) as u16;
+        buffer[1] = (0xDC00 + (offset & 0x3FF)) as u16;
+        &buffer[..2]
+    }
+}
+
+impl Default for StringRep {
+    fn default() -> Self {
+        StringRep::empty()
+    }
+}
+
+impl From<&str> for StringRep {
+    fn from(value: &str) -> Self {
+        if value.is_ascii() {
+            StringRep::from_latin1(value.as_bytes())
+        } else {
+            let units: Vec<u16> = value.encode_utf16().collect();
+            StringRep::from_utf16(&units)
+        }
+    }
+}
+
+impl From<String> for StringRep {
+    fn from(value: String) -> Self {
+        StringRep::from(value.as_str())
+    }
+}
+
+impl From<&String> for StringRep {
+    fn from(value: &String) -> Self {
+        StringRep::from(value.as_str())
+    }
+}
+
+impl PartialEq for StringRep {
+    fn eq(&self, other: &Self) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        match (&self.flat().0, &other.flat().0) {
+            (Repr::Latin1(a), Repr::Latin1(b)) => a == b,
+            (Repr::Utf16(a), Repr::Utf16(b)) => a == b,
+            _ => self.code_units().eq(other.code_units()),
+        }
+    }
+}
+
+impl Eq for StringRep {}
+
+impl PartialEq<str> for StringRep {
+    fn eq(&self, other: &str) -> bool {
+        self.code_units().eq(other.encode_utf16())
+    }
+}
+
+impl PartialEq<&str> for StringRep {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialOrd for StringRep {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Strings are ordered lexicographically by code unit, as required by [IsLessThan](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-islessthan).
+impl Ord for StringRep {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.code_units().cmp(other.code_units())
+    }
+}
+
+impl Hash for StringRep {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for unit in self.code_units() {
+            state.write_u16(unit);
+        }
+    }
+}
+
+impl fmt::Debug for StringRep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        for c in char::decode_utf16(self.code_units()) {
+            match c {
+                Ok(c) => {
+                    for escaped in c.escape_debug() {
+                        fmt::Write::write_char(f, escaped)?;
+                    }
+                }
+                Err(err) => write!(f, "\\u{{{:x}}}", err.unpaired_surrogate())?,
+            }
+        }
+        f.write_str("\"")
+    }
+}
+
+/// Displays the string, replacing lone surrogates with U+FFFD REPLACEMENT CHARACTER.
+impl fmt::Display for StringRep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_std_string_lossy())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uses_latin1_when_possible() {
+        assert!(StringRep::from("caf\u{e9}").is_latin1());
+        assert!(!StringRep::from("\u{20ac}").is_latin1());
+        assert!(StringRep::from_utf16(&[0x66, 0x6f, 0x6f]).is_latin1());
+        assert_eq!(StringRep::from_utf16(&[0x66, 0x6f, 0x6f]), "foo");
+    }
+
+    #[test]
+    fn length_is_in_code_units() {
+        assert_eq!(StringRep::from("foo").len(), 3);
+        assert_eq!(StringRep::from("\u{e9}").len(), 1);
+        assert_eq!(StringRep::from("\u{1F600}").len(), 2);
+        assert!(StringRep::empty().is_empty());
+    }
+
+    #[test]
+    fn indexing_uses_code_units() {
+        let s = StringRep::from("a\u{1F600}");
+        assert_eq!(s.code_unit_at(1), Some(0xD83D));
+        assert_eq!(s.code_unit_at(2), Some(0xDE00));
+        assert_eq!(s.code_unit_at(3), None);
+        assert_eq!(s.substring(1, 2).to_utf16(), vec![0xD83D]);
+        assert_eq!(s.code_points().collect::<Vec<_>>(), vec![0x61, 0x1F600]);
+    }
+
+    #[test]
+    fn keeps_lone_surrogates() {
+        let s = StringRep::from_utf16(&[0x61, 0xD800]);
+        assert_eq!(s.len(), 2);
+        assert!(s.to_std_string().is_err());
+        assert_eq!(s.to_std_string_lossy(), "a\u{FFFD}");
+        assert_eq!(format!("{s:?}"), "\"a\\u{d800}\"");
+        assert_eq!(s.code_points().collect::<Vec<_>>(), vec![0x61, 0xD800]);
+    }
+
+    #[test]
+    fn round_trips_rust_strings() {
+        let text = "h\u{e9}llo \u{1F600} w\u{f6}rld";
+        assert_eq!(StringRep::from(text).to_std_string().unwrap(), text);
+    }
+
+    #[test]
+    fn compares_across_representations() {
+        let latin1 = StringRep::from("ab");
+        let utf16 = StringRep(Repr::Utf16(Rc::from([0x61u16, 0x62])));
+        assert_eq!(latin1, utf16);
+        let (emoji, halfwidth) = (StringRep::from("\u{1F600}"), StringRep::from("\u{FF61}"));
+        assert!(emoji < halfwidth);
+        let (a, ab) = (StringRep::from("a"), StringRep::from("ab"));
+        assert!(a < ab);
+    }
+
+    #[test]
+    fn concat_works() {
+        let a = StringRep::from("foo");
+        assert_eq!(a.concat(&"bar".into()), "foobar");
+        assert!(a.concat(&"bar".into()).is_latin1());
+        assert_eq!(a.concat(&"\u{20ac}".into()), "foo\u{20ac}");
+    }
+
+    #[test]
+    fn index_of_works() {
+        let s = StringRep::from("abcabc");
+        assert_eq!(s.index_of(&"bc".into(), 0), Some(1));
+        assert_eq!(s.index_of(&"bc".into(), 2), Some(4));
+        assert_eq!(s.index_of(&"".into(), 6), Some(6));
+        assert_eq!(s.index_of(&"x".into(), 0), None);
+    }
+
+    #[test]
+    fn concat_builds_ropes() {
+        let a = StringRep::from("0123456789");
+        let ab = a.concat(&a);
+        assert!(matches!(ab.0, Repr::Rope(_)));
+        assert!(ab.is_latin1());
+        assert_eq!(ab.len(), 20);
+        assert_eq!(ab, "01234567890123456789");
+        assert_eq!(ab.code_unit_at(19), Some(0x39));
+
+        let euro = ab.concat(&"\u{20ac}".into());
+        assert!(!euro.is_latin1());
+        assert_eq!(euro.len(), 21);
+        assert_eq!(euro.substring(18, 21), "89\u{20ac}");
+        assert!(matches!(a.concat(&StringRep::empty()).0, Repr::Latin1(_)));
+    }
+
+    #[test]
+    fn deep_ropes_are_linear() {
+        let unit = StringRep::from("x\u{e9}");
+        let mut s = StringRep::empty();
+        for _ in 0..200_000 {
+            s = s.concat(&unit);
+        }
+        assert_eq!(s.len(), 400_000);
+        assert!(s.is_latin1());
+        assert_eq!(s.code_unit_at(399_999), Some(0xE9));
+        assert_eq!(s.code_units().filter(|unit| *unit == 0x78).count(), 200_000);
+
+        let mut t = StringRep::from("\u{1F600}");
+        for _ in 0..200_000 {
+            t = unit.concat(&t);
+        }
+        assert_eq!(t.len(), 400_002);
+        drop(s);
+        drop(t);
+    }
+}
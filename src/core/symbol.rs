@@ -1,32 +1,132 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt;
-use std::rc::Rc;
+use std::hash::{Hash, Hasher};
 
 use super::id::MagicId;
+use super::StringRep;
 
-/// Implements the global [symbols registry](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-symbol.for).
+/// Implements the [GlobalSymbolRegistry](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-globalsymbolregistry-record-fields) list.
+///
+/// The registry is owned by the agent, so registered symbols are shared by every realm.
 #[derive(Default)]
 pub(crate) struct SymbolRegistry {
-    map: RefCell<HashMap<String, Rc<SymbolRep>>>,
+    symbols: RefCell<HashMap<StringRep, SymbolRep>>,
+    keys: RefCell<HashMap<SymbolRep, StringRep>>,
 }
 
 impl SymbolRegistry {
-    /// Returns a symbol from the registry, creating it if not previously available.
-    pub fn get(&mut self, description: String) -> Rc<SymbolRep> {
-        self.map
+    /// Returns the symbol registered for `key`, registering a new one if not previously available. This implements the lookup of [`Symbol.for`](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-symbol.for).
+    pub fn get(&self, key: impl Into<StringRep>) -> SymbolRep {
+        let key = key.into();
+        if let Some(symbol) = self.symbols.borrow().get(&key) {
+            return symbol.clone();
+        }
+        let symbol = SymbolRep {
+            id: MagicId::new(),
+            description: Some(key.clone()),
+            registered: true,
+        };
+        self.symbols
             .borrow_mut()
-            .entry(description.clone())
-            .or_insert_with(|| Rc::new(SymbolRep::named(description.clone())))
-            .clone()
+            .insert(key.clone(), symbol.clone());
+        self.keys.borrow_mut().insert(symbol.clone(), key);
+        symbol
+    }
+
+    /// Returns the key `symbol` was registered with, implementing [KeyForSymbol](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-keyforsymbol).
+    pub fn key_for(&self, symbol: &SymbolRep) -> Option<StringRep> {
+        self.keys.borrow().get(symbol).cloned()
+    }
+}
+
+/// A [well-known symbol](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-well-known-symbols).
+///
+/// Well-known symbols are shared by every realm.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum WellKnownSymbol {
+    /// The `@@asyncIterator` symbol, used by [GetIterator](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-getiterator).
+    AsyncIterator,
+    /// The `@@hasInstance` symbol, used by [InstanceofOperator](https://tc39.es/ecma262/multipage/ecmascript-language-expressions.html#sec-instanceofoperator).
+    HasInstance,
+    /// The `@@isConcatSpreadable` symbol, used by [IsConcatSpreadable](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-isconcatspreadable).
+    IsConcatSpreadable,
+    /// The `@@iterator` symbol, used by [GetIterator](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-getiterator).
+    Iterator,
+    /// The `@@match` symbol, used by [IsRegExp](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-isregexp) and `String.prototype.match`.
+    Match,
+    /// The `@@matchAll` symbol, used by `String.prototype.matchAll`.
+    MatchAll,
+    /// The `@@replace` symbol, used by `String.prototype.replace`.
+    Replace,
+    /// The `@@search` symbol, used by `String.prototype.search`.
+    Search,
+    /// The `@@species` symbol, used by [SpeciesConstructor](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-speciesconstructor).
+    Species,
+    /// The `@@split` symbol, used by `String.prototype.split`.
+    Split,
+    /// The `@@toPrimitive` symbol, used by [ToPrimitive](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-toprimitive).
+    ToPrimitive,
+    /// The `@@toStringTag` symbol, used by `Object.prototype.toString`.
+    ToStringTag,
+    /// The `@@unscopables` symbol, used by [HasBinding](https://tc39.es/ecma262/multipage/executable-code-and-execution-contexts.html#sec-object-environment-records-hasbinding-n) of object environments.
+    Unscopables,
+}
+
+impl WellKnownSymbol {
+    /// Every well-known symbol.
+    pub const ALL: [WellKnownSymbol; 13] = [
+        Self::AsyncIterator,
+        Self::HasInstance,
+        Self::IsConcatSpreadable,
+        Self::Iterator,
+        Self::Match,
+        Self::MatchAll,
+        Self::Replace,
+        Self::Search,
+        Self::Species,
+        Self::Split,
+        Self::ToPrimitive,
+        Self::ToStringTag,
+        Self::Unscopables,
+    ];
+
+    /// Returns the name of the property of the `Symbol` constructor holding this symbol, e.g. `"iterator"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::AsyncIterator => "asyncIterator",
+            Self::HasInstance => "hasInstance",
+            Self::IsConcatSpreadable => "isConcatSpreadable",
+            Self::Iterator => "iterator",
+            Self::Match => "match",
+            Self::MatchAll => "matchAll",
+            Self::Replace => "replace",
+            Self::Search => "search",
+            Self::Species => "species",
+            Self::Split => "split",
+            Self::ToPrimitive => "toPrimitive",
+            Self::ToStringTag => "toStringTag",
+            Self::Unscopables => "unscopables",
+        }
+    }
+
+    /// Returns the `[[Description]]` of this symbol, e.g. `"Symbol.iterator"`.
+    pub fn description(self) -> String {
+        format!("Symbol.{}", self.name())
     }
 }
 
 /// The internal implementation of an ES [Symbol](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-ecmascript-language-types-symbol-type) value.
 ///
-/// Each Symbol value have a `[[Description]]` immutable slot that contains an [optional](Option) [String].
-#[derive(Clone, Eq, Hash, PartialEq)]
-pub struct SymbolRep(MagicId, Option<String>);
+/// Each Symbol value have a `[[Description]]` immutable slot that contains an [optional](Option) [StringRep].
+///
+/// Symbols are compared and hashed by identity only.
+#[derive(Clone)]
+pub struct SymbolRep {
+    id: MagicId,
+    description: Option<StringRep>,
+    registered: bool,
+}
 
 impl SymbolRep {
     /// Creates a new [SymbolRep] with an empty (undefined) `[[Description]]` slot.
@@ -35,19 +135,66 @@ impl SymbolRep {
     }
 
     /// Creates a new [SymbolRep], setting the `[[Description]]` slot to the provided string.
-    pub fn named(description: String) -> Self {
-        SymbolRep::new(Some(description))
+    pub fn named(description: impl Into<StringRep>) -> Self {
+        SymbolRep::new(Some(description.into()))
     }
 
     /// Creates a new [SymbolRep], with or without a `[[Description]]` slot.
-    pub fn new(description: Option<String>) -> Self {
-        SymbolRep(MagicId::new(), description)
+    pub fn new(description: Option<StringRep>) -> Self {
+        SymbolRep {
+            id: MagicId::new(),
+            description,
+            registered: false,
+        }
+    }
+
+    /// Returns the [SymbolRep] of a [well-known symbol](WellKnownSymbol).
+    pub fn well_known(symbol: WellKnownSymbol) -> Self {
+        SymbolRep {
+            id: MagicId::reserved(symbol as u64),
+            description: Some(symbol.description().into()),
+            registered: false,
+        }
+    }
+
+    /// Returns the `[[Description]]` slot.
+    pub fn description(&self) -> Option<&StringRep> {
+        self.description.as_ref()
+    }
+
+    /// Returns the [SymbolDescriptiveString](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-symboldescriptivestring), e.g. `"Symbol(foo)"`.
+    pub fn descriptive_string(&self) -> StringRep {
+        let description = self.description.clone().unwrap_or_default();
+        StringRep::from("Symbol(")
+            .concat(&description)
+            .concat(&StringRep::from(")"))
+    }
+
+    /// Returns if the symbol was created by `Symbol.for`, i.e. is in the [GlobalSymbolRegistry](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-globalsymbolregistry-record-fields).
+    ///
+    /// Registered symbols [cannot be held weakly](https://tc39.es/ecma262/multipage/executable-code-and-execution-contexts.html#sec-canbeheldweakly).
+    pub fn is_registered(&self) -> bool {
+        self.registered
+    }
+}
+
+impl PartialEq for SymbolRep {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for SymbolRep {}
+
+impl Hash for SymbolRep {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
     }
 }
 
 impl fmt::Debug for SymbolRep {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("SymbolRep").field(&self.1).finish()
+        f.debug_tuple("SymbolRep").field(&self.description).finish()
     }
 }
 
@@ -70,12 +217,54 @@ mod tests {
         assert_ne!(a, b);
     }
 
+    #[test]
+    fn well_known_are_shared() {
+        let a = SymbolRep::well_known(WellKnownSymbol::ToPrimitive);
+        let b = SymbolRep::well_known(WellKnownSymbol::ToPrimitive);
+
+        assert_eq!(a, b);
+        assert_ne!(a, SymbolRep::named("Symbol.toPrimitive".to_string()));
+    }
+
     #[test]
     fn can_be_retrieved() {
-        let mut registry = SymbolRegistry::default();
+        let registry = SymbolRegistry::default();
         let a = registry.get("foo".to_string());
         let b = registry.get("foo".to_string());
 
         assert_eq!(a, b);
+        assert!(a.is_registered());
+        assert_eq!(registry.key_for(&a), Some("foo".into()));
+    }
+
+    #[test]
+    fn unregistered_have_no_key() {
+        let registry = SymbolRegistry::default();
+        let a = SymbolRep::named("foo");
+        registry.get("foo");
+
+        assert!(!a.is_registered());
+        assert_eq!(registry.key_for(&a), None);
+        let iterator = SymbolRep::well_known(WellKnownSymbol::Iterator);
+        assert!(!iterator.is_registered());
+        assert_eq!(registry.key_for(&iterator), None);
+    }
+
+    #[test]
+    fn well_known_are_distinct() {
+        for (i, a) in WellKnownSymbol::ALL.iter().enumerate() {
+            for (j, b) in WellKnownSymbol::ALL.iter().enumerate() {
+                assert_eq!(
+                    SymbolRep::well_known(*a) == SymbolRep::well_known(*b),
+                    i == j
+                );
+            }
+        }
+        assert_eq!(
+            *SymbolRep::well_known(WellKnownSymbol::AsyncIterator)
+                .description()
+                .unwrap(),
+            "Symbol.asyncIterator"
+        );
     }
 }
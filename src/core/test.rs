@@ -1,7 +1,67 @@
-use super::Value;
+use num_bigint::BigInt;
+use num_traits::FromPrimitive;
+use std::cmp::Ordering;
+
+use super::annex_b::p262_is_document_dot_all;
+use super::cast::{
+    e262_string_to_big_int, e262_to_boolean, e262_to_number, e262_to_numeric, e262_to_primitive,
+    Numeric, PreferredType,
+};
 use super::{bigint, numbers};
+use super::{
+    e262_get, p262_get_slot, p262_has_slot, ObjectRep, PropertyKey, SymbolRep, Value,
+    WellKnownSymbol,
+};
 use crate::errors::{CoreError, CoreResult};
 
+/// Returns if `v` can be the target of a weak reference, implementing [CanBeHeldWeakly](https://tc39.es/ecma262/multipage/executable-code-and-execution-contexts.html#sec-canbeheldweakly).
+pub(crate) fn e262_can_be_held_weakly(v: &Value) -> bool {
+    match v {
+        Value::Object(_) => true,
+        Value::Symbol(symbol) => !symbol.is_registered(),
+        _ => false,
+    }
+}
+
+/// Implements [IsArray](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-isarray).
+///
+/// Proxies, recognized by their `[[ProxyHandler]]` and `[[ProxyTarget]]` slots, are arrays if their target is.
+pub(crate) fn e262_is_array(argument: &Value) -> CoreResult<bool> {
+    let Value::Object(obj) = argument else {
+        return Ok(false);
+    };
+    if obj.0.clone().is_array_exotic() {
+        return Ok(true);
+    }
+    match p262_get_slot::<Option<ObjectRep>>(obj.0.clone(), "ProxyHandler".to_string()) {
+        None => Ok(false),
+        Some(handler) if handler.is_none() => Err(CoreError::TypeError(
+            "Cannot perform IsArray on a revoked proxy".to_string(),
+        )),
+        Some(_) => {
+            let target =
+                p262_get_slot::<Option<ObjectRep>>(obj.0.clone(), "ProxyTarget".to_string())
+                    .and_then(|target| target.as_ref().clone())
+                    .expect("Proxies with a handler have a target");
+            e262_is_array(&Value::Object(target))
+        }
+    }
+}
+
+pub(crate) fn e262_is_constructor(argument: &Value) -> bool {
+    match argument {
+        Value::Object(obj) => obj.0.clone().as_constructor().is_some(),
+        _ => false,
+    }
+}
+
+pub(crate) fn e262_is_callable(argument: &Value) -> bool {
+    match argument {
+        Value::Object(obj) => obj.0.clone().as_callable().is_some(),
+        _ => false,
+    }
+}
+
 pub(crate) fn e262_is_integral_number(argument: &Value) -> bool {
     if let Value::Number(value) = argument {
         value.is_finite() && value.floor() == (*value)
@@ -10,6 +70,104 @@ pub(crate) fn e262_is_integral_number(argument: &Value) -> bool {
     }
 }
 
+pub(crate) fn e262_is_less_than(
+    x: &Value,
+    y: &Value,
+    left_first: bool,
+) -> CoreResult<Option<bool>> {
+    let (px, py) = if left_first {
+        let px = e262_to_primitive(x, Some(PreferredType::Number))?;
+        let py = e262_to_primitive(y, Some(PreferredType::Number))?;
+        (px, py)
+    } else {
+        let py = e262_to_primitive(y, Some(PreferredType::Number))?;
+        let px = e262_to_primitive(x, Some(PreferredType::Number))?;
+        (px, py)
+    };
+    match (&px, &py) {
+        (Value::String(px), Value::String(py)) => Ok(Some(px < py)),
+        (Value::BigInt(px), Value::String(py)) => {
+            Ok(e262_string_to_big_int(&py.to_std_string_lossy())
+                .map(|ny| bigint::e262_less_than(px, &ny)))
+        }
+        (Value::String(px), Value::BigInt(py)) => {
+            Ok(e262_string_to_big_int(&px.to_std_string_lossy())
+                .map(|nx| bigint::e262_less_than(&nx, py)))
+        }
+        _ => {
+            let nx = e262_to_numeric(&px)?;
+            let ny = e262_to_numeric(&py)?;
+            match (nx, ny) {
+                (Numeric::Number(nx), Numeric::Number(ny)) => Ok(numbers::e262_less_than(nx, ny)),
+                (Numeric::BigInt(nx), Numeric::BigInt(ny)) => {
+                    Ok(Some(bigint::e262_less_than(&nx, &ny)))
+                }
+                (Numeric::BigInt(nx), Numeric::Number(ny)) => {
+                    Ok(compare_big_int_to_number(&nx, ny).map(|o| o == Ordering::Less))
+                }
+                (Numeric::Number(nx), Numeric::BigInt(ny)) => {
+                    Ok(compare_big_int_to_number(&ny, nx).map(|o| o == Ordering::Greater))
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn e262_is_loosely_equal(x: &Value, y: &Value) -> CoreResult<bool> {
+    if e262_type(x) == e262_type(y) {
+        return Ok(e262_is_strictly_equal(x, y));
+    }
+    match (x, y) {
+        (Value::Null, Value::Undefined) | (Value::Undefined, Value::Null) => Ok(true),
+        (Value::Object(obj), Value::Null | Value::Undefined)
+        | (Value::Null | Value::Undefined, Value::Object(obj))
+            if p262_is_document_dot_all(obj.0.clone()) =>
+        {
+            Ok(true)
+        }
+        (Value::Number(x), Value::String(_)) => Ok(numbers::e262_equal(*x, e262_to_number(y)?)),
+        (Value::String(_), Value::Number(y)) => Ok(numbers::e262_equal(e262_to_number(x)?, *y)),
+        (Value::BigInt(_), Value::String(y)) => {
+            match e262_string_to_big_int(&y.to_std_string_lossy()) {
+                Some(n) => e262_is_loosely_equal(x, &Value::BigInt(n)),
+                None => Ok(false),
+            }
+        }
+        (Value::String(_), Value::BigInt(_)) => e262_is_loosely_equal(y, x),
+        (Value::Boolean(_), _) => e262_is_loosely_equal(&Value::Number(e262_to_number(x)?), y),
+        (_, Value::Boolean(_)) => e262_is_loosely_equal(x, &Value::Number(e262_to_number(y)?)),
+        (
+            Value::String(_) | Value::Number(_) | Value::BigInt(_) | Value::Symbol(_),
+            Value::Object(_),
+        ) => e262_is_loosely_equal(x, &e262_to_primitive(y, None)?),
+        (
+            Value::Object(_),
+            Value::String(_) | Value::Number(_) | Value::BigInt(_) | Value::Symbol(_),
+        ) => e262_is_loosely_equal(&e262_to_primitive(x, None)?, y),
+        (Value::BigInt(x), Value::Number(y)) | (Value::Number(y), Value::BigInt(x)) => {
+            Ok(compare_big_int_to_number(x, *y) == Some(Ordering::Equal))
+        }
+        (_, _) => Ok(false),
+    }
+}
+
+/// Compares the mathematical values of a BigInt and a Number, returning [`None`] if the Number is NaN.
+fn compare_big_int_to_number(x: &BigInt, y: f64) -> Option<Ordering> {
+    if numbers::is_nan(y) {
+        None
+    } else if numbers::is_pos_inf(y) {
+        Some(Ordering::Less)
+    } else if numbers::is_neg_inf(y) {
+        Some(Ordering::Greater)
+    } else {
+        let truncated = BigInt::from_f64(y.trunc()).expect("Finite Numbers are convertible");
+        match x.cmp(&truncated) {
+            Ordering::Equal => 0f64.partial_cmp(&y.fract()),
+            ordering => Some(ordering),
+        }
+    }
+}
+
 pub(crate) fn e262_is_strictly_equal(x: &Value, y: &Value) -> bool {
     if e262_type(x) != e262_type(y) {
         false
@@ -21,6 +179,18 @@ pub(crate) fn e262_is_strictly_equal(x: &Value, y: &Value) -> bool {
     }
 }
 
+/// Implements [IsRegExp](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-isregexp).
+pub(crate) fn e262_is_reg_exp(argument: &Value) -> CoreResult<bool> {
+    let Value::Object(obj) = argument else {
+        return Ok(false);
+    };
+    let key = PropertyKey::from(SymbolRep::well_known(WellKnownSymbol::Match));
+    match e262_get(obj, &key)? {
+        Value::Undefined => Ok(p262_has_slot(obj.0.clone(), "RegExpMatcher".to_string())),
+        matcher => Ok(e262_to_boolean(&matcher)),
+    }
+}
+
 pub(crate) fn e262_is_property_key(argument: &Value) -> bool {
     matches!(argument, Value::String(_) | Value::Symbol(_))
 }
@@ -96,3 +266,159 @@ pub(crate) fn e262_type(value: &Value) -> Type {
         Value::Undefined => Type::Undefined,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::core::function::e262_create_builtin_function;
+    use crate::core::{e262_create_data_property, e262_ordinary_object_create, PropertyKey};
+
+    fn big(value: i64) -> Value {
+        Value::BigInt(BigInt::from(value))
+    }
+
+    fn str(value: &'static str) -> Value {
+        Value::String(value.into())
+    }
+
+    fn object_with_value_of(value: Value) -> Value {
+        let obj = e262_ordinary_object_create(None);
+        let func =
+            e262_create_builtin_function(Rc::new(move |_, _| Ok(value.clone())), 0, "", None);
+        e262_create_data_property(&obj, PropertyKey::from("valueOf"), Value::Object(func)).unwrap();
+        Value::Object(obj)
+    }
+
+    #[test]
+    fn loosely_equal_works() {
+        assert!(e262_is_loosely_equal(&Value::Null, &Value::Undefined).unwrap());
+        assert!(!e262_is_loosely_equal(&Value::Null, &Value::Number(0.0)).unwrap());
+        assert!(e262_is_loosely_equal(&Value::Number(1.0), &str(" 1 ")).unwrap());
+        assert!(e262_is_loosely_equal(&str(""), &Value::Number(0.0)).unwrap());
+        assert!(!e262_is_loosely_equal(&Value::Number(f64::NAN), &str("NaN")).unwrap());
+        assert!(e262_is_loosely_equal(&Value::Boolean(true), &str("1")).unwrap());
+        assert!(e262_is_loosely_equal(&big(10), &str("10")).unwrap());
+        assert!(!e262_is_loosely_equal(&str("1.5"), &big(1)).unwrap());
+        assert!(e262_is_loosely_equal(&big(2), &Value::Number(2.0)).unwrap());
+        assert!(!e262_is_loosely_equal(&big(2), &Value::Number(2.5)).unwrap());
+        assert!(!e262_is_loosely_equal(&big(2), &Value::Number(f64::INFINITY)).unwrap());
+        assert!(
+            e262_is_loosely_equal(&object_with_value_of(Value::Number(3.0)), &str("3")).unwrap()
+        );
+        assert!(e262_is_loosely_equal(&big(3), &object_with_value_of(Value::Number(3.0))).unwrap());
+        assert!(!e262_is_loosely_equal(&object_with_value_of(Value::Null), &Value::Null).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "annex-b")]
+    fn loosely_equal_handles_is_html_dda() {
+        let obj = e262_ordinary_object_create(None);
+        obj.0
+            .clone()
+            .set_slot("IsHTMLDDA".to_string(), Rc::new(true));
+        let obj = Value::Object(obj);
+
+        assert!(e262_is_loosely_equal(&obj, &Value::Undefined).unwrap());
+        assert!(e262_is_loosely_equal(&Value::Null, &obj).unwrap());
+    }
+
+    #[test]
+    fn less_than_works() {
+        assert_eq!(
+            e262_is_less_than(&Value::Number(1.0), &str("2"), true).unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            e262_is_less_than(&str("a"), &str("b"), true).unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            e262_is_less_than(&str("10"), &str("9"), true).unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            e262_is_less_than(&Value::Number(f64::NAN), &Value::Number(1.0), true).unwrap(),
+            None
+        );
+        assert_eq!(
+            e262_is_less_than(&big(1), &str("2"), true).unwrap(),
+            Some(true)
+        );
+        assert_eq!(e262_is_less_than(&str("x"), &big(2), true).unwrap(), None);
+        assert_eq!(
+            e262_is_less_than(&big(1), &Value::Number(1.5), true).unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            e262_is_less_than(&Value::Number(1.5), &big(1), true).unwrap(),
+            Some(false)
+        );
+        assert_eq!(
+            e262_is_less_than(&Value::Number(-1.5), &big(-1), true).unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            e262_is_less_than(&big(1), &Value::Number(f64::INFINITY), true).unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            e262_is_less_than(&Value::Number(f64::NAN), &big(1), true).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn less_than_compares_code_units() {
+        // U+FF61 sorts after U+1F600 by code point, but before it by UTF-16 code unit.
+        assert_eq!(
+            e262_is_less_than(&str("\u{1F600}"), &str("\u{FF61}"), true).unwrap(),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn less_than_respects_evaluation_order() {
+        use std::cell::RefCell;
+
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let logged = |name: &'static str| {
+            let log = log.clone();
+            let obj = e262_ordinary_object_create(None);
+            let func = e262_create_builtin_function(
+                Rc::new(move |_, _| {
+                    log.borrow_mut().push(name);
+                    Ok(Value::Number(0.0))
+                }),
+                0,
+                "",
+                None,
+            );
+            e262_create_data_property(&obj, PropertyKey::from("valueOf"), Value::Object(func))
+                .unwrap();
+            Value::Object(obj)
+        };
+        let (x, y) = (logged("x"), logged("y"));
+
+        e262_is_less_than(&x, &y, true).unwrap();
+        e262_is_less_than(&x, &y, false).unwrap();
+        assert_eq!(*log.borrow(), vec!["x", "y", "y", "x"]);
+    }
+
+    #[test]
+    fn registered_symbols_cannot_be_held_weakly() {
+        use crate::agent::p262_symbol_for;
+        use crate::core::{SymbolRep, WellKnownSymbol};
+
+        assert!(e262_can_be_held_weakly(&object_with_value_of(Value::Null)));
+        assert!(e262_can_be_held_weakly(&Value::Symbol(SymbolRep::anon())));
+        assert!(e262_can_be_held_weakly(&Value::Symbol(
+            SymbolRep::well_known(WellKnownSymbol::Iterator)
+        )));
+        assert!(!e262_can_be_held_weakly(&Value::Symbol(p262_symbol_for(
+            "weak"
+        ))));
+        assert!(!e262_can_be_held_weakly(&str("weak")));
+    }
+}
@@ -0,0 +1,504 @@
+//! Unicode algorithms and properties needed by the built-ins, backed by tables generated from the Unicode Character Database.
+
+#[rustfmt::skip]
+mod tables;
+
+use std::cmp::Ordering;
+
+pub use self::tables::UNICODE_VERSION;
+use self::tables::{
+    BINARY_PROPERTIES, COMBINING_CLASSES, COMPOSITIONS, DECOMPOSITIONS, DECOMPOSITION_DATA,
+    GENERAL_CATEGORIES, GENERAL_CATEGORY_VALUES, LOWERCASE_MAPPINGS, SCRIPTS, SCRIPT_EXTENSIONS,
+    SCRIPT_VALUES, SIMPLE_CASE_FOLDING, STRING_PROPERTIES, UPPERCASE_MAPPINGS,
+};
+
+/// A [normalization form](https://unicode.org/reports/tr15/#Norm_Forms) accepted by `String.prototype.normalize`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NormalizationForm {
+    /// Canonical decomposition followed by canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+impl NormalizationForm {
+    /// Parses the name of a normalization form, e.g. `"NFC"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "NFC" => Some(Self::Nfc),
+            "NFD" => Some(Self::Nfd),
+            "NFKC" => Some(Self::Nfkc),
+            "NFKD" => Some(Self::Nfkd),
+            _ => None,
+        }
+    }
+}
+
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+/// Returns the value of the range of `table` containing `code_point`, if any.
+fn find_range<T: Copy>(table: &[(u32, u32, T)], code_point: u32) -> Option<T> {
+    table
+        .binary_search_by(|&(first, last, _)| {
+            if last < code_point {
+                Ordering::Less
+            } else if first > code_point {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|index| table[index].2)
+}
+
+/// Returns the Canonical_Combining_Class of a code point.
+pub fn p262_combining_class(code_point: u32) -> u8 {
+    find_range(COMBINING_CLASSES, code_point).unwrap_or(0)
+}
+
+/// Appends the full decomposition of `code_point` to `result`, following compatibility mappings when `compatibility` is set.
+fn decompose_into(code_point: u32, compatibility: bool, result: &mut Vec<u32>) {
+    let s_index = code_point.wrapping_sub(HANGUL_S_BASE);
+    if s_index < HANGUL_S_COUNT {
+        result.push(HANGUL_L_BASE + s_index / HANGUL_N_COUNT);
+        result.push(HANGUL_V_BASE + (s_index % HANGUL_N_COUNT) / HANGUL_T_COUNT);
+        if !s_index.is_multiple_of(HANGUL_T_COUNT) {
+            result.push(HANGUL_T_BASE + s_index % HANGUL_T_COUNT);
+        }
+        return;
+    }
+    match DECOMPOSITIONS.binary_search_by_key(&code_point, |&(cp, ..)| cp) {
+        Ok(index) if compatibility || !DECOMPOSITIONS[index].3 => {
+            let (_, offset, len, _) = DECOMPOSITIONS[index];
+            let offset = offset as usize;
+            for &part in &DECOMPOSITION_DATA[offset..offset + len as usize] {
+                decompose_into(part, compatibility, result);
+            }
+        }
+        _ => result.push(code_point),
+    }
+}
+
+/// Returns the primary composite of a pair of code points, if any.
+fn compose_pair(first: u32, second: u32) -> Option<u32> {
+    let l_index = first.wrapping_sub(HANGUL_L_BASE);
+    let v_index = second.wrapping_sub(HANGUL_V_BASE);
+    if l_index < HANGUL_L_COUNT && v_index < HANGUL_V_COUNT {
+        return Some(HANGUL_S_BASE + (l_index * HANGUL_V_COUNT + v_index) * HANGUL_T_COUNT);
+    }
+    let s_index = first.wrapping_sub(HANGUL_S_BASE);
+    let t_index = second.wrapping_sub(HANGUL_T_BASE);
+    if s_index < HANGUL_S_COUNT
+        && s_index.is_multiple_of(HANGUL_T_COUNT)
+        && 0 < t_index
+        && t_index < HANGUL_T_COUNT
+    {
+        return Some(first + t_index);
+    }
+    COMPOSITIONS
+        .binary_search_by_key(&(first, second), |&(a, b, _)| (a, b))
+        .ok()
+        .map(|index| COMPOSITIONS[index].2)
+}
+
+/// Applies the [canonical ordering algorithm](https://www.unicode.org/versions/latest/ch03.pdf#G49591), stably sorting each run of non-starters by combining class.
+fn canonical_order(code_points: &mut [u32]) {
+    let mut start = 0;
+    while start < code_points.len() {
+        if p262_combining_class(code_points[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let end = code_points[start..]
+            .iter()
+            .position(|&cp| p262_combining_class(cp) == 0)
+            .map_or(code_points.len(), |offset| start + offset);
+        code_points[start..end].sort_by_key(|&cp| p262_combining_class(cp));
+        start = end;
+    }
+}
+
+/// Applies the [canonical composition algorithm](https://www.unicode.org/versions/latest/ch03.pdf#G50628) to a decomposed sequence.
+fn compose(code_points: Vec<u32>) -> Vec<u32> {
+    let mut result: Vec<u32> = Vec::with_capacity(code_points.len());
+    let mut starter: Option<usize> = None;
+    // The combining class of the last code point appended after the starter, if any.
+    let mut last_class: Option<u8> = None;
+    for code_point in code_points {
+        let class = p262_combining_class(code_point);
+        if let Some(index) = starter {
+            let unblocked = last_class.is_none_or(|last| last < class);
+            if unblocked {
+                if let Some(composite) = compose_pair(result[index], code_point) {
+                    result[index] = composite;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(result.len());
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        result.push(code_point);
+    }
+    result
+}
+
+/// Normalizes a sequence of code points to the given form, leaving lone surrogates untouched.
+pub fn p262_normalize(
+    code_points: impl IntoIterator<Item = u32>,
+    form: NormalizationForm,
+) -> Vec<u32> {
+    let compatibility = matches!(form, NormalizationForm::Nfkc | NormalizationForm::Nfkd);
+    let mut decomposed = Vec::new();
+    for code_point in code_points {
+        decompose_into(code_point, compatibility, &mut decomposed);
+    }
+    canonical_order(&mut decomposed);
+    match form {
+        NormalizationForm::Nfc | NormalizationForm::Nfkc => compose(decomposed),
+        NormalizationForm::Nfd | NormalizationForm::Nfkd => decomposed,
+    }
+}
+
+/// Collects the ranges of `table` whose value satisfies `predicate`, merging adjacent ones.
+fn collect_ranges<T: Copy>(
+    table: &[(u32, u32, T)],
+    predicate: impl Fn(T) -> bool,
+) -> Vec<(u32, u32)> {
+    let mut result: Vec<(u32, u32)> = Vec::new();
+    for &(first, last, value) in table {
+        if !predicate(value) {
+            continue;
+        }
+        match result.last_mut() {
+            Some(previous) if previous.1 + 1 == first => previous.1 = last,
+            _ => result.push((first, last)),
+        }
+    }
+    result
+}
+
+/// Returns the sorted ranges of code points having a binary property, given its name or alias, e.g. `"Alpha"`.
+pub fn p262_binary_property(name: &str) -> Option<&'static [(u32, u32)]> {
+    BINARY_PROPERTIES
+        .iter()
+        .find(|&&(property, alias, _)| property == name || alias == name)
+        .map(|&(.., ranges)| ranges)
+}
+
+/// Returns if a code point has a binary property, given its name or alias.
+fn has_binary_property(name: &str, code_point: u32) -> bool {
+    let ranges = p262_binary_property(name).expect("The property is always generated");
+    ranges
+        .binary_search_by(|&(first, last)| {
+            if last < code_point {
+                Ordering::Less
+            } else if first > code_point {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Returns if a code point has the ID_Start property, i.e. may start a Unicode identifier.
+pub fn p262_is_id_start(code_point: u32) -> bool {
+    has_binary_property("ID_Start", code_point)
+}
+
+/// Returns if a code point has the ID_Continue property, i.e. may continue a Unicode identifier.
+pub fn p262_is_id_continue(code_point: u32) -> bool {
+    has_binary_property("ID_Continue", code_point)
+}
+
+/// Returns the short name of the General_Category of a code point, e.g. `"Lu"`.
+pub fn p262_general_category_of(code_point: u32) -> &'static str {
+    let index =
+        find_range(GENERAL_CATEGORIES, code_point).expect("Every code point has a category");
+    GENERAL_CATEGORY_VALUES
+        .iter()
+        .find(|&&(_, mask)| mask == 1 << index)
+        .map(|&(aliases, _)| aliases[0])
+        .expect("Every category has a value")
+}
+
+/// Returns the long name of the Script of a code point, e.g. `"Latin"`.
+pub fn p262_script_of(code_point: u32) -> &'static str {
+    find_range(SCRIPTS, code_point).map_or("Unknown", |index| SCRIPT_VALUES[index as usize][1])
+}
+
+/// Returns the sorted ranges of code points of a General_Category value, given any of its aliases, e.g. `"Lu"` or `"Letter"`.
+pub fn p262_general_category(value: &str) -> Option<Vec<(u32, u32)>> {
+    let &(_, mask) = GENERAL_CATEGORY_VALUES
+        .iter()
+        .find(|(aliases, _)| aliases.contains(&value))?;
+    Some(collect_ranges(GENERAL_CATEGORIES, |index| {
+        mask & (1 << index) != 0
+    }))
+}
+
+/// Returns the sorted ranges of code points of a script, given its short or long name, as the Script property or the Script_Extensions property when `extensions` is set.
+pub fn p262_script(value: &str, extensions: bool) -> Option<Vec<(u32, u32)>> {
+    let script = SCRIPT_VALUES
+        .iter()
+        .position(|aliases| aliases.contains(&value))? as u8;
+    Some(if extensions {
+        collect_ranges(SCRIPT_EXTENSIONS, |scripts| scripts.contains(&script))
+    } else {
+        collect_ranges(SCRIPTS, |index| index == script)
+    })
+}
+
+/// Returns the sequences of code points of a [property of strings](https://tc39.es/ecma262/multipage/text-processing.html#table-binary-unicode-properties-of-strings), given its name.
+pub fn p262_string_property(name: &str) -> Option<Vec<&'static [u32]>> {
+    // RGI_Emoji is the union of all the other properties.
+    if name == "RGI_Emoji" {
+        let sequences = STRING_PROPERTIES
+            .iter()
+            .flat_map(|&(_, members)| members.iter().copied());
+        return Some(sequences.collect());
+    }
+    STRING_PROPERTIES
+        .iter()
+        .find(|&&(property, _)| property == name)
+        .map(|&(_, members)| members.to_vec())
+}
+
+/// Returns the Simple_Case_Folding of a code point.
+pub fn p262_simple_case_folding(code_point: u32) -> u32 {
+    SIMPLE_CASE_FOLDING
+        .binary_search_by_key(&code_point, |&(cp, _)| cp)
+        .map_or(code_point, |index| SIMPLE_CASE_FOLDING[index].1)
+}
+
+/// Returns every code point whose Simple_Case_Folding is not itself, with its folding.
+pub fn p262_simple_case_foldings() -> impl Iterator<Item = (u32, u32)> {
+    SIMPLE_CASE_FOLDING.iter().copied()
+}
+
+/// Returns the full uppercase mapping of a code point, or `None` if it maps to itself.
+pub fn p262_uppercase_mapping(code_point: u32) -> Option<&'static [u32]> {
+    UPPERCASE_MAPPINGS
+        .binary_search_by_key(&code_point, |&(cp, _)| cp)
+        .ok()
+        .map(|index| UPPERCASE_MAPPINGS[index].1)
+}
+
+/// Returns every code point whose full uppercase mapping is not itself, with its mapping.
+pub fn p262_uppercase_mappings() -> impl Iterator<Item = (u32, &'static [u32])> {
+    UPPERCASE_MAPPINGS.iter().copied()
+}
+
+/// Returns the full lowercase mapping of a code point, or `None` if it maps to itself, leaving out the contextual mapping of a final sigma.
+pub fn p262_lowercase_mapping(code_point: u32) -> Option<&'static [u32]> {
+    LOWERCASE_MAPPINGS
+        .binary_search_by_key(&code_point, |&(cp, _)| cp)
+        .ok()
+        .map(|index| LOWERCASE_MAPPINGS[index].1)
+}
+
+/// Returns if the code point at `index` ends a word, following the [Final_Sigma](https://www.unicode.org/versions/latest/ch03.pdf#G54277) casing context.
+fn is_final_sigma(code_points: &[u32], index: usize) -> bool {
+    let is_ignorable = |cp: &&u32| has_binary_property("Case_Ignorable", **cp);
+    let is_cased = |cp: Option<&u32>| cp.is_some_and(|&cp| has_binary_property("Cased", cp));
+    let mut before = code_points[..index].iter().rev().skip_while(is_ignorable);
+    let mut after = code_points[index + 1..].iter().skip_while(is_ignorable);
+    is_cased(before.next()) && !is_cased(after.next())
+}
+
+/// Converts code points to uppercase with the full, locale-independent [default case conversion](https://www.unicode.org/versions/latest/ch03.pdf#G33992).
+pub fn p262_to_uppercase(code_points: impl IntoIterator<Item = u32>) -> Vec<u32> {
+    let mut result = Vec::new();
+    for code_point in code_points {
+        match p262_uppercase_mapping(code_point) {
+            Some(mapping) => result.extend_from_slice(mapping),
+            None => result.push(code_point),
+        }
+    }
+    result
+}
+
+/// Converts code points to lowercase with the full, locale-independent [default case conversion](https://www.unicode.org/versions/latest/ch03.pdf#G33992), including the mapping of a final sigma.
+pub fn p262_to_lowercase(code_points: impl IntoIterator<Item = u32>) -> Vec<u32> {
+    const CAPITAL_SIGMA: u32 = 0x3A3;
+    const FINAL_SIGMA: u32 = 0x3C2;
+
+    let code_points: Vec<u32> = code_points.into_iter().collect();
+    let mut result = Vec::with_capacity(code_points.len());
+    for (index, &code_point) in code_points.iter().enumerate() {
+        if code_point == CAPITAL_SIGMA && is_final_sigma(&code_points, index) {
+            result.push(FINAL_SIGMA);
+            continue;
+        }
+        match p262_lowercase_mapping(code_point) {
+            Some(mapping) => result.extend_from_slice(mapping),
+            None => result.push(code_point),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(text: &str, form: NormalizationForm) -> String {
+        p262_normalize(text.chars().map(|c| c as u32), form)
+            .into_iter()
+            .map(|cp| char::from_u32(cp).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn decomposes_and_composes() {
+        assert_eq!(normalize("\u{E9}", NormalizationForm::Nfd), "e\u{301}");
+        assert_eq!(normalize("e\u{301}", NormalizationForm::Nfc), "\u{E9}");
+        assert_eq!(
+            normalize("\u{1E0B}\u{323}", NormalizationForm::Nfc),
+            "\u{1E0D}\u{307}"
+        );
+        assert_eq!(
+            normalize("q\u{307}\u{323}", NormalizationForm::Nfd),
+            "q\u{323}\u{307}"
+        );
+        assert_eq!(normalize("\u{FB01}", NormalizationForm::Nfc), "\u{FB01}");
+        assert_eq!(normalize("\u{FB01}", NormalizationForm::Nfkc), "fi");
+        assert_eq!(normalize("\u{2126}", NormalizationForm::Nfc), "\u{3A9}");
+    }
+
+    #[test]
+    fn handles_hangul_algorithmically() {
+        assert_eq!(
+            normalize("\u{D4DB}", NormalizationForm::Nfd),
+            "\u{1111}\u{1171}\u{11B6}"
+        );
+        assert_eq!(
+            normalize("\u{1111}\u{1171}\u{11B6}", NormalizationForm::Nfc),
+            "\u{D4DB}"
+        );
+    }
+
+    #[test]
+    fn looks_up_properties() {
+        let alpha = p262_binary_property("Alpha").unwrap();
+        assert_eq!(p262_binary_property("Alphabetic"), Some(alpha));
+        assert!(alpha
+            .iter()
+            .any(|&(first, last)| first <= 0xE9 && 0xE9 <= last));
+        assert!(p262_binary_property("Alphabet").is_none());
+        assert_eq!(p262_general_category("Nd").unwrap()[0], (0x30, 0x39));
+        assert_eq!(
+            p262_general_category("digit"),
+            p262_general_category("Decimal_Number")
+        );
+        let letters = p262_general_category("L").unwrap();
+        assert!(letters.contains(&(0x41, 0x5A)) && letters.contains(&(0x61, 0x7A)));
+        let greek = p262_script("Greek", false).unwrap();
+        assert!(greek
+            .iter()
+            .any(|&(first, last)| first <= 0x3B1 && 0x3B1 <= last));
+        let in_script = |cp: u32, extensions| {
+            p262_script("Deva", extensions)
+                .unwrap()
+                .iter()
+                .any(|&(first, last)| first <= cp && cp <= last)
+        };
+        assert!(!in_script(0x964, false) && in_script(0x964, true));
+        assert!(p262_script("Klingon", false).is_none());
+    }
+
+    #[test]
+    fn maps_cases() {
+        assert_eq!(p262_simple_case_folding('A' as u32), 'a' as u32);
+        assert_eq!(p262_simple_case_folding(0x1E9E), 0xDF);
+        assert_eq!(p262_simple_case_folding(0x17F), 's' as u32);
+        assert_eq!(p262_simple_case_folding('a' as u32), 'a' as u32);
+        assert_eq!(p262_uppercase_mapping(0xDF), Some(&[0x53, 0x53][..]));
+        assert_eq!(p262_uppercase_mapping('A' as u32), None);
+        assert_eq!(p262_lowercase_mapping(0x130), Some(&[0x69, 0x307][..]));
+        assert_eq!(p262_lowercase_mapping('a' as u32), None);
+        // Added in Unicode 16.
+        assert_eq!(p262_lowercase_mapping(0xA7CB), Some(&[0x264][..]));
+        assert_eq!(p262_uppercase_mapping(0x264), Some(&[0xA7CB][..]));
+        assert_eq!(p262_simple_case_folding(0xA7DC), 0x19B);
+    }
+
+    #[test]
+    fn converts_cases() {
+        let convert = |text: &str, upper: bool| -> String {
+            let code_points = text.chars().map(|c| c as u32);
+            let mapped = if upper {
+                p262_to_uppercase(code_points)
+            } else {
+                p262_to_lowercase(code_points)
+            };
+            mapped
+                .into_iter()
+                .map(|cp| char::from_u32(cp).unwrap())
+                .collect()
+        };
+        assert_eq!(convert("stra\u{DF}e", true), "STRASSE");
+        assert_eq!(convert("\u{1F0}", true), "J\u{30C}");
+        assert_eq!(convert("\u{39F}\u{3A3}", false), "\u{3BF}\u{3C2}");
+        assert_eq!(convert("\u{39F}\u{3A3}'.", false), "\u{3BF}\u{3C2}'.");
+        assert_eq!(convert("\u{3A3}\u{39F}", false), "\u{3C3}\u{3BF}");
+        assert_eq!(
+            convert("\u{39F}\u{3A3}'\u{39F}", false),
+            "\u{3BF}\u{3C3}'\u{3BF}"
+        );
+        assert_eq!(convert("\u{3A3}", false), "\u{3C3}");
+    }
+
+    #[test]
+    fn classifies_code_points() {
+        assert!(p262_is_id_start('a' as u32) && p262_is_id_start(0x3B1));
+        assert!(!p262_is_id_start('1' as u32) && p262_is_id_continue('1' as u32));
+        assert!(!p262_is_id_start('$' as u32) && !p262_is_id_continue('-' as u32));
+        assert!(p262_is_id_continue(0x301) && !p262_is_id_start(0x301));
+        assert_eq!(p262_general_category_of('A' as u32), "Lu");
+        assert_eq!(p262_general_category_of(0x301), "Mn");
+        assert_eq!(p262_general_category_of(0xD800), "Cs");
+        assert_eq!(p262_general_category_of(0x10FFFF), "Cn");
+        assert_eq!(p262_script_of('a' as u32), "Latin");
+        assert_eq!(p262_script_of(0x3B1), "Greek");
+        assert_eq!(p262_script_of(0x10FFFF), "Unknown");
+        assert_eq!(UNICODE_VERSION, (17, 0, 0));
+    }
+
+    #[test]
+    fn lists_sequences() {
+        let keycaps = p262_string_property("Emoji_Keycap_Sequence").unwrap();
+        assert_eq!(keycaps.len(), 12);
+        let emoji = p262_string_property("RGI_Emoji").unwrap();
+        assert_eq!(emoji.len(), 3953);
+        let family = [0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F466];
+        assert!(emoji.contains(&&family[..]));
+        let zwj = p262_string_property("RGI_Emoji_ZWJ_Sequence").unwrap();
+        assert!(zwj.contains(&&family[..]) && zwj.is_sorted());
+        assert!(p262_string_property("Emoji").is_none());
+    }
+
+    #[test]
+    fn looks_up_combining_classes() {
+        assert_eq!(p262_combining_class('a' as u32), 0);
+        assert_eq!(p262_combining_class(0x301), 230);
+        assert_eq!(p262_combining_class(0x323), 220);
+    }
+}
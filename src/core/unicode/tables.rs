@@ -0,0 +1,9233 @@
+// Generated by tools/unicode_tables.py, do not edit by hand.
+
+/// The version of the Unicode Character Database the tables are generated from.
+pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+/// Ranges of code points with a non-zero Canonical_Combining_Class, as `(first, last, class)`.
+pub(super) static COMBINING_CLASSES: &[(u32, u32, u8)] = &[
+    (0x300, 0x314, 230), (0x315, 0x315, 232), (0x316, 0x319, 220), (0x31A, 0x31A, 232),
+    (0x31B, 0x31B, 216), (0x31C, 0x320, 220), (0x321, 0x322, 202), (0x323, 0x326, 220),
+    (0x327, 0x328, 202), (0x329, 0x333, 220), (0x334, 0x338, 1), (0x339, 0x33C, 220),
+    (0x33D, 0x344, 230), (0x345, 0x345, 240), (0x346, 0x346, 230), (0x347, 0x349, 220),
+    (0x34A, 0x34C, 230), (0x34D, 0x34E, 220), (0x350, 0x352, 230), (0x353, 0x356, 220),
+    (0x357, 0x357, 230), (0x358, 0x358, 232), (0x359, 0x35A, 220), (0x35B, 0x35B, 230),
+    (0x35C, 0x35C, 233), (0x35D, 0x35E, 234), (0x35F, 0x35F, 233), (0x360, 0x361, 234),
+    (0x362, 0x362, 233), (0x363, 0x36F, 230), (0x483, 0x487, 230), (0x591, 0x591, 220),
+    (0x592, 0x595, 230), (0x596, 0x596, 220), (0x597, 0x599, 230), (0x59A, 0x59A, 222),
+    (0x59B, 0x59B, 220), (0x59C, 0x5A1, 230), (0x5A2, 0x5A7, 220), (0x5A8, 0x5A9, 230),
+    (0x5AA, 0x5AA, 220), (0x5AB, 0x5AC, 230), (0x5AD, 0x5AD, 222), (0x5AE, 0x5AE, 228),
+    (0x5AF, 0x5AF, 230), (0x5B0, 0x5B0, 10), (0x5B1, 0x5B1, 11), (0x5B2, 0x5B2, 12),
+    (0x5B3, 0x5B3, 13), (0x5B4, 0x5B4, 14), (0x5B5, 0x5B5, 15), (0x5B6, 0x5B6, 16),
+    (0x5B7, 0x5B7, 17), (0x5B8, 0x5B8, 18), (0x5B9, 0x5BA, 19), (0x5BB, 0x5BB, 20),
+    (0x5BC, 0x5BC, 21), (0x5BD, 0x5BD, 22), (0x5BF, 0x5BF, 23), (0x5C1, 0x5C1, 24),
+    (0x5C2, 0x5C2, 25), (0x5C4, 0x5C4, 230), (0x5C5, 0x5C5, 220), (0x5C7, 0x5C7, 18),
+    (0x610, 0x617, 230), (0x618, 0x618, 30), (0x619, 0x619, 31), (0x61A, 0x61A, 32),
+    (0x64B, 0x64B, 27), (0x64C, 0x64C, 28), (0x64D, 0x64D, 29), (0x64E, 0x64E, 30),
+    (0x64F, 0x64F, 31), (0x650, 0x650, 32), (0x651, 0x651, 33), (0x652, 0x652, 34),
+    (0x653, 0x654, 230), (0x655, 0x656, 220), (0x657, 0x65B, 230), (0x65C, 0x65C, 220),
+    (0x65D, 0x65E, 230), (0x65F, 0x65F, 220), (0x670, 0x670, 35), (0x6D6, 0x6DC, 230),
+    (0x6DF, 0x6E2, 230), (0x6E3, 0x6E3, 220), (0x6E4, 0x6E4, 230), (0x6E7, 0x6E8, 230),
+    (0x6EA, 0x6EA, 220), (0x6EB, 0x6EC, 230), (0x6ED, 0x6ED, 220), (0x711, 0x711, 36),
+    (0x730, 0x730, 230), (0x731, 0x731, 220), (0x732, 0x733, 230), (0x734, 0x734, 220),
+    (0x735, 0x736, 230), (0x737, 0x739, 220), (0x73A, 0x73A, 230), (0x73B, 0x73C, 220),
+    (0x73D, 0x73D, 230), (0x73E, 0x73E, 220), (0x73F, 0x741, 230), (0x742, 0x742, 220),
+    (0x743, 0x743, 230), (0x744, 0x744, 220), (0x745, 0x745, 230), (0x746, 0x746, 220),
+    (0x747, 0x747, 230), (0x748, 0x748, 220), (0x749, 0x74A, 230), (0x7EB, 0x7F1, 230),
+    (0x7F2, 0x7F2, 220), (0x7F3, 0x7F3, 230), (0x7FD, 0x7FD, 220), (0x816, 0x819, 230),
+    (0x81B, 0x823, 230), (0x825, 0x827, 230), (0x829, 0x82D, 230), (0x859, 0x85B, 220),
+    (0x897, 0x898, 230), (0x899, 0x89B, 220), (0x89C, 0x89F, 230), (0x8CA, 0x8CE, 230),
+    (0x8CF, 0x8D3, 220), (0x8D4, 0x8E1, 230), (0x8E3, 0x8E3, 220), (0x8E4, 0x8E5, 230),
+    (0x8E6, 0x8E6, 220), (0x8E7, 0x8E8, 230), (0x8E9, 0x8E9, 220), (0x8EA, 0x8EC, 230),
+    (0x8ED, 0x8EF, 220), (0x8F0, 0x8F0, 27), (0x8F1, 0x8F1, 28), (0x8F2, 0x8F2, 29),
+    (0x8F3, 0x8F5, 230), (0x8F6, 0x8F6, 220), (0x8F7, 0x8F8, 230), (0x8F9, 0x8FA, 220),
+    (0x8FB, 0x8FF, 230), (0x93C, 0x93C, 7), (0x94D, 0x94D, 9), (0x951, 0x951, 230),
+    (0x952, 0x952, 220), (0x953, 0x954, 230), (0x9BC, 0x9BC, 7), (0x9CD, 0x9CD, 9),
+    (0x9FE, 0x9FE, 230), (0xA3C, 0xA3C, 7), (0xA4D, 0xA4D, 9), (0xABC, 0xABC, 7),
+    (0xACD, 0xACD, 9), (0xB3C, 0xB3C, 7), (0xB4D, 0xB4D, 9), (0xBCD, 0xBCD, 9),
+    (0xC3C, 0xC3C, 7), (0xC4D, 0xC4D, 9), (0xC55, 0xC55, 84), (0xC56, 0xC56, 91),
+    (0xCBC, 0xCBC, 7), (0xCCD, 0xCCD, 9), (0xD3B, 0xD3C, 9), (0xD4D, 0xD4D, 9),
+    (0xDCA, 0xDCA, 9), (0xE38, 0xE39, 103), (0xE3A, 0xE3A, 9), (0xE48, 0xE4B, 107),
+    (0xEB8, 0xEB9, 118), (0xEBA, 0xEBA, 9), (0xEC8, 0xECB, 122), (0xF18, 0xF19, 220),
+    (0xF35, 0xF35, 220), (0xF37, 0xF37, 220), (0xF39, 0xF39, 216), (0xF71, 0xF71, 129),
+    (0xF72, 0xF72, 130), (0xF74, 0xF74, 132), (0xF7A, 0xF7D, 130), (0xF80, 0xF80, 130),
+    (0xF82, 0xF83, 230), (0xF84, 0xF84, 9), (0xF86, 0xF87, 230), (0xFC6, 0xFC6, 220),
+    (0x1037, 0x1037, 7), (0x1039, 0x103A, 9), (0x108D, 0x108D, 220), (0x135D, 0x135F, 230),
+    (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17D2, 0x17D2, 9), (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228), (0x1939, 0x1939, 222), (0x193A, 0x193A, 230), (0x193B, 0x193B, 220),
+    (0x1A17, 0x1A17, 230), (0x1A18, 0x1A18, 220), (0x1A60, 0x1A60, 9), (0x1A75, 0x1A7C, 230),
+    (0x1A7F, 0x1A7F, 220), (0x1AB0, 0x1AB4, 230), (0x1AB5, 0x1ABA, 220), (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220), (0x1ABF, 0x1AC0, 220), (0x1AC1, 0x1AC2, 230), (0x1AC3, 0x1AC4, 220),
+    (0x1AC5, 0x1AC9, 230), (0x1ACA, 0x1ACA, 220), (0x1ACB, 0x1ADC, 230), (0x1ADD, 0x1ADD, 220),
+    (0x1AE0, 0x1AE5, 230), (0x1AE6, 0x1AE6, 220), (0x1AE7, 0x1AEA, 230), (0x1AEB, 0x1AEB, 234),
+    (0x1B34, 0x1B34, 7), (0x1B44, 0x1B44, 9), (0x1B6B, 0x1B6B, 230), (0x1B6C, 0x1B6C, 220),
+    (0x1B6D, 0x1B73, 230), (0x1BAA, 0x1BAB, 9), (0x1BE6, 0x1BE6, 7), (0x1BF2, 0x1BF3, 9),
+    (0x1C37, 0x1C37, 7), (0x1CD0, 0x1CD2, 230), (0x1CD4, 0x1CD4, 1), (0x1CD5, 0x1CD9, 220),
+    (0x1CDA, 0x1CDB, 230), (0x1CDC, 0x1CDF, 220), (0x1CE0, 0x1CE0, 230), (0x1CE2, 0x1CE8, 1),
+    (0x1CED, 0x1CED, 220), (0x1CF4, 0x1CF4, 230), (0x1CF8, 0x1CF9, 230), (0x1DC0, 0x1DC1, 230),
+    (0x1DC2, 0x1DC2, 220), (0x1DC3, 0x1DC9, 230), (0x1DCA, 0x1DCA, 220), (0x1DCB, 0x1DCC, 230),
+    (0x1DCD, 0x1DCD, 234), (0x1DCE, 0x1DCE, 214), (0x1DCF, 0x1DCF, 220), (0x1DD0, 0x1DD0, 202),
+    (0x1DD1, 0x1DF5, 230), (0x1DF6, 0x1DF6, 232), (0x1DF7, 0x1DF8, 228), (0x1DF9, 0x1DF9, 220),
+    (0x1DFA, 0x1DFA, 218), (0x1DFB, 0x1DFB, 230), (0x1DFC, 0x1DFC, 233), (0x1DFD, 0x1DFD, 220),
+    (0x1DFE, 0x1DFE, 230), (0x1DFF, 0x1DFF, 220), (0x20D0, 0x20D1, 230), (0x20D2, 0x20D3, 1),
+    (0x20D4, 0x20D7, 230), (0x20D8, 0x20DA, 1), (0x20DB, 0x20DC, 230), (0x20E1, 0x20E1, 230),
+    (0x20E5, 0x20E6, 1), (0x20E7, 0x20E7, 230), (0x20E8, 0x20E8, 220), (0x20E9, 0x20E9, 230),
+    (0x20EA, 0x20EB, 1), (0x20EC, 0x20EF, 220), (0x20F0, 0x20F0, 230), (0x2CEF, 0x2CF1, 230),
+    (0x2D7F, 0x2D7F, 9), (0x2DE0, 0x2DFF, 230), (0x302A, 0x302A, 218), (0x302B, 0x302B, 228),
+    (0x302C, 0x302C, 232), (0x302D, 0x302D, 222), (0x302E, 0x302F, 224), (0x3099, 0x309A, 8),
+    (0xA66F, 0xA66F, 230), (0xA674, 0xA67D, 230), (0xA69E, 0xA69F, 230), (0xA6F0, 0xA6F1, 230),
+    (0xA806, 0xA806, 9), (0xA82C, 0xA82C, 9), (0xA8C4, 0xA8C4, 9), (0xA8E0, 0xA8F1, 230),
+    (0xA92B, 0xA92D, 220), (0xA953, 0xA953, 9), (0xA9B3, 0xA9B3, 7), (0xA9C0, 0xA9C0, 9),
+    (0xAAB0, 0xAAB0, 230), (0xAAB2, 0xAAB3, 230), (0xAAB4, 0xAAB4, 220), (0xAAB7, 0xAAB8, 230),
+    (0xAABE, 0xAABF, 230), (0xAAC1, 0xAAC1, 230), (0xAAF6, 0xAAF6, 9), (0xABED, 0xABED, 9),
+    (0xFB1E, 0xFB1E, 26), (0xFE20, 0xFE26, 230), (0xFE27, 0xFE2D, 220), (0xFE2E, 0xFE2F, 230),
+    (0x101FD, 0x101FD, 220), (0x102E0, 0x102E0, 220), (0x10376, 0x1037A, 230), (0x10A0D, 0x10A0D, 220),
+    (0x10A0F, 0x10A0F, 230), (0x10A38, 0x10A38, 230), (0x10A39, 0x10A39, 1), (0x10A3A, 0x10A3A, 220),
+    (0x10A3F, 0x10A3F, 9), (0x10AE5, 0x10AE5, 230), (0x10AE6, 0x10AE6, 220), (0x10D24, 0x10D27, 230),
+    (0x10D69, 0x10D6D, 230), (0x10EAB, 0x10EAC, 230), (0x10EFA, 0x10EFB, 220), (0x10EFD, 0x10EFF, 220),
+    (0x10F46, 0x10F47, 220), (0x10F48, 0x10F4A, 230), (0x10F4B, 0x10F4B, 220), (0x10F4C, 0x10F4C, 230),
+    (0x10F4D, 0x10F50, 220), (0x10F82, 0x10F82, 230), (0x10F83, 0x10F83, 220), (0x10F84, 0x10F84, 230),
+    (0x10F85, 0x10F85, 220), (0x11046, 0x11046, 9), (0x11070, 0x11070, 9), (0x1107F, 0x1107F, 9),
+    (0x110B9, 0x110B9, 9), (0x110BA, 0x110BA, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7), (0x111C0, 0x111C0, 9), (0x111CA, 0x111CA, 7), (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7), (0x112E9, 0x112E9, 7), (0x112EA, 0x112EA, 9), (0x1133B, 0x1133C, 7),
+    (0x1134D, 0x1134D, 9), (0x11366, 0x1136C, 230), (0x11370, 0x11374, 230), (0x113CE, 0x113D0, 9),
+    (0x11442, 0x11442, 9), (0x11446, 0x11446, 7), (0x1145E, 0x1145E, 230), (0x114C2, 0x114C2, 9),
+    (0x114C3, 0x114C3, 7), (0x115BF, 0x115BF, 9), (0x115C0, 0x115C0, 7), (0x1163F, 0x1163F, 9),
+    (0x116B6, 0x116B6, 9), (0x116B7, 0x116B7, 7), (0x1172B, 0x1172B, 9), (0x11839, 0x11839, 9),
+    (0x1183A, 0x1183A, 7), (0x1193D, 0x1193E, 9), (0x11943, 0x11943, 7), (0x119E0, 0x119E0, 9),
+    (0x11A34, 0x11A34, 9), (0x11A47, 0x11A47, 9), (0x11A99, 0x11A99, 9), (0x11C3F, 0x11C3F, 9),
+    (0x11D42, 0x11D42, 7), (0x11D44, 0x11D45, 9), (0x11D97, 0x11D97, 9), (0x11F41, 0x11F42, 9),
+    (0x1612F, 0x1612F, 9), (0x16AF0, 0x16AF4, 1), (0x16B30, 0x16B36, 230), (0x16FF0, 0x16FF1, 6),
+    (0x1BC9E, 0x1BC9E, 1), (0x1D165, 0x1D166, 216), (0x1D167, 0x1D169, 1), (0x1D16D, 0x1D16D, 226),
+    (0x1D16E, 0x1D172, 216), (0x1D17B, 0x1D182, 220), (0x1D185, 0x1D189, 230), (0x1D18A, 0x1D18B, 220),
+    (0x1D1AA, 0x1D1AD, 230), (0x1D242, 0x1D244, 230), (0x1E000, 0x1E006, 230), (0x1E008, 0x1E018, 230),
+    (0x1E01B, 0x1E021, 230), (0x1E023, 0x1E024, 230), (0x1E026, 0x1E02A, 230), (0x1E08F, 0x1E08F, 230),
+    (0x1E130, 0x1E136, 230), (0x1E2AE, 0x1E2AE, 230), (0x1E2EC, 0x1E2EF, 230), (0x1E4EC, 0x1E4ED, 232),
+    (0x1E4EE, 0x1E4EE, 220), (0x1E4EF, 0x1E4EF, 230), (0x1E5EE, 0x1E5EE, 230), (0x1E5EF, 0x1E5EF, 220),
+    (0x1E6E3, 0x1E6E3, 230), (0x1E6E6, 0x1E6E6, 230), (0x1E6EE, 0x1E6EF, 230), (0x1E6F5, 0x1E6F5, 230),
+    (0x1E8D0, 0x1E8D6, 220), (0x1E944, 0x1E949, 230), (0x1E94A, 0x1E94A, 7),
+];
+
+/// The Decomposition_Mapping of each code point except Hangul syllables, as
+/// `(code point, offset in DECOMPOSITION_DATA, length, is compatibility mapping)`.
+pub(super) static DECOMPOSITIONS: &[(u32, u16, u8, bool)] = &[
+    (0xA0, 0, 1, true), (0xA8, 1, 2, true), (0xAA, 3, 1, true), (0xAF, 4, 2, true),
+    (0xB2, 6, 1, true), (0xB3, 7, 1, true), (0xB4, 8, 2, true), (0xB5, 10, 1, true),
+    (0xB8, 11, 2, true), (0xB9, 13, 1, true), (0xBA, 14, 1, true), (0xBC, 15, 3, true),
+    (0xBD, 18, 3, true), (0xBE, 21, 3, true), (0xC0, 24, 2, false), (0xC1, 26, 2, false),
+    (0xC2, 28, 2, false), (0xC3, 30, 2, false), (0xC4, 32, 2, false), (0xC5, 34, 2, false),
+    (0xC7, 36, 2, false), (0xC8, 38, 2, false), (0xC9, 40, 2, false), (0xCA, 42, 2, false),
+    (0xCB, 44, 2, false), (0xCC, 46, 2, false), (0xCD, 48, 2, false), (0xCE, 50, 2, false),
+    (0xCF, 52, 2, false), (0xD1, 54, 2, false), (0xD2, 56, 2, false), (0xD3, 58, 2, false),
+    (0xD4, 60, 2, false), (0xD5, 62, 2, false), (0xD6, 64, 2, false), (0xD9, 66, 2, false),
+    (0xDA, 68, 2, false), (0xDB, 70, 2, false), (0xDC, 72, 2, false), (0xDD, 74, 2, false),
+    (0xE0, 76, 2, false), (0xE1, 78, 2, false), (0xE2, 80, 2, false), (0xE3, 82, 2, false),
+    (0xE4, 84, 2, false), (0xE5, 86, 2, false), (0xE7, 88, 2, false), (0xE8, 90, 2, false),
+    (0xE9, 92, 2, false), (0xEA, 94, 2, false), (0xEB, 96, 2, false), (0xEC, 98, 2, false),
+    (0xED, 100, 2, false), (0xEE, 102, 2, false), (0xEF, 104, 2, false), (0xF1, 106, 2, false),
+    (0xF2, 108, 2, false), (0xF3, 110, 2, false), (0xF4, 112, 2, false), (0xF5, 114, 2, false),
+    (0xF6, 116, 2, false), (0xF9, 118, 2, false), (0xFA, 120, 2, false), (0xFB, 122, 2, false),
+    (0xFC, 124, 2, false), (0xFD, 126, 2, false), (0xFF, 128, 2, false), (0x100, 130, 2, false),
+    (0x101, 132, 2, false), (0x102, 134, 2, false), (0x103, 136, 2, false), (0x104, 138, 2, false),
+    (0x105, 140, 2, false), (0x106, 142, 2, false), (0x107, 144, 2, false), (0x108, 146, 2, false),
+    (0x109, 148, 2, false), (0x10A, 150, 2, false), (0x10B, 152, 2, false), (0x10C, 154, 2, false),
+    (0x10D, 156, 2, false), (0x10E, 158, 2, false), (0x10F, 160, 2, false), (0x112, 162, 2, false),
+    (0x113, 164, 2, false), (0x114, 166, 2, false), (0x115, 168, 2, false), (0x116, 170, 2, false),
+    (0x117, 172, 2, false), (0x118, 174, 2, false), (0x119, 176, 2, false), (0x11A, 178, 2, false),
+    (0x11B, 180, 2, false), (0x11C, 182, 2, false), (0x11D, 184, 2, false), (0x11E, 186, 2, false),
+    (0x11F, 188, 2, false), (0x120, 190, 2, false), (0x121, 192, 2, false), (0x122, 194, 2, false),
+    (0x123, 196, 2, false), (0x124, 198, 2, false), (0x125, 200, 2, false), (0x128, 202, 2, false),
+    (0x129, 204, 2, false), (0x12A, 206, 2, false), (0x12B, 208, 2, false), (0x12C, 210, 2, false),
+    (0x12D, 212, 2, false), (0x12E, 214, 2, false), (0x12F, 216, 2, false), (0x130, 218, 2, false),
+    (0x132, 220, 2, true), (0x133, 222, 2, true), (0x134, 224, 2, false), (0x135, 226, 2, false),
+    (0x136, 228, 2, false), (0x137, 230, 2, false), (0x139, 232, 2, false), (0x13A, 234, 2, false),
+    (0x13B, 236, 2, false), (0x13C, 238, 2, false), (0x13D, 240, 2, false), (0x13E, 242, 2, false),
+    (0x13F, 244, 2, true), (0x140, 246, 2, true), (0x143, 248, 2, false), (0x144, 250, 2, false),
+    (0x145, 252, 2, false), (0x146, 254, 2, false), (0x147, 256, 2, false), (0x148, 258, 2, false),
+    (0x149, 260, 2, true), (0x14C, 262, 2, false), (0x14D, 264, 2, false), (0x14E, 266, 2, false),
+    (0x14F, 268, 2, false), (0x150, 270, 2, false), (0x151, 272, 2, false), (0x154, 274, 2, false),
+    (0x155, 276, 2, false), (0x156, 278, 2, false), (0x157, 280, 2, false), (0x158, 282, 2, false),
+    (0x159, 284, 2, false), (0x15A, 286, 2, false), (0x15B, 288, 2, false), (0x15C, 290, 2, false),
+    (0x15D, 292, 2, false), (0x15E, 294, 2, false), (0x15F, 296, 2, false), (0x160, 298, 2, false),
+    (0x161, 300, 2, false), (0x162, 302, 2, false), (0x163, 304, 2, false), (0x164, 306, 2, false),
+    (0x165, 308, 2, false), (0x168, 310, 2, false), (0x169, 312, 2, false), (0x16A, 314, 2, false),
+    (0x16B, 316, 2, false), (0x16C, 318, 2, false), (0x16D, 320, 2, false), (0x16E, 322, 2, false),
+    (0x16F, 324, 2, false), (0x170, 326, 2, false), (0x171, 328, 2, false), (0x172, 330, 2, false),
+    (0x173, 332, 2, false), (0x174, 334, 2, false), (0x175, 336, 2, false), (0x176, 338, 2, false),
+    (0x177, 340, 2, false), (0x178, 342, 2, false), (0x179, 344, 2, false), (0x17A, 346, 2, false),
+    (0x17B, 348, 2, false), (0x17C, 350, 2, false), (0x17D, 352, 2, false), (0x17E, 354, 2, false),
+    (0x17F, 356, 1, true), (0x1A0, 357, 2, false), (0x1A1, 359, 2, false), (0x1AF, 361, 2, false),
+    (0x1B0, 363, 2, false), (0x1C4, 365, 2, true), (0x1C5, 367, 2, true), (0x1C6, 369, 2, true),
+    (0x1C7, 371, 2, true), (0x1C8, 373, 2, true), (0x1C9, 375, 2, true), (0x1CA, 377, 2, true),
+    (0x1CB, 379, 2, true), (0x1CC, 381, 2, true), (0x1CD, 383, 2, false), (0x1CE, 385, 2, false),
+    (0x1CF, 387, 2, false), (0x1D0, 389, 2, false), (0x1D1, 391, 2, false), (0x1D2, 393, 2, false),
+    (0x1D3, 395, 2, false), (0x1D4, 397, 2, false), (0x1D5, 399, 2, false), (0x1D6, 401, 2, false),
+    (0x1D7, 403, 2, false), (0x1D8, 405, 2, false), (0x1D9, 407, 2, false), (0x1DA, 409, 2, false),
+    (0x1DB, 411, 2, false), (0x1DC, 413, 2, false), (0x1DE, 415, 2, false), (0x1DF, 417, 2, false),
+    (0x1E0, 419, 2, false), (0x1E1, 421, 2, false), (0x1E2, 423, 2, false), (0x1E3, 425, 2, false),
+    (0x1E6, 427, 2, false), (0x1E7, 429, 2, false), (0x1E8, 431, 2, false), (0x1E9, 433, 2, false),
+    (0x1EA, 435, 2, false), (0x1EB, 437, 2, false), (0x1EC, 439, 2, false), (0x1ED, 441, 2, false),
+    (0x1EE, 443, 2, false), (0x1EF, 445, 2, false), (0x1F0, 447, 2, false), (0x1F1, 449, 2, true),
+    (0x1F2, 451, 2, true), (0x1F3, 453, 2, true), (0x1F4, 455, 2, false), (0x1F5, 457, 2, false),
+    (0x1F8, 459, 2, false), (0x1F9, 461, 2, false), (0x1FA, 463, 2, false), (0x1FB, 465, 2, false),
+    (0x1FC, 467, 2, false), (0x1FD, 469, 2, false), (0x1FE, 471, 2, false), (0x1FF, 473, 2, false),
+    (0x200, 475, 2, false), (0x201, 477, 2, false), (0x202, 479, 2, false), (0x203, 481, 2, false),
+    (0x204, 483, 2, false), (0x205, 485, 2, false), (0x206, 487, 2, false), (0x207, 489, 2, false),
+    (0x208, 491, 2, false), (0x209, 493, 2, false), (0x20A, 495, 2, false), (0x20B, 497, 2, false),
+    (0x20C, 499, 2, false), (0x20D, 501, 2, false), (0x20E, 503, 2, false), (0x20F, 505, 2, false),
+    (0x210, 507, 2, false), (0x211, 509, 2, false), (0x212, 511, 2, false), (0x213, 513, 2, false),
+    (0x214, 515, 2, false), (0x215, 517, 2, false), (0x216, 519, 2, false), (0x217, 521, 2, false),
+    (0x218, 523, 2, false), (0x219, 525, 2, false), (0x21A, 527, 2, false), (0x21B, 529, 2, false),
+    (0x21E, 531, 2, false), (0x21F, 533, 2, false), (0x226, 535, 2, false), (0x227, 537, 2, false),
+    (0x228, 539, 2, false), (0x229, 541, 2, false), (0x22A, 543, 2, false), (0x22B, 545, 2, false),
+    (0x22C, 547, 2, false), (0x22D, 549, 2, false), (0x22E, 551, 2, false), (0x22F, 553, 2, false),
+    (0x230, 555, 2, false), (0x231, 557, 2, false), (0x232, 559, 2, false), (0x233, 561, 2, false),
+    (0x2B0, 563, 1, true), (0x2B1, 564, 1, true), (0x2B2, 565, 1, true), (0x2B3, 566, 1, true),
+    (0x2B4, 567, 1, true), (0x2B5, 568, 1, true), (0x2B6, 569, 1, true), (0x2B7, 570, 1, true),
+    (0x2B8, 571, 1, true), (0x2D8, 572, 2, true), (0x2D9, 574, 2, true), (0x2DA, 576, 2, true),
+    (0x2DB, 578, 2, true), (0x2DC, 580, 2, true), (0x2DD, 582, 2, true), (0x2E0, 584, 1, true),
+    (0x2E1, 585, 1, true), (0x2E2, 586, 1, true), (0x2E3, 587, 1, true), (0x2E4, 588, 1, true),
+    (0x340, 589, 1, false), (0x341, 590, 1, false), (0x343, 591, 1, false), (0x344, 592, 2, false),
+    (0x374, 594, 1, false), (0x37A, 595, 2, true), (0x37E, 597, 1, false), (0x384, 598, 2, true),
+    (0x385, 600, 2, false), (0x386, 602, 2, false), (0x387, 604, 1, false), (0x388, 605, 2, false),
+    (0x389, 607, 2, false), (0x38A, 609, 2, false), (0x38C, 611, 2, false), (0x38E, 613, 2, false),
+    (0x38F, 615, 2, false), (0x390, 617, 2, false), (0x3AA, 619, 2, false), (0x3AB, 621, 2, false),
+    (0x3AC, 623, 2, false), (0x3AD, 625, 2, false), (0x3AE, 627, 2, false), (0x3AF, 629, 2, false),
+    (0x3B0, 631, 2, false), (0x3CA, 633, 2, false), (0x3CB, 635, 2, false), (0x3CC, 637, 2, false),
+    (0x3CD, 639, 2, false), (0x3CE, 641, 2, false), (0x3D0, 643, 1, true), (0x3D1, 644, 1, true),
+    (0x3D2, 645, 1, true), (0x3D3, 646, 2, false), (0x3D4, 648, 2, false), (0x3D5, 650, 1, true),
+    (0x3D6, 651, 1, true), (0x3F0, 652, 1, true), (0x3F1, 653, 1, true), (0x3F2, 654, 1, true),
+    (0x3F4, 655, 1, true), (0x3F5, 656, 1, true), (0x3F9, 657, 1, true), (0x400, 658, 2, false),
+    (0x401, 660, 2, false), (0x403, 662, 2, false), (0x407, 664, 2, false), (0x40C, 666, 2, false),
+    (0x40D, 668, 2, false), (0x40E, 670, 2, false), (0x419, 672, 2, false), (0x439, 674, 2, false),
+    (0x450, 676, 2, false), (0x451, 678, 2, false), (0x453, 680, 2, false), (0x457, 682, 2, false),
+    (0x45C, 684, 2, false), (0x45D, 686, 2, false), (0x45E, 688, 2, false), (0x476, 690, 2, false),
+    (0x477, 692, 2, false), (0x4C1, 694, 2, false), (0x4C2, 696, 2, false), (0x4D0, 698, 2, false),
+    (0x4D1, 700, 2, false), (0x4D2, 702, 2, false), (0x4D3, 704, 2, false), (0x4D6, 706, 2, false),
+    (0x4D7, 708, 2, false), (0x4DA, 710, 2, false), (0x4DB, 712, 2, false), (0x4DC, 714, 2, false),
+    (0x4DD, 716, 2, false), (0x4DE, 718, 2, false), (0x4DF, 720, 2, false), (0x4E2, 722, 2, false),
+    (0x4E3, 724, 2, false), (0x4E4, 726, 2, false), (0x4E5, 728, 2, false), (0x4E6, 730, 2, false),
+    (0x4E7, 732, 2, false), (0x4EA, 734, 2, false), (0x4EB, 736, 2, false), (0x4EC, 738, 2, false),
+    (0x4ED, 740, 2, false), (0x4EE, 742, 2, false), (0x4EF, 744, 2, false), (0x4F0, 746, 2, false),
+    (0x4F1, 748, 2, false), (0x4F2, 750, 2, false), (0x4F3, 752, 2, false), (0x4F4, 754, 2, false),
+    (0x4F5, 756, 2, false), (0x4F8, 758, 2, false), (0x4F9, 760, 2, false), (0x587, 762, 2, true),
+    (0x622, 764, 2, false), (0x623, 766, 2, false), (0x624, 768, 2, false), (0x625, 770, 2, false),
+    (0x626, 772, 2, false), (0x675, 774, 2, true), (0x676, 776, 2, true), (0x677, 778, 2, true),
+    (0x678, 780, 2, true), (0x6C0, 782, 2, false), (0x6C2, 784, 2, false), (0x6D3, 786, 2, false),
+    (0x929, 788, 2, false), (0x931, 790, 2, false), (0x934, 792, 2, false), (0x958, 794, 2, false),
+    (0x959, 796, 2, false), (0x95A, 798, 2, false), (0x95B, 800, 2, false), (0x95C, 802, 2, false),
+    (0x95D, 804, 2, false), (0x95E, 806, 2, false), (0x95F, 808, 2, false), (0x9CB, 810, 2, false),
+    (0x9CC, 812, 2, false), (0x9DC, 814, 2, false), (0x9DD, 816, 2, false), (0x9DF, 818, 2, false),
+    (0xA33, 820, 2, false), (0xA36, 822, 2, false), (0xA59, 824, 2, false), (0xA5A, 826, 2, false),
+    (0xA5B, 828, 2, false), (0xA5E, 830, 2, false), (0xB48, 832, 2, false), (0xB4B, 834, 2, false),
+    (0xB4C, 836, 2, false), (0xB5C, 838, 2, false), (0xB5D, 840, 2, false), (0xB94, 842, 2, false),
+    (0xBCA, 844, 2, false), (0xBCB, 846, 2, false), (0xBCC, 848, 2, false), (0xC48, 850, 2, false),
+    (0xCC0, 852, 2, false), (0xCC7, 854, 2, false), (0xCC8, 856, 2, false), (0xCCA, 858, 2, false),
+    (0xCCB, 860, 2, false), (0xD4A, 862, 2, false), (0xD4B, 864, 2, false), (0xD4C, 866, 2, false),
+    (0xDDA, 868, 2, false), (0xDDC, 870, 2, false), (0xDDD, 872, 2, false), (0xDDE, 874, 2, false),
+    (0xE33, 876, 2, true), (0xEB3, 878, 2, true), (0xEDC, 880, 2, true), (0xEDD, 882, 2, true),
+    (0xF0C, 884, 1, true), (0xF43, 885, 2, false), (0xF4D, 887, 2, false), (0xF52, 889, 2, false),
+    (0xF57, 891, 2, false), (0xF5C, 893, 2, false), (0xF69, 895, 2, false), (0xF73, 897, 2, false),
+    (0xF75, 899, 2, false), (0xF76, 901, 2, false), (0xF77, 903, 2, true), (0xF78, 905, 2, false),
+    (0xF79, 907, 2, true), (0xF81, 909, 2, false), (0xF93, 911, 2, false), (0xF9D, 913, 2, false),
+    (0xFA2, 915, 2, false), (0xFA7, 917, 2, false), (0xFAC, 919, 2, false), (0xFB9, 921, 2, false),
+    (0x1026, 923, 2, false), (0x10FC, 925, 1, true), (0x1B06, 926, 2, false), (0x1B08, 928, 2, false),
+    (0x1B0A, 930, 2, false), (0x1B0C, 932, 2, false), (0x1B0E, 934, 2, false), (0x1B12, 936, 2, false),
+    (0x1B3B, 938, 2, false), (0x1B3D, 940, 2, false), (0x1B40, 942, 2, false), (0x1B41, 944, 2, false),
+    (0x1B43, 946, 2, false), (0x1D2C, 948, 1, true), (0x1D2D, 949, 1, true), (0x1D2E, 950, 1, true),
+    (0x1D30, 951, 1, true), (0x1D31, 952, 1, true), (0x1D32, 953, 1, true), (0x1D33, 954, 1, true),
+    (0x1D34, 955, 1, true), (0x1D35, 956, 1, true), (0x1D36, 957, 1, true), (0x1D37, 958, 1, true),
+    (0x1D38, 959, 1, true), (0x1D39, 960, 1, true), (0x1D3A, 961, 1, true), (0x1D3C, 962, 1, true),
+    (0x1D3D, 963, 1, true), (0x1D3E, 964, 1, true), (0x1D3F, 965, 1, true), (0x1D40, 966, 1, true),
+    (0x1D41, 967, 1, true), (0x1D42, 968, 1, true), (0x1D43, 969, 1, true), (0x1D44, 970, 1, true),
+    (0x1D45, 971, 1, true), (0x1D46, 972, 1, true), (0x1D47, 973, 1, true), (0x1D48, 974, 1, true),
+    (0x1D49, 975, 1, true), (0x1D4A, 976, 1, true), (0x1D4B, 977, 1, true), (0x1D4C, 978, 1, true),
+    (0x1D4D, 979, 1, true), (0x1D4F, 980, 1, true), (0x1D50, 981, 1, true), (0x1D51, 982, 1, true),
+    (0x1D52, 983, 1, true), (0x1D53, 984, 1, true), (0x1D54, 985, 1, true), (0x1D55, 986, 1, true),
+    (0x1D56, 987, 1, true), (0x1D57, 988, 1, true), (0x1D58, 989, 1, true), (0x1D59, 990, 1, true),
+    (0x1D5A, 991, 1, true), (0x1D5B, 992, 1, true), (0x1D5C, 993, 1, true), (0x1D5D, 994, 1, true),
+    (0x1D5E, 995, 1, true), (0x1D5F, 996, 1, true), (0x1D60, 997, 1, true), (0x1D61, 998, 1, true),
+    (0x1D62, 999, 1, true), (0x1D63, 1000, 1, true), (0x1D64, 1001, 1, true), (0x1D65, 1002, 1, true),
+    (0x1D66, 1003, 1, true), (0x1D67, 1004, 1, true), (0x1D68, 1005, 1, true), (0x1D69, 1006, 1, true),
+    (0x1D6A, 1007, 1, true), (0x1D78, 1008, 1, true), (0x1D9B, 1009, 1, true), (0x1D9C, 1010, 1, true),
+    (0x1D9D, 1011, 1, true), (0x1D9E, 1012, 1, true), (0x1D9F, 1013, 1, true), (0x1DA0, 1014, 1, true),
+    (0x1DA1, 1015, 1, true), (0x1DA2, 1016, 1, true), (0x1DA3, 1017, 1, true), (0x1DA4, 1018, 1, true),
+    (0x1DA5, 1019, 1, true), (0x1DA6, 1020, 1, true), (0x1DA7, 1021, 1, true), (0x1DA8, 1022, 1, true),
+    (0x1DA9, 1023, 1, true), (0x1DAA, 1024, 1, true), (0x1DAB, 1025, 1, true), (0x1DAC, 1026, 1, true),
+    (0x1DAD, 1027, 1, true), (0x1DAE, 1028, 1, true), (0x1DAF, 1029, 1, true), (0x1DB0, 1030, 1, true),
+    (0x1DB1, 1031, 1, true), (0x1DB2, 1032, 1, true), (0x1DB3, 1033, 1, true), (0x1DB4, 1034, 1, true),
+    (0x1DB5, 1035, 1, true), (0x1DB6, 1036, 1, true), (0x1DB7, 1037, 1, true), (0x1DB8, 1038, 1, true),
+    (0x1DB9, 1039, 1, true), (0x1DBA, 1040, 1, true), (0x1DBB, 1041, 1, true), (0x1DBC, 1042, 1, true),
+    (0x1DBD, 1043, 1, true), (0x1DBE, 1044, 1, true), (0x1DBF, 1045, 1, true), (0x1E00, 1046, 2, false),
+    (0x1E01, 1048, 2, false), (0x1E02, 1050, 2, false), (0x1E03, 1052, 2, false), (0x1E04, 1054, 2, false),
+    (0x1E05, 1056, 2, false), (0x1E06, 1058, 2, false), (0x1E07, 1060, 2, false), (0x1E08, 1062, 2, false),
+    (0x1E09, 1064, 2, false), (0x1E0A, 1066, 2, false), (0x1E0B, 1068, 2, false), (0x1E0C, 1070, 2, false),
+    (0x1E0D, 1072, 2, false), (0x1E0E, 1074, 2, false), (0x1E0F, 1076, 2, false), (0x1E10, 1078, 2, false),
+    (0x1E11, 1080, 2, false), (0x1E12, 1082, 2, false), (0x1E13, 1084, 2, false), (0x1E14, 1086, 2, false),
+    (0x1E15, 1088, 2, false), (0x1E16, 1090, 2, false), (0x1E17, 1092, 2, false), (0x1E18, 1094, 2, false),
+    (0x1E19, 1096, 2, false), (0x1E1A, 1098, 2, false), (0x1E1B, 1100, 2, false), (0x1E1C, 1102, 2, false),
+    (0x1E1D, 1104, 2, false), (0x1E1E, 1106, 2, false), (0x1E1F, 1108, 2, false), (0x1E20, 1110, 2, false),
+    (0x1E21, 1112, 2, false), (0x1E22, 1114, 2, false), (0x1E23, 1116, 2, false), (0x1E24, 1118, 2, false),
+    (0x1E25, 1120, 2, false), (0x1E26, 1122, 2, false), (0x1E27, 1124, 2, false), (0x1E28, 1126, 2, false),
+    (0x1E29, 1128, 2, false), (0x1E2A, 1130, 2, false), (0x1E2B, 1132, 2, false), (0x1E2C, 1134, 2, false),
+    (0x1E2D, 1136, 2, false), (0x1E2E, 1138, 2, false), (0x1E2F, 1140, 2, false), (0x1E30, 1142, 2, false),
+    (0x1E31, 1144, 2, false), (0x1E32, 1146, 2, false), (0x1E33, 1148, 2, false), (0x1E34, 1150, 2, false),
+    (0x1E35, 1152, 2, false), (0x1E36, 1154, 2, false), (0x1E37, 1156, 2, false), (0x1E38, 1158, 2, false),
+    (0x1E39, 1160, 2, false), (0x1E3A, 1162, 2, false), (0x1E3B, 1164, 2, false), (0x1E3C, 1166, 2, false),
+    (0x1E3D, 1168, 2, false), (0x1E3E, 1170, 2, false), (0x1E3F, 1172, 2, false), (0x1E40, 1174, 2, false),
+    (0x1E41, 1176, 2, false), (0x1E42, 1178, 2, false), (0x1E43, 1180, 2, false), (0x1E44, 1182, 2, false),
+    (0x1E45, 1184, 2, false), (0x1E46, 1186, 2, false), (0x1E47, 1188, 2, false), (0x1E48, 1190, 2, false),
+    (0x1E49, 1192, 2, false), (0x1E4A, 1194, 2, false), (0x1E4B, 1196, 2, false), (0x1E4C, 1198, 2, false),
+    (0x1E4D, 1200, 2, false), (0x1E4E, 1202, 2, false), (0x1E4F, 1204, 2, false), (0x1E50, 1206, 2, false),
+    (0x1E51, 1208, 2, false), (0x1E52, 1210, 2, false), (0x1E53, 1212, 2, false), (0x1E54, 1214, 2, false),
+    (0x1E55, 1216, 2, false), (0x1E56, 1218, 2, false), (0x1E57, 1220, 2, false), (0x1E58, 1222, 2, false),
+    (0x1E59, 1224, 2, false), (0x1E5A, 1226, 2, false), (0x1E5B, 1228, 2, false), (0x1E5C, 1230, 2, false),
+    (0x1E5D, 1232, 2, false), (0x1E5E, 1234, 2, false), (0x1E5F, 1236, 2, false), (0x1E60, 1238, 2, false),
+    (0x1E61, 1240, 2, false), (0x1E62, 1242, 2, false), (0x1E63, 1244, 2, false), (0x1E64, 1246, 2, false),
+    (0x1E65, 1248, 2, false), (0x1E66, 1250, 2, false), (0x1E67, 1252, 2, false), (0x1E68, 1254, 2, false),
+    (0x1E69, 1256, 2, false), (0x1E6A, 1258, 2, false), (0x1E6B, 1260, 2, false), (0x1E6C, 1262, 2, false),
+    (0x1E6D, 1264, 2, false), (0x1E6E, 1266, 2, false), (0x1E6F, 1268, 2, false), (0x1E70, 1270, 2, false),
+    (0x1E71, 1272, 2, false), (0x1E72, 1274, 2, false), (0x1E73, 1276, 2, false), (0x1E74, 1278, 2, false),
+    (0x1E75, 1280, 2, false), (0x1E76, 1282, 2, false), (0x1E77, 1284, 2, false), (0x1E78, 1286, 2, false),
+    (0x1E79, 1288, 2, false), (0x1E7A, 1290, 2, false), (0x1E7B, 1292, 2, false), (0x1E7C, 1294, 2, false),
+    (0x1E7D, 1296, 2, false), (0x1E7E, 1298, 2, false), (0x1E7F, 1300, 2, false), (0x1E80, 1302, 2, false),
+    (0x1E81, 1304, 2, false), (0x1E82, 1306, 2, false), (0x1E83, 1308, 2, false), (0x1E84, 1310, 2, false),
+    (0x1E85, 1312, 2, false), (0x1E86, 1314, 2, false), (0x1E87, 1316, 2, false), (0x1E88, 1318, 2, false),
+    (0x1E89, 1320, 2, false), (0x1E8A, 1322, 2, false), (0x1E8B, 1324, 2, false), (0x1E8C, 1326, 2, false),
+    (0x1E8D, 1328, 2, false), (0x1E8E, 1330, 2, false), (0x1E8F, 1332, 2, false), (0x1E90, 1334, 2, false),
+    (0x1E91, 1336, 2, false), (0x1E92, 1338, 2, false), (0x1E93, 1340, 2, false), (0x1E94, 1342, 2, false),
+    (0x1E95, 1344, 2, false), (0x1E96, 1346, 2, false), (0x1E97, 1348, 2, false), (0x1E98, 1350, 2, false),
+    (0x1E99, 1352, 2, false), (0x1E9A, 1354, 2, true), (0x1E9B, 1356, 2, false), (0x1EA0, 1358, 2, false),
+    (0x1EA1, 1360, 2, false), (0x1EA2, 1362, 2, false), (0x1EA3, 1364, 2, false), (0x1EA4, 1366, 2, false),
+    (0x1EA5, 1368, 2, false), (0x1EA6, 1370, 2, false), (0x1EA7, 1372, 2, false), (0x1EA8, 1374, 2, false),
+    (0x1EA9, 1376, 2, false), (0x1EAA, 1378, 2, false), (0x1EAB, 1380, 2, false), (0x1EAC, 1382, 2, false),
+    (0x1EAD, 1384, 2, false), (0x1EAE, 1386, 2, false), (0x1EAF, 1388, 2, false), (0x1EB0, 1390, 2, false),
+    (0x1EB1, 1392, 2, false), (0x1EB2, 1394, 2, false), (0x1EB3, 1396, 2, false), (0x1EB4, 1398, 2, false),
+    (0x1EB5, 1400, 2, false), (0x1EB6, 1402, 2, false), (0x1EB7, 1404, 2, false), (0x1EB8, 1406, 2, false),
+    (0x1EB9, 1408, 2, false), (0x1EBA, 1410, 2, false), (0x1EBB, 1412, 2, false), (0x1EBC, 1414, 2, false),
+    (0x1EBD, 1416, 2, false), (0x1EBE, 1418, 2, false), (0x1EBF, 1420, 2, false), (0x1EC0, 1422, 2, false),
+    (0x1EC1, 1424, 2, false), (0x1EC2, 1426, 2, false), (0x1EC3, 1428, 2, false), (0x1EC4, 1430, 2, false),
+    (0x1EC5, 1432, 2, false), (0x1EC6, 1434, 2, false), (0x1EC7, 1436, 2, false), (0x1EC8, 1438, 2, false),
+    (0x1EC9, 1440, 2, false), (0x1ECA, 1442, 2, false), (0x1ECB, 1444, 2, false), (0x1ECC, 1446, 2, false),
+    (0x1ECD, 1448, 2, false), (0x1ECE, 1450, 2, false), (0x1ECF, 1452, 2, false), (0x1ED0, 1454, 2, false),
+    (0x1ED1, 1456, 2, false), (0x1ED2, 1458, 2, false), (0x1ED3, 1460, 2, false), (0x1ED4, 1462, 2, false),
+    (0x1ED5, 1464, 2, false), (0x1ED6, 1466, 2, false), (0x1ED7, 1468, 2, false), (0x1ED8, 1470, 2, false),
+    (0x1ED9, 1472, 2, false), (0x1EDA, 1474, 2, false), (0x1EDB, 1476, 2, false), (0x1EDC, 1478, 2, false),
+    (0x1EDD, 1480, 2, false), (0x1EDE, 1482, 2, false), (0x1EDF, 1484, 2, false), (0x1EE0, 1486, 2, false),
+    (0x1EE1, 1488, 2, false), (0x1EE2, 1490, 2, false), (0x1EE3, 1492, 2, false), (0x1EE4, 1494, 2, false),
+    (0x1EE5, 1496, 2, false), (0x1EE6, 1498, 2, false), (0x1EE7, 1500, 2, false), (0x1EE8, 1502, 2, false),
+    (0x1EE9, 1504, 2, false), (0x1EEA, 1506, 2, false), (0x1EEB, 1508, 2, false), (0x1EEC, 1510, 2, false),
+    (0x1EED, 1512, 2, false), (0x1EEE, 1514, 2, false), (0x1EEF, 1516, 2, false), (0x1EF0, 1518, 2, false),
+    (0x1EF1, 1520, 2, false), (0x1EF2, 1522, 2, false), (0x1EF3, 1524, 2, false), (0x1EF4, 1526, 2, false),
+    (0x1EF5, 1528, 2, false), (0x1EF6, 1530, 2, false), (0x1EF7, 1532, 2, false), (0x1EF8, 1534, 2, false),
+    (0x1EF9, 1536, 2, false), (0x1F00, 1538, 2, false), (0x1F01, 1540, 2, false), (0x1F02, 1542, 2, false),
+    (0x1F03, 1544, 2, false), (0x1F04, 1546, 2, false), (0x1F05, 1548, 2, false), (0x1F06, 1550, 2, false),
+    (0x1F07, 1552, 2, false), (0x1F08, 1554, 2, false), (0x1F09, 1556, 2, false), (0x1F0A, 1558, 2, false),
+    (0x1F0B, 1560, 2, false), (0x1F0C, 1562, 2, false), (0x1F0D, 1564, 2, false), (0x1F0E, 1566, 2, false),
+    (0x1F0F, 1568, 2, false), (0x1F10, 1570, 2, false), (0x1F11, 1572, 2, false), (0x1F12, 1574, 2, false),
+    (0x1F13, 1576, 2, false), (0x1F14, 1578, 2, false), (0x1F15, 1580, 2, false), (0x1F18, 1582, 2, false),
+    (0x1F19, 1584, 2, false), (0x1F1A, 1586, 2, false), (0x1F1B, 1588, 2, false), (0x1F1C, 1590, 2, false),
+    (0x1F1D, 1592, 2, false), (0x1F20, 1594, 2, false), (0x1F21, 1596, 2, false), (0x1F22, 1598, 2, false),
+    (0x1F23, 1600, 2, false), (0x1F24, 1602, 2, false), (0x1F25, 1604, 2, false), (0x1F26, 1606, 2, false),
+    (0x1F27, 1608, 2, false), (0x1F28, 1610, 2, false), (0x1F29, 1612, 2, false), (0x1F2A, 1614, 2, false),
+    (0x1F2B, 1616, 2, false), (0x1F2C, 1618, 2, false), (0x1F2D, 1620, 2, false), (0x1F2E, 1622, 2, false),
+    (0x1F2F, 1624, 2, false), (0x1F30, 1626, 2, false), (0x1F31, 1628, 2, false), (0x1F32, 1630, 2, false),
+    (0x1F33, 1632, 2, false), (0x1F34, 1634, 2, false), (0x1F35, 1636, 2, false), (0x1F36, 1638, 2, false),
+    (0x1F37, 1640, 2, false), (0x1F38, 1642, 2, false), (0x1F39, 1644, 2, false), (0x1F3A, 1646, 2, false),
+    (0x1F3B, 1648, 2, false), (0x1F3C, 1650, 2, false), (0x1F3D, 1652, 2, false), (0x1F3E, 1654, 2, false),
+    (0x1F3F, 1656, 2, false), (0x1F40, 1658, 2, false), (0x1F41, 1660, 2, false), (0x1F42, 1662, 2, false),
+    (0x1F43, 1664, 2, false), (0x1F44, 1666, 2, false), (0x1F45, 1668, 2, false), (0x1F48, 1670, 2, false),
+    (0x1F49, 1672, 2, false), (0x1F4A, 1674, 2, false), (0x1F4B, 1676, 2, false), (0x1F4C, 1678, 2, false),
+    (0x1F4D, 1680, 2, false), (0x1F50, 1682, 2, false), (0x1F51, 1684, 2, false), (0x1F52, 1686, 2, false),
+    (0x1F53, 1688, 2, false), (0x1F54, 1690, 2, false), (0x1F55, 1692, 2, false), (0x1F56, 1694, 2, false),
+    (0x1F57, 1696, 2, false), (0x1F59, 1698, 2, false), (0x1F5B, 1700, 2, false), (0x1F5D, 1702, 2, false),
+    (0x1F5F, 1704, 2, false), (0x1F60, 1706, 2, false), (0x1F61, 1708, 2, false), (0x1F62, 1710, 2, false),
+    (0x1F63, 1712, 2, false), (0x1F64, 1714, 2, false), (0x1F65, 1716, 2, false), (0x1F66, 1718, 2, false),
+    (0x1F67, 1720, 2, false), (0x1F68, 1722, 2, false), (0x1F69, 1724, 2, false), (0x1F6A, 1726, 2, false),
+    (0x1F6B, 1728, 2, false), (0x1F6C, 1730, 2, false), (0x1F6D, 1732, 2, false), (0x1F6E, 1734, 2, false),
+    (0x1F6F, 1736, 2, false), (0x1F70, 1738, 2, false), (0x1F71, 1740, 1, false), (0x1F72, 1741, 2, false),
+    (0x1F73, 1743, 1, false), (0x1F74, 1744, 2, false), (0x1F75, 1746, 1, false), (0x1F76, 1747, 2, false),
+    (0x1F77, 1749, 1, false), (0x1F78, 1750, 2, false), (0x1F79, 1752, 1, false), (0x1F7A, 1753, 2, false),
+    (0x1F7B, 1755, 1, false), (0x1F7C, 1756, 2, false), (0x1F7D, 1758, 1, false), (0x1F80, 1759, 2, false),
+    (0x1F81, 1761, 2, false), (0x1F82, 1763, 2, false), (0x1F83, 1765, 2, false), (0x1F84, 1767, 2, false),
+    (0x1F85, 1769, 2, false), (0x1F86, 1771, 2, false), (0x1F87, 1773, 2, false), (0x1F88, 1775, 2, false),
+    (0x1F89, 1777, 2, false), (0x1F8A, 1779, 2, false), (0x1F8B, 1781, 2, false), (0x1F8C, 1783, 2, false),
+    (0x1F8D, 1785, 2, false), (0x1F8E, 1787, 2, false), (0x1F8F, 1789, 2, false), (0x1F90, 1791, 2, false),
+    (0x1F91, 1793, 2, false), (0x1F92, 1795, 2, false), (0x1F93, 1797, 2, false), (0x1F94, 1799, 2, false),
+    (0x1F95, 1801, 2, false), (0x1F96, 1803, 2, false), (0x1F97, 1805, 2, false), (0x1F98, 1807, 2, false),
+    (0x1F99, 1809, 2, false), (0x1F9A, 1811, 2, false), (0x1F9B, 1813, 2, false), (0x1F9C, 1815, 2, false),
+    (0x1F9D, 1817, 2, false), (0x1F9E, 1819, 2, false), (0x1F9F, 1821, 2, false), (0x1FA0, 1823, 2, false),
+    (0x1FA1, 1825, 2, false), (0x1FA2, 1827, 2, false), (0x1FA3, 1829, 2, false), (0x1FA4, 1831, 2, false),
+    (0x1FA5, 1833, 2, false), (0x1FA6, 1835, 2, false), (0x1FA7, 1837, 2, false), (0x1FA8, 1839, 2, false),
+    (0x1FA9, 1841, 2, false), (0x1FAA, 1843, 2, false), (0x1FAB, 1845, 2, false), (0x1FAC, 1847, 2, false),
+    (0x1FAD, 1849, 2, false), (0x1FAE, 1851, 2, false), (0x1FAF, 1853, 2, false), (0x1FB0, 1855, 2, false),
+    (0x1FB1, 1857, 2, false), (0x1FB2, 1859, 2, false), (0x1FB3, 1861, 2, false), (0x1FB4, 1863, 2, false),
+    (0x1FB6, 1865, 2, false), (0x1FB7, 1867, 2, false), (0x1FB8, 1869, 2, false), (0x1FB9, 1871, 2, false),
+    (0x1FBA, 1873, 2, false), (0x1FBB, 1875, 1, false), (0x1FBC, 1876, 2, false), (0x1FBD, 1878, 2, true),
+    (0x1FBE, 1880, 1, false), (0x1FBF, 1881, 2, true), (0x1FC0, 1883, 2, true), (0x1FC1, 1885, 2, false),
+    (0x1FC2, 1887, 2, false), (0x1FC3, 1889, 2, false), (0x1FC4, 1891, 2, false), (0x1FC6, 1893, 2, false),
+    (0x1FC7, 1895, 2, false), (0x1FC8, 1897, 2, false), (0x1FC9, 1899, 1, false), (0x1FCA, 1900, 2, false),
+    (0x1FCB, 1902, 1, false), (0x1FCC, 1903, 2, false), (0x1FCD, 1905, 2, false), (0x1FCE, 1907, 2, false),
+    (0x1FCF, 1909, 2, false), (0x1FD0, 1911, 2, false), (0x1FD1, 1913, 2, false), (0x1FD2, 1915, 2, false),
+    (0x1FD3, 1917, 1, false), (0x1FD6, 1918, 2, false), (0x1FD7, 1920, 2, false), (0x1FD8, 1922, 2, false),
+    (0x1FD9, 1924, 2, false), (0x1FDA, 1926, 2, false), (0x1FDB, 1928, 1, false), (0x1FDD, 1929, 2, false),
+    (0x1FDE, 1931, 2, false), (0x1FDF, 1933, 2, false), (0x1FE0, 1935, 2, false), (0x1FE1, 1937, 2, false),
+    (0x1FE2, 1939, 2, false), (0x1FE3, 1941, 1, false), (0x1FE4, 1942, 2, false), (0x1FE5, 1944, 2, false),
+    (0x1FE6, 1946, 2, false), (0x1FE7, 1948, 2, false), (0x1FE8, 1950, 2, false), (0x1FE9, 1952, 2, false),
+    (0x1FEA, 1954, 2, false), (0x1FEB, 1956, 1, false), (0x1FEC, 1957, 2, false), (0x1FED, 1959, 2, false),
+    (0x1FEE, 1961, 1, false), (0x1FEF, 1962, 1, false), (0x1FF2, 1963, 2, false), (0x1FF3, 1965, 2, false),
+    (0x1FF4, 1967, 2, false), (0x1FF6, 1969, 2, false), (0x1FF7, 1971, 2, false), (0x1FF8, 1973, 2, false),
+    (0x1FF9, 1975, 1, false), (0x1FFA, 1976, 2, false), (0x1FFB, 1978, 1, false), (0x1FFC, 1979, 2, false),
+    (0x1FFD, 1981, 1, false), (0x1FFE, 1982, 2, true), (0x2000, 1984, 1, false), (0x2001, 1985, 1, false),
+    (0x2002, 1986, 1, true), (0x2003, 1987, 1, true), (0x2004, 1988, 1, true), (0x2005, 1989, 1, true),
+    (0x2006, 1990, 1, true), (0x2007, 1991, 1, true), (0x2008, 1992, 1, true), (0x2009, 1993, 1, true),
+    (0x200A, 1994, 1, true), (0x2011, 1995, 1, true), (0x2017, 1996, 2, true), (0x2024, 1998, 1, true),
+    (0x2025, 1999, 2, true), (0x2026, 2001, 3, true), (0x202F, 2004, 1, true), (0x2033, 2005, 2, true),
+    (0x2034, 2007, 3, true), (0x2036, 2010, 2, true), (0x2037, 2012, 3, true), (0x203C, 2015, 2, true),
+    (0x203E, 2017, 2, true), (0x2047, 2019, 2, true), (0x2048, 2021, 2, true), (0x2049, 2023, 2, true),
+    (0x2057, 2025, 4, true), (0x205F, 2029, 1, true), (0x2070, 2030, 1, true), (0x2071, 2031, 1, true),
+    (0x2074, 2032, 1, true), (0x2075, 2033, 1, true), (0x2076, 2034, 1, true), (0x2077, 2035, 1, true),
+    (0x2078, 2036, 1, true), (0x2079, 2037, 1, true), (0x207A, 2038, 1, true), (0x207B, 2039, 1, true),
+    (0x207C, 2040, 1, true), (0x207D, 2041, 1, true), (0x207E, 2042, 1, true), (0x207F, 2043, 1, true),
+    (0x2080, 2044, 1, true), (0x2081, 2045, 1, true), (0x2082, 2046, 1, true), (0x2083, 2047, 1, true),
+    (0x2084, 2048, 1, true), (0x2085, 2049, 1, true), (0x2086, 2050, 1, true), (0x2087, 2051, 1, true),
+    (0x2088, 2052, 1, true), (0x2089, 2053, 1, true), (0x208A, 2054, 1, true), (0x208B, 2055, 1, true),
+    (0x208C, 2056, 1, true), (0x208D, 2057, 1, true), (0x208E, 2058, 1, true), (0x2090, 2059, 1, true),
+    (0x2091, 2060, 1, true), (0x2092, 2061, 1, true), (0x2093, 2062, 1, true), (0x2094, 2063, 1, true),
+    (0x2095, 2064, 1, true), (0x2096, 2065, 1, true), (0x2097, 2066, 1, true), (0x2098, 2067, 1, true),
+    (0x2099, 2068, 1, true), (0x209A, 2069, 1, true), (0x209B, 2070, 1, true), (0x209C, 2071, 1, true),
+    (0x20A8, 2072, 2, true), (0x2100, 2074, 3, true), (0x2101, 2077, 3, true), (0x2102, 2080, 1, true),
+    (0x2103, 2081, 2, true), (0x2105, 2083, 3, true), (0x2106, 2086, 3, true), (0x2107, 2089, 1, true),
+    (0x2109, 2090, 2, true), (0x210A, 2092, 1, true), (0x210B, 2093, 1, true), (0x210C, 2094, 1, true),
+    (0x210D, 2095, 1, true), (0x210E, 2096, 1, true), (0x210F, 2097, 1, true), (0x2110, 2098, 1, true),
+    (0x2111, 2099, 1, true), (0x2112, 2100, 1, true), (0x2113, 2101, 1, true), (0x2115, 2102, 1, true),
+    (0x2116, 2103, 2, true), (0x2119, 2105, 1, true), (0x211A, 2106, 1, true), (0x211B, 2107, 1, true),
+    (0x211C, 2108, 1, true), (0x211D, 2109, 1, true), (0x2120, 2110, 2, true), (0x2121, 2112, 3, true),
+    (0x2122, 2115, 2, true), (0x2124, 2117, 1, true), (0x2126, 2118, 1, false), (0x2128, 2119, 1, true),
+    (0x212A, 2120, 1, false), (0x212B, 2121, 1, false), (0x212C, 2122, 1, true), (0x212D, 2123, 1, true),
+    (0x212F, 2124, 1, true), (0x2130, 2125, 1, true), (0x2131, 2126, 1, true), (0x2133, 2127, 1, true),
+    (0x2134, 2128, 1, true), (0x2135, 2129, 1, true), (0x2136, 2130, 1, true), (0x2137, 2131, 1, true),
+    (0x2138, 2132, 1, true), (0x2139, 2133, 1, true), (0x213B, 2134, 3, true), (0x213C, 2137, 1, true),
+    (0x213D, 2138, 1, true), (0x213E, 2139, 1, true), (0x213F, 2140, 1, true), (0x2140, 2141, 1, true),
+    (0x2145, 2142, 1, true), (0x2146, 2143, 1, true), (0x2147, 2144, 1, true), (0x2148, 2145, 1, true),
+    (0x2149, 2146, 1, true), (0x2150, 2147, 3, true), (0x2151, 2150, 3, true), (0x2152, 2153, 4, true),
+    (0x2153, 2157, 3, true), (0x2154, 2160, 3, true), (0x2155, 2163, 3, true), (0x2156, 2166, 3, true),
+    (0x2157, 2169, 3, true), (0x2158, 2172, 3, true), (0x2159, 2175, 3, true), (0x215A, 2178, 3, true),
+    (0x215B, 2181, 3, true), (0x215C, 2184, 3, true), (0x215D, 2187, 3, true), (0x215E, 2190, 3, true),
+    (0x215F, 2193, 2, true), (0x2160, 2195, 1, true), (0x2161, 2196, 2, true), (0x2162, 2198, 3, true),
+    (0x2163, 2201, 2, true), (0x2164, 2203, 1, true), (0x2165, 2204, 2, true), (0x2166, 2206, 3, true),
+    (0x2167, 2209, 4, true), (0x2168, 2213, 2, true), (0x2169, 2215, 1, true), (0x216A, 2216, 2, true),
+    (0x216B, 2218, 3, true), (0x216C, 2221, 1, true), (0x216D, 2222, 1, true), (0x216E, 2223, 1, true),
+    (0x216F, 2224, 1, true), (0x2170, 2225, 1, true), (0x2171, 2226, 2, true), (0x2172, 2228, 3, true),
+    (0x2173, 2231, 2, true), (0x2174, 2233, 1, true), (0x2175, 2234, 2, true), (0x2176, 2236, 3, true),
+    (0x2177, 2239, 4, true), (0x2178, 2243, 2, true), (0x2179, 2245, 1, true), (0x217A, 2246, 2, true),
+    (0x217B, 2248, 3, true), (0x217C, 2251, 1, true), (0x217D, 2252, 1, true), (0x217E, 2253, 1, true),
+    (0x217F, 2254, 1, true), (0x2189, 2255, 3, true), (0x219A, 2258, 2, false), (0x219B, 2260, 2, false),
+    (0x21AE, 2262, 2, false), (0x21CD, 2264, 2, false), (0x21CE, 2266, 2, false), (0x21CF, 2268, 2, false),
+    (0x2204, 2270, 2, false), (0x2209, 2272, 2, false), (0x220C, 2274, 2, false), (0x2224, 2276, 2, false),
+    (0x2226, 2278, 2, false), (0x222C, 2280, 2, true), (0x222D, 2282, 3, true), (0x222F, 2285, 2, true),
+    (0x2230, 2287, 3, true), (0x2241, 2290, 2, false), (0x2244, 2292, 2, false), (0x2247, 2294, 2, false),
+    (0x2249, 2296, 2, false), (0x2260, 2298, 2, false), (0x2262, 2300, 2, false), (0x226D, 2302, 2, false),
+    (0x226E, 2304, 2, false), (0x226F, 2306, 2, false), (0x2270, 2308, 2, false), (0x2271, 2310, 2, false),
+    (0x2274, 2312, 2, false), (0x2275, 2314, 2, false), (0x2278, 2316, 2, false), (0x2279, 2318, 2, false),
+    (0x2280, 2320, 2, false), (0x2281, 2322, 2, false), (0x2284, 2324, 2, false), (0x2285, 2326, 2, false),
+    (0x2288, 2328, 2, false), (0x2289, 2330, 2, false), (0x22AC, 2332, 2, false), (0x22AD, 2334, 2, false),
+    (0x22AE, 2336, 2, false), (0x22AF, 2338, 2, false), (0x22E0, 2340, 2, false), (0x22E1, 2342, 2, false),
+    (0x22E2, 2344, 2, false), (0x22E3, 2346, 2, false), (0x22EA, 2348, 2, false), (0x22EB, 2350, 2, false),
+    (0x22EC, 2352, 2, false), (0x22ED, 2354, 2, false), (0x2329, 2356, 1, false), (0x232A, 2357, 1, false),
+    (0x2460, 2358, 1, true), (0x2461, 2359, 1, true), (0x2462, 2360, 1, true), (0x2463, 2361, 1, true),
+    (0x2464, 2362, 1, true), (0x2465, 2363, 1, true), (0x2466, 2364, 1, true), (0x2467, 2365, 1, true),
+    (0x2468, 2366, 1, true), (0x2469, 2367, 2, true), (0x246A, 2369, 2, true), (0x246B, 2371, 2, true),
+    (0x246C, 2373, 2, true), (0x246D, 2375, 2, true), (0x246E, 2377, 2, true), (0x246F, 2379, 2, true),
+    (0x2470, 2381, 2, true), (0x2471, 2383, 2, true), (0x2472, 2385, 2, true), (0x2473, 2387, 2, true),
+    (0x2474, 2389, 3, true), (0x2475, 2392, 3, true), (0x2476, 2395, 3, true), (0x2477, 2398, 3, true),
+    (0x2478, 2401, 3, true), (0x2479, 2404, 3, true), (0x247A, 2407, 3, true), (0x247B, 2410, 3, true),
+    (0x247C, 2413, 3, true), (0x247D, 2416, 4, true), (0x247E, 2420, 4, true), (0x247F, 2424, 4, true),
+    (0x2480, 2428, 4, true), (0x2481, 2432, 4, true), (0x2482, 2436, 4, true), (0x2483, 2440, 4, true),
+    (0x2484, 2444, 4, true), (0x2485, 2448, 4, true), (0x2486, 2452, 4, true), (0x2487, 2456, 4, true),
+    (0x2488, 2460, 2, true), (0x2489, 2462, 2, true), (0x248A, 2464, 2, true), (0x248B, 2466, 2, true),
+    (0x248C, 2468, 2, true), (0x248D, 2470, 2, true), (0x248E, 2472, 2, true), (0x248F, 2474, 2, true),
+    (0x2490, 2476, 2, true), (0x2491, 2478, 3, true), (0x2492, 2481, 3, true), (0x2493, 2484, 3, true),
+    (0x2494, 2487, 3, true), (0x2495, 2490, 3, true), (0x2496, 2493, 3, true), (0x2497, 2496, 3, true),
+    (0x2498, 2499, 3, true), (0x2499, 2502, 3, true), (0x249A, 2505, 3, true), (0x249B, 2508, 3, true),
+    (0x249C, 2511, 3, true), (0x249D, 2514, 3, true), (0x249E, 2517, 3, true), (0x249F, 2520, 3, true),
+    (0x24A0, 2523, 3, true), (0x24A1, 2526, 3, true), (0x24A2, 2529, 3, true), (0x24A3, 2532, 3, true),
+    (0x24A4, 2535, 3, true), (0x24A5, 2538, 3, true), (0x24A6, 2541, 3, true), (0x24A7, 2544, 3, true),
+    (0x24A8, 2547, 3, true), (0x24A9, 2550, 3, true), (0x24AA, 2553, 3, true), (0x24AB, 2556, 3, true),
+    (0x24AC, 2559, 3, true), (0x24AD, 2562, 3, true), (0x24AE, 2565, 3, true), (0x24AF, 2568, 3, true),
+    (0x24B0, 2571, 3, true), (0x24B1, 2574, 3, true), (0x24B2, 2577, 3, true), (0x24B3, 2580, 3, true),
+    (0x24B4, 2583, 3, true), (0x24B5, 2586, 3, true), (0x24B6, 2589, 1, true), (0x24B7, 2590, 1, true),
+    (0x24B8, 2591, 1, true), (0x24B9, 2592, 1, true), (0x24BA, 2593, 1, true), (0x24BB, 2594, 1, true),
+    (0x24BC, 2595, 1, true), (0x24BD, 2596, 1, true), (0x24BE, 2597, 1, true), (0x24BF, 2598, 1, true),
+    (0x24C0, 2599, 1, true), (0x24C1, 2600, 1, true), (0x24C2, 2601, 1, true), (0x24C3, 2602, 1, true),
+    (0x24C4, 2603, 1, true), (0x24C5, 2604, 1, true), (0x24C6, 2605, 1, true), (0x24C7, 2606, 1, true),
+    (0x24C8, 2607, 1, true), (0x24C9, 2608, 1, true), (0x24CA, 2609, 1, true), (0x24CB, 2610, 1, true),
+    (0x24CC, 2611, 1, true), (0x24CD, 2612, 1, true), (0x24CE, 2613, 1, true), (0x24CF, 2614, 1, true),
+    (0x24D0, 2615, 1, true), (0x24D1, 2616, 1, true), (0x24D2, 2617, 1, true), (0x24D3, 2618, 1, true),
+    (0x24D4, 2619, 1, true), (0x24D5, 2620, 1, true), (0x24D6, 2621, 1, true), (0x24D7, 2622, 1, true),
+    (0x24D8, 2623, 1, true), (0x24D9, 2624, 1, true), (0x24DA, 2625, 1, true), (0x24DB, 2626, 1, true),
+    (0x24DC, 2627, 1, true), (0x24DD, 2628, 1, true), (0x24DE, 2629, 1, true), (0x24DF, 2630, 1, true),
+    (0x24E0, 2631, 1, true), (0x24E1, 2632, 1, true), (0x24E2, 2633, 1, true), (0x24E3, 2634, 1, true),
+    (0x24E4, 2635, 1, true), (0x24E5, 2636, 1, true), (0x24E6, 2637, 1, true), (0x24E7, 2638, 1, true),
+    (0x24E8, 2639, 1, true), (0x24E9, 2640, 1, true), (0x24EA, 2641, 1, true), (0x2A0C, 2642, 4, true),
+    (0x2A74, 2646, 3, true), (0x2A75, 2649, 2, true), (0x2A76, 2651, 3, true), (0x2ADC, 2654, 2, false),
+    (0x2C7C, 2656, 1, true), (0x2C7D, 2657, 1, true), (0x2D6F, 2658, 1, true), (0x2E9F, 2659, 1, true),
+    (0x2EF3, 2660, 1, true), (0x2F00, 2661, 1, true), (0x2F01, 2662, 1, true), (0x2F02, 2663, 1, true),
+    (0x2F03, 2664, 1, true), (0x2F04, 2665, 1, true), (0x2F05, 2666, 1, true), (0x2F06, 2667, 1, true),
+    (0x2F07, 2668, 1, true), (0x2F08, 2669, 1, true), (0x2F09, 2670, 1, true), (0x2F0A, 2671, 1, true),
+    (0x2F0B, 2672, 1, true), (0x2F0C, 2673, 1, true), (0x2F0D, 2674, 1, true), (0x2F0E, 2675, 1, true),
+    (0x2F0F, 2676, 1, true), (0x2F10, 2677, 1, true), (0x2F11, 2678, 1, true), (0x2F12, 2679, 1, true),
+    (0x2F13, 2680, 1, true), (0x2F14, 2681, 1, true), (0x2F15, 2682, 1, true), (0x2F16, 2683, 1, true),
+    (0x2F17, 2684, 1, true), (0x2F18, 2685, 1, true), (0x2F19, 2686, 1, true), (0x2F1A, 2687, 1, true),
+    (0x2F1B, 2688, 1, true), (0x2F1C, 2689, 1, true), (0x2F1D, 2690, 1, true), (0x2F1E, 2691, 1, true),
+    (0x2F1F, 2692, 1, true), (0x2F20, 2693, 1, true), (0x2F21, 2694, 1, true), (0x2F22, 2695, 1, true),
+    (0x2F23, 2696, 1, true), (0x2F24, 2697, 1, true), (0x2F25, 2698, 1, true), (0x2F26, 2699, 1, true),
+    (0x2F27, 2700, 1, true), (0x2F28, 2701, 1, true), (0x2F29, 2702, 1, true), (0x2F2A, 2703, 1, true),
+    (0x2F2B, 2704, 1, true), (0x2F2C, 2705, 1, true), (0x2F2D, 2706, 1, true), (0x2F2E, 2707, 1, true),
+    (0x2F2F, 2708, 1, true), (0x2F30, 2709, 1, true), (0x2F31, 2710, 1, true), (0x2F32, 2711, 1, true),
+    (0x2F33, 2712, 1, true), (0x2F34, 2713, 1, true), (0x2F35, 2714, 1, true), (0x2F36, 2715, 1, true),
+    (0x2F37, 2716, 1, true), (0x2F38, 2717, 1, true), (0x2F39, 2718, 1, true), (0x2F3A, 2719, 1, true),
+    (0x2F3B, 2720, 1, true), (0x2F3C, 2721, 1, true), (0x2F3D, 2722, 1, true), (0x2F3E, 2723, 1, true),
+    (0x2F3F, 2724, 1, true), (0x2F40, 2725, 1, true), (0x2F41, 2726, 1, true), (0x2F42, 2727, 1, true),
+    (0x2F43, 2728, 1, true), (0x2F44, 2729, 1, true), (0x2F45, 2730, 1, true), (0x2F46, 2731, 1, true),
+    (0x2F47, 2732, 1, true), (0x2F48, 2733, 1, true), (0x2F49, 2734, 1, true), (0x2F4A, 2735, 1, true),
+    (0x2F4B, 2736, 1, true), (0x2F4C, 2737, 1, true), (0x2F4D, 2738, 1, true), (0x2F4E, 2739, 1, true),
+    (0x2F4F, 2740, 1, true), (0x2F50, 2741, 1, true), (0x2F51, 2742, 1, true), (0x2F52, 2743, 1, true),
+    (0x2F53, 2744, 1, true), (0x2F54, 2745, 1, true), (0x2F55, 2746, 1, true), (0x2F56, 2747, 1, true),
+    (0x2F57, 2748, 1, true), (0x2F58, 2749, 1, true), (0x2F59, 2750, 1, true), (0x2F5A, 2751, 1, true),
+    (0x2F5B, 2752, 1, true), (0x2F5C, 2753, 1, true), (0x2F5D, 2754, 1, true), (0x2F5E, 2755, 1, true),
+    (0x2F5F, 2756, 1, true), (0x2F60, 2757, 1, true), (0x2F61, 2758, 1, true), (0x2F62, 2759, 1, true),
+    (0x2F63, 2760, 1, true), (0x2F64, 2761, 1, true), (0x2F65, 2762, 1, true), (0x2F66, 2763, 1, true),
+    (0x2F67, 2764, 1, true), (0x2F68, 2765, 1, true), (0x2F69, 2766, 1, true), (0x2F6A, 2767, 1, true),
+    (0x2F6B, 2768, 1, true), (0x2F6C, 2769, 1, true), (0x2F6D, 2770, 1, true), (0x2F6E, 2771, 1, true),
+    (0x2F6F, 2772, 1, true), (0x2F70, 2773, 1, true), (0x2F71, 2774, 1, true), (0x2F72, 2775, 1, true),
+    (0x2F73, 2776, 1, true), (0x2F74, 2777, 1, true), (0x2F75, 2778, 1, true), (0x2F76, 2779, 1, true),
+    (0x2F77, 2780, 1, true), (0x2F78, 2781, 1, true), (0x2F79, 2782, 1, true), (0x2F7A, 2783, 1, true),
+    (0x2F7B, 2784, 1, true), (0x2F7C, 2785, 1, true), (0x2F7D, 2786, 1, true), (0x2F7E, 2787, 1, true),
+    (0x2F7F, 2788, 1, true), (0x2F80, 2789, 1, true), (0x2F81, 2790, 1, true), (0x2F82, 2791, 1, true),
+    (0x2F83, 2792, 1, true), (0x2F84, 2793, 1, true), (0x2F85, 2794, 1, true), (0x2F86, 2795, 1, true),
+    (0x2F87, 2796, 1, true), (0x2F88, 2797, 1, true), (0x2F89, 2798, 1, true), (0x2F8A, 2799, 1, true),
+    (0x2F8B, 2800, 1, true), (0x2F8C, 2801, 1, true), (0x2F8D, 2802, 1, true), (0x2F8E, 2803, 1, true),
+    (0x2F8F, 2804, 1, true), (0x2F90, 2805, 1, true), (0x2F91, 2806, 1, true), (0x2F92, 2807, 1, true),
+    (0x2F93, 2808, 1, true), (0x2F94, 2809, 1, true), (0x2F95, 2810, 1, true), (0x2F96, 2811, 1, true),
+    (0x2F97, 2812, 1, true), (0x2F98, 2813, 1, true), (0x2F99, 2814, 1, true), (0x2F9A, 2815, 1, true),
+    (0x2F9B, 2816, 1, true), (0x2F9C, 2817, 1, true), (0x2F9D, 2818, 1, true), (0x2F9E, 2819, 1, true),
+    (0x2F9F, 2820, 1, true), (0x2FA0, 2821, 1, true), (0x2FA1, 2822, 1, true), (0x2FA2, 2823, 1, true),
+    (0x2FA3, 2824, 1, true), (0x2FA4, 2825, 1, true), (0x2FA5, 2826, 1, true), (0x2FA6, 2827, 1, true),
+    (0x2FA7, 2828, 1, true), (0x2FA8, 2829, 1, true), (0x2FA9, 2830, 1, true), (0x2FAA, 2831, 1, true),
+    (0x2FAB, 2832, 1, true), (0x2FAC, 2833, 1, true), (0x2FAD, 2834, 1, true), (0x2FAE, 2835, 1, true),
+    (0x2FAF, 2836, 1, true), (0x2FB0, 2837, 1, true), (0x2FB1, 2838, 1, true), (0x2FB2, 2839, 1, true),
+    (0x2FB3, 2840, 1, true), (0x2FB4, 2841, 1, true), (0x2FB5, 2842, 1, true), (0x2FB6, 2843, 1, true),
+    (0x2FB7, 2844, 1, true), (0x2FB8, 2845, 1, true), (0x2FB9, 2846, 1, true), (0x2FBA, 2847, 1, true),
+    (0x2FBB, 2848, 1, true), (0x2FBC, 2849, 1, true), (0x2FBD, 2850, 1, true), (0x2FBE, 2851, 1, true),
+    (0x2FBF, 2852, 1, true), (0x2FC0, 2853, 1, true), (0x2FC1, 2854, 1, true), (0x2FC2, 2855, 1, true),
+    (0x2FC3, 2856, 1, true), (0x2FC4, 2857, 1, true), (0x2FC5, 2858, 1, true), (0x2FC6, 2859, 1, true),
+    (0x2FC7, 2860, 1, true), (0x2FC8, 2861, 1, true), (0x2FC9, 2862, 1, true), (0x2FCA, 2863, 1, true),
+    (0x2FCB, 2864, 1, true), (0x2FCC, 2865, 1, true), (0x2FCD, 2866, 1, true), (0x2FCE, 2867, 1, true),
+    (0x2FCF, 2868, 1, true), (0x2FD0, 2869, 1, true), (0x2FD1, 2870, 1, true), (0x2FD2, 2871, 1, true),
+    (0x2FD3, 2872, 1, true), (0x2FD4, 2873, 1, true), (0x2FD5, 2874, 1, true), (0x3000, 2875, 1, true),
+    (0x3036, 2876, 1, true), (0x3038, 2877, 1, true), (0x3039, 2878, 1, true), (0x303A, 2879, 1, true),
+    (0x304C, 2880, 2, false), (0x304E, 2882, 2, false), (0x3050, 2884, 2, false), (0x3052, 2886, 2, false),
+    (0x3054, 2888, 2, false), (0x3056, 2890, 2, false), (0x3058, 2892, 2, false), (0x305A, 2894, 2, false),
+    (0x305C, 2896, 2, false), (0x305E, 2898, 2, false), (0x3060, 2900, 2, false), (0x3062, 2902, 2, false),
+    (0x3065, 2904, 2, false), (0x3067, 2906, 2, false), (0x3069, 2908, 2, false), (0x3070, 2910, 2, false),
+    (0x3071, 2912, 2, false), (0x3073, 2914, 2, false), (0x3074, 2916, 2, false), (0x3076, 2918, 2, false),
+    (0x3077, 2920, 2, false), (0x3079, 2922, 2, false), (0x307A, 2924, 2, false), (0x307C, 2926, 2, false),
+    (0x307D, 2928, 2, false), (0x3094, 2930, 2, false), (0x309B, 2932, 2, true), (0x309C, 2934, 2, true),
+    (0x309E, 2936, 2, false), (0x309F, 2938, 2, true), (0x30AC, 2940, 2, false), (0x30AE, 2942, 2, false),
+    (0x30B0, 2944, 2, false), (0x30B2, 2946, 2, false), (0x30B4, 2948, 2, false), (0x30B6, 2950, 2, false),
+    (0x30B8, 2952, 2, false), (0x30BA, 2954, 2, false), (0x30BC, 2956, 2, false), (0x30BE, 2958, 2, false),
+    (0x30C0, 2960, 2, false), (0x30C2, 2962, 2, false), (0x30C5, 2964, 2, false), (0x30C7, 2966, 2, false),
+    (0x30C9, 2968, 2, false), (0x30D0, 2970, 2, false), (0x30D1, 2972, 2, false), (0x30D3, 2974, 2, false),
+    (0x30D4, 2976, 2, false), (0x30D6, 2978, 2, false), (0x30D7, 2980, 2, false), (0x30D9, 2982, 2, false),
+    (0x30DA, 2984, 2, false), (0x30DC, 2986, 2, false), (0x30DD, 2988, 2, false), (0x30F4, 2990, 2, false),
+    (0x30F7, 2992, 2, false), (0x30F8, 2994, 2, false), (0x30F9, 2996, 2, false), (0x30FA, 2998, 2, false),
+    (0x30FE, 3000, 2, false), (0x30FF, 3002, 2, true), (0x3131, 3004, 1, true), (0x3132, 3005, 1, true),
+    (0x3133, 3006, 1, true), (0x3134, 3007, 1, true), (0x3135, 3008, 1, true), (0x3136, 3009, 1, true),
+    (0x3137, 3010, 1, true), (0x3138, 3011, 1, true), (0x3139, 3012, 1, true), (0x313A, 3013, 1, true),
+    (0x313B, 3014, 1, true), (0x313C, 3015, 1, true), (0x313D, 3016, 1, true), (0x313E, 3017, 1, true),
+    (0x313F, 3018, 1, true), (0x3140, 3019, 1, true), (0x3141, 3020, 1, true), (0x3142, 3021, 1, true),
+    (0x3143, 3022, 1, true), (0x3144, 3023, 1, true), (0x3145, 3024, 1, true), (0x3146, 3025, 1, true),
+    (0x3147, 3026, 1, true), (0x3148, 3027, 1, true), (0x3149, 3028, 1, true), (0x314A, 3029, 1, true),
+    (0x314B, 3030, 1, true), (0x314C, 3031, 1, true), (0x314D, 3032, 1, true), (0x314E, 3033, 1, true),
+    (0x314F, 3034, 1, true), (0x3150, 3035, 1, true), (0x3151, 3036, 1, true), (0x3152, 3037, 1, true),
+    (0x3153, 3038, 1, true), (0x3154, 3039, 1, true), (0x3155, 3040, 1, true), (0x3156, 3041, 1, true),
+    (0x3157, 3042, 1, true), (0x3158, 3043, 1, true), (0x3159, 3044, 1, true), (0x315A, 3045, 1, true),
+    (0x315B, 3046, 1, true), (0x315C, 3047, 1, true), (0x315D, 3048, 1, true), (0x315E, 3049, 1, true),
+    (0x315F, 3050, 1, true), (0x3160, 3051, 1, true), (0x3161, 3052, 1, true), (0x3162, 3053, 1, true),
+    (0x3163, 3054, 1, true), (0x3164, 3055, 1, true), (0x3165, 3056, 1, true), (0x3166, 3057, 1, true),
+    (0x3167, 3058, 1, true), (0x3168, 3059, 1, true), (0x3169, 3060, 1, true), (0x316A, 3061, 1, true),
+    (0x316B, 3062, 1, true), (0x316C, 3063, 1, true), (0x316D, 3064, 1, true), (0x316E, 3065, 1, true),
+    (0x316F, 3066, 1, true), (0x3170, 3067, 1, true), (0x3171, 3068, 1, true), (0x3172, 3069, 1, true),
+    (0x3173, 3070, 1, true), (0x3174, 3071, 1, true), (0x3175, 3072, 1, true), (0x3176, 3073, 1, true),
+    (0x3177, 3074, 1, true), (0x3178, 3075, 1, true), (0x3179, 3076, 1, true), (0x317A, 3077, 1, true),
+    (0x317B, 3078, 1, true), (0x317C, 3079, 1, true), (0x317D, 3080, 1, true), (0x317E, 3081, 1, true),
+    (0x317F, 3082, 1, true), (0x3180, 3083, 1, true), (0x3181, 3084, 1, true), (0x3182, 3085, 1, true),
+    (0x3183, 3086, 1, true), (0x3184, 3087, 1, true), (0x3185, 3088, 1, true), (0x3186, 3089, 1, true),
+    (0x3187, 3090, 1, true), (0x3188, 3091, 1, true), (0x3189, 3092, 1, true), (0x318A, 3093, 1, true),
+    (0x318B, 3094, 1, true), (0x318C, 3095, 1, true), (0x318D, 3096, 1, true), (0x318E, 3097, 1, true),
+    (0x3192, 3098, 1, true), (0x3193, 3099, 1, true), (0x3194, 3100, 1, true), (0x3195, 3101, 1, true),
+    (0x3196, 3102, 1, true), (0x3197, 3103, 1, true), (0x3198, 3104, 1, true), (0x3199, 3105, 1, true),
+    (0x319A, 3106, 1, true), (0x319B, 3107, 1, true), (0x319C, 3108, 1, true), (0x319D, 3109, 1, true),
+    (0x319E, 3110, 1, true), (0x319F, 3111, 1, true), (0x3200, 3112, 3, true), (0x3201, 3115, 3, true),
+    (0x3202, 3118, 3, true), (0x3203, 3121, 3, true), (0x3204, 3124, 3, true), (0x3205, 3127, 3, true),
+    (0x3206, 3130, 3, true), (0x3207, 3133, 3, true), (0x3208, 3136, 3, true), (0x3209, 3139, 3, true),
+    (0x320A, 3142, 3, true), (0x320B, 3145, 3, true), (0x320C, 3148, 3, true), (0x320D, 3151, 3, true),
+    (0x320E, 3154, 4, true), (0x320F, 3158, 4, true), (0x3210, 3162, 4, true), (0x3211, 3166, 4, true),
+    (0x3212, 3170, 4, true), (0x3213, 3174, 4, true), (0x3214, 3178, 4, true), (0x3215, 3182, 4, true),
+    (0x3216, 3186, 4, true), (0x3217, 3190, 4, true), (0x3218, 3194, 4, true), (0x3219, 3198, 4, true),
+    (0x321A, 3202, 4, true), (0x321B, 3206, 4, true), (0x321C, 3210, 4, true), (0x321D, 3214, 7, true),
+    (0x321E, 3221, 6, true), (0x3220, 3227, 3, true), (0x3221, 3230, 3, true), (0x3222, 3233, 3, true),
+    (0x3223, 3236, 3, true), (0x3224, 3239, 3, true), (0x3225, 3242, 3, true), (0x3226, 3245, 3, true),
+    (0x3227, 3248, 3, true), (0x3228, 3251, 3, true), (0x3229, 3254, 3, true), (0x322A, 3257, 3, true),
+    (0x322B, 3260, 3, true), (0x322C, 3263, 3, true), (0x322D, 3266, 3, true), (0x322E, 3269, 3, true),
+    (0x322F, 3272, 3, true), (0x3230, 3275, 3, true), (0x3231, 3278, 3, true), (0x3232, 3281, 3, true),
+    (0x3233, 3284, 3, true), (0x3234, 3287, 3, true), (0x3235, 3290, 3, true), (0x3236, 3293, 3, true),
+    (0x3237, 3296, 3, true), (0x3238, 3299, 3, true), (0x3239, 3302, 3, true), (0x323A, 3305, 3, true),
+    (0x323B, 3308, 3, true), (0x323C, 3311, 3, true), (0x323D, 3314, 3, true), (0x323E, 3317, 3, true),
+    (0x323F, 3320, 3, true), (0x3240, 3323, 3, true), (0x3241, 3326, 3, true), (0x3242, 3329, 3, true),
+    (0x3243, 3332, 3, true), (0x3244, 3335, 1, true), (0x3245, 3336, 1, true), (0x3246, 3337, 1, true),
+    (0x3247, 3338, 1, true), (0x3250, 3339, 3, true), (0x3251, 3342, 2, true), (0x3252, 3344, 2, true),
+    (0x3253, 3346, 2, true), (0x3254, 3348, 2, true), (0x3255, 3350, 2, true), (0x3256, 3352, 2, true),
+    (0x3257, 3354, 2, true), (0x3258, 3356, 2, true), (0x3259, 3358, 2, true), (0x325A, 3360, 2, true),
+    (0x325B, 3362, 2, true), (0x325C, 3364, 2, true), (0x325D, 3366, 2, true), (0x325E, 3368, 2, true),
+    (0x325F, 3370, 2, true), (0x3260, 3372, 1, true), (0x3261, 3373, 1, true), (0x3262, 3374, 1, true),
+    (0x3263, 3375, 1, true), (0x3264, 3376, 1, true), (0x3265, 3377, 1, true), (0x3266, 3378, 1, true),
+    (0x3267, 3379, 1, true), (0x3268, 3380, 1, true), (0x3269, 3381, 1, true), (0x326A, 3382, 1, true),
+    (0x326B, 3383, 1, true), (0x326C, 3384, 1, true), (0x326D, 3385, 1, true), (0x326E, 3386, 2, true),
+    (0x326F, 3388, 2, true), (0x3270, 3390, 2, true), (0x3271, 3392, 2, true), (0x3272, 3394, 2, true),
+    (0x3273, 3396, 2, true), (0x3274, 3398, 2, true), (0x3275, 3400, 2, true), (0x3276, 3402, 2, true),
+    (0x3277, 3404, 2, true), (0x3278, 3406, 2, true), (0x3279, 3408, 2, true), (0x327A, 3410, 2, true),
+    (0x327B, 3412, 2, true), (0x327C, 3414, 5, true), (0x327D, 3419, 4, true), (0x327E, 3423, 2, true),
+    (0x3280, 3425, 1, true), (0x3281, 3426, 1, true), (0x3282, 3427, 1, true), (0x3283, 3428, 1, true),
+    (0x3284, 3429, 1, true), (0x3285, 3430, 1, true), (0x3286, 3431, 1, true), (0x3287, 3432, 1, true),
+    (0x3288, 3433, 1, true), (0x3289, 3434, 1, true), (0x328A, 3435, 1, true), (0x328B, 3436, 1, true),
+    (0x328C, 3437, 1, true), (0x328D, 3438, 1, true), (0x328E, 3439, 1, true), (0x328F, 3440, 1, true),
+    (0x3290, 3441, 1, true), (0x3291, 3442, 1, true), (0x3292, 3443, 1, true), (0x3293, 3444, 1, true),
+    (0x3294, 3445, 1, true), (0x3295, 3446, 1, true), (0x3296, 3447, 1, true), (0x3297, 3448, 1, true),
+    (0x3298, 3449, 1, true), (0x3299, 3450, 1, true), (0x329A, 3451, 1, true), (0x329B, 3452, 1, true),
+    (0x329C, 3453, 1, true), (0x329D, 3454, 1, true), (0x329E, 3455, 1, true), (0x329F, 3456, 1, true),
+    (0x32A0, 3457, 1, true), (0x32A1, 3458, 1, true), (0x32A2, 3459, 1, true), (0x32A3, 3460, 1, true),
+    (0x32A4, 3461, 1, true), (0x32A5, 3462, 1, true), (0x32A6, 3463, 1, true), (0x32A7, 3464, 1, true),
+    (0x32A8, 3465, 1, true), (0x32A9, 3466, 1, true), (0x32AA, 3467, 1, true), (0x32AB, 3468, 1, true),
+    (0x32AC, 3469, 1, true), (0x32AD, 3470, 1, true), (0x32AE, 3471, 1, true), (0x32AF, 3472, 1, true),
+    (0x32B0, 3473, 1, true), (0x32B1, 3474, 2, true), (0x32B2, 3476, 2, true), (0x32B3, 3478, 2, true),
+    (0x32B4, 3480, 2, true), (0x32B5, 3482, 2, true), (0x32B6, 3484, 2, true), (0x32B7, 3486, 2, true),
+    (0x32B8, 3488, 2, true), (0x32B9, 3490, 2, true), (0x32BA, 3492, 2, true), (0x32BB, 3494, 2, true),
+    (0x32BC, 3496, 2, true), (0x32BD, 3498, 2, true), (0x32BE, 3500, 2, true), (0x32BF, 3502, 2, true),
+    (0x32C0, 3504, 2, true), (0x32C1, 3506, 2, true), (0x32C2, 3508, 2, true), (0x32C3, 3510, 2, true),
+    (0x32C4, 3512, 2, true), (0x32C5, 3514, 2, true), (0x32C6, 3516, 2, true), (0x32C7, 3518, 2, true),
+    (0x32C8, 3520, 2, true), (0x32C9, 3522, 3, true), (0x32CA, 3525, 3, true), (0x32CB, 3528, 3, true),
+    (0x32CC, 3531, 2, true), (0x32CD, 3533, 3, true), (0x32CE, 3536, 2, true), (0x32CF, 3538, 3, true),
+    (0x32D0, 3541, 1, true), (0x32D1, 3542, 1, true), (0x32D2, 3543, 1, true), (0x32D3, 3544, 1, true),
+    (0x32D4, 3545, 1, true), (0x32D5, 3546, 1, true), (0x32D6, 3547, 1, true), (0x32D7, 3548, 1, true),
+    (0x32D8, 3549, 1, true), (0x32D9, 3550, 1, true), (0x32DA, 3551, 1, true), (0x32DB, 3552, 1, true),
+    (0x32DC, 3553, 1, true), (0x32DD, 3554, 1, true), (0x32DE, 3555, 1, true), (0x32DF, 3556, 1, true),
+    (0x32E0, 3557, 1, true), (0x32E1, 3558, 1, true), (0x32E2, 3559, 1, true), (0x32E3, 3560, 1, true),
+    (0x32E4, 3561, 1, true), (0x32E5, 3562, 1, true), (0x32E6, 3563, 1, true), (0x32E7, 3564, 1, true),
+    (0x32E8, 3565, 1, true), (0x32E9, 3566, 1, true), (0x32EA, 3567, 1, true), (0x32EB, 3568, 1, true),
+    (0x32EC, 3569, 1, true), (0x32ED, 3570, 1, true), (0x32EE, 3571, 1, true), (0x32EF, 3572, 1, true),
+    (0x32F0, 3573, 1, true), (0x32F1, 3574, 1, true), (0x32F2, 3575, 1, true), (0x32F3, 3576, 1, true),
+    (0x32F4, 3577, 1, true), (0x32F5, 3578, 1, true), (0x32F6, 3579, 1, true), (0x32F7, 3580, 1, true),
+    (0x32F8, 3581, 1, true), (0x32F9, 3582, 1, true), (0x32FA, 3583, 1, true), (0x32FB, 3584, 1, true),
+    (0x32FC, 3585, 1, true), (0x32FD, 3586, 1, true), (0x32FE, 3587, 1, true), (0x32FF, 3588, 2, true),
+    (0x3300, 3590, 4, true), (0x3301, 3594, 4, true), (0x3302, 3598, 4, true), (0x3303, 3602, 3, true),
+    (0x3304, 3605, 4, true), (0x3305, 3609, 3, true), (0x3306, 3612, 3, true), (0x3307, 3615, 5, true),
+    (0x3308, 3620, 4, true), (0x3309, 3624, 3, true), (0x330A, 3627, 3, true), (0x330B, 3630, 3, true),
+    (0x330C, 3633, 4, true), (0x330D, 3637, 4, true), (0x330E, 3641, 3, true), (0x330F, 3644, 3, true),
+    (0x3310, 3647, 2, true), (0x3311, 3649, 3, true), (0x3312, 3652, 4, true), (0x3313, 3656, 4, true),
+    (0x3314, 3660, 2, true), (0x3315, 3662, 5, true), (0x3316, 3667, 6, true), (0x3317, 3673, 5, true),
+    (0x3318, 3678, 3, true), (0x3319, 3681, 5, true), (0x331A, 3686, 5, true), (0x331B, 3691, 4, true),
+    (0x331C, 3695, 3, true), (0x331D, 3698, 3, true), (0x331E, 3701, 3, true), (0x331F, 3704, 4, true),
+    (0x3320, 3708, 5, true), (0x3321, 3713, 4, true), (0x3322, 3717, 3, true), (0x3323, 3720, 3, true),
+    (0x3324, 3723, 3, true), (0x3325, 3726, 2, true), (0x3326, 3728, 2, true), (0x3327, 3730, 2, true),
+    (0x3328, 3732, 2, true), (0x3329, 3734, 3, true), (0x332A, 3737, 3, true), (0x332B, 3740, 5, true),
+    (0x332C, 3745, 3, true), (0x332D, 3748, 4, true), (0x332E, 3752, 5, true), (0x332F, 3757, 3, true),
+    (0x3330, 3760, 2, true), (0x3331, 3762, 2, true), (0x3332, 3764, 5, true), (0x3333, 3769, 4, true),
+    (0x3334, 3773, 5, true), (0x3335, 3778, 3, true), (0x3336, 3781, 5, true), (0x3337, 3786, 2, true),
+    (0x3338, 3788, 3, true), (0x3339, 3791, 3, true), (0x333A, 3794, 3, true), (0x333B, 3797, 3, true),
+    (0x333C, 3800, 3, true), (0x333D, 3803, 4, true), (0x333E, 3807, 3, true), (0x333F, 3810, 2, true),
+    (0x3340, 3812, 3, true), (0x3341, 3815, 3, true), (0x3342, 3818, 3, true), (0x3343, 3821, 4, true),
+    (0x3344, 3825, 3, true), (0x3345, 3828, 3, true), (0x3346, 3831, 3, true), (0x3347, 3834, 5, true),
+    (0x3348, 3839, 4, true), (0x3349, 3843, 2, true), (0x334A, 3845, 5, true), (0x334B, 3850, 2, true),
+    (0x334C, 3852, 4, true), (0x334D, 3856, 4, true), (0x334E, 3860, 3, true), (0x334F, 3863, 3, true),
+    (0x3350, 3866, 3, true), (0x3351, 3869, 4, true), (0x3352, 3873, 2, true), (0x3353, 3875, 3, true),
+    (0x3354, 3878, 4, true), (0x3355, 3882, 2, true), (0x3356, 3884, 5, true), (0x3357, 3889, 3, true),
+    (0x3358, 3892, 2, true), (0x3359, 3894, 2, true), (0x335A, 3896, 2, true), (0x335B, 3898, 2, true),
+    (0x335C, 3900, 2, true), (0x335D, 3902, 2, true), (0x335E, 3904, 2, true), (0x335F, 3906, 2, true),
+    (0x3360, 3908, 2, true), (0x3361, 3910, 2, true), (0x3362, 3912, 3, true), (0x3363, 3915, 3, true),
+    (0x3364, 3918, 3, true), (0x3365, 3921, 3, true), (0x3366, 3924, 3, true), (0x3367, 3927, 3, true),
+    (0x3368, 3930, 3, true), (0x3369, 3933, 3, true), (0x336A, 3936, 3, true), (0x336B, 3939, 3, true),
+    (0x336C, 3942, 3, true), (0x336D, 3945, 3, true), (0x336E, 3948, 3, true), (0x336F, 3951, 3, true),
+    (0x3370, 3954, 3, true), (0x3371, 3957, 3, true), (0x3372, 3960, 2, true), (0x3373, 3962, 2, true),
+    (0x3374, 3964, 3, true), (0x3375, 3967, 2, true), (0x3376, 3969, 2, true), (0x3377, 3971, 2, true),
+    (0x3378, 3973, 3, true), (0x3379, 3976, 3, true), (0x337A, 3979, 2, true), (0x337B, 3981, 2, true),
+    (0x337C, 3983, 2, true), (0x337D, 3985, 2, true), (0x337E, 3987, 2, true), (0x337F, 3989, 4, true),
+    (0x3380, 3993, 2, true), (0x3381, 3995, 2, true), (0x3382, 3997, 2, true), (0x3383, 3999, 2, true),
+    (0x3384, 4001, 2, true), (0x3385, 4003, 2, true), (0x3386, 4005, 2, true), (0x3387, 4007, 2, true),
+    (0x3388, 4009, 3, true), (0x3389, 4012, 4, true), (0x338A, 4016, 2, true), (0x338B, 4018, 2, true),
+    (0x338C, 4020, 2, true), (0x338D, 4022, 2, true), (0x338E, 4024, 2, true), (0x338F, 4026, 2, true),
+    (0x3390, 4028, 2, true), (0x3391, 4030, 3, true), (0x3392, 4033, 3, true), (0x3393, 4036, 3, true),
+    (0x3394, 4039, 3, true), (0x3395, 4042, 2, true), (0x3396, 4044, 2, true), (0x3397, 4046, 2, true),
+    (0x3398, 4048, 2, true), (0x3399, 4050, 2, true), (0x339A, 4052, 2, true), (0x339B, 4054, 2, true),
+    (0x339C, 4056, 2, true), (0x339D, 4058, 2, true), (0x339E, 4060, 2, true), (0x339F, 4062, 3, true),
+    (0x33A0, 4065, 3, true), (0x33A1, 4068, 2, true), (0x33A2, 4070, 3, true), (0x33A3, 4073, 3, true),
+    (0x33A4, 4076, 3, true), (0x33A5, 4079, 2, true), (0x33A6, 4081, 3, true), (0x33A7, 4084, 3, true),
+    (0x33A8, 4087, 4, true), (0x33A9, 4091, 2, true), (0x33AA, 4093, 3, true), (0x33AB, 4096, 3, true),
+    (0x33AC, 4099, 3, true), (0x33AD, 4102, 3, true), (0x33AE, 4105, 5, true), (0x33AF, 4110, 6, true),
+    (0x33B0, 4116, 2, true), (0x33B1, 4118, 2, true), (0x33B2, 4120, 2, true), (0x33B3, 4122, 2, true),
+    (0x33B4, 4124, 2, true), (0x33B5, 4126, 2, true), (0x33B6, 4128, 2, true), (0x33B7, 4130, 2, true),
+    (0x33B8, 4132, 2, true), (0x33B9, 4134, 2, true), (0x33BA, 4136, 2, true), (0x33BB, 4138, 2, true),
+    (0x33BC, 4140, 2, true), (0x33BD, 4142, 2, true), (0x33BE, 4144, 2, true), (0x33BF, 4146, 2, true),
+    (0x33C0, 4148, 2, true), (0x33C1, 4150, 2, true), (0x33C2, 4152, 4, true), (0x33C3, 4156, 2, true),
+    (0x33C4, 4158, 2, true), (0x33C5, 4160, 2, true), (0x33C6, 4162, 4, true), (0x33C7, 4166, 3, true),
+    (0x33C8, 4169, 2, true), (0x33C9, 4171, 2, true), (0x33CA, 4173, 2, true), (0x33CB, 4175, 2, true),
+    (0x33CC, 4177, 2, true), (0x33CD, 4179, 2, true), (0x33CE, 4181, 2, true), (0x33CF, 4183, 2, true),
+    (0x33D0, 4185, 2, true), (0x33D1, 4187, 2, true), (0x33D2, 4189, 3, true), (0x33D3, 4192, 2, true),
+    (0x33D4, 4194, 2, true), (0x33D5, 4196, 3, true), (0x33D6, 4199, 3, true), (0x33D7, 4202, 2, true),
+    (0x33D8, 4204, 4, true), (0x33D9, 4208, 3, true), (0x33DA, 4211, 2, true), (0x33DB, 4213, 2, true),
+    (0x33DC, 4215, 2, true), (0x33DD, 4217, 2, true), (0x33DE, 4219, 3, true), (0x33DF, 4222, 3, true),
+    (0x33E0, 4225, 2, true), (0x33E1, 4227, 2, true), (0x33E2, 4229, 2, true), (0x33E3, 4231, 2, true),
+    (0x33E4, 4233, 2, true), (0x33E5, 4235, 2, true), (0x33E6, 4237, 2, true), (0x33E7, 4239, 2, true),
+    (0x33E8, 4241, 2, true), (0x33E9, 4243, 3, true), (0x33EA, 4246, 3, true), (0x33EB, 4249, 3, true),
+    (0x33EC, 4252, 3, true), (0x33ED, 4255, 3, true), (0x33EE, 4258, 3, true), (0x33EF, 4261, 3, true),
+    (0x33F0, 4264, 3, true), (0x33F1, 4267, 3, true), (0x33F2, 4270, 3, true), (0x33F3, 4273, 3, true),
+    (0x33F4, 4276, 3, true), (0x33F5, 4279, 3, true), (0x33F6, 4282, 3, true), (0x33F7, 4285, 3, true),
+    (0x33F8, 4288, 3, true), (0x33F9, 4291, 3, true), (0x33FA, 4294, 3, true), (0x33FB, 4297, 3, true),
+    (0x33FC, 4300, 3, true), (0x33FD, 4303, 3, true), (0x33FE, 4306, 3, true), (0x33FF, 4309, 3, true),
+    (0xA69C, 4312, 1, true), (0xA69D, 4313, 1, true), (0xA770, 4314, 1, true), (0xA7F1, 4315, 1, true),
+    (0xA7F2, 4316, 1, true), (0xA7F3, 4317, 1, true), (0xA7F4, 4318, 1, true), (0xA7F8, 4319, 1, true),
+    (0xA7F9, 4320, 1, true), (0xAB5C, 4321, 1, true), (0xAB5D, 4322, 1, true), (0xAB5E, 4323, 1, true),
+    (0xAB5F, 4324, 1, true), (0xAB69, 4325, 1, true), (0xF900, 4326, 1, false), (0xF901, 4327, 1, false),
+    (0xF902, 4328, 1, false), (0xF903, 4329, 1, false), (0xF904, 4330, 1, false), (0xF905, 4331, 1, false),
+    (0xF906, 4332, 1, false), (0xF907, 4333, 1, false), (0xF908, 4334, 1, false), (0xF909, 4335, 1, false),
+    (0xF90A, 4336, 1, false), (0xF90B, 4337, 1, false), (0xF90C, 4338, 1, false), (0xF90D, 4339, 1, false),
+    (0xF90E, 4340, 1, false), (0xF90F, 4341, 1, false), (0xF910, 4342, 1, false), (0xF911, 4343, 1, false),
+    (0xF912, 4344, 1, false), (0xF913, 4345, 1, false), (0xF914, 4346, 1, false), (0xF915, 4347, 1, false),
+    (0xF916, 4348, 1, false), (0xF917, 4349, 1, false), (0xF918, 4350, 1, false), (0xF919, 4351, 1, false),
+    (0xF91A, 4352, 1, false), (0xF91B, 4353, 1, false), (0xF91C, 4354, 1, false), (0xF91D, 4355, 1, false),
+    (0xF91E, 4356, 1, false), (0xF91F, 4357, 1, false), (0xF920, 4358, 1, false), (0xF921, 4359, 1, false),
+    (0xF922, 4360, 1, false), (0xF923, 4361, 1, false), (0xF924, 4362, 1, false), (0xF925, 4363, 1, false),
+    (0xF926, 4364, 1, false), (0xF927, 4365, 1, false), (0xF928, 4366, 1, false), (0xF929, 4367, 1, false),
+    (0xF92A, 4368, 1, false), (0xF92B, 4369, 1, false), (0xF92C, 4370, 1, false), (0xF92D, 4371, 1, false),
+    (0xF92E, 4372, 1, false), (0xF92F, 4373, 1, false), (0xF930, 4374, 1, false), (0xF931, 4375, 1, false),
+    (0xF932, 4376, 1, false), (0xF933, 4377, 1, false), (0xF934, 4378, 1, false), (0xF935, 4379, 1, false),
+    (0xF936, 4380, 1, false), (0xF937, 4381, 1, false), (0xF938, 4382, 1, false), (0xF939, 4383, 1, false),
+    (0xF93A, 4384, 1, false), (0xF93B, 4385, 1, false), (0xF93C, 4386, 1, false), (0xF93D, 4387, 1, false),
+    (0xF93E, 4388, 1, false), (0xF93F, 4389, 1, false), (0xF940, 4390, 1, false), (0xF941, 4391, 1, false),
+    (0xF942, 4392, 1, false), (0xF943, 4393, 1, false), (0xF944, 4394, 1, false), (0xF945, 4395, 1, false),
+    (0xF946, 4396, 1, false), (0xF947, 4397, 1, false), (0xF948, 4398, 1, false), (0xF949, 4399, 1, false),
+    (0xF94A, 4400, 1, false), (0xF94B, 4401, 1, false), (0xF94C, 4402, 1, false), (0xF94D, 4403, 1, false),
+    (0xF94E, 4404, 1, false), (0xF94F, 4405, 1, false), (0xF950, 4406, 1, false), (0xF951, 4407, 1, false),
+    (0xF952, 4408, 1, false), (0xF953, 4409, 1, false), (0xF954, 4410, 1, false), (0xF955, 4411, 1, false),
+    (0xF956, 4412, 1, false), (0xF957, 4413, 1, false), (0xF958, 4414, 1, false), (0xF959, 4415, 1, false),
+    (0xF95A, 4416, 1, false), (0xF95B, 4417, 1, false), (0xF95C, 4418, 1, false), (0xF95D, 4419, 1, false),
+    (0xF95E, 4420, 1, false), (0xF95F, 4421, 1, false), (0xF960, 4422, 1, false), (0xF961, 4423, 1, false),
+    (0xF962, 4424, 1, false), (0xF963, 4425, 1, false), (0xF964, 4426, 1, false), (0xF965, 4427, 1, false),
+    (0xF966, 4428, 1, false), (0xF967, 4429, 1, false), (0xF968, 4430, 1, false), (0xF969, 4431, 1, false),
+    (0xF96A, 4432, 1, false), (0xF96B, 4433, 1, false), (0xF96C, 4434, 1, false), (0xF96D, 4435, 1, false),
+    (0xF96E, 4436, 1, false), (0xF96F, 4437, 1, false), (0xF970, 4438, 1, false), (0xF971, 4439, 1, false),
+    (0xF972, 4440, 1, false), (0xF973, 4441, 1, false), (0xF974, 4442, 1, false), (0xF975, 4443, 1, false),
+    (0xF976, 4444, 1, false), (0xF977, 4445, 1, false), (0xF978, 4446, 1, false), (0xF979, 4447, 1, false),
+    (0xF97A, 4448, 1, false), (0xF97B, 4449, 1, false), (0xF97C, 4450, 1, false), (0xF97D, 4451, 1, false),
+    (0xF97E, 4452, 1, false), (0xF97F, 4453, 1, false), (0xF980, 4454, 1, false), (0xF981, 4455, 1, false),
+    (0xF982, 4456, 1, false), (0xF983, 4457, 1, false), (0xF984, 4458, 1, false), (0xF985, 4459, 1, false),
+    (0xF986, 4460, 1, false), (0xF987, 4461, 1, false), (0xF988, 4462, 1, false), (0xF989, 4463, 1, false),
+    (0xF98A, 4464, 1, false), (0xF98B, 4465, 1, false), (0xF98C, 4466, 1, false), (0xF98D, 4467, 1, false),
+    (0xF98E, 4468, 1, false), (0xF98F, 4469, 1, false), (0xF990, 4470, 1, false), (0xF991, 4471, 1, false),
+    (0xF992, 4472, 1, false), (0xF993, 4473, 1, false), (0xF994, 4474, 1, false), (0xF995, 4475, 1, false),
+    (0xF996, 4476, 1, false), (0xF997, 4477, 1, false), (0xF998, 4478, 1, false), (0xF999, 4479, 1, false),
+    (0xF99A, 4480, 1, false), (0xF99B, 4481, 1, false), (0xF99C, 4482, 1, false), (0xF99D, 4483, 1, false),
+    (0xF99E, 4484, 1, false), (0xF99F, 4485, 1, false), (0xF9A0, 4486, 1, false), (0xF9A1, 4487, 1, false),
+    (0xF9A2, 4488, 1, false), (0xF9A3, 4489, 1, false), (0xF9A4, 4490, 1, false), (0xF9A5, 4491, 1, false),
+    (0xF9A6, 4492, 1, false), (0xF9A7, 4493, 1, false), (0xF9A8, 4494, 1, false), (0xF9A9, 4495, 1, false),
+    (0xF9AA, 4496, 1, false), (0xF9AB, 4497, 1, false), (0xF9AC, 4498, 1, false), (0xF9AD, 4499, 1, false),
+    (0xF9AE, 4500, 1, false), (0xF9AF, 4501, 1, false), (0xF9B0, 4502, 1, false), (0xF9B1, 4503, 1, false),
+    (0xF9B2, 4504, 1, false), (0xF9B3, 4505, 1, false), (0xF9B4, 4506, 1, false), (0xF9B5, 4507, 1, false),
+    (0xF9B6, 4508, 1, false), (0xF9B7, 4509, 1, false), (0xF9B8, 4510, 1, false), (0xF9B9, 4511, 1, false),
+    (0xF9BA, 4512, 1, false), (0xF9BB, 4513, 1, false), (0xF9BC, 4514, 1, false), (0xF9BD, 4515, 1, false),
+    (0xF9BE, 4516, 1, false), (0xF9BF, 4517, 1, false), (0xF9C0, 4518, 1, false), (0xF9C1, 4519, 1, false),
+    (0xF9C2, 4520, 1, false), (0xF9C3, 4521, 1, false), (0xF9C4, 4522, 1, false), (0xF9C5, 4523, 1, false),
+    (0xF9C6, 4524, 1, false), (0xF9C7, 4525, 1, false), (0xF9C8, 4526, 1, false), (0xF9C9, 4527, 1, false),
+    (0xF9CA, 4528, 1, false), (0xF9CB, 4529, 1, false), (0xF9CC, 4530, 1, false), (0xF9CD, 4531, 1, false),
+    (0xF9CE, 4532, 1, false), (0xF9CF, 4533, 1, false), (0xF9D0, 4534, 1, false), (0xF9D1, 4535, 1, false),
+    (0xF9D2, 4536, 1, false), (0xF9D3, 4537, 1, false), (0xF9D4, 4538, 1, false), (0xF9D5, 4539, 1, false),
+    (0xF9D6, 4540, 1, false), (0xF9D7, 4541, 1, false), (0xF9D8, 4542, 1, false), (0xF9D9, 4543, 1, false),
+    (0xF9DA, 4544, 1, false), (0xF9DB, 4545, 1, false), (0xF9DC, 4546, 1, false), (0xF9DD, 4547, 1, false),
+    (0xF9DE, 4548, 1, false), (0xF9DF, 4549, 1, false), (0xF9E0, 4550, 1, false), (0xF9E1, 4551, 1, false),
+    (0xF9E2, 4552, 1, false), (0xF9E3, 4553, 1, false), (0xF9E4, 4554, 1, false), (0xF9E5, 4555, 1, false),
+    (0xF9E6, 4556, 1, false), (0xF9E7, 4557, 1, false), (0xF9E8, 4558, 1, false), (0xF9E9, 4559, 1, false),
+    (0xF9EA, 4560, 1, false), (0xF9EB, 4561, 1, false), (0xF9EC, 4562, 1, false), (0xF9ED, 4563, 1, false),
+    (0xF9EE, 4564, 1, false), (0xF9EF, 4565, 1, false), (0xF9F0, 4566, 1, false), (0xF9F1, 4567, 1, false),
+    (0xF9F2, 4568, 1, false), (0xF9F3, 4569, 1, false), (0xF9F4, 4570, 1, false), (0xF9F5, 4571, 1, false),
+    (0xF9F6, 4572, 1, false), (0xF9F7, 4573, 1, false), (0xF9F8, 4574, 1, false), (0xF9F9, 4575, 1, false),
+    (0xF9FA, 4576, 1, false), (0xF9FB, 4577, 1, false), (0xF9FC, 4578, 1, false), (0xF9FD, 4579, 1, false),
+    (0xF9FE, 4580, 1, false), (0xF9FF, 4581, 1, false), (0xFA00, 4582, 1, false), (0xFA01, 4583, 1, false),
+    (0xFA02, 4584, 1, false), (0xFA03, 4585, 1, false), (0xFA04, 4586, 1, false), (0xFA05, 4587, 1, false),
+    (0xFA06, 4588, 1, false), (0xFA07, 4589, 1, false), (0xFA08, 4590, 1, false), (0xFA09, 4591, 1, false),
+    (0xFA0A, 4592, 1, false), (0xFA0B, 4593, 1, false), (0xFA0C, 4594, 1, false), (0xFA0D, 4595, 1, false),
+    (0xFA10, 4596, 1, false), (0xFA12, 4597, 1, false), (0xFA15, 4598, 1, false), (0xFA16, 4599, 1, false),
+    (0xFA17, 4600, 1, false), (0xFA18, 4601, 1, false), (0xFA19, 4602, 1, false), (0xFA1A, 4603, 1, false),
+    (0xFA1B, 4604, 1, false), (0xFA1C, 4605, 1, false), (0xFA1D, 4606, 1, false), (0xFA1E, 4607, 1, false),
+    (0xFA20, 4608, 1, false), (0xFA22, 4609, 1, false), (0xFA25, 4610, 1, false), (0xFA26, 4611, 1, false),
+    (0xFA2A, 4612, 1, false), (0xFA2B, 4613, 1, false), (0xFA2C, 4614, 1, false), (0xFA2D, 4615, 1, false),
+    (0xFA2E, 4616, 1, false), (0xFA2F, 4617, 1, false), (0xFA30, 4618, 1, false), (0xFA31, 4619, 1, false),
+    (0xFA32, 4620, 1, false), (0xFA33, 4621, 1, false), (0xFA34, 4622, 1, false), (0xFA35, 4623, 1, false),
+    (0xFA36, 4624, 1, false), (0xFA37, 4625, 1, false), (0xFA38, 4626, 1, false), (0xFA39, 4627, 1, false),
+    (0xFA3A, 4628, 1, false), (0xFA3B, 4629, 1, false), (0xFA3C, 4630, 1, false), (0xFA3D, 4631, 1, false),
+    (0xFA3E, 4632, 1, false), (0xFA3F, 4633, 1, false), (0xFA40, 4634, 1, false), (0xFA41, 4635, 1, false),
+    (0xFA42, 4636, 1, false), (0xFA43, 4637, 1, false), (0xFA44, 4638, 1, false), (0xFA45, 4639, 1, false),
+    (0xFA46, 4640, 1, false), (0xFA47, 4641, 1, false), (0xFA48, 4642, 1, false), (0xFA49, 4643, 1, false),
+    (0xFA4A, 4644, 1, false), (0xFA4B, 4645, 1, false), (0xFA4C, 4646, 1, false), (0xFA4D, 4647, 1, false),
+    (0xFA4E, 4648, 1, false), (0xFA4F, 4649, 1, false), (0xFA50, 4650, 1, false), (0xFA51, 4651, 1, false),
+    (0xFA52, 4652, 1, false), (0xFA53, 4653, 1, false), (0xFA54, 4654, 1, false), (0xFA55, 4655, 1, false),
+    (0xFA56, 4656, 1, false), (0xFA57, 4657, 1, false), (0xFA58, 4658, 1, false), (0xFA59, 4659, 1, false),
+    (0xFA5A, 4660, 1, false), (0xFA5B, 4661, 1, false), (0xFA5C, 4662, 1, false), (0xFA5D, 4663, 1, false),
+    (0xFA5E, 4664, 1, false), (0xFA5F, 4665, 1, false), (0xFA60, 4666, 1, false), (0xFA61, 4667, 1, false),
+    (0xFA62, 4668, 1, false), (0xFA63, 4669, 1, false), (0xFA64, 4670, 1, false), (0xFA65, 4671, 1, false),
+    (0xFA66, 4672, 1, false), (0xFA67, 4673, 1, false), (0xFA68, 4674, 1, false), (0xFA69, 4675, 1, false),
+    (0xFA6A, 4676, 1, false), (0xFA6B, 4677, 1, false), (0xFA6C, 4678, 1, false), (0xFA6D, 4679, 1, false),
+    (0xFA70, 4680, 1, false), (0xFA71, 4681, 1, false), (0xFA72, 4682, 1, false), (0xFA73, 4683, 1, false),
+    (0xFA74, 4684, 1, false), (0xFA75, 4685, 1, false), (0xFA76, 4686, 1, false), (0xFA77, 4687, 1, false),
+    (0xFA78, 4688, 1, false), (0xFA79, 4689, 1, false), (0xFA7A, 4690, 1, false), (0xFA7B, 4691, 1, false),
+    (0xFA7C, 4692, 1, false), (0xFA7D, 4693, 1, false), (0xFA7E, 4694, 1, false), (0xFA7F, 4695, 1, false),
+    (0xFA80, 4696, 1, false), (0xFA81, 4697, 1, false), (0xFA82, 4698, 1, false), (0xFA83, 4699, 1, false),
+    (0xFA84, 4700, 1, false), (0xFA85, 4701, 1, false), (0xFA86, 4702, 1, false), (0xFA87, 4703, 1, false),
+    (0xFA88, 4704, 1, false), (0xFA89, 4705, 1, false), (0xFA8A, 4706, 1, false), (0xFA8B, 4707, 1, false),
+    (0xFA8C, 4708, 1, false), (0xFA8D, 4709, 1, false), (0xFA8E, 4710, 1, false), (0xFA8F, 4711, 1, false),
+    (0xFA90, 4712, 1, false), (0xFA91, 4713, 1, false), (0xFA92, 4714, 1, false), (0xFA93, 4715, 1, false),
+    (0xFA94, 4716, 1, false), (0xFA95, 4717, 1, false), (0xFA96, 4718, 1, false), (0xFA97, 4719, 1, false),
+    (0xFA98, 4720, 1, false), (0xFA99, 4721, 1, false), (0xFA9A, 4722, 1, false), (0xFA9B, 4723, 1, false),
+    (0xFA9C, 4724, 1, false), (0xFA9D, 4725, 1, false), (0xFA9E, 4726, 1, false), (0xFA9F, 4727, 1, false),
+    (0xFAA0, 4728, 1, false), (0xFAA1, 4729, 1, false), (0xFAA2, 4730, 1, false), (0xFAA3, 4731, 1, false),
+    (0xFAA4, 4732, 1, false), (0xFAA5, 4733, 1, false), (0xFAA6, 4734, 1, false), (0xFAA7, 4735, 1, false),
+    (0xFAA8, 4736, 1, false), (0xFAA9, 4737, 1, false), (0xFAAA, 4738, 1, false), (0xFAAB, 4739, 1, false),
+    (0xFAAC, 4740, 1, false), (0xFAAD, 4741, 1, false), (0xFAAE, 4742, 1, false), (0xFAAF, 4743, 1, false),
+    (0xFAB0, 4744, 1, false), (0xFAB1, 4745, 1, false), (0xFAB2, 4746, 1, false), (0xFAB3, 4747, 1, false),
+    (0xFAB4, 4748, 1, false), (0xFAB5, 4749, 1, false), (0xFAB6, 4750, 1, false), (0xFAB7, 4751, 1, false),
+    (0xFAB8, 4752, 1, false), (0xFAB9, 4753, 1, false), (0xFABA, 4754, 1, false), (0xFABB, 4755, 1, false),
+    (0xFABC, 4756, 1, false), (0xFABD, 4757, 1, false), (0xFABE, 4758, 1, false), (0xFABF, 4759, 1, false),
+    (0xFAC0, 4760, 1, false), (0xFAC1, 4761, 1, false), (0xFAC2, 4762, 1, false), (0xFAC3, 4763, 1, false),
+    (0xFAC4, 4764, 1, false), (0xFAC5, 4765, 1, false), (0xFAC6, 4766, 1, false), (0xFAC7, 4767, 1, false),
+    (0xFAC8, 4768, 1, false), (0xFAC9, 4769, 1, false), (0xFACA, 4770, 1, false), (0xFACB, 4771, 1, false),
+    (0xFACC, 4772, 1, false), (0xFACD, 4773, 1, false), (0xFACE, 4774, 1, false), (0xFACF, 4775, 1, false),
+    (0xFAD0, 4776, 1, false), (0xFAD1, 4777, 1, false), (0xFAD2, 4778, 1, false), (0xFAD3, 4779, 1, false),
+    (0xFAD4, 4780, 1, false), (0xFAD5, 4781, 1, false), (0xFAD6, 4782, 1, false), (0xFAD7, 4783, 1, false),
+    (0xFAD8, 4784, 1, false), (0xFAD9, 4785, 1, false), (0xFB00, 4786, 2, true), (0xFB01, 4788, 2, true),
+    (0xFB02, 4790, 2, true), (0xFB03, 4792, 3, true), (0xFB04, 4795, 3, true), (0xFB05, 4798, 2, true),
+    (0xFB06, 4800, 2, true), (0xFB13, 4802, 2, true), (0xFB14, 4804, 2, true), (0xFB15, 4806, 2, true),
+    (0xFB16, 4808, 2, true), (0xFB17, 4810, 2, true), (0xFB1D, 4812, 2, false), (0xFB1F, 4814, 2, false),
+    (0xFB20, 4816, 1, true), (0xFB21, 4817, 1, true), (0xFB22, 4818, 1, true), (0xFB23, 4819, 1, true),
+    (0xFB24, 4820, 1, true), (0xFB25, 4821, 1, true), (0xFB26, 4822, 1, true), (0xFB27, 4823, 1, true),
+    (0xFB28, 4824, 1, true), (0xFB29, 4825, 1, true), (0xFB2A, 4826, 2, false), (0xFB2B, 4828, 2, false),
+    (0xFB2C, 4830, 2, false), (0xFB2D, 4832, 2, false), (0xFB2E, 4834, 2, false), (0xFB2F, 4836, 2, false),
+    (0xFB30, 4838, 2, false), (0xFB31, 4840, 2, false), (0xFB32, 4842, 2, false), (0xFB33, 4844, 2, false),
+    (0xFB34, 4846, 2, false), (0xFB35, 4848, 2, false), (0xFB36, 4850, 2, false), (0xFB38, 4852, 2, false),
+    (0xFB39, 4854, 2, false), (0xFB3A, 4856, 2, false), (0xFB3B, 4858, 2, false), (0xFB3C, 4860, 2, false),
+    (0xFB3E, 4862, 2, false), (0xFB40, 4864, 2, false), (0xFB41, 4866, 2, false), (0xFB43, 4868, 2, false),
+    (0xFB44, 4870, 2, false), (0xFB46, 4872, 2, false), (0xFB47, 4874, 2, false), (0xFB48, 4876, 2, false),
+    (0xFB49, 4878, 2, false), (0xFB4A, 4880, 2, false), (0xFB4B, 4882, 2, false), (0xFB4C, 4884, 2, false),
+    (0xFB4D, 4886, 2, false), (0xFB4E, 4888, 2, false), (0xFB4F, 4890, 2, true), (0xFB50, 4892, 1, true),
+    (0xFB51, 4893, 1, true), (0xFB52, 4894, 1, true), (0xFB53, 4895, 1, true), (0xFB54, 4896, 1, true),
+    (0xFB55, 4897, 1, true), (0xFB56, 4898, 1, true), (0xFB57, 4899, 1, true), (0xFB58, 4900, 1, true),
+    (0xFB59, 4901, 1, true), (0xFB5A, 4902, 1, true), (0xFB5B, 4903, 1, true), (0xFB5C, 4904, 1, true),
+    (0xFB5D, 4905, 1, true), (0xFB5E, 4906, 1, true), (0xFB5F, 4907, 1, true), (0xFB60, 4908, 1, true),
+    (0xFB61, 4909, 1, true), (0xFB62, 4910, 1, true), (0xFB63, 4911, 1, true), (0xFB64, 4912, 1, true),
+    (0xFB65, 4913, 1, true), (0xFB66, 4914, 1, true), (0xFB67, 4915, 1, true), (0xFB68, 4916, 1, true),
+    (0xFB69, 4917, 1, true), (0xFB6A, 4918, 1, true), (0xFB6B, 4919, 1, true), (0xFB6C, 4920, 1, true),
+    (0xFB6D, 4921, 1, true), (0xFB6E, 4922, 1, true), (0xFB6F, 4923, 1, true), (0xFB70, 4924, 1, true),
+    (0xFB71, 4925, 1, true), (0xFB72, 4926, 1, true), (0xFB73, 4927, 1, true), (0xFB74, 4928, 1, true),
+    (0xFB75, 4929, 1, true), (0xFB76, 4930, 1, true), (0xFB77, 4931, 1, true), (0xFB78, 4932, 1, true),
+    (0xFB79, 4933, 1, true), (0xFB7A, 4934, 1, true), (0xFB7B, 4935, 1, true), (0xFB7C, 4936, 1, true),
+    (0xFB7D, 4937, 1, true), (0xFB7E, 4938, 1, true), (0xFB7F, 4939, 1, true), (0xFB80, 4940, 1, true),
+    (0xFB81, 4941, 1, true), (0xFB82, 4942, 1, true), (0xFB83, 4943, 1, true), (0xFB84, 4944, 1, true),
+    (0xFB85, 4945, 1, true), (0xFB86, 4946, 1, true), (0xFB87, 4947, 1, true), (0xFB88, 4948, 1, true),
+    (0xFB89, 4949, 1, true), (0xFB8A, 4950, 1, true), (0xFB8B, 4951, 1, true), (0xFB8C, 4952, 1, true),
+    (0xFB8D, 4953, 1, true), (0xFB8E, 4954, 1, true), (0xFB8F, 4955, 1, true), (0xFB90, 4956, 1, true),
+    (0xFB91, 4957, 1, true), (0xFB92, 4958, 1, true), (0xFB93, 4959, 1, true), (0xFB94, 4960, 1, true),
+    (0xFB95, 4961, 1, true), (0xFB96, 4962, 1, true), (0xFB97, 4963, 1, true), (0xFB98, 4964, 1, true),
+    (0xFB99, 4965, 1, true), (0xFB9A, 4966, 1, true), (0xFB9B, 4967, 1, true), (0xFB9C, 4968, 1, true),
+    (0xFB9D, 4969, 1, true), (0xFB9E, 4970, 1, true), (0xFB9F, 4971, 1, true), (0xFBA0, 4972, 1, true),
+    (0xFBA1, 4973, 1, true), (0xFBA2, 4974, 1, true), (0xFBA3, 4975, 1, true), (0xFBA4, 4976, 1, true),
+    (0xFBA5, 4977, 1, true), (0xFBA6, 4978, 1, true), (0xFBA7, 4979, 1, true), (0xFBA8, 4980, 1, true),
+    (0xFBA9, 4981, 1, true), (0xFBAA, 4982, 1, true), (0xFBAB, 4983, 1, true), (0xFBAC, 4984, 1, true),
+    (0xFBAD, 4985, 1, true), (0xFBAE, 4986, 1, true), (0xFBAF, 4987, 1, true), (0xFBB0, 4988, 1, true),
+    (0xFBB1, 4989, 1, true), (0xFBD3, 4990, 1, true), (0xFBD4, 4991, 1, true), (0xFBD5, 4992, 1, true),
+    (0xFBD6, 4993, 1, true), (0xFBD7, 4994, 1, true), (0xFBD8, 4995, 1, true), (0xFBD9, 4996, 1, true),
+    (0xFBDA, 4997, 1, true), (0xFBDB, 4998, 1, true), (0xFBDC, 4999, 1, true), (0xFBDD, 5000, 1, true),
+    (0xFBDE, 5001, 1, true), (0xFBDF, 5002, 1, true), (0xFBE0, 5003, 1, true), (0xFBE1, 5004, 1, true),
+    (0xFBE2, 5005, 1, true), (0xFBE3, 5006, 1, true), (0xFBE4, 5007, 1, true), (0xFBE5, 5008, 1, true),
+    (0xFBE6, 5009, 1, true), (0xFBE7, 5010, 1, true), (0xFBE8, 5011, 1, true), (0xFBE9, 5012, 1, true),
+    (0xFBEA, 5013, 2, true), (0xFBEB, 5015, 2, true), (0xFBEC, 5017, 2, true), (0xFBED, 5019, 2, true),
+    (0xFBEE, 5021, 2, true), (0xFBEF, 5023, 2, true), (0xFBF0, 5025, 2, true), (0xFBF1, 5027, 2, true),
+    (0xFBF2, 5029, 2, true), (0xFBF3, 5031, 2, true), (0xFBF4, 5033, 2, true), (0xFBF5, 5035, 2, true),
+    (0xFBF6, 5037, 2, true), (0xFBF7, 5039, 2, true), (0xFBF8, 5041, 2, true), (0xFBF9, 5043, 2, true),
+    (0xFBFA, 5045, 2, true), (0xFBFB, 5047, 2, true), (0xFBFC, 5049, 1, true), (0xFBFD, 5050, 1, true),
+    (0xFBFE, 5051, 1, true), (0xFBFF, 5052, 1, true), (0xFC00, 5053, 2, true), (0xFC01, 5055, 2, true),
+    (0xFC02, 5057, 2, true), (0xFC03, 5059, 2, true), (0xFC04, 5061, 2, true), (0xFC05, 5063, 2, true),
+    (0xFC06, 5065, 2, true), (0xFC07, 5067, 2, true), (0xFC08, 5069, 2, true), (0xFC09, 5071, 2, true),
+    (0xFC0A, 5073, 2, true), (0xFC0B, 5075, 2, true), (0xFC0C, 5077, 2, true), (0xFC0D, 5079, 2, true),
+    (0xFC0E, 5081, 2, true), (0xFC0F, 5083, 2, true), (0xFC10, 5085, 2, true), (0xFC11, 5087, 2, true),
+    (0xFC12, 5089, 2, true), (0xFC13, 5091, 2, true), (0xFC14, 5093, 2, true), (0xFC15, 5095, 2, true),
+    (0xFC16, 5097, 2, true), (0xFC17, 5099, 2, true), (0xFC18, 5101, 2, true), (0xFC19, 5103, 2, true),
+    (0xFC1A, 5105, 2, true), (0xFC1B, 5107, 2, true), (0xFC1C, 5109, 2, true), (0xFC1D, 5111, 2, true),
+    (0xFC1E, 5113, 2, true), (0xFC1F, 5115, 2, true), (0xFC20, 5117, 2, true), (0xFC21, 5119, 2, true),
+    (0xFC22, 5121, 2, true), (0xFC23, 5123, 2, true), (0xFC24, 5125, 2, true), (0xFC25, 5127, 2, true),
+    (0xFC26, 5129, 2, true), (0xFC27, 5131, 2, true), (0xFC28, 5133, 2, true), (0xFC29, 5135, 2, true),
+    (0xFC2A, 5137, 2, true), (0xFC2B, 5139, 2, true), (0xFC2C, 5141, 2, true), (0xFC2D, 5143, 2, true),
+    (0xFC2E, 5145, 2, true), (0xFC2F, 5147, 2, true), (0xFC30, 5149, 2, true), (0xFC31, 5151, 2, true),
+    (0xFC32, 5153, 2, true), (0xFC33, 5155, 2, true), (0xFC34, 5157, 2, true), (0xFC35, 5159, 2, true),
+    (0xFC36, 5161, 2, true), (0xFC37, 5163, 2, true), (0xFC38, 5165, 2, true), (0xFC39, 5167, 2, true),
+    (0xFC3A, 5169, 2, true), (0xFC3B, 5171, 2, true), (0xFC3C, 5173, 2, true), (0xFC3D, 5175, 2, true),
+    (0xFC3E, 5177, 2, true), (0xFC3F, 5179, 2, true), (0xFC40, 5181, 2, true), (0xFC41, 5183, 2, true),
+    (0xFC42, 5185, 2, true), (0xFC43, 5187, 2, true), (0xFC44, 5189, 2, true), (0xFC45, 5191, 2, true),
+    (0xFC46, 5193, 2, true), (0xFC47, 5195, 2, true), (0xFC48, 5197, 2, true), (0xFC49, 5199, 2, true),
+    (0xFC4A, 5201, 2, true), (0xFC4B, 5203, 2, true), (0xFC4C, 5205, 2, true), (0xFC4D, 5207, 2, true),
+    (0xFC4E, 5209, 2, true), (0xFC4F, 5211, 2, true), (0xFC50, 5213, 2, true), (0xFC51, 5215, 2, true),
+    (0xFC52, 5217, 2, true), (0xFC53, 5219, 2, true), (0xFC54, 5221, 2, true), (0xFC55, 5223, 2, true),
+    (0xFC56, 5225, 2, true), (0xFC57, 5227, 2, true), (0xFC58, 5229, 2, true), (0xFC59, 5231, 2, true),
+    (0xFC5A, 5233, 2, true), (0xFC5B, 5235, 2, true), (0xFC5C, 5237, 2, true), (0xFC5D, 5239, 2, true),
+    (0xFC5E, 5241, 3, true), (0xFC5F, 5244, 3, true), (0xFC60, 5247, 3, true), (0xFC61, 5250, 3, true),
+    (0xFC62, 5253, 3, true), (0xFC63, 5256, 3, true), (0xFC64, 5259, 2, true), (0xFC65, 5261, 2, true),
+    (0xFC66, 5263, 2, true), (0xFC67, 5265, 2, true), (0xFC68, 5267, 2, true), (0xFC69, 5269, 2, true),
+    (0xFC6A, 5271, 2, true), (0xFC6B, 5273, 2, true), (0xFC6C, 5275, 2, true), (0xFC6D, 5277, 2, true),
+    (0xFC6E, 5279, 2, true), (0xFC6F, 5281, 2, true), (0xFC70, 5283, 2, true), (0xFC71, 5285, 2, true),
+    (0xFC72, 5287, 2, true), (0xFC73, 5289, 2, true), (0xFC74, 5291, 2, true), (0xFC75, 5293, 2, true),
+    (0xFC76, 5295, 2, true), (0xFC77, 5297, 2, true), (0xFC78, 5299, 2, true), (0xFC79, 5301, 2, true),
+    (0xFC7A, 5303, 2, true), (0xFC7B, 5305, 2, true), (0xFC7C, 5307, 2, true), (0xFC7D, 5309, 2, true),
+    (0xFC7E, 5311, 2, true), (0xFC7F, 5313, 2, true), (0xFC80, 5315, 2, true), (0xFC81, 5317, 2, true),
+    (0xFC82, 5319, 2, true), (0xFC83, 5321, 2, true), (0xFC84, 5323, 2, true), (0xFC85, 5325, 2, true),
+    (0xFC86, 5327, 2, true), (0xFC87, 5329, 2, true), (0xFC88, 5331, 2, true), (0xFC89, 5333, 2, true),
+    (0xFC8A, 5335, 2, true), (0xFC8B, 5337, 2, true), (0xFC8C, 5339, 2, true), (0xFC8D, 5341, 2, true),
+    (0xFC8E, 5343, 2, true), (0xFC8F, 5345, 2, true), (0xFC90, 5347, 2, true), (0xFC91, 5349, 2, true),
+    (0xFC92, 5351, 2, true), (0xFC93, 5353, 2, true), (0xFC94, 5355, 2, true), (0xFC95, 5357, 2, true),
+    (0xFC96, 5359, 2, true), (0xFC97, 5361, 2, true), (0xFC98, 5363, 2, true), (0xFC99, 5365, 2, true),
+    (0xFC9A, 5367, 2, true), (0xFC9B, 5369, 2, true), (0xFC9C, 5371, 2, true), (0xFC9D, 5373, 2, true),
+    (0xFC9E, 5375, 2, true), (0xFC9F, 5377, 2, true), (0xFCA0, 5379, 2, true), (0xFCA1, 5381, 2, true),
+    (0xFCA2, 5383, 2, true), (0xFCA3, 5385, 2, true), (0xFCA4, 5387, 2, true), (0xFCA5, 5389, 2, true),
+    (0xFCA6, 5391, 2, true), (0xFCA7, 5393, 2, true), (0xFCA8, 5395, 2, true), (0xFCA9, 5397, 2, true),
+    (0xFCAA, 5399, 2, true), (0xFCAB, 5401, 2, true), (0xFCAC, 5403, 2, true), (0xFCAD, 5405, 2, true),
+    (0xFCAE, 5407, 2, true), (0xFCAF, 5409, 2, true), (0xFCB0, 5411, 2, true), (0xFCB1, 5413, 2, true),
+    (0xFCB2, 5415, 2, true), (0xFCB3, 5417, 2, true), (0xFCB4, 5419, 2, true), (0xFCB5, 5421, 2, true),
+    (0xFCB6, 5423, 2, true), (0xFCB7, 5425, 2, true), (0xFCB8, 5427, 2, true), (0xFCB9, 5429, 2, true),
+    (0xFCBA, 5431, 2, true), (0xFCBB, 5433, 2, true), (0xFCBC, 5435, 2, true), (0xFCBD, 5437, 2, true),
+    (0xFCBE, 5439, 2, true), (0xFCBF, 5441, 2, true), (0xFCC0, 5443, 2, true), (0xFCC1, 5445, 2, true),
+    (0xFCC2, 5447, 2, true), (0xFCC3, 5449, 2, true), (0xFCC4, 5451, 2, true), (0xFCC5, 5453, 2, true),
+    (0xFCC6, 5455, 2, true), (0xFCC7, 5457, 2, true), (0xFCC8, 5459, 2, true), (0xFCC9, 5461, 2, true),
+    (0xFCCA, 5463, 2, true), (0xFCCB, 5465, 2, true), (0xFCCC, 5467, 2, true), (0xFCCD, 5469, 2, true),
+    (0xFCCE, 5471, 2, true), (0xFCCF, 5473, 2, true), (0xFCD0, 5475, 2, true), (0xFCD1, 5477, 2, true),
+    (0xFCD2, 5479, 2, true), (0xFCD3, 5481, 2, true), (0xFCD4, 5483, 2, true), (0xFCD5, 5485, 2, true),
+    (0xFCD6, 5487, 2, true), (0xFCD7, 5489, 2, true), (0xFCD8, 5491, 2, true), (0xFCD9, 5493, 2, true),
+    (0xFCDA, 5495, 2, true), (0xFCDB, 5497, 2, true), (0xFCDC, 5499, 2, true), (0xFCDD, 5501, 2, true),
+    (0xFCDE, 5503, 2, true), (0xFCDF, 5505, 2, true), (0xFCE0, 5507, 2, true), (0xFCE1, 5509, 2, true),
+    (0xFCE2, 5511, 2, true), (0xFCE3, 5513, 2, true), (0xFCE4, 5515, 2, true), (0xFCE5, 5517, 2, true),
+    (0xFCE6, 5519, 2, true), (0xFCE7, 5521, 2, true), (0xFCE8, 5523, 2, true), (0xFCE9, 5525, 2, true),
+    (0xFCEA, 5527, 2, true), (0xFCEB, 5529, 2, true), (0xFCEC, 5531, 2, true), (0xFCED, 5533, 2, true),
+    (0xFCEE, 5535, 2, true), (0xFCEF, 5537, 2, true), (0xFCF0, 5539, 2, true), (0xFCF1, 5541, 2, true),
+    (0xFCF2, 5543, 3, true), (0xFCF3, 5546, 3, true), (0xFCF4, 5549, 3, true), (0xFCF5, 5552, 2, true),
+    (0xFCF6, 5554, 2, true), (0xFCF7, 5556, 2, true), (0xFCF8, 5558, 2, true), (0xFCF9, 5560, 2, true),
+    (0xFCFA, 5562, 2, true), (0xFCFB, 5564, 2, true), (0xFCFC, 5566, 2, true), (0xFCFD, 5568, 2, true),
+    (0xFCFE, 5570, 2, true), (0xFCFF, 5572, 2, true), (0xFD00, 5574, 2, true), (0xFD01, 5576, 2, true),
+    (0xFD02, 5578, 2, true), (0xFD03, 5580, 2, true), (0xFD04, 5582, 2, true), (0xFD05, 5584, 2, true),
+    (0xFD06, 5586, 2, true), (0xFD07, 5588, 2, true), (0xFD08, 5590, 2, true), (0xFD09, 5592, 2, true),
+    (0xFD0A, 5594, 2, true), (0xFD0B, 5596, 2, true), (0xFD0C, 5598, 2, true), (0xFD0D, 5600, 2, true),
+    (0xFD0E, 5602, 2, true), (0xFD0F, 5604, 2, true), (0xFD10, 5606, 2, true), (0xFD11, 5608, 2, true),
+    (0xFD12, 5610, 2, true), (0xFD13, 5612, 2, true), (0xFD14, 5614, 2, true), (0xFD15, 5616, 2, true),
+    (0xFD16, 5618, 2, true), (0xFD17, 5620, 2, true), (0xFD18, 5622, 2, true), (0xFD19, 5624, 2, true),
+    (0xFD1A, 5626, 2, true), (0xFD1B, 5628, 2, true), (0xFD1C, 5630, 2, true), (0xFD1D, 5632, 2, true),
+    (0xFD1E, 5634, 2, true), (0xFD1F, 5636, 2, true), (0xFD20, 5638, 2, true), (0xFD21, 5640, 2, true),
+    (0xFD22, 5642, 2, true), (0xFD23, 5644, 2, true), (0xFD24, 5646, 2, true), (0xFD25, 5648, 2, true),
+    (0xFD26, 5650, 2, true), (0xFD27, 5652, 2, true), (0xFD28, 5654, 2, true), (0xFD29, 5656, 2, true),
+    (0xFD2A, 5658, 2, true), (0xFD2B, 5660, 2, true), (0xFD2C, 5662, 2, true), (0xFD2D, 5664, 2, true),
+    (0xFD2E, 5666, 2, true), (0xFD2F, 5668, 2, true), (0xFD30, 5670, 2, true), (0xFD31, 5672, 2, true),
+    (0xFD32, 5674, 2, true), (0xFD33, 5676, 2, true), (0xFD34, 5678, 2, true), (0xFD35, 5680, 2, true),
+    (0xFD36, 5682, 2, true), (0xFD37, 5684, 2, true), (0xFD38, 5686, 2, true), (0xFD39, 5688, 2, true),
+    (0xFD3A, 5690, 2, true), (0xFD3B, 5692, 2, true), (0xFD3C, 5694, 2, true), (0xFD3D, 5696, 2, true),
+    (0xFD50, 5698, 3, true), (0xFD51, 5701, 3, true), (0xFD52, 5704, 3, true), (0xFD53, 5707, 3, true),
+    (0xFD54, 5710, 3, true), (0xFD55, 5713, 3, true), (0xFD56, 5716, 3, true), (0xFD57, 5719, 3, true),
+    (0xFD58, 5722, 3, true), (0xFD59, 5725, 3, true), (0xFD5A, 5728, 3, true), (0xFD5B, 5731, 3, true),
+    (0xFD5C, 5734, 3, true), (0xFD5D, 5737, 3, true), (0xFD5E, 5740, 3, true), (0xFD5F, 5743, 3, true),
+    (0xFD60, 5746, 3, true), (0xFD61, 5749, 3, true), (0xFD62, 5752, 3, true), (0xFD63, 5755, 3, true),
+    (0xFD64, 5758, 3, true), (0xFD65, 5761, 3, true), (0xFD66, 5764, 3, true), (0xFD67, 5767, 3, true),
+    (0xFD68, 5770, 3, true), (0xFD69, 5773, 3, true), (0xFD6A, 5776, 3, true), (0xFD6B, 5779, 3, true),
+    (0xFD6C, 5782, 3, true), (0xFD6D, 5785, 3, true), (0xFD6E, 5788, 3, true), (0xFD6F, 5791, 3, true),
+    (0xFD70, 5794, 3, true), (0xFD71, 5797, 3, true), (0xFD72, 5800, 3, true), (0xFD73, 5803, 3, true),
+    (0xFD74, 5806, 3, true), (0xFD75, 5809, 3, true), (0xFD76, 5812, 3, true), (0xFD77, 5815, 3, true),
+    (0xFD78, 5818, 3, true), (0xFD79, 5821, 3, true), (0xFD7A, 5824, 3, true), (0xFD7B, 5827, 3, true),
+    (0xFD7C, 5830, 3, true), (0xFD7D, 5833, 3, true), (0xFD7E, 5836, 3, true), (0xFD7F, 5839, 3, true),
+    (0xFD80, 5842, 3, true), (0xFD81, 5845, 3, true), (0xFD82, 5848, 3, true), (0xFD83, 5851, 3, true),
+    (0xFD84, 5854, 3, true), (0xFD85, 5857, 3, true), (0xFD86, 5860, 3, true), (0xFD87, 5863, 3, true),
+    (0xFD88, 5866, 3, true), (0xFD89, 5869, 3, true), (0xFD8A, 5872, 3, true), (0xFD8B, 5875, 3, true),
+    (0xFD8C, 5878, 3, true), (0xFD8D, 5881, 3, true), (0xFD8E, 5884, 3, true), (0xFD8F, 5887, 3, true),
+    (0xFD92, 5890, 3, true), (0xFD93, 5893, 3, true), (0xFD94, 5896, 3, true), (0xFD95, 5899, 3, true),
+    (0xFD96, 5902, 3, true), (0xFD97, 5905, 3, true), (0xFD98, 5908, 3, true), (0xFD99, 5911, 3, true),
+    (0xFD9A, 5914, 3, true), (0xFD9B, 5917, 3, true), (0xFD9C, 5920, 3, true), (0xFD9D, 5923, 3, true),
+    (0xFD9E, 5926, 3, true), (0xFD9F, 5929, 3, true), (0xFDA0, 5932, 3, true), (0xFDA1, 5935, 3, true),
+    (0xFDA2, 5938, 3, true), (0xFDA3, 5941, 3, true), (0xFDA4, 5944, 3, true), (0xFDA5, 5947, 3, true),
+    (0xFDA6, 5950, 3, true), (0xFDA7, 5953, 3, true), (0xFDA8, 5956, 3, true), (0xFDA9, 5959, 3, true),
+    (0xFDAA, 5962, 3, true), (0xFDAB, 5965, 3, true), (0xFDAC, 5968, 3, true), (0xFDAD, 5971, 3, true),
+    (0xFDAE, 5974, 3, true), (0xFDAF, 5977, 3, true), (0xFDB0, 5980, 3, true), (0xFDB1, 5983, 3, true),
+    (0xFDB2, 5986, 3, true), (0xFDB3, 5989, 3, true), (0xFDB4, 5992, 3, true), (0xFDB5, 5995, 3, true),
+    (0xFDB6, 5998, 3, true), (0xFDB7, 6001, 3, true), (0xFDB8, 6004, 3, true), (0xFDB9, 6007, 3, true),
+    (0xFDBA, 6010, 3, true), (0xFDBB, 6013, 3, true), (0xFDBC, 6016, 3, true), (0xFDBD, 6019, 3, true),
+    (0xFDBE, 6022, 3, true), (0xFDBF, 6025, 3, true), (0xFDC0, 6028, 3, true), (0xFDC1, 6031, 3, true),
+    (0xFDC2, 6034, 3, true), (0xFDC3, 6037, 3, true), (0xFDC4, 6040, 3, true), (0xFDC5, 6043, 3, true),
+    (0xFDC6, 6046, 3, true), (0xFDC7, 6049, 3, true), (0xFDF0, 6052, 3, true), (0xFDF1, 6055, 3, true),
+    (0xFDF2, 6058, 4, true), (0xFDF3, 6062, 4, true), (0xFDF4, 6066, 4, true), (0xFDF5, 6070, 4, true),
+    (0xFDF6, 6074, 4, true), (0xFDF7, 6078, 4, true), (0xFDF8, 6082, 4, true), (0xFDF9, 6086, 3, true),
+    (0xFDFA, 6089, 18, true), (0xFDFB, 6107, 8, true), (0xFDFC, 6115, 4, true), (0xFE10, 6119, 1, true),
+    (0xFE11, 6120, 1, true), (0xFE12, 6121, 1, true), (0xFE13, 6122, 1, true), (0xFE14, 6123, 1, true),
+    (0xFE15, 6124, 1, true), (0xFE16, 6125, 1, true), (0xFE17, 6126, 1, true), (0xFE18, 6127, 1, true),
+    (0xFE19, 6128, 1, true), (0xFE30, 6129, 1, true), (0xFE31, 6130, 1, true), (0xFE32, 6131, 1, true),
+    (0xFE33, 6132, 1, true), (0xFE34, 6133, 1, true), (0xFE35, 6134, 1, true), (0xFE36, 6135, 1, true),
+    (0xFE37, 6136, 1, true), (0xFE38, 6137, 1, true), (0xFE39, 6138, 1, true), (0xFE3A, 6139, 1, true),
+    (0xFE3B, 6140, 1, true), (0xFE3C, 6141, 1, true), (0xFE3D, 6142, 1, true), (0xFE3E, 6143, 1, true),
+    (0xFE3F, 6144, 1, true), (0xFE40, 6145, 1, true), (0xFE41, 6146, 1, true), (0xFE42, 6147, 1, true),
+    (0xFE43, 6148, 1, true), (0xFE44, 6149, 1, true), (0xFE47, 6150, 1, true), (0xFE48, 6151, 1, true),
+    (0xFE49, 6152, 1, true), (0xFE4A, 6153, 1, true), (0xFE4B, 6154, 1, true), (0xFE4C, 6155, 1, true),
+    (0xFE4D, 6156, 1, true), (0xFE4E, 6157, 1, true), (0xFE4F, 6158, 1, true), (0xFE50, 6159, 1, true),
+    (0xFE51, 6160, 1, true), (0xFE52, 6161, 1, true), (0xFE54, 6162, 1, true), (0xFE55, 6163, 1, true),
+    (0xFE56, 6164, 1, true), (0xFE57, 6165, 1, true), (0xFE58, 6166, 1, true), (0xFE59, 6167, 1, true),
+    (0xFE5A, 6168, 1, true), (0xFE5B, 6169, 1, true), (0xFE5C, 6170, 1, true), (0xFE5D, 6171, 1, true),
+    (0xFE5E, 6172, 1, true), (0xFE5F, 6173, 1, true), (0xFE60, 6174, 1, true), (0xFE61, 6175, 1, true),
+    (0xFE62, 6176, 1, true), (0xFE63, 6177, 1, true), (0xFE64, 6178, 1, true), (0xFE65, 6179, 1, true),
+    (0xFE66, 6180, 1, true), (0xFE68, 6181, 1, true), (0xFE69, 6182, 1, true), (0xFE6A, 6183, 1, true),
+    (0xFE6B, 6184, 1, true), (0xFE70, 6185, 2, true), (0xFE71, 6187, 2, true), (0xFE72, 6189, 2, true),
+    (0xFE74, 6191, 2, true), (0xFE76, 6193, 2, true), (0xFE77, 6195, 2, true), (0xFE78, 6197, 2, true),
+    (0xFE79, 6199, 2, true), (0xFE7A, 6201, 2, true), (0xFE7B, 6203, 2, true), (0xFE7C, 6205, 2, true),
+    (0xFE7D, 6207, 2, true), (0xFE7E, 6209, 2, true), (0xFE7F, 6211, 2, true), (0xFE80, 6213, 1, true),
+    (0xFE81, 6214, 1, true), (0xFE82, 6215, 1, true), (0xFE83, 6216, 1, true), (0xFE84, 6217, 1, true),
+    (0xFE85, 6218, 1, true), (0xFE86, 6219, 1, true), (0xFE87, 6220, 1, true), (0xFE88, 6221, 1, true),
+    (0xFE89, 6222, 1, true), (0xFE8A, 6223, 1, true), (0xFE8B, 6224, 1, true), (0xFE8C, 6225, 1, true),
+    (0xFE8D, 6226, 1, true), (0xFE8E, 6227, 1, true), (0xFE8F, 6228, 1, true), (0xFE90, 6229, 1, true),
+    (0xFE91, 6230, 1, true), (0xFE92, 6231, 1, true), (0xFE93, 6232, 1, true), (0xFE94, 6233, 1, true),
+    (0xFE95, 6234, 1, true), (0xFE96, 6235, 1, true), (0xFE97, 6236, 1, true), (0xFE98, 6237, 1, true),
+    (0xFE99, 6238, 1, true), (0xFE9A, 6239, 1, true), (0xFE9B, 6240, 1, true), (0xFE9C, 6241, 1, true),
+    (0xFE9D, 6242, 1, true), (0xFE9E, 6243, 1, true), (0xFE9F, 6244, 1, true), (0xFEA0, 6245, 1, true),
+    (0xFEA1, 6246, 1, true), (0xFEA2, 6247, 1, true), (0xFEA3, 6248, 1, true), (0xFEA4, 6249, 1, true),
+    (0xFEA5, 6250, 1, true), (0xFEA6, 6251, 1, true), (0xFEA7, 6252, 1, true), (0xFEA8, 6253, 1, true),
+    (0xFEA9, 6254, 1, true), (0xFEAA, 6255, 1, true), (0xFEAB, 6256, 1, true), (0xFEAC, 6257, 1, true),
+    (0xFEAD, 6258, 1, true), (0xFEAE, 6259, 1, true), (0xFEAF, 6260, 1, true), (0xFEB0, 6261, 1, true),
+    (0xFEB1, 6262, 1, true), (0xFEB2, 6263, 1, true), (0xFEB3, 6264, 1, true), (0xFEB4, 6265, 1, true),
+    (0xFEB5, 6266, 1, true), (0xFEB6, 6267, 1, true), (0xFEB7, 6268, 1, true), (0xFEB8, 6269, 1, true),
+    (0xFEB9, 6270, 1, true), (0xFEBA, 6271, 1, true), (0xFEBB, 6272, 1, true), (0xFEBC, 6273, 1, true),
+    (0xFEBD, 6274, 1, true), (0xFEBE, 6275, 1, true), (0xFEBF, 6276, 1, true), (0xFEC0, 6277, 1, true),
+    (0xFEC1, 6278, 1, true), (0xFEC2, 6279, 1, true), (0xFEC3, 6280, 1, true), (0xFEC4, 6281, 1, true),
+    (0xFEC5, 6282, 1, true), (0xFEC6, 6283, 1, true), (0xFEC7, 6284, 1, true), (0xFEC8, 6285, 1, true),
+    (0xFEC9, 6286, 1, true), (0xFECA, 6287, 1, true), (0xFECB, 6288, 1, true), (0xFECC, 6289, 1, true),
+    (0xFECD, 6290, 1, true), (0xFECE, 6291, 1, true), (0xFECF, 6292, 1, true), (0xFED0, 6293, 1, true),
+    (0xFED1, 6294, 1, true), (0xFED2, 6295, 1, true), (0xFED3, 6296, 1, true), (0xFED4, 6297, 1, true),
+    (0xFED5, 6298, 1, true), (0xFED6, 6299, 1, true), (0xFED7, 6300, 1, true), (0xFED8, 6301, 1, true),
+    (0xFED9, 6302, 1, true), (0xFEDA, 6303, 1, true), (0xFEDB, 6304, 1, true), (0xFEDC, 6305, 1, true),
+    (0xFEDD, 6306, 1, true), (0xFEDE, 6307, 1, true), (0xFEDF, 6308, 1, true), (0xFEE0, 6309, 1, true),
+    (0xFEE1, 6310, 1, true), (0xFEE2, 6311, 1, true), (0xFEE3, 6312, 1, true), (0xFEE4, 6313, 1, true),
+    (0xFEE5, 6314, 1, true), (0xFEE6, 6315, 1, true), (0xFEE7, 6316, 1, true), (0xFEE8, 6317, 1, true),
+    (0xFEE9, 6318, 1, true), (0xFEEA, 6319, 1, true), (0xFEEB, 6320, 1, true), (0xFEEC, 6321, 1, true),
+    (0xFEED, 6322, 1, true), (0xFEEE, 6323, 1, true), (0xFEEF, 6324, 1, true), (0xFEF0, 6325, 1, true),
+    (0xFEF1, 6326, 1, true), (0xFEF2, 6327, 1, true), (0xFEF3, 6328, 1, true), (0xFEF4, 6329, 1, true),
+    (0xFEF5, 6330, 2, true), (0xFEF6, 6332, 2, true), (0xFEF7, 6334, 2, true), (0xFEF8, 6336, 2, true),
+    (0xFEF9, 6338, 2, true), (0xFEFA, 6340, 2, true), (0xFEFB, 6342, 2, true), (0xFEFC, 6344, 2, true),
+    (0xFF01, 6346, 1, true), (0xFF02, 6347, 1, true), (0xFF03, 6348, 1, true), (0xFF04, 6349, 1, true),
+    (0xFF05, 6350, 1, true), (0xFF06, 6351, 1, true), (0xFF07, 6352, 1, true), (0xFF08, 6353, 1, true),
+    (0xFF09, 6354, 1, true), (0xFF0A, 6355, 1, true), (0xFF0B, 6356, 1, true), (0xFF0C, 6357, 1, true),
+    (0xFF0D, 6358, 1, true), (0xFF0E, 6359, 1, true), (0xFF0F, 6360, 1, true), (0xFF10, 6361, 1, true),
+    (0xFF11, 6362, 1, true), (0xFF12, 6363, 1, true), (0xFF13, 6364, 1, true), (0xFF14, 6365, 1, true),
+    (0xFF15, 6366, 1, true), (0xFF16, 6367, 1, true), (0xFF17, 6368, 1, true), (0xFF18, 6369, 1, true),
+    (0xFF19, 6370, 1, true), (0xFF1A, 6371, 1, true), (0xFF1B, 6372, 1, true), (0xFF1C, 6373, 1, true),
+    (0xFF1D, 6374, 1, true), (0xFF1E, 6375, 1, true), (0xFF1F, 6376, 1, true), (0xFF20, 6377, 1, true),
+    (0xFF21, 6378, 1, true), (0xFF22, 6379, 1, true), (0xFF23, 6380, 1, true), (0xFF24, 6381, 1, true),
+    (0xFF25, 6382, 1, true), (0xFF26, 6383, 1, true), (0xFF27, 6384, 1, true), (0xFF28, 6385, 1, true),
+    (0xFF29, 6386, 1, true), (0xFF2A, 6387, 1, true), (0xFF2B, 6388, 1, true), (0xFF2C, 6389, 1, true),
+    (0xFF2D, 6390, 1, true), (0xFF2E, 6391, 1, true), (0xFF2F, 6392, 1, true), (0xFF30, 6393, 1, true),
+    (0xFF31, 6394, 1, true), (0xFF32, 6395, 1, true), (0xFF33, 6396, 1, true), (0xFF34, 6397, 1, true),
+    (0xFF35, 6398, 1, true), (0xFF36, 6399, 1, true), (0xFF37, 6400, 1, true), (0xFF38, 6401, 1, true),
+    (0xFF39, 6402, 1, true), (0xFF3A, 6403, 1, true), (0xFF3B, 6404, 1, true), (0xFF3C, 6405, 1, true),
+    (0xFF3D, 6406, 1, true), (0xFF3E, 6407, 1, true), (0xFF3F, 6408, 1, true), (0xFF40, 6409, 1, true),
+    (0xFF41, 6410, 1, true), (0xFF42, 6411, 1, true), (0xFF43, 6412, 1, true), (0xFF44, 6413, 1, true),
+    (0xFF45, 6414, 1, true), (0xFF46, 6415, 1, true), (0xFF47, 6416, 1, true), (0xFF48, 6417, 1, true),
+    (0xFF49, 6418, 1, true), (0xFF4A, 6419, 1, true), (0xFF4B, 6420, 1, true), (0xFF4C, 6421, 1, true),
+    (0xFF4D, 6422, 1, true), (0xFF4E, 6423, 1, true), (0xFF4F, 6424, 1, true), (0xFF50, 6425, 1, true),
+    (0xFF51, 6426, 1, true), (0xFF52, 6427, 1, true), (0xFF53, 6428, 1, true), (0xFF54, 6429, 1, true),
+    (0xFF55, 6430, 1, true), (0xFF56, 6431, 1, true), (0xFF57, 6432, 1, true), (0xFF58, 6433, 1, true),
+    (0xFF59, 6434, 1, true), (0xFF5A, 6435, 1, true), (0xFF5B, 6436, 1, true), (0xFF5C, 6437, 1, true),
+    (0xFF5D, 6438, 1, true), (0xFF5E, 6439, 1, true), (0xFF5F, 6440, 1, true), (0xFF60, 6441, 1, true),
+    (0xFF61, 6442, 1, true), (0xFF62, 6443, 1, true), (0xFF63, 6444, 1, true), (0xFF64, 6445, 1, true),
+    (0xFF65, 6446, 1, true), (0xFF66, 6447, 1, true), (0xFF67, 6448, 1, true), (0xFF68, 6449, 1, true),
+    (0xFF69, 6450, 1, true), (0xFF6A, 6451, 1, true), (0xFF6B, 6452, 1, true), (0xFF6C, 6453, 1, true),
+    (0xFF6D, 6454, 1, true), (0xFF6E, 6455, 1, true), (0xFF6F, 6456, 1, true), (0xFF70, 6457, 1, true),
+    (0xFF71, 6458, 1, true), (0xFF72, 6459, 1, true), (0xFF73, 6460, 1, true), (0xFF74, 6461, 1, true),
+    (0xFF75, 6462, 1, true), (0xFF76, 6463, 1, true), (0xFF77, 6464, 1, true), (0xFF78, 6465, 1, true),
+    (0xFF79, 6466, 1, true), (0xFF7A, 6467, 1, true), (0xFF7B, 6468, 1, true), (0xFF7C, 6469, 1, true),
+    (0xFF7D, 6470, 1, true), (0xFF7E, 6471, 1, true), (0xFF7F, 6472, 1, true), (0xFF80, 6473, 1, true),
+    (0xFF81, 6474, 1, true), (0xFF82, 6475, 1, true), (0xFF83, 6476, 1, true), (0xFF84, 6477, 1, true),
+    (0xFF85, 6478, 1, true), (0xFF86, 6479, 1, true), (0xFF87, 6480, 1, true), (0xFF88, 6481, 1, true),
+    (0xFF89, 6482, 1, true), (0xFF8A, 6483, 1, true), (0xFF8B, 6484, 1, true), (0xFF8C, 6485, 1, true),
+    (0xFF8D, 6486, 1, true), (0xFF8E, 6487, 1, true), (0xFF8F, 6488, 1, true), (0xFF90, 6489, 1, true),
+    (0xFF91, 6490, 1, true), (0xFF92, 6491, 1, true), (0xFF93, 6492, 1, true), (0xFF94, 6493, 1, true),
+    (0xFF95, 6494, 1, true), (0xFF96, 6495, 1, true), (0xFF97, 6496, 1, true), (0xFF98, 6497, 1, true),
+    (0xFF99, 6498, 1, true), (0xFF9A, 6499, 1, true), (0xFF9B, 6500, 1, true), (0xFF9C, 6501, 1, true),
+    (0xFF9D, 6502, 1, true), (0xFF9E, 6503, 1, true), (0xFF9F, 6504, 1, true), (0xFFA0, 6505, 1, true),
+    (0xFFA1, 6506, 1, true), (0xFFA2, 6507, 1, true), (0xFFA3, 6508, 1, true), (0xFFA4, 6509, 1, true),
+    (0xFFA5, 6510, 1, true), (0xFFA6, 6511, 1, true), (0xFFA7, 6512, 1, true), (0xFFA8, 6513, 1, true),
+    (0xFFA9, 6514, 1, true), (0xFFAA, 6515, 1, true), (0xFFAB, 6516, 1, true), (0xFFAC, 6517, 1, true),
+    (0xFFAD, 6518, 1, true), (0xFFAE, 6519, 1, true), (0xFFAF, 6520, 1, true), (0xFFB0, 6521, 1, true),
+    (0xFFB1, 6522, 1, true), (0xFFB2, 6523, 1, true), (0xFFB3, 6524, 1, true), (0xFFB4, 6525, 1, true),
+    (0xFFB5, 6526, 1, true), (0xFFB6, 6527, 1, true), (0xFFB7, 6528, 1, true), (0xFFB8, 6529, 1, true),
+    (0xFFB9, 6530, 1, true), (0xFFBA, 6531, 1, true), (0xFFBB, 6532, 1, true), (0xFFBC, 6533, 1, true),
+    (0xFFBD, 6534, 1, true), (0xFFBE, 6535, 1, true), (0xFFC2, 6536, 1, true), (0xFFC3, 6537, 1, true),
+    (0xFFC4, 6538, 1, true), (0xFFC5, 6539, 1, true), (0xFFC6, 6540, 1, true), (0xFFC7, 6541, 1, true),
+    (0xFFCA, 6542, 1, true), (0xFFCB, 6543, 1, true), (0xFFCC, 6544, 1, true), (0xFFCD, 6545, 1, true),
+    (0xFFCE, 6546, 1, true), (0xFFCF, 6547, 1, true), (0xFFD2, 6548, 1, true), (0xFFD3, 6549, 1, true),
+    (0xFFD4, 6550, 1, true), (0xFFD5, 6551, 1, true), (0xFFD6, 6552, 1, true), (0xFFD7, 6553, 1, true),
+    (0xFFDA, 6554, 1, true), (0xFFDB, 6555, 1, true), (0xFFDC, 6556, 1, true), (0xFFE0, 6557, 1, true),
+    (0xFFE1, 6558, 1, true), (0xFFE2, 6559, 1, true), (0xFFE3, 6560, 1, true), (0xFFE4, 6561, 1, true),
+    (0xFFE5, 6562, 1, true), (0xFFE6, 6563, 1, true), (0xFFE8, 6564, 1, true), (0xFFE9, 6565, 1, true),
+    (0xFFEA, 6566, 1, true), (0xFFEB, 6567, 1, true), (0xFFEC, 6568, 1, true), (0xFFED, 6569, 1, true),
+    (0xFFEE, 6570, 1, true), (0x105C9, 6571, 2, false), (0x105E4, 6573, 2, false), (0x10781, 6575, 1, true),
+    (0x10782, 6576, 1, true), (0x10783, 6577, 1, true), (0x10784, 6578, 1, true), (0x10785, 6579, 1, true),
+    (0x10787, 6580, 1, true), (0x10788, 6581, 1, true), (0x10789, 6582, 1, true), (0x1078A, 6583, 1, true),
+    (0x1078B, 6584, 1, true), (0x1078C, 6585, 1, true), (0x1078D, 6586, 1, true), (0x1078E, 6587, 1, true),
+    (0x1078F, 6588, 1, true), (0x10790, 6589, 1, true), (0x10791, 6590, 1, true), (0x10792, 6591, 1, true),
+    (0x10793, 6592, 1, true), (0x10794, 6593, 1, true), (0x10795, 6594, 1, true), (0x10796, 6595, 1, true),
+    (0x10797, 6596, 1, true), (0x10798, 6597, 1, true), (0x10799, 6598, 1, true), (0x1079A, 6599, 1, true),
+    (0x1079B, 6600, 1, true), (0x1079C, 6601, 1, true), (0x1079D, 6602, 1, true), (0x1079E, 6603, 1, true),
+    (0x1079F, 6604, 1, true), (0x107A0, 6605, 1, true), (0x107A1, 6606, 1, true), (0x107A2, 6607, 1, true),
+    (0x107A3, 6608, 1, true), (0x107A4, 6609, 1, true), (0x107A5, 6610, 1, true), (0x107A6, 6611, 1, true),
+    (0x107A7, 6612, 1, true), (0x107A8, 6613, 1, true), (0x107A9, 6614, 1, true), (0x107AA, 6615, 1, true),
+    (0x107AB, 6616, 1, true), (0x107AC, 6617, 1, true), (0x107AD, 6618, 1, true), (0x107AE, 6619, 1, true),
+    (0x107AF, 6620, 1, true), (0x107B0, 6621, 1, true), (0x107B2, 6622, 1, true), (0x107B3, 6623, 1, true),
+    (0x107B4, 6624, 1, true), (0x107B5, 6625, 1, true), (0x107B6, 6626, 1, true), (0x107B7, 6627, 1, true),
+    (0x107B8, 6628, 1, true), (0x107B9, 6629, 1, true), (0x107BA, 6630, 1, true), (0x1109A, 6631, 2, false),
+    (0x1109C, 6633, 2, false), (0x110AB, 6635, 2, false), (0x1112E, 6637, 2, false), (0x1112F, 6639, 2, false),
+    (0x1134B, 6641, 2, false), (0x1134C, 6643, 2, false), (0x11383, 6645, 2, false), (0x11385, 6647, 2, false),
+    (0x1138E, 6649, 2, false), (0x11391, 6651, 2, false), (0x113C5, 6653, 2, false), (0x113C7, 6655, 2, false),
+    (0x113C8, 6657, 2, false), (0x114BB, 6659, 2, false), (0x114BC, 6661, 2, false), (0x114BE, 6663, 2, false),
+    (0x115BA, 6665, 2, false), (0x115BB, 6667, 2, false), (0x11938, 6669, 2, false), (0x16121, 6671, 2, false),
+    (0x16122, 6673, 2, false), (0x16123, 6675, 2, false), (0x16124, 6677, 2, false), (0x16125, 6679, 2, false),
+    (0x16126, 6681, 2, false), (0x16127, 6683, 2, false), (0x16128, 6685, 2, false), (0x16D68, 6687, 2, false),
+    (0x16D69, 6689, 2, false), (0x16D6A, 6691, 2, false), (0x1CCD6, 6693, 1, true), (0x1CCD7, 6694, 1, true),
+    (0x1CCD8, 6695, 1, true), (0x1CCD9, 6696, 1, true), (0x1CCDA, 6697, 1, true), (0x1CCDB, 6698, 1, true),
+    (0x1CCDC, 6699, 1, true), (0x1CCDD, 6700, 1, true), (0x1CCDE, 6701, 1, true), (0x1CCDF, 6702, 1, true),
+    (0x1CCE0, 6703, 1, true), (0x1CCE1, 6704, 1, true), (0x1CCE2, 6705, 1, true), (0x1CCE3, 6706, 1, true),
+    (0x1CCE4, 6707, 1, true), (0x1CCE5, 6708, 1, true), (0x1CCE6, 6709, 1, true), (0x1CCE7, 6710, 1, true),
+    (0x1CCE8, 6711, 1, true), (0x1CCE9, 6712, 1, true), (0x1CCEA, 6713, 1, true), (0x1CCEB, 6714, 1, true),
+    (0x1CCEC, 6715, 1, true), (0x1CCED, 6716, 1, true), (0x1CCEE, 6717, 1, true), (0x1CCEF, 6718, 1, true),
+    (0x1CCF0, 6719, 1, true), (0x1CCF1, 6720, 1, true), (0x1CCF2, 6721, 1, true), (0x1CCF3, 6722, 1, true),
+    (0x1CCF4, 6723, 1, true), (0x1CCF5, 6724, 1, true), (0x1CCF6, 6725, 1, true), (0x1CCF7, 6726, 1, true),
+    (0x1CCF8, 6727, 1, true), (0x1CCF9, 6728, 1, true), (0x1D15E, 6729, 2, false), (0x1D15F, 6731, 2, false),
+    (0x1D160, 6733, 2, false), (0x1D161, 6735, 2, false), (0x1D162, 6737, 2, false), (0x1D163, 6739, 2, false),
+    (0x1D164, 6741, 2, false), (0x1D1BB, 6743, 2, false), (0x1D1BC, 6745, 2, false), (0x1D1BD, 6747, 2, false),
+    (0x1D1BE, 6749, 2, false), (0x1D1BF, 6751, 2, false), (0x1D1C0, 6753, 2, false), (0x1D400, 6755, 1, true),
+    (0x1D401, 6756, 1, true), (0x1D402, 6757, 1, true), (0x1D403, 6758, 1, true), (0x1D404, 6759, 1, true),
+    (0x1D405, 6760, 1, true), (0x1D406, 6761, 1, true), (0x1D407, 6762, 1, true), (0x1D408, 6763, 1, true),
+    (0x1D409, 6764, 1, true), (0x1D40A, 6765, 1, true), (0x1D40B, 6766, 1, true), (0x1D40C, 6767, 1, true),
+    (0x1D40D, 6768, 1, true), (0x1D40E, 6769, 1, true), (0x1D40F, 6770, 1, true), (0x1D410, 6771, 1, true),
+    (0x1D411, 6772, 1, true), (0x1D412, 6773, 1, true), (0x1D413, 6774, 1, true), (0x1D414, 6775, 1, true),
+    (0x1D415, 6776, 1, true), (0x1D416, 6777, 1, true), (0x1D417, 6778, 1, true), (0x1D418, 6779, 1, true),
+    (0x1D419, 6780, 1, true), (0x1D41A, 6781, 1, true), (0x1D41B, 6782, 1, true), (0x1D41C, 6783, 1, true),
+    (0x1D41D, 6784, 1, true), (0x1D41E, 6785, 1, true), (0x1D41F, 6786, 1, true), (0x1D420, 6787, 1, true),
+    (0x1D421, 6788, 1, true), (0x1D422, 6789, 1, true), (0x1D423, 6790, 1, true), (0x1D424, 6791, 1, true),
+    (0x1D425, 6792, 1, true), (0x1D426, 6793, 1, true), (0x1D427, 6794, 1, true), (0x1D428, 6795, 1, true),
+    (0x1D429, 6796, 1, true), (0x1D42A, 6797, 1, true), (0x1D42B, 6798, 1, true), (0x1D42C, 6799, 1, true),
+    (0x1D42D, 6800, 1, true), (0x1D42E, 6801, 1, true), (0x1D42F, 6802, 1, true), (0x1D430, 6803, 1, true),
+    (0x1D431, 6804, 1, true), (0x1D432, 6805, 1, true), (0x1D433, 6806, 1, true), (0x1D434, 6807, 1, true),
+    (0x1D435, 6808, 1, true), (0x1D436, 6809, 1, true), (0x1D437, 6810, 1, true), (0x1D438, 6811, 1, true),
+    (0x1D439, 6812, 1, true), (0x1D43A, 6813, 1, true), (0x1D43B, 6814, 1, true), (0x1D43C, 6815, 1, true),
+    (0x1D43D, 6816, 1, true), (0x1D43E, 6817, 1, true), (0x1D43F, 6818, 1, true), (0x1D440, 6819, 1, true),
+    (0x1D441, 6820, 1, true), (0x1D442, 6821, 1, true), (0x1D443, 6822, 1, true), (0x1D444, 6823, 1, true),
+    (0x1D445, 6824, 1, true), (0x1D446, 6825, 1, true), (0x1D447, 6826, 1, true), (0x1D448, 6827, 1, true),
+    (0x1D449, 6828, 1, true), (0x1D44A, 6829, 1, true), (0x1D44B, 6830, 1, true), (0x1D44C, 6831, 1, true),
+    (0x1D44D, 6832, 1, true), (0x1D44E, 6833, 1, true), (0x1D44F, 6834, 1, true), (0x1D450, 6835, 1, true),
+    (0x1D451, 6836, 1, true), (0x1D452, 6837, 1, true), (0x1D453, 6838, 1, true), (0x1D454, 6839, 1, true),
+    (0x1D456, 6840, 1, true), (0x1D457, 6841, 1, true), (0x1D458, 6842, 1, true), (0x1D459, 6843, 1, true),
+    (0x1D45A, 6844, 1, true), (0x1D45B, 6845, 1, true), (0x1D45C, 6846, 1, true), (0x1D45D, 6847, 1, true),
+    (0x1D45E, 6848, 1, true), (0x1D45F, 6849, 1, true), (0x1D460, 6850, 1, true), (0x1D461, 6851, 1, true),
+    (0x1D462, 6852, 1, true), (0x1D463, 6853, 1, true), (0x1D464, 6854, 1, true), (0x1D465, 6855, 1, true),
+    (0x1D466, 6856, 1, true), (0x1D467, 6857, 1, true), (0x1D468, 6858, 1, true), (0x1D469, 6859, 1, true),
+    (0x1D46A, 6860, 1, true), (0x1D46B, 6861, 1, true), (0x1D46C, 6862, 1, true), (0x1D46D, 6863, 1, true),
+    (0x1D46E, 6864, 1, true), (0x1D46F, 6865, 1, true), (0x1D470, 6866, 1, true), (0x1D471, 6867, 1, true),
+    (0x1D472, 6868, 1, true), (0x1D473, 6869, 1, true), (0x1D474, 6870, 1, true), (0x1D475, 6871, 1, true),
+    (0x1D476, 6872, 1, true), (0x1D477, 6873, 1, true), (0x1D478, 6874, 1, true), (0x1D479, 6875, 1, true),
+    (0x1D47A, 6876, 1, true), (0x1D47B, 6877, 1, true), (0x1D47C, 6878, 1, true), (0x1D47D, 6879, 1, true),
+    (0x1D47E, 6880, 1, true), (0x1D47F, 6881, 1, true), (0x1D480, 6882, 1, true), (0x1D481, 6883, 1, true),
+    (0x1D482, 6884, 1, true), (0x1D483, 6885, 1, true), (0x1D484, 6886, 1, true), (0x1D485, 6887, 1, true),
+    (0x1D486, 6888, 1, true), (0x1D487, 6889, 1, true), (0x1D488, 6890, 1, true), (0x1D489, 6891, 1, true),
+    (0x1D48A, 6892, 1, true), (0x1D48B, 6893, 1, true), (0x1D48C, 6894, 1, true), (0x1D48D, 6895, 1, true),
+    (0x1D48E, 6896, 1, true), (0x1D48F, 6897, 1, true), (0x1D490, 6898, 1, true), (0x1D491, 6899, 1, true),
+    (0x1D492, 6900, 1, true), (0x1D493, 6901, 1, true), (0x1D494, 6902, 1, true), (0x1D495, 6903, 1, true),
+    (0x1D496, 6904, 1, true), (0x1D497, 6905, 1, true), (0x1D498, 6906, 1, true), (0x1D499, 6907, 1, true),
+    (0x1D49A, 6908, 1, true), (0x1D49B, 6909, 1, true), (0x1D49C, 6910, 1, true), (0x1D49E, 6911, 1, true),
+    (0x1D49F, 6912, 1, true), (0x1D4A2, 6913, 1, true), (0x1D4A5, 6914, 1, true), (0x1D4A6, 6915, 1, true),
+    (0x1D4A9, 6916, 1, true), (0x1D4AA, 6917, 1, true), (0x1D4AB, 6918, 1, true), (0x1D4AC, 6919, 1, true),
+    (0x1D4AE, 6920, 1, true), (0x1D4AF, 6921, 1, true), (0x1D4B0, 6922, 1, true), (0x1D4B1, 6923, 1, true),
+    (0x1D4B2, 6924, 1, true), (0x1D4B3, 6925, 1, true), (0x1D4B4, 6926, 1, true), (0x1D4B5, 6927, 1, true),
+    (0x1D4B6, 6928, 1, true), (0x1D4B7, 6929, 1, true), (0x1D4B8, 6930, 1, true), (0x1D4B9, 6931, 1, true),
+    (0x1D4BB, 6932, 1, true), (0x1D4BD, 6933, 1, true), (0x1D4BE, 6934, 1, true), (0x1D4BF, 6935, 1, true),
+    (0x1D4C0, 6936, 1, true), (0x1D4C1, 6937, 1, true), (0x1D4C2, 6938, 1, true), (0x1D4C3, 6939, 1, true),
+    (0x1D4C5, 6940, 1, true), (0x1D4C6, 6941, 1, true), (0x1D4C7, 6942, 1, true), (0x1D4C8, 6943, 1, true),
+    (0x1D4C9, 6944, 1, true), (0x1D4CA, 6945, 1, true), (0x1D4CB, 6946, 1, true), (0x1D4CC, 6947, 1, true),
+    (0x1D4CD, 6948, 1, true), (0x1D4CE, 6949, 1, true), (0x1D4CF, 6950, 1, true), (0x1D4D0, 6951, 1, true),
+    (0x1D4D1, 6952, 1, true), (0x1D4D2, 6953, 1, true), (0x1D4D3, 6954, 1, true), (0x1D4D4, 6955, 1, true),
+    (0x1D4D5, 6956, 1, true), (0x1D4D6, 6957, 1, true), (0x1D4D7, 6958, 1, true), (0x1D4D8, 6959, 1, true),
+    (0x1D4D9, 6960, 1, true), (0x1D4DA, 6961, 1, true), (0x1D4DB, 6962, 1, true), (0x1D4DC, 6963, 1, true),
+    (0x1D4DD, 6964, 1, true), (0x1D4DE, 6965, 1, true), (0x1D4DF, 6966, 1, true), (0x1D4E0, 6967, 1, true),
+    (0x1D4E1, 6968, 1, true), (0x1D4E2, 6969, 1, true), (0x1D4E3, 6970, 1, true), (0x1D4E4, 6971, 1, true),
+    (0x1D4E5, 6972, 1, true), (0x1D4E6, 6973, 1, true), (0x1D4E7, 6974, 1, true), (0x1D4E8, 6975, 1, true),
+    (0x1D4E9, 6976, 1, true), (0x1D4EA, 6977, 1, true), (0x1D4EB, 6978, 1, true), (0x1D4EC, 6979, 1, true),
+    (0x1D4ED, 6980, 1, true), (0x1D4EE, 6981, 1, true), (0x1D4EF, 6982, 1, true), (0x1D4F0, 6983, 1, true),
+    (0x1D4F1, 6984, 1, true), (0x1D4F2, 6985, 1, true), (0x1D4F3, 6986, 1, true), (0x1D4F4, 6987, 1, true),
+    (0x1D4F5, 6988, 1, true), (0x1D4F6, 6989, 1, true), (0x1D4F7, 6990, 1, true), (0x1D4F8, 6991, 1, true),
+    (0x1D4F9, 6992, 1, true), (0x1D4FA, 6993, 1, true), (0x1D4FB, 6994, 1, true), (0x1D4FC, 6995, 1, true),
+    (0x1D4FD, 6996, 1, true), (0x1D4FE, 6997, 1, true), (0x1D4FF, 6998, 1, true), (0x1D500, 6999, 1, true),
+    (0x1D501, 7000, 1, true), (0x1D502, 7001, 1, true), (0x1D503, 7002, 1, true), (0x1D504, 7003, 1, true),
+    (0x1D505, 7004, 1, true), (0x1D507, 7005, 1, true), (0x1D508, 7006, 1, true), (0x1D509, 7007, 1, true),
+    (0x1D50A, 7008, 1, true), (0x1D50D, 7009, 1, true), (0x1D50E, 7010, 1, true), (0x1D50F, 7011, 1, true),
+    (0x1D510, 7012, 1, true), (0x1D511, 7013, 1, true), (0x1D512, 7014, 1, true), (0x1D513, 7015, 1, true),
+    (0x1D514, 7016, 1, true), (0x1D516, 7017, 1, true), (0x1D517, 7018, 1, true), (0x1D518, 7019, 1, true),
+    (0x1D519, 7020, 1, true), (0x1D51A, 7021, 1, true), (0x1D51B, 7022, 1, true), (0x1D51C, 7023, 1, true),
+    (0x1D51E, 7024, 1, true), (0x1D51F, 7025, 1, true), (0x1D520, 7026, 1, true), (0x1D521, 7027, 1, true),
+    (0x1D522, 7028, 1, true), (0x1D523, 7029, 1, true), (0x1D524, 7030, 1, true), (0x1D525, 7031, 1, true),
+    (0x1D526, 7032, 1, true), (0x1D527, 7033, 1, true), (0x1D528, 7034, 1, true), (0x1D529, 7035, 1, true),
+    (0x1D52A, 7036, 1, true), (0x1D52B, 7037, 1, true), (0x1D52C, 7038, 1, true), (0x1D52D, 7039, 1, true),
+    (0x1D52E, 7040, 1, true), (0x1D52F, 7041, 1, true), (0x1D530, 7042, 1, true), (0x1D531, 7043, 1, true),
+    (0x1D532, 7044, 1, true), (0x1D533, 7045, 1, true), (0x1D534, 7046, 1, true), (0x1D535, 7047, 1, true),
+    (0x1D536, 7048, 1, true), (0x1D537, 7049, 1, true), (0x1D538, 7050, 1, true), (0x1D539, 7051, 1, true),
+    (0x1D53B, 7052, 1, true), (0x1D53C, 7053, 1, true), (0x1D53D, 7054, 1, true), (0x1D53E, 7055, 1, true),
+    (0x1D540, 7056, 1, true), (0x1D541, 7057, 1, true), (0x1D542, 7058, 1, true), (0x1D543, 7059, 1, true),
+    (0x1D544, 7060, 1, true), (0x1D546, 7061, 1, true), (0x1D54A, 7062, 1, true), (0x1D54B, 7063, 1, true),
+    (0x1D54C, 7064, 1, true), (0x1D54D, 7065, 1, true), (0x1D54E, 7066, 1, true), (0x1D54F, 7067, 1, true),
+    (0x1D550, 7068, 1, true), (0x1D552, 7069, 1, true), (0x1D553, 7070, 1, true), (0x1D554, 7071, 1, true),
+    (0x1D555, 7072, 1, true), (0x1D556, 7073, 1, true), (0x1D557, 7074, 1, true), (0x1D558, 7075, 1, true),
+    (0x1D559, 7076, 1, true), (0x1D55A, 7077, 1, true), (0x1D55B, 7078, 1, true), (0x1D55C, 7079, 1, true),
+    (0x1D55D, 7080, 1, true), (0x1D55E, 7081, 1, true), (0x1D55F, 7082, 1, true), (0x1D560, 7083, 1, true),
+    (0x1D561, 7084, 1, true), (0x1D562, 7085, 1, true), (0x1D563, 7086, 1, true), (0x1D564, 7087, 1, true),
+    (0x1D565, 7088, 1, true), (0x1D566, 7089, 1, true), (0x1D567, 7090, 1, true), (0x1D568, 7091, 1, true),
+    (0x1D569, 7092, 1, true), (0x1D56A, 7093, 1, true), (0x1D56B, 7094, 1, true), (0x1D56C, 7095, 1, true),
+    (0x1D56D, 7096, 1, true), (0x1D56E, 7097, 1, true), (0x1D56F, 7098, 1, true), (0x1D570, 7099, 1, true),
+    (0x1D571, 7100, 1, true), (0x1D572, 7101, 1, true), (0x1D573, 7102, 1, true), (0x1D574, 7103, 1, true),
+    (0x1D575, 7104, 1, true), (0x1D576, 7105, 1, true), (0x1D577, 7106, 1, true), (0x1D578, 7107, 1, true),
+    (0x1D579, 7108, 1, true), (0x1D57A, 7109, 1, true), (0x1D57B, 7110, 1, true), (0x1D57C, 7111, 1, true),
+    (0x1D57D, 7112, 1, true), (0x1D57E, 7113, 1, true), (0x1D57F, 7114, 1, true), (0x1D580, 7115, 1, true),
+    (0x1D581, 7116, 1, true), (0x1D582, 7117, 1, true), (0x1D583, 7118, 1, true), (0x1D584, 7119, 1, true),
+    (0x1D585, 7120, 1, true), (0x1D586, 7121, 1, true), (0x1D587, 7122, 1, true), (0x1D588, 7123, 1, true),
+    (0x1D589, 7124, 1, true), (0x1D58A, 7125, 1, true), (0x1D58B, 7126, 1, true), (0x1D58C, 7127, 1, true),
+    (0x1D58D, 7128, 1, true), (0x1D58E, 7129, 1, true), (0x1D58F, 7130, 1, true), (0x1D590, 7131, 1, true),
+    (0x1D591, 7132, 1, true), (0x1D592, 7133, 1, true), (0x1D593, 7134, 1, true), (0x1D594, 7135, 1, true),
+    (0x1D595, 7136, 1, true), (0x1D596, 7137, 1, true), (0x1D597, 7138, 1, true), (0x1D598, 7139, 1, true),
+    (0x1D599, 7140, 1, true), (0x1D59A, 7141, 1, true), (0x1D59B, 7142, 1, true), (0x1D59C, 7143, 1, true),
+    (0x1D59D, 7144, 1, true), (0x1D59E, 7145, 1, true), (0x1D59F, 7146, 1, true), (0x1D5A0, 7147, 1, true),
+    (0x1D5A1, 7148, 1, true), (0x1D5A2, 7149, 1, true), (0x1D5A3, 7150, 1, true), (0x1D5A4, 7151, 1, true),
+    (0x1D5A5, 7152, 1, true), (0x1D5A6, 7153, 1, true), (0x1D5A7, 7154, 1, true), (0x1D5A8, 7155, 1, true),
+    (0x1D5A9, 7156, 1, true), (0x1D5AA, 7157, 1, true), (0x1D5AB, 7158, 1, true), (0x1D5AC, 7159, 1, true),
+    (0x1D5AD, 7160, 1, true), (0x1D5AE, 7161, 1, true), (0x1D5AF, 7162, 1, true), (0x1D5B0, 7163, 1, true),
+    (0x1D5B1, 7164, 1, true), (0x1D5B2, 7165, 1, true), (0x1D5B3, 7166, 1, true), (0x1D5B4, 7167, 1, true),
+    (0x1D5B5, 7168, 1, true), (0x1D5B6, 7169, 1, true), (0x1D5B7, 7170, 1, true), (0x1D5B8, 7171, 1, true),
+    (0x1D5B9, 7172, 1, true), (0x1D5BA, 7173, 1, true), (0x1D5BB, 7174, 1, true), (0x1D5BC, 7175, 1, true),
+    (0x1D5BD, 7176, 1, true), (0x1D5BE, 7177, 1, true), (0x1D5BF, 7178, 1, true), (0x1D5C0, 7179, 1, true),
+    (0x1D5C1, 7180, 1, true), (0x1D5C2, 7181, 1, true), (0x1D5C3, 7182, 1, true), (0x1D5C4, 7183, 1, true),
+    (0x1D5C5, 7184, 1, true), (0x1D5C6, 7185, 1, true), (0x1D5C7, 7186, 1, true), (0x1D5C8, 7187, 1, true),
+    (0x1D5C9, 7188, 1, true), (0x1D5CA, 7189, 1, true), (0x1D5CB, 7190, 1, true), (0x1D5CC, 7191, 1, true),
+    (0x1D5CD, 7192, 1, true), (0x1D5CE, 7193, 1, true), (0x1D5CF, 7194, 1, true), (0x1D5D0, 7195, 1, true),
+    (0x1D5D1, 7196, 1, true), (0x1D5D2, 7197, 1, true), (0x1D5D3, 7198, 1, true), (0x1D5D4, 7199, 1, true),
+    (0x1D5D5, 7200, 1, true), (0x1D5D6, 7201, 1, true), (0x1D5D7, 7202, 1, true), (0x1D5D8, 7203, 1, true),
+    (0x1D5D9, 7204, 1, true), (0x1D5DA, 7205, 1, true), (0x1D5DB, 7206, 1, true), (0x1D5DC, 7207, 1, true),
+    (0x1D5DD, 7208, 1, true), (0x1D5DE, 7209, 1, true), (0x1D5DF, 7210, 1, true), (0x1D5E0, 7211, 1, true),
+    (0x1D5E1, 7212, 1, true), (0x1D5E2, 7213, 1, true), (0x1D5E3, 7214, 1, true), (0x1D5E4, 7215, 1, true),
+    (0x1D5E5, 7216, 1, true), (0x1D5E6, 7217, 1, true), (0x1D5E7, 7218, 1, true), (0x1D5E8, 7219, 1, true),
+    (0x1D5E9, 7220, 1, true), (0x1D5EA, 7221, 1, true), (0x1D5EB, 7222, 1, true), (0x1D5EC, 7223, 1, true),
+    (0x1D5ED, 7224, 1, true), (0x1D5EE, 7225, 1, true), (0x1D5EF, 7226, 1, true), (0x1D5F0, 7227, 1, true),
+    (0x1D5F1, 7228, 1, true), (0x1D5F2, 7229, 1, true), (0x1D5F3, 7230, 1, true), (0x1D5F4, 7231, 1, true),
+    (0x1D5F5, 7232, 1, true), (0x1D5F6, 7233, 1, true), (0x1D5F7, 7234, 1, true), (0x1D5F8, 7235, 1, true),
+    (0x1D5F9, 7236, 1, true), (0x1D5FA, 7237, 1, true), (0x1D5FB, 7238, 1, true), (0x1D5FC, 7239, 1, true),
+    (0x1D5FD, 7240, 1, true), (0x1D5FE, 7241, 1, true), (0x1D5FF, 7242, 1, true), (0x1D600, 7243, 1, true),
+    (0x1D601, 7244, 1, true), (0x1D602, 7245, 1, true), (0x1D603, 7246, 1, true), (0x1D604, 7247, 1, true),
+    (0x1D605, 7248, 1, true), (0x1D606, 7249, 1, true), (0x1D607, 7250, 1, true), (0x1D608, 7251, 1, true),
+    (0x1D609, 7252, 1, true), (0x1D60A, 7253, 1, true), (0x1D60B, 7254, 1, true), (0x1D60C, 7255, 1, true),
+    (0x1D60D, 7256, 1, true), (0x1D60E, 7257, 1, true), (0x1D60F, 7258, 1, true), (0x1D610, 7259, 1, true),
+    (0x1D611, 7260, 1, true), (0x1D612, 7261, 1, true), (0x1D613, 7262, 1, true), (0x1D614, 7263, 1, true),
+    (0x1D615, 7264, 1, true), (0x1D616, 7265, 1, true), (0x1D617, 7266, 1, true), (0x1D618, 7267, 1, true),
+    (0x1D619, 7268, 1, true), (0x1D61A, 7269, 1, true), (0x1D61B, 7270, 1, true), (0x1D61C, 7271, 1, true),
+    (0x1D61D, 7272, 1, true), (0x1D61E, 7273, 1, true), (0x1D61F, 7274, 1, true), (0x1D620, 7275, 1, true),
+    (0x1D621, 7276, 1, true), (0x1D622, 7277, 1, true), (0x1D623, 7278, 1, true), (0x1D624, 7279, 1, true),
+    (0x1D625, 7280, 1, true), (0x1D626, 7281, 1, true), (0x1D627, 7282, 1, true), (0x1D628, 7283, 1, true),
+    (0x1D629, 7284, 1, true), (0x1D62A, 7285, 1, true), (0x1D62B, 7286, 1, true), (0x1D62C, 7287, 1, true),
+    (0x1D62D, 7288, 1, true), (0x1D62E, 7289, 1, true), (0x1D62F, 7290, 1, true), (0x1D630, 7291, 1, true),
+    (0x1D631, 7292, 1, true), (0x1D632, 7293, 1, true), (0x1D633, 7294, 1, true), (0x1D634, 7295, 1, true),
+    (0x1D635, 7296, 1, true), (0x1D636, 7297, 1, true), (0x1D637, 7298, 1, true), (0x1D638, 7299, 1, true),
+    (0x1D639, 7300, 1, true), (0x1D63A, 7301, 1, true), (0x1D63B, 7302, 1, true), (0x1D63C, 7303, 1, true),
+    (0x1D63D, 7304, 1, true), (0x1D63E, 7305, 1, true), (0x1D63F, 7306, 1, true), (0x1D640, 7307, 1, true),
+    (0x1D641, 7308, 1, true), (0x1D642, 7309, 1, true), (0x1D643, 7310, 1, true), (0x1D644, 7311, 1, true),
+    (0x1D645, 7312, 1, true), (0x1D646, 7313, 1, true), (0x1D647, 7314, 1, true), (0x1D648, 7315, 1, true),
+    (0x1D649, 7316, 1, true), (0x1D64A, 7317, 1, true), (0x1D64B, 7318, 1, true), (0x1D64C, 7319, 1, true),
+    (0x1D64D, 7320, 1, true), (0x1D64E, 7321, 1, true), (0x1D64F, 7322, 1, true), (0x1D650, 7323, 1, true),
+    (0x1D651, 7324, 1, true), (0x1D652, 7325, 1, true), (0x1D653, 7326, 1, true), (0x1D654, 7327, 1, true),
+    (0x1D655, 7328, 1, true), (0x1D656, 7329, 1, true), (0x1D657, 7330, 1, true), (0x1D658, 7331, 1, true),
+    (0x1D659, 7332, 1, true), (0x1D65A, 7333, 1, true), (0x1D65B, 7334, 1, true), (0x1D65C, 7335, 1, true),
+    (0x1D65D, 7336, 1, true), (0x1D65E, 7337, 1, true), (0x1D65F, 7338, 1, true), (0x1D660, 7339, 1, true),
+    (0x1D661, 7340, 1, true), (0x1D662, 7341, 1, true), (0x1D663, 7342, 1, true), (0x1D664, 7343, 1, true),
+    (0x1D665, 7344, 1, true), (0x1D666, 7345, 1, true), (0x1D667, 7346, 1, true), (0x1D668, 7347, 1, true),
+    (0x1D669, 7348, 1, true), (0x1D66A, 7349, 1, true), (0x1D66B, 7350, 1, true), (0x1D66C, 7351, 1, true),
+    (0x1D66D, 7352, 1, true), (0x1D66E, 7353, 1, true), (0x1D66F, 7354, 1, true), (0x1D670, 7355, 1, true),
+    (0x1D671, 7356, 1, true), (0x1D672, 7357, 1, true), (0x1D673, 7358, 1, true), (0x1D674, 7359, 1, true),
+    (0x1D675, 7360, 1, true), (0x1D676, 7361, 1, true), (0x1D677, 7362, 1, true), (0x1D678, 7363, 1, true),
+    (0x1D679, 7364, 1, true), (0x1D67A, 7365, 1, true), (0x1D67B, 7366, 1, true), (0x1D67C, 7367, 1, true),
+    (0x1D67D, 7368, 1, true), (0x1D67E, 7369, 1, true), (0x1D67F, 7370, 1, true), (0x1D680, 7371, 1, true),
+    (0x1D681, 7372, 1, true), (0x1D682, 7373, 1, true), (0x1D683, 7374, 1, true), (0x1D684, 7375, 1, true),
+    (0x1D685, 7376, 1, true), (0x1D686, 7377, 1, true), (0x1D687, 7378, 1, true), (0x1D688, 7379, 1, true),
+    (0x1D689, 7380, 1, true), (0x1D68A, 7381, 1, true), (0x1D68B, 7382, 1, true), (0x1D68C, 7383, 1, true),
+    (0x1D68D, 7384, 1, true), (0x1D68E, 7385, 1, true), (0x1D68F, 7386, 1, true), (0x1D690, 7387, 1, true),
+    (0x1D691, 7388, 1, true), (0x1D692, 7389, 1, true), (0x1D693, 7390, 1, true), (0x1D694, 7391, 1, true),
+    (0x1D695, 7392, 1, true), (0x1D696, 7393, 1, true), (0x1D697, 7394, 1, true), (0x1D698, 7395, 1, true),
+    (0x1D699, 7396, 1, true), (0x1D69A, 7397, 1, true), (0x1D69B, 7398, 1, true), (0x1D69C, 7399, 1, true),
+    (0x1D69D, 7400, 1, true), (0x1D69E, 7401, 1, true), (0x1D69F, 7402, 1, true), (0x1D6A0, 7403, 1, true),
+    (0x1D6A1, 7404, 1, true), (0x1D6A2, 7405, 1, true), (0x1D6A3, 7406, 1, true), (0x1D6A4, 7407, 1, true),
+    (0x1D6A5, 7408, 1, true), (0x1D6A8, 7409, 1, true), (0x1D6A9, 7410, 1, true), (0x1D6AA, 7411, 1, true),
+    (0x1D6AB, 7412, 1, true), (0x1D6AC, 7413, 1, true), (0x1D6AD, 7414, 1, true), (0x1D6AE, 7415, 1, true),
+    (0x1D6AF, 7416, 1, true), (0x1D6B0, 7417, 1, true), (0x1D6B1, 7418, 1, true), (0x1D6B2, 7419, 1, true),
+    (0x1D6B3, 7420, 1, true), (0x1D6B4, 7421, 1, true), (0x1D6B5, 7422, 1, true), (0x1D6B6, 7423, 1, true),
+    (0x1D6B7, 7424, 1, true), (0x1D6B8, 7425, 1, true), (0x1D6B9, 7426, 1, true), (0x1D6BA, 7427, 1, true),
+    (0x1D6BB, 7428, 1, true), (0x1D6BC, 7429, 1, true), (0x1D6BD, 7430, 1, true), (0x1D6BE, 7431, 1, true),
+    (0x1D6BF, 7432, 1, true), (0x1D6C0, 7433, 1, true), (0x1D6C1, 7434, 1, true), (0x1D6C2, 7435, 1, true),
+    (0x1D6C3, 7436, 1, true), (0x1D6C4, 7437, 1, true), (0x1D6C5, 7438, 1, true), (0x1D6C6, 7439, 1, true),
+    (0x1D6C7, 7440, 1, true), (0x1D6C8, 7441, 1, true), (0x1D6C9, 7442, 1, true), (0x1D6CA, 7443, 1, true),
+    (0x1D6CB, 7444, 1, true), (0x1D6CC, 7445, 1, true), (0x1D6CD, 7446, 1, true), (0x1D6CE, 7447, 1, true),
+    (0x1D6CF, 7448, 1, true), (0x1D6D0, 7449, 1, true), (0x1D6D1, 7450, 1, true), (0x1D6D2, 7451, 1, true),
+    (0x1D6D3, 7452, 1, true), (0x1D6D4, 7453, 1, true), (0x1D6D5, 7454, 1, true), (0x1D6D6, 7455, 1, true),
+    (0x1D6D7, 7456, 1, true), (0x1D6D8, 7457, 1, true), (0x1D6D9, 7458, 1, true), (0x1D6DA, 7459, 1, true),
+    (0x1D6DB, 7460, 1, true), (0x1D6DC, 7461, 1, true), (0x1D6DD, 7462, 1, true), (0x1D6DE, 7463, 1, true),
+    (0x1D6DF, 7464, 1, true), (0x1D6E0, 7465, 1, true), (0x1D6E1, 7466, 1, true), (0x1D6E2, 7467, 1, true),
+    (0x1D6E3, 7468, 1, true), (0x1D6E4, 7469, 1, true), (0x1D6E5, 7470, 1, true), (0x1D6E6, 7471, 1, true),
+    (0x1D6E7, 7472, 1, true), (0x1D6E8, 7473, 1, true), (0x1D6E9, 7474, 1, true), (0x1D6EA, 7475, 1, true),
+    (0x1D6EB, 7476, 1, true), (0x1D6EC, 7477, 1, true), (0x1D6ED, 7478, 1, true), (0x1D6EE, 7479, 1, true),
+    (0x1D6EF, 7480, 1, true), (0x1D6F0, 7481, 1, true), (0x1D6F1, 7482, 1, true), (0x1D6F2, 7483, 1, true),
+    (0x1D6F3, 7484, 1, true), (0x1D6F4, 7485, 1, true), (0x1D6F5, 7486, 1, true), (0x1D6F6, 7487, 1, true),
+    (0x1D6F7, 7488, 1, true), (0x1D6F8, 7489, 1, true), (0x1D6F9, 7490, 1, true), (0x1D6FA, 7491, 1, true),
+    (0x1D6FB, 7492, 1, true), (0x1D6FC, 7493, 1, true), (0x1D6FD, 7494, 1, true), (0x1D6FE, 7495, 1, true),
+    (0x1D6FF, 7496, 1, true), (0x1D700, 7497, 1, true), (0x1D701, 7498, 1, true), (0x1D702, 7499, 1, true),
+    (0x1D703, 7500, 1, true), (0x1D704, 7501, 1, true), (0x1D705, 7502, 1, true), (0x1D706, 7503, 1, true),
+    (0x1D707, 7504, 1, true), (0x1D708, 7505, 1, true), (0x1D709, 7506, 1, true), (0x1D70A, 7507, 1, true),
+    (0x1D70B, 7508, 1, true), (0x1D70C, 7509, 1, true), (0x1D70D, 7510, 1, true), (0x1D70E, 7511, 1, true),
+    (0x1D70F, 7512, 1, true), (0x1D710, 7513, 1, true), (0x1D711, 7514, 1, true), (0x1D712, 7515, 1, true),
+    (0x1D713, 7516, 1, true), (0x1D714, 7517, 1, true), (0x1D715, 7518, 1, true), (0x1D716, 7519, 1, true),
+    (0x1D717, 7520, 1, true), (0x1D718, 7521, 1, true), (0x1D719, 7522, 1, true), (0x1D71A, 7523, 1, true),
+    (0x1D71B, 7524, 1, true), (0x1D71C, 7525, 1, true), (0x1D71D, 7526, 1, true), (0x1D71E, 7527, 1, true),
+    (0x1D71F, 7528, 1, true), (0x1D720, 7529, 1, true), (0x1D721, 7530, 1, true), (0x1D722, 7531, 1, true),
+    (0x1D723, 7532, 1, true), (0x1D724, 7533, 1, true), (0x1D725, 7534, 1, true), (0x1D726, 7535, 1, true),
+    (0x1D727, 7536, 1, true), (0x1D728, 7537, 1, true), (0x1D729, 7538, 1, true), (0x1D72A, 7539, 1, true),
+    (0x1D72B, 7540, 1, true), (0x1D72C, 7541, 1, true), (0x1D72D, 7542, 1, true), (0x1D72E, 7543, 1, true),
+    (0x1D72F, 7544, 1, true), (0x1D730, 7545, 1, true), (0x1D731, 7546, 1, true), (0x1D732, 7547, 1, true),
+    (0x1D733, 7548, 1, true), (0x1D734, 7549, 1, true), (0x1D735, 7550, 1, true), (0x1D736, 7551, 1, true),
+    (0x1D737, 7552, 1, true), (0x1D738, 7553, 1, true), (0x1D739, 7554, 1, true), (0x1D73A, 7555, 1, true),
+    (0x1D73B, 7556, 1, true), (0x1D73C, 7557, 1, true), (0x1D73D, 7558, 1, true), (0x1D73E, 7559, 1, true),
+    (0x1D73F, 7560, 1, true), (0x1D740, 7561, 1, true), (0x1D741, 7562, 1, true), (0x1D742, 7563, 1, true),
+    (0x1D743, 7564, 1, true), (0x1D744, 7565, 1, true), (0x1D745, 7566, 1, true), (0x1D746, 7567, 1, true),
+    (0x1D747, 7568, 1, true), (0x1D748, 7569, 1, true), (0x1D749, 7570, 1, true), (0x1D74A, 7571, 1, true),
+    (0x1D74B, 7572, 1, true), (0x1D74C, 7573, 1, true), (0x1D74D, 7574, 1, true), (0x1D74E, 7575, 1, true),
+    (0x1D74F, 7576, 1, true), (0x1D750, 7577, 1, true), (0x1D751, 7578, 1, true), (0x1D752, 7579, 1, true),
+    (0x1D753, 7580, 1, true), (0x1D754, 7581, 1, true), (0x1D755, 7582, 1, true), (0x1D756, 7583, 1, true),
+    (0x1D757, 7584, 1, true), (0x1D758, 7585, 1, true), (0x1D759, 7586, 1, true), (0x1D75A, 7587, 1, true),
+    (0x1D75B, 7588, 1, true), (0x1D75C, 7589, 1, true), (0x1D75D, 7590, 1, true), (0x1D75E, 7591, 1, true),
+    (0x1D75F, 7592, 1, true), (0x1D760, 7593, 1, true), (0x1D761, 7594, 1, true), (0x1D762, 7595, 1, true),
+    (0x1D763, 7596, 1, true), (0x1D764, 7597, 1, true), (0x1D765, 7598, 1, true), (0x1D766, 7599, 1, true),
+    (0x1D767, 7600, 1, true), (0x1D768, 7601, 1, true), (0x1D769, 7602, 1, true), (0x1D76A, 7603, 1, true),
+    (0x1D76B, 7604, 1, true), (0x1D76C, 7605, 1, true), (0x1D76D, 7606, 1, true), (0x1D76E, 7607, 1, true),
+    (0x1D76F, 7608, 1, true), (0x1D770, 7609, 1, true), (0x1D771, 7610, 1, true), (0x1D772, 7611, 1, true),
+    (0x1D773, 7612, 1, true), (0x1D774, 7613, 1, true), (0x1D775, 7614, 1, true), (0x1D776, 7615, 1, true),
+    (0x1D777, 7616, 1, true), (0x1D778, 7617, 1, true), (0x1D779, 7618, 1, true), (0x1D77A, 7619, 1, true),
+    (0x1D77B, 7620, 1, true), (0x1D77C, 7621, 1, true), (0x1D77D, 7622, 1, true), (0x1D77E, 7623, 1, true),
+    (0x1D77F, 7624, 1, true), (0x1D780, 7625, 1, true), (0x1D781, 7626, 1, true), (0x1D782, 7627, 1, true),
+    (0x1D783, 7628, 1, true), (0x1D784, 7629, 1, true), (0x1D785, 7630, 1, true), (0x1D786, 7631, 1, true),
+    (0x1D787, 7632, 1, true), (0x1D788, 7633, 1, true), (0x1D789, 7634, 1, true), (0x1D78A, 7635, 1, true),
+    (0x1D78B, 7636, 1, true), (0x1D78C, 7637, 1, true), (0x1D78D, 7638, 1, true), (0x1D78E, 7639, 1, true),
+    (0x1D78F, 7640, 1, true), (0x1D790, 7641, 1, true), (0x1D791, 7642, 1, true), (0x1D792, 7643, 1, true),
+    (0x1D793, 7644, 1, true), (0x1D794, 7645, 1, true), (0x1D795, 7646, 1, true), (0x1D796, 7647, 1, true),
+    (0x1D797, 7648, 1, true), (0x1D798, 7649, 1, true), (0x1D799, 7650, 1, true), (0x1D79A, 7651, 1, true),
+    (0x1D79B, 7652, 1, true), (0x1D79C, 7653, 1, true), (0x1D79D, 7654, 1, true), (0x1D79E, 7655, 1, true),
+    (0x1D79F, 7656, 1, true), (0x1D7A0, 7657, 1, true), (0x1D7A1, 7658, 1, true), (0x1D7A2, 7659, 1, true),
+    (0x1D7A3, 7660, 1, true), (0x1D7A4, 7661, 1, true), (0x1D7A5, 7662, 1, true), (0x1D7A6, 7663, 1, true),
+    (0x1D7A7, 7664, 1, true), (0x1D7A8, 7665, 1, true), (0x1D7A9, 7666, 1, true), (0x1D7AA, 7667, 1, true),
+    (0x1D7AB, 7668, 1, true), (0x1D7AC, 7669, 1, true), (0x1D7AD, 7670, 1, true), (0x1D7AE, 7671, 1, true),
+    (0x1D7AF, 7672, 1, true), (0x1D7B0, 7673, 1, true), (0x1D7B1, 7674, 1, true), (0x1D7B2, 7675, 1, true),
+    (0x1D7B3, 7676, 1, true), (0x1D7B4, 7677, 1, true), (0x1D7B5, 7678, 1, true), (0x1D7B6, 7679, 1, true),
+    (0x1D7B7, 7680, 1, true), (0x1D7B8, 7681, 1, true), (0x1D7B9, 7682, 1, true), (0x1D7BA, 7683, 1, true),
+    (0x1D7BB, 7684, 1, true), (0x1D7BC, 7685, 1, true), (0x1D7BD, 7686, 1, true), (0x1D7BE, 7687, 1, true),
+    (0x1D7BF, 7688, 1, true), (0x1D7C0, 7689, 1, true), (0x1D7C1, 7690, 1, true), (0x1D7C2, 7691, 1, true),
+    (0x1D7C3, 7692, 1, true), (0x1D7C4, 7693, 1, true), (0x1D7C5, 7694, 1, true), (0x1D7C6, 7695, 1, true),
+    (0x1D7C7, 7696, 1, true), (0x1D7C8, 7697, 1, true), (0x1D7C9, 7698, 1, true), (0x1D7CA, 7699, 1, true),
+    (0x1D7CB, 7700, 1, true), (0x1D7CE, 7701, 1, true), (0x1D7CF, 7702, 1, true), (0x1D7D0, 7703, 1, true),
+    (0x1D7D1, 7704, 1, true), (0x1D7D2, 7705, 1, true), (0x1D7D3, 7706, 1, true), (0x1D7D4, 7707, 1, true),
+    (0x1D7D5, 7708, 1, true), (0x1D7D6, 7709, 1, true), (0x1D7D7, 7710, 1, true), (0x1D7D8, 7711, 1, true),
+    (0x1D7D9, 7712, 1, true), (0x1D7DA, 7713, 1, true), (0x1D7DB, 7714, 1, true), (0x1D7DC, 7715, 1, true),
+    (0x1D7DD, 7716, 1, true), (0x1D7DE, 7717, 1, true), (0x1D7DF, 7718, 1, true), (0x1D7E0, 7719, 1, true),
+    (0x1D7E1, 7720, 1, true), (0x1D7E2, 7721, 1, true), (0x1D7E3, 7722, 1, true), (0x1D7E4, 7723, 1, true),
+    (0x1D7E5, 7724, 1, true), (0x1D7E6, 7725, 1, true), (0x1D7E7, 7726, 1, true), (0x1D7E8, 7727, 1, true),
+    (0x1D7E9, 7728, 1, true), (0x1D7EA, 7729, 1, true), (0x1D7EB, 7730, 1, true), (0x1D7EC, 7731, 1, true),
+    (0x1D7ED, 7732, 1, true), (0x1D7EE, 7733, 1, true), (0x1D7EF, 7734, 1, true), (0x1D7F0, 7735, 1, true),
+    (0x1D7F1, 7736, 1, true), (0x1D7F2, 7737, 1, true), (0x1D7F3, 7738, 1, true), (0x1D7F4, 7739, 1, true),
+    (0x1D7F5, 7740, 1, true), (0x1D7F6, 7741, 1, true), (0x1D7F7, 7742, 1, true), (0x1D7F8, 7743, 1, true),
+    (0x1D7F9, 7744, 1, true), (0x1D7FA, 7745, 1, true), (0x1D7FB, 7746, 1, true), (0x1D7FC, 7747, 1, true),
+    (0x1D7FD, 7748, 1, true), (0x1D7FE, 7749, 1, true), (0x1D7FF, 7750, 1, true), (0x1E030, 7751, 1, true),
+    (0x1E031, 7752, 1, true), (0x1E032, 7753, 1, true), (0x1E033, 7754, 1, true), (0x1E034, 7755, 1, true),
+    (0x1E035, 7756, 1, true), (0x1E036, 7757, 1, true), (0x1E037, 7758, 1, true), (0x1E038, 7759, 1, true),
+    (0x1E039, 7760, 1, true), (0x1E03A, 7761, 1, true), (0x1E03B, 7762, 1, true), (0x1E03C, 7763, 1, true),
+    (0x1E03D, 7764, 1, true), (0x1E03E, 7765, 1, true), (0x1E03F, 7766, 1, true), (0x1E040, 7767, 1, true),
+    (0x1E041, 7768, 1, true), (0x1E042, 7769, 1, true), (0x1E043, 7770, 1, true), (0x1E044, 7771, 1, true),
+    (0x1E045, 7772, 1, true), (0x1E046, 7773, 1, true), (0x1E047, 7774, 1, true), (0x1E048, 7775, 1, true),
+    (0x1E049, 7776, 1, true), (0x1E04A, 7777, 1, true), (0x1E04B, 7778, 1, true), (0x1E04C, 7779, 1, true),
+    (0x1E04D, 7780, 1, true), (0x1E04E, 7781, 1, true), (0x1E04F, 7782, 1, true), (0x1E050, 7783, 1, true),
+    (0x1E051, 7784, 1, true), (0x1E052, 7785, 1, true), (0x1E053, 7786, 1, true), (0x1E054, 7787, 1, true),
+    (0x1E055, 7788, 1, true), (0x1E056, 7789, 1, true), (0x1E057, 7790, 1, true), (0x1E058, 7791, 1, true),
+    (0x1E059, 7792, 1, true), (0x1E05A, 7793, 1, true), (0x1E05B, 7794, 1, true), (0x1E05C, 7795, 1, true),
+    (0x1E05D, 7796, 1, true), (0x1E05E, 7797, 1, true), (0x1E05F, 7798, 1, true), (0x1E060, 7799, 1, true),
+    (0x1E061, 7800, 1, true), (0x1E062, 7801, 1, true), (0x1E063, 7802, 1, true), (0x1E064, 7803, 1, true),
+    (0x1E065, 7804, 1, true), (0x1E066, 7805, 1, true), (0x1E067, 7806, 1, true), (0x1E068, 7807, 1, true),
+    (0x1E069, 7808, 1, true), (0x1E06A, 7809, 1, true), (0x1E06B, 7810, 1, true), (0x1E06C, 7811, 1, true),
+    (0x1E06D, 7812, 1, true), (0x1EE00, 7813, 1, true), (0x1EE01, 7814, 1, true), (0x1EE02, 7815, 1, true),
+    (0x1EE03, 7816, 1, true), (0x1EE05, 7817, 1, true), (0x1EE06, 7818, 1, true), (0x1EE07, 7819, 1, true),
+    (0x1EE08, 7820, 1, true), (0x1EE09, 7821, 1, true), (0x1EE0A, 7822, 1, true), (0x1EE0B, 7823, 1, true),
+    (0x1EE0C, 7824, 1, true), (0x1EE0D, 7825, 1, true), (0x1EE0E, 7826, 1, true), (0x1EE0F, 7827, 1, true),
+    (0x1EE10, 7828, 1, true), (0x1EE11, 7829, 1, true), (0x1EE12, 7830, 1, true), (0x1EE13, 7831, 1, true),
+    (0x1EE14, 7832, 1, true), (0x1EE15, 7833, 1, true), (0x1EE16, 7834, 1, true), (0x1EE17, 7835, 1, true),
+    (0x1EE18, 7836, 1, true), (0x1EE19, 7837, 1, true), (0x1EE1A, 7838, 1, true), (0x1EE1B, 7839, 1, true),
+    (0x1EE1C, 7840, 1, true), (0x1EE1D, 7841, 1, true), (0x1EE1E, 7842, 1, true), (0x1EE1F, 7843, 1, true),
+    (0x1EE21, 7844, 1, true), (0x1EE22, 7845, 1, true), (0x1EE24, 7846, 1, true), (0x1EE27, 7847, 1, true),
+    (0x1EE29, 7848, 1, true), (0x1EE2A, 7849, 1, true), (0x1EE2B, 7850, 1, true), (0x1EE2C, 7851, 1, true),
+    (0x1EE2D, 7852, 1, true), (0x1EE2E, 7853, 1, true), (0x1EE2F, 7854, 1, true), (0x1EE30, 7855, 1, true),
+    (0x1EE31, 7856, 1, true), (0x1EE32, 7857, 1, true), (0x1EE34, 7858, 1, true), (0x1EE35, 7859, 1, true),
+    (0x1EE36, 7860, 1, true), (0x1EE37, 7861, 1, true), (0x1EE39, 7862, 1, true), (0x1EE3B, 7863, 1, true),
+    (0x1EE42, 7864, 1, true), (0x1EE47, 7865, 1, true), (0x1EE49, 7866, 1, true), (0x1EE4B, 7867, 1, true),
+    (0x1EE4D, 7868, 1, true), (0x1EE4E, 7869, 1, true), (0x1EE4F, 7870, 1, true), (0x1EE51, 7871, 1, true),
+    (0x1EE52, 7872, 1, true), (0x1EE54, 7873, 1, true), (0x1EE57, 7874, 1, true), (0x1EE59, 7875, 1, true),
+    (0x1EE5B, 7876, 1, true), (0x1EE5D, 7877, 1, true), (0x1EE5F, 7878, 1, true), (0x1EE61, 7879, 1, true),
+    (0x1EE62, 7880, 1, true), (0x1EE64, 7881, 1, true), (0x1EE67, 7882, 1, true), (0x1EE68, 7883, 1, true),
+    (0x1EE69, 7884, 1, true), (0x1EE6A, 7885, 1, true), (0x1EE6C, 7886, 1, true), (0x1EE6D, 7887, 1, true),
+    (0x1EE6E, 7888, 1, true), (0x1EE6F, 7889, 1, true), (0x1EE70, 7890, 1, true), (0x1EE71, 7891, 1, true),
+    (0x1EE72, 7892, 1, true), (0x1EE74, 7893, 1, true), (0x1EE75, 7894, 1, true), (0x1EE76, 7895, 1, true),
+    (0x1EE77, 7896, 1, true), (0x1EE79, 7897, 1, true), (0x1EE7A, 7898, 1, true), (0x1EE7B, 7899, 1, true),
+    (0x1EE7C, 7900, 1, true), (0x1EE7E, 7901, 1, true), (0x1EE80, 7902, 1, true), (0x1EE81, 7903, 1, true),
+    (0x1EE82, 7904, 1, true), (0x1EE83, 7905, 1, true), (0x1EE84, 7906, 1, true), (0x1EE85, 7907, 1, true),
+    (0x1EE86, 7908, 1, true), (0x1EE87, 7909, 1, true), (0x1EE88, 7910, 1, true), (0x1EE89, 7911, 1, true),
+    (0x1EE8B, 7912, 1, true), (0x1EE8C, 7913, 1, true), (0x1EE8D, 7914, 1, true), (0x1EE8E, 7915, 1, true),
+    (0x1EE8F, 7916, 1, true), (0x1EE90, 7917, 1, true), (0x1EE91, 7918, 1, true), (0x1EE92, 7919, 1, true),
+    (0x1EE93, 7920, 1, true), (0x1EE94, 7921, 1, true), (0x1EE95, 7922, 1, true), (0x1EE96, 7923, 1, true),
+    (0x1EE97, 7924, 1, true), (0x1EE98, 7925, 1, true), (0x1EE99, 7926, 1, true), (0x1EE9A, 7927, 1, true),
+    (0x1EE9B, 7928, 1, true), (0x1EEA1, 7929, 1, true), (0x1EEA2, 7930, 1, true), (0x1EEA3, 7931, 1, true),
+    (0x1EEA5, 7932, 1, true), (0x1EEA6, 7933, 1, true), (0x1EEA7, 7934, 1, true), (0x1EEA8, 7935, 1, true),
+    (0x1EEA9, 7936, 1, true), (0x1EEAB, 7937, 1, true), (0x1EEAC, 7938, 1, true), (0x1EEAD, 7939, 1, true),
+    (0x1EEAE, 7940, 1, true), (0x1EEAF, 7941, 1, true), (0x1EEB0, 7942, 1, true), (0x1EEB1, 7943, 1, true),
+    (0x1EEB2, 7944, 1, true), (0x1EEB3, 7945, 1, true), (0x1EEB4, 7946, 1, true), (0x1EEB5, 7947, 1, true),
+    (0x1EEB6, 7948, 1, true), (0x1EEB7, 7949, 1, true), (0x1EEB8, 7950, 1, true), (0x1EEB9, 7951, 1, true),
+    (0x1EEBA, 7952, 1, true), (0x1EEBB, 7953, 1, true), (0x1F100, 7954, 2, true), (0x1F101, 7956, 2, true),
+    (0x1F102, 7958, 2, true), (0x1F103, 7960, 2, true), (0x1F104, 7962, 2, true), (0x1F105, 7964, 2, true),
+    (0x1F106, 7966, 2, true), (0x1F107, 7968, 2, true), (0x1F108, 7970, 2, true), (0x1F109, 7972, 2, true),
+    (0x1F10A, 7974, 2, true), (0x1F110, 7976, 3, true), (0x1F111, 7979, 3, true), (0x1F112, 7982, 3, true),
+    (0x1F113, 7985, 3, true), (0x1F114, 7988, 3, true), (0x1F115, 7991, 3, true), (0x1F116, 7994, 3, true),
+    (0x1F117, 7997, 3, true), (0x1F118, 8000, 3, true), (0x1F119, 8003, 3, true), (0x1F11A, 8006, 3, true),
+    (0x1F11B, 8009, 3, true), (0x1F11C, 8012, 3, true), (0x1F11D, 8015, 3, true), (0x1F11E, 8018, 3, true),
+    (0x1F11F, 8021, 3, true), (0x1F120, 8024, 3, true), (0x1F121, 8027, 3, true), (0x1F122, 8030, 3, true),
+    (0x1F123, 8033, 3, true), (0x1F124, 8036, 3, true), (0x1F125, 8039, 3, true), (0x1F126, 8042, 3, true),
+    (0x1F127, 8045, 3, true), (0x1F128, 8048, 3, true), (0x1F129, 8051, 3, true), (0x1F12A, 8054, 3, true),
+    (0x1F12B, 8057, 1, true), (0x1F12C, 8058, 1, true), (0x1F12D, 8059, 2, true), (0x1F12E, 8061, 2, true),
+    (0x1F130, 8063, 1, true), (0x1F131, 8064, 1, true), (0x1F132, 8065, 1, true), (0x1F133, 8066, 1, true),
+    (0x1F134, 8067, 1, true), (0x1F135, 8068, 1, true), (0x1F136, 8069, 1, true), (0x1F137, 8070, 1, true),
+    (0x1F138, 8071, 1, true), (0x1F139, 8072, 1, true), (0x1F13A, 8073, 1, true), (0x1F13B, 8074, 1, true),
+    (0x1F13C, 8075, 1, true), (0x1F13D, 8076, 1, true), (0x1F13E, 8077, 1, true), (0x1F13F, 8078, 1, true),
+    (0x1F140, 8079, 1, true), (0x1F141, 8080, 1, true), (0x1F142, 8081, 1, true), (0x1F143, 8082, 1, true),
+    (0x1F144, 8083, 1, true), (0x1F145, 8084, 1, true), (0x1F146, 8085, 1, true), (0x1F147, 8086, 1, true),
+    (0x1F148, 8087, 1, true), (0x1F149, 8088, 1, true), (0x1F14A, 8089, 2, true), (0x1F14B, 8091, 2, true),
+    (0x1F14C, 8093, 2, true), (0x1F14D, 8095, 2, true), (0x1F14E, 8097, 3, true), (0x1F14F, 8100, 2, true),
+    (0x1F16A, 8102, 2, true), (0x1F16B, 8104, 2, true), (0x1F16C, 8106, 2, true), (0x1F190, 8108, 2, true),
+    (0x1F200, 8110, 2, true), (0x1F201, 8112, 2, true), (0x1F202, 8114, 1, true), (0x1F210, 8115, 1, true),
+    (0x1F211, 8116, 1, true), (0x1F212, 8117, 1, true), (0x1F213, 8118, 1, true), (0x1F214, 8119, 1, true),
+    (0x1F215, 8120, 1, true), (0x1F216, 8121, 1, true), (0x1F217, 8122, 1, true), (0x1F218, 8123, 1, true),
+    (0x1F219, 8124, 1, true), (0x1F21A, 8125, 1, true), (0x1F21B, 8126, 1, true), (0x1F21C, 8127, 1, true),
+    (0x1F21D, 8128, 1, true), (0x1F21E, 8129, 1, true), (0x1F21F, 8130, 1, true), (0x1F220, 8131, 1, true),
+    (0x1F221, 8132, 1, true), (0x1F222, 8133, 1, true), (0x1F223, 8134, 1, true), (0x1F224, 8135, 1, true),
+    (0x1F225, 8136, 1, true), (0x1F226, 8137, 1, true), (0x1F227, 8138, 1, true), (0x1F228, 8139, 1, true),
+    (0x1F229, 8140, 1, true), (0x1F22A, 8141, 1, true), (0x1F22B, 8142, 1, true), (0x1F22C, 8143, 1, true),
+    (0x1F22D, 8144, 1, true), (0x1F22E, 8145, 1, true), (0x1F22F, 8146, 1, true), (0x1F230, 8147, 1, true),
+    (0x1F231, 8148, 1, true), (0x1F232, 8149, 1, true), (0x1F233, 8150, 1, true), (0x1F234, 8151, 1, true),
+    (0x1F235, 8152, 1, true), (0x1F236, 8153, 1, true), (0x1F237, 8154, 1, true), (0x1F238, 8155, 1, true),
+    (0x1F239, 8156, 1, true), (0x1F23A, 8157, 1, true), (0x1F23B, 8158, 1, true), (0x1F240, 8159, 3, true),
+    (0x1F241, 8162, 3, true), (0x1F242, 8165, 3, true), (0x1F243, 8168, 3, true), (0x1F244, 8171, 3, true),
+    (0x1F245, 8174, 3, true), (0x1F246, 8177, 3, true), (0x1F247, 8180, 3, true), (0x1F248, 8183, 3, true),
+    (0x1F250, 8186, 1, true), (0x1F251, 8187, 1, true), (0x1FBF0, 8188, 1, true), (0x1FBF1, 8189, 1, true),
+    (0x1FBF2, 8190, 1, true), (0x1FBF3, 8191, 1, true), (0x1FBF4, 8192, 1, true), (0x1FBF5, 8193, 1, true),
+    (0x1FBF6, 8194, 1, true), (0x1FBF7, 8195, 1, true), (0x1FBF8, 8196, 1, true), (0x1FBF9, 8197, 1, true),
+    (0x2F800, 8198, 1, false), (0x2F801, 8199, 1, false), (0x2F802, 8200, 1, false), (0x2F803, 8201, 1, false),
+    (0x2F804, 8202, 1, false), (0x2F805, 8203, 1, false), (0x2F806, 8204, 1, false), (0x2F807, 8205, 1, false),
+    (0x2F808, 8206, 1, false), (0x2F809, 8207, 1, false), (0x2F80A, 8208, 1, false), (0x2F80B, 8209, 1, false),
+    (0x2F80C, 8210, 1, false), (0x2F80D, 8211, 1, false), (0x2F80E, 8212, 1, false), (0x2F80F, 8213, 1, false),
+    (0x2F810, 8214, 1, false), (0x2F811, 8215, 1, false), (0x2F812, 8216, 1, false), (0x2F813, 8217, 1, false),
+    (0x2F814, 8218, 1, false), (0x2F815, 8219, 1, false), (0x2F816, 8220, 1, false), (0x2F817, 8221, 1, false),
+    (0x2F818, 8222, 1, false), (0x2F819, 8223, 1, false), (0x2F81A, 8224, 1, false), (0x2F81B, 8225, 1, false),
+    (0x2F81C, 8226, 1, false), (0x2F81D, 8227, 1, false), (0x2F81E, 8228, 1, false), (0x2F81F, 8229, 1, false),
+    (0x2F820, 8230, 1, false), (0x2F821, 8231, 1, false), (0x2F822, 8232, 1, false), (0x2F823, 8233, 1, false),
+    (0x2F824, 8234, 1, false), (0x2F825, 8235, 1, false), (0x2F826, 8236, 1, false), (0x2F827, 8237, 1, false),
+    (0x2F828, 8238, 1, false), (0x2F829, 8239, 1, false), (0x2F82A, 8240, 1, false), (0x2F82B, 8241, 1, false),
+    (0x2F82C, 8242, 1, false), (0x2F82D, 8243, 1, false), (0x2F82E, 8244, 1, false), (0x2F82F, 8245, 1, false),
+    (0x2F830, 8246, 1, false), (0x2F831, 8247, 1, false), (0x2F832, 8248, 1, false), (0x2F833, 8249, 1, false),
+    (0x2F834, 8250, 1, false), (0x2F835, 8251, 1, false), (0x2F836, 8252, 1, false), (0x2F837, 8253, 1, false),
+    (0x2F838, 8254, 1, false), (0x2F839, 8255, 1, false), (0x2F83A, 8256, 1, false), (0x2F83B, 8257, 1, false),
+    (0x2F83C, 8258, 1, false), (0x2F83D, 8259, 1, false), (0x2F83E, 8260, 1, false), (0x2F83F, 8261, 1, false),
+    (0x2F840, 8262, 1, false), (0x2F841, 8263, 1, false), (0x2F842, 8264, 1, false), (0x2F843, 8265, 1, false),
+    (0x2F844, 8266, 1, false), (0x2F845, 8267, 1, false), (0x2F846, 8268, 1, false), (0x2F847, 8269, 1, false),
+    (0x2F848, 8270, 1, false), (0x2F849, 8271, 1, false), (0x2F84A, 8272, 1, false), (0x2F84B, 8273, 1, false),
+    (0x2F84C, 8274, 1, false), (0x2F84D, 8275, 1, false), (0x2F84E, 8276, 1, false), (0x2F84F, 8277, 1, false),
+    (0x2F850, 8278, 1, false), (0x2F851, 8279, 1, false), (0x2F852, 8280, 1, false), (0x2F853, 8281, 1, false),
+    (0x2F854, 8282, 1, false), (0x2F855, 8283, 1, false), (0x2F856, 8284, 1, false), (0x2F857, 8285, 1, false),
+    (0x2F858, 8286, 1, false), (0x2F859, 8287, 1, false), (0x2F85A, 8288, 1, false), (0x2F85B, 8289, 1, false),
+    (0x2F85C, 8290, 1, false), (0x2F85D, 8291, 1, false), (0x2F85E, 8292, 1, false), (0x2F85F, 8293, 1, false),
+    (0x2F860, 8294, 1, false), (0x2F861, 8295, 1, false), (0x2F862, 8296, 1, false), (0x2F863, 8297, 1, false),
+    (0x2F864, 8298, 1, false), (0x2F865, 8299, 1, false), (0x2F866, 8300, 1, false), (0x2F867, 8301, 1, false),
+    (0x2F868, 8302, 1, false), (0x2F869, 8303, 1, false), (0x2F86A, 8304, 1, false), (0x2F86B, 8305, 1, false),
+    (0x2F86C, 8306, 1, false), (0x2F86D, 8307, 1, false), (0x2F86E, 8308, 1, false), (0x2F86F, 8309, 1, false),
+    (0x2F870, 8310, 1, false), (0x2F871, 8311, 1, false), (0x2F872, 8312, 1, false), (0x2F873, 8313, 1, false),
+    (0x2F874, 8314, 1, false), (0x2F875, 8315, 1, false), (0x2F876, 8316, 1, false), (0x2F877, 8317, 1, false),
+    (0x2F878, 8318, 1, false), (0x2F879, 8319, 1, false), (0x2F87A, 8320, 1, false), (0x2F87B, 8321, 1, false),
+    (0x2F87C, 8322, 1, false), (0x2F87D, 8323, 1, false), (0x2F87E, 8324, 1, false), (0x2F87F, 8325, 1, false),
+    (0x2F880, 8326, 1, false), (0x2F881, 8327, 1, false), (0x2F882, 8328, 1, false), (0x2F883, 8329, 1, false),
+    (0x2F884, 8330, 1, false), (0x2F885, 8331, 1, false), (0x2F886, 8332, 1, false), (0x2F887, 8333, 1, false),
+    (0x2F888, 8334, 1, false), (0x2F889, 8335, 1, false), (0x2F88A, 8336, 1, false), (0x2F88B, 8337, 1, false),
+    (0x2F88C, 8338, 1, false), (0x2F88D, 8339, 1, false), (0x2F88E, 8340, 1, false), (0x2F88F, 8341, 1, false),
+    (0x2F890, 8342, 1, false), (0x2F891, 8343, 1, false), (0x2F892, 8344, 1, false), (0x2F893, 8345, 1, false),
+    (0x2F894, 8346, 1, false), (0x2F895, 8347, 1, false), (0x2F896, 8348, 1, false), (0x2F897, 8349, 1, false),
+    (0x2F898, 8350, 1, false), (0x2F899, 8351, 1, false), (0x2F89A, 8352, 1, false), (0x2F89B, 8353, 1, false),
+    (0x2F89C, 8354, 1, false), (0x2F89D, 8355, 1, false), (0x2F89E, 8356, 1, false), (0x2F89F, 8357, 1, false),
+    (0x2F8A0, 8358, 1, false), (0x2F8A1, 8359, 1, false), (0x2F8A2, 8360, 1, false), (0x2F8A3, 8361, 1, false),
+    (0x2F8A4, 8362, 1, false), (0x2F8A5, 8363, 1, false), (0x2F8A6, 8364, 1, false), (0x2F8A7, 8365, 1, false),
+    (0x2F8A8, 8366, 1, false), (0x2F8A9, 8367, 1, false), (0x2F8AA, 8368, 1, false), (0x2F8AB, 8369, 1, false),
+    (0x2F8AC, 8370, 1, false), (0x2F8AD, 8371, 1, false), (0x2F8AE, 8372, 1, false), (0x2F8AF, 8373, 1, false),
+    (0x2F8B0, 8374, 1, false), (0x2F8B1, 8375, 1, false), (0x2F8B2, 8376, 1, false), (0x2F8B3, 8377, 1, false),
+    (0x2F8B4, 8378, 1, false), (0x2F8B5, 8379, 1, false), (0x2F8B6, 8380, 1, false), (0x2F8B7, 8381, 1, false),
+    (0x2F8B8, 8382, 1, false), (0x2F8B9, 8383, 1, false), (0x2F8BA, 8384, 1, false), (0x2F8BB, 8385, 1, false),
+    (0x2F8BC, 8386, 1, false), (0x2F8BD, 8387, 1, false), (0x2F8BE, 8388, 1, false), (0x2F8BF, 8389, 1, false),
+    (0x2F8C0, 8390, 1, false), (0x2F8C1, 8391, 1, false), (0x2F8C2, 8392, 1, false), (0x2F8C3, 8393, 1, false),
+    (0x2F8C4, 8394, 1, false), (0x2F8C5, 8395, 1, false), (0x2F8C6, 8396, 1, false), (0x2F8C7, 8397, 1, false),
+    (0x2F8C8, 8398, 1, false), (0x2F8C9, 8399, 1, false), (0x2F8CA, 8400, 1, false), (0x2F8CB, 8401, 1, false),
+    (0x2F8CC, 8402, 1, false), (0x2F8CD, 8403, 1, false), (0x2F8CE, 8404, 1, false), (0x2F8CF, 8405, 1, false),
+    (0x2F8D0, 8406, 1, false), (0x2F8D1, 8407, 1, false), (0x2F8D2, 8408, 1, false), (0x2F8D3, 8409, 1, false),
+    (0x2F8D4, 8410, 1, false), (0x2F8D5, 8411, 1, false), (0x2F8D6, 8412, 1, false), (0x2F8D7, 8413, 1, false),
+    (0x2F8D8, 8414, 1, false), (0x2F8D9, 8415, 1, false), (0x2F8DA, 8416, 1, false), (0x2F8DB, 8417, 1, false),
+    (0x2F8DC, 8418, 1, false), (0x2F8DD, 8419, 1, false), (0x2F8DE, 8420, 1, false), (0x2F8DF, 8421, 1, false),
+    (0x2F8E0, 8422, 1, false), (0x2F8E1, 8423, 1, false), (0x2F8E2, 8424, 1, false), (0x2F8E3, 8425, 1, false),
+    (0x2F8E4, 8426, 1, false), (0x2F8E5, 8427, 1, false), (0x2F8E6, 8428, 1, false), (0x2F8E7, 8429, 1, false),
+    (0x2F8E8, 8430, 1, false), (0x2F8E9, 8431, 1, false), (0x2F8EA, 8432, 1, false), (0x2F8EB, 8433, 1, false),
+    (0x2F8EC, 8434, 1, false), (0x2F8ED, 8435, 1, false), (0x2F8EE, 8436, 1, false), (0x2F8EF, 8437, 1, false),
+    (0x2F8F0, 8438, 1, false), (0x2F8F1, 8439, 1, false), (0x2F8F2, 8440, 1, false), (0x2F8F3, 8441, 1, false),
+    (0x2F8F4, 8442, 1, false), (0x2F8F5, 8443, 1, false), (0x2F8F6, 8444, 1, false), (0x2F8F7, 8445, 1, false),
+    (0x2F8F8, 8446, 1, false), (0x2F8F9, 8447, 1, false), (0x2F8FA, 8448, 1, false), (0x2F8FB, 8449, 1, false),
+    (0x2F8FC, 8450, 1, false), (0x2F8FD, 8451, 1, false), (0x2F8FE, 8452, 1, false), (0x2F8FF, 8453, 1, false),
+    (0x2F900, 8454, 1, false), (0x2F901, 8455, 1, false), (0x2F902, 8456, 1, false), (0x2F903, 8457, 1, false),
+    (0x2F904, 8458, 1, false), (0x2F905, 8459, 1, false), (0x2F906, 8460, 1, false), (0x2F907, 8461, 1, false),
+    (0x2F908, 8462, 1, false), (0x2F909, 8463, 1, false), (0x2F90A, 8464, 1, false), (0x2F90B, 8465, 1, false),
+    (0x2F90C, 8466, 1, false), (0x2F90D, 8467, 1, false), (0x2F90E, 8468, 1, false), (0x2F90F, 8469, 1, false),
+    (0x2F910, 8470, 1, false), (0x2F911, 8471, 1, false), (0x2F912, 8472, 1, false), (0x2F913, 8473, 1, false),
+    (0x2F914, 8474, 1, false), (0x2F915, 8475, 1, false), (0x2F916, 8476, 1, false), (0x2F917, 8477, 1, false),
+    (0x2F918, 8478, 1, false), (0x2F919, 8479, 1, false), (0x2F91A, 8480, 1, false), (0x2F91B, 8481, 1, false),
+    (0x2F91C, 8482, 1, false), (0x2F91D, 8483, 1, false), (0x2F91E, 8484, 1, false), (0x2F91F, 8485, 1, false),
+    (0x2F920, 8486, 1, false), (0x2F921, 8487, 1, false), (0x2F922, 8488, 1, false), (0x2F923, 8489, 1, false),
+    (0x2F924, 8490, 1, false), (0x2F925, 8491, 1, false), (0x2F926, 8492, 1, false), (0x2F927, 8493, 1, false),
+    (0x2F928, 8494, 1, false), (0x2F929, 8495, 1, false), (0x2F92A, 8496, 1, false), (0x2F92B, 8497, 1, false),
+    (0x2F92C, 8498, 1, false), (0x2F92D, 8499, 1, false), (0x2F92E, 8500, 1, false), (0x2F92F, 8501, 1, false),
+    (0x2F930, 8502, 1, false), (0x2F931, 8503, 1, false), (0x2F932, 8504, 1, false), (0x2F933, 8505, 1, false),
+    (0x2F934, 8506, 1, false), (0x2F935, 8507, 1, false), (0x2F936, 8508, 1, false), (0x2F937, 8509, 1, false),
+    (0x2F938, 8510, 1, false), (0x2F939, 8511, 1, false), (0x2F93A, 8512, 1, false), (0x2F93B, 8513, 1, false),
+    (0x2F93C, 8514, 1, false), (0x2F93D, 8515, 1, false), (0x2F93E, 8516, 1, false), (0x2F93F, 8517, 1, false),
+    (0x2F940, 8518, 1, false), (0x2F941, 8519, 1, false), (0x2F942, 8520, 1, false), (0x2F943, 8521, 1, false),
+    (0x2F944, 8522, 1, false), (0x2F945, 8523, 1, false), (0x2F946, 8524, 1, false), (0x2F947, 8525, 1, false),
+    (0x2F948, 8526, 1, false), (0x2F949, 8527, 1, false), (0x2F94A, 8528, 1, false), (0x2F94B, 8529, 1, false),
+    (0x2F94C, 8530, 1, false), (0x2F94D, 8531, 1, false), (0x2F94E, 8532, 1, false), (0x2F94F, 8533, 1, false),
+    (0x2F950, 8534, 1, false), (0x2F951, 8535, 1, false), (0x2F952, 8536, 1, false), (0x2F953, 8537, 1, false),
+    (0x2F954, 8538, 1, false), (0x2F955, 8539, 1, false), (0x2F956, 8540, 1, false), (0x2F957, 8541, 1, false),
+    (0x2F958, 8542, 1, false), (0x2F959, 8543, 1, false), (0x2F95A, 8544, 1, false), (0x2F95B, 8545, 1, false),
+    (0x2F95C, 8546, 1, false), (0x2F95D, 8547, 1, false), (0x2F95E, 8548, 1, false), (0x2F95F, 8549, 1, false),
+    (0x2F960, 8550, 1, false), (0x2F961, 8551, 1, false), (0x2F962, 8552, 1, false), (0x2F963, 8553, 1, false),
+    (0x2F964, 8554, 1, false), (0x2F965, 8555, 1, false), (0x2F966, 8556, 1, false), (0x2F967, 8557, 1, false),
+    (0x2F968, 8558, 1, false), (0x2F969, 8559, 1, false), (0x2F96A, 8560, 1, false), (0x2F96B, 8561, 1, false),
+    (0x2F96C, 8562, 1, false), (0x2F96D, 8563, 1, false), (0x2F96E, 8564, 1, false), (0x2F96F, 8565, 1, false),
+    (0x2F970, 8566, 1, false), (0x2F971, 8567, 1, false), (0x2F972, 8568, 1, false), (0x2F973, 8569, 1, false),
+    (0x2F974, 8570, 1, false), (0x2F975, 8571, 1, false), (0x2F976, 8572, 1, false), (0x2F977, 8573, 1, false),
+    (0x2F978, 8574, 1, false), (0x2F979, 8575, 1, false), (0x2F97A, 8576, 1, false), (0x2F97B, 8577, 1, false),
+    (0x2F97C, 8578, 1, false), (0x2F97D, 8579, 1, false), (0x2F97E, 8580, 1, false), (0x2F97F, 8581, 1, false),
+    (0x2F980, 8582, 1, false), (0x2F981, 8583, 1, false), (0x2F982, 8584, 1, false), (0x2F983, 8585, 1, false),
+    (0x2F984, 8586, 1, false), (0x2F985, 8587, 1, false), (0x2F986, 8588, 1, false), (0x2F987, 8589, 1, false),
+    (0x2F988, 8590, 1, false), (0x2F989, 8591, 1, false), (0x2F98A, 8592, 1, false), (0x2F98B, 8593, 1, false),
+    (0x2F98C, 8594, 1, false), (0x2F98D, 8595, 1, false), (0x2F98E, 8596, 1, false), (0x2F98F, 8597, 1, false),
+    (0x2F990, 8598, 1, false), (0x2F991, 8599, 1, false), (0x2F992, 8600, 1, false), (0x2F993, 8601, 1, false),
+    (0x2F994, 8602, 1, false), (0x2F995, 8603, 1, false), (0x2F996, 8604, 1, false), (0x2F997, 8605, 1, false),
+    (0x2F998, 8606, 1, false), (0x2F999, 8607, 1, false), (0x2F99A, 8608, 1, false), (0x2F99B, 8609, 1, false),
+    (0x2F99C, 8610, 1, false), (0x2F99D, 8611, 1, false), (0x2F99E, 8612, 1, false), (0x2F99F, 8613, 1, false),
+    (0x2F9A0, 8614, 1, false), (0x2F9A1, 8615, 1, false), (0x2F9A2, 8616, 1, false), (0x2F9A3, 8617, 1, false),
+    (0x2F9A4, 8618, 1, false), (0x2F9A5, 8619, 1, false), (0x2F9A6, 8620, 1, false), (0x2F9A7, 8621, 1, false),
+    (0x2F9A8, 8622, 1, false), (0x2F9A9, 8623, 1, false), (0x2F9AA, 8624, 1, false), (0x2F9AB, 8625, 1, false),
+    (0x2F9AC, 8626, 1, false), (0x2F9AD, 8627, 1, false), (0x2F9AE, 8628, 1, false), (0x2F9AF, 8629, 1, false),
+    (0x2F9B0, 8630, 1, false), (0x2F9B1, 8631, 1, false), (0x2F9B2, 8632, 1, false), (0x2F9B3, 8633, 1, false),
+    (0x2F9B4, 8634, 1, false), (0x2F9B5, 8635, 1, false), (0x2F9B6, 8636, 1, false), (0x2F9B7, 8637, 1, false),
+    (0x2F9B8, 8638, 1, false), (0x2F9B9, 8639, 1, false), (0x2F9BA, 8640, 1, false), (0x2F9BB, 8641, 1, false),
+    (0x2F9BC, 8642, 1, false), (0x2F9BD, 8643, 1, false), (0x2F9BE, 8644, 1, false), (0x2F9BF, 8645, 1, false),
+    (0x2F9C0, 8646, 1, false), (0x2F9C1, 8647, 1, false), (0x2F9C2, 8648, 1, false), (0x2F9C3, 8649, 1, false),
+    (0x2F9C4, 8650, 1, false), (0x2F9C5, 8651, 1, false), (0x2F9C6, 8652, 1, false), (0x2F9C7, 8653, 1, false),
+    (0x2F9C8, 8654, 1, false), (0x2F9C9, 8655, 1, false), (0x2F9CA, 8656, 1, false), (0x2F9CB, 8657, 1, false),
+    (0x2F9CC, 8658, 1, false), (0x2F9CD, 8659, 1, false), (0x2F9CE, 8660, 1, false), (0x2F9CF, 8661, 1, false),
+    (0x2F9D0, 8662, 1, false), (0x2F9D1, 8663, 1, false), (0x2F9D2, 8664, 1, false), (0x2F9D3, 8665, 1, false),
+    (0x2F9D4, 8666, 1, false), (0x2F9D5, 8667, 1, false), (0x2F9D6, 8668, 1, false), (0x2F9D7, 8669, 1, false),
+    (0x2F9D8, 8670, 1, false), (0x2F9D9, 8671, 1, false), (0x2F9DA, 8672, 1, false), (0x2F9DB, 8673, 1, false),
+    (0x2F9DC, 8674, 1, false), (0x2F9DD, 8675, 1, false), (0x2F9DE, 8676, 1, false), (0x2F9DF, 8677, 1, false),
+    (0x2F9E0, 8678, 1, false), (0x2F9E1, 8679, 1, false), (0x2F9E2, 8680, 1, false), (0x2F9E3, 8681, 1, false),
+    (0x2F9E4, 8682, 1, false), (0x2F9E5, 8683, 1, false), (0x2F9E6, 8684, 1, false), (0x2F9E7, 8685, 1, false),
+    (0x2F9E8, 8686, 1, false), (0x2F9E9, 8687, 1, false), (0x2F9EA, 8688, 1, false), (0x2F9EB, 8689, 1, false),
+    (0x2F9EC, 8690, 1, false), (0x2F9ED, 8691, 1, false), (0x2F9EE, 8692, 1, false), (0x2F9EF, 8693, 1, false),
+    (0x2F9F0, 8694, 1, false), (0x2F9F1, 8695, 1, false), (0x2F9F2, 8696, 1, false), (0x2F9F3, 8697, 1, false),
+    (0x2F9F4, 8698, 1, false), (0x2F9F5, 8699, 1, false), (0x2F9F6, 8700, 1, false), (0x2F9F7, 8701, 1, false),
+    (0x2F9F8, 8702, 1, false), (0x2F9F9, 8703, 1, false), (0x2F9FA, 8704, 1, false), (0x2F9FB, 8705, 1, false),
+    (0x2F9FC, 8706, 1, false), (0x2F9FD, 8707, 1, false), (0x2F9FE, 8708, 1, false), (0x2F9FF, 8709, 1, false),
+    (0x2FA00, 8710, 1, false), (0x2FA01, 8711, 1, false), (0x2FA02, 8712, 1, false), (0x2FA03, 8713, 1, false),
+    (0x2FA04, 8714, 1, false), (0x2FA05, 8715, 1, false), (0x2FA06, 8716, 1, false), (0x2FA07, 8717, 1, false),
+    (0x2FA08, 8718, 1, false), (0x2FA09, 8719, 1, false), (0x2FA0A, 8720, 1, false), (0x2FA0B, 8721, 1, false),
+    (0x2FA0C, 8722, 1, false), (0x2FA0D, 8723, 1, false), (0x2FA0E, 8724, 1, false), (0x2FA0F, 8725, 1, false),
+    (0x2FA10, 8726, 1, false), (0x2FA11, 8727, 1, false), (0x2FA12, 8728, 1, false), (0x2FA13, 8729, 1, false),
+    (0x2FA14, 8730, 1, false), (0x2FA15, 8731, 1, false), (0x2FA16, 8732, 1, false), (0x2FA17, 8733, 1, false),
+    (0x2FA18, 8734, 1, false), (0x2FA19, 8735, 1, false), (0x2FA1A, 8736, 1, false), (0x2FA1B, 8737, 1, false),
+    (0x2FA1C, 8738, 1, false), (0x2FA1D, 8739, 1, false),
+];
+
+pub(super) static DECOMPOSITION_DATA: &[u32] = &[
+    0x20, 0x20, 0x308, 0x61, 0x20, 0x304, 0x32, 0x33, 0x20, 0x301,
+    0x3BC, 0x20, 0x327, 0x31, 0x6F, 0x31, 0x2044, 0x34, 0x31, 0x2044,
+    0x32, 0x33, 0x2044, 0x34, 0x41, 0x300, 0x41, 0x301, 0x41, 0x302,
+    0x41, 0x303, 0x41, 0x308, 0x41, 0x30A, 0x43, 0x327, 0x45, 0x300,
+    0x45, 0x301, 0x45, 0x302, 0x45, 0x308, 0x49, 0x300, 0x49, 0x301,
+    0x49, 0x302, 0x49, 0x308, 0x4E, 0x303, 0x4F, 0x300, 0x4F, 0x301,
+    0x4F, 0x302, 0x4F, 0x303, 0x4F, 0x308, 0x55, 0x300, 0x55, 0x301,
+    0x55, 0x302, 0x55, 0x308, 0x59, 0x301, 0x61, 0x300, 0x61, 0x301,
+    0x61, 0x302, 0x61, 0x303, 0x61, 0x308, 0x61, 0x30A, 0x63, 0x327,
+    0x65, 0x300, 0x65, 0x301, 0x65, 0x302, 0x65, 0x308, 0x69, 0x300,
+    0x69, 0x301, 0x69, 0x302, 0x69, 0x308, 0x6E, 0x303, 0x6F, 0x300,
+    0x6F, 0x301, 0x6F, 0x302, 0x6F, 0x303, 0x6F, 0x308, 0x75, 0x300,
+    0x75, 0x301, 0x75, 0x302, 0x75, 0x308, 0x79, 0x301, 0x79, 0x308,
+    0x41, 0x304, 0x61, 0x304, 0x41, 0x306, 0x61, 0x306, 0x41, 0x328,
+    0x61, 0x328, 0x43, 0x301, 0x63, 0x301, 0x43, 0x302, 0x63, 0x302,
+    0x43, 0x307, 0x63, 0x307, 0x43, 0x30C, 0x63, 0x30C, 0x44, 0x30C,
+    0x64, 0x30C, 0x45, 0x304, 0x65, 0x304, 0x45, 0x306, 0x65, 0x306,
+    0x45, 0x307, 0x65, 0x307, 0x45, 0x328, 0x65, 0x328, 0x45, 0x30C,
+    0x65, 0x30C, 0x47, 0x302, 0x67, 0x302, 0x47, 0x306, 0x67, 0x306,
+    0x47, 0x307, 0x67, 0x307, 0x47, 0x327, 0x67, 0x327, 0x48, 0x302,
+    0x68, 0x302, 0x49, 0x303, 0x69, 0x303, 0x49, 0x304, 0x69, 0x304,
+    0x49, 0x306, 0x69, 0x306, 0x49, 0x328, 0x69, 0x328, 0x49, 0x307,
+    0x49, 0x4A, 0x69, 0x6A, 0x4A, 0x302, 0x6A, 0x302, 0x4B, 0x327,
+    0x6B, 0x327, 0x4C, 0x301, 0x6C, 0x301, 0x4C, 0x327, 0x6C, 0x327,
+    0x4C, 0x30C, 0x6C, 0x30C, 0x4C, 0xB7, 0x6C, 0xB7, 0x4E, 0x301,
+    0x6E, 0x301, 0x4E, 0x327, 0x6E, 0x327, 0x4E, 0x30C, 0x6E, 0x30C,
+    0x2BC, 0x6E, 0x4F, 0x304, 0x6F, 0x304, 0x4F, 0x306, 0x6F, 0x306,
+    0x4F, 0x30B, 0x6F, 0x30B, 0x52, 0x301, 0x72, 0x301, 0x52, 0x327,
+    0x72, 0x327, 0x52, 0x30C, 0x72, 0x30C, 0x53, 0x301, 0x73, 0x301,
+    0x53, 0x302, 0x73, 0x302, 0x53, 0x327, 0x73, 0x327, 0x53, 0x30C,
+    0x73, 0x30C, 0x54, 0x327, 0x74, 0x327, 0x54, 0x30C, 0x74, 0x30C,
+    0x55, 0x303, 0x75, 0x303, 0x55, 0x304, 0x75, 0x304, 0x55, 0x306,
+    0x75, 0x306, 0x55, 0x30A, 0x75, 0x30A, 0x55, 0x30B, 0x75, 0x30B,
+    0x55, 0x328, 0x75, 0x328, 0x57, 0x302, 0x77, 0x302, 0x59, 0x302,
+    0x79, 0x302, 0x59, 0x308, 0x5A, 0x301, 0x7A, 0x301, 0x5A, 0x307,
+    0x7A, 0x307, 0x5A, 0x30C, 0x7A, 0x30C, 0x73, 0x4F, 0x31B, 0x6F,
+    0x31B, 0x55, 0x31B, 0x75, 0x31B, 0x44, 0x17D, 0x44, 0x17E, 0x64,
+    0x17E, 0x4C, 0x4A, 0x4C, 0x6A, 0x6C, 0x6A, 0x4E, 0x4A, 0x4E,
+    0x6A, 0x6E, 0x6A, 0x41, 0x30C, 0x61, 0x30C, 0x49, 0x30C, 0x69,
+    0x30C, 0x4F, 0x30C, 0x6F, 0x30C, 0x55, 0x30C, 0x75, 0x30C, 0xDC,
+    0x304, 0xFC, 0x304, 0xDC, 0x301, 0xFC, 0x301, 0xDC, 0x30C, 0xFC,
+    0x30C, 0xDC, 0x300, 0xFC, 0x300, 0xC4, 0x304, 0xE4, 0x304, 0x226,
+    0x304, 0x227, 0x304, 0xC6, 0x304, 0xE6, 0x304, 0x47, 0x30C, 0x67,
+    0x30C, 0x4B, 0x30C, 0x6B, 0x30C, 0x4F, 0x328, 0x6F, 0x328, 0x1EA,
+    0x304, 0x1EB, 0x304, 0x1B7, 0x30C, 0x292, 0x30C, 0x6A, 0x30C, 0x44,
+    0x5A, 0x44, 0x7A, 0x64, 0x7A, 0x47, 0x301, 0x67, 0x301, 0x4E,
+    0x300, 0x6E, 0x300, 0xC5, 0x301, 0xE5, 0x301, 0xC6, 0x301, 0xE6,
+    0x301, 0xD8, 0x301, 0xF8, 0x301, 0x41, 0x30F, 0x61, 0x30F, 0x41,
+    0x311, 0x61, 0x311, 0x45, 0x30F, 0x65, 0x30F, 0x45, 0x311, 0x65,
+    0x311, 0x49, 0x30F, 0x69, 0x30F, 0x49, 0x311, 0x69, 0x311, 0x4F,
+    0x30F, 0x6F, 0x30F, 0x4F, 0x311, 0x6F, 0x311, 0x52, 0x30F, 0x72,
+    0x30F, 0x52, 0x311, 0x72, 0x311, 0x55, 0x30F, 0x75, 0x30F, 0x55,
+    0x311, 0x75, 0x311, 0x53, 0x326, 0x73, 0x326, 0x54, 0x326, 0x74,
+    0x326, 0x48, 0x30C, 0x68, 0x30C, 0x41, 0x307, 0x61, 0x307, 0x45,
+    0x327, 0x65, 0x327, 0xD6, 0x304, 0xF6, 0x304, 0xD5, 0x304, 0xF5,
+    0x304, 0x4F, 0x307, 0x6F, 0x307, 0x22E, 0x304, 0x22F, 0x304, 0x59,
+    0x304, 0x79, 0x304, 0x68, 0x266, 0x6A, 0x72, 0x279, 0x27B, 0x281,
+    0x77, 0x79, 0x20, 0x306, 0x20, 0x307, 0x20, 0x30A, 0x20, 0x328,
+    0x20, 0x303, 0x20, 0x30B, 0x263, 0x6C, 0x73, 0x78, 0x295, 0x300,
+    0x301, 0x313, 0x308, 0x301, 0x2B9, 0x20, 0x345, 0x3B, 0x20, 0x301,
+    0xA8, 0x301, 0x391, 0x301, 0xB7, 0x395, 0x301, 0x397, 0x301, 0x399,
+    0x301, 0x39F, 0x301, 0x3A5, 0x301, 0x3A9, 0x301, 0x3CA, 0x301, 0x399,
+    0x308, 0x3A5, 0x308, 0x3B1, 0x301, 0x3B5, 0x301, 0x3B7, 0x301, 0x3B9,
+    0x301, 0x3CB, 0x301, 0x3B9, 0x308, 0x3C5, 0x308, 0x3BF, 0x301, 0x3C5,
+    0x301, 0x3C9, 0x301, 0x3B2, 0x3B8, 0x3A5, 0x3D2, 0x301, 0x3D2, 0x308,
+    0x3C6, 0x3C0, 0x3BA, 0x3C1, 0x3C2, 0x398, 0x3B5, 0x3A3, 0x415, 0x300,
+    0x415, 0x308, 0x413, 0x301, 0x406, 0x308, 0x41A, 0x301, 0x418, 0x300,
+    0x423, 0x306, 0x418, 0x306, 0x438, 0x306, 0x435, 0x300, 0x435, 0x308,
+    0x433, 0x301, 0x456, 0x308, 0x43A, 0x301, 0x438, 0x300, 0x443, 0x306,
+    0x474, 0x30F, 0x475, 0x30F, 0x416, 0x306, 0x436, 0x306, 0x410, 0x306,
+    0x430, 0x306, 0x410, 0x308, 0x430, 0x308, 0x415, 0x306, 0x435, 0x306,
+    0x4D8, 0x308, 0x4D9, 0x308, 0x416, 0x308, 0x436, 0x308, 0x417, 0x308,
+    0x437, 0x308, 0x418, 0x304, 0x438, 0x304, 0x418, 0x308, 0x438, 0x308,
+    0x41E, 0x308, 0x43E, 0x308, 0x4E8, 0x308, 0x4E9, 0x308, 0x42D, 0x308,
+    0x44D, 0x308, 0x423, 0x304, 0x443, 0x304, 0x423, 0x308, 0x443, 0x308,
+    0x423, 0x30B, 0x443, 0x30B, 0x427, 0x308, 0x447, 0x308, 0x42B, 0x308,
+    0x44B, 0x308, 0x565, 0x582, 0x627, 0x653, 0x627, 0x654, 0x648, 0x654,
+    0x627, 0x655, 0x64A, 0x654, 0x627, 0x674, 0x648, 0x674, 0x6C7, 0x674,
+    0x64A, 0x674, 0x6D5, 0x654, 0x6C1, 0x654, 0x6D2, 0x654, 0x928, 0x93C,
+    0x930, 0x93C, 0x933, 0x93C, 0x915, 0x93C, 0x916, 0x93C, 0x917, 0x93C,
+    0x91C, 0x93C, 0x921, 0x93C, 0x922, 0x93C, 0x92B, 0x93C, 0x92F, 0x93C,
+    0x9C7, 0x9BE, 0x9C7, 0x9D7, 0x9A1, 0x9BC, 0x9A2, 0x9BC, 0x9AF, 0x9BC,
+    0xA32, 0xA3C, 0xA38, 0xA3C, 0xA16, 0xA3C, 0xA17, 0xA3C, 0xA1C, 0xA3C,
+    0xA2B, 0xA3C, 0xB47, 0xB56, 0xB47, 0xB3E, 0xB47, 0xB57, 0xB21, 0xB3C,
+    0xB22, 0xB3C, 0xB92, 0xBD7, 0xBC6, 0xBBE, 0xBC7, 0xBBE, 0xBC6, 0xBD7,
+    0xC46, 0xC56, 0xCBF, 0xCD5, 0xCC6, 0xCD5, 0xCC6, 0xCD6, 0xCC6, 0xCC2,
+    0xCCA, 0xCD5, 0xD46, 0xD3E, 0xD47, 0xD3E, 0xD46, 0xD57, 0xDD9, 0xDCA,
+    0xDD9, 0xDCF, 0xDDC, 0xDCA, 0xDD9, 0xDDF, 0xE4D, 0xE32, 0xECD, 0xEB2,
+    0xEAB, 0xE99, 0xEAB, 0xEA1, 0xF0B, 0xF42, 0xFB7, 0xF4C, 0xFB7, 0xF51,
+    0xFB7, 0xF56, 0xFB7, 0xF5B, 0xFB7, 0xF40, 0xFB5, 0xF71, 0xF72, 0xF71,
+    0xF74, 0xFB2, 0xF80, 0xFB2, 0xF81, 0xFB3, 0xF80, 0xFB3, 0xF81, 0xF71,
+    0xF80, 0xF92, 0xFB7, 0xF9C, 0xFB7, 0xFA1, 0xFB7, 0xFA6, 0xFB7, 0xFAB,
+    0xFB7, 0xF90, 0xFB5, 0x1025, 0x102E, 0x10DC, 0x1B05, 0x1B35, 0x1B07, 0x1B35,
+    0x1B09, 0x1B35, 0x1B0B, 0x1B35, 0x1B0D, 0x1B35, 0x1B11, 0x1B35, 0x1B3A, 0x1B35,
+    0x1B3C, 0x1B35, 0x1B3E, 0x1B35, 0x1B3F, 0x1B35, 0x1B42, 0x1B35, 0x41, 0xC6,
+    0x42, 0x44, 0x45, 0x18E, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C,
+    0x4D, 0x4E, 0x4F, 0x222, 0x50, 0x52, 0x54, 0x55, 0x57, 0x61,
+    0x250, 0x251, 0x1D02, 0x62, 0x64, 0x65, 0x259, 0x25B, 0x25C, 0x67,
+    0x6B, 0x6D, 0x14B, 0x6F, 0x254, 0x1D16, 0x1D17, 0x70, 0x74, 0x75,
+    0x1D1D, 0x26F, 0x76, 0x1D25, 0x3B2, 0x3B3, 0x3B4, 0x3C6, 0x3C7, 0x69,
+    0x72, 0x75, 0x76, 0x3B2, 0x3B3, 0x3C1, 0x3C6, 0x3C7, 0x43D, 0x252,
+    0x63, 0x255, 0xF0, 0x25C, 0x66, 0x25F, 0x261, 0x265, 0x268, 0x269,
+    0x26A, 0x1D7B, 0x29D, 0x26D, 0x1D85, 0x29F, 0x271, 0x270, 0x272, 0x273,
+    0x274, 0x275, 0x278, 0x282, 0x283, 0x1AB, 0x289, 0x28A, 0x1D1C, 0x28B,
+    0x28C, 0x7A, 0x290, 0x291, 0x292, 0x3B8, 0x41, 0x325, 0x61, 0x325,
+    0x42, 0x307, 0x62, 0x307, 0x42, 0x323, 0x62, 0x323, 0x42, 0x331,
+    0x62, 0x331, 0xC7, 0x301, 0xE7, 0x301, 0x44, 0x307, 0x64, 0x307,
+    0x44, 0x323, 0x64, 0x323, 0x44, 0x331, 0x64, 0x331, 0x44, 0x327,
+    0x64, 0x327, 0x44, 0x32D, 0x64, 0x32D, 0x112, 0x300, 0x113, 0x300,
+    0x112, 0x301, 0x113, 0x301, 0x45, 0x32D, 0x65, 0x32D, 0x45, 0x330,
+    0x65, 0x330, 0x228, 0x306, 0x229, 0x306, 0x46, 0x307, 0x66, 0x307,
+    0x47, 0x304, 0x67, 0x304, 0x48, 0x307, 0x68, 0x307, 0x48, 0x323,
+    0x68, 0x323, 0x48, 0x308, 0x68, 0x308, 0x48, 0x327, 0x68, 0x327,
+    0x48, 0x32E, 0x68, 0x32E, 0x49, 0x330, 0x69, 0x330, 0xCF, 0x301,
+    0xEF, 0x301, 0x4B, 0x301, 0x6B, 0x301, 0x4B, 0x323, 0x6B, 0x323,
+    0x4B, 0x331, 0x6B, 0x331, 0x4C, 0x323, 0x6C, 0x323, 0x1E36, 0x304,
+    0x1E37, 0x304, 0x4C, 0x331, 0x6C, 0x331, 0x4C, 0x32D, 0x6C, 0x32D,
+    0x4D, 0x301, 0x6D, 0x301, 0x4D, 0x307, 0x6D, 0x307, 0x4D, 0x323,
+    0x6D, 0x323, 0x4E, 0x307, 0x6E, 0x307, 0x4E, 0x323, 0x6E, 0x323,
+    0x4E, 0x331, 0x6E, 0x331, 0x4E, 0x32D, 0x6E, 0x32D, 0xD5, 0x301,
+    0xF5, 0x301, 0xD5, 0x308, 0xF5, 0x308, 0x14C, 0x300, 0x14D, 0x300,
+    0x14C, 0x301, 0x14D, 0x301, 0x50, 0x301, 0x70, 0x301, 0x50, 0x307,
+    0x70, 0x307, 0x52, 0x307, 0x72, 0x307, 0x52, 0x323, 0x72, 0x323,
+    0x1E5A, 0x304, 0x1E5B, 0x304, 0x52, 0x331, 0x72, 0x331, 0x53, 0x307,
+    0x73, 0x307, 0x53, 0x323, 0x73, 0x323, 0x15A, 0x307, 0x15B, 0x307,
+    0x160, 0x307, 0x161, 0x307, 0x1E62, 0x307, 0x1E63, 0x307, 0x54, 0x307,
+    0x74, 0x307, 0x54, 0x323, 0x74, 0x323, 0x54, 0x331, 0x74, 0x331,
+    0x54, 0x32D, 0x74, 0x32D, 0x55, 0x324, 0x75, 0x324, 0x55, 0x330,
+    0x75, 0x330, 0x55, 0x32D, 0x75, 0x32D, 0x168, 0x301, 0x169, 0x301,
+    0x16A, 0x308, 0x16B, 0x308, 0x56, 0x303, 0x76, 0x303, 0x56, 0x323,
+    0x76, 0x323, 0x57, 0x300, 0x77, 0x300, 0x57, 0x301, 0x77, 0x301,
+    0x57, 0x308, 0x77, 0x308, 0x57, 0x307, 0x77, 0x307, 0x57, 0x323,
+    0x77, 0x323, 0x58, 0x307, 0x78, 0x307, 0x58, 0x308, 0x78, 0x308,
+    0x59, 0x307, 0x79, 0x307, 0x5A, 0x302, 0x7A, 0x302, 0x5A, 0x323,
+    0x7A, 0x323, 0x5A, 0x331, 0x7A, 0x331, 0x68, 0x331, 0x74, 0x308,
+    0x77, 0x30A, 0x79, 0x30A, 0x61, 0x2BE, 0x17F, 0x307, 0x41, 0x323,
+    0x61, 0x323, 0x41, 0x309, 0x61, 0x309, 0xC2, 0x301, 0xE2, 0x301,
+    0xC2, 0x300, 0xE2, 0x300, 0xC2, 0x309, 0xE2, 0x309, 0xC2, 0x303,
+    0xE2, 0x303, 0x1EA0, 0x302, 0x1EA1, 0x302, 0x102, 0x301, 0x103, 0x301,
+    0x102, 0x300, 0x103, 0x300, 0x102, 0x309, 0x103, 0x309, 0x102, 0x303,
+    0x103, 0x303, 0x1EA0, 0x306, 0x1EA1, 0x306, 0x45, 0x323, 0x65, 0x323,
+    0x45, 0x309, 0x65, 0x309, 0x45, 0x303, 0x65, 0x303, 0xCA, 0x301,
+    0xEA, 0x301, 0xCA, 0x300, 0xEA, 0x300, 0xCA, 0x309, 0xEA, 0x309,
+    0xCA, 0x303, 0xEA, 0x303, 0x1EB8, 0x302, 0x1EB9, 0x302, 0x49, 0x309,
+    0x69, 0x309, 0x49, 0x323, 0x69, 0x323, 0x4F, 0x323, 0x6F, 0x323,
+    0x4F, 0x309, 0x6F, 0x309, 0xD4, 0x301, 0xF4, 0x301, 0xD4, 0x300,
+    0xF4, 0x300, 0xD4, 0x309, 0xF4, 0x309, 0xD4, 0x303, 0xF4, 0x303,
+    0x1ECC, 0x302, 0x1ECD, 0x302, 0x1A0, 0x301, 0x1A1, 0x301, 0x1A0, 0x300,
+    0x1A1, 0x300, 0x1A0, 0x309, 0x1A1, 0x309, 0x1A0, 0x303, 0x1A1, 0x303,
+    0x1A0, 0x323, 0x1A1, 0x323, 0x55, 0x323, 0x75, 0x323, 0x55, 0x309,
+    0x75, 0x309, 0x1AF, 0x301, 0x1B0, 0x301, 0x1AF, 0x300, 0x1B0, 0x300,
+    0x1AF, 0x309, 0x1B0, 0x309, 0x1AF, 0x303, 0x1B0, 0x303, 0x1AF, 0x323,
+    0x1B0, 0x323, 0x59, 0x300, 0x79, 0x300, 0x59, 0x323, 0x79, 0x323,
+    0x59, 0x309, 0x79, 0x309, 0x59, 0x303, 0x79, 0x303, 0x3B1, 0x313,
+    0x3B1, 0x314, 0x1F00, 0x300, 0x1F01, 0x300, 0x1F00, 0x301, 0x1F01, 0x301,
+    0x1F00, 0x342, 0x1F01, 0x342, 0x391, 0x313, 0x391, 0x314, 0x1F08, 0x300,
+    0x1F09, 0x300, 0x1F08, 0x301, 0x1F09, 0x301, 0x1F08, 0x342, 0x1F09, 0x342,
+    0x3B5, 0x313, 0x3B5, 0x314, 0x1F10, 0x300, 0x1F11, 0x300, 0x1F10, 0x301,
+    0x1F11, 0x301, 0x395, 0x313, 0x395, 0x314, 0x1F18, 0x300, 0x1F19, 0x300,
+    0x1F18, 0x301, 0x1F19, 0x301, 0x3B7, 0x313, 0x3B7, 0x314, 0x1F20, 0x300,
+    0x1F21, 0x300, 0x1F20, 0x301, 0x1F21, 0x301, 0x1F20, 0x342, 0x1F21, 0x342,
+    0x397, 0x313, 0x397, 0x314, 0x1F28, 0x300, 0x1F29, 0x300, 0x1F28, 0x301,
+    0x1F29, 0x301, 0x1F28, 0x342, 0x1F29, 0x342, 0x3B9, 0x313, 0x3B9, 0x314,
+    0x1F30, 0x300, 0x1F31, 0x300, 0x1F30, 0x301, 0x1F31, 0x301, 0x1F30, 0x342,
+    0x1F31, 0x342, 0x399, 0x313, 0x399, 0x314, 0x1F38, 0x300, 0x1F39, 0x300,
+    0x1F38, 0x301, 0x1F39, 0x301, 0x1F38, 0x342, 0x1F39, 0x342, 0x3BF, 0x313,
+    0x3BF, 0x314, 0x1F40, 0x300, 0x1F41, 0x300, 0x1F40, 0x301, 0x1F41, 0x301,
+    0x39F, 0x313, 0x39F, 0x314, 0x1F48, 0x300, 0x1F49, 0x300, 0x1F48, 0x301,
+    0x1F49, 0x301, 0x3C5, 0x313, 0x3C5, 0x314, 0x1F50, 0x300, 0x1F51, 0x300,
+    0x1F50, 0x301, 0x1F51, 0x301, 0x1F50, 0x342, 0x1F51, 0x342, 0x3A5, 0x314,
+    0x1F59, 0x300, 0x1F59, 0x301, 0x1F59, 0x342, 0x3C9, 0x313, 0x3C9, 0x314,
+    0x1F60, 0x300, 0x1F61, 0x300, 0x1F60, 0x301, 0x1F61, 0x301, 0x1F60, 0x342,
+    0x1F61, 0x342, 0x3A9, 0x313, 0x3A9, 0x314, 0x1F68, 0x300, 0x1F69, 0x300,
+    0x1F68, 0x301, 0x1F69, 0x301, 0x1F68, 0x342, 0x1F69, 0x342, 0x3B1, 0x300,
+    0x3AC, 0x3B5, 0x300, 0x3AD, 0x3B7, 0x300, 0x3AE, 0x3B9, 0x300, 0x3AF,
+    0x3BF, 0x300, 0x3CC, 0x3C5, 0x300, 0x3CD, 0x3C9, 0x300, 0x3CE, 0x1F00,
+    0x345, 0x1F01, 0x345, 0x1F02, 0x345, 0x1F03, 0x345, 0x1F04, 0x345, 0x1F05,
+    0x345, 0x1F06, 0x345, 0x1F07, 0x345, 0x1F08, 0x345, 0x1F09, 0x345, 0x1F0A,
+    0x345, 0x1F0B, 0x345, 0x1F0C, 0x345, 0x1F0D, 0x345, 0x1F0E, 0x345, 0x1F0F,
+    0x345, 0x1F20, 0x345, 0x1F21, 0x345, 0x1F22, 0x345, 0x1F23, 0x345, 0x1F24,
+    0x345, 0x1F25, 0x345, 0x1F26, 0x345, 0x1F27, 0x345, 0x1F28, 0x345, 0x1F29,
+    0x345, 0x1F2A, 0x345, 0x1F2B, 0x345, 0x1F2C, 0x345, 0x1F2D, 0x345, 0x1F2E,
+    0x345, 0x1F2F, 0x345, 0x1F60, 0x345, 0x1F61, 0x345, 0x1F62, 0x345, 0x1F63,
+    0x345, 0x1F64, 0x345, 0x1F65, 0x345, 0x1F66, 0x345, 0x1F67, 0x345, 0x1F68,
+    0x345, 0x1F69, 0x345, 0x1F6A, 0x345, 0x1F6B, 0x345, 0x1F6C, 0x345, 0x1F6D,
+    0x345, 0x1F6E, 0x345, 0x1F6F, 0x345, 0x3B1, 0x306, 0x3B1, 0x304, 0x1F70,
+    0x345, 0x3B1, 0x345, 0x3AC, 0x345, 0x3B1, 0x342, 0x1FB6, 0x345, 0x391,
+    0x306, 0x391, 0x304, 0x391, 0x300, 0x386, 0x391, 0x345, 0x20, 0x313,
+    0x3B9, 0x20, 0x313, 0x20, 0x342, 0xA8, 0x342, 0x1F74, 0x345, 0x3B7,
+    0x345, 0x3AE, 0x345, 0x3B7, 0x342, 0x1FC6, 0x345, 0x395, 0x300, 0x388,
+    0x397, 0x300, 0x389, 0x397, 0x345, 0x1FBF, 0x300, 0x1FBF, 0x301, 0x1FBF,
+    0x342, 0x3B9, 0x306, 0x3B9, 0x304, 0x3CA, 0x300, 0x390, 0x3B9, 0x342,
+    0x3CA, 0x342, 0x399, 0x306, 0x399, 0x304, 0x399, 0x300, 0x38A, 0x1FFE,
+    0x300, 0x1FFE, 0x301, 0x1FFE, 0x342, 0x3C5, 0x306, 0x3C5, 0x304, 0x3CB,
+    0x300, 0x3B0, 0x3C1, 0x313, 0x3C1, 0x314, 0x3C5, 0x342, 0x3CB, 0x342,
+    0x3A5, 0x306, 0x3A5, 0x304, 0x3A5, 0x300, 0x38E, 0x3A1, 0x314, 0xA8,
+    0x300, 0x385, 0x60, 0x1F7C, 0x345, 0x3C9, 0x345, 0x3CE, 0x345, 0x3C9,
+    0x342, 0x1FF6, 0x345, 0x39F, 0x300, 0x38C, 0x3A9, 0x300, 0x38F, 0x3A9,
+    0x345, 0xB4, 0x20, 0x314, 0x2002, 0x2003, 0x20, 0x20, 0x20, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0x20, 0x2010, 0x20, 0x333, 0x2E, 0x2E,
+    0x2E, 0x2E, 0x2E, 0x2E, 0x20, 0x2032, 0x2032, 0x2032, 0x2032, 0x2032,
+    0x2035, 0x2035, 0x2035, 0x2035, 0x2035, 0x21, 0x21, 0x20, 0x305, 0x3F,
+    0x3F, 0x3F, 0x21, 0x21, 0x3F, 0x2032, 0x2032, 0x2032, 0x2032, 0x20,
+    0x30, 0x69, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x2B, 0x2212,
+    0x3D, 0x28, 0x29, 0x6E, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35,
+    0x36, 0x37, 0x38, 0x39, 0x2B, 0x2212, 0x3D, 0x28, 0x29, 0x61,
+    0x65, 0x6F, 0x78, 0x259, 0x68, 0x6B, 0x6C, 0x6D, 0x6E, 0x70,
+    0x73, 0x74, 0x52, 0x73, 0x61, 0x2F, 0x63, 0x61, 0x2F, 0x73,
+    0x43, 0xB0, 0x43, 0x63, 0x2F, 0x6F, 0x63, 0x2F, 0x75, 0x190,
+    0xB0, 0x46, 0x67, 0x48, 0x48, 0x48, 0x68, 0x127, 0x49, 0x49,
+    0x4C, 0x6C, 0x4E, 0x4E, 0x6F, 0x50, 0x51, 0x52, 0x52, 0x52,
+    0x53, 0x4D, 0x54, 0x45, 0x4C, 0x54, 0x4D, 0x5A, 0x3A9, 0x5A,
+    0x4B, 0xC5, 0x42, 0x43, 0x65, 0x45, 0x46, 0x4D, 0x6F, 0x5D0,
+    0x5D1, 0x5D2, 0x5D3, 0x69, 0x46, 0x41, 0x58, 0x3C0, 0x3B3, 0x393,
+    0x3A0, 0x2211, 0x44, 0x64, 0x65, 0x69, 0x6A, 0x31, 0x2044, 0x37,
+    0x31, 0x2044, 0x39, 0x31, 0x2044, 0x31, 0x30, 0x31, 0x2044, 0x33,
+    0x32, 0x2044, 0x33, 0x31, 0x2044, 0x35, 0x32, 0x2044, 0x35, 0x33,
+    0x2044, 0x35, 0x34, 0x2044, 0x35, 0x31, 0x2044, 0x36, 0x35, 0x2044,
+    0x36, 0x31, 0x2044, 0x38, 0x33, 0x2044, 0x38, 0x35, 0x2044, 0x38,
+    0x37, 0x2044, 0x38, 0x31, 0x2044, 0x49, 0x49, 0x49, 0x49, 0x49,
+    0x49, 0x49, 0x56, 0x56, 0x56, 0x49, 0x56, 0x49, 0x49, 0x56,
+    0x49, 0x49, 0x49, 0x49, 0x58, 0x58, 0x58, 0x49, 0x58, 0x49,
+    0x49, 0x4C, 0x43, 0x44, 0x4D, 0x69, 0x69, 0x69, 0x69, 0x69,
+    0x69, 0x69, 0x76, 0x76, 0x76, 0x69, 0x76, 0x69, 0x69, 0x76,
+    0x69, 0x69, 0x69, 0x69, 0x78, 0x78, 0x78, 0x69, 0x78, 0x69,
+    0x69, 0x6C, 0x63, 0x64, 0x6D, 0x30, 0x2044, 0x33, 0x2190, 0x338,
+    0x2192, 0x338, 0x2194, 0x338, 0x21D0, 0x338, 0x21D4, 0x338, 0x21D2, 0x338,
+    0x2203, 0x338, 0x2208, 0x338, 0x220B, 0x338, 0x2223, 0x338, 0x2225, 0x338,
+    0x222B, 0x222B, 0x222B, 0x222B, 0x222B, 0x222E, 0x222E, 0x222E, 0x222E, 0x222E,
+    0x223C, 0x338, 0x2243, 0x338, 0x2245, 0x338, 0x2248, 0x338, 0x3D, 0x338,
+    0x2261, 0x338, 0x224D, 0x338, 0x3C, 0x338, 0x3E, 0x338, 0x2264, 0x338,
+    0x2265, 0x338, 0x2272, 0x338, 0x2273, 0x338, 0x2276, 0x338, 0x2277, 0x338,
+    0x227A, 0x338, 0x227B, 0x338, 0x2282, 0x338, 0x2283, 0x338, 0x2286, 0x338,
+    0x2287, 0x338, 0x22A2, 0x338, 0x22A8, 0x338, 0x22A9, 0x338, 0x22AB, 0x338,
+    0x227C, 0x338, 0x227D, 0x338, 0x2291, 0x338, 0x2292, 0x338, 0x22B2, 0x338,
+    0x22B3, 0x338, 0x22B4, 0x338, 0x22B5, 0x338, 0x3008, 0x3009, 0x31, 0x32,
+    0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x31, 0x30, 0x31,
+    0x31, 0x31, 0x32, 0x31, 0x33, 0x31, 0x34, 0x31, 0x35, 0x31,
+    0x36, 0x31, 0x37, 0x31, 0x38, 0x31, 0x39, 0x32, 0x30, 0x28,
+    0x31, 0x29, 0x28, 0x32, 0x29, 0x28, 0x33, 0x29, 0x28, 0x34,
+    0x29, 0x28, 0x35, 0x29, 0x28, 0x36, 0x29, 0x28, 0x37, 0x29,
+    0x28, 0x38, 0x29, 0x28, 0x39, 0x29, 0x28, 0x31, 0x30, 0x29,
+    0x28, 0x31, 0x31, 0x29, 0x28, 0x31, 0x32, 0x29, 0x28, 0x31,
+    0x33, 0x29, 0x28, 0x31, 0x34, 0x29, 0x28, 0x31, 0x35, 0x29,
+    0x28, 0x31, 0x36, 0x29, 0x28, 0x31, 0x37, 0x29, 0x28, 0x31,
+    0x38, 0x29, 0x28, 0x31, 0x39, 0x29, 0x28, 0x32, 0x30, 0x29,
+    0x31, 0x2E, 0x32, 0x2E, 0x33, 0x2E, 0x34, 0x2E, 0x35, 0x2E,
+    0x36, 0x2E, 0x37, 0x2E, 0x38, 0x2E, 0x39, 0x2E, 0x31, 0x30,
+    0x2E, 0x31, 0x31, 0x2E, 0x31, 0x32, 0x2E, 0x31, 0x33, 0x2E,
+    0x31, 0x34, 0x2E, 0x31, 0x35, 0x2E, 0x31, 0x36, 0x2E, 0x31,
+    0x37, 0x2E, 0x31, 0x38, 0x2E, 0x31, 0x39, 0x2E, 0x32, 0x30,
+    0x2E, 0x28, 0x61, 0x29, 0x28, 0x62, 0x29, 0x28, 0x63, 0x29,
+    0x28, 0x64, 0x29, 0x28, 0x65, 0x29, 0x28, 0x66, 0x29, 0x28,
+    0x67, 0x29, 0x28, 0x68, 0x29, 0x28, 0x69, 0x29, 0x28, 0x6A,
+    0x29, 0x28, 0x6B, 0x29, 0x28, 0x6C, 0x29, 0x28, 0x6D, 0x29,
+    0x28, 0x6E, 0x29, 0x28, 0x6F, 0x29, 0x28, 0x70, 0x29, 0x28,
+    0x71, 0x29, 0x28, 0x72, 0x29, 0x28, 0x73, 0x29, 0x28, 0x74,
+    0x29, 0x28, 0x75, 0x29, 0x28, 0x76, 0x29, 0x28, 0x77, 0x29,
+    0x28, 0x78, 0x29, 0x28, 0x79, 0x29, 0x28, 0x7A, 0x29, 0x41,
+    0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B,
+    0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55,
+    0x56, 0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65,
+    0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F,
+    0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79,
+    0x7A, 0x30, 0x222B, 0x222B, 0x222B, 0x222B, 0x3A, 0x3A, 0x3D, 0x3D,
+    0x3D, 0x3D, 0x3D, 0x3D, 0x2ADD, 0x338, 0x6A, 0x56, 0x2D61, 0x6BCD,
+    0x9F9F, 0x4E00, 0x4E28, 0x4E36, 0x4E3F, 0x4E59, 0x4E85, 0x4E8C, 0x4EA0, 0x4EBA,
+    0x513F, 0x5165, 0x516B, 0x5182, 0x5196, 0x51AB, 0x51E0, 0x51F5, 0x5200, 0x529B,
+    0x52F9, 0x5315, 0x531A, 0x5338, 0x5341, 0x535C, 0x5369, 0x5382, 0x53B6, 0x53C8,
+    0x53E3, 0x56D7, 0x571F, 0x58EB, 0x5902, 0x590A, 0x5915, 0x5927, 0x5973, 0x5B50,
+    0x5B80, 0x5BF8, 0x5C0F, 0x5C22, 0x5C38, 0x5C6E, 0x5C71, 0x5DDB, 0x5DE5, 0x5DF1,
+    0x5DFE, 0x5E72, 0x5E7A, 0x5E7F, 0x5EF4, 0x5EFE, 0x5F0B, 0x5F13, 0x5F50, 0x5F61,
+    0x5F73, 0x5FC3, 0x6208, 0x6236, 0x624B, 0x652F, 0x6534, 0x6587, 0x6597, 0x65A4,
+    0x65B9, 0x65E0, 0x65E5, 0x66F0, 0x6708, 0x6728, 0x6B20, 0x6B62, 0x6B79, 0x6BB3,
+    0x6BCB, 0x6BD4, 0x6BDB, 0x6C0F, 0x6C14, 0x6C34, 0x706B, 0x722A, 0x7236, 0x723B,
+    0x723F, 0x7247, 0x7259, 0x725B, 0x72AC, 0x7384, 0x7389, 0x74DC, 0x74E6, 0x7518,
+    0x751F, 0x7528, 0x7530, 0x758B, 0x7592, 0x7676, 0x767D, 0x76AE, 0x76BF, 0x76EE,
+    0x77DB, 0x77E2, 0x77F3, 0x793A, 0x79B8, 0x79BE, 0x7A74, 0x7ACB, 0x7AF9, 0x7C73,
+    0x7CF8, 0x7F36, 0x7F51, 0x7F8A, 0x7FBD, 0x8001, 0x800C, 0x8012, 0x8033, 0x807F,
+    0x8089, 0x81E3, 0x81EA, 0x81F3, 0x81FC, 0x820C, 0x821B, 0x821F, 0x826E, 0x8272,
+    0x8278, 0x864D, 0x866B, 0x8840, 0x884C, 0x8863, 0x897E, 0x898B, 0x89D2, 0x8A00,
+    0x8C37, 0x8C46, 0x8C55, 0x8C78, 0x8C9D, 0x8D64, 0x8D70, 0x8DB3, 0x8EAB, 0x8ECA,
+    0x8F9B, 0x8FB0, 0x8FB5, 0x9091, 0x9149, 0x91C6, 0x91CC, 0x91D1, 0x9577, 0x9580,
+    0x961C, 0x96B6, 0x96B9, 0x96E8, 0x9751, 0x975E, 0x9762, 0x9769, 0x97CB, 0x97ED,
+    0x97F3, 0x9801, 0x98A8, 0x98DB, 0x98DF, 0x9996, 0x9999, 0x99AC, 0x9AA8, 0x9AD8,
+    0x9ADF, 0x9B25, 0x9B2F, 0x9B32, 0x9B3C, 0x9B5A, 0x9CE5, 0x9E75, 0x9E7F, 0x9EA5,
+    0x9EBB, 0x9EC3, 0x9ECD, 0x9ED1, 0x9EF9, 0x9EFD, 0x9F0E, 0x9F13, 0x9F20, 0x9F3B,
+    0x9F4A, 0x9F52, 0x9F8D, 0x9F9C, 0x9FA0, 0x20, 0x3012, 0x5341, 0x5344, 0x5345,
+    0x304B, 0x3099, 0x304D, 0x3099, 0x304F, 0x3099, 0x3051, 0x3099, 0x3053, 0x3099,
+    0x3055, 0x3099, 0x3057, 0x3099, 0x3059, 0x3099, 0x305B, 0x3099, 0x305D, 0x3099,
+    0x305F, 0x3099, 0x3061, 0x3099, 0x3064, 0x3099, 0x3066, 0x3099, 0x3068, 0x3099,
+    0x306F, 0x3099, 0x306F, 0x309A, 0x3072, 0x3099, 0x3072, 0x309A, 0x3075, 0x3099,
+    0x3075, 0x309A, 0x3078, 0x3099, 0x3078, 0x309A, 0x307B, 0x3099, 0x307B, 0x309A,
+    0x3046, 0x3099, 0x20, 0x3099, 0x20, 0x309A, 0x309D, 0x3099, 0x3088, 0x308A,
+    0x30AB, 0x3099, 0x30AD, 0x3099, 0x30AF, 0x3099, 0x30B1, 0x3099, 0x30B3, 0x3099,
+    0x30B5, 0x3099, 0x30B7, 0x3099, 0x30B9, 0x3099, 0x30BB, 0x3099, 0x30BD, 0x3099,
+    0x30BF, 0x3099, 0x30C1, 0x3099, 0x30C4, 0x3099, 0x30C6, 0x3099, 0x30C8, 0x3099,
+    0x30CF, 0x3099, 0x30CF, 0x309A, 0x30D2, 0x3099, 0x30D2, 0x309A, 0x30D5, 0x3099,
+    0x30D5, 0x309A, 0x30D8, 0x3099, 0x30D8, 0x309A, 0x30DB, 0x3099, 0x30DB, 0x309A,
+    0x30A6, 0x3099, 0x30EF, 0x3099, 0x30F0, 0x3099, 0x30F1, 0x3099, 0x30F2, 0x3099,
+    0x30FD, 0x3099, 0x30B3, 0x30C8, 0x1100, 0x1101, 0x11AA, 0x1102, 0x11AC, 0x11AD,
+    0x1103, 0x1104, 0x1105, 0x11B0, 0x11B1, 0x11B2, 0x11B3, 0x11B4, 0x11B5, 0x111A,
+    0x1106, 0x1107, 0x1108, 0x1121, 0x1109, 0x110A, 0x110B, 0x110C, 0x110D, 0x110E,
+    0x110F, 0x1110, 0x1111, 0x1112, 0x1161, 0x1162, 0x1163, 0x1164, 0x1165, 0x1166,
+    0x1167, 0x1168, 0x1169, 0x116A, 0x116B, 0x116C, 0x116D, 0x116E, 0x116F, 0x1170,
+    0x1171, 0x1172, 0x1173, 0x1174, 0x1175, 0x1160, 0x1114, 0x1115, 0x11C7, 0x11C8,
+    0x11CC, 0x11CE, 0x11D3, 0x11D7, 0x11D9, 0x111C, 0x11DD, 0x11DF, 0x111D, 0x111E,
+    0x1120, 0x1122, 0x1123, 0x1127, 0x1129, 0x112B, 0x112C, 0x112D, 0x112E, 0x112F,
+    0x1132, 0x1136, 0x1140, 0x1147, 0x114C, 0x11F1, 0x11F2, 0x1157, 0x1158, 0x1159,
+    0x1184, 0x1185, 0x1188, 0x1191, 0x1192, 0x1194, 0x119E, 0x11A1, 0x4E00, 0x4E8C,
+    0x4E09, 0x56DB, 0x4E0A, 0x4E2D, 0x4E0B, 0x7532, 0x4E59, 0x4E19, 0x4E01, 0x5929,
+    0x5730, 0x4EBA, 0x28, 0x1100, 0x29, 0x28, 0x1102, 0x29, 0x28, 0x1103,
+    0x29, 0x28, 0x1105, 0x29, 0x28, 0x1106, 0x29, 0x28, 0x1107, 0x29,
+    0x28, 0x1109, 0x29, 0x28, 0x110B, 0x29, 0x28, 0x110C, 0x29, 0x28,
+    0x110E, 0x29, 0x28, 0x110F, 0x29, 0x28, 0x1110, 0x29, 0x28, 0x1111,
+    0x29, 0x28, 0x1112, 0x29, 0x28, 0x1100, 0x1161, 0x29, 0x28, 0x1102,
+    0x1161, 0x29, 0x28, 0x1103, 0x1161, 0x29, 0x28, 0x1105, 0x1161, 0x29,
+    0x28, 0x1106, 0x1161, 0x29, 0x28, 0x1107, 0x1161, 0x29, 0x28, 0x1109,
+    0x1161, 0x29, 0x28, 0x110B, 0x1161, 0x29, 0x28, 0x110C, 0x1161, 0x29,
+    0x28, 0x110E, 0x1161, 0x29, 0x28, 0x110F, 0x1161, 0x29, 0x28, 0x1110,
+    0x1161, 0x29, 0x28, 0x1111, 0x1161, 0x29, 0x28, 0x1112, 0x1161, 0x29,
+    0x28, 0x110C, 0x116E, 0x29, 0x28, 0x110B, 0x1169, 0x110C, 0x1165, 0x11AB,
+    0x29, 0x28, 0x110B, 0x1169, 0x1112, 0x116E, 0x29, 0x28, 0x4E00, 0x29,
+    0x28, 0x4E8C, 0x29, 0x28, 0x4E09, 0x29, 0x28, 0x56DB, 0x29, 0x28,
+    0x4E94, 0x29, 0x28, 0x516D, 0x29, 0x28, 0x4E03, 0x29, 0x28, 0x516B,
+    0x29, 0x28, 0x4E5D, 0x29, 0x28, 0x5341, 0x29, 0x28, 0x6708, 0x29,
+    0x28, 0x706B, 0x29, 0x28, 0x6C34, 0x29, 0x28, 0x6728, 0x29, 0x28,
+    0x91D1, 0x29, 0x28, 0x571F, 0x29, 0x28, 0x65E5, 0x29, 0x28, 0x682A,
+    0x29, 0x28, 0x6709, 0x29, 0x28, 0x793E, 0x29, 0x28, 0x540D, 0x29,
+    0x28, 0x7279, 0x29, 0x28, 0x8CA1, 0x29, 0x28, 0x795D, 0x29, 0x28,
+    0x52B4, 0x29, 0x28, 0x4EE3, 0x29, 0x28, 0x547C, 0x29, 0x28, 0x5B66,
+    0x29, 0x28, 0x76E3, 0x29, 0x28, 0x4F01, 0x29, 0x28, 0x8CC7, 0x29,
+    0x28, 0x5354, 0x29, 0x28, 0x796D, 0x29, 0x28, 0x4F11, 0x29, 0x28,
+    0x81EA, 0x29, 0x28, 0x81F3, 0x29, 0x554F, 0x5E7C, 0x6587, 0x7B8F, 0x50,
+    0x54, 0x45, 0x32, 0x31, 0x32, 0x32, 0x32, 0x33, 0x32, 0x34,
+    0x32, 0x35, 0x32, 0x36, 0x32, 0x37, 0x32, 0x38, 0x32, 0x39,
+    0x33, 0x30, 0x33, 0x31, 0x33, 0x32, 0x33, 0x33, 0x33, 0x34,
+    0x33, 0x35, 0x1100, 0x1102, 0x1103, 0x1105, 0x1106, 0x1107, 0x1109, 0x110B,
+    0x110C, 0x110E, 0x110F, 0x1110, 0x1111, 0x1112, 0x1100, 0x1161, 0x1102, 0x1161,
+    0x1103, 0x1161, 0x1105, 0x1161, 0x1106, 0x1161, 0x1107, 0x1161, 0x1109, 0x1161,
+    0x110B, 0x1161, 0x110C, 0x1161, 0x110E, 0x1161, 0x110F, 0x1161, 0x1110, 0x1161,
+    0x1111, 0x1161, 0x1112, 0x1161, 0x110E, 0x1161, 0x11B7, 0x1100, 0x1169, 0x110C,
+    0x116E, 0x110B, 0x1174, 0x110B, 0x116E, 0x4E00, 0x4E8C, 0x4E09, 0x56DB, 0x4E94,
+    0x516D, 0x4E03, 0x516B, 0x4E5D, 0x5341, 0x6708, 0x706B, 0x6C34, 0x6728, 0x91D1,
+    0x571F, 0x65E5, 0x682A, 0x6709, 0x793E, 0x540D, 0x7279, 0x8CA1, 0x795D, 0x52B4,
+    0x79D8, 0x7537, 0x5973, 0x9069, 0x512A, 0x5370, 0x6CE8, 0x9805, 0x4F11, 0x5199,
+    0x6B63, 0x4E0A, 0x4E2D, 0x4E0B, 0x5DE6, 0x53F3, 0x533B, 0x5B97, 0x5B66, 0x76E3,
+    0x4F01, 0x8CC7, 0x5354, 0x591C, 0x33, 0x36, 0x33, 0x37, 0x33, 0x38,
+    0x33, 0x39, 0x34, 0x30, 0x34, 0x31, 0x34, 0x32, 0x34, 0x33,
+    0x34, 0x34, 0x34, 0x35, 0x34, 0x36, 0x34, 0x37, 0x34, 0x38,
+    0x34, 0x39, 0x35, 0x30, 0x31, 0x6708, 0x32, 0x6708, 0x33, 0x6708,
+    0x34, 0x6708, 0x35, 0x6708, 0x36, 0x6708, 0x37, 0x6708, 0x38, 0x6708,
+    0x39, 0x6708, 0x31, 0x30, 0x6708, 0x31, 0x31, 0x6708, 0x31, 0x32,
+    0x6708, 0x48, 0x67, 0x65, 0x72, 0x67, 0x65, 0x56, 0x4C, 0x54,
+    0x44, 0x30A2, 0x30A4, 0x30A6, 0x30A8, 0x30AA, 0x30AB, 0x30AD, 0x30AF, 0x30B1,
+    0x30B3, 0x30B5, 0x30B7, 0x30B9, 0x30BB, 0x30BD, 0x30BF, 0x30C1, 0x30C4, 0x30C6,
+    0x30C8, 0x30CA, 0x30CB, 0x30CC, 0x30CD, 0x30CE, 0x30CF, 0x30D2, 0x30D5, 0x30D8,
+    0x30DB, 0x30DE, 0x30DF, 0x30E0, 0x30E1, 0x30E2, 0x30E4, 0x30E6, 0x30E8, 0x30E9,
+    0x30EA, 0x30EB, 0x30EC, 0x30ED, 0x30EF, 0x30F0, 0x30F1, 0x30F2, 0x4EE4, 0x548C,
+    0x30A2, 0x30D1, 0x30FC, 0x30C8, 0x30A2, 0x30EB, 0x30D5, 0x30A1, 0x30A2, 0x30F3,
+    0x30DA, 0x30A2, 0x30A2, 0x30FC, 0x30EB, 0x30A4, 0x30CB, 0x30F3, 0x30B0, 0x30A4,
+    0x30F3, 0x30C1, 0x30A6, 0x30A9, 0x30F3, 0x30A8, 0x30B9, 0x30AF, 0x30FC, 0x30C9,
+    0x30A8, 0x30FC, 0x30AB, 0x30FC, 0x30AA, 0x30F3, 0x30B9, 0x30AA, 0x30FC, 0x30E0,
+    0x30AB, 0x30A4, 0x30EA, 0x30AB, 0x30E9, 0x30C3, 0x30C8, 0x30AB, 0x30ED, 0x30EA,
+    0x30FC, 0x30AC, 0x30ED, 0x30F3, 0x30AC, 0x30F3, 0x30DE, 0x30AE, 0x30AC, 0x30AE,
+    0x30CB, 0x30FC, 0x30AD, 0x30E5, 0x30EA, 0x30FC, 0x30AE, 0x30EB, 0x30C0, 0x30FC,
+    0x30AD, 0x30ED, 0x30AD, 0x30ED, 0x30B0, 0x30E9, 0x30E0, 0x30AD, 0x30ED, 0x30E1,
+    0x30FC, 0x30C8, 0x30EB, 0x30AD, 0x30ED, 0x30EF, 0x30C3, 0x30C8, 0x30B0, 0x30E9,
+    0x30E0, 0x30B0, 0x30E9, 0x30E0, 0x30C8, 0x30F3, 0x30AF, 0x30EB, 0x30BC, 0x30A4,
+    0x30ED, 0x30AF, 0x30ED, 0x30FC, 0x30CD, 0x30B1, 0x30FC, 0x30B9, 0x30B3, 0x30EB,
+    0x30CA, 0x30B3, 0x30FC, 0x30DD, 0x30B5, 0x30A4, 0x30AF, 0x30EB, 0x30B5, 0x30F3,
+    0x30C1, 0x30FC, 0x30E0, 0x30B7, 0x30EA, 0x30F3, 0x30B0, 0x30BB, 0x30F3, 0x30C1,
+    0x30BB, 0x30F3, 0x30C8, 0x30C0, 0x30FC, 0x30B9, 0x30C7, 0x30B7, 0x30C9, 0x30EB,
+    0x30C8, 0x30F3, 0x30CA, 0x30CE, 0x30CE, 0x30C3, 0x30C8, 0x30CF, 0x30A4, 0x30C4,
+    0x30D1, 0x30FC, 0x30BB, 0x30F3, 0x30C8, 0x30D1, 0x30FC, 0x30C4, 0x30D0, 0x30FC,
+    0x30EC, 0x30EB, 0x30D4, 0x30A2, 0x30B9, 0x30C8, 0x30EB, 0x30D4, 0x30AF, 0x30EB,
+    0x30D4, 0x30B3, 0x30D3, 0x30EB, 0x30D5, 0x30A1, 0x30E9, 0x30C3, 0x30C9, 0x30D5,
+    0x30A3, 0x30FC, 0x30C8, 0x30D6, 0x30C3, 0x30B7, 0x30A7, 0x30EB, 0x30D5, 0x30E9,
+    0x30F3, 0x30D8, 0x30AF, 0x30BF, 0x30FC, 0x30EB, 0x30DA, 0x30BD, 0x30DA, 0x30CB,
+    0x30D2, 0x30D8, 0x30EB, 0x30C4, 0x30DA, 0x30F3, 0x30B9, 0x30DA, 0x30FC, 0x30B8,
+    0x30D9, 0x30FC, 0x30BF, 0x30DD, 0x30A4, 0x30F3, 0x30C8, 0x30DC, 0x30EB, 0x30C8,
+    0x30DB, 0x30F3, 0x30DD, 0x30F3, 0x30C9, 0x30DB, 0x30FC, 0x30EB, 0x30DB, 0x30FC,
+    0x30F3, 0x30DE, 0x30A4, 0x30AF, 0x30ED, 0x30DE, 0x30A4, 0x30EB, 0x30DE, 0x30C3,
+    0x30CF, 0x30DE, 0x30EB, 0x30AF, 0x30DE, 0x30F3, 0x30B7, 0x30E7, 0x30F3, 0x30DF,
+    0x30AF, 0x30ED, 0x30F3, 0x30DF, 0x30EA, 0x30DF, 0x30EA, 0x30D0, 0x30FC, 0x30EB,
+    0x30E1, 0x30AC, 0x30E1, 0x30AC, 0x30C8, 0x30F3, 0x30E1, 0x30FC, 0x30C8, 0x30EB,
+    0x30E4, 0x30FC, 0x30C9, 0x30E4, 0x30FC, 0x30EB, 0x30E6, 0x30A2, 0x30F3, 0x30EA,
+    0x30C3, 0x30C8, 0x30EB, 0x30EA, 0x30E9, 0x30EB, 0x30D4, 0x30FC, 0x30EB, 0x30FC,
+    0x30D6, 0x30EB, 0x30EC, 0x30E0, 0x30EC, 0x30F3, 0x30C8, 0x30B2, 0x30F3, 0x30EF,
+    0x30C3, 0x30C8, 0x30, 0x70B9, 0x31, 0x70B9, 0x32, 0x70B9, 0x33, 0x70B9,
+    0x34, 0x70B9, 0x35, 0x70B9, 0x36, 0x70B9, 0x37, 0x70B9, 0x38, 0x70B9,
+    0x39, 0x70B9, 0x31, 0x30, 0x70B9, 0x31, 0x31, 0x70B9, 0x31, 0x32,
+    0x70B9, 0x31, 0x33, 0x70B9, 0x31, 0x34, 0x70B9, 0x31, 0x35, 0x70B9,
+    0x31, 0x36, 0x70B9, 0x31, 0x37, 0x70B9, 0x31, 0x38, 0x70B9, 0x31,
+    0x39, 0x70B9, 0x32, 0x30, 0x70B9, 0x32, 0x31, 0x70B9, 0x32, 0x32,
+    0x70B9, 0x32, 0x33, 0x70B9, 0x32, 0x34, 0x70B9, 0x68, 0x50, 0x61,
+    0x64, 0x61, 0x41, 0x55, 0x62, 0x61, 0x72, 0x6F, 0x56, 0x70,
+    0x63, 0x64, 0x6D, 0x64, 0x6D, 0xB2, 0x64, 0x6D, 0xB3, 0x49,
+    0x55, 0x5E73, 0x6210, 0x662D, 0x548C, 0x5927, 0x6B63, 0x660E, 0x6CBB, 0x682A,
+    0x5F0F, 0x4F1A, 0x793E, 0x70, 0x41, 0x6E, 0x41, 0x3BC, 0x41, 0x6D,
+    0x41, 0x6B, 0x41, 0x4B, 0x42, 0x4D, 0x42, 0x47, 0x42, 0x63,
+    0x61, 0x6C, 0x6B, 0x63, 0x61, 0x6C, 0x70, 0x46, 0x6E, 0x46,
+    0x3BC, 0x46, 0x3BC, 0x67, 0x6D, 0x67, 0x6B, 0x67, 0x48, 0x7A,
+    0x6B, 0x48, 0x7A, 0x4D, 0x48, 0x7A, 0x47, 0x48, 0x7A, 0x54,
+    0x48, 0x7A, 0x3BC, 0x2113, 0x6D, 0x2113, 0x64, 0x2113, 0x6B, 0x2113,
+    0x66, 0x6D, 0x6E, 0x6D, 0x3BC, 0x6D, 0x6D, 0x6D, 0x63, 0x6D,
+    0x6B, 0x6D, 0x6D, 0x6D, 0xB2, 0x63, 0x6D, 0xB2, 0x6D, 0xB2,
+    0x6B, 0x6D, 0xB2, 0x6D, 0x6D, 0xB3, 0x63, 0x6D, 0xB3, 0x6D,
+    0xB3, 0x6B, 0x6D, 0xB3, 0x6D, 0x2215, 0x73, 0x6D, 0x2215, 0x73,
+    0xB2, 0x50, 0x61, 0x6B, 0x50, 0x61, 0x4D, 0x50, 0x61, 0x47,
+    0x50, 0x61, 0x72, 0x61, 0x64, 0x72, 0x61, 0x64, 0x2215, 0x73,
+    0x72, 0x61, 0x64, 0x2215, 0x73, 0xB2, 0x70, 0x73, 0x6E, 0x73,
+    0x3BC, 0x73, 0x6D, 0x73, 0x70, 0x56, 0x6E, 0x56, 0x3BC, 0x56,
+    0x6D, 0x56, 0x6B, 0x56, 0x4D, 0x56, 0x70, 0x57, 0x6E, 0x57,
+    0x3BC, 0x57, 0x6D, 0x57, 0x6B, 0x57, 0x4D, 0x57, 0x6B, 0x3A9,
+    0x4D, 0x3A9, 0x61, 0x2E, 0x6D, 0x2E, 0x42, 0x71, 0x63, 0x63,
+    0x63, 0x64, 0x43, 0x2215, 0x6B, 0x67, 0x43, 0x6F, 0x2E, 0x64,
+    0x42, 0x47, 0x79, 0x68, 0x61, 0x48, 0x50, 0x69, 0x6E, 0x4B,
+    0x4B, 0x4B, 0x4D, 0x6B, 0x74, 0x6C, 0x6D, 0x6C, 0x6E, 0x6C,
+    0x6F, 0x67, 0x6C, 0x78, 0x6D, 0x62, 0x6D, 0x69, 0x6C, 0x6D,
+    0x6F, 0x6C, 0x50, 0x48, 0x70, 0x2E, 0x6D, 0x2E, 0x50, 0x50,
+    0x4D, 0x50, 0x52, 0x73, 0x72, 0x53, 0x76, 0x57, 0x62, 0x56,
+    0x2215, 0x6D, 0x41, 0x2215, 0x6D, 0x31, 0x65E5, 0x32, 0x65E5, 0x33,
+    0x65E5, 0x34, 0x65E5, 0x35, 0x65E5, 0x36, 0x65E5, 0x37, 0x65E5, 0x38,
+    0x65E5, 0x39, 0x65E5, 0x31, 0x30, 0x65E5, 0x31, 0x31, 0x65E5, 0x31,
+    0x32, 0x65E5, 0x31, 0x33, 0x65E5, 0x31, 0x34, 0x65E5, 0x31, 0x35,
+    0x65E5, 0x31, 0x36, 0x65E5, 0x31, 0x37, 0x65E5, 0x31, 0x38, 0x65E5,
+    0x31, 0x39, 0x65E5, 0x32, 0x30, 0x65E5, 0x32, 0x31, 0x65E5, 0x32,
+    0x32, 0x65E5, 0x32, 0x33, 0x65E5, 0x32, 0x34, 0x65E5, 0x32, 0x35,
+    0x65E5, 0x32, 0x36, 0x65E5, 0x32, 0x37, 0x65E5, 0x32, 0x38, 0x65E5,
+    0x32, 0x39, 0x65E5, 0x33, 0x30, 0x65E5, 0x33, 0x31, 0x65E5, 0x67,
+    0x61, 0x6C, 0x44A, 0x44C, 0xA76F, 0x53, 0x43, 0x46, 0x51, 0x126,
+    0x153, 0xA727, 0xAB37, 0x26B, 0xAB52, 0x28D, 0x8C48, 0x66F4, 0x8ECA, 0x8CC8,
+    0x6ED1, 0x4E32, 0x53E5, 0x9F9C, 0x9F9C, 0x5951, 0x91D1, 0x5587, 0x5948, 0x61F6,
+    0x7669, 0x7F85, 0x863F, 0x87BA, 0x88F8, 0x908F, 0x6A02, 0x6D1B, 0x70D9, 0x73DE,
+    0x843D, 0x916A, 0x99F1, 0x4E82, 0x5375, 0x6B04, 0x721B, 0x862D, 0x9E1E, 0x5D50,
+    0x6FEB, 0x85CD, 0x8964, 0x62C9, 0x81D8, 0x881F, 0x5ECA, 0x6717, 0x6D6A, 0x72FC,
+    0x90CE, 0x4F86, 0x51B7, 0x52DE, 0x64C4, 0x6AD3, 0x7210, 0x76E7, 0x8001, 0x8606,
+    0x865C, 0x8DEF, 0x9732, 0x9B6F, 0x9DFA, 0x788C, 0x797F, 0x7DA0, 0x83C9, 0x9304,
+    0x9E7F, 0x8AD6, 0x58DF, 0x5F04, 0x7C60, 0x807E, 0x7262, 0x78CA, 0x8CC2, 0x96F7,
+    0x58D8, 0x5C62, 0x6A13, 0x6DDA, 0x6F0F, 0x7D2F, 0x7E37, 0x964B, 0x52D2, 0x808B,
+    0x51DC, 0x51CC, 0x7A1C, 0x7DBE, 0x83F1, 0x9675, 0x8B80, 0x62CF, 0x6A02, 0x8AFE,
+    0x4E39, 0x5BE7, 0x6012, 0x7387, 0x7570, 0x5317, 0x78FB, 0x4FBF, 0x5FA9, 0x4E0D,
+    0x6CCC, 0x6578, 0x7D22, 0x53C3, 0x585E, 0x7701, 0x8449, 0x8AAA, 0x6BBA, 0x8FB0,
+    0x6C88, 0x62FE, 0x82E5, 0x63A0, 0x7565, 0x4EAE, 0x5169, 0x51C9, 0x6881, 0x7CE7,
+    0x826F, 0x8AD2, 0x91CF, 0x52F5, 0x5442, 0x5973, 0x5EEC, 0x65C5, 0x6FFE, 0x792A,
+    0x95AD, 0x9A6A, 0x9E97, 0x9ECE, 0x529B, 0x66C6, 0x6B77, 0x8F62, 0x5E74, 0x6190,
+    0x6200, 0x649A, 0x6F23, 0x7149, 0x7489, 0x79CA, 0x7DF4, 0x806F, 0x8F26, 0x84EE,
+    0x9023, 0x934A, 0x5217, 0x52A3, 0x54BD, 0x70C8, 0x88C2, 0x8AAA, 0x5EC9, 0x5FF5,
+    0x637B, 0x6BAE, 0x7C3E, 0x7375, 0x4EE4, 0x56F9, 0x5BE7, 0x5DBA, 0x601C, 0x73B2,
+    0x7469, 0x7F9A, 0x8046, 0x9234, 0x96F6, 0x9748, 0x9818, 0x4F8B, 0x79AE, 0x91B4,
+    0x96B8, 0x60E1, 0x4E86, 0x50DA, 0x5BEE, 0x5C3F, 0x6599, 0x6A02, 0x71CE, 0x7642,
+    0x84FC, 0x907C, 0x9F8D, 0x6688, 0x962E, 0x5289, 0x677B, 0x67F3, 0x6D41, 0x6E9C,
+    0x7409, 0x7559, 0x786B, 0x7D10, 0x985E, 0x516D, 0x622E, 0x9678, 0x502B, 0x5D19,
+    0x6DEA, 0x8F2A, 0x5F8B, 0x6144, 0x6817, 0x7387, 0x9686, 0x5229, 0x540F, 0x5C65,
+    0x6613, 0x674E, 0x68A8, 0x6CE5, 0x7406, 0x75E2, 0x7F79, 0x88CF, 0x88E1, 0x91CC,
+    0x96E2, 0x533F, 0x6EBA, 0x541D, 0x71D0, 0x7498, 0x85FA, 0x96A3, 0x9C57, 0x9E9F,
+    0x6797, 0x6DCB, 0x81E8, 0x7ACB, 0x7B20, 0x7C92, 0x72C0, 0x7099, 0x8B58, 0x4EC0,
+    0x8336, 0x523A, 0x5207, 0x5EA6, 0x62D3, 0x7CD6, 0x5B85, 0x6D1E, 0x66B4, 0x8F3B,
+    0x884C, 0x964D, 0x898B, 0x5ED3, 0x5140, 0x55C0, 0x585A, 0x6674, 0x51DE, 0x732A,
+    0x76CA, 0x793C, 0x795E, 0x7965, 0x798F, 0x9756, 0x7CBE, 0x7FBD, 0x8612, 0x8AF8,
+    0x9038, 0x90FD, 0x98EF, 0x98FC, 0x9928, 0x9DB4, 0x90DE, 0x96B7, 0x4FAE, 0x50E7,
+    0x514D, 0x52C9, 0x52E4, 0x5351, 0x559D, 0x5606, 0x5668, 0x5840, 0x58A8, 0x5C64,
+    0x5C6E, 0x6094, 0x6168, 0x618E, 0x61F2, 0x654F, 0x65E2, 0x6691, 0x6885, 0x6D77,
+    0x6E1A, 0x6F22, 0x716E, 0x722B, 0x7422, 0x7891, 0x793E, 0x7949, 0x7948, 0x7950,
+    0x7956, 0x795D, 0x798D, 0x798E, 0x7A40, 0x7A81, 0x7BC0, 0x7DF4, 0x7E09, 0x7E41,
+    0x7F72, 0x8005, 0x81ED, 0x8279, 0x8279, 0x8457, 0x8910, 0x8996, 0x8B01, 0x8B39,
+    0x8CD3, 0x8D08, 0x8FB6, 0x9038, 0x96E3, 0x97FF, 0x983B, 0x6075, 0x242EE, 0x8218,
+    0x4E26, 0x51B5, 0x5168, 0x4F80, 0x5145, 0x5180, 0x52C7, 0x52FA, 0x559D, 0x5555,
+    0x5599, 0x55E2, 0x585A, 0x58B3, 0x5944, 0x5954, 0x5A62, 0x5B28, 0x5ED2, 0x5ED9,
+    0x5F69, 0x5FAD, 0x60D8, 0x614E, 0x6108, 0x618E, 0x6160, 0x61F2, 0x6234, 0x63C4,
+    0x641C, 0x6452, 0x6556, 0x6674, 0x6717, 0x671B, 0x6756, 0x6B79, 0x6BBA, 0x6D41,
+    0x6EDB, 0x6ECB, 0x6F22, 0x701E, 0x716E, 0x77A7, 0x7235, 0x72AF, 0x732A, 0x7471,
+    0x7506, 0x753B, 0x761D, 0x761F, 0x76CA, 0x76DB, 0x76F4, 0x774A, 0x7740, 0x78CC,
+    0x7AB1, 0x7BC0, 0x7C7B, 0x7D5B, 0x7DF4, 0x7F3E, 0x8005, 0x8352, 0x83EF, 0x8779,
+    0x8941, 0x8986, 0x8996, 0x8ABF, 0x8AF8, 0x8ACB, 0x8B01, 0x8AFE, 0x8AED, 0x8B39,
+    0x8B8A, 0x8D08, 0x8F38, 0x9072, 0x9199, 0x9276, 0x967C, 0x96E3, 0x9756, 0x97DB,
+    0x97FF, 0x980B, 0x983B, 0x9B12, 0x9F9C, 0x2284A, 0x22844, 0x233D5, 0x3B9D, 0x4018,
+    0x4039, 0x25249, 0x25CD0, 0x27ED3, 0x9F43, 0x9F8E, 0x66, 0x66, 0x66, 0x69,
+    0x66, 0x6C, 0x66, 0x66, 0x69, 0x66, 0x66, 0x6C, 0x17F, 0x74,
+    0x73, 0x74, 0x574, 0x576, 0x574, 0x565, 0x574, 0x56B, 0x57E, 0x576,
+    0x574, 0x56D, 0x5D9, 0x5B4, 0x5F2, 0x5B7, 0x5E2, 0x5D0, 0x5D3, 0x5D4,
+    0x5DB, 0x5DC, 0x5DD, 0x5E8, 0x5EA, 0x2B, 0x5E9, 0x5C1, 0x5E9, 0x5C2,
+    0xFB49, 0x5C1, 0xFB49, 0x5C2, 0x5D0, 0x5B7, 0x5D0, 0x5B8, 0x5D0, 0x5BC,
+    0x5D1, 0x5BC, 0x5D2, 0x5BC, 0x5D3, 0x5BC, 0x5D4, 0x5BC, 0x5D5, 0x5BC,
+    0x5D6, 0x5BC, 0x5D8, 0x5BC, 0x5D9, 0x5BC, 0x5DA, 0x5BC, 0x5DB, 0x5BC,
+    0x5DC, 0x5BC, 0x5DE, 0x5BC, 0x5E0, 0x5BC, 0x5E1, 0x5BC, 0x5E3, 0x5BC,
+    0x5E4, 0x5BC, 0x5E6, 0x5BC, 0x5E7, 0x5BC, 0x5E8, 0x5BC, 0x5E9, 0x5BC,
+    0x5EA, 0x5BC, 0x5D5, 0x5B9, 0x5D1, 0x5BF, 0x5DB, 0x5BF, 0x5E4, 0x5BF,
+    0x5D0, 0x5DC, 0x671, 0x671, 0x67B, 0x67B, 0x67B, 0x67B, 0x67E, 0x67E,
+    0x67E, 0x67E, 0x680, 0x680, 0x680, 0x680, 0x67A, 0x67A, 0x67A, 0x67A,
+    0x67F, 0x67F, 0x67F, 0x67F, 0x679, 0x679, 0x679, 0x679, 0x6A4, 0x6A4,
+    0x6A4, 0x6A4, 0x6A6, 0x6A6, 0x6A6, 0x6A6, 0x684, 0x684, 0x684, 0x684,
+    0x683, 0x683, 0x683, 0x683, 0x686, 0x686, 0x686, 0x686, 0x687, 0x687,
+    0x687, 0x687, 0x68D, 0x68D, 0x68C, 0x68C, 0x68E, 0x68E, 0x688, 0x688,
+    0x698, 0x698, 0x691, 0x691, 0x6A9, 0x6A9, 0x6A9, 0x6A9, 0x6AF, 0x6AF,
+    0x6AF, 0x6AF, 0x6B3, 0x6B3, 0x6B3, 0x6B3, 0x6B1, 0x6B1, 0x6B1, 0x6B1,
+    0x6BA, 0x6BA, 0x6BB, 0x6BB, 0x6BB, 0x6BB, 0x6C0, 0x6C0, 0x6C1, 0x6C1,
+    0x6C1, 0x6C1, 0x6BE, 0x6BE, 0x6BE, 0x6BE, 0x6D2, 0x6D2, 0x6D3, 0x6D3,
+    0x6AD, 0x6AD, 0x6AD, 0x6AD, 0x6C7, 0x6C7, 0x6C6, 0x6C6, 0x6C8, 0x6C8,
+    0x677, 0x6CB, 0x6CB, 0x6C5, 0x6C5, 0x6C9, 0x6C9, 0x6D0, 0x6D0, 0x6D0,
+    0x6D0, 0x649, 0x649, 0x626, 0x627, 0x626, 0x627, 0x626, 0x6D5, 0x626,
+    0x6D5, 0x626, 0x648, 0x626, 0x648, 0x626, 0x6C7, 0x626, 0x6C7, 0x626,
+    0x6C6, 0x626, 0x6C6, 0x626, 0x6C8, 0x626, 0x6C8, 0x626, 0x6D0, 0x626,
+    0x6D0, 0x626, 0x6D0, 0x626, 0x649, 0x626, 0x649, 0x626, 0x649, 0x6CC,
+    0x6CC, 0x6CC, 0x6CC, 0x626, 0x62C, 0x626, 0x62D, 0x626, 0x645, 0x626,
+    0x649, 0x626, 0x64A, 0x628, 0x62C, 0x628, 0x62D, 0x628, 0x62E, 0x628,
+    0x645, 0x628, 0x649, 0x628, 0x64A, 0x62A, 0x62C, 0x62A, 0x62D, 0x62A,
+    0x62E, 0x62A, 0x645, 0x62A, 0x649, 0x62A, 0x64A, 0x62B, 0x62C, 0x62B,
+    0x645, 0x62B, 0x649, 0x62B, 0x64A, 0x62C, 0x62D, 0x62C, 0x645, 0x62D,
+    0x62C, 0x62D, 0x645, 0x62E, 0x62C, 0x62E, 0x62D, 0x62E, 0x645, 0x633,
+    0x62C, 0x633, 0x62D, 0x633, 0x62E, 0x633, 0x645, 0x635, 0x62D, 0x635,
+    0x645, 0x636, 0x62C, 0x636, 0x62D, 0x636, 0x62E, 0x636, 0x645, 0x637,
+    0x62D, 0x637, 0x645, 0x638, 0x645, 0x639, 0x62C, 0x639, 0x645, 0x63A,
+    0x62C, 0x63A, 0x645, 0x641, 0x62C, 0x641, 0x62D, 0x641, 0x62E, 0x641,
+    0x645, 0x641, 0x649, 0x641, 0x64A, 0x642, 0x62D, 0x642, 0x645, 0x642,
+    0x649, 0x642, 0x64A, 0x643, 0x627, 0x643, 0x62C, 0x643, 0x62D, 0x643,
+    0x62E, 0x643, 0x644, 0x643, 0x645, 0x643, 0x649, 0x643, 0x64A, 0x644,
+    0x62C, 0x644, 0x62D, 0x644, 0x62E, 0x644, 0x645, 0x644, 0x649, 0x644,
+    0x64A, 0x645, 0x62C, 0x645, 0x62D, 0x645, 0x62E, 0x645, 0x645, 0x645,
+    0x649, 0x645, 0x64A, 0x646, 0x62C, 0x646, 0x62D, 0x646, 0x62E, 0x646,
+    0x645, 0x646, 0x649, 0x646, 0x64A, 0x647, 0x62C, 0x647, 0x645, 0x647,
+    0x649, 0x647, 0x64A, 0x64A, 0x62C, 0x64A, 0x62D, 0x64A, 0x62E, 0x64A,
+    0x645, 0x64A, 0x649, 0x64A, 0x64A, 0x630, 0x670, 0x631, 0x670, 0x649,
+    0x670, 0x20, 0x64C, 0x651, 0x20, 0x64D, 0x651, 0x20, 0x64E, 0x651,
+    0x20, 0x64F, 0x651, 0x20, 0x650, 0x651, 0x20, 0x651, 0x670, 0x626,
+    0x631, 0x626, 0x632, 0x626, 0x645, 0x626, 0x646, 0x626, 0x649, 0x626,
+    0x64A, 0x628, 0x631, 0x628, 0x632, 0x628, 0x645, 0x628, 0x646, 0x628,
+    0x649, 0x628, 0x64A, 0x62A, 0x631, 0x62A, 0x632, 0x62A, 0x645, 0x62A,
+    0x646, 0x62A, 0x649, 0x62A, 0x64A, 0x62B, 0x631, 0x62B, 0x632, 0x62B,
+    0x645, 0x62B, 0x646, 0x62B, 0x649, 0x62B, 0x64A, 0x641, 0x649, 0x641,
+    0x64A, 0x642, 0x649, 0x642, 0x64A, 0x643, 0x627, 0x643, 0x644, 0x643,
+    0x645, 0x643, 0x649, 0x643, 0x64A, 0x644, 0x645, 0x644, 0x649, 0x644,
+    0x64A, 0x645, 0x627, 0x645, 0x645, 0x646, 0x631, 0x646, 0x632, 0x646,
+    0x645, 0x646, 0x646, 0x646, 0x649, 0x646, 0x64A, 0x649, 0x670, 0x64A,
+    0x631, 0x64A, 0x632, 0x64A, 0x645, 0x64A, 0x646, 0x64A, 0x649, 0x64A,
+    0x64A, 0x626, 0x62C, 0x626, 0x62D, 0x626, 0x62E, 0x626, 0x645, 0x626,
+    0x647, 0x628, 0x62C, 0x628, 0x62D, 0x628, 0x62E, 0x628, 0x645, 0x628,
+    0x647, 0x62A, 0x62C, 0x62A, 0x62D, 0x62A, 0x62E, 0x62A, 0x645, 0x62A,
+    0x647, 0x62B, 0x645, 0x62C, 0x62D, 0x62C, 0x645, 0x62D, 0x62C, 0x62D,
+    0x645, 0x62E, 0x62C, 0x62E, 0x645, 0x633, 0x62C, 0x633, 0x62D, 0x633,
+    0x62E, 0x633, 0x645, 0x635, 0x62D, 0x635, 0x62E, 0x635, 0x645, 0x636,
+    0x62C, 0x636, 0x62D, 0x636, 0x62E, 0x636, 0x645, 0x637, 0x62D, 0x638,
+    0x645, 0x639, 0x62C, 0x639, 0x645, 0x63A, 0x62C, 0x63A, 0x645, 0x641,
+    0x62C, 0x641, 0x62D, 0x641, 0x62E, 0x641, 0x645, 0x642, 0x62D, 0x642,
+    0x645, 0x643, 0x62C, 0x643, 0x62D, 0x643, 0x62E, 0x643, 0x644, 0x643,
+    0x645, 0x644, 0x62C, 0x644, 0x62D, 0x644, 0x62E, 0x644, 0x645, 0x644,
+    0x647, 0x645, 0x62C, 0x645, 0x62D, 0x645, 0x62E, 0x645, 0x645, 0x646,
+    0x62C, 0x646, 0x62D, 0x646, 0x62E, 0x646, 0x645, 0x646, 0x647, 0x647,
+    0x62C, 0x647, 0x645, 0x647, 0x670, 0x64A, 0x62C, 0x64A, 0x62D, 0x64A,
+    0x62E, 0x64A, 0x645, 0x64A, 0x647, 0x626, 0x645, 0x626, 0x647, 0x628,
+    0x645, 0x628, 0x647, 0x62A, 0x645, 0x62A, 0x647, 0x62B, 0x645, 0x62B,
+    0x647, 0x633, 0x645, 0x633, 0x647, 0x634, 0x645, 0x634, 0x647, 0x643,
+    0x644, 0x643, 0x645, 0x644, 0x645, 0x646, 0x645, 0x646, 0x647, 0x64A,
+    0x645, 0x64A, 0x647, 0x640, 0x64E, 0x651, 0x640, 0x64F, 0x651, 0x640,
+    0x650, 0x651, 0x637, 0x649, 0x637, 0x64A, 0x639, 0x649, 0x639, 0x64A,
+    0x63A, 0x649, 0x63A, 0x64A, 0x633, 0x649, 0x633, 0x64A, 0x634, 0x649,
+    0x634, 0x64A, 0x62D, 0x649, 0x62D, 0x64A, 0x62C, 0x649, 0x62C, 0x64A,
+    0x62E, 0x649, 0x62E, 0x64A, 0x635, 0x649, 0x635, 0x64A, 0x636, 0x649,
+    0x636, 0x64A, 0x634, 0x62C, 0x634, 0x62D, 0x634, 0x62E, 0x634, 0x645,
+    0x634, 0x631, 0x633, 0x631, 0x635, 0x631, 0x636, 0x631, 0x637, 0x649,
+    0x637, 0x64A, 0x639, 0x649, 0x639, 0x64A, 0x63A, 0x649, 0x63A, 0x64A,
+    0x633, 0x649, 0x633, 0x64A, 0x634, 0x649, 0x634, 0x64A, 0x62D, 0x649,
+    0x62D, 0x64A, 0x62C, 0x649, 0x62C, 0x64A, 0x62E, 0x649, 0x62E, 0x64A,
+    0x635, 0x649, 0x635, 0x64A, 0x636, 0x649, 0x636, 0x64A, 0x634, 0x62C,
+    0x634, 0x62D, 0x634, 0x62E, 0x634, 0x645, 0x634, 0x631, 0x633, 0x631,
+    0x635, 0x631, 0x636, 0x631, 0x634, 0x62C, 0x634, 0x62D, 0x634, 0x62E,
+    0x634, 0x645, 0x633, 0x647, 0x634, 0x647, 0x637, 0x645, 0x633, 0x62C,
+    0x633, 0x62D, 0x633, 0x62E, 0x634, 0x62C, 0x634, 0x62D, 0x634, 0x62E,
+    0x637, 0x645, 0x638, 0x645, 0x627, 0x64B, 0x627, 0x64B, 0x62A, 0x62C,
+    0x645, 0x62A, 0x62D, 0x62C, 0x62A, 0x62D, 0x62C, 0x62A, 0x62D, 0x645,
+    0x62A, 0x62E, 0x645, 0x62A, 0x645, 0x62C, 0x62A, 0x645, 0x62D, 0x62A,
+    0x645, 0x62E, 0x62C, 0x645, 0x62D, 0x62C, 0x645, 0x62D, 0x62D, 0x645,
+    0x64A, 0x62D, 0x645, 0x649, 0x633, 0x62D, 0x62C, 0x633, 0x62C, 0x62D,
+    0x633, 0x62C, 0x649, 0x633, 0x645, 0x62D, 0x633, 0x645, 0x62D, 0x633,
+    0x645, 0x62C, 0x633, 0x645, 0x645, 0x633, 0x645, 0x645, 0x635, 0x62D,
+    0x62D, 0x635, 0x62D, 0x62D, 0x635, 0x645, 0x645, 0x634, 0x62D, 0x645,
+    0x634, 0x62D, 0x645, 0x634, 0x62C, 0x64A, 0x634, 0x645, 0x62E, 0x634,
+    0x645, 0x62E, 0x634, 0x645, 0x645, 0x634, 0x645, 0x645, 0x636, 0x62D,
+    0x649, 0x636, 0x62E, 0x645, 0x636, 0x62E, 0x645, 0x637, 0x645, 0x62D,
+    0x637, 0x645, 0x62D, 0x637, 0x645, 0x645, 0x637, 0x645, 0x64A, 0x639,
+    0x62C, 0x645, 0x639, 0x645, 0x645, 0x639, 0x645, 0x645, 0x639, 0x645,
+    0x649, 0x63A, 0x645, 0x645, 0x63A, 0x645, 0x64A, 0x63A, 0x645, 0x649,
+    0x641, 0x62E, 0x645, 0x641, 0x62E, 0x645, 0x642, 0x645, 0x62D, 0x642,
+    0x645, 0x645, 0x644, 0x62D, 0x645, 0x644, 0x62D, 0x64A, 0x644, 0x62D,
+    0x649, 0x644, 0x62C, 0x62C, 0x644, 0x62C, 0x62C, 0x644, 0x62E, 0x645,
+    0x644, 0x62E, 0x645, 0x644, 0x645, 0x62D, 0x644, 0x645, 0x62D, 0x645,
+    0x62D, 0x62C, 0x645, 0x62D, 0x645, 0x645, 0x62D, 0x64A, 0x645, 0x62C,
+    0x62D, 0x645, 0x62C, 0x645, 0x645, 0x62E, 0x62C, 0x645, 0x62E, 0x645,
+    0x645, 0x62C, 0x62E, 0x647, 0x645, 0x62C, 0x647, 0x645, 0x645, 0x646,
+    0x62D, 0x645, 0x646, 0x62D, 0x649, 0x646, 0x62C, 0x645, 0x646, 0x62C,
+    0x645, 0x646, 0x62C, 0x649, 0x646, 0x645, 0x64A, 0x646, 0x645, 0x649,
+    0x64A, 0x645, 0x645, 0x64A, 0x645, 0x645, 0x628, 0x62E, 0x64A, 0x62A,
+    0x62C, 0x64A, 0x62A, 0x62C, 0x649, 0x62A, 0x62E, 0x64A, 0x62A, 0x62E,
+    0x649, 0x62A, 0x645, 0x64A, 0x62A, 0x645, 0x649, 0x62C, 0x645, 0x64A,
+    0x62C, 0x62D, 0x649, 0x62C, 0x645, 0x649, 0x633, 0x62E, 0x649, 0x635,
+    0x62D, 0x64A, 0x634, 0x62D, 0x64A, 0x636, 0x62D, 0x64A, 0x644, 0x62C,
+    0x64A, 0x644, 0x645, 0x64A, 0x64A, 0x62D, 0x64A, 0x64A, 0x62C, 0x64A,
+    0x64A, 0x645, 0x64A, 0x645, 0x645, 0x64A, 0x642, 0x645, 0x64A, 0x646,
+    0x62D, 0x64A, 0x642, 0x645, 0x62D, 0x644, 0x62D, 0x645, 0x639, 0x645,
+    0x64A, 0x643, 0x645, 0x64A, 0x646, 0x62C, 0x62D, 0x645, 0x62E, 0x64A,
+    0x644, 0x62C, 0x645, 0x643, 0x645, 0x645, 0x644, 0x62C, 0x645, 0x646,
+    0x62C, 0x62D, 0x62C, 0x62D, 0x64A, 0x62D, 0x62C, 0x64A, 0x645, 0x62C,
+    0x64A, 0x641, 0x645, 0x64A, 0x628, 0x62D, 0x64A, 0x643, 0x645, 0x645,
+    0x639, 0x62C, 0x645, 0x635, 0x645, 0x645, 0x633, 0x62E, 0x64A, 0x646,
+    0x62C, 0x64A, 0x635, 0x644, 0x6D2, 0x642, 0x644, 0x6D2, 0x627, 0x644,
+    0x644, 0x647, 0x627, 0x643, 0x628, 0x631, 0x645, 0x62D, 0x645, 0x62F,
+    0x635, 0x644, 0x639, 0x645, 0x631, 0x633, 0x648, 0x644, 0x639, 0x644,
+    0x64A, 0x647, 0x648, 0x633, 0x644, 0x645, 0x635, 0x644, 0x649, 0x635,
+    0x644, 0x649, 0x20, 0x627, 0x644, 0x644, 0x647, 0x20, 0x639, 0x644,
+    0x64A, 0x647, 0x20, 0x648, 0x633, 0x644, 0x645, 0x62C, 0x644, 0x20,
+    0x62C, 0x644, 0x627, 0x644, 0x647, 0x631, 0x6CC, 0x627, 0x644, 0x2C,
+    0x3001, 0x3002, 0x3A, 0x3B, 0x21, 0x3F, 0x3016, 0x3017, 0x2026, 0x2025,
+    0x2014, 0x2013, 0x5F, 0x5F, 0x28, 0x29, 0x7B, 0x7D, 0x3014, 0x3015,
+    0x3010, 0x3011, 0x300A, 0x300B, 0x3008, 0x3009, 0x300C, 0x300D, 0x300E, 0x300F,
+    0x5B, 0x5D, 0x203E, 0x203E, 0x203E, 0x203E, 0x5F, 0x5F, 0x5F, 0x2C,
+    0x3001, 0x2E, 0x3B, 0x3A, 0x3F, 0x21, 0x2014, 0x28, 0x29, 0x7B,
+    0x7D, 0x3014, 0x3015, 0x23, 0x26, 0x2A, 0x2B, 0x2D, 0x3C, 0x3E,
+    0x3D, 0x5C, 0x24, 0x25, 0x40, 0x20, 0x64B, 0x640, 0x64B, 0x20,
+    0x64C, 0x20, 0x64D, 0x20, 0x64E, 0x640, 0x64E, 0x20, 0x64F, 0x640,
+    0x64F, 0x20, 0x650, 0x640, 0x650, 0x20, 0x651, 0x640, 0x651, 0x20,
+    0x652, 0x640, 0x652, 0x621, 0x622, 0x622, 0x623, 0x623, 0x624, 0x624,
+    0x625, 0x625, 0x626, 0x626, 0x626, 0x626, 0x627, 0x627, 0x628, 0x628,
+    0x628, 0x628, 0x629, 0x629, 0x62A, 0x62A, 0x62A, 0x62A, 0x62B, 0x62B,
+    0x62B, 0x62B, 0x62C, 0x62C, 0x62C, 0x62C, 0x62D, 0x62D, 0x62D, 0x62D,
+    0x62E, 0x62E, 0x62E, 0x62E, 0x62F, 0x62F, 0x630, 0x630, 0x631, 0x631,
+    0x632, 0x632, 0x633, 0x633, 0x633, 0x633, 0x634, 0x634, 0x634, 0x634,
+    0x635, 0x635, 0x635, 0x635, 0x636, 0x636, 0x636, 0x636, 0x637, 0x637,
+    0x637, 0x637, 0x638, 0x638, 0x638, 0x638, 0x639, 0x639, 0x639, 0x639,
+    0x63A, 0x63A, 0x63A, 0x63A, 0x641, 0x641, 0x641, 0x641, 0x642, 0x642,
+    0x642, 0x642, 0x643, 0x643, 0x643, 0x643, 0x644, 0x644, 0x644, 0x644,
+    0x645, 0x645, 0x645, 0x645, 0x646, 0x646, 0x646, 0x646, 0x647, 0x647,
+    0x647, 0x647, 0x648, 0x648, 0x649, 0x649, 0x64A, 0x64A, 0x64A, 0x64A,
+    0x644, 0x622, 0x644, 0x622, 0x644, 0x623, 0x644, 0x623, 0x644, 0x625,
+    0x644, 0x625, 0x644, 0x627, 0x644, 0x627, 0x21, 0x22, 0x23, 0x24,
+    0x25, 0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D, 0x2E,
+    0x2F, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x3A, 0x3B, 0x3C, 0x3D, 0x3E, 0x3F, 0x40, 0x41, 0x42,
+    0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C,
+    0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56,
+    0x57, 0x58, 0x59, 0x5A, 0x5B, 0x5C, 0x5D, 0x5E, 0x5F, 0x60,
+    0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A,
+    0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74,
+    0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x7B, 0x7C, 0x7D, 0x7E,
+    0x2985, 0x2986, 0x3002, 0x300C, 0x300D, 0x3001, 0x30FB, 0x30F2, 0x30A1, 0x30A3,
+    0x30A5, 0x30A7, 0x30A9, 0x30E3, 0x30E5, 0x30E7, 0x30C3, 0x30FC, 0x30A2, 0x30A4,
+    0x30A6, 0x30A8, 0x30AA, 0x30AB, 0x30AD, 0x30AF, 0x30B1, 0x30B3, 0x30B5, 0x30B7,
+    0x30B9, 0x30BB, 0x30BD, 0x30BF, 0x30C1, 0x30C4, 0x30C6, 0x30C8, 0x30CA, 0x30CB,
+    0x30CC, 0x30CD, 0x30CE, 0x30CF, 0x30D2, 0x30D5, 0x30D8, 0x30DB, 0x30DE, 0x30DF,
+    0x30E0, 0x30E1, 0x30E2, 0x30E4, 0x30E6, 0x30E8, 0x30E9, 0x30EA, 0x30EB, 0x30EC,
+    0x30ED, 0x30EF, 0x30F3, 0x3099, 0x309A, 0x3164, 0x3131, 0x3132, 0x3133, 0x3134,
+    0x3135, 0x3136, 0x3137, 0x3138, 0x3139, 0x313A, 0x313B, 0x313C, 0x313D, 0x313E,
+    0x313F, 0x3140, 0x3141, 0x3142, 0x3143, 0x3144, 0x3145, 0x3146, 0x3147, 0x3148,
+    0x3149, 0x314A, 0x314B, 0x314C, 0x314D, 0x314E, 0x314F, 0x3150, 0x3151, 0x3152,
+    0x3153, 0x3154, 0x3155, 0x3156, 0x3157, 0x3158, 0x3159, 0x315A, 0x315B, 0x315C,
+    0x315D, 0x315E, 0x315F, 0x3160, 0x3161, 0x3162, 0x3163, 0xA2, 0xA3, 0xAC,
+    0xAF, 0xA6, 0xA5, 0x20A9, 0x2502, 0x2190, 0x2191, 0x2192, 0x2193, 0x25A0,
+    0x25CB, 0x105D2, 0x307, 0x105DA, 0x307, 0x2D0, 0x2D1, 0xE6, 0x299, 0x253,
+    0x2A3, 0xAB66, 0x2A5, 0x2A4, 0x256, 0x257, 0x1D91, 0x258, 0x25E, 0x2A9,
+    0x264, 0x262, 0x260, 0x29B, 0x127, 0x29C, 0x267, 0x284, 0x2AA, 0x2AB,
+    0x26C, 0x1DF04, 0xA78E, 0x26E, 0x1DF05, 0x28E, 0x1DF06, 0xF8, 0x276, 0x277,
+    0x71, 0x27A, 0x1DF08, 0x27D, 0x27E, 0x280, 0x2A8, 0x2A6, 0xAB67, 0x2A7,
+    0x288, 0x2C71, 0x28F, 0x2A1, 0x2A2, 0x298, 0x1C0, 0x1C1, 0x1C2, 0x1DF0A,
+    0x1DF1E, 0x11099, 0x110BA, 0x1109B, 0x110BA, 0x110A5, 0x110BA, 0x11131, 0x11127, 0x11132,
+    0x11127, 0x11347, 0x1133E, 0x11347, 0x11357, 0x11382, 0x113C9, 0x11384, 0x113BB, 0x1138B,
+    0x113C2, 0x11390, 0x113C9, 0x113C2, 0x113C2, 0x113C2, 0x113B8, 0x113C2, 0x113C9, 0x114B9,
+    0x114BA, 0x114B9, 0x114B0, 0x114B9, 0x114BD, 0x115B8, 0x115AF, 0x115B9, 0x115AF, 0x11935,
+    0x11930, 0x1611E, 0x1611E, 0x1611E, 0x16129, 0x1611E, 0x1611F, 0x16129, 0x1611F, 0x1611E,
+    0x16120, 0x16121, 0x1611F, 0x16122, 0x1611F, 0x16121, 0x16120, 0x16D67, 0x16D67, 0x16D63,
+    0x16D67, 0x16D69, 0x16D67, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
+    0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51,
+    0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x30,
+    0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x1D157,
+    0x1D165, 0x1D158, 0x1D165, 0x1D15F, 0x1D16E, 0x1D15F, 0x1D16F, 0x1D15F, 0x1D170, 0x1D15F,
+    0x1D171, 0x1D15F, 0x1D172, 0x1D1B9, 0x1D165, 0x1D1BA, 0x1D165, 0x1D1BB, 0x1D16E, 0x1D1BC,
+    0x1D16E, 0x1D1BB, 0x1D16F, 0x1D1BC, 0x1D16F, 0x41, 0x42, 0x43, 0x44, 0x45,
+    0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F,
+    0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59,
+    0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69,
+    0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73,
+    0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x43,
+    0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D,
+    0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57,
+    0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67,
+    0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72,
+    0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x42,
+    0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C,
+    0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56,
+    0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66,
+    0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70,
+    0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A,
+    0x41, 0x43, 0x44, 0x47, 0x4A, 0x4B, 0x4E, 0x4F, 0x50, 0x51,
+    0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x61, 0x62,
+    0x63, 0x64, 0x66, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E,
+    0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79,
+    0x7A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49,
+    0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53,
+    0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63,
+    0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D,
+    0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77,
+    0x78, 0x79, 0x7A, 0x41, 0x42, 0x44, 0x45, 0x46, 0x47, 0x4A,
+    0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x53, 0x54, 0x55,
+    0x56, 0x57, 0x58, 0x59, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66,
+    0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70,
+    0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A,
+    0x41, 0x42, 0x44, 0x45, 0x46, 0x47, 0x49, 0x4A, 0x4B, 0x4C,
+    0x4D, 0x4F, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x61,
+    0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B,
+    0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75,
+    0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x43, 0x44, 0x45,
+    0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F,
+    0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59,
+    0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69,
+    0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73,
+    0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x43,
+    0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D,
+    0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57,
+    0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67,
+    0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71,
+    0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x41,
+    0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B,
+    0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55,
+    0x56, 0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65,
+    0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F,
+    0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79,
+    0x7A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49,
+    0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53,
+    0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63,
+    0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D,
+    0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77,
+    0x78, 0x79, 0x7A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
+    0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51,
+    0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x61,
+    0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B,
+    0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75,
+    0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x43, 0x44, 0x45,
+    0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F,
+    0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59,
+    0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69,
+    0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73,
+    0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x131, 0x237, 0x391,
+    0x392, 0x393, 0x394, 0x395, 0x396, 0x397, 0x398, 0x399, 0x39A, 0x39B,
+    0x39C, 0x39D, 0x39E, 0x39F, 0x3A0, 0x3A1, 0x3F4, 0x3A3, 0x3A4, 0x3A5,
+    0x3A6, 0x3A7, 0x3A8, 0x3A9, 0x2207, 0x3B1, 0x3B2, 0x3B3, 0x3B4, 0x3B5,
+    0x3B6, 0x3B7, 0x3B8, 0x3B9, 0x3BA, 0x3BB, 0x3BC, 0x3BD, 0x3BE, 0x3BF,
+    0x3C0, 0x3C1, 0x3C2, 0x3C3, 0x3C4, 0x3C5, 0x3C6, 0x3C7, 0x3C8, 0x3C9,
+    0x2202, 0x3F5, 0x3D1, 0x3F0, 0x3D5, 0x3F1, 0x3D6, 0x391, 0x392, 0x393,
+    0x394, 0x395, 0x396, 0x397, 0x398, 0x399, 0x39A, 0x39B, 0x39C, 0x39D,
+    0x39E, 0x39F, 0x3A0, 0x3A1, 0x3F4, 0x3A3, 0x3A4, 0x3A5, 0x3A6, 0x3A7,
+    0x3A8, 0x3A9, 0x2207, 0x3B1, 0x3B2, 0x3B3, 0x3B4, 0x3B5, 0x3B6, 0x3B7,
+    0x3B8, 0x3B9, 0x3BA, 0x3BB, 0x3BC, 0x3BD, 0x3BE, 0x3BF, 0x3C0, 0x3C1,
+    0x3C2, 0x3C3, 0x3C4, 0x3C5, 0x3C6, 0x3C7, 0x3C8, 0x3C9, 0x2202, 0x3F5,
+    0x3D1, 0x3F0, 0x3D5, 0x3F1, 0x3D6, 0x391, 0x392, 0x393, 0x394, 0x395,
+    0x396, 0x397, 0x398, 0x399, 0x39A, 0x39B, 0x39C, 0x39D, 0x39E, 0x39F,
+    0x3A0, 0x3A1, 0x3F4, 0x3A3, 0x3A4, 0x3A5, 0x3A6, 0x3A7, 0x3A8, 0x3A9,
+    0x2207, 0x3B1, 0x3B2, 0x3B3, 0x3B4, 0x3B5, 0x3B6, 0x3B7, 0x3B8, 0x3B9,
+    0x3BA, 0x3BB, 0x3BC, 0x3BD, 0x3BE, 0x3BF, 0x3C0, 0x3C1, 0x3C2, 0x3C3,
+    0x3C4, 0x3C5, 0x3C6, 0x3C7, 0x3C8, 0x3C9, 0x2202, 0x3F5, 0x3D1, 0x3F0,
+    0x3D5, 0x3F1, 0x3D6, 0x391, 0x392, 0x393, 0x394, 0x395, 0x396, 0x397,
+    0x398, 0x399, 0x39A, 0x39B, 0x39C, 0x39D, 0x39E, 0x39F, 0x3A0, 0x3A1,
+    0x3F4, 0x3A3, 0x3A4, 0x3A5, 0x3A6, 0x3A7, 0x3A8, 0x3A9, 0x2207, 0x3B1,
+    0x3B2, 0x3B3, 0x3B4, 0x3B5, 0x3B6, 0x3B7, 0x3B8, 0x3B9, 0x3BA, 0x3BB,
+    0x3BC, 0x3BD, 0x3BE, 0x3BF, 0x3C0, 0x3C1, 0x3C2, 0x3C3, 0x3C4, 0x3C5,
+    0x3C6, 0x3C7, 0x3C8, 0x3C9, 0x2202, 0x3F5, 0x3D1, 0x3F0, 0x3D5, 0x3F1,
+    0x3D6, 0x391, 0x392, 0x393, 0x394, 0x395, 0x396, 0x397, 0x398, 0x399,
+    0x39A, 0x39B, 0x39C, 0x39D, 0x39E, 0x39F, 0x3A0, 0x3A1, 0x3F4, 0x3A3,
+    0x3A4, 0x3A5, 0x3A6, 0x3A7, 0x3A8, 0x3A9, 0x2207, 0x3B1, 0x3B2, 0x3B3,
+    0x3B4, 0x3B5, 0x3B6, 0x3B7, 0x3B8, 0x3B9, 0x3BA, 0x3BB, 0x3BC, 0x3BD,
+    0x3BE, 0x3BF, 0x3C0, 0x3C1, 0x3C2, 0x3C3, 0x3C4, 0x3C5, 0x3C6, 0x3C7,
+    0x3C8, 0x3C9, 0x2202, 0x3F5, 0x3D1, 0x3F0, 0x3D5, 0x3F1, 0x3D6, 0x3DC,
+    0x3DD, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+    0x39, 0x430, 0x431, 0x432, 0x433, 0x434, 0x435, 0x436, 0x437, 0x438,
+    0x43A, 0x43B, 0x43C, 0x43E, 0x43F, 0x440, 0x441, 0x442, 0x443, 0x444,
+    0x445, 0x446, 0x447, 0x448, 0x44B, 0x44D, 0x44E, 0xA689, 0x4D9, 0x456,
+    0x458, 0x4E9, 0x4AF, 0x4CF, 0x430, 0x431, 0x432, 0x433, 0x434, 0x435,
+    0x436, 0x437, 0x438, 0x43A, 0x43B, 0x43E, 0x43F, 0x441, 0x443, 0x444,
+    0x445, 0x446, 0x447, 0x448, 0x44A, 0x44B, 0x491, 0x456, 0x455, 0x45F,
+    0x4AB, 0xA651, 0x4B1, 0x627, 0x628, 0x62C, 0x62F, 0x648, 0x632, 0x62D,
+    0x637, 0x64A, 0x643, 0x644, 0x645, 0x646, 0x633, 0x639, 0x641, 0x635,
+    0x642, 0x631, 0x634, 0x62A, 0x62B, 0x62E, 0x630, 0x636, 0x638, 0x63A,
+    0x66E, 0x6BA, 0x6A1, 0x66F, 0x628, 0x62C, 0x647, 0x62D, 0x64A, 0x643,
+    0x644, 0x645, 0x646, 0x633, 0x639, 0x641, 0x635, 0x642, 0x634, 0x62A,
+    0x62B, 0x62E, 0x636, 0x63A, 0x62C, 0x62D, 0x64A, 0x644, 0x646, 0x633,
+    0x639, 0x635, 0x642, 0x634, 0x62E, 0x636, 0x63A, 0x6BA, 0x66F, 0x628,
+    0x62C, 0x647, 0x62D, 0x637, 0x64A, 0x643, 0x645, 0x646, 0x633, 0x639,
+    0x641, 0x635, 0x642, 0x634, 0x62A, 0x62B, 0x62E, 0x636, 0x638, 0x63A,
+    0x66E, 0x6A1, 0x627, 0x628, 0x62C, 0x62F, 0x647, 0x648, 0x632, 0x62D,
+    0x637, 0x64A, 0x644, 0x645, 0x646, 0x633, 0x639, 0x641, 0x635, 0x642,
+    0x631, 0x634, 0x62A, 0x62B, 0x62E, 0x630, 0x636, 0x638, 0x63A, 0x628,
+    0x62C, 0x62F, 0x648, 0x632, 0x62D, 0x637, 0x64A, 0x644, 0x645, 0x646,
+    0x633, 0x639, 0x641, 0x635, 0x642, 0x631, 0x634, 0x62A, 0x62B, 0x62E,
+    0x630, 0x636, 0x638, 0x63A, 0x30, 0x2E, 0x30, 0x2C, 0x31, 0x2C,
+    0x32, 0x2C, 0x33, 0x2C, 0x34, 0x2C, 0x35, 0x2C, 0x36, 0x2C,
+    0x37, 0x2C, 0x38, 0x2C, 0x39, 0x2C, 0x28, 0x41, 0x29, 0x28,
+    0x42, 0x29, 0x28, 0x43, 0x29, 0x28, 0x44, 0x29, 0x28, 0x45,
+    0x29, 0x28, 0x46, 0x29, 0x28, 0x47, 0x29, 0x28, 0x48, 0x29,
+    0x28, 0x49, 0x29, 0x28, 0x4A, 0x29, 0x28, 0x4B, 0x29, 0x28,
+    0x4C, 0x29, 0x28, 0x4D, 0x29, 0x28, 0x4E, 0x29, 0x28, 0x4F,
+    0x29, 0x28, 0x50, 0x29, 0x28, 0x51, 0x29, 0x28, 0x52, 0x29,
+    0x28, 0x53, 0x29, 0x28, 0x54, 0x29, 0x28, 0x55, 0x29, 0x28,
+    0x56, 0x29, 0x28, 0x57, 0x29, 0x28, 0x58, 0x29, 0x28, 0x59,
+    0x29, 0x28, 0x5A, 0x29, 0x3014, 0x53, 0x3015, 0x43, 0x52, 0x43,
+    0x44, 0x57, 0x5A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
+    0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51,
+    0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x48,
+    0x56, 0x4D, 0x56, 0x53, 0x44, 0x53, 0x53, 0x50, 0x50, 0x56,
+    0x57, 0x43, 0x4D, 0x43, 0x4D, 0x44, 0x4D, 0x52, 0x44, 0x4A,
+    0x307B, 0x304B, 0x30B3, 0x30B3, 0x30B5, 0x624B, 0x5B57, 0x53CC, 0x30C7, 0x4E8C,
+    0x591A, 0x89E3, 0x5929, 0x4EA4, 0x6620, 0x7121, 0x6599, 0x524D, 0x5F8C, 0x518D,
+    0x65B0, 0x521D, 0x7D42, 0x751F, 0x8CA9, 0x58F0, 0x5439, 0x6F14, 0x6295, 0x6355,
+    0x4E00, 0x4E09, 0x904A, 0x5DE6, 0x4E2D, 0x53F3, 0x6307, 0x8D70, 0x6253, 0x7981,
+    0x7A7A, 0x5408, 0x6E80, 0x6709, 0x6708, 0x7533, 0x5272, 0x55B6, 0x914D, 0x3014,
+    0x672C, 0x3015, 0x3014, 0x4E09, 0x3015, 0x3014, 0x4E8C, 0x3015, 0x3014, 0x5B89,
+    0x3015, 0x3014, 0x70B9, 0x3015, 0x3014, 0x6253, 0x3015, 0x3014, 0x76D7, 0x3015,
+    0x3014, 0x52DD, 0x3015, 0x3014, 0x6557, 0x3015, 0x5F97, 0x53EF, 0x30, 0x31,
+    0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x4E3D, 0x4E38,
+    0x4E41, 0x20122, 0x4F60, 0x4FAE, 0x4FBB, 0x5002, 0x507A, 0x5099, 0x50E7, 0x50CF,
+    0x349E, 0x2063A, 0x514D, 0x5154, 0x5164, 0x5177, 0x2051C, 0x34B9, 0x5167, 0x518D,
+    0x2054B, 0x5197, 0x51A4, 0x4ECC, 0x51AC, 0x51B5, 0x291DF, 0x51F5, 0x5203, 0x34DF,
+    0x523B, 0x5246, 0x5272, 0x5277, 0x3515, 0x52C7, 0x52C9, 0x52E4, 0x52FA, 0x5305,
+    0x5306, 0x5317, 0x5349, 0x5351, 0x535A, 0x5373, 0x537D, 0x537F, 0x537F, 0x537F,
+    0x20A2C, 0x7070, 0x53CA, 0x53DF, 0x20B63, 0x53EB, 0x53F1, 0x5406, 0x549E, 0x5438,
+    0x5448, 0x5468, 0x54A2, 0x54F6, 0x5510, 0x5553, 0x5563, 0x5584, 0x5584, 0x5599,
+    0x55AB, 0x55B3, 0x55C2, 0x5716, 0x5606, 0x5717, 0x5651, 0x5674, 0x5207, 0x58EE,
+    0x57CE, 0x57F4, 0x580D, 0x578B, 0x5832, 0x5831, 0x58AC, 0x214E4, 0x58F2, 0x58F7,
+    0x5906, 0x591A, 0x5922, 0x5962, 0x216A8, 0x216EA, 0x59EC, 0x5A1B, 0x5A27, 0x59D8,
+    0x5A66, 0x36EE, 0x36FC, 0x5B08, 0x5B3E, 0x5B3E, 0x219C8, 0x5BC3, 0x5BD8, 0x5BE7,
+    0x5BF3, 0x21B18, 0x5BFF, 0x5C06, 0x5F53, 0x5C22, 0x3781, 0x5C60, 0x5C6E, 0x5CC0,
+    0x5C8D, 0x21DE4, 0x5D43, 0x21DE6, 0x5D6E, 0x5D6B, 0x5D7C, 0x5DE1, 0x5DE2, 0x382F,
+    0x5DFD, 0x5E28, 0x5E3D, 0x5E69, 0x3862, 0x22183, 0x387C, 0x5EB0, 0x5EB3, 0x5EB6,
+    0x5ECA, 0x2A392, 0x5EFE, 0x22331, 0x22331, 0x8201, 0x5F22, 0x5F22, 0x38C7, 0x232B8,
+    0x261DA, 0x5F62, 0x5F6B, 0x38E3, 0x5F9A, 0x5FCD, 0x5FD7, 0x5FF9, 0x6081, 0x393A,
+    0x391C, 0x6094, 0x226D4, 0x60C7, 0x6148, 0x614C, 0x614E, 0x614C, 0x617A, 0x618E,
+    0x61B2, 0x61A4, 0x61AF, 0x61DE, 0x61F2, 0x61F6, 0x6210, 0x621B, 0x625D, 0x62B1,
+    0x62D4, 0x6350, 0x22B0C, 0x633D, 0x62FC, 0x6368, 0x6383, 0x63E4, 0x22BF1, 0x6422,
+    0x63C5, 0x63A9, 0x3A2E, 0x6469, 0x647E, 0x649D, 0x6477, 0x3A6C, 0x654F, 0x656C,
+    0x2300A, 0x65E3, 0x66F8, 0x6649, 0x3B19, 0x6691, 0x3B08, 0x3AE4, 0x5192, 0x5195,
+    0x6700, 0x669C, 0x80AD, 0x43D9, 0x6717, 0x671B, 0x6721, 0x675E, 0x6753, 0x233C3,
+    0x3B49, 0x67FA, 0x6785, 0x6852, 0x6885, 0x2346D, 0x688E, 0x681F, 0x6914, 0x3B9D,
+    0x6942, 0x69A3, 0x69EA, 0x6AA8, 0x236A3, 0x6ADB, 0x3C18, 0x6B21, 0x238A7, 0x6B54,
+    0x3C4E, 0x6B72, 0x6B9F, 0x6BBA, 0x6BBB, 0x23A8D, 0x21D0B, 0x23AFA, 0x6C4E, 0x23CBC,
+    0x6CBF, 0x6CCD, 0x6C67, 0x6D16, 0x6D3E, 0x6D77, 0x6D41, 0x6D69, 0x6D78, 0x6D85,
+    0x23D1E, 0x6D34, 0x6E2F, 0x6E6E, 0x3D33, 0x6ECB, 0x6EC7, 0x23ED1, 0x6DF9, 0x6F6E,
+    0x23F5E, 0x23F8E, 0x6FC6, 0x7039, 0x701E, 0x701B, 0x3D96, 0x704A, 0x707D, 0x7077,
+    0x70AD, 0x20525, 0x7145, 0x24263, 0x719C, 0x243AB, 0x7228, 0x7235, 0x7250, 0x24608,
+    0x7280, 0x7295, 0x24735, 0x24814, 0x737A, 0x738B, 0x3EAC, 0x73A5, 0x3EB8, 0x3EB8,
+    0x7447, 0x745C, 0x7471, 0x7485, 0x74CA, 0x3F1B, 0x7524, 0x24C36, 0x753E, 0x24C92,
+    0x7570, 0x2219F, 0x7610, 0x24FA1, 0x24FB8, 0x25044, 0x3FFC, 0x4008, 0x76F4, 0x250F3,
+    0x250F2, 0x25119, 0x25133, 0x771E, 0x771F, 0x771F, 0x774A, 0x4039, 0x778B, 0x4046,
+    0x4096, 0x2541D, 0x784E, 0x788C, 0x78CC, 0x40E3, 0x25626, 0x7956, 0x2569A, 0x256C5,
+    0x798F, 0x79EB, 0x412F, 0x7A40, 0x7A4A, 0x7A4F, 0x2597C, 0x25AA7, 0x25AA7, 0x7AEE,
+    0x4202, 0x25BAB, 0x7BC6, 0x7BC9, 0x4227, 0x25C80, 0x7CD2, 0x42A0, 0x7CE8, 0x7CE3,
+    0x7D00, 0x25F86, 0x7D63, 0x4301, 0x7DC7, 0x7E02, 0x7E45, 0x4334, 0x26228, 0x26247,
+    0x4359, 0x262D9, 0x7F7A, 0x2633E, 0x7F95, 0x7FFA, 0x8005, 0x264DA, 0x26523, 0x8060,
+    0x265A8, 0x8070, 0x2335F, 0x43D5, 0x80B2, 0x8103, 0x440B, 0x813E, 0x5AB5, 0x267A7,
+    0x267B5, 0x23393, 0x2339C, 0x8201, 0x8204, 0x8F9E, 0x446B, 0x8291, 0x828B, 0x829D,
+    0x52B3, 0x82B1, 0x82B3, 0x82BD, 0x82E6, 0x26B3C, 0x82E5, 0x831D, 0x8363, 0x83AD,
+    0x8323, 0x83BD, 0x83E7, 0x8457, 0x8353, 0x83CA, 0x83CC, 0x83DC, 0x26C36, 0x26D6B,
+    0x26CD5, 0x452B, 0x84F1, 0x84F3, 0x8516, 0x273CA, 0x8564, 0x26F2C, 0x455D, 0x4561,
+    0x26FB1, 0x270D2, 0x456B, 0x8650, 0x865C, 0x8667, 0x8669, 0x86A9, 0x8688, 0x870E,
+    0x86E2, 0x8779, 0x8728, 0x876B, 0x8786, 0x45D7, 0x87E1, 0x8801, 0x45F9, 0x8860,
+    0x8863, 0x27667, 0x88D7, 0x88DE, 0x4635, 0x88FA, 0x34BB, 0x278AE, 0x27966, 0x46BE,
+    0x46C7, 0x8AA0, 0x8AED, 0x8B8A, 0x8C55, 0x27CA8, 0x8CAB, 0x8CC1, 0x8D1B, 0x8D77,
+    0x27F2F, 0x20804, 0x8DCB, 0x8DBC, 0x8DF0, 0x208DE, 0x8ED4, 0x8F38, 0x285D2, 0x285ED,
+    0x9094, 0x90F1, 0x9111, 0x2872E, 0x911B, 0x9238, 0x92D7, 0x92D8, 0x927C, 0x93F9,
+    0x9415, 0x28BFA, 0x958B, 0x4995, 0x95B7, 0x28D77, 0x49E6, 0x96C3, 0x5DB2, 0x9723,
+    0x29145, 0x2921A, 0x4A6E, 0x4A76, 0x97E0, 0x2940A, 0x4AB2, 0x29496, 0x980B, 0x980B,
+    0x9829, 0x295B6, 0x98E2, 0x4B33, 0x9929, 0x99A7, 0x99C2, 0x99FE, 0x4BCE, 0x29B30,
+    0x9B12, 0x9C40, 0x9CFD, 0x4CCE, 0x4CED, 0x9D67, 0x2A0CE, 0x4CF8, 0x2A105, 0x2A20E,
+    0x2A291, 0x9EBB, 0x4D56, 0x9EF9, 0x9EFE, 0x9F05, 0x9F0F, 0x9F16, 0x9F3B, 0x2A600,
+];
+
+/// The primary composites, as `(first, second, composite)` sorted by the pair they compose.
+pub(super) static COMPOSITIONS: &[(u32, u32, u32)] = &[
+    (0x3C, 0x338, 0x226E), (0x3D, 0x338, 0x2260), (0x3E, 0x338, 0x226F), (0x41, 0x300, 0xC0),
+    (0x41, 0x301, 0xC1), (0x41, 0x302, 0xC2), (0x41, 0x303, 0xC3), (0x41, 0x304, 0x100),
+    (0x41, 0x306, 0x102), (0x41, 0x307, 0x226), (0x41, 0x308, 0xC4), (0x41, 0x309, 0x1EA2),
+    (0x41, 0x30A, 0xC5), (0x41, 0x30C, 0x1CD), (0x41, 0x30F, 0x200), (0x41, 0x311, 0x202),
+    (0x41, 0x323, 0x1EA0), (0x41, 0x325, 0x1E00), (0x41, 0x328, 0x104), (0x42, 0x307, 0x1E02),
+    (0x42, 0x323, 0x1E04), (0x42, 0x331, 0x1E06), (0x43, 0x301, 0x106), (0x43, 0x302, 0x108),
+    (0x43, 0x307, 0x10A), (0x43, 0x30C, 0x10C), (0x43, 0x327, 0xC7), (0x44, 0x307, 0x1E0A),
+    (0x44, 0x30C, 0x10E), (0x44, 0x323, 0x1E0C), (0x44, 0x327, 0x1E10), (0x44, 0x32D, 0x1E12),
+    (0x44, 0x331, 0x1E0E), (0x45, 0x300, 0xC8), (0x45, 0x301, 0xC9), (0x45, 0x302, 0xCA),
+    (0x45, 0x303, 0x1EBC), (0x45, 0x304, 0x112), (0x45, 0x306, 0x114), (0x45, 0x307, 0x116),
+    (0x45, 0x308, 0xCB), (0x45, 0x309, 0x1EBA), (0x45, 0x30C, 0x11A), (0x45, 0x30F, 0x204),
+    (0x45, 0x311, 0x206), (0x45, 0x323, 0x1EB8), (0x45, 0x327, 0x228), (0x45, 0x328, 0x118),
+    (0x45, 0x32D, 0x1E18), (0x45, 0x330, 0x1E1A), (0x46, 0x307, 0x1E1E), (0x47, 0x301, 0x1F4),
+    (0x47, 0x302, 0x11C), (0x47, 0x304, 0x1E20), (0x47, 0x306, 0x11E), (0x47, 0x307, 0x120),
+    (0x47, 0x30C, 0x1E6), (0x47, 0x327, 0x122), (0x48, 0x302, 0x124), (0x48, 0x307, 0x1E22),
+    (0x48, 0x308, 0x1E26), (0x48, 0x30C, 0x21E), (0x48, 0x323, 0x1E24), (0x48, 0x327, 0x1E28),
+    (0x48, 0x32E, 0x1E2A), (0x49, 0x300, 0xCC), (0x49, 0x301, 0xCD), (0x49, 0x302, 0xCE),
+    (0x49, 0x303, 0x128), (0x49, 0x304, 0x12A), (0x49, 0x306, 0x12C), (0x49, 0x307, 0x130),
+    (0x49, 0x308, 0xCF), (0x49, 0x309, 0x1EC8), (0x49, 0x30C, 0x1CF), (0x49, 0x30F, 0x208),
+    (0x49, 0x311, 0x20A), (0x49, 0x323, 0x1ECA), (0x49, 0x328, 0x12E), (0x49, 0x330, 0x1E2C),
+    (0x4A, 0x302, 0x134), (0x4B, 0x301, 0x1E30), (0x4B, 0x30C, 0x1E8), (0x4B, 0x323, 0x1E32),
+    (0x4B, 0x327, 0x136), (0x4B, 0x331, 0x1E34), (0x4C, 0x301, 0x139), (0x4C, 0x30C, 0x13D),
+    (0x4C, 0x323, 0x1E36), (0x4C, 0x327, 0x13B), (0x4C, 0x32D, 0x1E3C), (0x4C, 0x331, 0x1E3A),
+    (0x4D, 0x301, 0x1E3E), (0x4D, 0x307, 0x1E40), (0x4D, 0x323, 0x1E42), (0x4E, 0x300, 0x1F8),
+    (0x4E, 0x301, 0x143), (0x4E, 0x303, 0xD1), (0x4E, 0x307, 0x1E44), (0x4E, 0x30C, 0x147),
+    (0x4E, 0x323, 0x1E46), (0x4E, 0x327, 0x145), (0x4E, 0x32D, 0x1E4A), (0x4E, 0x331, 0x1E48),
+    (0x4F, 0x300, 0xD2), (0x4F, 0x301, 0xD3), (0x4F, 0x302, 0xD4), (0x4F, 0x303, 0xD5),
+    (0x4F, 0x304, 0x14C), (0x4F, 0x306, 0x14E), (0x4F, 0x307, 0x22E), (0x4F, 0x308, 0xD6),
+    (0x4F, 0x309, 0x1ECE), (0x4F, 0x30B, 0x150), (0x4F, 0x30C, 0x1D1), (0x4F, 0x30F, 0x20C),
+    (0x4F, 0x311, 0x20E), (0x4F, 0x31B, 0x1A0), (0x4F, 0x323, 0x1ECC), (0x4F, 0x328, 0x1EA),
+    (0x50, 0x301, 0x1E54), (0x50, 0x307, 0x1E56), (0x52, 0x301, 0x154), (0x52, 0x307, 0x1E58),
+    (0x52, 0x30C, 0x158), (0x52, 0x30F, 0x210), (0x52, 0x311, 0x212), (0x52, 0x323, 0x1E5A),
+    (0x52, 0x327, 0x156), (0x52, 0x331, 0x1E5E), (0x53, 0x301, 0x15A), (0x53, 0x302, 0x15C),
+    (0x53, 0x307, 0x1E60), (0x53, 0x30C, 0x160), (0x53, 0x323, 0x1E62), (0x53, 0x326, 0x218),
+    (0x53, 0x327, 0x15E), (0x54, 0x307, 0x1E6A), (0x54, 0x30C, 0x164), (0x54, 0x323, 0x1E6C),
+    (0x54, 0x326, 0x21A), (0x54, 0x327, 0x162), (0x54, 0x32D, 0x1E70), (0x54, 0x331, 0x1E6E),
+    (0x55, 0x300, 0xD9), (0x55, 0x301, 0xDA), (0x55, 0x302, 0xDB), (0x55, 0x303, 0x168),
+    (0x55, 0x304, 0x16A), (0x55, 0x306, 0x16C), (0x55, 0x308, 0xDC), (0x55, 0x309, 0x1EE6),
+    (0x55, 0x30A, 0x16E), (0x55, 0x30B, 0x170), (0x55, 0x30C, 0x1D3), (0x55, 0x30F, 0x214),
+    (0x55, 0x311, 0x216), (0x55, 0x31B, 0x1AF), (0x55, 0x323, 0x1EE4), (0x55, 0x324, 0x1E72),
+    (0x55, 0x328, 0x172), (0x55, 0x32D, 0x1E76), (0x55, 0x330, 0x1E74), (0x56, 0x303, 0x1E7C),
+    (0x56, 0x323, 0x1E7E), (0x57, 0x300, 0x1E80), (0x57, 0x301, 0x1E82), (0x57, 0x302, 0x174),
+    (0x57, 0x307, 0x1E86), (0x57, 0x308, 0x1E84), (0x57, 0x323, 0x1E88), (0x58, 0x307, 0x1E8A),
+    (0x58, 0x308, 0x1E8C), (0x59, 0x300, 0x1EF2), (0x59, 0x301, 0xDD), (0x59, 0x302, 0x176),
+    (0x59, 0x303, 0x1EF8), (0x59, 0x304, 0x232), (0x59, 0x307, 0x1E8E), (0x59, 0x308, 0x178),
+    (0x59, 0x309, 0x1EF6), (0x59, 0x323, 0x1EF4), (0x5A, 0x301, 0x179), (0x5A, 0x302, 0x1E90),
+    (0x5A, 0x307, 0x17B), (0x5A, 0x30C, 0x17D), (0x5A, 0x323, 0x1E92), (0x5A, 0x331, 0x1E94),
+    (0x61, 0x300, 0xE0), (0x61, 0x301, 0xE1), (0x61, 0x302, 0xE2), (0x61, 0x303, 0xE3),
+    (0x61, 0x304, 0x101), (0x61, 0x306, 0x103), (0x61, 0x307, 0x227), (0x61, 0x308, 0xE4),
+    (0x61, 0x309, 0x1EA3), (0x61, 0x30A, 0xE5), (0x61, 0x30C, 0x1CE), (0x61, 0x30F, 0x201),
+    (0x61, 0x311, 0x203), (0x61, 0x323, 0x1EA1), (0x61, 0x325, 0x1E01), (0x61, 0x328, 0x105),
+    (0x62, 0x307, 0x1E03), (0x62, 0x323, 0x1E05), (0x62, 0x331, 0x1E07), (0x63, 0x301, 0x107),
+    (0x63, 0x302, 0x109), (0x63, 0x307, 0x10B), (0x63, 0x30C, 0x10D), (0x63, 0x327, 0xE7),
+    (0x64, 0x307, 0x1E0B), (0x64, 0x30C, 0x10F), (0x64, 0x323, 0x1E0D), (0x64, 0x327, 0x1E11),
+    (0x64, 0x32D, 0x1E13), (0x64, 0x331, 0x1E0F), (0x65, 0x300, 0xE8), (0x65, 0x301, 0xE9),
+    (0x65, 0x302, 0xEA), (0x65, 0x303, 0x1EBD), (0x65, 0x304, 0x113), (0x65, 0x306, 0x115),
+    (0x65, 0x307, 0x117), (0x65, 0x308, 0xEB), (0x65, 0x309, 0x1EBB), (0x65, 0x30C, 0x11B),
+    (0x65, 0x30F, 0x205), (0x65, 0x311, 0x207), (0x65, 0x323, 0x1EB9), (0x65, 0x327, 0x229),
+    (0x65, 0x328, 0x119), (0x65, 0x32D, 0x1E19), (0x65, 0x330, 0x1E1B), (0x66, 0x307, 0x1E1F),
+    (0x67, 0x301, 0x1F5), (0x67, 0x302, 0x11D), (0x67, 0x304, 0x1E21), (0x67, 0x306, 0x11F),
+    (0x67, 0x307, 0x121), (0x67, 0x30C, 0x1E7), (0x67, 0x327, 0x123), (0x68, 0x302, 0x125),
+    (0x68, 0x307, 0x1E23), (0x68, 0x308, 0x1E27), (0x68, 0x30C, 0x21F), (0x68, 0x323, 0x1E25),
+    (0x68, 0x327, 0x1E29), (0x68, 0x32E, 0x1E2B), (0x68, 0x331, 0x1E96), (0x69, 0x300, 0xEC),
+    (0x69, 0x301, 0xED), (0x69, 0x302, 0xEE), (0x69, 0x303, 0x129), (0x69, 0x304, 0x12B),
+    (0x69, 0x306, 0x12D), (0x69, 0x308, 0xEF), (0x69, 0x309, 0x1EC9), (0x69, 0x30C, 0x1D0),
+    (0x69, 0x30F, 0x209), (0x69, 0x311, 0x20B), (0x69, 0x323, 0x1ECB), (0x69, 0x328, 0x12F),
+    (0x69, 0x330, 0x1E2D), (0x6A, 0x302, 0x135), (0x6A, 0x30C, 0x1F0), (0x6B, 0x301, 0x1E31),
+    (0x6B, 0x30C, 0x1E9), (0x6B, 0x323, 0x1E33), (0x6B, 0x327, 0x137), (0x6B, 0x331, 0x1E35),
+    (0x6C, 0x301, 0x13A), (0x6C, 0x30C, 0x13E), (0x6C, 0x323, 0x1E37), (0x6C, 0x327, 0x13C),
+    (0x6C, 0x32D, 0x1E3D), (0x6C, 0x331, 0x1E3B), (0x6D, 0x301, 0x1E3F), (0x6D, 0x307, 0x1E41),
+    (0x6D, 0x323, 0x1E43), (0x6E, 0x300, 0x1F9), (0x6E, 0x301, 0x144), (0x6E, 0x303, 0xF1),
+    (0x6E, 0x307, 0x1E45), (0x6E, 0x30C, 0x148), (0x6E, 0x323, 0x1E47), (0x6E, 0x327, 0x146),
+    (0x6E, 0x32D, 0x1E4B), (0x6E, 0x331, 0x1E49), (0x6F, 0x300, 0xF2), (0x6F, 0x301, 0xF3),
+    (0x6F, 0x302, 0xF4), (0x6F, 0x303, 0xF5), (0x6F, 0x304, 0x14D), (0x6F, 0x306, 0x14F),
+    (0x6F, 0x307, 0x22F), (0x6F, 0x308, 0xF6), (0x6F, 0x309, 0x1ECF), (0x6F, 0x30B, 0x151),
+    (0x6F, 0x30C, 0x1D2), (0x6F, 0x30F, 0x20D), (0x6F, 0x311, 0x20F), (0x6F, 0x31B, 0x1A1),
+    (0x6F, 0x323, 0x1ECD), (0x6F, 0x328, 0x1EB), (0x70, 0x301, 0x1E55), (0x70, 0x307, 0x1E57),
+    (0x72, 0x301, 0x155), (0x72, 0x307, 0x1E59), (0x72, 0x30C, 0x159), (0x72, 0x30F, 0x211),
+    (0x72, 0x311, 0x213), (0x72, 0x323, 0x1E5B), (0x72, 0x327, 0x157), (0x72, 0x331, 0x1E5F),
+    (0x73, 0x301, 0x15B), (0x73, 0x302, 0x15D), (0x73, 0x307, 0x1E61), (0x73, 0x30C, 0x161),
+    (0x73, 0x323, 0x1E63), (0x73, 0x326, 0x219), (0x73, 0x327, 0x15F), (0x74, 0x307, 0x1E6B),
+    (0x74, 0x308, 0x1E97), (0x74, 0x30C, 0x165), (0x74, 0x323, 0x1E6D), (0x74, 0x326, 0x21B),
+    (0x74, 0x327, 0x163), (0x74, 0x32D, 0x1E71), (0x74, 0x331, 0x1E6F), (0x75, 0x300, 0xF9),
+    (0x75, 0x301, 0xFA), (0x75, 0x302, 0xFB), (0x75, 0x303, 0x169), (0x75, 0x304, 0x16B),
+    (0x75, 0x306, 0x16D), (0x75, 0x308, 0xFC), (0x75, 0x309, 0x1EE7), (0x75, 0x30A, 0x16F),
+    (0x75, 0x30B, 0x171), (0x75, 0x30C, 0x1D4), (0x75, 0x30F, 0x215), (0x75, 0x311, 0x217),
+    (0x75, 0x31B, 0x1B0), (0x75, 0x323, 0x1EE5), (0x75, 0x324, 0x1E73), (0x75, 0x328, 0x173),
+    (0x75, 0x32D, 0x1E77), (0x75, 0x330, 0x1E75), (0x76, 0x303, 0x1E7D), (0x76, 0x323, 0x1E7F),
+    (0x77, 0x300, 0x1E81), (0x77, 0x301, 0x1E83), (0x77, 0x302, 0x175), (0x77, 0x307, 0x1E87),
+    (0x77, 0x308, 0x1E85), (0x77, 0x30A, 0x1E98), (0x77, 0x323, 0x1E89), (0x78, 0x307, 0x1E8B),
+    (0x78, 0x308, 0x1E8D), (0x79, 0x300, 0x1EF3), (0x79, 0x301, 0xFD), (0x79, 0x302, 0x177),
+    (0x79, 0x303, 0x1EF9), (0x79, 0x304, 0x233), (0x79, 0x307, 0x1E8F), (0x79, 0x308, 0xFF),
+    (0x79, 0x309, 0x1EF7), (0x79, 0x30A, 0x1E99), (0x79, 0x323, 0x1EF5), (0x7A, 0x301, 0x17A),
+    (0x7A, 0x302, 0x1E91), (0x7A, 0x307, 0x17C), (0x7A, 0x30C, 0x17E), (0x7A, 0x323, 0x1E93),
+    (0x7A, 0x331, 0x1E95), (0xA8, 0x300, 0x1FED), (0xA8, 0x301, 0x385), (0xA8, 0x342, 0x1FC1),
+    (0xC2, 0x300, 0x1EA6), (0xC2, 0x301, 0x1EA4), (0xC2, 0x303, 0x1EAA), (0xC2, 0x309, 0x1EA8),
+    (0xC4, 0x304, 0x1DE), (0xC5, 0x301, 0x1FA), (0xC6, 0x301, 0x1FC), (0xC6, 0x304, 0x1E2),
+    (0xC7, 0x301, 0x1E08), (0xCA, 0x300, 0x1EC0), (0xCA, 0x301, 0x1EBE), (0xCA, 0x303, 0x1EC4),
+    (0xCA, 0x309, 0x1EC2), (0xCF, 0x301, 0x1E2E), (0xD4, 0x300, 0x1ED2), (0xD4, 0x301, 0x1ED0),
+    (0xD4, 0x303, 0x1ED6), (0xD4, 0x309, 0x1ED4), (0xD5, 0x301, 0x1E4C), (0xD5, 0x304, 0x22C),
+    (0xD5, 0x308, 0x1E4E), (0xD6, 0x304, 0x22A), (0xD8, 0x301, 0x1FE), (0xDC, 0x300, 0x1DB),
+    (0xDC, 0x301, 0x1D7), (0xDC, 0x304, 0x1D5), (0xDC, 0x30C, 0x1D9), (0xE2, 0x300, 0x1EA7),
+    (0xE2, 0x301, 0x1EA5), (0xE2, 0x303, 0x1EAB), (0xE2, 0x309, 0x1EA9), (0xE4, 0x304, 0x1DF),
+    (0xE5, 0x301, 0x1FB), (0xE6, 0x301, 0x1FD), (0xE6, 0x304, 0x1E3), (0xE7, 0x301, 0x1E09),
+    (0xEA, 0x300, 0x1EC1), (0xEA, 0x301, 0x1EBF), (0xEA, 0x303, 0x1EC5), (0xEA, 0x309, 0x1EC3),
+    (0xEF, 0x301, 0x1E2F), (0xF4, 0x300, 0x1ED3), (0xF4, 0x301, 0x1ED1), (0xF4, 0x303, 0x1ED7),
+    (0xF4, 0x309, 0x1ED5), (0xF5, 0x301, 0x1E4D), (0xF5, 0x304, 0x22D), (0xF5, 0x308, 0x1E4F),
+    (0xF6, 0x304, 0x22B), (0xF8, 0x301, 0x1FF), (0xFC, 0x300, 0x1DC), (0xFC, 0x301, 0x1D8),
+    (0xFC, 0x304, 0x1D6), (0xFC, 0x30C, 0x1DA), (0x102, 0x300, 0x1EB0), (0x102, 0x301, 0x1EAE),
+    (0x102, 0x303, 0x1EB4), (0x102, 0x309, 0x1EB2), (0x103, 0x300, 0x1EB1), (0x103, 0x301, 0x1EAF),
+    (0x103, 0x303, 0x1EB5), (0x103, 0x309, 0x1EB3), (0x112, 0x300, 0x1E14), (0x112, 0x301, 0x1E16),
+    (0x113, 0x300, 0x1E15), (0x113, 0x301, 0x1E17), (0x14C, 0x300, 0x1E50), (0x14C, 0x301, 0x1E52),
+    (0x14D, 0x300, 0x1E51), (0x14D, 0x301, 0x1E53), (0x15A, 0x307, 0x1E64), (0x15B, 0x307, 0x1E65),
+    (0x160, 0x307, 0x1E66), (0x161, 0x307, 0x1E67), (0x168, 0x301, 0x1E78), (0x169, 0x301, 0x1E79),
+    (0x16A, 0x308, 0x1E7A), (0x16B, 0x308, 0x1E7B), (0x17F, 0x307, 0x1E9B), (0x1A0, 0x300, 0x1EDC),
+    (0x1A0, 0x301, 0x1EDA), (0x1A0, 0x303, 0x1EE0), (0x1A0, 0x309, 0x1EDE), (0x1A0, 0x323, 0x1EE2),
+    (0x1A1, 0x300, 0x1EDD), (0x1A1, 0x301, 0x1EDB), (0x1A1, 0x303, 0x1EE1), (0x1A1, 0x309, 0x1EDF),
+    (0x1A1, 0x323, 0x1EE3), (0x1AF, 0x300, 0x1EEA), (0x1AF, 0x301, 0x1EE8), (0x1AF, 0x303, 0x1EEE),
+    (0x1AF, 0x309, 0x1EEC), (0x1AF, 0x323, 0x1EF0), (0x1B0, 0x300, 0x1EEB), (0x1B0, 0x301, 0x1EE9),
+    (0x1B0, 0x303, 0x1EEF), (0x1B0, 0x309, 0x1EED), (0x1B0, 0x323, 0x1EF1), (0x1B7, 0x30C, 0x1EE),
+    (0x1EA, 0x304, 0x1EC), (0x1EB, 0x304, 0x1ED), (0x226, 0x304, 0x1E0), (0x227, 0x304, 0x1E1),
+    (0x228, 0x306, 0x1E1C), (0x229, 0x306, 0x1E1D), (0x22E, 0x304, 0x230), (0x22F, 0x304, 0x231),
+    (0x292, 0x30C, 0x1EF), (0x391, 0x300, 0x1FBA), (0x391, 0x301, 0x386), (0x391, 0x304, 0x1FB9),
+    (0x391, 0x306, 0x1FB8), (0x391, 0x313, 0x1F08), (0x391, 0x314, 0x1F09), (0x391, 0x345, 0x1FBC),
+    (0x395, 0x300, 0x1FC8), (0x395, 0x301, 0x388), (0x395, 0x313, 0x1F18), (0x395, 0x314, 0x1F19),
+    (0x397, 0x300, 0x1FCA), (0x397, 0x301, 0x389), (0x397, 0x313, 0x1F28), (0x397, 0x314, 0x1F29),
+    (0x397, 0x345, 0x1FCC), (0x399, 0x300, 0x1FDA), (0x399, 0x301, 0x38A), (0x399, 0x304, 0x1FD9),
+    (0x399, 0x306, 0x1FD8), (0x399, 0x308, 0x3AA), (0x399, 0x313, 0x1F38), (0x399, 0x314, 0x1F39),
+    (0x39F, 0x300, 0x1FF8), (0x39F, 0x301, 0x38C), (0x39F, 0x313, 0x1F48), (0x39F, 0x314, 0x1F49),
+    (0x3A1, 0x314, 0x1FEC), (0x3A5, 0x300, 0x1FEA), (0x3A5, 0x301, 0x38E), (0x3A5, 0x304, 0x1FE9),
+    (0x3A5, 0x306, 0x1FE8), (0x3A5, 0x308, 0x3AB), (0x3A5, 0x314, 0x1F59), (0x3A9, 0x300, 0x1FFA),
+    (0x3A9, 0x301, 0x38F), (0x3A9, 0x313, 0x1F68), (0x3A9, 0x314, 0x1F69), (0x3A9, 0x345, 0x1FFC),
+    (0x3AC, 0x345, 0x1FB4), (0x3AE, 0x345, 0x1FC4), (0x3B1, 0x300, 0x1F70), (0x3B1, 0x301, 0x3AC),
+    (0x3B1, 0x304, 0x1FB1), (0x3B1, 0x306, 0x1FB0), (0x3B1, 0x313, 0x1F00), (0x3B1, 0x314, 0x1F01),
+    (0x3B1, 0x342, 0x1FB6), (0x3B1, 0x345, 0x1FB3), (0x3B5, 0x300, 0x1F72), (0x3B5, 0x301, 0x3AD),
+    (0x3B5, 0x313, 0x1F10), (0x3B5, 0x314, 0x1F11), (0x3B7, 0x300, 0x1F74), (0x3B7, 0x301, 0x3AE),
+    (0x3B7, 0x313, 0x1F20), (0x3B7, 0x314, 0x1F21), (0x3B7, 0x342, 0x1FC6), (0x3B7, 0x345, 0x1FC3),
+    (0x3B9, 0x300, 0x1F76), (0x3B9, 0x301, 0x3AF), (0x3B9, 0x304, 0x1FD1), (0x3B9, 0x306, 0x1FD0),
+    (0x3B9, 0x308, 0x3CA), (0x3B9, 0x313, 0x1F30), (0x3B9, 0x314, 0x1F31), (0x3B9, 0x342, 0x1FD6),
+    (0x3BF, 0x300, 0x1F78), (0x3BF, 0x301, 0x3CC), (0x3BF, 0x313, 0x1F40), (0x3BF, 0x314, 0x1F41),
+    (0x3C1, 0x313, 0x1FE4), (0x3C1, 0x314, 0x1FE5), (0x3C5, 0x300, 0x1F7A), (0x3C5, 0x301, 0x3CD),
+    (0x3C5, 0x304, 0x1FE1), (0x3C5, 0x306, 0x1FE0), (0x3C5, 0x308, 0x3CB), (0x3C5, 0x313, 0x1F50),
+    (0x3C5, 0x314, 0x1F51), (0x3C5, 0x342, 0x1FE6), (0x3C9, 0x300, 0x1F7C), (0x3C9, 0x301, 0x3CE),
+    (0x3C9, 0x313, 0x1F60), (0x3C9, 0x314, 0x1F61), (0x3C9, 0x342, 0x1FF6), (0x3C9, 0x345, 0x1FF3),
+    (0x3CA, 0x300, 0x1FD2), (0x3CA, 0x301, 0x390), (0x3CA, 0x342, 0x1FD7), (0x3CB, 0x300, 0x1FE2),
+    (0x3CB, 0x301, 0x3B0), (0x3CB, 0x342, 0x1FE7), (0x3CE, 0x345, 0x1FF4), (0x3D2, 0x301, 0x3D3),
+    (0x3D2, 0x308, 0x3D4), (0x406, 0x308, 0x407), (0x410, 0x306, 0x4D0), (0x410, 0x308, 0x4D2),
+    (0x413, 0x301, 0x403), (0x415, 0x300, 0x400), (0x415, 0x306, 0x4D6), (0x415, 0x308, 0x401),
+    (0x416, 0x306, 0x4C1), (0x416, 0x308, 0x4DC), (0x417, 0x308, 0x4DE), (0x418, 0x300, 0x40D),
+    (0x418, 0x304, 0x4E2), (0x418, 0x306, 0x419), (0x418, 0x308, 0x4E4), (0x41A, 0x301, 0x40C),
+    (0x41E, 0x308, 0x4E6), (0x423, 0x304, 0x4EE), (0x423, 0x306, 0x40E), (0x423, 0x308, 0x4F0),
+    (0x423, 0x30B, 0x4F2), (0x427, 0x308, 0x4F4), (0x42B, 0x308, 0x4F8), (0x42D, 0x308, 0x4EC),
+    (0x430, 0x306, 0x4D1), (0x430, 0x308, 0x4D3), (0x433, 0x301, 0x453), (0x435, 0x300, 0x450),
+    (0x435, 0x306, 0x4D7), (0x435, 0x308, 0x451), (0x436, 0x306, 0x4C2), (0x436, 0x308, 0x4DD),
+    (0x437, 0x308, 0x4DF), (0x438, 0x300, 0x45D), (0x438, 0x304, 0x4E3), (0x438, 0x306, 0x439),
+    (0x438, 0x308, 0x4E5), (0x43A, 0x301, 0x45C), (0x43E, 0x308, 0x4E7), (0x443, 0x304, 0x4EF),
+    (0x443, 0x306, 0x45E), (0x443, 0x308, 0x4F1), (0x443, 0x30B, 0x4F3), (0x447, 0x308, 0x4F5),
+    (0x44B, 0x308, 0x4F9), (0x44D, 0x308, 0x4ED), (0x456, 0x308, 0x457), (0x474, 0x30F, 0x476),
+    (0x475, 0x30F, 0x477), (0x4D8, 0x308, 0x4DA), (0x4D9, 0x308, 0x4DB), (0x4E8, 0x308, 0x4EA),
+    (0x4E9, 0x308, 0x4EB), (0x627, 0x653, 0x622), (0x627, 0x654, 0x623), (0x627, 0x655, 0x625),
+    (0x648, 0x654, 0x624), (0x64A, 0x654, 0x626), (0x6C1, 0x654, 0x6C2), (0x6D2, 0x654, 0x6D3),
+    (0x6D5, 0x654, 0x6C0), (0x928, 0x93C, 0x929), (0x930, 0x93C, 0x931), (0x933, 0x93C, 0x934),
+    (0x9C7, 0x9BE, 0x9CB), (0x9C7, 0x9D7, 0x9CC), (0xB47, 0xB3E, 0xB4B), (0xB47, 0xB56, 0xB48),
+    (0xB47, 0xB57, 0xB4C), (0xB92, 0xBD7, 0xB94), (0xBC6, 0xBBE, 0xBCA), (0xBC6, 0xBD7, 0xBCC),
+    (0xBC7, 0xBBE, 0xBCB), (0xC46, 0xC56, 0xC48), (0xCBF, 0xCD5, 0xCC0), (0xCC6, 0xCC2, 0xCCA),
+    (0xCC6, 0xCD5, 0xCC7), (0xCC6, 0xCD6, 0xCC8), (0xCCA, 0xCD5, 0xCCB), (0xD46, 0xD3E, 0xD4A),
+    (0xD46, 0xD57, 0xD4C), (0xD47, 0xD3E, 0xD4B), (0xDD9, 0xDCA, 0xDDA), (0xDD9, 0xDCF, 0xDDC),
+    (0xDD9, 0xDDF, 0xDDE), (0xDDC, 0xDCA, 0xDDD), (0x1025, 0x102E, 0x1026), (0x1B05, 0x1B35, 0x1B06),
+    (0x1B07, 0x1B35, 0x1B08), (0x1B09, 0x1B35, 0x1B0A), (0x1B0B, 0x1B35, 0x1B0C), (0x1B0D, 0x1B35, 0x1B0E),
+    (0x1B11, 0x1B35, 0x1B12), (0x1B3A, 0x1B35, 0x1B3B), (0x1B3C, 0x1B35, 0x1B3D), (0x1B3E, 0x1B35, 0x1B40),
+    (0x1B3F, 0x1B35, 0x1B41), (0x1B42, 0x1B35, 0x1B43), (0x1E36, 0x304, 0x1E38), (0x1E37, 0x304, 0x1E39),
+    (0x1E5A, 0x304, 0x1E5C), (0x1E5B, 0x304, 0x1E5D), (0x1E62, 0x307, 0x1E68), (0x1E63, 0x307, 0x1E69),
+    (0x1EA0, 0x302, 0x1EAC), (0x1EA0, 0x306, 0x1EB6), (0x1EA1, 0x302, 0x1EAD), (0x1EA1, 0x306, 0x1EB7),
+    (0x1EB8, 0x302, 0x1EC6), (0x1EB9, 0x302, 0x1EC7), (0x1ECC, 0x302, 0x1ED8), (0x1ECD, 0x302, 0x1ED9),
+    (0x1F00, 0x300, 0x1F02), (0x1F00, 0x301, 0x1F04), (0x1F00, 0x342, 0x1F06), (0x1F00, 0x345, 0x1F80),
+    (0x1F01, 0x300, 0x1F03), (0x1F01, 0x301, 0x1F05), (0x1F01, 0x342, 0x1F07), (0x1F01, 0x345, 0x1F81),
+    (0x1F02, 0x345, 0x1F82), (0x1F03, 0x345, 0x1F83), (0x1F04, 0x345, 0x1F84), (0x1F05, 0x345, 0x1F85),
+    (0x1F06, 0x345, 0x1F86), (0x1F07, 0x345, 0x1F87), (0x1F08, 0x300, 0x1F0A), (0x1F08, 0x301, 0x1F0C),
+    (0x1F08, 0x342, 0x1F0E), (0x1F08, 0x345, 0x1F88), (0x1F09, 0x300, 0x1F0B), (0x1F09, 0x301, 0x1F0D),
+    (0x1F09, 0x342, 0x1F0F), (0x1F09, 0x345, 0x1F89), (0x1F0A, 0x345, 0x1F8A), (0x1F0B, 0x345, 0x1F8B),
+    (0x1F0C, 0x345, 0x1F8C), (0x1F0D, 0x345, 0x1F8D), (0x1F0E, 0x345, 0x1F8E), (0x1F0F, 0x345, 0x1F8F),
+    (0x1F10, 0x300, 0x1F12), (0x1F10, 0x301, 0x1F14), (0x1F11, 0x300, 0x1F13), (0x1F11, 0x301, 0x1F15),
+    (0x1F18, 0x300, 0x1F1A), (0x1F18, 0x301, 0x1F1C), (0x1F19, 0x300, 0x1F1B), (0x1F19, 0x301, 0x1F1D),
+    (0x1F20, 0x300, 0x1F22), (0x1F20, 0x301, 0x1F24), (0x1F20, 0x342, 0x1F26), (0x1F20, 0x345, 0x1F90),
+    (0x1F21, 0x300, 0x1F23), (0x1F21, 0x301, 0x1F25), (0x1F21, 0x342, 0x1F27), (0x1F21, 0x345, 0x1F91),
+    (0x1F22, 0x345, 0x1F92), (0x1F23, 0x345, 0x1F93), (0x1F24, 0x345, 0x1F94), (0x1F25, 0x345, 0x1F95),
+    (0x1F26, 0x345, 0x1F96), (0x1F27, 0x345, 0x1F97), (0x1F28, 0x300, 0x1F2A), (0x1F28, 0x301, 0x1F2C),
+    (0x1F28, 0x342, 0x1F2E), (0x1F28, 0x345, 0x1F98), (0x1F29, 0x300, 0x1F2B), (0x1F29, 0x301, 0x1F2D),
+    (0x1F29, 0x342, 0x1F2F), (0x1F29, 0x345, 0x1F99), (0x1F2A, 0x345, 0x1F9A), (0x1F2B, 0x345, 0x1F9B),
+    (0x1F2C, 0x345, 0x1F9C), (0x1F2D, 0x345, 0x1F9D), (0x1F2E, 0x345, 0x1F9E), (0x1F2F, 0x345, 0x1F9F),
+    (0x1F30, 0x300, 0x1F32), (0x1F30, 0x301, 0x1F34), (0x1F30, 0x342, 0x1F36), (0x1F31, 0x300, 0x1F33),
+    (0x1F31, 0x301, 0x1F35), (0x1F31, 0x342, 0x1F37), (0x1F38, 0x300, 0x1F3A), (0x1F38, 0x301, 0x1F3C),
+    (0x1F38, 0x342, 0x1F3E), (0x1F39, 0x300, 0x1F3B), (0x1F39, 0x301, 0x1F3D), (0x1F39, 0x342, 0x1F3F),
+    (0x1F40, 0x300, 0x1F42), (0x1F40, 0x301, 0x1F44), (0x1F41, 0x300, 0x1F43), (0x1F41, 0x301, 0x1F45),
+    (0x1F48, 0x300, 0x1F4A), (0x1F48, 0x301, 0x1F4C), (0x1F49, 0x300, 0x1F4B), (0x1F49, 0x301, 0x1F4D),
+    (0x1F50, 0x300, 0x1F52), (0x1F50, 0x301, 0x1F54), (0x1F50, 0x342, 0x1F56), (0x1F51, 0x300, 0x1F53),
+    (0x1F51, 0x301, 0x1F55), (0x1F51, 0x342, 0x1F57), (0x1F59, 0x300, 0x1F5B), (0x1F59, 0x301, 0x1F5D),
+    (0x1F59, 0x342, 0x1F5F), (0x1F60, 0x300, 0x1F62), (0x1F60, 0x301, 0x1F64), (0x1F60, 0x342, 0x1F66),
+    (0x1F60, 0x345, 0x1FA0), (0x1F61, 0x300, 0x1F63), (0x1F61, 0x301, 0x1F65), (0x1F61, 0x342, 0x1F67),
+    (0x1F61, 0x345, 0x1FA1), (0x1F62, 0x345, 0x1FA2), (0x1F63, 0x345, 0x1FA3), (0x1F64, 0x345, 0x1FA4),
+    (0x1F65, 0x345, 0x1FA5), (0x1F66, 0x345, 0x1FA6), (0x1F67, 0x345, 0x1FA7), (0x1F68, 0x300, 0x1F6A),
+    (0x1F68, 0x301, 0x1F6C), (0x1F68, 0x342, 0x1F6E), (0x1F68, 0x345, 0x1FA8), (0x1F69, 0x300, 0x1F6B),
+    (0x1F69, 0x301, 0x1F6D), (0x1F69, 0x342, 0x1F6F), (0x1F69, 0x345, 0x1FA9), (0x1F6A, 0x345, 0x1FAA),
+    (0x1F6B, 0x345, 0x1FAB), (0x1F6C, 0x345, 0x1FAC), (0x1F6D, 0x345, 0x1FAD), (0x1F6E, 0x345, 0x1FAE),
+    (0x1F6F, 0x345, 0x1FAF), (0x1F70, 0x345, 0x1FB2), (0x1F74, 0x345, 0x1FC2), (0x1F7C, 0x345, 0x1FF2),
+    (0x1FB6, 0x345, 0x1FB7), (0x1FBF, 0x300, 0x1FCD), (0x1FBF, 0x301, 0x1FCE), (0x1FBF, 0x342, 0x1FCF),
+    (0x1FC6, 0x345, 0x1FC7), (0x1FF6, 0x345, 0x1FF7), (0x1FFE, 0x300, 0x1FDD), (0x1FFE, 0x301, 0x1FDE),
+    (0x1FFE, 0x342, 0x1FDF), (0x2190, 0x338, 0x219A), (0x2192, 0x338, 0x219B), (0x2194, 0x338, 0x21AE),
+    (0x21D0, 0x338, 0x21CD), (0x21D2, 0x338, 0x21CF), (0x21D4, 0x338, 0x21CE), (0x2203, 0x338, 0x2204),
+    (0x2208, 0x338, 0x2209), (0x220B, 0x338, 0x220C), (0x2223, 0x338, 0x2224), (0x2225, 0x338, 0x2226),
+    (0x223C, 0x338, 0x2241), (0x2243, 0x338, 0x2244), (0x2245, 0x338, 0x2247), (0x2248, 0x338, 0x2249),
+    (0x224D, 0x338, 0x226D), (0x2261, 0x338, 0x2262), (0x2264, 0x338, 0x2270), (0x2265, 0x338, 0x2271),
+    (0x2272, 0x338, 0x2274), (0x2273, 0x338, 0x2275), (0x2276, 0x338, 0x2278), (0x2277, 0x338, 0x2279),
+    (0x227A, 0x338, 0x2280), (0x227B, 0x338, 0x2281), (0x227C, 0x338, 0x22E0), (0x227D, 0x338, 0x22E1),
+    (0x2282, 0x338, 0x2284), (0x2283, 0x338, 0x2285), (0x2286, 0x338, 0x2288), (0x2287, 0x338, 0x2289),
+    (0x2291, 0x338, 0x22E2), (0x2292, 0x338, 0x22E3), (0x22A2, 0x338, 0x22AC), (0x22A8, 0x338, 0x22AD),
+    (0x22A9, 0x338, 0x22AE), (0x22AB, 0x338, 0x22AF), (0x22B2, 0x338, 0x22EA), (0x22B3, 0x338, 0x22EB),
+    (0x22B4, 0x338, 0x22EC), (0x22B5, 0x338, 0x22ED), (0x3046, 0x3099, 0x3094), (0x304B, 0x3099, 0x304C),
+    (0x304D, 0x3099, 0x304E), (0x304F, 0x3099, 0x3050), (0x3051, 0x3099, 0x3052), (0x3053, 0x3099, 0x3054),
+    (0x3055, 0x3099, 0x3056), (0x3057, 0x3099, 0x3058), (0x3059, 0x3099, 0x305A), (0x305B, 0x3099, 0x305C),
+    (0x305D, 0x3099, 0x305E), (0x305F, 0x3099, 0x3060), (0x3061, 0x3099, 0x3062), (0x3064, 0x3099, 0x3065),
+    (0x3066, 0x3099, 0x3067), (0x3068, 0x3099, 0x3069), (0x306F, 0x3099, 0x3070), (0x306F, 0x309A, 0x3071),
+    (0x3072, 0x3099, 0x3073), (0x3072, 0x309A, 0x3074), (0x3075, 0x3099, 0x3076), (0x3075, 0x309A, 0x3077),
+    (0x3078, 0x3099, 0x3079), (0x3078, 0x309A, 0x307A), (0x307B, 0x3099, 0x307C), (0x307B, 0x309A, 0x307D),
+    (0x309D, 0x3099, 0x309E), (0x30A6, 0x3099, 0x30F4), (0x30AB, 0x3099, 0x30AC), (0x30AD, 0x3099, 0x30AE),
+    (0x30AF, 0x3099, 0x30B0), (0x30B1, 0x3099, 0x30B2), (0x30B3, 0x3099, 0x30B4), (0x30B5, 0x3099, 0x30B6),
+    (0x30B7, 0x3099, 0x30B8), (0x30B9, 0x3099, 0x30BA), (0x30BB, 0x3099, 0x30BC), (0x30BD, 0x3099, 0x30BE),
+    (0x30BF, 0x3099, 0x30C0), (0x30C1, 0x3099, 0x30C2), (0x30C4, 0x3099, 0x30C5), (0x30C6, 0x3099, 0x30C7),
+    (0x30C8, 0x3099, 0x30C9), (0x30CF, 0x3099, 0x30D0), (0x30CF, 0x309A, 0x30D1), (0x30D2, 0x3099, 0x30D3),
+    (0x30D2, 0x309A, 0x30D4), (0x30D5, 0x3099, 0x30D6), (0x30D5, 0x309A, 0x30D7), (0x30D8, 0x3099, 0x30D9),
+    (0x30D8, 0x309A, 0x30DA), (0x30DB, 0x3099, 0x30DC), (0x30DB, 0x309A, 0x30DD), (0x30EF, 0x3099, 0x30F7),
+    (0x30F0, 0x3099, 0x30F8), (0x30F1, 0x3099, 0x30F9), (0x30F2, 0x3099, 0x30FA), (0x30FD, 0x3099, 0x30FE),
+    (0x105D2, 0x307, 0x105C9), (0x105DA, 0x307, 0x105E4), (0x11099, 0x110BA, 0x1109A), (0x1109B, 0x110BA, 0x1109C),
+    (0x110A5, 0x110BA, 0x110AB), (0x11131, 0x11127, 0x1112E), (0x11132, 0x11127, 0x1112F), (0x11347, 0x1133E, 0x1134B),
+    (0x11347, 0x11357, 0x1134C), (0x11382, 0x113C9, 0x11383), (0x11384, 0x113BB, 0x11385), (0x1138B, 0x113C2, 0x1138E),
+    (0x11390, 0x113C9, 0x11391), (0x113C2, 0x113B8, 0x113C7), (0x113C2, 0x113C2, 0x113C5), (0x113C2, 0x113C9, 0x113C8),
+    (0x114B9, 0x114B0, 0x114BC), (0x114B9, 0x114BA, 0x114BB), (0x114B9, 0x114BD, 0x114BE), (0x115B8, 0x115AF, 0x115BA),
+    (0x115B9, 0x115AF, 0x115BB), (0x11935, 0x11930, 0x11938), (0x1611E, 0x1611E, 0x16121), (0x1611E, 0x1611F, 0x16123),
+    (0x1611E, 0x16120, 0x16125), (0x1611E, 0x16129, 0x16122), (0x16121, 0x1611F, 0x16126), (0x16121, 0x16120, 0x16128),
+    (0x16122, 0x1611F, 0x16127), (0x16129, 0x1611F, 0x16124), (0x16D63, 0x16D67, 0x16D69), (0x16D67, 0x16D67, 0x16D68),
+    (0x16D69, 0x16D67, 0x16D6A),
+];
+
+/// Sorted inclusive ranges of code points.
+pub(super) type Ranges = &'static [(u32, u32)];
+
+/// The binary properties usable in regular expressions, as `(name, alias, ranges)`.
+pub(super) static BINARY_PROPERTIES: &[(&str, &str, Ranges)] = &[
+    ("ASCII", "", &[
+        (0x0, 0x7F),
+    ]),
+    ("ASCII_Hex_Digit", "AHex", &[
+        (0x30, 0x39), (0x41, 0x46), (0x61, 0x66),
+    ]),
+    ("Alphabetic", "Alpha", &[
+        (0x41, 0x5A), (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5), (0xBA, 0xBA), (0xC0, 0xD6),
+        (0xD8, 0xF6), (0xF8, 0x2C1), (0x2C6, 0x2D1), (0x2E0, 0x2E4), (0x2EC, 0x2EC), (0x2EE, 0x2EE),
+        (0x345, 0x345), (0x363, 0x374), (0x376, 0x377), (0x37A, 0x37D), (0x37F, 0x37F), (0x386, 0x386),
+        (0x388, 0x38A), (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x3F5), (0x3F7, 0x481), (0x48A, 0x52F),
+        (0x531, 0x556), (0x559, 0x559), (0x560, 0x588), (0x5B0, 0x5BD), (0x5BF, 0x5BF), (0x5C1, 0x5C2),
+        (0x5C4, 0x5C5), (0x5C7, 0x5C7), (0x5D0, 0x5EA), (0x5EF, 0x5F2), (0x610, 0x61A), (0x620, 0x657),
+        (0x659, 0x65F), (0x66E, 0x6D3), (0x6D5, 0x6DC), (0x6E1, 0x6E8), (0x6ED, 0x6EF), (0x6FA, 0x6FC),
+        (0x6FF, 0x6FF), (0x710, 0x73F), (0x74D, 0x7B1), (0x7CA, 0x7EA), (0x7F4, 0x7F5), (0x7FA, 0x7FA),
+        (0x800, 0x817), (0x81A, 0x82C), (0x840, 0x858), (0x860, 0x86A), (0x870, 0x887), (0x889, 0x88F),
+        (0x897, 0x897), (0x8A0, 0x8C9), (0x8D4, 0x8DF), (0x8E3, 0x8E9), (0x8F0, 0x93B), (0x93D, 0x94C),
+        (0x94E, 0x950), (0x955, 0x963), (0x971, 0x983), (0x985, 0x98C), (0x98F, 0x990), (0x993, 0x9A8),
+        (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9), (0x9BD, 0x9C4), (0x9C7, 0x9C8), (0x9CB, 0x9CC),
+        (0x9CE, 0x9CE), (0x9D7, 0x9D7), (0x9DC, 0x9DD), (0x9DF, 0x9E3), (0x9F0, 0x9F1), (0x9FC, 0x9FC),
+        (0xA01, 0xA03), (0xA05, 0xA0A), (0xA0F, 0xA10), (0xA13, 0xA28), (0xA2A, 0xA30), (0xA32, 0xA33),
+        (0xA35, 0xA36), (0xA38, 0xA39), (0xA3E, 0xA42), (0xA47, 0xA48), (0xA4B, 0xA4C), (0xA51, 0xA51),
+        (0xA59, 0xA5C), (0xA5E, 0xA5E), (0xA70, 0xA75), (0xA81, 0xA83), (0xA85, 0xA8D), (0xA8F, 0xA91),
+        (0xA93, 0xAA8), (0xAAA, 0xAB0), (0xAB2, 0xAB3), (0xAB5, 0xAB9), (0xABD, 0xAC5), (0xAC7, 0xAC9),
+        (0xACB, 0xACC), (0xAD0, 0xAD0), (0xAE0, 0xAE3), (0xAF9, 0xAFC), (0xB01, 0xB03), (0xB05, 0xB0C),
+        (0xB0F, 0xB10), (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33), (0xB35, 0xB39), (0xB3D, 0xB44),
+        (0xB47, 0xB48), (0xB4B, 0xB4C), (0xB56, 0xB57), (0xB5C, 0xB5D), (0xB5F, 0xB63), (0xB71, 0xB71),
+        (0xB82, 0xB83), (0xB85, 0xB8A), (0xB8E, 0xB90), (0xB92, 0xB95), (0xB99, 0xB9A), (0xB9C, 0xB9C),
+        (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA), (0xBAE, 0xBB9), (0xBBE, 0xBC2), (0xBC6, 0xBC8),
+        (0xBCA, 0xBCC), (0xBD0, 0xBD0), (0xBD7, 0xBD7), (0xC00, 0xC0C), (0xC0E, 0xC10), (0xC12, 0xC28),
+        (0xC2A, 0xC39), (0xC3D, 0xC44), (0xC46, 0xC48), (0xC4A, 0xC4C), (0xC55, 0xC56), (0xC58, 0xC5A),
+        (0xC5C, 0xC5D), (0xC60, 0xC63), (0xC80, 0xC83), (0xC85, 0xC8C), (0xC8E, 0xC90), (0xC92, 0xCA8),
+        (0xCAA, 0xCB3), (0xCB5, 0xCB9), (0xCBD, 0xCC4), (0xCC6, 0xCC8), (0xCCA, 0xCCC), (0xCD5, 0xCD6),
+        (0xCDC, 0xCDE), (0xCE0, 0xCE3), (0xCF1, 0xCF3), (0xD00, 0xD0C), (0xD0E, 0xD10), (0xD12, 0xD3A),
+        (0xD3D, 0xD44), (0xD46, 0xD48), (0xD4A, 0xD4C), (0xD4E, 0xD4E), (0xD54, 0xD57), (0xD5F, 0xD63),
+        (0xD7A, 0xD7F), (0xD81, 0xD83), (0xD85, 0xD96), (0xD9A, 0xDB1), (0xDB3, 0xDBB), (0xDBD, 0xDBD),
+        (0xDC0, 0xDC6), (0xDCF, 0xDD4), (0xDD6, 0xDD6), (0xDD8, 0xDDF), (0xDF2, 0xDF3), (0xE01, 0xE3A),
+        (0xE40, 0xE46), (0xE4D, 0xE4D), (0xE81, 0xE82), (0xE84, 0xE84), (0xE86, 0xE8A), (0xE8C, 0xEA3),
+        (0xEA5, 0xEA5), (0xEA7, 0xEB9), (0xEBB, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6), (0xECD, 0xECD),
+        (0xEDC, 0xEDF), (0xF00, 0xF00), (0xF40, 0xF47), (0xF49, 0xF6C), (0xF71, 0xF83), (0xF88, 0xF97),
+        (0xF99, 0xFBC), (0x1000, 0x1036), (0x1038, 0x1038), (0x103B, 0x103F), (0x1050, 0x108F), (0x109A, 0x109D),
+        (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD), (0x10D0, 0x10FA), (0x10FC, 0x1248), (0x124A, 0x124D),
+        (0x1250, 0x1256), (0x1258, 0x1258), (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0),
+        (0x12B2, 0x12B5), (0x12B8, 0x12BE), (0x12C0, 0x12C0), (0x12C2, 0x12C5), (0x12C8, 0x12D6), (0x12D8, 0x1310),
+        (0x1312, 0x1315), (0x1318, 0x135A), (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1401, 0x166C),
+        (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16EE, 0x16F8), (0x1700, 0x1713), (0x171F, 0x1733),
+        (0x1740, 0x1753), (0x1760, 0x176C), (0x176E, 0x1770), (0x1772, 0x1773), (0x1780, 0x17B3), (0x17B6, 0x17C8),
+        (0x17D7, 0x17D7), (0x17DC, 0x17DC), (0x1820, 0x1878), (0x1880, 0x18AA), (0x18B0, 0x18F5), (0x1900, 0x191E),
+        (0x1920, 0x192B), (0x1930, 0x1938), (0x1950, 0x196D), (0x1970, 0x1974), (0x1980, 0x19AB), (0x19B0, 0x19C9),
+        (0x1A00, 0x1A1B), (0x1A20, 0x1A5E), (0x1A61, 0x1A74), (0x1AA7, 0x1AA7), (0x1ABF, 0x1AC0), (0x1ACC, 0x1ACE),
+        (0x1B00, 0x1B33), (0x1B35, 0x1B43), (0x1B45, 0x1B4C), (0x1B80, 0x1BA9), (0x1BAC, 0x1BAF), (0x1BBA, 0x1BE5),
+        (0x1BE7, 0x1BF1), (0x1C00, 0x1C36), (0x1C4D, 0x1C4F), (0x1C5A, 0x1C7D), (0x1C80, 0x1C8A), (0x1C90, 0x1CBA),
+        (0x1CBD, 0x1CBF), (0x1CE9, 0x1CEC), (0x1CEE, 0x1CF3), (0x1CF5, 0x1CF6), (0x1CFA, 0x1CFA), (0x1D00, 0x1DBF),
+        (0x1DD3, 0x1DF4), (0x1E00, 0x1F15), (0x1F18, 0x1F1D), (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57),
+        (0x1F59, 0x1F59), (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D), (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC),
+        (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC), (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC),
+        (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFC), (0x2071, 0x2071), (0x207F, 0x207F), (0x2090, 0x209C), (0x2102, 0x2102),
+        (0x2107, 0x2107), (0x210A, 0x2113), (0x2115, 0x2115), (0x2119, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126),
+        (0x2128, 0x2128), (0x212A, 0x212D), (0x212F, 0x2139), (0x213C, 0x213F), (0x2145, 0x2149), (0x214E, 0x214E),
+        (0x2160, 0x2188), (0x24B6, 0x24E9), (0x2C00, 0x2CE4), (0x2CEB, 0x2CEE), (0x2CF2, 0x2CF3), (0x2D00, 0x2D25),
+        (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0x2D30, 0x2D67), (0x2D6F, 0x2D6F), (0x2D80, 0x2D96), (0x2DA0, 0x2DA6),
+        (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6), (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6), (0x2DC8, 0x2DCE), (0x2DD0, 0x2DD6),
+        (0x2DD8, 0x2DDE), (0x2DE0, 0x2DFF), (0x2E2F, 0x2E2F), (0x3005, 0x3007), (0x3021, 0x3029), (0x3031, 0x3035),
+        (0x3038, 0x303C), (0x3041, 0x3096), (0x309D, 0x309F), (0x30A1, 0x30FA), (0x30FC, 0x30FF), (0x3105, 0x312F),
+        (0x3131, 0x318E), (0x31A0, 0x31BF), (0x31F0, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0xA48C), (0xA4D0, 0xA4FD),
+        (0xA500, 0xA60C), (0xA610, 0xA61F), (0xA62A, 0xA62B), (0xA640, 0xA66E), (0xA674, 0xA67B), (0xA67F, 0xA6EF),
+        (0xA717, 0xA71F), (0xA722, 0xA788), (0xA78B, 0xA7DC), (0xA7F1, 0xA805), (0xA807, 0xA827), (0xA840, 0xA873),
+        (0xA880, 0xA8C3), (0xA8C5, 0xA8C5), (0xA8F2, 0xA8F7), (0xA8FB, 0xA8FB), (0xA8FD, 0xA8FF), (0xA90A, 0xA92A),
+        (0xA930, 0xA952), (0xA960, 0xA97C), (0xA980, 0xA9B2), (0xA9B4, 0xA9BF), (0xA9CF, 0xA9CF), (0xA9E0, 0xA9EF),
+        (0xA9FA, 0xA9FE), (0xAA00, 0xAA36), (0xAA40, 0xAA4D), (0xAA60, 0xAA76), (0xAA7A, 0xAABE), (0xAAC0, 0xAAC0),
+        (0xAAC2, 0xAAC2), (0xAADB, 0xAADD), (0xAAE0, 0xAAEF), (0xAAF2, 0xAAF5), (0xAB01, 0xAB06), (0xAB09, 0xAB0E),
+        (0xAB11, 0xAB16), (0xAB20, 0xAB26), (0xAB28, 0xAB2E), (0xAB30, 0xAB5A), (0xAB5C, 0xAB69), (0xAB70, 0xABEA),
+        (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB), (0xF900, 0xFA6D), (0xFA70, 0xFAD9), (0xFB00, 0xFB06),
+        (0xFB13, 0xFB17), (0xFB1D, 0xFB28), (0xFB2A, 0xFB36), (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41),
+        (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7), (0xFDF0, 0xFDFB),
+        (0xFE70, 0xFE74), (0xFE76, 0xFEFC), (0xFF21, 0xFF3A), (0xFF41, 0xFF5A), (0xFF66, 0xFFBE), (0xFFC2, 0xFFC7),
+        (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC), (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A),
+        (0x1003C, 0x1003D), (0x1003F, 0x1004D), (0x10050, 0x1005D), (0x10080, 0x100FA), (0x10140, 0x10174), (0x10280, 0x1029C),
+        (0x102A0, 0x102D0), (0x10300, 0x1031F), (0x1032D, 0x1034A), (0x10350, 0x1037A), (0x10380, 0x1039D), (0x103A0, 0x103C3),
+        (0x103C8, 0x103CF), (0x103D1, 0x103D5), (0x10400, 0x1049D), (0x104B0, 0x104D3), (0x104D8, 0x104FB), (0x10500, 0x10527),
+        (0x10530, 0x10563), (0x10570, 0x1057A), (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1),
+        (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x105C0, 0x105F3), (0x10600, 0x10736), (0x10740, 0x10755),
+        (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10800, 0x10805), (0x10808, 0x10808),
+        (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C), (0x1083F, 0x10855), (0x10860, 0x10876), (0x10880, 0x1089E),
+        (0x108E0, 0x108F2), (0x108F4, 0x108F5), (0x10900, 0x10915), (0x10920, 0x10939), (0x10940, 0x10959), (0x10980, 0x109B7),
+        (0x109BE, 0x109BF), (0x10A00, 0x10A03), (0x10A05, 0x10A06), (0x10A0C, 0x10A13), (0x10A15, 0x10A17), (0x10A19, 0x10A35),
+        (0x10A60, 0x10A7C), (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7), (0x10AC9, 0x10AE4), (0x10B00, 0x10B35), (0x10B40, 0x10B55),
+        (0x10B60, 0x10B72), (0x10B80, 0x10B91), (0x10C00, 0x10C48), (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2), (0x10D00, 0x10D27),
+        (0x10D4A, 0x10D65), (0x10D69, 0x10D69), (0x10D6F, 0x10D85), (0x10E80, 0x10EA9), (0x10EAB, 0x10EAC), (0x10EB0, 0x10EB1),
+        (0x10EC2, 0x10EC7), (0x10EFA, 0x10EFC), (0x10F00, 0x10F1C), (0x10F27, 0x10F27), (0x10F30, 0x10F45), (0x10F70, 0x10F81),
+        (0x10FB0, 0x10FC4), (0x10FE0, 0x10FF6), (0x11000, 0x11045), (0x11071, 0x11075), (0x11080, 0x110B8), (0x110C2, 0x110C2),
+        (0x110D0, 0x110E8), (0x11100, 0x11132), (0x11144, 0x11147), (0x11150, 0x11172), (0x11176, 0x11176), (0x11180, 0x111BF),
+        (0x111C1, 0x111C4), (0x111CE, 0x111CF), (0x111DA, 0x111DA), (0x111DC, 0x111DC), (0x11200, 0x11211), (0x11213, 0x11234),
+        (0x11237, 0x11237), (0x1123E, 0x11241), (0x11280, 0x11286), (0x11288, 0x11288), (0x1128A, 0x1128D), (0x1128F, 0x1129D),
+        (0x1129F, 0x112A8), (0x112B0, 0x112E8), (0x11300, 0x11303), (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328),
+        (0x1132A, 0x11330), (0x11332, 0x11333), (0x11335, 0x11339), (0x1133D, 0x11344), (0x11347, 0x11348), (0x1134B, 0x1134C),
+        (0x11350, 0x11350), (0x11357, 0x11357), (0x1135D, 0x11363), (0x11380, 0x11389), (0x1138B, 0x1138B), (0x1138E, 0x1138E),
+        (0x11390, 0x113B5), (0x113B7, 0x113C0), (0x113C2, 0x113C2), (0x113C5, 0x113C5), (0x113C7, 0x113CA), (0x113CC, 0x113CD),
+        (0x113D1, 0x113D1), (0x113D3, 0x113D3), (0x11400, 0x11441), (0x11443, 0x11445), (0x11447, 0x1144A), (0x1145F, 0x11461),
+        (0x11480, 0x114C1), (0x114C4, 0x114C5), (0x114C7, 0x114C7), (0x11580, 0x115B5), (0x115B8, 0x115BE), (0x115D8, 0x115DD),
+        (0x11600, 0x1163E), (0x11640, 0x11640), (0x11644, 0x11644), (0x11680, 0x116B5), (0x116B8, 0x116B8), (0x11700, 0x1171A),
+        (0x1171D, 0x1172A), (0x11740, 0x11746), (0x11800, 0x11838), (0x118A0, 0x118DF), (0x118FF, 0x11906), (0x11909, 0x11909),
+        (0x1190C, 0x11913), (0x11915, 0x11916), (0x11918, 0x11935), (0x11937, 0x11938), (0x1193B, 0x1193C), (0x1193F, 0x11942),
+        (0x119A0, 0x119A7), (0x119AA, 0x119D7), (0x119DA, 0x119DF), (0x119E1, 0x119E1), (0x119E3, 0x119E4), (0x11A00, 0x11A32),
+        (0x11A35, 0x11A3E), (0x11A50, 0x11A97), (0x11A9D, 0x11A9D), (0x11AB0, 0x11AF8), (0x11B60, 0x11B67), (0x11BC0, 0x11BE0),
+        (0x11C00, 0x11C08), (0x11C0A, 0x11C36), (0x11C38, 0x11C3E), (0x11C40, 0x11C40), (0x11C72, 0x11C8F), (0x11C92, 0x11CA7),
+        (0x11CA9, 0x11CB6), (0x11D00, 0x11D06), (0x11D08, 0x11D09), (0x11D0B, 0x11D36), (0x11D3A, 0x11D3A), (0x11D3C, 0x11D3D),
+        (0x11D3F, 0x11D41), (0x11D43, 0x11D43), (0x11D46, 0x11D47), (0x11D60, 0x11D65), (0x11D67, 0x11D68), (0x11D6A, 0x11D8E),
+        (0x11D90, 0x11D91), (0x11D93, 0x11D96), (0x11D98, 0x11D98), (0x11DB0, 0x11DDB), (0x11EE0, 0x11EF6), (0x11F00, 0x11F10),
+        (0x11F12, 0x11F3A), (0x11F3E, 0x11F40), (0x11FB0, 0x11FB0), (0x12000, 0x12399), (0x12400, 0x1246E), (0x12480, 0x12543),
+        (0x12F90, 0x12FF0), (0x13000, 0x1342F), (0x13441, 0x13446), (0x13460, 0x143FA), (0x14400, 0x14646), (0x16100, 0x1612E),
+        (0x16800, 0x16A38), (0x16A40, 0x16A5E), (0x16A70, 0x16ABE), (0x16AD0, 0x16AED), (0x16B00, 0x16B2F), (0x16B40, 0x16B43),
+        (0x16B63, 0x16B77), (0x16B7D, 0x16B8F), (0x16D40, 0x16D6C), (0x16E40, 0x16E7F), (0x16EA0, 0x16EB8), (0x16EBB, 0x16ED3),
+        (0x16F00, 0x16F4A), (0x16F4F, 0x16F87), (0x16F8F, 0x16F9F), (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE3), (0x16FF0, 0x16FF6),
+        (0x17000, 0x18CD5), (0x18CFF, 0x18D1E), (0x18D80, 0x18DF2), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE),
+        (0x1B000, 0x1B122), (0x1B132, 0x1B132), (0x1B150, 0x1B152), (0x1B155, 0x1B155), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB),
+        (0x1BC00, 0x1BC6A), (0x1BC70, 0x1BC7C), (0x1BC80, 0x1BC88), (0x1BC90, 0x1BC99), (0x1BC9E, 0x1BC9E), (0x1D400, 0x1D454),
+        (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9),
+        (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C),
+        (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546), (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5),
+        (0x1D6A8, 0x1D6C0), (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6FA), (0x1D6FC, 0x1D714), (0x1D716, 0x1D734), (0x1D736, 0x1D74E),
+        (0x1D750, 0x1D76E), (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8), (0x1D7AA, 0x1D7C2), (0x1D7C4, 0x1D7CB), (0x1DF00, 0x1DF1E),
+        (0x1DF25, 0x1DF2A), (0x1E000, 0x1E006), (0x1E008, 0x1E018), (0x1E01B, 0x1E021), (0x1E023, 0x1E024), (0x1E026, 0x1E02A),
+        (0x1E030, 0x1E06D), (0x1E08F, 0x1E08F), (0x1E100, 0x1E12C), (0x1E137, 0x1E13D), (0x1E14E, 0x1E14E), (0x1E290, 0x1E2AD),
+        (0x1E2C0, 0x1E2EB), (0x1E4D0, 0x1E4EB), (0x1E5D0, 0x1E5ED), (0x1E5F0, 0x1E5F0), (0x1E6C0, 0x1E6DE), (0x1E6E0, 0x1E6F5),
+        (0x1E6FE, 0x1E6FF), (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB), (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4),
+        (0x1E900, 0x1E943), (0x1E947, 0x1E947), (0x1E94B, 0x1E94B), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22),
+        (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B),
+        (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47), (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52),
+        (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F),
+        (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64), (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C),
+        (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB),
+        (0x1F130, 0x1F149), (0x1F150, 0x1F169), (0x1F170, 0x1F189), (0x20000, 0x2A6DF), (0x2A700, 0x2B81D), (0x2B820, 0x2CEAD),
+        (0x2CEB0, 0x2EBE0), (0x2EBF0, 0x2EE5D), (0x2F800, 0x2FA1D), (0x30000, 0x3134A), (0x31350, 0x33479),
+    ]),
+    ("Any", "", &[
+        (0x0, 0x10FFFF),
+    ]),
+    ("Assigned", "", &[
+        (0x0, 0x377), (0x37A, 0x37F), (0x384, 0x38A), (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x52F),
+        (0x531, 0x556), (0x559, 0x58A), (0x58D, 0x58F), (0x591, 0x5C7), (0x5D0, 0x5EA), (0x5EF, 0x5F4),
+        (0x600, 0x70D), (0x70F, 0x74A), (0x74D, 0x7B1), (0x7C0, 0x7FA), (0x7FD, 0x82D), (0x830, 0x83E),
+        (0x840, 0x85B), (0x85E, 0x85E), (0x860, 0x86A), (0x870, 0x891), (0x897, 0x983), (0x985, 0x98C),
+        (0x98F, 0x990), (0x993, 0x9A8), (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9), (0x9BC, 0x9C4),
+        (0x9C7, 0x9C8), (0x9CB, 0x9CE), (0x9D7, 0x9D7), (0x9DC, 0x9DD), (0x9DF, 0x9E3), (0x9E6, 0x9FE),
+        (0xA01, 0xA03), (0xA05, 0xA0A), (0xA0F, 0xA10), (0xA13, 0xA28), (0xA2A, 0xA30), (0xA32, 0xA33),
+        (0xA35, 0xA36), (0xA38, 0xA39), (0xA3C, 0xA3C), (0xA3E, 0xA42), (0xA47, 0xA48), (0xA4B, 0xA4D),
+        (0xA51, 0xA51), (0xA59, 0xA5C), (0xA5E, 0xA5E), (0xA66, 0xA76), (0xA81, 0xA83), (0xA85, 0xA8D),
+        (0xA8F, 0xA91), (0xA93, 0xAA8), (0xAAA, 0xAB0), (0xAB2, 0xAB3), (0xAB5, 0xAB9), (0xABC, 0xAC5),
+        (0xAC7, 0xAC9), (0xACB, 0xACD), (0xAD0, 0xAD0), (0xAE0, 0xAE3), (0xAE6, 0xAF1), (0xAF9, 0xAFF),
+        (0xB01, 0xB03), (0xB05, 0xB0C), (0xB0F, 0xB10), (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33),
+        (0xB35, 0xB39), (0xB3C, 0xB44), (0xB47, 0xB48), (0xB4B, 0xB4D), (0xB55, 0xB57), (0xB5C, 0xB5D),
+        (0xB5F, 0xB63), (0xB66, 0xB77), (0xB82, 0xB83), (0xB85, 0xB8A), (0xB8E, 0xB90), (0xB92, 0xB95),
+        (0xB99, 0xB9A), (0xB9C, 0xB9C), (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA), (0xBAE, 0xBB9),
+        (0xBBE, 0xBC2), (0xBC6, 0xBC8), (0xBCA, 0xBCD), (0xBD0, 0xBD0), (0xBD7, 0xBD7), (0xBE6, 0xBFA),
+        (0xC00, 0xC0C), (0xC0E, 0xC10), (0xC12, 0xC28), (0xC2A, 0xC39), (0xC3C, 0xC44), (0xC46, 0xC48),
+        (0xC4A, 0xC4D), (0xC55, 0xC56), (0xC58, 0xC5A), (0xC5C, 0xC5D), (0xC60, 0xC63), (0xC66, 0xC6F),
+        (0xC77, 0xC8C), (0xC8E, 0xC90), (0xC92, 0xCA8), (0xCAA, 0xCB3), (0xCB5, 0xCB9), (0xCBC, 0xCC4),
+        (0xCC6, 0xCC8), (0xCCA, 0xCCD), (0xCD5, 0xCD6), (0xCDC, 0xCDE), (0xCE0, 0xCE3), (0xCE6, 0xCEF),
+        (0xCF1, 0xCF3), (0xD00, 0xD0C), (0xD0E, 0xD10), (0xD12, 0xD44), (0xD46, 0xD48), (0xD4A, 0xD4F),
+        (0xD54, 0xD63), (0xD66, 0xD7F), (0xD81, 0xD83), (0xD85, 0xD96), (0xD9A, 0xDB1), (0xDB3, 0xDBB),
+        (0xDBD, 0xDBD), (0xDC0, 0xDC6), (0xDCA, 0xDCA), (0xDCF, 0xDD4), (0xDD6, 0xDD6), (0xDD8, 0xDDF),
+        (0xDE6, 0xDEF), (0xDF2, 0xDF4), (0xE01, 0xE3A), (0xE3F, 0xE5B), (0xE81, 0xE82), (0xE84, 0xE84),
+        (0xE86, 0xE8A), (0xE8C, 0xEA3), (0xEA5, 0xEA5), (0xEA7, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6),
+        (0xEC8, 0xECE), (0xED0, 0xED9), (0xEDC, 0xEDF), (0xF00, 0xF47), (0xF49, 0xF6C), (0xF71, 0xF97),
+        (0xF99, 0xFBC), (0xFBE, 0xFCC), (0xFCE, 0xFDA), (0x1000, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD),
+        (0x10D0, 0x1248), (0x124A, 0x124D), (0x1250, 0x1256), (0x1258, 0x1258), (0x125A, 0x125D), (0x1260, 0x1288),
+        (0x128A, 0x128D), (0x1290, 0x12B0), (0x12B2, 0x12B5), (0x12B8, 0x12BE), (0x12C0, 0x12C0), (0x12C2, 0x12C5),
+        (0x12C8, 0x12D6), (0x12D8, 0x1310), (0x1312, 0x1315), (0x1318, 0x135A), (0x135D, 0x137C), (0x1380, 0x1399),
+        (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1400, 0x169C), (0x16A0, 0x16F8), (0x1700, 0x1715), (0x171F, 0x1736),
+        (0x1740, 0x1753), (0x1760, 0x176C), (0x176E, 0x1770), (0x1772, 0x1773), (0x1780, 0x17DD), (0x17E0, 0x17E9),
+        (0x17F0, 0x17F9), (0x1800, 0x1819), (0x1820, 0x1878), (0x1880, 0x18AA), (0x18B0, 0x18F5), (0x1900, 0x191E),
+        (0x1920, 0x192B), (0x1930, 0x193B), (0x1940, 0x1940), (0x1944, 0x196D), (0x1970, 0x1974), (0x1980, 0x19AB),
+        (0x19B0, 0x19C9), (0x19D0, 0x19DA), (0x19DE, 0x1A1B), (0x1A1E, 0x1A5E), (0x1A60, 0x1A7C), (0x1A7F, 0x1A89),
+        (0x1A90, 0x1A99), (0x1AA0, 0x1AAD), (0x1AB0, 0x1ADD), (0x1AE0, 0x1AEB), (0x1B00, 0x1B4C), (0x1B4E, 0x1BF3),
+        (0x1BFC, 0x1C37), (0x1C3B, 0x1C49), (0x1C4D, 0x1C8A), (0x1C90, 0x1CBA), (0x1CBD, 0x1CC7), (0x1CD0, 0x1CFA),
+        (0x1D00, 0x1F15), (0x1F18, 0x1F1D), (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59),
+        (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D), (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FC4), (0x1FC6, 0x1FD3),
+        (0x1FD6, 0x1FDB), (0x1FDD, 0x1FEF), (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFE), (0x2000, 0x2064), (0x2066, 0x2071),
+        (0x2074, 0x208E), (0x2090, 0x209C), (0x20A0, 0x20C1), (0x20D0, 0x20F0), (0x2100, 0x218B), (0x2190, 0x2429),
+        (0x2440, 0x244A), (0x2460, 0x2B73), (0x2B76, 0x2CF3), (0x2CF9, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D),
+        (0x2D30, 0x2D67), (0x2D6F, 0x2D70), (0x2D7F, 0x2D96), (0x2DA0, 0x2DA6), (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6),
+        (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6), (0x2DC8, 0x2DCE), (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE), (0x2DE0, 0x2E5D),
+        (0x2E80, 0x2E99), (0x2E9B, 0x2EF3), (0x2F00, 0x2FD5), (0x2FF0, 0x303F), (0x3041, 0x3096), (0x3099, 0x30FF),
+        (0x3105, 0x312F), (0x3131, 0x318E), (0x3190, 0x31E5), (0x31EF, 0x321E), (0x3220, 0xA48C), (0xA490, 0xA4C6),
+        (0xA4D0, 0xA62B), (0xA640, 0xA6F7), (0xA700, 0xA7DC), (0xA7F1, 0xA82C), (0xA830, 0xA839), (0xA840, 0xA877),
+        (0xA880, 0xA8C5), (0xA8CE, 0xA8D9), (0xA8E0, 0xA953), (0xA95F, 0xA97C), (0xA980, 0xA9CD), (0xA9CF, 0xA9D9),
+        (0xA9DE, 0xA9FE), (0xAA00, 0xAA36), (0xAA40, 0xAA4D), (0xAA50, 0xAA59), (0xAA5C, 0xAAC2), (0xAADB, 0xAAF6),
+        (0xAB01, 0xAB06), (0xAB09, 0xAB0E), (0xAB11, 0xAB16), (0xAB20, 0xAB26), (0xAB28, 0xAB2E), (0xAB30, 0xAB6B),
+        (0xAB70, 0xABED), (0xABF0, 0xABF9), (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB), (0xD800, 0xFA6D),
+        (0xFA70, 0xFAD9), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFB1D, 0xFB36), (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E),
+        (0xFB40, 0xFB41), (0xFB43, 0xFB44), (0xFB46, 0xFDCF), (0xFDF0, 0xFE19), (0xFE20, 0xFE52), (0xFE54, 0xFE66),
+        (0xFE68, 0xFE6B), (0xFE70, 0xFE74), (0xFE76, 0xFEFC), (0xFEFF, 0xFEFF), (0xFF01, 0xFFBE), (0xFFC2, 0xFFC7),
+        (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC), (0xFFE0, 0xFFE6), (0xFFE8, 0xFFEE), (0xFFF9, 0xFFFD),
+        (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A), (0x1003C, 0x1003D), (0x1003F, 0x1004D), (0x10050, 0x1005D),
+        (0x10080, 0x100FA), (0x10100, 0x10102), (0x10107, 0x10133), (0x10137, 0x1018E), (0x10190, 0x1019C), (0x101A0, 0x101A0),
+        (0x101D0, 0x101FD), (0x10280, 0x1029C), (0x102A0, 0x102D0), (0x102E0, 0x102FB), (0x10300, 0x10323), (0x1032D, 0x1034A),
+        (0x10350, 0x1037A), (0x10380, 0x1039D), (0x1039F, 0x103C3), (0x103C8, 0x103D5), (0x10400, 0x1049D), (0x104A0, 0x104A9),
+        (0x104B0, 0x104D3), (0x104D8, 0x104FB), (0x10500, 0x10527), (0x10530, 0x10563), (0x1056F, 0x1057A), (0x1057C, 0x1058A),
+        (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1), (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC),
+        (0x105C0, 0x105F3), (0x10600, 0x10736), (0x10740, 0x10755), (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107B0),
+        (0x107B2, 0x107BA), (0x10800, 0x10805), (0x10808, 0x10808), (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C),
+        (0x1083F, 0x10855), (0x10857, 0x1089E), (0x108A7, 0x108AF), (0x108E0, 0x108F2), (0x108F4, 0x108F5), (0x108FB, 0x1091B),
+        (0x1091F, 0x10939), (0x1093F, 0x10959), (0x10980, 0x109B7), (0x109BC, 0x109CF), (0x109D2, 0x10A03), (0x10A05, 0x10A06),
+        (0x10A0C, 0x10A13), (0x10A15, 0x10A17), (0x10A19, 0x10A35), (0x10A38, 0x10A3A), (0x10A3F, 0x10A48), (0x10A50, 0x10A58),
+        (0x10A60, 0x10A9F), (0x10AC0, 0x10AE6), (0x10AEB, 0x10AF6), (0x10B00, 0x10B35), (0x10B39, 0x10B55), (0x10B58, 0x10B72),
+        (0x10B78, 0x10B91), (0x10B99, 0x10B9C), (0x10BA9, 0x10BAF), (0x10C00, 0x10C48), (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2),
+        (0x10CFA, 0x10D27), (0x10D30, 0x10D39), (0x10D40, 0x10D65), (0x10D69, 0x10D85), (0x10D8E, 0x10D8F), (0x10E60, 0x10E7E),
+        (0x10E80, 0x10EA9), (0x10EAB, 0x10EAD), (0x10EB0, 0x10EB1), (0x10EC2, 0x10EC7), (0x10ED0, 0x10ED8), (0x10EFA, 0x10F27),
+        (0x10F30, 0x10F59), (0x10F70, 0x10F89), (0x10FB0, 0x10FCB), (0x10FE0, 0x10FF6), (0x11000, 0x1104D), (0x11052, 0x11075),
+        (0x1107F, 0x110C2), (0x110CD, 0x110CD), (0x110D0, 0x110E8), (0x110F0, 0x110F9), (0x11100, 0x11134), (0x11136, 0x11147),
+        (0x11150, 0x11176), (0x11180, 0x111DF), (0x111E1, 0x111F4), (0x11200, 0x11211), (0x11213, 0x11241), (0x11280, 0x11286),
+        (0x11288, 0x11288), (0x1128A, 0x1128D), (0x1128F, 0x1129D), (0x1129F, 0x112A9), (0x112B0, 0x112EA), (0x112F0, 0x112F9),
+        (0x11300, 0x11303), (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328), (0x1132A, 0x11330), (0x11332, 0x11333),
+        (0x11335, 0x11339), (0x1133B, 0x11344), (0x11347, 0x11348), (0x1134B, 0x1134D), (0x11350, 0x11350), (0x11357, 0x11357),
+        (0x1135D, 0x11363), (0x11366, 0x1136C), (0x11370, 0x11374), (0x11380, 0x11389), (0x1138B, 0x1138B), (0x1138E, 0x1138E),
+        (0x11390, 0x113B5), (0x113B7, 0x113C0), (0x113C2, 0x113C2), (0x113C5, 0x113C5), (0x113C7, 0x113CA), (0x113CC, 0x113D5),
+        (0x113D7, 0x113D8), (0x113E1, 0x113E2), (0x11400, 0x1145B), (0x1145D, 0x11461), (0x11480, 0x114C7), (0x114D0, 0x114D9),
+        (0x11580, 0x115B5), (0x115B8, 0x115DD), (0x11600, 0x11644), (0x11650, 0x11659), (0x11660, 0x1166C), (0x11680, 0x116B9),
+        (0x116C0, 0x116C9), (0x116D0, 0x116E3), (0x11700, 0x1171A), (0x1171D, 0x1172B), (0x11730, 0x11746), (0x11800, 0x1183B),
+        (0x118A0, 0x118F2), (0x118FF, 0x11906), (0x11909, 0x11909), (0x1190C, 0x11913), (0x11915, 0x11916), (0x11918, 0x11935),
+        (0x11937, 0x11938), (0x1193B, 0x11946), (0x11950, 0x11959), (0x119A0, 0x119A7), (0x119AA, 0x119D7), (0x119DA, 0x119E4),
+        (0x11A00, 0x11A47), (0x11A50, 0x11AA2), (0x11AB0, 0x11AF8), (0x11B00, 0x11B09), (0x11B60, 0x11B67), (0x11BC0, 0x11BE1),
+        (0x11BF0, 0x11BF9), (0x11C00, 0x11C08), (0x11C0A, 0x11C36), (0x11C38, 0x11C45), (0x11C50, 0x11C6C), (0x11C70, 0x11C8F),
+        (0x11C92, 0x11CA7), (0x11CA9, 0x11CB6), (0x11D00, 0x11D06), (0x11D08, 0x11D09), (0x11D0B, 0x11D36), (0x11D3A, 0x11D3A),
+        (0x11D3C, 0x11D3D), (0x11D3F, 0x11D47), (0x11D50, 0x11D59), (0x11D60, 0x11D65), (0x11D67, 0x11D68), (0x11D6A, 0x11D8E),
+        (0x11D90, 0x11D91), (0x11D93, 0x11D98), (0x11DA0, 0x11DA9), (0x11DB0, 0x11DDB), (0x11DE0, 0x11DE9), (0x11EE0, 0x11EF8),
+        (0x11F00, 0x11F10), (0x11F12, 0x11F3A), (0x11F3E, 0x11F5A), (0x11FB0, 0x11FB0), (0x11FC0, 0x11FF1), (0x11FFF, 0x12399),
+        (0x12400, 0x1246E), (0x12470, 0x12474), (0x12480, 0x12543), (0x12F90, 0x12FF2), (0x13000, 0x13455), (0x13460, 0x143FA),
+        (0x14400, 0x14646), (0x16100, 0x16139), (0x16800, 0x16A38), (0x16A40, 0x16A5E), (0x16A60, 0x16A69), (0x16A6E, 0x16ABE),
+        (0x16AC0, 0x16AC9), (0x16AD0, 0x16AED), (0x16AF0, 0x16AF5), (0x16B00, 0x16B45), (0x16B50, 0x16B59), (0x16B5B, 0x16B61),
+        (0x16B63, 0x16B77), (0x16B7D, 0x16B8F), (0x16D40, 0x16D79), (0x16E40, 0x16E9A), (0x16EA0, 0x16EB8), (0x16EBB, 0x16ED3),
+        (0x16F00, 0x16F4A), (0x16F4F, 0x16F87), (0x16F8F, 0x16F9F), (0x16FE0, 0x16FE4), (0x16FF0, 0x16FF6), (0x17000, 0x18CD5),
+        (0x18CFF, 0x18D1E), (0x18D80, 0x18DF2), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122),
+        (0x1B132, 0x1B132), (0x1B150, 0x1B152), (0x1B155, 0x1B155), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A),
+        (0x1BC70, 0x1BC7C), (0x1BC80, 0x1BC88), (0x1BC90, 0x1BC99), (0x1BC9C, 0x1BCA3), (0x1CC00, 0x1CCFC), (0x1CD00, 0x1CEB3),
+        (0x1CEBA, 0x1CED0), (0x1CEE0, 0x1CEF0), (0x1CF00, 0x1CF2D), (0x1CF30, 0x1CF46), (0x1CF50, 0x1CFC3), (0x1D000, 0x1D0F5),
+        (0x1D100, 0x1D126), (0x1D129, 0x1D1EA), (0x1D200, 0x1D245), (0x1D2C0, 0x1D2D3), (0x1D2E0, 0x1D2F3), (0x1D300, 0x1D356),
+        (0x1D360, 0x1D378), (0x1D400, 0x1D454), (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6),
+        (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A),
+        (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546),
+        (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D7CB), (0x1D7CE, 0x1DA8B), (0x1DA9B, 0x1DA9F), (0x1DAA1, 0x1DAAF),
+        (0x1DF00, 0x1DF1E), (0x1DF25, 0x1DF2A), (0x1E000, 0x1E006), (0x1E008, 0x1E018), (0x1E01B, 0x1E021), (0x1E023, 0x1E024),
+        (0x1E026, 0x1E02A), (0x1E030, 0x1E06D), (0x1E08F, 0x1E08F), (0x1E100, 0x1E12C), (0x1E130, 0x1E13D), (0x1E140, 0x1E149),
+        (0x1E14E, 0x1E14F), (0x1E290, 0x1E2AE), (0x1E2C0, 0x1E2F9), (0x1E2FF, 0x1E2FF), (0x1E4D0, 0x1E4F9), (0x1E5D0, 0x1E5FA),
+        (0x1E5FF, 0x1E5FF), (0x1E6C0, 0x1E6DE), (0x1E6E0, 0x1E6F5), (0x1E6FE, 0x1E6FF), (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB),
+        (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4), (0x1E8C7, 0x1E8D6), (0x1E900, 0x1E94B), (0x1E950, 0x1E959),
+        (0x1E95E, 0x1E95F), (0x1EC71, 0x1ECB4), (0x1ED01, 0x1ED3D), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22),
+        (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B),
+        (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47), (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52),
+        (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F),
+        (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64), (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C),
+        (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB),
+        (0x1EEF0, 0x1EEF1), (0x1F000, 0x1F02B), (0x1F030, 0x1F093), (0x1F0A0, 0x1F0AE), (0x1F0B1, 0x1F0BF), (0x1F0C1, 0x1F0CF),
+        (0x1F0D1, 0x1F0F5), (0x1F100, 0x1F1AD), (0x1F1E6, 0x1F202), (0x1F210, 0x1F23B), (0x1F240, 0x1F248), (0x1F250, 0x1F251),
+        (0x1F260, 0x1F265), (0x1F300, 0x1F6D8), (0x1F6DC, 0x1F6EC), (0x1F6F0, 0x1F6FC), (0x1F700, 0x1F7D9), (0x1F7E0, 0x1F7EB),
+        (0x1F7F0, 0x1F7F0), (0x1F800, 0x1F80B), (0x1F810, 0x1F847), (0x1F850, 0x1F859), (0x1F860, 0x1F887), (0x1F890, 0x1F8AD),
+        (0x1F8B0, 0x1F8BB), (0x1F8C0, 0x1F8C1), (0x1F8D0, 0x1F8D8), (0x1F900, 0x1FA57), (0x1FA60, 0x1FA6D), (0x1FA70, 0x1FA7C),
+        (0x1FA80, 0x1FA8A), (0x1FA8E, 0x1FAC6), (0x1FAC8, 0x1FAC8), (0x1FACD, 0x1FADC), (0x1FADF, 0x1FAEA), (0x1FAEF, 0x1FAF8),
+        (0x1FB00, 0x1FB92), (0x1FB94, 0x1FBFA), (0x20000, 0x2A6DF), (0x2A700, 0x2B81D), (0x2B820, 0x2CEAD), (0x2CEB0, 0x2EBE0),
+        (0x2EBF0, 0x2EE5D), (0x2F800, 0x2FA1D), (0x30000, 0x3134A), (0x31350, 0x33479), (0xE0001, 0xE0001), (0xE0020, 0xE007F),
+        (0xE0100, 0xE01EF), (0xF0000, 0xFFFFD), (0x100000, 0x10FFFD),
+    ]),
+    ("Bidi_Control", "Bidi_C", &[
+        (0x61C, 0x61C), (0x200E, 0x200F), (0x202A, 0x202E), (0x2066, 0x2069),
+    ]),
+    ("Bidi_Mirrored", "Bidi_M", &[
+        (0x28, 0x29), (0x3C, 0x3C), (0x3E, 0x3E), (0x5B, 0x5B), (0x5D, 0x5D), (0x7B, 0x7B),
+        (0x7D, 0x7D), (0xAB, 0xAB), (0xBB, 0xBB), (0xF3A, 0xF3D), (0x169B, 0x169C), (0x2039, 0x203A),
+        (0x2045, 0x2046), (0x207D, 0x207E), (0x208D, 0x208E), (0x2140, 0x2140), (0x2201, 0x2204), (0x2208, 0x220D),
+        (0x2211, 0x2211), (0x2215, 0x2216), (0x221A, 0x221D), (0x221F, 0x2222), (0x2224, 0x2224), (0x2226, 0x2226),
+        (0x222B, 0x2233), (0x2239, 0x2239), (0x223B, 0x224C), (0x2252, 0x2255), (0x225F, 0x2260), (0x2262, 0x2262),
+        (0x2264, 0x226B), (0x226D, 0x228C), (0x228F, 0x2292), (0x2298, 0x2298), (0x22A2, 0x22A3), (0x22A6, 0x22B8),
+        (0x22BE, 0x22BF), (0x22C9, 0x22CD), (0x22D0, 0x22D1), (0x22D6, 0x22ED), (0x22F0, 0x22FF), (0x2308, 0x230B),
+        (0x2320, 0x2321), (0x2329, 0x232A), (0x2768, 0x2775), (0x27C0, 0x27C0), (0x27C3, 0x27C6), (0x27C8, 0x27C9),
+        (0x27CB, 0x27CD), (0x27D3, 0x27D6), (0x27DC, 0x27DE), (0x27E2, 0x27EF), (0x2983, 0x2998), (0x299B, 0x29A0),
+        (0x29A2, 0x29AF), (0x29B8, 0x29B8), (0x29C0, 0x29C5), (0x29C9, 0x29C9), (0x29CE, 0x29D2), (0x29D4, 0x29D5),
+        (0x29D8, 0x29DC), (0x29E1, 0x29E1), (0x29E3, 0x29E5), (0x29E8, 0x29E9), (0x29F4, 0x29F9), (0x29FC, 0x29FD),
+        (0x2A0A, 0x2A1C), (0x2A1E, 0x2A21), (0x2A24, 0x2A24), (0x2A26, 0x2A26), (0x2A29, 0x2A29), (0x2A2B, 0x2A2E),
+        (0x2A34, 0x2A35), (0x2A3C, 0x2A3E), (0x2A57, 0x2A58), (0x2A64, 0x2A65), (0x2A6A, 0x2A6D), (0x2A6F, 0x2A70),
+        (0x2A73, 0x2A74), (0x2A79, 0x2AA3), (0x2AA6, 0x2AAD), (0x2AAF, 0x2AD6), (0x2ADC, 0x2ADC), (0x2ADE, 0x2ADE),
+        (0x2AE2, 0x2AE6), (0x2AEC, 0x2AEE), (0x2AF3, 0x2AF3), (0x2AF7, 0x2AFB), (0x2AFD, 0x2AFD), (0x2BFE, 0x2BFE),
+        (0x2E02, 0x2E05), (0x2E09, 0x2E0A), (0x2E0C, 0x2E0D), (0x2E1C, 0x2E1D), (0x2E20, 0x2E29), (0x2E55, 0x2E5C),
+        (0x3008, 0x3011), (0x3014, 0x301B), (0xFE59, 0xFE5E), (0xFE64, 0xFE65), (0xFF08, 0xFF09), (0xFF1C, 0xFF1C),
+        (0xFF1E, 0xFF1E), (0xFF3B, 0xFF3B), (0xFF3D, 0xFF3D), (0xFF5B, 0xFF5B), (0xFF5D, 0xFF5D), (0xFF5F, 0xFF60),
+        (0xFF62, 0xFF63), (0x1D6DB, 0x1D6DB), (0x1D715, 0x1D715), (0x1D74F, 0x1D74F), (0x1D789, 0x1D789), (0x1D7C3, 0x1D7C3),
+    ]),
+    ("Case_Ignorable", "CI", &[
+        (0x27, 0x27), (0x2E, 0x2E), (0x3A, 0x3A), (0x5E, 0x5E), (0x60, 0x60), (0xA8, 0xA8),
+        (0xAD, 0xAD), (0xAF, 0xAF), (0xB4, 0xB4), (0xB7, 0xB8), (0x2B0, 0x36F), (0x374, 0x375),
+        (0x37A, 0x37A), (0x384, 0x385), (0x387, 0x387), (0x483, 0x489), (0x559, 0x559), (0x55F, 0x55F),
+        (0x591, 0x5BD), (0x5BF, 0x5BF), (0x5C1, 0x5C2), (0x5C4, 0x5C5), (0x5C7, 0x5C7), (0x5F4, 0x5F4),
+        (0x600, 0x605), (0x610, 0x61A), (0x61C, 0x61C), (0x640, 0x640), (0x64B, 0x65F), (0x670, 0x670),
+        (0x6D6, 0x6DD), (0x6DF, 0x6E8), (0x6EA, 0x6ED), (0x70F, 0x70F), (0x711, 0x711), (0x730, 0x74A),
+        (0x7A6, 0x7B0), (0x7EB, 0x7F5), (0x7FA, 0x7FA), (0x7FD, 0x7FD), (0x816, 0x82D), (0x859, 0x85B),
+        (0x888, 0x888), (0x890, 0x891), (0x897, 0x89F), (0x8C9, 0x902), (0x93A, 0x93A), (0x93C, 0x93C),
+        (0x941, 0x948), (0x94D, 0x94D), (0x951, 0x957), (0x962, 0x963), (0x971, 0x971), (0x981, 0x981),
+        (0x9BC, 0x9BC), (0x9C1, 0x9C4), (0x9CD, 0x9CD), (0x9E2, 0x9E3), (0x9FE, 0x9FE), (0xA01, 0xA02),
+        (0xA3C, 0xA3C), (0xA41, 0xA42), (0xA47, 0xA48), (0xA4B, 0xA4D), (0xA51, 0xA51), (0xA70, 0xA71),
+        (0xA75, 0xA75), (0xA81, 0xA82), (0xABC, 0xABC), (0xAC1, 0xAC5), (0xAC7, 0xAC8), (0xACD, 0xACD),
+        (0xAE2, 0xAE3), (0xAFA, 0xAFF), (0xB01, 0xB01), (0xB3C, 0xB3C), (0xB3F, 0xB3F), (0xB41, 0xB44),
+        (0xB4D, 0xB4D), (0xB55, 0xB56), (0xB62, 0xB63), (0xB82, 0xB82), (0xBC0, 0xBC0), (0xBCD, 0xBCD),
+        (0xC00, 0xC00), (0xC04, 0xC04), (0xC3C, 0xC3C), (0xC3E, 0xC40), (0xC46, 0xC48), (0xC4A, 0xC4D),
+        (0xC55, 0xC56), (0xC62, 0xC63), (0xC81, 0xC81), (0xCBC, 0xCBC), (0xCBF, 0xCBF), (0xCC6, 0xCC6),
+        (0xCCC, 0xCCD), (0xCE2, 0xCE3), (0xD00, 0xD01), (0xD3B, 0xD3C), (0xD41, 0xD44), (0xD4D, 0xD4D),
+        (0xD62, 0xD63), (0xD81, 0xD81), (0xDCA, 0xDCA), (0xDD2, 0xDD4), (0xDD6, 0xDD6), (0xE31, 0xE31),
+        (0xE34, 0xE3A), (0xE46, 0xE4E), (0xEB1, 0xEB1), (0xEB4, 0xEBC), (0xEC6, 0xEC6), (0xEC8, 0xECE),
+        (0xF18, 0xF19), (0xF35, 0xF35), (0xF37, 0xF37), (0xF39, 0xF39), (0xF71, 0xF7E), (0xF80, 0xF84),
+        (0xF86, 0xF87), (0xF8D, 0xF97), (0xF99, 0xFBC), (0xFC6, 0xFC6), (0x102D, 0x1030), (0x1032, 0x1037),
+        (0x1039, 0x103A), (0x103D, 0x103E), (0x1058, 0x1059), (0x105E, 0x1060), (0x1071, 0x1074), (0x1082, 0x1082),
+        (0x1085, 0x1086), (0x108D, 0x108D), (0x109D, 0x109D), (0x10FC, 0x10FC), (0x135D, 0x135F), (0x1712, 0x1714),
+        (0x1732, 0x1733), (0x1752, 0x1753), (0x1772, 0x1773), (0x17B4, 0x17B5), (0x17B7, 0x17BD), (0x17C6, 0x17C6),
+        (0x17C9, 0x17D3), (0x17D7, 0x17D7), (0x17DD, 0x17DD), (0x180B, 0x180F), (0x1843, 0x1843), (0x1885, 0x1886),
+        (0x18A9, 0x18A9), (0x1920, 0x1922), (0x1927, 0x1928), (0x1932, 0x1932), (0x1939, 0x193B), (0x1A17, 0x1A18),
+        (0x1A1B, 0x1A1B), (0x1A56, 0x1A56), (0x1A58, 0x1A5E), (0x1A60, 0x1A60), (0x1A62, 0x1A62), (0x1A65, 0x1A6C),
+        (0x1A73, 0x1A7C), (0x1A7F, 0x1A7F), (0x1AA7, 0x1AA7), (0x1AB0, 0x1ADD), (0x1AE0, 0x1AEB), (0x1B00, 0x1B03),
+        (0x1B34, 0x1B34), (0x1B36, 0x1B3A), (0x1B3C, 0x1B3C), (0x1B42, 0x1B42), (0x1B6B, 0x1B73), (0x1B80, 0x1B81),
+        (0x1BA2, 0x1BA5), (0x1BA8, 0x1BA9), (0x1BAB, 0x1BAD), (0x1BE6, 0x1BE6), (0x1BE8, 0x1BE9), (0x1BED, 0x1BED),
+        (0x1BEF, 0x1BF1), (0x1C2C, 0x1C33), (0x1C36, 0x1C37), (0x1C78, 0x1C7D), (0x1CD0, 0x1CD2), (0x1CD4, 0x1CE0),
+        (0x1CE2, 0x1CE8), (0x1CED, 0x1CED), (0x1CF4, 0x1CF4), (0x1CF8, 0x1CF9), (0x1D2C, 0x1D6A), (0x1D78, 0x1D78),
+        (0x1D9B, 0x1DFF), (0x1FBD, 0x1FBD), (0x1FBF, 0x1FC1), (0x1FCD, 0x1FCF), (0x1FDD, 0x1FDF), (0x1FED, 0x1FEF),
+        (0x1FFD, 0x1FFE), (0x200B, 0x200F), (0x2018, 0x2019), (0x2024, 0x2024), (0x2027, 0x2027), (0x202A, 0x202E),
+        (0x2060, 0x2064), (0x2066, 0x206F), (0x2071, 0x2071), (0x207F, 0x207F), (0x2090, 0x209C), (0x20D0, 0x20F0),
+        (0x2C7C, 0x2C7D), (0x2CEF, 0x2CF1), (0x2D6F, 0x2D6F), (0x2D7F, 0x2D7F), (0x2DE0, 0x2DFF), (0x2E2F, 0x2E2F),
+        (0x3005, 0x3005), (0x302A, 0x302D), (0x3031, 0x3035), (0x303B, 0x303B), (0x3099, 0x309E), (0x30FC, 0x30FE),
+        (0xA015, 0xA015), (0xA4F8, 0xA4FD), (0xA60C, 0xA60C), (0xA66F, 0xA672), (0xA674, 0xA67D), (0xA67F, 0xA67F),
+        (0xA69C, 0xA69F), (0xA6F0, 0xA6F1), (0xA700, 0xA721), (0xA770, 0xA770), (0xA788, 0xA78A), (0xA7F1, 0xA7F4),
+        (0xA7F8, 0xA7F9), (0xA802, 0xA802), (0xA806, 0xA806), (0xA80B, 0xA80B), (0xA825, 0xA826), (0xA82C, 0xA82C),
+        (0xA8C4, 0xA8C5), (0xA8E0, 0xA8F1), (0xA8FF, 0xA8FF), (0xA926, 0xA92D), (0xA947, 0xA951), (0xA980, 0xA982),
+        (0xA9B3, 0xA9B3), (0xA9B6, 0xA9B9), (0xA9BC, 0xA9BD), (0xA9CF, 0xA9CF), (0xA9E5, 0xA9E6), (0xAA29, 0xAA2E),
+        (0xAA31, 0xAA32), (0xAA35, 0xAA36), (0xAA43, 0xAA43), (0xAA4C, 0xAA4C), (0xAA70, 0xAA70), (0xAA7C, 0xAA7C),
+        (0xAAB0, 0xAAB0), (0xAAB2, 0xAAB4), (0xAAB7, 0xAAB8), (0xAABE, 0xAABF), (0xAAC1, 0xAAC1), (0xAADD, 0xAADD),
+        (0xAAEC, 0xAAED), (0xAAF3, 0xAAF4), (0xAAF6, 0xAAF6), (0xAB5B, 0xAB5F), (0xAB69, 0xAB6B), (0xABE5, 0xABE5),
+        (0xABE8, 0xABE8), (0xABED, 0xABED), (0xFB1E, 0xFB1E), (0xFBB2, 0xFBC2), (0xFE00, 0xFE0F), (0xFE13, 0xFE13),
+        (0xFE20, 0xFE2F), (0xFE52, 0xFE52), (0xFE55, 0xFE55), (0xFEFF, 0xFEFF), (0xFF07, 0xFF07), (0xFF0E, 0xFF0E),
+        (0xFF1A, 0xFF1A), (0xFF3E, 0xFF3E), (0xFF40, 0xFF40), (0xFF70, 0xFF70), (0xFF9E, 0xFF9F), (0xFFE3, 0xFFE3),
+        (0xFFF9, 0xFFFB), (0x101FD, 0x101FD), (0x102E0, 0x102E0), (0x10376, 0x1037A), (0x10780, 0x10785), (0x10787, 0x107B0),
+        (0x107B2, 0x107BA), (0x10A01, 0x10A03), (0x10A05, 0x10A06), (0x10A0C, 0x10A0F), (0x10A38, 0x10A3A), (0x10A3F, 0x10A3F),
+        (0x10AE5, 0x10AE6), (0x10D24, 0x10D27), (0x10D4E, 0x10D4E), (0x10D69, 0x10D6D), (0x10D6F, 0x10D6F), (0x10EAB, 0x10EAC),
+        (0x10EC5, 0x10EC5), (0x10EFA, 0x10EFF), (0x10F46, 0x10F50), (0x10F82, 0x10F85), (0x11001, 0x11001), (0x11038, 0x11046),
+        (0x11070, 0x11070), (0x11073, 0x11074), (0x1107F, 0x11081), (0x110B3, 0x110B6), (0x110B9, 0x110BA), (0x110BD, 0x110BD),
+        (0x110C2, 0x110C2), (0x110CD, 0x110CD), (0x11100, 0x11102), (0x11127, 0x1112B), (0x1112D, 0x11134), (0x11173, 0x11173),
+        (0x11180, 0x11181), (0x111B6, 0x111BE), (0x111C9, 0x111CC), (0x111CF, 0x111CF), (0x1122F, 0x11231), (0x11234, 0x11234),
+        (0x11236, 0x11237), (0x1123E, 0x1123E), (0x11241, 0x11241), (0x112DF, 0x112DF), (0x112E3, 0x112EA), (0x11300, 0x11301),
+        (0x1133B, 0x1133C), (0x11340, 0x11340), (0x11366, 0x1136C), (0x11370, 0x11374), (0x113BB, 0x113C0), (0x113CE, 0x113CE),
+        (0x113D0, 0x113D0), (0x113D2, 0x113D2), (0x113E1, 0x113E2), (0x11438, 0x1143F), (0x11442, 0x11444), (0x11446, 0x11446),
+        (0x1145E, 0x1145E), (0x114B3, 0x114B8), (0x114BA, 0x114BA), (0x114BF, 0x114C0), (0x114C2, 0x114C3), (0x115B2, 0x115B5),
+        (0x115BC, 0x115BD), (0x115BF, 0x115C0), (0x115DC, 0x115DD), (0x11633, 0x1163A), (0x1163D, 0x1163D), (0x1163F, 0x11640),
+        (0x116AB, 0x116AB), (0x116AD, 0x116AD), (0x116B0, 0x116B5), (0x116B7, 0x116B7), (0x1171D, 0x1171D), (0x1171F, 0x1171F),
+        (0x11722, 0x11725), (0x11727, 0x1172B), (0x1182F, 0x11837), (0x11839, 0x1183A), (0x1193B, 0x1193C), (0x1193E, 0x1193E),
+        (0x11943, 0x11943), (0x119D4, 0x119D7), (0x119DA, 0x119DB), (0x119E0, 0x119E0), (0x11A01, 0x11A0A), (0x11A33, 0x11A38),
+        (0x11A3B, 0x11A3E), (0x11A47, 0x11A47), (0x11A51, 0x11A56), (0x11A59, 0x11A5B), (0x11A8A, 0x11A96), (0x11A98, 0x11A99),
+        (0x11B60, 0x11B60), (0x11B62, 0x11B64), (0x11B66, 0x11B66), (0x11C30, 0x11C36), (0x11C38, 0x11C3D), (0x11C3F, 0x11C3F),
+        (0x11C92, 0x11CA7), (0x11CAA, 0x11CB0), (0x11CB2, 0x11CB3), (0x11CB5, 0x11CB6), (0x11D31, 0x11D36), (0x11D3A, 0x11D3A),
+        (0x11D3C, 0x11D3D), (0x11D3F, 0x11D45), (0x11D47, 0x11D47), (0x11D90, 0x11D91), (0x11D95, 0x11D95), (0x11D97, 0x11D97),
+        (0x11DD9, 0x11DD9), (0x11EF3, 0x11EF4), (0x11F00, 0x11F01), (0x11F36, 0x11F3A), (0x11F40, 0x11F40), (0x11F42, 0x11F42),
+        (0x11F5A, 0x11F5A), (0x13430, 0x13440), (0x13447, 0x13455), (0x1611E, 0x16129), (0x1612D, 0x1612F), (0x16AF0, 0x16AF4),
+        (0x16B30, 0x16B36), (0x16B40, 0x16B43), (0x16D40, 0x16D42), (0x16D6B, 0x16D6C), (0x16F4F, 0x16F4F), (0x16F8F, 0x16F9F),
+        (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE4), (0x16FF2, 0x16FF3), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE),
+        (0x1BC9D, 0x1BC9E), (0x1BCA0, 0x1BCA3), (0x1CF00, 0x1CF2D), (0x1CF30, 0x1CF46), (0x1D167, 0x1D169), (0x1D173, 0x1D182),
+        (0x1D185, 0x1D18B), (0x1D1AA, 0x1D1AD), (0x1D242, 0x1D244), (0x1DA00, 0x1DA36), (0x1DA3B, 0x1DA6C), (0x1DA75, 0x1DA75),
+        (0x1DA84, 0x1DA84), (0x1DA9B, 0x1DA9F), (0x1DAA1, 0x1DAAF), (0x1E000, 0x1E006), (0x1E008, 0x1E018), (0x1E01B, 0x1E021),
+        (0x1E023, 0x1E024), (0x1E026, 0x1E02A), (0x1E030, 0x1E06D), (0x1E08F, 0x1E08F), (0x1E130, 0x1E13D), (0x1E2AE, 0x1E2AE),
+        (0x1E2EC, 0x1E2EF), (0x1E4EB, 0x1E4EF), (0x1E5EE, 0x1E5EF), (0x1E6E3, 0x1E6E3), (0x1E6E6, 0x1E6E6), (0x1E6EE, 0x1E6EF),
+        (0x1E6F5, 0x1E6F5), (0x1E6FF, 0x1E6FF), (0x1E8D0, 0x1E8D6), (0x1E944, 0x1E94B), (0x1F3FB, 0x1F3FF), (0xE0001, 0xE0001),
+        (0xE0020, 0xE007F), (0xE0100, 0xE01EF),
+    ]),
+    ("Cased", "", &[
+        (0x41, 0x5A), (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5), (0xBA, 0xBA), (0xC0, 0xD6),
+        (0xD8, 0xF6), (0xF8, 0x1BA), (0x1BC, 0x1BF), (0x1C4, 0x293), (0x296, 0x2B8), (0x2C0, 0x2C1),
+        (0x2E0, 0x2E4), (0x345, 0x345), (0x370, 0x373), (0x376, 0x377), (0x37A, 0x37D), (0x37F, 0x37F),
+        (0x386, 0x386), (0x388, 0x38A), (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x3F5), (0x3F7, 0x481),
+        (0x48A, 0x52F), (0x531, 0x556), (0x560, 0x588), (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD),
+        (0x10D0, 0x10FA), (0x10FC, 0x10FF), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1C80, 0x1C8A), (0x1C90, 0x1CBA),
+        (0x1CBD, 0x1CBF), (0x1D00, 0x1DBF), (0x1E00, 0x1F15), (0x1F18, 0x1F1D), (0x1F20, 0x1F45), (0x1F48, 0x1F4D),
+        (0x1F50, 0x1F57), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D), (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4),
+        (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC), (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB),
+        (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFC), (0x2071, 0x2071), (0x207F, 0x207F), (0x2090, 0x209C),
+        (0x2102, 0x2102), (0x2107, 0x2107), (0x210A, 0x2113), (0x2115, 0x2115), (0x2119, 0x211D), (0x2124, 0x2124),
+        (0x2126, 0x2126), (0x2128, 0x2128), (0x212A, 0x212D), (0x212F, 0x2134), (0x2139, 0x2139), (0x213C, 0x213F),
+        (0x2145, 0x2149), (0x214E, 0x214E), (0x2160, 0x217F), (0x2183, 0x2184), (0x24B6, 0x24E9), (0x2C00, 0x2CE4),
+        (0x2CEB, 0x2CEE), (0x2CF2, 0x2CF3), (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0xA640, 0xA66D),
+        (0xA680, 0xA69D), (0xA722, 0xA787), (0xA78B, 0xA78E), (0xA790, 0xA7DC), (0xA7F1, 0xA7F6), (0xA7F8, 0xA7FA),
+        (0xAB30, 0xAB5A), (0xAB5C, 0xAB69), (0xAB70, 0xABBF), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFF21, 0xFF3A),
+        (0xFF41, 0xFF5A), (0x10400, 0x1044F), (0x104B0, 0x104D3), (0x104D8, 0x104FB), (0x10570, 0x1057A), (0x1057C, 0x1058A),
+        (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1), (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC),
+        (0x10780, 0x10780), (0x10783, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2),
+        (0x10D50, 0x10D65), (0x10D70, 0x10D85), (0x118A0, 0x118DF), (0x16E40, 0x16E7F), (0x16EA0, 0x16EB8), (0x16EBB, 0x16ED3),
+        (0x1D400, 0x1D454), (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC),
+        (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A), (0x1D50D, 0x1D514),
+        (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546), (0x1D54A, 0x1D550),
+        (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D6C0), (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6FA), (0x1D6FC, 0x1D714), (0x1D716, 0x1D734),
+        (0x1D736, 0x1D74E), (0x1D750, 0x1D76E), (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8), (0x1D7AA, 0x1D7C2), (0x1D7C4, 0x1D7CB),
+        (0x1DF00, 0x1DF09), (0x1DF0B, 0x1DF1E), (0x1DF25, 0x1DF2A), (0x1E030, 0x1E06D), (0x1E900, 0x1E943), (0x1F130, 0x1F149),
+        (0x1F150, 0x1F169), (0x1F170, 0x1F189),
+    ]),
+    ("Changes_When_Casefolded", "CWCF", &[
+        (0x41, 0x5A), (0xB5, 0xB5), (0xC0, 0xD6), (0xD8, 0xDF), (0x100, 0x100), (0x102, 0x102),
+        (0x104, 0x104), (0x106, 0x106), (0x108, 0x108), (0x10A, 0x10A), (0x10C, 0x10C), (0x10E, 0x10E),
+        (0x110, 0x110), (0x112, 0x112), (0x114, 0x114), (0x116, 0x116), (0x118, 0x118), (0x11A, 0x11A),
+        (0x11C, 0x11C), (0x11E, 0x11E), (0x120, 0x120), (0x122, 0x122), (0x124, 0x124), (0x126, 0x126),
+        (0x128, 0x128), (0x12A, 0x12A), (0x12C, 0x12C), (0x12E, 0x12E), (0x130, 0x130), (0x132, 0x132),
+        (0x134, 0x134), (0x136, 0x136), (0x139, 0x139), (0x13B, 0x13B), (0x13D, 0x13D), (0x13F, 0x13F),
+        (0x141, 0x141), (0x143, 0x143), (0x145, 0x145), (0x147, 0x147), (0x149, 0x14A), (0x14C, 0x14C),
+        (0x14E, 0x14E), (0x150, 0x150), (0x152, 0x152), (0x154, 0x154), (0x156, 0x156), (0x158, 0x158),
+        (0x15A, 0x15A), (0x15C, 0x15C), (0x15E, 0x15E), (0x160, 0x160), (0x162, 0x162), (0x164, 0x164),
+        (0x166, 0x166), (0x168, 0x168), (0x16A, 0x16A), (0x16C, 0x16C), (0x16E, 0x16E), (0x170, 0x170),
+        (0x172, 0x172), (0x174, 0x174), (0x176, 0x176), (0x178, 0x179), (0x17B, 0x17B), (0x17D, 0x17D),
+        (0x17F, 0x17F), (0x181, 0x182), (0x184, 0x184), (0x186, 0x187), (0x189, 0x18B), (0x18E, 0x191),
+        (0x193, 0x194), (0x196, 0x198), (0x19C, 0x19D), (0x19F, 0x1A0), (0x1A2, 0x1A2), (0x1A4, 0x1A4),
+        (0x1A6, 0x1A7), (0x1A9, 0x1A9), (0x1AC, 0x1AC), (0x1AE, 0x1AF), (0x1B1, 0x1B3), (0x1B5, 0x1B5),
+        (0x1B7, 0x1B8), (0x1BC, 0x1BC), (0x1C4, 0x1C5), (0x1C7, 0x1C8), (0x1CA, 0x1CB), (0x1CD, 0x1CD),
+        (0x1CF, 0x1CF), (0x1D1, 0x1D1), (0x1D3, 0x1D3), (0x1D5, 0x1D5), (0x1D7, 0x1D7), (0x1D9, 0x1D9),
+        (0x1DB, 0x1DB), (0x1DE, 0x1DE), (0x1E0, 0x1E0), (0x1E2, 0x1E2), (0x1E4, 0x1E4), (0x1E6, 0x1E6),
+        (0x1E8, 0x1E8), (0x1EA, 0x1EA), (0x1EC, 0x1EC), (0x1EE, 0x1EE), (0x1F1, 0x1F2), (0x1F4, 0x1F4),
+        (0x1F6, 0x1F8), (0x1FA, 0x1FA), (0x1FC, 0x1FC), (0x1FE, 0x1FE), (0x200, 0x200), (0x202, 0x202),
+        (0x204, 0x204), (0x206, 0x206), (0x208, 0x208), (0x20A, 0x20A), (0x20C, 0x20C), (0x20E, 0x20E),
+        (0x210, 0x210), (0x212, 0x212), (0x214, 0x214), (0x216, 0x216), (0x218, 0x218), (0x21A, 0x21A),
+        (0x21C, 0x21C), (0x21E, 0x21E), (0x220, 0x220), (0x222, 0x222), (0x224, 0x224), (0x226, 0x226),
+        (0x228, 0x228), (0x22A, 0x22A), (0x22C, 0x22C), (0x22E, 0x22E), (0x230, 0x230), (0x232, 0x232),
+        (0x23A, 0x23B), (0x23D, 0x23E), (0x241, 0x241), (0x243, 0x246), (0x248, 0x248), (0x24A, 0x24A),
+        (0x24C, 0x24C), (0x24E, 0x24E), (0x345, 0x345), (0x370, 0x370), (0x372, 0x372), (0x376, 0x376),
+        (0x37F, 0x37F), (0x386, 0x386), (0x388, 0x38A), (0x38C, 0x38C), (0x38E, 0x38F), (0x391, 0x3A1),
+        (0x3A3, 0x3AB), (0x3C2, 0x3C2), (0x3CF, 0x3D1), (0x3D5, 0x3D6), (0x3D8, 0x3D8), (0x3DA, 0x3DA),
+        (0x3DC, 0x3DC), (0x3DE, 0x3DE), (0x3E0, 0x3E0), (0x3E2, 0x3E2), (0x3E4, 0x3E4), (0x3E6, 0x3E6),
+        (0x3E8, 0x3E8), (0x3EA, 0x3EA), (0x3EC, 0x3EC), (0x3EE, 0x3EE), (0x3F0, 0x3F1), (0x3F4, 0x3F5),
+        (0x3F7, 0x3F7), (0x3F9, 0x3FA), (0x3FD, 0x42F), (0x460, 0x460), (0x462, 0x462), (0x464, 0x464),
+        (0x466, 0x466), (0x468, 0x468), (0x46A, 0x46A), (0x46C, 0x46C), (0x46E, 0x46E), (0x470, 0x470),
+        (0x472, 0x472), (0x474, 0x474), (0x476, 0x476), (0x478, 0x478), (0x47A, 0x47A), (0x47C, 0x47C),
+        (0x47E, 0x47E), (0x480, 0x480), (0x48A, 0x48A), (0x48C, 0x48C), (0x48E, 0x48E), (0x490, 0x490),
+        (0x492, 0x492), (0x494, 0x494), (0x496, 0x496), (0x498, 0x498), (0x49A, 0x49A), (0x49C, 0x49C),
+        (0x49E, 0x49E), (0x4A0, 0x4A0), (0x4A2, 0x4A2), (0x4A4, 0x4A4), (0x4A6, 0x4A6), (0x4A8, 0x4A8),
+        (0x4AA, 0x4AA), (0x4AC, 0x4AC), (0x4AE, 0x4AE), (0x4B0, 0x4B0), (0x4B2, 0x4B2), (0x4B4, 0x4B4),
+        (0x4B6, 0x4B6), (0x4B8, 0x4B8), (0x4BA, 0x4BA), (0x4BC, 0x4BC), (0x4BE, 0x4BE), (0x4C0, 0x4C1),
+        (0x4C3, 0x4C3), (0x4C5, 0x4C5), (0x4C7, 0x4C7), (0x4C9, 0x4C9), (0x4CB, 0x4CB), (0x4CD, 0x4CD),
+        (0x4D0, 0x4D0), (0x4D2, 0x4D2), (0x4D4, 0x4D4), (0x4D6, 0x4D6), (0x4D8, 0x4D8), (0x4DA, 0x4DA),
+        (0x4DC, 0x4DC), (0x4DE, 0x4DE), (0x4E0, 0x4E0), (0x4E2, 0x4E2), (0x4E4, 0x4E4), (0x4E6, 0x4E6),
+        (0x4E8, 0x4E8), (0x4EA, 0x4EA), (0x4EC, 0x4EC), (0x4EE, 0x4EE), (0x4F0, 0x4F0), (0x4F2, 0x4F2),
+        (0x4F4, 0x4F4), (0x4F6, 0x4F6), (0x4F8, 0x4F8), (0x4FA, 0x4FA), (0x4FC, 0x4FC), (0x4FE, 0x4FE),
+        (0x500, 0x500), (0x502, 0x502), (0x504, 0x504), (0x506, 0x506), (0x508, 0x508), (0x50A, 0x50A),
+        (0x50C, 0x50C), (0x50E, 0x50E), (0x510, 0x510), (0x512, 0x512), (0x514, 0x514), (0x516, 0x516),
+        (0x518, 0x518), (0x51A, 0x51A), (0x51C, 0x51C), (0x51E, 0x51E), (0x520, 0x520), (0x522, 0x522),
+        (0x524, 0x524), (0x526, 0x526), (0x528, 0x528), (0x52A, 0x52A), (0x52C, 0x52C), (0x52E, 0x52E),
+        (0x531, 0x556), (0x587, 0x587), (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD), (0x13F8, 0x13FD),
+        (0x1C80, 0x1C89), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1E00, 0x1E00), (0x1E02, 0x1E02), (0x1E04, 0x1E04),
+        (0x1E06, 0x1E06), (0x1E08, 0x1E08), (0x1E0A, 0x1E0A), (0x1E0C, 0x1E0C), (0x1E0E, 0x1E0E), (0x1E10, 0x1E10),
+        (0x1E12, 0x1E12), (0x1E14, 0x1E14), (0x1E16, 0x1E16), (0x1E18, 0x1E18), (0x1E1A, 0x1E1A), (0x1E1C, 0x1E1C),
+        (0x1E1E, 0x1E1E), (0x1E20, 0x1E20), (0x1E22, 0x1E22), (0x1E24, 0x1E24), (0x1E26, 0x1E26), (0x1E28, 0x1E28),
+        (0x1E2A, 0x1E2A), (0x1E2C, 0x1E2C), (0x1E2E, 0x1E2E), (0x1E30, 0x1E30), (0x1E32, 0x1E32), (0x1E34, 0x1E34),
+        (0x1E36, 0x1E36), (0x1E38, 0x1E38), (0x1E3A, 0x1E3A), (0x1E3C, 0x1E3C), (0x1E3E, 0x1E3E), (0x1E40, 0x1E40),
+        (0x1E42, 0x1E42), (0x1E44, 0x1E44), (0x1E46, 0x1E46), (0x1E48, 0x1E48), (0x1E4A, 0x1E4A), (0x1E4C, 0x1E4C),
+        (0x1E4E, 0x1E4E), (0x1E50, 0x1E50), (0x1E52, 0x1E52), (0x1E54, 0x1E54), (0x1E56, 0x1E56), (0x1E58, 0x1E58),
+        (0x1E5A, 0x1E5A), (0x1E5C, 0x1E5C), (0x1E5E, 0x1E5E), (0x1E60, 0x1E60), (0x1E62, 0x1E62), (0x1E64, 0x1E64),
+        (0x1E66, 0x1E66), (0x1E68, 0x1E68), (0x1E6A, 0x1E6A), (0x1E6C, 0x1E6C), (0x1E6E, 0x1E6E), (0x1E70, 0x1E70),
+        (0x1E72, 0x1E72), (0x1E74, 0x1E74), (0x1E76, 0x1E76), (0x1E78, 0x1E78), (0x1E7A, 0x1E7A), (0x1E7C, 0x1E7C),
+        (0x1E7E, 0x1E7E), (0x1E80, 0x1E80), (0x1E82, 0x1E82), (0x1E84, 0x1E84), (0x1E86, 0x1E86), (0x1E88, 0x1E88),
+        (0x1E8A, 0x1E8A), (0x1E8C, 0x1E8C), (0x1E8E, 0x1E8E), (0x1E90, 0x1E90), (0x1E92, 0x1E92), (0x1E94, 0x1E94),
+        (0x1E9A, 0x1E9B), (0x1E9E, 0x1E9E), (0x1EA0, 0x1EA0), (0x1EA2, 0x1EA2), (0x1EA4, 0x1EA4), (0x1EA6, 0x1EA6),
+        (0x1EA8, 0x1EA8), (0x1EAA, 0x1EAA), (0x1EAC, 0x1EAC), (0x1EAE, 0x1EAE), (0x1EB0, 0x1EB0), (0x1EB2, 0x1EB2),
+        (0x1EB4, 0x1EB4), (0x1EB6, 0x1EB6), (0x1EB8, 0x1EB8), (0x1EBA, 0x1EBA), (0x1EBC, 0x1EBC), (0x1EBE, 0x1EBE),
+        (0x1EC0, 0x1EC0), (0x1EC2, 0x1EC2), (0x1EC4, 0x1EC4), (0x1EC6, 0x1EC6), (0x1EC8, 0x1EC8), (0x1ECA, 0x1ECA),
+        (0x1ECC, 0x1ECC), (0x1ECE, 0x1ECE), (0x1ED0, 0x1ED0), (0x1ED2, 0x1ED2), (0x1ED4, 0x1ED4), (0x1ED6, 0x1ED6),
+        (0x1ED8, 0x1ED8), (0x1EDA, 0x1EDA), (0x1EDC, 0x1EDC), (0x1EDE, 0x1EDE), (0x1EE0, 0x1EE0), (0x1EE2, 0x1EE2),
+        (0x1EE4, 0x1EE4), (0x1EE6, 0x1EE6), (0x1EE8, 0x1EE8), (0x1EEA, 0x1EEA), (0x1EEC, 0x1EEC), (0x1EEE, 0x1EEE),
+        (0x1EF0, 0x1EF0), (0x1EF2, 0x1EF2), (0x1EF4, 0x1EF4), (0x1EF6, 0x1EF6), (0x1EF8, 0x1EF8), (0x1EFA, 0x1EFA),
+        (0x1EFC, 0x1EFC), (0x1EFE, 0x1EFE), (0x1F08, 0x1F0F), (0x1F18, 0x1F1D), (0x1F28, 0x1F2F), (0x1F38, 0x1F3F),
+        (0x1F48, 0x1F4D), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D), (0x1F5F, 0x1F5F), (0x1F68, 0x1F6F),
+        (0x1F80, 0x1FAF), (0x1FB2, 0x1FB4), (0x1FB7, 0x1FBC), (0x1FC2, 0x1FC4), (0x1FC7, 0x1FCC), (0x1FD8, 0x1FDB),
+        (0x1FE8, 0x1FEC), (0x1FF2, 0x1FF4), (0x1FF7, 0x1FFC), (0x2126, 0x2126), (0x212A, 0x212B), (0x2132, 0x2132),
+        (0x2160, 0x216F), (0x2183, 0x2183), (0x24B6, 0x24CF), (0x2C00, 0x2C2F), (0x2C60, 0x2C60), (0x2C62, 0x2C64),
+        (0x2C67, 0x2C67), (0x2C69, 0x2C69), (0x2C6B, 0x2C6B), (0x2C6D, 0x2C70), (0x2C72, 0x2C72), (0x2C75, 0x2C75),
+        (0x2C7E, 0x2C80), (0x2C82, 0x2C82), (0x2C84, 0x2C84), (0x2C86, 0x2C86), (0x2C88, 0x2C88), (0x2C8A, 0x2C8A),
+        (0x2C8C, 0x2C8C), (0x2C8E, 0x2C8E), (0x2C90, 0x2C90), (0x2C92, 0x2C92), (0x2C94, 0x2C94), (0x2C96, 0x2C96),
+        (0x2C98, 0x2C98), (0x2C9A, 0x2C9A), (0x2C9C, 0x2C9C), (0x2C9E, 0x2C9E), (0x2CA0, 0x2CA0), (0x2CA2, 0x2CA2),
+        (0x2CA4, 0x2CA4), (0x2CA6, 0x2CA6), (0x2CA8, 0x2CA8), (0x2CAA, 0x2CAA), (0x2CAC, 0x2CAC), (0x2CAE, 0x2CAE),
+        (0x2CB0, 0x2CB0), (0x2CB2, 0x2CB2), (0x2CB4, 0x2CB4), (0x2CB6, 0x2CB6), (0x2CB8, 0x2CB8), (0x2CBA, 0x2CBA),
+        (0x2CBC, 0x2CBC), (0x2CBE, 0x2CBE), (0x2CC0, 0x2CC0), (0x2CC2, 0x2CC2), (0x2CC4, 0x2CC4), (0x2CC6, 0x2CC6),
+        (0x2CC8, 0x2CC8), (0x2CCA, 0x2CCA), (0x2CCC, 0x2CCC), (0x2CCE, 0x2CCE), (0x2CD0, 0x2CD0), (0x2CD2, 0x2CD2),
+        (0x2CD4, 0x2CD4), (0x2CD6, 0x2CD6), (0x2CD8, 0x2CD8), (0x2CDA, 0x2CDA), (0x2CDC, 0x2CDC), (0x2CDE, 0x2CDE),
+        (0x2CE0, 0x2CE0), (0x2CE2, 0x2CE2), (0x2CEB, 0x2CEB), (0x2CED, 0x2CED), (0x2CF2, 0x2CF2), (0xA640, 0xA640),
+        (0xA642, 0xA642), (0xA644, 0xA644), (0xA646, 0xA646), (0xA648, 0xA648), (0xA64A, 0xA64A), (0xA64C, 0xA64C),
+        (0xA64E, 0xA64E), (0xA650, 0xA650), (0xA652, 0xA652), (0xA654, 0xA654), (0xA656, 0xA656), (0xA658, 0xA658),
+        (0xA65A, 0xA65A), (0xA65C, 0xA65C), (0xA65E, 0xA65E), (0xA660, 0xA660), (0xA662, 0xA662), (0xA664, 0xA664),
+        (0xA666, 0xA666), (0xA668, 0xA668), (0xA66A, 0xA66A), (0xA66C, 0xA66C), (0xA680, 0xA680), (0xA682, 0xA682),
+        (0xA684, 0xA684), (0xA686, 0xA686), (0xA688, 0xA688), (0xA68A, 0xA68A), (0xA68C, 0xA68C), (0xA68E, 0xA68E),
+        (0xA690, 0xA690), (0xA692, 0xA692), (0xA694, 0xA694), (0xA696, 0xA696), (0xA698, 0xA698), (0xA69A, 0xA69A),
+        (0xA722, 0xA722), (0xA724, 0xA724), (0xA726, 0xA726), (0xA728, 0xA728), (0xA72A, 0xA72A), (0xA72C, 0xA72C),
+        (0xA72E, 0xA72E), (0xA732, 0xA732), (0xA734, 0xA734), (0xA736, 0xA736), (0xA738, 0xA738), (0xA73A, 0xA73A),
+        (0xA73C, 0xA73C), (0xA73E, 0xA73E), (0xA740, 0xA740), (0xA742, 0xA742), (0xA744, 0xA744), (0xA746, 0xA746),
+        (0xA748, 0xA748), (0xA74A, 0xA74A), (0xA74C, 0xA74C), (0xA74E, 0xA74E), (0xA750, 0xA750), (0xA752, 0xA752),
+        (0xA754, 0xA754), (0xA756, 0xA756), (0xA758, 0xA758), (0xA75A, 0xA75A), (0xA75C, 0xA75C), (0xA75E, 0xA75E),
+        (0xA760, 0xA760), (0xA762, 0xA762), (0xA764, 0xA764), (0xA766, 0xA766), (0xA768, 0xA768), (0xA76A, 0xA76A),
+        (0xA76C, 0xA76C), (0xA76E, 0xA76E), (0xA779, 0xA779), (0xA77B, 0xA77B), (0xA77D, 0xA77E), (0xA780, 0xA780),
+        (0xA782, 0xA782), (0xA784, 0xA784), (0xA786, 0xA786), (0xA78B, 0xA78B), (0xA78D, 0xA78D), (0xA790, 0xA790),
+        (0xA792, 0xA792), (0xA796, 0xA796), (0xA798, 0xA798), (0xA79A, 0xA79A), (0xA79C, 0xA79C), (0xA79E, 0xA79E),
+        (0xA7A0, 0xA7A0), (0xA7A2, 0xA7A2), (0xA7A4, 0xA7A4), (0xA7A6, 0xA7A6), (0xA7A8, 0xA7A8), (0xA7AA, 0xA7AE),
+        (0xA7B0, 0xA7B4), (0xA7B6, 0xA7B6), (0xA7B8, 0xA7B8), (0xA7BA, 0xA7BA), (0xA7BC, 0xA7BC), (0xA7BE, 0xA7BE),
+        (0xA7C0, 0xA7C0), (0xA7C2, 0xA7C2), (0xA7C4, 0xA7C7), (0xA7C9, 0xA7C9), (0xA7CB, 0xA7CC), (0xA7CE, 0xA7CE),
+        (0xA7D0, 0xA7D0), (0xA7D2, 0xA7D2), (0xA7D4, 0xA7D4), (0xA7D6, 0xA7D6), (0xA7D8, 0xA7D8), (0xA7DA, 0xA7DA),
+        (0xA7DC, 0xA7DC), (0xA7F5, 0xA7F5), (0xAB70, 0xABBF), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFF21, 0xFF3A),
+        (0x10400, 0x10427), (0x104B0, 0x104D3), (0x10570, 0x1057A), (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595),
+        (0x10C80, 0x10CB2), (0x10D50, 0x10D65), (0x118A0, 0x118BF), (0x16E40, 0x16E5F), (0x16EA0, 0x16EB8), (0x1E900, 0x1E921),
+    ]),
+    ("Changes_When_Casemapped", "CWCM", &[
+        (0x41, 0x5A), (0x61, 0x7A), (0xB5, 0xB5), (0xC0, 0xD6), (0xD8, 0xF6), (0xF8, 0x137),
+        (0x139, 0x18C), (0x18E, 0x1A9), (0x1AC, 0x1B9), (0x1BC, 0x1BD), (0x1BF, 0x1BF), (0x1C4, 0x220),
+        (0x222, 0x233), (0x23A, 0x254), (0x256, 0x257), (0x259, 0x259), (0x25B, 0x25C), (0x260, 0x261),
+        (0x263, 0x266), (0x268, 0x26C), (0x26F, 0x26F), (0x271, 0x272), (0x275, 0x275), (0x27D, 0x27D),
+        (0x280, 0x280), (0x282, 0x283), (0x287, 0x28C), (0x292, 0x292), (0x29D, 0x29E), (0x345, 0x345),
+        (0x370, 0x373), (0x376, 0x377), (0x37B, 0x37D), (0x37F, 0x37F), (0x386, 0x386), (0x388, 0x38A),
+        (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x3D1), (0x3D5, 0x3F5), (0x3F7, 0x3FB), (0x3FD, 0x481),
+        (0x48A, 0x52F), (0x531, 0x556), (0x561, 0x587), (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD),
+        (0x10D0, 0x10FA), (0x10FD, 0x10FF), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1C80, 0x1C8A), (0x1C90, 0x1CBA),
+        (0x1CBD, 0x1CBF), (0x1D79, 0x1D79), (0x1D7D, 0x1D7D), (0x1D8E, 0x1D8E), (0x1E00, 0x1E9B), (0x1E9E, 0x1E9E),
+        (0x1EA0, 0x1F15), (0x1F18, 0x1F1D), (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59),
+        (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D), (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE),
+        (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC), (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4),
+        (0x1FF6, 0x1FFC), (0x2126, 0x2126), (0x212A, 0x212B), (0x2132, 0x2132), (0x214E, 0x214E), (0x2160, 0x217F),
+        (0x2183, 0x2184), (0x24B6, 0x24E9), (0x2C00, 0x2C70), (0x2C72, 0x2C73), (0x2C75, 0x2C76), (0x2C7E, 0x2CE3),
+        (0x2CEB, 0x2CEE), (0x2CF2, 0x2CF3), (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0xA640, 0xA66D),
+        (0xA680, 0xA69B), (0xA722, 0xA72F), (0xA732, 0xA76F), (0xA779, 0xA787), (0xA78B, 0xA78D), (0xA790, 0xA794),
+        (0xA796, 0xA7AE), (0xA7B0, 0xA7DC), (0xA7F5, 0xA7F6), (0xAB53, 0xAB53), (0xAB70, 0xABBF), (0xFB00, 0xFB06),
+        (0xFB13, 0xFB17), (0xFF21, 0xFF3A), (0xFF41, 0xFF5A), (0x10400, 0x1044F), (0x104B0, 0x104D3), (0x104D8, 0x104FB),
+        (0x10570, 0x1057A), (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1), (0x105A3, 0x105B1),
+        (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2), (0x10D50, 0x10D65), (0x10D70, 0x10D85),
+        (0x118A0, 0x118DF), (0x16E40, 0x16E7F), (0x16EA0, 0x16EB8), (0x16EBB, 0x16ED3), (0x1E900, 0x1E943),
+    ]),
+    ("Changes_When_Lowercased", "CWL", &[
+        (0x41, 0x5A), (0xC0, 0xD6), (0xD8, 0xDE), (0x100, 0x100), (0x102, 0x102), (0x104, 0x104),
+        (0x106, 0x106), (0x108, 0x108), (0x10A, 0x10A), (0x10C, 0x10C), (0x10E, 0x10E), (0x110, 0x110),
+        (0x112, 0x112), (0x114, 0x114), (0x116, 0x116), (0x118, 0x118), (0x11A, 0x11A), (0x11C, 0x11C),
+        (0x11E, 0x11E), (0x120, 0x120), (0x122, 0x122), (0x124, 0x124), (0x126, 0x126), (0x128, 0x128),
+        (0x12A, 0x12A), (0x12C, 0x12C), (0x12E, 0x12E), (0x130, 0x130), (0x132, 0x132), (0x134, 0x134),
+        (0x136, 0x136), (0x139, 0x139), (0x13B, 0x13B), (0x13D, 0x13D), (0x13F, 0x13F), (0x141, 0x141),
+        (0x143, 0x143), (0x145, 0x145), (0x147, 0x147), (0x14A, 0x14A), (0x14C, 0x14C), (0x14E, 0x14E),
+        (0x150, 0x150), (0x152, 0x152), (0x154, 0x154), (0x156, 0x156), (0x158, 0x158), (0x15A, 0x15A),
+        (0x15C, 0x15C), (0x15E, 0x15E), (0x160, 0x160), (0x162, 0x162), (0x164, 0x164), (0x166, 0x166),
+        (0x168, 0x168), (0x16A, 0x16A), (0x16C, 0x16C), (0x16E, 0x16E), (0x170, 0x170), (0x172, 0x172),
+        (0x174, 0x174), (0x176, 0x176), (0x178, 0x179), (0x17B, 0x17B), (0x17D, 0x17D), (0x181, 0x182),
+        (0x184, 0x184), (0x186, 0x187), (0x189, 0x18B), (0x18E, 0x191), (0x193, 0x194), (0x196, 0x198),
+        (0x19C, 0x19D), (0x19F, 0x1A0), (0x1A2, 0x1A2), (0x1A4, 0x1A4), (0x1A6, 0x1A7), (0x1A9, 0x1A9),
+        (0x1AC, 0x1AC), (0x1AE, 0x1AF), (0x1B1, 0x1B3), (0x1B5, 0x1B5), (0x1B7, 0x1B8), (0x1BC, 0x1BC),
+        (0x1C4, 0x1C5), (0x1C7, 0x1C8), (0x1CA, 0x1CB), (0x1CD, 0x1CD), (0x1CF, 0x1CF), (0x1D1, 0x1D1),
+        (0x1D3, 0x1D3), (0x1D5, 0x1D5), (0x1D7, 0x1D7), (0x1D9, 0x1D9), (0x1DB, 0x1DB), (0x1DE, 0x1DE),
+        (0x1E0, 0x1E0), (0x1E2, 0x1E2), (0x1E4, 0x1E4), (0x1E6, 0x1E6), (0x1E8, 0x1E8), (0x1EA, 0x1EA),
+        (0x1EC, 0x1EC), (0x1EE, 0x1EE), (0x1F1, 0x1F2), (0x1F4, 0x1F4), (0x1F6, 0x1F8), (0x1FA, 0x1FA),
+        (0x1FC, 0x1FC), (0x1FE, 0x1FE), (0x200, 0x200), (0x202, 0x202), (0x204, 0x204), (0x206, 0x206),
+        (0x208, 0x208), (0x20A, 0x20A), (0x20C, 0x20C), (0x20E, 0x20E), (0x210, 0x210), (0x212, 0x212),
+        (0x214, 0x214), (0x216, 0x216), (0x218, 0x218), (0x21A, 0x21A), (0x21C, 0x21C), (0x21E, 0x21E),
+        (0x220, 0x220), (0x222, 0x222), (0x224, 0x224), (0x226, 0x226), (0x228, 0x228), (0x22A, 0x22A),
+        (0x22C, 0x22C), (0x22E, 0x22E), (0x230, 0x230), (0x232, 0x232), (0x23A, 0x23B), (0x23D, 0x23E),
+        (0x241, 0x241), (0x243, 0x246), (0x248, 0x248), (0x24A, 0x24A), (0x24C, 0x24C), (0x24E, 0x24E),
+        (0x370, 0x370), (0x372, 0x372), (0x376, 0x376), (0x37F, 0x37F), (0x386, 0x386), (0x388, 0x38A),
+        (0x38C, 0x38C), (0x38E, 0x38F), (0x391, 0x3A1), (0x3A3, 0x3AB), (0x3CF, 0x3CF), (0x3D8, 0x3D8),
+        (0x3DA, 0x3DA), (0x3DC, 0x3DC), (0x3DE, 0x3DE), (0x3E0, 0x3E0), (0x3E2, 0x3E2), (0x3E4, 0x3E4),
+        (0x3E6, 0x3E6), (0x3E8, 0x3E8), (0x3EA, 0x3EA), (0x3EC, 0x3EC), (0x3EE, 0x3EE), (0x3F4, 0x3F4),
+        (0x3F7, 0x3F7), (0x3F9, 0x3FA), (0x3FD, 0x42F), (0x460, 0x460), (0x462, 0x462), (0x464, 0x464),
+        (0x466, 0x466), (0x468, 0x468), (0x46A, 0x46A), (0x46C, 0x46C), (0x46E, 0x46E), (0x470, 0x470),
+        (0x472, 0x472), (0x474, 0x474), (0x476, 0x476), (0x478, 0x478), (0x47A, 0x47A), (0x47C, 0x47C),
+        (0x47E, 0x47E), (0x480, 0x480), (0x48A, 0x48A), (0x48C, 0x48C), (0x48E, 0x48E), (0x490, 0x490),
+        (0x492, 0x492), (0x494, 0x494), (0x496, 0x496), (0x498, 0x498), (0x49A, 0x49A), (0x49C, 0x49C),
+        (0x49E, 0x49E), (0x4A0, 0x4A0), (0x4A2, 0x4A2), (0x4A4, 0x4A4), (0x4A6, 0x4A6), (0x4A8, 0x4A8),
+        (0x4AA, 0x4AA), (0x4AC, 0x4AC), (0x4AE, 0x4AE), (0x4B0, 0x4B0), (0x4B2, 0x4B2), (0x4B4, 0x4B4),
+        (0x4B6, 0x4B6), (0x4B8, 0x4B8), (0x4BA, 0x4BA), (0x4BC, 0x4BC), (0x4BE, 0x4BE), (0x4C0, 0x4C1),
+        (0x4C3, 0x4C3), (0x4C5, 0x4C5), (0x4C7, 0x4C7), (0x4C9, 0x4C9), (0x4CB, 0x4CB), (0x4CD, 0x4CD),
+        (0x4D0, 0x4D0), (0x4D2, 0x4D2), (0x4D4, 0x4D4), (0x4D6, 0x4D6), (0x4D8, 0x4D8), (0x4DA, 0x4DA),
+        (0x4DC, 0x4DC), (0x4DE, 0x4DE), (0x4E0, 0x4E0), (0x4E2, 0x4E2), (0x4E4, 0x4E4), (0x4E6, 0x4E6),
+        (0x4E8, 0x4E8), (0x4EA, 0x4EA), (0x4EC, 0x4EC), (0x4EE, 0x4EE), (0x4F0, 0x4F0), (0x4F2, 0x4F2),
+        (0x4F4, 0x4F4), (0x4F6, 0x4F6), (0x4F8, 0x4F8), (0x4FA, 0x4FA), (0x4FC, 0x4FC), (0x4FE, 0x4FE),
+        (0x500, 0x500), (0x502, 0x502), (0x504, 0x504), (0x506, 0x506), (0x508, 0x508), (0x50A, 0x50A),
+        (0x50C, 0x50C), (0x50E, 0x50E), (0x510, 0x510), (0x512, 0x512), (0x514, 0x514), (0x516, 0x516),
+        (0x518, 0x518), (0x51A, 0x51A), (0x51C, 0x51C), (0x51E, 0x51E), (0x520, 0x520), (0x522, 0x522),
+        (0x524, 0x524), (0x526, 0x526), (0x528, 0x528), (0x52A, 0x52A), (0x52C, 0x52C), (0x52E, 0x52E),
+        (0x531, 0x556), (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD), (0x13A0, 0x13F5), (0x1C89, 0x1C89),
+        (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1E00, 0x1E00), (0x1E02, 0x1E02), (0x1E04, 0x1E04), (0x1E06, 0x1E06),
+        (0x1E08, 0x1E08), (0x1E0A, 0x1E0A), (0x1E0C, 0x1E0C), (0x1E0E, 0x1E0E), (0x1E10, 0x1E10), (0x1E12, 0x1E12),
+        (0x1E14, 0x1E14), (0x1E16, 0x1E16), (0x1E18, 0x1E18), (0x1E1A, 0x1E1A), (0x1E1C, 0x1E1C), (0x1E1E, 0x1E1E),
+        (0x1E20, 0x1E20), (0x1E22, 0x1E22), (0x1E24, 0x1E24), (0x1E26, 0x1E26), (0x1E28, 0x1E28), (0x1E2A, 0x1E2A),
+        (0x1E2C, 0x1E2C), (0x1E2E, 0x1E2E), (0x1E30, 0x1E30), (0x1E32, 0x1E32), (0x1E34, 0x1E34), (0x1E36, 0x1E36),
+        (0x1E38, 0x1E38), (0x1E3A, 0x1E3A), (0x1E3C, 0x1E3C), (0x1E3E, 0x1E3E), (0x1E40, 0x1E40), (0x1E42, 0x1E42),
+        (0x1E44, 0x1E44), (0x1E46, 0x1E46), (0x1E48, 0x1E48), (0x1E4A, 0x1E4A), (0x1E4C, 0x1E4C), (0x1E4E, 0x1E4E),
+        (0x1E50, 0x1E50), (0x1E52, 0x1E52), (0x1E54, 0x1E54), (0x1E56, 0x1E56), (0x1E58, 0x1E58), (0x1E5A, 0x1E5A),
+        (0x1E5C, 0x1E5C), (0x1E5E, 0x1E5E), (0x1E60, 0x1E60), (0x1E62, 0x1E62), (0x1E64, 0x1E64), (0x1E66, 0x1E66),
+        (0x1E68, 0x1E68), (0x1E6A, 0x1E6A), (0x1E6C, 0x1E6C), (0x1E6E, 0x1E6E), (0x1E70, 0x1E70), (0x1E72, 0x1E72),
+        (0x1E74, 0x1E74), (0x1E76, 0x1E76), (0x1E78, 0x1E78), (0x1E7A, 0x1E7A), (0x1E7C, 0x1E7C), (0x1E7E, 0x1E7E),
+        (0x1E80, 0x1E80), (0x1E82, 0x1E82), (0x1E84, 0x1E84), (0x1E86, 0x1E86), (0x1E88, 0x1E88), (0x1E8A, 0x1E8A),
+        (0x1E8C, 0x1E8C), (0x1E8E, 0x1E8E), (0x1E90, 0x1E90), (0x1E92, 0x1E92), (0x1E94, 0x1E94), (0x1E9E, 0x1E9E),
+        (0x1EA0, 0x1EA0), (0x1EA2, 0x1EA2), (0x1EA4, 0x1EA4), (0x1EA6, 0x1EA6), (0x1EA8, 0x1EA8), (0x1EAA, 0x1EAA),
+        (0x1EAC, 0x1EAC), (0x1EAE, 0x1EAE), (0x1EB0, 0x1EB0), (0x1EB2, 0x1EB2), (0x1EB4, 0x1EB4), (0x1EB6, 0x1EB6),
+        (0x1EB8, 0x1EB8), (0x1EBA, 0x1EBA), (0x1EBC, 0x1EBC), (0x1EBE, 0x1EBE), (0x1EC0, 0x1EC0), (0x1EC2, 0x1EC2),
+        (0x1EC4, 0x1EC4), (0x1EC6, 0x1EC6), (0x1EC8, 0x1EC8), (0x1ECA, 0x1ECA), (0x1ECC, 0x1ECC), (0x1ECE, 0x1ECE),
+        (0x1ED0, 0x1ED0), (0x1ED2, 0x1ED2), (0x1ED4, 0x1ED4), (0x1ED6, 0x1ED6), (0x1ED8, 0x1ED8), (0x1EDA, 0x1EDA),
+        (0x1EDC, 0x1EDC), (0x1EDE, 0x1EDE), (0x1EE0, 0x1EE0), (0x1EE2, 0x1EE2), (0x1EE4, 0x1EE4), (0x1EE6, 0x1EE6),
+        (0x1EE8, 0x1EE8), (0x1EEA, 0x1EEA), (0x1EEC, 0x1EEC), (0x1EEE, 0x1EEE), (0x1EF0, 0x1EF0), (0x1EF2, 0x1EF2),
+        (0x1EF4, 0x1EF4), (0x1EF6, 0x1EF6), (0x1EF8, 0x1EF8), (0x1EFA, 0x1EFA), (0x1EFC, 0x1EFC), (0x1EFE, 0x1EFE),
+        (0x1F08, 0x1F0F), (0x1F18, 0x1F1D), (0x1F28, 0x1F2F), (0x1F38, 0x1F3F), (0x1F48, 0x1F4D), (0x1F59, 0x1F59),
+        (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D), (0x1F5F, 0x1F5F), (0x1F68, 0x1F6F), (0x1F88, 0x1F8F), (0x1F98, 0x1F9F),
+        (0x1FA8, 0x1FAF), (0x1FB8, 0x1FBC), (0x1FC8, 0x1FCC), (0x1FD8, 0x1FDB), (0x1FE8, 0x1FEC), (0x1FF8, 0x1FFC),
+        (0x2126, 0x2126), (0x212A, 0x212B), (0x2132, 0x2132), (0x2160, 0x216F), (0x2183, 0x2183), (0x24B6, 0x24CF),
+        (0x2C00, 0x2C2F), (0x2C60, 0x2C60), (0x2C62, 0x2C64), (0x2C67, 0x2C67), (0x2C69, 0x2C69), (0x2C6B, 0x2C6B),
+        (0x2C6D, 0x2C70), (0x2C72, 0x2C72), (0x2C75, 0x2C75), (0x2C7E, 0x2C80), (0x2C82, 0x2C82), (0x2C84, 0x2C84),
+        (0x2C86, 0x2C86), (0x2C88, 0x2C88), (0x2C8A, 0x2C8A), (0x2C8C, 0x2C8C), (0x2C8E, 0x2C8E), (0x2C90, 0x2C90),
+        (0x2C92, 0x2C92), (0x2C94, 0x2C94), (0x2C96, 0x2C96), (0x2C98, 0x2C98), (0x2C9A, 0x2C9A), (0x2C9C, 0x2C9C),
+        (0x2C9E, 0x2C9E), (0x2CA0, 0x2CA0), (0x2CA2, 0x2CA2), (0x2CA4, 0x2CA4), (0x2CA6, 0x2CA6), (0x2CA8, 0x2CA8),
+        (0x2CAA, 0x2CAA), (0x2CAC, 0x2CAC), (0x2CAE, 0x2CAE), (0x2CB0, 0x2CB0), (0x2CB2, 0x2CB2), (0x2CB4, 0x2CB4),
+        (0x2CB6, 0x2CB6), (0x2CB8, 0x2CB8), (0x2CBA, 0x2CBA), (0x2CBC, 0x2CBC), (0x2CBE, 0x2CBE), (0x2CC0, 0x2CC0),
+        (0x2CC2, 0x2CC2), (0x2CC4, 0x2CC4), (0x2CC6, 0x2CC6), (0x2CC8, 0x2CC8), (0x2CCA, 0x2CCA), (0x2CCC, 0x2CCC),
+        (0x2CCE, 0x2CCE), (0x2CD0, 0x2CD0), (0x2CD2, 0x2CD2), (0x2CD4, 0x2CD4), (0x2CD6, 0x2CD6), (0x2CD8, 0x2CD8),
+        (0x2CDA, 0x2CDA), (0x2CDC, 0x2CDC), (0x2CDE, 0x2CDE), (0x2CE0, 0x2CE0), (0x2CE2, 0x2CE2), (0x2CEB, 0x2CEB),
+        (0x2CED, 0x2CED), (0x2CF2, 0x2CF2), (0xA640, 0xA640), (0xA642, 0xA642), (0xA644, 0xA644), (0xA646, 0xA646),
+        (0xA648, 0xA648), (0xA64A, 0xA64A), (0xA64C, 0xA64C), (0xA64E, 0xA64E), (0xA650, 0xA650), (0xA652, 0xA652),
+        (0xA654, 0xA654), (0xA656, 0xA656), (0xA658, 0xA658), (0xA65A, 0xA65A), (0xA65C, 0xA65C), (0xA65E, 0xA65E),
+        (0xA660, 0xA660), (0xA662, 0xA662), (0xA664, 0xA664), (0xA666, 0xA666), (0xA668, 0xA668), (0xA66A, 0xA66A),
+        (0xA66C, 0xA66C), (0xA680, 0xA680), (0xA682, 0xA682), (0xA684, 0xA684), (0xA686, 0xA686), (0xA688, 0xA688),
+        (0xA68A, 0xA68A), (0xA68C, 0xA68C), (0xA68E, 0xA68E), (0xA690, 0xA690), (0xA692, 0xA692), (0xA694, 0xA694),
+        (0xA696, 0xA696), (0xA698, 0xA698), (0xA69A, 0xA69A), (0xA722, 0xA722), (0xA724, 0xA724), (0xA726, 0xA726),
+        (0xA728, 0xA728), (0xA72A, 0xA72A), (0xA72C, 0xA72C), (0xA72E, 0xA72E), (0xA732, 0xA732), (0xA734, 0xA734),
+        (0xA736, 0xA736), (0xA738, 0xA738), (0xA73A, 0xA73A), (0xA73C, 0xA73C), (0xA73E, 0xA73E), (0xA740, 0xA740),
+        (0xA742, 0xA742), (0xA744, 0xA744), (0xA746, 0xA746), (0xA748, 0xA748), (0xA74A, 0xA74A), (0xA74C, 0xA74C),
+        (0xA74E, 0xA74E), (0xA750, 0xA750), (0xA752, 0xA752), (0xA754, 0xA754), (0xA756, 0xA756), (0xA758, 0xA758),
+        (0xA75A, 0xA75A), (0xA75C, 0xA75C), (0xA75E, 0xA75E), (0xA760, 0xA760), (0xA762, 0xA762), (0xA764, 0xA764),
+        (0xA766, 0xA766), (0xA768, 0xA768), (0xA76A, 0xA76A), (0xA76C, 0xA76C), (0xA76E, 0xA76E), (0xA779, 0xA779),
+        (0xA77B, 0xA77B), (0xA77D, 0xA77E), (0xA780, 0xA780), (0xA782, 0xA782), (0xA784, 0xA784), (0xA786, 0xA786),
+        (0xA78B, 0xA78B), (0xA78D, 0xA78D), (0xA790, 0xA790), (0xA792, 0xA792), (0xA796, 0xA796), (0xA798, 0xA798),
+        (0xA79A, 0xA79A), (0xA79C, 0xA79C), (0xA79E, 0xA79E), (0xA7A0, 0xA7A0), (0xA7A2, 0xA7A2), (0xA7A4, 0xA7A4),
+        (0xA7A6, 0xA7A6), (0xA7A8, 0xA7A8), (0xA7AA, 0xA7AE), (0xA7B0, 0xA7B4), (0xA7B6, 0xA7B6), (0xA7B8, 0xA7B8),
+        (0xA7BA, 0xA7BA), (0xA7BC, 0xA7BC), (0xA7BE, 0xA7BE), (0xA7C0, 0xA7C0), (0xA7C2, 0xA7C2), (0xA7C4, 0xA7C7),
+        (0xA7C9, 0xA7C9), (0xA7CB, 0xA7CC), (0xA7CE, 0xA7CE), (0xA7D0, 0xA7D0), (0xA7D2, 0xA7D2), (0xA7D4, 0xA7D4),
+        (0xA7D6, 0xA7D6), (0xA7D8, 0xA7D8), (0xA7DA, 0xA7DA), (0xA7DC, 0xA7DC), (0xA7F5, 0xA7F5), (0xFF21, 0xFF3A),
+        (0x10400, 0x10427), (0x104B0, 0x104D3), (0x10570, 0x1057A), (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595),
+        (0x10C80, 0x10CB2), (0x10D50, 0x10D65), (0x118A0, 0x118BF), (0x16E40, 0x16E5F), (0x16EA0, 0x16EB8), (0x1E900, 0x1E921),
+    ]),
+    ("Changes_When_NFKC_Casefolded", "CWKCF", &[
+        (0x41, 0x5A), (0xA0, 0xA0), (0xA8, 0xA8), (0xAA, 0xAA), (0xAD, 0xAD), (0xAF, 0xAF),
+        (0xB2, 0xB5), (0xB8, 0xBA), (0xBC, 0xBE), (0xC0, 0xD6), (0xD8, 0xDF), (0x100, 0x100),
+        (0x102, 0x102), (0x104, 0x104), (0x106, 0x106), (0x108, 0x108), (0x10A, 0x10A), (0x10C, 0x10C),
+        (0x10E, 0x10E), (0x110, 0x110), (0x112, 0x112), (0x114, 0x114), (0x116, 0x116), (0x118, 0x118),
+        (0x11A, 0x11A), (0x11C, 0x11C), (0x11E, 0x11E), (0x120, 0x120), (0x122, 0x122), (0x124, 0x124),
+        (0x126, 0x126), (0x128, 0x128), (0x12A, 0x12A), (0x12C, 0x12C), (0x12E, 0x12E), (0x130, 0x130),
+        (0x132, 0x134), (0x136, 0x136), (0x139, 0x139), (0x13B, 0x13B), (0x13D, 0x13D), (0x13F, 0x141),
+        (0x143, 0x143), (0x145, 0x145), (0x147, 0x147), (0x149, 0x14A), (0x14C, 0x14C), (0x14E, 0x14E),
+        (0x150, 0x150), (0x152, 0x152), (0x154, 0x154), (0x156, 0x156), (0x158, 0x158), (0x15A, 0x15A),
+        (0x15C, 0x15C), (0x15E, 0x15E), (0x160, 0x160), (0x162, 0x162), (0x164, 0x164), (0x166, 0x166),
+        (0x168, 0x168), (0x16A, 0x16A), (0x16C, 0x16C), (0x16E, 0x16E), (0x170, 0x170), (0x172, 0x172),
+        (0x174, 0x174), (0x176, 0x176), (0x178, 0x179), (0x17B, 0x17B), (0x17D, 0x17D), (0x17F, 0x17F),
+        (0x181, 0x182), (0x184, 0x184), (0x186, 0x187), (0x189, 0x18B), (0x18E, 0x191), (0x193, 0x194),
+        (0x196, 0x198), (0x19C, 0x19D), (0x19F, 0x1A0), (0x1A2, 0x1A2), (0x1A4, 0x1A4), (0x1A6, 0x1A7),
+        (0x1A9, 0x1A9), (0x1AC, 0x1AC), (0x1AE, 0x1AF), (0x1B1, 0x1B3), (0x1B5, 0x1B5), (0x1B7, 0x1B8),
+        (0x1BC, 0x1BC), (0x1C4, 0x1CD), (0x1CF, 0x1CF), (0x1D1, 0x1D1), (0x1D3, 0x1D3), (0x1D5, 0x1D5),
+        (0x1D7, 0x1D7), (0x1D9, 0x1D9), (0x1DB, 0x1DB), (0x1DE, 0x1DE), (0x1E0, 0x1E0), (0x1E2, 0x1E2),
+        (0x1E4, 0x1E4), (0x1E6, 0x1E6), (0x1E8, 0x1E8), (0x1EA, 0x1EA), (0x1EC, 0x1EC), (0x1EE, 0x1EE),
+        (0x1F1, 0x1F4), (0x1F6, 0x1F8), (0x1FA, 0x1FA), (0x1FC, 0x1FC), (0x1FE, 0x1FE), (0x200, 0x200),
+        (0x202, 0x202), (0x204, 0x204), (0x206, 0x206), (0x208, 0x208), (0x20A, 0x20A), (0x20C, 0x20C),
+        (0x20E, 0x20E), (0x210, 0x210), (0x212, 0x212), (0x214, 0x214), (0x216, 0x216), (0x218, 0x218),
+        (0x21A, 0x21A), (0x21C, 0x21C), (0x21E, 0x21E), (0x220, 0x220), (0x222, 0x222), (0x224, 0x224),
+        (0x226, 0x226), (0x228, 0x228), (0x22A, 0x22A), (0x22C, 0x22C), (0x22E, 0x22E), (0x230, 0x230),
+        (0x232, 0x232), (0x23A, 0x23B), (0x23D, 0x23E), (0x241, 0x241), (0x243, 0x246), (0x248, 0x248),
+        (0x24A, 0x24A), (0x24C, 0x24C), (0x24E, 0x24E), (0x2B0, 0x2B8), (0x2D8, 0x2DD), (0x2E0, 0x2E4),
+        (0x340, 0x341), (0x343, 0x345), (0x34F, 0x34F), (0x370, 0x370), (0x372, 0x372), (0x374, 0x374),
+        (0x376, 0x376), (0x37A, 0x37A), (0x37E, 0x37F), (0x384, 0x38A), (0x38C, 0x38C), (0x38E, 0x38F),
+        (0x391, 0x3A1), (0x3A3, 0x3AB), (0x3C2, 0x3C2), (0x3CF, 0x3D6), (0x3D8, 0x3D8), (0x3DA, 0x3DA),
+        (0x3DC, 0x3DC), (0x3DE, 0x3DE), (0x3E0, 0x3E0), (0x3E2, 0x3E2), (0x3E4, 0x3E4), (0x3E6, 0x3E6),
+        (0x3E8, 0x3E8), (0x3EA, 0x3EA), (0x3EC, 0x3EC), (0x3EE, 0x3EE), (0x3F0, 0x3F2), (0x3F4, 0x3F5),
+        (0x3F7, 0x3F7), (0x3F9, 0x3FA), (0x3FD, 0x42F), (0x460, 0x460), (0x462, 0x462), (0x464, 0x464),
+        (0x466, 0x466), (0x468, 0x468), (0x46A, 0x46A), (0x46C, 0x46C), (0x46E, 0x46E), (0x470, 0x470),
+        (0x472, 0x472), (0x474, 0x474), (0x476, 0x476), (0x478, 0x478), (0x47A, 0x47A), (0x47C, 0x47C),
+        (0x47E, 0x47E), (0x480, 0x480), (0x48A, 0x48A), (0x48C, 0x48C), (0x48E, 0x48E), (0x490, 0x490),
+        (0x492, 0x492), (0x494, 0x494), (0x496, 0x496), (0x498, 0x498), (0x49A, 0x49A), (0x49C, 0x49C),
+        (0x49E, 0x49E), (0x4A0, 0x4A0), (0x4A2, 0x4A2), (0x4A4, 0x4A4), (0x4A6, 0x4A6), (0x4A8, 0x4A8),
+        (0x4AA, 0x4AA), (0x4AC, 0x4AC), (0x4AE, 0x4AE), (0x4B0, 0x4B0), (0x4B2, 0x4B2), (0x4B4, 0x4B4),
+        (0x4B6, 0x4B6), (0x4B8, 0x4B8), (0x4BA, 0x4BA), (0x4BC, 0x4BC), (0x4BE, 0x4BE), (0x4C0, 0x4C1),
+        (0x4C3, 0x4C3), (0x4C5, 0x4C5), (0x4C7, 0x4C7), (0x4C9, 0x4C9), (0x4CB, 0x4CB), (0x4CD, 0x4CD),
+        (0x4D0, 0x4D0), (0x4D2, 0x4D2), (0x4D4, 0x4D4), (0x4D6, 0x4D6), (0x4D8, 0x4D8), (0x4DA, 0x4DA),
+        (0x4DC, 0x4DC), (0x4DE, 0x4DE), (0x4E0, 0x4E0), (0x4E2, 0x4E2), (0x4E4, 0x4E4), (0x4E6, 0x4E6),
+        (0x4E8, 0x4E8), (0x4EA, 0x4EA), (0x4EC, 0x4EC), (0x4EE, 0x4EE), (0x4F0, 0x4F0), (0x4F2, 0x4F2),
+        (0x4F4, 0x4F4), (0x4F6, 0x4F6), (0x4F8, 0x4F8), (0x4FA, 0x4FA), (0x4FC, 0x4FC), (0x4FE, 0x4FE),
+        (0x500, 0x500), (0x502, 0x502), (0x504, 0x504), (0x506, 0x506), (0x508, 0x508), (0x50A, 0x50A),
+        (0x50C, 0x50C), (0x50E, 0x50E), (0x510, 0x510), (0x512, 0x512), (0x514, 0x514), (0x516, 0x516),
+        (0x518, 0x518), (0x51A, 0x51A), (0x51C, 0x51C), (0x51E, 0x51E), (0x520, 0x520), (0x522, 0x522),
+        (0x524, 0x524), (0x526, 0x526), (0x528, 0x528), (0x52A, 0x52A), (0x52C, 0x52C), (0x52E, 0x52E),
+        (0x531, 0x556), (0x587, 0x587), (0x61C, 0x61C), (0x675, 0x678), (0x958, 0x95F), (0x9DC, 0x9DD),
+        (0x9DF, 0x9DF), (0xA33, 0xA33), (0xA36, 0xA36), (0xA59, 0xA5B), (0xA5E, 0xA5E), (0xB5C, 0xB5D),
+        (0xE33, 0xE33), (0xEB3, 0xEB3), (0xEDC, 0xEDD), (0xF0C, 0xF0C), (0xF43, 0xF43), (0xF4D, 0xF4D),
+        (0xF52, 0xF52), (0xF57, 0xF57), (0xF5C, 0xF5C), (0xF69, 0xF69), (0xF73, 0xF73), (0xF75, 0xF79),
+        (0xF81, 0xF81), (0xF93, 0xF93), (0xF9D, 0xF9D), (0xFA2, 0xFA2), (0xFA7, 0xFA7), (0xFAC, 0xFAC),
+        (0xFB9, 0xFB9), (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD), (0x10FC, 0x10FC), (0x115F, 0x1160),
+        (0x13F8, 0x13FD), (0x17B4, 0x17B5), (0x180B, 0x180F), (0x1C80, 0x1C89), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF),
+        (0x1D2C, 0x1D2E), (0x1D30, 0x1D3A), (0x1D3C, 0x1D4D), (0x1D4F, 0x1D6A), (0x1D78, 0x1D78), (0x1D9B, 0x1DBF),
+        (0x1E00, 0x1E00), (0x1E02, 0x1E02), (0x1E04, 0x1E04), (0x1E06, 0x1E06), (0x1E08, 0x1E08), (0x1E0A, 0x1E0A),
+        (0x1E0C, 0x1E0C), (0x1E0E, 0x1E0E), (0x1E10, 0x1E10), (0x1E12, 0x1E12), (0x1E14, 0x1E14), (0x1E16, 0x1E16),
+        (0x1E18, 0x1E18), (0x1E1A, 0x1E1A), (0x1E1C, 0x1E1C), (0x1E1E, 0x1E1E), (0x1E20, 0x1E20), (0x1E22, 0x1E22),
+        (0x1E24, 0x1E24), (0x1E26, 0x1E26), (0x1E28, 0x1E28), (0x1E2A, 0x1E2A), (0x1E2C, 0x1E2C), (0x1E2E, 0x1E2E),
+        (0x1E30, 0x1E30), (0x1E32, 0x1E32), (0x1E34, 0x1E34), (0x1E36, 0x1E36), (0x1E38, 0x1E38), (0x1E3A, 0x1E3A),
+        (0x1E3C, 0x1E3C), (0x1E3E, 0x1E3E), (0x1E40, 0x1E40), (0x1E42, 0x1E42), (0x1E44, 0x1E44), (0x1E46, 0x1E46),
+        (0x1E48, 0x1E48), (0x1E4A, 0x1E4A), (0x1E4C, 0x1E4C), (0x1E4E, 0x1E4E), (0x1E50, 0x1E50), (0x1E52, 0x1E52),
+        (0x1E54, 0x1E54), (0x1E56, 0x1E56), (0x1E58, 0x1E58), (0x1E5A, 0x1E5A), (0x1E5C, 0x1E5C), (0x1E5E, 0x1E5E),
+        (0x1E60, 0x1E60), (0x1E62, 0x1E62), (0x1E64, 0x1E64), (0x1E66, 0x1E66), (0x1E68, 0x1E68), (0x1E6A, 0x1E6A),
+        (0x1E6C, 0x1E6C), (0x1E6E, 0x1E6E), (0x1E70, 0x1E70), (0x1E72, 0x1E72), (0x1E74, 0x1E74), (0x1E76, 0x1E76),
+        (0x1E78, 0x1E78), (0x1E7A, 0x1E7A), (0x1E7C, 0x1E7C), (0x1E7E, 0x1E7E), (0x1E80, 0x1E80), (0x1E82, 0x1E82),
+        (0x1E84, 0x1E84), (0x1E86, 0x1E86), (0x1E88, 0x1E88), (0x1E8A, 0x1E8A), (0x1E8C, 0x1E8C), (0x1E8E, 0x1E8E),
+        (0x1E90, 0x1E90), (0x1E92, 0x1E92), (0x1E94, 0x1E94), (0x1E9A, 0x1E9B), (0x1E9E, 0x1E9E), (0x1EA0, 0x1EA0),
+        (0x1EA2, 0x1EA2), (0x1EA4, 0x1EA4), (0x1EA6, 0x1EA6), (0x1EA8, 0x1EA8), (0x1EAA, 0x1EAA), (0x1EAC, 0x1EAC),
+        (0x1EAE, 0x1EAE), (0x1EB0, 0x1EB0), (0x1EB2, 0x1EB2), (0x1EB4, 0x1EB4), (0x1EB6, 0x1EB6), (0x1EB8, 0x1EB8),
+        (0x1EBA, 0x1EBA), (0x1EBC, 0x1EBC), (0x1EBE, 0x1EBE), (0x1EC0, 0x1EC0), (0x1EC2, 0x1EC2), (0x1EC4, 0x1EC4),
+        (0x1EC6, 0x1EC6), (0x1EC8, 0x1EC8), (0x1ECA, 0x1ECA), (0x1ECC, 0x1ECC), (0x1ECE, 0x1ECE), (0x1ED0, 0x1ED0),
+        (0x1ED2, 0x1ED2), (0x1ED4, 0x1ED4), (0x1ED6, 0x1ED6), (0x1ED8, 0x1ED8), (0x1EDA, 0x1EDA), (0x1EDC, 0x1EDC),
+        (0x1EDE, 0x1EDE), (0x1EE0, 0x1EE0), (0x1EE2, 0x1EE2), (0x1EE4, 0x1EE4), (0x1EE6, 0x1EE6), (0x1EE8, 0x1EE8),
+        (0x1EEA, 0x1EEA), (0x1EEC, 0x1EEC), (0x1EEE, 0x1EEE), (0x1EF0, 0x1EF0), (0x1EF2, 0x1EF2), (0x1EF4, 0x1EF4),
+        (0x1EF6, 0x1EF6), (0x1EF8, 0x1EF8), (0x1EFA, 0x1EFA), (0x1EFC, 0x1EFC), (0x1EFE, 0x1EFE), (0x1F08, 0x1F0F),
+        (0x1F18, 0x1F1D), (0x1F28, 0x1F2F), (0x1F38, 0x1F3F), (0x1F48, 0x1F4D), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B),
+        (0x1F5D, 0x1F5D), (0x1F5F, 0x1F5F), (0x1F68, 0x1F6F), (0x1F71, 0x1F71), (0x1F73, 0x1F73), (0x1F75, 0x1F75),
+        (0x1F77, 0x1F77), (0x1F79, 0x1F79), (0x1F7B, 0x1F7B), (0x1F7D, 0x1F7D), (0x1F80, 0x1FAF), (0x1FB2, 0x1FB4),
+        (0x1FB7, 0x1FC4), (0x1FC7, 0x1FCF), (0x1FD3, 0x1FD3), (0x1FD8, 0x1FDB), (0x1FDD, 0x1FDF), (0x1FE3, 0x1FE3),
+        (0x1FE8, 0x1FEF), (0x1FF2, 0x1FF4), (0x1FF7, 0x1FFE), (0x2000, 0x200F), (0x2011, 0x2011), (0x2017, 0x2017),
+        (0x2024, 0x2026), (0x202A, 0x202F), (0x2033, 0x2034), (0x2036, 0x2037), (0x203C, 0x203C), (0x203E, 0x203E),
+        (0x2047, 0x2049), (0x2057, 0x2057), (0x205F, 0x2071), (0x2074, 0x208E), (0x2090, 0x209C), (0x20A8, 0x20A8),
+        (0x2100, 0x2103), (0x2105, 0x2107), (0x2109, 0x2113), (0x2115, 0x2116), (0x2119, 0x211D), (0x2120, 0x2122),
+        (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128), (0x212A, 0x212D), (0x212F, 0x2139), (0x213B, 0x2140),
+        (0x2145, 0x2149), (0x2150, 0x217F), (0x2183, 0x2183), (0x2189, 0x2189), (0x222C, 0x222D), (0x222F, 0x2230),
+        (0x2329, 0x232A), (0x2460, 0x24EA), (0x2A0C, 0x2A0C), (0x2A74, 0x2A76), (0x2ADC, 0x2ADC), (0x2C00, 0x2C2F),
+        (0x2C60, 0x2C60), (0x2C62, 0x2C64), (0x2C67, 0x2C67), (0x2C69, 0x2C69), (0x2C6B, 0x2C6B), (0x2C6D, 0x2C70),
+        (0x2C72, 0x2C72), (0x2C75, 0x2C75), (0x2C7C, 0x2C80), (0x2C82, 0x2C82), (0x2C84, 0x2C84), (0x2C86, 0x2C86),
+        (0x2C88, 0x2C88), (0x2C8A, 0x2C8A), (0x2C8C, 0x2C8C), (0x2C8E, 0x2C8E), (0x2C90, 0x2C90), (0x2C92, 0x2C92),
+        (0x2C94, 0x2C94), (0x2C96, 0x2C96), (0x2C98, 0x2C98), (0x2C9A, 0x2C9A), (0x2C9C, 0x2C9C), (0x2C9E, 0x2C9E),
+        (0x2CA0, 0x2CA0), (0x2CA2, 0x2CA2), (0x2CA4, 0x2CA4), (0x2CA6, 0x2CA6), (0x2CA8, 0x2CA8), (0x2CAA, 0x2CAA),
+        (0x2CAC, 0x2CAC), (0x2CAE, 0x2CAE), (0x2CB0, 0x2CB0), (0x2CB2, 0x2CB2), (0x2CB4, 0x2CB4), (0x2CB6, 0x2CB6),
+        (0x2CB8, 0x2CB8), (0x2CBA, 0x2CBA), (0x2CBC, 0x2CBC), (0x2CBE, 0x2CBE), (0x2CC0, 0x2CC0), (0x2CC2, 0x2CC2),
+        (0x2CC4, 0x2CC4), (0x2CC6, 0x2CC6), (0x2CC8, 0x2CC8), (0x2CCA, 0x2CCA), (0x2CCC, 0x2CCC), (0x2CCE, 0x2CCE),
+        (0x2CD0, 0x2CD0), (0x2CD2, 0x2CD2), (0x2CD4, 0x2CD4), (0x2CD6, 0x2CD6), (0x2CD8, 0x2CD8), (0x2CDA, 0x2CDA),
+        (0x2CDC, 0x2CDC), (0x2CDE, 0x2CDE), (0x2CE0, 0x2CE0), (0x2CE2, 0x2CE2), (0x2CEB, 0x2CEB), (0x2CED, 0x2CED),
+        (0x2CF2, 0x2CF2), (0x2D6F, 0x2D6F), (0x2E9F, 0x2E9F), (0x2EF3, 0x2EF3), (0x2F00, 0x2FD5), (0x3000, 0x3000),
+        (0x3036, 0x3036), (0x3038, 0x303A), (0x309B, 0x309C), (0x309F, 0x309F), (0x30FF, 0x30FF), (0x3131, 0x318E),
+        (0x3192, 0x319F), (0x3200, 0x321E), (0x3220, 0x3247), (0x3250, 0x327E), (0x3280, 0x33FF), (0xA640, 0xA640),
+        (0xA642, 0xA642), (0xA644, 0xA644), (0xA646, 0xA646), (0xA648, 0xA648), (0xA64A, 0xA64A), (0xA64C, 0xA64C),
+        (0xA64E, 0xA64E), (0xA650, 0xA650), (0xA652, 0xA652), (0xA654, 0xA654), (0xA656, 0xA656), (0xA658, 0xA658),
+        (0xA65A, 0xA65A), (0xA65C, 0xA65C), (0xA65E, 0xA65E), (0xA660, 0xA660), (0xA662, 0xA662), (0xA664, 0xA664),
+        (0xA666, 0xA666), (0xA668, 0xA668), (0xA66A, 0xA66A), (0xA66C, 0xA66C), (0xA680, 0xA680), (0xA682, 0xA682),
+        (0xA684, 0xA684), (0xA686, 0xA686), (0xA688, 0xA688), (0xA68A, 0xA68A), (0xA68C, 0xA68C), (0xA68E, 0xA68E),
+        (0xA690, 0xA690), (0xA692, 0xA692), (0xA694, 0xA694), (0xA696, 0xA696), (0xA698, 0xA698), (0xA69A, 0xA69A),
+        (0xA69C, 0xA69D), (0xA722, 0xA722), (0xA724, 0xA724), (0xA726, 0xA726), (0xA728, 0xA728), (0xA72A, 0xA72A),
+        (0xA72C, 0xA72C), (0xA72E, 0xA72E), (0xA732, 0xA732), (0xA734, 0xA734), (0xA736, 0xA736), (0xA738, 0xA738),
+        (0xA73A, 0xA73A), (0xA73C, 0xA73C), (0xA73E, 0xA73E), (0xA740, 0xA740), (0xA742, 0xA742), (0xA744, 0xA744),
+        (0xA746, 0xA746), (0xA748, 0xA748), (0xA74A, 0xA74A), (0xA74C, 0xA74C), (0xA74E, 0xA74E), (0xA750, 0xA750),
+        (0xA752, 0xA752), (0xA754, 0xA754), (0xA756, 0xA756), (0xA758, 0xA758), (0xA75A, 0xA75A), (0xA75C, 0xA75C),
+        (0xA75E, 0xA75E), (0xA760, 0xA760), (0xA762, 0xA762), (0xA764, 0xA764), (0xA766, 0xA766), (0xA768, 0xA768),
+        (0xA76A, 0xA76A), (0xA76C, 0xA76C), (0xA76E, 0xA76E), (0xA770, 0xA770), (0xA779, 0xA779), (0xA77B, 0xA77B),
+        (0xA77D, 0xA77E), (0xA780, 0xA780), (0xA782, 0xA782), (0xA784, 0xA784), (0xA786, 0xA786), (0xA78B, 0xA78B),
+        (0xA78D, 0xA78D), (0xA790, 0xA790), (0xA792, 0xA792), (0xA796, 0xA796), (0xA798, 0xA798), (0xA79A, 0xA79A),
+        (0xA79C, 0xA79C), (0xA79E, 0xA79E), (0xA7A0, 0xA7A0), (0xA7A2, 0xA7A2), (0xA7A4, 0xA7A4), (0xA7A6, 0xA7A6),
+        (0xA7A8, 0xA7A8), (0xA7AA, 0xA7AE), (0xA7B0, 0xA7B4), (0xA7B6, 0xA7B6), (0xA7B8, 0xA7B8), (0xA7BA, 0xA7BA),
+        (0xA7BC, 0xA7BC), (0xA7BE, 0xA7BE), (0xA7C0, 0xA7C0), (0xA7C2, 0xA7C2), (0xA7C4, 0xA7C7), (0xA7C9, 0xA7C9),
+        (0xA7CB, 0xA7CC), (0xA7CE, 0xA7CE), (0xA7D0, 0xA7D0), (0xA7D2, 0xA7D2), (0xA7D4, 0xA7D4), (0xA7D6, 0xA7D6),
+        (0xA7D8, 0xA7D8), (0xA7DA, 0xA7DA), (0xA7DC, 0xA7DC), (0xA7F1, 0xA7F5), (0xA7F8, 0xA7F9), (0xAB5C, 0xAB5F),
+        (0xAB69, 0xAB69), (0xAB70, 0xABBF), (0xF900, 0xFA0D), (0xFA10, 0xFA10), (0xFA12, 0xFA12), (0xFA15, 0xFA1E),
+        (0xFA20, 0xFA20), (0xFA22, 0xFA22), (0xFA25, 0xFA26), (0xFA2A, 0xFA6D), (0xFA70, 0xFAD9), (0xFB00, 0xFB06),
+        (0xFB13, 0xFB17), (0xFB1D, 0xFB1D), (0xFB1F, 0xFB36), (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41),
+        (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7), (0xFDF0, 0xFDFC),
+        (0xFE00, 0xFE19), (0xFE30, 0xFE44), (0xFE47, 0xFE52), (0xFE54, 0xFE66), (0xFE68, 0xFE6B), (0xFE70, 0xFE72),
+        (0xFE74, 0xFE74), (0xFE76, 0xFEFC), (0xFEFF, 0xFEFF), (0xFF01, 0xFFBE), (0xFFC2, 0xFFC7), (0xFFCA, 0xFFCF),
+        (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC), (0xFFE0, 0xFFE6), (0xFFE8, 0xFFEE), (0xFFF0, 0xFFF8), (0x10400, 0x10427),
+        (0x104B0, 0x104D3), (0x10570, 0x1057A), (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595), (0x10781, 0x10785),
+        (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10C80, 0x10CB2), (0x10D50, 0x10D65), (0x118A0, 0x118BF), (0x16E40, 0x16E5F),
+        (0x16EA0, 0x16EB8), (0x1BCA0, 0x1BCA3), (0x1CCD6, 0x1CCF9), (0x1D15E, 0x1D164), (0x1D173, 0x1D17A), (0x1D1BB, 0x1D1C0),
+        (0x1D400, 0x1D454), (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC),
+        (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A), (0x1D50D, 0x1D514),
+        (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546), (0x1D54A, 0x1D550),
+        (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D7CB), (0x1D7CE, 0x1D7FF), (0x1E030, 0x1E06D), (0x1E900, 0x1E921), (0x1EE00, 0x1EE03),
+        (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22), (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37),
+        (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47), (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B),
+        (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52), (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B),
+        (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64), (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72),
+        (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C), (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3),
+        (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB), (0x1F100, 0x1F10A), (0x1F110, 0x1F12E), (0x1F130, 0x1F14F), (0x1F16A, 0x1F16C),
+        (0x1F190, 0x1F190), (0x1F200, 0x1F202), (0x1F210, 0x1F23B), (0x1F240, 0x1F248), (0x1F250, 0x1F251), (0x1FBF0, 0x1FBF9),
+        (0x2F800, 0x2FA1D), (0xE0000, 0xE0FFF),
+    ]),
+    ("Changes_When_Titlecased", "CWT", &[
+        (0x61, 0x7A), (0xB5, 0xB5), (0xDF, 0xF6), (0xF8, 0xFF), (0x101, 0x101), (0x103, 0x103),
+        (0x105, 0x105), (0x107, 0x107), (0x109, 0x109), (0x10B, 0x10B), (0x10D, 0x10D), (0x10F, 0x10F),
+        (0x111, 0x111), (0x113, 0x113), (0x115, 0x115), (0x117, 0x117), (0x119, 0x119), (0x11B, 0x11B),
+        (0x11D, 0x11D), (0x11F, 0x11F), (0x121, 0x121), (0x123, 0x123), (0x125, 0x125), (0x127, 0x127),
+        (0x129, 0x129), (0x12B, 0x12B), (0x12D, 0x12D), (0x12F, 0x12F), (0x131, 0x131), (0x133, 0x133),
+        (0x135, 0x135), (0x137, 0x137), (0x13A, 0x13A), (0x13C, 0x13C), (0x13E, 0x13E), (0x140, 0x140),
+        (0x142, 0x142), (0x144, 0x144), (0x146, 0x146), (0x148, 0x149), (0x14B, 0x14B), (0x14D, 0x14D),
+        (0x14F, 0x14F), (0x151, 0x151), (0x153, 0x153), (0x155, 0x155), (0x157, 0x157), (0x159, 0x159),
+        (0x15B, 0x15B), (0x15D, 0x15D), (0x15F, 0x15F), (0x161, 0x161), (0x163, 0x163), (0x165, 0x165),
+        (0x167, 0x167), (0x169, 0x169), (0x16B, 0x16B), (0x16D, 0x16D), (0x16F, 0x16F), (0x171, 0x171),
+        (0x173, 0x173), (0x175, 0x175), (0x177, 0x177), (0x17A, 0x17A), (0x17C, 0x17C), (0x17E, 0x180),
+        (0x183, 0x183), (0x185, 0x185), (0x188, 0x188), (0x18C, 0x18C), (0x192, 0x192), (0x195, 0x195),
+        (0x199, 0x19B), (0x19E, 0x19E), (0x1A1, 0x1A1), (0x1A3, 0x1A3), (0x1A5, 0x1A5), (0x1A8, 0x1A8),
+        (0x1AD, 0x1AD), (0x1B0, 0x1B0), (0x1B4, 0x1B4), (0x1B6, 0x1B6), (0x1B9, 0x1B9), (0x1BD, 0x1BD),
+        (0x1BF, 0x1BF), (0x1C4, 0x1C4), (0x1C6, 0x1C7), (0x1C9, 0x1CA), (0x1CC, 0x1CC), (0x1CE, 0x1CE),
+        (0x1D0, 0x1D0), (0x1D2, 0x1D2), (0x1D4, 0x1D4), (0x1D6, 0x1D6), (0x1D8, 0x1D8), (0x1DA, 0x1DA),
+        (0x1DC, 0x1DD), (0x1DF, 0x1DF), (0x1E1, 0x1E1), (0x1E3, 0x1E3), (0x1E5, 0x1E5), (0x1E7, 0x1E7),
+        (0x1E9, 0x1E9), (0x1EB, 0x1EB), (0x1ED, 0x1ED), (0x1EF, 0x1F1), (0x1F3, 0x1F3), (0x1F5, 0x1F5),
+        (0x1F9, 0x1F9), (0x1FB, 0x1FB), (0x1FD, 0x1FD), (0x1FF, 0x1FF), (0x201, 0x201), (0x203, 0x203),
+        (0x205, 0x205), (0x207, 0x207), (0x209, 0x209), (0x20B, 0x20B), (0x20D, 0x20D), (0x20F, 0x20F),
+        (0x211, 0x211), (0x213, 0x213), (0x215, 0x215), (0x217, 0x217), (0x219, 0x219), (0x21B, 0x21B),
+        (0x21D, 0x21D), (0x21F, 0x21F), (0x223, 0x223), (0x225, 0x225), (0x227, 0x227), (0x229, 0x229),
+        (0x22B, 0x22B), (0x22D, 0x22D), (0x22F, 0x22F), (0x231, 0x231), (0x233, 0x233), (0x23C, 0x23C),
+        (0x23F, 0x240), (0x242, 0x242), (0x247, 0x247), (0x249, 0x249), (0x24B, 0x24B), (0x24D, 0x24D),
+        (0x24F, 0x254), (0x256, 0x257), (0x259, 0x259), (0x25B, 0x25C), (0x260, 0x261), (0x263, 0x266),
+        (0x268, 0x26C), (0x26F, 0x26F), (0x271, 0x272), (0x275, 0x275), (0x27D, 0x27D), (0x280, 0x280),
+        (0x282, 0x283), (0x287, 0x28C), (0x292, 0x292), (0x29D, 0x29E), (0x345, 0x345), (0x371, 0x371),
+        (0x373, 0x373), (0x377, 0x377), (0x37B, 0x37D), (0x390, 0x390), (0x3AC, 0x3CE), (0x3D0, 0x3D1),
+        (0x3D5, 0x3D7), (0x3D9, 0x3D9), (0x3DB, 0x3DB), (0x3DD, 0x3DD), (0x3DF, 0x3DF), (0x3E1, 0x3E1),
+        (0x3E3, 0x3E3), (0x3E5, 0x3E5), (0x3E7, 0x3E7), (0x3E9, 0x3E9), (0x3EB, 0x3EB), (0x3ED, 0x3ED),
+        (0x3EF, 0x3F3), (0x3F5, 0x3F5), (0x3F8, 0x3F8), (0x3FB, 0x3FB), (0x430, 0x45F), (0x461, 0x461),
+        (0x463, 0x463), (0x465, 0x465), (0x467, 0x467), (0x469, 0x469), (0x46B, 0x46B), (0x46D, 0x46D),
+        (0x46F, 0x46F), (0x471, 0x471), (0x473, 0x473), (0x475, 0x475), (0x477, 0x477), (0x479, 0x479),
+        (0x47B, 0x47B), (0x47D, 0x47D), (0x47F, 0x47F), (0x481, 0x481), (0x48B, 0x48B), (0x48D, 0x48D),
+        (0x48F, 0x48F), (0x491, 0x491), (0x493, 0x493), (0x495, 0x495), (0x497, 0x497), (0x499, 0x499),
+        (0x49B, 0x49B), (0x49D, 0x49D), (0x49F, 0x49F), (0x4A1, 0x4A1), (0x4A3, 0x4A3), (0x4A5, 0x4A5),
+        (0x4A7, 0x4A7), (0x4A9, 0x4A9), (0x4AB, 0x4AB), (0x4AD, 0x4AD), (0x4AF, 0x4AF), (0x4B1, 0x4B1),
+        (0x4B3, 0x4B3), (0x4B5, 0x4B5), (0x4B7, 0x4B7), (0x4B9, 0x4B9), (0x4BB, 0x4BB), (0x4BD, 0x4BD),
+        (0x4BF, 0x4BF), (0x4C2, 0x4C2), (0x4C4, 0x4C4), (0x4C6, 0x4C6), (0x4C8, 0x4C8), (0x4CA, 0x4CA),
+        (0x4CC, 0x4CC), (0x4CE, 0x4CF), (0x4D1, 0x4D1), (0x4D3, 0x4D3), (0x4D5, 0x4D5), (0x4D7, 0x4D7),
+        (0x4D9, 0x4D9), (0x4DB, 0x4DB), (0x4DD, 0x4DD), (0x4DF, 0x4DF), (0x4E1, 0x4E1), (0x4E3, 0x4E3),
+        (0x4E5, 0x4E5), (0x4E7, 0x4E7), (0x4E9, 0x4E9), (0x4EB, 0x4EB), (0x4ED, 0x4ED), (0x4EF, 0x4EF),
+        (0x4F1, 0x4F1), (0x4F3, 0x4F3), (0x4F5, 0x4F5), (0x4F7, 0x4F7), (0x4F9, 0x4F9), (0x4FB, 0x4FB),
+        (0x4FD, 0x4FD), (0x4FF, 0x4FF), (0x501, 0x501), (0x503, 0x503), (0x505, 0x505), (0x507, 0x507),
+        (0x509, 0x509), (0x50B, 0x50B), (0x50D, 0x50D), (0x50F, 0x50F), (0x511, 0x511), (0x513, 0x513),
+        (0x515, 0x515), (0x517, 0x517), (0x519, 0x519), (0x51B, 0x51B), (0x51D, 0x51D), (0x51F, 0x51F),
+        (0x521, 0x521), (0x523, 0x523), (0x525, 0x525), (0x527, 0x527), (0x529, 0x529), (0x52B, 0x52B),
+        (0x52D, 0x52D), (0x52F, 0x52F), (0x561, 0x587), (0x13F8, 0x13FD), (0x1C80, 0x1C88), (0x1C8A, 0x1C8A),
+        (0x1D79, 0x1D79), (0x1D7D, 0x1D7D), (0x1D8E, 0x1D8E), (0x1E01, 0x1E01), (0x1E03, 0x1E03), (0x1E05, 0x1E05),
+        (0x1E07, 0x1E07), (0x1E09, 0x1E09), (0x1E0B, 0x1E0B), (0x1E0D, 0x1E0D), (0x1E0F, 0x1E0F), (0x1E11, 0x1E11),
+        (0x1E13, 0x1E13), (0x1E15, 0x1E15), (0x1E17, 0x1E17), (0x1E19, 0x1E19), (0x1E1B, 0x1E1B), (0x1E1D, 0x1E1D),
+        (0x1E1F, 0x1E1F), (0x1E21, 0x1E21), (0x1E23, 0x1E23), (0x1E25, 0x1E25), (0x1E27, 0x1E27), (0x1E29, 0x1E29),
+        (0x1E2B, 0x1E2B), (0x1E2D, 0x1E2D), (0x1E2F, 0x1E2F), (0x1E31, 0x1E31), (0x1E33, 0x1E33), (0x1E35, 0x1E35),
+        (0x1E37, 0x1E37), (0x1E39, 0x1E39), (0x1E3B, 0x1E3B), (0x1E3D, 0x1E3D), (0x1E3F, 0x1E3F), (0x1E41, 0x1E41),
+        (0x1E43, 0x1E43), (0x1E45, 0x1E45), (0x1E47, 0x1E47), (0x1E49, 0x1E49), (0x1E4B, 0x1E4B), (0x1E4D, 0x1E4D),
+        (0x1E4F, 0x1E4F), (0x1E51, 0x1E51), (0x1E53, 0x1E53), (0x1E55, 0x1E55), (0x1E57, 0x1E57), (0x1E59, 0x1E59),
+        (0x1E5B, 0x1E5B), (0x1E5D, 0x1E5D), (0x1E5F, 0x1E5F), (0x1E61, 0x1E61), (0x1E63, 0x1E63), (0x1E65, 0x1E65),
+        (0x1E67, 0x1E67), (0x1E69, 0x1E69), (0x1E6B, 0x1E6B), (0x1E6D, 0x1E6D), (0x1E6F, 0x1E6F), (0x1E71, 0x1E71),
+        (0x1E73, 0x1E73), (0x1E75, 0x1E75), (0x1E77, 0x1E77), (0x1E79, 0x1E79), (0x1E7B, 0x1E7B), (0x1E7D, 0x1E7D),
+        (0x1E7F, 0x1E7F), (0x1E81, 0x1E81), (0x1E83, 0x1E83), (0x1E85, 0x1E85), (0x1E87, 0x1E87), (0x1E89, 0x1E89),
+        (0x1E8B, 0x1E8B), (0x1E8D, 0x1E8D), (0x1E8F, 0x1E8F), (0x1E91, 0x1E91), (0x1E93, 0x1E93), (0x1E95, 0x1E9B),
+        (0x1EA1, 0x1EA1), (0x1EA3, 0x1EA3), (0x1EA5, 0x1EA5), (0x1EA7, 0x1EA7), (0x1EA9, 0x1EA9), (0x1EAB, 0x1EAB),
+        (0x1EAD, 0x1EAD), (0x1EAF, 0x1EAF), (0x1EB1, 0x1EB1), (0x1EB3, 0x1EB3), (0x1EB5, 0x1EB5), (0x1EB7, 0x1EB7),
+        (0x1EB9, 0x1EB9), (0x1EBB, 0x1EBB), (0x1EBD, 0x1EBD), (0x1EBF, 0x1EBF), (0x1EC1, 0x1EC1), (0x1EC3, 0x1EC3),
+        (0x1EC5, 0x1EC5), (0x1EC7, 0x1EC7), (0x1EC9, 0x1EC9), (0x1ECB, 0x1ECB), (0x1ECD, 0x1ECD), (0x1ECF, 0x1ECF),
+        (0x1ED1, 0x1ED1), (0x1ED3, 0x1ED3), (0x1ED5, 0x1ED5), (0x1ED7, 0x1ED7), (0x1ED9, 0x1ED9), (0x1EDB, 0x1EDB),
+        (0x1EDD, 0x1EDD), (0x1EDF, 0x1EDF), (0x1EE1, 0x1EE1), (0x1EE3, 0x1EE3), (0x1EE5, 0x1EE5), (0x1EE7, 0x1EE7),
+        (0x1EE9, 0x1EE9), (0x1EEB, 0x1EEB), (0x1EED, 0x1EED), (0x1EEF, 0x1EEF), (0x1EF1, 0x1EF1), (0x1EF3, 0x1EF3),
+        (0x1EF5, 0x1EF5), (0x1EF7, 0x1EF7), (0x1EF9, 0x1EF9), (0x1EFB, 0x1EFB), (0x1EFD, 0x1EFD), (0x1EFF, 0x1F07),
+        (0x1F10, 0x1F15), (0x1F20, 0x1F27), (0x1F30, 0x1F37), (0x1F40, 0x1F45), (0x1F50, 0x1F57), (0x1F60, 0x1F67),
+        (0x1F70, 0x1F7D), (0x1F80, 0x1F87), (0x1F90, 0x1F97), (0x1FA0, 0x1FA7), (0x1FB0, 0x1FB4), (0x1FB6, 0x1FB7),
+        (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4), (0x1FC6, 0x1FC7), (0x1FD0, 0x1FD3), (0x1FD6, 0x1FD7), (0x1FE0, 0x1FE7),
+        (0x1FF2, 0x1FF4), (0x1FF6, 0x1FF7), (0x214E, 0x214E), (0x2170, 0x217F), (0x2184, 0x2184), (0x24D0, 0x24E9),
+        (0x2C30, 0x2C5F), (0x2C61, 0x2C61), (0x2C65, 0x2C66), (0x2C68, 0x2C68), (0x2C6A, 0x2C6A), (0x2C6C, 0x2C6C),
+        (0x2C73, 0x2C73), (0x2C76, 0x2C76), (0x2C81, 0x2C81), (0x2C83, 0x2C83), (0x2C85, 0x2C85), (0x2C87, 0x2C87),
+        (0x2C89, 0x2C89), (0x2C8B, 0x2C8B), (0x2C8D, 0x2C8D), (0x2C8F, 0x2C8F), (0x2C91, 0x2C91), (0x2C93, 0x2C93),
+        (0x2C95, 0x2C95), (0x2C97, 0x2C97), (0x2C99, 0x2C99), (0x2C9B, 0x2C9B), (0x2C9D, 0x2C9D), (0x2C9F, 0x2C9F),
+        (0x2CA1, 0x2CA1), (0x2CA3, 0x2CA3), (0x2CA5, 0x2CA5), (0x2CA7, 0x2CA7), (0x2CA9, 0x2CA9), (0x2CAB, 0x2CAB),
+        (0x2CAD, 0x2CAD), (0x2CAF, 0x2CAF), (0x2CB1, 0x2CB1), (0x2CB3, 0x2CB3), (0x2CB5, 0x2CB5), (0x2CB7, 0x2CB7),
+        (0x2CB9, 0x2CB9), (0x2CBB, 0x2CBB), (0x2CBD, 0x2CBD), (0x2CBF, 0x2CBF), (0x2CC1, 0x2CC1), (0x2CC3, 0x2CC3),
+        (0x2CC5, 0x2CC5), (0x2CC7, 0x2CC7), (0x2CC9, 0x2CC9), (0x2CCB, 0x2CCB), (0x2CCD, 0x2CCD), (0x2CCF, 0x2CCF),
+        (0x2CD1, 0x2CD1), (0x2CD3, 0x2CD3), (0x2CD5, 0x2CD5), (0x2CD7, 0x2CD7), (0x2CD9, 0x2CD9), (0x2CDB, 0x2CDB),
+        (0x2CDD, 0x2CDD), (0x2CDF, 0x2CDF), (0x2CE1, 0x2CE1), (0x2CE3, 0x2CE3), (0x2CEC, 0x2CEC), (0x2CEE, 0x2CEE),
+        (0x2CF3, 0x2CF3), (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0xA641, 0xA641), (0xA643, 0xA643),
+        (0xA645, 0xA645), (0xA647, 0xA647), (0xA649, 0xA649), (0xA64B, 0xA64B), (0xA64D, 0xA64D), (0xA64F, 0xA64F),
+        (0xA651, 0xA651), (0xA653, 0xA653), (0xA655, 0xA655), (0xA657, 0xA657), (0xA659, 0xA659), (0xA65B, 0xA65B),
+        (0xA65D, 0xA65D), (0xA65F, 0xA65F), (0xA661, 0xA661), (0xA663, 0xA663), (0xA665, 0xA665), (0xA667, 0xA667),
+        (0xA669, 0xA669), (0xA66B, 0xA66B), (0xA66D, 0xA66D), (0xA681, 0xA681), (0xA683, 0xA683), (0xA685, 0xA685),
+        (0xA687, 0xA687), (0xA689, 0xA689), (0xA68B, 0xA68B), (0xA68D, 0xA68D), (0xA68F, 0xA68F), (0xA691, 0xA691),
+        (0xA693, 0xA693), (0xA695, 0xA695), (0xA697, 0xA697), (0xA699, 0xA699), (0xA69B, 0xA69B), (0xA723, 0xA723),
+        (0xA725, 0xA725), (0xA727, 0xA727), (0xA729, 0xA729), (0xA72B, 0xA72B), (0xA72D, 0xA72D), (0xA72F, 0xA72F),
+        (0xA733, 0xA733), (0xA735, 0xA735), (0xA737, 0xA737), (0xA739, 0xA739), (0xA73B, 0xA73B), (0xA73D, 0xA73D),
+        (0xA73F, 0xA73F), (0xA741, 0xA741), (0xA743, 0xA743), (0xA745, 0xA745), (0xA747, 0xA747), (0xA749, 0xA749),
+        (0xA74B, 0xA74B), (0xA74D, 0xA74D), (0xA74F, 0xA74F), (0xA751, 0xA751), (0xA753, 0xA753), (0xA755, 0xA755),
+        (0xA757, 0xA757), (0xA759, 0xA759), (0xA75B, 0xA75B), (0xA75D, 0xA75D), (0xA75F, 0xA75F), (0xA761, 0xA761),
+        (0xA763, 0xA763), (0xA765, 0xA765), (0xA767, 0xA767), (0xA769, 0xA769), (0xA76B, 0xA76B), (0xA76D, 0xA76D),
+        (0xA76F, 0xA76F), (0xA77A, 0xA77A), (0xA77C, 0xA77C), (0xA77F, 0xA77F), (0xA781, 0xA781), (0xA783, 0xA783),
+        (0xA785, 0xA785), (0xA787, 0xA787), (0xA78C, 0xA78C), (0xA791, 0xA791), (0xA793, 0xA794), (0xA797, 0xA797),
+        (0xA799, 0xA799), (0xA79B, 0xA79B), (0xA79D, 0xA79D), (0xA79F, 0xA79F), (0xA7A1, 0xA7A1), (0xA7A3, 0xA7A3),
+        (0xA7A5, 0xA7A5), (0xA7A7, 0xA7A7), (0xA7A9, 0xA7A9), (0xA7B5, 0xA7B5), (0xA7B7, 0xA7B7), (0xA7B9, 0xA7B9),
+        (0xA7BB, 0xA7BB), (0xA7BD, 0xA7BD), (0xA7BF, 0xA7BF), (0xA7C1, 0xA7C1), (0xA7C3, 0xA7C3), (0xA7C8, 0xA7C8),
+        (0xA7CA, 0xA7CA), (0xA7CD, 0xA7CD), (0xA7CF, 0xA7CF), (0xA7D1, 0xA7D1), (0xA7D3, 0xA7D3), (0xA7D5, 0xA7D5),
+        (0xA7D7, 0xA7D7), (0xA7D9, 0xA7D9), (0xA7DB, 0xA7DB), (0xA7F6, 0xA7F6), (0xAB53, 0xAB53), (0xAB70, 0xABBF),
+        (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFF41, 0xFF5A), (0x10428, 0x1044F), (0x104D8, 0x104FB), (0x10597, 0x105A1),
+        (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x10CC0, 0x10CF2), (0x10D70, 0x10D85), (0x118C0, 0x118DF),
+        (0x16E60, 0x16E7F), (0x16EBB, 0x16ED3), (0x1E922, 0x1E943),
+    ]),
+    ("Changes_When_Uppercased", "CWU", &[
+        (0x61, 0x7A), (0xB5, 0xB5), (0xDF, 0xF6), (0xF8, 0xFF), (0x101, 0x101), (0x103, 0x103),
+        (0x105, 0x105), (0x107, 0x107), (0x109, 0x109), (0x10B, 0x10B), (0x10D, 0x10D), (0x10F, 0x10F),
+        (0x111, 0x111), (0x113, 0x113), (0x115, 0x115), (0x117, 0x117), (0x119, 0x119), (0x11B, 0x11B),
+        (0x11D, 0x11D), (0x11F, 0x11F), (0x121, 0x121), (0x123, 0x123), (0x125, 0x125), (0x127, 0x127),
+        (0x129, 0x129), (0x12B, 0x12B), (0x12D, 0x12D), (0x12F, 0x12F), (0x131, 0x131), (0x133, 0x133),
+        (0x135, 0x135), (0x137, 0x137), (0x13A, 0x13A), (0x13C, 0x13C), (0x13E, 0x13E), (0x140, 0x140),
+        (0x142, 0x142), (0x144, 0x144), (0x146, 0x146), (0x148, 0x149), (0x14B, 0x14B), (0x14D, 0x14D),
+        (0x14F, 0x14F), (0x151, 0x151), (0x153, 0x153), (0x155, 0x155), (0x157, 0x157), (0x159, 0x159),
+        (0x15B, 0x15B), (0x15D, 0x15D), (0x15F, 0x15F), (0x161, 0x161), (0x163, 0x163), (0x165, 0x165),
+        (0x167, 0x167), (0x169, 0x169), (0x16B, 0x16B), (0x16D, 0x16D), (0x16F, 0x16F), (0x171, 0x171),
+        (0x173, 0x173), (0x175, 0x175), (0x177, 0x177), (0x17A, 0x17A), (0x17C, 0x17C), (0x17E, 0x180),
+        (0x183, 0x183), (0x185, 0x185), (0x188, 0x188), (0x18C, 0x18C), (0x192, 0x192), (0x195, 0x195),
+        (0x199, 0x19B), (0x19E, 0x19E), (0x1A1, 0x1A1), (0x1A3, 0x1A3), (0x1A5, 0x1A5), (0x1A8, 0x1A8),
+        (0x1AD, 0x1AD), (0x1B0, 0x1B0), (0x1B4, 0x1B4), (0x1B6, 0x1B6), (0x1B9, 0x1B9), (0x1BD, 0x1BD),
+        (0x1BF, 0x1BF), (0x1C5, 0x1C6), (0x1C8, 0x1C9), (0x1CB, 0x1CC), (0x1CE, 0x1CE), (0x1D0, 0x1D0),
+        (0x1D2, 0x1D2), (0x1D4, 0x1D4), (0x1D6, 0x1D6), (0x1D8, 0x1D8), (0x1DA, 0x1DA), (0x1DC, 0x1DD),
+        (0x1DF, 0x1DF), (0x1E1, 0x1E1), (0x1E3, 0x1E3), (0x1E5, 0x1E5), (0x1E7, 0x1E7), (0x1E9, 0x1E9),
+        (0x1EB, 0x1EB), (0x1ED, 0x1ED), (0x1EF, 0x1F0), (0x1F2, 0x1F3), (0x1F5, 0x1F5), (0x1F9, 0x1F9),
+        (0x1FB, 0x1FB), (0x1FD, 0x1FD), (0x1FF, 0x1FF), (0x201, 0x201), (0x203, 0x203), (0x205, 0x205),
+        (0x207, 0x207), (0x209, 0x209), (0x20B, 0x20B), (0x20D, 0x20D), (0x20F, 0x20F), (0x211, 0x211),
+        (0x213, 0x213), (0x215, 0x215), (0x217, 0x217), (0x219, 0x219), (0x21B, 0x21B), (0x21D, 0x21D),
+        (0x21F, 0x21F), (0x223, 0x223), (0x225, 0x225), (0x227, 0x227), (0x229, 0x229), (0x22B, 0x22B),
+        (0x22D, 0x22D), (0x22F, 0x22F), (0x231, 0x231), (0x233, 0x233), (0x23C, 0x23C), (0x23F, 0x240),
+        (0x242, 0x242), (0x247, 0x247), (0x249, 0x249), (0x24B, 0x24B), (0x24D, 0x24D), (0x24F, 0x254),
+        (0x256, 0x257), (0x259, 0x259), (0x25B, 0x25C), (0x260, 0x261), (0x263, 0x266), (0x268, 0x26C),
+        (0x26F, 0x26F), (0x271, 0x272), (0x275, 0x275), (0x27D, 0x27D), (0x280, 0x280), (0x282, 0x283),
+        (0x287, 0x28C), (0x292, 0x292), (0x29D, 0x29E), (0x345, 0x345), (0x371, 0x371), (0x373, 0x373),
+        (0x377, 0x377), (0x37B, 0x37D), (0x390, 0x390), (0x3AC, 0x3CE), (0x3D0, 0x3D1), (0x3D5, 0x3D7),
+        (0x3D9, 0x3D9), (0x3DB, 0x3DB), (0x3DD, 0x3DD), (0x3DF, 0x3DF), (0x3E1, 0x3E1), (0x3E3, 0x3E3),
+        (0x3E5, 0x3E5), (0x3E7, 0x3E7), (0x3E9, 0x3E9), (0x3EB, 0x3EB), (0x3ED, 0x3ED), (0x3EF, 0x3F3),
+        (0x3F5, 0x3F5), (0x3F8, 0x3F8), (0x3FB, 0x3FB), (0x430, 0x45F), (0x461, 0x461), (0x463, 0x463),
+        (0x465, 0x465), (0x467, 0x467), (0x469, 0x469), (0x46B, 0x46B), (0x46D, 0x46D), (0x46F, 0x46F),
+        (0x471, 0x471), (0x473, 0x473), (0x475, 0x475), (0x477, 0x477), (0x479, 0x479), (0x47B, 0x47B),
+        (0x47D, 0x47D), (0x47F, 0x47F), (0x481, 0x481), (0x48B, 0x48B), (0x48D, 0x48D), (0x48F, 0x48F),
+        (0x491, 0x491), (0x493, 0x493), (0x495, 0x495), (0x497, 0x497), (0x499, 0x499), (0x49B, 0x49B),
+        (0x49D, 0x49D), (0x49F, 0x49F), (0x4A1, 0x4A1), (0x4A3, 0x4A3), (0x4A5, 0x4A5), (0x4A7, 0x4A7),
+        (0x4A9, 0x4A9), (0x4AB, 0x4AB), (0x4AD, 0x4AD), (0x4AF, 0x4AF), (0x4B1, 0x4B1), (0x4B3, 0x4B3),
+        (0x4B5, 0x4B5), (0x4B7, 0x4B7), (0x4B9, 0x4B9), (0x4BB, 0x4BB), (0x4BD, 0x4BD), (0x4BF, 0x4BF),
+        (0x4C2, 0x4C2), (0x4C4, 0x4C4), (0x4C6, 0x4C6), (0x4C8, 0x4C8), (0x4CA, 0x4CA), (0x4CC, 0x4CC),
+        (0x4CE, 0x4CF), (0x4D1, 0x4D1), (0x4D3, 0x4D3), (0x4D5, 0x4D5), (0x4D7, 0x4D7), (0x4D9, 0x4D9),
+        (0x4DB, 0x4DB), (0x4DD, 0x4DD), (0x4DF, 0x4DF), (0x4E1, 0x4E1), (0x4E3, 0x4E3), (0x4E5, 0x4E5),
+        (0x4E7, 0x4E7), (0x4E9, 0x4E9), (0x4EB, 0x4EB), (0x4ED, 0x4ED), (0x4EF, 0x4EF), (0x4F1, 0x4F1),
+        (0x4F3, 0x4F3), (0x4F5, 0x4F5), (0x4F7, 0x4F7), (0x4F9, 0x4F9), (0x4FB, 0x4FB), (0x4FD, 0x4FD),
+        (0x4FF, 0x4FF), (0x501, 0x501), (0x503, 0x503), (0x505, 0x505), (0x507, 0x507), (0x509, 0x509),
+        (0x50B, 0x50B), (0x50D, 0x50D), (0x50F, 0x50F), (0x511, 0x511), (0x513, 0x513), (0x515, 0x515),
+        (0x517, 0x517), (0x519, 0x519), (0x51B, 0x51B), (0x51D, 0x51D), (0x51F, 0x51F), (0x521, 0x521),
+        (0x523, 0x523), (0x525, 0x525), (0x527, 0x527), (0x529, 0x529), (0x52B, 0x52B), (0x52D, 0x52D),
+        (0x52F, 0x52F), (0x561, 0x587), (0x10D0, 0x10FA), (0x10FD, 0x10FF), (0x13F8, 0x13FD), (0x1C80, 0x1C88),
+        (0x1C8A, 0x1C8A), (0x1D79, 0x1D79), (0x1D7D, 0x1D7D), (0x1D8E, 0x1D8E), (0x1E01, 0x1E01), (0x1E03, 0x1E03),
+        (0x1E05, 0x1E05), (0x1E07, 0x1E07), (0x1E09, 0x1E09), (0x1E0B, 0x1E0B), (0x1E0D, 0x1E0D), (0x1E0F, 0x1E0F),
+        (0x1E11, 0x1E11), (0x1E13, 0x1E13), (0x1E15, 0x1E15), (0x1E17, 0x1E17), (0x1E19, 0x1E19), (0x1E1B, 0x1E1B),
+        (0x1E1D, 0x1E1D), (0x1E1F, 0x1E1F), (0x1E21, 0x1E21), (0x1E23, 0x1E23), (0x1E25, 0x1E25), (0x1E27, 0x1E27),
+        (0x1E29, 0x1E29), (0x1E2B, 0x1E2B), (0x1E2D, 0x1E2D), (0x1E2F, 0x1E2F), (0x1E31, 0x1E31), (0x1E33, 0x1E33),
+        (0x1E35, 0x1E35), (0x1E37, 0x1E37), (0x1E39, 0x1E39), (0x1E3B, 0x1E3B), (0x1E3D, 0x1E3D), (0x1E3F, 0x1E3F),
+        (0x1E41, 0x1E41), (0x1E43, 0x1E43), (0x1E45, 0x1E45), (0x1E47, 0x1E47), (0x1E49, 0x1E49), (0x1E4B, 0x1E4B),
+        (0x1E4D, 0x1E4D), (0x1E4F, 0x1E4F), (0x1E51, 0x1E51), (0x1E53, 0x1E53), (0x1E55, 0x1E55), (0x1E57, 0x1E57),
+        (0x1E59, 0x1E59), (0x1E5B, 0x1E5B), (0x1E5D, 0x1E5D), (0x1E5F, 0x1E5F), (0x1E61, 0x1E61), (0x1E63, 0x1E63),
+        (0x1E65, 0x1E65), (0x1E67, 0x1E67), (0x1E69, 0x1E69), (0x1E6B, 0x1E6B), (0x1E6D, 0x1E6D), (0x1E6F, 0x1E6F),
+        (0x1E71, 0x1E71), (0x1E73, 0x1E73), (0x1E75, 0x1E75), (0x1E77, 0x1E77), (0x1E79, 0x1E79), (0x1E7B, 0x1E7B),
+        (0x1E7D, 0x1E7D), (0x1E7F, 0x1E7F), (0x1E81, 0x1E81), (0x1E83, 0x1E83), (0x1E85, 0x1E85), (0x1E87, 0x1E87),
+        (0x1E89, 0x1E89), (0x1E8B, 0x1E8B), (0x1E8D, 0x1E8D), (0x1E8F, 0x1E8F), (0x1E91, 0x1E91), (0x1E93, 0x1E93),
+        (0x1E95, 0x1E9B), (0x1EA1, 0x1EA1), (0x1EA3, 0x1EA3), (0x1EA5, 0x1EA5), (0x1EA7, 0x1EA7), (0x1EA9, 0x1EA9),
+        (0x1EAB, 0x1EAB), (0x1EAD, 0x1EAD), (0x1EAF, 0x1EAF), (0x1EB1, 0x1EB1), (0x1EB3, 0x1EB3), (0x1EB5, 0x1EB5),
+        (0x1EB7, 0x1EB7), (0x1EB9, 0x1EB9), (0x1EBB, 0x1EBB), (0x1EBD, 0x1EBD), (0x1EBF, 0x1EBF), (0x1EC1, 0x1EC1),
+        (0x1EC3, 0x1EC3), (0x1EC5, 0x1EC5), (0x1EC7, 0x1EC7), (0x1EC9, 0x1EC9), (0x1ECB, 0x1ECB), (0x1ECD, 0x1ECD),
+        (0x1ECF, 0x1ECF), (0x1ED1, 0x1ED1), (0x1ED3, 0x1ED3), (0x1ED5, 0x1ED5), (0x1ED7, 0x1ED7), (0x1ED9, 0x1ED9),
+        (0x1EDB, 0x1EDB), (0x1EDD, 0x1EDD), (0x1EDF, 0x1EDF), (0x1EE1, 0x1EE1), (0x1EE3, 0x1EE3), (0x1EE5, 0x1EE5),
+        (0x1EE7, 0x1EE7), (0x1EE9, 0x1EE9), (0x1EEB, 0x1EEB), (0x1EED, 0x1EED), (0x1EEF, 0x1EEF), (0x1EF1, 0x1EF1),
+        (0x1EF3, 0x1EF3), (0x1EF5, 0x1EF5), (0x1EF7, 0x1EF7), (0x1EF9, 0x1EF9), (0x1EFB, 0x1EFB), (0x1EFD, 0x1EFD),
+        (0x1EFF, 0x1F07), (0x1F10, 0x1F15), (0x1F20, 0x1F27), (0x1F30, 0x1F37), (0x1F40, 0x1F45), (0x1F50, 0x1F57),
+        (0x1F60, 0x1F67), (0x1F70, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FB7), (0x1FBC, 0x1FBC), (0x1FBE, 0x1FBE),
+        (0x1FC2, 0x1FC4), (0x1FC6, 0x1FC7), (0x1FCC, 0x1FCC), (0x1FD0, 0x1FD3), (0x1FD6, 0x1FD7), (0x1FE0, 0x1FE7),
+        (0x1FF2, 0x1FF4), (0x1FF6, 0x1FF7), (0x1FFC, 0x1FFC), (0x214E, 0x214E), (0x2170, 0x217F), (0x2184, 0x2184),
+        (0x24D0, 0x24E9), (0x2C30, 0x2C5F), (0x2C61, 0x2C61), (0x2C65, 0x2C66), (0x2C68, 0x2C68), (0x2C6A, 0x2C6A),
+        (0x2C6C, 0x2C6C), (0x2C73, 0x2C73), (0x2C76, 0x2C76), (0x2C81, 0x2C81), (0x2C83, 0x2C83), (0x2C85, 0x2C85),
+        (0x2C87, 0x2C87), (0x2C89, 0x2C89), (0x2C8B, 0x2C8B), (0x2C8D, 0x2C8D), (0x2C8F, 0x2C8F), (0x2C91, 0x2C91),
+        (0x2C93, 0x2C93), (0x2C95, 0x2C95), (0x2C97, 0x2C97), (0x2C99, 0x2C99), (0x2C9B, 0x2C9B), (0x2C9D, 0x2C9D),
+        (0x2C9F, 0x2C9F), (0x2CA1, 0x2CA1), (0x2CA3, 0x2CA3), (0x2CA5, 0x2CA5), (0x2CA7, 0x2CA7), (0x2CA9, 0x2CA9),
+        (0x2CAB, 0x2CAB), (0x2CAD, 0x2CAD), (0x2CAF, 0x2CAF), (0x2CB1, 0x2CB1), (0x2CB3, 0x2CB3), (0x2CB5, 0x2CB5),
+        (0x2CB7, 0x2CB7), (0x2CB9, 0x2CB9), (0x2CBB, 0x2CBB), (0x2CBD, 0x2CBD), (0x2CBF, 0x2CBF), (0x2CC1, 0x2CC1),
+        (0x2CC3, 0x2CC3), (0x2CC5, 0x2CC5), (0x2CC7, 0x2CC7), (0x2CC9, 0x2CC9), (0x2CCB, 0x2CCB), (0x2CCD, 0x2CCD),
+        (0x2CCF, 0x2CCF), (0x2CD1, 0x2CD1), (0x2CD3, 0x2CD3), (0x2CD5, 0x2CD5), (0x2CD7, 0x2CD7), (0x2CD9, 0x2CD9),
+        (0x2CDB, 0x2CDB), (0x2CDD, 0x2CDD), (0x2CDF, 0x2CDF), (0x2CE1, 0x2CE1), (0x2CE3, 0x2CE3), (0x2CEC, 0x2CEC),
+        (0x2CEE, 0x2CEE), (0x2CF3, 0x2CF3), (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0xA641, 0xA641),
+        (0xA643, 0xA643), (0xA645, 0xA645), (0xA647, 0xA647), (0xA649, 0xA649), (0xA64B, 0xA64B), (0xA64D, 0xA64D),
+        (0xA64F, 0xA64F), (0xA651, 0xA651), (0xA653, 0xA653), (0xA655, 0xA655), (0xA657, 0xA657), (0xA659, 0xA659),
+        (0xA65B, 0xA65B), (0xA65D, 0xA65D), (0xA65F, 0xA65F), (0xA661, 0xA661), (0xA663, 0xA663), (0xA665, 0xA665),
+        (0xA667, 0xA667), (0xA669, 0xA669), (0xA66B, 0xA66B), (0xA66D, 0xA66D), (0xA681, 0xA681), (0xA683, 0xA683),
+        (0xA685, 0xA685), (0xA687, 0xA687), (0xA689, 0xA689), (0xA68B, 0xA68B), (0xA68D, 0xA68D), (0xA68F, 0xA68F),
+        (0xA691, 0xA691), (0xA693, 0xA693), (0xA695, 0xA695), (0xA697, 0xA697), (0xA699, 0xA699), (0xA69B, 0xA69B),
+        (0xA723, 0xA723), (0xA725, 0xA725), (0xA727, 0xA727), (0xA729, 0xA729), (0xA72B, 0xA72B), (0xA72D, 0xA72D),
+        (0xA72F, 0xA72F), (0xA733, 0xA733), (0xA735, 0xA735), (0xA737, 0xA737), (0xA739, 0xA739), (0xA73B, 0xA73B),
+        (0xA73D, 0xA73D), (0xA73F, 0xA73F), (0xA741, 0xA741), (0xA743, 0xA743), (0xA745, 0xA745), (0xA747, 0xA747),
+        (0xA749, 0xA749), (0xA74B, 0xA74B), (0xA74D, 0xA74D), (0xA74F, 0xA74F), (0xA751, 0xA751), (0xA753, 0xA753),
+        (0xA755, 0xA755), (0xA757, 0xA757), (0xA759, 0xA759), (0xA75B, 0xA75B), (0xA75D, 0xA75D), (0xA75F, 0xA75F),
+        (0xA761, 0xA761), (0xA763, 0xA763), (0xA765, 0xA765), (0xA767, 0xA767), (0xA769, 0xA769), (0xA76B, 0xA76B),
+        (0xA76D, 0xA76D), (0xA76F, 0xA76F), (0xA77A, 0xA77A), (0xA77C, 0xA77C), (0xA77F, 0xA77F), (0xA781, 0xA781),
+        (0xA783, 0xA783), (0xA785, 0xA785), (0xA787, 0xA787), (0xA78C, 0xA78C), (0xA791, 0xA791), (0xA793, 0xA794),
+        (0xA797, 0xA797), (0xA799, 0xA799), (0xA79B, 0xA79B), (0xA79D, 0xA79D), (0xA79F, 0xA79F), (0xA7A1, 0xA7A1),
+        (0xA7A3, 0xA7A3), (0xA7A5, 0xA7A5), (0xA7A7, 0xA7A7), (0xA7A9, 0xA7A9), (0xA7B5, 0xA7B5), (0xA7B7, 0xA7B7),
+        (0xA7B9, 0xA7B9), (0xA7BB, 0xA7BB), (0xA7BD, 0xA7BD), (0xA7BF, 0xA7BF), (0xA7C1, 0xA7C1), (0xA7C3, 0xA7C3),
+        (0xA7C8, 0xA7C8), (0xA7CA, 0xA7CA), (0xA7CD, 0xA7CD), (0xA7CF, 0xA7CF), (0xA7D1, 0xA7D1), (0xA7D3, 0xA7D3),
+        (0xA7D5, 0xA7D5), (0xA7D7, 0xA7D7), (0xA7D9, 0xA7D9), (0xA7DB, 0xA7DB), (0xA7F6, 0xA7F6), (0xAB53, 0xAB53),
+        (0xAB70, 0xABBF), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFF41, 0xFF5A), (0x10428, 0x1044F), (0x104D8, 0x104FB),
+        (0x10597, 0x105A1), (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x10CC0, 0x10CF2), (0x10D70, 0x10D85),
+        (0x118C0, 0x118DF), (0x16E60, 0x16E7F), (0x16EBB, 0x16ED3), (0x1E922, 0x1E943),
+    ]),
+    ("Dash", "", &[
+        (0x2D, 0x2D), (0x58A, 0x58A), (0x5BE, 0x5BE), (0x1400, 0x1400), (0x1806, 0x1806), (0x2010, 0x2015),
+        (0x2053, 0x2053), (0x207B, 0x207B), (0x208B, 0x208B), (0x2212, 0x2212), (0x2E17, 0x2E17), (0x2E1A, 0x2E1A),
+        (0x2E3A, 0x2E3B), (0x2E40, 0x2E40), (0x2E5D, 0x2E5D), (0x301C, 0x301C), (0x3030, 0x3030), (0x30A0, 0x30A0),
+        (0xFE31, 0xFE32), (0xFE58, 0xFE58), (0xFE63, 0xFE63), (0xFF0D, 0xFF0D), (0x10D6E, 0x10D6E), (0x10EAD, 0x10EAD),
+    ]),
+    ("Default_Ignorable_Code_Point", "DI", &[
+        (0xAD, 0xAD), (0x34F, 0x34F), (0x61C, 0x61C), (0x115F, 0x1160), (0x17B4, 0x17B5), (0x180B, 0x180F),
+        (0x200B, 0x200F), (0x202A, 0x202E), (0x2060, 0x206F), (0x3164, 0x3164), (0xFE00, 0xFE0F), (0xFEFF, 0xFEFF),
+        (0xFFA0, 0xFFA0), (0xFFF0, 0xFFF8), (0x1BCA0, 0x1BCA3), (0x1D173, 0x1D17A), (0xE0000, 0xE0FFF),
+    ]),
+    ("Deprecated", "Dep", &[
+        (0x149, 0x149), (0x673, 0x673), (0xF77, 0xF77), (0xF79, 0xF79), (0x17A3, 0x17A4), (0x206A, 0x206F),
+        (0x2329, 0x232A), (0xE0001, 0xE0001),
+    ]),
+    ("Diacritic", "Dia", &[
+        (0x5E, 0x5E), (0x60, 0x60), (0xA8, 0xA8), (0xAF, 0xAF), (0xB4, 0xB4), (0xB7, 0xB8),
+        (0x2B0, 0x34E), (0x350, 0x357), (0x35D, 0x362), (0x374, 0x375), (0x37A, 0x37A), (0x384, 0x385),
+        (0x483, 0x487), (0x559, 0x559), (0x591, 0x5BD), (0x5BF, 0x5BF), (0x5C1, 0x5C2), (0x5C4, 0x5C5),
+        (0x5C7, 0x5C7), (0x64B, 0x652), (0x657, 0x658), (0x6DF, 0x6E0), (0x6E5, 0x6E6), (0x6EA, 0x6EC),
+        (0x730, 0x74A), (0x7A6, 0x7B0), (0x7EB, 0x7F5), (0x818, 0x819), (0x898, 0x89F), (0x8C9, 0x8D2),
+        (0x8E3, 0x8FE), (0x93C, 0x93C), (0x94D, 0x94D), (0x951, 0x954), (0x971, 0x971), (0x9BC, 0x9BC),
+        (0x9CD, 0x9CD), (0xA3C, 0xA3C), (0xA4D, 0xA4D), (0xABC, 0xABC), (0xACD, 0xACD), (0xAFD, 0xAFF),
+        (0xB3C, 0xB3C), (0xB4D, 0xB4D), (0xB55, 0xB55), (0xBCD, 0xBCD), (0xC3C, 0xC3C), (0xC4D, 0xC4D),
+        (0xCBC, 0xCBC), (0xCCD, 0xCCD), (0xD3B, 0xD3C), (0xD4D, 0xD4D), (0xDCA, 0xDCA), (0xE3A, 0xE3A),
+        (0xE47, 0xE4C), (0xE4E, 0xE4E), (0xEBA, 0xEBA), (0xEC8, 0xECC), (0xF18, 0xF19), (0xF35, 0xF35),
+        (0xF37, 0xF37), (0xF39, 0xF39), (0xF3E, 0xF3F), (0xF82, 0xF84), (0xF86, 0xF87), (0xFC6, 0xFC6),
+        (0x1037, 0x1037), (0x1039, 0x103A), (0x1063, 0x1064), (0x1069, 0x106D), (0x1087, 0x108D), (0x108F, 0x108F),
+        (0x109A, 0x109B), (0x135D, 0x135F), (0x1714, 0x1715), (0x1734, 0x1734), (0x17C9, 0x17D3), (0x17DD, 0x17DD),
+        (0x1939, 0x193B), (0x1A60, 0x1A60), (0x1A75, 0x1A7C), (0x1A7F, 0x1A7F), (0x1AB0, 0x1ABE), (0x1AC1, 0x1ACB),
+        (0x1ACF, 0x1ADD), (0x1AE0, 0x1AEB), (0x1B34, 0x1B34), (0x1B44, 0x1B44), (0x1B6B, 0x1B73), (0x1BAA, 0x1BAB),
+        (0x1BE6, 0x1BE6), (0x1BF2, 0x1BF3), (0x1C36, 0x1C37), (0x1C78, 0x1C7D), (0x1CD0, 0x1CE8), (0x1CED, 0x1CED),
+        (0x1CF4, 0x1CF4), (0x1CF7, 0x1CF9), (0x1D2C, 0x1D6A), (0x1D9B, 0x1DBE), (0x1DC4, 0x1DCF), (0x1DF5, 0x1DFF),
+        (0x1FBD, 0x1FBD), (0x1FBF, 0x1FC1), (0x1FCD, 0x1FCF), (0x1FDD, 0x1FDF), (0x1FED, 0x1FEF), (0x1FFD, 0x1FFE),
+        (0x2CEF, 0x2CF1), (0x2E2F, 0x2E2F), (0x302A, 0x302F), (0x3099, 0x309C), (0x30FC, 0x30FC), (0xA66F, 0xA66F),
+        (0xA67C, 0xA67D), (0xA67F, 0xA67F), (0xA69C, 0xA69D), (0xA6F0, 0xA6F1), (0xA700, 0xA721), (0xA788, 0xA78A),
+        (0xA7F1, 0xA7F1), (0xA7F8, 0xA7F9), (0xA806, 0xA806), (0xA82C, 0xA82C), (0xA8C4, 0xA8C4), (0xA8E0, 0xA8F1),
+        (0xA92B, 0xA92E), (0xA953, 0xA953), (0xA9B3, 0xA9B3), (0xA9C0, 0xA9C0), (0xA9E5, 0xA9E5), (0xAA7B, 0xAA7D),
+        (0xAABF, 0xAAC2), (0xAAF6, 0xAAF6), (0xAB5B, 0xAB5F), (0xAB69, 0xAB6B), (0xABEC, 0xABED), (0xFB1E, 0xFB1E),
+        (0xFE20, 0xFE2F), (0xFF3E, 0xFF3E), (0xFF40, 0xFF40), (0xFF70, 0xFF70), (0xFF9E, 0xFF9F), (0xFFE3, 0xFFE3),
+        (0x102E0, 0x102E0), (0x10780, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10A38, 0x10A3A), (0x10A3F, 0x10A3F),
+        (0x10AE5, 0x10AE6), (0x10D22, 0x10D27), (0x10D4E, 0x10D4E), (0x10D69, 0x10D6D), (0x10EFA, 0x10EFA), (0x10EFD, 0x10EFF),
+        (0x10F46, 0x10F50), (0x10F82, 0x10F85), (0x11046, 0x11046), (0x11070, 0x11070), (0x110B9, 0x110BA), (0x11133, 0x11134),
+        (0x11173, 0x11173), (0x111C0, 0x111C0), (0x111CA, 0x111CC), (0x11235, 0x11236), (0x112E9, 0x112EA), (0x1133B, 0x1133C),
+        (0x1134D, 0x1134D), (0x11366, 0x1136C), (0x11370, 0x11374), (0x113CE, 0x113D0), (0x113D2, 0x113D3), (0x113E1, 0x113E2),
+        (0x11442, 0x11442), (0x11446, 0x11446), (0x114C2, 0x114C3), (0x115BF, 0x115C0), (0x1163F, 0x1163F), (0x116B6, 0x116B7),
+        (0x1172B, 0x1172B), (0x11839, 0x1183A), (0x1193D, 0x1193E), (0x11943, 0x11943), (0x119E0, 0x119E0), (0x11A34, 0x11A34),
+        (0x11A47, 0x11A47), (0x11A99, 0x11A99), (0x11C3F, 0x11C3F), (0x11D42, 0x11D42), (0x11D44, 0x11D45), (0x11D97, 0x11D97),
+        (0x11DD9, 0x11DD9), (0x11F41, 0x11F42), (0x11F5A, 0x11F5A), (0x13447, 0x13455), (0x1612F, 0x1612F), (0x16AF0, 0x16AF4),
+        (0x16B30, 0x16B36), (0x16D6B, 0x16D6C), (0x16F8F, 0x16F9F), (0x16FF0, 0x16FF1), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB),
+        (0x1AFFD, 0x1AFFE), (0x1CF00, 0x1CF2D), (0x1CF30, 0x1CF46), (0x1D167, 0x1D169), (0x1D16D, 0x1D172), (0x1D17B, 0x1D182),
+        (0x1D185, 0x1D18B), (0x1D1AA, 0x1D1AD), (0x1E030, 0x1E06D), (0x1E130, 0x1E136), (0x1E2AE, 0x1E2AE), (0x1E2EC, 0x1E2EF),
+        (0x1E5EE, 0x1E5EF), (0x1E8D0, 0x1E8D6), (0x1E944, 0x1E946), (0x1E948, 0x1E94A),
+    ]),
+    ("Emoji", "", &[
+        (0x23, 0x23), (0x2A, 0x2A), (0x30, 0x39), (0xA9, 0xA9), (0xAE, 0xAE), (0x203C, 0x203C),
+        (0x2049, 0x2049), (0x2122, 0x2122), (0x2139, 0x2139), (0x2194, 0x2199), (0x21A9, 0x21AA), (0x231A, 0x231B),
+        (0x2328, 0x2328), (0x23CF, 0x23CF), (0x23E9, 0x23F3), (0x23F8, 0x23FA), (0x24C2, 0x24C2), (0x25AA, 0x25AB),
+        (0x25B6, 0x25B6), (0x25C0, 0x25C0), (0x25FB, 0x25FE), (0x2600, 0x2604), (0x260E, 0x260E), (0x2611, 0x2611),
+        (0x2614, 0x2615), (0x2618, 0x2618), (0x261D, 0x261D), (0x2620, 0x2620), (0x2622, 0x2623), (0x2626, 0x2626),
+        (0x262A, 0x262A), (0x262E, 0x262F), (0x2638, 0x263A), (0x2640, 0x2640), (0x2642, 0x2642), (0x2648, 0x2653),
+        (0x265F, 0x2660), (0x2663, 0x2663), (0x2665, 0x2666), (0x2668, 0x2668), (0x267B, 0x267B), (0x267E, 0x267F),
+        (0x2692, 0x2697), (0x2699, 0x2699), (0x269B, 0x269C), (0x26A0, 0x26A1), (0x26A7, 0x26A7), (0x26AA, 0x26AB),
+        (0x26B0, 0x26B1), (0x26BD, 0x26BE), (0x26C4, 0x26C5), (0x26C8, 0x26C8), (0x26CE, 0x26CF), (0x26D1, 0x26D1),
+        (0x26D3, 0x26D4), (0x26E9, 0x26EA), (0x26F0, 0x26F5), (0x26F7, 0x26FA), (0x26FD, 0x26FD), (0x2702, 0x2702),
+        (0x2705, 0x2705), (0x2708, 0x270D), (0x270F, 0x270F), (0x2712, 0x2712), (0x2714, 0x2714), (0x2716, 0x2716),
+        (0x271D, 0x271D), (0x2721, 0x2721), (0x2728, 0x2728), (0x2733, 0x2734), (0x2744, 0x2744), (0x2747, 0x2747),
+        (0x274C, 0x274C), (0x274E, 0x274E), (0x2753, 0x2755), (0x2757, 0x2757), (0x2763, 0x2764), (0x2795, 0x2797),
+        (0x27A1, 0x27A1), (0x27B0, 0x27B0), (0x27BF, 0x27BF), (0x2934, 0x2935), (0x2B05, 0x2B07), (0x2B1B, 0x2B1C),
+        (0x2B50, 0x2B50), (0x2B55, 0x2B55), (0x3030, 0x3030), (0x303D, 0x303D), (0x3297, 0x3297), (0x3299, 0x3299),
+        (0x1F004, 0x1F004), (0x1F0CF, 0x1F0CF), (0x1F170, 0x1F171), (0x1F17E, 0x1F17F), (0x1F18E, 0x1F18E), (0x1F191, 0x1F19A),
+        (0x1F1E6, 0x1F1FF), (0x1F201, 0x1F202), (0x1F21A, 0x1F21A), (0x1F22F, 0x1F22F), (0x1F232, 0x1F23A), (0x1F250, 0x1F251),
+        (0x1F300, 0x1F321), (0x1F324, 0x1F393), (0x1F396, 0x1F397), (0x1F399, 0x1F39B), (0x1F39E, 0x1F3F0), (0x1F3F3, 0x1F3F5),
+        (0x1F3F7, 0x1F4FD), (0x1F4FF, 0x1F53D), (0x1F549, 0x1F54E), (0x1F550, 0x1F567), (0x1F56F, 0x1F570), (0x1F573, 0x1F57A),
+        (0x1F587, 0x1F587), (0x1F58A, 0x1F58D), (0x1F590, 0x1F590), (0x1F595, 0x1F596), (0x1F5A4, 0x1F5A5), (0x1F5A8, 0x1F5A8),
+        (0x1F5B1, 0x1F5B2), (0x1F5BC, 0x1F5BC), (0x1F5C2, 0x1F5C4), (0x1F5D1, 0x1F5D3), (0x1F5DC, 0x1F5DE), (0x1F5E1, 0x1F5E1),
+        (0x1F5E3, 0x1F5E3), (0x1F5E8, 0x1F5E8), (0x1F5EF, 0x1F5EF), (0x1F5F3, 0x1F5F3), (0x1F5FA, 0x1F64F), (0x1F680, 0x1F6C5),
+        (0x1F6CB, 0x1F6D2), (0x1F6D5, 0x1F6D8), (0x1F6DC, 0x1F6E5), (0x1F6E9, 0x1F6E9), (0x1F6EB, 0x1F6EC), (0x1F6F0, 0x1F6F0),
+        (0x1F6F3, 0x1F6FC), (0x1F7E0, 0x1F7EB), (0x1F7F0, 0x1F7F0), (0x1F90C, 0x1F93A), (0x1F93C, 0x1F945), (0x1F947, 0x1F9FF),
+        (0x1FA70, 0x1FA7C), (0x1FA80, 0x1FA8A), (0x1FA8E, 0x1FAC6), (0x1FAC8, 0x1FAC8), (0x1FACD, 0x1FADC), (0x1FADF, 0x1FAEA),
+        (0x1FAEF, 0x1FAF8),
+    ]),
+    ("Emoji_Component", "EComp", &[
+        (0x23, 0x23), (0x2A, 0x2A), (0x30, 0x39), (0x200D, 0x200D), (0x20E3, 0x20E3), (0xFE0F, 0xFE0F),
+        (0x1F1E6, 0x1F1FF), (0x1F3FB, 0x1F3FF), (0x1F9B0, 0x1F9B3), (0xE0020, 0xE007F),
+    ]),
+    ("Emoji_Modifier", "EMod", &[
+        (0x1F3FB, 0x1F3FF),
+    ]),
+    ("Emoji_Modifier_Base", "EBase", &[
+        (0x261D, 0x261D), (0x26F9, 0x26F9), (0x270A, 0x270D), (0x1F385, 0x1F385), (0x1F3C2, 0x1F3C4), (0x1F3C7, 0x1F3C7),
+        (0x1F3CA, 0x1F3CC), (0x1F442, 0x1F443), (0x1F446, 0x1F450), (0x1F466, 0x1F478), (0x1F47C, 0x1F47C), (0x1F481, 0x1F483),
+        (0x1F485, 0x1F487), (0x1F48F, 0x1F48F), (0x1F491, 0x1F491), (0x1F4AA, 0x1F4AA), (0x1F574, 0x1F575), (0x1F57A, 0x1F57A),
+        (0x1F590, 0x1F590), (0x1F595, 0x1F596), (0x1F645, 0x1F647), (0x1F64B, 0x1F64F), (0x1F6A3, 0x1F6A3), (0x1F6B4, 0x1F6B6),
+        (0x1F6C0, 0x1F6C0), (0x1F6CC, 0x1F6CC), (0x1F90C, 0x1F90C), (0x1F90F, 0x1F90F), (0x1F918, 0x1F91F), (0x1F926, 0x1F926),
+        (0x1F930, 0x1F939), (0x1F93C, 0x1F93E), (0x1F977, 0x1F977), (0x1F9B5, 0x1F9B6), (0x1F9B8, 0x1F9B9), (0x1F9BB, 0x1F9BB),
+        (0x1F9CD, 0x1F9CF), (0x1F9D1, 0x1F9DD), (0x1FAC3, 0x1FAC5), (0x1FAF0, 0x1FAF8),
+    ]),
+    ("Emoji_Presentation", "EPres", &[
+        (0x231A, 0x231B), (0x23E9, 0x23EC), (0x23F0, 0x23F0), (0x23F3, 0x23F3), (0x25FD, 0x25FE), (0x2614, 0x2615),
+        (0x2648, 0x2653), (0x267F, 0x267F), (0x2693, 0x2693), (0x26A1, 0x26A1), (0x26AA, 0x26AB), (0x26BD, 0x26BE),
+        (0x26C4, 0x26C5), (0x26CE, 0x26CE), (0x26D4, 0x26D4), (0x26EA, 0x26EA), (0x26F2, 0x26F3), (0x26F5, 0x26F5),
+        (0x26FA, 0x26FA), (0x26FD, 0x26FD), (0x2705, 0x2705), (0x270A, 0x270B), (0x2728, 0x2728), (0x274C, 0x274C),
+        (0x274E, 0x274E), (0x2753, 0x2755), (0x2757, 0x2757), (0x2795, 0x2797), (0x27B0, 0x27B0), (0x27BF, 0x27BF),
+        (0x2B1B, 0x2B1C), (0x2B50, 0x2B50), (0x2B55, 0x2B55), (0x1F004, 0x1F004), (0x1F0CF, 0x1F0CF), (0x1F18E, 0x1F18E),
+        (0x1F191, 0x1F19A), (0x1F1E6, 0x1F1FF), (0x1F201, 0x1F201), (0x1F21A, 0x1F21A), (0x1F22F, 0x1F22F), (0x1F232, 0x1F236),
+        (0x1F238, 0x1F23A), (0x1F250, 0x1F251), (0x1F300, 0x1F320), (0x1F32D, 0x1F335), (0x1F337, 0x1F37C), (0x1F37E, 0x1F393),
+        (0x1F3A0, 0x1F3CA), (0x1F3CF, 0x1F3D3), (0x1F3E0, 0x1F3F0), (0x1F3F4, 0x1F3F4), (0x1F3F8, 0x1F43E), (0x1F440, 0x1F440),
+        (0x1F442, 0x1F4FC), (0x1F4FF, 0x1F53D), (0x1F54B, 0x1F54E), (0x1F550, 0x1F567), (0x1F57A, 0x1F57A), (0x1F595, 0x1F596),
+        (0x1F5A4, 0x1F5A4), (0x1F5FB, 0x1F64F), (0x1F680, 0x1F6C5), (0x1F6CC, 0x1F6CC), (0x1F6D0, 0x1F6D2), (0x1F6D5, 0x1F6D8),
+        (0x1F6DC, 0x1F6DF), (0x1F6EB, 0x1F6EC), (0x1F6F4, 0x1F6FC), (0x1F7E0, 0x1F7EB), (0x1F7F0, 0x1F7F0), (0x1F90C, 0x1F93A),
+        (0x1F93C, 0x1F945), (0x1F947, 0x1F9FF), (0x1FA70, 0x1FA7C), (0x1FA80, 0x1FA8A), (0x1FA8E, 0x1FAC6), (0x1FAC8, 0x1FAC8),
+        (0x1FACD, 0x1FADC), (0x1FADF, 0x1FAEA), (0x1FAEF, 0x1FAF8),
+    ]),
+    ("Extended_Pictographic", "ExtPict", &[
+        (0xA9, 0xA9), (0xAE, 0xAE), (0x203C, 0x203C), (0x2049, 0x2049), (0x2122, 0x2122), (0x2139, 0x2139),
+        (0x2194, 0x2199), (0x21A9, 0x21AA), (0x231A, 0x231B), (0x2328, 0x2328), (0x23CF, 0x23CF), (0x23E9, 0x23F3),
+        (0x23F8, 0x23FA), (0x24C2, 0x24C2), (0x25AA, 0x25AB), (0x25B6, 0x25B6), (0x25C0, 0x25C0), (0x25FB, 0x25FE),
+        (0x2600, 0x2604), (0x260E, 0x260E), (0x2611, 0x2611), (0x2614, 0x2615), (0x2618, 0x2618), (0x261D, 0x261D),
+        (0x2620, 0x2620), (0x2622, 0x2623), (0x2626, 0x2626), (0x262A, 0x262A), (0x262E, 0x262F), (0x2638, 0x263A),
+        (0x2640, 0x2640), (0x2642, 0x2642), (0x2648, 0x2653), (0x265F, 0x2660), (0x2663, 0x2663), (0x2665, 0x2666),
+        (0x2668, 0x2668), (0x267B, 0x267B), (0x267E, 0x267F), (0x2692, 0x2697), (0x2699, 0x2699), (0x269B, 0x269C),
+        (0x26A0, 0x26A1), (0x26A7, 0x26A7), (0x26AA, 0x26AB), (0x26B0, 0x26B1), (0x26BD, 0x26BE), (0x26C4, 0x26C5),
+        (0x26C8, 0x26C8), (0x26CE, 0x26CF), (0x26D1, 0x26D1), (0x26D3, 0x26D4), (0x26E9, 0x26EA), (0x26F0, 0x26F5),
+        (0x26F7, 0x26FA), (0x26FD, 0x26FD), (0x2702, 0x2702), (0x2705, 0x2705), (0x2708, 0x270D), (0x270F, 0x270F),
+        (0x2712, 0x2712), (0x2714, 0x2714), (0x2716, 0x2716), (0x271D, 0x271D), (0x2721, 0x2721), (0x2728, 0x2728),
+        (0x2733, 0x2734), (0x2744, 0x2744), (0x2747, 0x2747), (0x274C, 0x274C), (0x274E, 0x274E), (0x2753, 0x2755),
+        (0x2757, 0x2757), (0x2763, 0x2764), (0x2795, 0x2797), (0x27A1, 0x27A1), (0x27B0, 0x27B0), (0x27BF, 0x27BF),
+        (0x2934, 0x2935), (0x2B05, 0x2B07), (0x2B1B, 0x2B1C), (0x2B50, 0x2B50), (0x2B55, 0x2B55), (0x3030, 0x3030),
+        (0x303D, 0x303D), (0x3297, 0x3297), (0x3299, 0x3299), (0x1F004, 0x1F004), (0x1F02C, 0x1F02F), (0x1F094, 0x1F09F),
+        (0x1F0AF, 0x1F0B0), (0x1F0C0, 0x1F0C0), (0x1F0CF, 0x1F0D0), (0x1F0F6, 0x1F0FF), (0x1F170, 0x1F171), (0x1F17E, 0x1F17F),
+        (0x1F18E, 0x1F18E), (0x1F191, 0x1F19A), (0x1F1AE, 0x1F1E5), (0x1F201, 0x1F20F), (0x1F21A, 0x1F21A), (0x1F22F, 0x1F22F),
+        (0x1F232, 0x1F23A), (0x1F23C, 0x1F23F), (0x1F249, 0x1F25F), (0x1F266, 0x1F321), (0x1F324, 0x1F393), (0x1F396, 0x1F397),
+        (0x1F399, 0x1F39B), (0x1F39E, 0x1F3F0), (0x1F3F3, 0x1F3F5), (0x1F3F7, 0x1F3FA), (0x1F400, 0x1F4FD), (0x1F4FF, 0x1F53D),
+        (0x1F549, 0x1F54E), (0x1F550, 0x1F567), (0x1F56F, 0x1F570), (0x1F573, 0x1F57A), (0x1F587, 0x1F587), (0x1F58A, 0x1F58D),
+        (0x1F590, 0x1F590), (0x1F595, 0x1F596), (0x1F5A4, 0x1F5A5), (0x1F5A8, 0x1F5A8), (0x1F5B1, 0x1F5B2), (0x1F5BC, 0x1F5BC),
+        (0x1F5C2, 0x1F5C4), (0x1F5D1, 0x1F5D3), (0x1F5DC, 0x1F5DE), (0x1F5E1, 0x1F5E1), (0x1F5E3, 0x1F5E3), (0x1F5E8, 0x1F5E8),
+        (0x1F5EF, 0x1F5EF), (0x1F5F3, 0x1F5F3), (0x1F5FA, 0x1F64F), (0x1F680, 0x1F6C5), (0x1F6CB, 0x1F6D2), (0x1F6D5, 0x1F6E5),
+        (0x1F6E9, 0x1F6E9), (0x1F6EB, 0x1F6F0), (0x1F6F3, 0x1F6FF), (0x1F7DA, 0x1F7FF), (0x1F80C, 0x1F80F), (0x1F848, 0x1F84F),
+        (0x1F85A, 0x1F85F), (0x1F888, 0x1F88F), (0x1F8AE, 0x1F8AF), (0x1F8BC, 0x1F8BF), (0x1F8C2, 0x1F8CF), (0x1F8D9, 0x1F8FF),
+        (0x1F90C, 0x1F93A), (0x1F93C, 0x1F945), (0x1F947, 0x1F9FF), (0x1FA58, 0x1FA5F), (0x1FA6E, 0x1FAFF), (0x1FC00, 0x1FFFD),
+    ]),
+    ("Extender", "Ext", &[
+        (0xB7, 0xB7), (0x2D0, 0x2D1), (0x640, 0x640), (0x7FA, 0x7FA), (0xA71, 0xA71), (0xAFB, 0xAFB),
+        (0xB55, 0xB55), (0xE46, 0xE46), (0xEC6, 0xEC6), (0x180A, 0x180A), (0x1843, 0x1843), (0x1AA7, 0x1AA7),
+        (0x1C36, 0x1C36), (0x1C7B, 0x1C7B), (0x3005, 0x3005), (0x3031, 0x3035), (0x309D, 0x309E), (0x30FC, 0x30FE),
+        (0xA015, 0xA015), (0xA60C, 0xA60C), (0xA9CF, 0xA9CF), (0xA9E6, 0xA9E6), (0xAA70, 0xAA70), (0xAADD, 0xAADD),
+        (0xAAF3, 0xAAF4), (0xFF70, 0xFF70), (0x10781, 0x10782), (0x10D4E, 0x10D4E), (0x10D6A, 0x10D6A), (0x10D6F, 0x10D6F),
+        (0x11237, 0x11237), (0x1135D, 0x1135D), (0x113D2, 0x113D3), (0x115C6, 0x115C8), (0x11A98, 0x11A98), (0x11DD9, 0x11DD9),
+        (0x16B42, 0x16B43), (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE3), (0x16FF2, 0x16FF3), (0x1E13C, 0x1E13D), (0x1E5EF, 0x1E5EF),
+        (0x1E944, 0x1E946),
+    ]),
+    ("Grapheme_Base", "Gr_Base", &[
+        (0x20, 0x7E), (0xA0, 0xAC), (0xAE, 0x2FF), (0x370, 0x377), (0x37A, 0x37F), (0x384, 0x38A),
+        (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x482), (0x48A, 0x52F), (0x531, 0x556), (0x559, 0x58A),
+        (0x58D, 0x58F), (0x5BE, 0x5BE), (0x5C0, 0x5C0), (0x5C3, 0x5C3), (0x5C6, 0x5C6), (0x5D0, 0x5EA),
+        (0x5EF, 0x5F4), (0x606, 0x60F), (0x61B, 0x61B), (0x61D, 0x64A), (0x660, 0x66F), (0x671, 0x6D5),
+        (0x6DE, 0x6DE), (0x6E5, 0x6E6), (0x6E9, 0x6E9), (0x6EE, 0x70D), (0x710, 0x710), (0x712, 0x72F),
+        (0x74D, 0x7A5), (0x7B1, 0x7B1), (0x7C0, 0x7EA), (0x7F4, 0x7FA), (0x7FE, 0x815), (0x81A, 0x81A),
+        (0x824, 0x824), (0x828, 0x828), (0x830, 0x83E), (0x840, 0x858), (0x85E, 0x85E), (0x860, 0x86A),
+        (0x870, 0x88F), (0x8A0, 0x8C9), (0x903, 0x939), (0x93B, 0x93B), (0x93D, 0x940), (0x949, 0x94C),
+        (0x94E, 0x950), (0x958, 0x961), (0x964, 0x980), (0x982, 0x983), (0x985, 0x98C), (0x98F, 0x990),
+        (0x993, 0x9A8), (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9), (0x9BD, 0x9BD), (0x9BF, 0x9C0),
+        (0x9C7, 0x9C8), (0x9CB, 0x9CC), (0x9CE, 0x9CE), (0x9DC, 0x9DD), (0x9DF, 0x9E1), (0x9E6, 0x9FD),
+        (0xA03, 0xA03), (0xA05, 0xA0A), (0xA0F, 0xA10), (0xA13, 0xA28), (0xA2A, 0xA30), (0xA32, 0xA33),
+        (0xA35, 0xA36), (0xA38, 0xA39), (0xA3E, 0xA40), (0xA59, 0xA5C), (0xA5E, 0xA5E), (0xA66, 0xA6F),
+        (0xA72, 0xA74), (0xA76, 0xA76), (0xA83, 0xA83), (0xA85, 0xA8D), (0xA8F, 0xA91), (0xA93, 0xAA8),
+        (0xAAA, 0xAB0), (0xAB2, 0xAB3), (0xAB5, 0xAB9), (0xABD, 0xAC0), (0xAC9, 0xAC9), (0xACB, 0xACC),
+        (0xAD0, 0xAD0), (0xAE0, 0xAE1), (0xAE6, 0xAF1), (0xAF9, 0xAF9), (0xB02, 0xB03), (0xB05, 0xB0C),
+        (0xB0F, 0xB10), (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33), (0xB35, 0xB39), (0xB3D, 0xB3D),
+        (0xB40, 0xB40), (0xB47, 0xB48), (0xB4B, 0xB4C), (0xB5C, 0xB5D), (0xB5F, 0xB61), (0xB66, 0xB77),
+        (0xB83, 0xB83), (0xB85, 0xB8A), (0xB8E, 0xB90), (0xB92, 0xB95), (0xB99, 0xB9A), (0xB9C, 0xB9C),
+        (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA), (0xBAE, 0xBB9), (0xBBF, 0xBBF), (0xBC1, 0xBC2),
+        (0xBC6, 0xBC8), (0xBCA, 0xBCC), (0xBD0, 0xBD0), (0xBE6, 0xBFA), (0xC01, 0xC03), (0xC05, 0xC0C),
+        (0xC0E, 0xC10), (0xC12, 0xC28), (0xC2A, 0xC39), (0xC3D, 0xC3D), (0xC41, 0xC44), (0xC58, 0xC5A),
+        (0xC5C, 0xC5D), (0xC60, 0xC61), (0xC66, 0xC6F), (0xC77, 0xC80), (0xC82, 0xC8C), (0xC8E, 0xC90),
+        (0xC92, 0xCA8), (0xCAA, 0xCB3), (0xCB5, 0xCB9), (0xCBD, 0xCBE), (0xCC1, 0xCC1), (0xCC3, 0xCC4),
+        (0xCDC, 0xCDE), (0xCE0, 0xCE1), (0xCE6, 0xCEF), (0xCF1, 0xCF3), (0xD02, 0xD0C), (0xD0E, 0xD10),
+        (0xD12, 0xD3A), (0xD3D, 0xD3D), (0xD3F, 0xD40), (0xD46, 0xD48), (0xD4A, 0xD4C), (0xD4E, 0xD4F),
+        (0xD54, 0xD56), (0xD58, 0xD61), (0xD66, 0xD7F), (0xD82, 0xD83), (0xD85, 0xD96), (0xD9A, 0xDB1),
+        (0xDB3, 0xDBB), (0xDBD, 0xDBD), (0xDC0, 0xDC6), (0xDD0, 0xDD1), (0xDD8, 0xDDE), (0xDE6, 0xDEF),
+        (0xDF2, 0xDF4), (0xE01, 0xE30), (0xE32, 0xE33), (0xE3F, 0xE46), (0xE4F, 0xE5B), (0xE81, 0xE82),
+        (0xE84, 0xE84), (0xE86, 0xE8A), (0xE8C, 0xEA3), (0xEA5, 0xEA5), (0xEA7, 0xEB0), (0xEB2, 0xEB3),
+        (0xEBD, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6), (0xED0, 0xED9), (0xEDC, 0xEDF), (0xF00, 0xF17),
+        (0xF1A, 0xF34), (0xF36, 0xF36), (0xF38, 0xF38), (0xF3A, 0xF47), (0xF49, 0xF6C), (0xF7F, 0xF7F),
+        (0xF85, 0xF85), (0xF88, 0xF8C), (0xFBE, 0xFC5), (0xFC7, 0xFCC), (0xFCE, 0xFDA), (0x1000, 0x102C),
+        (0x1031, 0x1031), (0x1038, 0x1038), (0x103B, 0x103C), (0x103F, 0x1057), (0x105A, 0x105D), (0x1061, 0x1070),
+        (0x1075, 0x1081), (0x1083, 0x1084), (0x1087, 0x108C), (0x108E, 0x109C), (0x109E, 0x10C5), (0x10C7, 0x10C7),
+        (0x10CD, 0x10CD), (0x10D0, 0x1248), (0x124A, 0x124D), (0x1250, 0x1256), (0x1258, 0x1258), (0x125A, 0x125D),
+        (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0), (0x12B2, 0x12B5), (0x12B8, 0x12BE), (0x12C0, 0x12C0),
+        (0x12C2, 0x12C5), (0x12C8, 0x12D6), (0x12D8, 0x1310), (0x1312, 0x1315), (0x1318, 0x135A), (0x1360, 0x137C),
+        (0x1380, 0x1399), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1400, 0x169C), (0x16A0, 0x16F8), (0x1700, 0x1711),
+        (0x171F, 0x1731), (0x1735, 0x1736), (0x1740, 0x1751), (0x1760, 0x176C), (0x176E, 0x1770), (0x1780, 0x17B3),
+        (0x17B6, 0x17B6), (0x17BE, 0x17C5), (0x17C7, 0x17C8), (0x17D4, 0x17DC), (0x17E0, 0x17E9), (0x17F0, 0x17F9),
+        (0x1800, 0x180A), (0x1810, 0x1819), (0x1820, 0x1878), (0x1880, 0x1884), (0x1887, 0x18A8), (0x18AA, 0x18AA),
+        (0x18B0, 0x18F5), (0x1900, 0x191E), (0x1923, 0x1926), (0x1929, 0x192B), (0x1930, 0x1931), (0x1933, 0x1938),
+        (0x1940, 0x1940), (0x1944, 0x196D), (0x1970, 0x1974), (0x1980, 0x19AB), (0x19B0, 0x19C9), (0x19D0, 0x19DA),
+        (0x19DE, 0x1A16), (0x1A19, 0x1A1A), (0x1A1E, 0x1A55), (0x1A57, 0x1A57), (0x1A61, 0x1A61), (0x1A63, 0x1A64),
+        (0x1A6D, 0x1A72), (0x1A80, 0x1A89), (0x1A90, 0x1A99), (0x1AA0, 0x1AAD), (0x1B04, 0x1B33), (0x1B3E, 0x1B41),
+        (0x1B45, 0x1B4C), (0x1B4E, 0x1B6A), (0x1B74, 0x1B7F), (0x1B82, 0x1BA1), (0x1BA6, 0x1BA7), (0x1BAE, 0x1BE5),
+        (0x1BE7, 0x1BE7), (0x1BEA, 0x1BEC), (0x1BEE, 0x1BEE), (0x1BFC, 0x1C2B), (0x1C34, 0x1C35), (0x1C3B, 0x1C49),
+        (0x1C4D, 0x1C8A), (0x1C90, 0x1CBA), (0x1CBD, 0x1CC7), (0x1CD3, 0x1CD3), (0x1CE1, 0x1CE1), (0x1CE9, 0x1CEC),
+        (0x1CEE, 0x1CF3), (0x1CF5, 0x1CF7), (0x1CFA, 0x1CFA), (0x1D00, 0x1DBF), (0x1E00, 0x1F15), (0x1F18, 0x1F1D),
+        (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D),
+        (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FC4), (0x1FC6, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FDD, 0x1FEF),
+        (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFE), (0x2000, 0x200A), (0x2010, 0x2027), (0x202F, 0x205F), (0x2070, 0x2071),
+        (0x2074, 0x208E), (0x2090, 0x209C), (0x20A0, 0x20C1), (0x2100, 0x218B), (0x2190, 0x2429), (0x2440, 0x244A),
+        (0x2460, 0x2B73), (0x2B76, 0x2CEE), (0x2CF2, 0x2CF3), (0x2CF9, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D),
+        (0x2D30, 0x2D67), (0x2D6F, 0x2D70), (0x2D80, 0x2D96), (0x2DA0, 0x2DA6), (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6),
+        (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6), (0x2DC8, 0x2DCE), (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE), (0x2E00, 0x2E5D),
+        (0x2E80, 0x2E99), (0x2E9B, 0x2EF3), (0x2F00, 0x2FD5), (0x2FF0, 0x3029), (0x3030, 0x303F), (0x3041, 0x3096),
+        (0x309B, 0x30FF), (0x3105, 0x312F), (0x3131, 0x318E), (0x3190, 0x31E5), (0x31EF, 0x321E), (0x3220, 0xA48C),
+        (0xA490, 0xA4C6), (0xA4D0, 0xA62B), (0xA640, 0xA66E), (0xA673, 0xA673), (0xA67E, 0xA69D), (0xA6A0, 0xA6EF),
+        (0xA6F2, 0xA6F7), (0xA700, 0xA7DC), (0xA7F1, 0xA801), (0xA803, 0xA805), (0xA807, 0xA80A), (0xA80C, 0xA824),
+        (0xA827, 0xA82B), (0xA830, 0xA839), (0xA840, 0xA877), (0xA880, 0xA8C3), (0xA8CE, 0xA8D9), (0xA8F2, 0xA8FE),
+        (0xA900, 0xA925), (0xA92E, 0xA946), (0xA952, 0xA952), (0xA95F, 0xA97C), (0xA983, 0xA9B2), (0xA9B4, 0xA9B5),
+        (0xA9BA, 0xA9BB), (0xA9BE, 0xA9BF), (0xA9C1, 0xA9CD), (0xA9CF, 0xA9D9), (0xA9DE, 0xA9E4), (0xA9E6, 0xA9FE),
+        (0xAA00, 0xAA28), (0xAA2F, 0xAA30), (0xAA33, 0xAA34), (0xAA40, 0xAA42), (0xAA44, 0xAA4B), (0xAA4D, 0xAA4D),
+        (0xAA50, 0xAA59), (0xAA5C, 0xAA7B), (0xAA7D, 0xAAAF), (0xAAB1, 0xAAB1), (0xAAB5, 0xAAB6), (0xAAB9, 0xAABD),
+        (0xAAC0, 0xAAC0), (0xAAC2, 0xAAC2), (0xAADB, 0xAAEB), (0xAAEE, 0xAAF5), (0xAB01, 0xAB06), (0xAB09, 0xAB0E),
+        (0xAB11, 0xAB16), (0xAB20, 0xAB26), (0xAB28, 0xAB2E), (0xAB30, 0xAB6B), (0xAB70, 0xABE4), (0xABE6, 0xABE7),
+        (0xABE9, 0xABEC), (0xABF0, 0xABF9), (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB), (0xF900, 0xFA6D),
+        (0xFA70, 0xFAD9), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFB1D, 0xFB1D), (0xFB1F, 0xFB36), (0xFB38, 0xFB3C),
+        (0xFB3E, 0xFB3E), (0xFB40, 0xFB41), (0xFB43, 0xFB44), (0xFB46, 0xFDCF), (0xFDF0, 0xFDFF), (0xFE10, 0xFE19),
+        (0xFE30, 0xFE52), (0xFE54, 0xFE66), (0xFE68, 0xFE6B), (0xFE70, 0xFE74), (0xFE76, 0xFEFC), (0xFF01, 0xFF9D),
+        (0xFFA0, 0xFFBE), (0xFFC2, 0xFFC7), (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC), (0xFFE0, 0xFFE6),
+        (0xFFE8, 0xFFEE), (0xFFFC, 0xFFFD), (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A), (0x1003C, 0x1003D),
+        (0x1003F, 0x1004D), (0x10050, 0x1005D), (0x10080, 0x100FA), (0x10100, 0x10102), (0x10107, 0x10133), (0x10137, 0x1018E),
+        (0x10190, 0x1019C), (0x101A0, 0x101A0), (0x101D0, 0x101FC), (0x10280, 0x1029C), (0x102A0, 0x102D0), (0x102E1, 0x102FB),
+        (0x10300, 0x10323), (0x1032D, 0x1034A), (0x10350, 0x10375), (0x10380, 0x1039D), (0x1039F, 0x103C3), (0x103C8, 0x103D5),
+        (0x10400, 0x1049D), (0x104A0, 0x104A9), (0x104B0, 0x104D3), (0x104D8, 0x104FB), (0x10500, 0x10527), (0x10530, 0x10563),
+        (0x1056F, 0x1057A), (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1), (0x105A3, 0x105B1),
+        (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x105C0, 0x105F3), (0x10600, 0x10736), (0x10740, 0x10755), (0x10760, 0x10767),
+        (0x10780, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10800, 0x10805), (0x10808, 0x10808), (0x1080A, 0x10835),
+        (0x10837, 0x10838), (0x1083C, 0x1083C), (0x1083F, 0x10855), (0x10857, 0x1089E), (0x108A7, 0x108AF), (0x108E0, 0x108F2),
+        (0x108F4, 0x108F5), (0x108FB, 0x1091B), (0x1091F, 0x10939), (0x1093F, 0x10959), (0x10980, 0x109B7), (0x109BC, 0x109CF),
+        (0x109D2, 0x10A00), (0x10A10, 0x10A13), (0x10A15, 0x10A17), (0x10A19, 0x10A35), (0x10A40, 0x10A48), (0x10A50, 0x10A58),
+        (0x10A60, 0x10A9F), (0x10AC0, 0x10AE4), (0x10AEB, 0x10AF6), (0x10B00, 0x10B35), (0x10B39, 0x10B55), (0x10B58, 0x10B72),
+        (0x10B78, 0x10B91), (0x10B99, 0x10B9C), (0x10BA9, 0x10BAF), (0x10C00, 0x10C48), (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2),
+        (0x10CFA, 0x10D23), (0x10D30, 0x10D39), (0x10D40, 0x10D65), (0x10D6E, 0x10D85), (0x10D8E, 0x10D8F), (0x10E60, 0x10E7E),
+        (0x10E80, 0x10EA9), (0x10EAD, 0x10EAD), (0x10EB0, 0x10EB1), (0x10EC2, 0x10EC7), (0x10ED0, 0x10ED8), (0x10F00, 0x10F27),
+        (0x10F30, 0x10F45), (0x10F51, 0x10F59), (0x10F70, 0x10F81), (0x10F86, 0x10F89), (0x10FB0, 0x10FCB), (0x10FE0, 0x10FF6),
+        (0x11000, 0x11000), (0x11002, 0x11037), (0x11047, 0x1104D), (0x11052, 0x1106F), (0x11071, 0x11072), (0x11075, 0x11075),
+        (0x11082, 0x110B2), (0x110B7, 0x110B8), (0x110BB, 0x110BC), (0x110BE, 0x110C1), (0x110D0, 0x110E8), (0x110F0, 0x110F9),
+        (0x11103, 0x11126), (0x1112C, 0x1112C), (0x11136, 0x11147), (0x11150, 0x11172), (0x11174, 0x11176), (0x11182, 0x111B5),
+        (0x111BF, 0x111BF), (0x111C1, 0x111C8), (0x111CD, 0x111CE), (0x111D0, 0x111DF), (0x111E1, 0x111F4), (0x11200, 0x11211),
+        (0x11213, 0x1122E), (0x11232, 0x11233), (0x11238, 0x1123D), (0x1123F, 0x11240), (0x11280, 0x11286), (0x11288, 0x11288),
+        (0x1128A, 0x1128D), (0x1128F, 0x1129D), (0x1129F, 0x112A9), (0x112B0, 0x112DE), (0x112E0, 0x112E2), (0x112F0, 0x112F9),
+        (0x11302, 0x11303), (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328), (0x1132A, 0x11330), (0x11332, 0x11333),
+        (0x11335, 0x11339), (0x1133D, 0x1133D), (0x1133F, 0x1133F), (0x11341, 0x11344), (0x11347, 0x11348), (0x1134B, 0x1134C),
+        (0x11350, 0x11350), (0x1135D, 0x11363), (0x11380, 0x11389), (0x1138B, 0x1138B), (0x1138E, 0x1138E), (0x11390, 0x113B5),
+        (0x113B7, 0x113B7), (0x113B9, 0x113BA), (0x113CA, 0x113CA), (0x113CC, 0x113CD), (0x113D1, 0x113D1), (0x113D3, 0x113D5),
+        (0x113D7, 0x113D8), (0x11400, 0x11437), (0x11440, 0x11441), (0x11445, 0x11445), (0x11447, 0x1145B), (0x1145D, 0x1145D),
+        (0x1145F, 0x11461), (0x11480, 0x114AF), (0x114B1, 0x114B2), (0x114B9, 0x114B9), (0x114BB, 0x114BC), (0x114BE, 0x114BE),
+        (0x114C1, 0x114C1), (0x114C4, 0x114C7), (0x114D0, 0x114D9), (0x11580, 0x115AE), (0x115B0, 0x115B1), (0x115B8, 0x115BB),
+        (0x115BE, 0x115BE), (0x115C1, 0x115DB), (0x11600, 0x11632), (0x1163B, 0x1163C), (0x1163E, 0x1163E), (0x11641, 0x11644),
+        (0x11650, 0x11659), (0x11660, 0x1166C), (0x11680, 0x116AA), (0x116AC, 0x116AC), (0x116AE, 0x116AF), (0x116B8, 0x116B9),
+        (0x116C0, 0x116C9), (0x116D0, 0x116E3), (0x11700, 0x1171A), (0x1171E, 0x1171E), (0x11720, 0x11721), (0x11726, 0x11726),
+        (0x11730, 0x11746), (0x11800, 0x1182E), (0x11838, 0x11838), (0x1183B, 0x1183B), (0x118A0, 0x118F2), (0x118FF, 0x11906),
+        (0x11909, 0x11909), (0x1190C, 0x11913), (0x11915, 0x11916), (0x11918, 0x1192F), (0x11931, 0x11935), (0x11937, 0x11938),
+        (0x1193F, 0x11942), (0x11944, 0x11946), (0x11950, 0x11959), (0x119A0, 0x119A7), (0x119AA, 0x119D3), (0x119DC, 0x119DF),
+        (0x119E1, 0x119E4), (0x11A00, 0x11A00), (0x11A0B, 0x11A32), (0x11A39, 0x11A3A), (0x11A3F, 0x11A46), (0x11A50, 0x11A50),
+        (0x11A57, 0x11A58), (0x11A5C, 0x11A89), (0x11A97, 0x11A97), (0x11A9A, 0x11AA2), (0x11AB0, 0x11AF8), (0x11B00, 0x11B09),
+        (0x11B61, 0x11B61), (0x11B65, 0x11B65), (0x11B67, 0x11B67), (0x11BC0, 0x11BE1), (0x11BF0, 0x11BF9), (0x11C00, 0x11C08),
+        (0x11C0A, 0x11C2F), (0x11C3E, 0x11C3E), (0x11C40, 0x11C45), (0x11C50, 0x11C6C), (0x11C70, 0x11C8F), (0x11CA9, 0x11CA9),
+        (0x11CB1, 0x11CB1), (0x11CB4, 0x11CB4), (0x11D00, 0x11D06), (0x11D08, 0x11D09), (0x11D0B, 0x11D30), (0x11D46, 0x11D46),
+        (0x11D50, 0x11D59), (0x11D60, 0x11D65), (0x11D67, 0x11D68), (0x11D6A, 0x11D8E), (0x11D93, 0x11D94), (0x11D96, 0x11D96),
+        (0x11D98, 0x11D98), (0x11DA0, 0x11DA9), (0x11DB0, 0x11DDB), (0x11DE0, 0x11DE9), (0x11EE0, 0x11EF2), (0x11EF5, 0x11EF8),
+        (0x11F02, 0x11F10), (0x11F12, 0x11F35), (0x11F3E, 0x11F3F), (0x11F43, 0x11F59), (0x11FB0, 0x11FB0), (0x11FC0, 0x11FF1),
+        (0x11FFF, 0x12399), (0x12400, 0x1246E), (0x12470, 0x12474), (0x12480, 0x12543), (0x12F90, 0x12FF2), (0x13000, 0x1342F),
+        (0x13441, 0x13446), (0x13460, 0x143FA), (0x14400, 0x14646), (0x16100, 0x1611D), (0x1612A, 0x1612C), (0x16130, 0x16139),
+        (0x16800, 0x16A38), (0x16A40, 0x16A5E), (0x16A60, 0x16A69), (0x16A6E, 0x16ABE), (0x16AC0, 0x16AC9), (0x16AD0, 0x16AED),
+        (0x16AF5, 0x16AF5), (0x16B00, 0x16B2F), (0x16B37, 0x16B45), (0x16B50, 0x16B59), (0x16B5B, 0x16B61), (0x16B63, 0x16B77),
+        (0x16B7D, 0x16B8F), (0x16D40, 0x16D79), (0x16E40, 0x16E9A), (0x16EA0, 0x16EB8), (0x16EBB, 0x16ED3), (0x16F00, 0x16F4A),
+        (0x16F50, 0x16F87), (0x16F93, 0x16F9F), (0x16FE0, 0x16FE3), (0x16FF2, 0x16FF6), (0x17000, 0x18CD5), (0x18CFF, 0x18D1E),
+        (0x18D80, 0x18DF2), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122), (0x1B132, 0x1B132),
+        (0x1B150, 0x1B152), (0x1B155, 0x1B155), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A), (0x1BC70, 0x1BC7C),
+        (0x1BC80, 0x1BC88), (0x1BC90, 0x1BC99), (0x1BC9C, 0x1BC9C), (0x1BC9F, 0x1BC9F), (0x1CC00, 0x1CCFC), (0x1CD00, 0x1CEB3),
+        (0x1CEBA, 0x1CED0), (0x1CEE0, 0x1CEF0), (0x1CF50, 0x1CFC3), (0x1D000, 0x1D0F5), (0x1D100, 0x1D126), (0x1D129, 0x1D164),
+        (0x1D16A, 0x1D16C), (0x1D183, 0x1D184), (0x1D18C, 0x1D1A9), (0x1D1AE, 0x1D1EA), (0x1D200, 0x1D241), (0x1D245, 0x1D245),
+        (0x1D2C0, 0x1D2D3), (0x1D2E0, 0x1D2F3), (0x1D300, 0x1D356), (0x1D360, 0x1D378), (0x1D400, 0x1D454), (0x1D456, 0x1D49C),
+        (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB),
+        (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539),
+        (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546), (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D7CB),
+        (0x1D7CE, 0x1D9FF), (0x1DA37, 0x1DA3A), (0x1DA6D, 0x1DA74), (0x1DA76, 0x1DA83), (0x1DA85, 0x1DA8B), (0x1DF00, 0x1DF1E),
+        (0x1DF25, 0x1DF2A), (0x1E030, 0x1E06D), (0x1E100, 0x1E12C), (0x1E137, 0x1E13D), (0x1E140, 0x1E149), (0x1E14E, 0x1E14F),
+        (0x1E290, 0x1E2AD), (0x1E2C0, 0x1E2EB), (0x1E2F0, 0x1E2F9), (0x1E2FF, 0x1E2FF), (0x1E4D0, 0x1E4EB), (0x1E4F0, 0x1E4F9),
+        (0x1E5D0, 0x1E5ED), (0x1E5F0, 0x1E5FA), (0x1E5FF, 0x1E5FF), (0x1E6C0, 0x1E6DE), (0x1E6E0, 0x1E6E2), (0x1E6E4, 0x1E6E5),
+        (0x1E6E7, 0x1E6ED), (0x1E6F0, 0x1E6F4), (0x1E6FE, 0x1E6FF), (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB), (0x1E7ED, 0x1E7EE),
+        (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4), (0x1E8C7, 0x1E8CF), (0x1E900, 0x1E943), (0x1E94B, 0x1E94B), (0x1E950, 0x1E959),
+        (0x1E95E, 0x1E95F), (0x1EC71, 0x1ECB4), (0x1ED01, 0x1ED3D), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22),
+        (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B),
+        (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47), (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52),
+        (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F),
+        (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64), (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C),
+        (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB),
+        (0x1EEF0, 0x1EEF1), (0x1F000, 0x1F02B), (0x1F030, 0x1F093), (0x1F0A0, 0x1F0AE), (0x1F0B1, 0x1F0BF), (0x1F0C1, 0x1F0CF),
+        (0x1F0D1, 0x1F0F5), (0x1F100, 0x1F1AD), (0x1F1E6, 0x1F202), (0x1F210, 0x1F23B), (0x1F240, 0x1F248), (0x1F250, 0x1F251),
+        (0x1F260, 0x1F265), (0x1F300, 0x1F6D8), (0x1F6DC, 0x1F6EC), (0x1F6F0, 0x1F6FC), (0x1F700, 0x1F7D9), (0x1F7E0, 0x1F7EB),
+        (0x1F7F0, 0x1F7F0), (0x1F800, 0x1F80B), (0x1F810, 0x1F847), (0x1F850, 0x1F859), (0x1F860, 0x1F887), (0x1F890, 0x1F8AD),
+        (0x1F8B0, 0x1F8BB), (0x1F8C0, 0x1F8C1), (0x1F8D0, 0x1F8D8), (0x1F900, 0x1FA57), (0x1FA60, 0x1FA6D), (0x1FA70, 0x1FA7C),
+        (0x1FA80, 0x1FA8A), (0x1FA8E, 0x1FAC6), (0x1FAC8, 0x1FAC8), (0x1FACD, 0x1FADC), (0x1FADF, 0x1FAEA), (0x1FAEF, 0x1FAF8),
+        (0x1FB00, 0x1FB92), (0x1FB94, 0x1FBFA), (0x20000, 0x2A6DF), (0x2A700, 0x2B81D), (0x2B820, 0x2CEAD), (0x2CEB0, 0x2EBE0),
+        (0x2EBF0, 0x2EE5D), (0x2F800, 0x2FA1D), (0x30000, 0x3134A), (0x31350, 0x33479),
+    ]),
+    ("Grapheme_Extend", "Gr_Ext", &[
+        (0x300, 0x36F), (0x483, 0x489), (0x591, 0x5BD), (0x5BF, 0x5BF), (0x5C1, 0x5C2), (0x5C4, 0x5C5),
+        (0x5C7, 0x5C7), (0x610, 0x61A), (0x64B, 0x65F), (0x670, 0x670), (0x6D6, 0x6DC), (0x6DF, 0x6E4),
+        (0x6E7, 0x6E8), (0x6EA, 0x6ED), (0x711, 0x711), (0x730, 0x74A), (0x7A6, 0x7B0), (0x7EB, 0x7F3),
+        (0x7FD, 0x7FD), (0x816, 0x819), (0x81B, 0x823), (0x825, 0x827), (0x829, 0x82D), (0x859, 0x85B),
+        (0x897, 0x89F), (0x8CA, 0x8E1), (0x8E3, 0x902), (0x93A, 0x93A), (0x93C, 0x93C), (0x941, 0x948),
+        (0x94D, 0x94D), (0x951, 0x957), (0x962, 0x963), (0x981, 0x981), (0x9BC, 0x9BC), (0x9BE, 0x9BE),
+        (0x9C1, 0x9C4), (0x9CD, 0x9CD), (0x9D7, 0x9D7), (0x9E2, 0x9E3), (0x9FE, 0x9FE), (0xA01, 0xA02),
+        (0xA3C, 0xA3C), (0xA41, 0xA42), (0xA47, 0xA48), (0xA4B, 0xA4D), (0xA51, 0xA51), (0xA70, 0xA71),
+        (0xA75, 0xA75), (0xA81, 0xA82), (0xABC, 0xABC), (0xAC1, 0xAC5), (0xAC7, 0xAC8), (0xACD, 0xACD),
+        (0xAE2, 0xAE3), (0xAFA, 0xAFF), (0xB01, 0xB01), (0xB3C, 0xB3C), (0xB3E, 0xB3F), (0xB41, 0xB44),
+        (0xB4D, 0xB4D), (0xB55, 0xB57), (0xB62, 0xB63), (0xB82, 0xB82), (0xBBE, 0xBBE), (0xBC0, 0xBC0),
+        (0xBCD, 0xBCD), (0xBD7, 0xBD7), (0xC00, 0xC00), (0xC04, 0xC04), (0xC3C, 0xC3C), (0xC3E, 0xC40),
+        (0xC46, 0xC48), (0xC4A, 0xC4D), (0xC55, 0xC56), (0xC62, 0xC63), (0xC81, 0xC81), (0xCBC, 0xCBC),
+        (0xCBF, 0xCC0), (0xCC2, 0xCC2), (0xCC6, 0xCC8), (0xCCA, 0xCCD), (0xCD5, 0xCD6), (0xCE2, 0xCE3),
+        (0xD00, 0xD01), (0xD3B, 0xD3C), (0xD3E, 0xD3E), (0xD41, 0xD44), (0xD4D, 0xD4D), (0xD57, 0xD57),
+        (0xD62, 0xD63), (0xD81, 0xD81), (0xDCA, 0xDCA), (0xDCF, 0xDCF), (0xDD2, 0xDD4), (0xDD6, 0xDD6),
+        (0xDDF, 0xDDF), (0xE31, 0xE31), (0xE34, 0xE3A), (0xE47, 0xE4E), (0xEB1, 0xEB1), (0xEB4, 0xEBC),
+        (0xEC8, 0xECE), (0xF18, 0xF19), (0xF35, 0xF35), (0xF37, 0xF37), (0xF39, 0xF39), (0xF71, 0xF7E),
+        (0xF80, 0xF84), (0xF86, 0xF87), (0xF8D, 0xF97), (0xF99, 0xFBC), (0xFC6, 0xFC6), (0x102D, 0x1030),
+        (0x1032, 0x1037), (0x1039, 0x103A), (0x103D, 0x103E), (0x1058, 0x1059), (0x105E, 0x1060), (0x1071, 0x1074),
+        (0x1082, 0x1082), (0x1085, 0x1086), (0x108D, 0x108D), (0x109D, 0x109D), (0x135D, 0x135F), (0x1712, 0x1715),
+        (0x1732, 0x1734), (0x1752, 0x1753), (0x1772, 0x1773), (0x17B4, 0x17B5), (0x17B7, 0x17BD), (0x17C6, 0x17C6),
+        (0x17C9, 0x17D3), (0x17DD, 0x17DD), (0x180B, 0x180D), (0x180F, 0x180F), (0x1885, 0x1886), (0x18A9, 0x18A9),
+        (0x1920, 0x1922), (0x1927, 0x1928), (0x1932, 0x1932), (0x1939, 0x193B), (0x1A17, 0x1A18), (0x1A1B, 0x1A1B),
+        (0x1A56, 0x1A56), (0x1A58, 0x1A5E), (0x1A60, 0x1A60), (0x1A62, 0x1A62), (0x1A65, 0x1A6C), (0x1A73, 0x1A7C),
+        (0x1A7F, 0x1A7F), (0x1AB0, 0x1ADD), (0x1AE0, 0x1AEB), (0x1B00, 0x1B03), (0x1B34, 0x1B3D), (0x1B42, 0x1B44),
+        (0x1B6B, 0x1B73), (0x1B80, 0x1B81), (0x1BA2, 0x1BA5), (0x1BA8, 0x1BAD), (0x1BE6, 0x1BE6), (0x1BE8, 0x1BE9),
+        (0x1BED, 0x1BED), (0x1BEF, 0x1BF3), (0x1C2C, 0x1C33), (0x1C36, 0x1C37), (0x1CD0, 0x1CD2), (0x1CD4, 0x1CE0),
+        (0x1CE2, 0x1CE8), (0x1CED, 0x1CED), (0x1CF4, 0x1CF4), (0x1CF8, 0x1CF9), (0x1DC0, 0x1DFF), (0x200C, 0x200C),
+        (0x20D0, 0x20F0), (0x2CEF, 0x2CF1), (0x2D7F, 0x2D7F), (0x2DE0, 0x2DFF), (0x302A, 0x302F), (0x3099, 0x309A),
+        (0xA66F, 0xA672), (0xA674, 0xA67D), (0xA69E, 0xA69F), (0xA6F0, 0xA6F1), (0xA802, 0xA802), (0xA806, 0xA806),
+        (0xA80B, 0xA80B), (0xA825, 0xA826), (0xA82C, 0xA82C), (0xA8C4, 0xA8C5), (0xA8E0, 0xA8F1), (0xA8FF, 0xA8FF),
+        (0xA926, 0xA92D), (0xA947, 0xA951), (0xA953, 0xA953), (0xA980, 0xA982), (0xA9B3, 0xA9B3), (0xA9B6, 0xA9B9),
+        (0xA9BC, 0xA9BD), (0xA9C0, 0xA9C0), (0xA9E5, 0xA9E5), (0xAA29, 0xAA2E), (0xAA31, 0xAA32), (0xAA35, 0xAA36),
+        (0xAA43, 0xAA43), (0xAA4C, 0xAA4C), (0xAA7C, 0xAA7C), (0xAAB0, 0xAAB0), (0xAAB2, 0xAAB4), (0xAAB7, 0xAAB8),
+        (0xAABE, 0xAABF), (0xAAC1, 0xAAC1), (0xAAEC, 0xAAED), (0xAAF6, 0xAAF6), (0xABE5, 0xABE5), (0xABE8, 0xABE8),
+        (0xABED, 0xABED), (0xFB1E, 0xFB1E), (0xFE00, 0xFE0F), (0xFE20, 0xFE2F), (0xFF9E, 0xFF9F), (0x101FD, 0x101FD),
+        (0x102E0, 0x102E0), (0x10376, 0x1037A), (0x10A01, 0x10A03), (0x10A05, 0x10A06), (0x10A0C, 0x10A0F), (0x10A38, 0x10A3A),
+        (0x10A3F, 0x10A3F), (0x10AE5, 0x10AE6), (0x10D24, 0x10D27), (0x10D69, 0x10D6D), (0x10EAB, 0x10EAC), (0x10EFA, 0x10EFF),
+        (0x10F46, 0x10F50), (0x10F82, 0x10F85), (0x11001, 0x11001), (0x11038, 0x11046), (0x11070, 0x11070), (0x11073, 0x11074),
+        (0x1107F, 0x11081), (0x110B3, 0x110B6), (0x110B9, 0x110BA), (0x110C2, 0x110C2), (0x11100, 0x11102), (0x11127, 0x1112B),
+        (0x1112D, 0x11134), (0x11173, 0x11173), (0x11180, 0x11181), (0x111B6, 0x111BE), (0x111C0, 0x111C0), (0x111C9, 0x111CC),
+        (0x111CF, 0x111CF), (0x1122F, 0x11231), (0x11234, 0x11237), (0x1123E, 0x1123E), (0x11241, 0x11241), (0x112DF, 0x112DF),
+        (0x112E3, 0x112EA), (0x11300, 0x11301), (0x1133B, 0x1133C), (0x1133E, 0x1133E), (0x11340, 0x11340), (0x1134D, 0x1134D),
+        (0x11357, 0x11357), (0x11366, 0x1136C), (0x11370, 0x11374), (0x113B8, 0x113B8), (0x113BB, 0x113C0), (0x113C2, 0x113C2),
+        (0x113C5, 0x113C5), (0x113C7, 0x113C9), (0x113CE, 0x113D0), (0x113D2, 0x113D2), (0x113E1, 0x113E2), (0x11438, 0x1143F),
+        (0x11442, 0x11444), (0x11446, 0x11446), (0x1145E, 0x1145E), (0x114B0, 0x114B0), (0x114B3, 0x114B8), (0x114BA, 0x114BA),
+        (0x114BD, 0x114BD), (0x114BF, 0x114C0), (0x114C2, 0x114C3), (0x115AF, 0x115AF), (0x115B2, 0x115B5), (0x115BC, 0x115BD),
+        (0x115BF, 0x115C0), (0x115DC, 0x115DD), (0x11633, 0x1163A), (0x1163D, 0x1163D), (0x1163F, 0x11640), (0x116AB, 0x116AB),
+        (0x116AD, 0x116AD), (0x116B0, 0x116B7), (0x1171D, 0x1171D), (0x1171F, 0x1171F), (0x11722, 0x11725), (0x11727, 0x1172B),
+        (0x1182F, 0x11837), (0x11839, 0x1183A), (0x11930, 0x11930), (0x1193B, 0x1193E), (0x11943, 0x11943), (0x119D4, 0x119D7),
+        (0x119DA, 0x119DB), (0x119E0, 0x119E0), (0x11A01, 0x11A0A), (0x11A33, 0x11A38), (0x11A3B, 0x11A3E), (0x11A47, 0x11A47),
+        (0x11A51, 0x11A56), (0x11A59, 0x11A5B), (0x11A8A, 0x11A96), (0x11A98, 0x11A99), (0x11B60, 0x11B60), (0x11B62, 0x11B64),
+        (0x11B66, 0x11B66), (0x11C30, 0x11C36), (0x11C38, 0x11C3D), (0x11C3F, 0x11C3F), (0x11C92, 0x11CA7), (0x11CAA, 0x11CB0),
+        (0x11CB2, 0x11CB3), (0x11CB5, 0x11CB6), (0x11D31, 0x11D36), (0x11D3A, 0x11D3A), (0x11D3C, 0x11D3D), (0x11D3F, 0x11D45),
+        (0x11D47, 0x11D47), (0x11D90, 0x11D91), (0x11D95, 0x11D95), (0x11D97, 0x11D97), (0x11EF3, 0x11EF4), (0x11F00, 0x11F01),
+        (0x11F36, 0x11F3A), (0x11F40, 0x11F42), (0x11F5A, 0x11F5A), (0x13440, 0x13440), (0x13447, 0x13455), (0x1611E, 0x16129),
+        (0x1612D, 0x1612F), (0x16AF0, 0x16AF4), (0x16B30, 0x16B36), (0x16F4F, 0x16F4F), (0x16F8F, 0x16F92), (0x16FE4, 0x16FE4),
+        (0x16FF0, 0x16FF1), (0x1BC9D, 0x1BC9E), (0x1CF00, 0x1CF2D), (0x1CF30, 0x1CF46), (0x1D165, 0x1D169), (0x1D16D, 0x1D172),
+        (0x1D17B, 0x1D182), (0x1D185, 0x1D18B), (0x1D1AA, 0x1D1AD), (0x1D242, 0x1D244), (0x1DA00, 0x1DA36), (0x1DA3B, 0x1DA6C),
+        (0x1DA75, 0x1DA75), (0x1DA84, 0x1DA84), (0x1DA9B, 0x1DA9F), (0x1DAA1, 0x1DAAF), (0x1E000, 0x1E006), (0x1E008, 0x1E018),
+        (0x1E01B, 0x1E021), (0x1E023, 0x1E024), (0x1E026, 0x1E02A), (0x1E08F, 0x1E08F), (0x1E130, 0x1E136), (0x1E2AE, 0x1E2AE),
+        (0x1E2EC, 0x1E2EF), (0x1E4EC, 0x1E4EF), (0x1E5EE, 0x1E5EF), (0x1E6E3, 0x1E6E3), (0x1E6E6, 0x1E6E6), (0x1E6EE, 0x1E6EF),
+        (0x1E6F5, 0x1E6F5), (0x1E8D0, 0x1E8D6), (0x1E944, 0x1E94A), (0xE0020, 0xE007F), (0xE0100, 0xE01EF),
+    ]),
+    ("Hex_Digit", "Hex", &[
+        (0x30, 0x39), (0x41, 0x46), (0x61, 0x66), (0xFF10, 0xFF19), (0xFF21, 0xFF26), (0xFF41, 0xFF46),
+    ]),
+    ("IDS_Binary_Operator", "IDSB", &[
+        (0x2FF0, 0x2FF1), (0x2FF4, 0x2FFD), (0x31EF, 0x31EF),
+    ]),
+    ("IDS_Trinary_Operator", "IDST", &[
+        (0x2FF2, 0x2FF3),
+    ]),
+    ("ID_Continue", "IDC", &[
+        (0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5),
+        (0xB7, 0xB7), (0xBA, 0xBA), (0xC0, 0xD6), (0xD8, 0xF6), (0xF8, 0x2C1), (0x2C6, 0x2D1),
+        (0x2E0, 0x2E4), (0x2EC, 0x2EC), (0x2EE, 0x2EE), (0x300, 0x374), (0x376, 0x377), (0x37A, 0x37D),
+        (0x37F, 0x37F), (0x386, 0x38A), (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x3F5), (0x3F7, 0x481),
+        (0x483, 0x487), (0x48A, 0x52F), (0x531, 0x556), (0x559, 0x559), (0x560, 0x588), (0x591, 0x5BD),
+        (0x5BF, 0x5BF), (0x5C1, 0x5C2), (0x5C4, 0x5C5), (0x5C7, 0x5C7), (0x5D0, 0x5EA), (0x5EF, 0x5F2),
+        (0x610, 0x61A), (0x620, 0x669), (0x66E, 0x6D3), (0x6D5, 0x6DC), (0x6DF, 0x6E8), (0x6EA, 0x6FC),
+        (0x6FF, 0x6FF), (0x710, 0x74A), (0x74D, 0x7B1), (0x7C0, 0x7F5), (0x7FA, 0x7FA), (0x7FD, 0x7FD),
+        (0x800, 0x82D), (0x840, 0x85B), (0x860, 0x86A), (0x870, 0x887), (0x889, 0x88F), (0x897, 0x8E1),
+        (0x8E3, 0x963), (0x966, 0x96F), (0x971, 0x983), (0x985, 0x98C), (0x98F, 0x990), (0x993, 0x9A8),
+        (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9), (0x9BC, 0x9C4), (0x9C7, 0x9C8), (0x9CB, 0x9CE),
+        (0x9D7, 0x9D7), (0x9DC, 0x9DD), (0x9DF, 0x9E3), (0x9E6, 0x9F1), (0x9FC, 0x9FC), (0x9FE, 0x9FE),
+        (0xA01, 0xA03), (0xA05, 0xA0A), (0xA0F, 0xA10), (0xA13, 0xA28), (0xA2A, 0xA30), (0xA32, 0xA33),
+        (0xA35, 0xA36), (0xA38, 0xA39), (0xA3C, 0xA3C), (0xA3E, 0xA42), (0xA47, 0xA48), (0xA4B, 0xA4D),
+        (0xA51, 0xA51), (0xA59, 0xA5C), (0xA5E, 0xA5E), (0xA66, 0xA75), (0xA81, 0xA83), (0xA85, 0xA8D),
+        (0xA8F, 0xA91), (0xA93, 0xAA8), (0xAAA, 0xAB0), (0xAB2, 0xAB3), (0xAB5, 0xAB9), (0xABC, 0xAC5),
+        (0xAC7, 0xAC9), (0xACB, 0xACD), (0xAD0, 0xAD0), (0xAE0, 0xAE3), (0xAE6, 0xAEF), (0xAF9, 0xAFF),
+        (0xB01, 0xB03), (0xB05, 0xB0C), (0xB0F, 0xB10), (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33),
+        (0xB35, 0xB39), (0xB3C, 0xB44), (0xB47, 0xB48), (0xB4B, 0xB4D), (0xB55, 0xB57), (0xB5C, 0xB5D),
+        (0xB5F, 0xB63), (0xB66, 0xB6F), (0xB71, 0xB71), (0xB82, 0xB83), (0xB85, 0xB8A), (0xB8E, 0xB90),
+        (0xB92, 0xB95), (0xB99, 0xB9A), (0xB9C, 0xB9C), (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA),
+        (0xBAE, 0xBB9), (0xBBE, 0xBC2), (0xBC6, 0xBC8), (0xBCA, 0xBCD), (0xBD0, 0xBD0), (0xBD7, 0xBD7),
+        (0xBE6, 0xBEF), (0xC00, 0xC0C), (0xC0E, 0xC10), (0xC12, 0xC28), (0xC2A, 0xC39), (0xC3C, 0xC44),
+        (0xC46, 0xC48), (0xC4A, 0xC4D), (0xC55, 0xC56), (0xC58, 0xC5A), (0xC5C, 0xC5D), (0xC60, 0xC63),
+        (0xC66, 0xC6F), (0xC80, 0xC83), (0xC85, 0xC8C), (0xC8E, 0xC90), (0xC92, 0xCA8), (0xCAA, 0xCB3),
+        (0xCB5, 0xCB9), (0xCBC, 0xCC4), (0xCC6, 0xCC8), (0xCCA, 0xCCD), (0xCD5, 0xCD6), (0xCDC, 0xCDE),
+        (0xCE0, 0xCE3), (0xCE6, 0xCEF), (0xCF1, 0xCF3), (0xD00, 0xD0C), (0xD0E, 0xD10), (0xD12, 0xD44),
+        (0xD46, 0xD48), (0xD4A, 0xD4E), (0xD54, 0xD57), (0xD5F, 0xD63), (0xD66, 0xD6F), (0xD7A, 0xD7F),
+        (0xD81, 0xD83), (0xD85, 0xD96), (0xD9A, 0xDB1), (0xDB3, 0xDBB), (0xDBD, 0xDBD), (0xDC0, 0xDC6),
+        (0xDCA, 0xDCA), (0xDCF, 0xDD4), (0xDD6, 0xDD6), (0xDD8, 0xDDF), (0xDE6, 0xDEF), (0xDF2, 0xDF3),
+        (0xE01, 0xE3A), (0xE40, 0xE4E), (0xE50, 0xE59), (0xE81, 0xE82), (0xE84, 0xE84), (0xE86, 0xE8A),
+        (0xE8C, 0xEA3), (0xEA5, 0xEA5), (0xEA7, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6), (0xEC8, 0xECE),
+        (0xED0, 0xED9), (0xEDC, 0xEDF), (0xF00, 0xF00), (0xF18, 0xF19), (0xF20, 0xF29), (0xF35, 0xF35),
+        (0xF37, 0xF37), (0xF39, 0xF39), (0xF3E, 0xF47), (0xF49, 0xF6C), (0xF71, 0xF84), (0xF86, 0xF97),
+        (0xF99, 0xFBC), (0xFC6, 0xFC6), (0x1000, 0x1049), (0x1050, 0x109D), (0x10A0, 0x10C5), (0x10C7, 0x10C7),
+        (0x10CD, 0x10CD), (0x10D0, 0x10FA), (0x10FC, 0x1248), (0x124A, 0x124D), (0x1250, 0x1256), (0x1258, 0x1258),
+        (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0), (0x12B2, 0x12B5), (0x12B8, 0x12BE),
+        (0x12C0, 0x12C0), (0x12C2, 0x12C5), (0x12C8, 0x12D6), (0x12D8, 0x1310), (0x1312, 0x1315), (0x1318, 0x135A),
+        (0x135D, 0x135F), (0x1369, 0x1371), (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1401, 0x166C),
+        (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16EE, 0x16F8), (0x1700, 0x1715), (0x171F, 0x1734),
+        (0x1740, 0x1753), (0x1760, 0x176C), (0x176E, 0x1770), (0x1772, 0x1773), (0x1780, 0x17D3), (0x17D7, 0x17D7),
+        (0x17DC, 0x17DD), (0x17E0, 0x17E9), (0x180B, 0x180D), (0x180F, 0x1819), (0x1820, 0x1878), (0x1880, 0x18AA),
+        (0x18B0, 0x18F5), (0x1900, 0x191E), (0x1920, 0x192B), (0x1930, 0x193B), (0x1946, 0x196D), (0x1970, 0x1974),
+        (0x1980, 0x19AB), (0x19B0, 0x19C9), (0x19D0, 0x19DA), (0x1A00, 0x1A1B), (0x1A20, 0x1A5E), (0x1A60, 0x1A7C),
+        (0x1A7F, 0x1A89), (0x1A90, 0x1A99), (0x1AA7, 0x1AA7), (0x1AB0, 0x1ABD), (0x1ABF, 0x1ADD), (0x1AE0, 0x1AEB),
+        (0x1B00, 0x1B4C), (0x1B50, 0x1B59), (0x1B6B, 0x1B73), (0x1B80, 0x1BF3), (0x1C00, 0x1C37), (0x1C40, 0x1C49),
+        (0x1C4D, 0x1C7D), (0x1C80, 0x1C8A), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1CD0, 0x1CD2), (0x1CD4, 0x1CFA),
+        (0x1D00, 0x1F15), (0x1F18, 0x1F1D), (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59),
+        (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D), (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE),
+        (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC), (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4),
+        (0x1FF6, 0x1FFC), (0x200C, 0x200D), (0x203F, 0x2040), (0x2054, 0x2054), (0x2071, 0x2071), (0x207F, 0x207F),
+        (0x2090, 0x209C), (0x20D0, 0x20DC), (0x20E1, 0x20E1), (0x20E5, 0x20F0), (0x2102, 0x2102), (0x2107, 0x2107),
+        (0x210A, 0x2113), (0x2115, 0x2115), (0x2118, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128),
+        (0x212A, 0x2139), (0x213C, 0x213F), (0x2145, 0x2149), (0x214E, 0x214E), (0x2160, 0x2188), (0x2C00, 0x2CE4),
+        (0x2CEB, 0x2CF3), (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0x2D30, 0x2D67), (0x2D6F, 0x2D6F),
+        (0x2D7F, 0x2D96), (0x2DA0, 0x2DA6), (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6), (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6),
+        (0x2DC8, 0x2DCE), (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE), (0x2DE0, 0x2DFF), (0x3005, 0x3007), (0x3021, 0x302F),
+        (0x3031, 0x3035), (0x3038, 0x303C), (0x3041, 0x3096), (0x3099, 0x309F), (0x30A1, 0x30FF), (0x3105, 0x312F),
+        (0x3131, 0x318E), (0x31A0, 0x31BF), (0x31F0, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0xA48C), (0xA4D0, 0xA4FD),
+        (0xA500, 0xA60C), (0xA610, 0xA62B), (0xA640, 0xA66F), (0xA674, 0xA67D), (0xA67F, 0xA6F1), (0xA717, 0xA71F),
+        (0xA722, 0xA788), (0xA78B, 0xA7DC), (0xA7F1, 0xA827), (0xA82C, 0xA82C), (0xA840, 0xA873), (0xA880, 0xA8C5),
+        (0xA8D0, 0xA8D9), (0xA8E0, 0xA8F7), (0xA8FB, 0xA8FB), (0xA8FD, 0xA92D), (0xA930, 0xA953), (0xA960, 0xA97C),
+        (0xA980, 0xA9C0), (0xA9CF, 0xA9D9), (0xA9E0, 0xA9FE), (0xAA00, 0xAA36), (0xAA40, 0xAA4D), (0xAA50, 0xAA59),
+        (0xAA60, 0xAA76), (0xAA7A, 0xAAC2), (0xAADB, 0xAADD), (0xAAE0, 0xAAEF), (0xAAF2, 0xAAF6), (0xAB01, 0xAB06),
+        (0xAB09, 0xAB0E), (0xAB11, 0xAB16), (0xAB20, 0xAB26), (0xAB28, 0xAB2E), (0xAB30, 0xAB5A), (0xAB5C, 0xAB69),
+        (0xAB70, 0xABEA), (0xABEC, 0xABED), (0xABF0, 0xABF9), (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB),
+        (0xF900, 0xFA6D), (0xFA70, 0xFAD9), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFB1D, 0xFB28), (0xFB2A, 0xFB36),
+        (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41), (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFD3D),
+        (0xFD50, 0xFD8F), (0xFD92, 0xFDC7), (0xFDF0, 0xFDFB), (0xFE00, 0xFE0F), (0xFE20, 0xFE2F), (0xFE33, 0xFE34),
+        (0xFE4D, 0xFE4F), (0xFE70, 0xFE74), (0xFE76, 0xFEFC), (0xFF10, 0xFF19), (0xFF21, 0xFF3A), (0xFF3F, 0xFF3F),
+        (0xFF41, 0xFF5A), (0xFF65, 0xFFBE), (0xFFC2, 0xFFC7), (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC),
+        (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A), (0x1003C, 0x1003D), (0x1003F, 0x1004D), (0x10050, 0x1005D),
+        (0x10080, 0x100FA), (0x10140, 0x10174), (0x101FD, 0x101FD), (0x10280, 0x1029C), (0x102A0, 0x102D0), (0x102E0, 0x102E0),
+        (0x10300, 0x1031F), (0x1032D, 0x1034A), (0x10350, 0x1037A), (0x10380, 0x1039D), (0x103A0, 0x103C3), (0x103C8, 0x103CF),
+        (0x103D1, 0x103D5), (0x10400, 0x1049D), (0x104A0, 0x104A9), (0x104B0, 0x104D3), (0x104D8, 0x104FB), (0x10500, 0x10527),
+        (0x10530, 0x10563), (0x10570, 0x1057A), (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1),
+        (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x105C0, 0x105F3), (0x10600, 0x10736), (0x10740, 0x10755),
+        (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10800, 0x10805), (0x10808, 0x10808),
+        (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C), (0x1083F, 0x10855), (0x10860, 0x10876), (0x10880, 0x1089E),
+        (0x108E0, 0x108F2), (0x108F4, 0x108F5), (0x10900, 0x10915), (0x10920, 0x10939), (0x10940, 0x10959), (0x10980, 0x109B7),
+        (0x109BE, 0x109BF), (0x10A00, 0x10A03), (0x10A05, 0x10A06), (0x10A0C, 0x10A13), (0x10A15, 0x10A17), (0x10A19, 0x10A35),
+        (0x10A38, 0x10A3A), (0x10A3F, 0x10A3F), (0x10A60, 0x10A7C), (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7), (0x10AC9, 0x10AE6),
+        (0x10B00, 0x10B35), (0x10B40, 0x10B55), (0x10B60, 0x10B72), (0x10B80, 0x10B91), (0x10C00, 0x10C48), (0x10C80, 0x10CB2),
+        (0x10CC0, 0x10CF2), (0x10D00, 0x10D27), (0x10D30, 0x10D39), (0x10D40, 0x10D65), (0x10D69, 0x10D6D), (0x10D6F, 0x10D85),
+        (0x10E80, 0x10EA9), (0x10EAB, 0x10EAC), (0x10EB0, 0x10EB1), (0x10EC2, 0x10EC7), (0x10EFA, 0x10F1C), (0x10F27, 0x10F27),
+        (0x10F30, 0x10F50), (0x10F70, 0x10F85), (0x10FB0, 0x10FC4), (0x10FE0, 0x10FF6), (0x11000, 0x11046), (0x11066, 0x11075),
+        (0x1107F, 0x110BA), (0x110C2, 0x110C2), (0x110D0, 0x110E8), (0x110F0, 0x110F9), (0x11100, 0x11134), (0x11136, 0x1113F),
+        (0x11144, 0x11147), (0x11150, 0x11173), (0x11176, 0x11176), (0x11180, 0x111C4), (0x111C9, 0x111CC), (0x111CE, 0x111DA),
+        (0x111DC, 0x111DC), (0x11200, 0x11211), (0x11213, 0x11237), (0x1123E, 0x11241), (0x11280, 0x11286), (0x11288, 0x11288),
+        (0x1128A, 0x1128D), (0x1128F, 0x1129D), (0x1129F, 0x112A8), (0x112B0, 0x112EA), (0x112F0, 0x112F9), (0x11300, 0x11303),
+        (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328), (0x1132A, 0x11330), (0x11332, 0x11333), (0x11335, 0x11339),
+        (0x1133B, 0x11344), (0x11347, 0x11348), (0x1134B, 0x1134D), (0x11350, 0x11350), (0x11357, 0x11357), (0x1135D, 0x11363),
+        (0x11366, 0x1136C), (0x11370, 0x11374), (0x11380, 0x11389), (0x1138B, 0x1138B), (0x1138E, 0x1138E), (0x11390, 0x113B5),
+        (0x113B7, 0x113C0), (0x113C2, 0x113C2), (0x113C5, 0x113C5), (0x113C7, 0x113CA), (0x113CC, 0x113D3), (0x113E1, 0x113E2),
+        (0x11400, 0x1144A), (0x11450, 0x11459), (0x1145E, 0x11461), (0x11480, 0x114C5), (0x114C7, 0x114C7), (0x114D0, 0x114D9),
+        (0x11580, 0x115B5), (0x115B8, 0x115C0), (0x115D8, 0x115DD), (0x11600, 0x11640), (0x11644, 0x11644), (0x11650, 0x11659),
+        (0x11680, 0x116B8), (0x116C0, 0x116C9), (0x116D0, 0x116E3), (0x11700, 0x1171A), (0x1171D, 0x1172B), (0x11730, 0x11739),
+        (0x11740, 0x11746), (0x11800, 0x1183A), (0x118A0, 0x118E9), (0x118FF, 0x11906), (0x11909, 0x11909), (0x1190C, 0x11913),
+        (0x11915, 0x11916), (0x11918, 0x11935), (0x11937, 0x11938), (0x1193B, 0x11943), (0x11950, 0x11959), (0x119A0, 0x119A7),
+        (0x119AA, 0x119D7), (0x119DA, 0x119E1), (0x119E3, 0x119E4), (0x11A00, 0x11A3E), (0x11A47, 0x11A47), (0x11A50, 0x11A99),
+        (0x11A9D, 0x11A9D), (0x11AB0, 0x11AF8), (0x11B60, 0x11B67), (0x11BC0, 0x11BE0), (0x11BF0, 0x11BF9), (0x11C00, 0x11C08),
+        (0x11C0A, 0x11C36), (0x11C38, 0x11C40), (0x11C50, 0x11C59), (0x11C72, 0x11C8F), (0x11C92, 0x11CA7), (0x11CA9, 0x11CB6),
+        (0x11D00, 0x11D06), (0x11D08, 0x11D09), (0x11D0B, 0x11D36), (0x11D3A, 0x11D3A), (0x11D3C, 0x11D3D), (0x11D3F, 0x11D47),
+        (0x11D50, 0x11D59), (0x11D60, 0x11D65), (0x11D67, 0x11D68), (0x11D6A, 0x11D8E), (0x11D90, 0x11D91), (0x11D93, 0x11D98),
+        (0x11DA0, 0x11DA9), (0x11DB0, 0x11DDB), (0x11DE0, 0x11DE9), (0x11EE0, 0x11EF6), (0x11F00, 0x11F10), (0x11F12, 0x11F3A),
+        (0x11F3E, 0x11F42), (0x11F50, 0x11F5A), (0x11FB0, 0x11FB0), (0x12000, 0x12399), (0x12400, 0x1246E), (0x12480, 0x12543),
+        (0x12F90, 0x12FF0), (0x13000, 0x1342F), (0x13440, 0x13455), (0x13460, 0x143FA), (0x14400, 0x14646), (0x16100, 0x16139),
+        (0x16800, 0x16A38), (0x16A40, 0x16A5E), (0x16A60, 0x16A69), (0x16A70, 0x16ABE), (0x16AC0, 0x16AC9), (0x16AD0, 0x16AED),
+        (0x16AF0, 0x16AF4), (0x16B00, 0x16B36), (0x16B40, 0x16B43), (0x16B50, 0x16B59), (0x16B63, 0x16B77), (0x16B7D, 0x16B8F),
+        (0x16D40, 0x16D6C), (0x16D70, 0x16D79), (0x16E40, 0x16E7F), (0x16EA0, 0x16EB8), (0x16EBB, 0x16ED3), (0x16F00, 0x16F4A),
+        (0x16F4F, 0x16F87), (0x16F8F, 0x16F9F), (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE4), (0x16FF0, 0x16FF6), (0x17000, 0x18CD5),
+        (0x18CFF, 0x18D1E), (0x18D80, 0x18DF2), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122),
+        (0x1B132, 0x1B132), (0x1B150, 0x1B152), (0x1B155, 0x1B155), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A),
+        (0x1BC70, 0x1BC7C), (0x1BC80, 0x1BC88), (0x1BC90, 0x1BC99), (0x1BC9D, 0x1BC9E), (0x1CCF0, 0x1CCF9), (0x1CF00, 0x1CF2D),
+        (0x1CF30, 0x1CF46), (0x1D165, 0x1D169), (0x1D16D, 0x1D172), (0x1D17B, 0x1D182), (0x1D185, 0x1D18B), (0x1D1AA, 0x1D1AD),
+        (0x1D242, 0x1D244), (0x1D400, 0x1D454), (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6),
+        (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A),
+        (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546),
+        (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D6C0), (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6FA), (0x1D6FC, 0x1D714),
+        (0x1D716, 0x1D734), (0x1D736, 0x1D74E), (0x1D750, 0x1D76E), (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8), (0x1D7AA, 0x1D7C2),
+        (0x1D7C4, 0x1D7CB), (0x1D7CE, 0x1D7FF), (0x1DA00, 0x1DA36), (0x1DA3B, 0x1DA6C), (0x1DA75, 0x1DA75), (0x1DA84, 0x1DA84),
+        (0x1DA9B, 0x1DA9F), (0x1DAA1, 0x1DAAF), (0x1DF00, 0x1DF1E), (0x1DF25, 0x1DF2A), (0x1E000, 0x1E006), (0x1E008, 0x1E018),
+        (0x1E01B, 0x1E021), (0x1E023, 0x1E024), (0x1E026, 0x1E02A), (0x1E030, 0x1E06D), (0x1E08F, 0x1E08F), (0x1E100, 0x1E12C),
+        (0x1E130, 0x1E13D), (0x1E140, 0x1E149), (0x1E14E, 0x1E14E), (0x1E290, 0x1E2AE), (0x1E2C0, 0x1E2F9), (0x1E4D0, 0x1E4F9),
+        (0x1E5D0, 0x1E5FA), (0x1E6C0, 0x1E6DE), (0x1E6E0, 0x1E6F5), (0x1E6FE, 0x1E6FF), (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB),
+        (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4), (0x1E8D0, 0x1E8D6), (0x1E900, 0x1E94B), (0x1E950, 0x1E959),
+        (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22), (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32),
+        (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47), (0x1EE49, 0x1EE49),
+        (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52), (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59),
+        (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64), (0x1EE67, 0x1EE6A),
+        (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C), (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B),
+        (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB), (0x1FBF0, 0x1FBF9), (0x20000, 0x2A6DF), (0x2A700, 0x2B81D),
+        (0x2B820, 0x2CEAD), (0x2CEB0, 0x2EBE0), (0x2EBF0, 0x2EE5D), (0x2F800, 0x2FA1D), (0x30000, 0x3134A), (0x31350, 0x33479),
+        (0xE0100, 0xE01EF),
+    ]),
+    ("ID_Start", "IDS", &[
+        (0x41, 0x5A), (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5), (0xBA, 0xBA), (0xC0, 0xD6),
+        (0xD8, 0xF6), (0xF8, 0x2C1), (0x2C6, 0x2D1), (0x2E0, 0x2E4), (0x2EC, 0x2EC), (0x2EE, 0x2EE),
+        (0x370, 0x374), (0x376, 0x377), (0x37A, 0x37D), (0x37F, 0x37F), (0x386, 0x386), (0x388, 0x38A),
+        (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x3F5), (0x3F7, 0x481), (0x48A, 0x52F), (0x531, 0x556),
+        (0x559, 0x559), (0x560, 0x588), (0x5D0, 0x5EA), (0x5EF, 0x5F2), (0x620, 0x64A), (0x66E, 0x66F),
+        (0x671, 0x6D3), (0x6D5, 0x6D5), (0x6E5, 0x6E6), (0x6EE, 0x6EF), (0x6FA, 0x6FC), (0x6FF, 0x6FF),
+        (0x710, 0x710), (0x712, 0x72F), (0x74D, 0x7A5), (0x7B1, 0x7B1), (0x7CA, 0x7EA), (0x7F4, 0x7F5),
+        (0x7FA, 0x7FA), (0x800, 0x815), (0x81A, 0x81A), (0x824, 0x824), (0x828, 0x828), (0x840, 0x858),
+        (0x860, 0x86A), (0x870, 0x887), (0x889, 0x88F), (0x8A0, 0x8C9), (0x904, 0x939), (0x93D, 0x93D),
+        (0x950, 0x950), (0x958, 0x961), (0x971, 0x980), (0x985, 0x98C), (0x98F, 0x990), (0x993, 0x9A8),
+        (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9), (0x9BD, 0x9BD), (0x9CE, 0x9CE), (0x9DC, 0x9DD),
+        (0x9DF, 0x9E1), (0x9F0, 0x9F1), (0x9FC, 0x9FC), (0xA05, 0xA0A), (0xA0F, 0xA10), (0xA13, 0xA28),
+        (0xA2A, 0xA30), (0xA32, 0xA33), (0xA35, 0xA36), (0xA38, 0xA39), (0xA59, 0xA5C), (0xA5E, 0xA5E),
+        (0xA72, 0xA74), (0xA85, 0xA8D), (0xA8F, 0xA91), (0xA93, 0xAA8), (0xAAA, 0xAB0), (0xAB2, 0xAB3),
+        (0xAB5, 0xAB9), (0xABD, 0xABD), (0xAD0, 0xAD0), (0xAE0, 0xAE1), (0xAF9, 0xAF9), (0xB05, 0xB0C),
+        (0xB0F, 0xB10), (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33), (0xB35, 0xB39), (0xB3D, 0xB3D),
+        (0xB5C, 0xB5D), (0xB5F, 0xB61), (0xB71, 0xB71), (0xB83, 0xB83), (0xB85, 0xB8A), (0xB8E, 0xB90),
+        (0xB92, 0xB95), (0xB99, 0xB9A), (0xB9C, 0xB9C), (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA),
+        (0xBAE, 0xBB9), (0xBD0, 0xBD0), (0xC05, 0xC0C), (0xC0E, 0xC10), (0xC12, 0xC28), (0xC2A, 0xC39),
+        (0xC3D, 0xC3D), (0xC58, 0xC5A), (0xC5C, 0xC5D), (0xC60, 0xC61), (0xC80, 0xC80), (0xC85, 0xC8C),
+        (0xC8E, 0xC90), (0xC92, 0xCA8), (0xCAA, 0xCB3), (0xCB5, 0xCB9), (0xCBD, 0xCBD), (0xCDC, 0xCDE),
+        (0xCE0, 0xCE1), (0xCF1, 0xCF2), (0xD04, 0xD0C), (0xD0E, 0xD10), (0xD12, 0xD3A), (0xD3D, 0xD3D),
+        (0xD4E, 0xD4E), (0xD54, 0xD56), (0xD5F, 0xD61), (0xD7A, 0xD7F), (0xD85, 0xD96), (0xD9A, 0xDB1),
+        (0xDB3, 0xDBB), (0xDBD, 0xDBD), (0xDC0, 0xDC6), (0xE01, 0xE30), (0xE32, 0xE33), (0xE40, 0xE46),
+        (0xE81, 0xE82), (0xE84, 0xE84), (0xE86, 0xE8A), (0xE8C, 0xEA3), (0xEA5, 0xEA5), (0xEA7, 0xEB0),
+        (0xEB2, 0xEB3), (0xEBD, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6), (0xEDC, 0xEDF), (0xF00, 0xF00),
+        (0xF40, 0xF47), (0xF49, 0xF6C), (0xF88, 0xF8C), (0x1000, 0x102A), (0x103F, 0x103F), (0x1050, 0x1055),
+        (0x105A, 0x105D), (0x1061, 0x1061), (0x1065, 0x1066), (0x106E, 0x1070), (0x1075, 0x1081), (0x108E, 0x108E),
+        (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD), (0x10D0, 0x10FA), (0x10FC, 0x1248), (0x124A, 0x124D),
+        (0x1250, 0x1256), (0x1258, 0x1258), (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0),
+        (0x12B2, 0x12B5), (0x12B8, 0x12BE), (0x12C0, 0x12C0), (0x12C2, 0x12C5), (0x12C8, 0x12D6), (0x12D8, 0x1310),
+        (0x1312, 0x1315), (0x1318, 0x135A), (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1401, 0x166C),
+        (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16EE, 0x16F8), (0x1700, 0x1711), (0x171F, 0x1731),
+        (0x1740, 0x1751), (0x1760, 0x176C), (0x176E, 0x1770), (0x1780, 0x17B3), (0x17D7, 0x17D7), (0x17DC, 0x17DC),
+        (0x1820, 0x1878), (0x1880, 0x18A8), (0x18AA, 0x18AA), (0x18B0, 0x18F5), (0x1900, 0x191E), (0x1950, 0x196D),
+        (0x1970, 0x1974), (0x1980, 0x19AB), (0x19B0, 0x19C9), (0x1A00, 0x1A16), (0x1A20, 0x1A54), (0x1AA7, 0x1AA7),
+        (0x1B05, 0x1B33), (0x1B45, 0x1B4C), (0x1B83, 0x1BA0), (0x1BAE, 0x1BAF), (0x1BBA, 0x1BE5), (0x1C00, 0x1C23),
+        (0x1C4D, 0x1C4F), (0x1C5A, 0x1C7D), (0x1C80, 0x1C8A), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1CE9, 0x1CEC),
+        (0x1CEE, 0x1CF3), (0x1CF5, 0x1CF6), (0x1CFA, 0x1CFA), (0x1D00, 0x1DBF), (0x1E00, 0x1F15), (0x1F18, 0x1F1D),
+        (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D),
+        (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC),
+        (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFC), (0x2071, 0x2071),
+        (0x207F, 0x207F), (0x2090, 0x209C), (0x2102, 0x2102), (0x2107, 0x2107), (0x210A, 0x2113), (0x2115, 0x2115),
+        (0x2118, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128), (0x212A, 0x2139), (0x213C, 0x213F),
+        (0x2145, 0x2149), (0x214E, 0x214E), (0x2160, 0x2188), (0x2C00, 0x2CE4), (0x2CEB, 0x2CEE), (0x2CF2, 0x2CF3),
+        (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0x2D30, 0x2D67), (0x2D6F, 0x2D6F), (0x2D80, 0x2D96),
+        (0x2DA0, 0x2DA6), (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6), (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6), (0x2DC8, 0x2DCE),
+        (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE), (0x3005, 0x3007), (0x3021, 0x3029), (0x3031, 0x3035), (0x3038, 0x303C),
+        (0x3041, 0x3096), (0x309B, 0x309F), (0x30A1, 0x30FA), (0x30FC, 0x30FF), (0x3105, 0x312F), (0x3131, 0x318E),
+        (0x31A0, 0x31BF), (0x31F0, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0xA48C), (0xA4D0, 0xA4FD), (0xA500, 0xA60C),
+        (0xA610, 0xA61F), (0xA62A, 0xA62B), (0xA640, 0xA66E), (0xA67F, 0xA69D), (0xA6A0, 0xA6EF), (0xA717, 0xA71F),
+        (0xA722, 0xA788), (0xA78B, 0xA7DC), (0xA7F1, 0xA801), (0xA803, 0xA805), (0xA807, 0xA80A), (0xA80C, 0xA822),
+        (0xA840, 0xA873), (0xA882, 0xA8B3), (0xA8F2, 0xA8F7), (0xA8FB, 0xA8FB), (0xA8FD, 0xA8FE), (0xA90A, 0xA925),
+        (0xA930, 0xA946), (0xA960, 0xA97C), (0xA984, 0xA9B2), (0xA9CF, 0xA9CF), (0xA9E0, 0xA9E4), (0xA9E6, 0xA9EF),
+        (0xA9FA, 0xA9FE), (0xAA00, 0xAA28), (0xAA40, 0xAA42), (0xAA44, 0xAA4B), (0xAA60, 0xAA76), (0xAA7A, 0xAA7A),
+        (0xAA7E, 0xAAAF), (0xAAB1, 0xAAB1), (0xAAB5, 0xAAB6), (0xAAB9, 0xAABD), (0xAAC0, 0xAAC0), (0xAAC2, 0xAAC2),
+        (0xAADB, 0xAADD), (0xAAE0, 0xAAEA), (0xAAF2, 0xAAF4), (0xAB01, 0xAB06), (0xAB09, 0xAB0E), (0xAB11, 0xAB16),
+        (0xAB20, 0xAB26), (0xAB28, 0xAB2E), (0xAB30, 0xAB5A), (0xAB5C, 0xAB69), (0xAB70, 0xABE2), (0xAC00, 0xD7A3),
+        (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB), (0xF900, 0xFA6D), (0xFA70, 0xFAD9), (0xFB00, 0xFB06), (0xFB13, 0xFB17),
+        (0xFB1D, 0xFB1D), (0xFB1F, 0xFB28), (0xFB2A, 0xFB36), (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41),
+        (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7), (0xFDF0, 0xFDFB),
+        (0xFE70, 0xFE74), (0xFE76, 0xFEFC), (0xFF21, 0xFF3A), (0xFF41, 0xFF5A), (0xFF66, 0xFFBE), (0xFFC2, 0xFFC7),
+        (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC), (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A),
+        (0x1003C, 0x1003D), (0x1003F, 0x1004D), (0x10050, 0x1005D), (0x10080, 0x100FA), (0x10140, 0x10174), (0x10280, 0x1029C),
+        (0x102A0, 0x102D0), (0x10300, 0x1031F), (0x1032D, 0x1034A), (0x10350, 0x10375), (0x10380, 0x1039D), (0x103A0, 0x103C3),
+        (0x103C8, 0x103CF), (0x103D1, 0x103D5), (0x10400, 0x1049D), (0x104B0, 0x104D3), (0x104D8, 0x104FB), (0x10500, 0x10527),
+        (0x10530, 0x10563), (0x10570, 0x1057A), (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1),
+        (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x105C0, 0x105F3), (0x10600, 0x10736), (0x10740, 0x10755),
+        (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10800, 0x10805), (0x10808, 0x10808),
+        (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C), (0x1083F, 0x10855), (0x10860, 0x10876), (0x10880, 0x1089E),
+        (0x108E0, 0x108F2), (0x108F4, 0x108F5), (0x10900, 0x10915), (0x10920, 0x10939), (0x10940, 0x10959), (0x10980, 0x109B7),
+        (0x109BE, 0x109BF), (0x10A00, 0x10A00), (0x10A10, 0x10A13), (0x10A15, 0x10A17), (0x10A19, 0x10A35), (0x10A60, 0x10A7C),
+        (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7), (0x10AC9, 0x10AE4), (0x10B00, 0x10B35), (0x10B40, 0x10B55), (0x10B60, 0x10B72),
+        (0x10B80, 0x10B91), (0x10C00, 0x10C48), (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2), (0x10D00, 0x10D23), (0x10D4A, 0x10D65),
+        (0x10D6F, 0x10D85), (0x10E80, 0x10EA9), (0x10EB0, 0x10EB1), (0x10EC2, 0x10EC7), (0x10F00, 0x10F1C), (0x10F27, 0x10F27),
+        (0x10F30, 0x10F45), (0x10F70, 0x10F81), (0x10FB0, 0x10FC4), (0x10FE0, 0x10FF6), (0x11003, 0x11037), (0x11071, 0x11072),
+        (0x11075, 0x11075), (0x11083, 0x110AF), (0x110D0, 0x110E8), (0x11103, 0x11126), (0x11144, 0x11144), (0x11147, 0x11147),
+        (0x11150, 0x11172), (0x11176, 0x11176), (0x11183, 0x111B2), (0x111C1, 0x111C4), (0x111DA, 0x111DA), (0x111DC, 0x111DC),
+        (0x11200, 0x11211), (0x11213, 0x1122B), (0x1123F, 0x11240), (0x11280, 0x11286), (0x11288, 0x11288), (0x1128A, 0x1128D),
+        (0x1128F, 0x1129D), (0x1129F, 0x112A8), (0x112B0, 0x112DE), (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328),
+        (0x1132A, 0x11330), (0x11332, 0x11333), (0x11335, 0x11339), (0x1133D, 0x1133D), (0x11350, 0x11350), (0x1135D, 0x11361),
+        (0x11380, 0x11389), (0x1138B, 0x1138B), (0x1138E, 0x1138E), (0x11390, 0x113B5), (0x113B7, 0x113B7), (0x113D1, 0x113D1),
+        (0x113D3, 0x113D3), (0x11400, 0x11434), (0x11447, 0x1144A), (0x1145F, 0x11461), (0x11480, 0x114AF), (0x114C4, 0x114C5),
+        (0x114C7, 0x114C7), (0x11580, 0x115AE), (0x115D8, 0x115DB), (0x11600, 0x1162F), (0x11644, 0x11644), (0x11680, 0x116AA),
+        (0x116B8, 0x116B8), (0x11700, 0x1171A), (0x11740, 0x11746), (0x11800, 0x1182B), (0x118A0, 0x118DF), (0x118FF, 0x11906),
+        (0x11909, 0x11909), (0x1190C, 0x11913), (0x11915, 0x11916), (0x11918, 0x1192F), (0x1193F, 0x1193F), (0x11941, 0x11941),
+        (0x119A0, 0x119A7), (0x119AA, 0x119D0), (0x119E1, 0x119E1), (0x119E3, 0x119E3), (0x11A00, 0x11A00), (0x11A0B, 0x11A32),
+        (0x11A3A, 0x11A3A), (0x11A50, 0x11A50), (0x11A5C, 0x11A89), (0x11A9D, 0x11A9D), (0x11AB0, 0x11AF8), (0x11BC0, 0x11BE0),
+        (0x11C00, 0x11C08), (0x11C0A, 0x11C2E), (0x11C40, 0x11C40), (0x11C72, 0x11C8F), (0x11D00, 0x11D06), (0x11D08, 0x11D09),
+        (0x11D0B, 0x11D30), (0x11D46, 0x11D46), (0x11D60, 0x11D65), (0x11D67, 0x11D68), (0x11D6A, 0x11D89), (0x11D98, 0x11D98),
+        (0x11DB0, 0x11DDB), (0x11EE0, 0x11EF2), (0x11F02, 0x11F02), (0x11F04, 0x11F10), (0x11F12, 0x11F33), (0x11FB0, 0x11FB0),
+        (0x12000, 0x12399), (0x12400, 0x1246E), (0x12480, 0x12543), (0x12F90, 0x12FF0), (0x13000, 0x1342F), (0x13441, 0x13446),
+        (0x13460, 0x143FA), (0x14400, 0x14646), (0x16100, 0x1611D), (0x16800, 0x16A38), (0x16A40, 0x16A5E), (0x16A70, 0x16ABE),
+        (0x16AD0, 0x16AED), (0x16B00, 0x16B2F), (0x16B40, 0x16B43), (0x16B63, 0x16B77), (0x16B7D, 0x16B8F), (0x16D40, 0x16D6C),
+        (0x16E40, 0x16E7F), (0x16EA0, 0x16EB8), (0x16EBB, 0x16ED3), (0x16F00, 0x16F4A), (0x16F50, 0x16F50), (0x16F93, 0x16F9F),
+        (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE3), (0x16FF2, 0x16FF6), (0x17000, 0x18CD5), (0x18CFF, 0x18D1E), (0x18D80, 0x18DF2),
+        (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122), (0x1B132, 0x1B132), (0x1B150, 0x1B152),
+        (0x1B155, 0x1B155), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A), (0x1BC70, 0x1BC7C), (0x1BC80, 0x1BC88),
+        (0x1BC90, 0x1BC99), (0x1D400, 0x1D454), (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6),
+        (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A),
+        (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546),
+        (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D6C0), (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6FA), (0x1D6FC, 0x1D714),
+        (0x1D716, 0x1D734), (0x1D736, 0x1D74E), (0x1D750, 0x1D76E), (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8), (0x1D7AA, 0x1D7C2),
+        (0x1D7C4, 0x1D7CB), (0x1DF00, 0x1DF1E), (0x1DF25, 0x1DF2A), (0x1E030, 0x1E06D), (0x1E100, 0x1E12C), (0x1E137, 0x1E13D),
+        (0x1E14E, 0x1E14E), (0x1E290, 0x1E2AD), (0x1E2C0, 0x1E2EB), (0x1E4D0, 0x1E4EB), (0x1E5D0, 0x1E5ED), (0x1E5F0, 0x1E5F0),
+        (0x1E6C0, 0x1E6DE), (0x1E6E0, 0x1E6E2), (0x1E6E4, 0x1E6E5), (0x1E6E7, 0x1E6ED), (0x1E6F0, 0x1E6F4), (0x1E6FE, 0x1E6FF),
+        (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB), (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4), (0x1E900, 0x1E943),
+        (0x1E94B, 0x1E94B), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22), (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27),
+        (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47),
+        (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52), (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57),
+        (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64),
+        (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C), (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89),
+        (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB), (0x20000, 0x2A6DF), (0x2A700, 0x2B81D),
+        (0x2B820, 0x2CEAD), (0x2CEB0, 0x2EBE0), (0x2EBF0, 0x2EE5D), (0x2F800, 0x2FA1D), (0x30000, 0x3134A), (0x31350, 0x33479),
+    ]),
+    ("Ideographic", "Ideo", &[
+        (0x3006, 0x3007), (0x3021, 0x3029), (0x3038, 0x303A), (0x3400, 0x4DBF), (0x4E00, 0x9FFF), (0xF900, 0xFA6D),
+        (0xFA70, 0xFAD9), (0x16FE4, 0x16FE4), (0x16FF2, 0x16FF6), (0x17000, 0x18CD5), (0x18CFF, 0x18D1E), (0x18D80, 0x18DF2),
+        (0x1B170, 0x1B2FB), (0x20000, 0x2A6DF), (0x2A700, 0x2B81D), (0x2B820, 0x2CEAD), (0x2CEB0, 0x2EBE0), (0x2EBF0, 0x2EE5D),
+        (0x2F800, 0x2FA1D), (0x30000, 0x3134A), (0x31350, 0x33479),
+    ]),
+    ("Join_Control", "Join_C", &[
+        (0x200C, 0x200D),
+    ]),
+    ("Logical_Order_Exception", "LOE", &[
+        (0xE40, 0xE44), (0xEC0, 0xEC4), (0x19B5, 0x19B7), (0x19BA, 0x19BA), (0xAAB5, 0xAAB6), (0xAAB9, 0xAAB9),
+        (0xAABB, 0xAABC),
+    ]),
+    ("Lowercase", "Lower", &[
+        (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5), (0xBA, 0xBA), (0xDF, 0xF6), (0xF8, 0xFF),
+        (0x101, 0x101), (0x103, 0x103), (0x105, 0x105), (0x107, 0x107), (0x109, 0x109), (0x10B, 0x10B),
+        (0x10D, 0x10D), (0x10F, 0x10F), (0x111, 0x111), (0x113, 0x113), (0x115, 0x115), (0x117, 0x117),
+        (0x119, 0x119), (0x11B, 0x11B), (0x11D, 0x11D), (0x11F, 0x11F), (0x121, 0x121), (0x123, 0x123),
+        (0x125, 0x125), (0x127, 0x127), (0x129, 0x129), (0x12B, 0x12B), (0x12D, 0x12D), (0x12F, 0x12F),
+        (0x131, 0x131), (0x133, 0x133), (0x135, 0x135), (0x137, 0x138), (0x13A, 0x13A), (0x13C, 0x13C),
+        (0x13E, 0x13E), (0x140, 0x140), (0x142, 0x142), (0x144, 0x144), (0x146, 0x146), (0x148, 0x149),
+        (0x14B, 0x14B), (0x14D, 0x14D), (0x14F, 0x14F), (0x151, 0x151), (0x153, 0x153), (0x155, 0x155),
+        (0x157, 0x157), (0x159, 0x159), (0x15B, 0x15B), (0x15D, 0x15D), (0x15F, 0x15F), (0x161, 0x161),
+        (0x163, 0x163), (0x165, 0x165), (0x167, 0x167), (0x169, 0x169), (0x16B, 0x16B), (0x16D, 0x16D),
+        (0x16F, 0x16F), (0x171, 0x171), (0x173, 0x173), (0x175, 0x175), (0x177, 0x177), (0x17A, 0x17A),
+        (0x17C, 0x17C), (0x17E, 0x180), (0x183, 0x183), (0x185, 0x185), (0x188, 0x188), (0x18C, 0x18D),
+        (0x192, 0x192), (0x195, 0x195), (0x199, 0x19B), (0x19E, 0x19E), (0x1A1, 0x1A1), (0x1A3, 0x1A3),
+        (0x1A5, 0x1A5), (0x1A8, 0x1A8), (0x1AA, 0x1AB), (0x1AD, 0x1AD), (0x1B0, 0x1B0), (0x1B4, 0x1B4),
+        (0x1B6, 0x1B6), (0x1B9, 0x1BA), (0x1BD, 0x1BF), (0x1C6, 0x1C6), (0x1C9, 0x1C9), (0x1CC, 0x1CC),
+        (0x1CE, 0x1CE), (0x1D0, 0x1D0), (0x1D2, 0x1D2), (0x1D4, 0x1D4), (0x1D6, 0x1D6), (0x1D8, 0x1D8),
+        (0x1DA, 0x1DA), (0x1DC, 0x1DD), (0x1DF, 0x1DF), (0x1E1, 0x1E1), (0x1E3, 0x1E3), (0x1E5, 0x1E5),
+        (0x1E7, 0x1E7), (0x1E9, 0x1E9), (0x1EB, 0x1EB), (0x1ED, 0x1ED), (0x1EF, 0x1F0), (0x1F3, 0x1F3),
+        (0x1F5, 0x1F5), (0x1F9, 0x1F9), (0x1FB, 0x1FB), (0x1FD, 0x1FD), (0x1FF, 0x1FF), (0x201, 0x201),
+        (0x203, 0x203), (0x205, 0x205), (0x207, 0x207), (0x209, 0x209), (0x20B, 0x20B), (0x20D, 0x20D),
+        (0x20F, 0x20F), (0x211, 0x211), (0x213, 0x213), (0x215, 0x215), (0x217, 0x217), (0x219, 0x219),
+        (0x21B, 0x21B), (0x21D, 0x21D), (0x21F, 0x21F), (0x221, 0x221), (0x223, 0x223), (0x225, 0x225),
+        (0x227, 0x227), (0x229, 0x229), (0x22B, 0x22B), (0x22D, 0x22D), (0x22F, 0x22F), (0x231, 0x231),
+        (0x233, 0x239), (0x23C, 0x23C), (0x23F, 0x240), (0x242, 0x242), (0x247, 0x247), (0x249, 0x249),
+        (0x24B, 0x24B), (0x24D, 0x24D), (0x24F, 0x293), (0x296, 0x2B8), (0x2C0, 0x2C1), (0x2E0, 0x2E4),
+        (0x345, 0x345), (0x371, 0x371), (0x373, 0x373), (0x377, 0x377), (0x37A, 0x37D), (0x390, 0x390),
+        (0x3AC, 0x3CE), (0x3D0, 0x3D1), (0x3D5, 0x3D7), (0x3D9, 0x3D9), (0x3DB, 0x3DB), (0x3DD, 0x3DD),
+        (0x3DF, 0x3DF), (0x3E1, 0x3E1), (0x3E3, 0x3E3), (0x3E5, 0x3E5), (0x3E7, 0x3E7), (0x3E9, 0x3E9),
+        (0x3EB, 0x3EB), (0x3ED, 0x3ED), (0x3EF, 0x3F3), (0x3F5, 0x3F5), (0x3F8, 0x3F8), (0x3FB, 0x3FC),
+        (0x430, 0x45F), (0x461, 0x461), (0x463, 0x463), (0x465, 0x465), (0x467, 0x467), (0x469, 0x469),
+        (0x46B, 0x46B), (0x46D, 0x46D), (0x46F, 0x46F), (0x471, 0x471), (0x473, 0x473), (0x475, 0x475),
+        (0x477, 0x477), (0x479, 0x479), (0x47B, 0x47B), (0x47D, 0x47D), (0x47F, 0x47F), (0x481, 0x481),
+        (0x48B, 0x48B), (0x48D, 0x48D), (0x48F, 0x48F), (0x491, 0x491), (0x493, 0x493), (0x495, 0x495),
+        (0x497, 0x497), (0x499, 0x499), (0x49B, 0x49B), (0x49D, 0x49D), (0x49F, 0x49F), (0x4A1, 0x4A1),
+        (0x4A3, 0x4A3), (0x4A5, 0x4A5), (0x4A7, 0x4A7), (0x4A9, 0x4A9), (0x4AB, 0x4AB), (0x4AD, 0x4AD),
+        (0x4AF, 0x4AF), (0x4B1, 0x4B1), (0x4B3, 0x4B3), (0x4B5, 0x4B5), (0x4B7, 0x4B7), (0x4B9, 0x4B9),
+        (0x4BB, 0x4BB), (0x4BD, 0x4BD), (0x4BF, 0x4BF), (0x4C2, 0x4C2), (0x4C4, 0x4C4), (0x4C6, 0x4C6),
+        (0x4C8, 0x4C8), (0x4CA, 0x4CA), (0x4CC, 0x4CC), (0x4CE, 0x4CF), (0x4D1, 0x4D1), (0x4D3, 0x4D3),
+        (0x4D5, 0x4D5), (0x4D7, 0x4D7), (0x4D9, 0x4D9), (0x4DB, 0x4DB), (0x4DD, 0x4DD), (0x4DF, 0x4DF),
+        (0x4E1, 0x4E1), (0x4E3, 0x4E3), (0x4E5, 0x4E5), (0x4E7, 0x4E7), (0x4E9, 0x4E9), (0x4EB, 0x4EB),
+        (0x4ED, 0x4ED), (0x4EF, 0x4EF), (0x4F1, 0x4F1), (0x4F3, 0x4F3), (0x4F5, 0x4F5), (0x4F7, 0x4F7),
+        (0x4F9, 0x4F9), (0x4FB, 0x4FB), (0x4FD, 0x4FD), (0x4FF, 0x4FF), (0x501, 0x501), (0x503, 0x503),
+        (0x505, 0x505), (0x507, 0x507), (0x509, 0x509), (0x50B, 0x50B), (0x50D, 0x50D), (0x50F, 0x50F),
+        (0x511, 0x511), (0x513, 0x513), (0x515, 0x515), (0x517, 0x517), (0x519, 0x519), (0x51B, 0x51B),
+        (0x51D, 0x51D), (0x51F, 0x51F), (0x521, 0x521), (0x523, 0x523), (0x525, 0x525), (0x527, 0x527),
+        (0x529, 0x529), (0x52B, 0x52B), (0x52D, 0x52D), (0x52F, 0x52F), (0x560, 0x588), (0x10D0, 0x10FA),
+        (0x10FC, 0x10FF), (0x13F8, 0x13FD), (0x1C80, 0x1C88), (0x1C8A, 0x1C8A), (0x1D00, 0x1DBF), (0x1E01, 0x1E01),
+        (0x1E03, 0x1E03), (0x1E05, 0x1E05), (0x1E07, 0x1E07), (0x1E09, 0x1E09), (0x1E0B, 0x1E0B), (0x1E0D, 0x1E0D),
+        (0x1E0F, 0x1E0F), (0x1E11, 0x1E11), (0x1E13, 0x1E13), (0x1E15, 0x1E15), (0x1E17, 0x1E17), (0x1E19, 0x1E19),
+        (0x1E1B, 0x1E1B), (0x1E1D, 0x1E1D), (0x1E1F, 0x1E1F), (0x1E21, 0x1E21), (0x1E23, 0x1E23), (0x1E25, 0x1E25),
+        (0x1E27, 0x1E27), (0x1E29, 0x1E29), (0x1E2B, 0x1E2B), (0x1E2D, 0x1E2D), (0x1E2F, 0x1E2F), (0x1E31, 0x1E31),
+        (0x1E33, 0x1E33), (0x1E35, 0x1E35), (0x1E37, 0x1E37), (0x1E39, 0x1E39), (0x1E3B, 0x1E3B), (0x1E3D, 0x1E3D),
+        (0x1E3F, 0x1E3F), (0x1E41, 0x1E41), (0x1E43, 0x1E43), (0x1E45, 0x1E45), (0x1E47, 0x1E47), (0x1E49, 0x1E49),
+        (0x1E4B, 0x1E4B), (0x1E4D, 0x1E4D), (0x1E4F, 0x1E4F), (0x1E51, 0x1E51), (0x1E53, 0x1E53), (0x1E55, 0x1E55),
+        (0x1E57, 0x1E57), (0x1E59, 0x1E59), (0x1E5B, 0x1E5B), (0x1E5D, 0x1E5D), (0x1E5F, 0x1E5F), (0x1E61, 0x1E61),
+        (0x1E63, 0x1E63), (0x1E65, 0x1E65), (0x1E67, 0x1E67), (0x1E69, 0x1E69), (0x1E6B, 0x1E6B), (0x1E6D, 0x1E6D),
+        (0x1E6F, 0x1E6F), (0x1E71, 0x1E71), (0x1E73, 0x1E73), (0x1E75, 0x1E75), (0x1E77, 0x1E77), (0x1E79, 0x1E79),
+        (0x1E7B, 0x1E7B), (0x1E7D, 0x1E7D), (0x1E7F, 0x1E7F), (0x1E81, 0x1E81), (0x1E83, 0x1E83), (0x1E85, 0x1E85),
+        (0x1E87, 0x1E87), (0x1E89, 0x1E89), (0x1E8B, 0x1E8B), (0x1E8D, 0x1E8D), (0x1E8F, 0x1E8F), (0x1E91, 0x1E91),
+        (0x1E93, 0x1E93), (0x1E95, 0x1E9D), (0x1E9F, 0x1E9F), (0x1EA1, 0x1EA1), (0x1EA3, 0x1EA3), (0x1EA5, 0x1EA5),
+        (0x1EA7, 0x1EA7), (0x1EA9, 0x1EA9), (0x1EAB, 0x1EAB), (0x1EAD, 0x1EAD), (0x1EAF, 0x1EAF), (0x1EB1, 0x1EB1),
+        (0x1EB3, 0x1EB3), (0x1EB5, 0x1EB5), (0x1EB7, 0x1EB7), (0x1EB9, 0x1EB9), (0x1EBB, 0x1EBB), (0x1EBD, 0x1EBD),
+        (0x1EBF, 0x1EBF), (0x1EC1, 0x1EC1), (0x1EC3, 0x1EC3), (0x1EC5, 0x1EC5), (0x1EC7, 0x1EC7), (0x1EC9, 0x1EC9),
+        (0x1ECB, 0x1ECB), (0x1ECD, 0x1ECD), (0x1ECF, 0x1ECF), (0x1ED1, 0x1ED1), (0x1ED3, 0x1ED3), (0x1ED5, 0x1ED5),
+        (0x1ED7, 0x1ED7), (0x1ED9, 0x1ED9), (0x1EDB, 0x1EDB), (0x1EDD, 0x1EDD), (0x1EDF, 0x1EDF), (0x1EE1, 0x1EE1),
+        (0x1EE3, 0x1EE3), (0x1EE5, 0x1EE5), (0x1EE7, 0x1EE7), (0x1EE9, 0x1EE9), (0x1EEB, 0x1EEB), (0x1EED, 0x1EED),
+        (0x1EEF, 0x1EEF), (0x1EF1, 0x1EF1), (0x1EF3, 0x1EF3), (0x1EF5, 0x1EF5), (0x1EF7, 0x1EF7), (0x1EF9, 0x1EF9),
+        (0x1EFB, 0x1EFB), (0x1EFD, 0x1EFD), (0x1EFF, 0x1F07), (0x1F10, 0x1F15), (0x1F20, 0x1F27), (0x1F30, 0x1F37),
+        (0x1F40, 0x1F45), (0x1F50, 0x1F57), (0x1F60, 0x1F67), (0x1F70, 0x1F7D), (0x1F80, 0x1F87), (0x1F90, 0x1F97),
+        (0x1FA0, 0x1FA7), (0x1FB0, 0x1FB4), (0x1FB6, 0x1FB7), (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4), (0x1FC6, 0x1FC7),
+        (0x1FD0, 0x1FD3), (0x1FD6, 0x1FD7), (0x1FE0, 0x1FE7), (0x1FF2, 0x1FF4), (0x1FF6, 0x1FF7), (0x2071, 0x2071),
+        (0x207F, 0x207F), (0x2090, 0x209C), (0x210A, 0x210A), (0x210E, 0x210F), (0x2113, 0x2113), (0x212F, 0x212F),
+        (0x2134, 0x2134), (0x2139, 0x2139), (0x213C, 0x213D), (0x2146, 0x2149), (0x214E, 0x214E), (0x2170, 0x217F),
+        (0x2184, 0x2184), (0x24D0, 0x24E9), (0x2C30, 0x2C5F), (0x2C61, 0x2C61), (0x2C65, 0x2C66), (0x2C68, 0x2C68),
+        (0x2C6A, 0x2C6A), (0x2C6C, 0x2C6C), (0x2C71, 0x2C71), (0x2C73, 0x2C74), (0x2C76, 0x2C7D), (0x2C81, 0x2C81),
+        (0x2C83, 0x2C83), (0x2C85, 0x2C85), (0x2C87, 0x2C87), (0x2C89, 0x2C89), (0x2C8B, 0x2C8B), (0x2C8D, 0x2C8D),
+        (0x2C8F, 0x2C8F), (0x2C91, 0x2C91), (0x2C93, 0x2C93), (0x2C95, 0x2C95), (0x2C97, 0x2C97), (0x2C99, 0x2C99),
+        (0x2C9B, 0x2C9B), (0x2C9D, 0x2C9D), (0x2C9F, 0x2C9F), (0x2CA1, 0x2CA1), (0x2CA3, 0x2CA3), (0x2CA5, 0x2CA5),
+        (0x2CA7, 0x2CA7), (0x2CA9, 0x2CA9), (0x2CAB, 0x2CAB), (0x2CAD, 0x2CAD), (0x2CAF, 0x2CAF), (0x2CB1, 0x2CB1),
+        (0x2CB3, 0x2CB3), (0x2CB5, 0x2CB5), (0x2CB7, 0x2CB7), (0x2CB9, 0x2CB9), (0x2CBB, 0x2CBB), (0x2CBD, 0x2CBD),
+        (0x2CBF, 0x2CBF), (0x2CC1, 0x2CC1), (0x2CC3, 0x2CC3), (0x2CC5, 0x2CC5), (0x2CC7, 0x2CC7), (0x2CC9, 0x2CC9),
+        (0x2CCB, 0x2CCB), (0x2CCD, 0x2CCD), (0x2CCF, 0x2CCF), (0x2CD1, 0x2CD1), (0x2CD3, 0x2CD3), (0x2CD5, 0x2CD5),
+        (0x2CD7, 0x2CD7), (0x2CD9, 0x2CD9), (0x2CDB, 0x2CDB), (0x2CDD, 0x2CDD), (0x2CDF, 0x2CDF), (0x2CE1, 0x2CE1),
+        (0x2CE3, 0x2CE4), (0x2CEC, 0x2CEC), (0x2CEE, 0x2CEE), (0x2CF3, 0x2CF3), (0x2D00, 0x2D25), (0x2D27, 0x2D27),
+        (0x2D2D, 0x2D2D), (0xA641, 0xA641), (0xA643, 0xA643), (0xA645, 0xA645), (0xA647, 0xA647), (0xA649, 0xA649),
+        (0xA64B, 0xA64B), (0xA64D, 0xA64D), (0xA64F, 0xA64F), (0xA651, 0xA651), (0xA653, 0xA653), (0xA655, 0xA655),
+        (0xA657, 0xA657), (0xA659, 0xA659), (0xA65B, 0xA65B), (0xA65D, 0xA65D), (0xA65F, 0xA65F), (0xA661, 0xA661),
+        (0xA663, 0xA663), (0xA665, 0xA665), (0xA667, 0xA667), (0xA669, 0xA669), (0xA66B, 0xA66B), (0xA66D, 0xA66D),
+        (0xA681, 0xA681), (0xA683, 0xA683), (0xA685, 0xA685), (0xA687, 0xA687), (0xA689, 0xA689), (0xA68B, 0xA68B),
+        (0xA68D, 0xA68D), (0xA68F, 0xA68F), (0xA691, 0xA691), (0xA693, 0xA693), (0xA695, 0xA695), (0xA697, 0xA697),
+        (0xA699, 0xA699), (0xA69B, 0xA69D), (0xA723, 0xA723), (0xA725, 0xA725), (0xA727, 0xA727), (0xA729, 0xA729),
+        (0xA72B, 0xA72B), (0xA72D, 0xA72D), (0xA72F, 0xA731), (0xA733, 0xA733), (0xA735, 0xA735), (0xA737, 0xA737),
+        (0xA739, 0xA739), (0xA73B, 0xA73B), (0xA73D, 0xA73D), (0xA73F, 0xA73F), (0xA741, 0xA741), (0xA743, 0xA743),
+        (0xA745, 0xA745), (0xA747, 0xA747), (0xA749, 0xA749), (0xA74B, 0xA74B), (0xA74D, 0xA74D), (0xA74F, 0xA74F),
+        (0xA751, 0xA751), (0xA753, 0xA753), (0xA755, 0xA755), (0xA757, 0xA757), (0xA759, 0xA759), (0xA75B, 0xA75B),
+        (0xA75D, 0xA75D), (0xA75F, 0xA75F), (0xA761, 0xA761), (0xA763, 0xA763), (0xA765, 0xA765), (0xA767, 0xA767),
+        (0xA769, 0xA769), (0xA76B, 0xA76B), (0xA76D, 0xA76D), (0xA76F, 0xA778), (0xA77A, 0xA77A), (0xA77C, 0xA77C),
+        (0xA77F, 0xA77F), (0xA781, 0xA781), (0xA783, 0xA783), (0xA785, 0xA785), (0xA787, 0xA787), (0xA78C, 0xA78C),
+        (0xA78E, 0xA78E), (0xA791, 0xA791), (0xA793, 0xA795), (0xA797, 0xA797), (0xA799, 0xA799), (0xA79B, 0xA79B),
+        (0xA79D, 0xA79D), (0xA79F, 0xA79F), (0xA7A1, 0xA7A1), (0xA7A3, 0xA7A3), (0xA7A5, 0xA7A5), (0xA7A7, 0xA7A7),
+        (0xA7A9, 0xA7A9), (0xA7AF, 0xA7AF), (0xA7B5, 0xA7B5), (0xA7B7, 0xA7B7), (0xA7B9, 0xA7B9), (0xA7BB, 0xA7BB),
+        (0xA7BD, 0xA7BD), (0xA7BF, 0xA7BF), (0xA7C1, 0xA7C1), (0xA7C3, 0xA7C3), (0xA7C8, 0xA7C8), (0xA7CA, 0xA7CA),
+        (0xA7CD, 0xA7CD), (0xA7CF, 0xA7CF), (0xA7D1, 0xA7D1), (0xA7D3, 0xA7D3), (0xA7D5, 0xA7D5), (0xA7D7, 0xA7D7),
+        (0xA7D9, 0xA7D9), (0xA7DB, 0xA7DB), (0xA7F1, 0xA7F4), (0xA7F6, 0xA7F6), (0xA7F8, 0xA7FA), (0xAB30, 0xAB5A),
+        (0xAB5C, 0xAB69), (0xAB70, 0xABBF), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFF41, 0xFF5A), (0x10428, 0x1044F),
+        (0x104D8, 0x104FB), (0x10597, 0x105A1), (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x10780, 0x10780),
+        (0x10783, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10CC0, 0x10CF2), (0x10D70, 0x10D85), (0x118C0, 0x118DF),
+        (0x16E60, 0x16E7F), (0x16EBB, 0x16ED3), (0x1D41A, 0x1D433), (0x1D44E, 0x1D454), (0x1D456, 0x1D467), (0x1D482, 0x1D49B),
+        (0x1D4B6, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D4CF), (0x1D4EA, 0x1D503), (0x1D51E, 0x1D537),
+        (0x1D552, 0x1D56B), (0x1D586, 0x1D59F), (0x1D5BA, 0x1D5D3), (0x1D5EE, 0x1D607), (0x1D622, 0x1D63B), (0x1D656, 0x1D66F),
+        (0x1D68A, 0x1D6A5), (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6E1), (0x1D6FC, 0x1D714), (0x1D716, 0x1D71B), (0x1D736, 0x1D74E),
+        (0x1D750, 0x1D755), (0x1D770, 0x1D788), (0x1D78A, 0x1D78F), (0x1D7AA, 0x1D7C2), (0x1D7C4, 0x1D7C9), (0x1D7CB, 0x1D7CB),
+        (0x1DF00, 0x1DF09), (0x1DF0B, 0x1DF1E), (0x1DF25, 0x1DF2A), (0x1E030, 0x1E06D), (0x1E922, 0x1E943),
+    ]),
+    ("Math", "", &[
+        (0x2B, 0x2B), (0x3C, 0x3E), (0x5E, 0x5E), (0x7C, 0x7C), (0x7E, 0x7E), (0xAC, 0xAC),
+        (0xB1, 0xB1), (0xD7, 0xD7), (0xF7, 0xF7), (0x3D0, 0x3D2), (0x3D5, 0x3D5), (0x3F0, 0x3F1),
+        (0x3F4, 0x3F6), (0x606, 0x608), (0x2016, 0x2016), (0x2032, 0x2034), (0x2040, 0x2040), (0x2044, 0x2044),
+        (0x2052, 0x2052), (0x2061, 0x2064), (0x207A, 0x207E), (0x208A, 0x208E), (0x20D0, 0x20DC), (0x20E1, 0x20E1),
+        (0x20E5, 0x20E6), (0x20EB, 0x20EF), (0x2102, 0x2102), (0x2107, 0x2107), (0x210A, 0x2113), (0x2115, 0x2115),
+        (0x2118, 0x211D), (0x2124, 0x2124), (0x2128, 0x2129), (0x212C, 0x212D), (0x212F, 0x2131), (0x2133, 0x2138),
+        (0x213C, 0x2149), (0x214B, 0x214B), (0x2190, 0x21A7), (0x21A9, 0x21AE), (0x21B0, 0x21B1), (0x21B6, 0x21B7),
+        (0x21BC, 0x21DB), (0x21DD, 0x21DD), (0x21E4, 0x21E5), (0x21F4, 0x22FF), (0x2308, 0x230B), (0x2320, 0x2321),
+        (0x237C, 0x237C), (0x239B, 0x23B5), (0x23B7, 0x23B7), (0x23D0, 0x23D0), (0x23DC, 0x23E2), (0x25A0, 0x25A1),
+        (0x25AE, 0x25B7), (0x25BC, 0x25C1), (0x25C6, 0x25C7), (0x25CA, 0x25CB), (0x25CF, 0x25D3), (0x25E2, 0x25E2),
+        (0x25E4, 0x25E4), (0x25E7, 0x25EC), (0x25F8, 0x25FF), (0x2605, 0x2606), (0x2640, 0x2640), (0x2642, 0x2642),
+        (0x2660, 0x2663), (0x266D, 0x266F), (0x27C0, 0x27FF), (0x2900, 0x2AFF), (0x2B30, 0x2B44), (0x2B47, 0x2B4C),
+        (0xFB29, 0xFB29), (0xFE61, 0xFE66), (0xFE68, 0xFE68), (0xFF0B, 0xFF0B), (0xFF1C, 0xFF1E), (0xFF3C, 0xFF3C),
+        (0xFF3E, 0xFF3E), (0xFF5C, 0xFF5C), (0xFF5E, 0xFF5E), (0xFFE2, 0xFFE2), (0xFFE9, 0xFFEC), (0x10D8E, 0x10D8F),
+        (0x1CEF0, 0x1CEF0), (0x1D400, 0x1D454), (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6),
+        (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A),
+        (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546),
+        (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D7CB), (0x1D7CE, 0x1D7FF), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F),
+        (0x1EE21, 0x1EE22), (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39),
+        (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47), (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F),
+        (0x1EE51, 0x1EE52), (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D),
+        (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64), (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77),
+        (0x1EE79, 0x1EE7C), (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9),
+        (0x1EEAB, 0x1EEBB), (0x1EEF0, 0x1EEF1), (0x1F8D0, 0x1F8D8),
+    ]),
+    ("Noncharacter_Code_Point", "NChar", &[
+        (0xFDD0, 0xFDEF), (0xFFFE, 0xFFFF), (0x1FFFE, 0x1FFFF), (0x2FFFE, 0x2FFFF), (0x3FFFE, 0x3FFFF), (0x4FFFE, 0x4FFFF),
+        (0x5FFFE, 0x5FFFF), (0x6FFFE, 0x6FFFF), (0x7FFFE, 0x7FFFF), (0x8FFFE, 0x8FFFF), (0x9FFFE, 0x9FFFF), (0xAFFFE, 0xAFFFF),
+        (0xBFFFE, 0xBFFFF), (0xCFFFE, 0xCFFFF), (0xDFFFE, 0xDFFFF), (0xEFFFE, 0xEFFFF), (0xFFFFE, 0xFFFFF), (0x10FFFE, 0x10FFFF),
+    ]),
+    ("Pattern_Syntax", "Pat_Syn", &[
+        (0x21, 0x2F), (0x3A, 0x40), (0x5B, 0x5E), (0x60, 0x60), (0x7B, 0x7E), (0xA1, 0xA7),
+        (0xA9, 0xA9), (0xAB, 0xAC), (0xAE, 0xAE), (0xB0, 0xB1), (0xB6, 0xB6), (0xBB, 0xBB),
+        (0xBF, 0xBF), (0xD7, 0xD7), (0xF7, 0xF7), (0x2010, 0x2027), (0x2030, 0x203E), (0x2041, 0x2053),
+        (0x2055, 0x205E), (0x2190, 0x245F), (0x2500, 0x2775), (0x2794, 0x2BFF), (0x2E00, 0x2E7F), (0x3001, 0x3003),
+        (0x3008, 0x3020), (0x3030, 0x3030), (0xFD3E, 0xFD3F), (0xFE45, 0xFE46),
+    ]),
+    ("Pattern_White_Space", "Pat_WS", &[
+        (0x9, 0xD), (0x20, 0x20), (0x85, 0x85), (0x200E, 0x200F), (0x2028, 0x2029),
+    ]),
+    ("Quotation_Mark", "QMark", &[
+        (0x22, 0x22), (0x27, 0x27), (0xAB, 0xAB), (0xBB, 0xBB), (0x2018, 0x201F), (0x2039, 0x203A),
+        (0x2E42, 0x2E42), (0x300C, 0x300F), (0x301D, 0x301F), (0xFE41, 0xFE44), (0xFF02, 0xFF02), (0xFF07, 0xFF07),
+        (0xFF62, 0xFF63),
+    ]),
+    ("Radical", "", &[
+        (0x2E80, 0x2E99), (0x2E9B, 0x2EF3), (0x2F00, 0x2FD5),
+    ]),
+    ("Regional_Indicator", "RI", &[
+        (0x1F1E6, 0x1F1FF),
+    ]),
+    ("Sentence_Terminal", "STerm", &[
+        (0x21, 0x21), (0x2E, 0x2E), (0x3F, 0x3F), (0x589, 0x589), (0x61D, 0x61F), (0x6D4, 0x6D4),
+        (0x700, 0x702), (0x7F9, 0x7F9), (0x837, 0x837), (0x839, 0x839), (0x83D, 0x83E), (0x964, 0x965),
+        (0x104A, 0x104B), (0x1362, 0x1362), (0x1367, 0x1368), (0x166E, 0x166E), (0x1735, 0x1736), (0x17D4, 0x17D5),
+        (0x1803, 0x1803), (0x1809, 0x1809), (0x1944, 0x1945), (0x1AA8, 0x1AAB), (0x1B4E, 0x1B4F), (0x1B5A, 0x1B5B),
+        (0x1B5E, 0x1B5F), (0x1B7D, 0x1B7F), (0x1C3B, 0x1C3C), (0x1C7E, 0x1C7F), (0x2024, 0x2024), (0x203C, 0x203D),
+        (0x2047, 0x2049), (0x2CF9, 0x2CFB), (0x2E2E, 0x2E2E), (0x2E3C, 0x2E3C), (0x2E53, 0x2E54), (0x3002, 0x3002),
+        (0xA4FF, 0xA4FF), (0xA60E, 0xA60F), (0xA6F3, 0xA6F3), (0xA6F7, 0xA6F7), (0xA876, 0xA877), (0xA8CE, 0xA8CF),
+        (0xA92F, 0xA92F), (0xA9C8, 0xA9C9), (0xAA5D, 0xAA5F), (0xAAF0, 0xAAF1), (0xABEB, 0xABEB), (0xFE12, 0xFE12),
+        (0xFE15, 0xFE16), (0xFE52, 0xFE52), (0xFE56, 0xFE57), (0xFF01, 0xFF01), (0xFF0E, 0xFF0E), (0xFF1F, 0xFF1F),
+        (0xFF61, 0xFF61), (0x10A56, 0x10A57), (0x10F55, 0x10F59), (0x10F86, 0x10F89), (0x11047, 0x11048), (0x110BE, 0x110C1),
+        (0x11141, 0x11143), (0x111C5, 0x111C6), (0x111CD, 0x111CD), (0x111DE, 0x111DF), (0x11238, 0x11239), (0x1123B, 0x1123C),
+        (0x112A9, 0x112A9), (0x113D4, 0x113D5), (0x1144B, 0x1144C), (0x115C2, 0x115C3), (0x115C9, 0x115D7), (0x11641, 0x11642),
+        (0x1173C, 0x1173E), (0x11944, 0x11944), (0x11946, 0x11946), (0x11A42, 0x11A43), (0x11A9B, 0x11A9C), (0x11C41, 0x11C42),
+        (0x11EF7, 0x11EF8), (0x11F43, 0x11F44), (0x16A6E, 0x16A6F), (0x16AF5, 0x16AF5), (0x16B37, 0x16B38), (0x16B44, 0x16B44),
+        (0x16D6E, 0x16D6F), (0x16E98, 0x16E98), (0x1BC9F, 0x1BC9F), (0x1DA88, 0x1DA88),
+    ]),
+    ("Soft_Dotted", "SD", &[
+        (0x69, 0x6A), (0x12F, 0x12F), (0x249, 0x249), (0x268, 0x268), (0x29D, 0x29D), (0x2B2, 0x2B2),
+        (0x3F3, 0x3F3), (0x456, 0x456), (0x458, 0x458), (0x1D62, 0x1D62), (0x1D96, 0x1D96), (0x1DA4, 0x1DA4),
+        (0x1DA8, 0x1DA8), (0x1E2D, 0x1E2D), (0x1ECB, 0x1ECB), (0x2071, 0x2071), (0x2148, 0x2149), (0x2C7C, 0x2C7C),
+        (0x1D422, 0x1D423), (0x1D456, 0x1D457), (0x1D48A, 0x1D48B), (0x1D4BE, 0x1D4BF), (0x1D4F2, 0x1D4F3), (0x1D526, 0x1D527),
+        (0x1D55A, 0x1D55B), (0x1D58E, 0x1D58F), (0x1D5C2, 0x1D5C3), (0x1D5F6, 0x1D5F7), (0x1D62A, 0x1D62B), (0x1D65E, 0x1D65F),
+        (0x1D692, 0x1D693), (0x1DF1A, 0x1DF1A), (0x1E04C, 0x1E04D), (0x1E068, 0x1E068),
+    ]),
+    ("Terminal_Punctuation", "Term", &[
+        (0x21, 0x21), (0x2C, 0x2C), (0x2E, 0x2E), (0x3A, 0x3B), (0x3F, 0x3F), (0x37E, 0x37E),
+        (0x387, 0x387), (0x589, 0x589), (0x5C3, 0x5C3), (0x60C, 0x60C), (0x61B, 0x61B), (0x61D, 0x61F),
+        (0x6D4, 0x6D4), (0x700, 0x70A), (0x70C, 0x70C), (0x7F8, 0x7F9), (0x830, 0x835), (0x837, 0x83E),
+        (0x85E, 0x85E), (0x964, 0x965), (0xE5A, 0xE5B), (0xF08, 0xF08), (0xF0D, 0xF12), (0x104A, 0x104B),
+        (0x1361, 0x1368), (0x166E, 0x166E), (0x16EB, 0x16ED), (0x1735, 0x1736), (0x17D4, 0x17D6), (0x17DA, 0x17DA),
+        (0x1802, 0x1805), (0x1808, 0x1809), (0x1944, 0x1945), (0x1AA8, 0x1AAB), (0x1B4E, 0x1B4F), (0x1B5A, 0x1B5B),
+        (0x1B5D, 0x1B5F), (0x1B7D, 0x1B7F), (0x1C3B, 0x1C3F), (0x1C7E, 0x1C7F), (0x2024, 0x2024), (0x203C, 0x203D),
+        (0x2047, 0x2049), (0x2CF9, 0x2CFB), (0x2E2E, 0x2E2E), (0x2E3C, 0x2E3C), (0x2E41, 0x2E41), (0x2E4C, 0x2E4C),
+        (0x2E4E, 0x2E4F), (0x2E53, 0x2E54), (0x3001, 0x3002), (0xA4FE, 0xA4FF), (0xA60D, 0xA60F), (0xA6F3, 0xA6F7),
+        (0xA876, 0xA877), (0xA8CE, 0xA8CF), (0xA92F, 0xA92F), (0xA9C7, 0xA9C9), (0xAA5D, 0xAA5F), (0xAADF, 0xAADF),
+        (0xAAF0, 0xAAF1), (0xABEB, 0xABEB), (0xFE12, 0xFE12), (0xFE15, 0xFE16), (0xFE50, 0xFE52), (0xFE54, 0xFE57),
+        (0xFF01, 0xFF01), (0xFF0C, 0xFF0C), (0xFF0E, 0xFF0E), (0xFF1A, 0xFF1B), (0xFF1F, 0xFF1F), (0xFF61, 0xFF61),
+        (0xFF64, 0xFF64), (0x1039F, 0x1039F), (0x103D0, 0x103D0), (0x10857, 0x10857), (0x1091F, 0x1091F), (0x10A56, 0x10A57),
+        (0x10AF0, 0x10AF5), (0x10B3A, 0x10B3F), (0x10B99, 0x10B9C), (0x10F55, 0x10F59), (0x10F86, 0x10F89), (0x11047, 0x1104D),
+        (0x110BE, 0x110C1), (0x11141, 0x11143), (0x111C5, 0x111C6), (0x111CD, 0x111CD), (0x111DE, 0x111DF), (0x11238, 0x1123C),
+        (0x112A9, 0x112A9), (0x113D4, 0x113D5), (0x1144B, 0x1144D), (0x1145A, 0x1145B), (0x115C2, 0x115C5), (0x115C9, 0x115D7),
+        (0x11641, 0x11642), (0x1173C, 0x1173E), (0x11944, 0x11944), (0x11946, 0x11946), (0x11A42, 0x11A43), (0x11A9B, 0x11A9C),
+        (0x11AA1, 0x11AA2), (0x11C41, 0x11C43), (0x11C71, 0x11C71), (0x11EF7, 0x11EF8), (0x11F43, 0x11F44), (0x12470, 0x12474),
+        (0x16A6E, 0x16A6F), (0x16AF5, 0x16AF5), (0x16B37, 0x16B39), (0x16B44, 0x16B44), (0x16D6E, 0x16D6F), (0x16E97, 0x16E98),
+        (0x1BC9F, 0x1BC9F), (0x1DA87, 0x1DA8A),
+    ]),
+    ("Unified_Ideograph", "UIdeo", &[
+        (0x3400, 0x4DBF), (0x4E00, 0x9FFF), (0xFA0E, 0xFA0F), (0xFA11, 0xFA11), (0xFA13, 0xFA14), (0xFA1F, 0xFA1F),
+        (0xFA21, 0xFA21), (0xFA23, 0xFA24), (0xFA27, 0xFA29), (0x20000, 0x2A6DF), (0x2A700, 0x2B81D), (0x2B820, 0x2CEAD),
+        (0x2CEB0, 0x2EBE0), (0x2EBF0, 0x2EE5D), (0x30000, 0x3134A), (0x31350, 0x33479),
+    ]),
+    ("Uppercase", "Upper", &[
+        (0x41, 0x5A), (0xC0, 0xD6), (0xD8, 0xDE), (0x100, 0x100), (0x102, 0x102), (0x104, 0x104),
+        (0x106, 0x106), (0x108, 0x108), (0x10A, 0x10A), (0x10C, 0x10C), (0x10E, 0x10E), (0x110, 0x110),
+        (0x112, 0x112), (0x114, 0x114), (0x116, 0x116), (0x118, 0x118), (0x11A, 0x11A), (0x11C, 0x11C),
+        (0x11E, 0x11E), (0x120, 0x120), (0x122, 0x122), (0x124, 0x124), (0x126, 0x126), (0x128, 0x128),
+        (0x12A, 0x12A), (0x12C, 0x12C), (0x12E, 0x12E), (0x130, 0x130), (0x132, 0x132), (0x134, 0x134),
+        (0x136, 0x136), (0x139, 0x139), (0x13B, 0x13B), (0x13D, 0x13D), (0x13F, 0x13F), (0x141, 0x141),
+        (0x143, 0x143), (0x145, 0x145), (0x147, 0x147), (0x14A, 0x14A), (0x14C, 0x14C), (0x14E, 0x14E),
+        (0x150, 0x150), (0x152, 0x152), (0x154, 0x154), (0x156, 0x156), (0x158, 0x158), (0x15A, 0x15A),
+        (0x15C, 0x15C), (0x15E, 0x15E), (0x160, 0x160), (0x162, 0x162), (0x164, 0x164), (0x166, 0x166),
+        (0x168, 0x168), (0x16A, 0x16A), (0x16C, 0x16C), (0x16E, 0x16E), (0x170, 0x170), (0x172, 0x172),
+        (0x174, 0x174), (0x176, 0x176), (0x178, 0x179), (0x17B, 0x17B), (0x17D, 0x17D), (0x181, 0x182),
+        (0x184, 0x184), (0x186, 0x187), (0x189, 0x18B), (0x18E, 0x191), (0x193, 0x194), (0x196, 0x198),
+        (0x19C, 0x19D), (0x19F, 0x1A0), (0x1A2, 0x1A2), (0x1A4, 0x1A4), (0x1A6, 0x1A7), (0x1A9, 0x1A9),
+        (0x1AC, 0x1AC), (0x1AE, 0x1AF), (0x1B1, 0x1B3), (0x1B5, 0x1B5), (0x1B7, 0x1B8), (0x1BC, 0x1BC),
+        (0x1C4, 0x1C4), (0x1C7, 0x1C7), (0x1CA, 0x1CA), (0x1CD, 0x1CD), (0x1CF, 0x1CF), (0x1D1, 0x1D1),
+        (0x1D3, 0x1D3), (0x1D5, 0x1D5), (0x1D7, 0x1D7), (0x1D9, 0x1D9), (0x1DB, 0x1DB), (0x1DE, 0x1DE),
+        (0x1E0, 0x1E0), (0x1E2, 0x1E2), (0x1E4, 0x1E4), (0x1E6, 0x1E6), (0x1E8, 0x1E8), (0x1EA, 0x1EA),
+        (0x1EC, 0x1EC), (0x1EE, 0x1EE), (0x1F1, 0x1F1), (0x1F4, 0x1F4), (0x1F6, 0x1F8), (0x1FA, 0x1FA),
+        (0x1FC, 0x1FC), (0x1FE, 0x1FE), (0x200, 0x200), (0x202, 0x202), (0x204, 0x204), (0x206, 0x206),
+        (0x208, 0x208), (0x20A, 0x20A), (0x20C, 0x20C), (0x20E, 0x20E), (0x210, 0x210), (0x212, 0x212),
+        (0x214, 0x214), (0x216, 0x216), (0x218, 0x218), (0x21A, 0x21A), (0x21C, 0x21C), (0x21E, 0x21E),
+        (0x220, 0x220), (0x222, 0x222), (0x224, 0x224), (0x226, 0x226), (0x228, 0x228), (0x22A, 0x22A),
+        (0x22C, 0x22C), (0x22E, 0x22E), (0x230, 0x230), (0x232, 0x232), (0x23A, 0x23B), (0x23D, 0x23E),
+        (0x241, 0x241), (0x243, 0x246), (0x248, 0x248), (0x24A, 0x24A), (0x24C, 0x24C), (0x24E, 0x24E),
+        (0x370, 0x370), (0x372, 0x372), (0x376, 0x376), (0x37F, 0x37F), (0x386, 0x386), (0x388, 0x38A),
+        (0x38C, 0x38C), (0x38E, 0x38F), (0x391, 0x3A1), (0x3A3, 0x3AB), (0x3CF, 0x3CF), (0x3D2, 0x3D4),
+        (0x3D8, 0x3D8), (0x3DA, 0x3DA), (0x3DC, 0x3DC), (0x3DE, 0x3DE), (0x3E0, 0x3E0), (0x3E2, 0x3E2),
+        (0x3E4, 0x3E4), (0x3E6, 0x3E6), (0x3E8, 0x3E8), (0x3EA, 0x3EA), (0x3EC, 0x3EC), (0x3EE, 0x3EE),
+        (0x3F4, 0x3F4), (0x3F7, 0x3F7), (0x3F9, 0x3FA), (0x3FD, 0x42F), (0x460, 0x460), (0x462, 0x462),
+        (0x464, 0x464), (0x466, 0x466), (0x468, 0x468), (0x46A, 0x46A), (0x46C, 0x46C), (0x46E, 0x46E),
+        (0x470, 0x470), (0x472, 0x472), (0x474, 0x474), (0x476, 0x476), (0x478, 0x478), (0x47A, 0x47A),
+        (0x47C, 0x47C), (0x47E, 0x47E), (0x480, 0x480), (0x48A, 0x48A), (0x48C, 0x48C), (0x48E, 0x48E),
+        (0x490, 0x490), (0x492, 0x492), (0x494, 0x494), (0x496, 0x496), (0x498, 0x498), (0x49A, 0x49A),
+        (0x49C, 0x49C), (0x49E, 0x49E), (0x4A0, 0x4A0), (0x4A2, 0x4A2), (0x4A4, 0x4A4), (0x4A6, 0x4A6),
+        (0x4A8, 0x4A8), (0x4AA, 0x4AA), (0x4AC, 0x4AC), (0x4AE, 0x4AE), (0x4B0, 0x4B0), (0x4B2, 0x4B2),
+        (0x4B4, 0x4B4), (0x4B6, 0x4B6), (0x4B8, 0x4B8), (0x4BA, 0x4BA), (0x4BC, 0x4BC), (0x4BE, 0x4BE),
+        (0x4C0, 0x4C1), (0x4C3, 0x4C3), (0x4C5, 0x4C5), (0x4C7, 0x4C7), (0x4C9, 0x4C9), (0x4CB, 0x4CB),
+        (0x4CD, 0x4CD), (0x4D0, 0x4D0), (0x4D2, 0x4D2), (0x4D4, 0x4D4), (0x4D6, 0x4D6), (0x4D8, 0x4D8),
+        (0x4DA, 0x4DA), (0x4DC, 0x4DC), (0x4DE, 0x4DE), (0x4E0, 0x4E0), (0x4E2, 0x4E2), (0x4E4, 0x4E4),
+        (0x4E6, 0x4E6), (0x4E8, 0x4E8), (0x4EA, 0x4EA), (0x4EC, 0x4EC), (0x4EE, 0x4EE), (0x4F0, 0x4F0),
+        (0x4F2, 0x4F2), (0x4F4, 0x4F4), (0x4F6, 0x4F6), (0x4F8, 0x4F8), (0x4FA, 0x4FA), (0x4FC, 0x4FC),
+        (0x4FE, 0x4FE), (0x500, 0x500), (0x502, 0x502), (0x504, 0x504), (0x506, 0x506), (0x508, 0x508),
+        (0x50A, 0x50A), (0x50C, 0x50C), (0x50E, 0x50E), (0x510, 0x510), (0x512, 0x512), (0x514, 0x514),
+        (0x516, 0x516), (0x518, 0x518), (0x51A, 0x51A), (0x51C, 0x51C), (0x51E, 0x51E), (0x520, 0x520),
+        (0x522, 0x522), (0x524, 0x524), (0x526, 0x526), (0x528, 0x528), (0x52A, 0x52A), (0x52C, 0x52C),
+        (0x52E, 0x52E), (0x531, 0x556), (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD), (0x13A0, 0x13F5),
+        (0x1C89, 0x1C89), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1E00, 0x1E00), (0x1E02, 0x1E02), (0x1E04, 0x1E04),
+        (0x1E06, 0x1E06), (0x1E08, 0x1E08), (0x1E0A, 0x1E0A), (0x1E0C, 0x1E0C), (0x1E0E, 0x1E0E), (0x1E10, 0x1E10),
+        (0x1E12, 0x1E12), (0x1E14, 0x1E14), (0x1E16, 0x1E16), (0x1E18, 0x1E18), (0x1E1A, 0x1E1A), (0x1E1C, 0x1E1C),
+        (0x1E1E, 0x1E1E), (0x1E20, 0x1E20), (0x1E22, 0x1E22), (0x1E24, 0x1E24), (0x1E26, 0x1E26), (0x1E28, 0x1E28),
+        (0x1E2A, 0x1E2A), (0x1E2C, 0x1E2C), (0x1E2E, 0x1E2E), (0x1E30, 0x1E30), (0x1E32, 0x1E32), (0x1E34, 0x1E34),
+        (0x1E36, 0x1E36), (0x1E38, 0x1E38), (0x1E3A, 0x1E3A), (0x1E3C, 0x1E3C), (0x1E3E, 0x1E3E), (0x1E40, 0x1E40),
+        (0x1E42, 0x1E42), (0x1E44, 0x1E44), (0x1E46, 0x1E46), (0x1E48, 0x1E48), (0x1E4A, 0x1E4A), (0x1E4C, 0x1E4C),
+        (0x1E4E, 0x1E4E), (0x1E50, 0x1E50), (0x1E52, 0x1E52), (0x1E54, 0x1E54), (0x1E56, 0x1E56), (0x1E58, 0x1E58),
+        (0x1E5A, 0x1E5A), (0x1E5C, 0x1E5C), (0x1E5E, 0x1E5E), (0x1E60, 0x1E60), (0x1E62, 0x1E62), (0x1E64, 0x1E64),
+        (0x1E66, 0x1E66), (0x1E68, 0x1E68), (0x1E6A, 0x1E6A), (0x1E6C, 0x1E6C), (0x1E6E, 0x1E6E), (0x1E70, 0x1E70),
+        (0x1E72, 0x1E72), (0x1E74, 0x1E74), (0x1E76, 0x1E76), (0x1E78, 0x1E78), (0x1E7A, 0x1E7A), (0x1E7C, 0x1E7C),
+        (0x1E7E, 0x1E7E), (0x1E80, 0x1E80), (0x1E82, 0x1E82), (0x1E84, 0x1E84), (0x1E86, 0x1E86), (0x1E88, 0x1E88),
+        (0x1E8A, 0x1E8A), (0x1E8C, 0x1E8C), (0x1E8E, 0x1E8E), (0x1E90, 0x1E90), (0x1E92, 0x1E92), (0x1E94, 0x1E94),
+        (0x1E9E, 0x1E9E), (0x1EA0, 0x1EA0), (0x1EA2, 0x1EA2), (0x1EA4, 0x1EA4), (0x1EA6, 0x1EA6), (0x1EA8, 0x1EA8),
+        (0x1EAA, 0x1EAA), (0x1EAC, 0x1EAC), (0x1EAE, 0x1EAE), (0x1EB0, 0x1EB0), (0x1EB2, 0x1EB2), (0x1EB4, 0x1EB4),
+        (0x1EB6, 0x1EB6), (0x1EB8, 0x1EB8), (0x1EBA, 0x1EBA), (0x1EBC, 0x1EBC), (0x1EBE, 0x1EBE), (0x1EC0, 0x1EC0),
+        (0x1EC2, 0x1EC2), (0x1EC4, 0x1EC4), (0x1EC6, 0x1EC6), (0x1EC8, 0x1EC8), (0x1ECA, 0x1ECA), (0x1ECC, 0x1ECC),
+        (0x1ECE, 0x1ECE), (0x1ED0, 0x1ED0), (0x1ED2, 0x1ED2), (0x1ED4, 0x1ED4), (0x1ED6, 0x1ED6), (0x1ED8, 0x1ED8),
+        (0x1EDA, 0x1EDA), (0x1EDC, 0x1EDC), (0x1EDE, 0x1EDE), (0x1EE0, 0x1EE0), (0x1EE2, 0x1EE2), (0x1EE4, 0x1EE4),
+        (0x1EE6, 0x1EE6), (0x1EE8, 0x1EE8), (0x1EEA, 0x1EEA), (0x1EEC, 0x1EEC), (0x1EEE, 0x1EEE), (0x1EF0, 0x1EF0),
+        (0x1EF2, 0x1EF2), (0x1EF4, 0x1EF4), (0x1EF6, 0x1EF6), (0x1EF8, 0x1EF8), (0x1EFA, 0x1EFA), (0x1EFC, 0x1EFC),
+        (0x1EFE, 0x1EFE), (0x1F08, 0x1F0F), (0x1F18, 0x1F1D), (0x1F28, 0x1F2F), (0x1F38, 0x1F3F), (0x1F48, 0x1F4D),
+        (0x1F59, 0x1F59), (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D), (0x1F5F, 0x1F5F), (0x1F68, 0x1F6F), (0x1FB8, 0x1FBB),
+        (0x1FC8, 0x1FCB), (0x1FD8, 0x1FDB), (0x1FE8, 0x1FEC), (0x1FF8, 0x1FFB), (0x2102, 0x2102), (0x2107, 0x2107),
+        (0x210B, 0x210D), (0x2110, 0x2112), (0x2115, 0x2115), (0x2119, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126),
+        (0x2128, 0x2128), (0x212A, 0x212D), (0x2130, 0x2133), (0x213E, 0x213F), (0x2145, 0x2145), (0x2160, 0x216F),
+        (0x2183, 0x2183), (0x24B6, 0x24CF), (0x2C00, 0x2C2F), (0x2C60, 0x2C60), (0x2C62, 0x2C64), (0x2C67, 0x2C67),
+        (0x2C69, 0x2C69), (0x2C6B, 0x2C6B), (0x2C6D, 0x2C70), (0x2C72, 0x2C72), (0x2C75, 0x2C75), (0x2C7E, 0x2C80),
+        (0x2C82, 0x2C82), (0x2C84, 0x2C84), (0x2C86, 0x2C86), (0x2C88, 0x2C88), (0x2C8A, 0x2C8A), (0x2C8C, 0x2C8C),
+        (0x2C8E, 0x2C8E), (0x2C90, 0x2C90), (0x2C92, 0x2C92), (0x2C94, 0x2C94), (0x2C96, 0x2C96), (0x2C98, 0x2C98),
+        (0x2C9A, 0x2C9A), (0x2C9C, 0x2C9C), (0x2C9E, 0x2C9E), (0x2CA0, 0x2CA0), (0x2CA2, 0x2CA2), (0x2CA4, 0x2CA4),
+        (0x2CA6, 0x2CA6), (0x2CA8, 0x2CA8), (0x2CAA, 0x2CAA), (0x2CAC, 0x2CAC), (0x2CAE, 0x2CAE), (0x2CB0, 0x2CB0),
+        (0x2CB2, 0x2CB2), (0x2CB4, 0x2CB4), (0x2CB6, 0x2CB6), (0x2CB8, 0x2CB8), (0x2CBA, 0x2CBA), (0x2CBC, 0x2CBC),
+        (0x2CBE, 0x2CBE), (0x2CC0, 0x2CC0), (0x2CC2, 0x2CC2), (0x2CC4, 0x2CC4), (0x2CC6, 0x2CC6), (0x2CC8, 0x2CC8),
+        (0x2CCA, 0x2CCA), (0x2CCC, 0x2CCC), (0x2CCE, 0x2CCE), (0x2CD0, 0x2CD0), (0x2CD2, 0x2CD2), (0x2CD4, 0x2CD4),
+        (0x2CD6, 0x2CD6), (0x2CD8, 0x2CD8), (0x2CDA, 0x2CDA), (0x2CDC, 0x2CDC), (0x2CDE, 0x2CDE), (0x2CE0, 0x2CE0),
+        (0x2CE2, 0x2CE2), (0x2CEB, 0x2CEB), (0x2CED, 0x2CED), (0x2CF2, 0x2CF2), (0xA640, 0xA640), (0xA642, 0xA642),
+        (0xA644, 0xA644), (0xA646, 0xA646), (0xA648, 0xA648), (0xA64A, 0xA64A), (0xA64C, 0xA64C), (0xA64E, 0xA64E),
+        (0xA650, 0xA650), (0xA652, 0xA652), (0xA654, 0xA654), (0xA656, 0xA656), (0xA658, 0xA658), (0xA65A, 0xA65A),
+        (0xA65C, 0xA65C), (0xA65E, 0xA65E), (0xA660, 0xA660), (0xA662, 0xA662), (0xA664, 0xA664), (0xA666, 0xA666),
+        (0xA668, 0xA668), (0xA66A, 0xA66A), (0xA66C, 0xA66C), (0xA680, 0xA680), (0xA682, 0xA682), (0xA684, 0xA684),
+        (0xA686, 0xA686), (0xA688, 0xA688), (0xA68A, 0xA68A), (0xA68C, 0xA68C), (0xA68E, 0xA68E), (0xA690, 0xA690),
+        (0xA692, 0xA692), (0xA694, 0xA694), (0xA696, 0xA696), (0xA698, 0xA698), (0xA69A, 0xA69A), (0xA722, 0xA722),
+        (0xA724, 0xA724), (0xA726, 0xA726), (0xA728, 0xA728), (0xA72A, 0xA72A), (0xA72C, 0xA72C), (0xA72E, 0xA72E),
+        (0xA732, 0xA732), (0xA734, 0xA734), (0xA736, 0xA736), (0xA738, 0xA738), (0xA73A, 0xA73A), (0xA73C, 0xA73C),
+        (0xA73E, 0xA73E), (0xA740, 0xA740), (0xA742, 0xA742), (0xA744, 0xA744), (0xA746, 0xA746), (0xA748, 0xA748),
+        (0xA74A, 0xA74A), (0xA74C, 0xA74C), (0xA74E, 0xA74E), (0xA750, 0xA750), (0xA752, 0xA752), (0xA754, 0xA754),
+        (0xA756, 0xA756), (0xA758, 0xA758), (0xA75A, 0xA75A), (0xA75C, 0xA75C), (0xA75E, 0xA75E), (0xA760, 0xA760),
+        (0xA762, 0xA762), (0xA764, 0xA764), (0xA766, 0xA766), (0xA768, 0xA768), (0xA76A, 0xA76A), (0xA76C, 0xA76C),
+        (0xA76E, 0xA76E), (0xA779, 0xA779), (0xA77B, 0xA77B), (0xA77D, 0xA77E), (0xA780, 0xA780), (0xA782, 0xA782),
+        (0xA784, 0xA784), (0xA786, 0xA786), (0xA78B, 0xA78B), (0xA78D, 0xA78D), (0xA790, 0xA790), (0xA792, 0xA792),
+        (0xA796, 0xA796), (0xA798, 0xA798), (0xA79A, 0xA79A), (0xA79C, 0xA79C), (0xA79E, 0xA79E), (0xA7A0, 0xA7A0),
+        (0xA7A2, 0xA7A2), (0xA7A4, 0xA7A4), (0xA7A6, 0xA7A6), (0xA7A8, 0xA7A8), (0xA7AA, 0xA7AE), (0xA7B0, 0xA7B4),
+        (0xA7B6, 0xA7B6), (0xA7B8, 0xA7B8), (0xA7BA, 0xA7BA), (0xA7BC, 0xA7BC), (0xA7BE, 0xA7BE), (0xA7C0, 0xA7C0),
+        (0xA7C2, 0xA7C2), (0xA7C4, 0xA7C7), (0xA7C9, 0xA7C9), (0xA7CB, 0xA7CC), (0xA7CE, 0xA7CE), (0xA7D0, 0xA7D0),
+        (0xA7D2, 0xA7D2), (0xA7D4, 0xA7D4), (0xA7D6, 0xA7D6), (0xA7D8, 0xA7D8), (0xA7DA, 0xA7DA), (0xA7DC, 0xA7DC),
+        (0xA7F5, 0xA7F5), (0xFF21, 0xFF3A), (0x10400, 0x10427), (0x104B0, 0x104D3), (0x10570, 0x1057A), (0x1057C, 0x1058A),
+        (0x1058C, 0x10592), (0x10594, 0x10595), (0x10C80, 0x10CB2), (0x10D50, 0x10D65), (0x118A0, 0x118BF), (0x16E40, 0x16E5F),
+        (0x16EA0, 0x16EB8), (0x1D400, 0x1D419), (0x1D434, 0x1D44D), (0x1D468, 0x1D481), (0x1D49C, 0x1D49C), (0x1D49E, 0x1D49F),
+        (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B5), (0x1D4D0, 0x1D4E9), (0x1D504, 0x1D505),
+        (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D538, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544),
+        (0x1D546, 0x1D546), (0x1D54A, 0x1D550), (0x1D56C, 0x1D585), (0x1D5A0, 0x1D5B9), (0x1D5D4, 0x1D5ED), (0x1D608, 0x1D621),
+        (0x1D63C, 0x1D655), (0x1D670, 0x1D689), (0x1D6A8, 0x1D6C0), (0x1D6E2, 0x1D6FA), (0x1D71C, 0x1D734), (0x1D756, 0x1D76E),
+        (0x1D790, 0x1D7A8), (0x1D7CA, 0x1D7CA), (0x1E900, 0x1E921), (0x1F130, 0x1F149), (0x1F150, 0x1F169), (0x1F170, 0x1F189),
+    ]),
+    ("Variation_Selector", "VS", &[
+        (0x180B, 0x180D), (0x180F, 0x180F), (0xFE00, 0xFE0F), (0xE0100, 0xE01EF),
+    ]),
+    ("White_Space", "space", &[
+        (0x9, 0xD), (0x20, 0x20), (0x85, 0x85), (0xA0, 0xA0), (0x1680, 0x1680), (0x2000, 0x200A),
+        (0x2028, 0x2029), (0x202F, 0x202F), (0x205F, 0x205F), (0x3000, 0x3000),
+    ]),
+    ("XID_Continue", "XIDC", &[
+        (0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5),
+        (0xB7, 0xB7), (0xBA, 0xBA), (0xC0, 0xD6), (0xD8, 0xF6), (0xF8, 0x2C1), (0x2C6, 0x2D1),
+        (0x2E0, 0x2E4), (0x2EC, 0x2EC), (0x2EE, 0x2EE), (0x300, 0x374), (0x376, 0x377), (0x37B, 0x37D),
+        (0x37F, 0x37F), (0x386, 0x38A), (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x3F5), (0x3F7, 0x481),
+        (0x483, 0x487), (0x48A, 0x52F), (0x531, 0x556), (0x559, 0x559), (0x560, 0x588), (0x591, 0x5BD),
+        (0x5BF, 0x5BF), (0x5C1, 0x5C2), (0x5C4, 0x5C5), (0x5C7, 0x5C7), (0x5D0, 0x5EA), (0x5EF, 0x5F2),
+        (0x610, 0x61A), (0x620, 0x669), (0x66E, 0x6D3), (0x6D5, 0x6DC), (0x6DF, 0x6E8), (0x6EA, 0x6FC),
+        (0x6FF, 0x6FF), (0x710, 0x74A), (0x74D, 0x7B1), (0x7C0, 0x7F5), (0x7FA, 0x7FA), (0x7FD, 0x7FD),
+        (0x800, 0x82D), (0x840, 0x85B), (0x860, 0x86A), (0x870, 0x887), (0x889, 0x88F), (0x897, 0x8E1),
+        (0x8E3, 0x963), (0x966, 0x96F), (0x971, 0x983), (0x985, 0x98C), (0x98F, 0x990), (0x993, 0x9A8),
+        (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9), (0x9BC, 0x9C4), (0x9C7, 0x9C8), (0x9CB, 0x9CE),
+        (0x9D7, 0x9D7), (0x9DC, 0x9DD), (0x9DF, 0x9E3), (0x9E6, 0x9F1), (0x9FC, 0x9FC), (0x9FE, 0x9FE),
+        (0xA01, 0xA03), (0xA05, 0xA0A), (0xA0F, 0xA10), (0xA13, 0xA28), (0xA2A, 0xA30), (0xA32, 0xA33),
+        (0xA35, 0xA36), (0xA38, 0xA39), (0xA3C, 0xA3C), (0xA3E, 0xA42), (0xA47, 0xA48), (0xA4B, 0xA4D),
+        (0xA51, 0xA51), (0xA59, 0xA5C), (0xA5E, 0xA5E), (0xA66, 0xA75), (0xA81, 0xA83), (0xA85, 0xA8D),
+        (0xA8F, 0xA91), (0xA93, 0xAA8), (0xAAA, 0xAB0), (0xAB2, 0xAB3), (0xAB5, 0xAB9), (0xABC, 0xAC5),
+        (0xAC7, 0xAC9), (0xACB, 0xACD), (0xAD0, 0xAD0), (0xAE0, 0xAE3), (0xAE6, 0xAEF), (0xAF9, 0xAFF),
+        (0xB01, 0xB03), (0xB05, 0xB0C), (0xB0F, 0xB10), (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33),
+        (0xB35, 0xB39), (0xB3C, 0xB44), (0xB47, 0xB48), (0xB4B, 0xB4D), (0xB55, 0xB57), (0xB5C, 0xB5D),
+        (0xB5F, 0xB63), (0xB66, 0xB6F), (0xB71, 0xB71), (0xB82, 0xB83), (0xB85, 0xB8A), (0xB8E, 0xB90),
+        (0xB92, 0xB95), (0xB99, 0xB9A), (0xB9C, 0xB9C), (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA),
+        (0xBAE, 0xBB9), (0xBBE, 0xBC2), (0xBC6, 0xBC8), (0xBCA, 0xBCD), (0xBD0, 0xBD0), (0xBD7, 0xBD7),
+        (0xBE6, 0xBEF), (0xC00, 0xC0C), (0xC0E, 0xC10), (0xC12, 0xC28), (0xC2A, 0xC39), (0xC3C, 0xC44),
+        (0xC46, 0xC48), (0xC4A, 0xC4D), (0xC55, 0xC56), (0xC58, 0xC5A), (0xC5C, 0xC5D), (0xC60, 0xC63),
+        (0xC66, 0xC6F), (0xC80, 0xC83), (0xC85, 0xC8C), (0xC8E, 0xC90), (0xC92, 0xCA8), (0xCAA, 0xCB3),
+        (0xCB5, 0xCB9), (0xCBC, 0xCC4), (0xCC6, 0xCC8), (0xCCA, 0xCCD), (0xCD5, 0xCD6), (0xCDC, 0xCDE),
+        (0xCE0, 0xCE3), (0xCE6, 0xCEF), (0xCF1, 0xCF3), (0xD00, 0xD0C), (0xD0E, 0xD10), (0xD12, 0xD44),
+        (0xD46, 0xD48), (0xD4A, 0xD4E), (0xD54, 0xD57), (0xD5F, 0xD63), (0xD66, 0xD6F), (0xD7A, 0xD7F),
+        (0xD81, 0xD83), (0xD85, 0xD96), (0xD9A, 0xDB1), (0xDB3, 0xDBB), (0xDBD, 0xDBD), (0xDC0, 0xDC6),
+        (0xDCA, 0xDCA), (0xDCF, 0xDD4), (0xDD6, 0xDD6), (0xDD8, 0xDDF), (0xDE6, 0xDEF), (0xDF2, 0xDF3),
+        (0xE01, 0xE3A), (0xE40, 0xE4E), (0xE50, 0xE59), (0xE81, 0xE82), (0xE84, 0xE84), (0xE86, 0xE8A),
+        (0xE8C, 0xEA3), (0xEA5, 0xEA5), (0xEA7, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6), (0xEC8, 0xECE),
+        (0xED0, 0xED9), (0xEDC, 0xEDF), (0xF00, 0xF00), (0xF18, 0xF19), (0xF20, 0xF29), (0xF35, 0xF35),
+        (0xF37, 0xF37), (0xF39, 0xF39), (0xF3E, 0xF47), (0xF49, 0xF6C), (0xF71, 0xF84), (0xF86, 0xF97),
+        (0xF99, 0xFBC), (0xFC6, 0xFC6), (0x1000, 0x1049), (0x1050, 0x109D), (0x10A0, 0x10C5), (0x10C7, 0x10C7),
+        (0x10CD, 0x10CD), (0x10D0, 0x10FA), (0x10FC, 0x1248), (0x124A, 0x124D), (0x1250, 0x1256), (0x1258, 0x1258),
+        (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0), (0x12B2, 0x12B5), (0x12B8, 0x12BE),
+        (0x12C0, 0x12C0), (0x12C2, 0x12C5), (0x12C8, 0x12D6), (0x12D8, 0x1310), (0x1312, 0x1315), (0x1318, 0x135A),
+        (0x135D, 0x135F), (0x1369, 0x1371), (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1401, 0x166C),
+        (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16EE, 0x16F8), (0x1700, 0x1715), (0x171F, 0x1734),
+        (0x1740, 0x1753), (0x1760, 0x176C), (0x176E, 0x1770), (0x1772, 0x1773), (0x1780, 0x17D3), (0x17D7, 0x17D7),
+        (0x17DC, 0x17DD), (0x17E0, 0x17E9), (0x180B, 0x180D), (0x180F, 0x1819), (0x1820, 0x1878), (0x1880, 0x18AA),
+        (0x18B0, 0x18F5), (0x1900, 0x191E), (0x1920, 0x192B), (0x1930, 0x193B), (0x1946, 0x196D), (0x1970, 0x1974),
+        (0x1980, 0x19AB), (0x19B0, 0x19C9), (0x19D0, 0x19DA), (0x1A00, 0x1A1B), (0x1A20, 0x1A5E), (0x1A60, 0x1A7C),
+        (0x1A7F, 0x1A89), (0x1A90, 0x1A99), (0x1AA7, 0x1AA7), (0x1AB0, 0x1ABD), (0x1ABF, 0x1ADD), (0x1AE0, 0x1AEB),
+        (0x1B00, 0x1B4C), (0x1B50, 0x1B59), (0x1B6B, 0x1B73), (0x1B80, 0x1BF3), (0x1C00, 0x1C37), (0x1C40, 0x1C49),
+        (0x1C4D, 0x1C7D), (0x1C80, 0x1C8A), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1CD0, 0x1CD2), (0x1CD4, 0x1CFA),
+        (0x1D00, 0x1F15), (0x1F18, 0x1F1D), (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59),
+        (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D), (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE),
+        (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC), (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4),
+        (0x1FF6, 0x1FFC), (0x200C, 0x200D), (0x203F, 0x2040), (0x2054, 0x2054), (0x2071, 0x2071), (0x207F, 0x207F),
+        (0x2090, 0x209C), (0x20D0, 0x20DC), (0x20E1, 0x20E1), (0x20E5, 0x20F0), (0x2102, 0x2102), (0x2107, 0x2107),
+        (0x210A, 0x2113), (0x2115, 0x2115), (0x2118, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128),
+        (0x212A, 0x2139), (0x213C, 0x213F), (0x2145, 0x2149), (0x214E, 0x214E), (0x2160, 0x2188), (0x2C00, 0x2CE4),
+        (0x2CEB, 0x2CF3), (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0x2D30, 0x2D67), (0x2D6F, 0x2D6F),
+        (0x2D7F, 0x2D96), (0x2DA0, 0x2DA6), (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6), (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6),
+        (0x2DC8, 0x2DCE), (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE), (0x2DE0, 0x2DFF), (0x3005, 0x3007), (0x3021, 0x302F),
+        (0x3031, 0x3035), (0x3038, 0x303C), (0x3041, 0x3096), (0x3099, 0x309A), (0x309D, 0x309F), (0x30A1, 0x30FF),
+        (0x3105, 0x312F), (0x3131, 0x318E), (0x31A0, 0x31BF), (0x31F0, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0xA48C),
+        (0xA4D0, 0xA4FD), (0xA500, 0xA60C), (0xA610, 0xA62B), (0xA640, 0xA66F), (0xA674, 0xA67D), (0xA67F, 0xA6F1),
+        (0xA717, 0xA71F), (0xA722, 0xA788), (0xA78B, 0xA7DC), (0xA7F1, 0xA827), (0xA82C, 0xA82C), (0xA840, 0xA873),
+        (0xA880, 0xA8C5), (0xA8D0, 0xA8D9), (0xA8E0, 0xA8F7), (0xA8FB, 0xA8FB), (0xA8FD, 0xA92D), (0xA930, 0xA953),
+        (0xA960, 0xA97C), (0xA980, 0xA9C0), (0xA9CF, 0xA9D9), (0xA9E0, 0xA9FE), (0xAA00, 0xAA36), (0xAA40, 0xAA4D),
+        (0xAA50, 0xAA59), (0xAA60, 0xAA76), (0xAA7A, 0xAAC2), (0xAADB, 0xAADD), (0xAAE0, 0xAAEF), (0xAAF2, 0xAAF6),
+        (0xAB01, 0xAB06), (0xAB09, 0xAB0E), (0xAB11, 0xAB16), (0xAB20, 0xAB26), (0xAB28, 0xAB2E), (0xAB30, 0xAB5A),
+        (0xAB5C, 0xAB69), (0xAB70, 0xABEA), (0xABEC, 0xABED), (0xABF0, 0xABF9), (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6),
+        (0xD7CB, 0xD7FB), (0xF900, 0xFA6D), (0xFA70, 0xFAD9), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFB1D, 0xFB28),
+        (0xFB2A, 0xFB36), (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41), (0xFB43, 0xFB44), (0xFB46, 0xFBB1),
+        (0xFBD3, 0xFC5D), (0xFC64, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7), (0xFDF0, 0xFDF9), (0xFE00, 0xFE0F),
+        (0xFE20, 0xFE2F), (0xFE33, 0xFE34), (0xFE4D, 0xFE4F), (0xFE71, 0xFE71), (0xFE73, 0xFE73), (0xFE77, 0xFE77),
+        (0xFE79, 0xFE79), (0xFE7B, 0xFE7B), (0xFE7D, 0xFE7D), (0xFE7F, 0xFEFC), (0xFF10, 0xFF19), (0xFF21, 0xFF3A),
+        (0xFF3F, 0xFF3F), (0xFF41, 0xFF5A), (0xFF65, 0xFFBE), (0xFFC2, 0xFFC7), (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7),
+        (0xFFDA, 0xFFDC), (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A), (0x1003C, 0x1003D), (0x1003F, 0x1004D),
+        (0x10050, 0x1005D), (0x10080, 0x100FA), (0x10140, 0x10174), (0x101FD, 0x101FD), (0x10280, 0x1029C), (0x102A0, 0x102D0),
+        (0x102E0, 0x102E0), (0x10300, 0x1031F), (0x1032D, 0x1034A), (0x10350, 0x1037A), (0x10380, 0x1039D), (0x103A0, 0x103C3),
+        (0x103C8, 0x103CF), (0x103D1, 0x103D5), (0x10400, 0x1049D), (0x104A0, 0x104A9), (0x104B0, 0x104D3), (0x104D8, 0x104FB),
+        (0x10500, 0x10527), (0x10530, 0x10563), (0x10570, 0x1057A), (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595),
+        (0x10597, 0x105A1), (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x105C0, 0x105F3), (0x10600, 0x10736),
+        (0x10740, 0x10755), (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10800, 0x10805),
+        (0x10808, 0x10808), (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C), (0x1083F, 0x10855), (0x10860, 0x10876),
+        (0x10880, 0x1089E), (0x108E0, 0x108F2), (0x108F4, 0x108F5), (0x10900, 0x10915), (0x10920, 0x10939), (0x10940, 0x10959),
+        (0x10980, 0x109B7), (0x109BE, 0x109BF), (0x10A00, 0x10A03), (0x10A05, 0x10A06), (0x10A0C, 0x10A13), (0x10A15, 0x10A17),
+        (0x10A19, 0x10A35), (0x10A38, 0x10A3A), (0x10A3F, 0x10A3F), (0x10A60, 0x10A7C), (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7),
+        (0x10AC9, 0x10AE6), (0x10B00, 0x10B35), (0x10B40, 0x10B55), (0x10B60, 0x10B72), (0x10B80, 0x10B91), (0x10C00, 0x10C48),
+        (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2), (0x10D00, 0x10D27), (0x10D30, 0x10D39), (0x10D40, 0x10D65), (0x10D69, 0x10D6D),
+        (0x10D6F, 0x10D85), (0x10E80, 0x10EA9), (0x10EAB, 0x10EAC), (0x10EB0, 0x10EB1), (0x10EC2, 0x10EC7), (0x10EFA, 0x10F1C),
+        (0x10F27, 0x10F27), (0x10F30, 0x10F50), (0x10F70, 0x10F85), (0x10FB0, 0x10FC4), (0x10FE0, 0x10FF6), (0x11000, 0x11046),
+        (0x11066, 0x11075), (0x1107F, 0x110BA), (0x110C2, 0x110C2), (0x110D0, 0x110E8), (0x110F0, 0x110F9), (0x11100, 0x11134),
+        (0x11136, 0x1113F), (0x11144, 0x11147), (0x11150, 0x11173), (0x11176, 0x11176), (0x11180, 0x111C4), (0x111C9, 0x111CC),
+        (0x111CE, 0x111DA), (0x111DC, 0x111DC), (0x11200, 0x11211), (0x11213, 0x11237), (0x1123E, 0x11241), (0x11280, 0x11286),
+        (0x11288, 0x11288), (0x1128A, 0x1128D), (0x1128F, 0x1129D), (0x1129F, 0x112A8), (0x112B0, 0x112EA), (0x112F0, 0x112F9),
+        (0x11300, 0x11303), (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328), (0x1132A, 0x11330), (0x11332, 0x11333),
+        (0x11335, 0x11339), (0x1133B, 0x11344), (0x11347, 0x11348), (0x1134B, 0x1134D), (0x11350, 0x11350), (0x11357, 0x11357),
+        (0x1135D, 0x11363), (0x11366, 0x1136C), (0x11370, 0x11374), (0x11380, 0x11389), (0x1138B, 0x1138B), (0x1138E, 0x1138E),
+        (0x11390, 0x113B5), (0x113B7, 0x113C0), (0x113C2, 0x113C2), (0x113C5, 0x113C5), (0x113C7, 0x113CA), (0x113CC, 0x113D3),
+        (0x113E1, 0x113E2), (0x11400, 0x1144A), (0x11450, 0x11459), (0x1145E, 0x11461), (0x11480, 0x114C5), (0x114C7, 0x114C7),
+        (0x114D0, 0x114D9), (0x11580, 0x115B5), (0x115B8, 0x115C0), (0x115D8, 0x115DD), (0x11600, 0x11640), (0x11644, 0x11644),
+        (0x11650, 0x11659), (0x11680, 0x116B8), (0x116C0, 0x116C9), (0x116D0, 0x116E3), (0x11700, 0x1171A), (0x1171D, 0x1172B),
+        (0x11730, 0x11739), (0x11740, 0x11746), (0x11800, 0x1183A), (0x118A0, 0x118E9), (0x118FF, 0x11906), (0x11909, 0x11909),
+        (0x1190C, 0x11913), (0x11915, 0x11916), (0x11918, 0x11935), (0x11937, 0x11938), (0x1193B, 0x11943), (0x11950, 0x11959),
+        (0x119A0, 0x119A7), (0x119AA, 0x119D7), (0x119DA, 0x119E1), (0x119E3, 0x119E4), (0x11A00, 0x11A3E), (0x11A47, 0x11A47),
+        (0x11A50, 0x11A99), (0x11A9D, 0x11A9D), (0x11AB0, 0x11AF8), (0x11B60, 0x11B67), (0x11BC0, 0x11BE0), (0x11BF0, 0x11BF9),
+        (0x11C00, 0x11C08), (0x11C0A, 0x11C36), (0x11C38, 0x11C40), (0x11C50, 0x11C59), (0x11C72, 0x11C8F), (0x11C92, 0x11CA7),
+        (0x11CA9, 0x11CB6), (0x11D00, 0x11D06), (0x11D08, 0x11D09), (0x11D0B, 0x11D36), (0x11D3A, 0x11D3A), (0x11D3C, 0x11D3D),
+        (0x11D3F, 0x11D47), (0x11D50, 0x11D59), (0x11D60, 0x11D65), (0x11D67, 0x11D68), (0x11D6A, 0x11D8E), (0x11D90, 0x11D91),
+        (0x11D93, 0x11D98), (0x11DA0, 0x11DA9), (0x11DB0, 0x11DDB), (0x11DE0, 0x11DE9), (0x11EE0, 0x11EF6), (0x11F00, 0x11F10),
+        (0x11F12, 0x11F3A), (0x11F3E, 0x11F42), (0x11F50, 0x11F5A), (0x11FB0, 0x11FB0), (0x12000, 0x12399), (0x12400, 0x1246E),
+        (0x12480, 0x12543), (0x12F90, 0x12FF0), (0x13000, 0x1342F), (0x13440, 0x13455), (0x13460, 0x143FA), (0x14400, 0x14646),
+        (0x16100, 0x16139), (0x16800, 0x16A38), (0x16A40, 0x16A5E), (0x16A60, 0x16A69), (0x16A70, 0x16ABE), (0x16AC0, 0x16AC9),
+        (0x16AD0, 0x16AED), (0x16AF0, 0x16AF4), (0x16B00, 0x16B36), (0x16B40, 0x16B43), (0x16B50, 0x16B59), (0x16B63, 0x16B77),
+        (0x16B7D, 0x16B8F), (0x16D40, 0x16D6C), (0x16D70, 0x16D79), (0x16E40, 0x16E7F), (0x16EA0, 0x16EB8), (0x16EBB, 0x16ED3),
+        (0x16F00, 0x16F4A), (0x16F4F, 0x16F87), (0x16F8F, 0x16F9F), (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE4), (0x16FF0, 0x16FF6),
+        (0x17000, 0x18CD5), (0x18CFF, 0x18D1E), (0x18D80, 0x18DF2), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE),
+        (0x1B000, 0x1B122), (0x1B132, 0x1B132), (0x1B150, 0x1B152), (0x1B155, 0x1B155), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB),
+        (0x1BC00, 0x1BC6A), (0x1BC70, 0x1BC7C), (0x1BC80, 0x1BC88), (0x1BC90, 0x1BC99), (0x1BC9D, 0x1BC9E), (0x1CCF0, 0x1CCF9),
+        (0x1CF00, 0x1CF2D), (0x1CF30, 0x1CF46), (0x1D165, 0x1D169), (0x1D16D, 0x1D172), (0x1D17B, 0x1D182), (0x1D185, 0x1D18B),
+        (0x1D1AA, 0x1D1AD), (0x1D242, 0x1D244), (0x1D400, 0x1D454), (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2),
+        (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505),
+        (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544),
+        (0x1D546, 0x1D546), (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D6C0), (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6FA),
+        (0x1D6FC, 0x1D714), (0x1D716, 0x1D734), (0x1D736, 0x1D74E), (0x1D750, 0x1D76E), (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8),
+        (0x1D7AA, 0x1D7C2), (0x1D7C4, 0x1D7CB), (0x1D7CE, 0x1D7FF), (0x1DA00, 0x1DA36), (0x1DA3B, 0x1DA6C), (0x1DA75, 0x1DA75),
+        (0x1DA84, 0x1DA84), (0x1DA9B, 0x1DA9F), (0x1DAA1, 0x1DAAF), (0x1DF00, 0x1DF1E), (0x1DF25, 0x1DF2A), (0x1E000, 0x1E006),
+        (0x1E008, 0x1E018), (0x1E01B, 0x1E021), (0x1E023, 0x1E024), (0x1E026, 0x1E02A), (0x1E030, 0x1E06D), (0x1E08F, 0x1E08F),
+        (0x1E100, 0x1E12C), (0x1E130, 0x1E13D), (0x1E140, 0x1E149), (0x1E14E, 0x1E14E), (0x1E290, 0x1E2AE), (0x1E2C0, 0x1E2F9),
+        (0x1E4D0, 0x1E4F9), (0x1E5D0, 0x1E5FA), (0x1E6C0, 0x1E6DE), (0x1E6E0, 0x1E6F5), (0x1E6FE, 0x1E6FF), (0x1E7E0, 0x1E7E6),
+        (0x1E7E8, 0x1E7EB), (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4), (0x1E8D0, 0x1E8D6), (0x1E900, 0x1E94B),
+        (0x1E950, 0x1E959), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22), (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27),
+        (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47),
+        (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52), (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57),
+        (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64),
+        (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C), (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89),
+        (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB), (0x1FBF0, 0x1FBF9), (0x20000, 0x2A6DF),
+        (0x2A700, 0x2B81D), (0x2B820, 0x2CEAD), (0x2CEB0, 0x2EBE0), (0x2EBF0, 0x2EE5D), (0x2F800, 0x2FA1D), (0x30000, 0x3134A),
+        (0x31350, 0x33479), (0xE0100, 0xE01EF),
+    ]),
+    ("XID_Start", "XIDS", &[
+        (0x41, 0x5A), (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5), (0xBA, 0xBA), (0xC0, 0xD6),
+        (0xD8, 0xF6), (0xF8, 0x2C1), (0x2C6, 0x2D1), (0x2E0, 0x2E4), (0x2EC, 0x2EC), (0x2EE, 0x2EE),
+        (0x370, 0x374), (0x376, 0x377), (0x37B, 0x37D), (0x37F, 0x37F), (0x386, 0x386), (0x388, 0x38A),
+        (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x3F5), (0x3F7, 0x481), (0x48A, 0x52F), (0x531, 0x556),
+        (0x559, 0x559), (0x560, 0x588), (0x5D0, 0x5EA), (0x5EF, 0x5F2), (0x620, 0x64A), (0x66E, 0x66F),
+        (0x671, 0x6D3), (0x6D5, 0x6D5), (0x6E5, 0x6E6), (0x6EE, 0x6EF), (0x6FA, 0x6FC), (0x6FF, 0x6FF),
+        (0x710, 0x710), (0x712, 0x72F), (0x74D, 0x7A5), (0x7B1, 0x7B1), (0x7CA, 0x7EA), (0x7F4, 0x7F5),
+        (0x7FA, 0x7FA), (0x800, 0x815), (0x81A, 0x81A), (0x824, 0x824), (0x828, 0x828), (0x840, 0x858),
+        (0x860, 0x86A), (0x870, 0x887), (0x889, 0x88F), (0x8A0, 0x8C9), (0x904, 0x939), (0x93D, 0x93D),
+        (0x950, 0x950), (0x958, 0x961), (0x971, 0x980), (0x985, 0x98C), (0x98F, 0x990), (0x993, 0x9A8),
+        (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9), (0x9BD, 0x9BD), (0x9CE, 0x9CE), (0x9DC, 0x9DD),
+        (0x9DF, 0x9E1), (0x9F0, 0x9F1), (0x9FC, 0x9FC), (0xA05, 0xA0A), (0xA0F, 0xA10), (0xA13, 0xA28),
+        (0xA2A, 0xA30), (0xA32, 0xA33), (0xA35, 0xA36), (0xA38, 0xA39), (0xA59, 0xA5C), (0xA5E, 0xA5E),
+        (0xA72, 0xA74), (0xA85, 0xA8D), (0xA8F, 0xA91), (0xA93, 0xAA8), (0xAAA, 0xAB0), (0xAB2, 0xAB3),
+        (0xAB5, 0xAB9), (0xABD, 0xABD), (0xAD0, 0xAD0), (0xAE0, 0xAE1), (0xAF9, 0xAF9), (0xB05, 0xB0C),
+        (0xB0F, 0xB10), (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33), (0xB35, 0xB39), (0xB3D, 0xB3D),
+        (0xB5C, 0xB5D), (0xB5F, 0xB61), (0xB71, 0xB71), (0xB83, 0xB83), (0xB85, 0xB8A), (0xB8E, 0xB90),
+        (0xB92, 0xB95), (0xB99, 0xB9A), (0xB9C, 0xB9C), (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA),
+        (0xBAE, 0xBB9), (0xBD0, 0xBD0), (0xC05, 0xC0C), (0xC0E, 0xC10), (0xC12, 0xC28), (0xC2A, 0xC39),
+        (0xC3D, 0xC3D), (0xC58, 0xC5A), (0xC5C, 0xC5D), (0xC60, 0xC61), (0xC80, 0xC80), (0xC85, 0xC8C),
+        (0xC8E, 0xC90), (0xC92, 0xCA8), (0xCAA, 0xCB3), (0xCB5, 0xCB9), (0xCBD, 0xCBD), (0xCDC, 0xCDE),
+        (0xCE0, 0xCE1), (0xCF1, 0xCF2), (0xD04, 0xD0C), (0xD0E, 0xD10), (0xD12, 0xD3A), (0xD3D, 0xD3D),
+        (0xD4E, 0xD4E), (0xD54, 0xD56), (0xD5F, 0xD61), (0xD7A, 0xD7F), (0xD85, 0xD96), (0xD9A, 0xDB1),
+        (0xDB3, 0xDBB), (0xDBD, 0xDBD), (0xDC0, 0xDC6), (0xE01, 0xE30), (0xE32, 0xE32), (0xE40, 0xE46),
+        (0xE81, 0xE82), (0xE84, 0xE84), (0xE86, 0xE8A), (0xE8C, 0xEA3), (0xEA5, 0xEA5), (0xEA7, 0xEB0),
+        (0xEB2, 0xEB2), (0xEBD, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6), (0xEDC, 0xEDF), (0xF00, 0xF00),
+        (0xF40, 0xF47), (0xF49, 0xF6C), (0xF88, 0xF8C), (0x1000, 0x102A), (0x103F, 0x103F), (0x1050, 0x1055),
+        (0x105A, 0x105D), (0x1061, 0x1061), (0x1065, 0x1066), (0x106E, 0x1070), (0x1075, 0x1081), (0x108E, 0x108E),
+        (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD), (0x10D0, 0x10FA), (0x10FC, 0x1248), (0x124A, 0x124D),
+        (0x1250, 0x1256), (0x1258, 0x1258), (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0),
+        (0x12B2, 0x12B5), (0x12B8, 0x12BE), (0x12C0, 0x12C0), (0x12C2, 0x12C5), (0x12C8, 0x12D6), (0x12D8, 0x1310),
+        (0x1312, 0x1315), (0x1318, 0x135A), (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1401, 0x166C),
+        (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16EE, 0x16F8), (0x1700, 0x1711), (0x171F, 0x1731),
+        (0x1740, 0x1751), (0x1760, 0x176C), (0x176E, 0x1770), (0x1780, 0x17B3), (0x17D7, 0x17D7), (0x17DC, 0x17DC),
+        (0x1820, 0x1878), (0x1880, 0x18A8), (0x18AA, 0x18AA), (0x18B0, 0x18F5), (0x1900, 0x191E), (0x1950, 0x196D),
+        (0x1970, 0x1974), (0x1980, 0x19AB), (0x19B0, 0x19C9), (0x1A00, 0x1A16), (0x1A20, 0x1A54), (0x1AA7, 0x1AA7),
+        (0x1B05, 0x1B33), (0x1B45, 0x1B4C), (0x1B83, 0x1BA0), (0x1BAE, 0x1BAF), (0x1BBA, 0x1BE5), (0x1C00, 0x1C23),
+        (0x1C4D, 0x1C4F), (0x1C5A, 0x1C7D), (0x1C80, 0x1C8A), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1CE9, 0x1CEC),
+        (0x1CEE, 0x1CF3), (0x1CF5, 0x1CF6), (0x1CFA, 0x1CFA), (0x1D00, 0x1DBF), (0x1E00, 0x1F15), (0x1F18, 0x1F1D),
+        (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D),
+        (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC),
+        (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFC), (0x2071, 0x2071),
+        (0x207F, 0x207F), (0x2090, 0x209C), (0x2102, 0x2102), (0x2107, 0x2107), (0x210A, 0x2113), (0x2115, 0x2115),
+        (0x2118, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128), (0x212A, 0x2139), (0x213C, 0x213F),
+        (0x2145, 0x2149), (0x214E, 0x214E), (0x2160, 0x2188), (0x2C00, 0x2CE4), (0x2CEB, 0x2CEE), (0x2CF2, 0x2CF3),
+        (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0x2D30, 0x2D67), (0x2D6F, 0x2D6F), (0x2D80, 0x2D96),
+        (0x2DA0, 0x2DA6), (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6), (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6), (0x2DC8, 0x2DCE),
+        (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE), (0x3005, 0x3007), (0x3021, 0x3029), (0x3031, 0x3035), (0x3038, 0x303C),
+        (0x3041, 0x3096), (0x309D, 0x309F), (0x30A1, 0x30FA), (0x30FC, 0x30FF), (0x3105, 0x312F), (0x3131, 0x318E),
+        (0x31A0, 0x31BF), (0x31F0, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0xA48C), (0xA4D0, 0xA4FD), (0xA500, 0xA60C),
+        (0xA610, 0xA61F), (0xA62A, 0xA62B), (0xA640, 0xA66E), (0xA67F, 0xA69D), (0xA6A0, 0xA6EF), (0xA717, 0xA71F),
+        (0xA722, 0xA788), (0xA78B, 0xA7DC), (0xA7F1, 0xA801), (0xA803, 0xA805), (0xA807, 0xA80A), (0xA80C, 0xA822),
+        (0xA840, 0xA873), (0xA882, 0xA8B3), (0xA8F2, 0xA8F7), (0xA8FB, 0xA8FB), (0xA8FD, 0xA8FE), (0xA90A, 0xA925),
+        (0xA930, 0xA946), (0xA960, 0xA97C), (0xA984, 0xA9B2), (0xA9CF, 0xA9CF), (0xA9E0, 0xA9E4), (0xA9E6, 0xA9EF),
+        (0xA9FA, 0xA9FE), (0xAA00, 0xAA28), (0xAA40, 0xAA42), (0xAA44, 0xAA4B), (0xAA60, 0xAA76), (0xAA7A, 0xAA7A),
+        (0xAA7E, 0xAAAF), (0xAAB1, 0xAAB1), (0xAAB5, 0xAAB6), (0xAAB9, 0xAABD), (0xAAC0, 0xAAC0), (0xAAC2, 0xAAC2),
+        (0xAADB, 0xAADD), (0xAAE0, 0xAAEA), (0xAAF2, 0xAAF4), (0xAB01, 0xAB06), (0xAB09, 0xAB0E), (0xAB11, 0xAB16),
+        (0xAB20, 0xAB26), (0xAB28, 0xAB2E), (0xAB30, 0xAB5A), (0xAB5C, 0xAB69), (0xAB70, 0xABE2), (0xAC00, 0xD7A3),
+        (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB), (0xF900, 0xFA6D), (0xFA70, 0xFAD9), (0xFB00, 0xFB06), (0xFB13, 0xFB17),
+        (0xFB1D, 0xFB1D), (0xFB1F, 0xFB28), (0xFB2A, 0xFB36), (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41),
+        (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFC5D), (0xFC64, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7),
+        (0xFDF0, 0xFDF9), (0xFE71, 0xFE71), (0xFE73, 0xFE73), (0xFE77, 0xFE77), (0xFE79, 0xFE79), (0xFE7B, 0xFE7B),
+        (0xFE7D, 0xFE7D), (0xFE7F, 0xFEFC), (0xFF21, 0xFF3A), (0xFF41, 0xFF5A), (0xFF66, 0xFF9D), (0xFFA0, 0xFFBE),
+        (0xFFC2, 0xFFC7), (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC), (0x10000, 0x1000B), (0x1000D, 0x10026),
+        (0x10028, 0x1003A), (0x1003C, 0x1003D), (0x1003F, 0x1004D), (0x10050, 0x1005D), (0x10080, 0x100FA), (0x10140, 0x10174),
+        (0x10280, 0x1029C), (0x102A0, 0x102D0), (0x10300, 0x1031F), (0x1032D, 0x1034A), (0x10350, 0x10375), (0x10380, 0x1039D),
+        (0x103A0, 0x103C3), (0x103C8, 0x103CF), (0x103D1, 0x103D5), (0x10400, 0x1049D), (0x104B0, 0x104D3), (0x104D8, 0x104FB),
+        (0x10500, 0x10527), (0x10530, 0x10563), (0x10570, 0x1057A), (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595),
+        (0x10597, 0x105A1), (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x105C0, 0x105F3), (0x10600, 0x10736),
+        (0x10740, 0x10755), (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10800, 0x10805),
+        (0x10808, 0x10808), (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C), (0x1083F, 0x10855), (0x10860, 0x10876),
+        (0x10880, 0x1089E), (0x108E0, 0x108F2), (0x108F4, 0x108F5), (0x10900, 0x10915), (0x10920, 0x10939), (0x10940, 0x10959),
+        (0x10980, 0x109B7), (0x109BE, 0x109BF), (0x10A00, 0x10A00), (0x10A10, 0x10A13), (0x10A15, 0x10A17), (0x10A19, 0x10A35),
+        (0x10A60, 0x10A7C), (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7), (0x10AC9, 0x10AE4), (0x10B00, 0x10B35), (0x10B40, 0x10B55),
+        (0x10B60, 0x10B72), (0x10B80, 0x10B91), (0x10C00, 0x10C48), (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2), (0x10D00, 0x10D23),
+        (0x10D4A, 0x10D65), (0x10D6F, 0x10D85), (0x10E80, 0x10EA9), (0x10EB0, 0x10EB1), (0x10EC2, 0x10EC7), (0x10F00, 0x10F1C),
+        (0x10F27, 0x10F27), (0x10F30, 0x10F45), (0x10F70, 0x10F81), (0x10FB0, 0x10FC4), (0x10FE0, 0x10FF6), (0x11003, 0x11037),
+        (0x11071, 0x11072), (0x11075, 0x11075), (0x11083, 0x110AF), (0x110D0, 0x110E8), (0x11103, 0x11126), (0x11144, 0x11144),
+        (0x11147, 0x11147), (0x11150, 0x11172), (0x11176, 0x11176), (0x11183, 0x111B2), (0x111C1, 0x111C4), (0x111DA, 0x111DA),
+        (0x111DC, 0x111DC), (0x11200, 0x11211), (0x11213, 0x1122B), (0x1123F, 0x11240), (0x11280, 0x11286), (0x11288, 0x11288),
+        (0x1128A, 0x1128D), (0x1128F, 0x1129D), (0x1129F, 0x112A8), (0x112B0, 0x112DE), (0x11305, 0x1130C), (0x1130F, 0x11310),
+        (0x11313, 0x11328), (0x1132A, 0x11330), (0x11332, 0x11333), (0x11335, 0x11339), (0x1133D, 0x1133D), (0x11350, 0x11350),
+        (0x1135D, 0x11361), (0x11380, 0x11389), (0x1138B, 0x1138B), (0x1138E, 0x1138E), (0x11390, 0x113B5), (0x113B7, 0x113B7),
+        (0x113D1, 0x113D1), (0x113D3, 0x113D3), (0x11400, 0x11434), (0x11447, 0x1144A), (0x1145F, 0x11461), (0x11480, 0x114AF),
+        (0x114C4, 0x114C5), (0x114C7, 0x114C7), (0x11580, 0x115AE), (0x115D8, 0x115DB), (0x11600, 0x1162F), (0x11644, 0x11644),
+        (0x11680, 0x116AA), (0x116B8, 0x116B8), (0x11700, 0x1171A), (0x11740, 0x11746), (0x11800, 0x1182B), (0x118A0, 0x118DF),
+        (0x118FF, 0x11906), (0x11909, 0x11909), (0x1190C, 0x11913), (0x11915, 0x11916), (0x11918, 0x1192F), (0x1193F, 0x1193F),
+        (0x11941, 0x11941), (0x119A0, 0x119A7), (0x119AA, 0x119D0), (0x119E1, 0x119E1), (0x119E3, 0x119E3), (0x11A00, 0x11A00),
+        (0x11A0B, 0x11A32), (0x11A3A, 0x11A3A), (0x11A50, 0x11A50), (0x11A5C, 0x11A89), (0x11A9D, 0x11A9D), (0x11AB0, 0x11AF8),
+        (0x11BC0, 0x11BE0), (0x11C00, 0x11C08), (0x11C0A, 0x11C2E), (0x11C40, 0x11C40), (0x11C72, 0x11C8F), (0x11D00, 0x11D06),
+        (0x11D08, 0x11D09), (0x11D0B, 0x11D30), (0x11D46, 0x11D46), (0x11D60, 0x11D65), (0x11D67, 0x11D68), (0x11D6A, 0x11D89),
+        (0x11D98, 0x11D98), (0x11DB0, 0x11DDB), (0x11EE0, 0x11EF2), (0x11F02, 0x11F02), (0x11F04, 0x11F10), (0x11F12, 0x11F33),
+        (0x11FB0, 0x11FB0), (0x12000, 0x12399), (0x12400, 0x1246E), (0x12480, 0x12543), (0x12F90, 0x12FF0), (0x13000, 0x1342F),
+        (0x13441, 0x13446), (0x13460, 0x143FA), (0x14400, 0x14646), (0x16100, 0x1611D), (0x16800, 0x16A38), (0x16A40, 0x16A5E),
+        (0x16A70, 0x16ABE), (0x16AD0, 0x16AED), (0x16B00, 0x16B2F), (0x16B40, 0x16B43), (0x16B63, 0x16B77), (0x16B7D, 0x16B8F),
+        (0x16D40, 0x16D6C), (0x16E40, 0x16E7F), (0x16EA0, 0x16EB8), (0x16EBB, 0x16ED3), (0x16F00, 0x16F4A), (0x16F50, 0x16F50),
+        (0x16F93, 0x16F9F), (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE3), (0x16FF2, 0x16FF6), (0x17000, 0x18CD5), (0x18CFF, 0x18D1E),
+        (0x18D80, 0x18DF2), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122), (0x1B132, 0x1B132),
+        (0x1B150, 0x1B152), (0x1B155, 0x1B155), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A), (0x1BC70, 0x1BC7C),
+        (0x1BC80, 0x1BC88), (0x1BC90, 0x1BC99), (0x1D400, 0x1D454), (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2),
+        (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505),
+        (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544),
+        (0x1D546, 0x1D546), (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D6C0), (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6FA),
+        (0x1D6FC, 0x1D714), (0x1D716, 0x1D734), (0x1D736, 0x1D74E), (0x1D750, 0x1D76E), (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8),
+        (0x1D7AA, 0x1D7C2), (0x1D7C4, 0x1D7CB), (0x1DF00, 0x1DF1E), (0x1DF25, 0x1DF2A), (0x1E030, 0x1E06D), (0x1E100, 0x1E12C),
+        (0x1E137, 0x1E13D), (0x1E14E, 0x1E14E), (0x1E290, 0x1E2AD), (0x1E2C0, 0x1E2EB), (0x1E4D0, 0x1E4EB), (0x1E5D0, 0x1E5ED),
+        (0x1E5F0, 0x1E5F0), (0x1E6C0, 0x1E6DE), (0x1E6E0, 0x1E6E2), (0x1E6E4, 0x1E6E5), (0x1E6E7, 0x1E6ED), (0x1E6F0, 0x1E6F4),
+        (0x1E6FE, 0x1E6FF), (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB), (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4),
+        (0x1E900, 0x1E943), (0x1E94B, 0x1E94B), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22), (0x1EE24, 0x1EE24),
+        (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42),
+        (0x1EE47, 0x1EE47), (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52), (0x1EE54, 0x1EE54),
+        (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62),
+        (0x1EE64, 0x1EE64), (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C), (0x1EE7E, 0x1EE7E),
+        (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB), (0x20000, 0x2A6DF),
+        (0x2A700, 0x2B81D), (0x2B820, 0x2CEAD), (0x2CEB0, 0x2EBE0), (0x2EBF0, 0x2EE5D), (0x2F800, 0x2FA1D), (0x30000, 0x3134A),
+        (0x31350, 0x33479),
+    ]),
+];
+
+/// The General_Category values, as `(aliases, mask)`, where the bits of the mask are the indices in
+/// GENERAL_CATEGORIES of the categories the value groups.
+pub(super) static GENERAL_CATEGORY_VALUES: &[(&[&str], u32)] = &[
+    (&["C", "Other"], 0x0000001F),
+    (&["Cc", "Control", "cntrl"], 0x00000001),
+    (&["Cf", "Format"], 0x00000002),
+    (&["Cn", "Unassigned"], 0x00000004),
+    (&["Co", "Private_Use"], 0x00000008),
+    (&["Cs", "Surrogate"], 0x00000010),
+    (&["L", "Letter"], 0x000003E0),
+    (&["LC", "Cased_Letter"], 0x00000320),
+    (&["Ll", "Lowercase_Letter"], 0x00000020),
+    (&["Lm", "Modifier_Letter"], 0x00000040),
+    (&["Lo", "Other_Letter"], 0x00000080),
+    (&["Lt", "Titlecase_Letter"], 0x00000100),
+    (&["Lu", "Uppercase_Letter"], 0x00000200),
+    (&["M", "Mark", "Combining_Mark"], 0x00001C00),
+    (&["Mc", "Spacing_Mark"], 0x00000400),
+    (&["Me", "Enclosing_Mark"], 0x00000800),
+    (&["Mn", "Nonspacing_Mark"], 0x00001000),
+    (&["N", "Number"], 0x0000E000),
+    (&["Nd", "Decimal_Number", "digit"], 0x00002000),
+    (&["Nl", "Letter_Number"], 0x00004000),
+    (&["No", "Other_Number"], 0x00008000),
+    (&["P", "Punctuation", "punct"], 0x007F0000),
+    (&["Pc", "Connector_Punctuation"], 0x00010000),
+    (&["Pd", "Dash_Punctuation"], 0x00020000),
+    (&["Pe", "Close_Punctuation"], 0x00040000),
+    (&["Pf", "Final_Punctuation"], 0x00080000),
+    (&["Pi", "Initial_Punctuation"], 0x00100000),
+    (&["Po", "Other_Punctuation"], 0x00200000),
+    (&["Ps", "Open_Punctuation"], 0x00400000),
+    (&["S", "Symbol"], 0x07800000),
+    (&["Sc", "Currency_Symbol"], 0x00800000),
+    (&["Sk", "Modifier_Symbol"], 0x01000000),
+    (&["Sm", "Math_Symbol"], 0x02000000),
+    (&["So", "Other_Symbol"], 0x04000000),
+    (&["Z", "Separator"], 0x38000000),
+    (&["Zl", "Line_Separator"], 0x08000000),
+    (&["Zp", "Paragraph_Separator"], 0x10000000),
+    (&["Zs", "Space_Separator"], 0x20000000),
+];
+
+/// The General_Category of every code point, as `(first, last, index)` where index is a bit of the value masks.
+pub(super) static GENERAL_CATEGORIES: &[(u32, u32, u8)] = &[
+    (0x0, 0x1F, 0), (0x20, 0x20, 29), (0x21, 0x23, 21), (0x24, 0x24, 23),
+    (0x25, 0x27, 21), (0x28, 0x28, 22), (0x29, 0x29, 18), (0x2A, 0x2A, 21),
+    (0x2B, 0x2B, 25), (0x2C, 0x2C, 21), (0x2D, 0x2D, 17), (0x2E, 0x2F, 21),
+    (0x30, 0x39, 13), (0x3A, 0x3B, 21), (0x3C, 0x3E, 25), (0x3F, 0x40, 21),
+    (0x41, 0x5A, 9), (0x5B, 0x5B, 22), (0x5C, 0x5C, 21), (0x5D, 0x5D, 18),
+    (0x5E, 0x5E, 24), (0x5F, 0x5F, 16), (0x60, 0x60, 24), (0x61, 0x7A, 5),
+    (0x7B, 0x7B, 22), (0x7C, 0x7C, 25), (0x7D, 0x7D, 18), (0x7E, 0x7E, 25),
+    (0x7F, 0x9F, 0), (0xA0, 0xA0, 29), (0xA1, 0xA1, 21), (0xA2, 0xA5, 23),
+    (0xA6, 0xA6, 26), (0xA7, 0xA7, 21), (0xA8, 0xA8, 24), (0xA9, 0xA9, 26),
+    (0xAA, 0xAA, 7), (0xAB, 0xAB, 20), (0xAC, 0xAC, 25), (0xAD, 0xAD, 1),
+    (0xAE, 0xAE, 26), (0xAF, 0xAF, 24), (0xB0, 0xB0, 26), (0xB1, 0xB1, 25),
+    (0xB2, 0xB3, 15), (0xB4, 0xB4, 24), (0xB5, 0xB5, 5), (0xB6, 0xB7, 21),
+    (0xB8, 0xB8, 24), (0xB9, 0xB9, 15), (0xBA, 0xBA, 7), (0xBB, 0xBB, 19),
+    (0xBC, 0xBE, 15), (0xBF, 0xBF, 21), (0xC0, 0xD6, 9), (0xD7, 0xD7, 25),
+    (0xD8, 0xDE, 9), (0xDF, 0xF6, 5), (0xF7, 0xF7, 25), (0xF8, 0xFF, 5),
+    (0x100, 0x100, 9), (0x101, 0x101, 5), (0x102, 0x102, 9), (0x103, 0x103, 5),
+    (0x104, 0x104, 9), (0x105, 0x105, 5), (0x106, 0x106, 9), (0x107, 0x107, 5),
+    (0x108, 0x108, 9), (0x109, 0x109, 5), (0x10A, 0x10A, 9), (0x10B, 0x10B, 5),
+    (0x10C, 0x10C, 9), (0x10D, 0x10D, 5), (0x10E, 0x10E, 9), (0x10F, 0x10F, 5),
+    (0x110, 0x110, 9), (0x111, 0x111, 5), (0x112, 0x112, 9), (0x113, 0x113, 5),
+    (0x114, 0x114, 9), (0x115, 0x115, 5), (0x116, 0x116, 9), (0x117, 0x117, 5),
+    (0x118, 0x118, 9), (0x119, 0x119, 5), (0x11A, 0x11A, 9), (0x11B, 0x11B, 5),
+    (0x11C, 0x11C, 9), (0x11D, 0x11D, 5), (0x11E, 0x11E, 9), (0x11F, 0x11F, 5),
+    (0x120, 0x120, 9), (0x121, 0x121, 5), (0x122, 0x122, 9), (0x123, 0x123, 5),
+    (0x124, 0x124, 9), (0x125, 0x125, 5), (0x126, 0x126, 9), (0x127, 0x127, 5),
+    (0x128, 0x128, 9), (0x129, 0x129, 5), (0x12A, 0x12A, 9), (0x12B, 0x12B, 5),
+    (0x12C, 0x12C, 9), (0x12D, 0x12D, 5), (0x12E, 0x12E, 9), (0x12F, 0x12F, 5),
+    (0x130, 0x130, 9), (0x131, 0x131, 5), (0x132, 0x132, 9), (0x133, 0x133, 5),
+    (0x134, 0x134, 9), (0x135, 0x135, 5), (0x136, 0x136, 9), (0x137, 0x138, 5),
+    (0x139, 0x139, 9), (0x13A, 0x13A, 5), (0x13B, 0x13B, 9), (0x13C, 0x13C, 5),
+    (0x13D, 0x13D, 9), (0x13E, 0x13E, 5), (0x13F, 0x13F, 9), (0x140, 0x140, 5),
+    (0x141, 0x141, 9), (0x142, 0x142, 5), (0x143, 0x143, 9), (0x144, 0x144, 5),
+    (0x145, 0x145, 9), (0x146, 0x146, 5), (0x147, 0x147, 9), (0x148, 0x149, 5),
+    (0x14A, 0x14A, 9), (0x14B, 0x14B, 5), (0x14C, 0x14C, 9), (0x14D, 0x14D, 5),
+    (0x14E, 0x14E, 9), (0x14F, 0x14F, 5), (0x150, 0x150, 9), (0x151, 0x151, 5),
+    (0x152, 0x152, 9), (0x153, 0x153, 5), (0x154, 0x154, 9), (0x155, 0x155, 5),
+    (0x156, 0x156, 9), (0x157, 0x157, 5), (0x158, 0x158, 9), (0x159, 0x159, 5),
+    (0x15A, 0x15A, 9), (0x15B, 0x15B, 5), (0x15C, 0x15C, 9), (0x15D, 0x15D, 5),
+    (0x15E, 0x15E, 9), (0x15F, 0x15F, 5), (0x160, 0x160, 9), (0x161, 0x161, 5),
+    (0x162, 0x162, 9), (0x163, 0x163, 5), (0x164, 0x164, 9), (0x165, 0x165, 5),
+    (0x166, 0x166, 9), (0x167, 0x167, 5), (0x168, 0x168, 9), (0x169, 0x169, 5),
+    (0x16A, 0x16A, 9), (0x16B, 0x16B, 5), (0x16C, 0x16C, 9), (0x16D, 0x16D, 5),
+    (0x16E, 0x16E, 9), (0x16F, 0x16F, 5), (0x170, 0x170, 9), (0x171, 0x171, 5),
+    (0x172, 0x172, 9), (0x173, 0x173, 5), (0x174, 0x174, 9), (0x175, 0x175, 5),
+    (0x176, 0x176, 9), (0x177, 0x177, 5), (0x178, 0x179, 9), (0x17A, 0x17A, 5),
+    (0x17B, 0x17B, 9), (0x17C, 0x17C, 5), (0x17D, 0x17D, 9), (0x17E, 0x180, 5),
+    (0x181, 0x182, 9), (0x183, 0x183, 5), (0x184, 0x184, 9), (0x185, 0x185, 5),
+    (0x186, 0x187, 9), (0x188, 0x188, 5), (0x189, 0x18B, 9), (0x18C, 0x18D, 5),
+    (0x18E, 0x191, 9), (0x192, 0x192, 5), (0x193, 0x194, 9), (0x195, 0x195, 5),
+    (0x196, 0x198, 9), (0x199, 0x19B, 5), (0x19C, 0x19D, 9), (0x19E, 0x19E, 5),
+    (0x19F, 0x1A0, 9), (0x1A1, 0x1A1, 5), (0x1A2, 0x1A2, 9), (0x1A3, 0x1A3, 5),
+    (0x1A4, 0x1A4, 9), (0x1A5, 0x1A5, 5), (0x1A6, 0x1A7, 9), (0x1A8, 0x1A8, 5),
+    (0x1A9, 0x1A9, 9), (0x1AA, 0x1AB, 5), (0x1AC, 0x1AC, 9), (0x1AD, 0x1AD, 5),
+    (0x1AE, 0x1AF, 9), (0x1B0, 0x1B0, 5), (0x1B1, 0x1B3, 9), (0x1B4, 0x1B4, 5),
+    (0x1B5, 0x1B5, 9), (0x1B6, 0x1B6, 5), (0x1B7, 0x1B8, 9), (0x1B9, 0x1BA, 5),
+    (0x1BB, 0x1BB, 7), (0x1BC, 0x1BC, 9), (0x1BD, 0x1BF, 5), (0x1C0, 0x1C3, 7),
+    (0x1C4, 0x1C4, 9), (0x1C5, 0x1C5, 8), (0x1C6, 0x1C6, 5), (0x1C7, 0x1C7, 9),
+    (0x1C8, 0x1C8, 8), (0x1C9, 0x1C9, 5), (0x1CA, 0x1CA, 9), (0x1CB, 0x1CB, 8),
+    (0x1CC, 0x1CC, 5), (0x1CD, 0x1CD, 9), (0x1CE, 0x1CE, 5), (0x1CF, 0x1CF, 9),
+    (0x1D0, 0x1D0, 5), (0x1D1, 0x1D1, 9), (0x1D2, 0x1D2, 5), (0x1D3, 0x1D3, 9),
+    (0x1D4, 0x1D4, 5), (0x1D5, 0x1D5, 9), (0x1D6, 0x1D6, 5), (0x1D7, 0x1D7, 9),
+    (0x1D8, 0x1D8, 5), (0x1D9, 0x1D9, 9), (0x1DA, 0x1DA, 5), (0x1DB, 0x1DB, 9),
+    (0x1DC, 0x1DD, 5), (0x1DE, 0x1DE, 9), (0x1DF, 0x1DF, 5), (0x1E0, 0x1E0, 9),
+    (0x1E1, 0x1E1, 5), (0x1E2, 0x1E2, 9), (0x1E3, 0x1E3, 5), (0x1E4, 0x1E4, 9),
+    (0x1E5, 0x1E5, 5), (0x1E6, 0x1E6, 9), (0x1E7, 0x1E7, 5), (0x1E8, 0x1E8, 9),
+    (0x1E9, 0x1E9, 5), (0x1EA, 0x1EA, 9), (0x1EB, 0x1EB, 5), (0x1EC, 0x1EC, 9),
+    (0x1ED, 0x1ED, 5), (0x1EE, 0x1EE, 9), (0x1EF, 0x1F0, 5), (0x1F1, 0x1F1, 9),
+    (0x1F2, 0x1F2, 8), (0x1F3, 0x1F3, 5), (0x1F4, 0x1F4, 9), (0x1F5, 0x1F5, 5),
+    (0x1F6, 0x1F8, 9), (0x1F9, 0x1F9, 5), (0x1FA, 0x1FA, 9), (0x1FB, 0x1FB, 5),
+    (0x1FC, 0x1FC, 9), (0x1FD, 0x1FD, 5), (0x1FE, 0x1FE, 9), (0x1FF, 0x1FF, 5),
+    (0x200, 0x200, 9), (0x201, 0x201, 5), (0x202, 0x202, 9), (0x203, 0x203, 5),
+    (0x204, 0x204, 9), (0x205, 0x205, 5), (0x206, 0x206, 9), (0x207, 0x207, 5),
+    (0x208, 0x208, 9), (0x209, 0x209, 5), (0x20A, 0x20A, 9), (0x20B, 0x20B, 5),
+    (0x20C, 0x20C, 9), (0x20D, 0x20D, 5), (0x20E, 0x20E, 9), (0x20F, 0x20F, 5),
+    (0x210, 0x210, 9), (0x211, 0x211, 5), (0x212, 0x212, 9), (0x213, 0x213, 5),
+    (0x214, 0x214, 9), (0x215, 0x215, 5), (0x216, 0x216, 9), (0x217, 0x217, 5),
+    (0x218, 0x218, 9), (0x219, 0x219, 5), (0x21A, 0x21A, 9), (0x21B, 0x21B, 5),
+    (0x21C, 0x21C, 9), (0x21D, 0x21D, 5), (0x21E, 0x21E, 9), (0x21F, 0x21F, 5),
+    (0x220, 0x220, 9), (0x221, 0x221, 5), (0x222, 0x222, 9), (0x223, 0x223, 5),
+    (0x224, 0x224, 9), (0x225, 0x225, 5), (0x226, 0x226, 9), (0x227, 0x227, 5),
+    (0x228, 0x228, 9), (0x229, 0x229, 5), (0x22A, 0x22A, 9), (0x22B, 0x22B, 5),
+    (0x22C, 0x22C, 9), (0x22D, 0x22D, 5), (0x22E, 0x22E, 9), (0x22F, 0x22F, 5),
+    (0x230, 0x230, 9), (0x231, 0x231, 5), (0x232, 0x232, 9), (0x233, 0x239, 5),
+    (0x23A, 0x23B, 9), (0x23C, 0x23C, 5), (0x23D, 0x23E, 9), (0x23F, 0x240, 5),
+    (0x241, 0x241, 9), (0x242, 0x242, 5), (0x243, 0x246, 9), (0x247, 0x247, 5),
+    (0x248, 0x248, 9), (0x249, 0x249, 5), (0x24A, 0x24A, 9), (0x24B, 0x24B, 5),
+    (0x24C, 0x24C, 9), (0x24D, 0x24D, 5), (0x24E, 0x24E, 9), (0x24F, 0x293, 5),
+    (0x294, 0x295, 7), (0x296, 0x2AF, 5), (0x2B0, 0x2C1, 6), (0x2C2, 0x2C5, 24),
+    (0x2C6, 0x2D1, 6), (0x2D2, 0x2DF, 24), (0x2E0, 0x2E4, 6), (0x2E5, 0x2EB, 24),
+    (0x2EC, 0x2EC, 6), (0x2ED, 0x2ED, 24), (0x2EE, 0x2EE, 6), (0x2EF, 0x2FF, 24),
+    (0x300, 0x36F, 12), (0x370, 0x370, 9), (0x371, 0x371, 5), (0x372, 0x372, 9),
+    (0x373, 0x373, 5), (0x374, 0x374, 6), (0x375, 0x375, 24), (0x376, 0x376, 9),
+    (0x377, 0x377, 5), (0x378, 0x379, 2), (0x37A, 0x37A, 6), (0x37B, 0x37D, 5),
+    (0x37E, 0x37E, 21), (0x37F, 0x37F, 9), (0x380, 0x383, 2), (0x384, 0x385, 24),
+    (0x386, 0x386, 9), (0x387, 0x387, 21), (0x388, 0x38A, 9), (0x38B, 0x38B, 2),
+    (0x38C, 0x38C, 9), (0x38D, 0x38D, 2), (0x38E, 0x38F, 9), (0x390, 0x390, 5),
+    (0x391, 0x3A1, 9), (0x3A2, 0x3A2, 2), (0x3A3, 0x3AB, 9), (0x3AC, 0x3CE, 5),
+    (0x3CF, 0x3CF, 9), (0x3D0, 0x3D1, 5), (0x3D2, 0x3D4, 9), (0x3D5, 0x3D7, 5),
+    (0x3D8, 0x3D8, 9), (0x3D9, 0x3D9, 5), (0x3DA, 0x3DA, 9), (0x3DB, 0x3DB, 5),
+    (0x3DC, 0x3DC, 9), (0x3DD, 0x3DD, 5), (0x3DE, 0x3DE, 9), (0x3DF, 0x3DF, 5),
+    (0x3E0, 0x3E0, 9), (0x3E1, 0x3E1, 5), (0x3E2, 0x3E2, 9), (0x3E3, 0x3E3, 5),
+    (0x3E4, 0x3E4, 9), (0x3E5, 0x3E5, 5), (0x3E6, 0x3E6, 9), (0x3E7, 0x3E7, 5),
+    (0x3E8, 0x3E8, 9), (0x3E9, 0x3E9, 5), (0x3EA, 0x3EA, 9), (0x3EB, 0x3EB, 5),
+    (0x3EC, 0x3EC, 9), (0x3ED, 0x3ED, 5), (0x3EE, 0x3EE, 9), (0x3EF, 0x3F3, 5),
+    (0x3F4, 0x3F4, 9), (0x3F5, 0x3F5, 5), (0x3F6, 0x3F6, 25), (0x3F7, 0x3F7, 9),
+    (0x3F8, 0x3F8, 5), (0x3F9, 0x3FA, 9), (0x3FB, 0x3FC, 5), (0x3FD, 0x42F, 9),
+    (0x430, 0x45F, 5), (0x460, 0x460, 9), (0x461, 0x461, 5), (0x462, 0x462, 9),
+    (0x463, 0x463, 5), (0x464, 0x464, 9), (0x465, 0x465, 5), (0x466, 0x466, 9),
+    (0x467, 0x467, 5), (0x468, 0x468, 9), (0x469, 0x469, 5), (0x46A, 0x46A, 9),
+    (0x46B, 0x46B, 5), (0x46C, 0x46C, 9), (0x46D, 0x46D, 5), (0x46E, 0x46E, 9),
+    (0x46F, 0x46F, 5), (0x470, 0x470, 9), (0x471, 0x471, 5), (0x472, 0x472, 9),
+    (0x473, 0x473, 5), (0x474, 0x474, 9), (0x475, 0x475, 5), (0x476, 0x476, 9),
+    (0x477, 0x477, 5), (0x478, 0x478, 9), (0x479, 0x479, 5), (0x47A, 0x47A, 9),
+    (0x47B, 0x47B, 5), (0x47C, 0x47C, 9), (0x47D, 0x47D, 5), (0x47E, 0x47E, 9),
+    (0x47F, 0x47F, 5), (0x480, 0x480, 9), (0x481, 0x481, 5), (0x482, 0x482, 26),
+    (0x483, 0x487, 12), (0x488, 0x489, 11), (0x48A, 0x48A, 9), (0x48B, 0x48B, 5),
+    (0x48C, 0x48C, 9), (0x48D, 0x48D, 5), (0x48E, 0x48E, 9), (0x48F, 0x48F, 5),
+    (0x490, 0x490, 9), (0x491, 0x491, 5), (0x492, 0x492, 9), (0x493, 0x493, 5),
+    (0x494, 0x494, 9), (0x495, 0x495, 5), (0x496, 0x496, 9), (0x497, 0x497, 5),
+    (0x498, 0x498, 9), (0x499, 0x499, 5), (0x49A, 0x49A, 9), (0x49B, 0x49B, 5),
+    (0x49C, 0x49C, 9), (0x49D, 0x49D, 5), (0x49E, 0x49E, 9), (0x49F, 0x49F, 5),
+    (0x4A0, 0x4A0, 9), (0x4A1, 0x4A1, 5), (0x4A2, 0x4A2, 9), (0x4A3, 0x4A3, 5),
+    (0x4A4, 0x4A4, 9), (0x4A5, 0x4A5, 5), (0x4A6, 0x4A6, 9), (0x4A7, 0x4A7, 5),
+    (0x4A8, 0x4A8, 9), (0x4A9, 0x4A9, 5), (0x4AA, 0x4AA, 9), (0x4AB, 0x4AB, 5),
+    (0x4AC, 0x4AC, 9), (0x4AD, 0x4AD, 5), (0x4AE, 0x4AE, 9), (0x4AF, 0x4AF, 5),
+    (0x4B0, 0x4B0, 9), (0x4B1, 0x4B1, 5), (0x4B2, 0x4B2, 9), (0x4B3, 0x4B3, 5),
+    (0x4B4, 0x4B4, 9), (0x4B5, 0x4B5, 5), (0x4B6, 0x4B6, 9), (0x4B7, 0x4B7, 5),
+    (0x4B8, 0x4B8, 9), (0x4B9, 0x4B9, 5), (0x4BA, 0x4BA, 9), (0x4BB, 0x4BB, 5),
+    (0x4BC, 0x4BC, 9), (0x4BD, 0x4BD, 5), (0x4BE, 0x4BE, 9), (0x4BF, 0x4BF, 5),
+    (0x4C0, 0x4C1, 9), (0x4C2, 0x4C2, 5), (0x4C3, 0x4C3, 9), (0x4C4, 0x4C4, 5),
+    (0x4C5, 0x4C5, 9), (0x4C6, 0x4C6, 5), (0x4C7, 0x4C7, 9), (0x4C8, 0x4C8, 5),
+    (0x4C9, 0x4C9, 9), (0x4CA, 0x4CA, 5), (0x4CB, 0x4CB, 9), (0x4CC, 0x4CC, 5),
+    (0x4CD, 0x4CD, 9), (0x4CE, 0x4CF, 5), (0x4D0, 0x4D0, 9), (0x4D1, 0x4D1, 5),
+    (0x4D2, 0x4D2, 9), (0x4D3, 0x4D3, 5), (0x4D4, 0x4D4, 9), (0x4D5, 0x4D5, 5),
+    (0x4D6, 0x4D6, 9), (0x4D7, 0x4D7, 5), (0x4D8, 0x4D8, 9), (0x4D9, 0x4D9, 5),
+    (0x4DA, 0x4DA, 9), (0x4DB, 0x4DB, 5), (0x4DC, 0x4DC, 9), (0x4DD, 0x4DD, 5),
+    (0x4DE, 0x4DE, 9), (0x4DF, 0x4DF, 5), (0x4E0, 0x4E0, 9), (0x4E1, 0x4E1, 5),
+    (0x4E2, 0x4E2, 9), (0x4E3, 0x4E3, 5), (0x4E4, 0x4E4, 9), (0x4E5, 0x4E5, 5),
+    (0x4E6, 0x4E6, 9), (0x4E7, 0x4E7, 5), (0x4E8, 0x4E8, 9), (0x4E9, 0x4E9, 5),
+    (0x4EA, 0x4EA, 9), (0x4EB, 0x4EB, 5), (0x4EC, 0x4EC, 9), (0x4ED, 0x4ED, 5),
+    (0x4EE, 0x4EE, 9), (0x4EF, 0x4EF, 5), (0x4F0, 0x4F0, 9), (0x4F1, 0x4F1, 5),
+    (0x4F2, 0x4F2, 9), (0x4F3, 0x4F3, 5), (0x4F4, 0x4F4, 9), (0x4F5, 0x4F5, 5),
+    (0x4F6, 0x4F6, 9), (0x4F7, 0x4F7, 5), (0x4F8, 0x4F8, 9), (0x4F9, 0x4F9, 5),
+    (0x4FA, 0x4FA, 9), (0x4FB, 0x4FB, 5), (0x4FC, 0x4FC, 9), (0x4FD, 0x4FD, 5),
+    (0x4FE, 0x4FE, 9), (0x4FF, 0x4FF, 5), (0x500, 0x500, 9), (0x501, 0x501, 5),
+    (0x502, 0x502, 9), (0x503, 0x503, 5), (0x504, 0x504, 9), (0x505, 0x505, 5),
+    (0x506, 0x506, 9), (0x507, 0x507, 5), (0x508, 0x508, 9), (0x509, 0x509, 5),
+    (0x50A, 0x50A, 9), (0x50B, 0x50B, 5), (0x50C, 0x50C, 9), (0x50D, 0x50D, 5),
+    (0x50E, 0x50E, 9), (0x50F, 0x50F, 5), (0x510, 0x510, 9), (0x511, 0x511, 5),
+    (0x512, 0x512, 9), (0x513, 0x513, 5), (0x514, 0x514, 9), (0x515, 0x515, 5),
+    (0x516, 0x516, 9), (0x517, 0x517, 5), (0x518, 0x518, 9), (0x519, 0x519, 5),
+    (0x51A, 0x51A, 9), (0x51B, 0x51B, 5), (0x51C, 0x51C, 9), (0x51D, 0x51D, 5),
+    (0x51E, 0x51E, 9), (0x51F, 0x51F, 5), (0x520, 0x520, 9), (0x521, 0x521, 5),
+    (0x522, 0x522, 9), (0x523, 0x523, 5), (0x524, 0x524, 9), (0x525, 0x525, 5),
+    (0x526, 0x526, 9), (0x527, 0x527, 5), (0x528, 0x528, 9), (0x529, 0x529, 5),
+    (0x52A, 0x52A, 9), (0x52B, 0x52B, 5), (0x52C, 0x52C, 9), (0x52D, 0x52D, 5),
+    (0x52E, 0x52E, 9), (0x52F, 0x52F, 5), (0x530, 0x530, 2), (0x531, 0x556, 9),
+    (0x557, 0x558, 2), (0x559, 0x559, 6), (0x55A, 0x55F, 21), (0x560, 0x588, 5),
+    (0x589, 0x589, 21), (0x58A, 0x58A, 17), (0x58B, 0x58C, 2), (0x58D, 0x58E, 26),
+    (0x58F, 0x58F, 23), (0x590, 0x590, 2), (0x591, 0x5BD, 12), (0x5BE, 0x5BE, 17),
+    (0x5BF, 0x5BF, 12), (0x5C0, 0x5C0, 21), (0x5C1, 0x5C2, 12), (0x5C3, 0x5C3, 21),
+    (0x5C4, 0x5C5, 12), (0x5C6, 0x5C6, 21), (0x5C7, 0x5C7, 12), (0x5C8, 0x5CF, 2),
+    (0x5D0, 0x5EA, 7), (0x5EB, 0x5EE, 2), (0x5EF, 0x5F2, 7), (0x5F3, 0x5F4, 21),
+    (0x5F5, 0x5FF, 2), (0x600, 0x605, 1), (0x606, 0x608, 25), (0x609, 0x60A, 21),
+    (0x60B, 0x60B, 23), (0x60C, 0x60D, 21), (0x60E, 0x60F, 26), (0x610, 0x61A, 12),
+    (0x61B, 0x61B, 21), (0x61C, 0x61C, 1), (0x61D, 0x61F, 21), (0x620, 0x63F, 7),
+    (0x640, 0x640, 6), (0x641, 0x64A, 7), (0x64B, 0x65F, 12), (0x660, 0x669, 13),
+    (0x66A, 0x66D, 21), (0x66E, 0x66F, 7), (0x670, 0x670, 12), (0x671, 0x6D3, 7),
+    (0x6D4, 0x6D4, 21), (0x6D5, 0x6D5, 7), (0x6D6, 0x6DC, 12), (0x6DD, 0x6DD, 1),
+    (0x6DE, 0x6DE, 26), (0x6DF, 0x6E4, 12), (0x6E5, 0x6E6, 6), (0x6E7, 0x6E8, 12),
+    (0x6E9, 0x6E9, 26), (0x6EA, 0x6ED, 12), (0x6EE, 0x6EF, 7), (0x6F0, 0x6F9, 13),
+    (0x6FA, 0x6FC, 7), (0x6FD, 0x6FE, 26), (0x6FF, 0x6FF, 7), (0x700, 0x70D, 21),
+    (0x70E, 0x70E, 2), (0x70F, 0x70F, 1), (0x710, 0x710, 7), (0x711, 0x711, 12),
+    (0x712, 0x72F, 7), (0x730, 0x74A, 12), (0x74B, 0x74C, 2), (0x74D, 0x7A5, 7),
+    (0x7A6, 0x7B0, 12), (0x7B1, 0x7B1, 7), (0x7B2, 0x7BF, 2), (0x7C0, 0x7C9, 13),
+    (0x7CA, 0x7EA, 7), (0x7EB, 0x7F3, 12), (0x7F4, 0x7F5, 6), (0x7F6, 0x7F6, 26),
+    (0x7F7, 0x7F9, 21), (0x7FA, 0x7FA, 6), (0x7FB, 0x7FC, 2), (0x7FD, 0x7FD, 12),
+    (0x7FE, 0x7FF, 23), (0x800, 0x815, 7), (0x816, 0x819, 12), (0x81A, 0x81A, 6),
+    (0x81B, 0x823, 12), (0x824, 0x824, 6), (0x825, 0x827, 12), (0x828, 0x828, 6),
+    (0x829, 0x82D, 12), (0x82E, 0x82F, 2), (0x830, 0x83E, 21), (0x83F, 0x83F, 2),
+    (0x840, 0x858, 7), (0x859, 0x85B, 12), (0x85C, 0x85D, 2), (0x85E, 0x85E, 21),
+    (0x85F, 0x85F, 2), (0x860, 0x86A, 7), (0x86B, 0x86F, 2), (0x870, 0x887, 7),
+    (0x888, 0x888, 24), (0x889, 0x88F, 7), (0x890, 0x891, 1), (0x892, 0x896, 2),
+    (0x897, 0x89F, 12), (0x8A0, 0x8C8, 7), (0x8C9, 0x8C9, 6), (0x8CA, 0x8E1, 12),
+    (0x8E2, 0x8E2, 1), (0x8E3, 0x902, 12), (0x903, 0x903, 10), (0x904, 0x939, 7),
+    (0x93A, 0x93A, 12), (0x93B, 0x93B, 10), (0x93C, 0x93C, 12), (0x93D, 0x93D, 7),
+    (0x93E, 0x940, 10), (0x941, 0x948, 12), (0x949, 0x94C, 10), (0x94D, 0x94D, 12),
+    (0x94E, 0x94F, 10), (0x950, 0x950, 7), (0x951, 0x957, 12), (0x958, 0x961, 7),
+    (0x962, 0x963, 12), (0x964, 0x965, 21), (0x966, 0x96F, 13), (0x970, 0x970, 21),
+    (0x971, 0x971, 6), (0x972, 0x980, 7), (0x981, 0x981, 12), (0x982, 0x983, 10),
+    (0x984, 0x984, 2), (0x985, 0x98C, 7), (0x98D, 0x98E, 2), (0x98F, 0x990, 7),
+    (0x991, 0x992, 2), (0x993, 0x9A8, 7), (0x9A9, 0x9A9, 2), (0x9AA, 0x9B0, 7),
+    (0x9B1, 0x9B1, 2), (0x9B2, 0x9B2, 7), (0x9B3, 0x9B5, 2), (0x9B6, 0x9B9, 7),
+    (0x9BA, 0x9BB, 2), (0x9BC, 0x9BC, 12), (0x9BD, 0x9BD, 7), (0x9BE, 0x9C0, 10),
+    (0x9C1, 0x9C4, 12), (0x9C5, 0x9C6, 2), (0x9C7, 0x9C8, 10), (0x9C9, 0x9CA, 2),
+    (0x9CB, 0x9CC, 10), (0x9CD, 0x9CD, 12), (0x9CE, 0x9CE, 7), (0x9CF, 0x9D6, 2),
+    (0x9D7, 0x9D7, 10), (0x9D8, 0x9DB, 2), (0x9DC, 0x9DD, 7), (0x9DE, 0x9DE, 2),
+    (0x9DF, 0x9E1, 7), (0x9E2, 0x9E3, 12), (0x9E4, 0x9E5, 2), (0x9E6, 0x9EF, 13),
+    (0x9F0, 0x9F1, 7), (0x9F2, 0x9F3, 23), (0x9F4, 0x9F9, 15), (0x9FA, 0x9FA, 26),
+    (0x9FB, 0x9FB, 23), (0x9FC, 0x9FC, 7), (0x9FD, 0x9FD, 21), (0x9FE, 0x9FE, 12),
+    (0x9FF, 0xA00, 2), (0xA01, 0xA02, 12), (0xA03, 0xA03, 10), (0xA04, 0xA04, 2),
+    (0xA05, 0xA0A, 7), (0xA0B, 0xA0E, 2), (0xA0F, 0xA10, 7), (0xA11, 0xA12, 2),
+    (0xA13, 0xA28, 7), (0xA29, 0xA29, 2), (0xA2A, 0xA30, 7), (0xA31, 0xA31, 2),
+    (0xA32, 0xA33, 7), (0xA34, 0xA34, 2), (0xA35, 0xA36, 7), (0xA37, 0xA37, 2),
+    (0xA38, 0xA39, 7), (0xA3A, 0xA3B, 2), (0xA3C, 0xA3C, 12), (0xA3D, 0xA3D, 2),
+    (0xA3E, 0xA40, 10), (0xA41, 0xA42, 12), (0xA43, 0xA46, 2), (0xA47, 0xA48, 12),
+    (0xA49, 0xA4A, 2), (0xA4B, 0xA4D, 12), (0xA4E, 0xA50, 2), (0xA51, 0xA51, 12),
+    (0xA52, 0xA58, 2), (0xA59, 0xA5C, 7), (0xA5D, 0xA5D, 2), (0xA5E, 0xA5E, 7),
+    (0xA5F, 0xA65, 2), (0xA66, 0xA6F, 13), (0xA70, 0xA71, 12), (0xA72, 0xA74, 7),
+    (0xA75, 0xA75, 12), (0xA76, 0xA76, 21), (0xA77, 0xA80, 2), (0xA81, 0xA82, 12),
+    (0xA83, 0xA83, 10), (0xA84, 0xA84, 2), (0xA85, 0xA8D, 7), (0xA8E, 0xA8E, 2),
+    (0xA8F, 0xA91, 7), (0xA92, 0xA92, 2), (0xA93, 0xAA8, 7), (0xAA9, 0xAA9, 2),
+    (0xAAA, 0xAB0, 7), (0xAB1, 0xAB1, 2), (0xAB2, 0xAB3, 7), (0xAB4, 0xAB4, 2),
+    (0xAB5, 0xAB9, 7), (0xABA, 0xABB, 2), (0xABC, 0xABC, 12), (0xABD, 0xABD, 7),
+    (0xABE, 0xAC0, 10), (0xAC1, 0xAC5, 12), (0xAC6, 0xAC6, 2), (0xAC7, 0xAC8, 12),
+    (0xAC9, 0xAC9, 10), (0xACA, 0xACA, 2), (0xACB, 0xACC, 10), (0xACD, 0xACD, 12),
+    (0xACE, 0xACF, 2), (0xAD0, 0xAD0, 7), (0xAD1, 0xADF, 2), (0xAE0, 0xAE1, 7),
+    (0xAE2, 0xAE3, 12), (0xAE4, 0xAE5, 2), (0xAE6, 0xAEF, 13), (0xAF0, 0xAF0, 21),
+    (0xAF1, 0xAF1, 23), (0xAF2, 0xAF8, 2), (0xAF9, 0xAF9, 7), (0xAFA, 0xAFF, 12),
+    (0xB00, 0xB00, 2), (0xB01, 0xB01, 12), (0xB02, 0xB03, 10), (0xB04, 0xB04, 2),
+    (0xB05, 0xB0C, 7), (0xB0D, 0xB0E, 2), (0xB0F, 0xB10, 7), (0xB11, 0xB12, 2),
+    (0xB13, 0xB28, 7), (0xB29, 0xB29, 2), (0xB2A, 0xB30, 7), (0xB31, 0xB31, 2),
+    (0xB32, 0xB33, 7), (0xB34, 0xB34, 2), (0xB35, 0xB39, 7), (0xB3A, 0xB3B, 2),
+    (0xB3C, 0xB3C, 12), (0xB3D, 0xB3D, 7), (0xB3E, 0xB3E, 10), (0xB3F, 0xB3F, 12),
+    (0xB40, 0xB40, 10), (0xB41, 0xB44, 12), (0xB45, 0xB46, 2), (0xB47, 0xB48, 10),
+    (0xB49, 0xB4A, 2), (0xB4B, 0xB4C, 10), (0xB4D, 0xB4D, 12), (0xB4E, 0xB54, 2),
+    (0xB55, 0xB56, 12), (0xB57, 0xB57, 10), (0xB58, 0xB5B, 2), (0xB5C, 0xB5D, 7),
+    (0xB5E, 0xB5E, 2), (0xB5F, 0xB61, 7), (0xB62, 0xB63, 12), (0xB64, 0xB65, 2),
+    (0xB66, 0xB6F, 13), (0xB70, 0xB70, 26), (0xB71, 0xB71, 7), (0xB72, 0xB77, 15),
+    (0xB78, 0xB81, 2), (0xB82, 0xB82, 12), (0xB83, 0xB83, 7), (0xB84, 0xB84, 2),
+    (0xB85, 0xB8A, 7), (0xB8B, 0xB8D, 2), (0xB8E, 0xB90, 7), (0xB91, 0xB91, 2),
+    (0xB92, 0xB95, 7), (0xB96, 0xB98, 2), (0xB99, 0xB9A, 7), (0xB9B, 0xB9B, 2),
+    (0xB9C, 0xB9C, 7), (0xB9D, 0xB9D, 2), (0xB9E, 0xB9F, 7), (0xBA0, 0xBA2, 2),
+    (0xBA3, 0xBA4, 7), (0xBA5, 0xBA7, 2), (0xBA8, 0xBAA, 7), (0xBAB, 0xBAD, 2),
+    (0xBAE, 0xBB9, 7), (0xBBA, 0xBBD, 2), (0xBBE, 0xBBF, 10), (0xBC0, 0xBC0, 12),
+    (0xBC1, 0xBC2, 10), (0xBC3, 0xBC5, 2), (0xBC6, 0xBC8, 10), (0xBC9, 0xBC9, 2),
+    (0xBCA, 0xBCC, 10), (0xBCD, 0xBCD, 12), (0xBCE, 0xBCF, 2), (0xBD0, 0xBD0, 7),
+    (0xBD1, 0xBD6, 2), (0xBD7, 0xBD7, 10), (0xBD8, 0xBE5, 2), (0xBE6, 0xBEF, 13),
+    (0xBF0, 0xBF2, 15), (0xBF3, 0xBF8, 26), (0xBF9, 0xBF9, 23), (0xBFA, 0xBFA, 26),
+    (0xBFB, 0xBFF, 2), (0xC00, 0xC00, 12), (0xC01, 0xC03, 10), (0xC04, 0xC04, 12),
+    (0xC05, 0xC0C, 7), (0xC0D, 0xC0D, 2), (0xC0E, 0xC10, 7), (0xC11, 0xC11, 2),
+    (0xC12, 0xC28, 7), (0xC29, 0xC29, 2), (0xC2A, 0xC39, 7), (0xC3A, 0xC3B, 2),
+    (0xC3C, 0xC3C, 12), (0xC3D, 0xC3D, 7), (0xC3E, 0xC40, 12), (0xC41, 0xC44, 10),
+    (0xC45, 0xC45, 2), (0xC46, 0xC48, 12), (0xC49, 0xC49, 2), (0xC4A, 0xC4D, 12),
+    (0xC4E, 0xC54, 2), (0xC55, 0xC56, 12), (0xC57, 0xC57, 2), (0xC58, 0xC5A, 7),
+    (0xC5B, 0xC5B, 2), (0xC5C, 0xC5D, 7), (0xC5E, 0xC5F, 2), (0xC60, 0xC61, 7),
+    (0xC62, 0xC63, 12), (0xC64, 0xC65, 2), (0xC66, 0xC6F, 13), (0xC70, 0xC76, 2),
+    (0xC77, 0xC77, 21), (0xC78, 0xC7E, 15), (0xC7F, 0xC7F, 26), (0xC80, 0xC80, 7),
+    (0xC81, 0xC81, 12), (0xC82, 0xC83, 10), (0xC84, 0xC84, 21), (0xC85, 0xC8C, 7),
+    (0xC8D, 0xC8D, 2), (0xC8E, 0xC90, 7), (0xC91, 0xC91, 2), (0xC92, 0xCA8, 7),
+    (0xCA9, 0xCA9, 2), (0xCAA, 0xCB3, 7), (0xCB4, 0xCB4, 2), (0xCB5, 0xCB9, 7),
+    (0xCBA, 0xCBB, 2), (0xCBC, 0xCBC, 12), (0xCBD, 0xCBD, 7), (0xCBE, 0xCBE, 10),
+    (0xCBF, 0xCBF, 12), (0xCC0, 0xCC4, 10), (0xCC5, 0xCC5, 2), (0xCC6, 0xCC6, 12),
+    (0xCC7, 0xCC8, 10), (0xCC9, 0xCC9, 2), (0xCCA, 0xCCB, 10), (0xCCC, 0xCCD, 12),
+    (0xCCE, 0xCD4, 2), (0xCD5, 0xCD6, 10), (0xCD7, 0xCDB, 2), (0xCDC, 0xCDE, 7),
+    (0xCDF, 0xCDF, 2), (0xCE0, 0xCE1, 7), (0xCE2, 0xCE3, 12), (0xCE4, 0xCE5, 2),
+    (0xCE6, 0xCEF, 13), (0xCF0, 0xCF0, 2), (0xCF1, 0xCF2, 7), (0xCF3, 0xCF3, 10),
+    (0xCF4, 0xCFF, 2), (0xD00, 0xD01, 12), (0xD02, 0xD03, 10), (0xD04, 0xD0C, 7),
+    (0xD0D, 0xD0D, 2), (0xD0E, 0xD10, 7), (0xD11, 0xD11, 2), (0xD12, 0xD3A, 7),
+    (0xD3B, 0xD3C, 12), (0xD3D, 0xD3D, 7), (0xD3E, 0xD40, 10), (0xD41, 0xD44, 12),
+    (0xD45, 0xD45, 2), (0xD46, 0xD48, 10), (0xD49, 0xD49, 2), (0xD4A, 0xD4C, 10),
+    (0xD4D, 0xD4D, 12), (0xD4E, 0xD4E, 7), (0xD4F, 0xD4F, 26), (0xD50, 0xD53, 2),
+    (0xD54, 0xD56, 7), (0xD57, 0xD57, 10), (0xD58, 0xD5E, 15), (0xD5F, 0xD61, 7),
+    (0xD62, 0xD63, 12), (0xD64, 0xD65, 2), (0xD66, 0xD6F, 13), (0xD70, 0xD78, 15),
+    (0xD79, 0xD79, 26), (0xD7A, 0xD7F, 7), (0xD80, 0xD80, 2), (0xD81, 0xD81, 12),
+    (0xD82, 0xD83, 10), (0xD84, 0xD84, 2), (0xD85, 0xD96, 7), (0xD97, 0xD99, 2),
+    (0xD9A, 0xDB1, 7), (0xDB2, 0xDB2, 2), (0xDB3, 0xDBB, 7), (0xDBC, 0xDBC, 2),
+    (0xDBD, 0xDBD, 7), (0xDBE, 0xDBF, 2), (0xDC0, 0xDC6, 7), (0xDC7, 0xDC9, 2),
+    (0xDCA, 0xDCA, 12), (0xDCB, 0xDCE, 2), (0xDCF, 0xDD1, 10), (0xDD2, 0xDD4, 12),
+    (0xDD5, 0xDD5, 2), (0xDD6, 0xDD6, 12), (0xDD7, 0xDD7, 2), (0xDD8, 0xDDF, 10),
+    (0xDE0, 0xDE5, 2), (0xDE6, 0xDEF, 13), (0xDF0, 0xDF1, 2), (0xDF2, 0xDF3, 10),
+    (0xDF4, 0xDF4, 21), (0xDF5, 0xE00, 2), (0xE01, 0xE30, 7), (0xE31, 0xE31, 12),
+    (0xE32, 0xE33, 7), (0xE34, 0xE3A, 12), (0xE3B, 0xE3E, 2), (0xE3F, 0xE3F, 23),
+    (0xE40, 0xE45, 7), (0xE46, 0xE46, 6), (0xE47, 0xE4E, 12), (0xE4F, 0xE4F, 21),
+    (0xE50, 0xE59, 13), (0xE5A, 0xE5B, 21), (0xE5C, 0xE80, 2), (0xE81, 0xE82, 7),
+    (0xE83, 0xE83, 2), (0xE84, 0xE84, 7), (0xE85, 0xE85, 2), (0xE86, 0xE8A, 7),
+    (0xE8B, 0xE8B, 2), (0xE8C, 0xEA3, 7), (0xEA4, 0xEA4, 2), (0xEA5, 0xEA5, 7),
+    (0xEA6, 0xEA6, 2), (0xEA7, 0xEB0, 7), (0xEB1, 0xEB1, 12), (0xEB2, 0xEB3, 7),
+    (0xEB4, 0xEBC, 12), (0xEBD, 0xEBD, 7), (0xEBE, 0xEBF, 2), (0xEC0, 0xEC4, 7),
+    (0xEC5, 0xEC5, 2), (0xEC6, 0xEC6, 6), (0xEC7, 0xEC7, 2), (0xEC8, 0xECE, 12),
+    (0xECF, 0xECF, 2), (0xED0, 0xED9, 13), (0xEDA, 0xEDB, 2), (0xEDC, 0xEDF, 7),
+    (0xEE0, 0xEFF, 2), (0xF00, 0xF00, 7), (0xF01, 0xF03, 26), (0xF04, 0xF12, 21),
+    (0xF13, 0xF13, 26), (0xF14, 0xF14, 21), (0xF15, 0xF17, 26), (0xF18, 0xF19, 12),
+    (0xF1A, 0xF1F, 26), (0xF20, 0xF29, 13), (0xF2A, 0xF33, 15), (0xF34, 0xF34, 26),
+    (0xF35, 0xF35, 12), (0xF36, 0xF36, 26), (0xF37, 0xF37, 12), (0xF38, 0xF38, 26),
+    (0xF39, 0xF39, 12), (0xF3A, 0xF3A, 22), (0xF3B, 0xF3B, 18), (0xF3C, 0xF3C, 22),
+    (0xF3D, 0xF3D, 18), (0xF3E, 0xF3F, 10), (0xF40, 0xF47, 7), (0xF48, 0xF48, 2),
+    (0xF49, 0xF6C, 7), (0xF6D, 0xF70, 2), (0xF71, 0xF7E, 12), (0xF7F, 0xF7F, 10),
+    (0xF80, 0xF84, 12), (0xF85, 0xF85, 21), (0xF86, 0xF87, 12), (0xF88, 0xF8C, 7),
+    (0xF8D, 0xF97, 12), (0xF98, 0xF98, 2), (0xF99, 0xFBC, 12), (0xFBD, 0xFBD, 2),
+    (0xFBE, 0xFC5, 26), (0xFC6, 0xFC6, 12), (0xFC7, 0xFCC, 26), (0xFCD, 0xFCD, 2),
+    (0xFCE, 0xFCF, 26), (0xFD0, 0xFD4, 21), (0xFD5, 0xFD8, 26), (0xFD9, 0xFDA, 21),
+    (0xFDB, 0xFFF, 2), (0x1000, 0x102A, 7), (0x102B, 0x102C, 10), (0x102D, 0x1030, 12),
+    (0x1031, 0x1031, 10), (0x1032, 0x1037, 12), (0x1038, 0x1038, 10), (0x1039, 0x103A, 12),
+    (0x103B, 0x103C, 10), (0x103D, 0x103E, 12), (0x103F, 0x103F, 7), (0x1040, 0x1049, 13),
+    (0x104A, 0x104F, 21), (0x1050, 0x1055, 7), (0x1056, 0x1057, 10), (0x1058, 0x1059, 12),
+    (0x105A, 0x105D, 7), (0x105E, 0x1060, 12), (0x1061, 0x1061, 7), (0x1062, 0x1064, 10),
+    (0x1065, 0x1066, 7), (0x1067, 0x106D, 10), (0x106E, 0x1070, 7), (0x1071, 0x1074, 12),
+    (0x1075, 0x1081, 7), (0x1082, 0x1082, 12), (0x1083, 0x1084, 10), (0x1085, 0x1086, 12),
+    (0x1087, 0x108C, 10), (0x108D, 0x108D, 12), (0x108E, 0x108E, 7), (0x108F, 0x108F, 10),
+    (0x1090, 0x1099, 13), (0x109A, 0x109C, 10), (0x109D, 0x109D, 12), (0x109E, 0x109F, 26),
+    (0x10A0, 0x10C5, 9), (0x10C6, 0x10C6, 2), (0x10C7, 0x10C7, 9), (0x10C8, 0x10CC, 2),
+    (0x10CD, 0x10CD, 9), (0x10CE, 0x10CF, 2), (0x10D0, 0x10FA, 5), (0x10FB, 0x10FB, 21),
+    (0x10FC, 0x10FC, 6), (0x10FD, 0x10FF, 5), (0x1100, 0x1248, 7), (0x1249, 0x1249, 2),
+    (0x124A, 0x124D, 7), (0x124E, 0x124F, 2), (0x1250, 0x1256, 7), (0x1257, 0x1257, 2),
+    (0x1258, 0x1258, 7), (0x1259, 0x1259, 2), (0x125A, 0x125D, 7), (0x125E, 0x125F, 2),
+    (0x1260, 0x1288, 7), (0x1289, 0x1289, 2), (0x128A, 0x128D, 7), (0x128E, 0x128F, 2),
+    (0x1290, 0x12B0, 7), (0x12B1, 0x12B1, 2), (0x12B2, 0x12B5, 7), (0x12B6, 0x12B7, 2),
+    (0x12B8, 0x12BE, 7), (0x12BF, 0x12BF, 2), (0x12C0, 0x12C0, 7), (0x12C1, 0x12C1, 2),
+    (0x12C2, 0x12C5, 7), (0x12C6, 0x12C7, 2), (0x12C8, 0x12D6, 7), (0x12D7, 0x12D7, 2),
+    (0x12D8, 0x1310, 7), (0x1311, 0x1311, 2), (0x1312, 0x1315, 7), (0x1316, 0x1317, 2),
+    (0x1318, 0x135A, 7), (0x135B, 0x135C, 2), (0x135D, 0x135F, 12), (0x1360, 0x1368, 21),
+    (0x1369, 0x137C, 15), (0x137D, 0x137F, 2), (0x1380, 0x138F, 7), (0x1390, 0x1399, 26),
+    (0x139A, 0x139F, 2), (0x13A0, 0x13F5, 9), (0x13F6, 0x13F7, 2), (0x13F8, 0x13FD, 5),
+    (0x13FE, 0x13FF, 2), (0x1400, 0x1400, 17), (0x1401, 0x166C, 7), (0x166D, 0x166D, 26),
+    (0x166E, 0x166E, 21), (0x166F, 0x167F, 7), (0x1680, 0x1680, 29), (0x1681, 0x169A, 7),
+    (0x169B, 0x169B, 22), (0x169C, 0x169C, 18), (0x169D, 0x169F, 2), (0x16A0, 0x16EA, 7),
+    (0x16EB, 0x16ED, 21), (0x16EE, 0x16F0, 14), (0x16F1, 0x16F8, 7), (0x16F9, 0x16FF, 2),
+    (0x1700, 0x1711, 7), (0x1712, 0x1714, 12), (0x1715, 0x1715, 10), (0x1716, 0x171E, 2),
+    (0x171F, 0x1731, 7), (0x1732, 0x1733, 12), (0x1734, 0x1734, 10), (0x1735, 0x1736, 21),
+    (0x1737, 0x173F, 2), (0x1740, 0x1751, 7), (0x1752, 0x1753, 12), (0x1754, 0x175F, 2),
+    (0x1760, 0x176C, 7), (0x176D, 0x176D, 2), (0x176E, 0x1770, 7), (0x1771, 0x1771, 2),
+    (0x1772, 0x1773, 12), (0x1774, 0x177F, 2), (0x1780, 0x17B3, 7), (0x17B4, 0x17B5, 12),
+    (0x17B6, 0x17B6, 10), (0x17B7, 0x17BD, 12), (0x17BE, 0x17C5, 10), (0x17C6, 0x17C6, 12),
+    (0x17C7, 0x17C8, 10), (0x17C9, 0x17D3, 12), (0x17D4, 0x17D6, 21), (0x17D7, 0x17D7, 6),
+    (0x17D8, 0x17DA, 21), (0x17DB, 0x17DB, 23), (0x17DC, 0x17DC, 7), (0x17DD, 0x17DD, 12),
+    (0x17DE, 0x17DF, 2), (0x17E0, 0x17E9, 13), (0x17EA, 0x17EF, 2), (0x17F0, 0x17F9, 15),
+    (0x17FA, 0x17FF, 2), (0x1800, 0x1805, 21), (0x1806, 0x1806, 17), (0x1807, 0x180A, 21),
+    (0x180B, 0x180D, 12), (0x180E, 0x180E, 1), (0x180F, 0x180F, 12), (0x1810, 0x1819, 13),
+    (0x181A, 0x181F, 2), (0x1820, 0x1842, 7), (0x1843, 0x1843, 6), (0x1844, 0x1878, 7),
+    (0x1879, 0x187F, 2), (0x1880, 0x1884, 7), (0x1885, 0x1886, 12), (0x1887, 0x18A8, 7),
+    (0x18A9, 0x18A9, 12), (0x18AA, 0x18AA, 7), (0x18AB, 0x18AF, 2), (0x18B0, 0x18F5, 7),
+    (0x18F6, 0x18FF, 2), (0x1900, 0x191E, 7), (0x191F, 0x191F, 2), (0x1920, 0x1922, 12),
+    (0x1923, 0x1926, 10), (0x1927, 0x1928, 12), (0x1929, 0x192B, 10), (0x192C, 0x192F, 2),
+    (0x1930, 0x1931, 10), (0x1932, 0x1932, 12), (0x1933, 0x1938, 10), (0x1939, 0x193B, 12),
+    (0x193C, 0x193F, 2), (0x1940, 0x1940, 26), (0x1941, 0x1943, 2), (0x1944, 0x1945, 21),
+    (0x1946, 0x194F, 13), (0x1950, 0x196D, 7), (0x196E, 0x196F, 2), (0x1970, 0x1974, 7),
+    (0x1975, 0x197F, 2), (0x1980, 0x19AB, 7), (0x19AC, 0x19AF, 2), (0x19B0, 0x19C9, 7),
+    (0x19CA, 0x19CF, 2), (0x19D0, 0x19D9, 13), (0x19DA, 0x19DA, 15), (0x19DB, 0x19DD, 2),
+    (0x19DE, 0x19FF, 26), (0x1A00, 0x1A16, 7), (0x1A17, 0x1A18, 12), (0x1A19, 0x1A1A, 10),
+    (0x1A1B, 0x1A1B, 12), (0x1A1C, 0x1A1D, 2), (0x1A1E, 0x1A1F, 21), (0x1A20, 0x1A54, 7),
+    (0x1A55, 0x1A55, 10), (0x1A56, 0x1A56, 12), (0x1A57, 0x1A57, 10), (0x1A58, 0x1A5E, 12),
+    (0x1A5F, 0x1A5F, 2), (0x1A60, 0x1A60, 12), (0x1A61, 0x1A61, 10), (0x1A62, 0x1A62, 12),
+    (0x1A63, 0x1A64, 10), (0x1A65, 0x1A6C, 12), (0x1A6D, 0x1A72, 10), (0x1A73, 0x1A7C, 12),
+    (0x1A7D, 0x1A7E, 2), (0x1A7F, 0x1A7F, 12), (0x1A80, 0x1A89, 13), (0x1A8A, 0x1A8F, 2),
+    (0x1A90, 0x1A99, 13), (0x1A9A, 0x1A9F, 2), (0x1AA0, 0x1AA6, 21), (0x1AA7, 0x1AA7, 6),
+    (0x1AA8, 0x1AAD, 21), (0x1AAE, 0x1AAF, 2), (0x1AB0, 0x1ABD, 12), (0x1ABE, 0x1ABE, 11),
+    (0x1ABF, 0x1ADD, 12), (0x1ADE, 0x1ADF, 2), (0x1AE0, 0x1AEB, 12), (0x1AEC, 0x1AFF, 2),
+    (0x1B00, 0x1B03, 12), (0x1B04, 0x1B04, 10), (0x1B05, 0x1B33, 7), (0x1B34, 0x1B34, 12),
+    (0x1B35, 0x1B35, 10), (0x1B36, 0x1B3A, 12), (0x1B3B, 0x1B3B, 10), (0x1B3C, 0x1B3C, 12),
+    (0x1B3D, 0x1B41, 10), (0x1B42, 0x1B42, 12), (0x1B43, 0x1B44, 10), (0x1B45, 0x1B4C, 7),
+    (0x1B4D, 0x1B4D, 2), (0x1B4E, 0x1B4F, 21), (0x1B50, 0x1B59, 13), (0x1B5A, 0x1B60, 21),
+    (0x1B61, 0x1B6A, 26), (0x1B6B, 0x1B73, 12), (0x1B74, 0x1B7C, 26), (0x1B7D, 0x1B7F, 21),
+    (0x1B80, 0x1B81, 12), (0x1B82, 0x1B82, 10), (0x1B83, 0x1BA0, 7), (0x1BA1, 0x1BA1, 10),
+    (0x1BA2, 0x1BA5, 12), (0x1BA6, 0x1BA7, 10), (0x1BA8, 0x1BA9, 12), (0x1BAA, 0x1BAA, 10),
+    (0x1BAB, 0x1BAD, 12), (0x1BAE, 0x1BAF, 7), (0x1BB0, 0x1BB9, 13), (0x1BBA, 0x1BE5, 7),
+    (0x1BE6, 0x1BE6, 12), (0x1BE7, 0x1BE7, 10), (0x1BE8, 0x1BE9, 12), (0x1BEA, 0x1BEC, 10),
+    (0x1BED, 0x1BED, 12), (0x1BEE, 0x1BEE, 10), (0x1BEF, 0x1BF1, 12), (0x1BF2, 0x1BF3, 10),
+    (0x1BF4, 0x1BFB, 2), (0x1BFC, 0x1BFF, 21), (0x1C00, 0x1C23, 7), (0x1C24, 0x1C2B, 10),
+    (0x1C2C, 0x1C33, 12), (0x1C34, 0x1C35, 10), (0x1C36, 0x1C37, 12), (0x1C38, 0x1C3A, 2),
+    (0x1C3B, 0x1C3F, 21), (0x1C40, 0x1C49, 13), (0x1C4A, 0x1C4C, 2), (0x1C4D, 0x1C4F, 7),
+    (0x1C50, 0x1C59, 13), (0x1C5A, 0x1C77, 7), (0x1C78, 0x1C7D, 6), (0x1C7E, 0x1C7F, 21),
+    (0x1C80, 0x1C88, 5), (0x1C89, 0x1C89, 9), (0x1C8A, 0x1C8A, 5), (0x1C8B, 0x1C8F, 2),
+    (0x1C90, 0x1CBA, 9), (0x1CBB, 0x1CBC, 2), (0x1CBD, 0x1CBF, 9), (0x1CC0, 0x1CC7, 21),
+    (0x1CC8, 0x1CCF, 2), (0x1CD0, 0x1CD2, 12), (0x1CD3, 0x1CD3, 21), (0x1CD4, 0x1CE0, 12),
+    (0x1CE1, 0x1CE1, 10), (0x1CE2, 0x1CE8, 12), (0x1CE9, 0x1CEC, 7), (0x1CED, 0x1CED, 12),
+    (0x1CEE, 0x1CF3, 7), (0x1CF4, 0x1CF4, 12), (0x1CF5, 0x1CF6, 7), (0x1CF7, 0x1CF7, 10),
+    (0x1CF8, 0x1CF9, 12), (0x1CFA, 0x1CFA, 7), (0x1CFB, 0x1CFF, 2), (0x1D00, 0x1D2B, 5),
+    (0x1D2C, 0x1D6A, 6), (0x1D6B, 0x1D77, 5), (0x1D78, 0x1D78, 6), (0x1D79, 0x1D9A, 5),
+    (0x1D9B, 0x1DBF, 6), (0x1DC0, 0x1DFF, 12), (0x1E00, 0x1E00, 9), (0x1E01, 0x1E01, 5),
+    (0x1E02, 0x1E02, 9), (0x1E03, 0x1E03, 5), (0x1E04, 0x1E04, 9), (0x1E05, 0x1E05, 5),
+    (0x1E06, 0x1E06, 9), (0x1E07, 0x1E07, 5), (0x1E08, 0x1E08, 9), (0x1E09, 0x1E09, 5),
+    (0x1E0A, 0x1E0A, 9), (0x1E0B, 0x1E0B, 5), (0x1E0C, 0x1E0C, 9), (0x1E0D, 0x1E0D, 5),
+    (0x1E0E, 0x1E0E, 9), (0x1E0F, 0x1E0F, 5), (0x1E10, 0x1E10, 9), (0x1E11, 0x1E11, 5),
+    (0x1E12, 0x1E12, 9), (0x1E13, 0x1E13, 5), (0x1E14, 0x1E14, 9), (0x1E15, 0x1E15, 5),
+    (0x1E16, 0x1E16, 9), (0x1E17, 0x1E17, 5), (0x1E18, 0x1E18, 9), (0x1E19, 0x1E19, 5),
+    (0x1E1A, 0x1E1A, 9), (0x1E1B, 0x1E1B, 5), (0x1E1C, 0x1E1C, 9), (0x1E1D, 0x1E1D, 5),
+    (0x1E1E, 0x1E1E, 9), (0x1E1F, 0x1E1F, 5), (0x1E20, 0x1E20, 9), (0x1E21, 0x1E21, 5),
+    (0x1E22, 0x1E22, 9), (0x1E23, 0x1E23, 5), (0x1E24, 0x1E24, 9), (0x1E25, 0x1E25, 5),
+    (0x1E26, 0x1E26, 9), (0x1E27, 0x1E27, 5), (0x1E28, 0x1E28, 9), (0x1E29, 0x1E29, 5),
+    (0x1E2A, 0x1E2A, 9), (0x1E2B, 0x1E2B, 5), (0x1E2C, 0x1E2C, 9), (0x1E2D, 0x1E2D, 5),
+    (0x1E2E, 0x1E2E, 9), (0x1E2F, 0x1E2F, 5), (0x1E30, 0x1E30, 9), (0x1E31, 0x1E31, 5),
+    (0x1E32, 0x1E32, 9), (0x1E33, 0x1E33, 5), (0x1E34, 0x1E34, 9), (0x1E35, 0x1E35, 5),
+    (0x1E36, 0x1E36, 9), (0x1E37, 0x1E37, 5), (0x1E38, 0x1E38, 9), (0x1E39, 0x1E39, 5),
+    (0x1E3A, 0x1E3A, 9), (0x1E3B, 0x1E3B, 5), (0x1E3C, 0x1E3C, 9), (0x1E3D, 0x1E3D, 5),
+    (0x1E3E, 0x1E3E, 9), (0x1E3F, 0x1E3F, 5), (0x1E40, 0x1E40, 9), (0x1E41, 0x1E41, 5),
+    (0x1E42, 0x1E42, 9), (0x1E43, 0x1E43, 5), (0x1E44, 0x1E44, 9), (0x1E45, 0x1E45, 5),
+    (0x1E46, 0x1E46, 9), (0x1E47, 0x1E47, 5), (0x1E48, 0x1E48, 9), (0x1E49, 0x1E49, 5),
+    (0x1E4A, 0x1E4A, 9), (0x1E4B, 0x1E4B, 5), (0x1E4C, 0x1E4C, 9), (0x1E4D, 0x1E4D, 5),
+    (0x1E4E, 0x1E4E, 9), (0x1E4F, 0x1E4F, 5), (0x1E50, 0x1E50, 9), (0x1E51, 0x1E51, 5),
+    (0x1E52, 0x1E52, 9), (0x1E53, 0x1E53, 5), (0x1E54, 0x1E54, 9), (0x1E55, 0x1E55, 5),
+    (0x1E56, 0x1E56, 9), (0x1E57, 0x1E57, 5), (0x1E58, 0x1E58, 9), (0x1E59, 0x1E59, 5),
+    (0x1E5A, 0x1E5A, 9), (0x1E5B, 0x1E5B, 5), (0x1E5C, 0x1E5C, 9), (0x1E5D, 0x1E5D, 5),
+    (0x1E5E, 0x1E5E, 9), (0x1E5F, 0x1E5F, 5), (0x1E60, 0x1E60, 9), (0x1E61, 0x1E61, 5),
+    (0x1E62, 0x1E62, 9), (0x1E63, 0x1E63, 5), (0x1E64, 0x1E64, 9), (0x1E65, 0x1E65, 5),
+    (0x1E66, 0x1E66, 9), (0x1E67, 0x1E67, 5), (0x1E68, 0x1E68, 9), (0x1E69, 0x1E69, 5),
+    (0x1E6A, 0x1E6A, 9), (0x1E6B, 0x1E6B, 5), (0x1E6C, 0x1E6C, 9), (0x1E6D, 0x1E6D, 5),
+    (0x1E6E, 0x1E6E, 9), (0x1E6F, 0x1E6F, 5), (0x1E70, 0x1E70, 9), (0x1E71, 0x1E71, 5),
+    (0x1E72, 0x1E72, 9), (0x1E73, 0x1E73, 5), (0x1E74, 0x1E74, 9), (0x1E75, 0x1E75, 5),
+    (0x1E76, 0x1E76, 9), (0x1E77, 0x1E77, 5), (0x1E78, 0x1E78, 9), (0x1E79, 0x1E79, 5),
+    (0x1E7A, 0x1E7A, 9), (0x1E7B, 0x1E7B, 5), (0x1E7C, 0x1E7C, 9), (0x1E7D, 0x1E7D, 5),
+    (0x1E7E, 0x1E7E, 9), (0x1E7F, 0x1E7F, 5), (0x1E80, 0x1E80, 9), (0x1E81, 0x1E81, 5),
+    (0x1E82, 0x1E82, 9), (0x1E83, 0x1E83, 5), (0x1E84, 0x1E84, 9), (0x1E85, 0x1E85, 5),
+    (0x1E86, 0x1E86, 9), (0x1E87, 0x1E87, 5), (0x1E88, 0x1E88, 9), (0x1E89, 0x1E89, 5),
+    (0x1E8A, 0x1E8A, 9), (0x1E8B, 0x1E8B, 5), (0x1E8C, 0x1E8C, 9), (0x1E8D, 0x1E8D, 5),
+    (0x1E8E, 0x1E8E, 9), (0x1E8F, 0x1E8F, 5), (0x1E90, 0x1E90, 9), (0x1E91, 0x1E91, 5),
+    (0x1E92, 0x1E92, 9), (0x1E93, 0x1E93, 5), (0x1E94, 0x1E94, 9), (0x1E95, 0x1E9D, 5),
+    (0x1E9E, 0x1E9E, 9), (0x1E9F, 0x1E9F, 5), (0x1EA0, 0x1EA0, 9), (0x1EA1, 0x1EA1, 5),
+    (0x1EA2, 0x1EA2, 9), (0x1EA3, 0x1EA3, 5), (0x1EA4, 0x1EA4, 9), (0x1EA5, 0x1EA5, 5),
+    (0x1EA6, 0x1EA6, 9), (0x1EA7, 0x1EA7, 5), (0x1EA8, 0x1EA8, 9), (0x1EA9, 0x1EA9, 5),
+    (0x1EAA, 0x1EAA, 9), (0x1EAB, 0x1EAB, 5), (0x1EAC, 0x1EAC, 9), (0x1EAD, 0x1EAD, 5),
+    (0x1EAE, 0x1EAE, 9), (0x1EAF, 0x1EAF, 5), (0x1EB0, 0x1EB0, 9), (0x1EB1, 0x1EB1, 5),
+    (0x1EB2, 0x1EB2, 9), (0x1EB3, 0x1EB3, 5), (0x1EB4, 0x1EB4, 9), (0x1EB5, 0x1EB5, 5),
+    (0x1EB6, 0x1EB6, 9), (0x1EB7, 0x1EB7, 5), (0x1EB8, 0x1EB8, 9), (0x1EB9, 0x1EB9, 5),
+    (0x1EBA, 0x1EBA, 9), (0x1EBB, 0x1EBB, 5), (0x1EBC, 0x1EBC, 9), (0x1EBD, 0x1EBD, 5),
+    (0x1EBE, 0x1EBE, 9), (0x1EBF, 0x1EBF, 5), (0x1EC0, 0x1EC0, 9), (0x1EC1, 0x1EC1, 5),
+    (0x1EC2, 0x1EC2, 9), (0x1EC3, 0x1EC3, 5), (0x1EC4, 0x1EC4, 9), (0x1EC5, 0x1EC5, 5),
+    (0x1EC6, 0x1EC6, 9), (0x1EC7, 0x1EC7, 5), (0x1EC8, 0x1EC8, 9), (0x1EC9, 0x1EC9, 5),
+    (0x1ECA, 0x1ECA, 9), (0x1ECB, 0x1ECB, 5), (0x1ECC, 0x1ECC, 9), (0x1ECD, 0x1ECD, 5),
+    (0x1ECE, 0x1ECE, 9), (0x1ECF, 0x1ECF, 5), (0x1ED0, 0x1ED0, 9), (0x1ED1, 0x1ED1, 5),
+    (0x1ED2, 0x1ED2, 9), (0x1ED3, 0x1ED3, 5), (0x1ED4, 0x1ED4, 9), (0x1ED5, 0x1ED5, 5),
+    (0x1ED6, 0x1ED6, 9), (0x1ED7, 0x1ED7, 5), (0x1ED8, 0x1ED8, 9), (0x1ED9, 0x1ED9, 5),
+    (0x1EDA, 0x1EDA, 9), (0x1EDB, 0x1EDB, 5), (0x1EDC, 0x1EDC, 9), (0x1EDD, 0x1EDD, 5),
+    (0x1EDE, 0x1EDE, 9), (0x1EDF, 0x1EDF, 5), (0x1EE0, 0x1EE0, 9), (0x1EE1, 0x1EE1, 5),
+    (0x1EE2, 0x1EE2, 9), (0x1EE3, 0x1EE3, 5), (0x1EE4, 0x1EE4, 9), (0x1EE5, 0x1EE5, 5),
+    (0x1EE6, 0x1EE6, 9), (0x1EE7, 0x1EE7, 5), (0x1EE8, 0x1EE8, 9), (0x1EE9, 0x1EE9, 5),
+    (0x1EEA, 0x1EEA, 9), (0x1EEB, 0x1EEB, 5), (0x1EEC, 0x1EEC, 9), (0x1EED, 0x1EED, 5),
+    (0x1EEE, 0x1EEE, 9), (0x1EEF, 0x1EEF, 5), (0x1EF0, 0x1EF0, 9), (0x1EF1, 0x1EF1, 5),
+    (0x1EF2, 0x1EF2, 9), (0x1EF3, 0x1EF3, 5), (0x1EF4, 0x1EF4, 9), (0x1EF5, 0x1EF5, 5),
+    (0x1EF6, 0x1EF6, 9), (0x1EF7, 0x1EF7, 5), (0x1EF8, 0x1EF8, 9), (0x1EF9, 0x1EF9, 5),
+    (0x1EFA, 0x1EFA, 9), (0x1EFB, 0x1EFB, 5), (0x1EFC, 0x1EFC, 9), (0x1EFD, 0x1EFD, 5),
+    (0x1EFE, 0x1EFE, 9), (0x1EFF, 0x1F07, 5), (0x1F08, 0x1F0F, 9), (0x1F10, 0x1F15, 5),
+    (0x1F16, 0x1F17, 2), (0x1F18, 0x1F1D, 9), (0x1F1E, 0x1F1F, 2), (0x1F20, 0x1F27, 5),
+    (0x1F28, 0x1F2F, 9), (0x1F30, 0x1F37, 5), (0x1F38, 0x1F3F, 9), (0x1F40, 0x1F45, 5),
+    (0x1F46, 0x1F47, 2), (0x1F48, 0x1F4D, 9), (0x1F4E, 0x1F4F, 2), (0x1F50, 0x1F57, 5),
+    (0x1F58, 0x1F58, 2), (0x1F59, 0x1F59, 9), (0x1F5A, 0x1F5A, 2), (0x1F5B, 0x1F5B, 9),
+    (0x1F5C, 0x1F5C, 2), (0x1F5D, 0x1F5D, 9), (0x1F5E, 0x1F5E, 2), (0x1F5F, 0x1F5F, 9),
+    (0x1F60, 0x1F67, 5), (0x1F68, 0x1F6F, 9), (0x1F70, 0x1F7D, 5), (0x1F7E, 0x1F7F, 2),
+    (0x1F80, 0x1F87, 5), (0x1F88, 0x1F8F, 8), (0x1F90, 0x1F97, 5), (0x1F98, 0x1F9F, 8),
+    (0x1FA0, 0x1FA7, 5), (0x1FA8, 0x1FAF, 8), (0x1FB0, 0x1FB4, 5), (0x1FB5, 0x1FB5, 2),
+    (0x1FB6, 0x1FB7, 5), (0x1FB8, 0x1FBB, 9), (0x1FBC, 0x1FBC, 8), (0x1FBD, 0x1FBD, 24),
+    (0x1FBE, 0x1FBE, 5), (0x1FBF, 0x1FC1, 24), (0x1FC2, 0x1FC4, 5), (0x1FC5, 0x1FC5, 2),
+    (0x1FC6, 0x1FC7, 5), (0x1FC8, 0x1FCB, 9), (0x1FCC, 0x1FCC, 8), (0x1FCD, 0x1FCF, 24),
+    (0x1FD0, 0x1FD3, 5), (0x1FD4, 0x1FD5, 2), (0x1FD6, 0x1FD7, 5), (0x1FD8, 0x1FDB, 9),
+    (0x1FDC, 0x1FDC, 2), (0x1FDD, 0x1FDF, 24), (0x1FE0, 0x1FE7, 5), (0x1FE8, 0x1FEC, 9),
+    (0x1FED, 0x1FEF, 24), (0x1FF0, 0x1FF1, 2), (0x1FF2, 0x1FF4, 5), (0x1FF5, 0x1FF5, 2),
+    (0x1FF6, 0x1FF7, 5), (0x1FF8, 0x1FFB, 9), (0x1FFC, 0x1FFC, 8), (0x1FFD, 0x1FFE, 24),
+    (0x1FFF, 0x1FFF, 2), (0x2000, 0x200A, 29), (0x200B, 0x200F, 1), (0x2010, 0x2015, 17),
+    (0x2016, 0x2017, 21), (0x2018, 0x2018, 20), (0x2019, 0x2019, 19), (0x201A, 0x201A, 22),
+    (0x201B, 0x201C, 20), (0x201D, 0x201D, 19), (0x201E, 0x201E, 22), (0x201F, 0x201F, 20),
+    (0x2020, 0x2027, 21), (0x2028, 0x2028, 27), (0x2029, 0x2029, 28), (0x202A, 0x202E, 1),
+    (0x202F, 0x202F, 29), (0x2030, 0x2038, 21), (0x2039, 0x2039, 20), (0x203A, 0x203A, 19),
+    (0x203B, 0x203E, 21), (0x203F, 0x2040, 16), (0x2041, 0x2043, 21), (0x2044, 0x2044, 25),
+    (0x2045, 0x2045, 22), (0x2046, 0x2046, 18), (0x2047, 0x2051, 21), (0x2052, 0x2052, 25),
+    (0x2053, 0x2053, 21), (0x2054, 0x2054, 16), (0x2055, 0x205E, 21), (0x205F, 0x205F, 29),
+    (0x2060, 0x2064, 1), (0x2065, 0x2065, 2), (0x2066, 0x206F, 1), (0x2070, 0x2070, 15),
+    (0x2071, 0x2071, 6), (0x2072, 0x2073, 2), (0x2074, 0x2079, 15), (0x207A, 0x207C, 25),
+    (0x207D, 0x207D, 22), (0x207E, 0x207E, 18), (0x207F, 0x207F, 6), (0x2080, 0x2089, 15),
+    (0x208A, 0x208C, 25), (0x208D, 0x208D, 22), (0x208E, 0x208E, 18), (0x208F, 0x208F, 2),
+    (0x2090, 0x209C, 6), (0x209D, 0x209F, 2), (0x20A0, 0x20C1, 23), (0x20C2, 0x20CF, 2),
+    (0x20D0, 0x20DC, 12), (0x20DD, 0x20E0, 11), (0x20E1, 0x20E1, 12), (0x20E2, 0x20E4, 11),
+    (0x20E5, 0x20F0, 12), (0x20F1, 0x20FF, 2), (0x2100, 0x2101, 26), (0x2102, 0x2102, 9),
+    (0x2103, 0x2106, 26), (0x2107, 0x2107, 9), (0x2108, 0x2109, 26), (0x210A, 0x210A, 5),
+    (0x210B, 0x210D, 9), (0x210E, 0x210F, 5), (0x2110, 0x2112, 9), (0x2113, 0x2113, 5),
+    (0x2114, 0x2114, 26), (0x2115, 0x2115, 9), (0x2116, 0x2117, 26), (0x2118, 0x2118, 25),
+    (0x2119, 0x211D, 9), (0x211E, 0x2123, 26), (0x2124, 0x2124, 9), (0x2125, 0x2125, 26),
+    (0x2126, 0x2126, 9), (0x2127, 0x2127, 26), (0x2128, 0x2128, 9), (0x2129, 0x2129, 26),
+    (0x212A, 0x212D, 9), (0x212E, 0x212E, 26), (0x212F, 0x212F, 5), (0x2130, 0x2133, 9),
+    (0x2134, 0x2134, 5), (0x2135, 0x2138, 7), (0x2139, 0x2139, 5), (0x213A, 0x213B, 26),
+    (0x213C, 0x213D, 5), (0x213E, 0x213F, 9), (0x2140, 0x2144, 25), (0x2145, 0x2145, 9),
+    (0x2146, 0x2149, 5), (0x214A, 0x214A, 26), (0x214B, 0x214B, 25), (0x214C, 0x214D, 26),
+    (0x214E, 0x214E, 5), (0x214F, 0x214F, 26), (0x2150, 0x215F, 15), (0x2160, 0x2182, 14),
+    (0x2183, 0x2183, 9), (0x2184, 0x2184, 5), (0x2185, 0x2188, 14), (0x2189, 0x2189, 15),
+    (0x218A, 0x218B, 26), (0x218C, 0x218F, 2), (0x2190, 0x2194, 25), (0x2195, 0x2199, 26),
+    (0x219A, 0x219B, 25), (0x219C, 0x219F, 26), (0x21A0, 0x21A0, 25), (0x21A1, 0x21A2, 26),
+    (0x21A3, 0x21A3, 25), (0x21A4, 0x21A5, 26), (0x21A6, 0x21A6, 25), (0x21A7, 0x21AD, 26),
+    (0x21AE, 0x21AE, 25), (0x21AF, 0x21CD, 26), (0x21CE, 0x21CF, 25), (0x21D0, 0x21D1, 26),
+    (0x21D2, 0x21D2, 25), (0x21D3, 0x21D3, 26), (0x21D4, 0x21D4, 25), (0x21D5, 0x21F3, 26),
+    (0x21F4, 0x22FF, 25), (0x2300, 0x2307, 26), (0x2308, 0x2308, 22), (0x2309, 0x2309, 18),
+    (0x230A, 0x230A, 22), (0x230B, 0x230B, 18), (0x230C, 0x231F, 26), (0x2320, 0x2321, 25),
+    (0x2322, 0x2328, 26), (0x2329, 0x2329, 22), (0x232A, 0x232A, 18), (0x232B, 0x237B, 26),
+    (0x237C, 0x237C, 25), (0x237D, 0x239A, 26), (0x239B, 0x23B3, 25), (0x23B4, 0x23DB, 26),
+    (0x23DC, 0x23E1, 25), (0x23E2, 0x2429, 26), (0x242A, 0x243F, 2), (0x2440, 0x244A, 26),
+    (0x244B, 0x245F, 2), (0x2460, 0x249B, 15), (0x249C, 0x24E9, 26), (0x24EA, 0x24FF, 15),
+    (0x2500, 0x25B6, 26), (0x25B7, 0x25B7, 25), (0x25B8, 0x25C0, 26), (0x25C1, 0x25C1, 25),
+    (0x25C2, 0x25F7, 26), (0x25F8, 0x25FF, 25), (0x2600, 0x266E, 26), (0x266F, 0x266F, 25),
+    (0x2670, 0x2767, 26), (0x2768, 0x2768, 22), (0x2769, 0x2769, 18), (0x276A, 0x276A, 22),
+    (0x276B, 0x276B, 18), (0x276C, 0x276C, 22), (0x276D, 0x276D, 18), (0x276E, 0x276E, 22),
+    (0x276F, 0x276F, 18), (0x2770, 0x2770, 22), (0x2771, 0x2771, 18), (0x2772, 0x2772, 22),
+    (0x2773, 0x2773, 18), (0x2774, 0x2774, 22), (0x2775, 0x2775, 18), (0x2776, 0x2793, 15),
+    (0x2794, 0x27BF, 26), (0x27C0, 0x27C4, 25), (0x27C5, 0x27C5, 22), (0x27C6, 0x27C6, 18),
+    (0x27C7, 0x27E5, 25), (0x27E6, 0x27E6, 22), (0x27E7, 0x27E7, 18), (0x27E8, 0x27E8, 22),
+    (0x27E9, 0x27E9, 18), (0x27EA, 0x27EA, 22), (0x27EB, 0x27EB, 18), (0x27EC, 0x27EC, 22),
+    (0x27ED, 0x27ED, 18), (0x27EE, 0x27EE, 22), (0x27EF, 0x27EF, 18), (0x27F0, 0x27FF, 25),
+    (0x2800, 0x28FF, 26), (0x2900, 0x2982, 25), (0x2983, 0x2983, 22), (0x2984, 0x2984, 18),
+    (0x2985, 0x2985, 22), (0x2986, 0x2986, 18), (0x2987, 0x2987, 22), (0x2988, 0x2988, 18),
+    (0x2989, 0x2989, 22), (0x298A, 0x298A, 18), (0x298B, 0x298B, 22), (0x298C, 0x298C, 18),
+    (0x298D, 0x298D, 22), (0x298E, 0x298E, 18), (0x298F, 0x298F, 22), (0x2990, 0x2990, 18),
+    (0x2991, 0x2991, 22), (0x2992, 0x2992, 18), (0x2993, 0x2993, 22), (0x2994, 0x2994, 18),
+    (0x2995, 0x2995, 22), (0x2996, 0x2996, 18), (0x2997, 0x2997, 22), (0x2998, 0x2998, 18),
+    (0x2999, 0x29D7, 25), (0x29D8, 0x29D8, 22), (0x29D9, 0x29D9, 18), (0x29DA, 0x29DA, 22),
+    (0x29DB, 0x29DB, 18), (0x29DC, 0x29FB, 25), (0x29FC, 0x29FC, 22), (0x29FD, 0x29FD, 18),
+    (0x29FE, 0x2AFF, 25), (0x2B00, 0x2B2F, 26), (0x2B30, 0x2B44, 25), (0x2B45, 0x2B46, 26),
+    (0x2B47, 0x2B4C, 25), (0x2B4D, 0x2B73, 26), (0x2B74, 0x2B75, 2), (0x2B76, 0x2BFF, 26),
+    (0x2C00, 0x2C2F, 9), (0x2C30, 0x2C5F, 5), (0x2C60, 0x2C60, 9), (0x2C61, 0x2C61, 5),
+    (0x2C62, 0x2C64, 9), (0x2C65, 0x2C66, 5), (0x2C67, 0x2C67, 9), (0x2C68, 0x2C68, 5),
+    (0x2C69, 0x2C69, 9), (0x2C6A, 0x2C6A, 5), (0x2C6B, 0x2C6B, 9), (0x2C6C, 0x2C6C, 5),
+    (0x2C6D, 0x2C70, 9), (0x2C71, 0x2C71, 5), (0x2C72, 0x2C72, 9), (0x2C73, 0x2C74, 5),
+    (0x2C75, 0x2C75, 9), (0x2C76, 0x2C7B, 5), (0x2C7C, 0x2C7D, 6), (0x2C7E, 0x2C80, 9),
+    (0x2C81, 0x2C81, 5), (0x2C82, 0x2C82, 9), (0x2C83, 0x2C83, 5), (0x2C84, 0x2C84, 9),
+    (0x2C85, 0x2C85, 5), (0x2C86, 0x2C86, 9), (0x2C87, 0x2C87, 5), (0x2C88, 0x2C88, 9),
+    (0x2C89, 0x2C89, 5), (0x2C8A, 0x2C8A, 9), (0x2C8B, 0x2C8B, 5), (0x2C8C, 0x2C8C, 9),
+    (0x2C8D, 0x2C8D, 5), (0x2C8E, 0x2C8E, 9), (0x2C8F, 0x2C8F, 5), (0x2C90, 0x2C90, 9),
+    (0x2C91, 0x2C91, 5), (0x2C92, 0x2C92, 9), (0x2C93, 0x2C93, 5), (0x2C94, 0x2C94, 9),
+    (0x2C95, 0x2C95, 5), (0x2C96, 0x2C96, 9), (0x2C97, 0x2C97, 5), (0x2C98, 0x2C98, 9),
+    (0x2C99, 0x2C99, 5), (0x2C9A, 0x2C9A, 9), (0x2C9B, 0x2C9B, 5), (0x2C9C, 0x2C9C, 9),
+    (0x2C9D, 0x2C9D, 5), (0x2C9E, 0x2C9E, 9), (0x2C9F, 0x2C9F, 5), (0x2CA0, 0x2CA0, 9),
+    (0x2CA1, 0x2CA1, 5), (0x2CA2, 0x2CA2, 9), (0x2CA3, 0x2CA3, 5), (0x2CA4, 0x2CA4, 9),
+    (0x2CA5, 0x2CA5, 5), (0x2CA6, 0x2CA6, 9), (0x2CA7, 0x2CA7, 5), (0x2CA8, 0x2CA8, 9),
+    (0x2CA9, 0x2CA9, 5), (0x2CAA, 0x2CAA, 9), (0x2CAB, 0x2CAB, 5), (0x2CAC, 0x2CAC, 9),
+    (0x2CAD, 0x2CAD, 5), (0x2CAE, 0x2CAE, 9), (0x2CAF, 0x2CAF, 5), (0x2CB0, 0x2CB0, 9),
+    (0x2CB1, 0x2CB1, 5), (0x2CB2, 0x2CB2, 9), (0x2CB3, 0x2CB3, 5), (0x2CB4, 0x2CB4, 9),
+    (0x2CB5, 0x2CB5, 5), (0x2CB6, 0x2CB6, 9), (0x2CB7, 0x2CB7, 5), (0x2CB8, 0x2CB8, 9),
+    (0x2CB9, 0x2CB9, 5), (0x2CBA, 0x2CBA, 9), (0x2CBB, 0x2CBB, 5), (0x2CBC, 0x2CBC, 9),
+    (0x2CBD, 0x2CBD, 5), (0x2CBE, 0x2CBE, 9), (0x2CBF, 0x2CBF, 5), (0x2CC0, 0x2CC0, 9),
+    (0x2CC1, 0x2CC1, 5), (0x2CC2, 0x2CC2, 9), (0x2CC3, 0x2CC3, 5), (0x2CC4, 0x2CC4, 9),
+    (0x2CC5, 0x2CC5, 5), (0x2CC6, 0x2CC6, 9), (0x2CC7, 0x2CC7, 5), (0x2CC8, 0x2CC8, 9),
+    (0x2CC9, 0x2CC9, 5), (0x2CCA, 0x2CCA, 9), (0x2CCB, 0x2CCB, 5), (0x2CCC, 0x2CCC, 9),
+    (0x2CCD, 0x2CCD, 5), (0x2CCE, 0x2CCE, 9), (0x2CCF, 0x2CCF, 5), (0x2CD0, 0x2CD0, 9),
+    (0x2CD1, 0x2CD1, 5), (0x2CD2, 0x2CD2, 9), (0x2CD3, 0x2CD3, 5), (0x2CD4, 0x2CD4, 9),
+    (0x2CD5, 0x2CD5, 5), (0x2CD6, 0x2CD6, 9), (0x2CD7, 0x2CD7, 5), (0x2CD8, 0x2CD8, 9),
+    (0x2CD9, 0x2CD9, 5), (0x2CDA, 0x2CDA, 9), (0x2CDB, 0x2CDB, 5), (0x2CDC, 0x2CDC, 9),
+    (0x2CDD, 0x2CDD, 5), (0x2CDE, 0x2CDE, 9), (0x2CDF, 0x2CDF, 5), (0x2CE0, 0x2CE0, 9),
+    (0x2CE1, 0x2CE1, 5), (0x2CE2, 0x2CE2, 9), (0x2CE3, 0x2CE4, 5), (0x2CE5, 0x2CEA, 26),
+    (0x2CEB, 0x2CEB, 9), (0x2CEC, 0x2CEC, 5), (0x2CED, 0x2CED, 9), (0x2CEE, 0x2CEE, 5),
+    (0x2CEF, 0x2CF1, 12), (0x2CF2, 0x2CF2, 9), (0x2CF3, 0x2CF3, 5), (0x2CF4, 0x2CF8, 2),
+    (0x2CF9, 0x2CFC, 21), (0x2CFD, 0x2CFD, 15), (0x2CFE, 0x2CFF, 21), (0x2D00, 0x2D25, 5),
+    (0x2D26, 0x2D26, 2), (0x2D27, 0x2D27, 5), (0x2D28, 0x2D2C, 2), (0x2D2D, 0x2D2D, 5),
+    (0x2D2E, 0x2D2F, 2), (0x2D30, 0x2D67, 7), (0x2D68, 0x2D6E, 2), (0x2D6F, 0x2D6F, 6),
+    (0x2D70, 0x2D70, 21), (0x2D71, 0x2D7E, 2), (0x2D7F, 0x2D7F, 12), (0x2D80, 0x2D96, 7),
+    (0x2D97, 0x2D9F, 2), (0x2DA0, 0x2DA6, 7), (0x2DA7, 0x2DA7, 2), (0x2DA8, 0x2DAE, 7),
+    (0x2DAF, 0x2DAF, 2), (0x2DB0, 0x2DB6, 7), (0x2DB7, 0x2DB7, 2), (0x2DB8, 0x2DBE, 7),
+    (0x2DBF, 0x2DBF, 2), (0x2DC0, 0x2DC6, 7), (0x2DC7, 0x2DC7, 2), (0x2DC8, 0x2DCE, 7),
+    (0x2DCF, 0x2DCF, 2), (0x2DD0, 0x2DD6, 7), (0x2DD7, 0x2DD7, 2), (0x2DD8, 0x2DDE, 7),
+    (0x2DDF, 0x2DDF, 2), (0x2DE0, 0x2DFF, 12), (0x2E00, 0x2E01, 21), (0x2E02, 0x2E02, 20),
+    (0x2E03, 0x2E03, 19), (0x2E04, 0x2E04, 20), (0x2E05, 0x2E05, 19), (0x2E06, 0x2E08, 21),
+    (0x2E09, 0x2E09, 20), (0x2E0A, 0x2E0A, 19), (0x2E0B, 0x2E0B, 21), (0x2E0C, 0x2E0C, 20),
+    (0x2E0D, 0x2E0D, 19), (0x2E0E, 0x2E16, 21), (0x2E17, 0x2E17, 17), (0x2E18, 0x2E19, 21),
+    (0x2E1A, 0x2E1A, 17), (0x2E1B, 0x2E1B, 21), (0x2E1C, 0x2E1C, 20), (0x2E1D, 0x2E1D, 19),
+    (0x2E1E, 0x2E1F, 21), (0x2E20, 0x2E20, 20), (0x2E21, 0x2E21, 19), (0x2E22, 0x2E22, 22),
+    (0x2E23, 0x2E23, 18), (0x2E24, 0x2E24, 22), (0x2E25, 0x2E25, 18), (0x2E26, 0x2E26, 22),
+    (0x2E27, 0x2E27, 18), (0x2E28, 0x2E28, 22), (0x2E29, 0x2E29, 18), (0x2E2A, 0x2E2E, 21),
+    (0x2E2F, 0x2E2F, 6), (0x2E30, 0x2E39, 21), (0x2E3A, 0x2E3B, 17), (0x2E3C, 0x2E3F, 21),
+    (0x2E40, 0x2E40, 17), (0x2E41, 0x2E41, 21), (0x2E42, 0x2E42, 22), (0x2E43, 0x2E4F, 21),
+    (0x2E50, 0x2E51, 26), (0x2E52, 0x2E54, 21), (0x2E55, 0x2E55, 22), (0x2E56, 0x2E56, 18),
+    (0x2E57, 0x2E57, 22), (0x2E58, 0x2E58, 18), (0x2E59, 0x2E59, 22), (0x2E5A, 0x2E5A, 18),
+    (0x2E5B, 0x2E5B, 22), (0x2E5C, 0x2E5C, 18), (0x2E5D, 0x2E5D, 17), (0x2E5E, 0x2E7F, 2),
+    (0x2E80, 0x2E99, 26), (0x2E9A, 0x2E9A, 2), (0x2E9B, 0x2EF3, 26), (0x2EF4, 0x2EFF, 2),
+    (0x2F00, 0x2FD5, 26), (0x2FD6, 0x2FEF, 2), (0x2FF0, 0x2FFF, 26), (0x3000, 0x3000, 29),
+    (0x3001, 0x3003, 21), (0x3004, 0x3004, 26), (0x3005, 0x3005, 6), (0x3006, 0x3006, 7),
+    (0x3007, 0x3007, 14), (0x3008, 0x3008, 22), (0x3009, 0x3009, 18), (0x300A, 0x300A, 22),
+    (0x300B, 0x300B, 18), (0x300C, 0x300C, 22), (0x300D, 0x300D, 18), (0x300E, 0x300E, 22),
+    (0x300F, 0x300F, 18), (0x3010, 0x3010, 22), (0x3011, 0x3011, 18), (0x3012, 0x3013, 26),
+    (0x3014, 0x3014, 22), (0x3015, 0x3015, 18), (0x3016, 0x3016, 22), (0x3017, 0x3017, 18),
+    (0x3018, 0x3018, 22), (0x3019, 0x3019, 18), (0x301A, 0x301A, 22), (0x301B, 0x301B, 18),
+    (0x301C, 0x301C, 17), (0x301D, 0x301D, 22), (0x301E, 0x301F, 18), (0x3020, 0x3020, 26),
+    (0x3021, 0x3029, 14), (0x302A, 0x302D, 12), (0x302E, 0x302F, 10), (0x3030, 0x3030, 17),
+    (0x3031, 0x3035, 6), (0x3036, 0x3037, 26), (0x3038, 0x303A, 14), (0x303B, 0x303B, 6),
+    (0x303C, 0x303C, 7), (0x303D, 0x303D, 21), (0x303E, 0x303F, 26), (0x3040, 0x3040, 2),
+    (0x3041, 0x3096, 7), (0x3097, 0x3098, 2), (0x3099, 0x309A, 12), (0x309B, 0x309C, 24),
+    (0x309D, 0x309E, 6), (0x309F, 0x309F, 7), (0x30A0, 0x30A0, 17), (0x30A1, 0x30FA, 7),
+    (0x30FB, 0x30FB, 21), (0x30FC, 0x30FE, 6), (0x30FF, 0x30FF, 7), (0x3100, 0x3104, 2),
+    (0x3105, 0x312F, 7), (0x3130, 0x3130, 2), (0x3131, 0x318E, 7), (0x318F, 0x318F, 2),
+    (0x3190, 0x3191, 26), (0x3192, 0x3195, 15), (0x3196, 0x319F, 26), (0x31A0, 0x31BF, 7),
+    (0x31C0, 0x31E5, 26), (0x31E6, 0x31EE, 2), (0x31EF, 0x31EF, 26), (0x31F0, 0x31FF, 7),
+    (0x3200, 0x321E, 26), (0x321F, 0x321F, 2), (0x3220, 0x3229, 15), (0x322A, 0x3247, 26),
+    (0x3248, 0x324F, 15), (0x3250, 0x3250, 26), (0x3251, 0x325F, 15), (0x3260, 0x327F, 26),
+    (0x3280, 0x3289, 15), (0x328A, 0x32B0, 26), (0x32B1, 0x32BF, 15), (0x32C0, 0x33FF, 26),
+    (0x3400, 0x4DBF, 7), (0x4DC0, 0x4DFF, 26), (0x4E00, 0xA014, 7), (0xA015, 0xA015, 6),
+    (0xA016, 0xA48C, 7), (0xA48D, 0xA48F, 2), (0xA490, 0xA4C6, 26), (0xA4C7, 0xA4CF, 2),
+    (0xA4D0, 0xA4F7, 7), (0xA4F8, 0xA4FD, 6), (0xA4FE, 0xA4FF, 21), (0xA500, 0xA60B, 7),
+    (0xA60C, 0xA60C, 6), (0xA60D, 0xA60F, 21), (0xA610, 0xA61F, 7), (0xA620, 0xA629, 13),
+    (0xA62A, 0xA62B, 7), (0xA62C, 0xA63F, 2), (0xA640, 0xA640, 9), (0xA641, 0xA641, 5),
+    (0xA642, 0xA642, 9), (0xA643, 0xA643, 5), (0xA644, 0xA644, 9), (0xA645, 0xA645, 5),
+    (0xA646, 0xA646, 9), (0xA647, 0xA647, 5), (0xA648, 0xA648, 9), (0xA649, 0xA649, 5),
+    (0xA64A, 0xA64A, 9), (0xA64B, 0xA64B, 5), (0xA64C, 0xA64C, 9), (0xA64D, 0xA64D, 5),
+    (0xA64E, 0xA64E, 9), (0xA64F, 0xA64F, 5), (0xA650, 0xA650, 9), (0xA651, 0xA651, 5),
+    (0xA652, 0xA652, 9), (0xA653, 0xA653, 5), (0xA654, 0xA654, 9), (0xA655, 0xA655, 5),
+    (0xA656, 0xA656, 9), (0xA657, 0xA657, 5), (0xA658, 0xA658, 9), (0xA659, 0xA659, 5),
+    (0xA65A, 0xA65A, 9), (0xA65B, 0xA65B, 5), (0xA65C, 0xA65C, 9), (0xA65D, 0xA65D, 5),
+    (0xA65E, 0xA65E, 9), (0xA65F, 0xA65F, 5), (0xA660, 0xA660, 9), (0xA661, 0xA661, 5),
+    (0xA662, 0xA662, 9), (0xA663, 0xA663, 5), (0xA664, 0xA664, 9), (0xA665, 0xA665, 5),
+    (0xA666, 0xA666, 9), (0xA667, 0xA667, 5), (0xA668, 0xA668, 9), (0xA669, 0xA669, 5),
+    (0xA66A, 0xA66A, 9), (0xA66B, 0xA66B, 5), (0xA66C, 0xA66C, 9), (0xA66D, 0xA66D, 5),
+    (0xA66E, 0xA66E, 7), (0xA66F, 0xA66F, 12), (0xA670, 0xA672, 11), (0xA673, 0xA673, 21),
+    (0xA674, 0xA67D, 12), (0xA67E, 0xA67E, 21), (0xA67F, 0xA67F, 6), (0xA680, 0xA680, 9),
+    (0xA681, 0xA681, 5), (0xA682, 0xA682, 9), (0xA683, 0xA683, 5), (0xA684, 0xA684, 9),
+    (0xA685, 0xA685, 5), (0xA686, 0xA686, 9), (0xA687, 0xA687, 5), (0xA688, 0xA688, 9),
+    (0xA689, 0xA689, 5), (0xA68A, 0xA68A, 9), (0xA68B, 0xA68B, 5), (0xA68C, 0xA68C, 9),
+    (0xA68D, 0xA68D, 5), (0xA68E, 0xA68E, 9), (0xA68F, 0xA68F, 5), (0xA690, 0xA690, 9),
+    (0xA691, 0xA691, 5), (0xA692, 0xA692, 9), (0xA693, 0xA693, 5), (0xA694, 0xA694, 9),
+    (0xA695, 0xA695, 5), (0xA696, 0xA696, 9), (0xA697, 0xA697, 5), (0xA698, 0xA698, 9),
+    (0xA699, 0xA699, 5), (0xA69A, 0xA69A, 9), (0xA69B, 0xA69B, 5), (0xA69C, 0xA69D, 6),
+    (0xA69E, 0xA69F, 12), (0xA6A0, 0xA6E5, 7), (0xA6E6, 0xA6EF, 14), (0xA6F0, 0xA6F1, 12),
+    (0xA6F2, 0xA6F7, 21), (0xA6F8, 0xA6FF, 2), (0xA700, 0xA716, 24), (0xA717, 0xA71F, 6),
+    (0xA720, 0xA721, 24), (0xA722, 0xA722, 9), (0xA723, 0xA723, 5), (0xA724, 0xA724, 9),
+    (0xA725, 0xA725, 5), (0xA726, 0xA726, 9), (0xA727, 0xA727, 5), (0xA728, 0xA728, 9),
+    (0xA729, 0xA729, 5), (0xA72A, 0xA72A, 9), (0xA72B, 0xA72B, 5), (0xA72C, 0xA72C, 9),
+    (0xA72D, 0xA72D, 5), (0xA72E, 0xA72E, 9), (0xA72F, 0xA731, 5), (0xA732, 0xA732, 9),
+    (0xA733, 0xA733, 5), (0xA734, 0xA734, 9), (0xA735, 0xA735, 5), (0xA736, 0xA736, 9),
+    (0xA737, 0xA737, 5), (0xA738, 0xA738, 9), (0xA739, 0xA739, 5), (0xA73A, 0xA73A, 9),
+    (0xA73B, 0xA73B, 5), (0xA73C, 0xA73C, 9), (0xA73D, 0xA73D, 5), (0xA73E, 0xA73E, 9),
+    (0xA73F, 0xA73F, 5), (0xA740, 0xA740, 9), (0xA741, 0xA741, 5), (0xA742, 0xA742, 9),
+    (0xA743, 0xA743, 5), (0xA744, 0xA744, 9), (0xA745, 0xA745, 5), (0xA746, 0xA746, 9),
+    (0xA747, 0xA747, 5), (0xA748, 0xA748, 9), (0xA749, 0xA749, 5), (0xA74A, 0xA74A, 9),
+    (0xA74B, 0xA74B, 5), (0xA74C, 0xA74C, 9), (0xA74D, 0xA74D, 5), (0xA74E, 0xA74E, 9),
+    (0xA74F, 0xA74F, 5), (0xA750, 0xA750, 9), (0xA751, 0xA751, 5), (0xA752, 0xA752, 9),
+    (0xA753, 0xA753, 5), (0xA754, 0xA754, 9), (0xA755, 0xA755, 5), (0xA756, 0xA756, 9),
+    (0xA757, 0xA757, 5), (0xA758, 0xA758, 9), (0xA759, 0xA759, 5), (0xA75A, 0xA75A, 9),
+    (0xA75B, 0xA75B, 5), (0xA75C, 0xA75C, 9), (0xA75D, 0xA75D, 5), (0xA75E, 0xA75E, 9),
+    (0xA75F, 0xA75F, 5), (0xA760, 0xA760, 9), (0xA761, 0xA761, 5), (0xA762, 0xA762, 9),
+    (0xA763, 0xA763, 5), (0xA764, 0xA764, 9), (0xA765, 0xA765, 5), (0xA766, 0xA766, 9),
+    (0xA767, 0xA767, 5), (0xA768, 0xA768, 9), (0xA769, 0xA769, 5), (0xA76A, 0xA76A, 9),
+    (0xA76B, 0xA76B, 5), (0xA76C, 0xA76C, 9), (0xA76D, 0xA76D, 5), (0xA76E, 0xA76E, 9),
+    (0xA76F, 0xA76F, 5), (0xA770, 0xA770, 6), (0xA771, 0xA778, 5), (0xA779, 0xA779, 9),
+    (0xA77A, 0xA77A, 5), (0xA77B, 0xA77B, 9), (0xA77C, 0xA77C, 5), (0xA77D, 0xA77E, 9),
+    (0xA77F, 0xA77F, 5), (0xA780, 0xA780, 9), (0xA781, 0xA781, 5), (0xA782, 0xA782, 9),
+    (0xA783, 0xA783, 5), (0xA784, 0xA784, 9), (0xA785, 0xA785, 5), (0xA786, 0xA786, 9),
+    (0xA787, 0xA787, 5), (0xA788, 0xA788, 6), (0xA789, 0xA78A, 24), (0xA78B, 0xA78B, 9),
+    (0xA78C, 0xA78C, 5), (0xA78D, 0xA78D, 9), (0xA78E, 0xA78E, 5), (0xA78F, 0xA78F, 7),
+    (0xA790, 0xA790, 9), (0xA791, 0xA791, 5), (0xA792, 0xA792, 9), (0xA793, 0xA795, 5),
+    (0xA796, 0xA796, 9), (0xA797, 0xA797, 5), (0xA798, 0xA798, 9), (0xA799, 0xA799, 5),
+    (0xA79A, 0xA79A, 9), (0xA79B, 0xA79B, 5), (0xA79C, 0xA79C, 9), (0xA79D, 0xA79D, 5),
+    (0xA79E, 0xA79E, 9), (0xA79F, 0xA79F, 5), (0xA7A0, 0xA7A0, 9), (0xA7A1, 0xA7A1, 5),
+    (0xA7A2, 0xA7A2, 9), (0xA7A3, 0xA7A3, 5), (0xA7A4, 0xA7A4, 9), (0xA7A5, 0xA7A5, 5),
+    (0xA7A6, 0xA7A6, 9), (0xA7A7, 0xA7A7, 5), (0xA7A8, 0xA7A8, 9), (0xA7A9, 0xA7A9, 5),
+    (0xA7AA, 0xA7AE, 9), (0xA7AF, 0xA7AF, 5), (0xA7B0, 0xA7B4, 9), (0xA7B5, 0xA7B5, 5),
+    (0xA7B6, 0xA7B6, 9), (0xA7B7, 0xA7B7, 5), (0xA7B8, 0xA7B8, 9), (0xA7B9, 0xA7B9, 5),
+    (0xA7BA, 0xA7BA, 9), (0xA7BB, 0xA7BB, 5), (0xA7BC, 0xA7BC, 9), (0xA7BD, 0xA7BD, 5),
+    (0xA7BE, 0xA7BE, 9), (0xA7BF, 0xA7BF, 5), (0xA7C0, 0xA7C0, 9), (0xA7C1, 0xA7C1, 5),
+    (0xA7C2, 0xA7C2, 9), (0xA7C3, 0xA7C3, 5), (0xA7C4, 0xA7C7, 9), (0xA7C8, 0xA7C8, 5),
+    (0xA7C9, 0xA7C9, 9), (0xA7CA, 0xA7CA, 5), (0xA7CB, 0xA7CC, 9), (0xA7CD, 0xA7CD, 5),
+    (0xA7CE, 0xA7CE, 9), (0xA7CF, 0xA7CF, 5), (0xA7D0, 0xA7D0, 9), (0xA7D1, 0xA7D1, 5),
+    (0xA7D2, 0xA7D2, 9), (0xA7D3, 0xA7D3, 5), (0xA7D4, 0xA7D4, 9), (0xA7D5, 0xA7D5, 5),
+    (0xA7D6, 0xA7D6, 9), (0xA7D7, 0xA7D7, 5), (0xA7D8, 0xA7D8, 9), (0xA7D9, 0xA7D9, 5),
+    (0xA7DA, 0xA7DA, 9), (0xA7DB, 0xA7DB, 5), (0xA7DC, 0xA7DC, 9), (0xA7DD, 0xA7F0, 2),
+    (0xA7F1, 0xA7F4, 6), (0xA7F5, 0xA7F5, 9), (0xA7F6, 0xA7F6, 5), (0xA7F7, 0xA7F7, 7),
+    (0xA7F8, 0xA7F9, 6), (0xA7FA, 0xA7FA, 5), (0xA7FB, 0xA801, 7), (0xA802, 0xA802, 12),
+    (0xA803, 0xA805, 7), (0xA806, 0xA806, 12), (0xA807, 0xA80A, 7), (0xA80B, 0xA80B, 12),
+    (0xA80C, 0xA822, 7), (0xA823, 0xA824, 10), (0xA825, 0xA826, 12), (0xA827, 0xA827, 10),
+    (0xA828, 0xA82B, 26), (0xA82C, 0xA82C, 12), (0xA82D, 0xA82F, 2), (0xA830, 0xA835, 15),
+    (0xA836, 0xA837, 26), (0xA838, 0xA838, 23), (0xA839, 0xA839, 26), (0xA83A, 0xA83F, 2),
+    (0xA840, 0xA873, 7), (0xA874, 0xA877, 21), (0xA878, 0xA87F, 2), (0xA880, 0xA881, 10),
+    (0xA882, 0xA8B3, 7), (0xA8B4, 0xA8C3, 10), (0xA8C4, 0xA8C5, 12), (0xA8C6, 0xA8CD, 2),
+    (0xA8CE, 0xA8CF, 21), (0xA8D0, 0xA8D9, 13), (0xA8DA, 0xA8DF, 2), (0xA8E0, 0xA8F1, 12),
+    (0xA8F2, 0xA8F7, 7), (0xA8F8, 0xA8FA, 21), (0xA8FB, 0xA8FB, 7), (0xA8FC, 0xA8FC, 21),
+    (0xA8FD, 0xA8FE, 7), (0xA8FF, 0xA8FF, 12), (0xA900, 0xA909, 13), (0xA90A, 0xA925, 7),
+    (0xA926, 0xA92D, 12), (0xA92E, 0xA92F, 21), (0xA930, 0xA946, 7), (0xA947, 0xA951, 12),
+    (0xA952, 0xA953, 10), (0xA954, 0xA95E, 2), (0xA95F, 0xA95F, 21), (0xA960, 0xA97C, 7),
+    (0xA97D, 0xA97F, 2), (0xA980, 0xA982, 12), (0xA983, 0xA983, 10), (0xA984, 0xA9B2, 7),
+    (0xA9B3, 0xA9B3, 12), (0xA9B4, 0xA9B5, 10), (0xA9B6, 0xA9B9, 12), (0xA9BA, 0xA9BB, 10),
+    (0xA9BC, 0xA9BD, 12), (0xA9BE, 0xA9C0, 10), (0xA9C1, 0xA9CD, 21), (0xA9CE, 0xA9CE, 2),
+    (0xA9CF, 0xA9CF, 6), (0xA9D0, 0xA9D9, 13), (0xA9DA, 0xA9DD, 2), (0xA9DE, 0xA9DF, 21),
+    (0xA9E0, 0xA9E4, 7), (0xA9E5, 0xA9E5, 12), (0xA9E6, 0xA9E6, 6), (0xA9E7, 0xA9EF, 7),
+    (0xA9F0, 0xA9F9, 13), (0xA9FA, 0xA9FE, 7), (0xA9FF, 0xA9FF, 2), (0xAA00, 0xAA28, 7),
+    (0xAA29, 0xAA2E, 12), (0xAA2F, 0xAA30, 10), (0xAA31, 0xAA32, 12), (0xAA33, 0xAA34, 10),
+    (0xAA35, 0xAA36, 12), (0xAA37, 0xAA3F, 2), (0xAA40, 0xAA42, 7), (0xAA43, 0xAA43, 12),
+    (0xAA44, 0xAA4B, 7), (0xAA4C, 0xAA4C, 12), (0xAA4D, 0xAA4D, 10), (0xAA4E, 0xAA4F, 2),
+    (0xAA50, 0xAA59, 13), (0xAA5A, 0xAA5B, 2), (0xAA5C, 0xAA5F, 21), (0xAA60, 0xAA6F, 7),
+    (0xAA70, 0xAA70, 6), (0xAA71, 0xAA76, 7), (0xAA77, 0xAA79, 26), (0xAA7A, 0xAA7A, 7),
+    (0xAA7B, 0xAA7B, 10), (0xAA7C, 0xAA7C, 12), (0xAA7D, 0xAA7D, 10), (0xAA7E, 0xAAAF, 7),
+    (0xAAB0, 0xAAB0, 12), (0xAAB1, 0xAAB1, 7), (0xAAB2, 0xAAB4, 12), (0xAAB5, 0xAAB6, 7),
+    (0xAAB7, 0xAAB8, 12), (0xAAB9, 0xAABD, 7), (0xAABE, 0xAABF, 12), (0xAAC0, 0xAAC0, 7),
+    (0xAAC1, 0xAAC1, 12), (0xAAC2, 0xAAC2, 7), (0xAAC3, 0xAADA, 2), (0xAADB, 0xAADC, 7),
+    (0xAADD, 0xAADD, 6), (0xAADE, 0xAADF, 21), (0xAAE0, 0xAAEA, 7), (0xAAEB, 0xAAEB, 10),
+    (0xAAEC, 0xAAED, 12), (0xAAEE, 0xAAEF, 10), (0xAAF0, 0xAAF1, 21), (0xAAF2, 0xAAF2, 7),
+    (0xAAF3, 0xAAF4, 6), (0xAAF5, 0xAAF5, 10), (0xAAF6, 0xAAF6, 12), (0xAAF7, 0xAB00, 2),
+    (0xAB01, 0xAB06, 7), (0xAB07, 0xAB08, 2), (0xAB09, 0xAB0E, 7), (0xAB0F, 0xAB10, 2),
+    (0xAB11, 0xAB16, 7), (0xAB17, 0xAB1F, 2), (0xAB20, 0xAB26, 7), (0xAB27, 0xAB27, 2),
+    (0xAB28, 0xAB2E, 7), (0xAB2F, 0xAB2F, 2), (0xAB30, 0xAB5A, 5), (0xAB5B, 0xAB5B, 24),
+    (0xAB5C, 0xAB5F, 6), (0xAB60, 0xAB68, 5), (0xAB69, 0xAB69, 6), (0xAB6A, 0xAB6B, 24),
+    (0xAB6C, 0xAB6F, 2), (0xAB70, 0xABBF, 5), (0xABC0, 0xABE2, 7), (0xABE3, 0xABE4, 10),
+    (0xABE5, 0xABE5, 12), (0xABE6, 0xABE7, 10), (0xABE8, 0xABE8, 12), (0xABE9, 0xABEA, 10),
+    (0xABEB, 0xABEB, 21), (0xABEC, 0xABEC, 10), (0xABED, 0xABED, 12), (0xABEE, 0xABEF, 2),
+    (0xABF0, 0xABF9, 13), (0xABFA, 0xABFF, 2), (0xAC00, 0xD7A3, 7), (0xD7A4, 0xD7AF, 2),
+    (0xD7B0, 0xD7C6, 7), (0xD7C7, 0xD7CA, 2), (0xD7CB, 0xD7FB, 7), (0xD7FC, 0xD7FF, 2),
+    (0xD800, 0xDFFF, 4), (0xE000, 0xF8FF, 3), (0xF900, 0xFA6D, 7), (0xFA6E, 0xFA6F, 2),
+    (0xFA70, 0xFAD9, 7), (0xFADA, 0xFAFF, 2), (0xFB00, 0xFB06, 5), (0xFB07, 0xFB12, 2),
+    (0xFB13, 0xFB17, 5), (0xFB18, 0xFB1C, 2), (0xFB1D, 0xFB1D, 7), (0xFB1E, 0xFB1E, 12),
+    (0xFB1F, 0xFB28, 7), (0xFB29, 0xFB29, 25), (0xFB2A, 0xFB36, 7), (0xFB37, 0xFB37, 2),
+    (0xFB38, 0xFB3C, 7), (0xFB3D, 0xFB3D, 2), (0xFB3E, 0xFB3E, 7), (0xFB3F, 0xFB3F, 2),
+    (0xFB40, 0xFB41, 7), (0xFB42, 0xFB42, 2), (0xFB43, 0xFB44, 7), (0xFB45, 0xFB45, 2),
+    (0xFB46, 0xFBB1, 7), (0xFBB2, 0xFBC2, 24), (0xFBC3, 0xFBD2, 26), (0xFBD3, 0xFD3D, 7),
+    (0xFD3E, 0xFD3E, 18), (0xFD3F, 0xFD3F, 22), (0xFD40, 0xFD4F, 26), (0xFD50, 0xFD8F, 7),
+    (0xFD90, 0xFD91, 26), (0xFD92, 0xFDC7, 7), (0xFDC8, 0xFDCF, 26), (0xFDD0, 0xFDEF, 2),
+    (0xFDF0, 0xFDFB, 7), (0xFDFC, 0xFDFC, 23), (0xFDFD, 0xFDFF, 26), (0xFE00, 0xFE0F, 12),
+    (0xFE10, 0xFE16, 21), (0xFE17, 0xFE17, 22), (0xFE18, 0xFE18, 18), (0xFE19, 0xFE19, 21),
+    (0xFE1A, 0xFE1F, 2), (0xFE20, 0xFE2F, 12), (0xFE30, 0xFE30, 21), (0xFE31, 0xFE32, 17),
+    (0xFE33, 0xFE34, 16), (0xFE35, 0xFE35, 22), (0xFE36, 0xFE36, 18), (0xFE37, 0xFE37, 22),
+    (0xFE38, 0xFE38, 18), (0xFE39, 0xFE39, 22), (0xFE3A, 0xFE3A, 18), (0xFE3B, 0xFE3B, 22),
+    (0xFE3C, 0xFE3C, 18), (0xFE3D, 0xFE3D, 22), (0xFE3E, 0xFE3E, 18), (0xFE3F, 0xFE3F, 22),
+    (0xFE40, 0xFE40, 18), (0xFE41, 0xFE41, 22), (0xFE42, 0xFE42, 18), (0xFE43, 0xFE43, 22),
+    (0xFE44, 0xFE44, 18), (0xFE45, 0xFE46, 21), (0xFE47, 0xFE47, 22), (0xFE48, 0xFE48, 18),
+    (0xFE49, 0xFE4C, 21), (0xFE4D, 0xFE4F, 16), (0xFE50, 0xFE52, 21), (0xFE53, 0xFE53, 2),
+    (0xFE54, 0xFE57, 21), (0xFE58, 0xFE58, 17), (0xFE59, 0xFE59, 22), (0xFE5A, 0xFE5A, 18),
+    (0xFE5B, 0xFE5B, 22), (0xFE5C, 0xFE5C, 18), (0xFE5D, 0xFE5D, 22), (0xFE5E, 0xFE5E, 18),
+    (0xFE5F, 0xFE61, 21), (0xFE62, 0xFE62, 25), (0xFE63, 0xFE63, 17), (0xFE64, 0xFE66, 25),
+    (0xFE67, 0xFE67, 2), (0xFE68, 0xFE68, 21), (0xFE69, 0xFE69, 23), (0xFE6A, 0xFE6B, 21),
+    (0xFE6C, 0xFE6F, 2), (0xFE70, 0xFE74, 7), (0xFE75, 0xFE75, 2), (0xFE76, 0xFEFC, 7),
+    (0xFEFD, 0xFEFE, 2), (0xFEFF, 0xFEFF, 1), (0xFF00, 0xFF00, 2), (0xFF01, 0xFF03, 21),
+    (0xFF04, 0xFF04, 23), (0xFF05, 0xFF07, 21), (0xFF08, 0xFF08, 22), (0xFF09, 0xFF09, 18),
+    (0xFF0A, 0xFF0A, 21), (0xFF0B, 0xFF0B, 25), (0xFF0C, 0xFF0C, 21), (0xFF0D, 0xFF0D, 17),
+    (0xFF0E, 0xFF0F, 21), (0xFF10, 0xFF19, 13), (0xFF1A, 0xFF1B, 21), (0xFF1C, 0xFF1E, 25),
+    (0xFF1F, 0xFF20, 21), (0xFF21, 0xFF3A, 9), (0xFF3B, 0xFF3B, 22), (0xFF3C, 0xFF3C, 21),
+    (0xFF3D, 0xFF3D, 18), (0xFF3E, 0xFF3E, 24), (0xFF3F, 0xFF3F, 16), (0xFF40, 0xFF40, 24),
+    (0xFF41, 0xFF5A, 5), (0xFF5B, 0xFF5B, 22), (0xFF5C, 0xFF5C, 25), (0xFF5D, 0xFF5D, 18),
+    (0xFF5E, 0xFF5E, 25), (0xFF5F, 0xFF5F, 22), (0xFF60, 0xFF60, 18), (0xFF61, 0xFF61, 21),
+    (0xFF62, 0xFF62, 22), (0xFF63, 0xFF63, 18), (0xFF64, 0xFF65, 21), (0xFF66, 0xFF6F, 7),
+    (0xFF70, 0xFF70, 6), (0xFF71, 0xFF9D, 7), (0xFF9E, 0xFF9F, 6), (0xFFA0, 0xFFBE, 7),
+    (0xFFBF, 0xFFC1, 2), (0xFFC2, 0xFFC7, 7), (0xFFC8, 0xFFC9, 2), (0xFFCA, 0xFFCF, 7),
+    (0xFFD0, 0xFFD1, 2), (0xFFD2, 0xFFD7, 7), (0xFFD8, 0xFFD9, 2), (0xFFDA, 0xFFDC, 7),
+    (0xFFDD, 0xFFDF, 2), (0xFFE0, 0xFFE1, 23), (0xFFE2, 0xFFE2, 25), (0xFFE3, 0xFFE3, 24),
+    (0xFFE4, 0xFFE4, 26), (0xFFE5, 0xFFE6, 23), (0xFFE7, 0xFFE7, 2), (0xFFE8, 0xFFE8, 26),
+    (0xFFE9, 0xFFEC, 25), (0xFFED, 0xFFEE, 26), (0xFFEF, 0xFFF8, 2), (0xFFF9, 0xFFFB, 1),
+    (0xFFFC, 0xFFFD, 26), (0xFFFE, 0xFFFF, 2), (0x10000, 0x1000B, 7), (0x1000C, 0x1000C, 2),
+    (0x1000D, 0x10026, 7), (0x10027, 0x10027, 2), (0x10028, 0x1003A, 7), (0x1003B, 0x1003B, 2),
+    (0x1003C, 0x1003D, 7), (0x1003E, 0x1003E, 2), (0x1003F, 0x1004D, 7), (0x1004E, 0x1004F, 2),
+    (0x10050, 0x1005D, 7), (0x1005E, 0x1007F, 2), (0x10080, 0x100FA, 7), (0x100FB, 0x100FF, 2),
+    (0x10100, 0x10102, 21), (0x10103, 0x10106, 2), (0x10107, 0x10133, 15), (0x10134, 0x10136, 2),
+    (0x10137, 0x1013F, 26), (0x10140, 0x10174, 14), (0x10175, 0x10178, 15), (0x10179, 0x10189, 26),
+    (0x1018A, 0x1018B, 15), (0x1018C, 0x1018E, 26), (0x1018F, 0x1018F, 2), (0x10190, 0x1019C, 26),
+    (0x1019D, 0x1019F, 2), (0x101A0, 0x101A0, 26), (0x101A1, 0x101CF, 2), (0x101D0, 0x101FC, 26),
+    (0x101FD, 0x101FD, 12), (0x101FE, 0x1027F, 2), (0x10280, 0x1029C, 7), (0x1029D, 0x1029F, 2),
+    (0x102A0, 0x102D0, 7), (0x102D1, 0x102DF, 2), (0x102E0, 0x102E0, 12), (0x102E1, 0x102FB, 15),
+    (0x102FC, 0x102FF, 2), (0x10300, 0x1031F, 7), (0x10320, 0x10323, 15), (0x10324, 0x1032C, 2),
+    (0x1032D, 0x10340, 7), (0x10341, 0x10341, 14), (0x10342, 0x10349, 7), (0x1034A, 0x1034A, 14),
+    (0x1034B, 0x1034F, 2), (0x10350, 0x10375, 7), (0x10376, 0x1037A, 12), (0x1037B, 0x1037F, 2),
+    (0x10380, 0x1039D, 7), (0x1039E, 0x1039E, 2), (0x1039F, 0x1039F, 21), (0x103A0, 0x103C3, 7),
+    (0x103C4, 0x103C7, 2), (0x103C8, 0x103CF, 7), (0x103D0, 0x103D0, 21), (0x103D1, 0x103D5, 14),
+    (0x103D6, 0x103FF, 2), (0x10400, 0x10427, 9), (0x10428, 0x1044F, 5), (0x10450, 0x1049D, 7),
+    (0x1049E, 0x1049F, 2), (0x104A0, 0x104A9, 13), (0x104AA, 0x104AF, 2), (0x104B0, 0x104D3, 9),
+    (0x104D4, 0x104D7, 2), (0x104D8, 0x104FB, 5), (0x104FC, 0x104FF, 2), (0x10500, 0x10527, 7),
+    (0x10528, 0x1052F, 2), (0x10530, 0x10563, 7), (0x10564, 0x1056E, 2), (0x1056F, 0x1056F, 21),
+    (0x10570, 0x1057A, 9), (0x1057B, 0x1057B, 2), (0x1057C, 0x1058A, 9), (0x1058B, 0x1058B, 2),
+    (0x1058C, 0x10592, 9), (0x10593, 0x10593, 2), (0x10594, 0x10595, 9), (0x10596, 0x10596, 2),
+    (0x10597, 0x105A1, 5), (0x105A2, 0x105A2, 2), (0x105A3, 0x105B1, 5), (0x105B2, 0x105B2, 2),
+    (0x105B3, 0x105B9, 5), (0x105BA, 0x105BA, 2), (0x105BB, 0x105BC, 5), (0x105BD, 0x105BF, 2),
+    (0x105C0, 0x105F3, 7), (0x105F4, 0x105FF, 2), (0x10600, 0x10736, 7), (0x10737, 0x1073F, 2),
+    (0x10740, 0x10755, 7), (0x10756, 0x1075F, 2), (0x10760, 0x10767, 7), (0x10768, 0x1077F, 2),
+    (0x10780, 0x10785, 6), (0x10786, 0x10786, 2), (0x10787, 0x107B0, 6), (0x107B1, 0x107B1, 2),
+    (0x107B2, 0x107BA, 6), (0x107BB, 0x107FF, 2), (0x10800, 0x10805, 7), (0x10806, 0x10807, 2),
+    (0x10808, 0x10808, 7), (0x10809, 0x10809, 2), (0x1080A, 0x10835, 7), (0x10836, 0x10836, 2),
+    (0x10837, 0x10838, 7), (0x10839, 0x1083B, 2), (0x1083C, 0x1083C, 7), (0x1083D, 0x1083E, 2),
+    (0x1083F, 0x10855, 7), (0x10856, 0x10856, 2), (0x10857, 0x10857, 21), (0x10858, 0x1085F, 15),
+    (0x10860, 0x10876, 7), (0x10877, 0x10878, 26), (0x10879, 0x1087F, 15), (0x10880, 0x1089E, 7),
+    (0x1089F, 0x108A6, 2), (0x108A7, 0x108AF, 15), (0x108B0, 0x108DF, 2), (0x108E0, 0x108F2, 7),
+    (0x108F3, 0x108F3, 2), (0x108F4, 0x108F5, 7), (0x108F6, 0x108FA, 2), (0x108FB, 0x108FF, 15),
+    (0x10900, 0x10915, 7), (0x10916, 0x1091B, 15), (0x1091C, 0x1091E, 2), (0x1091F, 0x1091F, 21),
+    (0x10920, 0x10939, 7), (0x1093A, 0x1093E, 2), (0x1093F, 0x1093F, 21), (0x10940, 0x10959, 7),
+    (0x1095A, 0x1097F, 2), (0x10980, 0x109B7, 7), (0x109B8, 0x109BB, 2), (0x109BC, 0x109BD, 15),
+    (0x109BE, 0x109BF, 7), (0x109C0, 0x109CF, 15), (0x109D0, 0x109D1, 2), (0x109D2, 0x109FF, 15),
+    (0x10A00, 0x10A00, 7), (0x10A01, 0x10A03, 12), (0x10A04, 0x10A04, 2), (0x10A05, 0x10A06, 12),
+    (0x10A07, 0x10A0B, 2), (0x10A0C, 0x10A0F, 12), (0x10A10, 0x10A13, 7), (0x10A14, 0x10A14, 2),
+    (0x10A15, 0x10A17, 7), (0x10A18, 0x10A18, 2), (0x10A19, 0x10A35, 7), (0x10A36, 0x10A37, 2),
+    (0x10A38, 0x10A3A, 12), (0x10A3B, 0x10A3E, 2), (0x10A3F, 0x10A3F, 12), (0x10A40, 0x10A48, 15),
+    (0x10A49, 0x10A4F, 2), (0x10A50, 0x10A58, 21), (0x10A59, 0x10A5F, 2), (0x10A60, 0x10A7C, 7),
+    (0x10A7D, 0x10A7E, 15), (0x10A7F, 0x10A7F, 21), (0x10A80, 0x10A9C, 7), (0x10A9D, 0x10A9F, 15),
+    (0x10AA0, 0x10ABF, 2), (0x10AC0, 0x10AC7, 7), (0x10AC8, 0x10AC8, 26), (0x10AC9, 0x10AE4, 7),
+    (0x10AE5, 0x10AE6, 12), (0x10AE7, 0x10AEA, 2), (0x10AEB, 0x10AEF, 15), (0x10AF0, 0x10AF6, 21),
+    (0x10AF7, 0x10AFF, 2), (0x10B00, 0x10B35, 7), (0x10B36, 0x10B38, 2), (0x10B39, 0x10B3F, 21),
+    (0x10B40, 0x10B55, 7), (0x10B56, 0x10B57, 2), (0x10B58, 0x10B5F, 15), (0x10B60, 0x10B72, 7),
+    (0x10B73, 0x10B77, 2), (0x10B78, 0x10B7F, 15), (0x10B80, 0x10B91, 7), (0x10B92, 0x10B98, 2),
+    (0x10B99, 0x10B9C, 21), (0x10B9D, 0x10BA8, 2), (0x10BA9, 0x10BAF, 15), (0x10BB0, 0x10BFF, 2),
+    (0x10C00, 0x10C48, 7), (0x10C49, 0x10C7F, 2), (0x10C80, 0x10CB2, 9), (0x10CB3, 0x10CBF, 2),
+    (0x10CC0, 0x10CF2, 5), (0x10CF3, 0x10CF9, 2), (0x10CFA, 0x10CFF, 15), (0x10D00, 0x10D23, 7),
+    (0x10D24, 0x10D27, 12), (0x10D28, 0x10D2F, 2), (0x10D30, 0x10D39, 13), (0x10D3A, 0x10D3F, 2),
+    (0x10D40, 0x10D49, 13), (0x10D4A, 0x10D4D, 7), (0x10D4E, 0x10D4E, 6), (0x10D4F, 0x10D4F, 7),
+    (0x10D50, 0x10D65, 9), (0x10D66, 0x10D68, 2), (0x10D69, 0x10D6D, 12), (0x10D6E, 0x10D6E, 17),
+    (0x10D6F, 0x10D6F, 6), (0x10D70, 0x10D85, 5), (0x10D86, 0x10D8D, 2), (0x10D8E, 0x10D8F, 25),
+    (0x10D90, 0x10E5F, 2), (0x10E60, 0x10E7E, 15), (0x10E7F, 0x10E7F, 2), (0x10E80, 0x10EA9, 7),
+    (0x10EAA, 0x10EAA, 2), (0x10EAB, 0x10EAC, 12), (0x10EAD, 0x10EAD, 17), (0x10EAE, 0x10EAF, 2),
+    (0x10EB0, 0x10EB1, 7), (0x10EB2, 0x10EC1, 2), (0x10EC2, 0x10EC4, 7), (0x10EC5, 0x10EC5, 6),
+    (0x10EC6, 0x10EC7, 7), (0x10EC8, 0x10ECF, 2), (0x10ED0, 0x10ED0, 21), (0x10ED1, 0x10ED8, 26),
+    (0x10ED9, 0x10EF9, 2), (0x10EFA, 0x10EFF, 12), (0x10F00, 0x10F1C, 7), (0x10F1D, 0x10F26, 15),
+    (0x10F27, 0x10F27, 7), (0x10F28, 0x10F2F, 2), (0x10F30, 0x10F45, 7), (0x10F46, 0x10F50, 12),
+    (0x10F51, 0x10F54, 15), (0x10F55, 0x10F59, 21), (0x10F5A, 0x10F6F, 2), (0x10F70, 0x10F81, 7),
+    (0x10F82, 0x10F85, 12), (0x10F86, 0x10F89, 21), (0x10F8A, 0x10FAF, 2), (0x10FB0, 0x10FC4, 7),
+    (0x10FC5, 0x10FCB, 15), (0x10FCC, 0x10FDF, 2), (0x10FE0, 0x10FF6, 7), (0x10FF7, 0x10FFF, 2),
+    (0x11000, 0x11000, 10), (0x11001, 0x11001, 12), (0x11002, 0x11002, 10), (0x11003, 0x11037, 7),
+    (0x11038, 0x11046, 12), (0x11047, 0x1104D, 21), (0x1104E, 0x11051, 2), (0x11052, 0x11065, 15),
+    (0x11066, 0x1106F, 13), (0x11070, 0x11070, 12), (0x11071, 0x11072, 7), (0x11073, 0x11074, 12),
+    (0x11075, 0x11075, 7), (0x11076, 0x1107E, 2), (0x1107F, 0x11081, 12), (0x11082, 0x11082, 10),
+    (0x11083, 0x110AF, 7), (0x110B0, 0x110B2, 10), (0x110B3, 0x110B6, 12), (0x110B7, 0x110B8, 10),
+    (0x110B9, 0x110BA, 12), (0x110BB, 0x110BC, 21), (0x110BD, 0x110BD, 1), (0x110BE, 0x110C1, 21),
+    (0x110C2, 0x110C2, 12), (0x110C3, 0x110CC, 2), (0x110CD, 0x110CD, 1), (0x110CE, 0x110CF, 2),
+    (0x110D0, 0x110E8, 7), (0x110E9, 0x110EF, 2), (0x110F0, 0x110F9, 13), (0x110FA, 0x110FF, 2),
+    (0x11100, 0x11102, 12), (0x11103, 0x11126, 7), (0x11127, 0x1112B, 12), (0x1112C, 0x1112C, 10),
+    (0x1112D, 0x11134, 12), (0x11135, 0x11135, 2), (0x11136, 0x1113F, 13), (0x11140, 0x11143, 21),
+    (0x11144, 0x11144, 7), (0x11145, 0x11146, 10), (0x11147, 0x11147, 7), (0x11148, 0x1114F, 2),
+    (0x11150, 0x11172, 7), (0x11173, 0x11173, 12), (0x11174, 0x11175, 21), (0x11176, 0x11176, 7),
+    (0x11177, 0x1117F, 2), (0x11180, 0x11181, 12), (0x11182, 0x11182, 10), (0x11183, 0x111B2, 7),
+    (0x111B3, 0x111B5, 10), (0x111B6, 0x111BE, 12), (0x111BF, 0x111C0, 10), (0x111C1, 0x111C4, 7),
+    (0x111C5, 0x111C8, 21), (0x111C9, 0x111CC, 12), (0x111CD, 0x111CD, 21), (0x111CE, 0x111CE, 10),
+    (0x111CF, 0x111CF, 12), (0x111D0, 0x111D9, 13), (0x111DA, 0x111DA, 7), (0x111DB, 0x111DB, 21),
+    (0x111DC, 0x111DC, 7), (0x111DD, 0x111DF, 21), (0x111E0, 0x111E0, 2), (0x111E1, 0x111F4, 15),
+    (0x111F5, 0x111FF, 2), (0x11200, 0x11211, 7), (0x11212, 0x11212, 2), (0x11213, 0x1122B, 7),
+    (0x1122C, 0x1122E, 10), (0x1122F, 0x11231, 12), (0x11232, 0x11233, 10), (0x11234, 0x11234, 12),
+    (0x11235, 0x11235, 10), (0x11236, 0x11237, 12), (0x11238, 0x1123D, 21), (0x1123E, 0x1123E, 12),
+    (0x1123F, 0x11240, 7), (0x11241, 0x11241, 12), (0x11242, 0x1127F, 2), (0x11280, 0x11286, 7),
+    (0x11287, 0x11287, 2), (0x11288, 0x11288, 7), (0x11289, 0x11289, 2), (0x1128A, 0x1128D, 7),
+    (0x1128E, 0x1128E, 2), (0x1128F, 0x1129D, 7), (0x1129E, 0x1129E, 2), (0x1129F, 0x112A8, 7),
+    (0x112A9, 0x112A9, 21), (0x112AA, 0x112AF, 2), (0x112B0, 0x112DE, 7), (0x112DF, 0x112DF, 12),
+    (0x112E0, 0x112E2, 10), (0x112E3, 0x112EA, 12), (0x112EB, 0x112EF, 2), (0x112F0, 0x112F9, 13),
+    (0x112FA, 0x112FF, 2), (0x11300, 0x11301, 12), (0x11302, 0x11303, 10), (0x11304, 0x11304, 2),
+    (0x11305, 0x1130C, 7), (0x1130D, 0x1130E, 2), (0x1130F, 0x11310, 7), (0x11311, 0x11312, 2),
+    (0x11313, 0x11328, 7), (0x11329, 0x11329, 2), (0x1132A, 0x11330, 7), (0x11331, 0x11331, 2),
+    (0x11332, 0x11333, 7), (0x11334, 0x11334, 2), (0x11335, 0x11339, 7), (0x1133A, 0x1133A, 2),
+    (0x1133B, 0x1133C, 12), (0x1133D, 0x1133D, 7), (0x1133E, 0x1133F, 10), (0x11340, 0x11340, 12),
+    (0x11341, 0x11344, 10), (0x11345, 0x11346, 2), (0x11347, 0x11348, 10), (0x11349, 0x1134A, 2),
+    (0x1134B, 0x1134D, 10), (0x1134E, 0x1134F, 2), (0x11350, 0x11350, 7), (0x11351, 0x11356, 2),
+    (0x11357, 0x11357, 10), (0x11358, 0x1135C, 2), (0x1135D, 0x11361, 7), (0x11362, 0x11363, 10),
+    (0x11364, 0x11365, 2), (0x11366, 0x1136C, 12), (0x1136D, 0x1136F, 2), (0x11370, 0x11374, 12),
+    (0x11375, 0x1137F, 2), (0x11380, 0x11389, 7), (0x1138A, 0x1138A, 2), (0x1138B, 0x1138B, 7),
+    (0x1138C, 0x1138D, 2), (0x1138E, 0x1138E, 7), (0x1138F, 0x1138F, 2), (0x11390, 0x113B5, 7),
+    (0x113B6, 0x113B6, 2), (0x113B7, 0x113B7, 7), (0x113B8, 0x113BA, 10), (0x113BB, 0x113C0, 12),
+    (0x113C1, 0x113C1, 2), (0x113C2, 0x113C2, 10), (0x113C3, 0x113C4, 2), (0x113C5, 0x113C5, 10),
+    (0x113C6, 0x113C6, 2), (0x113C7, 0x113CA, 10), (0x113CB, 0x113CB, 2), (0x113CC, 0x113CD, 10),
+    (0x113CE, 0x113CE, 12), (0x113CF, 0x113CF, 10), (0x113D0, 0x113D0, 12), (0x113D1, 0x113D1, 7),
+    (0x113D2, 0x113D2, 12), (0x113D3, 0x113D3, 7), (0x113D4, 0x113D5, 21), (0x113D6, 0x113D6, 2),
+    (0x113D7, 0x113D8, 21), (0x113D9, 0x113E0, 2), (0x113E1, 0x113E2, 12), (0x113E3, 0x113FF, 2),
+    (0x11400, 0x11434, 7), (0x11435, 0x11437, 10), (0x11438, 0x1143F, 12), (0x11440, 0x11441, 10),
+    (0x11442, 0x11444, 12), (0x11445, 0x11445, 10), (0x11446, 0x11446, 12), (0x11447, 0x1144A, 7),
+    (0x1144B, 0x1144F, 21), (0x11450, 0x11459, 13), (0x1145A, 0x1145B, 21), (0x1145C, 0x1145C, 2),
+    (0x1145D, 0x1145D, 21), (0x1145E, 0x1145E, 12), (0x1145F, 0x11461, 7), (0x11462, 0x1147F, 2),
+    (0x11480, 0x114AF, 7), (0x114B0, 0x114B2, 10), (0x114B3, 0x114B8, 12), (0x114B9, 0x114B9, 10),
+    (0x114BA, 0x114BA, 12), (0x114BB, 0x114BE, 10), (0x114BF, 0x114C0, 12), (0x114C1, 0x114C1, 10),
+    (0x114C2, 0x114C3, 12), (0x114C4, 0x114C5, 7), (0x114C6, 0x114C6, 21), (0x114C7, 0x114C7, 7),
+    (0x114C8, 0x114CF, 2), (0x114D0, 0x114D9, 13), (0x114DA, 0x1157F, 2), (0x11580, 0x115AE, 7),
+    (0x115AF, 0x115B1, 10), (0x115B2, 0x115B5, 12), (0x115B6, 0x115B7, 2), (0x115B8, 0x115BB, 10),
+    (0x115BC, 0x115BD, 12), (0x115BE, 0x115BE, 10), (0x115BF, 0x115C0, 12), (0x115C1, 0x115D7, 21),
+    (0x115D8, 0x115DB, 7), (0x115DC, 0x115DD, 12), (0x115DE, 0x115FF, 2), (0x11600, 0x1162F, 7),
+    (0x11630, 0x11632, 10), (0x11633, 0x1163A, 12), (0x1163B, 0x1163C, 10), (0x1163D, 0x1163D, 12),
+    (0x1163E, 0x1163E, 10), (0x1163F, 0x11640, 12), (0x11641, 0x11643, 21), (0x11644, 0x11644, 7),
+    (0x11645, 0x1164F, 2), (0x11650, 0x11659, 13), (0x1165A, 0x1165F, 2), (0x11660, 0x1166C, 21),
+    (0x1166D, 0x1167F, 2), (0x11680, 0x116AA, 7), (0x116AB, 0x116AB, 12), (0x116AC, 0x116AC, 10),
+    (0x116AD, 0x116AD, 12), (0x116AE, 0x116AF, 10), (0x116B0, 0x116B5, 12), (0x116B6, 0x116B6, 10),
+    (0x116B7, 0x116B7, 12), (0x116B8, 0x116B8, 7), (0x116B9, 0x116B9, 21), (0x116BA, 0x116BF, 2),
+    (0x116C0, 0x116C9, 13), (0x116CA, 0x116CF, 2), (0x116D0, 0x116E3, 13), (0x116E4, 0x116FF, 2),
+    (0x11700, 0x1171A, 7), (0x1171B, 0x1171C, 2), (0x1171D, 0x1171D, 12), (0x1171E, 0x1171E, 10),
+    (0x1171F, 0x1171F, 12), (0x11720, 0x11721, 10), (0x11722, 0x11725, 12), (0x11726, 0x11726, 10),
+    (0x11727, 0x1172B, 12), (0x1172C, 0x1172F, 2), (0x11730, 0x11739, 13), (0x1173A, 0x1173B, 15),
+    (0x1173C, 0x1173E, 21), (0x1173F, 0x1173F, 26), (0x11740, 0x11746, 7), (0x11747, 0x117FF, 2),
+    (0x11800, 0x1182B, 7), (0x1182C, 0x1182E, 10), (0x1182F, 0x11837, 12), (0x11838, 0x11838, 10),
+    (0x11839, 0x1183A, 12), (0x1183B, 0x1183B, 21), (0x1183C, 0x1189F, 2), (0x118A0, 0x118BF, 9),
+    (0x118C0, 0x118DF, 5), (0x118E0, 0x118E9, 13), (0x118EA, 0x118F2, 15), (0x118F3, 0x118FE, 2),
+    (0x118FF, 0x11906, 7), (0x11907, 0x11908, 2), (0x11909, 0x11909, 7), (0x1190A, 0x1190B, 2),
+    (0x1190C, 0x11913, 7), (0x11914, 0x11914, 2), (0x11915, 0x11916, 7), (0x11917, 0x11917, 2),
+    (0x11918, 0x1192F, 7), (0x11930, 0x11935, 10), (0x11936, 0x11936, 2), (0x11937, 0x11938, 10),
+    (0x11939, 0x1193A, 2), (0x1193B, 0x1193C, 12), (0x1193D, 0x1193D, 10), (0x1193E, 0x1193E, 12),
+    (0x1193F, 0x1193F, 7), (0x11940, 0x11940, 10), (0x11941, 0x11941, 7), (0x11942, 0x11942, 10),
+    (0x11943, 0x11943, 12), (0x11944, 0x11946, 21), (0x11947, 0x1194F, 2), (0x11950, 0x11959, 13),
+    (0x1195A, 0x1199F, 2), (0x119A0, 0x119A7, 7), (0x119A8, 0x119A9, 2), (0x119AA, 0x119D0, 7),
+    (0x119D1, 0x119D3, 10), (0x119D4, 0x119D7, 12), (0x119D8, 0x119D9, 2), (0x119DA, 0x119DB, 12),
+    (0x119DC, 0x119DF, 10), (0x119E0, 0x119E0, 12), (0x119E1, 0x119E1, 7), (0x119E2, 0x119E2, 21),
+    (0x119E3, 0x119E3, 7), (0x119E4, 0x119E4, 10), (0x119E5, 0x119FF, 2), (0x11A00, 0x11A00, 7),
+    (0x11A01, 0x11A0A, 12), (0x11A0B, 0x11A32, 7), (0x11A33, 0x11A38, 12), (0x11A39, 0x11A39, 10),
+    (0x11A3A, 0x11A3A, 7), (0x11A3B, 0x11A3E, 12), (0x11A3F, 0x11A46, 21), (0x11A47, 0x11A47, 12),
+    (0x11A48, 0x11A4F, 2), (0x11A50, 0x11A50, 7), (0x11A51, 0x11A56, 12), (0x11A57, 0x11A58, 10),
+    (0x11A59, 0x11A5B, 12), (0x11A5C, 0x11A89, 7), (0x11A8A, 0x11A96, 12), (0x11A97, 0x11A97, 10),
+    (0x11A98, 0x11A99, 12), (0x11A9A, 0x11A9C, 21), (0x11A9D, 0x11A9D, 7), (0x11A9E, 0x11AA2, 21),
+    (0x11AA3, 0x11AAF, 2), (0x11AB0, 0x11AF8, 7), (0x11AF9, 0x11AFF, 2), (0x11B00, 0x11B09, 21),
+    (0x11B0A, 0x11B5F, 2), (0x11B60, 0x11B60, 12), (0x11B61, 0x11B61, 10), (0x11B62, 0x11B64, 12),
+    (0x11B65, 0x11B65, 10), (0x11B66, 0x11B66, 12), (0x11B67, 0x11B67, 10), (0x11B68, 0x11BBF, 2),
+    (0x11BC0, 0x11BE0, 7), (0x11BE1, 0x11BE1, 21), (0x11BE2, 0x11BEF, 2), (0x11BF0, 0x11BF9, 13),
+    (0x11BFA, 0x11BFF, 2), (0x11C00, 0x11C08, 7), (0x11C09, 0x11C09, 2), (0x11C0A, 0x11C2E, 7),
+    (0x11C2F, 0x11C2F, 10), (0x11C30, 0x11C36, 12), (0x11C37, 0x11C37, 2), (0x11C38, 0x11C3D, 12),
+    (0x11C3E, 0x11C3E, 10), (0x11C3F, 0x11C3F, 12), (0x11C40, 0x11C40, 7), (0x11C41, 0x11C45, 21),
+    (0x11C46, 0x11C4F, 2), (0x11C50, 0x11C59, 13), (0x11C5A, 0x11C6C, 15), (0x11C6D, 0x11C6F, 2),
+    (0x11C70, 0x11C71, 21), (0x11C72, 0x11C8F, 7), (0x11C90, 0x11C91, 2), (0x11C92, 0x11CA7, 12),
+    (0x11CA8, 0x11CA8, 2), (0x11CA9, 0x11CA9, 10), (0x11CAA, 0x11CB0, 12), (0x11CB1, 0x11CB1, 10),
+    (0x11CB2, 0x11CB3, 12), (0x11CB4, 0x11CB4, 10), (0x11CB5, 0x11CB6, 12), (0x11CB7, 0x11CFF, 2),
+    (0x11D00, 0x11D06, 7), (0x11D07, 0x11D07, 2), (0x11D08, 0x11D09, 7), (0x11D0A, 0x11D0A, 2),
+    (0x11D0B, 0x11D30, 7), (0x11D31, 0x11D36, 12), (0x11D37, 0x11D39, 2), (0x11D3A, 0x11D3A, 12),
+    (0x11D3B, 0x11D3B, 2), (0x11D3C, 0x11D3D, 12), (0x11D3E, 0x11D3E, 2), (0x11D3F, 0x11D45, 12),
+    (0x11D46, 0x11D46, 7), (0x11D47, 0x11D47, 12), (0x11D48, 0x11D4F, 2), (0x11D50, 0x11D59, 13),
+    (0x11D5A, 0x11D5F, 2), (0x11D60, 0x11D65, 7), (0x11D66, 0x11D66, 2), (0x11D67, 0x11D68, 7),
+    (0x11D69, 0x11D69, 2), (0x11D6A, 0x11D89, 7), (0x11D8A, 0x11D8E, 10), (0x11D8F, 0x11D8F, 2),
+    (0x11D90, 0x11D91, 12), (0x11D92, 0x11D92, 2), (0x11D93, 0x11D94, 10), (0x11D95, 0x11D95, 12),
+    (0x11D96, 0x11D96, 10), (0x11D97, 0x11D97, 12), (0x11D98, 0x11D98, 7), (0x11D99, 0x11D9F, 2),
+    (0x11DA0, 0x11DA9, 13), (0x11DAA, 0x11DAF, 2), (0x11DB0, 0x11DD8, 7), (0x11DD9, 0x11DD9, 6),
+    (0x11DDA, 0x11DDB, 7), (0x11DDC, 0x11DDF, 2), (0x11DE0, 0x11DE9, 13), (0x11DEA, 0x11EDF, 2),
+    (0x11EE0, 0x11EF2, 7), (0x11EF3, 0x11EF4, 12), (0x11EF5, 0x11EF6, 10), (0x11EF7, 0x11EF8, 21),
+    (0x11EF9, 0x11EFF, 2), (0x11F00, 0x11F01, 12), (0x11F02, 0x11F02, 7), (0x11F03, 0x11F03, 10),
+    (0x11F04, 0x11F10, 7), (0x11F11, 0x11F11, 2), (0x11F12, 0x11F33, 7), (0x11F34, 0x11F35, 10),
+    (0x11F36, 0x11F3A, 12), (0x11F3B, 0x11F3D, 2), (0x11F3E, 0x11F3F, 10), (0x11F40, 0x11F40, 12),
+    (0x11F41, 0x11F41, 10), (0x11F42, 0x11F42, 12), (0x11F43, 0x11F4F, 21), (0x11F50, 0x11F59, 13),
+    (0x11F5A, 0x11F5A, 12), (0x11F5B, 0x11FAF, 2), (0x11FB0, 0x11FB0, 7), (0x11FB1, 0x11FBF, 2),
+    (0x11FC0, 0x11FD4, 15), (0x11FD5, 0x11FDC, 26), (0x11FDD, 0x11FE0, 23), (0x11FE1, 0x11FF1, 26),
+    (0x11FF2, 0x11FFE, 2), (0x11FFF, 0x11FFF, 21), (0x12000, 0x12399, 7), (0x1239A, 0x123FF, 2),
+    (0x12400, 0x1246E, 14), (0x1246F, 0x1246F, 2), (0x12470, 0x12474, 21), (0x12475, 0x1247F, 2),
+    (0x12480, 0x12543, 7), (0x12544, 0x12F8F, 2), (0x12F90, 0x12FF0, 7), (0x12FF1, 0x12FF2, 21),
+    (0x12FF3, 0x12FFF, 2), (0x13000, 0x1342F, 7), (0x13430, 0x1343F, 1), (0x13440, 0x13440, 12),
+    (0x13441, 0x13446, 7), (0x13447, 0x13455, 12), (0x13456, 0x1345F, 2), (0x13460, 0x143FA, 7),
+    (0x143FB, 0x143FF, 2), (0x14400, 0x14646, 7), (0x14647, 0x160FF, 2), (0x16100, 0x1611D, 7),
+    (0x1611E, 0x16129, 12), (0x1612A, 0x1612C, 10), (0x1612D, 0x1612F, 12), (0x16130, 0x16139, 13),
+    (0x1613A, 0x167FF, 2), (0x16800, 0x16A38, 7), (0x16A39, 0x16A3F, 2), (0x16A40, 0x16A5E, 7),
+    (0x16A5F, 0x16A5F, 2), (0x16A60, 0x16A69, 13), (0x16A6A, 0x16A6D, 2), (0x16A6E, 0x16A6F, 21),
+    (0x16A70, 0x16ABE, 7), (0x16ABF, 0x16ABF, 2), (0x16AC0, 0x16AC9, 13), (0x16ACA, 0x16ACF, 2),
+    (0x16AD0, 0x16AED, 7), (0x16AEE, 0x16AEF, 2), (0x16AF0, 0x16AF4, 12), (0x16AF5, 0x16AF5, 21),
+    (0x16AF6, 0x16AFF, 2), (0x16B00, 0x16B2F, 7), (0x16B30, 0x16B36, 12), (0x16B37, 0x16B3B, 21),
+    (0x16B3C, 0x16B3F, 26), (0x16B40, 0x16B43, 6), (0x16B44, 0x16B44, 21), (0x16B45, 0x16B45, 26),
+    (0x16B46, 0x16B4F, 2), (0x16B50, 0x16B59, 13), (0x16B5A, 0x16B5A, 2), (0x16B5B, 0x16B61, 15),
+    (0x16B62, 0x16B62, 2), (0x16B63, 0x16B77, 7), (0x16B78, 0x16B7C, 2), (0x16B7D, 0x16B8F, 7),
+    (0x16B90, 0x16D3F, 2), (0x16D40, 0x16D42, 6), (0x16D43, 0x16D6A, 7), (0x16D6B, 0x16D6C, 6),
+    (0x16D6D, 0x16D6F, 21), (0x16D70, 0x16D79, 13), (0x16D7A, 0x16E3F, 2), (0x16E40, 0x16E5F, 9),
+    (0x16E60, 0x16E7F, 5), (0x16E80, 0x16E96, 15), (0x16E97, 0x16E9A, 21), (0x16E9B, 0x16E9F, 2),
+    (0x16EA0, 0x16EB8, 9), (0x16EB9, 0x16EBA, 2), (0x16EBB, 0x16ED3, 5), (0x16ED4, 0x16EFF, 2),
+    (0x16F00, 0x16F4A, 7), (0x16F4B, 0x16F4E, 2), (0x16F4F, 0x16F4F, 12), (0x16F50, 0x16F50, 7),
+    (0x16F51, 0x16F87, 10), (0x16F88, 0x16F8E, 2), (0x16F8F, 0x16F92, 12), (0x16F93, 0x16F9F, 6),
+    (0x16FA0, 0x16FDF, 2), (0x16FE0, 0x16FE1, 6), (0x16FE2, 0x16FE2, 21), (0x16FE3, 0x16FE3, 6),
+    (0x16FE4, 0x16FE4, 12), (0x16FE5, 0x16FEF, 2), (0x16FF0, 0x16FF1, 10), (0x16FF2, 0x16FF3, 6),
+    (0x16FF4, 0x16FF6, 14), (0x16FF7, 0x16FFF, 2), (0x17000, 0x18CD5, 7), (0x18CD6, 0x18CFE, 2),
+    (0x18CFF, 0x18D1E, 7), (0x18D1F, 0x18D7F, 2), (0x18D80, 0x18DF2, 7), (0x18DF3, 0x1AFEF, 2),
+    (0x1AFF0, 0x1AFF3, 6), (0x1AFF4, 0x1AFF4, 2), (0x1AFF5, 0x1AFFB, 6), (0x1AFFC, 0x1AFFC, 2),
+    (0x1AFFD, 0x1AFFE, 6), (0x1AFFF, 0x1AFFF, 2), (0x1B000, 0x1B122, 7), (0x1B123, 0x1B131, 2),
+    (0x1B132, 0x1B132, 7), (0x1B133, 0x1B14F, 2), (0x1B150, 0x1B152, 7), (0x1B153, 0x1B154, 2),
+    (0x1B155, 0x1B155, 7), (0x1B156, 0x1B163, 2), (0x1B164, 0x1B167, 7), (0x1B168, 0x1B16F, 2),
+    (0x1B170, 0x1B2FB, 7), (0x1B2FC, 0x1BBFF, 2), (0x1BC00, 0x1BC6A, 7), (0x1BC6B, 0x1BC6F, 2),
+    (0x1BC70, 0x1BC7C, 7), (0x1BC7D, 0x1BC7F, 2), (0x1BC80, 0x1BC88, 7), (0x1BC89, 0x1BC8F, 2),
+    (0x1BC90, 0x1BC99, 7), (0x1BC9A, 0x1BC9B, 2), (0x1BC9C, 0x1BC9C, 26), (0x1BC9D, 0x1BC9E, 12),
+    (0x1BC9F, 0x1BC9F, 21), (0x1BCA0, 0x1BCA3, 1), (0x1BCA4, 0x1CBFF, 2), (0x1CC00, 0x1CCEF, 26),
+    (0x1CCF0, 0x1CCF9, 13), (0x1CCFA, 0x1CCFC, 26), (0x1CCFD, 0x1CCFF, 2), (0x1CD00, 0x1CEB3, 26),
+    (0x1CEB4, 0x1CEB9, 2), (0x1CEBA, 0x1CED0, 26), (0x1CED1, 0x1CEDF, 2), (0x1CEE0, 0x1CEEF, 26),
+    (0x1CEF0, 0x1CEF0, 25), (0x1CEF1, 0x1CEFF, 2), (0x1CF00, 0x1CF2D, 12), (0x1CF2E, 0x1CF2F, 2),
+    (0x1CF30, 0x1CF46, 12), (0x1CF47, 0x1CF4F, 2), (0x1CF50, 0x1CFC3, 26), (0x1CFC4, 0x1CFFF, 2),
+    (0x1D000, 0x1D0F5, 26), (0x1D0F6, 0x1D0FF, 2), (0x1D100, 0x1D126, 26), (0x1D127, 0x1D128, 2),
+    (0x1D129, 0x1D164, 26), (0x1D165, 0x1D166, 10), (0x1D167, 0x1D169, 12), (0x1D16A, 0x1D16C, 26),
+    (0x1D16D, 0x1D172, 10), (0x1D173, 0x1D17A, 1), (0x1D17B, 0x1D182, 12), (0x1D183, 0x1D184, 26),
+    (0x1D185, 0x1D18B, 12), (0x1D18C, 0x1D1A9, 26), (0x1D1AA, 0x1D1AD, 12), (0x1D1AE, 0x1D1EA, 26),
+    (0x1D1EB, 0x1D1FF, 2), (0x1D200, 0x1D241, 26), (0x1D242, 0x1D244, 12), (0x1D245, 0x1D245, 26),
+    (0x1D246, 0x1D2BF, 2), (0x1D2C0, 0x1D2D3, 15), (0x1D2D4, 0x1D2DF, 2), (0x1D2E0, 0x1D2F3, 15),
+    (0x1D2F4, 0x1D2FF, 2), (0x1D300, 0x1D356, 26), (0x1D357, 0x1D35F, 2), (0x1D360, 0x1D378, 15),
+    (0x1D379, 0x1D3FF, 2), (0x1D400, 0x1D419, 9), (0x1D41A, 0x1D433, 5), (0x1D434, 0x1D44D, 9),
+    (0x1D44E, 0x1D454, 5), (0x1D455, 0x1D455, 2), (0x1D456, 0x1D467, 5), (0x1D468, 0x1D481, 9),
+    (0x1D482, 0x1D49B, 5), (0x1D49C, 0x1D49C, 9), (0x1D49D, 0x1D49D, 2), (0x1D49E, 0x1D49F, 9),
+    (0x1D4A0, 0x1D4A1, 2), (0x1D4A2, 0x1D4A2, 9), (0x1D4A3, 0x1D4A4, 2), (0x1D4A5, 0x1D4A6, 9),
+    (0x1D4A7, 0x1D4A8, 2), (0x1D4A9, 0x1D4AC, 9), (0x1D4AD, 0x1D4AD, 2), (0x1D4AE, 0x1D4B5, 9),
+    (0x1D4B6, 0x1D4B9, 5), (0x1D4BA, 0x1D4BA, 2), (0x1D4BB, 0x1D4BB, 5), (0x1D4BC, 0x1D4BC, 2),
+    (0x1D4BD, 0x1D4C3, 5), (0x1D4C4, 0x1D4C4, 2), (0x1D4C5, 0x1D4CF, 5), (0x1D4D0, 0x1D4E9, 9),
+    (0x1D4EA, 0x1D503, 5), (0x1D504, 0x1D505, 9), (0x1D506, 0x1D506, 2), (0x1D507, 0x1D50A, 9),
+    (0x1D50B, 0x1D50C, 2), (0x1D50D, 0x1D514, 9), (0x1D515, 0x1D515, 2), (0x1D516, 0x1D51C, 9),
+    (0x1D51D, 0x1D51D, 2), (0x1D51E, 0x1D537, 5), (0x1D538, 0x1D539, 9), (0x1D53A, 0x1D53A, 2),
+    (0x1D53B, 0x1D53E, 9), (0x1D53F, 0x1D53F, 2), (0x1D540, 0x1D544, 9), (0x1D545, 0x1D545, 2),
+    (0x1D546, 0x1D546, 9), (0x1D547, 0x1D549, 2), (0x1D54A, 0x1D550, 9), (0x1D551, 0x1D551, 2),
+    (0x1D552, 0x1D56B, 5), (0x1D56C, 0x1D585, 9), (0x1D586, 0x1D59F, 5), (0x1D5A0, 0x1D5B9, 9),
+    (0x1D5BA, 0x1D5D3, 5), (0x1D5D4, 0x1D5ED, 9), (0x1D5EE, 0x1D607, 5), (0x1D608, 0x1D621, 9),
+    (0x1D622, 0x1D63B, 5), (0x1D63C, 0x1D655, 9), (0x1D656, 0x1D66F, 5), (0x1D670, 0x1D689, 9),
+    (0x1D68A, 0x1D6A5, 5), (0x1D6A6, 0x1D6A7, 2), (0x1D6A8, 0x1D6C0, 9), (0x1D6C1, 0x1D6C1, 25),
+    (0x1D6C2, 0x1D6DA, 5), (0x1D6DB, 0x1D6DB, 25), (0x1D6DC, 0x1D6E1, 5), (0x1D6E2, 0x1D6FA, 9),
+    (0x1D6FB, 0x1D6FB, 25), (0x1D6FC, 0x1D714, 5), (0x1D715, 0x1D715, 25), (0x1D716, 0x1D71B, 5),
+    (0x1D71C, 0x1D734, 9), (0x1D735, 0x1D735, 25), (0x1D736, 0x1D74E, 5), (0x1D74F, 0x1D74F, 25),
+    (0x1D750, 0x1D755, 5), (0x1D756, 0x1D76E, 9), (0x1D76F, 0x1D76F, 25), (0x1D770, 0x1D788, 5),
+    (0x1D789, 0x1D789, 25), (0x1D78A, 0x1D78F, 5), (0x1D790, 0x1D7A8, 9), (0x1D7A9, 0x1D7A9, 25),
+    (0x1D7AA, 0x1D7C2, 5), (0x1D7C3, 0x1D7C3, 25), (0x1D7C4, 0x1D7C9, 5), (0x1D7CA, 0x1D7CA, 9),
+    (0x1D7CB, 0x1D7CB, 5), (0x1D7CC, 0x1D7CD, 2), (0x1D7CE, 0x1D7FF, 13), (0x1D800, 0x1D9FF, 26),
+    (0x1DA00, 0x1DA36, 12), (0x1DA37, 0x1DA3A, 26), (0x1DA3B, 0x1DA6C, 12), (0x1DA6D, 0x1DA74, 26),
+    (0x1DA75, 0x1DA75, 12), (0x1DA76, 0x1DA83, 26), (0x1DA84, 0x1DA84, 12), (0x1DA85, 0x1DA86, 26),
+    (0x1DA87, 0x1DA8B, 21), (0x1DA8C, 0x1DA9A, 2), (0x1DA9B, 0x1DA9F, 12), (0x1DAA0, 0x1DAA0, 2),
+    (0x1DAA1, 0x1DAAF, 12), (0x1DAB0, 0x1DEFF, 2), (0x1DF00, 0x1DF09, 5), (0x1DF0A, 0x1DF0A, 7),
+    (0x1DF0B, 0x1DF1E, 5), (0x1DF1F, 0x1DF24, 2), (0x1DF25, 0x1DF2A, 5), (0x1DF2B, 0x1DFFF, 2),
+    (0x1E000, 0x1E006, 12), (0x1E007, 0x1E007, 2), (0x1E008, 0x1E018, 12), (0x1E019, 0x1E01A, 2),
+    (0x1E01B, 0x1E021, 12), (0x1E022, 0x1E022, 2), (0x1E023, 0x1E024, 12), (0x1E025, 0x1E025, 2),
+    (0x1E026, 0x1E02A, 12), (0x1E02B, 0x1E02F, 2), (0x1E030, 0x1E06D, 6), (0x1E06E, 0x1E08E, 2),
+    (0x1E08F, 0x1E08F, 12), (0x1E090, 0x1E0FF, 2), (0x1E100, 0x1E12C, 7), (0x1E12D, 0x1E12F, 2),
+    (0x1E130, 0x1E136, 12), (0x1E137, 0x1E13D, 6), (0x1E13E, 0x1E13F, 2), (0x1E140, 0x1E149, 13),
+    (0x1E14A, 0x1E14D, 2), (0x1E14E, 0x1E14E, 7), (0x1E14F, 0x1E14F, 26), (0x1E150, 0x1E28F, 2),
+    (0x1E290, 0x1E2AD, 7), (0x1E2AE, 0x1E2AE, 12), (0x1E2AF, 0x1E2BF, 2), (0x1E2C0, 0x1E2EB, 7),
+    (0x1E2EC, 0x1E2EF, 12), (0x1E2F0, 0x1E2F9, 13), (0x1E2FA, 0x1E2FE, 2), (0x1E2FF, 0x1E2FF, 23),
+    (0x1E300, 0x1E4CF, 2), (0x1E4D0, 0x1E4EA, 7), (0x1E4EB, 0x1E4EB, 6), (0x1E4EC, 0x1E4EF, 12),
+    (0x1E4F0, 0x1E4F9, 13), (0x1E4FA, 0x1E5CF, 2), (0x1E5D0, 0x1E5ED, 7), (0x1E5EE, 0x1E5EF, 12),
+    (0x1E5F0, 0x1E5F0, 7), (0x1E5F1, 0x1E5FA, 13), (0x1E5FB, 0x1E5FE, 2), (0x1E5FF, 0x1E5FF, 21),
+    (0x1E600, 0x1E6BF, 2), (0x1E6C0, 0x1E6DE, 7), (0x1E6DF, 0x1E6DF, 2), (0x1E6E0, 0x1E6E2, 7),
+    (0x1E6E3, 0x1E6E3, 12), (0x1E6E4, 0x1E6E5, 7), (0x1E6E6, 0x1E6E6, 12), (0x1E6E7, 0x1E6ED, 7),
+    (0x1E6EE, 0x1E6EF, 12), (0x1E6F0, 0x1E6F4, 7), (0x1E6F5, 0x1E6F5, 12), (0x1E6F6, 0x1E6FD, 2),
+    (0x1E6FE, 0x1E6FE, 7), (0x1E6FF, 0x1E6FF, 6), (0x1E700, 0x1E7DF, 2), (0x1E7E0, 0x1E7E6, 7),
+    (0x1E7E7, 0x1E7E7, 2), (0x1E7E8, 0x1E7EB, 7), (0x1E7EC, 0x1E7EC, 2), (0x1E7ED, 0x1E7EE, 7),
+    (0x1E7EF, 0x1E7EF, 2), (0x1E7F0, 0x1E7FE, 7), (0x1E7FF, 0x1E7FF, 2), (0x1E800, 0x1E8C4, 7),
+    (0x1E8C5, 0x1E8C6, 2), (0x1E8C7, 0x1E8CF, 15), (0x1E8D0, 0x1E8D6, 12), (0x1E8D7, 0x1E8FF, 2),
+    (0x1E900, 0x1E921, 9), (0x1E922, 0x1E943, 5), (0x1E944, 0x1E94A, 12), (0x1E94B, 0x1E94B, 6),
+    (0x1E94C, 0x1E94F, 2), (0x1E950, 0x1E959, 13), (0x1E95A, 0x1E95D, 2), (0x1E95E, 0x1E95F, 21),
+    (0x1E960, 0x1EC70, 2), (0x1EC71, 0x1ECAB, 15), (0x1ECAC, 0x1ECAC, 26), (0x1ECAD, 0x1ECAF, 15),
+    (0x1ECB0, 0x1ECB0, 23), (0x1ECB1, 0x1ECB4, 15), (0x1ECB5, 0x1ED00, 2), (0x1ED01, 0x1ED2D, 15),
+    (0x1ED2E, 0x1ED2E, 26), (0x1ED2F, 0x1ED3D, 15), (0x1ED3E, 0x1EDFF, 2), (0x1EE00, 0x1EE03, 7),
+    (0x1EE04, 0x1EE04, 2), (0x1EE05, 0x1EE1F, 7), (0x1EE20, 0x1EE20, 2), (0x1EE21, 0x1EE22, 7),
+    (0x1EE23, 0x1EE23, 2), (0x1EE24, 0x1EE24, 7), (0x1EE25, 0x1EE26, 2), (0x1EE27, 0x1EE27, 7),
+    (0x1EE28, 0x1EE28, 2), (0x1EE29, 0x1EE32, 7), (0x1EE33, 0x1EE33, 2), (0x1EE34, 0x1EE37, 7),
+    (0x1EE38, 0x1EE38, 2), (0x1EE39, 0x1EE39, 7), (0x1EE3A, 0x1EE3A, 2), (0x1EE3B, 0x1EE3B, 7),
+    (0x1EE3C, 0x1EE41, 2), (0x1EE42, 0x1EE42, 7), (0x1EE43, 0x1EE46, 2), (0x1EE47, 0x1EE47, 7),
+    (0x1EE48, 0x1EE48, 2), (0x1EE49, 0x1EE49, 7), (0x1EE4A, 0x1EE4A, 2), (0x1EE4B, 0x1EE4B, 7),
+    (0x1EE4C, 0x1EE4C, 2), (0x1EE4D, 0x1EE4F, 7), (0x1EE50, 0x1EE50, 2), (0x1EE51, 0x1EE52, 7),
+    (0x1EE53, 0x1EE53, 2), (0x1EE54, 0x1EE54, 7), (0x1EE55, 0x1EE56, 2), (0x1EE57, 0x1EE57, 7),
+    (0x1EE58, 0x1EE58, 2), (0x1EE59, 0x1EE59, 7), (0x1EE5A, 0x1EE5A, 2), (0x1EE5B, 0x1EE5B, 7),
+    (0x1EE5C, 0x1EE5C, 2), (0x1EE5D, 0x1EE5D, 7), (0x1EE5E, 0x1EE5E, 2), (0x1EE5F, 0x1EE5F, 7),
+    (0x1EE60, 0x1EE60, 2), (0x1EE61, 0x1EE62, 7), (0x1EE63, 0x1EE63, 2), (0x1EE64, 0x1EE64, 7),
+    (0x1EE65, 0x1EE66, 2), (0x1EE67, 0x1EE6A, 7), (0x1EE6B, 0x1EE6B, 2), (0x1EE6C, 0x1EE72, 7),
+    (0x1EE73, 0x1EE73, 2), (0x1EE74, 0x1EE77, 7), (0x1EE78, 0x1EE78, 2), (0x1EE79, 0x1EE7C, 7),
+    (0x1EE7D, 0x1EE7D, 2), (0x1EE7E, 0x1EE7E, 7), (0x1EE7F, 0x1EE7F, 2), (0x1EE80, 0x1EE89, 7),
+    (0x1EE8A, 0x1EE8A, 2), (0x1EE8B, 0x1EE9B, 7), (0x1EE9C, 0x1EEA0, 2), (0x1EEA1, 0x1EEA3, 7),
+    (0x1EEA4, 0x1EEA4, 2), (0x1EEA5, 0x1EEA9, 7), (0x1EEAA, 0x1EEAA, 2), (0x1EEAB, 0x1EEBB, 7),
+    (0x1EEBC, 0x1EEEF, 2), (0x1EEF0, 0x1EEF1, 25), (0x1EEF2, 0x1EFFF, 2), (0x1F000, 0x1F02B, 26),
+    (0x1F02C, 0x1F02F, 2), (0x1F030, 0x1F093, 26), (0x1F094, 0x1F09F, 2), (0x1F0A0, 0x1F0AE, 26),
+    (0x1F0AF, 0x1F0B0, 2), (0x1F0B1, 0x1F0BF, 26), (0x1F0C0, 0x1F0C0, 2), (0x1F0C1, 0x1F0CF, 26),
+    (0x1F0D0, 0x1F0D0, 2), (0x1F0D1, 0x1F0F5, 26), (0x1F0F6, 0x1F0FF, 2), (0x1F100, 0x1F10C, 15),
+    (0x1F10D, 0x1F1AD, 26), (0x1F1AE, 0x1F1E5, 2), (0x1F1E6, 0x1F202, 26), (0x1F203, 0x1F20F, 2),
+    (0x1F210, 0x1F23B, 26), (0x1F23C, 0x1F23F, 2), (0x1F240, 0x1F248, 26), (0x1F249, 0x1F24F, 2),
+    (0x1F250, 0x1F251, 26), (0x1F252, 0x1F25F, 2), (0x1F260, 0x1F265, 26), (0x1F266, 0x1F2FF, 2),
+    (0x1F300, 0x1F3FA, 26), (0x1F3FB, 0x1F3FF, 24), (0x1F400, 0x1F6D8, 26), (0x1F6D9, 0x1F6DB, 2),
+    (0x1F6DC, 0x1F6EC, 26), (0x1F6ED, 0x1F6EF, 2), (0x1F6F0, 0x1F6FC, 26), (0x1F6FD, 0x1F6FF, 2),
+    (0x1F700, 0x1F7D9, 26), (0x1F7DA, 0x1F7DF, 2), (0x1F7E0, 0x1F7EB, 26), (0x1F7EC, 0x1F7EF, 2),
+    (0x1F7F0, 0x1F7F0, 26), (0x1F7F1, 0x1F7FF, 2), (0x1F800, 0x1F80B, 26), (0x1F80C, 0x1F80F, 2),
+    (0x1F810, 0x1F847, 26), (0x1F848, 0x1F84F, 2), (0x1F850, 0x1F859, 26), (0x1F85A, 0x1F85F, 2),
+    (0x1F860, 0x1F887, 26), (0x1F888, 0x1F88F, 2), (0x1F890, 0x1F8AD, 26), (0x1F8AE, 0x1F8AF, 2),
+    (0x1F8B0, 0x1F8BB, 26), (0x1F8BC, 0x1F8BF, 2), (0x1F8C0, 0x1F8C1, 26), (0x1F8C2, 0x1F8CF, 2),
+    (0x1F8D0, 0x1F8D8, 25), (0x1F8D9, 0x1F8FF, 2), (0x1F900, 0x1FA57, 26), (0x1FA58, 0x1FA5F, 2),
+    (0x1FA60, 0x1FA6D, 26), (0x1FA6E, 0x1FA6F, 2), (0x1FA70, 0x1FA7C, 26), (0x1FA7D, 0x1FA7F, 2),
+    (0x1FA80, 0x1FA8A, 26), (0x1FA8B, 0x1FA8D, 2), (0x1FA8E, 0x1FAC6, 26), (0x1FAC7, 0x1FAC7, 2),
+    (0x1FAC8, 0x1FAC8, 26), (0x1FAC9, 0x1FACC, 2), (0x1FACD, 0x1FADC, 26), (0x1FADD, 0x1FADE, 2),
+    (0x1FADF, 0x1FAEA, 26), (0x1FAEB, 0x1FAEE, 2), (0x1FAEF, 0x1FAF8, 26), (0x1FAF9, 0x1FAFF, 2),
+    (0x1FB00, 0x1FB92, 26), (0x1FB93, 0x1FB93, 2), (0x1FB94, 0x1FBEF, 26), (0x1FBF0, 0x1FBF9, 13),
+    (0x1FBFA, 0x1FBFA, 26), (0x1FBFB, 0x1FFFF, 2), (0x20000, 0x2A6DF, 7), (0x2A6E0, 0x2A6FF, 2),
+    (0x2A700, 0x2B81D, 7), (0x2B81E, 0x2B81F, 2), (0x2B820, 0x2CEAD, 7), (0x2CEAE, 0x2CEAF, 2),
+    (0x2CEB0, 0x2EBE0, 7), (0x2EBE1, 0x2EBEF, 2), (0x2EBF0, 0x2EE5D, 7), (0x2EE5E, 0x2F7FF, 2),
+    (0x2F800, 0x2FA1D, 7), (0x2FA1E, 0x2FFFF, 2), (0x30000, 0x3134A, 7), (0x3134B, 0x3134F, 2),
+    (0x31350, 0x33479, 7), (0x3347A, 0xE0000, 2), (0xE0001, 0xE0001, 1), (0xE0002, 0xE001F, 2),
+    (0xE0020, 0xE007F, 1), (0xE0080, 0xE00FF, 2), (0xE0100, 0xE01EF, 12), (0xE01F0, 0xEFFFF, 2),
+    (0xF0000, 0xFFFFD, 3), (0xFFFFE, 0xFFFFF, 2), (0x100000, 0x10FFFD, 3), (0x10FFFE, 0x10FFFF, 2),
+];
+
+/// The Script values, as their aliases; the first alias is the short name and the second the long one.
+pub(super) static SCRIPT_VALUES: &[&[&str]] = &[
+    &["Adlm", "Adlam"], &["Ahom", "Ahom"],
+    &["Hluw", "Anatolian_Hieroglyphs"], &["Arab", "Arabic"],
+    &["Armn", "Armenian"], &["Avst", "Avestan"],
+    &["Bali", "Balinese"], &["Bamu", "Bamum"],
+    &["Bass", "Bassa_Vah"], &["Batk", "Batak"],
+    &["Beng", "Bengali"], &["Berf", "Beria_Erfe"],
+    &["Bhks", "Bhaiksuki"], &["Bopo", "Bopomofo"],
+    &["Brah", "Brahmi"], &["Brai", "Braille"],
+    &["Bugi", "Buginese"], &["Buhd", "Buhid"],
+    &["Cans", "Canadian_Aboriginal"], &["Cari", "Carian"],
+    &["Aghb", "Caucasian_Albanian"], &["Cakm", "Chakma"],
+    &["Cham", "Cham"], &["Cher", "Cherokee"],
+    &["Chrs", "Chorasmian"], &["Zyyy", "Common"],
+    &["Copt", "Coptic", "Qaac"], &["Xsux", "Cuneiform"],
+    &["Cprt", "Cypriot"], &["Cpmn", "Cypro_Minoan"],
+    &["Cyrl", "Cyrillic"], &["Dsrt", "Deseret"],
+    &["Deva", "Devanagari"], &["Diak", "Dives_Akuru"],
+    &["Dogr", "Dogra"], &["Dupl", "Duployan"],
+    &["Egyp", "Egyptian_Hieroglyphs"], &["Elba", "Elbasan"],
+    &["Elym", "Elymaic"], &["Ethi", "Ethiopic"],
+    &["Gara", "Garay"], &["Geor", "Georgian"],
+    &["Glag", "Glagolitic"], &["Goth", "Gothic"],
+    &["Gran", "Grantha"], &["Grek", "Greek"],
+    &["Gujr", "Gujarati"], &["Gong", "Gunjala_Gondi"],
+    &["Guru", "Gurmukhi"], &["Gukh", "Gurung_Khema"],
+    &["Hani", "Han"], &["Hang", "Hangul"],
+    &["Rohg", "Hanifi_Rohingya"], &["Hano", "Hanunoo"],
+    &["Hatr", "Hatran"], &["Hebr", "Hebrew"],
+    &["Hira", "Hiragana"], &["Armi", "Imperial_Aramaic"],
+    &["Zinh", "Inherited", "Qaai"], &["Phli", "Inscriptional_Pahlavi"],
+    &["Prti", "Inscriptional_Parthian"], &["Java", "Javanese"],
+    &["Kthi", "Kaithi"], &["Knda", "Kannada"],
+    &["Kana", "Katakana"], &["Hrkt", "Katakana_Or_Hiragana"],
+    &["Kawi", "Kawi"], &["Kali", "Kayah_Li"],
+    &["Khar", "Kharoshthi"], &["Kits", "Khitan_Small_Script"],
+    &["Khmr", "Khmer"], &["Khoj", "Khojki"],
+    &["Sind", "Khudawadi"], &["Krai", "Kirat_Rai"],
+    &["Laoo", "Lao"], &["Latn", "Latin"],
+    &["Lepc", "Lepcha"], &["Limb", "Limbu"],
+    &["Lina", "Linear_A"], &["Linb", "Linear_B"],
+    &["Lisu", "Lisu"], &["Lyci", "Lycian"],
+    &["Lydi", "Lydian"], &["Mahj", "Mahajani"],
+    &["Maka", "Makasar"], &["Mlym", "Malayalam"],
+    &["Mand", "Mandaic"], &["Mani", "Manichaean"],
+    &["Marc", "Marchen"], &["Gonm", "Masaram_Gondi"],
+    &["Medf", "Medefaidrin"], &["Mtei", "Meetei_Mayek"],
+    &["Mend", "Mende_Kikakui"], &["Merc", "Meroitic_Cursive"],
+    &["Mero", "Meroitic_Hieroglyphs"], &["Plrd", "Miao"],
+    &["Modi", "Modi"], &["Mong", "Mongolian"],
+    &["Mroo", "Mro"], &["Mult", "Multani"],
+    &["Mymr", "Myanmar"], &["Nbat", "Nabataean"],
+    &["Nagm", "Nag_Mundari"], &["Nand", "Nandinagari"],
+    &["Talu", "New_Tai_Lue"], &["Newa", "Newa"],
+    &["Nkoo", "Nko"], &["Nshu", "Nushu"],
+    &["Hmnp", "Nyiakeng_Puachue_Hmong"], &["Ogam", "Ogham"],
+    &["Olck", "Ol_Chiki"], &["Onao", "Ol_Onal"],
+    &["Hung", "Old_Hungarian"], &["Ital", "Old_Italic"],
+    &["Narb", "Old_North_Arabian"], &["Perm", "Old_Permic"],
+    &["Xpeo", "Old_Persian"], &["Sogo", "Old_Sogdian"],
+    &["Sarb", "Old_South_Arabian"], &["Orkh", "Old_Turkic"],
+    &["Ougr", "Old_Uyghur"], &["Orya", "Oriya"],
+    &["Osge", "Osage"], &["Osma", "Osmanya"],
+    &["Hmng", "Pahawh_Hmong"], &["Palm", "Palmyrene"],
+    &["Pauc", "Pau_Cin_Hau"], &["Phag", "Phags_Pa"],
+    &["Phnx", "Phoenician"], &["Phlp", "Psalter_Pahlavi"],
+    &["Rjng", "Rejang"], &["Runr", "Runic"],
+    &["Samr", "Samaritan"], &["Saur", "Saurashtra"],
+    &["Shrd", "Sharada"], &["Shaw", "Shavian"],
+    &["Sidd", "Siddham"], &["Sidt", "Sidetic"],
+    &["Sgnw", "SignWriting"], &["Sinh", "Sinhala"],
+    &["Sogd", "Sogdian"], &["Sora", "Sora_Sompeng"],
+    &["Soyo", "Soyombo"], &["Sund", "Sundanese"],
+    &["Sunu", "Sunuwar"], &["Sylo", "Syloti_Nagri"],
+    &["Syrc", "Syriac"], &["Tglg", "Tagalog"],
+    &["Tagb", "Tagbanwa"], &["Tale", "Tai_Le"],
+    &["Lana", "Tai_Tham"], &["Tavt", "Tai_Viet"],
+    &["Tayo", "Tai_Yo"], &["Takr", "Takri"],
+    &["Taml", "Tamil"], &["Tnsa", "Tangsa"],
+    &["Tang", "Tangut"], &["Telu", "Telugu"],
+    &["Thaa", "Thaana"], &["Thai", "Thai"],
+    &["Tibt", "Tibetan"], &["Tfng", "Tifinagh"],
+    &["Tirh", "Tirhuta"], &["Todr", "Todhri"],
+    &["Tols", "Tolong_Siki"], &["Toto", "Toto"],
+    &["Tutg", "Tulu_Tigalari"], &["Ugar", "Ugaritic"],
+    &["Zzzz", "Unknown"], &["Vaii", "Vai"],
+    &["Vith", "Vithkuqi"], &["Wcho", "Wancho"],
+    &["Wara", "Warang_Citi"], &["Yezi", "Yezidi"],
+    &["Yiii", "Yi"], &["Zanb", "Zanabazar_Square"],
+];
+
+/// The Script of every code point, as `(first, last, index in SCRIPT_VALUES)`.
+pub(super) static SCRIPTS: &[(u32, u32, u8)] = &[
+    (0x0, 0x40, 25), (0x41, 0x5A, 75), (0x5B, 0x60, 25), (0x61, 0x7A, 75),
+    (0x7B, 0xA9, 25), (0xAA, 0xAA, 75), (0xAB, 0xB9, 25), (0xBA, 0xBA, 75),
+    (0xBB, 0xBF, 25), (0xC0, 0xD6, 75), (0xD7, 0xD7, 25), (0xD8, 0xF6, 75),
+    (0xF7, 0xF7, 25), (0xF8, 0x2B8, 75), (0x2B9, 0x2DF, 25), (0x2E0, 0x2E4, 75),
+    (0x2E5, 0x2E9, 25), (0x2EA, 0x2EB, 13), (0x2EC, 0x2FF, 25), (0x300, 0x36F, 58),
+    (0x370, 0x373, 45), (0x374, 0x374, 25), (0x375, 0x377, 45), (0x378, 0x379, 168),
+    (0x37A, 0x37D, 45), (0x37E, 0x37E, 25), (0x37F, 0x37F, 45), (0x380, 0x383, 168),
+    (0x384, 0x384, 45), (0x385, 0x385, 25), (0x386, 0x386, 45), (0x387, 0x387, 25),
+    (0x388, 0x38A, 45), (0x38B, 0x38B, 168), (0x38C, 0x38C, 45), (0x38D, 0x38D, 168),
+    (0x38E, 0x3A1, 45), (0x3A2, 0x3A2, 168), (0x3A3, 0x3E1, 45), (0x3E2, 0x3EF, 26),
+    (0x3F0, 0x3FF, 45), (0x400, 0x484, 30), (0x485, 0x486, 58), (0x487, 0x52F, 30),
+    (0x530, 0x530, 168), (0x531, 0x556, 4), (0x557, 0x558, 168), (0x559, 0x58A, 4),
+    (0x58B, 0x58C, 168), (0x58D, 0x58F, 4), (0x590, 0x590, 168), (0x591, 0x5C7, 55),
+    (0x5C8, 0x5CF, 168), (0x5D0, 0x5EA, 55), (0x5EB, 0x5EE, 168), (0x5EF, 0x5F4, 55),
+    (0x5F5, 0x5FF, 168), (0x600, 0x604, 3), (0x605, 0x605, 25), (0x606, 0x60B, 3),
+    (0x60C, 0x60C, 25), (0x60D, 0x61A, 3), (0x61B, 0x61B, 25), (0x61C, 0x61E, 3),
+    (0x61F, 0x61F, 25), (0x620, 0x63F, 3), (0x640, 0x640, 25), (0x641, 0x64A, 3),
+    (0x64B, 0x655, 58), (0x656, 0x66F, 3), (0x670, 0x670, 58), (0x671, 0x6DC, 3),
+    (0x6DD, 0x6DD, 25), (0x6DE, 0x6FF, 3), (0x700, 0x70D, 146), (0x70E, 0x70E, 168),
+    (0x70F, 0x74A, 146), (0x74B, 0x74C, 168), (0x74D, 0x74F, 146), (0x750, 0x77F, 3),
+    (0x780, 0x7B1, 158), (0x7B2, 0x7BF, 168), (0x7C0, 0x7FA, 106), (0x7FB, 0x7FC, 168),
+    (0x7FD, 0x7FF, 106), (0x800, 0x82D, 132), (0x82E, 0x82F, 168), (0x830, 0x83E, 132),
+    (0x83F, 0x83F, 168), (0x840, 0x85B, 86), (0x85C, 0x85D, 168), (0x85E, 0x85E, 86),
+    (0x85F, 0x85F, 168), (0x860, 0x86A, 146), (0x86B, 0x86F, 168), (0x870, 0x891, 3),
+    (0x892, 0x896, 168), (0x897, 0x8E1, 3), (0x8E2, 0x8E2, 25), (0x8E3, 0x8FF, 3),
+    (0x900, 0x950, 32), (0x951, 0x954, 58), (0x955, 0x963, 32), (0x964, 0x965, 25),
+    (0x966, 0x97F, 32), (0x980, 0x983, 10), (0x984, 0x984, 168), (0x985, 0x98C, 10),
+    (0x98D, 0x98E, 168), (0x98F, 0x990, 10), (0x991, 0x992, 168), (0x993, 0x9A8, 10),
+    (0x9A9, 0x9A9, 168), (0x9AA, 0x9B0, 10), (0x9B1, 0x9B1, 168), (0x9B2, 0x9B2, 10),
+    (0x9B3, 0x9B5, 168), (0x9B6, 0x9B9, 10), (0x9BA, 0x9BB, 168), (0x9BC, 0x9C4, 10),
+    (0x9C5, 0x9C6, 168), (0x9C7, 0x9C8, 10), (0x9C9, 0x9CA, 168), (0x9CB, 0x9CE, 10),
+    (0x9CF, 0x9D6, 168), (0x9D7, 0x9D7, 10), (0x9D8, 0x9DB, 168), (0x9DC, 0x9DD, 10),
+    (0x9DE, 0x9DE, 168), (0x9DF, 0x9E3, 10), (0x9E4, 0x9E5, 168), (0x9E6, 0x9FE, 10),
+    (0x9FF, 0xA00, 168), (0xA01, 0xA03, 48), (0xA04, 0xA04, 168), (0xA05, 0xA0A, 48),
+    (0xA0B, 0xA0E, 168), (0xA0F, 0xA10, 48), (0xA11, 0xA12, 168), (0xA13, 0xA28, 48),
+    (0xA29, 0xA29, 168), (0xA2A, 0xA30, 48), (0xA31, 0xA31, 168), (0xA32, 0xA33, 48),
+    (0xA34, 0xA34, 168), (0xA35, 0xA36, 48), (0xA37, 0xA37, 168), (0xA38, 0xA39, 48),
+    (0xA3A, 0xA3B, 168), (0xA3C, 0xA3C, 48), (0xA3D, 0xA3D, 168), (0xA3E, 0xA42, 48),
+    (0xA43, 0xA46, 168), (0xA47, 0xA48, 48), (0xA49, 0xA4A, 168), (0xA4B, 0xA4D, 48),
+    (0xA4E, 0xA50, 168), (0xA51, 0xA51, 48), (0xA52, 0xA58, 168), (0xA59, 0xA5C, 48),
+    (0xA5D, 0xA5D, 168), (0xA5E, 0xA5E, 48), (0xA5F, 0xA65, 168), (0xA66, 0xA76, 48),
+    (0xA77, 0xA80, 168), (0xA81, 0xA83, 46), (0xA84, 0xA84, 168), (0xA85, 0xA8D, 46),
+    (0xA8E, 0xA8E, 168), (0xA8F, 0xA91, 46), (0xA92, 0xA92, 168), (0xA93, 0xAA8, 46),
+    (0xAA9, 0xAA9, 168), (0xAAA, 0xAB0, 46), (0xAB1, 0xAB1, 168), (0xAB2, 0xAB3, 46),
+    (0xAB4, 0xAB4, 168), (0xAB5, 0xAB9, 46), (0xABA, 0xABB, 168), (0xABC, 0xAC5, 46),
+    (0xAC6, 0xAC6, 168), (0xAC7, 0xAC9, 46), (0xACA, 0xACA, 168), (0xACB, 0xACD, 46),
+    (0xACE, 0xACF, 168), (0xAD0, 0xAD0, 46), (0xAD1, 0xADF, 168), (0xAE0, 0xAE3, 46),
+    (0xAE4, 0xAE5, 168), (0xAE6, 0xAF1, 46), (0xAF2, 0xAF8, 168), (0xAF9, 0xAFF, 46),
+    (0xB00, 0xB00, 168), (0xB01, 0xB03, 121), (0xB04, 0xB04, 168), (0xB05, 0xB0C, 121),
+    (0xB0D, 0xB0E, 168), (0xB0F, 0xB10, 121), (0xB11, 0xB12, 168), (0xB13, 0xB28, 121),
+    (0xB29, 0xB29, 168), (0xB2A, 0xB30, 121), (0xB31, 0xB31, 168), (0xB32, 0xB33, 121),
+    (0xB34, 0xB34, 168), (0xB35, 0xB39, 121), (0xB3A, 0xB3B, 168), (0xB3C, 0xB44, 121),
+    (0xB45, 0xB46, 168), (0xB47, 0xB48, 121), (0xB49, 0xB4A, 168), (0xB4B, 0xB4D, 121),
+    (0xB4E, 0xB54, 168), (0xB55, 0xB57, 121), (0xB58, 0xB5B, 168), (0xB5C, 0xB5D, 121),
+    (0xB5E, 0xB5E, 168), (0xB5F, 0xB63, 121), (0xB64, 0xB65, 168), (0xB66, 0xB77, 121),
+    (0xB78, 0xB81, 168), (0xB82, 0xB83, 154), (0xB84, 0xB84, 168), (0xB85, 0xB8A, 154),
+    (0xB8B, 0xB8D, 168), (0xB8E, 0xB90, 154), (0xB91, 0xB91, 168), (0xB92, 0xB95, 154),
+    (0xB96, 0xB98, 168), (0xB99, 0xB9A, 154), (0xB9B, 0xB9B, 168), (0xB9C, 0xB9C, 154),
+    (0xB9D, 0xB9D, 168), (0xB9E, 0xB9F, 154), (0xBA0, 0xBA2, 168), (0xBA3, 0xBA4, 154),
+    (0xBA5, 0xBA7, 168), (0xBA8, 0xBAA, 154), (0xBAB, 0xBAD, 168), (0xBAE, 0xBB9, 154),
+    (0xBBA, 0xBBD, 168), (0xBBE, 0xBC2, 154), (0xBC3, 0xBC5, 168), (0xBC6, 0xBC8, 154),
+    (0xBC9, 0xBC9, 168), (0xBCA, 0xBCD, 154), (0xBCE, 0xBCF, 168), (0xBD0, 0xBD0, 154),
+    (0xBD1, 0xBD6, 168), (0xBD7, 0xBD7, 154), (0xBD8, 0xBE5, 168), (0xBE6, 0xBFA, 154),
+    (0xBFB, 0xBFF, 168), (0xC00, 0xC0C, 157), (0xC0D, 0xC0D, 168), (0xC0E, 0xC10, 157),
+    (0xC11, 0xC11, 168), (0xC12, 0xC28, 157), (0xC29, 0xC29, 168), (0xC2A, 0xC39, 157),
+    (0xC3A, 0xC3B, 168), (0xC3C, 0xC44, 157), (0xC45, 0xC45, 168), (0xC46, 0xC48, 157),
+    (0xC49, 0xC49, 168), (0xC4A, 0xC4D, 157), (0xC4E, 0xC54, 168), (0xC55, 0xC56, 157),
+    (0xC57, 0xC57, 168), (0xC58, 0xC5A, 157), (0xC5B, 0xC5B, 168), (0xC5C, 0xC5D, 157),
+    (0xC5E, 0xC5F, 168), (0xC60, 0xC63, 157), (0xC64, 0xC65, 168), (0xC66, 0xC6F, 157),
+    (0xC70, 0xC76, 168), (0xC77, 0xC7F, 157), (0xC80, 0xC8C, 63), (0xC8D, 0xC8D, 168),
+    (0xC8E, 0xC90, 63), (0xC91, 0xC91, 168), (0xC92, 0xCA8, 63), (0xCA9, 0xCA9, 168),
+    (0xCAA, 0xCB3, 63), (0xCB4, 0xCB4, 168), (0xCB5, 0xCB9, 63), (0xCBA, 0xCBB, 168),
+    (0xCBC, 0xCC4, 63), (0xCC5, 0xCC5, 168), (0xCC6, 0xCC8, 63), (0xCC9, 0xCC9, 168),
+    (0xCCA, 0xCCD, 63), (0xCCE, 0xCD4, 168), (0xCD5, 0xCD6, 63), (0xCD7, 0xCDB, 168),
+    (0xCDC, 0xCDE, 63), (0xCDF, 0xCDF, 168), (0xCE0, 0xCE3, 63), (0xCE4, 0xCE5, 168),
+    (0xCE6, 0xCEF, 63), (0xCF0, 0xCF0, 168), (0xCF1, 0xCF3, 63), (0xCF4, 0xCFF, 168),
+    (0xD00, 0xD0C, 85), (0xD0D, 0xD0D, 168), (0xD0E, 0xD10, 85), (0xD11, 0xD11, 168),
+    (0xD12, 0xD44, 85), (0xD45, 0xD45, 168), (0xD46, 0xD48, 85), (0xD49, 0xD49, 168),
+    (0xD4A, 0xD4F, 85), (0xD50, 0xD53, 168), (0xD54, 0xD63, 85), (0xD64, 0xD65, 168),
+    (0xD66, 0xD7F, 85), (0xD80, 0xD80, 168), (0xD81, 0xD83, 139), (0xD84, 0xD84, 168),
+    (0xD85, 0xD96, 139), (0xD97, 0xD99, 168), (0xD9A, 0xDB1, 139), (0xDB2, 0xDB2, 168),
+    (0xDB3, 0xDBB, 139), (0xDBC, 0xDBC, 168), (0xDBD, 0xDBD, 139), (0xDBE, 0xDBF, 168),
+    (0xDC0, 0xDC6, 139), (0xDC7, 0xDC9, 168), (0xDCA, 0xDCA, 139), (0xDCB, 0xDCE, 168),
+    (0xDCF, 0xDD4, 139), (0xDD5, 0xDD5, 168), (0xDD6, 0xDD6, 139), (0xDD7, 0xDD7, 168),
+    (0xDD8, 0xDDF, 139), (0xDE0, 0xDE5, 168), (0xDE6, 0xDEF, 139), (0xDF0, 0xDF1, 168),
+    (0xDF2, 0xDF4, 139), (0xDF5, 0xE00, 168), (0xE01, 0xE3A, 159), (0xE3B, 0xE3E, 168),
+    (0xE3F, 0xE3F, 25), (0xE40, 0xE5B, 159), (0xE5C, 0xE80, 168), (0xE81, 0xE82, 74),
+    (0xE83, 0xE83, 168), (0xE84, 0xE84, 74), (0xE85, 0xE85, 168), (0xE86, 0xE8A, 74),
+    (0xE8B, 0xE8B, 168), (0xE8C, 0xEA3, 74), (0xEA4, 0xEA4, 168), (0xEA5, 0xEA5, 74),
+    (0xEA6, 0xEA6, 168), (0xEA7, 0xEBD, 74), (0xEBE, 0xEBF, 168), (0xEC0, 0xEC4, 74),
+    (0xEC5, 0xEC5, 168), (0xEC6, 0xEC6, 74), (0xEC7, 0xEC7, 168), (0xEC8, 0xECE, 74),
+    (0xECF, 0xECF, 168), (0xED0, 0xED9, 74), (0xEDA, 0xEDB, 168), (0xEDC, 0xEDF, 74),
+    (0xEE0, 0xEFF, 168), (0xF00, 0xF47, 160), (0xF48, 0xF48, 168), (0xF49, 0xF6C, 160),
+    (0xF6D, 0xF70, 168), (0xF71, 0xF97, 160), (0xF98, 0xF98, 168), (0xF99, 0xFBC, 160),
+    (0xFBD, 0xFBD, 168), (0xFBE, 0xFCC, 160), (0xFCD, 0xFCD, 168), (0xFCE, 0xFD4, 160),
+    (0xFD5, 0xFD8, 25), (0xFD9, 0xFDA, 160), (0xFDB, 0xFFF, 168), (0x1000, 0x109F, 100),
+    (0x10A0, 0x10C5, 41), (0x10C6, 0x10C6, 168), (0x10C7, 0x10C7, 41), (0x10C8, 0x10CC, 168),
+    (0x10CD, 0x10CD, 41), (0x10CE, 0x10CF, 168), (0x10D0, 0x10FA, 41), (0x10FB, 0x10FB, 25),
+    (0x10FC, 0x10FF, 41), (0x1100, 0x11FF, 51), (0x1200, 0x1248, 39), (0x1249, 0x1249, 168),
+    (0x124A, 0x124D, 39), (0x124E, 0x124F, 168), (0x1250, 0x1256, 39), (0x1257, 0x1257, 168),
+    (0x1258, 0x1258, 39), (0x1259, 0x1259, 168), (0x125A, 0x125D, 39), (0x125E, 0x125F, 168),
+    (0x1260, 0x1288, 39), (0x1289, 0x1289, 168), (0x128A, 0x128D, 39), (0x128E, 0x128F, 168),
+    (0x1290, 0x12B0, 39), (0x12B1, 0x12B1, 168), (0x12B2, 0x12B5, 39), (0x12B6, 0x12B7, 168),
+    (0x12B8, 0x12BE, 39), (0x12BF, 0x12BF, 168), (0x12C0, 0x12C0, 39), (0x12C1, 0x12C1, 168),
+    (0x12C2, 0x12C5, 39), (0x12C6, 0x12C7, 168), (0x12C8, 0x12D6, 39), (0x12D7, 0x12D7, 168),
+    (0x12D8, 0x1310, 39), (0x1311, 0x1311, 168), (0x1312, 0x1315, 39), (0x1316, 0x1317, 168),
+    (0x1318, 0x135A, 39), (0x135B, 0x135C, 168), (0x135D, 0x137C, 39), (0x137D, 0x137F, 168),
+    (0x1380, 0x1399, 39), (0x139A, 0x139F, 168), (0x13A0, 0x13F5, 23), (0x13F6, 0x13F7, 168),
+    (0x13F8, 0x13FD, 23), (0x13FE, 0x13FF, 168), (0x1400, 0x167F, 18), (0x1680, 0x169C, 109),
+    (0x169D, 0x169F, 168), (0x16A0, 0x16EA, 131), (0x16EB, 0x16ED, 25), (0x16EE, 0x16F8, 131),
+    (0x16F9, 0x16FF, 168), (0x1700, 0x1715, 147), (0x1716, 0x171E, 168), (0x171F, 0x171F, 147),
+    (0x1720, 0x1734, 53), (0x1735, 0x1736, 25), (0x1737, 0x173F, 168), (0x1740, 0x1753, 17),
+    (0x1754, 0x175F, 168), (0x1760, 0x176C, 148), (0x176D, 0x176D, 168), (0x176E, 0x1770, 148),
+    (0x1771, 0x1771, 168), (0x1772, 0x1773, 148), (0x1774, 0x177F, 168), (0x1780, 0x17DD, 70),
+    (0x17DE, 0x17DF, 168), (0x17E0, 0x17E9, 70), (0x17EA, 0x17EF, 168), (0x17F0, 0x17F9, 70),
+    (0x17FA, 0x17FF, 168), (0x1800, 0x1801, 97), (0x1802, 0x1803, 25), (0x1804, 0x1804, 97),
+    (0x1805, 0x1805, 25), (0x1806, 0x1819, 97), (0x181A, 0x181F, 168), (0x1820, 0x1878, 97),
+    (0x1879, 0x187F, 168), (0x1880, 0x18AA, 97), (0x18AB, 0x18AF, 168), (0x18B0, 0x18F5, 18),
+    (0x18F6, 0x18FF, 168), (0x1900, 0x191E, 77), (0x191F, 0x191F, 168), (0x1920, 0x192B, 77),
+    (0x192C, 0x192F, 168), (0x1930, 0x193B, 77), (0x193C, 0x193F, 168), (0x1940, 0x1940, 77),
+    (0x1941, 0x1943, 168), (0x1944, 0x194F, 77), (0x1950, 0x196D, 149), (0x196E, 0x196F, 168),
+    (0x1970, 0x1974, 149), (0x1975, 0x197F, 168), (0x1980, 0x19AB, 104), (0x19AC, 0x19AF, 168),
+    (0x19B0, 0x19C9, 104), (0x19CA, 0x19CF, 168), (0x19D0, 0x19DA, 104), (0x19DB, 0x19DD, 168),
+    (0x19DE, 0x19DF, 104), (0x19E0, 0x19FF, 70), (0x1A00, 0x1A1B, 16), (0x1A1C, 0x1A1D, 168),
+    (0x1A1E, 0x1A1F, 16), (0x1A20, 0x1A5E, 150), (0x1A5F, 0x1A5F, 168), (0x1A60, 0x1A7C, 150),
+    (0x1A7D, 0x1A7E, 168), (0x1A7F, 0x1A89, 150), (0x1A8A, 0x1A8F, 168), (0x1A90, 0x1A99, 150),
+    (0x1A9A, 0x1A9F, 168), (0x1AA0, 0x1AAD, 150), (0x1AAE, 0x1AAF, 168), (0x1AB0, 0x1ADD, 58),
+    (0x1ADE, 0x1ADF, 168), (0x1AE0, 0x1AEB, 58), (0x1AEC, 0x1AFF, 168), (0x1B00, 0x1B4C, 6),
+    (0x1B4D, 0x1B4D, 168), (0x1B4E, 0x1B7F, 6), (0x1B80, 0x1BBF, 143), (0x1BC0, 0x1BF3, 9),
+    (0x1BF4, 0x1BFB, 168), (0x1BFC, 0x1BFF, 9), (0x1C00, 0x1C37, 76), (0x1C38, 0x1C3A, 168),
+    (0x1C3B, 0x1C49, 76), (0x1C4A, 0x1C4C, 168), (0x1C4D, 0x1C4F, 76), (0x1C50, 0x1C7F, 110),
+    (0x1C80, 0x1C8A, 30), (0x1C8B, 0x1C8F, 168), (0x1C90, 0x1CBA, 41), (0x1CBB, 0x1CBC, 168),
+    (0x1CBD, 0x1CBF, 41), (0x1CC0, 0x1CC7, 143), (0x1CC8, 0x1CCF, 168), (0x1CD0, 0x1CD2, 58),
+    (0x1CD3, 0x1CD3, 25), (0x1CD4, 0x1CE0, 58), (0x1CE1, 0x1CE1, 25), (0x1CE2, 0x1CE8, 58),
+    (0x1CE9, 0x1CEC, 25), (0x1CED, 0x1CED, 58), (0x1CEE, 0x1CF3, 25), (0x1CF4, 0x1CF4, 58),
+    (0x1CF5, 0x1CF7, 25), (0x1CF8, 0x1CF9, 58), (0x1CFA, 0x1CFA, 25), (0x1CFB, 0x1CFF, 168),
+    (0x1D00, 0x1D25, 75), (0x1D26, 0x1D2A, 45), (0x1D2B, 0x1D2B, 30), (0x1D2C, 0x1D5C, 75),
+    (0x1D5D, 0x1D61, 45), (0x1D62, 0x1D65, 75), (0x1D66, 0x1D6A, 45), (0x1D6B, 0x1D77, 75),
+    (0x1D78, 0x1D78, 30), (0x1D79, 0x1DBE, 75), (0x1DBF, 0x1DBF, 45), (0x1DC0, 0x1DFF, 58),
+    (0x1E00, 0x1EFF, 75), (0x1F00, 0x1F15, 45), (0x1F16, 0x1F17, 168), (0x1F18, 0x1F1D, 45),
+    (0x1F1E, 0x1F1F, 168), (0x1F20, 0x1F45, 45), (0x1F46, 0x1F47, 168), (0x1F48, 0x1F4D, 45),
+    (0x1F4E, 0x1F4F, 168), (0x1F50, 0x1F57, 45), (0x1F58, 0x1F58, 168), (0x1F59, 0x1F59, 45),
+    (0x1F5A, 0x1F5A, 168), (0x1F5B, 0x1F5B, 45), (0x1F5C, 0x1F5C, 168), (0x1F5D, 0x1F5D, 45),
+    (0x1F5E, 0x1F5E, 168), (0x1F5F, 0x1F7D, 45), (0x1F7E, 0x1F7F, 168), (0x1F80, 0x1FB4, 45),
+    (0x1FB5, 0x1FB5, 168), (0x1FB6, 0x1FC4, 45), (0x1FC5, 0x1FC5, 168), (0x1FC6, 0x1FD3, 45),
+    (0x1FD4, 0x1FD5, 168), (0x1FD6, 0x1FDB, 45), (0x1FDC, 0x1FDC, 168), (0x1FDD, 0x1FEF, 45),
+    (0x1FF0, 0x1FF1, 168), (0x1FF2, 0x1FF4, 45), (0x1FF5, 0x1FF5, 168), (0x1FF6, 0x1FFE, 45),
+    (0x1FFF, 0x1FFF, 168), (0x2000, 0x200B, 25), (0x200C, 0x200D, 58), (0x200E, 0x2064, 25),
+    (0x2065, 0x2065, 168), (0x2066, 0x2070, 25), (0x2071, 0x2071, 75), (0x2072, 0x2073, 168),
+    (0x2074, 0x207E, 25), (0x207F, 0x207F, 75), (0x2080, 0x208E, 25), (0x208F, 0x208F, 168),
+    (0x2090, 0x209C, 75), (0x209D, 0x209F, 168), (0x20A0, 0x20C1, 25), (0x20C2, 0x20CF, 168),
+    (0x20D0, 0x20F0, 58), (0x20F1, 0x20FF, 168), (0x2100, 0x2125, 25), (0x2126, 0x2126, 45),
+    (0x2127, 0x2129, 25), (0x212A, 0x212B, 75), (0x212C, 0x2131, 25), (0x2132, 0x2132, 75),
+    (0x2133, 0x214D, 25), (0x214E, 0x214E, 75), (0x214F, 0x215F, 25), (0x2160, 0x2188, 75),
+    (0x2189, 0x218B, 25), (0x218C, 0x218F, 168), (0x2190, 0x2429, 25), (0x242A, 0x243F, 168),
+    (0x2440, 0x244A, 25), (0x244B, 0x245F, 168), (0x2460, 0x27FF, 25), (0x2800, 0x28FF, 15),
+    (0x2900, 0x2B73, 25), (0x2B74, 0x2B75, 168), (0x2B76, 0x2BFF, 25), (0x2C00, 0x2C5F, 42),
+    (0x2C60, 0x2C7F, 75), (0x2C80, 0x2CF3, 26), (0x2CF4, 0x2CF8, 168), (0x2CF9, 0x2CFF, 26),
+    (0x2D00, 0x2D25, 41), (0x2D26, 0x2D26, 168), (0x2D27, 0x2D27, 41), (0x2D28, 0x2D2C, 168),
+    (0x2D2D, 0x2D2D, 41), (0x2D2E, 0x2D2F, 168), (0x2D30, 0x2D67, 161), (0x2D68, 0x2D6E, 168),
+    (0x2D6F, 0x2D70, 161), (0x2D71, 0x2D7E, 168), (0x2D7F, 0x2D7F, 161), (0x2D80, 0x2D96, 39),
+    (0x2D97, 0x2D9F, 168), (0x2DA0, 0x2DA6, 39), (0x2DA7, 0x2DA7, 168), (0x2DA8, 0x2DAE, 39),
+    (0x2DAF, 0x2DAF, 168), (0x2DB0, 0x2DB6, 39), (0x2DB7, 0x2DB7, 168), (0x2DB8, 0x2DBE, 39),
+    (0x2DBF, 0x2DBF, 168), (0x2DC0, 0x2DC6, 39), (0x2DC7, 0x2DC7, 168), (0x2DC8, 0x2DCE, 39),
+    (0x2DCF, 0x2DCF, 168), (0x2DD0, 0x2DD6, 39), (0x2DD7, 0x2DD7, 168), (0x2DD8, 0x2DDE, 39),
+    (0x2DDF, 0x2DDF, 168), (0x2DE0, 0x2DFF, 30), (0x2E00, 0x2E5D, 25), (0x2E5E, 0x2E7F, 168),
+    (0x2E80, 0x2E99, 50), (0x2E9A, 0x2E9A, 168), (0x2E9B, 0x2EF3, 50), (0x2EF4, 0x2EFF, 168),
+    (0x2F00, 0x2FD5, 50), (0x2FD6, 0x2FEF, 168), (0x2FF0, 0x3004, 25), (0x3005, 0x3005, 50),
+    (0x3006, 0x3006, 25), (0x3007, 0x3007, 50), (0x3008, 0x3020, 25), (0x3021, 0x3029, 50),
+    (0x302A, 0x302D, 58), (0x302E, 0x302F, 51), (0x3030, 0x3037, 25), (0x3038, 0x303B, 50),
+    (0x303C, 0x303F, 25), (0x3040, 0x3040, 168), (0x3041, 0x3096, 56), (0x3097, 0x3098, 168),
+    (0x3099, 0x309A, 58), (0x309B, 0x309C, 25), (0x309D, 0x309F, 56), (0x30A0, 0x30A0, 25),
+    (0x30A1, 0x30FA, 64), (0x30FB, 0x30FC, 25), (0x30FD, 0x30FF, 64), (0x3100, 0x3104, 168),
+    (0x3105, 0x312F, 13), (0x3130, 0x3130, 168), (0x3131, 0x318E, 51), (0x318F, 0x318F, 168),
+    (0x3190, 0x319F, 25), (0x31A0, 0x31BF, 13), (0x31C0, 0x31E5, 25), (0x31E6, 0x31EE, 168),
+    (0x31EF, 0x31EF, 25), (0x31F0, 0x31FF, 64), (0x3200, 0x321E, 51), (0x321F, 0x321F, 168),
+    (0x3220, 0x325F, 25), (0x3260, 0x327E, 51), (0x327F, 0x32CF, 25), (0x32D0, 0x32FE, 64),
+    (0x32FF, 0x32FF, 25), (0x3300, 0x3357, 64), (0x3358, 0x33FF, 25), (0x3400, 0x4DBF, 50),
+    (0x4DC0, 0x4DFF, 25), (0x4E00, 0x9FFF, 50), (0xA000, 0xA48C, 174), (0xA48D, 0xA48F, 168),
+    (0xA490, 0xA4C6, 174), (0xA4C7, 0xA4CF, 168), (0xA4D0, 0xA4FF, 80), (0xA500, 0xA62B, 169),
+    (0xA62C, 0xA63F, 168), (0xA640, 0xA69F, 30), (0xA6A0, 0xA6F7, 7), (0xA6F8, 0xA6FF, 168),
+    (0xA700, 0xA721, 25), (0xA722, 0xA787, 75), (0xA788, 0xA78A, 25), (0xA78B, 0xA7DC, 75),
+    (0xA7DD, 0xA7F0, 168), (0xA7F1, 0xA7FF, 75), (0xA800, 0xA82C, 145), (0xA82D, 0xA82F, 168),
+    (0xA830, 0xA839, 25), (0xA83A, 0xA83F, 168), (0xA840, 0xA877, 127), (0xA878, 0xA87F, 168),
+    (0xA880, 0xA8C5, 133), (0xA8C6, 0xA8CD, 168), (0xA8CE, 0xA8D9, 133), (0xA8DA, 0xA8DF, 168),
+    (0xA8E0, 0xA8FF, 32), (0xA900, 0xA92D, 67), (0xA92E, 0xA92E, 25), (0xA92F, 0xA92F, 67),
+    (0xA930, 0xA953, 130), (0xA954, 0xA95E, 168), (0xA95F, 0xA95F, 130), (0xA960, 0xA97C, 51),
+    (0xA97D, 0xA97F, 168), (0xA980, 0xA9CD, 61), (0xA9CE, 0xA9CE, 168), (0xA9CF, 0xA9CF, 25),
+    (0xA9D0, 0xA9D9, 61), (0xA9DA, 0xA9DD, 168), (0xA9DE, 0xA9DF, 61), (0xA9E0, 0xA9FE, 100),
+    (0xA9FF, 0xA9FF, 168), (0xAA00, 0xAA36, 22), (0xAA37, 0xAA3F, 168), (0xAA40, 0xAA4D, 22),
+    (0xAA4E, 0xAA4F, 168), (0xAA50, 0xAA59, 22), (0xAA5A, 0xAA5B, 168), (0xAA5C, 0xAA5F, 22),
+    (0xAA60, 0xAA7F, 100), (0xAA80, 0xAAC2, 151), (0xAAC3, 0xAADA, 168), (0xAADB, 0xAADF, 151),
+    (0xAAE0, 0xAAF6, 91), (0xAAF7, 0xAB00, 168), (0xAB01, 0xAB06, 39), (0xAB07, 0xAB08, 168),
+    (0xAB09, 0xAB0E, 39), (0xAB0F, 0xAB10, 168), (0xAB11, 0xAB16, 39), (0xAB17, 0xAB1F, 168),
+    (0xAB20, 0xAB26, 39), (0xAB27, 0xAB27, 168), (0xAB28, 0xAB2E, 39), (0xAB2F, 0xAB2F, 168),
+    (0xAB30, 0xAB5A, 75), (0xAB5B, 0xAB5B, 25), (0xAB5C, 0xAB64, 75), (0xAB65, 0xAB65, 45),
+    (0xAB66, 0xAB69, 75), (0xAB6A, 0xAB6B, 25), (0xAB6C, 0xAB6F, 168), (0xAB70, 0xABBF, 23),
+    (0xABC0, 0xABED, 91), (0xABEE, 0xABEF, 168), (0xABF0, 0xABF9, 91), (0xABFA, 0xABFF, 168),
+    (0xAC00, 0xD7A3, 51), (0xD7A4, 0xD7AF, 168), (0xD7B0, 0xD7C6, 51), (0xD7C7, 0xD7CA, 168),
+    (0xD7CB, 0xD7FB, 51), (0xD7FC, 0xF8FF, 168), (0xF900, 0xFA6D, 50), (0xFA6E, 0xFA6F, 168),
+    (0xFA70, 0xFAD9, 50), (0xFADA, 0xFAFF, 168), (0xFB00, 0xFB06, 75), (0xFB07, 0xFB12, 168),
+    (0xFB13, 0xFB17, 4), (0xFB18, 0xFB1C, 168), (0xFB1D, 0xFB36, 55), (0xFB37, 0xFB37, 168),
+    (0xFB38, 0xFB3C, 55), (0xFB3D, 0xFB3D, 168), (0xFB3E, 0xFB3E, 55), (0xFB3F, 0xFB3F, 168),
+    (0xFB40, 0xFB41, 55), (0xFB42, 0xFB42, 168), (0xFB43, 0xFB44, 55), (0xFB45, 0xFB45, 168),
+    (0xFB46, 0xFB4F, 55), (0xFB50, 0xFD3D, 3), (0xFD3E, 0xFD3F, 25), (0xFD40, 0xFDCF, 3),
+    (0xFDD0, 0xFDEF, 168), (0xFDF0, 0xFDFF, 3), (0xFE00, 0xFE0F, 58), (0xFE10, 0xFE19, 25),
+    (0xFE1A, 0xFE1F, 168), (0xFE20, 0xFE2D, 58), (0xFE2E, 0xFE2F, 30), (0xFE30, 0xFE52, 25),
+    (0xFE53, 0xFE53, 168), (0xFE54, 0xFE66, 25), (0xFE67, 0xFE67, 168), (0xFE68, 0xFE6B, 25),
+    (0xFE6C, 0xFE6F, 168), (0xFE70, 0xFE74, 3), (0xFE75, 0xFE75, 168), (0xFE76, 0xFEFC, 3),
+    (0xFEFD, 0xFEFE, 168), (0xFEFF, 0xFEFF, 25), (0xFF00, 0xFF00, 168), (0xFF01, 0xFF20, 25),
+    (0xFF21, 0xFF3A, 75), (0xFF3B, 0xFF40, 25), (0xFF41, 0xFF5A, 75), (0xFF5B, 0xFF65, 25),
+    (0xFF66, 0xFF6F, 64), (0xFF70, 0xFF70, 25), (0xFF71, 0xFF9D, 64), (0xFF9E, 0xFF9F, 25),
+    (0xFFA0, 0xFFBE, 51), (0xFFBF, 0xFFC1, 168), (0xFFC2, 0xFFC7, 51), (0xFFC8, 0xFFC9, 168),
+    (0xFFCA, 0xFFCF, 51), (0xFFD0, 0xFFD1, 168), (0xFFD2, 0xFFD7, 51), (0xFFD8, 0xFFD9, 168),
+    (0xFFDA, 0xFFDC, 51), (0xFFDD, 0xFFDF, 168), (0xFFE0, 0xFFE6, 25), (0xFFE7, 0xFFE7, 168),
+    (0xFFE8, 0xFFEE, 25), (0xFFEF, 0xFFF8, 168), (0xFFF9, 0xFFFD, 25), (0xFFFE, 0xFFFF, 168),
+    (0x10000, 0x1000B, 79), (0x1000C, 0x1000C, 168), (0x1000D, 0x10026, 79), (0x10027, 0x10027, 168),
+    (0x10028, 0x1003A, 79), (0x1003B, 0x1003B, 168), (0x1003C, 0x1003D, 79), (0x1003E, 0x1003E, 168),
+    (0x1003F, 0x1004D, 79), (0x1004E, 0x1004F, 168), (0x10050, 0x1005D, 79), (0x1005E, 0x1007F, 168),
+    (0x10080, 0x100FA, 79), (0x100FB, 0x100FF, 168), (0x10100, 0x10102, 25), (0x10103, 0x10106, 168),
+    (0x10107, 0x10133, 25), (0x10134, 0x10136, 168), (0x10137, 0x1013F, 25), (0x10140, 0x1018E, 45),
+    (0x1018F, 0x1018F, 168), (0x10190, 0x1019C, 25), (0x1019D, 0x1019F, 168), (0x101A0, 0x101A0, 45),
+    (0x101A1, 0x101CF, 168), (0x101D0, 0x101FC, 25), (0x101FD, 0x101FD, 58), (0x101FE, 0x1027F, 168),
+    (0x10280, 0x1029C, 81), (0x1029D, 0x1029F, 168), (0x102A0, 0x102D0, 19), (0x102D1, 0x102DF, 168),
+    (0x102E0, 0x102E0, 58), (0x102E1, 0x102FB, 25), (0x102FC, 0x102FF, 168), (0x10300, 0x10323, 113),
+    (0x10324, 0x1032C, 168), (0x1032D, 0x1032F, 113), (0x10330, 0x1034A, 43), (0x1034B, 0x1034F, 168),
+    (0x10350, 0x1037A, 115), (0x1037B, 0x1037F, 168), (0x10380, 0x1039D, 167), (0x1039E, 0x1039E, 168),
+    (0x1039F, 0x1039F, 167), (0x103A0, 0x103C3, 116), (0x103C4, 0x103C7, 168), (0x103C8, 0x103D5, 116),
+    (0x103D6, 0x103FF, 168), (0x10400, 0x1044F, 31), (0x10450, 0x1047F, 135), (0x10480, 0x1049D, 123),
+    (0x1049E, 0x1049F, 168), (0x104A0, 0x104A9, 123), (0x104AA, 0x104AF, 168), (0x104B0, 0x104D3, 122),
+    (0x104D4, 0x104D7, 168), (0x104D8, 0x104FB, 122), (0x104FC, 0x104FF, 168), (0x10500, 0x10527, 37),
+    (0x10528, 0x1052F, 168), (0x10530, 0x10563, 20), (0x10564, 0x1056E, 168), (0x1056F, 0x1056F, 20),
+    (0x10570, 0x1057A, 170), (0x1057B, 0x1057B, 168), (0x1057C, 0x1058A, 170), (0x1058B, 0x1058B, 168),
+    (0x1058C, 0x10592, 170), (0x10593, 0x10593, 168), (0x10594, 0x10595, 170), (0x10596, 0x10596, 168),
+    (0x10597, 0x105A1, 170), (0x105A2, 0x105A2, 168), (0x105A3, 0x105B1, 170), (0x105B2, 0x105B2, 168),
+    (0x105B3, 0x105B9, 170), (0x105BA, 0x105BA, 168), (0x105BB, 0x105BC, 170), (0x105BD, 0x105BF, 168),
+    (0x105C0, 0x105F3, 163), (0x105F4, 0x105FF, 168), (0x10600, 0x10736, 78), (0x10737, 0x1073F, 168),
+    (0x10740, 0x10755, 78), (0x10756, 0x1075F, 168), (0x10760, 0x10767, 78), (0x10768, 0x1077F, 168),
+    (0x10780, 0x10785, 75), (0x10786, 0x10786, 168), (0x10787, 0x107B0, 75), (0x107B1, 0x107B1, 168),
+    (0x107B2, 0x107BA, 75), (0x107BB, 0x107FF, 168), (0x10800, 0x10805, 28), (0x10806, 0x10807, 168),
+    (0x10808, 0x10808, 28), (0x10809, 0x10809, 168), (0x1080A, 0x10835, 28), (0x10836, 0x10836, 168),
+    (0x10837, 0x10838, 28), (0x10839, 0x1083B, 168), (0x1083C, 0x1083C, 28), (0x1083D, 0x1083E, 168),
+    (0x1083F, 0x1083F, 28), (0x10840, 0x10855, 57), (0x10856, 0x10856, 168), (0x10857, 0x1085F, 57),
+    (0x10860, 0x1087F, 125), (0x10880, 0x1089E, 101), (0x1089F, 0x108A6, 168), (0x108A7, 0x108AF, 101),
+    (0x108B0, 0x108DF, 168), (0x108E0, 0x108F2, 54), (0x108F3, 0x108F3, 168), (0x108F4, 0x108F5, 54),
+    (0x108F6, 0x108FA, 168), (0x108FB, 0x108FF, 54), (0x10900, 0x1091B, 128), (0x1091C, 0x1091E, 168),
+    (0x1091F, 0x1091F, 128), (0x10920, 0x10939, 82), (0x1093A, 0x1093E, 168), (0x1093F, 0x1093F, 82),
+    (0x10940, 0x10959, 137), (0x1095A, 0x1097F, 168), (0x10980, 0x1099F, 94), (0x109A0, 0x109B7, 93),
+    (0x109B8, 0x109BB, 168), (0x109BC, 0x109CF, 93), (0x109D0, 0x109D1, 168), (0x109D2, 0x109FF, 93),
+    (0x10A00, 0x10A03, 68), (0x10A04, 0x10A04, 168), (0x10A05, 0x10A06, 68), (0x10A07, 0x10A0B, 168),
+    (0x10A0C, 0x10A13, 68), (0x10A14, 0x10A14, 168), (0x10A15, 0x10A17, 68), (0x10A18, 0x10A18, 168),
+    (0x10A19, 0x10A35, 68), (0x10A36, 0x10A37, 168), (0x10A38, 0x10A3A, 68), (0x10A3B, 0x10A3E, 168),
+    (0x10A3F, 0x10A48, 68), (0x10A49, 0x10A4F, 168), (0x10A50, 0x10A58, 68), (0x10A59, 0x10A5F, 168),
+    (0x10A60, 0x10A7F, 118), (0x10A80, 0x10A9F, 114), (0x10AA0, 0x10ABF, 168), (0x10AC0, 0x10AE6, 87),
+    (0x10AE7, 0x10AEA, 168), (0x10AEB, 0x10AF6, 87), (0x10AF7, 0x10AFF, 168), (0x10B00, 0x10B35, 5),
+    (0x10B36, 0x10B38, 168), (0x10B39, 0x10B3F, 5), (0x10B40, 0x10B55, 60), (0x10B56, 0x10B57, 168),
+    (0x10B58, 0x10B5F, 60), (0x10B60, 0x10B72, 59), (0x10B73, 0x10B77, 168), (0x10B78, 0x10B7F, 59),
+    (0x10B80, 0x10B91, 129), (0x10B92, 0x10B98, 168), (0x10B99, 0x10B9C, 129), (0x10B9D, 0x10BA8, 168),
+    (0x10BA9, 0x10BAF, 129), (0x10BB0, 0x10BFF, 168), (0x10C00, 0x10C48, 119), (0x10C49, 0x10C7F, 168),
+    (0x10C80, 0x10CB2, 112), (0x10CB3, 0x10CBF, 168), (0x10CC0, 0x10CF2, 112), (0x10CF3, 0x10CF9, 168),
+    (0x10CFA, 0x10CFF, 112), (0x10D00, 0x10D27, 52), (0x10D28, 0x10D2F, 168), (0x10D30, 0x10D39, 52),
+    (0x10D3A, 0x10D3F, 168), (0x10D40, 0x10D65, 40), (0x10D66, 0x10D68, 168), (0x10D69, 0x10D85, 40),
+    (0x10D86, 0x10D8D, 168), (0x10D8E, 0x10D8F, 40), (0x10D90, 0x10E5F, 168), (0x10E60, 0x10E7E, 3),
+    (0x10E7F, 0x10E7F, 168), (0x10E80, 0x10EA9, 173), (0x10EAA, 0x10EAA, 168), (0x10EAB, 0x10EAD, 173),
+    (0x10EAE, 0x10EAF, 168), (0x10EB0, 0x10EB1, 173), (0x10EB2, 0x10EC1, 168), (0x10EC2, 0x10EC7, 3),
+    (0x10EC8, 0x10ECF, 168), (0x10ED0, 0x10ED8, 3), (0x10ED9, 0x10EF9, 168), (0x10EFA, 0x10EFF, 3),
+    (0x10F00, 0x10F27, 117), (0x10F28, 0x10F2F, 168), (0x10F30, 0x10F59, 140), (0x10F5A, 0x10F6F, 168),
+    (0x10F70, 0x10F89, 120), (0x10F8A, 0x10FAF, 168), (0x10FB0, 0x10FCB, 24), (0x10FCC, 0x10FDF, 168),
+    (0x10FE0, 0x10FF6, 38), (0x10FF7, 0x10FFF, 168), (0x11000, 0x1104D, 14), (0x1104E, 0x11051, 168),
+    (0x11052, 0x11075, 14), (0x11076, 0x1107E, 168), (0x1107F, 0x1107F, 14), (0x11080, 0x110C2, 62),
+    (0x110C3, 0x110CC, 168), (0x110CD, 0x110CD, 62), (0x110CE, 0x110CF, 168), (0x110D0, 0x110E8, 141),
+    (0x110E9, 0x110EF, 168), (0x110F0, 0x110F9, 141), (0x110FA, 0x110FF, 168), (0x11100, 0x11134, 21),
+    (0x11135, 0x11135, 168), (0x11136, 0x11147, 21), (0x11148, 0x1114F, 168), (0x11150, 0x11176, 83),
+    (0x11177, 0x1117F, 168), (0x11180, 0x111DF, 134), (0x111E0, 0x111E0, 168), (0x111E1, 0x111F4, 139),
+    (0x111F5, 0x111FF, 168), (0x11200, 0x11211, 71), (0x11212, 0x11212, 168), (0x11213, 0x11241, 71),
+    (0x11242, 0x1127F, 168), (0x11280, 0x11286, 99), (0x11287, 0x11287, 168), (0x11288, 0x11288, 99),
+    (0x11289, 0x11289, 168), (0x1128A, 0x1128D, 99), (0x1128E, 0x1128E, 168), (0x1128F, 0x1129D, 99),
+    (0x1129E, 0x1129E, 168), (0x1129F, 0x112A9, 99), (0x112AA, 0x112AF, 168), (0x112B0, 0x112EA, 72),
+    (0x112EB, 0x112EF, 168), (0x112F0, 0x112F9, 72), (0x112FA, 0x112FF, 168), (0x11300, 0x11303, 44),
+    (0x11304, 0x11304, 168), (0x11305, 0x1130C, 44), (0x1130D, 0x1130E, 168), (0x1130F, 0x11310, 44),
+    (0x11311, 0x11312, 168), (0x11313, 0x11328, 44), (0x11329, 0x11329, 168), (0x1132A, 0x11330, 44),
+    (0x11331, 0x11331, 168), (0x11332, 0x11333, 44), (0x11334, 0x11334, 168), (0x11335, 0x11339, 44),
+    (0x1133A, 0x1133A, 168), (0x1133B, 0x1133B, 58), (0x1133C, 0x11344, 44), (0x11345, 0x11346, 168),
+    (0x11347, 0x11348, 44), (0x11349, 0x1134A, 168), (0x1134B, 0x1134D, 44), (0x1134E, 0x1134F, 168),
+    (0x11350, 0x11350, 44), (0x11351, 0x11356, 168), (0x11357, 0x11357, 44), (0x11358, 0x1135C, 168),
+    (0x1135D, 0x11363, 44), (0x11364, 0x11365, 168), (0x11366, 0x1136C, 44), (0x1136D, 0x1136F, 168),
+    (0x11370, 0x11374, 44), (0x11375, 0x1137F, 168), (0x11380, 0x11389, 166), (0x1138A, 0x1138A, 168),
+    (0x1138B, 0x1138B, 166), (0x1138C, 0x1138D, 168), (0x1138E, 0x1138E, 166), (0x1138F, 0x1138F, 168),
+    (0x11390, 0x113B5, 166), (0x113B6, 0x113B6, 168), (0x113B7, 0x113C0, 166), (0x113C1, 0x113C1, 168),
+    (0x113C2, 0x113C2, 166), (0x113C3, 0x113C4, 168), (0x113C5, 0x113C5, 166), (0x113C6, 0x113C6, 168),
+    (0x113C7, 0x113CA, 166), (0x113CB, 0x113CB, 168), (0x113CC, 0x113D5, 166), (0x113D6, 0x113D6, 168),
+    (0x113D7, 0x113D8, 166), (0x113D9, 0x113E0, 168), (0x113E1, 0x113E2, 166), (0x113E3, 0x113FF, 168),
+    (0x11400, 0x1145B, 105), (0x1145C, 0x1145C, 168), (0x1145D, 0x11461, 105), (0x11462, 0x1147F, 168),
+    (0x11480, 0x114C7, 162), (0x114C8, 0x114CF, 168), (0x114D0, 0x114D9, 162), (0x114DA, 0x1157F, 168),
+    (0x11580, 0x115B5, 136), (0x115B6, 0x115B7, 168), (0x115B8, 0x115DD, 136), (0x115DE, 0x115FF, 168),
+    (0x11600, 0x11644, 96), (0x11645, 0x1164F, 168), (0x11650, 0x11659, 96), (0x1165A, 0x1165F, 168),
+    (0x11660, 0x1166C, 97), (0x1166D, 0x1167F, 168), (0x11680, 0x116B9, 153), (0x116BA, 0x116BF, 168),
+    (0x116C0, 0x116C9, 153), (0x116CA, 0x116CF, 168), (0x116D0, 0x116E3, 100), (0x116E4, 0x116FF, 168),
+    (0x11700, 0x1171A, 1), (0x1171B, 0x1171C, 168), (0x1171D, 0x1172B, 1), (0x1172C, 0x1172F, 168),
+    (0x11730, 0x11746, 1), (0x11747, 0x117FF, 168), (0x11800, 0x1183B, 34), (0x1183C, 0x1189F, 168),
+    (0x118A0, 0x118F2, 172), (0x118F3, 0x118FE, 168), (0x118FF, 0x118FF, 172), (0x11900, 0x11906, 33),
+    (0x11907, 0x11908, 168), (0x11909, 0x11909, 33), (0x1190A, 0x1190B, 168), (0x1190C, 0x11913, 33),
+    (0x11914, 0x11914, 168), (0x11915, 0x11916, 33), (0x11917, 0x11917, 168), (0x11918, 0x11935, 33),
+    (0x11936, 0x11936, 168), (0x11937, 0x11938, 33), (0x11939, 0x1193A, 168), (0x1193B, 0x11946, 33),
+    (0x11947, 0x1194F, 168), (0x11950, 0x11959, 33), (0x1195A, 0x1199F, 168), (0x119A0, 0x119A7, 103),
+    (0x119A8, 0x119A9, 168), (0x119AA, 0x119D7, 103), (0x119D8, 0x119D9, 168), (0x119DA, 0x119E4, 103),
+    (0x119E5, 0x119FF, 168), (0x11A00, 0x11A47, 175), (0x11A48, 0x11A4F, 168), (0x11A50, 0x11AA2, 142),
+    (0x11AA3, 0x11AAF, 168), (0x11AB0, 0x11ABF, 18), (0x11AC0, 0x11AF8, 126), (0x11AF9, 0x11AFF, 168),
+    (0x11B00, 0x11B09, 32), (0x11B0A, 0x11B5F, 168), (0x11B60, 0x11B67, 134), (0x11B68, 0x11BBF, 168),
+    (0x11BC0, 0x11BE1, 144), (0x11BE2, 0x11BEF, 168), (0x11BF0, 0x11BF9, 144), (0x11BFA, 0x11BFF, 168),
+    (0x11C00, 0x11C08, 12), (0x11C09, 0x11C09, 168), (0x11C0A, 0x11C36, 12), (0x11C37, 0x11C37, 168),
+    (0x11C38, 0x11C45, 12), (0x11C46, 0x11C4F, 168), (0x11C50, 0x11C6C, 12), (0x11C6D, 0x11C6F, 168),
+    (0x11C70, 0x11C8F, 88), (0x11C90, 0x11C91, 168), (0x11C92, 0x11CA7, 88), (0x11CA8, 0x11CA8, 168),
+    (0x11CA9, 0x11CB6, 88), (0x11CB7, 0x11CFF, 168), (0x11D00, 0x11D06, 89), (0x11D07, 0x11D07, 168),
+    (0x11D08, 0x11D09, 89), (0x11D0A, 0x11D0A, 168), (0x11D0B, 0x11D36, 89), (0x11D37, 0x11D39, 168),
+    (0x11D3A, 0x11D3A, 89), (0x11D3B, 0x11D3B, 168), (0x11D3C, 0x11D3D, 89), (0x11D3E, 0x11D3E, 168),
+    (0x11D3F, 0x11D47, 89), (0x11D48, 0x11D4F, 168), (0x11D50, 0x11D59, 89), (0x11D5A, 0x11D5F, 168),
+    (0x11D60, 0x11D65, 47), (0x11D66, 0x11D66, 168), (0x11D67, 0x11D68, 47), (0x11D69, 0x11D69, 168),
+    (0x11D6A, 0x11D8E, 47), (0x11D8F, 0x11D8F, 168), (0x11D90, 0x11D91, 47), (0x11D92, 0x11D92, 168),
+    (0x11D93, 0x11D98, 47), (0x11D99, 0x11D9F, 168), (0x11DA0, 0x11DA9, 47), (0x11DAA, 0x11DAF, 168),
+    (0x11DB0, 0x11DDB, 164), (0x11DDC, 0x11DDF, 168), (0x11DE0, 0x11DE9, 164), (0x11DEA, 0x11EDF, 168),
+    (0x11EE0, 0x11EF8, 84), (0x11EF9, 0x11EFF, 168), (0x11F00, 0x11F10, 66), (0x11F11, 0x11F11, 168),
+    (0x11F12, 0x11F3A, 66), (0x11F3B, 0x11F3D, 168), (0x11F3E, 0x11F5A, 66), (0x11F5B, 0x11FAF, 168),
+    (0x11FB0, 0x11FB0, 80), (0x11FB1, 0x11FBF, 168), (0x11FC0, 0x11FF1, 154), (0x11FF2, 0x11FFE, 168),
+    (0x11FFF, 0x11FFF, 154), (0x12000, 0x12399, 27), (0x1239A, 0x123FF, 168), (0x12400, 0x1246E, 27),
+    (0x1246F, 0x1246F, 168), (0x12470, 0x12474, 27), (0x12475, 0x1247F, 168), (0x12480, 0x12543, 27),
+    (0x12544, 0x12F8F, 168), (0x12F90, 0x12FF2, 29), (0x12FF3, 0x12FFF, 168), (0x13000, 0x13455, 36),
+    (0x13456, 0x1345F, 168), (0x13460, 0x143FA, 36), (0x143FB, 0x143FF, 168), (0x14400, 0x14646, 2),
+    (0x14647, 0x160FF, 168), (0x16100, 0x16139, 49), (0x1613A, 0x167FF, 168), (0x16800, 0x16A38, 7),
+    (0x16A39, 0x16A3F, 168), (0x16A40, 0x16A5E, 98), (0x16A5F, 0x16A5F, 168), (0x16A60, 0x16A69, 98),
+    (0x16A6A, 0x16A6D, 168), (0x16A6E, 0x16A6F, 98), (0x16A70, 0x16ABE, 155), (0x16ABF, 0x16ABF, 168),
+    (0x16AC0, 0x16AC9, 155), (0x16ACA, 0x16ACF, 168), (0x16AD0, 0x16AED, 8), (0x16AEE, 0x16AEF, 168),
+    (0x16AF0, 0x16AF5, 8), (0x16AF6, 0x16AFF, 168), (0x16B00, 0x16B45, 124), (0x16B46, 0x16B4F, 168),
+    (0x16B50, 0x16B59, 124), (0x16B5A, 0x16B5A, 168), (0x16B5B, 0x16B61, 124), (0x16B62, 0x16B62, 168),
+    (0x16B63, 0x16B77, 124), (0x16B78, 0x16B7C, 168), (0x16B7D, 0x16B8F, 124), (0x16B90, 0x16D3F, 168),
+    (0x16D40, 0x16D79, 73), (0x16D7A, 0x16E3F, 168), (0x16E40, 0x16E9A, 90), (0x16E9B, 0x16E9F, 168),
+    (0x16EA0, 0x16EB8, 11), (0x16EB9, 0x16EBA, 168), (0x16EBB, 0x16ED3, 11), (0x16ED4, 0x16EFF, 168),
+    (0x16F00, 0x16F4A, 95), (0x16F4B, 0x16F4E, 168), (0x16F4F, 0x16F87, 95), (0x16F88, 0x16F8E, 168),
+    (0x16F8F, 0x16F9F, 95), (0x16FA0, 0x16FDF, 168), (0x16FE0, 0x16FE0, 156), (0x16FE1, 0x16FE1, 107),
+    (0x16FE2, 0x16FE3, 50), (0x16FE4, 0x16FE4, 69), (0x16FE5, 0x16FEF, 168), (0x16FF0, 0x16FF6, 50),
+    (0x16FF7, 0x16FFF, 168), (0x17000, 0x18AFF, 156), (0x18B00, 0x18CD5, 69), (0x18CD6, 0x18CFE, 168),
+    (0x18CFF, 0x18CFF, 69), (0x18D00, 0x18D1E, 156), (0x18D1F, 0x18D7F, 168), (0x18D80, 0x18DF2, 156),
+    (0x18DF3, 0x1AFEF, 168), (0x1AFF0, 0x1AFF3, 64), (0x1AFF4, 0x1AFF4, 168), (0x1AFF5, 0x1AFFB, 64),
+    (0x1AFFC, 0x1AFFC, 168), (0x1AFFD, 0x1AFFE, 64), (0x1AFFF, 0x1AFFF, 168), (0x1B000, 0x1B000, 64),
+    (0x1B001, 0x1B11F, 56), (0x1B120, 0x1B122, 64), (0x1B123, 0x1B131, 168), (0x1B132, 0x1B132, 56),
+    (0x1B133, 0x1B14F, 168), (0x1B150, 0x1B152, 56), (0x1B153, 0x1B154, 168), (0x1B155, 0x1B155, 64),
+    (0x1B156, 0x1B163, 168), (0x1B164, 0x1B167, 64), (0x1B168, 0x1B16F, 168), (0x1B170, 0x1B2FB, 107),
+    (0x1B2FC, 0x1BBFF, 168), (0x1BC00, 0x1BC6A, 35), (0x1BC6B, 0x1BC6F, 168), (0x1BC70, 0x1BC7C, 35),
+    (0x1BC7D, 0x1BC7F, 168), (0x1BC80, 0x1BC88, 35), (0x1BC89, 0x1BC8F, 168), (0x1BC90, 0x1BC99, 35),
+    (0x1BC9A, 0x1BC9B, 168), (0x1BC9C, 0x1BC9F, 35), (0x1BCA0, 0x1BCA3, 25), (0x1BCA4, 0x1CBFF, 168),
+    (0x1CC00, 0x1CCFC, 25), (0x1CCFD, 0x1CCFF, 168), (0x1CD00, 0x1CEB3, 25), (0x1CEB4, 0x1CEB9, 168),
+    (0x1CEBA, 0x1CED0, 25), (0x1CED1, 0x1CEDF, 168), (0x1CEE0, 0x1CEF0, 25), (0x1CEF1, 0x1CEFF, 168),
+    (0x1CF00, 0x1CF2D, 58), (0x1CF2E, 0x1CF2F, 168), (0x1CF30, 0x1CF46, 58), (0x1CF47, 0x1CF4F, 168),
+    (0x1CF50, 0x1CFC3, 25), (0x1CFC4, 0x1CFFF, 168), (0x1D000, 0x1D0F5, 25), (0x1D0F6, 0x1D0FF, 168),
+    (0x1D100, 0x1D126, 25), (0x1D127, 0x1D128, 168), (0x1D129, 0x1D166, 25), (0x1D167, 0x1D169, 58),
+    (0x1D16A, 0x1D17A, 25), (0x1D17B, 0x1D182, 58), (0x1D183, 0x1D184, 25), (0x1D185, 0x1D18B, 58),
+    (0x1D18C, 0x1D1A9, 25), (0x1D1AA, 0x1D1AD, 58), (0x1D1AE, 0x1D1EA, 25), (0x1D1EB, 0x1D1FF, 168),
+    (0x1D200, 0x1D245, 45), (0x1D246, 0x1D2BF, 168), (0x1D2C0, 0x1D2D3, 25), (0x1D2D4, 0x1D2DF, 168),
+    (0x1D2E0, 0x1D2F3, 25), (0x1D2F4, 0x1D2FF, 168), (0x1D300, 0x1D356, 25), (0x1D357, 0x1D35F, 168),
+    (0x1D360, 0x1D378, 25), (0x1D379, 0x1D3FF, 168), (0x1D400, 0x1D454, 25), (0x1D455, 0x1D455, 168),
+    (0x1D456, 0x1D49C, 25), (0x1D49D, 0x1D49D, 168), (0x1D49E, 0x1D49F, 25), (0x1D4A0, 0x1D4A1, 168),
+    (0x1D4A2, 0x1D4A2, 25), (0x1D4A3, 0x1D4A4, 168), (0x1D4A5, 0x1D4A6, 25), (0x1D4A7, 0x1D4A8, 168),
+    (0x1D4A9, 0x1D4AC, 25), (0x1D4AD, 0x1D4AD, 168), (0x1D4AE, 0x1D4B9, 25), (0x1D4BA, 0x1D4BA, 168),
+    (0x1D4BB, 0x1D4BB, 25), (0x1D4BC, 0x1D4BC, 168), (0x1D4BD, 0x1D4C3, 25), (0x1D4C4, 0x1D4C4, 168),
+    (0x1D4C5, 0x1D505, 25), (0x1D506, 0x1D506, 168), (0x1D507, 0x1D50A, 25), (0x1D50B, 0x1D50C, 168),
+    (0x1D50D, 0x1D514, 25), (0x1D515, 0x1D515, 168), (0x1D516, 0x1D51C, 25), (0x1D51D, 0x1D51D, 168),
+    (0x1D51E, 0x1D539, 25), (0x1D53A, 0x1D53A, 168), (0x1D53B, 0x1D53E, 25), (0x1D53F, 0x1D53F, 168),
+    (0x1D540, 0x1D544, 25), (0x1D545, 0x1D545, 168), (0x1D546, 0x1D546, 25), (0x1D547, 0x1D549, 168),
+    (0x1D54A, 0x1D550, 25), (0x1D551, 0x1D551, 168), (0x1D552, 0x1D6A5, 25), (0x1D6A6, 0x1D6A7, 168),
+    (0x1D6A8, 0x1D7CB, 25), (0x1D7CC, 0x1D7CD, 168), (0x1D7CE, 0x1D7FF, 25), (0x1D800, 0x1DA8B, 138),
+    (0x1DA8C, 0x1DA9A, 168), (0x1DA9B, 0x1DA9F, 138), (0x1DAA0, 0x1DAA0, 168), (0x1DAA1, 0x1DAAF, 138),
+    (0x1DAB0, 0x1DEFF, 168), (0x1DF00, 0x1DF1E, 75), (0x1DF1F, 0x1DF24, 168), (0x1DF25, 0x1DF2A, 75),
+    (0x1DF2B, 0x1DFFF, 168), (0x1E000, 0x1E006, 42), (0x1E007, 0x1E007, 168), (0x1E008, 0x1E018, 42),
+    (0x1E019, 0x1E01A, 168), (0x1E01B, 0x1E021, 42), (0x1E022, 0x1E022, 168), (0x1E023, 0x1E024, 42),
+    (0x1E025, 0x1E025, 168), (0x1E026, 0x1E02A, 42), (0x1E02B, 0x1E02F, 168), (0x1E030, 0x1E06D, 30),
+    (0x1E06E, 0x1E08E, 168), (0x1E08F, 0x1E08F, 30), (0x1E090, 0x1E0FF, 168), (0x1E100, 0x1E12C, 108),
+    (0x1E12D, 0x1E12F, 168), (0x1E130, 0x1E13D, 108), (0x1E13E, 0x1E13F, 168), (0x1E140, 0x1E149, 108),
+    (0x1E14A, 0x1E14D, 168), (0x1E14E, 0x1E14F, 108), (0x1E150, 0x1E28F, 168), (0x1E290, 0x1E2AE, 165),
+    (0x1E2AF, 0x1E2BF, 168), (0x1E2C0, 0x1E2F9, 171), (0x1E2FA, 0x1E2FE, 168), (0x1E2FF, 0x1E2FF, 171),
+    (0x1E300, 0x1E4CF, 168), (0x1E4D0, 0x1E4F9, 102), (0x1E4FA, 0x1E5CF, 168), (0x1E5D0, 0x1E5FA, 111),
+    (0x1E5FB, 0x1E5FE, 168), (0x1E5FF, 0x1E5FF, 111), (0x1E600, 0x1E6BF, 168), (0x1E6C0, 0x1E6DE, 152),
+    (0x1E6DF, 0x1E6DF, 168), (0x1E6E0, 0x1E6F5, 152), (0x1E6F6, 0x1E6FD, 168), (0x1E6FE, 0x1E6FF, 152),
+    (0x1E700, 0x1E7DF, 168), (0x1E7E0, 0x1E7E6, 39), (0x1E7E7, 0x1E7E7, 168), (0x1E7E8, 0x1E7EB, 39),
+    (0x1E7EC, 0x1E7EC, 168), (0x1E7ED, 0x1E7EE, 39), (0x1E7EF, 0x1E7EF, 168), (0x1E7F0, 0x1E7FE, 39),
+    (0x1E7FF, 0x1E7FF, 168), (0x1E800, 0x1E8C4, 92), (0x1E8C5, 0x1E8C6, 168), (0x1E8C7, 0x1E8D6, 92),
+    (0x1E8D7, 0x1E8FF, 168), (0x1E900, 0x1E94B, 0), (0x1E94C, 0x1E94F, 168), (0x1E950, 0x1E959, 0),
+    (0x1E95A, 0x1E95D, 168), (0x1E95E, 0x1E95F, 0), (0x1E960, 0x1EC70, 168), (0x1EC71, 0x1ECB4, 25),
+    (0x1ECB5, 0x1ED00, 168), (0x1ED01, 0x1ED3D, 25), (0x1ED3E, 0x1EDFF, 168), (0x1EE00, 0x1EE03, 3),
+    (0x1EE04, 0x1EE04, 168), (0x1EE05, 0x1EE1F, 3), (0x1EE20, 0x1EE20, 168), (0x1EE21, 0x1EE22, 3),
+    (0x1EE23, 0x1EE23, 168), (0x1EE24, 0x1EE24, 3), (0x1EE25, 0x1EE26, 168), (0x1EE27, 0x1EE27, 3),
+    (0x1EE28, 0x1EE28, 168), (0x1EE29, 0x1EE32, 3), (0x1EE33, 0x1EE33, 168), (0x1EE34, 0x1EE37, 3),
+    (0x1EE38, 0x1EE38, 168), (0x1EE39, 0x1EE39, 3), (0x1EE3A, 0x1EE3A, 168), (0x1EE3B, 0x1EE3B, 3),
+    (0x1EE3C, 0x1EE41, 168), (0x1EE42, 0x1EE42, 3), (0x1EE43, 0x1EE46, 168), (0x1EE47, 0x1EE47, 3),
+    (0x1EE48, 0x1EE48, 168), (0x1EE49, 0x1EE49, 3), (0x1EE4A, 0x1EE4A, 168), (0x1EE4B, 0x1EE4B, 3),
+    (0x1EE4C, 0x1EE4C, 168), (0x1EE4D, 0x1EE4F, 3), (0x1EE50, 0x1EE50, 168), (0x1EE51, 0x1EE52, 3),
+    (0x1EE53, 0x1EE53, 168), (0x1EE54, 0x1EE54, 3), (0x1EE55, 0x1EE56, 168), (0x1EE57, 0x1EE57, 3),
+    (0x1EE58, 0x1EE58, 168), (0x1EE59, 0x1EE59, 3), (0x1EE5A, 0x1EE5A, 168), (0x1EE5B, 0x1EE5B, 3),
+    (0x1EE5C, 0x1EE5C, 168), (0x1EE5D, 0x1EE5D, 3), (0x1EE5E, 0x1EE5E, 168), (0x1EE5F, 0x1EE5F, 3),
+    (0x1EE60, 0x1EE60, 168), (0x1EE61, 0x1EE62, 3), (0x1EE63, 0x1EE63, 168), (0x1EE64, 0x1EE64, 3),
+    (0x1EE65, 0x1EE66, 168), (0x1EE67, 0x1EE6A, 3), (0x1EE6B, 0x1EE6B, 168), (0x1EE6C, 0x1EE72, 3),
+    (0x1EE73, 0x1EE73, 168), (0x1EE74, 0x1EE77, 3), (0x1EE78, 0x1EE78, 168), (0x1EE79, 0x1EE7C, 3),
+    (0x1EE7D, 0x1EE7D, 168), (0x1EE7E, 0x1EE7E, 3), (0x1EE7F, 0x1EE7F, 168), (0x1EE80, 0x1EE89, 3),
+    (0x1EE8A, 0x1EE8A, 168), (0x1EE8B, 0x1EE9B, 3), (0x1EE9C, 0x1EEA0, 168), (0x1EEA1, 0x1EEA3, 3),
+    (0x1EEA4, 0x1EEA4, 168), (0x1EEA5, 0x1EEA9, 3), (0x1EEAA, 0x1EEAA, 168), (0x1EEAB, 0x1EEBB, 3),
+    (0x1EEBC, 0x1EEEF, 168), (0x1EEF0, 0x1EEF1, 3), (0x1EEF2, 0x1EFFF, 168), (0x1F000, 0x1F02B, 25),
+    (0x1F02C, 0x1F02F, 168), (0x1F030, 0x1F093, 25), (0x1F094, 0x1F09F, 168), (0x1F0A0, 0x1F0AE, 25),
+    (0x1F0AF, 0x1F0B0, 168), (0x1F0B1, 0x1F0BF, 25), (0x1F0C0, 0x1F0C0, 168), (0x1F0C1, 0x1F0CF, 25),
+    (0x1F0D0, 0x1F0D0, 168), (0x1F0D1, 0x1F0F5, 25), (0x1F0F6, 0x1F0FF, 168), (0x1F100, 0x1F1AD, 25),
+    (0x1F1AE, 0x1F1E5, 168), (0x1F1E6, 0x1F1FF, 25), (0x1F200, 0x1F200, 56), (0x1F201, 0x1F202, 25),
+    (0x1F203, 0x1F20F, 168), (0x1F210, 0x1F23B, 25), (0x1F23C, 0x1F23F, 168), (0x1F240, 0x1F248, 25),
+    (0x1F249, 0x1F24F, 168), (0x1F250, 0x1F251, 25), (0x1F252, 0x1F25F, 168), (0x1F260, 0x1F265, 25),
+    (0x1F266, 0x1F2FF, 168), (0x1F300, 0x1F6D8, 25), (0x1F6D9, 0x1F6DB, 168), (0x1F6DC, 0x1F6EC, 25),
+    (0x1F6ED, 0x1F6EF, 168), (0x1F6F0, 0x1F6FC, 25), (0x1F6FD, 0x1F6FF, 168), (0x1F700, 0x1F7D9, 25),
+    (0x1F7DA, 0x1F7DF, 168), (0x1F7E0, 0x1F7EB, 25), (0x1F7EC, 0x1F7EF, 168), (0x1F7F0, 0x1F7F0, 25),
+    (0x1F7F1, 0x1F7FF, 168), (0x1F800, 0x1F80B, 25), (0x1F80C, 0x1F80F, 168), (0x1F810, 0x1F847, 25),
+    (0x1F848, 0x1F84F, 168), (0x1F850, 0x1F859, 25), (0x1F85A, 0x1F85F, 168), (0x1F860, 0x1F887, 25),
+    (0x1F888, 0x1F88F, 168), (0x1F890, 0x1F8AD, 25), (0x1F8AE, 0x1F8AF, 168), (0x1F8B0, 0x1F8BB, 25),
+    (0x1F8BC, 0x1F8BF, 168), (0x1F8C0, 0x1F8C1, 25), (0x1F8C2, 0x1F8CF, 168), (0x1F8D0, 0x1F8D8, 25),
+    (0x1F8D9, 0x1F8FF, 168), (0x1F900, 0x1FA57, 25), (0x1FA58, 0x1FA5F, 168), (0x1FA60, 0x1FA6D, 25),
+    (0x1FA6E, 0x1FA6F, 168), (0x1FA70, 0x1FA7C, 25), (0x1FA7D, 0x1FA7F, 168), (0x1FA80, 0x1FA8A, 25),
+    (0x1FA8B, 0x1FA8D, 168), (0x1FA8E, 0x1FAC6, 25), (0x1FAC7, 0x1FAC7, 168), (0x1FAC8, 0x1FAC8, 25),
+    (0x1FAC9, 0x1FACC, 168), (0x1FACD, 0x1FADC, 25), (0x1FADD, 0x1FADE, 168), (0x1FADF, 0x1FAEA, 25),
+    (0x1FAEB, 0x1FAEE, 168), (0x1FAEF, 0x1FAF8, 25), (0x1FAF9, 0x1FAFF, 168), (0x1FB00, 0x1FB92, 25),
+    (0x1FB93, 0x1FB93, 168), (0x1FB94, 0x1FBFA, 25), (0x1FBFB, 0x1FFFF, 168), (0x20000, 0x2A6DF, 50),
+    (0x2A6E0, 0x2A6FF, 168), (0x2A700, 0x2B81D, 50), (0x2B81E, 0x2B81F, 168), (0x2B820, 0x2CEAD, 50),
+    (0x2CEAE, 0x2CEAF, 168), (0x2CEB0, 0x2EBE0, 50), (0x2EBE1, 0x2EBEF, 168), (0x2EBF0, 0x2EE5D, 50),
+    (0x2EE5E, 0x2F7FF, 168), (0x2F800, 0x2FA1D, 50), (0x2FA1E, 0x2FFFF, 168), (0x30000, 0x3134A, 50),
+    (0x3134B, 0x3134F, 168), (0x31350, 0x33479, 50), (0x3347A, 0xE0000, 168), (0xE0001, 0xE0001, 25),
+    (0xE0002, 0xE001F, 168), (0xE0020, 0xE007F, 25), (0xE0080, 0xE00FF, 168), (0xE0100, 0xE01EF, 58),
+    (0xE01F0, 0x10FFFF, 168),
+];
+
+/// The Script_Extensions of every code point, as `(first, last, indices in SCRIPT_VALUES)`.
+pub(super) static SCRIPT_EXTENSIONS: &[(u32, u32, &[u8])] = &[
+    (0x0, 0x40, &[25]), (0x41, 0x5A, &[75]), (0x5B, 0x60, &[25]),
+    (0x61, 0x7A, &[75]), (0x7B, 0xA9, &[25]), (0xAA, 0xAA, &[75]),
+    (0xAB, 0xB6, &[25]), (0xB7, 0xB7, &[26, 41, 43, 45, 50, 75, 135, 42, 115, 19, 82, 5, 35, 37, 83, 47]), (0xB8, 0xB9, &[25]),
+    (0xBA, 0xBA, &[75]), (0xBB, 0xBF, &[25]), (0xC0, 0xD6, &[75]),
+    (0xD7, 0xD7, &[25]), (0xD8, 0xF6, &[75]), (0xF7, 0xF7, &[25]),
+    (0xF8, 0x2B8, &[75]), (0x2B9, 0x2BB, &[25]), (0x2BC, 0x2BC, &[10, 30, 32, 75, 159, 80, 165]),
+    (0x2BD, 0x2C6, &[25]), (0x2C7, 0x2C7, &[13, 75]), (0x2C8, 0x2C8, &[25]),
+    (0x2C9, 0x2CB, &[13, 75]), (0x2CC, 0x2CC, &[25]), (0x2CD, 0x2CD, &[75, 80]),
+    (0x2CE, 0x2D6, &[25]), (0x2D7, 0x2D7, &[75, 159]), (0x2D8, 0x2D8, &[25]),
+    (0x2D9, 0x2D9, &[13, 75]), (0x2DA, 0x2DF, &[25]), (0x2E0, 0x2E4, &[75]),
+    (0x2E5, 0x2E9, &[25]), (0x2EA, 0x2EB, &[13]), (0x2EC, 0x2FF, &[25]),
+    (0x300, 0x300, &[23, 26, 30, 45, 75, 149, 115, 144]), (0x301, 0x301, &[23, 30, 45, 75, 149, 122, 144, 163]), (0x302, 0x302, &[23, 30, 75, 161]),
+    (0x303, 0x303, &[75, 146, 159, 42, 144]), (0x304, 0x304, &[23, 26, 30, 43, 45, 75, 146, 161, 20, 122, 163]), (0x305, 0x305, &[26, 43, 64, 75, 42, 37]),
+    (0x306, 0x306, &[30, 45, 75, 161, 115]), (0x307, 0x307, &[26, 55, 75, 146, 149, 161, 115, 35, 163]), (0x308, 0x308, &[4, 30, 43, 45, 55, 75, 146, 149, 161, 115, 35]),
+    (0x309, 0x309, &[75, 161]), (0x30A, 0x30A, &[75, 146, 35]), (0x30B, 0x30B, &[23, 30, 75, 122]),
+    (0x30C, 0x30C, &[23, 75, 149]), (0x30D, 0x30D, &[75, 144]), (0x30E, 0x30E, &[39, 75]),
+    (0x30F, 0x30F, &[58]), (0x310, 0x310, &[75, 144]), (0x311, 0x311, &[30, 75, 163]),
+    (0x312, 0x312, &[58]), (0x313, 0x313, &[45, 75, 115, 163]), (0x314, 0x322, &[58]),
+    (0x323, 0x323, &[23, 64, 75, 146, 161, 35]), (0x324, 0x324, &[23, 75, 146, 35]), (0x325, 0x325, &[75, 146]),
+    (0x326, 0x32C, &[58]), (0x32D, 0x32D, &[75, 146, 144]), (0x32E, 0x32E, &[75, 146]),
+    (0x32F, 0x32F, &[58]), (0x330, 0x330, &[23, 75, 146]), (0x331, 0x331, &[23, 43, 75, 146, 159, 20, 144]),
+    (0x332, 0x341, &[58]), (0x342, 0x342, &[45]), (0x343, 0x344, &[58]),
+    (0x345, 0x345, &[45]), (0x346, 0x357, &[58]), (0x358, 0x358, &[75, 122]),
+    (0x359, 0x35D, &[58]), (0x35E, 0x35E, &[75, 20, 163]), (0x35F, 0x362, &[58]),
+    (0x363, 0x36F, &[75]), (0x370, 0x373, &[45]), (0x374, 0x375, &[26, 45]),
+    (0x376, 0x377, &[45]), (0x378, 0x379, &[168]), (0x37A, 0x37D, &[45]),
+    (0x37E, 0x37E, &[25]), (0x37F, 0x37F, &[45]), (0x380, 0x383, &[168]),
+    (0x384, 0x384, &[45]), (0x385, 0x385, &[25]), (0x386, 0x386, &[45]),
+    (0x387, 0x387, &[25]), (0x388, 0x38A, &[45]), (0x38B, 0x38B, &[168]),
+    (0x38C, 0x38C, &[45]), (0x38D, 0x38D, &[168]), (0x38E, 0x3A1, &[45]),
+    (0x3A2, 0x3A2, &[168]), (0x3A3, 0x3E1, &[45]), (0x3E2, 0x3EF, &[26]),
+    (0x3F0, 0x3FF, &[45]), (0x400, 0x482, &[30]), (0x483, 0x483, &[30, 115]),
+    (0x484, 0x484, &[30, 42]), (0x485, 0x486, &[30, 75]), (0x487, 0x487, &[30, 42]),
+    (0x488, 0x52F, &[30]), (0x530, 0x530, &[168]), (0x531, 0x556, &[4]),
+    (0x557, 0x558, &[168]), (0x559, 0x588, &[4]), (0x589, 0x589, &[4, 41, 42]),
+    (0x58A, 0x58A, &[4]), (0x58B, 0x58C, &[168]), (0x58D, 0x58F, &[4]),
+    (0x590, 0x590, &[168]), (0x591, 0x5C7, &[55]), (0x5C8, 0x5CF, &[168]),
+    (0x5D0, 0x5EA, &[55]), (0x5EB, 0x5EE, &[168]), (0x5EF, 0x5F4, &[55]),
+    (0x5F5, 0x5FF, &[168]), (0x600, 0x604, &[3]), (0x605, 0x605, &[25]),
+    (0x606, 0x60B, &[3]), (0x60C, 0x60C, &[3, 146, 158, 106, 52, 173, 40]), (0x60D, 0x61A, &[3]),
+    (0x61B, 0x61B, &[3, 146, 158, 106, 52, 173, 40]), (0x61C, 0x61C, &[3, 146, 158]), (0x61D, 0x61E, &[3]),
+    (0x61F, 0x61F, &[3, 146, 158, 106, 0, 52, 173, 40]), (0x620, 0x63F, &[3]), (0x640, 0x640, &[3, 146, 86, 87, 129, 0, 52, 140, 120]),
+    (0x641, 0x64A, &[3]), (0x64B, 0x655, &[3, 146]), (0x656, 0x65F, &[3]),
+    (0x660, 0x669, &[3, 158, 173]), (0x66A, 0x66F, &[3]), (0x670, 0x670, &[3, 146]),
+    (0x671, 0x6D3, &[3]), (0x6D4, 0x6D4, &[3, 52]), (0x6D5, 0x6DC, &[3]),
+    (0x6DD, 0x6DD, &[25]), (0x6DE, 0x6FF, &[3]), (0x700, 0x70D, &[146]),
+    (0x70E, 0x70E, &[168]), (0x70F, 0x74A, &[146]), (0x74B, 0x74C, &[168]),
+    (0x74D, 0x74F, &[146]), (0x750, 0x77F, &[3]), (0x780, 0x7B1, &[158]),
+    (0x7B2, 0x7BF, &[168]), (0x7C0, 0x7FA, &[106]), (0x7FB, 0x7FC, &[168]),
+    (0x7FD, 0x7FF, &[106]), (0x800, 0x82D, &[132]), (0x82E, 0x82F, &[168]),
+    (0x830, 0x83E, &[132]), (0x83F, 0x83F, &[168]), (0x840, 0x85B, &[86]),
+    (0x85C, 0x85D, &[168]), (0x85E, 0x85E, &[86]), (0x85F, 0x85F, &[168]),
+    (0x860, 0x86A, &[146]), (0x86B, 0x86F, &[168]), (0x870, 0x891, &[3]),
+    (0x892, 0x896, &[168]), (0x897, 0x8E1, &[3]), (0x8E2, 0x8E2, &[25]),
+    (0x8E3, 0x8FF, &[3]), (0x900, 0x950, &[32]), (0x951, 0x951, &[10, 32, 46, 48, 63, 75, 85, 121, 154, 157, 44, 134, 162, 105, 103]),
+    (0x952, 0x952, &[10, 32, 46, 48, 63, 75, 85, 121, 154, 157, 44, 162, 105]), (0x953, 0x954, &[58]), (0x955, 0x963, &[32]),
+    (0x964, 0x964, &[10, 32, 46, 48, 63, 85, 121, 139, 154, 157, 145, 44, 72, 153, 162, 83, 89, 34, 47, 103, 111]), (0x965, 0x965, &[10, 32, 46, 48, 63, 85, 121, 139, 154, 157, 77, 145, 44, 72, 153, 162, 83, 89, 34, 47, 103, 49, 111]), (0x966, 0x96F, &[32, 62, 83, 34]),
+    (0x970, 0x97F, &[32]), (0x980, 0x983, &[10]), (0x984, 0x984, &[168]),
+    (0x985, 0x98C, &[10]), (0x98D, 0x98E, &[168]), (0x98F, 0x990, &[10]),
+    (0x991, 0x992, &[168]), (0x993, 0x9A8, &[10]), (0x9A9, 0x9A9, &[168]),
+    (0x9AA, 0x9B0, &[10]), (0x9B1, 0x9B1, &[168]), (0x9B2, 0x9B2, &[10]),
+    (0x9B3, 0x9B5, &[168]), (0x9B6, 0x9B9, &[10]), (0x9BA, 0x9BB, &[168]),
+    (0x9BC, 0x9C4, &[10]), (0x9C5, 0x9C6, &[168]), (0x9C7, 0x9C8, &[10]),
+    (0x9C9, 0x9CA, &[168]), (0x9CB, 0x9CE, &[10]), (0x9CF, 0x9D6, &[168]),
+    (0x9D7, 0x9D7, &[10]), (0x9D8, 0x9DB, &[168]), (0x9DC, 0x9DD, &[10]),
+    (0x9DE, 0x9DE, &[168]), (0x9DF, 0x9E3, &[10]), (0x9E4, 0x9E5, &[168]),
+    (0x9E6, 0x9EF, &[10, 145, 21]), (0x9F0, 0x9FE, &[10]), (0x9FF, 0xA00, &[168]),
+    (0xA01, 0xA03, &[48]), (0xA04, 0xA04, &[168]), (0xA05, 0xA0A, &[48]),
+    (0xA0B, 0xA0E, &[168]), (0xA0F, 0xA10, &[48]), (0xA11, 0xA12, &[168]),
+    (0xA13, 0xA28, &[48]), (0xA29, 0xA29, &[168]), (0xA2A, 0xA30, &[48]),
+    (0xA31, 0xA31, &[168]), (0xA32, 0xA33, &[48]), (0xA34, 0xA34, &[168]),
+    (0xA35, 0xA36, &[48]), (0xA37, 0xA37, &[168]), (0xA38, 0xA39, &[48]),
+    (0xA3A, 0xA3B, &[168]), (0xA3C, 0xA3C, &[48]), (0xA3D, 0xA3D, &[168]),
+    (0xA3E, 0xA42, &[48]), (0xA43, 0xA46, &[168]), (0xA47, 0xA48, &[48]),
+    (0xA49, 0xA4A, &[168]), (0xA4B, 0xA4D, &[48]), (0xA4E, 0xA50, &[168]),
+    (0xA51, 0xA51, &[48]), (0xA52, 0xA58, &[168]), (0xA59, 0xA5C, &[48]),
+    (0xA5D, 0xA5D, &[168]), (0xA5E, 0xA5E, &[48]), (0xA5F, 0xA65, &[168]),
+    (0xA66, 0xA6F, &[48, 99]), (0xA70, 0xA76, &[48]), (0xA77, 0xA80, &[168]),
+    (0xA81, 0xA83, &[46]), (0xA84, 0xA84, &[168]), (0xA85, 0xA8D, &[46]),
+    (0xA8E, 0xA8E, &[168]), (0xA8F, 0xA91, &[46]), (0xA92, 0xA92, &[168]),
+    (0xA93, 0xAA8, &[46]), (0xAA9, 0xAA9, &[168]), (0xAAA, 0xAB0, &[46]),
+    (0xAB1, 0xAB1, &[168]), (0xAB2, 0xAB3, &[46]), (0xAB4, 0xAB4, &[168]),
+    (0xAB5, 0xAB9, &[46]), (0xABA, 0xABB, &[168]), (0xABC, 0xAC5, &[46]),
+    (0xAC6, 0xAC6, &[168]), (0xAC7, 0xAC9, &[46]), (0xACA, 0xACA, &[168]),
+    (0xACB, 0xACD, &[46]), (0xACE, 0xACF, &[168]), (0xAD0, 0xAD0, &[46]),
+    (0xAD1, 0xADF, &[168]), (0xAE0, 0xAE3, &[46]), (0xAE4, 0xAE5, &[168]),
+    (0xAE6, 0xAEF, &[46, 71]), (0xAF0, 0xAF1, &[46]), (0xAF2, 0xAF8, &[168]),
+    (0xAF9, 0xAFF, &[46]), (0xB00, 0xB00, &[168]), (0xB01, 0xB03, &[121]),
+    (0xB04, 0xB04, &[168]), (0xB05, 0xB0C, &[121]), (0xB0D, 0xB0E, &[168]),
+    (0xB0F, 0xB10, &[121]), (0xB11, 0xB12, &[168]), (0xB13, 0xB28, &[121]),
+    (0xB29, 0xB29, &[168]), (0xB2A, 0xB30, &[121]), (0xB31, 0xB31, &[168]),
+    (0xB32, 0xB33, &[121]), (0xB34, 0xB34, &[168]), (0xB35, 0xB39, &[121]),
+    (0xB3A, 0xB3B, &[168]), (0xB3C, 0xB44, &[121]), (0xB45, 0xB46, &[168]),
+    (0xB47, 0xB48, &[121]), (0xB49, 0xB4A, &[168]), (0xB4B, 0xB4D, &[121]),
+    (0xB4E, 0xB54, &[168]), (0xB55, 0xB57, &[121]), (0xB58, 0xB5B, &[168]),
+    (0xB5C, 0xB5D, &[121]), (0xB5E, 0xB5E, &[168]), (0xB5F, 0xB63, &[121]),
+    (0xB64, 0xB65, &[168]), (0xB66, 0xB77, &[121]), (0xB78, 0xB81, &[168]),
+    (0xB82, 0xB83, &[154]), (0xB84, 0xB84, &[168]), (0xB85, 0xB8A, &[154]),
+    (0xB8B, 0xB8D, &[168]), (0xB8E, 0xB90, &[154]), (0xB91, 0xB91, &[168]),
+    (0xB92, 0xB95, &[154]), (0xB96, 0xB98, &[168]), (0xB99, 0xB9A, &[154]),
+    (0xB9B, 0xB9B, &[168]), (0xB9C, 0xB9C, &[154]), (0xB9D, 0xB9D, &[168]),
+    (0xB9E, 0xB9F, &[154]), (0xBA0, 0xBA2, &[168]), (0xBA3, 0xBA4, &[154]),
+    (0xBA5, 0xBA7, &[168]), (0xBA8, 0xBAA, &[154]), (0xBAB, 0xBAD, &[168]),
+    (0xBAE, 0xBB9, &[154]), (0xBBA, 0xBBD, &[168]), (0xBBE, 0xBC2, &[154]),
+    (0xBC3, 0xBC5, &[168]), (0xBC6, 0xBC8, &[154]), (0xBC9, 0xBC9, &[168]),
+    (0xBCA, 0xBCD, &[154]), (0xBCE, 0xBCF, &[168]), (0xBD0, 0xBD0, &[154]),
+    (0xBD1, 0xBD6, &[168]), (0xBD7, 0xBD7, &[154]), (0xBD8, 0xBE5, &[168]),
+    (0xBE6, 0xBF3, &[154, 44]), (0xBF4, 0xBFA, &[154]), (0xBFB, 0xBFF, &[168]),
+    (0xC00, 0xC0C, &[157]), (0xC0D, 0xC0D, &[168]), (0xC0E, 0xC10, &[157]),
+    (0xC11, 0xC11, &[168]), (0xC12, 0xC28, &[157]), (0xC29, 0xC29, &[168]),
+    (0xC2A, 0xC39, &[157]), (0xC3A, 0xC3B, &[168]), (0xC3C, 0xC44, &[157]),
+    (0xC45, 0xC45, &[168]), (0xC46, 0xC48, &[157]), (0xC49, 0xC49, &[168]),
+    (0xC4A, 0xC4D, &[157]), (0xC4E, 0xC54, &[168]), (0xC55, 0xC56, &[157]),
+    (0xC57, 0xC57, &[168]), (0xC58, 0xC5A, &[157]), (0xC5B, 0xC5B, &[168]),
+    (0xC5C, 0xC5D, &[157]), (0xC5E, 0xC5F, &[168]), (0xC60, 0xC63, &[157]),
+    (0xC64, 0xC65, &[168]), (0xC66, 0xC6F, &[157]), (0xC70, 0xC76, &[168]),
+    (0xC77, 0xC7F, &[157]), (0xC80, 0xC8C, &[63]), (0xC8D, 0xC8D, &[168]),
+    (0xC8E, 0xC90, &[63]), (0xC91, 0xC91, &[168]), (0xC92, 0xCA8, &[63]),
+    (0xCA9, 0xCA9, &[168]), (0xCAA, 0xCB3, &[63]), (0xCB4, 0xCB4, &[168]),
+    (0xCB5, 0xCB9, &[63]), (0xCBA, 0xCBB, &[168]), (0xCBC, 0xCC4, &[63]),
+    (0xCC5, 0xCC5, &[168]), (0xCC6, 0xCC8, &[63]), (0xCC9, 0xCC9, &[168]),
+    (0xCCA, 0xCCD, &[63]), (0xCCE, 0xCD4, &[168]), (0xCD5, 0xCD6, &[63]),
+    (0xCD7, 0xCDB, &[168]), (0xCDC, 0xCDE, &[63]), (0xCDF, 0xCDF, &[168]),
+    (0xCE0, 0xCE3, &[63]), (0xCE4, 0xCE5, &[168]), (0xCE6, 0xCEF, &[63, 103, 166]),
+    (0xCF0, 0xCF0, &[168]), (0xCF1, 0xCF3, &[63]), (0xCF4, 0xCFF, &[168]),
+    (0xD00, 0xD0C, &[85]), (0xD0D, 0xD0D, &[168]), (0xD0E, 0xD10, &[85]),
+    (0xD11, 0xD11, &[168]), (0xD12, 0xD44, &[85]), (0xD45, 0xD45, &[168]),
+    (0xD46, 0xD48, &[85]), (0xD49, 0xD49, &[168]), (0xD4A, 0xD4F, &[85]),
+    (0xD50, 0xD53, &[168]), (0xD54, 0xD63, &[85]), (0xD64, 0xD65, &[168]),
+    (0xD66, 0xD7F, &[85]), (0xD80, 0xD80, &[168]), (0xD81, 0xD83, &[139]),
+    (0xD84, 0xD84, &[168]), (0xD85, 0xD96, &[139]), (0xD97, 0xD99, &[168]),
+    (0xD9A, 0xDB1, &[139]), (0xDB2, 0xDB2, &[168]), (0xDB3, 0xDBB, &[139]),
+    (0xDBC, 0xDBC, &[168]), (0xDBD, 0xDBD, &[139]), (0xDBE, 0xDBF, &[168]),
+    (0xDC0, 0xDC6, &[139]), (0xDC7, 0xDC9, &[168]), (0xDCA, 0xDCA, &[139]),
+    (0xDCB, 0xDCE, &[168]), (0xDCF, 0xDD4, &[139]), (0xDD5, 0xDD5, &[168]),
+    (0xDD6, 0xDD6, &[139]), (0xDD7, 0xDD7, &[168]), (0xDD8, 0xDDF, &[139]),
+    (0xDE0, 0xDE5, &[168]), (0xDE6, 0xDEF, &[139]), (0xDF0, 0xDF1, &[168]),
+    (0xDF2, 0xDF4, &[139]), (0xDF5, 0xE00, &[168]), (0xE01, 0xE3A, &[159]),
+    (0xE3B, 0xE3E, &[168]), (0xE3F, 0xE3F, &[25]), (0xE40, 0xE5B, &[159]),
+    (0xE5C, 0xE80, &[168]), (0xE81, 0xE82, &[74]), (0xE83, 0xE83, &[168]),
+    (0xE84, 0xE84, &[74]), (0xE85, 0xE85, &[168]), (0xE86, 0xE8A, &[74]),
+    (0xE8B, 0xE8B, &[168]), (0xE8C, 0xEA3, &[74]), (0xEA4, 0xEA4, &[168]),
+    (0xEA5, 0xEA5, &[74]), (0xEA6, 0xEA6, &[168]), (0xEA7, 0xEBD, &[74]),
+    (0xEBE, 0xEBF, &[168]), (0xEC0, 0xEC4, &[74]), (0xEC5, 0xEC5, &[168]),
+    (0xEC6, 0xEC6, &[74]), (0xEC7, 0xEC7, &[168]), (0xEC8, 0xECE, &[74]),
+    (0xECF, 0xECF, &[168]), (0xED0, 0xED9, &[74]), (0xEDA, 0xEDB, &[168]),
+    (0xEDC, 0xEDF, &[74]), (0xEE0, 0xEFF, &[168]), (0xF00, 0xF47, &[160]),
+    (0xF48, 0xF48, &[168]), (0xF49, 0xF6C, &[160]), (0xF6D, 0xF70, &[168]),
+    (0xF71, 0xF97, &[160]), (0xF98, 0xF98, &[168]), (0xF99, 0xFBC, &[160]),
+    (0xFBD, 0xFBD, &[168]), (0xFBE, 0xFCC, &[160]), (0xFCD, 0xFCD, &[168]),
+    (0xFCE, 0xFD4, &[160]), (0xFD5, 0xFD8, &[25]), (0xFD9, 0xFDA, &[160]),
+    (0xFDB, 0xFFF, &[168]), (0x1000, 0x103F, &[100]), (0x1040, 0x1049, &[100, 149, 21]),
+    (0x104A, 0x109F, &[100]), (0x10A0, 0x10C5, &[41]), (0x10C6, 0x10C6, &[168]),
+    (0x10C7, 0x10C7, &[41]), (0x10C8, 0x10CC, &[168]), (0x10CD, 0x10CD, &[41]),
+    (0x10CE, 0x10CF, &[168]), (0x10D0, 0x10FA, &[41]), (0x10FB, 0x10FB, &[41, 75, 42]),
+    (0x10FC, 0x10FF, &[41]), (0x1100, 0x11FF, &[51]), (0x1200, 0x1248, &[39]),
+    (0x1249, 0x1249, &[168]), (0x124A, 0x124D, &[39]), (0x124E, 0x124F, &[168]),
+    (0x1250, 0x1256, &[39]), (0x1257, 0x1257, &[168]), (0x1258, 0x1258, &[39]),
+    (0x1259, 0x1259, &[168]), (0x125A, 0x125D, &[39]), (0x125E, 0x125F, &[168]),
+    (0x1260, 0x1288, &[39]), (0x1289, 0x1289, &[168]), (0x128A, 0x128D, &[39]),
+    (0x128E, 0x128F, &[168]), (0x1290, 0x12B0, &[39]), (0x12B1, 0x12B1, &[168]),
+    (0x12B2, 0x12B5, &[39]), (0x12B6, 0x12B7, &[168]), (0x12B8, 0x12BE, &[39]),
+    (0x12BF, 0x12BF, &[168]), (0x12C0, 0x12C0, &[39]), (0x12C1, 0x12C1, &[168]),
+    (0x12C2, 0x12C5, &[39]), (0x12C6, 0x12C7, &[168]), (0x12C8, 0x12D6, &[39]),
+    (0x12D7, 0x12D7, &[168]), (0x12D8, 0x1310, &[39]), (0x1311, 0x1311, &[168]),
+    (0x1312, 0x1315, &[39]), (0x1316, 0x1317, &[168]), (0x1318, 0x135A, &[39]),
+    (0x135B, 0x135C, &[168]), (0x135D, 0x137C, &[39]), (0x137D, 0x137F, &[168]),
+    (0x1380, 0x1399, &[39]), (0x139A, 0x139F, &[168]), (0x13A0, 0x13F5, &[23]),
+    (0x13F6, 0x13F7, &[168]), (0x13F8, 0x13FD, &[23]), (0x13FE, 0x13FF, &[168]),
+    (0x1400, 0x167F, &[18]), (0x1680, 0x169C, &[109]), (0x169D, 0x169F, &[168]),
+    (0x16A0, 0x16EA, &[131]), (0x16EB, 0x16ED, &[131]), (0x16EE, 0x16F8, &[131]),
+    (0x16F9, 0x16FF, &[168]), (0x1700, 0x1715, &[147]), (0x1716, 0x171E, &[168]),
+    (0x171F, 0x171F, &[147]), (0x1720, 0x1734, &[53]), (0x1735, 0x1736, &[147, 53, 17, 148]),
+    (0x1737, 0x173F, &[168]), (0x1740, 0x1753, &[17]), (0x1754, 0x175F, &[168]),
+    (0x1760, 0x176C, &[148]), (0x176D, 0x176D, &[168]), (0x176E, 0x1770, &[148]),
+    (0x1771, 0x1771, &[168]), (0x1772, 0x1773, &[148]), (0x1774, 0x177F, &[168]),
+    (0x1780, 0x17DD, &[70]), (0x17DE, 0x17DF, &[168]), (0x17E0, 0x17E9, &[70]),
+    (0x17EA, 0x17EF, &[168]), (0x17F0, 0x17F9, &[70]), (0x17FA, 0x17FF, &[168]),
+    (0x1800, 0x1801, &[97]), (0x1802, 0x1803, &[97, 127]), (0x1804, 0x1804, &[97]),
+    (0x1805, 0x1805, &[97, 127]), (0x1806, 0x1819, &[97]), (0x181A, 0x181F, &[168]),
+    (0x1820, 0x1878, &[97]), (0x1879, 0x187F, &[168]), (0x1880, 0x18AA, &[97]),
+    (0x18AB, 0x18AF, &[168]), (0x18B0, 0x18F5, &[18]), (0x18F6, 0x18FF, &[168]),
+    (0x1900, 0x191E, &[77]), (0x191F, 0x191F, &[168]), (0x1920, 0x192B, &[77]),
+    (0x192C, 0x192F, &[168]), (0x1930, 0x193B, &[77]), (0x193C, 0x193F, &[168]),
+    (0x1940, 0x1940, &[77]), (0x1941, 0x1943, &[168]), (0x1944, 0x194F, &[77]),
+    (0x1950, 0x196D, &[149]), (0x196E, 0x196F, &[168]), (0x1970, 0x1974, &[149]),
+    (0x1975, 0x197F, &[168]), (0x1980, 0x19AB, &[104]), (0x19AC, 0x19AF, &[168]),
+    (0x19B0, 0x19C9, &[104]), (0x19CA, 0x19CF, &[168]), (0x19D0, 0x19DA, &[104]),
+    (0x19DB, 0x19DD, &[168]), (0x19DE, 0x19DF, &[104]), (0x19E0, 0x19FF, &[70]),
+    (0x1A00, 0x1A1B, &[16]), (0x1A1C, 0x1A1D, &[168]), (0x1A1E, 0x1A1F, &[16]),
+    (0x1A20, 0x1A5E, &[150]), (0x1A5F, 0x1A5F, &[168]), (0x1A60, 0x1A7C, &[150]),
+    (0x1A7D, 0x1A7E, &[168]), (0x1A7F, 0x1A89, &[150]), (0x1A8A, 0x1A8F, &[168]),
+    (0x1A90, 0x1A99, &[150]), (0x1A9A, 0x1A9F, &[168]), (0x1AA0, 0x1AAD, &[150]),
+    (0x1AAE, 0x1AAF, &[168]), (0x1AB0, 0x1ADD, &[58]), (0x1ADE, 0x1ADF, &[168]),
+    (0x1AE0, 0x1AEB, &[58]), (0x1AEC, 0x1AFF, &[168]), (0x1B00, 0x1B4C, &[6]),
+    (0x1B4D, 0x1B4D, &[168]), (0x1B4E, 0x1B7F, &[6]), (0x1B80, 0x1BBF, &[143]),
+    (0x1BC0, 0x1BF3, &[9]), (0x1BF4, 0x1BFB, &[168]), (0x1BFC, 0x1BFF, &[9]),
+    (0x1C00, 0x1C37, &[76]), (0x1C38, 0x1C3A, &[168]), (0x1C3B, 0x1C49, &[76]),
+    (0x1C4A, 0x1C4C, &[168]), (0x1C4D, 0x1C4F, &[76]), (0x1C50, 0x1C7F, &[110]),
+    (0x1C80, 0x1C8A, &[30]), (0x1C8B, 0x1C8F, &[168]), (0x1C90, 0x1CBA, &[41]),
+    (0x1CBB, 0x1CBC, &[168]), (0x1CBD, 0x1CBF, &[41]), (0x1CC0, 0x1CC7, &[143]),
+    (0x1CC8, 0x1CCF, &[168]), (0x1CD0, 0x1CD0, &[10, 32, 63, 44]), (0x1CD1, 0x1CD1, &[32]),
+    (0x1CD2, 0x1CD2, &[10, 32, 63, 44]), (0x1CD3, 0x1CD3, &[32, 63, 44]), (0x1CD4, 0x1CD4, &[32]),
+    (0x1CD5, 0x1CD5, &[10, 32, 157, 162, 105]), (0x1CD6, 0x1CD6, &[10, 32, 157]), (0x1CD7, 0x1CD7, &[32, 134, 105]),
+    (0x1CD8, 0x1CD8, &[10, 32, 157, 105]), (0x1CD9, 0x1CD9, &[32, 134]), (0x1CDA, 0x1CDA, &[32, 63, 85, 121, 154, 157]),
+    (0x1CDB, 0x1CDB, &[32]), (0x1CDC, 0x1CDD, &[32, 134]), (0x1CDE, 0x1CDF, &[32]),
+    (0x1CE0, 0x1CE0, &[32, 134]), (0x1CE1, 0x1CE1, &[10, 32]), (0x1CE2, 0x1CE2, &[32, 162, 105]),
+    (0x1CE3, 0x1CE8, &[32]), (0x1CE9, 0x1CE9, &[32, 105, 103]), (0x1CEA, 0x1CEA, &[10, 32, 134]),
+    (0x1CEB, 0x1CEB, &[32, 105]), (0x1CEC, 0x1CEC, &[32]), (0x1CED, 0x1CED, &[10, 32, 134, 105]),
+    (0x1CEE, 0x1CF1, &[32]), (0x1CF2, 0x1CF2, &[10, 32, 63, 85, 121, 139, 157, 44, 162, 103, 166]), (0x1CF3, 0x1CF3, &[32, 44]),
+    (0x1CF4, 0x1CF4, &[32, 63, 44, 166]), (0x1CF5, 0x1CF6, &[10, 32]), (0x1CF7, 0x1CF7, &[10]),
+    (0x1CF8, 0x1CF9, &[32, 44]), (0x1CFA, 0x1CFA, &[103]), (0x1CFB, 0x1CFF, &[168]),
+    (0x1D00, 0x1D25, &[75]), (0x1D26, 0x1D2A, &[45]), (0x1D2B, 0x1D2B, &[30]),
+    (0x1D2C, 0x1D5C, &[75]), (0x1D5D, 0x1D61, &[45]), (0x1D62, 0x1D65, &[75]),
+    (0x1D66, 0x1D6A, &[45]), (0x1D6B, 0x1D77, &[75]), (0x1D78, 0x1D78, &[30]),
+    (0x1D79, 0x1DBE, &[75]), (0x1DBF, 0x1DBF, &[45]), (0x1DC0, 0x1DC1, &[45]),
+    (0x1DC2, 0x1DF7, &[58]), (0x1DF8, 0x1DF8, &[30, 75, 146]), (0x1DF9, 0x1DF9, &[58]),
+    (0x1DFA, 0x1DFA, &[146]), (0x1DFB, 0x1DFF, &[58]), (0x1E00, 0x1EFF, &[75]),
+    (0x1F00, 0x1F15, &[45]), (0x1F16, 0x1F17, &[168]), (0x1F18, 0x1F1D, &[45]),
+    (0x1F1E, 0x1F1F, &[168]), (0x1F20, 0x1F45, &[45]), (0x1F46, 0x1F47, &[168]),
+    (0x1F48, 0x1F4D, &[45]), (0x1F4E, 0x1F4F, &[168]), (0x1F50, 0x1F57, &[45]),
+    (0x1F58, 0x1F58, &[168]), (0x1F59, 0x1F59, &[45]), (0x1F5A, 0x1F5A, &[168]),
+    (0x1F5B, 0x1F5B, &[45]), (0x1F5C, 0x1F5C, &[168]), (0x1F5D, 0x1F5D, &[45]),
+    (0x1F5E, 0x1F5E, &[168]), (0x1F5F, 0x1F7D, &[45]), (0x1F7E, 0x1F7F, &[168]),
+    (0x1F80, 0x1FB4, &[45]), (0x1FB5, 0x1FB5, &[168]), (0x1FB6, 0x1FC4, &[45]),
+    (0x1FC5, 0x1FC5, &[168]), (0x1FC6, 0x1FD3, &[45]), (0x1FD4, 0x1FD5, &[168]),
+    (0x1FD6, 0x1FDB, &[45]), (0x1FDC, 0x1FDC, &[168]), (0x1FDD, 0x1FEF, &[45]),
+    (0x1FF0, 0x1FF1, &[168]), (0x1FF2, 0x1FF4, &[45]), (0x1FF5, 0x1FF5, &[168]),
+    (0x1FF6, 0x1FFE, &[45]), (0x1FFF, 0x1FFF, &[168]), (0x2000, 0x200B, &[25]),
+    (0x200C, 0x200D, &[58]), (0x200E, 0x202E, &[25]), (0x202F, 0x202F, &[75, 97, 127]),
+    (0x2030, 0x204E, &[25]), (0x204F, 0x204F, &[3, 0]), (0x2050, 0x2059, &[25]),
+    (0x205A, 0x205A, &[41, 42, 112, 119, 19, 81]), (0x205B, 0x205C, &[25]), (0x205D, 0x205D, &[45, 112, 94, 19]),
+    (0x205E, 0x2064, &[25]), (0x2065, 0x2065, &[168]), (0x2066, 0x2070, &[25]),
+    (0x2071, 0x2071, &[75]), (0x2072, 0x2073, &[168]), (0x2074, 0x207E, &[25]),
+    (0x207F, 0x207F, &[75]), (0x2080, 0x208E, &[25]), (0x208F, 0x208F, &[168]),
+    (0x2090, 0x209C, &[75]), (0x209D, 0x209F, &[168]), (0x20A0, 0x20C1, &[25]),
+    (0x20C2, 0x20CF, &[168]), (0x20D0, 0x20EF, &[58]), (0x20F0, 0x20F0, &[32, 75, 44]),
+    (0x20F1, 0x20FF, &[168]), (0x2100, 0x2125, &[25]), (0x2126, 0x2126, &[45]),
+    (0x2127, 0x2129, &[25]), (0x212A, 0x212B, &[75]), (0x212C, 0x2131, &[25]),
+    (0x2132, 0x2132, &[75]), (0x2133, 0x214D, &[25]), (0x214E, 0x214E, &[75]),
+    (0x214F, 0x215F, &[25]), (0x2160, 0x2188, &[75]), (0x2189, 0x218B, &[25]),
+    (0x218C, 0x218F, &[168]), (0x2190, 0x2429, &[25]), (0x242A, 0x243F, &[168]),
+    (0x2440, 0x244A, &[25]), (0x244B, 0x245F, &[168]), (0x2460, 0x27FF, &[25]),
+    (0x2800, 0x28FF, &[15]), (0x2900, 0x2B73, &[25]), (0x2B74, 0x2B75, &[168]),
+    (0x2B76, 0x2BFF, &[25]), (0x2C00, 0x2C5F, &[42]), (0x2C60, 0x2C7F, &[75]),
+    (0x2C80, 0x2CF3, &[26]), (0x2CF4, 0x2CF8, &[168]), (0x2CF9, 0x2CFF, &[26]),
+    (0x2D00, 0x2D25, &[41]), (0x2D26, 0x2D26, &[168]), (0x2D27, 0x2D27, &[41]),
+    (0x2D28, 0x2D2C, &[168]), (0x2D2D, 0x2D2D, &[41]), (0x2D2E, 0x2D2F, &[168]),
+    (0x2D30, 0x2D67, &[161]), (0x2D68, 0x2D6E, &[168]), (0x2D6F, 0x2D70, &[161]),
+    (0x2D71, 0x2D7E, &[168]), (0x2D7F, 0x2D7F, &[161]), (0x2D80, 0x2D96, &[39]),
+    (0x2D97, 0x2D9F, &[168]), (0x2DA0, 0x2DA6, &[39]), (0x2DA7, 0x2DA7, &[168]),
+    (0x2DA8, 0x2DAE, &[39]), (0x2DAF, 0x2DAF, &[168]), (0x2DB0, 0x2DB6, &[39]),
+    (0x2DB7, 0x2DB7, &[168]), (0x2DB8, 0x2DBE, &[39]), (0x2DBF, 0x2DBF, &[168]),
+    (0x2DC0, 0x2DC6, &[39]), (0x2DC7, 0x2DC7, &[168]), (0x2DC8, 0x2DCE, &[39]),
+    (0x2DCF, 0x2DCF, &[168]), (0x2DD0, 0x2DD6, &[39]), (0x2DD7, 0x2DD7, &[168]),
+    (0x2DD8, 0x2DDE, &[39]), (0x2DDF, 0x2DDF, &[168]), (0x2DE0, 0x2DFF, &[30]),
+    (0x2E00, 0x2E16, &[25]), (0x2E17, 0x2E17, &[26, 75]), (0x2E18, 0x2E2F, &[25]),
+    (0x2E30, 0x2E30, &[119, 5]), (0x2E31, 0x2E31, &[41, 112, 19, 82, 5, 62, 132]), (0x2E32, 0x2E3B, &[25]),
+    (0x2E3C, 0x2E3C, &[35]), (0x2E3D, 0x2E40, &[25]), (0x2E41, 0x2E41, &[3, 112, 0]),
+    (0x2E42, 0x2E42, &[25]), (0x2E43, 0x2E43, &[30, 42]), (0x2E44, 0x2E5D, &[25]),
+    (0x2E5E, 0x2E7F, &[168]), (0x2E80, 0x2E99, &[50]), (0x2E9A, 0x2E9A, &[168]),
+    (0x2E9B, 0x2EF3, &[50]), (0x2EF4, 0x2EFF, &[168]), (0x2F00, 0x2FD5, &[50]),
+    (0x2FD6, 0x2FEF, &[168]), (0x2FF0, 0x2FFF, &[50, 156]), (0x3000, 0x3000, &[25]),
+    (0x3001, 0x3001, &[13, 50, 51, 56, 64, 97, 174]), (0x3002, 0x3002, &[13, 50, 51, 56, 64, 97, 174, 127]), (0x3003, 0x3003, &[13, 50, 51, 56, 64]),
+    (0x3004, 0x3004, &[25]), (0x3005, 0x3005, &[50]), (0x3006, 0x3006, &[50]),
+    (0x3007, 0x3007, &[50]), (0x3008, 0x3009, &[13, 50, 51, 56, 64, 97, 160, 174]), (0x300A, 0x300B, &[13, 50, 51, 56, 64, 97, 160, 174, 80]),
+    (0x300C, 0x3011, &[13, 50, 51, 56, 64, 174]), (0x3012, 0x3012, &[25]), (0x3013, 0x3013, &[13, 50, 51, 56, 64]),
+    (0x3014, 0x301B, &[13, 50, 51, 56, 64, 174]), (0x301C, 0x301F, &[13, 50, 51, 56, 64]), (0x3020, 0x3020, &[25]),
+    (0x3021, 0x3029, &[50]), (0x302A, 0x302D, &[13, 50]), (0x302E, 0x302F, &[51]),
+    (0x3030, 0x3030, &[13, 50, 51, 56, 64]), (0x3031, 0x3035, &[56, 64]), (0x3036, 0x3036, &[25]),
+    (0x3037, 0x3037, &[13, 50, 51, 56, 64]), (0x3038, 0x303B, &[50]), (0x303C, 0x303D, &[50, 56, 64]),
+    (0x303E, 0x303F, &[50]), (0x3040, 0x3040, &[168]), (0x3041, 0x3096, &[56]),
+    (0x3097, 0x3098, &[168]), (0x3099, 0x309C, &[56, 64]), (0x309D, 0x309F, &[56]),
+    (0x30A0, 0x30A0, &[56, 64]), (0x30A1, 0x30FA, &[64]), (0x30FB, 0x30FB, &[13, 50, 51, 56, 64, 174]),
+    (0x30FC, 0x30FC, &[56, 64]), (0x30FD, 0x30FF, &[64]), (0x3100, 0x3104, &[168]),
+    (0x3105, 0x312F, &[13]), (0x3130, 0x3130, &[168]), (0x3131, 0x318E, &[51]),
+    (0x318F, 0x318F, &[168]), (0x3190, 0x319F, &[50]), (0x31A0, 0x31BF, &[13]),
+    (0x31C0, 0x31E5, &[50]), (0x31E6, 0x31EE, &[168]), (0x31EF, 0x31EF, &[50, 156]),
+    (0x31F0, 0x31FF, &[64]), (0x3200, 0x321E, &[51]), (0x321F, 0x321F, &[168]),
+    (0x3220, 0x3247, &[50]), (0x3248, 0x325F, &[25]), (0x3260, 0x327E, &[51]),
+    (0x327F, 0x327F, &[25]), (0x3280, 0x32B0, &[50]), (0x32B1, 0x32BF, &[25]),
+    (0x32C0, 0x32CB, &[50]), (0x32CC, 0x32CF, &[25]), (0x32D0, 0x32FE, &[64]),
+    (0x32FF, 0x32FF, &[50]), (0x3300, 0x3357, &[64]), (0x3358, 0x3370, &[50]),
+    (0x3371, 0x337A, &[25]), (0x337B, 0x337F, &[50]), (0x3380, 0x33DF, &[25]),
+    (0x33E0, 0x33FE, &[50]), (0x33FF, 0x33FF, &[25]), (0x3400, 0x4DBF, &[50]),
+    (0x4DC0, 0x4DFF, &[25]), (0x4E00, 0x9FFF, &[50]), (0xA000, 0xA48C, &[174]),
+    (0xA48D, 0xA48F, &[168]), (0xA490, 0xA4C6, &[174]), (0xA4C7, 0xA4CF, &[168]),
+    (0xA4D0, 0xA4FF, &[80]), (0xA500, 0xA62B, &[169]), (0xA62C, 0xA63F, &[168]),
+    (0xA640, 0xA66E, &[30]), (0xA66F, 0xA66F, &[30, 42]), (0xA670, 0xA69F, &[30]),
+    (0xA6A0, 0xA6F7, &[7]), (0xA6F8, 0xA6FF, &[168]), (0xA700, 0xA707, &[50, 75]),
+    (0xA708, 0xA721, &[25]), (0xA722, 0xA787, &[75]), (0xA788, 0xA78A, &[25]),
+    (0xA78B, 0xA7DC, &[75]), (0xA7DD, 0xA7F0, &[168]), (0xA7F1, 0xA7FF, &[75]),
+    (0xA800, 0xA82C, &[145]), (0xA82D, 0xA82F, &[168]), (0xA830, 0xA832, &[32, 46, 48, 63, 85, 62, 72, 134, 153, 71, 162, 83, 96, 34, 103, 166]),
+    (0xA833, 0xA835, &[32, 46, 48, 63, 62, 72, 134, 153, 71, 162, 83, 96, 34, 103, 166]), (0xA836, 0xA837, &[32, 46, 48, 62, 72, 153, 71, 162, 83, 96, 34]), (0xA838, 0xA838, &[32, 46, 48, 62, 72, 134, 153, 71, 162, 83, 96, 34]),
+    (0xA839, 0xA839, &[32, 46, 48, 62, 72, 153, 71, 162, 83, 96, 34]), (0xA83A, 0xA83F, &[168]), (0xA840, 0xA877, &[127]),
+    (0xA878, 0xA87F, &[168]), (0xA880, 0xA8C5, &[133]), (0xA8C6, 0xA8CD, &[168]),
+    (0xA8CE, 0xA8D9, &[133]), (0xA8DA, 0xA8DF, &[168]), (0xA8E0, 0xA8F0, &[32]),
+    (0xA8F1, 0xA8F1, &[10, 32, 166]), (0xA8F2, 0xA8F2, &[32]), (0xA8F3, 0xA8F3, &[32, 154]),
+    (0xA8F4, 0xA8FF, &[32]), (0xA900, 0xA92D, &[67]), (0xA92E, 0xA92E, &[75, 100, 67]),
+    (0xA92F, 0xA92F, &[67]), (0xA930, 0xA953, &[130]), (0xA954, 0xA95E, &[168]),
+    (0xA95F, 0xA95F, &[130]), (0xA960, 0xA97C, &[51]), (0xA97D, 0xA97F, &[168]),
+    (0xA980, 0xA9CD, &[61]), (0xA9CE, 0xA9CE, &[168]), (0xA9CF, 0xA9CF, &[16, 61]),
+    (0xA9D0, 0xA9D9, &[61]), (0xA9DA, 0xA9DD, &[168]), (0xA9DE, 0xA9DF, &[61]),
+    (0xA9E0, 0xA9FE, &[100]), (0xA9FF, 0xA9FF, &[168]), (0xAA00, 0xAA36, &[22]),
+    (0xAA37, 0xAA3F, &[168]), (0xAA40, 0xAA4D, &[22]), (0xAA4E, 0xAA4F, &[168]),
+    (0xAA50, 0xAA59, &[22]), (0xAA5A, 0xAA5B, &[168]), (0xAA5C, 0xAA5F, &[22]),
+    (0xAA60, 0xAA7F, &[100]), (0xAA80, 0xAAC2, &[151]), (0xAAC3, 0xAADA, &[168]),
+    (0xAADB, 0xAADF, &[151]), (0xAAE0, 0xAAF6, &[91]), (0xAAF7, 0xAB00, &[168]),
+    (0xAB01, 0xAB06, &[39]), (0xAB07, 0xAB08, &[168]), (0xAB09, 0xAB0E, &[39]),
+    (0xAB0F, 0xAB10, &[168]), (0xAB11, 0xAB16, &[39]), (0xAB17, 0xAB1F, &[168]),
+    (0xAB20, 0xAB26, &[39]), (0xAB27, 0xAB27, &[168]), (0xAB28, 0xAB2E, &[39]),
+    (0xAB2F, 0xAB2F, &[168]), (0xAB30, 0xAB5A, &[75]), (0xAB5B, 0xAB5B, &[25]),
+    (0xAB5C, 0xAB64, &[75]), (0xAB65, 0xAB65, &[45]), (0xAB66, 0xAB69, &[75]),
+    (0xAB6A, 0xAB6B, &[25]), (0xAB6C, 0xAB6F, &[168]), (0xAB70, 0xABBF, &[23]),
+    (0xABC0, 0xABED, &[91]), (0xABEE, 0xABEF, &[168]), (0xABF0, 0xABF9, &[91]),
+    (0xABFA, 0xABFF, &[168]), (0xAC00, 0xD7A3, &[51]), (0xD7A4, 0xD7AF, &[168]),
+    (0xD7B0, 0xD7C6, &[51]), (0xD7C7, 0xD7CA, &[168]), (0xD7CB, 0xD7FB, &[51]),
+    (0xD7FC, 0xF8FF, &[168]), (0xF900, 0xFA6D, &[50]), (0xFA6E, 0xFA6F, &[168]),
+    (0xFA70, 0xFAD9, &[50]), (0xFADA, 0xFAFF, &[168]), (0xFB00, 0xFB06, &[75]),
+    (0xFB07, 0xFB12, &[168]), (0xFB13, 0xFB17, &[4]), (0xFB18, 0xFB1C, &[168]),
+    (0xFB1D, 0xFB36, &[55]), (0xFB37, 0xFB37, &[168]), (0xFB38, 0xFB3C, &[55]),
+    (0xFB3D, 0xFB3D, &[168]), (0xFB3E, 0xFB3E, &[55]), (0xFB3F, 0xFB3F, &[168]),
+    (0xFB40, 0xFB41, &[55]), (0xFB42, 0xFB42, &[168]), (0xFB43, 0xFB44, &[55]),
+    (0xFB45, 0xFB45, &[168]), (0xFB46, 0xFB4F, &[55]), (0xFB50, 0xFD3D, &[3]),
+    (0xFD3E, 0xFD3F, &[3, 106]), (0xFD40, 0xFDCF, &[3]), (0xFDD0, 0xFDEF, &[168]),
+    (0xFDF0, 0xFDF1, &[3]), (0xFDF2, 0xFDF2, &[3, 158]), (0xFDF3, 0xFDFC, &[3]),
+    (0xFDFD, 0xFDFD, &[3, 158]), (0xFDFE, 0xFDFF, &[3]), (0xFE00, 0xFE0F, &[58]),
+    (0xFE10, 0xFE19, &[25]), (0xFE1A, 0xFE1F, &[168]), (0xFE20, 0xFE2D, &[58]),
+    (0xFE2E, 0xFE2F, &[30]), (0xFE30, 0xFE44, &[25]), (0xFE45, 0xFE46, &[13, 50, 51, 56, 64]),
+    (0xFE47, 0xFE52, &[25]), (0xFE53, 0xFE53, &[168]), (0xFE54, 0xFE66, &[25]),
+    (0xFE67, 0xFE67, &[168]), (0xFE68, 0xFE6B, &[25]), (0xFE6C, 0xFE6F, &[168]),
+    (0xFE70, 0xFE74, &[3]), (0xFE75, 0xFE75, &[168]), (0xFE76, 0xFEFC, &[3]),
+    (0xFEFD, 0xFEFE, &[168]), (0xFEFF, 0xFEFF, &[25]), (0xFF00, 0xFF00, &[168]),
+    (0xFF01, 0xFF20, &[25]), (0xFF21, 0xFF3A, &[75]), (0xFF3B, 0xFF40, &[25]),
+    (0xFF41, 0xFF5A, &[75]), (0xFF5B, 0xFF60, &[25]), (0xFF61, 0xFF65, &[13, 50, 51, 56, 64, 174]),
+    (0xFF66, 0xFF6F, &[64]), (0xFF70, 0xFF70, &[56, 64]), (0xFF71, 0xFF9D, &[64]),
+    (0xFF9E, 0xFF9F, &[56, 64]), (0xFFA0, 0xFFBE, &[51]), (0xFFBF, 0xFFC1, &[168]),
+    (0xFFC2, 0xFFC7, &[51]), (0xFFC8, 0xFFC9, &[168]), (0xFFCA, 0xFFCF, &[51]),
+    (0xFFD0, 0xFFD1, &[168]), (0xFFD2, 0xFFD7, &[51]), (0xFFD8, 0xFFD9, &[168]),
+    (0xFFDA, 0xFFDC, &[51]), (0xFFDD, 0xFFDF, &[168]), (0xFFE0, 0xFFE6, &[25]),
+    (0xFFE7, 0xFFE7, &[168]), (0xFFE8, 0xFFEE, &[25]), (0xFFEF, 0xFFF8, &[168]),
+    (0xFFF9, 0xFFFD, &[25]), (0xFFFE, 0xFFFF, &[168]), (0x10000, 0x1000B, &[79]),
+    (0x1000C, 0x1000C, &[168]), (0x1000D, 0x10026, &[79]), (0x10027, 0x10027, &[168]),
+    (0x10028, 0x1003A, &[79]), (0x1003B, 0x1003B, &[168]), (0x1003C, 0x1003D, &[79]),
+    (0x1003E, 0x1003E, &[168]), (0x1003F, 0x1004D, &[79]), (0x1004E, 0x1004F, &[168]),
+    (0x10050, 0x1005D, &[79]), (0x1005E, 0x1007F, &[168]), (0x10080, 0x100FA, &[79]),
+    (0x100FB, 0x100FF, &[168]), (0x10100, 0x10101, &[28, 79, 29]), (0x10102, 0x10102, &[28, 79]),
+    (0x10103, 0x10106, &[168]), (0x10107, 0x10133, &[28, 79, 78]), (0x10134, 0x10136, &[168]),
+    (0x10137, 0x1013F, &[28, 79]), (0x10140, 0x1018E, &[45]), (0x1018F, 0x1018F, &[168]),
+    (0x10190, 0x1019C, &[25]), (0x1019D, 0x1019F, &[168]), (0x101A0, 0x101A0, &[45]),
+    (0x101A1, 0x101CF, &[168]), (0x101D0, 0x101FC, &[25]), (0x101FD, 0x101FD, &[58]),
+    (0x101FE, 0x1027F, &[168]), (0x10280, 0x1029C, &[81]), (0x1029D, 0x1029F, &[168]),
+    (0x102A0, 0x102D0, &[19]), (0x102D1, 0x102DF, &[168]), (0x102E0, 0x102FB, &[3, 26]),
+    (0x102FC, 0x102FF, &[168]), (0x10300, 0x10323, &[113]), (0x10324, 0x1032C, &[168]),
+    (0x1032D, 0x1032F, &[113]), (0x10330, 0x1034A, &[43]), (0x1034B, 0x1034F, &[168]),
+    (0x10350, 0x1037A, &[115]), (0x1037B, 0x1037F, &[168]), (0x10380, 0x1039D, &[167]),
+    (0x1039E, 0x1039E, &[168]), (0x1039F, 0x1039F, &[167]), (0x103A0, 0x103C3, &[116]),
+    (0x103C4, 0x103C7, &[168]), (0x103C8, 0x103D5, &[116]), (0x103D6, 0x103FF, &[168]),
+    (0x10400, 0x1044F, &[31]), (0x10450, 0x1047F, &[135]), (0x10480, 0x1049D, &[123]),
+    (0x1049E, 0x1049F, &[168]), (0x104A0, 0x104A9, &[123]), (0x104AA, 0x104AF, &[168]),
+    (0x104B0, 0x104D3, &[122]), (0x104D4, 0x104D7, &[168]), (0x104D8, 0x104FB, &[122]),
+    (0x104FC, 0x104FF, &[168]), (0x10500, 0x10527, &[37]), (0x10528, 0x1052F, &[168]),
+    (0x10530, 0x10563, &[20]), (0x10564, 0x1056E, &[168]), (0x1056F, 0x1056F, &[20]),
+    (0x10570, 0x1057A, &[170]), (0x1057B, 0x1057B, &[168]), (0x1057C, 0x1058A, &[170]),
+    (0x1058B, 0x1058B, &[168]), (0x1058C, 0x10592, &[170]), (0x10593, 0x10593, &[168]),
+    (0x10594, 0x10595, &[170]), (0x10596, 0x10596, &[168]), (0x10597, 0x105A1, &[170]),
+    (0x105A2, 0x105A2, &[168]), (0x105A3, 0x105B1, &[170]), (0x105B2, 0x105B2, &[168]),
+    (0x105B3, 0x105B9, &[170]), (0x105BA, 0x105BA, &[168]), (0x105BB, 0x105BC, &[170]),
+    (0x105BD, 0x105BF, &[168]), (0x105C0, 0x105F3, &[163]), (0x105F4, 0x105FF, &[168]),
+    (0x10600, 0x10736, &[78]), (0x10737, 0x1073F, &[168]), (0x10740, 0x10755, &[78]),
+    (0x10756, 0x1075F, &[168]), (0x10760, 0x10767, &[78]), (0x10768, 0x1077F, &[168]),
+    (0x10780, 0x10785, &[75]), (0x10786, 0x10786, &[168]), (0x10787, 0x107B0, &[75]),
+    (0x107B1, 0x107B1, &[168]), (0x107B2, 0x107BA, &[75]), (0x107BB, 0x107FF, &[168]),
+    (0x10800, 0x10805, &[28]), (0x10806, 0x10807, &[168]), (0x10808, 0x10808, &[28]),
+    (0x10809, 0x10809, &[168]), (0x1080A, 0x10835, &[28]), (0x10836, 0x10836, &[168]),
+    (0x10837, 0x10838, &[28]), (0x10839, 0x1083B, &[168]), (0x1083C, 0x1083C, &[28]),
+    (0x1083D, 0x1083E, &[168]), (0x1083F, 0x1083F, &[28]), (0x10840, 0x10855, &[57]),
+    (0x10856, 0x10856, &[168]), (0x10857, 0x1085F, &[57]), (0x10860, 0x1087F, &[125]),
+    (0x10880, 0x1089E, &[101]), (0x1089F, 0x108A6, &[168]), (0x108A7, 0x108AF, &[101]),
+    (0x108B0, 0x108DF, &[168]), (0x108E0, 0x108F2, &[54]), (0x108F3, 0x108F3, &[168]),
+    (0x108F4, 0x108F5, &[54]), (0x108F6, 0x108FA, &[168]), (0x108FB, 0x108FF, &[54]),
+    (0x10900, 0x1091B, &[128]), (0x1091C, 0x1091E, &[168]), (0x1091F, 0x1091F, &[128]),
+    (0x10920, 0x10939, &[82]), (0x1093A, 0x1093E, &[168]), (0x1093F, 0x1093F, &[82]),
+    (0x10940, 0x10959, &[137]), (0x1095A, 0x1097F, &[168]), (0x10980, 0x1099F, &[94]),
+    (0x109A0, 0x109B7, &[93]), (0x109B8, 0x109BB, &[168]), (0x109BC, 0x109CF, &[93]),
+    (0x109D0, 0x109D1, &[168]), (0x109D2, 0x109FF, &[93]), (0x10A00, 0x10A03, &[68]),
+    (0x10A04, 0x10A04, &[168]), (0x10A05, 0x10A06, &[68]), (0x10A07, 0x10A0B, &[168]),
+    (0x10A0C, 0x10A13, &[68]), (0x10A14, 0x10A14, &[168]), (0x10A15, 0x10A17, &[68]),
+    (0x10A18, 0x10A18, &[168]), (0x10A19, 0x10A35, &[68]), (0x10A36, 0x10A37, &[168]),
+    (0x10A38, 0x10A3A, &[68]), (0x10A3B, 0x10A3E, &[168]), (0x10A3F, 0x10A48, &[68]),
+    (0x10A49, 0x10A4F, &[168]), (0x10A50, 0x10A58, &[68]), (0x10A59, 0x10A5F, &[168]),
+    (0x10A60, 0x10A7F, &[118]), (0x10A80, 0x10A9F, &[114]), (0x10AA0, 0x10ABF, &[168]),
+    (0x10AC0, 0x10AE6, &[87]), (0x10AE7, 0x10AEA, &[168]), (0x10AEB, 0x10AF1, &[87]),
+    (0x10AF2, 0x10AF2, &[87, 120]), (0x10AF3, 0x10AF6, &[87]), (0x10AF7, 0x10AFF, &[168]),
+    (0x10B00, 0x10B35, &[5]), (0x10B36, 0x10B38, &[168]), (0x10B39, 0x10B3F, &[5]),
+    (0x10B40, 0x10B55, &[60]), (0x10B56, 0x10B57, &[168]), (0x10B58, 0x10B5F, &[60]),
+    (0x10B60, 0x10B72, &[59]), (0x10B73, 0x10B77, &[168]), (0x10B78, 0x10B7F, &[59]),
+    (0x10B80, 0x10B91, &[129]), (0x10B92, 0x10B98, &[168]), (0x10B99, 0x10B9C, &[129]),
+    (0x10B9D, 0x10BA8, &[168]), (0x10BA9, 0x10BAF, &[129]), (0x10BB0, 0x10BFF, &[168]),
+    (0x10C00, 0x10C48, &[119]), (0x10C49, 0x10C7F, &[168]), (0x10C80, 0x10CB2, &[112]),
+    (0x10CB3, 0x10CBF, &[168]), (0x10CC0, 0x10CF2, &[112]), (0x10CF3, 0x10CF9, &[168]),
+    (0x10CFA, 0x10CFF, &[112]), (0x10D00, 0x10D27, &[52]), (0x10D28, 0x10D2F, &[168]),
+    (0x10D30, 0x10D39, &[52]), (0x10D3A, 0x10D3F, &[168]), (0x10D40, 0x10D65, &[40]),
+    (0x10D66, 0x10D68, &[168]), (0x10D69, 0x10D85, &[40]), (0x10D86, 0x10D8D, &[168]),
+    (0x10D8E, 0x10D8F, &[40]), (0x10D90, 0x10E5F, &[168]), (0x10E60, 0x10E7E, &[3]),
+    (0x10E7F, 0x10E7F, &[168]), (0x10E80, 0x10EA9, &[173]), (0x10EAA, 0x10EAA, &[168]),
+    (0x10EAB, 0x10EAD, &[173]), (0x10EAE, 0x10EAF, &[168]), (0x10EB0, 0x10EB1, &[173]),
+    (0x10EB2, 0x10EC1, &[168]), (0x10EC2, 0x10EC7, &[3]), (0x10EC8, 0x10ECF, &[168]),
+    (0x10ED0, 0x10ED8, &[3]), (0x10ED9, 0x10EF9, &[168]), (0x10EFA, 0x10EFF, &[3]),
+    (0x10F00, 0x10F27, &[117]), (0x10F28, 0x10F2F, &[168]), (0x10F30, 0x10F59, &[140]),
+    (0x10F5A, 0x10F6F, &[168]), (0x10F70, 0x10F89, &[120]), (0x10F8A, 0x10FAF, &[168]),
+    (0x10FB0, 0x10FCB, &[24]), (0x10FCC, 0x10FDF, &[168]), (0x10FE0, 0x10FF6, &[38]),
+    (0x10FF7, 0x10FFF, &[168]), (0x11000, 0x1104D, &[14]), (0x1104E, 0x11051, &[168]),
+    (0x11052, 0x11075, &[14]), (0x11076, 0x1107E, &[168]), (0x1107F, 0x1107F, &[14]),
+    (0x11080, 0x110C2, &[62]), (0x110C3, 0x110CC, &[168]), (0x110CD, 0x110CD, &[62]),
+    (0x110CE, 0x110CF, &[168]), (0x110D0, 0x110E8, &[141]), (0x110E9, 0x110EF, &[168]),
+    (0x110F0, 0x110F9, &[141]), (0x110FA, 0x110FF, &[168]), (0x11100, 0x11134, &[21]),
+    (0x11135, 0x11135, &[168]), (0x11136, 0x11147, &[21]), (0x11148, 0x1114F, &[168]),
+    (0x11150, 0x11176, &[83]), (0x11177, 0x1117F, &[168]), (0x11180, 0x111DF, &[134]),
+    (0x111E0, 0x111E0, &[168]), (0x111E1, 0x111F4, &[139]), (0x111F5, 0x111FF, &[168]),
+    (0x11200, 0x11211, &[71]), (0x11212, 0x11212, &[168]), (0x11213, 0x11241, &[71]),
+    (0x11242, 0x1127F, &[168]), (0x11280, 0x11286, &[99]), (0x11287, 0x11287, &[168]),
+    (0x11288, 0x11288, &[99]), (0x11289, 0x11289, &[168]), (0x1128A, 0x1128D, &[99]),
+    (0x1128E, 0x1128E, &[168]), (0x1128F, 0x1129D, &[99]), (0x1129E, 0x1129E, &[168]),
+    (0x1129F, 0x112A9, &[99]), (0x112AA, 0x112AF, &[168]), (0x112B0, 0x112EA, &[72]),
+    (0x112EB, 0x112EF, &[168]), (0x112F0, 0x112F9, &[72]), (0x112FA, 0x112FF, &[168]),
+    (0x11300, 0x11300, &[44]), (0x11301, 0x11301, &[154, 44]), (0x11302, 0x11302, &[44]),
+    (0x11303, 0x11303, &[154, 44]), (0x11304, 0x11304, &[168]), (0x11305, 0x1130C, &[44]),
+    (0x1130D, 0x1130E, &[168]), (0x1130F, 0x11310, &[44]), (0x11311, 0x11312, &[168]),
+    (0x11313, 0x11328, &[44]), (0x11329, 0x11329, &[168]), (0x1132A, 0x11330, &[44]),
+    (0x11331, 0x11331, &[168]), (0x11332, 0x11333, &[44]), (0x11334, 0x11334, &[168]),
+    (0x11335, 0x11339, &[44]), (0x1133A, 0x1133A, &[168]), (0x1133B, 0x1133C, &[154, 44]),
+    (0x1133D, 0x11344, &[44]), (0x11345, 0x11346, &[168]), (0x11347, 0x11348, &[44]),
+    (0x11349, 0x1134A, &[168]), (0x1134B, 0x1134D, &[44]), (0x1134E, 0x1134F, &[168]),
+    (0x11350, 0x11350, &[44]), (0x11351, 0x11356, &[168]), (0x11357, 0x11357, &[44]),
+    (0x11358, 0x1135C, &[168]), (0x1135D, 0x11363, &[44]), (0x11364, 0x11365, &[168]),
+    (0x11366, 0x1136C, &[44]), (0x1136D, 0x1136F, &[168]), (0x11370, 0x11374, &[44]),
+    (0x11375, 0x1137F, &[168]), (0x11380, 0x11389, &[166]), (0x1138A, 0x1138A, &[168]),
+    (0x1138B, 0x1138B, &[166]), (0x1138C, 0x1138D, &[168]), (0x1138E, 0x1138E, &[166]),
+    (0x1138F, 0x1138F, &[168]), (0x11390, 0x113B5, &[166]), (0x113B6, 0x113B6, &[168]),
+    (0x113B7, 0x113C0, &[166]), (0x113C1, 0x113C1, &[168]), (0x113C2, 0x113C2, &[166]),
+    (0x113C3, 0x113C4, &[168]), (0x113C5, 0x113C5, &[166]), (0x113C6, 0x113C6, &[168]),
+    (0x113C7, 0x113CA, &[166]), (0x113CB, 0x113CB, &[168]), (0x113CC, 0x113D5, &[166]),
+    (0x113D6, 0x113D6, &[168]), (0x113D7, 0x113D8, &[166]), (0x113D9, 0x113E0, &[168]),
+    (0x113E1, 0x113E2, &[166]), (0x113E3, 0x113FF, &[168]), (0x11400, 0x1145B, &[105]),
+    (0x1145C, 0x1145C, &[168]), (0x1145D, 0x11461, &[105]), (0x11462, 0x1147F, &[168]),
+    (0x11480, 0x114C7, &[162]), (0x114C8, 0x114CF, &[168]), (0x114D0, 0x114D9, &[162]),
+    (0x114DA, 0x1157F, &[168]), (0x11580, 0x115B5, &[136]), (0x115B6, 0x115B7, &[168]),
+    (0x115B8, 0x115DD, &[136]), (0x115DE, 0x115FF, &[168]), (0x11600, 0x11644, &[96]),
+    (0x11645, 0x1164F, &[168]), (0x11650, 0x11659, &[96]), (0x1165A, 0x1165F, &[168]),
+    (0x11660, 0x1166C, &[97]), (0x1166D, 0x1167F, &[168]), (0x11680, 0x116B9, &[153]),
+    (0x116BA, 0x116BF, &[168]), (0x116C0, 0x116C9, &[153]), (0x116CA, 0x116CF, &[168]),
+    (0x116D0, 0x116E3, &[100]), (0x116E4, 0x116FF, &[168]), (0x11700, 0x1171A, &[1]),
+    (0x1171B, 0x1171C, &[168]), (0x1171D, 0x1172B, &[1]), (0x1172C, 0x1172F, &[168]),
+    (0x11730, 0x11746, &[1]), (0x11747, 0x117FF, &[168]), (0x11800, 0x1183B, &[34]),
+    (0x1183C, 0x1189F, &[168]), (0x118A0, 0x118F2, &[172]), (0x118F3, 0x118FE, &[168]),
+    (0x118FF, 0x118FF, &[172]), (0x11900, 0x11906, &[33]), (0x11907, 0x11908, &[168]),
+    (0x11909, 0x11909, &[33]), (0x1190A, 0x1190B, &[168]), (0x1190C, 0x11913, &[33]),
+    (0x11914, 0x11914, &[168]), (0x11915, 0x11916, &[33]), (0x11917, 0x11917, &[168]),
+    (0x11918, 0x11935, &[33]), (0x11936, 0x11936, &[168]), (0x11937, 0x11938, &[33]),
+    (0x11939, 0x1193A, &[168]), (0x1193B, 0x11946, &[33]), (0x11947, 0x1194F, &[168]),
+    (0x11950, 0x11959, &[33]), (0x1195A, 0x1199F, &[168]), (0x119A0, 0x119A7, &[103]),
+    (0x119A8, 0x119A9, &[168]), (0x119AA, 0x119D7, &[103]), (0x119D8, 0x119D9, &[168]),
+    (0x119DA, 0x119E4, &[103]), (0x119E5, 0x119FF, &[168]), (0x11A00, 0x11A47, &[175]),
+    (0x11A48, 0x11A4F, &[168]), (0x11A50, 0x11AA2, &[142]), (0x11AA3, 0x11AAF, &[168]),
+    (0x11AB0, 0x11ABF, &[18]), (0x11AC0, 0x11AF8, &[126]), (0x11AF9, 0x11AFF, &[168]),
+    (0x11B00, 0x11B09, &[32]), (0x11B0A, 0x11B5F, &[168]), (0x11B60, 0x11B67, &[134]),
+    (0x11B68, 0x11BBF, &[168]), (0x11BC0, 0x11BE1, &[144]), (0x11BE2, 0x11BEF, &[168]),
+    (0x11BF0, 0x11BF9, &[144]), (0x11BFA, 0x11BFF, &[168]), (0x11C00, 0x11C08, &[12]),
+    (0x11C09, 0x11C09, &[168]), (0x11C0A, 0x11C36, &[12]), (0x11C37, 0x11C37, &[168]),
+    (0x11C38, 0x11C45, &[12]), (0x11C46, 0x11C4F, &[168]), (0x11C50, 0x11C6C, &[12]),
+    (0x11C6D, 0x11C6F, &[168]), (0x11C70, 0x11C8F, &[88]), (0x11C90, 0x11C91, &[168]),
+    (0x11C92, 0x11CA7, &[88]), (0x11CA8, 0x11CA8, &[168]), (0x11CA9, 0x11CB6, &[88]),
+    (0x11CB7, 0x11CFF, &[168]), (0x11D00, 0x11D06, &[89]), (0x11D07, 0x11D07, &[168]),
+    (0x11D08, 0x11D09, &[89]), (0x11D0A, 0x11D0A, &[168]), (0x11D0B, 0x11D36, &[89]),
+    (0x11D37, 0x11D39, &[168]), (0x11D3A, 0x11D3A, &[89]), (0x11D3B, 0x11D3B, &[168]),
+    (0x11D3C, 0x11D3D, &[89]), (0x11D3E, 0x11D3E, &[168]), (0x11D3F, 0x11D47, &[89]),
+    (0x11D48, 0x11D4F, &[168]), (0x11D50, 0x11D59, &[89]), (0x11D5A, 0x11D5F, &[168]),
+    (0x11D60, 0x11D65, &[47]), (0x11D66, 0x11D66, &[168]), (0x11D67, 0x11D68, &[47]),
+    (0x11D69, 0x11D69, &[168]), (0x11D6A, 0x11D8E, &[47]), (0x11D8F, 0x11D8F, &[168]),
+    (0x11D90, 0x11D91, &[47]), (0x11D92, 0x11D92, &[168]), (0x11D93, 0x11D98, &[47]),
+    (0x11D99, 0x11D9F, &[168]), (0x11DA0, 0x11DA9, &[47]), (0x11DAA, 0x11DAF, &[168]),
+    (0x11DB0, 0x11DDB, &[164]), (0x11DDC, 0x11DDF, &[168]), (0x11DE0, 0x11DE9, &[164]),
+    (0x11DEA, 0x11EDF, &[168]), (0x11EE0, 0x11EF8, &[84]), (0x11EF9, 0x11EFF, &[168]),
+    (0x11F00, 0x11F10, &[66]), (0x11F11, 0x11F11, &[168]), (0x11F12, 0x11F3A, &[66]),
+    (0x11F3B, 0x11F3D, &[168]), (0x11F3E, 0x11F5A, &[66]), (0x11F5B, 0x11FAF, &[168]),
+    (0x11FB0, 0x11FB0, &[80]), (0x11FB1, 0x11FBF, &[168]), (0x11FC0, 0x11FCF, &[154]),
+    (0x11FD0, 0x11FD1, &[154, 44]), (0x11FD2, 0x11FD2, &[154]), (0x11FD3, 0x11FD3, &[154, 44]),
+    (0x11FD4, 0x11FF1, &[154]), (0x11FF2, 0x11FFE, &[168]), (0x11FFF, 0x11FFF, &[154]),
+    (0x12000, 0x12399, &[27]), (0x1239A, 0x123FF, &[168]), (0x12400, 0x1246E, &[27]),
+    (0x1246F, 0x1246F, &[168]), (0x12470, 0x12474, &[27]), (0x12475, 0x1247F, &[168]),
+    (0x12480, 0x12543, &[27]), (0x12544, 0x12F8F, &[168]), (0x12F90, 0x12FF2, &[29]),
+    (0x12FF3, 0x12FFF, &[168]), (0x13000, 0x13455, &[36]), (0x13456, 0x1345F, &[168]),
+    (0x13460, 0x143FA, &[36]), (0x143FB, 0x143FF, &[168]), (0x14400, 0x14646, &[2]),
+    (0x14647, 0x160FF, &[168]), (0x16100, 0x16139, &[49]), (0x1613A, 0x167FF, &[168]),
+    (0x16800, 0x16A38, &[7]), (0x16A39, 0x16A3F, &[168]), (0x16A40, 0x16A5E, &[98]),
+    (0x16A5F, 0x16A5F, &[168]), (0x16A60, 0x16A69, &[98]), (0x16A6A, 0x16A6D, &[168]),
+    (0x16A6E, 0x16A6F, &[98]), (0x16A70, 0x16ABE, &[155]), (0x16ABF, 0x16ABF, &[168]),
+    (0x16AC0, 0x16AC9, &[155]), (0x16ACA, 0x16ACF, &[168]), (0x16AD0, 0x16AED, &[8]),
+    (0x16AEE, 0x16AEF, &[168]), (0x16AF0, 0x16AF5, &[8]), (0x16AF6, 0x16AFF, &[168]),
+    (0x16B00, 0x16B45, &[124]), (0x16B46, 0x16B4F, &[168]), (0x16B50, 0x16B59, &[124]),
+    (0x16B5A, 0x16B5A, &[168]), (0x16B5B, 0x16B61, &[124]), (0x16B62, 0x16B62, &[168]),
+    (0x16B63, 0x16B77, &[124]), (0x16B78, 0x16B7C, &[168]), (0x16B7D, 0x16B8F, &[124]),
+    (0x16B90, 0x16D3F, &[168]), (0x16D40, 0x16D79, &[73]), (0x16D7A, 0x16E3F, &[168]),
+    (0x16E40, 0x16E9A, &[90]), (0x16E9B, 0x16E9F, &[168]), (0x16EA0, 0x16EB8, &[11]),
+    (0x16EB9, 0x16EBA, &[168]), (0x16EBB, 0x16ED3, &[11]), (0x16ED4, 0x16EFF, &[168]),
+    (0x16F00, 0x16F4A, &[95]), (0x16F4B, 0x16F4E, &[168]), (0x16F4F, 0x16F87, &[95]),
+    (0x16F88, 0x16F8E, &[168]), (0x16F8F, 0x16F9F, &[95]), (0x16FA0, 0x16FDF, &[168]),
+    (0x16FE0, 0x16FE0, &[156]), (0x16FE1, 0x16FE1, &[107]), (0x16FE2, 0x16FE3, &[50]),
+    (0x16FE4, 0x16FE4, &[69]), (0x16FE5, 0x16FEF, &[168]), (0x16FF0, 0x16FF6, &[50]),
+    (0x16FF7, 0x16FFF, &[168]), (0x17000, 0x18AFF, &[156]), (0x18B00, 0x18CD5, &[69]),
+    (0x18CD6, 0x18CFE, &[168]), (0x18CFF, 0x18CFF, &[69]), (0x18D00, 0x18D1E, &[156]),
+    (0x18D1F, 0x18D7F, &[168]), (0x18D80, 0x18DF2, &[156]), (0x18DF3, 0x1AFEF, &[168]),
+    (0x1AFF0, 0x1AFF3, &[64]), (0x1AFF4, 0x1AFF4, &[168]), (0x1AFF5, 0x1AFFB, &[64]),
+    (0x1AFFC, 0x1AFFC, &[168]), (0x1AFFD, 0x1AFFE, &[64]), (0x1AFFF, 0x1AFFF, &[168]),
+    (0x1B000, 0x1B000, &[64]), (0x1B001, 0x1B11F, &[56]), (0x1B120, 0x1B122, &[64]),
+    (0x1B123, 0x1B131, &[168]), (0x1B132, 0x1B132, &[56]), (0x1B133, 0x1B14F, &[168]),
+    (0x1B150, 0x1B152, &[56]), (0x1B153, 0x1B154, &[168]), (0x1B155, 0x1B155, &[64]),
+    (0x1B156, 0x1B163, &[168]), (0x1B164, 0x1B167, &[64]), (0x1B168, 0x1B16F, &[168]),
+    (0x1B170, 0x1B2FB, &[107]), (0x1B2FC, 0x1BBFF, &[168]), (0x1BC00, 0x1BC6A, &[35]),
+    (0x1BC6B, 0x1BC6F, &[168]), (0x1BC70, 0x1BC7C, &[35]), (0x1BC7D, 0x1BC7F, &[168]),
+    (0x1BC80, 0x1BC88, &[35]), (0x1BC89, 0x1BC8F, &[168]), (0x1BC90, 0x1BC99, &[35]),
+    (0x1BC9A, 0x1BC9B, &[168]), (0x1BC9C, 0x1BC9F, &[35]), (0x1BCA0, 0x1BCA3, &[35]),
+    (0x1BCA4, 0x1CBFF, &[168]), (0x1CC00, 0x1CCFC, &[25]), (0x1CCFD, 0x1CCFF, &[168]),
+    (0x1CD00, 0x1CEB3, &[25]), (0x1CEB4, 0x1CEB9, &[168]), (0x1CEBA, 0x1CED0, &[25]),
+    (0x1CED1, 0x1CEDF, &[168]), (0x1CEE0, 0x1CEF0, &[25]), (0x1CEF1, 0x1CEFF, &[168]),
+    (0x1CF00, 0x1CF2D, &[58]), (0x1CF2E, 0x1CF2F, &[168]), (0x1CF30, 0x1CF46, &[58]),
+    (0x1CF47, 0x1CF4F, &[168]), (0x1CF50, 0x1CFC3, &[25]), (0x1CFC4, 0x1CFFF, &[168]),
+    (0x1D000, 0x1D0F5, &[25]), (0x1D0F6, 0x1D0FF, &[168]), (0x1D100, 0x1D126, &[25]),
+    (0x1D127, 0x1D128, &[168]), (0x1D129, 0x1D166, &[25]), (0x1D167, 0x1D169, &[58]),
+    (0x1D16A, 0x1D17A, &[25]), (0x1D17B, 0x1D182, &[58]), (0x1D183, 0x1D184, &[25]),
+    (0x1D185, 0x1D18B, &[58]), (0x1D18C, 0x1D1A9, &[25]), (0x1D1AA, 0x1D1AD, &[58]),
+    (0x1D1AE, 0x1D1EA, &[25]), (0x1D1EB, 0x1D1FF, &[168]), (0x1D200, 0x1D245, &[45]),
+    (0x1D246, 0x1D2BF, &[168]), (0x1D2C0, 0x1D2D3, &[25]), (0x1D2D4, 0x1D2DF, &[168]),
+    (0x1D2E0, 0x1D2F3, &[25]), (0x1D2F4, 0x1D2FF, &[168]), (0x1D300, 0x1D356, &[25]),
+    (0x1D357, 0x1D35F, &[168]), (0x1D360, 0x1D371, &[50]), (0x1D372, 0x1D378, &[25]),
+    (0x1D379, 0x1D3FF, &[168]), (0x1D400, 0x1D454, &[25]), (0x1D455, 0x1D455, &[168]),
+    (0x1D456, 0x1D49C, &[25]), (0x1D49D, 0x1D49D, &[168]), (0x1D49E, 0x1D49F, &[25]),
+    (0x1D4A0, 0x1D4A1, &[168]), (0x1D4A2, 0x1D4A2, &[25]), (0x1D4A3, 0x1D4A4, &[168]),
+    (0x1D4A5, 0x1D4A6, &[25]), (0x1D4A7, 0x1D4A8, &[168]), (0x1D4A9, 0x1D4AC, &[25]),
+    (0x1D4AD, 0x1D4AD, &[168]), (0x1D4AE, 0x1D4B9, &[25]), (0x1D4BA, 0x1D4BA, &[168]),
+    (0x1D4BB, 0x1D4BB, &[25]), (0x1D4BC, 0x1D4BC, &[168]), (0x1D4BD, 0x1D4C3, &[25]),
+    (0x1D4C4, 0x1D4C4, &[168]), (0x1D4C5, 0x1D505, &[25]), (0x1D506, 0x1D506, &[168]),
+    (0x1D507, 0x1D50A, &[25]), (0x1D50B, 0x1D50C, &[168]), (0x1D50D, 0x1D514, &[25]),
+    (0x1D515, 0x1D515, &[168]), (0x1D516, 0x1D51C, &[25]), (0x1D51D, 0x1D51D, &[168]),
+    (0x1D51E, 0x1D539, &[25]), (0x1D53A, 0x1D53A, &[168]), (0x1D53B, 0x1D53E, &[25]),
+    (0x1D53F, 0x1D53F, &[168]), (0x1D540, 0x1D544, &[25]), (0x1D545, 0x1D545, &[168]),
+    (0x1D546, 0x1D546, &[25]), (0x1D547, 0x1D549, &[168]), (0x1D54A, 0x1D550, &[25]),
+    (0x1D551, 0x1D551, &[168]), (0x1D552, 0x1D6A5, &[25]), (0x1D6A6, 0x1D6A7, &[168]),
+    (0x1D6A8, 0x1D7CB, &[25]), (0x1D7CC, 0x1D7CD, &[168]), (0x1D7CE, 0x1D7FF, &[25]),
+    (0x1D800, 0x1DA8B, &[138]), (0x1DA8C, 0x1DA9A, &[168]), (0x1DA9B, 0x1DA9F, &[138]),
+    (0x1DAA0, 0x1DAA0, &[168]), (0x1DAA1, 0x1DAAF, &[138]), (0x1DAB0, 0x1DEFF, &[168]),
+    (0x1DF00, 0x1DF1E, &[75]), (0x1DF1F, 0x1DF24, &[168]), (0x1DF25, 0x1DF2A, &[75]),
+    (0x1DF2B, 0x1DFFF, &[168]), (0x1E000, 0x1E006, &[42]), (0x1E007, 0x1E007, &[168]),
+    (0x1E008, 0x1E018, &[42]), (0x1E019, 0x1E01A, &[168]), (0x1E01B, 0x1E021, &[42]),
+    (0x1E022, 0x1E022, &[168]), (0x1E023, 0x1E024, &[42]), (0x1E025, 0x1E025, &[168]),
+    (0x1E026, 0x1E02A, &[42]), (0x1E02B, 0x1E02F, &[168]), (0x1E030, 0x1E06D, &[30]),
+    (0x1E06E, 0x1E08E, &[168]), (0x1E08F, 0x1E08F, &[30]), (0x1E090, 0x1E0FF, &[168]),
+    (0x1E100, 0x1E12C, &[108]), (0x1E12D, 0x1E12F, &[168]), (0x1E130, 0x1E13D, &[108]),
+    (0x1E13E, 0x1E13F, &[168]), (0x1E140, 0x1E149, &[108]), (0x1E14A, 0x1E14D, &[168]),
+    (0x1E14E, 0x1E14F, &[108]), (0x1E150, 0x1E28F, &[168]), (0x1E290, 0x1E2AE, &[165]),
+    (0x1E2AF, 0x1E2BF, &[168]), (0x1E2C0, 0x1E2F9, &[171]), (0x1E2FA, 0x1E2FE, &[168]),
+    (0x1E2FF, 0x1E2FF, &[171]), (0x1E300, 0x1E4CF, &[168]), (0x1E4D0, 0x1E4F9, &[102]),
+    (0x1E4FA, 0x1E5CF, &[168]), (0x1E5D0, 0x1E5FA, &[111]), (0x1E5FB, 0x1E5FE, &[168]),
+    (0x1E5FF, 0x1E5FF, &[111]), (0x1E600, 0x1E6BF, &[168]), (0x1E6C0, 0x1E6DE, &[152]),
+    (0x1E6DF, 0x1E6DF, &[168]), (0x1E6E0, 0x1E6F5, &[152]), (0x1E6F6, 0x1E6FD, &[168]),
+    (0x1E6FE, 0x1E6FF, &[152]), (0x1E700, 0x1E7DF, &[168]), (0x1E7E0, 0x1E7E6, &[39]),
+    (0x1E7E7, 0x1E7E7, &[168]), (0x1E7E8, 0x1E7EB, &[39]), (0x1E7EC, 0x1E7EC, &[168]),
+    (0x1E7ED, 0x1E7EE, &[39]), (0x1E7EF, 0x1E7EF, &[168]), (0x1E7F0, 0x1E7FE, &[39]),
+    (0x1E7FF, 0x1E7FF, &[168]), (0x1E800, 0x1E8C4, &[92]), (0x1E8C5, 0x1E8C6, &[168]),
+    (0x1E8C7, 0x1E8D6, &[92]), (0x1E8D7, 0x1E8FF, &[168]), (0x1E900, 0x1E94B, &[0]),
+    (0x1E94C, 0x1E94F, &[168]), (0x1E950, 0x1E959, &[0]), (0x1E95A, 0x1E95D, &[168]),
+    (0x1E95E, 0x1E95F, &[0]), (0x1E960, 0x1EC70, &[168]), (0x1EC71, 0x1ECB4, &[25]),
+    (0x1ECB5, 0x1ED00, &[168]), (0x1ED01, 0x1ED3D, &[25]), (0x1ED3E, 0x1EDFF, &[168]),
+    (0x1EE00, 0x1EE03, &[3]), (0x1EE04, 0x1EE04, &[168]), (0x1EE05, 0x1EE1F, &[3]),
+    (0x1EE20, 0x1EE20, &[168]), (0x1EE21, 0x1EE22, &[3]), (0x1EE23, 0x1EE23, &[168]),
+    (0x1EE24, 0x1EE24, &[3]), (0x1EE25, 0x1EE26, &[168]), (0x1EE27, 0x1EE27, &[3]),
+    (0x1EE28, 0x1EE28, &[168]), (0x1EE29, 0x1EE32, &[3]), (0x1EE33, 0x1EE33, &[168]),
+    (0x1EE34, 0x1EE37, &[3]), (0x1EE38, 0x1EE38, &[168]), (0x1EE39, 0x1EE39, &[3]),
+    (0x1EE3A, 0x1EE3A, &[168]), (0x1EE3B, 0x1EE3B, &[3]), (0x1EE3C, 0x1EE41, &[168]),
+    (0x1EE42, 0x1EE42, &[3]), (0x1EE43, 0x1EE46, &[168]), (0x1EE47, 0x1EE47, &[3]),
+    (0x1EE48, 0x1EE48, &[168]), (0x1EE49, 0x1EE49, &[3]), (0x1EE4A, 0x1EE4A, &[168]),
+    (0x1EE4B, 0x1EE4B, &[3]), (0x1EE4C, 0x1EE4C, &[168]), (0x1EE4D, 0x1EE4F, &[3]),
+    (0x1EE50, 0x1EE50, &[168]), (0x1EE51, 0x1EE52, &[3]), (0x1EE53, 0x1EE53, &[168]),
+    (0x1EE54, 0x1EE54, &[3]), (0x1EE55, 0x1EE56, &[168]), (0x1EE57, 0x1EE57, &[3]),
+    (0x1EE58, 0x1EE58, &[168]), (0x1EE59, 0x1EE59, &[3]), (0x1EE5A, 0x1EE5A, &[168]),
+    (0x1EE5B, 0x1EE5B, &[3]), (0x1EE5C, 0x1EE5C, &[168]), (0x1EE5D, 0x1EE5D, &[3]),
+    (0x1EE5E, 0x1EE5E, &[168]), (0x1EE5F, 0x1EE5F, &[3]), (0x1EE60, 0x1EE60, &[168]),
+    (0x1EE61, 0x1EE62, &[3]), (0x1EE63, 0x1EE63, &[168]), (0x1EE64, 0x1EE64, &[3]),
+    (0x1EE65, 0x1EE66, &[168]), (0x1EE67, 0x1EE6A, &[3]), (0x1EE6B, 0x1EE6B, &[168]),
+    (0x1EE6C, 0x1EE72, &[3]), (0x1EE73, 0x1EE73, &[168]), (0x1EE74, 0x1EE77, &[3]),
+    (0x1EE78, 0x1EE78, &[168]), (0x1EE79, 0x1EE7C, &[3]), (0x1EE7D, 0x1EE7D, &[168]),
+    (0x1EE7E, 0x1EE7E, &[3]), (0x1EE7F, 0x1EE7F, &[168]), (0x1EE80, 0x1EE89, &[3]),
+    (0x1EE8A, 0x1EE8A, &[168]), (0x1EE8B, 0x1EE9B, &[3]), (0x1EE9C, 0x1EEA0, &[168]),
+    (0x1EEA1, 0x1EEA3, &[3]), (0x1EEA4, 0x1EEA4, &[168]), (0x1EEA5, 0x1EEA9, &[3]),
+    (0x1EEAA, 0x1EEAA, &[168]), (0x1EEAB, 0x1EEBB, &[3]), (0x1EEBC, 0x1EEEF, &[168]),
+    (0x1EEF0, 0x1EEF1, &[3]), (0x1EEF2, 0x1EFFF, &[168]), (0x1F000, 0x1F02B, &[25]),
+    (0x1F02C, 0x1F02F, &[168]), (0x1F030, 0x1F093, &[25]), (0x1F094, 0x1F09F, &[168]),
+    (0x1F0A0, 0x1F0AE, &[25]), (0x1F0AF, 0x1F0B0, &[168]), (0x1F0B1, 0x1F0BF, &[25]),
+    (0x1F0C0, 0x1F0C0, &[168]), (0x1F0C1, 0x1F0CF, &[25]), (0x1F0D0, 0x1F0D0, &[168]),
+    (0x1F0D1, 0x1F0F5, &[25]), (0x1F0F6, 0x1F0FF, &[168]), (0x1F100, 0x1F1AD, &[25]),
+    (0x1F1AE, 0x1F1E5, &[168]), (0x1F1E6, 0x1F1FF, &[25]), (0x1F200, 0x1F200, &[56]),
+    (0x1F201, 0x1F202, &[25]), (0x1F203, 0x1F20F, &[168]), (0x1F210, 0x1F23B, &[25]),
+    (0x1F23C, 0x1F23F, &[168]), (0x1F240, 0x1F248, &[25]), (0x1F249, 0x1F24F, &[168]),
+    (0x1F250, 0x1F251, &[50]), (0x1F252, 0x1F25F, &[168]), (0x1F260, 0x1F265, &[25]),
+    (0x1F266, 0x1F2FF, &[168]), (0x1F300, 0x1F6D8, &[25]), (0x1F6D9, 0x1F6DB, &[168]),
+    (0x1F6DC, 0x1F6EC, &[25]), (0x1F6ED, 0x1F6EF, &[168]), (0x1F6F0, 0x1F6FC, &[25]),
+    (0x1F6FD, 0x1F6FF, &[168]), (0x1F700, 0x1F7D9, &[25]), (0x1F7DA, 0x1F7DF, &[168]),
+    (0x1F7E0, 0x1F7EB, &[25]), (0x1F7EC, 0x1F7EF, &[168]), (0x1F7F0, 0x1F7F0, &[25]),
+    (0x1F7F1, 0x1F7FF, &[168]), (0x1F800, 0x1F80B, &[25]), (0x1F80C, 0x1F80F, &[168]),
+    (0x1F810, 0x1F847, &[25]), (0x1F848, 0x1F84F, &[168]), (0x1F850, 0x1F859, &[25]),
+    (0x1F85A, 0x1F85F, &[168]), (0x1F860, 0x1F887, &[25]), (0x1F888, 0x1F88F, &[168]),
+    (0x1F890, 0x1F8AD, &[25]), (0x1F8AE, 0x1F8AF, &[168]), (0x1F8B0, 0x1F8BB, &[25]),
+    (0x1F8BC, 0x1F8BF, &[168]), (0x1F8C0, 0x1F8C1, &[25]), (0x1F8C2, 0x1F8CF, &[168]),
+    (0x1F8D0, 0x1F8D8, &[25]), (0x1F8D9, 0x1F8FF, &[168]), (0x1F900, 0x1FA57, &[25]),
+    (0x1FA58, 0x1FA5F, &[168]), (0x1FA60, 0x1FA6D, &[25]), (0x1FA6E, 0x1FA6F, &[168]),
+    (0x1FA70, 0x1FA7C, &[25]), (0x1FA7D, 0x1FA7F, &[168]), (0x1FA80, 0x1FA8A, &[25]),
+    (0x1FA8B, 0x1FA8D, &[168]), (0x1FA8E, 0x1FAC6, &[25]), (0x1FAC7, 0x1FAC7, &[168]),
+    (0x1FAC8, 0x1FAC8, &[25]), (0x1FAC9, 0x1FACC, &[168]), (0x1FACD, 0x1FADC, &[25]),
+    (0x1FADD, 0x1FADE, &[168]), (0x1FADF, 0x1FAEA, &[25]), (0x1FAEB, 0x1FAEE, &[168]),
+    (0x1FAEF, 0x1FAF8, &[25]), (0x1FAF9, 0x1FAFF, &[168]), (0x1FB00, 0x1FB92, &[25]),
+    (0x1FB93, 0x1FB93, &[168]), (0x1FB94, 0x1FBFA, &[25]), (0x1FBFB, 0x1FFFF, &[168]),
+    (0x20000, 0x2A6DF, &[50]), (0x2A6E0, 0x2A6FF, &[168]), (0x2A700, 0x2B81D, &[50]),
+    (0x2B81E, 0x2B81F, &[168]), (0x2B820, 0x2CEAD, &[50]), (0x2CEAE, 0x2CEAF, &[168]),
+    (0x2CEB0, 0x2EBE0, &[50]), (0x2EBE1, 0x2EBEF, &[168]), (0x2EBF0, 0x2EE5D, &[50]),
+    (0x2EE5E, 0x2F7FF, &[168]), (0x2F800, 0x2FA1D, &[50]), (0x2FA1E, 0x2FFFF, &[168]),
+    (0x30000, 0x3134A, &[50]), (0x3134B, 0x3134F, &[168]), (0x31350, 0x33479, &[50]),
+    (0x3347A, 0xE0000, &[168]), (0xE0001, 0xE0001, &[25]), (0xE0002, 0xE001F, &[168]),
+    (0xE0020, 0xE007F, &[25]), (0xE0080, 0xE00FF, &[168]), (0xE0100, 0xE01EF, &[58]),
+    (0xE01F0, 0x10FFFF, &[168]),
+];
+
+/// The Simple_Case_Folding of every code point that does not fold to itself, as `(code point, folded)`.
+pub(super) static SIMPLE_CASE_FOLDING: &[(u32, u32)] = &[
+    (0x41, 0x61), (0x42, 0x62), (0x43, 0x63), (0x44, 0x64), (0x45, 0x65), (0x46, 0x66),
+    (0x47, 0x67), (0x48, 0x68), (0x49, 0x69), (0x4A, 0x6A), (0x4B, 0x6B), (0x4C, 0x6C),
+    (0x4D, 0x6D), (0x4E, 0x6E), (0x4F, 0x6F), (0x50, 0x70), (0x51, 0x71), (0x52, 0x72),
+    (0x53, 0x73), (0x54, 0x74), (0x55, 0x75), (0x56, 0x76), (0x57, 0x77), (0x58, 0x78),
+    (0x59, 0x79), (0x5A, 0x7A), (0xB5, 0x3BC), (0xC0, 0xE0), (0xC1, 0xE1), (0xC2, 0xE2),
+    (0xC3, 0xE3), (0xC4, 0xE4), (0xC5, 0xE5), (0xC6, 0xE6), (0xC7, 0xE7), (0xC8, 0xE8),
+    (0xC9, 0xE9), (0xCA, 0xEA), (0xCB, 0xEB), (0xCC, 0xEC), (0xCD, 0xED), (0xCE, 0xEE),
+    (0xCF, 0xEF), (0xD0, 0xF0), (0xD1, 0xF1), (0xD2, 0xF2), (0xD3, 0xF3), (0xD4, 0xF4),
+    (0xD5, 0xF5), (0xD6, 0xF6), (0xD8, 0xF8), (0xD9, 0xF9), (0xDA, 0xFA), (0xDB, 0xFB),
+    (0xDC, 0xFC), (0xDD, 0xFD), (0xDE, 0xFE), (0x100, 0x101), (0x102, 0x103), (0x104, 0x105),
+    (0x106, 0x107), (0x108, 0x109), (0x10A, 0x10B), (0x10C, 0x10D), (0x10E, 0x10F), (0x110, 0x111),
+    (0x112, 0x113), (0x114, 0x115), (0x116, 0x117), (0x118, 0x119), (0x11A, 0x11B), (0x11C, 0x11D),
+    (0x11E, 0x11F), (0x120, 0x121), (0x122, 0x123), (0x124, 0x125), (0x126, 0x127), (0x128, 0x129),
+    (0x12A, 0x12B), (0x12C, 0x12D), (0x12E, 0x12F), (0x132, 0x133), (0x134, 0x135), (0x136, 0x137),
+    (0x139, 0x13A), (0x13B, 0x13C), (0x13D, 0x13E), (0x13F, 0x140), (0x141, 0x142), (0x143, 0x144),
+    (0x145, 0x146), (0x147, 0x148), (0x14A, 0x14B), (0x14C, 0x14D), (0x14E, 0x14F), (0x150, 0x151),
+    (0x152, 0x153), (0x154, 0x155), (0x156, 0x157), (0x158, 0x159), (0x15A, 0x15B), (0x15C, 0x15D),
+    (0x15E, 0x15F), (0x160, 0x161), (0x162, 0x163), (0x164, 0x165), (0x166, 0x167), (0x168, 0x169),
+    (0x16A, 0x16B), (0x16C, 0x16D), (0x16E, 0x16F), (0x170, 0x171), (0x172, 0x173), (0x174, 0x175),
+    (0x176, 0x177), (0x178, 0xFF), (0x179, 0x17A), (0x17B, 0x17C), (0x17D, 0x17E), (0x17F, 0x73),
+    (0x181, 0x253), (0x182, 0x183), (0x184, 0x185), (0x186, 0x254), (0x187, 0x188), (0x189, 0x256),
+    (0x18A, 0x257), (0x18B, 0x18C), (0x18E, 0x1DD), (0x18F, 0x259), (0x190, 0x25B), (0x191, 0x192),
+    (0x193, 0x260), (0x194, 0x263), (0x196, 0x269), (0x197, 0x268), (0x198, 0x199), (0x19C, 0x26F),
+    (0x19D, 0x272), (0x19F, 0x275), (0x1A0, 0x1A1), (0x1A2, 0x1A3), (0x1A4, 0x1A5), (0x1A6, 0x280),
+    (0x1A7, 0x1A8), (0x1A9, 0x283), (0x1AC, 0x1AD), (0x1AE, 0x288), (0x1AF, 0x1B0), (0x1B1, 0x28A),
+    (0x1B2, 0x28B), (0x1B3, 0x1B4), (0x1B5, 0x1B6), (0x1B7, 0x292), (0x1B8, 0x1B9), (0x1BC, 0x1BD),
+    (0x1C4, 0x1C6), (0x1C5, 0x1C6), (0x1C7, 0x1C9), (0x1C8, 0x1C9), (0x1CA, 0x1CC), (0x1CB, 0x1CC),
+    (0x1CD, 0x1CE), (0x1CF, 0x1D0), (0x1D1, 0x1D2), (0x1D3, 0x1D4), (0x1D5, 0x1D6), (0x1D7, 0x1D8),
+    (0x1D9, 0x1DA), (0x1DB, 0x1DC), (0x1DE, 0x1DF), (0x1E0, 0x1E1), (0x1E2, 0x1E3), (0x1E4, 0x1E5),
+    (0x1E6, 0x1E7), (0x1E8, 0x1E9), (0x1EA, 0x1EB), (0x1EC, 0x1ED), (0x1EE, 0x1EF), (0x1F1, 0x1F3),
+    (0x1F2, 0x1F3), (0x1F4, 0x1F5), (0x1F6, 0x195), (0x1F7, 0x1BF), (0x1F8, 0x1F9), (0x1FA, 0x1FB),
+    (0x1FC, 0x1FD), (0x1FE, 0x1FF), (0x200, 0x201), (0x202, 0x203), (0x204, 0x205), (0x206, 0x207),
+    (0x208, 0x209), (0x20A, 0x20B), (0x20C, 0x20D), (0x20E, 0x20F), (0x210, 0x211), (0x212, 0x213),
+    (0x214, 0x215), (0x216, 0x217), (0x218, 0x219), (0x21A, 0x21B), (0x21C, 0x21D), (0x21E, 0x21F),
+    (0x220, 0x19E), (0x222, 0x223), (0x224, 0x225), (0x226, 0x227), (0x228, 0x229), (0x22A, 0x22B),
+    (0x22C, 0x22D), (0x22E, 0x22F), (0x230, 0x231), (0x232, 0x233), (0x23A, 0x2C65), (0x23B, 0x23C),
+    (0x23D, 0x19A), (0x23E, 0x2C66), (0x241, 0x242), (0x243, 0x180), (0x244, 0x289), (0x245, 0x28C),
+    (0x246, 0x247), (0x248, 0x249), (0x24A, 0x24B), (0x24C, 0x24D), (0x24E, 0x24F), (0x345, 0x3B9),
+    (0x370, 0x371), (0x372, 0x373), (0x376, 0x377), (0x37F, 0x3F3), (0x386, 0x3AC), (0x388, 0x3AD),
+    (0x389, 0x3AE), (0x38A, 0x3AF), (0x38C, 0x3CC), (0x38E, 0x3CD), (0x38F, 0x3CE), (0x391, 0x3B1),
+    (0x392, 0x3B2), (0x393, 0x3B3), (0x394, 0x3B4), (0x395, 0x3B5), (0x396, 0x3B6), (0x397, 0x3B7),
+    (0x398, 0x3B8), (0x399, 0x3B9), (0x39A, 0x3BA), (0x39B, 0x3BB), (0x39C, 0x3BC), (0x39D, 0x3BD),
+    (0x39E, 0x3BE), (0x39F, 0x3BF), (0x3A0, 0x3C0), (0x3A1, 0x3C1), (0x3A3, 0x3C3), (0x3A4, 0x3C4),
+    (0x3A5, 0x3C5), (0x3A6, 0x3C6), (0x3A7, 0x3C7), (0x3A8, 0x3C8), (0x3A9, 0x3C9), (0x3AA, 0x3CA),
+    (0x3AB, 0x3CB), (0x3C2, 0x3C3), (0x3CF, 0x3D7), (0x3D0, 0x3B2), (0x3D1, 0x3B8), (0x3D5, 0x3C6),
+    (0x3D6, 0x3C0), (0x3D8, 0x3D9), (0x3DA, 0x3DB), (0x3DC, 0x3DD), (0x3DE, 0x3DF), (0x3E0, 0x3E1),
+    (0x3E2, 0x3E3), (0x3E4, 0x3E5), (0x3E6, 0x3E7), (0x3E8, 0x3E9), (0x3EA, 0x3EB), (0x3EC, 0x3ED),
+    (0x3EE, 0x3EF), (0x3F0, 0x3BA), (0x3F1, 0x3C1), (0x3F4, 0x3B8), (0x3F5, 0x3B5), (0x3F7, 0x3F8),
+    (0x3F9, 0x3F2), (0x3FA, 0x3FB), (0x3FD, 0x37B), (0x3FE, 0x37C), (0x3FF, 0x37D), (0x400, 0x450),
+    (0x401, 0x451), (0x402, 0x452), (0x403, 0x453), (0x404, 0x454), (0x405, 0x455), (0x406, 0x456),
+    (0x407, 0x457), (0x408, 0x458), (0x409, 0x459), (0x40A, 0x45A), (0x40B, 0x45B), (0x40C, 0x45C),
+    (0x40D, 0x45D), (0x40E, 0x45E), (0x40F, 0x45F), (0x410, 0x430), (0x411, 0x431), (0x412, 0x432),
+    (0x413, 0x433), (0x414, 0x434), (0x415, 0x435), (0x416, 0x436), (0x417, 0x437), (0x418, 0x438),
+    (0x419, 0x439), (0x41A, 0x43A), (0x41B, 0x43B), (0x41C, 0x43C), (0x41D, 0x43D), (0x41E, 0x43E),
+    (0x41F, 0x43F), (0x420, 0x440), (0x421, 0x441), (0x422, 0x442), (0x423, 0x443), (0x424, 0x444),
+    (0x425, 0x445), (0x426, 0x446), (0x427, 0x447), (0x428, 0x448), (0x429, 0x449), (0x42A, 0x44A),
+    (0x42B, 0x44B), (0x42C, 0x44C), (0x42D, 0x44D), (0x42E, 0x44E), (0x42F, 0x44F), (0x460, 0x461),
+    (0x462, 0x463), (0x464, 0x465), (0x466, 0x467), (0x468, 0x469), (0x46A, 0x46B), (0x46C, 0x46D),
+    (0x46E, 0x46F), (0x470, 0x471), (0x472, 0x473), (0x474, 0x475), (0x476, 0x477), (0x478, 0x479),
+    (0x47A, 0x47B), (0x47C, 0x47D), (0x47E, 0x47F), (0x480, 0x481), (0x48A, 0x48B), (0x48C, 0x48D),
+    (0x48E, 0x48F), (0x490, 0x491), (0x492, 0x493), (0x494, 0x495), (0x496, 0x497), (0x498, 0x499),
+    (0x49A, 0x49B), (0x49C, 0x49D), (0x49E, 0x49F), (0x4A0, 0x4A1), (0x4A2, 0x4A3), (0x4A4, 0x4A5),
+    (0x4A6, 0x4A7), (0x4A8, 0x4A9), (0x4AA, 0x4AB), (0x4AC, 0x4AD), (0x4AE, 0x4AF), (0x4B0, 0x4B1),
+    (0x4B2, 0x4B3), (0x4B4, 0x4B5), (0x4B6, 0x4B7), (0x4B8, 0x4B9), (0x4BA, 0x4BB), (0x4BC, 0x4BD),
+    (0x4BE, 0x4BF), (0x4C0, 0x4CF), (0x4C1, 0x4C2), (0x4C3, 0x4C4), (0x4C5, 0x4C6), (0x4C7, 0x4C8),
+    (0x4C9, 0x4CA), (0x4CB, 0x4CC), (0x4CD, 0x4CE), (0x4D0, 0x4D1), (0x4D2, 0x4D3), (0x4D4, 0x4D5),
+    (0x4D6, 0x4D7), (0x4D8, 0x4D9), (0x4DA, 0x4DB), (0x4DC, 0x4DD), (0x4DE, 0x4DF), (0x4E0, 0x4E1),
+    (0x4E2, 0x4E3), (0x4E4, 0x4E5), (0x4E6, 0x4E7), (0x4E8, 0x4E9), (0x4EA, 0x4EB), (0x4EC, 0x4ED),
+    (0x4EE, 0x4EF), (0x4F0, 0x4F1), (0x4F2, 0x4F3), (0x4F4, 0x4F5), (0x4F6, 0x4F7), (0x4F8, 0x4F9),
+    (0x4FA, 0x4FB), (0x4FC, 0x4FD), (0x4FE, 0x4FF), (0x500, 0x501), (0x502, 0x503), (0x504, 0x505),
+    (0x506, 0x507), (0x508, 0x509), (0x50A, 0x50B), (0x50C, 0x50D), (0x50E, 0x50F), (0x510, 0x511),
+    (0x512, 0x513), (0x514, 0x515), (0x516, 0x517), (0x518, 0x519), (0x51A, 0x51B), (0x51C, 0x51D),
+    (0x51E, 0x51F), (0x520, 0x521), (0x522, 0x523), (0x524, 0x525), (0x526, 0x527), (0x528, 0x529),
+    (0x52A, 0x52B), (0x52C, 0x52D), (0x52E, 0x52F), (0x531, 0x561), (0x532, 0x562), (0x533, 0x563),
+    (0x534, 0x564), (0x535, 0x565), (0x536, 0x566), (0x537, 0x567), (0x538, 0x568), (0x539, 0x569),
+    (0x53A, 0x56A), (0x53B, 0x56B), (0x53C, 0x56C), (0x53D, 0x56D), (0x53E, 0x56E), (0x53F, 0x56F),
+    (0x540, 0x570), (0x541, 0x571), (0x542, 0x572), (0x543, 0x573), (0x544, 0x574), (0x545, 0x575),
+    (0x546, 0x576), (0x547, 0x577), (0x548, 0x578), (0x549, 0x579), (0x54A, 0x57A), (0x54B, 0x57B),
+    (0x54C, 0x57C), (0x54D, 0x57D), (0x54E, 0x57E), (0x54F, 0x57F), (0x550, 0x580), (0x551, 0x581),
+    (0x552, 0x582), (0x553, 0x583), (0x554, 0x584), (0x555, 0x585), (0x556, 0x586), (0x10A0, 0x2D00),
+    (0x10A1, 0x2D01), (0x10A2, 0x2D02), (0x10A3, 0x2D03), (0x10A4, 0x2D04), (0x10A5, 0x2D05), (0x10A6, 0x2D06),
+    (0x10A7, 0x2D07), (0x10A8, 0x2D08), (0x10A9, 0x2D09), (0x10AA, 0x2D0A), (0x10AB, 0x2D0B), (0x10AC, 0x2D0C),
+    (0x10AD, 0x2D0D), (0x10AE, 0x2D0E), (0x10AF, 0x2D0F), (0x10B0, 0x2D10), (0x10B1, 0x2D11), (0x10B2, 0x2D12),
+    (0x10B3, 0x2D13), (0x10B4, 0x2D14), (0x10B5, 0x2D15), (0x10B6, 0x2D16), (0x10B7, 0x2D17), (0x10B8, 0x2D18),
+    (0x10B9, 0x2D19), (0x10BA, 0x2D1A), (0x10BB, 0x2D1B), (0x10BC, 0x2D1C), (0x10BD, 0x2D1D), (0x10BE, 0x2D1E),
+    (0x10BF, 0x2D1F), (0x10C0, 0x2D20), (0x10C1, 0x2D21), (0x10C2, 0x2D22), (0x10C3, 0x2D23), (0x10C4, 0x2D24),
+    (0x10C5, 0x2D25), (0x10C7, 0x2D27), (0x10CD, 0x2D2D), (0x13F8, 0x13F0), (0x13F9, 0x13F1), (0x13FA, 0x13F2),
+    (0x13FB, 0x13F3), (0x13FC, 0x13F4), (0x13FD, 0x13F5), (0x1C80, 0x432), (0x1C81, 0x434), (0x1C82, 0x43E),
+    (0x1C83, 0x441), (0x1C84, 0x442), (0x1C85, 0x442), (0x1C86, 0x44A), (0x1C87, 0x463), (0x1C88, 0xA64B),
+    (0x1C89, 0x1C8A), (0x1C90, 0x10D0), (0x1C91, 0x10D1), (0x1C92, 0x10D2), (0x1C93, 0x10D3), (0x1C94, 0x10D4),
+    (0x1C95, 0x10D5), (0x1C96, 0x10D6), (0x1C97, 0x10D7), (0x1C98, 0x10D8), (0x1C99, 0x10D9), (0x1C9A, 0x10DA),
+    (0x1C9B, 0x10DB), (0x1C9C, 0x10DC), (0x1C9D, 0x10DD), (0x1C9E, 0x10DE), (0x1C9F, 0x10DF), (0x1CA0, 0x10E0),
+    (0x1CA1, 0x10E1), (0x1CA2, 0x10E2), (0x1CA3, 0x10E3), (0x1CA4, 0x10E4), (0x1CA5, 0x10E5), (0x1CA6, 0x10E6),
+    (0x1CA7, 0x10E7), (0x1CA8, 0x10E8), (0x1CA9, 0x10E9), (0x1CAA, 0x10EA), (0x1CAB, 0x10EB), (0x1CAC, 0x10EC),
+    (0x1CAD, 0x10ED), (0x1CAE, 0x10EE), (0x1CAF, 0x10EF), (0x1CB0, 0x10F0), (0x1CB1, 0x10F1), (0x1CB2, 0x10F2),
+    (0x1CB3, 0x10F3), (0x1CB4, 0x10F4), (0x1CB5, 0x10F5), (0x1CB6, 0x10F6), (0x1CB7, 0x10F7), (0x1CB8, 0x10F8),
+    (0x1CB9, 0x10F9), (0x1CBA, 0x10FA), (0x1CBD, 0x10FD), (0x1CBE, 0x10FE), (0x1CBF, 0x10FF), (0x1E00, 0x1E01),
+    (0x1E02, 0x1E03), (0x1E04, 0x1E05), (0x1E06, 0x1E07), (0x1E08, 0x1E09), (0x1E0A, 0x1E0B), (0x1E0C, 0x1E0D),
+    (0x1E0E, 0x1E0F), (0x1E10, 0x1E11), (0x1E12, 0x1E13), (0x1E14, 0x1E15), (0x1E16, 0x1E17), (0x1E18, 0x1E19),
+    (0x1E1A, 0x1E1B), (0x1E1C, 0x1E1D), (0x1E1E, 0x1E1F), (0x1E20, 0x1E21), (0x1E22, 0x1E23), (0x1E24, 0x1E25),
+    (0x1E26, 0x1E27), (0x1E28, 0x1E29), (0x1E2A, 0x1E2B), (0x1E2C, 0x1E2D), (0x1E2E, 0x1E2F), (0x1E30, 0x1E31),
+    (0x1E32, 0x1E33), (0x1E34, 0x1E35), (0x1E36, 0x1E37), (0x1E38, 0x1E39), (0x1E3A, 0x1E3B), (0x1E3C, 0x1E3D),
+    (0x1E3E, 0x1E3F), (0x1E40, 0x1E41), (0x1E42, 0x1E43), (0x1E44, 0x1E45), (0x1E46, 0x1E47), (0x1E48, 0x1E49),
+    (0x1E4A, 0x1E4B), (0x1E4C, 0x1E4D), (0x1E4E, 0x1E4F), (0x1E50, 0x1E51), (0x1E52, 0x1E53), (0x1E54, 0x1E55),
+    (0x1E56, 0x1E57), (0x1E58, 0x1E59), (0x1E5A, 0x1E5B), (0x1E5C, 0x1E5D), (0x1E5E, 0x1E5F), (0x1E60, 0x1E61),
+    (0x1E62, 0x1E63), (0x1E64, 0x1E65), (0x1E66, 0x1E67), (0x1E68, 0x1E69), (0x1E6A, 0x1E6B), (0x1E6C, 0x1E6D),
+    (0x1E6E, 0x1E6F), (0x1E70, 0x1E71), (0x1E72, 0x1E73), (0x1E74, 0x1E75), (0x1E76, 0x1E77), (0x1E78, 0x1E79),
+    (0x1E7A, 0x1E7B), (0x1E7C, 0x1E7D), (0x1E7E, 0x1E7F), (0x1E80, 0x1E81), (0x1E82, 0x1E83), (0x1E84, 0x1E85),
+    (0x1E86, 0x1E87), (0x1E88, 0x1E89), (0x1E8A, 0x1E8B), (0x1E8C, 0x1E8D), (0x1E8E, 0x1E8F), (0x1E90, 0x1E91),
+    (0x1E92, 0x1E93), (0x1E94, 0x1E95), (0x1E9B, 0x1E61), (0x1E9E, 0xDF), (0x1EA0, 0x1EA1), (0x1EA2, 0x1EA3),
+    (0x1EA4, 0x1EA5), (0x1EA6, 0x1EA7), (0x1EA8, 0x1EA9), (0x1EAA, 0x1EAB), (0x1EAC, 0x1EAD), (0x1EAE, 0x1EAF),
+    (0x1EB0, 0x1EB1), (0x1EB2, 0x1EB3), (0x1EB4, 0x1EB5), (0x1EB6, 0x1EB7), (0x1EB8, 0x1EB9), (0x1EBA, 0x1EBB),
+    (0x1EBC, 0x1EBD), (0x1EBE, 0x1EBF), (0x1EC0, 0x1EC1), (0x1EC2, 0x1EC3), (0x1EC4, 0x1EC5), (0x1EC6, 0x1EC7),
+    (0x1EC8, 0x1EC9), (0x1ECA, 0x1ECB), (0x1ECC, 0x1ECD), (0x1ECE, 0x1ECF), (0x1ED0, 0x1ED1), (0x1ED2, 0x1ED3),
+    (0x1ED4, 0x1ED5), (0x1ED6, 0x1ED7), (0x1ED8, 0x1ED9), (0x1EDA, 0x1EDB), (0x1EDC, 0x1EDD), (0x1EDE, 0x1EDF),
+    (0x1EE0, 0x1EE1), (0x1EE2, 0x1EE3), (0x1EE4, 0x1EE5), (0x1EE6, 0x1EE7), (0x1EE8, 0x1EE9), (0x1EEA, 0x1EEB),
+    (0x1EEC, 0x1EED), (0x1EEE, 0x1EEF), (0x1EF0, 0x1EF1), (0x1EF2, 0x1EF3), (0x1EF4, 0x1EF5), (0x1EF6, 0x1EF7),
+    (0x1EF8, 0x1EF9), (0x1EFA, 0x1EFB), (0x1EFC, 0x1EFD), (0x1EFE, 0x1EFF), (0x1F08, 0x1F00), (0x1F09, 0x1F01),
+    (0x1F0A, 0x1F02), (0x1F0B, 0x1F03), (0x1F0C, 0x1F04), (0x1F0D, 0x1F05), (0x1F0E, 0x1F06), (0x1F0F, 0x1F07),
+    (0x1F18, 0x1F10), (0x1F19, 0x1F11), (0x1F1A, 0x1F12), (0x1F1B, 0x1F13), (0x1F1C, 0x1F14), (0x1F1D, 0x1F15),
+    (0x1F28, 0x1F20), (0x1F29, 0x1F21), (0x1F2A, 0x1F22), (0x1F2B, 0x1F23), (0x1F2C, 0x1F24), (0x1F2D, 0x1F25),
+    (0x1F2E, 0x1F26), (0x1F2F, 0x1F27), (0x1F38, 0x1F30), (0x1F39, 0x1F31), (0x1F3A, 0x1F32), (0x1F3B, 0x1F33),
+    (0x1F3C, 0x1F34), (0x1F3D, 0x1F35), (0x1F3E, 0x1F36), (0x1F3F, 0x1F37), (0x1F48, 0x1F40), (0x1F49, 0x1F41),
+    (0x1F4A, 0x1F42), (0x1F4B, 0x1F43), (0x1F4C, 0x1F44), (0x1F4D, 0x1F45), (0x1F59, 0x1F51), (0x1F5B, 0x1F53),
+    (0x1F5D, 0x1F55), (0x1F5F, 0x1F57), (0x1F68, 0x1F60), (0x1F69, 0x1F61), (0x1F6A, 0x1F62), (0x1F6B, 0x1F63),
+    (0x1F6C, 0x1F64), (0x1F6D, 0x1F65), (0x1F6E, 0x1F66), (0x1F6F, 0x1F67), (0x1F88, 0x1F80), (0x1F89, 0x1F81),
+    (0x1F8A, 0x1F82), (0x1F8B, 0x1F83), (0x1F8C, 0x1F84), (0x1F8D, 0x1F85), (0x1F8E, 0x1F86), (0x1F8F, 0x1F87),
+    (0x1F98, 0x1F90), (0x1F99, 0x1F91), (0x1F9A, 0x1F92), (0x1F9B, 0x1F93), (0x1F9C, 0x1F94), (0x1F9D, 0x1F95),
+    (0x1F9E, 0x1F96), (0x1F9F, 0x1F97), (0x1FA8, 0x1FA0), (0x1FA9, 0x1FA1), (0x1FAA, 0x1FA2), (0x1FAB, 0x1FA3),
+    (0x1FAC, 0x1FA4), (0x1FAD, 0x1FA5), (0x1FAE, 0x1FA6), (0x1FAF, 0x1FA7), (0x1FB8, 0x1FB0), (0x1FB9, 0x1FB1),
+    (0x1FBA, 0x1F70), (0x1FBB, 0x1F71), (0x1FBC, 0x1FB3), (0x1FBE, 0x3B9), (0x1FC8, 0x1F72), (0x1FC9, 0x1F73),
+    (0x1FCA, 0x1F74), (0x1FCB, 0x1F75), (0x1FCC, 0x1FC3), (0x1FD3, 0x390), (0x1FD8, 0x1FD0), (0x1FD9, 0x1FD1),
+    (0x1FDA, 0x1F76), (0x1FDB, 0x1F77), (0x1FE3, 0x3B0), (0x1FE8, 0x1FE0), (0x1FE9, 0x1FE1), (0x1FEA, 0x1F7A),
+    (0x1FEB, 0x1F7B), (0x1FEC, 0x1FE5), (0x1FF8, 0x1F78), (0x1FF9, 0x1F79), (0x1FFA, 0x1F7C), (0x1FFB, 0x1F7D),
+    (0x1FFC, 0x1FF3), (0x2126, 0x3C9), (0x212A, 0x6B), (0x212B, 0xE5), (0x2132, 0x214E), (0x2160, 0x2170),
+    (0x2161, 0x2171), (0x2162, 0x2172), (0x2163, 0x2173), (0x2164, 0x2174), (0x2165, 0x2175), (0x2166, 0x2176),
+    (0x2167, 0x2177), (0x2168, 0x2178), (0x2169, 0x2179), (0x216A, 0x217A), (0x216B, 0x217B), (0x216C, 0x217C),
+    (0x216D, 0x217D), (0x216E, 0x217E), (0x216F, 0x217F), (0x2183, 0x2184), (0x24B6, 0x24D0), (0x24B7, 0x24D1),
+    (0x24B8, 0x24D2), (0x24B9, 0x24D3), (0x24BA, 0x24D4), (0x24BB, 0x24D5), (0x24BC, 0x24D6), (0x24BD, 0x24D7),
+    (0x24BE, 0x24D8), (0x24BF, 0x24D9), (0x24C0, 0x24DA), (0x24C1, 0x24DB), (0x24C2, 0x24DC), (0x24C3, 0x24DD),
+    (0x24C4, 0x24DE), (0x24C5, 0x24DF), (0x24C6, 0x24E0), (0x24C7, 0x24E1), (0x24C8, 0x24E2), (0x24C9, 0x24E3),
+    (0x24CA, 0x24E4), (0x24CB, 0x24E5), (0x24CC, 0x24E6), (0x24CD, 0x24E7), (0x24CE, 0x24E8), (0x24CF, 0x24E9),
+    (0x2C00, 0x2C30), (0x2C01, 0x2C31), (0x2C02, 0x2C32), (0x2C03, 0x2C33), (0x2C04, 0x2C34), (0x2C05, 0x2C35),
+    (0x2C06, 0x2C36), (0x2C07, 0x2C37), (0x2C08, 0x2C38), (0x2C09, 0x2C39), (0x2C0A, 0x2C3A), (0x2C0B, 0x2C3B),
+    (0x2C0C, 0x2C3C), (0x2C0D, 0x2C3D), (0x2C0E, 0x2C3E), (0x2C0F, 0x2C3F), (0x2C10, 0x2C40), (0x2C11, 0x2C41),
+    (0x2C12, 0x2C42), (0x2C13, 0x2C43), (0x2C14, 0x2C44), (0x2C15, 0x2C45), (0x2C16, 0x2C46), (0x2C17, 0x2C47),
+    (0x2C18, 0x2C48), (0x2C19, 0x2C49), (0x2C1A, 0x2C4A), (0x2C1B, 0x2C4B), (0x2C1C, 0x2C4C), (0x2C1D, 0x2C4D),
+    (0x2C1E, 0x2C4E), (0x2C1F, 0x2C4F), (0x2C20, 0x2C50), (0x2C21, 0x2C51), (0x2C22, 0x2C52), (0x2C23, 0x2C53),
+    (0x2C24, 0x2C54), (0x2C25, 0x2C55), (0x2C26, 0x2C56), (0x2C27, 0x2C57), (0x2C28, 0x2C58), (0x2C29, 0x2C59),
+    (0x2C2A, 0x2C5A), (0x2C2B, 0x2C5B), (0x2C2C, 0x2C5C), (0x2C2D, 0x2C5D), (0x2C2E, 0x2C5E), (0x2C2F, 0x2C5F),
+    (0x2C60, 0x2C61), (0x2C62, 0x26B), (0x2C63, 0x1D7D), (0x2C64, 0x27D), (0x2C67, 0x2C68), (0x2C69, 0x2C6A),
+    (0x2C6B, 0x2C6C), (0x2C6D, 0x251), (0x2C6E, 0x271), (0x2C6F, 0x250), (0x2C70, 0x252), (0x2C72, 0x2C73),
+    (0x2C75, 0x2C76), (0x2C7E, 0x23F), (0x2C7F, 0x240), (0x2C80, 0x2C81), (0x2C82, 0x2C83), (0x2C84, 0x2C85),
+    (0x2C86, 0x2C87), (0x2C88, 0x2C89), (0x2C8A, 0x2C8B), (0x2C8C, 0x2C8D), (0x2C8E, 0x2C8F), (0x2C90, 0x2C91),
+    (0x2C92, 0x2C93), (0x2C94, 0x2C95), (0x2C96, 0x2C97), (0x2C98, 0x2C99), (0x2C9A, 0x2C9B), (0x2C9C, 0x2C9D),
+    (0x2C9E, 0x2C9F), (0x2CA0, 0x2CA1), (0x2CA2, 0x2CA3), (0x2CA4, 0x2CA5), (0x2CA6, 0x2CA7), (0x2CA8, 0x2CA9),
+    (0x2CAA, 0x2CAB), (0x2CAC, 0x2CAD), (0x2CAE, 0x2CAF), (0x2CB0, 0x2CB1), (0x2CB2, 0x2CB3), (0x2CB4, 0x2CB5),
+    (0x2CB6, 0x2CB7), (0x2CB8, 0x2CB9), (0x2CBA, 0x2CBB), (0x2CBC, 0x2CBD), (0x2CBE, 0x2CBF), (0x2CC0, 0x2CC1),
+    (0x2CC2, 0x2CC3), (0x2CC4, 0x2CC5), (0x2CC6, 0x2CC7), (0x2CC8, 0x2CC9), (0x2CCA, 0x2CCB), (0x2CCC, 0x2CCD),
+    (0x2CCE, 0x2CCF), (0x2CD0, 0x2CD1), (0x2CD2, 0x2CD3), (0x2CD4, 0x2CD5), (0x2CD6, 0x2CD7), (0x2CD8, 0x2CD9),
+    (0x2CDA, 0x2CDB), (0x2CDC, 0x2CDD), (0x2CDE, 0x2CDF), (0x2CE0, 0x2CE1), (0x2CE2, 0x2CE3), (0x2CEB, 0x2CEC),
+    (0x2CED, 0x2CEE), (0x2CF2, 0x2CF3), (0xA640, 0xA641), (0xA642, 0xA643), (0xA644, 0xA645), (0xA646, 0xA647),
+    (0xA648, 0xA649), (0xA64A, 0xA64B), (0xA64C, 0xA64D), (0xA64E, 0xA64F), (0xA650, 0xA651), (0xA652, 0xA653),
+    (0xA654, 0xA655), (0xA656, 0xA657), (0xA658, 0xA659), (0xA65A, 0xA65B), (0xA65C, 0xA65D), (0xA65E, 0xA65F),
+    (0xA660, 0xA661), (0xA662, 0xA663), (0xA664, 0xA665), (0xA666, 0xA667), (0xA668, 0xA669), (0xA66A, 0xA66B),
+    (0xA66C, 0xA66D), (0xA680, 0xA681), (0xA682, 0xA683), (0xA684, 0xA685), (0xA686, 0xA687), (0xA688, 0xA689),
+    (0xA68A, 0xA68B), (0xA68C, 0xA68D), (0xA68E, 0xA68F), (0xA690, 0xA691), (0xA692, 0xA693), (0xA694, 0xA695),
+    (0xA696, 0xA697), (0xA698, 0xA699), (0xA69A, 0xA69B), (0xA722, 0xA723), (0xA724, 0xA725), (0xA726, 0xA727),
+    (0xA728, 0xA729), (0xA72A, 0xA72B), (0xA72C, 0xA72D), (0xA72E, 0xA72F), (0xA732, 0xA733), (0xA734, 0xA735),
+    (0xA736, 0xA737), (0xA738, 0xA739), (0xA73A, 0xA73B), (0xA73C, 0xA73D), (0xA73E, 0xA73F), (0xA740, 0xA741),
+    (0xA742, 0xA743), (0xA744, 0xA745), (0xA746, 0xA747), (0xA748, 0xA749), (0xA74A, 0xA74B), (0xA74C, 0xA74D),
+    (0xA74E, 0xA74F), (0xA750, 0xA751), (0xA752, 0xA753), (0xA754, 0xA755), (0xA756, 0xA757), (0xA758, 0xA759),
+    (0xA75A, 0xA75B), (0xA75C, 0xA75D), (0xA75E, 0xA75F), (0xA760, 0xA761), (0xA762, 0xA763), (0xA764, 0xA765),
+    (0xA766, 0xA767), (0xA768, 0xA769), (0xA76A, 0xA76B), (0xA76C, 0xA76D), (0xA76E, 0xA76F), (0xA779, 0xA77A),
+    (0xA77B, 0xA77C), (0xA77D, 0x1D79), (0xA77E, 0xA77F), (0xA780, 0xA781), (0xA782, 0xA783), (0xA784, 0xA785),
+    (0xA786, 0xA787), (0xA78B, 0xA78C), (0xA78D, 0x265), (0xA790, 0xA791), (0xA792, 0xA793), (0xA796, 0xA797),
+    (0xA798, 0xA799), (0xA79A, 0xA79B), (0xA79C, 0xA79D), (0xA79E, 0xA79F), (0xA7A0, 0xA7A1), (0xA7A2, 0xA7A3),
+    (0xA7A4, 0xA7A5), (0xA7A6, 0xA7A7), (0xA7A8, 0xA7A9), (0xA7AA, 0x266), (0xA7AB, 0x25C), (0xA7AC, 0x261),
+    (0xA7AD, 0x26C), (0xA7AE, 0x26A), (0xA7B0, 0x29E), (0xA7B1, 0x287), (0xA7B2, 0x29D), (0xA7B3, 0xAB53),
+    (0xA7B4, 0xA7B5), (0xA7B6, 0xA7B7), (0xA7B8, 0xA7B9), (0xA7BA, 0xA7BB), (0xA7BC, 0xA7BD), (0xA7BE, 0xA7BF),
+    (0xA7C0, 0xA7C1), (0xA7C2, 0xA7C3), (0xA7C4, 0xA794), (0xA7C5, 0x282), (0xA7C6, 0x1D8E), (0xA7C7, 0xA7C8),
+    (0xA7C9, 0xA7CA), (0xA7CB, 0x264), (0xA7CC, 0xA7CD), (0xA7CE, 0xA7CF), (0xA7D0, 0xA7D1), (0xA7D2, 0xA7D3),
+    (0xA7D4, 0xA7D5), (0xA7D6, 0xA7D7), (0xA7D8, 0xA7D9), (0xA7DA, 0xA7DB), (0xA7DC, 0x19B), (0xA7F5, 0xA7F6),
+    (0xAB70, 0x13A0), (0xAB71, 0x13A1), (0xAB72, 0x13A2), (0xAB73, 0x13A3), (0xAB74, 0x13A4), (0xAB75, 0x13A5),
+    (0xAB76, 0x13A6), (0xAB77, 0x13A7), (0xAB78, 0x13A8), (0xAB79, 0x13A9), (0xAB7A, 0x13AA), (0xAB7B, 0x13AB),
+    (0xAB7C, 0x13AC), (0xAB7D, 0x13AD), (0xAB7E, 0x13AE), (0xAB7F, 0x13AF), (0xAB80, 0x13B0), (0xAB81, 0x13B1),
+    (0xAB82, 0x13B2), (0xAB83, 0x13B3), (0xAB84, 0x13B4), (0xAB85, 0x13B5), (0xAB86, 0x13B6), (0xAB87, 0x13B7),
+    (0xAB88, 0x13B8), (0xAB89, 0x13B9), (0xAB8A, 0x13BA), (0xAB8B, 0x13BB), (0xAB8C, 0x13BC), (0xAB8D, 0x13BD),
+    (0xAB8E, 0x13BE), (0xAB8F, 0x13BF), (0xAB90, 0x13C0), (0xAB91, 0x13C1), (0xAB92, 0x13C2), (0xAB93, 0x13C3),
+    (0xAB94, 0x13C4), (0xAB95, 0x13C5), (0xAB96, 0x13C6), (0xAB97, 0x13C7), (0xAB98, 0x13C8), (0xAB99, 0x13C9),
+    (0xAB9A, 0x13CA), (0xAB9B, 0x13CB), (0xAB9C, 0x13CC), (0xAB9D, 0x13CD), (0xAB9E, 0x13CE), (0xAB9F, 0x13CF),
+    (0xABA0, 0x13D0), (0xABA1, 0x13D1), (0xABA2, 0x13D2), (0xABA3, 0x13D3), (0xABA4, 0x13D4), (0xABA5, 0x13D5),
+    (0xABA6, 0x13D6), (0xABA7, 0x13D7), (0xABA8, 0x13D8), (0xABA9, 0x13D9), (0xABAA, 0x13DA), (0xABAB, 0x13DB),
+    (0xABAC, 0x13DC), (0xABAD, 0x13DD), (0xABAE, 0x13DE), (0xABAF, 0x13DF), (0xABB0, 0x13E0), (0xABB1, 0x13E1),
+    (0xABB2, 0x13E2), (0xABB3, 0x13E3), (0xABB4, 0x13E4), (0xABB5, 0x13E5), (0xABB6, 0x13E6), (0xABB7, 0x13E7),
+    (0xABB8, 0x13E8), (0xABB9, 0x13E9), (0xABBA, 0x13EA), (0xABBB, 0x13EB), (0xABBC, 0x13EC), (0xABBD, 0x13ED),
+    (0xABBE, 0x13EE), (0xABBF, 0x13EF), (0xFB05, 0xFB06), (0xFF21, 0xFF41), (0xFF22, 0xFF42), (0xFF23, 0xFF43),
+    (0xFF24, 0xFF44), (0xFF25, 0xFF45), (0xFF26, 0xFF46), (0xFF27, 0xFF47), (0xFF28, 0xFF48), (0xFF29, 0xFF49),
+    (0xFF2A, 0xFF4A), (0xFF2B, 0xFF4B), (0xFF2C, 0xFF4C), (0xFF2D, 0xFF4D), (0xFF2E, 0xFF4E), (0xFF2F, 0xFF4F),
+    (0xFF30, 0xFF50), (0xFF31, 0xFF51), (0xFF32, 0xFF52), (0xFF33, 0xFF53), (0xFF34, 0xFF54), (0xFF35, 0xFF55),
+    (0xFF36, 0xFF56), (0xFF37, 0xFF57), (0xFF38, 0xFF58), (0xFF39, 0xFF59), (0xFF3A, 0xFF5A), (0x10400, 0x10428),
+    (0x10401, 0x10429), (0x10402, 0x1042A), (0x10403, 0x1042B), (0x10404, 0x1042C), (0x10405, 0x1042D), (0x10406, 0x1042E),
+    (0x10407, 0x1042F), (0x10408, 0x10430), (0x10409, 0x10431), (0x1040A, 0x10432), (0x1040B, 0x10433), (0x1040C, 0x10434),
+    (0x1040D, 0x10435), (0x1040E, 0x10436), (0x1040F, 0x10437), (0x10410, 0x10438), (0x10411, 0x10439), (0x10412, 0x1043A),
+    (0x10413, 0x1043B), (0x10414, 0x1043C), (0x10415, 0x1043D), (0x10416, 0x1043E), (0x10417, 0x1043F), (0x10418, 0x10440),
+    (0x10419, 0x10441), (0x1041A, 0x10442), (0x1041B, 0x10443), (0x1041C, 0x10444), (0x1041D, 0x10445), (0x1041E, 0x10446),
+    (0x1041F, 0x10447), (0x10420, 0x10448), (0x10421, 0x10449), (0x10422, 0x1044A), (0x10423, 0x1044B), (0x10424, 0x1044C),
+    (0x10425, 0x1044D), (0x10426, 0x1044E), (0x10427, 0x1044F), (0x104B0, 0x104D8), (0x104B1, 0x104D9), (0x104B2, 0x104DA),
+    (0x104B3, 0x104DB), (0x104B4, 0x104DC), (0x104B5, 0x104DD), (0x104B6, 0x104DE), (0x104B7, 0x104DF), (0x104B8, 0x104E0),
+    (0x104B9, 0x104E1), (0x104BA, 0x104E2), (0x104BB, 0x104E3), (0x104BC, 0x104E4), (0x104BD, 0x104E5), (0x104BE, 0x104E6),
+    (0x104BF, 0x104E7), (0x104C0, 0x104E8), (0x104C1, 0x104E9), (0x104C2, 0x104EA), (0x104C3, 0x104EB), (0x104C4, 0x104EC),
+    (0x104C5, 0x104ED), (0x104C6, 0x104EE), (0x104C7, 0x104EF), (0x104C8, 0x104F0), (0x104C9, 0x104F1), (0x104CA, 0x104F2),
+    (0x104CB, 0x104F3), (0x104CC, 0x104F4), (0x104CD, 0x104F5), (0x104CE, 0x104F6), (0x104CF, 0x104F7), (0x104D0, 0x104F8),
+    (0x104D1, 0x104F9), (0x104D2, 0x104FA), (0x104D3, 0x104FB), (0x10570, 0x10597), (0x10571, 0x10598), (0x10572, 0x10599),
+    (0x10573, 0x1059A), (0x10574, 0x1059B), (0x10575, 0x1059C), (0x10576, 0x1059D), (0x10577, 0x1059E), (0x10578, 0x1059F),
+    (0x10579, 0x105A0), (0x1057A, 0x105A1), (0x1057C, 0x105A3), (0x1057D, 0x105A4), (0x1057E, 0x105A5), (0x1057F, 0x105A6),
+    (0x10580, 0x105A7), (0x10581, 0x105A8), (0x10582, 0x105A9), (0x10583, 0x105AA), (0x10584, 0x105AB), (0x10585, 0x105AC),
+    (0x10586, 0x105AD), (0x10587, 0x105AE), (0x10588, 0x105AF), (0x10589, 0x105B0), (0x1058A, 0x105B1), (0x1058C, 0x105B3),
+    (0x1058D, 0x105B4), (0x1058E, 0x105B5), (0x1058F, 0x105B6), (0x10590, 0x105B7), (0x10591, 0x105B8), (0x10592, 0x105B9),
+    (0x10594, 0x105BB), (0x10595, 0x105BC), (0x10C80, 0x10CC0), (0x10C81, 0x10CC1), (0x10C82, 0x10CC2), (0x10C83, 0x10CC3),
+    (0x10C84, 0x10CC4), (0x10C85, 0x10CC5), (0x10C86, 0x10CC6), (0x10C87, 0x10CC7), (0x10C88, 0x10CC8), (0x10C89, 0x10CC9),
+    (0x10C8A, 0x10CCA), (0x10C8B, 0x10CCB), (0x10C8C, 0x10CCC), (0x10C8D, 0x10CCD), (0x10C8E, 0x10CCE), (0x10C8F, 0x10CCF),
+    (0x10C90, 0x10CD0), (0x10C91, 0x10CD1), (0x10C92, 0x10CD2), (0x10C93, 0x10CD3), (0x10C94, 0x10CD4), (0x10C95, 0x10CD5),
+    (0x10C96, 0x10CD6), (0x10C97, 0x10CD7), (0x10C98, 0x10CD8), (0x10C99, 0x10CD9), (0x10C9A, 0x10CDA), (0x10C9B, 0x10CDB),
+    (0x10C9C, 0x10CDC), (0x10C9D, 0x10CDD), (0x10C9E, 0x10CDE), (0x10C9F, 0x10CDF), (0x10CA0, 0x10CE0), (0x10CA1, 0x10CE1),
+    (0x10CA2, 0x10CE2), (0x10CA3, 0x10CE3), (0x10CA4, 0x10CE4), (0x10CA5, 0x10CE5), (0x10CA6, 0x10CE6), (0x10CA7, 0x10CE7),
+    (0x10CA8, 0x10CE8), (0x10CA9, 0x10CE9), (0x10CAA, 0x10CEA), (0x10CAB, 0x10CEB), (0x10CAC, 0x10CEC), (0x10CAD, 0x10CED),
+    (0x10CAE, 0x10CEE), (0x10CAF, 0x10CEF), (0x10CB0, 0x10CF0), (0x10CB1, 0x10CF1), (0x10CB2, 0x10CF2), (0x10D50, 0x10D70),
+    (0x10D51, 0x10D71), (0x10D52, 0x10D72), (0x10D53, 0x10D73), (0x10D54, 0x10D74), (0x10D55, 0x10D75), (0x10D56, 0x10D76),
+    (0x10D57, 0x10D77), (0x10D58, 0x10D78), (0x10D59, 0x10D79), (0x10D5A, 0x10D7A), (0x10D5B, 0x10D7B), (0x10D5C, 0x10D7C),
+    (0x10D5D, 0x10D7D), (0x10D5E, 0x10D7E), (0x10D5F, 0x10D7F), (0x10D60, 0x10D80), (0x10D61, 0x10D81), (0x10D62, 0x10D82),
+    (0x10D63, 0x10D83), (0x10D64, 0x10D84), (0x10D65, 0x10D85), (0x118A0, 0x118C0), (0x118A1, 0x118C1), (0x118A2, 0x118C2),
+    (0x118A3, 0x118C3), (0x118A4, 0x118C4), (0x118A5, 0x118C5), (0x118A6, 0x118C6), (0x118A7, 0x118C7), (0x118A8, 0x118C8),
+    (0x118A9, 0x118C9), (0x118AA, 0x118CA), (0x118AB, 0x118CB), (0x118AC, 0x118CC), (0x118AD, 0x118CD), (0x118AE, 0x118CE),
+    (0x118AF, 0x118CF), (0x118B0, 0x118D0), (0x118B1, 0x118D1), (0x118B2, 0x118D2), (0x118B3, 0x118D3), (0x118B4, 0x118D4),
+    (0x118B5, 0x118D5), (0x118B6, 0x118D6), (0x118B7, 0x118D7), (0x118B8, 0x118D8), (0x118B9, 0x118D9), (0x118BA, 0x118DA),
+    (0x118BB, 0x118DB), (0x118BC, 0x118DC), (0x118BD, 0x118DD), (0x118BE, 0x118DE), (0x118BF, 0x118DF), (0x16E40, 0x16E60),
+    (0x16E41, 0x16E61), (0x16E42, 0x16E62), (0x16E43, 0x16E63), (0x16E44, 0x16E64), (0x16E45, 0x16E65), (0x16E46, 0x16E66),
+    (0x16E47, 0x16E67), (0x16E48, 0x16E68), (0x16E49, 0x16E69), (0x16E4A, 0x16E6A), (0x16E4B, 0x16E6B), (0x16E4C, 0x16E6C),
+    (0x16E4D, 0x16E6D), (0x16E4E, 0x16E6E), (0x16E4F, 0x16E6F), (0x16E50, 0x16E70), (0x16E51, 0x16E71), (0x16E52, 0x16E72),
+    (0x16E53, 0x16E73), (0x16E54, 0x16E74), (0x16E55, 0x16E75), (0x16E56, 0x16E76), (0x16E57, 0x16E77), (0x16E58, 0x16E78),
+    (0x16E59, 0x16E79), (0x16E5A, 0x16E7A), (0x16E5B, 0x16E7B), (0x16E5C, 0x16E7C), (0x16E5D, 0x16E7D), (0x16E5E, 0x16E7E),
+    (0x16E5F, 0x16E7F), (0x16EA0, 0x16EBB), (0x16EA1, 0x16EBC), (0x16EA2, 0x16EBD), (0x16EA3, 0x16EBE), (0x16EA4, 0x16EBF),
+    (0x16EA5, 0x16EC0), (0x16EA6, 0x16EC1), (0x16EA7, 0x16EC2), (0x16EA8, 0x16EC3), (0x16EA9, 0x16EC4), (0x16EAA, 0x16EC5),
+    (0x16EAB, 0x16EC6), (0x16EAC, 0x16EC7), (0x16EAD, 0x16EC8), (0x16EAE, 0x16EC9), (0x16EAF, 0x16ECA), (0x16EB0, 0x16ECB),
+    (0x16EB1, 0x16ECC), (0x16EB2, 0x16ECD), (0x16EB3, 0x16ECE), (0x16EB4, 0x16ECF), (0x16EB5, 0x16ED0), (0x16EB6, 0x16ED1),
+    (0x16EB7, 0x16ED2), (0x16EB8, 0x16ED3), (0x1E900, 0x1E922), (0x1E901, 0x1E923), (0x1E902, 0x1E924), (0x1E903, 0x1E925),
+    (0x1E904, 0x1E926), (0x1E905, 0x1E927), (0x1E906, 0x1E928), (0x1E907, 0x1E929), (0x1E908, 0x1E92A), (0x1E909, 0x1E92B),
+    (0x1E90A, 0x1E92C), (0x1E90B, 0x1E92D), (0x1E90C, 0x1E92E), (0x1E90D, 0x1E92F), (0x1E90E, 0x1E930), (0x1E90F, 0x1E931),
+    (0x1E910, 0x1E932), (0x1E911, 0x1E933), (0x1E912, 0x1E934), (0x1E913, 0x1E935), (0x1E914, 0x1E936), (0x1E915, 0x1E937),
+    (0x1E916, 0x1E938), (0x1E917, 0x1E939), (0x1E918, 0x1E93A), (0x1E919, 0x1E93B), (0x1E91A, 0x1E93C), (0x1E91B, 0x1E93D),
+    (0x1E91C, 0x1E93E), (0x1E91D, 0x1E93F), (0x1E91E, 0x1E940), (0x1E91F, 0x1E941), (0x1E920, 0x1E942), (0x1E921, 0x1E943),
+];
+
+/// The full uppercase mapping of every code point that does not map to itself, as `(code point, mapping)`.
+pub(super) static UPPERCASE_MAPPINGS: &[(u32, &[u32])] = &[
+    (0x61, &[0x41]), (0x62, &[0x42]), (0x63, &[0x43]), (0x64, &[0x44]),
+    (0x65, &[0x45]), (0x66, &[0x46]), (0x67, &[0x47]), (0x68, &[0x48]),
+    (0x69, &[0x49]), (0x6A, &[0x4A]), (0x6B, &[0x4B]), (0x6C, &[0x4C]),
+    (0x6D, &[0x4D]), (0x6E, &[0x4E]), (0x6F, &[0x4F]), (0x70, &[0x50]),
+    (0x71, &[0x51]), (0x72, &[0x52]), (0x73, &[0x53]), (0x74, &[0x54]),
+    (0x75, &[0x55]), (0x76, &[0x56]), (0x77, &[0x57]), (0x78, &[0x58]),
+    (0x79, &[0x59]), (0x7A, &[0x5A]), (0xB5, &[0x39C]), (0xDF, &[0x53, 0x53]),
+    (0xE0, &[0xC0]), (0xE1, &[0xC1]), (0xE2, &[0xC2]), (0xE3, &[0xC3]),
+    (0xE4, &[0xC4]), (0xE5, &[0xC5]), (0xE6, &[0xC6]), (0xE7, &[0xC7]),
+    (0xE8, &[0xC8]), (0xE9, &[0xC9]), (0xEA, &[0xCA]), (0xEB, &[0xCB]),
+    (0xEC, &[0xCC]), (0xED, &[0xCD]), (0xEE, &[0xCE]), (0xEF, &[0xCF]),
+    (0xF0, &[0xD0]), (0xF1, &[0xD1]), (0xF2, &[0xD2]), (0xF3, &[0xD3]),
+    (0xF4, &[0xD4]), (0xF5, &[0xD5]), (0xF6, &[0xD6]), (0xF8, &[0xD8]),
+    (0xF9, &[0xD9]), (0xFA, &[0xDA]), (0xFB, &[0xDB]), (0xFC, &[0xDC]),
+    (0xFD, &[0xDD]), (0xFE, &[0xDE]), (0xFF, &[0x178]), (0x101, &[0x100]),
+    (0x103, &[0x102]), (0x105, &[0x104]), (0x107, &[0x106]), (0x109, &[0x108]),
+    (0x10B, &[0x10A]), (0x10D, &[0x10C]), (0x10F, &[0x10E]), (0x111, &[0x110]),
+    (0x113, &[0x112]), (0x115, &[0x114]), (0x117, &[0x116]), (0x119, &[0x118]),
+    (0x11B, &[0x11A]), (0x11D, &[0x11C]), (0x11F, &[0x11E]), (0x121, &[0x120]),
+    (0x123, &[0x122]), (0x125, &[0x124]), (0x127, &[0x126]), (0x129, &[0x128]),
+    (0x12B, &[0x12A]), (0x12D, &[0x12C]), (0x12F, &[0x12E]), (0x131, &[0x49]),
+    (0x133, &[0x132]), (0x135, &[0x134]), (0x137, &[0x136]), (0x13A, &[0x139]),
+    (0x13C, &[0x13B]), (0x13E, &[0x13D]), (0x140, &[0x13F]), (0x142, &[0x141]),
+    (0x144, &[0x143]), (0x146, &[0x145]), (0x148, &[0x147]), (0x149, &[0x2BC, 0x4E]),
+    (0x14B, &[0x14A]), (0x14D, &[0x14C]), (0x14F, &[0x14E]), (0x151, &[0x150]),
+    (0x153, &[0x152]), (0x155, &[0x154]), (0x157, &[0x156]), (0x159, &[0x158]),
+    (0x15B, &[0x15A]), (0x15D, &[0x15C]), (0x15F, &[0x15E]), (0x161, &[0x160]),
+    (0x163, &[0x162]), (0x165, &[0x164]), (0x167, &[0x166]), (0x169, &[0x168]),
+    (0x16B, &[0x16A]), (0x16D, &[0x16C]), (0x16F, &[0x16E]), (0x171, &[0x170]),
+    (0x173, &[0x172]), (0x175, &[0x174]), (0x177, &[0x176]), (0x17A, &[0x179]),
+    (0x17C, &[0x17B]), (0x17E, &[0x17D]), (0x17F, &[0x53]), (0x180, &[0x243]),
+    (0x183, &[0x182]), (0x185, &[0x184]), (0x188, &[0x187]), (0x18C, &[0x18B]),
+    (0x192, &[0x191]), (0x195, &[0x1F6]), (0x199, &[0x198]), (0x19A, &[0x23D]),
+    (0x19B, &[0xA7DC]), (0x19E, &[0x220]), (0x1A1, &[0x1A0]), (0x1A3, &[0x1A2]),
+    (0x1A5, &[0x1A4]), (0x1A8, &[0x1A7]), (0x1AD, &[0x1AC]), (0x1B0, &[0x1AF]),
+    (0x1B4, &[0x1B3]), (0x1B6, &[0x1B5]), (0x1B9, &[0x1B8]), (0x1BD, &[0x1BC]),
+    (0x1BF, &[0x1F7]), (0x1C5, &[0x1C4]), (0x1C6, &[0x1C4]), (0x1C8, &[0x1C7]),
+    (0x1C9, &[0x1C7]), (0x1CB, &[0x1CA]), (0x1CC, &[0x1CA]), (0x1CE, &[0x1CD]),
+    (0x1D0, &[0x1CF]), (0x1D2, &[0x1D1]), (0x1D4, &[0x1D3]), (0x1D6, &[0x1D5]),
+    (0x1D8, &[0x1D7]), (0x1DA, &[0x1D9]), (0x1DC, &[0x1DB]), (0x1DD, &[0x18E]),
+    (0x1DF, &[0x1DE]), (0x1E1, &[0x1E0]), (0x1E3, &[0x1E2]), (0x1E5, &[0x1E4]),
+    (0x1E7, &[0x1E6]), (0x1E9, &[0x1E8]), (0x1EB, &[0x1EA]), (0x1ED, &[0x1EC]),
+    (0x1EF, &[0x1EE]), (0x1F0, &[0x4A, 0x30C]), (0x1F2, &[0x1F1]), (0x1F3, &[0x1F1]),
+    (0x1F5, &[0x1F4]), (0x1F9, &[0x1F8]), (0x1FB, &[0x1FA]), (0x1FD, &[0x1FC]),
+    (0x1FF, &[0x1FE]), (0x201, &[0x200]), (0x203, &[0x202]), (0x205, &[0x204]),
+    (0x207, &[0x206]), (0x209, &[0x208]), (0x20B, &[0x20A]), (0x20D, &[0x20C]),
+    (0x20F, &[0x20E]), (0x211, &[0x210]), (0x213, &[0x212]), (0x215, &[0x214]),
+    (0x217, &[0x216]), (0x219, &[0x218]), (0x21B, &[0x21A]), (0x21D, &[0x21C]),
+    (0x21F, &[0x21E]), (0x223, &[0x222]), (0x225, &[0x224]), (0x227, &[0x226]),
+    (0x229, &[0x228]), (0x22B, &[0x22A]), (0x22D, &[0x22C]), (0x22F, &[0x22E]),
+    (0x231, &[0x230]), (0x233, &[0x232]), (0x23C, &[0x23B]), (0x23F, &[0x2C7E]),
+    (0x240, &[0x2C7F]), (0x242, &[0x241]), (0x247, &[0x246]), (0x249, &[0x248]),
+    (0x24B, &[0x24A]), (0x24D, &[0x24C]), (0x24F, &[0x24E]), (0x250, &[0x2C6F]),
+    (0x251, &[0x2C6D]), (0x252, &[0x2C70]), (0x253, &[0x181]), (0x254, &[0x186]),
+    (0x256, &[0x189]), (0x257, &[0x18A]), (0x259, &[0x18F]), (0x25B, &[0x190]),
+    (0x25C, &[0xA7AB]), (0x260, &[0x193]), (0x261, &[0xA7AC]), (0x263, &[0x194]),
+    (0x264, &[0xA7CB]), (0x265, &[0xA78D]), (0x266, &[0xA7AA]), (0x268, &[0x197]),
+    (0x269, &[0x196]), (0x26A, &[0xA7AE]), (0x26B, &[0x2C62]), (0x26C, &[0xA7AD]),
+    (0x26F, &[0x19C]), (0x271, &[0x2C6E]), (0x272, &[0x19D]), (0x275, &[0x19F]),
+    (0x27D, &[0x2C64]), (0x280, &[0x1A6]), (0x282, &[0xA7C5]), (0x283, &[0x1A9]),
+    (0x287, &[0xA7B1]), (0x288, &[0x1AE]), (0x289, &[0x244]), (0x28A, &[0x1B1]),
+    (0x28B, &[0x1B2]), (0x28C, &[0x245]), (0x292, &[0x1B7]), (0x29D, &[0xA7B2]),
+    (0x29E, &[0xA7B0]), (0x345, &[0x399]), (0x371, &[0x370]), (0x373, &[0x372]),
+    (0x377, &[0x376]), (0x37B, &[0x3FD]), (0x37C, &[0x3FE]), (0x37D, &[0x3FF]),
+    (0x390, &[0x399, 0x308, 0x301]), (0x3AC, &[0x386]), (0x3AD, &[0x388]), (0x3AE, &[0x389]),
+    (0x3AF, &[0x38A]), (0x3B0, &[0x3A5, 0x308, 0x301]), (0x3B1, &[0x391]), (0x3B2, &[0x392]),
+    (0x3B3, &[0x393]), (0x3B4, &[0x394]), (0x3B5, &[0x395]), (0x3B6, &[0x396]),
+    (0x3B7, &[0x397]), (0x3B8, &[0x398]), (0x3B9, &[0x399]), (0x3BA, &[0x39A]),
+    (0x3BB, &[0x39B]), (0x3BC, &[0x39C]), (0x3BD, &[0x39D]), (0x3BE, &[0x39E]),
+    (0x3BF, &[0x39F]), (0x3C0, &[0x3A0]), (0x3C1, &[0x3A1]), (0x3C2, &[0x3A3]),
+    (0x3C3, &[0x3A3]), (0x3C4, &[0x3A4]), (0x3C5, &[0x3A5]), (0x3C6, &[0x3A6]),
+    (0x3C7, &[0x3A7]), (0x3C8, &[0x3A8]), (0x3C9, &[0x3A9]), (0x3CA, &[0x3AA]),
+    (0x3CB, &[0x3AB]), (0x3CC, &[0x38C]), (0x3CD, &[0x38E]), (0x3CE, &[0x38F]),
+    (0x3D0, &[0x392]), (0x3D1, &[0x398]), (0x3D5, &[0x3A6]), (0x3D6, &[0x3A0]),
+    (0x3D7, &[0x3CF]), (0x3D9, &[0x3D8]), (0x3DB, &[0x3DA]), (0x3DD, &[0x3DC]),
+    (0x3DF, &[0x3DE]), (0x3E1, &[0x3E0]), (0x3E3, &[0x3E2]), (0x3E5, &[0x3E4]),
+    (0x3E7, &[0x3E6]), (0x3E9, &[0x3E8]), (0x3EB, &[0x3EA]), (0x3ED, &[0x3EC]),
+    (0x3EF, &[0x3EE]), (0x3F0, &[0x39A]), (0x3F1, &[0x3A1]), (0x3F2, &[0x3F9]),
+    (0x3F3, &[0x37F]), (0x3F5, &[0x395]), (0x3F8, &[0x3F7]), (0x3FB, &[0x3FA]),
+    (0x430, &[0x410]), (0x431, &[0x411]), (0x432, &[0x412]), (0x433, &[0x413]),
+    (0x434, &[0x414]), (0x435, &[0x415]), (0x436, &[0x416]), (0x437, &[0x417]),
+    (0x438, &[0x418]), (0x439, &[0x419]), (0x43A, &[0x41A]), (0x43B, &[0x41B]),
+    (0x43C, &[0x41C]), (0x43D, &[0x41D]), (0x43E, &[0x41E]), (0x43F, &[0x41F]),
+    (0x440, &[0x420]), (0x441, &[0x421]), (0x442, &[0x422]), (0x443, &[0x423]),
+    (0x444, &[0x424]), (0x445, &[0x425]), (0x446, &[0x426]), (0x447, &[0x427]),
+    (0x448, &[0x428]), (0x449, &[0x429]), (0x44A, &[0x42A]), (0x44B, &[0x42B]),
+    (0x44C, &[0x42C]), (0x44D, &[0x42D]), (0x44E, &[0x42E]), (0x44F, &[0x42F]),
+    (0x450, &[0x400]), (0x451, &[0x401]), (0x452, &[0x402]), (0x453, &[0x403]),
+    (0x454, &[0x404]), (0x455, &[0x405]), (0x456, &[0x406]), (0x457, &[0x407]),
+    (0x458, &[0x408]), (0x459, &[0x409]), (0x45A, &[0x40A]), (0x45B, &[0x40B]),
+    (0x45C, &[0x40C]), (0x45D, &[0x40D]), (0x45E, &[0x40E]), (0x45F, &[0x40F]),
+    (0x461, &[0x460]), (0x463, &[0x462]), (0x465, &[0x464]), (0x467, &[0x466]),
+    (0x469, &[0x468]), (0x46B, &[0x46A]), (0x46D, &[0x46C]), (0x46F, &[0x46E]),
+    (0x471, &[0x470]), (0x473, &[0x472]), (0x475, &[0x474]), (0x477, &[0x476]),
+    (0x479, &[0x478]), (0x47B, &[0x47A]), (0x47D, &[0x47C]), (0x47F, &[0x47E]),
+    (0x481, &[0x480]), (0x48B, &[0x48A]), (0x48D, &[0x48C]), (0x48F, &[0x48E]),
+    (0x491, &[0x490]), (0x493, &[0x492]), (0x495, &[0x494]), (0x497, &[0x496]),
+    (0x499, &[0x498]), (0x49B, &[0x49A]), (0x49D, &[0x49C]), (0x49F, &[0x49E]),
+    (0x4A1, &[0x4A0]), (0x4A3, &[0x4A2]), (0x4A5, &[0x4A4]), (0x4A7, &[0x4A6]),
+    (0x4A9, &[0x4A8]), (0x4AB, &[0x4AA]), (0x4AD, &[0x4AC]), (0x4AF, &[0x4AE]),
+    (0x4B1, &[0x4B0]), (0x4B3, &[0x4B2]), (0x4B5, &[0x4B4]), (0x4B7, &[0x4B6]),
+    (0x4B9, &[0x4B8]), (0x4BB, &[0x4BA]), (0x4BD, &[0x4BC]), (0x4BF, &[0x4BE]),
+    (0x4C2, &[0x4C1]), (0x4C4, &[0x4C3]), (0x4C6, &[0x4C5]), (0x4C8, &[0x4C7]),
+    (0x4CA, &[0x4C9]), (0x4CC, &[0x4CB]), (0x4CE, &[0x4CD]), (0x4CF, &[0x4C0]),
+    (0x4D1, &[0x4D0]), (0x4D3, &[0x4D2]), (0x4D5, &[0x4D4]), (0x4D7, &[0x4D6]),
+    (0x4D9, &[0x4D8]), (0x4DB, &[0x4DA]), (0x4DD, &[0x4DC]), (0x4DF, &[0x4DE]),
+    (0x4E1, &[0x4E0]), (0x4E3, &[0x4E2]), (0x4E5, &[0x4E4]), (0x4E7, &[0x4E6]),
+    (0x4E9, &[0x4E8]), (0x4EB, &[0x4EA]), (0x4ED, &[0x4EC]), (0x4EF, &[0x4EE]),
+    (0x4F1, &[0x4F0]), (0x4F3, &[0x4F2]), (0x4F5, &[0x4F4]), (0x4F7, &[0x4F6]),
+    (0x4F9, &[0x4F8]), (0x4FB, &[0x4FA]), (0x4FD, &[0x4FC]), (0x4FF, &[0x4FE]),
+    (0x501, &[0x500]), (0x503, &[0x502]), (0x505, &[0x504]), (0x507, &[0x506]),
+    (0x509, &[0x508]), (0x50B, &[0x50A]), (0x50D, &[0x50C]), (0x50F, &[0x50E]),
+    (0x511, &[0x510]), (0x513, &[0x512]), (0x515, &[0x514]), (0x517, &[0x516]),
+    (0x519, &[0x518]), (0x51B, &[0x51A]), (0x51D, &[0x51C]), (0x51F, &[0x51E]),
+    (0x521, &[0x520]), (0x523, &[0x522]), (0x525, &[0x524]), (0x527, &[0x526]),
+    (0x529, &[0x528]), (0x52B, &[0x52A]), (0x52D, &[0x52C]), (0x52F, &[0x52E]),
+    (0x561, &[0x531]), (0x562, &[0x532]), (0x563, &[0x533]), (0x564, &[0x534]),
+    (0x565, &[0x535]), (0x566, &[0x536]), (0x567, &[0x537]), (0x568, &[0x538]),
+    (0x569, &[0x539]), (0x56A, &[0x53A]), (0x56B, &[0x53B]), (0x56C, &[0x53C]),
+    (0x56D, &[0x53D]), (0x56E, &[0x53E]), (0x56F, &[0x53F]), (0x570, &[0x540]),
+    (0x571, &[0x541]), (0x572, &[0x542]), (0x573, &[0x543]), (0x574, &[0x544]),
+    (0x575, &[0x545]), (0x576, &[0x546]), (0x577, &[0x547]), (0x578, &[0x548]),
+    (0x579, &[0x549]), (0x57A, &[0x54A]), (0x57B, &[0x54B]), (0x57C, &[0x54C]),
+    (0x57D, &[0x54D]), (0x57E, &[0x54E]), (0x57F, &[0x54F]), (0x580, &[0x550]),
+    (0x581, &[0x551]), (0x582, &[0x552]), (0x583, &[0x553]), (0x584, &[0x554]),
+    (0x585, &[0x555]), (0x586, &[0x556]), (0x587, &[0x535, 0x552]), (0x10D0, &[0x1C90]),
+    (0x10D1, &[0x1C91]), (0x10D2, &[0x1C92]), (0x10D3, &[0x1C93]), (0x10D4, &[0x1C94]),
+    (0x10D5, &[0x1C95]), (0x10D6, &[0x1C96]), (0x10D7, &[0x1C97]), (0x10D8, &[0x1C98]),
+    (0x10D9, &[0x1C99]), (0x10DA, &[0x1C9A]), (0x10DB, &[0x1C9B]), (0x10DC, &[0x1C9C]),
+    (0x10DD, &[0x1C9D]), (0x10DE, &[0x1C9E]), (0x10DF, &[0x1C9F]), (0x10E0, &[0x1CA0]),
+    (0x10E1, &[0x1CA1]), (0x10E2, &[0x1CA2]), (0x10E3, &[0x1CA3]), (0x10E4, &[0x1CA4]),
+    (0x10E5, &[0x1CA5]), (0x10E6, &[0x1CA6]), (0x10E7, &[0x1CA7]), (0x10E8, &[0x1CA8]),
+    (0x10E9, &[0x1CA9]), (0x10EA, &[0x1CAA]), (0x10EB, &[0x1CAB]), (0x10EC, &[0x1CAC]),
+    (0x10ED, &[0x1CAD]), (0x10EE, &[0x1CAE]), (0x10EF, &[0x1CAF]), (0x10F0, &[0x1CB0]),
+    (0x10F1, &[0x1CB1]), (0x10F2, &[0x1CB2]), (0x10F3, &[0x1CB3]), (0x10F4, &[0x1CB4]),
+    (0x10F5, &[0x1CB5]), (0x10F6, &[0x1CB6]), (0x10F7, &[0x1CB7]), (0x10F8, &[0x1CB8]),
+    (0x10F9, &[0x1CB9]), (0x10FA, &[0x1CBA]), (0x10FD, &[0x1CBD]), (0x10FE, &[0x1CBE]),
+    (0x10FF, &[0x1CBF]), (0x13F8, &[0x13F0]), (0x13F9, &[0x13F1]), (0x13FA, &[0x13F2]),
+    (0x13FB, &[0x13F3]), (0x13FC, &[0x13F4]), (0x13FD, &[0x13F5]), (0x1C80, &[0x412]),
+    (0x1C81, &[0x414]), (0x1C82, &[0x41E]), (0x1C83, &[0x421]), (0x1C84, &[0x422]),
+    (0x1C85, &[0x422]), (0x1C86, &[0x42A]), (0x1C87, &[0x462]), (0x1C88, &[0xA64A]),
+    (0x1C8A, &[0x1C89]), (0x1D79, &[0xA77D]), (0x1D7D, &[0x2C63]), (0x1D8E, &[0xA7C6]),
+    (0x1E01, &[0x1E00]), (0x1E03, &[0x1E02]), (0x1E05, &[0x1E04]), (0x1E07, &[0x1E06]),
+    (0x1E09, &[0x1E08]), (0x1E0B, &[0x1E0A]), (0x1E0D, &[0x1E0C]), (0x1E0F, &[0x1E0E]),
+    (0x1E11, &[0x1E10]), (0x1E13, &[0x1E12]), (0x1E15, &[0x1E14]), (0x1E17, &[0x1E16]),
+    (0x1E19, &[0x1E18]), (0x1E1B, &[0x1E1A]), (0x1E1D, &[0x1E1C]), (0x1E1F, &[0x1E1E]),
+    (0x1E21, &[0x1E20]), (0x1E23, &[0x1E22]), (0x1E25, &[0x1E24]), (0x1E27, &[0x1E26]),
+    (0x1E29, &[0x1E28]), (0x1E2B, &[0x1E2A]), (0x1E2D, &[0x1E2C]), (0x1E2F, &[0x1E2E]),
+    (0x1E31, &[0x1E30]), (0x1E33, &[0x1E32]), (0x1E35, &[0x1E34]), (0x1E37, &[0x1E36]),
+    (0x1E39, &[0x1E38]), (0x1E3B, &[0x1E3A]), (0x1E3D, &[0x1E3C]), (0x1E3F, &[0x1E3E]),
+    (0x1E41, &[0x1E40]), (0x1E43, &[0x1E42]), (0x1E45, &[0x1E44]), (0x1E47, &[0x1E46]),
+    (0x1E49, &[0x1E48]), (0x1E4B, &[0x1E4A]), (0x1E4D, &[0x1E4C]), (0x1E4F, &[0x1E4E]),
+    (0x1E51, &[0x1E50]), (0x1E53, &[0x1E52]), (0x1E55, &[0x1E54]), (0x1E57, &[0x1E56]),
+    (0x1E59, &[0x1E58]), (0x1E5B, &[0x1E5A]), (0x1E5D, &[0x1E5C]), (0x1E5F, &[0x1E5E]),
+    (0x1E61, &[0x1E60]), (0x1E63, &[0x1E62]), (0x1E65, &[0x1E64]), (0x1E67, &[0x1E66]),
+    (0x1E69, &[0x1E68]), (0x1E6B, &[0x1E6A]), (0x1E6D, &[0x1E6C]), (0x1E6F, &[0x1E6E]),
+    (0x1E71, &[0x1E70]), (0x1E73, &[0x1E72]), (0x1E75, &[0x1E74]), (0x1E77, &[0x1E76]),
+    (0x1E79, &[0x1E78]), (0x1E7B, &[0x1E7A]), (0x1E7D, &[0x1E7C]), (0x1E7F, &[0x1E7E]),
+    (0x1E81, &[0x1E80]), (0x1E83, &[0x1E82]), (0x1E85, &[0x1E84]), (0x1E87, &[0x1E86]),
+    (0x1E89, &[0x1E88]), (0x1E8B, &[0x1E8A]), (0x1E8D, &[0x1E8C]), (0x1E8F, &[0x1E8E]),
+    (0x1E91, &[0x1E90]), (0x1E93, &[0x1E92]), (0x1E95, &[0x1E94]), (0x1E96, &[0x48, 0x331]),
+    (0x1E97, &[0x54, 0x308]), (0x1E98, &[0x57, 0x30A]), (0x1E99, &[0x59, 0x30A]), (0x1E9A, &[0x41, 0x2BE]),
+    (0x1E9B, &[0x1E60]), (0x1EA1, &[0x1EA0]), (0x1EA3, &[0x1EA2]), (0x1EA5, &[0x1EA4]),
+    (0x1EA7, &[0x1EA6]), (0x1EA9, &[0x1EA8]), (0x1EAB, &[0x1EAA]), (0x1EAD, &[0x1EAC]),
+    (0x1EAF, &[0x1EAE]), (0x1EB1, &[0x1EB0]), (0x1EB3, &[0x1EB2]), (0x1EB5, &[0x1EB4]),
+    (0x1EB7, &[0x1EB6]), (0x1EB9, &[0x1EB8]), (0x1EBB, &[0x1EBA]), (0x1EBD, &[0x1EBC]),
+    (0x1EBF, &[0x1EBE]), (0x1EC1, &[0x1EC0]), (0x1EC3, &[0x1EC2]), (0x1EC5, &[0x1EC4]),
+    (0x1EC7, &[0x1EC6]), (0x1EC9, &[0x1EC8]), (0x1ECB, &[0x1ECA]), (0x1ECD, &[0x1ECC]),
+    (0x1ECF, &[0x1ECE]), (0x1ED1, &[0x1ED0]), (0x1ED3, &[0x1ED2]), (0x1ED5, &[0x1ED4]),
+    (0x1ED7, &[0x1ED6]), (0x1ED9, &[0x1ED8]), (0x1EDB, &[0x1EDA]), (0x1EDD, &[0x1EDC]),
+    (0x1EDF, &[0x1EDE]), (0x1EE1, &[0x1EE0]), (0x1EE3, &[0x1EE2]), (0x1EE5, &[0x1EE4]),
+    (0x1EE7, &[0x1EE6]), (0x1EE9, &[0x1EE8]), (0x1EEB, &[0x1EEA]), (0x1EED, &[0x1EEC]),
+    (0x1EEF, &[0x1EEE]), (0x1EF1, &[0x1EF0]), (0x1EF3, &[0x1EF2]), (0x1EF5, &[0x1EF4]),
+    (0x1EF7, &[0x1EF6]), (0x1EF9, &[0x1EF8]), (0x1EFB, &[0x1EFA]), (0x1EFD, &[0x1EFC]),
+    (0x1EFF, &[0x1EFE]), (0x1F00, &[0x1F08]), (0x1F01, &[0x1F09]), (0x1F02, &[0x1F0A]),
+    (0x1F03, &[0x1F0B]), (0x1F04, &[0x1F0C]), (0x1F05, &[0x1F0D]), (0x1F06, &[0x1F0E]),
+    (0x1F07, &[0x1F0F]), (0x1F10, &[0x1F18]), (0x1F11, &[0x1F19]), (0x1F12, &[0x1F1A]),
+    (0x1F13, &[0x1F1B]), (0x1F14, &[0x1F1C]), (0x1F15, &[0x1F1D]), (0x1F20, &[0x1F28]),
+    (0x1F21, &[0x1F29]), (0x1F22, &[0x1F2A]), (0x1F23, &[0x1F2B]), (0x1F24, &[0x1F2C]),
+    (0x1F25, &[0x1F2D]), (0x1F26, &[0x1F2E]), (0x1F27, &[0x1F2F]), (0x1F30, &[0x1F38]),
+    (0x1F31, &[0x1F39]), (0x1F32, &[0x1F3A]), (0x1F33, &[0x1F3B]), (0x1F34, &[0x1F3C]),
+    (0x1F35, &[0x1F3D]), (0x1F36, &[0x1F3E]), (0x1F37, &[0x1F3F]), (0x1F40, &[0x1F48]),
+    (0x1F41, &[0x1F49]), (0x1F42, &[0x1F4A]), (0x1F43, &[0x1F4B]), (0x1F44, &[0x1F4C]),
+    (0x1F45, &[0x1F4D]), (0x1F50, &[0x3A5, 0x313]), (0x1F51, &[0x1F59]), (0x1F52, &[0x3A5, 0x313, 0x300]),
+    (0x1F53, &[0x1F5B]), (0x1F54, &[0x3A5, 0x313, 0x301]), (0x1F55, &[0x1F5D]), (0x1F56, &[0x3A5, 0x313, 0x342]),
+    (0x1F57, &[0x1F5F]), (0x1F60, &[0x1F68]), (0x1F61, &[0x1F69]), (0x1F62, &[0x1F6A]),
+    (0x1F63, &[0x1F6B]), (0x1F64, &[0x1F6C]), (0x1F65, &[0x1F6D]), (0x1F66, &[0x1F6E]),
+    (0x1F67, &[0x1F6F]), (0x1F70, &[0x1FBA]), (0x1F71, &[0x1FBB]), (0x1F72, &[0x1FC8]),
+    (0x1F73, &[0x1FC9]), (0x1F74, &[0x1FCA]), (0x1F75, &[0x1FCB]), (0x1F76, &[0x1FDA]),
+    (0x1F77, &[0x1FDB]), (0x1F78, &[0x1FF8]), (0x1F79, &[0x1FF9]), (0x1F7A, &[0x1FEA]),
+    (0x1F7B, &[0x1FEB]), (0x1F7C, &[0x1FFA]), (0x1F7D, &[0x1FFB]), (0x1F80, &[0x1F08, 0x399]),
+    (0x1F81, &[0x1F09, 0x399]), (0x1F82, &[0x1F0A, 0x399]), (0x1F83, &[0x1F0B, 0x399]), (0x1F84, &[0x1F0C, 0x399]),
+    (0x1F85, &[0x1F0D, 0x399]), (0x1F86, &[0x1F0E, 0x399]), (0x1F87, &[0x1F0F, 0x399]), (0x1F88, &[0x1F08, 0x399]),
+    (0x1F89, &[0x1F09, 0x399]), (0x1F8A, &[0x1F0A, 0x399]), (0x1F8B, &[0x1F0B, 0x399]), (0x1F8C, &[0x1F0C, 0x399]),
+    (0x1F8D, &[0x1F0D, 0x399]), (0x1F8E, &[0x1F0E, 0x399]), (0x1F8F, &[0x1F0F, 0x399]), (0x1F90, &[0x1F28, 0x399]),
+    (0x1F91, &[0x1F29, 0x399]), (0x1F92, &[0x1F2A, 0x399]), (0x1F93, &[0x1F2B, 0x399]), (0x1F94, &[0x1F2C, 0x399]),
+    (0x1F95, &[0x1F2D, 0x399]), (0x1F96, &[0x1F2E, 0x399]), (0x1F97, &[0x1F2F, 0x399]), (0x1F98, &[0x1F28, 0x399]),
+    (0x1F99, &[0x1F29, 0x399]), (0x1F9A, &[0x1F2A, 0x399]), (0x1F9B, &[0x1F2B, 0x399]), (0x1F9C, &[0x1F2C, 0x399]),
+    (0x1F9D, &[0x1F2D, 0x399]), (0x1F9E, &[0x1F2E, 0x399]), (0x1F9F, &[0x1F2F, 0x399]), (0x1FA0, &[0x1F68, 0x399]),
+    (0x1FA1, &[0x1F69, 0x399]), (0x1FA2, &[0x1F6A, 0x399]), (0x1FA3, &[0x1F6B, 0x399]), (0x1FA4, &[0x1F6C, 0x399]),
+    (0x1FA5, &[0x1F6D, 0x399]), (0x1FA6, &[0x1F6E, 0x399]), (0x1FA7, &[0x1F6F, 0x399]), (0x1FA8, &[0x1F68, 0x399]),
+    (0x1FA9, &[0x1F69, 0x399]), (0x1FAA, &[0x1F6A, 0x399]), (0x1FAB, &[0x1F6B, 0x399]), (0x1FAC, &[0x1F6C, 0x399]),
+    (0x1FAD, &[0x1F6D, 0x399]), (0x1FAE, &[0x1F6E, 0x399]), (0x1FAF, &[0x1F6F, 0x399]), (0x1FB0, &[0x1FB8]),
+    (0x1FB1, &[0x1FB9]), (0x1FB2, &[0x1FBA, 0x399]), (0x1FB3, &[0x391, 0x399]), (0x1FB4, &[0x386, 0x399]),
+    (0x1FB6, &[0x391, 0x342]), (0x1FB7, &[0x391, 0x342, 0x399]), (0x1FBC, &[0x391, 0x399]), (0x1FBE, &[0x399]),
+    (0x1FC2, &[0x1FCA, 0x399]), (0x1FC3, &[0x397, 0x399]), (0x1FC4, &[0x389, 0x399]), (0x1FC6, &[0x397, 0x342]),
+    (0x1FC7, &[0x397, 0x342, 0x399]), (0x1FCC, &[0x397, 0x399]), (0x1FD0, &[0x1FD8]), (0x1FD1, &[0x1FD9]),
+    (0x1FD2, &[0x399, 0x308, 0x300]), (0x1FD3, &[0x399, 0x308, 0x301]), (0x1FD6, &[0x399, 0x342]), (0x1FD7, &[0x399, 0x308, 0x342]),
+    (0x1FE0, &[0x1FE8]), (0x1FE1, &[0x1FE9]), (0x1FE2, &[0x3A5, 0x308, 0x300]), (0x1FE3, &[0x3A5, 0x308, 0x301]),
+    (0x1FE4, &[0x3A1, 0x313]), (0x1FE5, &[0x1FEC]), (0x1FE6, &[0x3A5, 0x342]), (0x1FE7, &[0x3A5, 0x308, 0x342]),
+    (0x1FF2, &[0x1FFA, 0x399]), (0x1FF3, &[0x3A9, 0x399]), (0x1FF4, &[0x38F, 0x399]), (0x1FF6, &[0x3A9, 0x342]),
+    (0x1FF7, &[0x3A9, 0x342, 0x399]), (0x1FFC, &[0x3A9, 0x399]), (0x214E, &[0x2132]), (0x2170, &[0x2160]),
+    (0x2171, &[0x2161]), (0x2172, &[0x2162]), (0x2173, &[0x2163]), (0x2174, &[0x2164]),
+    (0x2175, &[0x2165]), (0x2176, &[0x2166]), (0x2177, &[0x2167]), (0x2178, &[0x2168]),
+    (0x2179, &[0x2169]), (0x217A, &[0x216A]), (0x217B, &[0x216B]), (0x217C, &[0x216C]),
+    (0x217D, &[0x216D]), (0x217E, &[0x216E]), (0x217F, &[0x216F]), (0x2184, &[0x2183]),
+    (0x24D0, &[0x24B6]), (0x24D1, &[0x24B7]), (0x24D2, &[0x24B8]), (0x24D3, &[0x24B9]),
+    (0x24D4, &[0x24BA]), (0x24D5, &[0x24BB]), (0x24D6, &[0x24BC]), (0x24D7, &[0x24BD]),
+    (0x24D8, &[0x24BE]), (0x24D9, &[0x24BF]), (0x24DA, &[0x24C0]), (0x24DB, &[0x24C1]),
+    (0x24DC, &[0x24C2]), (0x24DD, &[0x24C3]), (0x24DE, &[0x24C4]), (0x24DF, &[0x24C5]),
+    (0x24E0, &[0x24C6]), (0x24E1, &[0x24C7]), (0x24E2, &[0x24C8]), (0x24E3, &[0x24C9]),
+    (0x24E4, &[0x24CA]), (0x24E5, &[0x24CB]), (0x24E6, &[0x24CC]), (0x24E7, &[0x24CD]),
+    (0x24E8, &[0x24CE]), (0x24E9, &[0x24CF]), (0x2C30, &[0x2C00]), (0x2C31, &[0x2C01]),
+    (0x2C32, &[0x2C02]), (0x2C33, &[0x2C03]), (0x2C34, &[0x2C04]), (0x2C35, &[0x2C05]),
+    (0x2C36, &[0x2C06]), (0x2C37, &[0x2C07]), (0x2C38, &[0x2C08]), (0x2C39, &[0x2C09]),
+    (0x2C3A, &[0x2C0A]), (0x2C3B, &[0x2C0B]), (0x2C3C, &[0x2C0C]), (0x2C3D, &[0x2C0D]),
+    (0x2C3E, &[0x2C0E]), (0x2C3F, &[0x2C0F]), (0x2C40, &[0x2C10]), (0x2C41, &[0x2C11]),
+    (0x2C42, &[0x2C12]), (0x2C43, &[0x2C13]), (0x2C44, &[0x2C14]), (0x2C45, &[0x2C15]),
+    (0x2C46, &[0x2C16]), (0x2C47, &[0x2C17]), (0x2C48, &[0x2C18]), (0x2C49, &[0x2C19]),
+    (0x2C4A, &[0x2C1A]), (0x2C4B, &[0x2C1B]), (0x2C4C, &[0x2C1C]), (0x2C4D, &[0x2C1D]),
+    (0x2C4E, &[0x2C1E]), (0x2C4F, &[0x2C1F]), (0x2C50, &[0x2C20]), (0x2C51, &[0x2C21]),
+    (0x2C52, &[0x2C22]), (0x2C53, &[0x2C23]), (0x2C54, &[0x2C24]), (0x2C55, &[0x2C25]),
+    (0x2C56, &[0x2C26]), (0x2C57, &[0x2C27]), (0x2C58, &[0x2C28]), (0x2C59, &[0x2C29]),
+    (0x2C5A, &[0x2C2A]), (0x2C5B, &[0x2C2B]), (0x2C5C, &[0x2C2C]), (0x2C5D, &[0x2C2D]),
+    (0x2C5E, &[0x2C2E]), (0x2C5F, &[0x2C2F]), (0x2C61, &[0x2C60]), (0x2C65, &[0x23A]),
+    (0x2C66, &[0x23E]), (0x2C68, &[0x2C67]), (0x2C6A, &[0x2C69]), (0x2C6C, &[0x2C6B]),
+    (0x2C73, &[0x2C72]), (0x2C76, &[0x2C75]), (0x2C81, &[0x2C80]), (0x2C83, &[0x2C82]),
+    (0x2C85, &[0x2C84]), (0x2C87, &[0x2C86]), (0x2C89, &[0x2C88]), (0x2C8B, &[0x2C8A]),
+    (0x2C8D, &[0x2C8C]), (0x2C8F, &[0x2C8E]), (0x2C91, &[0x2C90]), (0x2C93, &[0x2C92]),
+    (0x2C95, &[0x2C94]), (0x2C97, &[0x2C96]), (0x2C99, &[0x2C98]), (0x2C9B, &[0x2C9A]),
+    (0x2C9D, &[0x2C9C]), (0x2C9F, &[0x2C9E]), (0x2CA1, &[0x2CA0]), (0x2CA3, &[0x2CA2]),
+    (0x2CA5, &[0x2CA4]), (0x2CA7, &[0x2CA6]), (0x2CA9, &[0x2CA8]), (0x2CAB, &[0x2CAA]),
+    (0x2CAD, &[0x2CAC]), (0x2CAF, &[0x2CAE]), (0x2CB1, &[0x2CB0]), (0x2CB3, &[0x2CB2]),
+    (0x2CB5, &[0x2CB4]), (0x2CB7, &[0x2CB6]), (0x2CB9, &[0x2CB8]), (0x2CBB, &[0x2CBA]),
+    (0x2CBD, &[0x2CBC]), (0x2CBF, &[0x2CBE]), (0x2CC1, &[0x2CC0]), (0x2CC3, &[0x2CC2]),
+    (0x2CC5, &[0x2CC4]), (0x2CC7, &[0x2CC6]), (0x2CC9, &[0x2CC8]), (0x2CCB, &[0x2CCA]),
+    (0x2CCD, &[0x2CCC]), (0x2CCF, &[0x2CCE]), (0x2CD1, &[0x2CD0]), (0x2CD3, &[0x2CD2]),
+    (0x2CD5, &[0x2CD4]), (0x2CD7, &[0x2CD6]), (0x2CD9, &[0x2CD8]), (0x2CDB, &[0x2CDA]),
+    (0x2CDD, &[0x2CDC]), (0x2CDF, &[0x2CDE]), (0x2CE1, &[0x2CE0]), (0x2CE3, &[0x2CE2]),
+    (0x2CEC, &[0x2CEB]), (0x2CEE, &[0x2CED]), (0x2CF3, &[0x2CF2]), (0x2D00, &[0x10A0]),
+    (0x2D01, &[0x10A1]), (0x2D02, &[0x10A2]), (0x2D03, &[0x10A3]), (0x2D04, &[0x10A4]),
+    (0x2D05, &[0x10A5]), (0x2D06, &[0x10A6]), (0x2D07, &[0x10A7]), (0x2D08, &[0x10A8]),
+    (0x2D09, &[0x10A9]), (0x2D0A, &[0x10AA]), (0x2D0B, &[0x10AB]), (0x2D0C, &[0x10AC]),
+    (0x2D0D, &[0x10AD]), (0x2D0E, &[0x10AE]), (0x2D0F, &[0x10AF]), (0x2D10, &[0x10B0]),
+    (0x2D11, &[0x10B1]), (0x2D12, &[0x10B2]), (0x2D13, &[0x10B3]), (0x2D14, &[0x10B4]),
+    (0x2D15, &[0x10B5]), (0x2D16, &[0x10B6]), (0x2D17, &[0x10B7]), (0x2D18, &[0x10B8]),
+    (0x2D19, &[0x10B9]), (0x2D1A, &[0x10BA]), (0x2D1B, &[0x10BB]), (0x2D1C, &[0x10BC]),
+    (0x2D1D, &[0x10BD]), (0x2D1E, &[0x10BE]), (0x2D1F, &[0x10BF]), (0x2D20, &[0x10C0]),
+    (0x2D21, &[0x10C1]), (0x2D22, &[0x10C2]), (0x2D23, &[0x10C3]), (0x2D24, &[0x10C4]),
+    (0x2D25, &[0x10C5]), (0x2D27, &[0x10C7]), (0x2D2D, &[0x10CD]), (0xA641, &[0xA640]),
+    (0xA643, &[0xA642]), (0xA645, &[0xA644]), (0xA647, &[0xA646]), (0xA649, &[0xA648]),
+    (0xA64B, &[0xA64A]), (0xA64D, &[0xA64C]), (0xA64F, &[0xA64E]), (0xA651, &[0xA650]),
+    (0xA653, &[0xA652]), (0xA655, &[0xA654]), (0xA657, &[0xA656]), (0xA659, &[0xA658]),
+    (0xA65B, &[0xA65A]), (0xA65D, &[0xA65C]), (0xA65F, &[0xA65E]), (0xA661, &[0xA660]),
+    (0xA663, &[0xA662]), (0xA665, &[0xA664]), (0xA667, &[0xA666]), (0xA669, &[0xA668]),
+    (0xA66B, &[0xA66A]), (0xA66D, &[0xA66C]), (0xA681, &[0xA680]), (0xA683, &[0xA682]),
+    (0xA685, &[0xA684]), (0xA687, &[0xA686]), (0xA689, &[0xA688]), (0xA68B, &[0xA68A]),
+    (0xA68D, &[0xA68C]), (0xA68F, &[0xA68E]), (0xA691, &[0xA690]), (0xA693, &[0xA692]),
+    (0xA695, &[0xA694]), (0xA697, &[0xA696]), (0xA699, &[0xA698]), (0xA69B, &[0xA69A]),
+    (0xA723, &[0xA722]), (0xA725, &[0xA724]), (0xA727, &[0xA726]), (0xA729, &[0xA728]),
+    (0xA72B, &[0xA72A]), (0xA72D, &[0xA72C]), (0xA72F, &[0xA72E]), (0xA733, &[0xA732]),
+    (0xA735, &[0xA734]), (0xA737, &[0xA736]), (0xA739, &[0xA738]), (0xA73B, &[0xA73A]),
+    (0xA73D, &[0xA73C]), (0xA73F, &[0xA73E]), (0xA741, &[0xA740]), (0xA743, &[0xA742]),
+    (0xA745, &[0xA744]), (0xA747, &[0xA746]), (0xA749, &[0xA748]), (0xA74B, &[0xA74A]),
+    (0xA74D, &[0xA74C]), (0xA74F, &[0xA74E]), (0xA751, &[0xA750]), (0xA753, &[0xA752]),
+    (0xA755, &[0xA754]), (0xA757, &[0xA756]), (0xA759, &[0xA758]), (0xA75B, &[0xA75A]),
+    (0xA75D, &[0xA75C]), (0xA75F, &[0xA75E]), (0xA761, &[0xA760]), (0xA763, &[0xA762]),
+    (0xA765, &[0xA764]), (0xA767, &[0xA766]), (0xA769, &[0xA768]), (0xA76B, &[0xA76A]),
+    (0xA76D, &[0xA76C]), (0xA76F, &[0xA76E]), (0xA77A, &[0xA779]), (0xA77C, &[0xA77B]),
+    (0xA77F, &[0xA77E]), (0xA781, &[0xA780]), (0xA783, &[0xA782]), (0xA785, &[0xA784]),
+    (0xA787, &[0xA786]), (0xA78C, &[0xA78B]), (0xA791, &[0xA790]), (0xA793, &[0xA792]),
+    (0xA794, &[0xA7C4]), (0xA797, &[0xA796]), (0xA799, &[0xA798]), (0xA79B, &[0xA79A]),
+    (0xA79D, &[0xA79C]), (0xA79F, &[0xA79E]), (0xA7A1, &[0xA7A0]), (0xA7A3, &[0xA7A2]),
+    (0xA7A5, &[0xA7A4]), (0xA7A7, &[0xA7A6]), (0xA7A9, &[0xA7A8]), (0xA7B5, &[0xA7B4]),
+    (0xA7B7, &[0xA7B6]), (0xA7B9, &[0xA7B8]), (0xA7BB, &[0xA7BA]), (0xA7BD, &[0xA7BC]),
+    (0xA7BF, &[0xA7BE]), (0xA7C1, &[0xA7C0]), (0xA7C3, &[0xA7C2]), (0xA7C8, &[0xA7C7]),
+    (0xA7CA, &[0xA7C9]), (0xA7CD, &[0xA7CC]), (0xA7CF, &[0xA7CE]), (0xA7D1, &[0xA7D0]),
+    (0xA7D3, &[0xA7D2]), (0xA7D5, &[0xA7D4]), (0xA7D7, &[0xA7D6]), (0xA7D9, &[0xA7D8]),
+    (0xA7DB, &[0xA7DA]), (0xA7F6, &[0xA7F5]), (0xAB53, &[0xA7B3]), (0xAB70, &[0x13A0]),
+    (0xAB71, &[0x13A1]), (0xAB72, &[0x13A2]), (0xAB73, &[0x13A3]), (0xAB74, &[0x13A4]),
+    (0xAB75, &[0x13A5]), (0xAB76, &[0x13A6]), (0xAB77, &[0x13A7]), (0xAB78, &[0x13A8]),
+    (0xAB79, &[0x13A9]), (0xAB7A, &[0x13AA]), (0xAB7B, &[0x13AB]), (0xAB7C, &[0x13AC]),
+    (0xAB7D, &[0x13AD]), (0xAB7E, &[0x13AE]), (0xAB7F, &[0x13AF]), (0xAB80, &[0x13B0]),
+    (0xAB81, &[0x13B1]), (0xAB82, &[0x13B2]), (0xAB83, &[0x13B3]), (0xAB84, &[0x13B4]),
+    (0xAB85, &[0x13B5]), (0xAB86, &[0x13B6]), (0xAB87, &[0x13B7]), (0xAB88, &[0x13B8]),
+    (0xAB89, &[0x13B9]), (0xAB8A, &[0x13BA]), (0xAB8B, &[0x13BB]), (0xAB8C, &[0x13BC]),
+    (0xAB8D, &[0x13BD]), (0xAB8E, &[0x13BE]), (0xAB8F, &[0x13BF]), (0xAB90, &[0x13C0]),
+    (0xAB91, &[0x13C1]), (0xAB92, &[0x13C2]), (0xAB93, &[0x13C3]), (0xAB94, &[0x13C4]),
+    (0xAB95, &[0x13C5]), (0xAB96, &[0x13C6]), (0xAB97, &[0x13C7]), (0xAB98, &[0x13C8]),
+    (0xAB99, &[0x13C9]), (0xAB9A, &[0x13CA]), (0xAB9B, &[0x13CB]), (0xAB9C, &[0x13CC]),
+    (0xAB9D, &[0x13CD]), (0xAB9E, &[0x13CE]), (0xAB9F, &[0x13CF]), (0xABA0, &[0x13D0]),
+    (0xABA1, &[0x13D1]), (0xABA2, &[0x13D2]), (0xABA3, &[0x13D3]), (0xABA4, &[0x13D4]),
+    (0xABA5, &[0x13D5]), (0xABA6, &[0x13D6]), (0xABA7, &[0x13D7]), (0xABA8, &[0x13D8]),
+    (0xABA9, &[0x13D9]), (0xABAA, &[0x13DA]), (0xABAB, &[0x13DB]), (0xABAC, &[0x13DC]),
+    (0xABAD, &[0x13DD]), (0xABAE, &[0x13DE]), (0xABAF, &[0x13DF]), (0xABB0, &[0x13E0]),
+    (0xABB1, &[0x13E1]), (0xABB2, &[0x13E2]), (0xABB3, &[0x13E3]), (0xABB4, &[0x13E4]),
+    (0xABB5, &[0x13E5]), (0xABB6, &[0x13E6]), (0xABB7, &[0x13E7]), (0xABB8, &[0x13E8]),
+    (0xABB9, &[0x13E9]), (0xABBA, &[0x13EA]), (0xABBB, &[0x13EB]), (0xABBC, &[0x13EC]),
+    (0xABBD, &[0x13ED]), (0xABBE, &[0x13EE]), (0xABBF, &[0x13EF]), (0xFB00, &[0x46, 0x46]),
+    (0xFB01, &[0x46, 0x49]), (0xFB02, &[0x46, 0x4C]), (0xFB03, &[0x46, 0x46, 0x49]), (0xFB04, &[0x46, 0x46, 0x4C]),
+    (0xFB05, &[0x53, 0x54]), (0xFB06, &[0x53, 0x54]), (0xFB13, &[0x544, 0x546]), (0xFB14, &[0x544, 0x535]),
+    (0xFB15, &[0x544, 0x53B]), (0xFB16, &[0x54E, 0x546]), (0xFB17, &[0x544, 0x53D]), (0xFF41, &[0xFF21]),
+    (0xFF42, &[0xFF22]), (0xFF43, &[0xFF23]), (0xFF44, &[0xFF24]), (0xFF45, &[0xFF25]),
+    (0xFF46, &[0xFF26]), (0xFF47, &[0xFF27]), (0xFF48, &[0xFF28]), (0xFF49, &[0xFF29]),
+    (0xFF4A, &[0xFF2A]), (0xFF4B, &[0xFF2B]), (0xFF4C, &[0xFF2C]), (0xFF4D, &[0xFF2D]),
+    (0xFF4E, &[0xFF2E]), (0xFF4F, &[0xFF2F]), (0xFF50, &[0xFF30]), (0xFF51, &[0xFF31]),
+    (0xFF52, &[0xFF32]), (0xFF53, &[0xFF33]), (0xFF54, &[0xFF34]), (0xFF55, &[0xFF35]),
+    (0xFF56, &[0xFF36]), (0xFF57, &[0xFF37]), (0xFF58, &[0xFF38]), (0xFF59, &[0xFF39]),
+    (0xFF5A, &[0xFF3A]), (0x10428, &[0x10400]), (0x10429, &[0x10401]), (0x1042A, &[0x10402]),
+    (0x1042B, &[0x10403]), (0x1042C, &[0x10404]), (0x1042D, &[0x10405]), (0x1042E, &[0x10406]),
+    (0x1042F, &[0x10407]), (0x10430, &[0x10408]), (0x10431, &[0x10409]), (0x10432, &[0x1040A]),
+    (0x10433, &[0x1040B]), (0x10434, &[0x1040C]), (0x10435, &[0x1040D]), (0x10436, &[0x1040E]),
+    (0x10437, &[0x1040F]), (0x10438, &[0x10410]), (0x10439, &[0x10411]), (0x1043A, &[0x10412]),
+    (0x1043B, &[0x10413]), (0x1043C, &[0x10414]), (0x1043D, &[0x10415]), (0x1043E, &[0x10416]),
+    (0x1043F, &[0x10417]), (0x10440, &[0x10418]), (0x10441, &[0x10419]), (0x10442, &[0x1041A]),
+    (0x10443, &[0x1041B]), (0x10444, &[0x1041C]), (0x10445, &[0x1041D]), (0x10446, &[0x1041E]),
+    (0x10447, &[0x1041F]), (0x10448, &[0x10420]), (0x10449, &[0x10421]), (0x1044A, &[0x10422]),
+    (0x1044B, &[0x10423]), (0x1044C, &[0x10424]), (0x1044D, &[0x10425]), (0x1044E, &[0x10426]),
+    (0x1044F, &[0x10427]), (0x104D8, &[0x104B0]), (0x104D9, &[0x104B1]), (0x104DA, &[0x104B2]),
+    (0x104DB, &[0x104B3]), (0x104DC, &[0x104B4]), (0x104DD, &[0x104B5]), (0x104DE, &[0x104B6]),
+    (0x104DF, &[0x104B7]), (0x104E0, &[0x104B8]), (0x104E1, &[0x104B9]), (0x104E2, &[0x104BA]),
+    (0x104E3, &[0x104BB]), (0x104E4, &[0x104BC]), (0x104E5, &[0x104BD]), (0x104E6, &[0x104BE]),
+    (0x104E7, &[0x104BF]), (0x104E8, &[0x104C0]), (0x104E9, &[0x104C1]), (0x104EA, &[0x104C2]),
+    (0x104EB, &[0x104C3]), (0x104EC, &[0x104C4]), (0x104ED, &[0x104C5]), (0x104EE, &[0x104C6]),
+    (0x104EF, &[0x104C7]), (0x104F0, &[0x104C8]), (0x104F1, &[0x104C9]), (0x104F2, &[0x104CA]),
+    (0x104F3, &[0x104CB]), (0x104F4, &[0x104CC]), (0x104F5, &[0x104CD]), (0x104F6, &[0x104CE]),
+    (0x104F7, &[0x104CF]), (0x104F8, &[0x104D0]), (0x104F9, &[0x104D1]), (0x104FA, &[0x104D2]),
+    (0x104FB, &[0x104D3]), (0x10597, &[0x10570]), (0x10598, &[0x10571]), (0x10599, &[0x10572]),
+    (0x1059A, &[0x10573]), (0x1059B, &[0x10574]), (0x1059C, &[0x10575]), (0x1059D, &[0x10576]),
+    (0x1059E, &[0x10577]), (0x1059F, &[0x10578]), (0x105A0, &[0x10579]), (0x105A1, &[0x1057A]),
+    (0x105A3, &[0x1057C]), (0x105A4, &[0x1057D]), (0x105A5, &[0x1057E]), (0x105A6, &[0x1057F]),
+    (0x105A7, &[0x10580]), (0x105A8, &[0x10581]), (0x105A9, &[0x10582]), (0x105AA, &[0x10583]),
+    (0x105AB, &[0x10584]), (0x105AC, &[0x10585]), (0x105AD, &[0x10586]), (0x105AE, &[0x10587]),
+    (0x105AF, &[0x10588]), (0x105B0, &[0x10589]), (0x105B1, &[0x1058A]), (0x105B3, &[0x1058C]),
+    (0x105B4, &[0x1058D]), (0x105B5, &[0x1058E]), (0x105B6, &[0x1058F]), (0x105B7, &[0x10590]),
+    (0x105B8, &[0x10591]), (0x105B9, &[0x10592]), (0x105BB, &[0x10594]), (0x105BC, &[0x10595]),
+    (0x10CC0, &[0x10C80]), (0x10CC1, &[0x10C81]), (0x10CC2, &[0x10C82]), (0x10CC3, &[0x10C83]),
+    (0x10CC4, &[0x10C84]), (0x10CC5, &[0x10C85]), (0x10CC6, &[0x10C86]), (0x10CC7, &[0x10C87]),
+    (0x10CC8, &[0x10C88]), (0x10CC9, &[0x10C89]), (0x10CCA, &[0x10C8A]), (0x10CCB, &[0x10C8B]),
+    (0x10CCC, &[0x10C8C]), (0x10CCD, &[0x10C8D]), (0x10CCE, &[0x10C8E]), (0x10CCF, &[0x10C8F]),
+    (0x10CD0, &[0x10C90]), (0x10CD1, &[0x10C91]), (0x10CD2, &[0x10C92]), (0x10CD3, &[0x10C93]),
+    (0x10CD4, &[0x10C94]), (0x10CD5, &[0x10C95]), (0x10CD6, &[0x10C96]), (0x10CD7, &[0x10C97]),
+    (0x10CD8, &[0x10C98]), (0x10CD9, &[0x10C99]), (0x10CDA, &[0x10C9A]), (0x10CDB, &[0x10C9B]),
+    (0x10CDC, &[0x10C9C]), (0x10CDD, &[0x10C9D]), (0x10CDE, &[0x10C9E]), (0x10CDF, &[0x10C9F]),
+    (0x10CE0, &[0x10CA0]), (0x10CE1, &[0x10CA1]), (0x10CE2, &[0x10CA2]), (0x10CE3, &[0x10CA3]),
+    (0x10CE4, &[0x10CA4]), (0x10CE5, &[0x10CA5]), (0x10CE6, &[0x10CA6]), (0x10CE7, &[0x10CA7]),
+    (0x10CE8, &[0x10CA8]), (0x10CE9, &[0x10CA9]), (0x10CEA, &[0x10CAA]), (0x10CEB, &[0x10CAB]),
+    (0x10CEC, &[0x10CAC]), (0x10CED, &[0x10CAD]), (0x10CEE, &[0x10CAE]), (0x10CEF, &[0x10CAF]),
+    (0x10CF0, &[0x10CB0]), (0x10CF1, &[0x10CB1]), (0x10CF2, &[0x10CB2]), (0x10D70, &[0x10D50]),
+    (0x10D71, &[0x10D51]), (0x10D72, &[0x10D52]), (0x10D73, &[0x10D53]), (0x10D74, &[0x10D54]),
+    (0x10D75, &[0x10D55]), (0x10D76, &[0x10D56]), (0x10D77, &[0x10D57]), (0x10D78, &[0x10D58]),
+    (0x10D79, &[0x10D59]), (0x10D7A, &[0x10D5A]), (0x10D7B, &[0x10D5B]), (0x10D7C, &[0x10D5C]),
+    (0x10D7D, &[0x10D5D]), (0x10D7E, &[0x10D5E]), (0x10D7F, &[0x10D5F]), (0x10D80, &[0x10D60]),
+    (0x10D81, &[0x10D61]), (0x10D82, &[0x10D62]), (0x10D83, &[0x10D63]), (0x10D84, &[0x10D64]),
+    (0x10D85, &[0x10D65]), (0x118C0, &[0x118A0]), (0x118C1, &[0x118A1]), (0x118C2, &[0x118A2]),
+    (0x118C3, &[0x118A3]), (0x118C4, &[0x118A4]), (0x118C5, &[0x118A5]), (0x118C6, &[0x118A6]),
+    (0x118C7, &[0x118A7]), (0x118C8, &[0x118A8]), (0x118C9, &[0x118A9]), (0x118CA, &[0x118AA]),
+    (0x118CB, &[0x118AB]), (0x118CC, &[0x118AC]), (0x118CD, &[0x118AD]), (0x118CE, &[0x118AE]),
+    (0x118CF, &[0x118AF]), (0x118D0, &[0x118B0]), (0x118D1, &[0x118B1]), (0x118D2, &[0x118B2]),
+    (0x118D3, &[0x118B3]), (0x118D4, &[0x118B4]), (0x118D5, &[0x118B5]), (0x118D6, &[0x118B6]),
+    (0x118D7, &[0x118B7]), (0x118D8, &[0x118B8]), (0x118D9, &[0x118B9]), (0x118DA, &[0x118BA]),
+    (0x118DB, &[0x118BB]), (0x118DC, &[0x118BC]), (0x118DD, &[0x118BD]), (0x118DE, &[0x118BE]),
+    (0x118DF, &[0x118BF]), (0x16E60, &[0x16E40]), (0x16E61, &[0x16E41]), (0x16E62, &[0x16E42]),
+    (0x16E63, &[0x16E43]), (0x16E64, &[0x16E44]), (0x16E65, &[0x16E45]), (0x16E66, &[0x16E46]),
+    (0x16E67, &[0x16E47]), (0x16E68, &[0x16E48]), (0x16E69, &[0x16E49]), (0x16E6A, &[0x16E4A]),
+    (0x16E6B, &[0x16E4B]), (0x16E6C, &[0x16E4C]), (0x16E6D, &[0x16E4D]), (0x16E6E, &[0x16E4E]),
+    (0x16E6F, &[0x16E4F]), (0x16E70, &[0x16E50]), (0x16E71, &[0x16E51]), (0x16E72, &[0x16E52]),
+    (0x16E73, &[0x16E53]), (0x16E74, &[0x16E54]), (0x16E75, &[0x16E55]), (0x16E76, &[0x16E56]),
+    (0x16E77, &[0x16E57]), (0x16E78, &[0x16E58]), (0x16E79, &[0x16E59]), (0x16E7A, &[0x16E5A]),
+    (0x16E7B, &[0x16E5B]), (0x16E7C, &[0x16E5C]), (0x16E7D, &[0x16E5D]), (0x16E7E, &[0x16E5E]),
+    (0x16E7F, &[0x16E5F]), (0x16EBB, &[0x16EA0]), (0x16EBC, &[0x16EA1]), (0x16EBD, &[0x16EA2]),
+    (0x16EBE, &[0x16EA3]), (0x16EBF, &[0x16EA4]), (0x16EC0, &[0x16EA5]), (0x16EC1, &[0x16EA6]),
+    (0x16EC2, &[0x16EA7]), (0x16EC3, &[0x16EA8]), (0x16EC4, &[0x16EA9]), (0x16EC5, &[0x16EAA]),
+    (0x16EC6, &[0x16EAB]), (0x16EC7, &[0x16EAC]), (0x16EC8, &[0x16EAD]), (0x16EC9, &[0x16EAE]),
+    (0x16ECA, &[0x16EAF]), (0x16ECB, &[0x16EB0]), (0x16ECC, &[0x16EB1]), (0x16ECD, &[0x16EB2]),
+    (0x16ECE, &[0x16EB3]), (0x16ECF, &[0x16EB4]), (0x16ED0, &[0x16EB5]), (0x16ED1, &[0x16EB6]),
+    (0x16ED2, &[0x16EB7]), (0x16ED3, &[0x16EB8]), (0x1E922, &[0x1E900]), (0x1E923, &[0x1E901]),
+    (0x1E924, &[0x1E902]), (0x1E925, &[0x1E903]), (0x1E926, &[0x1E904]), (0x1E927, &[0x1E905]),
+    (0x1E928, &[0x1E906]), (0x1E929, &[0x1E907]), (0x1E92A, &[0x1E908]), (0x1E92B, &[0x1E909]),
+    (0x1E92C, &[0x1E90A]), (0x1E92D, &[0x1E90B]), (0x1E92E, &[0x1E90C]), (0x1E92F, &[0x1E90D]),
+    (0x1E930, &[0x1E90E]), (0x1E931, &[0x1E90F]), (0x1E932, &[0x1E910]), (0x1E933, &[0x1E911]),
+    (0x1E934, &[0x1E912]), (0x1E935, &[0x1E913]), (0x1E936, &[0x1E914]), (0x1E937, &[0x1E915]),
+    (0x1E938, &[0x1E916]), (0x1E939, &[0x1E917]), (0x1E93A, &[0x1E918]), (0x1E93B, &[0x1E919]),
+    (0x1E93C, &[0x1E91A]), (0x1E93D, &[0x1E91B]), (0x1E93E, &[0x1E91C]), (0x1E93F, &[0x1E91D]),
+    (0x1E940, &[0x1E91E]), (0x1E941, &[0x1E91F]), (0x1E942, &[0x1E920]), (0x1E943, &[0x1E921]),
+];
+
+/// The full lowercase mapping of every code point that does not map to itself, as `(code point, mapping)`,
+/// leaving out the contextual mapping of U+03A3 GREEK CAPITAL LETTER SIGMA at the end of a word.
+pub(super) static LOWERCASE_MAPPINGS: &[(u32, &[u32])] = &[
+    (0x41, &[0x61]), (0x42, &[0x62]), (0x43, &[0x63]), (0x44, &[0x64]),
+    (0x45, &[0x65]), (0x46, &[0x66]), (0x47, &[0x67]), (0x48, &[0x68]),
+    (0x49, &[0x69]), (0x4A, &[0x6A]), (0x4B, &[0x6B]), (0x4C, &[0x6C]),
+    (0x4D, &[0x6D]), (0x4E, &[0x6E]), (0x4F, &[0x6F]), (0x50, &[0x70]),
+    (0x51, &[0x71]), (0x52, &[0x72]), (0x53, &[0x73]), (0x54, &[0x74]),
+    (0x55, &[0x75]), (0x56, &[0x76]), (0x57, &[0x77]), (0x58, &[0x78]),
+    (0x59, &[0x79]), (0x5A, &[0x7A]), (0xC0, &[0xE0]), (0xC1, &[0xE1]),
+    (0xC2, &[0xE2]), (0xC3, &[0xE3]), (0xC4, &[0xE4]), (0xC5, &[0xE5]),
+    (0xC6, &[0xE6]), (0xC7, &[0xE7]), (0xC8, &[0xE8]), (0xC9, &[0xE9]),
+    (0xCA, &[0xEA]), (0xCB, &[0xEB]), (0xCC, &[0xEC]), (0xCD, &[0xED]),
+    (0xCE, &[0xEE]), (0xCF, &[0xEF]), (0xD0, &[0xF0]), (0xD1, &[0xF1]),
+    (0xD2, &[0xF2]), (0xD3, &[0xF3]), (0xD4, &[0xF4]), (0xD5, &[0xF5]),
+    (0xD6, &[0xF6]), (0xD8, &[0xF8]), (0xD9, &[0xF9]), (0xDA, &[0xFA]),
+    (0xDB, &[0xFB]), (0xDC, &[0xFC]), (0xDD, &[0xFD]), (0xDE, &[0xFE]),
+    (0x100, &[0x101]), (0x102, &[0x103]), (0x104, &[0x105]), (0x106, &[0x107]),
+    (0x108, &[0x109]), (0x10A, &[0x10B]), (0x10C, &[0x10D]), (0x10E, &[0x10F]),
+    (0x110, &[0x111]), (0x112, &[0x113]), (0x114, &[0x115]), (0x116, &[0x117]),
+    (0x118, &[0x119]), (0x11A, &[0x11B]), (0x11C, &[0x11D]), (0x11E, &[0x11F]),
+    (0x120, &[0x121]), (0x122, &[0x123]), (0x124, &[0x125]), (0x126, &[0x127]),
+    (0x128, &[0x129]), (0x12A, &[0x12B]), (0x12C, &[0x12D]), (0x12E, &[0x12F]),
+    (0x130, &[0x69, 0x307]), (0x132, &[0x133]), (0x134, &[0x135]), (0x136, &[0x137]),
+    (0x139, &[0x13A]), (0x13B, &[0x13C]), (0x13D, &[0x13E]), (0x13F, &[0x140]),
+    (0x141, &[0x142]), (0x143, &[0x144]), (0x145, &[0x146]), (0x147, &[0x148]),
+    (0x14A, &[0x14B]), (0x14C, &[0x14D]), (0x14E, &[0x14F]), (0x150, &[0x151]),
+    (0x152, &[0x153]), (0x154, &[0x155]), (0x156, &[0x157]), (0x158, &[0x159]),
+    (0x15A, &[0x15B]), (0x15C, &[0x15D]), (0x15E, &[0x15F]), (0x160, &[0x161]),
+    (0x162, &[0x163]), (0x164, &[0x165]), (0x166, &[0x167]), (0x168, &[0x169]),
+    (0x16A, &[0x16B]), (0x16C, &[0x16D]), (0x16E, &[0x16F]), (0x170, &[0x171]),
+    (0x172, &[0x173]), (0x174, &[0x175]), (0x176, &[0x177]), (0x178, &[0xFF]),
+    (0x179, &[0x17A]), (0x17B, &[0x17C]), (0x17D, &[0x17E]), (0x181, &[0x253]),
+    (0x182, &[0x183]), (0x184, &[0x185]), (0x186, &[0x254]), (0x187, &[0x188]),
+    (0x189, &[0x256]), (0x18A, &[0x257]), (0x18B, &[0x18C]), (0x18E, &[0x1DD]),
+    (0x18F, &[0x259]), (0x190, &[0x25B]), (0x191, &[0x192]), (0x193, &[0x260]),
+    (0x194, &[0x263]), (0x196, &[0x269]), (0x197, &[0x268]), (0x198, &[0x199]),
+    (0x19C, &[0x26F]), (0x19D, &[0x272]), (0x19F, &[0x275]), (0x1A0, &[0x1A1]),
+    (0x1A2, &[0x1A3]), (0x1A4, &[0x1A5]), (0x1A6, &[0x280]), (0x1A7, &[0x1A8]),
+    (0x1A9, &[0x283]), (0x1AC, &[0x1AD]), (0x1AE, &[0x288]), (0x1AF, &[0x1B0]),
+    (0x1B1, &[0x28A]), (0x1B2, &[0x28B]), (0x1B3, &[0x1B4]), (0x1B5, &[0x1B6]),
+    (0x1B7, &[0x292]), (0x1B8, &[0x1B9]), (0x1BC, &[0x1BD]), (0x1C4, &[0x1C6]),
+    (0x1C5, &[0x1C6]), (0x1C7, &[0x1C9]), (0x1C8, &[0x1C9]), (0x1CA, &[0x1CC]),
+    (0x1CB, &[0x1CC]), (0x1CD, &[0x1CE]), (0x1CF, &[0x1D0]), (0x1D1, &[0x1D2]),
+    (0x1D3, &[0x1D4]), (0x1D5, &[0x1D6]), (0x1D7, &[0x1D8]), (0x1D9, &[0x1DA]),
+    (0x1DB, &[0x1DC]), (0x1DE, &[0x1DF]), (0x1E0, &[0x1E1]), (0x1E2, &[0x1E3]),
+    (0x1E4, &[0x1E5]), (0x1E6, &[0x1E7]), (0x1E8, &[0x1E9]), (0x1EA, &[0x1EB]),
+    (0x1EC, &[0x1ED]), (0x1EE, &[0x1EF]), (0x1F1, &[0x1F3]), (0x1F2, &[0x1F3]),
+    (0x1F4, &[0x1F5]), (0x1F6, &[0x195]), (0x1F7, &[0x1BF]), (0x1F8, &[0x1F9]),
+    (0x1FA, &[0x1FB]), (0x1FC, &[0x1FD]), (0x1FE, &[0x1FF]), (0x200, &[0x201]),
+    (0x202, &[0x203]), (0x204, &[0x205]), (0x206, &[0x207]), (0x208, &[0x209]),
+    (0x20A, &[0x20B]), (0x20C, &[0x20D]), (0x20E, &[0x20F]), (0x210, &[0x211]),
+    (0x212, &[0x213]), (0x214, &[0x215]), (0x216, &[0x217]), (0x218, &[0x219]),
+    (0x21A, &[0x21B]), (0x21C, &[0x21D]), (0x21E, &[0x21F]), (0x220, &[0x19E]),
+    (0x222, &[0x223]), (0x224, &[0x225]), (0x226, &[0x227]), (0x228, &[0x229]),
+    (0x22A, &[0x22B]), (0x22C, &[0x22D]), (0x22E, &[0x22F]), (0x230, &[0x231]),
+    (0x232, &[0x233]), (0x23A, &[0x2C65]), (0x23B, &[0x23C]), (0x23D, &[0x19A]),
+    (0x23E, &[0x2C66]), (0x241, &[0x242]), (0x243, &[0x180]), (0x244, &[0x289]),
+    (0x245, &[0x28C]), (0x246, &[0x247]), (0x248, &[0x249]), (0x24A, &[0x24B]),
+    (0x24C, &[0x24D]), (0x24E, &[0x24F]), (0x370, &[0x371]), (0x372, &[0x373]),
+    (0x376, &[0x377]), (0x37F, &[0x3F3]), (0x386, &[0x3AC]), (0x388, &[0x3AD]),
+    (0x389, &[0x3AE]), (0x38A, &[0x3AF]), (0x38C, &[0x3CC]), (0x38E, &[0x3CD]),
+    (0x38F, &[0x3CE]), (0x391, &[0x3B1]), (0x392, &[0x3B2]), (0x393, &[0x3B3]),
+    (0x394, &[0x3B4]), (0x395, &[0x3B5]), (0x396, &[0x3B6]), (0x397, &[0x3B7]),
+    (0x398, &[0x3B8]), (0x399, &[0x3B9]), (0x39A, &[0x3BA]), (0x39B, &[0x3BB]),
+    (0x39C, &[0x3BC]), (0x39D, &[0x3BD]), (0x39E, &[0x3BE]), (0x39F, &[0x3BF]),
+    (0x3A0, &[0x3C0]), (0x3A1, &[0x3C1]), (0x3A3, &[0x3C3]), (0x3A4, &[0x3C4]),
+    (0x3A5, &[0x3C5]), (0x3A6, &[0x3C6]), (0x3A7, &[0x3C7]), (0x3A8, &[0x3C8]),
+    (0x3A9, &[0x3C9]), (0x3AA, &[0x3CA]), (0x3AB, &[0x3CB]), (0x3CF, &[0x3D7]),
+    (0x3D8, &[0x3D9]), (0x3DA, &[0x3DB]), (0x3DC, &[0x3DD]), (0x3DE, &[0x3DF]),
+    (0x3E0, &[0x3E1]), (0x3E2, &[0x3E3]), (0x3E4, &[0x3E5]), (0x3E6, &[0x3E7]),
+    (0x3E8, &[0x3E9]), (0x3EA, &[0x3EB]), (0x3EC, &[0x3ED]), (0x3EE, &[0x3EF]),
+    (0x3F4, &[0x3B8]), (0x3F7, &[0x3F8]), (0x3F9, &[0x3F2]), (0x3FA, &[0x3FB]),
+    (0x3FD, &[0x37B]), (0x3FE, &[0x37C]), (0x3FF, &[0x37D]), (0x400, &[0x450]),
+    (0x401, &[0x451]), (0x402, &[0x452]), (0x403, &[0x453]), (0x404, &[0x454]),
+    (0x405, &[0x455]), (0x406, &[0x456]), (0x407, &[0x457]), (0x408, &[0x458]),
+    (0x409, &[0x459]), (0x40A, &[0x45A]), (0x40B, &[0x45B]), (0x40C, &[0x45C]),
+    (0x40D, &[0x45D]), (0x40E, &[0x45E]), (0x40F, &[0x45F]), (0x410, &[0x430]),
+    (0x411, &[0x431]), (0x412, &[0x432]), (0x413, &[0x433]), (0x414, &[0x434]),
+    (0x415, &[0x435]), (0x416, &[0x436]), (0x417, &[0x437]), (0x418, &[0x438]),
+    (0x419, &[0x439]), (0x41A, &[0x43A]), (0x41B, &[0x43B]), (0x41C, &[0x43C]),
+    (0x41D, &[0x43D]), (0x41E, &[0x43E]), (0x41F, &[0x43F]), (0x420, &[0x440]),
+    (0x421, &[0x441]), (0x422, &[0x442]), (0x423, &[0x443]), (0x424, &[0x444]),
+    (0x425, &[0x445]), (0x426, &[0x446]), (0x427, &[0x447]), (0x428, &[0x448]),
+    (0x429, &[0x449]), (0x42A, &[0x44A]), (0x42B, &[0x44B]), (0x42C, &[0x44C]),
+    (0x42D, &[0x44D]), (0x42E, &[0x44E]), (0x42F, &[0x44F]), (0x460, &[0x461]),
+    (0x462, &[0x463]), (0x464, &[0x465]), (0x466, &[0x467]), (0x468, &[0x469]),
+    (0x46A, &[0x46B]), (0x46C, &[0x46D]), (0x46E, &[0x46F]), (0x470, &[0x471]),
+    (0x472, &[0x473]), (0x474, &[0x475]), (0x476, &[0x477]), (0x478, &[0x479]),
+    (0x47A, &[0x47B]), (0x47C, &[0x47D]), (0x47E, &[0x47F]), (0x480, &[0x481]),
+    (0x48A, &[0x48B]), (0x48C, &[0x48D]), (0x48E, &[0x48F]), (0x490, &[0x491]),
+    (0x492, &[0x493]), (0x494, &[0x495]), (0x496, &[0x497]), (0x498, &[0x499]),
+    (0x49A, &[0x49B]), (0x49C, &[0x49D]), (0x49E, &[0x49F]), (0x4A0, &[0x4A1]),
+    (0x4A2, &[0x4A3]), (0x4A4, &[0x4A5]), (0x4A6, &[0x4A7]), (0x4A8, &[0x4A9]),
+    (0x4AA, &[0x4AB]), (0x4AC, &[0x4AD]), (0x4AE, &[0x4AF]), (0x4B0, &[0x4B1]),
+    (0x4B2, &[0x4B3]), (0x4B4, &[0x4B5]), (0x4B6, &[0x4B7]), (0x4B8, &[0x4B9]),
+    (0x4BA, &[0x4BB]), (0x4BC, &[0x4BD]), (0x4BE, &[0x4BF]), (0x4C0, &[0x4CF]),
+    (0x4C1, &[0x4C2]), (0x4C3, &[0x4C4]), (0x4C5, &[0x4C6]), (0x4C7, &[0x4C8]),
+    (0x4C9, &[0x4CA]), (0x4CB, &[0x4CC]), (0x4CD, &[0x4CE]), (0x4D0, &[0x4D1]),
+    (0x4D2, &[0x4D3]), (0x4D4, &[0x4D5]), (0x4D6, &[0x4D7]), (0x4D8, &[0x4D9]),
+    (0x4DA, &[0x4DB]), (0x4DC, &[0x4DD]), (0x4DE, &[0x4DF]), (0x4E0, &[0x4E1]),
+    (0x4E2, &[0x4E3]), (0x4E4, &[0x4E5]), (0x4E6, &[0x4E7]), (0x4E8, &[0x4E9]),
+    (0x4EA, &[0x4EB]), (0x4EC, &[0x4ED]), (0x4EE, &[0x4EF]), (0x4F0, &[0x4F1]),
+    (0x4F2, &[0x4F3]), (0x4F4, &[0x4F5]), (0x4F6, &[0x4F7]), (0x4F8, &[0x4F9]),
+    (0x4FA, &[0x4FB]), (0x4FC, &[0x4FD]), (0x4FE, &[0x4FF]), (0x500, &[0x501]),
+    (0x502, &[0x503]), (0x504, &[0x505]), (0x506, &[0x507]), (0x508, &[0x509]),
+    (0x50A, &[0x50B]), (0x50C, &[0x50D]), (0x50E, &[0x50F]), (0x510, &[0x511]),
+    (0x512, &[0x513]), (0x514, &[0x515]), (0x516, &[0x517]), (0x518, &[0x519]),
+    (0x51A, &[0x51B]), (0x51C, &[0x51D]), (0x51E, &[0x51F]), (0x520, &[0x521]),
+    (0x522, &[0x523]), (0x524, &[0x525]), (0x526, &[0x527]), (0x528, &[0x529]),
+    (0x52A, &[0x52B]), (0x52C, &[0x52D]), (0x52E, &[0x52F]), (0x531, &[0x561]),
+    (0x532, &[0x562]), (0x533, &[0x563]), (0x534, &[0x564]), (0x535, &[0x565]),
+    (0x536, &[0x566]), (0x537, &[0x567]), (0x538, &[0x568]), (0x539, &[0x569]),
+    (0x53A, &[0x56A]), (0x53B, &[0x56B]), (0x53C, &[0x56C]), (0x53D, &[0x56D]),
+    (0x53E, &[0x56E]), (0x53F, &[0x56F]), (0x540, &[0x570]), (0x541, &[0x571]),
+    (0x542, &[0x572]), (0x543, &[0x573]), (0x544, &[0x574]), (0x545, &[0x575]),
+    (0x546, &[0x576]), (0x547, &[0x577]), (0x548, &[0x578]), (0x549, &[0x579]),
+    (0x54A, &[0x57A]), (0x54B, &[0x57B]), (0x54C, &[0x57C]), (0x54D, &[0x57D]),
+    (0x54E, &[0x57E]), (0x54F, &[0x57F]), (0x550, &[0x580]), (0x551, &[0x581]),
+    (0x552, &[0x582]), (0x553, &[0x583]), (0x554, &[0x584]), (0x555, &[0x585]),
+    (0x556, &[0x586]), (0x10A0, &[0x2D00]), (0x10A1, &[0x2D01]), (0x10A2, &[0x2D02]),
+    (0x10A3, &[0x2D03]), (0x10A4, &[0x2D04]), (0x10A5, &[0x2D05]), (0x10A6, &[0x2D06]),
+    (0x10A7, &[0x2D07]), (0x10A8, &[0x2D08]), (0x10A9, &[0x2D09]), (0x10AA, &[0x2D0A]),
+    (0x10AB, &[0x2D0B]), (0x10AC, &[0x2D0C]), (0x10AD, &[0x2D0D]), (0x10AE, &[0x2D0E]),
+    (0x10AF, &[0x2D0F]), (0x10B0, &[0x2D10]), (0x10B1, &[0x2D11]), (0x10B2, &[0x2D12]),
+    (0x10B3, &[0x2D13]), (0x10B4, &[0x2D14]), (0x10B5, &[0x2D15]), (0x10B6, &[0x2D16]),
+    (0x10B7, &[0x2D17]), (0x10B8, &[0x2D18]), (0x10B9, &[0x2D19]), (0x10BA, &[0x2D1A]),
+    (0x10BB, &[0x2D1B]), (0x10BC, &[0x2D1C]), (0x10BD, &[0x2D1D]), (0x10BE, &[0x2D1E]),
+    (0x10BF, &[0x2D1F]), (0x10C0, &[0x2D20]), (0x10C1, &[0x2D21]), (0x10C2, &[0x2D22]),
+    (0x10C3, &[0x2D23]), (0x10C4, &[0x2D24]), (0x10C5, &[0x2D25]), (0x10C7, &[0x2D27]),
+    (0x10CD, &[0x2D2D]), (0x13A0, &[0xAB70]), (0x13A1, &[0xAB71]), (0x13A2, &[0xAB72]),
+    (0x13A3, &[0xAB73]), (0x13A4, &[0xAB74]), (0x13A5, &[0xAB75]), (0x13A6, &[0xAB76]),
+    (0x13A7, &[0xAB77]), (0x13A8, &[0xAB78]), (0x13A9, &[0xAB79]), (0x13AA, &[0xAB7A]),
+    (0x13AB, &[0xAB7B]), (0x13AC, &[0xAB7C]), (0x13AD, &[0xAB7D]), (0x13AE, &[0xAB7E]),
+    (0x13AF, &[0xAB7F]), (0x13B0, &[0xAB80]), (0x13B1, &[0xAB81]), (0x13B2, &[0xAB82]),
+    (0x13B3, &[0xAB83]), (0x13B4, &[0xAB84]), (0x13B5, &[0xAB85]), (0x13B6, &[0xAB86]),
+    (0x13B7, &[0xAB87]), (0x13B8, &[0xAB88]), (0x13B9, &[0xAB89]), (0x13BA, &[0xAB8A]),
+    (0x13BB, &[0xAB8B]), (0x13BC, &[0xAB8C]), (0x13BD, &[0xAB8D]), (0x13BE, &[0xAB8E]),
+    (0x13BF, &[0xAB8F]), (0x13C0, &[0xAB90]), (0x13C1, &[0xAB91]), (0x13C2, &[0xAB92]),
+    (0x13C3, &[0xAB93]), (0x13C4, &[0xAB94]), (0x13C5, &[0xAB95]), (0x13C6, &[0xAB96]),
+    (0x13C7, &[0xAB97]), (0x13C8, &[0xAB98]), (0x13C9, &[0xAB99]), (0x13CA, &[0xAB9A]),
+    (0x13CB, &[0xAB9B]), (0x13CC, &[0xAB9C]), (0x13CD, &[0xAB9D]), (0x13CE, &[0xAB9E]),
+    (0x13CF, &[0xAB9F]), (0x13D0, &[0xABA0]), (0x13D1, &[0xABA1]), (0x13D2, &[0xABA2]),
+    (0x13D3, &[0xABA3]), (0x13D4, &[0xABA4]), (0x13D5, &[0xABA5]), (0x13D6, &[0xABA6]),
+    (0x13D7, &[0xABA7]), (0x13D8, &[0xABA8]), (0x13D9, &[0xABA9]), (0x13DA, &[0xABAA]),
+    (0x13DB, &[0xABAB]), (0x13DC, &[0xABAC]), (0x13DD, &[0xABAD]), (0x13DE, &[0xABAE]),
+    (0x13DF, &[0xABAF]), (0x13E0, &[0xABB0]), (0x13E1, &[0xABB1]), (0x13E2, &[0xABB2]),
+    (0x13E3, &[0xABB3]), (0x13E4, &[0xABB4]), (0x13E5, &[0xABB5]), (0x13E6, &[0xABB6]),
+    (0x13E7, &[0xABB7]), (0x13E8, &[0xABB8]), (0x13E9, &[0xABB9]), (0x13EA, &[0xABBA]),
+    (0x13EB, &[0xABBB]), (0x13EC, &[0xABBC]), (0x13ED, &[0xABBD]), (0x13EE, &[0xABBE]),
+    (0x13EF, &[0xABBF]), (0x13F0, &[0x13F8]), (0x13F1, &[0x13F9]), (0x13F2, &[0x13FA]),
+    (0x13F3, &[0x13FB]), (0x13F4, &[0x13FC]), (0x13F5, &[0x13FD]), (0x1C89, &[0x1C8A]),
+    (0x1C90, &[0x10D0]), (0x1C91, &[0x10D1]), (0x1C92, &[0x10D2]), (0x1C93, &[0x10D3]),
+    (0x1C94, &[0x10D4]), (0x1C95, &[0x10D5]), (0x1C96, &[0x10D6]), (0x1C97, &[0x10D7]),
+    (0x1C98, &[0x10D8]), (0x1C99, &[0x10D9]), (0x1C9A, &[0x10DA]), (0x1C9B, &[0x10DB]),
+    (0x1C9C, &[0x10DC]), (0x1C9D, &[0x10DD]), (0x1C9E, &[0x10DE]), (0x1C9F, &[0x10DF]),
+    (0x1CA0, &[0x10E0]), (0x1CA1, &[0x10E1]), (0x1CA2, &[0x10E2]), (0x1CA3, &[0x10E3]),
+    (0x1CA4, &[0x10E4]), (0x1CA5, &[0x10E5]), (0x1CA6, &[0x10E6]), (0x1CA7, &[0x10E7]),
+    (0x1CA8, &[0x10E8]), (0x1CA9, &[0x10E9]), (0x1CAA, &[0x10EA]), (0x1CAB, &[0x10EB]),
+    (0x1CAC, &[0x10EC]), (0x1CAD, &[0x10ED]), (0x1CAE, &[0x10EE]), (0x1CAF, &[0x10EF]),
+    (0x1CB0, &[0x10F0]), (0x1CB1, &[0x10F1]), (0x1CB2, &[0x10F2]), (0x1CB3, &[0x10F3]),
+    (0x1CB4, &[0x10F4]), (0x1CB5, &[0x10F5]), (0x1CB6, &[0x10F6]), (0x1CB7, &[0x10F7]),
+    (0x1CB8, &[0x10F8]), (0x1CB9, &[0x10F9]), (0x1CBA, &[0x10FA]), (0x1CBD, &[0x10FD]),
+    (0x1CBE, &[0x10FE]), (0x1CBF, &[0x10FF]), (0x1E00, &[0x1E01]), (0x1E02, &[0x1E03]),
+    (0x1E04, &[0x1E05]), (0x1E06, &[0x1E07]), (0x1E08, &[0x1E09]), (0x1E0A, &[0x1E0B]),
+    (0x1E0C, &[0x1E0D]), (0x1E0E, &[0x1E0F]), (0x1E10, &[0x1E11]), (0x1E12, &[0x1E13]),
+    (0x1E14, &[0x1E15]), (0x1E16, &[0x1E17]), (0x1E18, &[0x1E19]), (0x1E1A, &[0x1E1B]),
+    (0x1E1C, &[0x1E1D]), (0x1E1E, &[0x1E1F]), (0x1E20, &[0x1E21]), (0x1E22, &[0x1E23]),
+    (0x1E24, &[0x1E25]), (0x1E26, &[0x1E27]), (0x1E28, &[0x1E29]), (0x1E2A, &[0x1E2B]),
+    (0x1E2C, &[0x1E2D]), (0x1E2E, &[0x1E2F]), (0x1E30, &[0x1E31]), (0x1E32, &[0x1E33]),
+    (0x1E34, &[0x1E35]), (0x1E36, &[0x1E37]), (0x1E38, &[0x1E39]), (0x1E3A, &[0x1E3B]),
+    (0x1E3C, &[0x1E3D]), (0x1E3E, &[0x1E3F]), (0x1E40, &[0x1E41]), (0x1E42, &[0x1E43]),
+    (0x1E44, &[0x1E45]), (0x1E46, &[0x1E47]), (0x1E48, &[0x1E49]), (0x1E4A, &[0x1E4B]),
+    (0x1E4C, &[0x1E4D]), (0x1E4E, &[0x1E4F]), (0x1E50, &[0x1E51]), (0x1E52, &[0x1E53]),
+    (0x1E54, &[0x1E55]), (0x1E56, &[0x1E57]), (0x1E58, &[0x1E59]), (0x1E5A, &[0x1E5B]),
+    (0x1E5C, &[0x1E5D]), (0x1E5E, &[0x1E5F]), (0x1E60, &[0x1E61]), (0x1E62, &[0x1E63]),
+    (0x1E64, &[0x1E65]), (0x1E66, &[0x1E67]), (0x1E68, &[0x1E69]), (0x1E6A, &[0x1E6B]),
+    (0x1E6C, &[0x1E6D]), (0x1E6E, &[0x1E6F]), (0x1E70, &[0x1E71]), (0x1E72, &[0x1E73]),
+    (0x1E74, &[0x1E75]), (0x1E76, &[0x1E77]), (0x1E78, &[0x1E79]), (0x1E7A, &[0x1E7B]),
+    (0x1E7C, &[0x1E7D]), (0x1E7E, &[0x1E7F]), (0x1E80, &[0x1E81]), (0x1E82, &[0x1E83]),
+    (0x1E84, &[0x1E85]), (0x1E86, &[0x1E87]), (0x1E88, &[0x1E89]), (0x1E8A, &[0x1E8B]),
+    (0x1E8C, &[0x1E8D]), (0x1E8E, &[0x1E8F]), (0x1E90, &[0x1E91]), (0x1E92, &[0x1E93]),
+    (0x1E94, &[0x1E95]), (0x1E9E, &[0xDF]), (0x1EA0, &[0x1EA1]), (0x1EA2, &[0x1EA3]),
+    (0x1EA4, &[0x1EA5]), (0x1EA6, &[0x1EA7]), (0x1EA8, &[0x1EA9]), (0x1EAA, &[0x1EAB]),
+    (0x1EAC, &[0x1EAD]), (0x1EAE, &[0x1EAF]), (0x1EB0, &[0x1EB1]), (0x1EB2, &[0x1EB3]),
+    (0x1EB4, &[0x1EB5]), (0x1EB6, &[0x1EB7]), (0x1EB8, &[0x1EB9]), (0x1EBA, &[0x1EBB]),
+    (0x1EBC, &[0x1EBD]), (0x1EBE, &[0x1EBF]), (0x1EC0, &[0x1EC1]), (0x1EC2, &[0x1EC3]),
+    (0x1EC4, &[0x1EC5]), (0x1EC6, &[0x1EC7]), (0x1EC8, &[0x1EC9]), (0x1ECA, &[0x1ECB]),
+    (0x1ECC, &[0x1ECD]), (0x1ECE, &[0x1ECF]), (0x1ED0, &[0x1ED1]), (0x1ED2, &[0x1ED3]),
+    (0x1ED4, &[0x1ED5]), (0x1ED6, &[0x1ED7]), (0x1ED8, &[0x1ED9]), (0x1EDA, &[0x1EDB]),
+    (0x1EDC, &[0x1EDD]), (0x1EDE, &[0x1EDF]), (0x1EE0, &[0x1EE1]), (0x1EE2, &[0x1EE3]),
+    (0x1EE4, &[0x1EE5]), (0x1EE6, &[0x1EE7]), (0x1EE8, &[0x1EE9]), (0x1EEA, &[0x1EEB]),
+    (0x1EEC, &[0x1EED]), (0x1EEE, &[0x1EEF]), (0x1EF0, &[0x1EF1]), (0x1EF2, &[0x1EF3]),
+    (0x1EF4, &[0x1EF5]), (0x1EF6, &[0x1EF7]), (0x1EF8, &[0x1EF9]), (0x1EFA, &[0x1EFB]),
+    (0x1EFC, &[0x1EFD]), (0x1EFE, &[0x1EFF]), (0x1F08, &[0x1F00]), (0x1F09, &[0x1F01]),
+    (0x1F0A, &[0x1F02]), (0x1F0B, &[0x1F03]), (0x1F0C, &[0x1F04]), (0x1F0D, &[0x1F05]),
+    (0x1F0E, &[0x1F06]), (0x1F0F, &[0x1F07]), (0x1F18, &[0x1F10]), (0x1F19, &[0x1F11]),
+    (0x1F1A, &[0x1F12]), (0x1F1B, &[0x1F13]), (0x1F1C, &[0x1F14]), (0x1F1D, &[0x1F15]),
+    (0x1F28, &[0x1F20]), (0x1F29, &[0x1F21]), (0x1F2A, &[0x1F22]), (0x1F2B, &[0x1F23]),
+    (0x1F2C, &[0x1F24]), (0x1F2D, &[0x1F25]), (0x1F2E, &[0x1F26]), (0x1F2F, &[0x1F27]),
+    (0x1F38, &[0x1F30]), (0x1F39, &[0x1F31]), (0x1F3A, &[0x1F32]), (0x1F3B, &[0x1F33]),
+    (0x1F3C, &[0x1F34]), (0x1F3D, &[0x1F35]), (0x1F3E, &[0x1F36]), (0x1F3F, &[0x1F37]),
+    (0x1F48, &[0x1F40]), (0x1F49, &[0x1F41]), (0x1F4A, &[0x1F42]), (0x1F4B, &[0x1F43]),
+    (0x1F4C, &[0x1F44]), (0x1F4D, &[0x1F45]), (0x1F59, &[0x1F51]), (0x1F5B, &[0x1F53]),
+    (0x1F5D, &[0x1F55]), (0x1F5F, &[0x1F57]), (0x1F68, &[0x1F60]), (0x1F69, &[0x1F61]),
+    (0x1F6A, &[0x1F62]), (0x1F6B, &[0x1F63]), (0x1F6C, &[0x1F64]), (0x1F6D, &[0x1F65]),
+    (0x1F6E, &[0x1F66]), (0x1F6F, &[0x1F67]), (0x1F88, &[0x1F80]), (0x1F89, &[0x1F81]),
+    (0x1F8A, &[0x1F82]), (0x1F8B, &[0x1F83]), (0x1F8C, &[0x1F84]), (0x1F8D, &[0x1F85]),
+    (0x1F8E, &[0x1F86]), (0x1F8F, &[0x1F87]), (0x1F98, &[0x1F90]), (0x1F99, &[0x1F91]),
+    (0x1F9A, &[0x1F92]), (0x1F9B, &[0x1F93]), (0x1F9C, &[0x1F94]), (0x1F9D, &[0x1F95]),
+    (0x1F9E, &[0x1F96]), (0x1F9F, &[0x1F97]), (0x1FA8, &[0x1FA0]), (0x1FA9, &[0x1FA1]),
+    (0x1FAA, &[0x1FA2]), (0x1FAB, &[0x1FA3]), (0x1FAC, &[0x1FA4]), (0x1FAD, &[0x1FA5]),
+    (0x1FAE, &[0x1FA6]), (0x1FAF, &[0x1FA7]), (0x1FB8, &[0x1FB0]), (0x1FB9, &[0x1FB1]),
+    (0x1FBA, &[0x1F70]), (0x1FBB, &[0x1F71]), (0x1FBC, &[0x1FB3]), (0x1FC8, &[0x1F72]),
+    (0x1FC9, &[0x1F73]), (0x1FCA, &[0x1F74]), (0x1FCB, &[0x1F75]), (0x1FCC, &[0x1FC3]),
+    (0x1FD8, &[0x1FD0]), (0x1FD9, &[0x1FD1]), (0x1FDA, &[0x1F76]), (0x1FDB, &[0x1F77]),
+    (0x1FE8, &[0x1FE0]), (0x1FE9, &[0x1FE1]), (0x1FEA, &[0x1F7A]), (0x1FEB, &[0x1F7B]),
+    (0x1FEC, &[0x1FE5]), (0x1FF8, &[0x1F78]), (0x1FF9, &[0x1F79]), (0x1FFA, &[0x1F7C]),
+    (0x1FFB, &[0x1F7D]), (0x1FFC, &[0x1FF3]), (0x2126, &[0x3C9]), (0x212A, &[0x6B]),
+    (0x212B, &[0xE5]), (0x2132, &[0x214E]), (0x2160, &[0x2170]), (0x2161, &[0x2171]),
+    (0x2162, &[0x2172]), (0x2163, &[0x2173]), (0x2164, &[0x2174]), (0x2165, &[0x2175]),
+    (0x2166, &[0x2176]), (0x2167, &[0x2177]), (0x2168, &[0x2178]), (0x2169, &[0x2179]),
+    (0x216A, &[0x217A]), (0x216B, &[0x217B]), (0x216C, &[0x217C]), (0x216D, &[0x217D]),
+    (0x216E, &[0x217E]), (0x216F, &[0x217F]), (0x2183, &[0x2184]), (0x24B6, &[0x24D0]),
+    (0x24B7, &[0x24D1]), (0x24B8, &[0x24D2]), (0x24B9, &[0x24D3]), (0x24BA, &[0x24D4]),
+    (0x24BB, &[0x24D5]), (0x24BC, &[0x24D6]), (0x24BD, &[0x24D7]), (0x24BE, &[0x24D8]),
+    (0x24BF, &[0x24D9]), (0x24C0, &[0x24DA]), (0x24C1, &[0x24DB]), (0x24C2, &[0x24DC]),
+    (0x24C3, &[0x24DD]), (0x24C4, &[0x24DE]), (0x24C5, &[0x24DF]), (0x24C6, &[0x24E0]),
+    (0x24C7, &[0x24E1]), (0x24C8, &[0x24E2]), (0x24C9, &[0x24E3]), (0x24CA, &[0x24E4]),
+    (0x24CB, &[0x24E5]), (0x24CC, &[0x24E6]), (0x24CD, &[0x24E7]), (0x24CE, &[0x24E8]),
+    (0x24CF, &[0x24E9]), (0x2C00, &[0x2C30]), (0x2C01, &[0x2C31]), (0x2C02, &[0x2C32]),
+    (0x2C03, &[0x2C33]), (0x2C04, &[0x2C34]), (0x2C05, &[0x2C35]), (0x2C06, &[0x2C36]),
+    (0x2C07, &[0x2C37]), (0x2C08, &[0x2C38]), (0x2C09, &[0x2C39]), (0x2C0A, &[0x2C3A]),
+    (0x2C0B, &[0x2C3B]), (0x2C0C, &[0x2C3C]), (0x2C0D, &[0x2C3D]), (0x2C0E, &[0x2C3E]),
+    (0x2C0F, &[0x2C3F]), (0x2C10, &[0x2C40]), (0x2C11, &[0x2C41]), (0x2C12, &[0x2C42]),
+    (0x2C13, &[0x2C43]), (0x2C14, &[0x2C44]), (0x2C15, &[0x2C45]), (0x2C16, &[0x2C46]),
+    (0x2C17, &[0x2C47]), (0x2C18, &[0x2C48]), (0x2C19, &[0x2C49]), (0x2C1A, &[0x2C4A]),
+    (0x2C1B, &[0x2C4B]), (0x2C1C, &[0x2C4C]), (0x2C1D, &[0x2C4D]), (0x2C1E, &[0x2C4E]),
+    (0x2C1F, &[0x2C4F]), (0x2C20, &[0x2C50]), (0x2C21, &[0x2C51]), (0x2C22, &[0x2C52]),
+    (0x2C23, &[0x2C53]), (0x2C24, &[0x2C54]), (0x2C25, &[0x2C55]), (0x2C26, &[0x2C56]),
+    (0x2C27, &[0x2C57]), (0x2C28, &[0x2C58]), (0x2C29, &[0x2C59]), (0x2C2A, &[0x2C5A]),
+    (0x2C2B, &[0x2C5B]), (0x2C2C, &[0x2C5C]), (0x2C2D, &[0x2C5D]), (0x2C2E, &[0x2C5E]),
+    (0x2C2F, &[0x2C5F]), (0x2C60, &[0x2C61]), (0x2C62, &[0x26B]), (0x2C63, &[0x1D7D]),
+    (0x2C64, &[0x27D]), (0x2C67, &[0x2C68]), (0x2C69, &[0x2C6A]), (0x2C6B, &[0x2C6C]),
+    (0x2C6D, &[0x251]), (0x2C6E, &[0x271]), (0x2C6F, &[0x250]), (0x2C70, &[0x252]),
+    (0x2C72, &[0x2C73]), (0x2C75, &[0x2C76]), (0x2C7E, &[0x23F]), (0x2C7F, &[0x240]),
+    (0x2C80, &[0x2C81]), (0x2C82, &[0x2C83]), (0x2C84, &[0x2C85]), (0x2C86, &[0x2C87]),
+    (0x2C88, &[0x2C89]), (0x2C8A, &[0x2C8B]), (0x2C8C, &[0x2C8D]), (0x2C8E, &[0x2C8F]),
+    (0x2C90, &[0x2C91]), (0x2C92, &[0x2C93]), (0x2C94, &[0x2C95]), (0x2C96, &[0x2C97]),
+    (0x2C98, &[0x2C99]), (0x2C9A, &[0x2C9B]), (0x2C9C, &[0x2C9D]), (0x2C9E, &[0x2C9F]),
+    (0x2CA0, &[0x2CA1]), (0x2CA2, &[0x2CA3]), (0x2CA4, &[0x2CA5]), (0x2CA6, &[0x2CA7]),
+    (0x2CA8, &[0x2CA9]), (0x2CAA, &[0x2CAB]), (0x2CAC, &[0x2CAD]), (0x2CAE, &[0x2CAF]),
+    (0x2CB0, &[0x2CB1]), (0x2CB2, &[0x2CB3]), (0x2CB4, &[0x2CB5]), (0x2CB6, &[0x2CB7]),
+    (0x2CB8, &[0x2CB9]), (0x2CBA, &[0x2CBB]), (0x2CBC, &[0x2CBD]), (0x2CBE, &[0x2CBF]),
+    (0x2CC0, &[0x2CC1]), (0x2CC2, &[0x2CC3]), (0x2CC4, &[0x2CC5]), (0x2CC6, &[0x2CC7]),
+    (0x2CC8, &[0x2CC9]), (0x2CCA, &[0x2CCB]), (0x2CCC, &[0x2CCD]), (0x2CCE, &[0x2CCF]),
+    (0x2CD0, &[0x2CD1]), (0x2CD2, &[0x2CD3]), (0x2CD4, &[0x2CD5]), (0x2CD6, &[0x2CD7]),
+    (0x2CD8, &[0x2CD9]), (0x2CDA, &[0x2CDB]), (0x2CDC, &[0x2CDD]), (0x2CDE, &[0x2CDF]),
+    (0x2CE0, &[0x2CE1]), (0x2CE2, &[0x2CE3]), (0x2CEB, &[0x2CEC]), (0x2CED, &[0x2CEE]),
+    (0x2CF2, &[0x2CF3]), (0xA640, &[0xA641]), (0xA642, &[0xA643]), (0xA644, &[0xA645]),
+    (0xA646, &[0xA647]), (0xA648, &[0xA649]), (0xA64A, &[0xA64B]), (0xA64C, &[0xA64D]),
+    (0xA64E, &[0xA64F]), (0xA650, &[0xA651]), (0xA652, &[0xA653]), (0xA654, &[0xA655]),
+    (0xA656, &[0xA657]), (0xA658, &[0xA659]), (0xA65A, &[0xA65B]), (0xA65C, &[0xA65D]),
+    (0xA65E, &[0xA65F]), (0xA660, &[0xA661]), (0xA662, &[0xA663]), (0xA664, &[0xA665]),
+    (0xA666, &[0xA667]), (0xA668, &[0xA669]), (0xA66A, &[0xA66B]), (0xA66C, &[0xA66D]),
+    (0xA680, &[0xA681]), (0xA682, &[0xA683]), (0xA684, &[0xA685]), (0xA686, &[0xA687]),
+    (0xA688, &[0xA689]), (0xA68A, &[0xA68B]), (0xA68C, &[0xA68D]), (0xA68E, &[0xA68F]),
+    (0xA690, &[0xA691]), (0xA692, &[0xA693]), (0xA694, &[0xA695]), (0xA696, &[0xA697]),
+    (0xA698, &[0xA699]), (0xA69A, &[0xA69B]), (0xA722, &[0xA723]), (0xA724, &[0xA725]),
+    (0xA726, &[0xA727]), (0xA728, &[0xA729]), (0xA72A, &[0xA72B]), (0xA72C, &[0xA72D]),
+    (0xA72E, &[0xA72F]), (0xA732, &[0xA733]), (0xA734, &[0xA735]), (0xA736, &[0xA737]),
+    (0xA738, &[0xA739]), (0xA73A, &[0xA73B]), (0xA73C, &[0xA73D]), (0xA73E, &[0xA73F]),
+    (0xA740, &[0xA741]), (0xA742, &[0xA743]), (0xA744, &[0xA745]), (0xA746, &[0xA747]),
+    (0xA748, &[0xA749]), (0xA74A, &[0xA74B]), (0xA74C, &[0xA74D]), (0xA74E, &[0xA74F]),
+    (0xA750, &[0xA751]), (0xA752, &[0xA753]), (0xA754, &[0xA755]), (0xA756, &[0xA757]),
+    (0xA758, &[0xA759]), (0xA75A, &[0xA75B]), (0xA75C, &[0xA75D]), (0xA75E, &[0xA75F]),
+    (0xA760, &[0xA761]), (0xA762, &[0xA763]), (0xA764, &[0xA765]), (0xA766, &[0xA767]),
+    (0xA768, &[0xA769]), (0xA76A, &[0xA76B]), (0xA76C, &[0xA76D]), (0xA76E, &[0xA76F]),
+    (0xA779, &[0xA77A]), (0xA77B, &[0xA77C]), (0xA77D, &[0x1D79]), (0xA77E, &[0xA77F]),
+    (0xA780, &[0xA781]), (0xA782, &[0xA783]), (0xA784, &[0xA785]), (0xA786, &[0xA787]),
+    (0xA78B, &[0xA78C]), (0xA78D, &[0x265]), (0xA790, &[0xA791]), (0xA792, &[0xA793]),
+    (0xA796, &[0xA797]), (0xA798, &[0xA799]), (0xA79A, &[0xA79B]), (0xA79C, &[0xA79D]),
+    (0xA79E, &[0xA79F]), (0xA7A0, &[0xA7A1]), (0xA7A2, &[0xA7A3]), (0xA7A4, &[0xA7A5]),
+    (0xA7A6, &[0xA7A7]), (0xA7A8, &[0xA7A9]), (0xA7AA, &[0x266]), (0xA7AB, &[0x25C]),
+    (0xA7AC, &[0x261]), (0xA7AD, &[0x26C]), (0xA7AE, &[0x26A]), (0xA7B0, &[0x29E]),
+    (0xA7B1, &[0x287]), (0xA7B2, &[0x29D]), (0xA7B3, &[0xAB53]), (0xA7B4, &[0xA7B5]),
+    (0xA7B6, &[0xA7B7]), (0xA7B8, &[0xA7B9]), (0xA7BA, &[0xA7BB]), (0xA7BC, &[0xA7BD]),
+    (0xA7BE, &[0xA7BF]), (0xA7C0, &[0xA7C1]), (0xA7C2, &[0xA7C3]), (0xA7C4, &[0xA794]),
+    (0xA7C5, &[0x282]), (0xA7C6, &[0x1D8E]), (0xA7C7, &[0xA7C8]), (0xA7C9, &[0xA7CA]),
+    (0xA7CB, &[0x264]), (0xA7CC, &[0xA7CD]), (0xA7CE, &[0xA7CF]), (0xA7D0, &[0xA7D1]),
+    (0xA7D2, &[0xA7D3]), (0xA7D4, &[0xA7D5]), (0xA7D6, &[0xA7D7]), (0xA7D8, &[0xA7D9]),
+    (0xA7DA, &[0xA7DB]), (0xA7DC, &[0x19B]), (0xA7F5, &[0xA7F6]), (0xFF21, &[0xFF41]),
+    (0xFF22, &[0xFF42]), (0xFF23, &[0xFF43]), (0xFF24, &[0xFF44]), (0xFF25, &[0xFF45]),
+    (0xFF26, &[0xFF46]), (0xFF27, &[0xFF47]), (0xFF28, &[0xFF48]), (0xFF29, &[0xFF49]),
+    (0xFF2A, &[0xFF4A]), (0xFF2B, &[0xFF4B]), (0xFF2C, &[0xFF4C]), (0xFF2D, &[0xFF4D]),
+    (0xFF2E, &[0xFF4E]), (0xFF2F, &[0xFF4F]), (0xFF30, &[0xFF50]), (0xFF31, &[0xFF51]),
+    (0xFF32, &[0xFF52]), (0xFF33, &[0xFF53]), (0xFF34, &[0xFF54]), (0xFF35, &[0xFF55]),
+    (0xFF36, &[0xFF56]), (0xFF37, &[0xFF57]), (0xFF38, &[0xFF58]), (0xFF39, &[0xFF59]),
+    (0xFF3A, &[0xFF5A]), (0x10400, &[0x10428]), (0x10401, &[0x10429]), (0x10402, &[0x1042A]),
+    (0x10403, &[0x1042B]), (0x10404, &[0x1042C]), (0x10405, &[0x1042D]), (0x10406, &[0x1042E]),
+    (0x10407, &[0x1042F]), (0x10408, &[0x10430]), (0x10409, &[0x10431]), (0x1040A, &[0x10432]),
+    (0x1040B, &[0x10433]), (0x1040C, &[0x10434]), (0x1040D, &[0x10435]), (0x1040E, &[0x10436]),
+    (0x1040F, &[0x10437]), (0x10410, &[0x10438]), (0x10411, &[0x10439]), (0x10412, &[0x1043A]),
+    (0x10413, &[0x1043B]), (0x10414, &[0x1043C]), (0x10415, &[0x1043D]), (0x10416, &[0x1043E]),
+    (0x10417, &[0x1043F]), (0x10418, &[0x10440]), (0x10419, &[0x10441]), (0x1041A, &[0x10442]),
+    (0x1041B, &[0x10443]), (0x1041C, &[0x10444]), (0x1041D, &[0x10445]), (0x1041E, &[0x10446]),
+    (0x1041F, &[0x10447]), (0x10420, &[0x10448]), (0x10421, &[0x10449]), (0x10422, &[0x1044A]),
+    (0x10423, &[0x1044B]), (0x10424, &[0x1044C]), (0x10425, &[0x1044D]), (0x10426, &[0x1044E]),
+    (0x10427, &[0x1044F]), (0x104B0, &[0x104D8]), (0x104B1, &[0x104D9]), (0x104B2, &[0x104DA]),
+    (0x104B3, &[0x104DB]), (0x104B4, &[0x104DC]), (0x104B5, &[0x104DD]), (0x104B6, &[0x104DE]),
+    (0x104B7, &[0x104DF]), (0x104B8, &[0x104E0]), (0x104B9, &[0x104E1]), (0x104BA, &[0x104E2]),
+    (0x104BB, &[0x104E3]), (0x104BC, &[0x104E4]), (0x104BD, &[0x104E5]), (0x104BE, &[0x104E6]),
+    (0x104BF, &[0x104E7]), (0x104C0, &[0x104E8]), (0x104C1, &[0x104E9]), (0x104C2, &[0x104EA]),
+    (0x104C3, &[0x104EB]), (0x104C4, &[0x104EC]), (0x104C5, &[0x104ED]), (0x104C6, &[0x104EE]),
+    (0x104C7, &[0x104EF]), (0x104C8, &[0x104F0]), (0x104C9, &[0x104F1]), (0x104CA, &[0x104F2]),
+    (0x104CB, &[0x104F3]), (0x104CC, &[0x104F4]), (0x104CD, &[0x104F5]), (0x104CE, &[0x104F6]),
+    (0x104CF, &[0x104F7]), (0x104D0, &[0x104F8]), (0x104D1, &[0x104F9]), (0x104D2, &[0x104FA]),
+    (0x104D3, &[0x104FB]), (0x10570, &[0x10597]), (0x10571, &[0x10598]), (0x10572, &[0x10599]),
+    (0x10573, &[0x1059A]), (0x10574, &[0x1059B]), (0x10575, &[0x1059C]), (0x10576, &[0x1059D]),
+    (0x10577, &[0x1059E]), (0x10578, &[0x1059F]), (0x10579, &[0x105A0]), (0x1057A, &[0x105A1]),
+    (0x1057C, &[0x105A3]), (0x1057D, &[0x105A4]), (0x1057E, &[0x105A5]), (0x1057F, &[0x105A6]),
+    (0x10580, &[0x105A7]), (0x10581, &[0x105A8]), (0x10582, &[0x105A9]), (0x10583, &[0x105AA]),
+    (0x10584, &[0x105AB]), (0x10585, &[0x105AC]), (0x10586, &[0x105AD]), (0x10587, &[0x105AE]),
+    (0x10588, &[0x105AF]), (0x10589, &[0x105B0]), (0x1058A, &[0x105B1]), (0x1058C, &[0x105B3]),
+    (0x1058D, &[0x105B4]), (0x1058E, &[0x105B5]), (0x1058F, &[0x105B6]), (0x10590, &[0x105B7]),
+    (0x10591, &[0x105B8]), (0x10592, &[0x105B9]), (0x10594, &[0x105BB]), (0x10595, &[0x105BC]),
+    (0x10C80, &[0x10CC0]), (0x10C81, &[0x10CC1]), (0x10C82, &[0x10CC2]), (0x10C83, &[0x10CC3]),
+    (0x10C84, &[0x10CC4]), (0x10C85, &[0x10CC5]), (0x10C86, &[0x10CC6]), (0x10C87, &[0x10CC7]),
+    (0x10C88, &[0x10CC8]), (0x10C89, &[0x10CC9]), (0x10C8A, &[0x10CCA]), (0x10C8B, &[0x10CCB]),
+    (0x10C8C, &[0x10CCC]), (0x10C8D, &[0x10CCD]), (0x10C8E, &[0x10CCE]), (0x10C8F, &[0x10CCF]),
+    (0x10C90, &[0x10CD0]), (0x10C91, &[0x10CD1]), (0x10C92, &[0x10CD2]), (0x10C93, &[0x10CD3]),
+    (0x10C94, &[0x10CD4]), (0x10C95, &[0x10CD5]), (0x10C96, &[0x10CD6]), (0x10C97, &[0x10CD7]),
+    (0x10C98, &[0x10CD8]), (0x10C99, &[0x10CD9]), (0x10C9A, &[0x10CDA]), (0x10C9B, &[0x10CDB]),
+    (0x10C9C, &[0x10CDC]), (0x10C9D, &[0x10CDD]), (0x10C9E, &[0x10CDE]), (0x10C9F, &[0x10CDF]),
+    (0x10CA0, &[0x10CE0]), (0x10CA1, &[0x10CE1]), (0x10CA2, &[0x10CE2]), (0x10CA3, &[0x10CE3]),
+    (0x10CA4, &[0x10CE4]), (0x10CA5, &[0x10CE5]), (0x10CA6, &[0x10CE6]), (0x10CA7, &[0x10CE7]),
+    (0x10CA8, &[0x10CE8]), (0x10CA9, &[0x10CE9]), (0x10CAA, &[0x10CEA]), (0x10CAB, &[0x10CEB]),
+    (0x10CAC, &[0x10CEC]), (0x10CAD, &[0x10CED]), (0x10CAE, &[0x10CEE]), (0x10CAF, &[0x10CEF]),
+    (0x10CB0, &[0x10CF0]), (0x10CB1, &[0x10CF1]), (0x10CB2, &[0x10CF2]), (0x10D50, &[0x10D70]),
+    (0x10D51, &[0x10D71]), (0x10D52, &[0x10D72]), (0x10D53, &[0x10D73]), (0x10D54, &[0x10D74]),
+    (0x10D55, &[0x10D75]), (0x10D56, &[0x10D76]), (0x10D57, &[0x10D77]), (0x10D58, &[0x10D78]),
+    (0x10D59, &[0x10D79]), (0x10D5A, &[0x10D7A]), (0x10D5B, &[0x10D7B]), (0x10D5C, &[0x10D7C]),
+    (0x10D5D, &[0x10D7D]), (0x10D5E, &[0x10D7E]), (0x10D5F, &[0x10D7F]), (0x10D60, &[0x10D80]),
+    (0x10D61, &[0x10D81]), (0x10D62, &[0x10D82]), (0x10D63, &[0x10D83]), (0x10D64, &[0x10D84]),
+    (0x10D65, &[0x10D85]), (0x118A0, &[0x118C0]), (0x118A1, &[0x118C1]), (0x118A2, &[0x118C2]),
+    (0x118A3, &[0x118C3]), (0x118A4, &[0x118C4]), (0x118A5, &[0x118C5]), (0x118A6, &[0x118C6]),
+    (0x118A7, &[0x118C7]), (0x118A8, &[0x118C8]), (0x118A9, &[0x118C9]), (0x118AA, &[0x118CA]),
+    (0x118AB, &[0x118CB]), (0x118AC, &[0x118CC]), (0x118AD, &[0x118CD]), (0x118AE, &[0x118CE]),
+    (0x118AF, &[0x118CF]), (0x118B0, &[0x118D0]), (0x118B1, &[0x118D1]), (0x118B2, &[0x118D2]),
+    (0x118B3, &[0x118D3]), (0x118B4, &[0x118D4]), (0x118B5, &[0x118D5]), (0x118B6, &[0x118D6]),
+    (0x118B7, &[0x118D7]), (0x118B8, &[0x118D8]), (0x118B9, &[0x118D9]), (0x118BA, &[0x118DA]),
+    (0x118BB, &[0x118DB]), (0x118BC, &[0x118DC]), (0x118BD, &[0x118DD]), (0x118BE, &[0x118DE]),
+    (0x118BF, &[0x118DF]), (0x16E40, &[0x16E60]), (0x16E41, &[0x16E61]), (0x16E42, &[0x16E62]),
+    (0x16E43, &[0x16E63]), (0x16E44, &[0x16E64]), (0x16E45, &[0x16E65]), (0x16E46, &[0x16E66]),
+    (0x16E47, &[0x16E67]), (0x16E48, &[0x16E68]), (0x16E49, &[0x16E69]), (0x16E4A, &[0x16E6A]),
+    (0x16E4B, &[0x16E6B]), (0x16E4C, &[0x16E6C]), (0x16E4D, &[0x16E6D]), (0x16E4E, &[0x16E6E]),
+    (0x16E4F, &[0x16E6F]), (0x16E50, &[0x16E70]), (0x16E51, &[0x16E71]), (0x16E52, &[0x16E72]),
+    (0x16E53, &[0x16E73]), (0x16E54, &[0x16E74]), (0x16E55, &[0x16E75]), (0x16E56, &[0x16E76]),
+    (0x16E57, &[0x16E77]), (0x16E58, &[0x16E78]), (0x16E59, &[0x16E79]), (0x16E5A, &[0x16E7A]),
+    (0x16E5B, &[0x16E7B]), (0x16E5C, &[0x16E7C]), (0x16E5D, &[0x16E7D]), (0x16E5E, &[0x16E7E]),
+    (0x16E5F, &[0x16E7F]), (0x16EA0, &[0x16EBB]), (0x16EA1, &[0x16EBC]), (0x16EA2, &[0x16EBD]),
+    (0x16EA3, &[0x16EBE]), (0x16EA4, &[0x16EBF]), (0x16EA5, &[0x16EC0]), (0x16EA6, &[0x16EC1]),
+    (0x16EA7, &[0x16EC2]), (0x16EA8, &[0x16EC3]), (0x16EA9, &[0x16EC4]), (0x16EAA, &[0x16EC5]),
+    (0x16EAB, &[0x16EC6]), (0x16EAC, &[0x16EC7]), (0x16EAD, &[0x16EC8]), (0x16EAE, &[0x16EC9]),
+    (0x16EAF, &[0x16ECA]), (0x16EB0, &[0x16ECB]), (0x16EB1, &[0x16ECC]), (0x16EB2, &[0x16ECD]),
+    (0x16EB3, &[0x16ECE]), (0x16EB4, &[0x16ECF]), (0x16EB5, &[0x16ED0]), (0x16EB6, &[0x16ED1]),
+    (0x16EB7, &[0x16ED2]), (0x16EB8, &[0x16ED3]), (0x1E900, &[0x1E922]), (0x1E901, &[0x1E923]),
+    (0x1E902, &[0x1E924]), (0x1E903, &[0x1E925]), (0x1E904, &[0x1E926]), (0x1E905, &[0x1E927]),
+    (0x1E906, &[0x1E928]), (0x1E907, &[0x1E929]), (0x1E908, &[0x1E92A]), (0x1E909, &[0x1E92B]),
+    (0x1E90A, &[0x1E92C]), (0x1E90B, &[0x1E92D]), (0x1E90C, &[0x1E92E]), (0x1E90D, &[0x1E92F]),
+    (0x1E90E, &[0x1E930]), (0x1E90F, &[0x1E931]), (0x1E910, &[0x1E932]), (0x1E911, &[0x1E933]),
+    (0x1E912, &[0x1E934]), (0x1E913, &[0x1E935]), (0x1E914, &[0x1E936]), (0x1E915, &[0x1E937]),
+    (0x1E916, &[0x1E938]), (0x1E917, &[0x1E939]), (0x1E918, &[0x1E93A]), (0x1E919, &[0x1E93B]),
+    (0x1E91A, &[0x1E93C]), (0x1E91B, &[0x1E93D]), (0x1E91C, &[0x1E93E]), (0x1E91D, &[0x1E93F]),
+    (0x1E91E, &[0x1E940]), (0x1E91F, &[0x1E941]), (0x1E920, &[0x1E942]), (0x1E921, &[0x1E943]),
+];
+
+/// The properties of strings usable in regular expressions with the `v` flag, as `(name, sorted sequences)`.
+pub(super) static STRING_PROPERTIES: &[(&str, &[&[u32]])] = &[
+    ("Basic_Emoji", &[
+        &[0xA9, 0xFE0F], &[0xAE, 0xFE0F], &[0x203C, 0xFE0F], &[0x2049, 0xFE0F],
+        &[0x2122, 0xFE0F], &[0x2139, 0xFE0F], &[0x2194, 0xFE0F], &[0x2195, 0xFE0F],
+        &[0x2196, 0xFE0F], &[0x2197, 0xFE0F], &[0x2198, 0xFE0F], &[0x2199, 0xFE0F],
+        &[0x21A9, 0xFE0F], &[0x21AA, 0xFE0F], &[0x231A], &[0x231B],
+        &[0x2328, 0xFE0F], &[0x23CF, 0xFE0F], &[0x23E9], &[0x23EA],
+        &[0x23EB], &[0x23EC], &[0x23ED, 0xFE0F], &[0x23EE, 0xFE0F],
+        &[0x23EF, 0xFE0F], &[0x23F0], &[0x23F1, 0xFE0F], &[0x23F2, 0xFE0F],
+        &[0x23F3], &[0x23F8, 0xFE0F], &[0x23F9, 0xFE0F], &[0x23FA, 0xFE0F],
+        &[0x24C2, 0xFE0F], &[0x25AA, 0xFE0F], &[0x25AB, 0xFE0F], &[0x25B6, 0xFE0F],
+        &[0x25C0, 0xFE0F], &[0x25FB, 0xFE0F], &[0x25FC, 0xFE0F], &[0x25FD],
+        &[0x25FE], &[0x2600, 0xFE0F], &[0x2601, 0xFE0F], &[0x2602, 0xFE0F],
+        &[0x2603, 0xFE0F], &[0x2604, 0xFE0F], &[0x260E, 0xFE0F], &[0x2611, 0xFE0F],
+        &[0x2614], &[0x2615], &[0x2618, 0xFE0F], &[0x261D, 0xFE0F],
+        &[0x2620, 0xFE0F], &[0x2622, 0xFE0F], &[0x2623, 0xFE0F], &[0x2626, 0xFE0F],
+        &[0x262A, 0xFE0F], &[0x262E, 0xFE0F], &[0x262F, 0xFE0F], &[0x2638, 0xFE0F],
+        &[0x2639, 0xFE0F], &[0x263A, 0xFE0F], &[0x2640, 0xFE0F], &[0x2642, 0xFE0F],
+        &[0x2648], &[0x2649], &[0x264A], &[0x264B],
+        &[0x264C], &[0x264D], &[0x264E], &[0x264F],
+        &[0x2650], &[0x2651], &[0x2652], &[0x2653],
+        &[0x265F, 0xFE0F], &[0x2660, 0xFE0F], &[0x2663, 0xFE0F], &[0x2665, 0xFE0F],
+        &[0x2666, 0xFE0F], &[0x2668, 0xFE0F], &[0x267B, 0xFE0F], &[0x267E, 0xFE0F],
+        &[0x267F], &[0x2692, 0xFE0F], &[0x2693], &[0x2694, 0xFE0F],
+        &[0x2695, 0xFE0F], &[0x2696, 0xFE0F], &[0x2697, 0xFE0F], &[0x2699, 0xFE0F],
+        &[0x269B, 0xFE0F], &[0x269C, 0xFE0F], &[0x26A0, 0xFE0F], &[0x26A1],
+        &[0x26A7, 0xFE0F], &[0x26AA], &[0x26AB], &[0x26B0, 0xFE0F],
+        &[0x26B1, 0xFE0F], &[0x26BD], &[0x26BE], &[0x26C4],
+        &[0x26C5], &[0x26C8, 0xFE0F], &[0x26CE], &[0x26CF, 0xFE0F],
+        &[0x26D1, 0xFE0F], &[0x26D3, 0xFE0F], &[0x26D4], &[0x26E9, 0xFE0F],
+        &[0x26EA], &[0x26F0, 0xFE0F], &[0x26F1, 0xFE0F], &[0x26F2],
+        &[0x26F3], &[0x26F4, 0xFE0F], &[0x26F5], &[0x26F7, 0xFE0F],
+        &[0x26F8, 0xFE0F], &[0x26F9, 0xFE0F], &[0x26FA], &[0x26FD],
+        &[0x2702, 0xFE0F], &[0x2705], &[0x2708, 0xFE0F], &[0x2709, 0xFE0F],
+        &[0x270A], &[0x270B], &[0x270C, 0xFE0F], &[0x270D, 0xFE0F],
+        &[0x270F, 0xFE0F], &[0x2712, 0xFE0F], &[0x2714, 0xFE0F], &[0x2716, 0xFE0F],
+        &[0x271D, 0xFE0F], &[0x2721, 0xFE0F], &[0x2728], &[0x2733, 0xFE0F],
+        &[0x2734, 0xFE0F], &[0x2744, 0xFE0F], &[0x2747, 0xFE0F], &[0x274C],
+        &[0x274E], &[0x2753], &[0x2754], &[0x2755],
+        &[0x2757], &[0x2763, 0xFE0F], &[0x2764, 0xFE0F], &[0x2795],
+        &[0x2796], &[0x2797], &[0x27A1, 0xFE0F], &[0x27B0],
+        &[0x27BF], &[0x2934, 0xFE0F], &[0x2935, 0xFE0F], &[0x2B05, 0xFE0F],
+        &[0x2B06, 0xFE0F], &[0x2B07, 0xFE0F], &[0x2B1B], &[0x2B1C],
+        &[0x2B50], &[0x2B55], &[0x3030, 0xFE0F], &[0x303D, 0xFE0F],
+        &[0x3297, 0xFE0F], &[0x3299, 0xFE0F], &[0x1F004], &[0x1F0CF],
+        &[0x1F170, 0xFE0F], &[0x1F171, 0xFE0F], &[0x1F17E, 0xFE0F], &[0x1F17F, 0xFE0F],
+        &[0x1F18E], &[0x1F191], &[0x1F192], &[0x1F193],
+        &[0x1F194], &[0x1F195], &[0x1F196], &[0x1F197],
+        &[0x1F198], &[0x1F199], &[0x1F19A], &[0x1F201],
+        &[0x1F202, 0xFE0F], &[0x1F21A], &[0x1F22F], &[0x1F232],
+        &[0x1F233], &[0x1F234], &[0x1F235], &[0x1F236],
+        &[0x1F237, 0xFE0F], &[0x1F238], &[0x1F239], &[0x1F23A],
+        &[0x1F250], &[0x1F251], &[0x1F300], &[0x1F301],
+        &[0x1F302], &[0x1F303], &[0x1F304], &[0x1F305],
+        &[0x1F306], &[0x1F307], &[0x1F308], &[0x1F309],
+        &[0x1F30A], &[0x1F30B], &[0x1F30C], &[0x1F30D],
+        &[0x1F30E], &[0x1F30F], &[0x1F310], &[0x1F311],
+        &[0x1F312], &[0x1F313], &[0x1F314], &[0x1F315],
+        &[0x1F316], &[0x1F317], &[0x1F318], &[0x1F319],
+        &[0x1F31A], &[0x1F31B], &[0x1F31C], &[0x1F31D],
+        &[0x1F31E], &[0x1F31F], &[0x1F320], &[0x1F321, 0xFE0F],
+        &[0x1F324, 0xFE0F], &[0x1F325, 0xFE0F], &[0x1F326, 0xFE0F], &[0x1F327, 0xFE0F],
+        &[0x1F328, 0xFE0F], &[0x1F329, 0xFE0F], &[0x1F32A, 0xFE0F], &[0x1F32B, 0xFE0F],
+        &[0x1F32C, 0xFE0F], &[0x1F32D], &[0x1F32E], &[0x1F32F],
+        &[0x1F330], &[0x1F331], &[0x1F332], &[0x1F333],
+        &[0x1F334], &[0x1F335], &[0x1F336, 0xFE0F], &[0x1F337],
+        &[0x1F338], &[0x1F339], &[0x1F33A], &[0x1F33B],
+        &[0x1F33C], &[0x1F33D], &[0x1F33E], &[0x1F33F],
+        &[0x1F340], &[0x1F341], &[0x1F342], &[0x1F343],
+        &[0x1F344], &[0x1F345], &[0x1F346], &[0x1F347],
+        &[0x1F348], &[0x1F349], &[0x1F34A], &[0x1F34B],
+        &[0x1F34C], &[0x1F34D], &[0x1F34E], &[0x1F34F],
+        &[0x1F350], &[0x1F351], &[0x1F352], &[0x1F353],
+        &[0x1F354], &[0x1F355], &[0x1F356], &[0x1F357],
+        &[0x1F358], &[0x1F359], &[0x1F35A], &[0x1F35B],
+        &[0x1F35C], &[0x1F35D], &[0x1F35E], &[0x1F35F],
+        &[0x1F360], &[0x1F361], &[0x1F362], &[0x1F363],
+        &[0x1F364], &[0x1F365], &[0x1F366], &[0x1F367],
+        &[0x1F368], &[0x1F369], &[0x1F36A], &[0x1F36B],
+        &[0x1F36C], &[0x1F36D], &[0x1F36E], &[0x1F36F],
+        &[0x1F370], &[0x1F371], &[0x1F372], &[0x1F373],
+        &[0x1F374], &[0x1F375], &[0x1F376], &[0x1F377],
+        &[0x1F378], &[0x1F379], &[0x1F37A], &[0x1F37B],
+        &[0x1F37C], &[0x1F37D, 0xFE0F], &[0x1F37E], &[0x1F37F],
+        &[0x1F380], &[0x1F381], &[0x1F382], &[0x1F383],
+        &[0x1F384], &[0x1F385], &[0x1F386], &[0x1F387],
+        &[0x1F388], &[0x1F389], &[0x1F38A], &[0x1F38B],
+        &[0x1F38C], &[0x1F38D], &[0x1F38E], &[0x1F38F],
+        &[0x1F390], &[0x1F391], &[0x1F392], &[0x1F393],
+        &[0x1F396, 0xFE0F], &[0x1F397, 0xFE0F], &[0x1F399, 0xFE0F], &[0x1F39A, 0xFE0F],
+        &[0x1F39B, 0xFE0F], &[0x1F39E, 0xFE0F], &[0x1F39F, 0xFE0F], &[0x1F3A0],
+        &[0x1F3A1], &[0x1F3A2], &[0x1F3A3], &[0x1F3A4],
+        &[0x1F3A5], &[0x1F3A6], &[0x1F3A7], &[0x1F3A8],
+        &[0x1F3A9], &[0x1F3AA], &[0x1F3AB], &[0x1F3AC],
+        &[0x1F3AD], &[0x1F3AE], &[0x1F3AF], &[0x1F3B0],
+        &[0x1F3B1], &[0x1F3B2], &[0x1F3B3], &[0x1F3B4],
+        &[0x1F3B5], &[0x1F3B6], &[0x1F3B7], &[0x1F3B8],
+        &[0x1F3B9], &[0x1F3BA], &[0x1F3BB], &[0x1F3BC],
+        &[0x1F3BD], &[0x1F3BE], &[0x1F3BF], &[0x1F3C0],
+        &[0x1F3C1], &[0x1F3C2], &[0x1F3C3], &[0x1F3C4],
+        &[0x1F3C5], &[0x1F3C6], &[0x1F3C7], &[0x1F3C8],
+        &[0x1F3C9], &[0x1F3CA], &[0x1F3CB, 0xFE0F], &[0x1F3CC, 0xFE0F],
+        &[0x1F3CD, 0xFE0F], &[0x1F3CE, 0xFE0F], &[0x1F3CF], &[0x1F3D0],
+        &[0x1F3D1], &[0x1F3D2], &[0x1F3D3], &[0x1F3D4, 0xFE0F],
+        &[0x1F3D5, 0xFE0F], &[0x1F3D6, 0xFE0F], &[0x1F3D7, 0xFE0F], &[0x1F3D8, 0xFE0F],
+        &[0x1F3D9, 0xFE0F], &[0x1F3DA, 0xFE0F], &[0x1F3DB, 0xFE0F], &[0x1F3DC, 0xFE0F],
+        &[0x1F3DD, 0xFE0F], &[0x1F3DE, 0xFE0F], &[0x1F3DF, 0xFE0F], &[0x1F3E0],
+        &[0x1F3E1], &[0x1F3E2], &[0x1F3E3], &[0x1F3E4],
+        &[0x1F3E5], &[0x1F3E6], &[0x1F3E7], &[0x1F3E8],
+        &[0x1F3E9], &[0x1F3EA], &[0x1F3EB], &[0x1F3EC],
+        &[0x1F3ED], &[0x1F3EE], &[0x1F3EF], &[0x1F3F0],
+        &[0x1F3F3, 0xFE0F], &[0x1F3F4], &[0x1F3F5, 0xFE0F], &[0x1F3F7, 0xFE0F],
+        &[0x1F3F8], &[0x1F3F9], &[0x1F3FA], &[0x1F3FB],
+        &[0x1F3FC], &[0x1F3FD], &[0x1F3FE], &[0x1F3FF],
+        &[0x1F400], &[0x1F401], &[0x1F402], &[0x1F403],
+        &[0x1F404], &[0x1F405], &[0x1F406], &[0x1F407],
+        &[0x1F408], &[0x1F409], &[0x1F40A], &[0x1F40B],
+        &[0x1F40C], &[0x1F40D], &[0x1F40E], &[0x1F40F],
+        &[0x1F410], &[0x1F411], &[0x1F412], &[0x1F413],
+        &[0x1F414], &[0x1F415], &[0x1F416], &[0x1F417],
+        &[0x1F418], &[0x1F419], &[0x1F41A], &[0x1F41B],
+        &[0x1F41C], &[0x1F41D], &[0x1F41E], &[0x1F41F],
+        &[0x1F420], &[0x1F421], &[0x1F422], &[0x1F423],
+        &[0x1F424], &[0x1F425], &[0x1F426], &[0x1F427],
+        &[0x1F428], &[0x1F429], &[0x1F42A], &[0x1F42B],
+        &[0x1F42C], &[0x1F42D], &[0x1F42E], &[0x1F42F],
+        &[0x1F430], &[0x1F431], &[0x1F432], &[0x1F433],
+        &[0x1F434], &[0x1F435], &[0x1F436], &[0x1F437],
+        &[0x1F438], &[0x1F439], &[0x1F43A], &[0x1F43B],
+        &[0x1F43C], &[0x1F43D], &[0x1F43E], &[0x1F43F, 0xFE0F],
+        &[0x1F440], &[0x1F441, 0xFE0F], &[0x1F442], &[0x1F443],
+        &[0x1F444], &[0x1F445], &[0x1F446], &[0x1F447],
+        &[0x1F448], &[0x1F449], &[0x1F44A], &[0x1F44B],
+        &[0x1F44C], &[0x1F44D], &[0x1F44E], &[0x1F44F],
+        &[0x1F450], &[0x1F451], &[0x1F452], &[0x1F453],
+        &[0x1F454], &[0x1F455], &[0x1F456], &[0x1F457],
+        &[0x1F458], &[0x1F459], &[0x1F45A], &[0x1F45B],
+        &[0x1F45C], &[0x1F45D], &[0x1F45E], &[0x1F45F],
+        &[0x1F460], &[0x1F461], &[0x1F462], &[0x1F463],
+        &[0x1F464], &[0x1F465], &[0x1F466], &[0x1F467],
+        &[0x1F468], &[0x1F469], &[0x1F46A], &[0x1F46B],
+        &[0x1F46C], &[0x1F46D], &[0x1F46E], &[0x1F46F],
+        &[0x1F470], &[0x1F471], &[0x1F472], &[0x1F473],
+        &[0x1F474], &[0x1F475], &[0x1F476], &[0x1F477],
+        &[0x1F478], &[0x1F479], &[0x1F47A], &[0x1F47B],
+        &[0x1F47C], &[0x1F47D], &[0x1F47E], &[0x1F47F],
+        &[0x1F480], &[0x1F481], &[0x1F482], &[0x1F483],
+        &[0x1F484], &[0x1F485], &[0x1F486], &[0x1F487],
+        &[0x1F488], &[0x1F489], &[0x1F48A], &[0x1F48B],
+        &[0x1F48C], &[0x1F48D], &[0x1F48E], &[0x1F48F],
+        &[0x1F490], &[0x1F491], &[0x1F492], &[0x1F493],
+        &[0x1F494], &[0x1F495], &[0x1F496], &[0x1F497],
+        &[0x1F498], &[0x1F499], &[0x1F49A], &[0x1F49B],
+        &[0x1F49C], &[0x1F49D], &[0x1F49E], &[0x1F49F],
+        &[0x1F4A0], &[0x1F4A1], &[0x1F4A2], &[0x1F4A3],
+        &[0x1F4A4], &[0x1F4A5], &[0x1F4A6], &[0x1F4A7],
+        &[0x1F4A8], &[0x1F4A9], &[0x1F4AA], &[0x1F4AB],
+        &[0x1F4AC], &[0x1F4AD], &[0x1F4AE], &[0x1F4AF],
+        &[0x1F4B0], &[0x1F4B1], &[0x1F4B2], &[0x1F4B3],
+        &[0x1F4B4], &[0x1F4B5], &[0x1F4B6], &[0x1F4B7],
+        &[0x1F4B8], &[0x1F4B9], &[0x1F4BA], &[0x1F4BB],
+        &[0x1F4BC], &[0x1F4BD], &[0x1F4BE], &[0x1F4BF],
+        &[0x1F4C0], &[0x1F4C1], &[0x1F4C2], &[0x1F4C3],
+        &[0x1F4C4], &[0x1F4C5], &[0x1F4C6], &[0x1F4C7],
+        &[0x1F4C8], &[0x1F4C9], &[0x1F4CA], &[0x1F4CB],
+        &[0x1F4CC], &[0x1F4CD], &[0x1F4CE], &[0x1F4CF],
+        &[0x1F4D0], &[0x1F4D1], &[0x1F4D2], &[0x1F4D3],
+        &[0x1F4D4], &[0x1F4D5], &[0x1F4D6], &[0x1F4D7],
+        &[0x1F4D8], &[0x1F4D9], &[0x1F4DA], &[0x1F4DB],
+        &[0x1F4DC], &[0x1F4DD], &[0x1F4DE], &[0x1F4DF],
+        &[0x1F4E0], &[0x1F4E1], &[0x1F4E2], &[0x1F4E3],
+        &[0x1F4E4], &[0x1F4E5], &[0x1F4E6], &[0x1F4E7],
+        &[0x1F4E8], &[0x1F4E9], &[0x1F4EA], &[0x1F4EB],
+        &[0x1F4EC], &[0x1F4ED], &[0x1F4EE], &[0x1F4EF],
+        &[0x1F4F0], &[0x1F4F1], &[0x1F4F2], &[0x1F4F3],
+        &[0x1F4F4], &[0x1F4F5], &[0x1F4F6], &[0x1F4F7],
+        &[0x1F4F8], &[0x1F4F9], &[0x1F4FA], &[0x1F4FB],
+        &[0x1F4FC], &[0x1F4FD, 0xFE0F], &[0x1F4FF], &[0x1F500],
+        &[0x1F501], &[0x1F502], &[0x1F503], &[0x1F504],
+        &[0x1F505], &[0x1F506], &[0x1F507], &[0x1F508],
+        &[0x1F509], &[0x1F50A], &[0x1F50B], &[0x1F50C],
+        &[0x1F50D], &[0x1F50E], &[0x1F50F], &[0x1F510],
+        &[0x1F511], &[0x1F512], &[0x1F513], &[0x1F514],
+        &[0x1F515], &[0x1F516], &[0x1F517], &[0x1F518],
+        &[0x1F519], &[0x1F51A], &[0x1F51B], &[0x1F51C],
+        &[0x1F51D], &[0x1F51E], &[0x1F51F], &[0x1F520],
+        &[0x1F521], &[0x1F522], &[0x1F523], &[0x1F524],
+        &[0x1F525], &[0x1F526], &[0x1F527], &[0x1F528],
+        &[0x1F529], &[0x1F52A], &[0x1F52B], &[0x1F52C],
+        &[0x1F52D], &[0x1F52E], &[0x1F52F], &[0x1F530],
+        &[0x1F531], &[0x1F532], &[0x1F533], &[0x1F534],
+        &[0x1F535], &[0x1F536], &[0x1F537], &[0x1F538],
+        &[0x1F539], &[0x1F53A], &[0x1F53B], &[0x1F53C],
+        &[0x1F53D], &[0x1F549, 0xFE0F], &[0x1F54A, 0xFE0F], &[0x1F54B],
+        &[0x1F54C], &[0x1F54D], &[0x1F54E], &[0x1F550],
+        &[0x1F551], &[0x1F552], &[0x1F553], &[0x1F554],
+        &[0x1F555], &[0x1F556], &[0x1F557], &[0x1F558],
+        &[0x1F559], &[0x1F55A], &[0x1F55B], &[0x1F55C],
+        &[0x1F55D], &[0x1F55E], &[0x1F55F], &[0x1F560],
+        &[0x1F561], &[0x1F562], &[0x1F563], &[0x1F564],
+        &[0x1F565], &[0x1F566], &[0x1F567], &[0x1F56F, 0xFE0F],
+        &[0x1F570, 0xFE0F], &[0x1F573, 0xFE0F], &[0x1F574, 0xFE0F], &[0x1F575, 0xFE0F],
+        &[0x1F576, 0xFE0F], &[0x1F577, 0xFE0F], &[0x1F578, 0xFE0F], &[0x1F579, 0xFE0F],
+        &[0x1F57A], &[0x1F587, 0xFE0F], &[0x1F58A, 0xFE0F], &[0x1F58B, 0xFE0F],
+        &[0x1F58C, 0xFE0F], &[0x1F58D, 0xFE0F], &[0x1F590, 0xFE0F], &[0x1F595],
+        &[0x1F596], &[0x1F5A4], &[0x1F5A5, 0xFE0F], &[0x1F5A8, 0xFE0F],
+        &[0x1F5B1, 0xFE0F], &[0x1F5B2, 0xFE0F], &[0x1F5BC, 0xFE0F], &[0x1F5C2, 0xFE0F],
+        &[0x1F5C3, 0xFE0F], &[0x1F5C4, 0xFE0F], &[0x1F5D1, 0xFE0F], &[0x1F5D2, 0xFE0F],
+        &[0x1F5D3, 0xFE0F], &[0x1F5DC, 0xFE0F], &[0x1F5DD, 0xFE0F], &[0x1F5DE, 0xFE0F],
+        &[0x1F5E1, 0xFE0F], &[0x1F5E3, 0xFE0F], &[0x1F5E8, 0xFE0F], &[0x1F5EF, 0xFE0F],
+        &[0x1F5F3, 0xFE0F], &[0x1F5FA, 0xFE0F], &[0x1F5FB], &[0x1F5FC],
+        &[0x1F5FD], &[0x1F5FE], &[0x1F5FF], &[0x1F600],
+        &[0x1F601], &[0x1F602], &[0x1F603], &[0x1F604],
+        &[0x1F605], &[0x1F606], &[0x1F607], &[0x1F608],
+        &[0x1F609], &[0x1F60A], &[0x1F60B], &[0x1F60C],
+        &[0x1F60D], &[0x1F60E], &[0x1F60F], &[0x1F610],
+        &[0x1F611], &[0x1F612], &[0x1F613], &[0x1F614],
+        &[0x1F615], &[0x1F616], &[0x1F617], &[0x1F618],
+        &[0x1F619], &[0x1F61A], &[0x1F61B], &[0x1F61C],
+        &[0x1F61D], &[0x1F61E], &[0x1F61F], &[0x1F620],
+        &[0x1F621], &[0x1F622], &[0x1F623], &[0x1F624],
+        &[0x1F625], &[0x1F626], &[0x1F627], &[0x1F628],
+        &[0x1F629], &[0x1F62A], &[0x1F62B], &[0x1F62C],
+        &[0x1F62D], &[0x1F62E], &[0x1F62F], &[0x1F630],
+        &[0x1F631], &[0x1F632], &[0x1F633], &[0x1F634],
+        &[0x1F635], &[0x1F636], &[0x1F637], &[0x1F638],
+        &[0x1F639], &[0x1F63A], &[0x1F63B], &[0x1F63C],
+        &[0x1F63D], &[0x1F63E], &[0x1F63F], &[0x1F640],
+        &[0x1F641], &[0x1F642], &[0x1F643], &[0x1F644],
+        &[0x1F645], &[0x1F646], &[0x1F647], &[0x1F648],
+        &[0x1F649], &[0x1F64A], &[0x1F64B], &[0x1F64C],
+        &[0x1F64D], &[0x1F64E], &[0x1F64F], &[0x1F680],
+        &[0x1F681], &[0x1F682], &[0x1F683], &[0x1F684],
+        &[0x1F685], &[0x1F686], &[0x1F687], &[0x1F688],
+        &[0x1F689], &[0x1F68A], &[0x1F68B], &[0x1F68C],
+        &[0x1F68D], &[0x1F68E], &[0x1F68F], &[0x1F690],
+        &[0x1F691], &[0x1F692], &[0x1F693], &[0x1F694],
+        &[0x1F695], &[0x1F696], &[0x1F697], &[0x1F698],
+        &[0x1F699], &[0x1F69A], &[0x1F69B], &[0x1F69C],
+        &[0x1F69D], &[0x1F69E], &[0x1F69F], &[0x1F6A0],
+        &[0x1F6A1], &[0x1F6A2], &[0x1F6A3], &[0x1F6A4],
+        &[0x1F6A5], &[0x1F6A6], &[0x1F6A7], &[0x1F6A8],
+        &[0x1F6A9], &[0x1F6AA], &[0x1F6AB], &[0x1F6AC],
+        &[0x1F6AD], &[0x1F6AE], &[0x1F6AF], &[0x1F6B0],
+        &[0x1F6B1], &[0x1F6B2], &[0x1F6B3], &[0x1F6B4],
+        &[0x1F6B5], &[0x1F6B6], &[0x1F6B7], &[0x1F6B8],
+        &[0x1F6B9], &[0x1F6BA], &[0x1F6BB], &[0x1F6BC],
+        &[0x1F6BD], &[0x1F6BE], &[0x1F6BF], &[0x1F6C0],
+        &[0x1F6C1], &[0x1F6C2], &[0x1F6C3], &[0x1F6C4],
+        &[0x1F6C5], &[0x1F6CB, 0xFE0F], &[0x1F6CC], &[0x1F6CD, 0xFE0F],
+        &[0x1F6CE, 0xFE0F], &[0x1F6CF, 0xFE0F], &[0x1F6D0], &[0x1F6D1],
+        &[0x1F6D2], &[0x1F6D5], &[0x1F6D6], &[0x1F6D7],
+        &[0x1F6D8], &[0x1F6DC], &[0x1F6DD], &[0x1F6DE],
+        &[0x1F6DF], &[0x1F6E0, 0xFE0F], &[0x1F6E1, 0xFE0F], &[0x1F6E2, 0xFE0F],
+        &[0x1F6E3, 0xFE0F], &[0x1F6E4, 0xFE0F], &[0x1F6E5, 0xFE0F], &[0x1F6E9, 0xFE0F],
+        &[0x1F6EB], &[0x1F6EC], &[0x1F6F0, 0xFE0F], &[0x1F6F3, 0xFE0F],
+        &[0x1F6F4], &[0x1F6F5], &[0x1F6F6], &[0x1F6F7],
+        &[0x1F6F8], &[0x1F6F9], &[0x1F6FA], &[0x1F6FB],
+        &[0x1F6FC], &[0x1F7E0], &[0x1F7E1], &[0x1F7E2],
+        &[0x1F7E3], &[0x1F7E4], &[0x1F7E5], &[0x1F7E6],
+        &[0x1F7E7], &[0x1F7E8], &[0x1F7E9], &[0x1F7EA],
+        &[0x1F7EB], &[0x1F7F0], &[0x1F90C], &[0x1F90D],
+        &[0x1F90E], &[0x1F90F], &[0x1F910], &[0x1F911],
+        &[0x1F912], &[0x1F913], &[0x1F914], &[0x1F915],
+        &[0x1F916], &[0x1F917], &[0x1F918], &[0x1F919],
+        &[0x1F91A], &[0x1F91B], &[0x1F91C], &[0x1F91D],
+        &[0x1F91E], &[0x1F91F], &[0x1F920], &[0x1F921],
+        &[0x1F922], &[0x1F923], &[0x1F924], &[0x1F925],
+        &[0x1F926], &[0x1F927], &[0x1F928], &[0x1F929],
+        &[0x1F92A], &[0x1F92B], &[0x1F92C], &[0x1F92D],
+        &[0x1F92E], &[0x1F92F], &[0x1F930], &[0x1F931],
+        &[0x1F932], &[0x1F933], &[0x1F934], &[0x1F935],
+        &[0x1F936], &[0x1F937], &[0x1F938], &[0x1F939],
+        &[0x1F93A], &[0x1F93C], &[0x1F93D], &[0x1F93E],
+        &[0x1F93F], &[0x1F940], &[0x1F941], &[0x1F942],
+        &[0x1F943], &[0x1F944], &[0x1F945], &[0x1F947],
+        &[0x1F948], &[0x1F949], &[0x1F94A], &[0x1F94B],
+        &[0x1F94C], &[0x1F94D], &[0x1F94E], &[0x1F94F],
+        &[0x1F950], &[0x1F951], &[0x1F952], &[0x1F953],
+        &[0x1F954], &[0x1F955], &[0x1F956], &[0x1F957],
+        &[0x1F958], &[0x1F959], &[0x1F95A], &[0x1F95B],
+        &[0x1F95C], &[0x1F95D], &[0x1F95E], &[0x1F95F],
+        &[0x1F960], &[0x1F961], &[0x1F962], &[0x1F963],
+        &[0x1F964], &[0x1F965], &[0x1F966], &[0x1F967],
+        &[0x1F968], &[0x1F969], &[0x1F96A], &[0x1F96B],
+        &[0x1F96C], &[0x1F96D], &[0x1F96E], &[0x1F96F],
+        &[0x1F970], &[0x1F971], &[0x1F972], &[0x1F973],
+        &[0x1F974], &[0x1F975], &[0x1F976], &[0x1F977],
+        &[0x1F978], &[0x1F979], &[0x1F97A], &[0x1F97B],
+        &[0x1F97C], &[0x1F97D], &[0x1F97E], &[0x1F97F],
+        &[0x1F980], &[0x1F981], &[0x1F982], &[0x1F983],
+        &[0x1F984], &[0x1F985], &[0x1F986], &[0x1F987],
+        &[0x1F988], &[0x1F989], &[0x1F98A], &[0x1F98B],
+        &[0x1F98C], &[0x1F98D], &[0x1F98E], &[0x1F98F],
+        &[0x1F990], &[0x1F991], &[0x1F992], &[0x1F993],
+        &[0x1F994], &[0x1F995], &[0x1F996], &[0x1F997],
+        &[0x1F998], &[0x1F999], &[0x1F99A], &[0x1F99B],
+        &[0x1F99C], &[0x1F99D], &[0x1F99E], &[0x1F99F],
+        &[0x1F9A0], &[0x1F9A1], &[0x1F9A2], &[0x1F9A3],
+        &[0x1F9A4], &[0x1F9A5], &[0x1F9A6], &[0x1F9A7],
+        &[0x1F9A8], &[0x1F9A9], &[0x1F9AA], &[0x1F9AB],
+        &[0x1F9AC], &[0x1F9AD], &[0x1F9AE], &[0x1F9AF],
+        &[0x1F9B0], &[0x1F9B1], &[0x1F9B2], &[0x1F9B3],
+        &[0x1F9B4], &[0x1F9B5], &[0x1F9B6], &[0x1F9B7],
+        &[0x1F9B8], &[0x1F9B9], &[0x1F9BA], &[0x1F9BB],
+        &[0x1F9BC], &[0x1F9BD], &[0x1F9BE], &[0x1F9BF],
+        &[0x1F9C0], &[0x1F9C1], &[0x1F9C2], &[0x1F9C3],
+        &[0x1F9C4], &[0x1F9C5], &[0x1F9C6], &[0x1F9C7],
+        &[0x1F9C8], &[0x1F9C9], &[0x1F9CA], &[0x1F9CB],
+        &[0x1F9CC], &[0x1F9CD], &[0x1F9CE], &[0x1F9CF],
+        &[0x1F9D0], &[0x1F9D1], &[0x1F9D2], &[0x1F9D3],
+        &[0x1F9D4], &[0x1F9D5], &[0x1F9D6], &[0x1F9D7],
+        &[0x1F9D8], &[0x1F9D9], &[0x1F9DA], &[0x1F9DB],
+        &[0x1F9DC], &[0x1F9DD], &[0x1F9DE], &[0x1F9DF],
+        &[0x1F9E0], &[0x1F9E1], &[0x1F9E2], &[0x1F9E3],
+        &[0x1F9E4], &[0x1F9E5], &[0x1F9E6], &[0x1F9E7],
+        &[0x1F9E8], &[0x1F9E9], &[0x1F9EA], &[0x1F9EB],
+        &[0x1F9EC], &[0x1F9ED], &[0x1F9EE], &[0x1F9EF],
+        &[0x1F9F0], &[0x1F9F1], &[0x1F9F2], &[0x1F9F3],
+        &[0x1F9F4], &[0x1F9F5], &[0x1F9F6], &[0x1F9F7],
+        &[0x1F9F8], &[0x1F9F9], &[0x1F9FA], &[0x1F9FB],
+        &[0x1F9FC], &[0x1F9FD], &[0x1F9FE], &[0x1F9FF],
+        &[0x1FA70], &[0x1FA71], &[0x1FA72], &[0x1FA73],
+        &[0x1FA74], &[0x1FA75], &[0x1FA76], &[0x1FA77],
+        &[0x1FA78], &[0x1FA79], &[0x1FA7A], &[0x1FA7B],
+        &[0x1FA7C], &[0x1FA80], &[0x1FA81], &[0x1FA82],
+        &[0x1FA83], &[0x1FA84], &[0x1FA85], &[0x1FA86],
+        &[0x1FA87], &[0x1FA88], &[0x1FA89], &[0x1FA8A],
+        &[0x1FA8E], &[0x1FA8F], &[0x1FA90], &[0x1FA91],
+        &[0x1FA92], &[0x1FA93], &[0x1FA94], &[0x1FA95],
+        &[0x1FA96], &[0x1FA97], &[0x1FA98], &[0x1FA99],
+        &[0x1FA9A], &[0x1FA9B], &[0x1FA9C], &[0x1FA9D],
+        &[0x1FA9E], &[0x1FA9F], &[0x1FAA0], &[0x1FAA1],
+        &[0x1FAA2], &[0x1FAA3], &[0x1FAA4], &[0x1FAA5],
+        &[0x1FAA6], &[0x1FAA7], &[0x1FAA8], &[0x1FAA9],
+        &[0x1FAAA], &[0x1FAAB], &[0x1FAAC], &[0x1FAAD],
+        &[0x1FAAE], &[0x1FAAF], &[0x1FAB0], &[0x1FAB1],
+        &[0x1FAB2], &[0x1FAB3], &[0x1FAB4], &[0x1FAB5],
+        &[0x1FAB6], &[0x1FAB7], &[0x1FAB8], &[0x1FAB9],
+        &[0x1FABA], &[0x1FABB], &[0x1FABC], &[0x1FABD],
+        &[0x1FABE], &[0x1FABF], &[0x1FAC0], &[0x1FAC1],
+        &[0x1FAC2], &[0x1FAC3], &[0x1FAC4], &[0x1FAC5],
+        &[0x1FAC6], &[0x1FAC8], &[0x1FACD], &[0x1FACE],
+        &[0x1FACF], &[0x1FAD0], &[0x1FAD1], &[0x1FAD2],
+        &[0x1FAD3], &[0x1FAD4], &[0x1FAD5], &[0x1FAD6],
+        &[0x1FAD7], &[0x1FAD8], &[0x1FAD9], &[0x1FADA],
+        &[0x1FADB], &[0x1FADC], &[0x1FADF], &[0x1FAE0],
+        &[0x1FAE1], &[0x1FAE2], &[0x1FAE3], &[0x1FAE4],
+        &[0x1FAE5], &[0x1FAE6], &[0x1FAE7], &[0x1FAE8],
+        &[0x1FAE9], &[0x1FAEA], &[0x1FAEF], &[0x1FAF0],
+        &[0x1FAF1], &[0x1FAF2], &[0x1FAF3], &[0x1FAF4],
+        &[0x1FAF5], &[0x1FAF6], &[0x1FAF7], &[0x1FAF8],
+    ]),
+    ("Emoji_Keycap_Sequence", &[
+        &[0x23, 0xFE0F, 0x20E3], &[0x2A, 0xFE0F, 0x20E3], &[0x30, 0xFE0F, 0x20E3], &[0x31, 0xFE0F, 0x20E3],
+        &[0x32, 0xFE0F, 0x20E3], &[0x33, 0xFE0F, 0x20E3], &[0x34, 0xFE0F, 0x20E3], &[0x35, 0xFE0F, 0x20E3],
+        &[0x36, 0xFE0F, 0x20E3], &[0x37, 0xFE0F, 0x20E3], &[0x38, 0xFE0F, 0x20E3], &[0x39, 0xFE0F, 0x20E3],
+    ]),
+    ("RGI_Emoji_Flag_Sequence", &[
+        &[0x1F1E6, 0x1F1E8], &[0x1F1E6, 0x1F1E9], &[0x1F1E6, 0x1F1EA], &[0x1F1E6, 0x1F1EB],
+        &[0x1F1E6, 0x1F1EC], &[0x1F1E6, 0x1F1EE], &[0x1F1E6, 0x1F1F1], &[0x1F1E6, 0x1F1F2],
+        &[0x1F1E6, 0x1F1F4], &[0x1F1E6, 0x1F1F6], &[0x1F1E6, 0x1F1F7], &[0x1F1E6, 0x1F1F8],
+        &[0x1F1E6, 0x1F1F9], &[0x1F1E6, 0x1F1FA], &[0x1F1E6, 0x1F1FC], &[0x1F1E6, 0x1F1FD],
+        &[0x1F1E6, 0x1F1FF], &[0x1F1E7, 0x1F1E6], &[0x1F1E7, 0x1F1E7], &[0x1F1E7, 0x1F1E9],
+        &[0x1F1E7, 0x1F1EA], &[0x1F1E7, 0x1F1EB], &[0x1F1E7, 0x1F1EC], &[0x1F1E7, 0x1F1ED],
+        &[0x1F1E7, 0x1F1EE], &[0x1F1E7, 0x1F1EF], &[0x1F1E7, 0x1F1F1], &[0x1F1E7, 0x1F1F2],
+        &[0x1F1E7, 0x1F1F3], &[0x1F1E7, 0x1F1F4], &[0x1F1E7, 0x1F1F6], &[0x1F1E7, 0x1F1F7],
+        &[0x1F1E7, 0x1F1F8], &[0x1F1E7, 0x1F1F9], &[0x1F1E7, 0x1F1FB], &[0x1F1E7, 0x1F1FC],
+        &[0x1F1E7, 0x1F1FE], &[0x1F1E7, 0x1F1FF], &[0x1F1E8, 0x1F1E6], &[0x1F1E8, 0x1F1E8],
+        &[0x1F1E8, 0x1F1E9], &[0x1F1E8, 0x1F1EB], &[0x1F1E8, 0x1F1EC], &[0x1F1E8, 0x1F1ED],
+        &[0x1F1E8, 0x1F1EE], &[0x1F1E8, 0x1F1F0], &[0x1F1E8, 0x1F1F1], &[0x1F1E8, 0x1F1F2],
+        &[0x1F1E8, 0x1F1F3], &[0x1F1E8, 0x1F1F4], &[0x1F1E8, 0x1F1F5], &[0x1F1E8, 0x1F1F6],
+        &[0x1F1E8, 0x1F1F7], &[0x1F1E8, 0x1F1FA], &[0x1F1E8, 0x1F1FB], &[0x1F1E8, 0x1F1FC],
+        &[0x1F1E8, 0x1F1FD], &[0x1F1E8, 0x1F1FE], &[0x1F1E8, 0x1F1FF], &[0x1F1E9, 0x1F1EA],
+        &[0x1F1E9, 0x1F1EC], &[0x1F1E9, 0x1F1EF], &[0x1F1E9, 0x1F1F0], &[0x1F1E9, 0x1F1F2],
+        &[0x1F1E9, 0x1F1F4], &[0x1F1E9, 0x1F1FF], &[0x1F1EA, 0x1F1E6], &[0x1F1EA, 0x1F1E8],
+        &[0x1F1EA, 0x1F1EA], &[0x1F1EA, 0x1F1EC], &[0x1F1EA, 0x1F1ED], &[0x1F1EA, 0x1F1F7],
+        &[0x1F1EA, 0x1F1F8], &[0x1F1EA, 0x1F1F9], &[0x1F1EA, 0x1F1FA], &[0x1F1EB, 0x1F1EE],
+        &[0x1F1EB, 0x1F1EF], &[0x1F1EB, 0x1F1F0], &[0x1F1EB, 0x1F1F2], &[0x1F1EB, 0x1F1F4],
+        &[0x1F1EB, 0x1F1F7], &[0x1F1EC, 0x1F1E6], &[0x1F1EC, 0x1F1E7], &[0x1F1EC, 0x1F1E9],
+        &[0x1F1EC, 0x1F1EA], &[0x1F1EC, 0x1F1EB], &[0x1F1EC, 0x1F1EC], &[0x1F1EC, 0x1F1ED],
+        &[0x1F1EC, 0x1F1EE], &[0x1F1EC, 0x1F1F1], &[0x1F1EC, 0x1F1F2], &[0x1F1EC, 0x1F1F3],
+        &[0x1F1EC, 0x1F1F5], &[0x1F1EC, 0x1F1F6], &[0x1F1EC, 0x1F1F7], &[0x1F1EC, 0x1F1F8],
+        &[0x1F1EC, 0x1F1F9], &[0x1F1EC, 0x1F1FA], &[0x1F1EC, 0x1F1FC], &[0x1F1EC, 0x1F1FE],
+        &[0x1F1ED, 0x1F1F0], &[0x1F1ED, 0x1F1F2], &[0x1F1ED, 0x1F1F3], &[0x1F1ED, 0x1F1F7],
+        &[0x1F1ED, 0x1F1F9], &[0x1F1ED, 0x1F1FA], &[0x1F1EE, 0x1F1E8], &[0x1F1EE, 0x1F1E9],
+        &[0x1F1EE, 0x1F1EA], &[0x1F1EE, 0x1F1F1], &[0x1F1EE, 0x1F1F2], &[0x1F1EE, 0x1F1F3],
+        &[0x1F1EE, 0x1F1F4], &[0x1F1EE, 0x1F1F6], &[0x1F1EE, 0x1F1F7], &[0x1F1EE, 0x1F1F8],
+        &[0x1F1EE, 0x1F1F9], &[0x1F1EF, 0x1F1EA], &[0x1F1EF, 0x1F1F2], &[0x1F1EF, 0x1F1F4],
+        &[0x1F1EF, 0x1F1F5], &[0x1F1F0, 0x1F1EA], &[0x1F1F0, 0x1F1EC], &[0x1F1F0, 0x1F1ED],
+        &[0x1F1F0, 0x1F1EE], &[0x1F1F0, 0x1F1F2], &[0x1F1F0, 0x1F1F3], &[0x1F1F0, 0x1F1F5],
+        &[0x1F1F0, 0x1F1F7], &[0x1F1F0, 0x1F1FC], &[0x1F1F0, 0x1F1FE], &[0x1F1F0, 0x1F1FF],
+        &[0x1F1F1, 0x1F1E6], &[0x1F1F1, 0x1F1E7], &[0x1F1F1, 0x1F1E8], &[0x1F1F1, 0x1F1EE],
+        &[0x1F1F1, 0x1F1F0], &[0x1F1F1, 0x1F1F7], &[0x1F1F1, 0x1F1F8], &[0x1F1F1, 0x1F1F9],
+        &[0x1F1F1, 0x1F1FA], &[0x1F1F1, 0x1F1FB], &[0x1F1F1, 0x1F1FE], &[0x1F1F2, 0x1F1E6],
+        &[0x1F1F2, 0x1F1E8], &[0x1F1F2, 0x1F1E9], &[0x1F1F2, 0x1F1EA], &[0x1F1F2, 0x1F1EB],
+        &[0x1F1F2, 0x1F1EC], &[0x1F1F2, 0x1F1ED], &[0x1F1F2, 0x1F1F0], &[0x1F1F2, 0x1F1F1],
+        &[0x1F1F2, 0x1F1F2], &[0x1F1F2, 0x1F1F3], &[0x1F1F2, 0x1F1F4], &[0x1F1F2, 0x1F1F5],
+        &[0x1F1F2, 0x1F1F6], &[0x1F1F2, 0x1F1F7], &[0x1F1F2, 0x1F1F8], &[0x1F1F2, 0x1F1F9],
+        &[0x1F1F2, 0x1F1FA], &[0x1F1F2, 0x1F1FB], &[0x1F1F2, 0x1F1FC], &[0x1F1F2, 0x1F1FD],
+        &[0x1F1F2, 0x1F1FE], &[0x1F1F2, 0x1F1FF], &[0x1F1F3, 0x1F1E6], &[0x1F1F3, 0x1F1E8],
+        &[0x1F1F3, 0x1F1EA], &[0x1F1F3, 0x1F1EB], &[0x1F1F3, 0x1F1EC], &[0x1F1F3, 0x1F1EE],
+        &[0x1F1F3, 0x1F1F1], &[0x1F1F3, 0x1F1F4], &[0x1F1F3, 0x1F1F5], &[0x1F1F3, 0x1F1F7],
+        &[0x1F1F3, 0x1F1FA], &[0x1F1F3, 0x1F1FF], &[0x1F1F4, 0x1F1F2], &[0x1F1F5, 0x1F1E6],
+        &[0x1F1F5, 0x1F1EA], &[0x1F1F5, 0x1F1EB], &[0x1F1F5, 0x1F1EC], &[0x1F1F5, 0x1F1ED],
+        &[0x1F1F5, 0x1F1F0], &[0x1F1F5, 0x1F1F1], &[0x1F1F5, 0x1F1F2], &[0x1F1F5, 0x1F1F3],
+        &[0x1F1F5, 0x1F1F7], &[0x1F1F5, 0x1F1F8], &[0x1F1F5, 0x1F1F9], &[0x1F1F5, 0x1F1FC],
+        &[0x1F1F5, 0x1F1FE], &[0x1F1F6, 0x1F1E6], &[0x1F1F7, 0x1F1EA], &[0x1F1F7, 0x1F1F4],
+        &[0x1F1F7, 0x1F1F8], &[0x1F1F7, 0x1F1FA], &[0x1F1F7, 0x1F1FC], &[0x1F1F8, 0x1F1E6],
+        &[0x1F1F8, 0x1F1E7], &[0x1F1F8, 0x1F1E8], &[0x1F1F8, 0x1F1E9], &[0x1F1F8, 0x1F1EA],
+        &[0x1F1F8, 0x1F1EC], &[0x1F1F8, 0x1F1ED], &[0x1F1F8, 0x1F1EE], &[0x1F1F8, 0x1F1EF],
+        &[0x1F1F8, 0x1F1F0], &[0x1F1F8, 0x1F1F1], &[0x1F1F8, 0x1F1F2], &[0x1F1F8, 0x1F1F3],
+        &[0x1F1F8, 0x1F1F4], &[0x1F1F8, 0x1F1F7], &[0x1F1F8, 0x1F1F8], &[0x1F1F8, 0x1F1F9],
+        &[0x1F1F8, 0x1F1FB], &[0x1F1F8, 0x1F1FD], &[0x1F1F8, 0x1F1FE], &[0x1F1F8, 0x1F1FF],
+        &[0x1F1F9, 0x1F1E6], &[0x1F1F9, 0x1F1E8], &[0x1F1F9, 0x1F1E9], &[0x1F1F9, 0x1F1EB],
+        &[0x1F1F9, 0x1F1EC], &[0x1F1F9, 0x1F1ED], &[0x1F1F9, 0x1F1EF], &[0x1F1F9, 0x1F1F0],
+        &[0x1F1F9, 0x1F1F1], &[0x1F1F9, 0x1F1F2], &[0x1F1F9, 0x1F1F3], &[0x1F1F9, 0x1F1F4],
+        &[0x1F1F9, 0x1F1F7], &[0x1F1F9, 0x1F1F9], &[0x1F1F9, 0x1F1FB], &[0x1F1F9, 0x1F1FC],
+        &[0x1F1F9, 0x1F1FF], &[0x1F1FA, 0x1F1E6], &[0x1F1FA, 0x1F1EC], &[0x1F1FA, 0x1F1F2],
+        &[0x1F1FA, 0x1F1F3], &[0x1F1FA, 0x1F1F8], &[0x1F1FA, 0x1F1FE], &[0x1F1FA, 0x1F1FF],
+        &[0x1F1FB, 0x1F1E6], &[0x1F1FB, 0x1F1E8], &[0x1F1FB, 0x1F1EA], &[0x1F1FB, 0x1F1EC],
+        &[0x1F1FB, 0x1F1EE], &[0x1F1FB, 0x1F1F3], &[0x1F1FB, 0x1F1FA], &[0x1F1FC, 0x1F1EB],
+        &[0x1F1FC, 0x1F1F8], &[0x1F1FD, 0x1F1F0], &[0x1F1FE, 0x1F1EA], &[0x1F1FE, 0x1F1F9],
+        &[0x1F1FF, 0x1F1E6], &[0x1F1FF, 0x1F1F2], &[0x1F1FF, 0x1F1FC],
+    ]),
+    ("RGI_Emoji_Modifier_Sequence", &[
+        &[0x261D, 0x1F3FB], &[0x261D, 0x1F3FC], &[0x261D, 0x1F3FD], &[0x261D, 0x1F3FE],
+        &[0x261D, 0x1F3FF], &[0x26F9, 0x1F3FB], &[0x26F9, 0x1F3FC], &[0x26F9, 0x1F3FD],
+        &[0x26F9, 0x1F3FE], &[0x26F9, 0x1F3FF], &[0x270A, 0x1F3FB], &[0x270A, 0x1F3FC],
+        &[0x270A, 0x1F3FD], &[0x270A, 0x1F3FE], &[0x270A, 0x1F3FF], &[0x270B, 0x1F3FB],
+        &[0x270B, 0x1F3FC], &[0x270B, 0x1F3FD], &[0x270B, 0x1F3FE], &[0x270B, 0x1F3FF],
+        &[0x270C, 0x1F3FB], &[0x270C, 0x1F3FC], &[0x270C, 0x1F3FD], &[0x270C, 0x1F3FE],
+        &[0x270C, 0x1F3FF], &[0x270D, 0x1F3FB], &[0x270D, 0x1F3FC], &[0x270D, 0x1F3FD],
+        &[0x270D, 0x1F3FE], &[0x270D, 0x1F3FF], &[0x1F385, 0x1F3FB], &[0x1F385, 0x1F3FC],
+        &[0x1F385, 0x1F3FD], &[0x1F385, 0x1F3FE], &[0x1F385, 0x1F3FF], &[0x1F3C2, 0x1F3FB],
+        &[0x1F3C2, 0x1F3FC], &[0x1F3C2, 0x1F3FD], &[0x1F3C2, 0x1F3FE], &[0x1F3C2, 0x1F3FF],
+        &[0x1F3C3, 0x1F3FB], &[0x1F3C3, 0x1F3FC], &[0x1F3C3, 0x1F3FD], &[0x1F3C3, 0x1F3FE],
+        &[0x1F3C3, 0x1F3FF], &[0x1F3C4, 0x1F3FB], &[0x1F3C4, 0x1F3FC], &[0x1F3C4, 0x1F3FD],
+        &[0x1F3C4, 0x1F3FE], &[0x1F3C4, 0x1F3FF], &[0x1F3C7, 0x1F3FB], &[0x1F3C7, 0x1F3FC],
+        &[0x1F3C7, 0x1F3FD], &[0x1F3C7, 0x1F3FE], &[0x1F3C7, 0x1F3FF], &[0x1F3CA, 0x1F3FB],
+        &[0x1F3CA, 0x1F3FC], &[0x1F3CA, 0x1F3FD], &[0x1F3CA, 0x1F3FE], &[0x1F3CA, 0x1F3FF],
+        &[0x1F3CB, 0x1F3FB], &[0x1F3CB, 0x1F3FC], &[0x1F3CB, 0x1F3FD], &[0x1F3CB, 0x1F3FE],
+        &[0x1F3CB, 0x1F3FF], &[0x1F3CC, 0x1F3FB], &[0x1F3CC, 0x1F3FC], &[0x1F3CC, 0x1F3FD],
+        &[0x1F3CC, 0x1F3FE], &[0x1F3CC, 0x1F3FF], &[0x1F442, 0x1F3FB], &[0x1F442, 0x1F3FC],
+        &[0x1F442, 0x1F3FD], &[0x1F442, 0x1F3FE], &[0x1F442, 0x1F3FF], &[0x1F443, 0x1F3FB],
+        &[0x1F443, 0x1F3FC], &[0x1F443, 0x1F3FD], &[0x1F443, 0x1F3FE], &[0x1F443, 0x1F3FF],
+        &[0x1F446, 0x1F3FB], &[0x1F446, 0x1F3FC], &[0x1F446, 0x1F3FD], &[0x1F446, 0x1F3FE],
+        &[0x1F446, 0x1F3FF], &[0x1F447, 0x1F3FB], &[0x1F447, 0x1F3FC], &[0x1F447, 0x1F3FD],
+        &[0x1F447, 0x1F3FE], &[0x1F447, 0x1F3FF], &[0x1F448, 0x1F3FB], &[0x1F448, 0x1F3FC],
+        &[0x1F448, 0x1F3FD], &[0x1F448, 0x1F3FE], &[0x1F448, 0x1F3FF], &[0x1F449, 0x1F3FB],
+        &[0x1F449, 0x1F3FC], &[0x1F449, 0x1F3FD], &[0x1F449, 0x1F3FE], &[0x1F449, 0x1F3FF],
+        &[0x1F44A, 0x1F3FB], &[0x1F44A, 0x1F3FC], &[0x1F44A, 0x1F3FD], &[0x1F44A, 0x1F3FE],
+        &[0x1F44A, 0x1F3FF], &[0x1F44B, 0x1F3FB], &[0x1F44B, 0x1F3FC], &[0x1F44B, 0x1F3FD],
+        &[0x1F44B, 0x1F3FE], &[0x1F44B, 0x1F3FF], &[0x1F44C, 0x1F3FB], &[0x1F44C, 0x1F3FC],
+        &[0x1F44C, 0x1F3FD], &[0x1F44C, 0x1F3FE], &[0x1F44C, 0x1F3FF], &[0x1F44D, 0x1F3FB],
+        &[0x1F44D, 0x1F3FC], &[0x1F44D, 0x1F3FD], &[0x1F44D, 0x1F3FE], &[0x1F44D, 0x1F3FF],
+        &[0x1F44E, 0x1F3FB], &[0x1F44E, 0x1F3FC], &[0x1F44E, 0x1F3FD], &[0x1F44E, 0x1F3FE],
+        &[0x1F44E, 0x1F3FF], &[0x1F44F, 0x1F3FB], &[0x1F44F, 0x1F3FC], &[0x1F44F, 0x1F3FD],
+        &[0x1F44F, 0x1F3FE], &[0x1F44F, 0x1F3FF], &[0x1F450, 0x1F3FB], &[0x1F450, 0x1F3FC],
+        &[0x1F450, 0x1F3FD], &[0x1F450, 0x1F3FE], &[0x1F450, 0x1F3FF], &[0x1F466, 0x1F3FB],
+        &[0x1F466, 0x1F3FC], &[0x1F466, 0x1F3FD], &[0x1F466, 0x1F3FE], &[0x1F466, 0x1F3FF],
+        &[0x1F467, 0x1F3FB], &[0x1F467, 0x1F3FC], &[0x1F467, 0x1F3FD], &[0x1F467, 0x1F3FE],
+        &[0x1F467, 0x1F3FF], &[0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FD],
+        &[0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FF], &[0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FC],
+        &[0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FF], &[0x1F46B, 0x1F3FB],
+        &[0x1F46B, 0x1F3FC], &[0x1F46B, 0x1F3FD], &[0x1F46B, 0x1F3FE], &[0x1F46B, 0x1F3FF],
+        &[0x1F46C, 0x1F3FB], &[0x1F46C, 0x1F3FC], &[0x1F46C, 0x1F3FD], &[0x1F46C, 0x1F3FE],
+        &[0x1F46C, 0x1F3FF], &[0x1F46D, 0x1F3FB], &[0x1F46D, 0x1F3FC], &[0x1F46D, 0x1F3FD],
+        &[0x1F46D, 0x1F3FE], &[0x1F46D, 0x1F3FF], &[0x1F46E, 0x1F3FB], &[0x1F46E, 0x1F3FC],
+        &[0x1F46E, 0x1F3FD], &[0x1F46E, 0x1F3FE], &[0x1F46E, 0x1F3FF], &[0x1F46F, 0x1F3FB],
+        &[0x1F46F, 0x1F3FC], &[0x1F46F, 0x1F3FD], &[0x1F46F, 0x1F3FE], &[0x1F46F, 0x1F3FF],
+        &[0x1F470, 0x1F3FB], &[0x1F470, 0x1F3FC], &[0x1F470, 0x1F3FD], &[0x1F470, 0x1F3FE],
+        &[0x1F470, 0x1F3FF], &[0x1F471, 0x1F3FB], &[0x1F471, 0x1F3FC], &[0x1F471, 0x1F3FD],
+        &[0x1F471, 0x1F3FE], &[0x1F471, 0x1F3FF], &[0x1F472, 0x1F3FB], &[0x1F472, 0x1F3FC],
+        &[0x1F472, 0x1F3FD], &[0x1F472, 0x1F3FE], &[0x1F472, 0x1F3FF], &[0x1F473, 0x1F3FB],
+        &[0x1F473, 0x1F3FC], &[0x1F473, 0x1F3FD], &[0x1F473, 0x1F3FE], &[0x1F473, 0x1F3FF],
+        &[0x1F474, 0x1F3FB], &[0x1F474, 0x1F3FC], &[0x1F474, 0x1F3FD], &[0x1F474, 0x1F3FE],
+        &[0x1F474, 0x1F3FF], &[0x1F475, 0x1F3FB], &[0x1F475, 0x1F3FC], &[0x1F475, 0x1F3FD],
+        &[0x1F475, 0x1F3FE], &[0x1F475, 0x1F3FF], &[0x1F476, 0x1F3FB], &[0x1F476, 0x1F3FC],
+        &[0x1F476, 0x1F3FD], &[0x1F476, 0x1F3FE], &[0x1F476, 0x1F3FF], &[0x1F477, 0x1F3FB],
+        &[0x1F477, 0x1F3FC], &[0x1F477, 0x1F3FD], &[0x1F477, 0x1F3FE], &[0x1F477, 0x1F3FF],
+        &[0x1F478, 0x1F3FB], &[0x1F478, 0x1F3FC], &[0x1F478, 0x1F3FD], &[0x1F478, 0x1F3FE],
+        &[0x1F478, 0x1F3FF], &[0x1F47C, 0x1F3FB], &[0x1F47C, 0x1F3FC], &[0x1F47C, 0x1F3FD],
+        &[0x1F47C, 0x1F3FE], &[0x1F47C, 0x1F3FF], &[0x1F481, 0x1F3FB], &[0x1F481, 0x1F3FC],
+        &[0x1F481, 0x1F3FD], &[0x1F481, 0x1F3FE], &[0x1F481, 0x1F3FF], &[0x1F482, 0x1F3FB],
+        &[0x1F482, 0x1F3FC], &[0x1F482, 0x1F3FD], &[0x1F482, 0x1F3FE], &[0x1F482, 0x1F3FF],
+        &[0x1F483, 0x1F3FB], &[0x1F483, 0x1F3FC], &[0x1F483, 0x1F3FD], &[0x1F483, 0x1F3FE],
+        &[0x1F483, 0x1F3FF], &[0x1F485, 0x1F3FB], &[0x1F485, 0x1F3FC], &[0x1F485, 0x1F3FD],
+        &[0x1F485, 0x1F3FE], &[0x1F485, 0x1F3FF], &[0x1F486, 0x1F3FB], &[0x1F486, 0x1F3FC],
+        &[0x1F486, 0x1F3FD], &[0x1F486, 0x1F3FE], &[0x1F486, 0x1F3FF], &[0x1F487, 0x1F3FB],
+        &[0x1F487, 0x1F3FC], &[0x1F487, 0x1F3FD], &[0x1F487, 0x1F3FE], &[0x1F487, 0x1F3FF],
+        &[0x1F48F, 0x1F3FB], &[0x1F48F, 0x1F3FC], &[0x1F48F, 0x1F3FD], &[0x1F48F, 0x1F3FE],
+        &[0x1F48F, 0x1F3FF], &[0x1F491, 0x1F3FB], &[0x1F491, 0x1F3FC], &[0x1F491, 0x1F3FD],
+        &[0x1F491, 0x1F3FE], &[0x1F491, 0x1F3FF], &[0x1F4AA, 0x1F3FB], &[0x1F4AA, 0x1F3FC],
+        &[0x1F4AA, 0x1F3FD], &[0x1F4AA, 0x1F3FE], &[0x1F4AA, 0x1F3FF], &[0x1F574, 0x1F3FB],
+        &[0x1F574, 0x1F3FC], &[0x1F574, 0x1F3FD], &[0x1F574, 0x1F3FE], &[0x1F574, 0x1F3FF],
+        &[0x1F575, 0x1F3FB], &[0x1F575, 0x1F3FC], &[0x1F575, 0x1F3FD], &[0x1F575, 0x1F3FE],
+        &[0x1F575, 0x1F3FF], &[0x1F57A, 0x1F3FB], &[0x1F57A, 0x1F3FC], &[0x1F57A, 0x1F3FD],
+        &[0x1F57A, 0x1F3FE], &[0x1F57A, 0x1F3FF], &[0x1F590, 0x1F3FB], &[0x1F590, 0x1F3FC],
+        &[0x1F590, 0x1F3FD], &[0x1F590, 0x1F3FE], &[0x1F590, 0x1F3FF], &[0x1F595, 0x1F3FB],
+        &[0x1F595, 0x1F3FC], &[0x1F595, 0x1F3FD], &[0x1F595, 0x1F3FE], &[0x1F595, 0x1F3FF],
+        &[0x1F596, 0x1F3FB], &[0x1F596, 0x1F3FC], &[0x1F596, 0x1F3FD], &[0x1F596, 0x1F3FE],
+        &[0x1F596, 0x1F3FF], &[0x1F645, 0x1F3FB], &[0x1F645, 0x1F3FC], &[0x1F645, 0x1F3FD],
+        &[0x1F645, 0x1F3FE], &[0x1F645, 0x1F3FF], &[0x1F646, 0x1F3FB], &[0x1F646, 0x1F3FC],
+        &[0x1F646, 0x1F3FD], &[0x1F646, 0x1F3FE], &[0x1F646, 0x1F3FF], &[0x1F647, 0x1F3FB],
+        &[0x1F647, 0x1F3FC], &[0x1F647, 0x1F3FD], &[0x1F647, 0x1F3FE], &[0x1F647, 0x1F3FF],
+        &[0x1F64B, 0x1F3FB], &[0x1F64B, 0x1F3FC], &[0x1F64B, 0x1F3FD], &[0x1F64B, 0x1F3FE],
+        &[0x1F64B, 0x1F3FF], &[0x1F64C, 0x1F3FB], &[0x1F64C, 0x1F3FC], &[0x1F64C, 0x1F3FD],
+        &[0x1F64C, 0x1F3FE], &[0x1F64C, 0x1F3FF], &[0x1F64D, 0x1F3FB], &[0x1F64D, 0x1F3FC],
+        &[0x1F64D, 0x1F3FD], &[0x1F64D, 0x1F3FE], &[0x1F64D, 0x1F3FF], &[0x1F64E, 0x1F3FB],
+        &[0x1F64E, 0x1F3FC], &[0x1F64E, 0x1F3FD], &[0x1F64E, 0x1F3FE], &[0x1F64E, 0x1F3FF],
+        &[0x1F64F, 0x1F3FB], &[0x1F64F, 0x1F3FC], &[0x1F64F, 0x1F3FD], &[0x1F64F, 0x1F3FE],
+        &[0x1F64F, 0x1F3FF], &[0x1F6A3, 0x1F3FB], &[0x1F6A3, 0x1F3FC], &[0x1F6A3, 0x1F3FD],
+        &[0x1F6A3, 0x1F3FE], &[0x1F6A3, 0x1F3FF], &[0x1F6B4, 0x1F3FB], &[0x1F6B4, 0x1F3FC],
+        &[0x1F6B4, 0x1F3FD], &[0x1F6B4, 0x1F3FE], &[0x1F6B4, 0x1F3FF], &[0x1F6B5, 0x1F3FB],
+        &[0x1F6B5, 0x1F3FC], &[0x1F6B5, 0x1F3FD], &[0x1F6B5, 0x1F3FE], &[0x1F6B5, 0x1F3FF],
+        &[0x1F6B6, 0x1F3FB], &[0x1F6B6, 0x1F3FC], &[0x1F6B6, 0x1F3FD], &[0x1F6B6, 0x1F3FE],
+        &[0x1F6B6, 0x1F3FF], &[0x1F6C0, 0x1F3FB], &[0x1F6C0, 0x1F3FC], &[0x1F6C0, 0x1F3FD],
+        &[0x1F6C0, 0x1F3FE], &[0x1F6C0, 0x1F3FF], &[0x1F6CC, 0x1F3FB], &[0x1F6CC, 0x1F3FC],
+        &[0x1F6CC, 0x1F3FD], &[0x1F6CC, 0x1F3FE], &[0x1F6CC, 0x1F3FF], &[0x1F90C, 0x1F3FB],
+        &[0x1F90C, 0x1F3FC], &[0x1F90C, 0x1F3FD], &[0x1F90C, 0x1F3FE], &[0x1F90C, 0x1F3FF],
+        &[0x1F90F, 0x1F3FB], &[0x1F90F, 0x1F3FC], &[0x1F90F, 0x1F3FD], &[0x1F90F, 0x1F3FE],
+        &[0x1F90F, 0x1F3FF], &[0x1F918, 0x1F3FB], &[0x1F918, 0x1F3FC], &[0x1F918, 0x1F3FD],
+        &[0x1F918, 0x1F3FE], &[0x1F918, 0x1F3FF], &[0x1F919, 0x1F3FB], &[0x1F919, 0x1F3FC],
+        &[0x1F919, 0x1F3FD], &[0x1F919, 0x1F3FE], &[0x1F919, 0x1F3FF], &[0x1F91A, 0x1F3FB],
+        &[0x1F91A, 0x1F3FC], &[0x1F91A, 0x1F3FD], &[0x1F91A, 0x1F3FE], &[0x1F91A, 0x1F3FF],
+        &[0x1F91B, 0x1F3FB], &[0x1F91B, 0x1F3FC], &[0x1F91B, 0x1F3FD], &[0x1F91B, 0x1F3FE],
+        &[0x1F91B, 0x1F3FF], &[0x1F91C, 0x1F3FB], &[0x1F91C, 0x1F3FC], &[0x1F91C, 0x1F3FD],
+        &[0x1F91C, 0x1F3FE], &[0x1F91C, 0x1F3FF], &[0x1F91D, 0x1F3FB], &[0x1F91D, 0x1F3FC],
+        &[0x1F91D, 0x1F3FD], &[0x1F91D, 0x1F3FE], &[0x1F91D, 0x1F3FF], &[0x1F91E, 0x1F3FB],
+        &[0x1F91E, 0x1F3FC], &[0x1F91E, 0x1F3FD], &[0x1F91E, 0x1F3FE], &[0x1F91E, 0x1F3FF],
+        &[0x1F91F, 0x1F3FB], &[0x1F91F, 0x1F3FC], &[0x1F91F, 0x1F3FD], &[0x1F91F, 0x1F3FE],
+        &[0x1F91F, 0x1F3FF], &[0x1F926, 0x1F3FB], &[0x1F926, 0x1F3FC], &[0x1F926, 0x1F3FD],
+        &[0x1F926, 0x1F3FE], &[0x1F926, 0x1F3FF], &[0x1F930, 0x1F3FB], &[0x1F930, 0x1F3FC],
+        &[0x1F930, 0x1F3FD], &[0x1F930, 0x1F3FE], &[0x1F930, 0x1F3FF], &[0x1F931, 0x1F3FB],
+        &[0x1F931, 0x1F3FC], &[0x1F931, 0x1F3FD], &[0x1F931, 0x1F3FE], &[0x1F931, 0x1F3FF],
+        &[0x1F932, 0x1F3FB], &[0x1F932, 0x1F3FC], &[0x1F932, 0x1F3FD], &[0x1F932, 0x1F3FE],
+        &[0x1F932, 0x1F3FF], &[0x1F933, 0x1F3FB], &[0x1F933, 0x1F3FC], &[0x1F933, 0x1F3FD],
+        &[0x1F933, 0x1F3FE], &[0x1F933, 0x1F3FF], &[0x1F934, 0x1F3FB], &[0x1F934, 0x1F3FC],
+        &[0x1F934, 0x1F3FD], &[0x1F934, 0x1F3FE], &[0x1F934, 0x1F3FF], &[0x1F935, 0x1F3FB],
+        &[0x1F935, 0x1F3FC], &[0x1F935, 0x1F3FD], &[0x1F935, 0x1F3FE], &[0x1F935, 0x1F3FF],
+        &[0x1F936, 0x1F3FB], &[0x1F936, 0x1F3FC], &[0x1F936, 0x1F3FD], &[0x1F936, 0x1F3FE],
+        &[0x1F936, 0x1F3FF], &[0x1F937, 0x1F3FB], &[0x1F937, 0x1F3FC], &[0x1F937, 0x1F3FD],
+        &[0x1F937, 0x1F3FE], &[0x1F937, 0x1F3FF], &[0x1F938, 0x1F3FB], &[0x1F938, 0x1F3FC],
+        &[0x1F938, 0x1F3FD], &[0x1F938, 0x1F3FE], &[0x1F938, 0x1F3FF], &[0x1F939, 0x1F3FB],
+        &[0x1F939, 0x1F3FC], &[0x1F939, 0x1F3FD], &[0x1F939, 0x1F3FE], &[0x1F939, 0x1F3FF],
+        &[0x1F93C, 0x1F3FB], &[0x1F93C, 0x1F3FC], &[0x1F93C, 0x1F3FD], &[0x1F93C, 0x1F3FE],
+        &[0x1F93C, 0x1F3FF], &[0x1F93D, 0x1F3FB], &[0x1F93D, 0x1F3FC], &[0x1F93D, 0x1F3FD],
+        &[0x1F93D, 0x1F3FE], &[0x1F93D, 0x1F3FF], &[0x1F93E, 0x1F3FB], &[0x1F93E, 0x1F3FC],
+        &[0x1F93E, 0x1F3FD], &[0x1F93E, 0x1F3FE], &[0x1F93E, 0x1F3FF], &[0x1F977, 0x1F3FB],
+        &[0x1F977, 0x1F3FC], &[0x1F977, 0x1F3FD], &[0x1F977, 0x1F3FE], &[0x1F977, 0x1F3FF],
+        &[0x1F9B5, 0x1F3FB], &[0x1F9B5, 0x1F3FC], &[0x1F9B5, 0x1F3FD], &[0x1F9B5, 0x1F3FE],
+        &[0x1F9B5, 0x1F3FF], &[0x1F9B6, 0x1F3FB], &[0x1F9B6, 0x1F3FC], &[0x1F9B6, 0x1F3FD],
+        &[0x1F9B6, 0x1F3FE], &[0x1F9B6, 0x1F3FF], &[0x1F9B8, 0x1F3FB], &[0x1F9B8, 0x1F3FC],
+        &[0x1F9B8, 0x1F3FD], &[0x1F9B8, 0x1F3FE], &[0x1F9B8, 0x1F3FF], &[0x1F9B9, 0x1F3FB],
+        &[0x1F9B9, 0x1F3FC], &[0x1F9B9, 0x1F3FD], &[0x1F9B9, 0x1F3FE], &[0x1F9B9, 0x1F3FF],
+        &[0x1F9BB, 0x1F3FB], &[0x1F9BB, 0x1F3FC], &[0x1F9BB, 0x1F3FD], &[0x1F9BB, 0x1F3FE],
+        &[0x1F9BB, 0x1F3FF], &[0x1F9CD, 0x1F3FB], &[0x1F9CD, 0x1F3FC], &[0x1F9CD, 0x1F3FD],
+        &[0x1F9CD, 0x1F3FE], &[0x1F9CD, 0x1F3FF], &[0x1F9CE, 0x1F3FB], &[0x1F9CE, 0x1F3FC],
+        &[0x1F9CE, 0x1F3FD], &[0x1F9CE, 0x1F3FE], &[0x1F9CE, 0x1F3FF], &[0x1F9CF, 0x1F3FB],
+        &[0x1F9CF, 0x1F3FC], &[0x1F9CF, 0x1F3FD], &[0x1F9CF, 0x1F3FE], &[0x1F9CF, 0x1F3FF],
+        &[0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FC], &[0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FE],
+        &[0x1F9D1, 0x1F3FF], &[0x1F9D2, 0x1F3FB], &[0x1F9D2, 0x1F3FC], &[0x1F9D2, 0x1F3FD],
+        &[0x1F9D2, 0x1F3FE], &[0x1F9D2, 0x1F3FF], &[0x1F9D3, 0x1F3FB], &[0x1F9D3, 0x1F3FC],
+        &[0x1F9D3, 0x1F3FD], &[0x1F9D3, 0x1F3FE], &[0x1F9D3, 0x1F3FF], &[0x1F9D4, 0x1F3FB],
+        &[0x1F9D4, 0x1F3FC], &[0x1F9D4, 0x1F3FD], &[0x1F9D4, 0x1F3FE], &[0x1F9D4, 0x1F3FF],
+        &[0x1F9D5, 0x1F3FB], &[0x1F9D5, 0x1F3FC], &[0x1F9D5, 0x1F3FD], &[0x1F9D5, 0x1F3FE],
+        &[0x1F9D5, 0x1F3FF], &[0x1F9D6, 0x1F3FB], &[0x1F9D6, 0x1F3FC], &[0x1F9D6, 0x1F3FD],
+        &[0x1F9D6, 0x1F3FE], &[0x1F9D6, 0x1F3FF], &[0x1F9D7, 0x1F3FB], &[0x1F9D7, 0x1F3FC],
+        &[0x1F9D7, 0x1F3FD], &[0x1F9D7, 0x1F3FE], &[0x1F9D7, 0x1F3FF], &[0x1F9D8, 0x1F3FB],
+        &[0x1F9D8, 0x1F3FC], &[0x1F9D8, 0x1F3FD], &[0x1F9D8, 0x1F3FE], &[0x1F9D8, 0x1F3FF],
+        &[0x1F9D9, 0x1F3FB], &[0x1F9D9, 0x1F3FC], &[0x1F9D9, 0x1F3FD], &[0x1F9D9, 0x1F3FE],
+        &[0x1F9D9, 0x1F3FF], &[0x1F9DA, 0x1F3FB], &[0x1F9DA, 0x1F3FC], &[0x1F9DA, 0x1F3FD],
+        &[0x1F9DA, 0x1F3FE], &[0x1F9DA, 0x1F3FF], &[0x1F9DB, 0x1F3FB], &[0x1F9DB, 0x1F3FC],
+        &[0x1F9DB, 0x1F3FD], &[0x1F9DB, 0x1F3FE], &[0x1F9DB, 0x1F3FF], &[0x1F9DC, 0x1F3FB],
+        &[0x1F9DC, 0x1F3FC], &[0x1F9DC, 0x1F3FD], &[0x1F9DC, 0x1F3FE], &[0x1F9DC, 0x1F3FF],
+        &[0x1F9DD, 0x1F3FB], &[0x1F9DD, 0x1F3FC], &[0x1F9DD, 0x1F3FD], &[0x1F9DD, 0x1F3FE],
+        &[0x1F9DD, 0x1F3FF], &[0x1FAC3, 0x1F3FB], &[0x1FAC3, 0x1F3FC], &[0x1FAC3, 0x1F3FD],
+        &[0x1FAC3, 0x1F3FE], &[0x1FAC3, 0x1F3FF], &[0x1FAC4, 0x1F3FB], &[0x1FAC4, 0x1F3FC],
+        &[0x1FAC4, 0x1F3FD], &[0x1FAC4, 0x1F3FE], &[0x1FAC4, 0x1F3FF], &[0x1FAC5, 0x1F3FB],
+        &[0x1FAC5, 0x1F3FC], &[0x1FAC5, 0x1F3FD], &[0x1FAC5, 0x1F3FE], &[0x1FAC5, 0x1F3FF],
+        &[0x1FAF0, 0x1F3FB], &[0x1FAF0, 0x1F3FC], &[0x1FAF0, 0x1F3FD], &[0x1FAF0, 0x1F3FE],
+        &[0x1FAF0, 0x1F3FF], &[0x1FAF1, 0x1F3FB], &[0x1FAF1, 0x1F3FC], &[0x1FAF1, 0x1F3FD],
+        &[0x1FAF1, 0x1F3FE], &[0x1FAF1, 0x1F3FF], &[0x1FAF2, 0x1F3FB], &[0x1FAF2, 0x1F3FC],
+        &[0x1FAF2, 0x1F3FD], &[0x1FAF2, 0x1F3FE], &[0x1FAF2, 0x1F3FF], &[0x1FAF3, 0x1F3FB],
+        &[0x1FAF3, 0x1F3FC], &[0x1FAF3, 0x1F3FD], &[0x1FAF3, 0x1F3FE], &[0x1FAF3, 0x1F3FF],
+        &[0x1FAF4, 0x1F3FB], &[0x1FAF4, 0x1F3FC], &[0x1FAF4, 0x1F3FD], &[0x1FAF4, 0x1F3FE],
+        &[0x1FAF4, 0x1F3FF], &[0x1FAF5, 0x1F3FB], &[0x1FAF5, 0x1F3FC], &[0x1FAF5, 0x1F3FD],
+        &[0x1FAF5, 0x1F3FE], &[0x1FAF5, 0x1F3FF], &[0x1FAF6, 0x1F3FB], &[0x1FAF6, 0x1F3FC],
+        &[0x1FAF6, 0x1F3FD], &[0x1FAF6, 0x1F3FE], &[0x1FAF6, 0x1F3FF], &[0x1FAF7, 0x1F3FB],
+        &[0x1FAF7, 0x1F3FC], &[0x1FAF7, 0x1F3FD], &[0x1FAF7, 0x1F3FE], &[0x1FAF7, 0x1F3FF],
+        &[0x1FAF8, 0x1F3FB], &[0x1FAF8, 0x1F3FC], &[0x1FAF8, 0x1F3FD], &[0x1FAF8, 0x1F3FE],
+        &[0x1FAF8, 0x1F3FF],
+    ]),
+    ("RGI_Emoji_Tag_Sequence", &[
+        &[0x1F3F4, 0xE0067, 0xE0062, 0xE0065, 0xE006E, 0xE0067, 0xE007F], &[0x1F3F4, 0xE0067, 0xE0062, 0xE0073, 0xE0063, 0xE0074, 0xE007F], &[0x1F3F4, 0xE0067, 0xE0062, 0xE0077, 0xE006C, 0xE0073, 0xE007F],
+    ]),
+    ("RGI_Emoji_ZWJ_Sequence", &[
+        &[0x26D3, 0xFE0F, 0x200D, 0x1F4A5], &[0x26F9, 0xFE0F, 0x200D, 0x2640, 0xFE0F], &[0x26F9, 0xFE0F, 0x200D, 0x2642, 0xFE0F], &[0x26F9, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+        &[0x26F9, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x26F9, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x26F9, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x26F9, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+        &[0x26F9, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x26F9, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x26F9, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x26F9, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+        &[0x26F9, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x2764, 0xFE0F, 0x200D, 0x1F525], &[0x2764, 0xFE0F, 0x200D, 0x1FA79], &[0x1F344, 0x200D, 0x1F7EB],
+        &[0x1F34B, 0x200D, 0x1F7E9], &[0x1F3C3, 0x200D, 0x2640, 0xFE0F], &[0x1F3C3, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F3C3, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F3C3, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F3C3, 0x200D, 0x27A1, 0xFE0F], &[0x1F3C3, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F3C3, 0x1F3FB, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F3C3, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F3C3, 0x1F3FB, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F3C3, 0x1F3FB, 0x200D, 0x27A1, 0xFE0F], &[0x1F3C3, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F3C3, 0x1F3FC, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F3C3, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F3C3, 0x1F3FC, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F3C3, 0x1F3FC, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F3C3, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F3C3, 0x1F3FD, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F3C3, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F3C3, 0x1F3FD, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F3C3, 0x1F3FD, 0x200D, 0x27A1, 0xFE0F], &[0x1F3C3, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F3C3, 0x1F3FE, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F3C3, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F3C3, 0x1F3FE, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F3C3, 0x1F3FE, 0x200D, 0x27A1, 0xFE0F], &[0x1F3C3, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F3C3, 0x1F3FF, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F3C3, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F3C3, 0x1F3FF, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F3C3, 0x1F3FF, 0x200D, 0x27A1, 0xFE0F], &[0x1F3C4, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F3C4, 0x200D, 0x2642, 0xFE0F], &[0x1F3C4, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F3C4, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F3C4, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F3C4, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F3C4, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F3C4, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F3C4, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F3C4, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F3C4, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F3C4, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F3CA, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F3CA, 0x200D, 0x2642, 0xFE0F], &[0x1F3CA, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F3CA, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F3CA, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F3CA, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F3CA, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F3CA, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F3CA, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F3CA, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F3CA, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F3CA, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F3CB, 0xFE0F, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F3CB, 0xFE0F, 0x200D, 0x2642, 0xFE0F], &[0x1F3CB, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F3CB, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F3CB, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F3CB, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F3CB, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F3CB, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F3CB, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F3CB, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F3CB, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F3CB, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F3CC, 0xFE0F, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F3CC, 0xFE0F, 0x200D, 0x2642, 0xFE0F], &[0x1F3CC, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F3CC, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F3CC, 0x1F3FC, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F3CC, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F3CC, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F3CC, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F3CC, 0x1F3FE, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F3CC, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F3CC, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F3CC, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F3F3, 0xFE0F, 0x200D, 0x26A7, 0xFE0F],
+        &[0x1F3F3, 0xFE0F, 0x200D, 0x1F308], &[0x1F3F4, 0x200D, 0x2620, 0xFE0F], &[0x1F408, 0x200D, 0x2B1B], &[0x1F415, 0x200D, 0x1F9BA],
+        &[0x1F426, 0x200D, 0x2B1B], &[0x1F426, 0x200D, 0x1F525], &[0x1F43B, 0x200D, 0x2744, 0xFE0F], &[0x1F441, 0xFE0F, 0x200D, 0x1F5E8, 0xFE0F],
+        &[0x1F468, 0x200D, 0x2695, 0xFE0F], &[0x1F468, 0x200D, 0x2696, 0xFE0F], &[0x1F468, 0x200D, 0x2708, 0xFE0F], &[0x1F468, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468],
+        &[0x1F468, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468], &[0x1F468, 0x200D, 0x1F33E], &[0x1F468, 0x200D, 0x1F373], &[0x1F468, 0x200D, 0x1F37C],
+        &[0x1F468, 0x200D, 0x1F393], &[0x1F468, 0x200D, 0x1F3A4], &[0x1F468, 0x200D, 0x1F3A8], &[0x1F468, 0x200D, 0x1F3EB],
+        &[0x1F468, 0x200D, 0x1F3ED], &[0x1F468, 0x200D, 0x1F466], &[0x1F468, 0x200D, 0x1F466, 0x200D, 0x1F466], &[0x1F468, 0x200D, 0x1F467],
+        &[0x1F468, 0x200D, 0x1F467, 0x200D, 0x1F466], &[0x1F468, 0x200D, 0x1F467, 0x200D, 0x1F467], &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F466], &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F466, 0x200D, 0x1F466],
+        &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F467], &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F467, 0x200D, 0x1F466], &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F467, 0x200D, 0x1F467], &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F466],
+        &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F466, 0x200D, 0x1F466], &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F467], &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F466], &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F467],
+        &[0x1F468, 0x200D, 0x1F4BB], &[0x1F468, 0x200D, 0x1F4BC], &[0x1F468, 0x200D, 0x1F527], &[0x1F468, 0x200D, 0x1F52C],
+        &[0x1F468, 0x200D, 0x1F680], &[0x1F468, 0x200D, 0x1F692], &[0x1F468, 0x200D, 0x1F9AF], &[0x1F468, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F468, 0x200D, 0x1F9B0], &[0x1F468, 0x200D, 0x1F9B1], &[0x1F468, 0x200D, 0x1F9B2], &[0x1F468, 0x200D, 0x1F9B3],
+        &[0x1F468, 0x200D, 0x1F9BC], &[0x1F468, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F468, 0x200D, 0x1F9BD], &[0x1F468, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F468, 0x1F3FB, 0x200D, 0x2695, 0xFE0F], &[0x1F468, 0x1F3FB, 0x200D, 0x2696, 0xFE0F], &[0x1F468, 0x1F3FB, 0x200D, 0x2708, 0xFE0F], &[0x1F468, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FB],
+        &[0x1F468, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FF],
+        &[0x1F468, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FE],
+        &[0x1F468, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FB, 0x200D, 0x1F33E], &[0x1F468, 0x1F3FB, 0x200D, 0x1F373], &[0x1F468, 0x1F3FB, 0x200D, 0x1F37C],
+        &[0x1F468, 0x1F3FB, 0x200D, 0x1F393], &[0x1F468, 0x1F3FB, 0x200D, 0x1F3A4], &[0x1F468, 0x1F3FB, 0x200D, 0x1F3A8], &[0x1F468, 0x1F3FB, 0x200D, 0x1F3EB],
+        &[0x1F468, 0x1F3FB, 0x200D, 0x1F3ED], &[0x1F468, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FE],
+        &[0x1F468, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FB, 0x200D, 0x1F4BB], &[0x1F468, 0x1F3FB, 0x200D, 0x1F4BC], &[0x1F468, 0x1F3FB, 0x200D, 0x1F527],
+        &[0x1F468, 0x1F3FB, 0x200D, 0x1F52C], &[0x1F468, 0x1F3FB, 0x200D, 0x1F680], &[0x1F468, 0x1F3FB, 0x200D, 0x1F692], &[0x1F468, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC],
+        &[0x1F468, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FB, 0x200D, 0x1F9AF],
+        &[0x1F468, 0x1F3FB, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F468, 0x1F3FB, 0x200D, 0x1F9B0], &[0x1F468, 0x1F3FB, 0x200D, 0x1F9B1], &[0x1F468, 0x1F3FB, 0x200D, 0x1F9B2],
+        &[0x1F468, 0x1F3FB, 0x200D, 0x1F9B3], &[0x1F468, 0x1F3FB, 0x200D, 0x1F9BC], &[0x1F468, 0x1F3FB, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F468, 0x1F3FB, 0x200D, 0x1F9BD],
+        &[0x1F468, 0x1F3FB, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F468, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FE],
+        &[0x1F468, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FC, 0x200D, 0x2695, 0xFE0F], &[0x1F468, 0x1F3FC, 0x200D, 0x2696, 0xFE0F], &[0x1F468, 0x1F3FC, 0x200D, 0x2708, 0xFE0F],
+        &[0x1F468, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FE],
+        &[0x1F468, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FD],
+        &[0x1F468, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FC, 0x200D, 0x1F33E], &[0x1F468, 0x1F3FC, 0x200D, 0x1F373],
+        &[0x1F468, 0x1F3FC, 0x200D, 0x1F37C], &[0x1F468, 0x1F3FC, 0x200D, 0x1F393], &[0x1F468, 0x1F3FC, 0x200D, 0x1F3A4], &[0x1F468, 0x1F3FC, 0x200D, 0x1F3A8],
+        &[0x1F468, 0x1F3FC, 0x200D, 0x1F3EB], &[0x1F468, 0x1F3FC, 0x200D, 0x1F3ED], &[0x1F468, 0x1F3FC, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FC, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FD],
+        &[0x1F468, 0x1F3FC, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FC, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FC, 0x200D, 0x1F4BB], &[0x1F468, 0x1F3FC, 0x200D, 0x1F4BC],
+        &[0x1F468, 0x1F3FC, 0x200D, 0x1F527], &[0x1F468, 0x1F3FC, 0x200D, 0x1F52C], &[0x1F468, 0x1F3FC, 0x200D, 0x1F680], &[0x1F468, 0x1F3FC, 0x200D, 0x1F692],
+        &[0x1F468, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF],
+        &[0x1F468, 0x1F3FC, 0x200D, 0x1F9AF], &[0x1F468, 0x1F3FC, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F468, 0x1F3FC, 0x200D, 0x1F9B0], &[0x1F468, 0x1F3FC, 0x200D, 0x1F9B1],
+        &[0x1F468, 0x1F3FC, 0x200D, 0x1F9B2], &[0x1F468, 0x1F3FC, 0x200D, 0x1F9B3], &[0x1F468, 0x1F3FC, 0x200D, 0x1F9BC], &[0x1F468, 0x1F3FC, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F468, 0x1F3FC, 0x200D, 0x1F9BD], &[0x1F468, 0x1F3FC, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F468, 0x1F3FC, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FC, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FD],
+        &[0x1F468, 0x1F3FC, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FC, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FD, 0x200D, 0x2695, 0xFE0F], &[0x1F468, 0x1F3FD, 0x200D, 0x2696, 0xFE0F],
+        &[0x1F468, 0x1F3FD, 0x200D, 0x2708, 0xFE0F], &[0x1F468, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FD],
+        &[0x1F468, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FC],
+        &[0x1F468, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FD, 0x200D, 0x1F33E],
+        &[0x1F468, 0x1F3FD, 0x200D, 0x1F373], &[0x1F468, 0x1F3FD, 0x200D, 0x1F37C], &[0x1F468, 0x1F3FD, 0x200D, 0x1F393], &[0x1F468, 0x1F3FD, 0x200D, 0x1F3A4],
+        &[0x1F468, 0x1F3FD, 0x200D, 0x1F3A8], &[0x1F468, 0x1F3FD, 0x200D, 0x1F3EB], &[0x1F468, 0x1F3FD, 0x200D, 0x1F3ED], &[0x1F468, 0x1F3FD, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FB],
+        &[0x1F468, 0x1F3FD, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FD, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FD, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FD, 0x200D, 0x1F4BB],
+        &[0x1F468, 0x1F3FD, 0x200D, 0x1F4BC], &[0x1F468, 0x1F3FD, 0x200D, 0x1F527], &[0x1F468, 0x1F3FD, 0x200D, 0x1F52C], &[0x1F468, 0x1F3FD, 0x200D, 0x1F680],
+        &[0x1F468, 0x1F3FD, 0x200D, 0x1F692], &[0x1F468, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE],
+        &[0x1F468, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FD, 0x200D, 0x1F9AF], &[0x1F468, 0x1F3FD, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F468, 0x1F3FD, 0x200D, 0x1F9B0],
+        &[0x1F468, 0x1F3FD, 0x200D, 0x1F9B1], &[0x1F468, 0x1F3FD, 0x200D, 0x1F9B2], &[0x1F468, 0x1F3FD, 0x200D, 0x1F9B3], &[0x1F468, 0x1F3FD, 0x200D, 0x1F9BC],
+        &[0x1F468, 0x1F3FD, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F468, 0x1F3FD, 0x200D, 0x1F9BD], &[0x1F468, 0x1F3FD, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F468, 0x1F3FD, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FB],
+        &[0x1F468, 0x1F3FD, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FD, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FD, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FE, 0x200D, 0x2695, 0xFE0F],
+        &[0x1F468, 0x1F3FE, 0x200D, 0x2696, 0xFE0F], &[0x1F468, 0x1F3FE, 0x200D, 0x2708, 0xFE0F], &[0x1F468, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FC],
+        &[0x1F468, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FB],
+        &[0x1F468, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FF],
+        &[0x1F468, 0x1F3FE, 0x200D, 0x1F33E], &[0x1F468, 0x1F3FE, 0x200D, 0x1F373], &[0x1F468, 0x1F3FE, 0x200D, 0x1F37C], &[0x1F468, 0x1F3FE, 0x200D, 0x1F393],
+        &[0x1F468, 0x1F3FE, 0x200D, 0x1F3A4], &[0x1F468, 0x1F3FE, 0x200D, 0x1F3A8], &[0x1F468, 0x1F3FE, 0x200D, 0x1F3EB], &[0x1F468, 0x1F3FE, 0x200D, 0x1F3ED],
+        &[0x1F468, 0x1F3FE, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FE, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FE, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FE, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FF],
+        &[0x1F468, 0x1F3FE, 0x200D, 0x1F4BB], &[0x1F468, 0x1F3FE, 0x200D, 0x1F4BC], &[0x1F468, 0x1F3FE, 0x200D, 0x1F527], &[0x1F468, 0x1F3FE, 0x200D, 0x1F52C],
+        &[0x1F468, 0x1F3FE, 0x200D, 0x1F680], &[0x1F468, 0x1F3FE, 0x200D, 0x1F692], &[0x1F468, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC],
+        &[0x1F468, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FE, 0x200D, 0x1F9AF], &[0x1F468, 0x1F3FE, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F468, 0x1F3FE, 0x200D, 0x1F9B0], &[0x1F468, 0x1F3FE, 0x200D, 0x1F9B1], &[0x1F468, 0x1F3FE, 0x200D, 0x1F9B2], &[0x1F468, 0x1F3FE, 0x200D, 0x1F9B3],
+        &[0x1F468, 0x1F3FE, 0x200D, 0x1F9BC], &[0x1F468, 0x1F3FE, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F468, 0x1F3FE, 0x200D, 0x1F9BD], &[0x1F468, 0x1F3FE, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F468, 0x1F3FE, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FE, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FE, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FE, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FF],
+        &[0x1F468, 0x1F3FF, 0x200D, 0x2695, 0xFE0F], &[0x1F468, 0x1F3FF, 0x200D, 0x2696, 0xFE0F], &[0x1F468, 0x1F3FF, 0x200D, 0x2708, 0xFE0F], &[0x1F468, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FB],
+        &[0x1F468, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FF],
+        &[0x1F468, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FE],
+        &[0x1F468, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FF], &[0x1F468, 0x1F3FF, 0x200D, 0x1F33E], &[0x1F468, 0x1F3FF, 0x200D, 0x1F373], &[0x1F468, 0x1F3FF, 0x200D, 0x1F37C],
+        &[0x1F468, 0x1F3FF, 0x200D, 0x1F393], &[0x1F468, 0x1F3FF, 0x200D, 0x1F3A4], &[0x1F468, 0x1F3FF, 0x200D, 0x1F3A8], &[0x1F468, 0x1F3FF, 0x200D, 0x1F3EB],
+        &[0x1F468, 0x1F3FF, 0x200D, 0x1F3ED], &[0x1F468, 0x1F3FF, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FF, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FF, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FD],
+        &[0x1F468, 0x1F3FF, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FF, 0x200D, 0x1F4BB], &[0x1F468, 0x1F3FF, 0x200D, 0x1F4BC], &[0x1F468, 0x1F3FF, 0x200D, 0x1F527],
+        &[0x1F468, 0x1F3FF, 0x200D, 0x1F52C], &[0x1F468, 0x1F3FF, 0x200D, 0x1F680], &[0x1F468, 0x1F3FF, 0x200D, 0x1F692], &[0x1F468, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB],
+        &[0x1F468, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD], &[0x1F468, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE], &[0x1F468, 0x1F3FF, 0x200D, 0x1F9AF],
+        &[0x1F468, 0x1F3FF, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F468, 0x1F3FF, 0x200D, 0x1F9B0], &[0x1F468, 0x1F3FF, 0x200D, 0x1F9B1], &[0x1F468, 0x1F3FF, 0x200D, 0x1F9B2],
+        &[0x1F468, 0x1F3FF, 0x200D, 0x1F9B3], &[0x1F468, 0x1F3FF, 0x200D, 0x1F9BC], &[0x1F468, 0x1F3FF, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F468, 0x1F3FF, 0x200D, 0x1F9BD],
+        &[0x1F468, 0x1F3FF, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F468, 0x1F3FF, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FB], &[0x1F468, 0x1F3FF, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FC], &[0x1F468, 0x1F3FF, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FD],
+        &[0x1F468, 0x1F3FF, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FE], &[0x1F469, 0x200D, 0x2695, 0xFE0F], &[0x1F469, 0x200D, 0x2696, 0xFE0F], &[0x1F469, 0x200D, 0x2708, 0xFE0F],
+        &[0x1F469, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468], &[0x1F469, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469], &[0x1F469, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468], &[0x1F469, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469],
+        &[0x1F469, 0x200D, 0x1F33E], &[0x1F469, 0x200D, 0x1F373], &[0x1F469, 0x200D, 0x1F37C], &[0x1F469, 0x200D, 0x1F393],
+        &[0x1F469, 0x200D, 0x1F3A4], &[0x1F469, 0x200D, 0x1F3A8], &[0x1F469, 0x200D, 0x1F3EB], &[0x1F469, 0x200D, 0x1F3ED],
+        &[0x1F469, 0x200D, 0x1F466], &[0x1F469, 0x200D, 0x1F466, 0x200D, 0x1F466], &[0x1F469, 0x200D, 0x1F467], &[0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F466],
+        &[0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F467], &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F466], &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F466, 0x200D, 0x1F466], &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F467],
+        &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F466], &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F467], &[0x1F469, 0x200D, 0x1F4BB], &[0x1F469, 0x200D, 0x1F4BC],
+        &[0x1F469, 0x200D, 0x1F527], &[0x1F469, 0x200D, 0x1F52C], &[0x1F469, 0x200D, 0x1F680], &[0x1F469, 0x200D, 0x1F692],
+        &[0x1F469, 0x200D, 0x1F9AF], &[0x1F469, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F469, 0x200D, 0x1F9B0], &[0x1F469, 0x200D, 0x1F9B1],
+        &[0x1F469, 0x200D, 0x1F9B2], &[0x1F469, 0x200D, 0x1F9B3], &[0x1F469, 0x200D, 0x1F9BC], &[0x1F469, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F469, 0x200D, 0x1F9BD], &[0x1F469, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F469, 0x1F3FB, 0x200D, 0x2695, 0xFE0F], &[0x1F469, 0x1F3FB, 0x200D, 0x2696, 0xFE0F],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x2708, 0xFE0F], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FB], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FC], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FD],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FE], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FF], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FC],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FB],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FC], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FD], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FE], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FF],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FE],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FB, 0x200D, 0x1F33E], &[0x1F469, 0x1F3FB, 0x200D, 0x1F373], &[0x1F469, 0x1F3FB, 0x200D, 0x1F37C],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x1F393], &[0x1F469, 0x1F3FB, 0x200D, 0x1F3A4], &[0x1F469, 0x1F3FB, 0x200D, 0x1F3A8], &[0x1F469, 0x1F3FB, 0x200D, 0x1F3EB],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x1F3ED], &[0x1F469, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FE],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FB, 0x200D, 0x1F4BB], &[0x1F469, 0x1F3FB, 0x200D, 0x1F4BC], &[0x1F469, 0x1F3FB, 0x200D, 0x1F527],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x1F52C], &[0x1F469, 0x1F3FB, 0x200D, 0x1F680], &[0x1F469, 0x1F3FB, 0x200D, 0x1F692], &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD], &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE], &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF], &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FC],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FB, 0x200D, 0x1F9AF],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F469, 0x1F3FB, 0x200D, 0x1F9B0], &[0x1F469, 0x1F3FB, 0x200D, 0x1F9B1], &[0x1F469, 0x1F3FB, 0x200D, 0x1F9B2],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x1F9B3], &[0x1F469, 0x1F3FB, 0x200D, 0x1F9BC], &[0x1F469, 0x1F3FB, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F469, 0x1F3FB, 0x200D, 0x1F9BD],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F469, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FE],
+        &[0x1F469, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FC, 0x200D, 0x2695, 0xFE0F], &[0x1F469, 0x1F3FC, 0x200D, 0x2696, 0xFE0F], &[0x1F469, 0x1F3FC, 0x200D, 0x2708, 0xFE0F],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FB], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FC], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FD], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FE],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FF], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FD],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FB], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FC],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FD], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FE], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FF], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FB],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FF],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x1F33E], &[0x1F469, 0x1F3FC, 0x200D, 0x1F373], &[0x1F469, 0x1F3FC, 0x200D, 0x1F37C], &[0x1F469, 0x1F3FC, 0x200D, 0x1F393],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x1F3A4], &[0x1F469, 0x1F3FC, 0x200D, 0x1F3A8], &[0x1F469, 0x1F3FC, 0x200D, 0x1F3EB], &[0x1F469, 0x1F3FC, 0x200D, 0x1F3ED],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FC, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FC, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FC, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FF],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x1F4BB], &[0x1F469, 0x1F3FC, 0x200D, 0x1F4BC], &[0x1F469, 0x1F3FC, 0x200D, 0x1F527], &[0x1F469, 0x1F3FC, 0x200D, 0x1F52C],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x1F680], &[0x1F469, 0x1F3FC, 0x200D, 0x1F692], &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB], &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE], &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF], &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FD],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FC, 0x200D, 0x1F9AF], &[0x1F469, 0x1F3FC, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x1F9B0], &[0x1F469, 0x1F3FC, 0x200D, 0x1F9B1], &[0x1F469, 0x1F3FC, 0x200D, 0x1F9B2], &[0x1F469, 0x1F3FC, 0x200D, 0x1F9B3],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x1F9BC], &[0x1F469, 0x1F3FC, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F469, 0x1F3FC, 0x200D, 0x1F9BD], &[0x1F469, 0x1F3FC, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F469, 0x1F3FC, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FC, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FC, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FC, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FF],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x2695, 0xFE0F], &[0x1F469, 0x1F3FD, 0x200D, 0x2696, 0xFE0F], &[0x1F469, 0x1F3FD, 0x200D, 0x2708, 0xFE0F], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FB],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FC], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FD], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FE], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FF],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FE],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FB], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FC], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FD],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FE], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FF], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FC],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FD, 0x200D, 0x1F33E],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x1F373], &[0x1F469, 0x1F3FD, 0x200D, 0x1F37C], &[0x1F469, 0x1F3FD, 0x200D, 0x1F393], &[0x1F469, 0x1F3FD, 0x200D, 0x1F3A4],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x1F3A8], &[0x1F469, 0x1F3FD, 0x200D, 0x1F3EB], &[0x1F469, 0x1F3FD, 0x200D, 0x1F3ED], &[0x1F469, 0x1F3FD, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FB],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FD, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FD, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FD, 0x200D, 0x1F4BB],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x1F4BC], &[0x1F469, 0x1F3FD, 0x200D, 0x1F527], &[0x1F469, 0x1F3FD, 0x200D, 0x1F52C], &[0x1F469, 0x1F3FD, 0x200D, 0x1F680],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x1F692], &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB], &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC], &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF], &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FE],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FD, 0x200D, 0x1F9AF], &[0x1F469, 0x1F3FD, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F469, 0x1F3FD, 0x200D, 0x1F9B0],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x1F9B1], &[0x1F469, 0x1F3FD, 0x200D, 0x1F9B2], &[0x1F469, 0x1F3FD, 0x200D, 0x1F9B3], &[0x1F469, 0x1F3FD, 0x200D, 0x1F9BC],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F469, 0x1F3FD, 0x200D, 0x1F9BD], &[0x1F469, 0x1F3FD, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F469, 0x1F3FD, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FB],
+        &[0x1F469, 0x1F3FD, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FD, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FD, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FE, 0x200D, 0x2695, 0xFE0F],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x2696, 0xFE0F], &[0x1F469, 0x1F3FE, 0x200D, 0x2708, 0xFE0F], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FB], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FC],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FD], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FE], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FF], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FB],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FF],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FB], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FC], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FD], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FE],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FF], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FD],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FE, 0x200D, 0x1F33E], &[0x1F469, 0x1F3FE, 0x200D, 0x1F373],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x1F37C], &[0x1F469, 0x1F3FE, 0x200D, 0x1F393], &[0x1F469, 0x1F3FE, 0x200D, 0x1F3A4], &[0x1F469, 0x1F3FE, 0x200D, 0x1F3A8],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x1F3EB], &[0x1F469, 0x1F3FE, 0x200D, 0x1F3ED], &[0x1F469, 0x1F3FE, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FE, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FC],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FE, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FE, 0x200D, 0x1F4BB], &[0x1F469, 0x1F3FE, 0x200D, 0x1F4BC],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x1F527], &[0x1F469, 0x1F3FE, 0x200D, 0x1F52C], &[0x1F469, 0x1F3FE, 0x200D, 0x1F680], &[0x1F469, 0x1F3FE, 0x200D, 0x1F692],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB], &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC], &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD], &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FF],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FF],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x1F9AF], &[0x1F469, 0x1F3FE, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F469, 0x1F3FE, 0x200D, 0x1F9B0], &[0x1F469, 0x1F3FE, 0x200D, 0x1F9B1],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x1F9B2], &[0x1F469, 0x1F3FE, 0x200D, 0x1F9B3], &[0x1F469, 0x1F3FE, 0x200D, 0x1F9BC], &[0x1F469, 0x1F3FE, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x1F9BD], &[0x1F469, 0x1F3FE, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F469, 0x1F3FE, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FE, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FC],
+        &[0x1F469, 0x1F3FE, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FE, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FF, 0x200D, 0x2695, 0xFE0F], &[0x1F469, 0x1F3FF, 0x200D, 0x2696, 0xFE0F],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x2708, 0xFE0F], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FB], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FC], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FD],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FE], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FF], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FC],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FB],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FC], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FD], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FE], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FF],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FE],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FF], &[0x1F469, 0x1F3FF, 0x200D, 0x1F33E], &[0x1F469, 0x1F3FF, 0x200D, 0x1F373], &[0x1F469, 0x1F3FF, 0x200D, 0x1F37C],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x1F393], &[0x1F469, 0x1F3FF, 0x200D, 0x1F3A4], &[0x1F469, 0x1F3FF, 0x200D, 0x1F3A8], &[0x1F469, 0x1F3FF, 0x200D, 0x1F3EB],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x1F3ED], &[0x1F469, 0x1F3FF, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FF, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FF, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FD],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FF, 0x200D, 0x1F4BB], &[0x1F469, 0x1F3FF, 0x200D, 0x1F4BC], &[0x1F469, 0x1F3FF, 0x200D, 0x1F527],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x1F52C], &[0x1F469, 0x1F3FF, 0x200D, 0x1F680], &[0x1F469, 0x1F3FF, 0x200D, 0x1F692], &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FC], &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FD], &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FE], &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FB],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FD], &[0x1F469, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FE], &[0x1F469, 0x1F3FF, 0x200D, 0x1F9AF],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F469, 0x1F3FF, 0x200D, 0x1F9B0], &[0x1F469, 0x1F3FF, 0x200D, 0x1F9B1], &[0x1F469, 0x1F3FF, 0x200D, 0x1F9B2],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x1F9B3], &[0x1F469, 0x1F3FF, 0x200D, 0x1F9BC], &[0x1F469, 0x1F3FF, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F469, 0x1F3FF, 0x200D, 0x1F9BD],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F469, 0x1F3FF, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FB], &[0x1F469, 0x1F3FF, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FC], &[0x1F469, 0x1F3FF, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FD],
+        &[0x1F469, 0x1F3FF, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FE], &[0x1F46E, 0x200D, 0x2640, 0xFE0F], &[0x1F46E, 0x200D, 0x2642, 0xFE0F], &[0x1F46E, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F46E, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F46E, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F46E, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F46E, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F46E, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F46E, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F46E, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F46E, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F46E, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F46F, 0x200D, 0x2640, 0xFE0F], &[0x1F46F, 0x200D, 0x2642, 0xFE0F], &[0x1F46F, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F46F, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F46F, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F46F, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F46F, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F46F, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F46F, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F46F, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F46F, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F46F, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F470, 0x200D, 0x2640, 0xFE0F], &[0x1F470, 0x200D, 0x2642, 0xFE0F], &[0x1F470, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F470, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F470, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F470, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F470, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F470, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F470, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F470, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F470, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F470, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F471, 0x200D, 0x2640, 0xFE0F], &[0x1F471, 0x200D, 0x2642, 0xFE0F], &[0x1F471, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F471, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F471, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F471, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F471, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F471, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F471, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F471, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F471, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F471, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F473, 0x200D, 0x2640, 0xFE0F], &[0x1F473, 0x200D, 0x2642, 0xFE0F], &[0x1F473, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F473, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F473, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F473, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F473, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F473, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F473, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F473, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F473, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F473, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F477, 0x200D, 0x2640, 0xFE0F], &[0x1F477, 0x200D, 0x2642, 0xFE0F], &[0x1F477, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F477, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F477, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F477, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F477, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F477, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F477, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F477, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F477, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F477, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F481, 0x200D, 0x2640, 0xFE0F], &[0x1F481, 0x200D, 0x2642, 0xFE0F], &[0x1F481, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F481, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F481, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F481, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F481, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F481, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F481, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F481, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F481, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F481, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F482, 0x200D, 0x2640, 0xFE0F], &[0x1F482, 0x200D, 0x2642, 0xFE0F], &[0x1F482, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F482, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F482, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F482, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F482, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F482, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F482, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F482, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F482, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F482, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F486, 0x200D, 0x2640, 0xFE0F], &[0x1F486, 0x200D, 0x2642, 0xFE0F], &[0x1F486, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F486, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F486, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F486, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F486, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F486, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F486, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F486, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F486, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F486, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F487, 0x200D, 0x2640, 0xFE0F], &[0x1F487, 0x200D, 0x2642, 0xFE0F], &[0x1F487, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F487, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F487, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F487, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F487, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F487, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F487, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F487, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F487, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F487, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F575, 0xFE0F, 0x200D, 0x2640, 0xFE0F], &[0x1F575, 0xFE0F, 0x200D, 0x2642, 0xFE0F], &[0x1F575, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F575, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F575, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F575, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F575, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F575, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F575, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F575, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F575, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F575, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F62E, 0x200D, 0x1F4A8], &[0x1F635, 0x200D, 0x1F4AB], &[0x1F636, 0x200D, 0x1F32B, 0xFE0F],
+        &[0x1F642, 0x200D, 0x2194, 0xFE0F], &[0x1F642, 0x200D, 0x2195, 0xFE0F], &[0x1F645, 0x200D, 0x2640, 0xFE0F], &[0x1F645, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F645, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F645, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F645, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F645, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F645, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F645, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F645, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F645, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F645, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F645, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F646, 0x200D, 0x2640, 0xFE0F], &[0x1F646, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F646, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F646, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F646, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F646, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F646, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F646, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F646, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F646, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F646, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F646, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F647, 0x200D, 0x2640, 0xFE0F], &[0x1F647, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F647, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F647, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F647, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F647, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F647, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F647, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F647, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F647, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F647, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F647, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F64B, 0x200D, 0x2640, 0xFE0F], &[0x1F64B, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F64B, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F64B, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F64B, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F64B, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F64B, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F64B, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F64B, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F64B, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F64B, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F64B, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F64D, 0x200D, 0x2640, 0xFE0F], &[0x1F64D, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F64D, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F64D, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F64D, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F64D, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F64D, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F64D, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F64D, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F64D, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F64D, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F64D, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F64E, 0x200D, 0x2640, 0xFE0F], &[0x1F64E, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F64E, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F64E, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F64E, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F64E, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F64E, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F64E, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F64E, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F64E, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F64E, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F64E, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F6A3, 0x200D, 0x2640, 0xFE0F], &[0x1F6A3, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F6A3, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F6A3, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F6A3, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F6A3, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F6A3, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F6A3, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F6A3, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F6A3, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F6A3, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F6A3, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F6B4, 0x200D, 0x2640, 0xFE0F], &[0x1F6B4, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F6B4, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F6B4, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F6B4, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F6B4, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F6B4, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F6B4, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F6B4, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F6B4, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F6B4, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F6B4, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F6B5, 0x200D, 0x2640, 0xFE0F], &[0x1F6B5, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F6B5, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F6B5, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F6B5, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F6B5, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F6B5, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F6B5, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F6B5, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F6B5, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F6B5, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F6B5, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F6B6, 0x200D, 0x2640, 0xFE0F], &[0x1F6B6, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F6B6, 0x200D, 0x2642, 0xFE0F], &[0x1F6B6, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F6B6, 0x200D, 0x27A1, 0xFE0F], &[0x1F6B6, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F6B6, 0x1F3FB, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F6B6, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F6B6, 0x1F3FB, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F6B6, 0x1F3FB, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F6B6, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F6B6, 0x1F3FC, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F6B6, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F6B6, 0x1F3FC, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F6B6, 0x1F3FC, 0x200D, 0x27A1, 0xFE0F], &[0x1F6B6, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F6B6, 0x1F3FD, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F6B6, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F6B6, 0x1F3FD, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F6B6, 0x1F3FD, 0x200D, 0x27A1, 0xFE0F], &[0x1F6B6, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F6B6, 0x1F3FE, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F6B6, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F6B6, 0x1F3FE, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F6B6, 0x1F3FE, 0x200D, 0x27A1, 0xFE0F], &[0x1F6B6, 0x1F3FF, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F6B6, 0x1F3FF, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F6B6, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F6B6, 0x1F3FF, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F6B6, 0x1F3FF, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F926, 0x200D, 0x2640, 0xFE0F], &[0x1F926, 0x200D, 0x2642, 0xFE0F], &[0x1F926, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F926, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F926, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F926, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F926, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F926, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F926, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F926, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F926, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F926, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F935, 0x200D, 0x2640, 0xFE0F], &[0x1F935, 0x200D, 0x2642, 0xFE0F], &[0x1F935, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F935, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F935, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F935, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F935, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F935, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F935, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F935, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F935, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F935, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F937, 0x200D, 0x2640, 0xFE0F], &[0x1F937, 0x200D, 0x2642, 0xFE0F], &[0x1F937, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F937, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F937, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F937, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F937, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F937, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F937, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F937, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F937, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F937, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F938, 0x200D, 0x2640, 0xFE0F], &[0x1F938, 0x200D, 0x2642, 0xFE0F], &[0x1F938, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F938, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F938, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F938, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F938, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F938, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F938, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F938, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F938, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F938, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F939, 0x200D, 0x2640, 0xFE0F], &[0x1F939, 0x200D, 0x2642, 0xFE0F], &[0x1F939, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F939, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F939, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F939, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F939, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F939, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F939, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F939, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F939, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F939, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F93C, 0x200D, 0x2640, 0xFE0F], &[0x1F93C, 0x200D, 0x2642, 0xFE0F], &[0x1F93C, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F93C, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F93C, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F93C, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F93C, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F93C, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F93C, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F93C, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F93C, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F93C, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F93D, 0x200D, 0x2640, 0xFE0F], &[0x1F93D, 0x200D, 0x2642, 0xFE0F], &[0x1F93D, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F93D, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F93D, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F93D, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F93D, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F93D, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F93D, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F93D, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F93D, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F93D, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F93E, 0x200D, 0x2640, 0xFE0F], &[0x1F93E, 0x200D, 0x2642, 0xFE0F], &[0x1F93E, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F93E, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F93E, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F93E, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F93E, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F93E, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F93E, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F93E, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F93E, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F93E, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9B8, 0x200D, 0x2640, 0xFE0F], &[0x1F9B8, 0x200D, 0x2642, 0xFE0F], &[0x1F9B8, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F9B8, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9B8, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F9B8, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F9B8, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F9B8, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9B8, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F9B8, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F9B8, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F9B8, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9B9, 0x200D, 0x2640, 0xFE0F], &[0x1F9B9, 0x200D, 0x2642, 0xFE0F], &[0x1F9B9, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F9B9, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9B9, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F9B9, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F9B9, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F9B9, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9B9, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F9B9, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F9B9, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F9B9, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9CD, 0x200D, 0x2640, 0xFE0F], &[0x1F9CD, 0x200D, 0x2642, 0xFE0F], &[0x1F9CD, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F9CD, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9CD, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F9CD, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F9CD, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F9CD, 0x1F3FD, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9CD, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F9CD, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F9CD, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F9CD, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9CE, 0x200D, 0x2640, 0xFE0F], &[0x1F9CE, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F9CE, 0x200D, 0x2642, 0xFE0F], &[0x1F9CE, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F9CE, 0x200D, 0x27A1, 0xFE0F], &[0x1F9CE, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F9CE, 0x1F3FB, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F9CE, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9CE, 0x1F3FB, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F9CE, 0x1F3FB, 0x200D, 0x27A1, 0xFE0F], &[0x1F9CE, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F9CE, 0x1F3FC, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F9CE, 0x1F3FC, 0x200D, 0x2642, 0xFE0F], &[0x1F9CE, 0x1F3FC, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F9CE, 0x1F3FC, 0x200D, 0x27A1, 0xFE0F], &[0x1F9CE, 0x1F3FD, 0x200D, 0x2640, 0xFE0F],
+        &[0x1F9CE, 0x1F3FD, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F9CE, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F9CE, 0x1F3FD, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F9CE, 0x1F3FD, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F9CE, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F9CE, 0x1F3FE, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F9CE, 0x1F3FE, 0x200D, 0x2642, 0xFE0F], &[0x1F9CE, 0x1F3FE, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+        &[0x1F9CE, 0x1F3FE, 0x200D, 0x27A1, 0xFE0F], &[0x1F9CE, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F9CE, 0x1F3FF, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F9CE, 0x1F3FF, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9CE, 0x1F3FF, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F], &[0x1F9CE, 0x1F3FF, 0x200D, 0x27A1, 0xFE0F], &[0x1F9CF, 0x200D, 0x2640, 0xFE0F], &[0x1F9CF, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9CF, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F9CF, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F9CF, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F9CF, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9CF, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F9CF, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F9CF, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F9CF, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9CF, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F9CF, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F9D1, 0x200D, 0x2695, 0xFE0F], &[0x1F9D1, 0x200D, 0x2696, 0xFE0F],
+        &[0x1F9D1, 0x200D, 0x2708, 0xFE0F], &[0x1F9D1, 0x200D, 0x1F33E], &[0x1F9D1, 0x200D, 0x1F373], &[0x1F9D1, 0x200D, 0x1F37C],
+        &[0x1F9D1, 0x200D, 0x1F384], &[0x1F9D1, 0x200D, 0x1F393], &[0x1F9D1, 0x200D, 0x1F3A4], &[0x1F9D1, 0x200D, 0x1F3A8],
+        &[0x1F9D1, 0x200D, 0x1F3EB], &[0x1F9D1, 0x200D, 0x1F3ED], &[0x1F9D1, 0x200D, 0x1F4BB], &[0x1F9D1, 0x200D, 0x1F4BC],
+        &[0x1F9D1, 0x200D, 0x1F527], &[0x1F9D1, 0x200D, 0x1F52C], &[0x1F9D1, 0x200D, 0x1F680], &[0x1F9D1, 0x200D, 0x1F692],
+        &[0x1F9D1, 0x200D, 0x1F91D, 0x200D, 0x1F9D1], &[0x1F9D1, 0x200D, 0x1F9AF], &[0x1F9D1, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x200D, 0x1F9B0],
+        &[0x1F9D1, 0x200D, 0x1F9B1], &[0x1F9D1, 0x200D, 0x1F9B2], &[0x1F9D1, 0x200D, 0x1F9B3], &[0x1F9D1, 0x200D, 0x1F9BC],
+        &[0x1F9D1, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x200D, 0x1F9BD], &[0x1F9D1, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x200D, 0x1F9D1, 0x200D, 0x1F9D2],
+        &[0x1F9D1, 0x200D, 0x1F9D1, 0x200D, 0x1F9D2, 0x200D, 0x1F9D2], &[0x1F9D1, 0x200D, 0x1F9D2], &[0x1F9D1, 0x200D, 0x1F9D2, 0x200D, 0x1F9D2], &[0x1F9D1, 0x200D, 0x1FA70],
+        &[0x1F9D1, 0x1F3FB, 0x200D, 0x2695, 0xFE0F], &[0x1F9D1, 0x1F3FB, 0x200D, 0x2696, 0xFE0F], &[0x1F9D1, 0x1F3FB, 0x200D, 0x2708, 0xFE0F], &[0x1F9D1, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FC],
+        &[0x1F9D1, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FC],
+        &[0x1F9D1, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F33E],
+        &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F373], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F37C], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F384], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F393],
+        &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F3A4], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F3A8], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F3EB], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F3ED],
+        &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FC], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FF],
+        &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F4BB], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F4BC], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F527], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F52C],
+        &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F680], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F692], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FC],
+        &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9AF],
+        &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9B0], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9B1], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9B2],
+        &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9B3], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9BC], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9BD],
+        &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1FA70], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FC], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FD],
+        &[0x1F9D1, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FC, 0x200D, 0x2695, 0xFE0F], &[0x1F9D1, 0x1F3FC, 0x200D, 0x2696, 0xFE0F],
+        &[0x1F9D1, 0x1F3FC, 0x200D, 0x2708, 0xFE0F], &[0x1F9D1, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FE],
+        &[0x1F9D1, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FE],
+        &[0x1F9D1, 0x1F3FC, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F33E], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F373], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F37C],
+        &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F384], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F393], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F3A4], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F3A8],
+        &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F3EB], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F3ED], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FD],
+        &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F4BB], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F4BC],
+        &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F527], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F52C], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F680], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F692],
+        &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FC], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FE],
+        &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9AF], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9B0],
+        &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9B1], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9B2], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9B3], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9BC],
+        &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9BD], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1FA70],
+        &[0x1F9D1, 0x1F3FC, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FC, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FF],
+        &[0x1F9D1, 0x1F3FD, 0x200D, 0x2695, 0xFE0F], &[0x1F9D1, 0x1F3FD, 0x200D, 0x2696, 0xFE0F], &[0x1F9D1, 0x1F3FD, 0x200D, 0x2708, 0xFE0F], &[0x1F9D1, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FB],
+        &[0x1F9D1, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FC], &[0x1F9D1, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FB],
+        &[0x1F9D1, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FC], &[0x1F9D1, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FD, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F33E],
+        &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F373], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F37C], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F384], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F393],
+        &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F3A4], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F3A8], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F3EB], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F3ED],
+        &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FC], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FF],
+        &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F4BB], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F4BC], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F527], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F52C],
+        &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F680], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F692], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FC],
+        &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9AF],
+        &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9B0], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9B1], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9B2],
+        &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9B3], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9BC], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9BD],
+        &[0x1F9D1, 0x1F3FD, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1FA70], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FC],
+        &[0x1F9D1, 0x1F3FD, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FD, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FE, 0x200D, 0x2695, 0xFE0F], &[0x1F9D1, 0x1F3FE, 0x200D, 0x2696, 0xFE0F],
+        &[0x1F9D1, 0x1F3FE, 0x200D, 0x2708, 0xFE0F], &[0x1F9D1, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FC], &[0x1F9D1, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FD],
+        &[0x1F9D1, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FC], &[0x1F9D1, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FD],
+        &[0x1F9D1, 0x1F3FE, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F33E], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F373], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F37C],
+        &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F384], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F393], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F3A4], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F3A8],
+        &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F3EB], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F3ED], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FC],
+        &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F4BB], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F4BC],
+        &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F527], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F52C], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F680], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F692],
+        &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FC], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FE],
+        &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9AF], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9B0],
+        &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9B1], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9B2], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9B3], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9BC],
+        &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9BD], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1FA70],
+        &[0x1F9D1, 0x1F3FE, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FC], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FE, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FF],
+        &[0x1F9D1, 0x1F3FF, 0x200D, 0x2695, 0xFE0F], &[0x1F9D1, 0x1F3FF, 0x200D, 0x2696, 0xFE0F], &[0x1F9D1, 0x1F3FF, 0x200D, 0x2708, 0xFE0F], &[0x1F9D1, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FB],
+        &[0x1F9D1, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FC], &[0x1F9D1, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FB],
+        &[0x1F9D1, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FC], &[0x1F9D1, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FF, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F33E],
+        &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F373], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F37C], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F384], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F393],
+        &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F3A4], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F3A8], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F3EB], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F3ED],
+        &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FC], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FE],
+        &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F4BB], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F4BC], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F527], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F52C],
+        &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F680], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F692], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FC],
+        &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FF], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9AF],
+        &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9B0], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9B1], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9B2],
+        &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9B3], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9BC], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9BD],
+        &[0x1F9D1, 0x1F3FF, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1FA70], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FB], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FC],
+        &[0x1F9D1, 0x1F3FF, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FD], &[0x1F9D1, 0x1F3FF, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FE], &[0x1F9D4, 0x200D, 0x2640, 0xFE0F], &[0x1F9D4, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D4, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F9D4, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F9D4, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F9D4, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D4, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F9D4, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F9D4, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F9D4, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D4, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F9D4, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F9D6, 0x200D, 0x2640, 0xFE0F], &[0x1F9D6, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D6, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F9D6, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F9D6, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F9D6, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D6, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F9D6, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F9D6, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F9D6, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D6, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F9D6, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F9D7, 0x200D, 0x2640, 0xFE0F], &[0x1F9D7, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D7, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F9D7, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F9D7, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F9D7, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D7, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F9D7, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F9D7, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F9D7, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D7, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F9D7, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F9D8, 0x200D, 0x2640, 0xFE0F], &[0x1F9D8, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D8, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F9D8, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F9D8, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F9D8, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D8, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F9D8, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F9D8, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F9D8, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D8, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F9D8, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F9D9, 0x200D, 0x2640, 0xFE0F], &[0x1F9D9, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D9, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F9D9, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F9D9, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F9D9, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D9, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F9D9, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F9D9, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F9D9, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9D9, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F9D9, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F9DA, 0x200D, 0x2640, 0xFE0F], &[0x1F9DA, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9DA, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F9DA, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F9DA, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F9DA, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9DA, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F9DA, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F9DA, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F9DA, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9DA, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F9DA, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F9DB, 0x200D, 0x2640, 0xFE0F], &[0x1F9DB, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9DB, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F9DB, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F9DB, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F9DB, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9DB, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F9DB, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F9DB, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F9DB, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9DB, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F9DB, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F9DC, 0x200D, 0x2640, 0xFE0F], &[0x1F9DC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9DC, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F9DC, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F9DC, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F9DC, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9DC, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F9DC, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F9DC, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F9DC, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9DC, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F9DC, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F9DD, 0x200D, 0x2640, 0xFE0F], &[0x1F9DD, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9DD, 0x1F3FB, 0x200D, 0x2640, 0xFE0F], &[0x1F9DD, 0x1F3FB, 0x200D, 0x2642, 0xFE0F], &[0x1F9DD, 0x1F3FC, 0x200D, 0x2640, 0xFE0F], &[0x1F9DD, 0x1F3FC, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9DD, 0x1F3FD, 0x200D, 0x2640, 0xFE0F], &[0x1F9DD, 0x1F3FD, 0x200D, 0x2642, 0xFE0F], &[0x1F9DD, 0x1F3FE, 0x200D, 0x2640, 0xFE0F], &[0x1F9DD, 0x1F3FE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9DD, 0x1F3FF, 0x200D, 0x2640, 0xFE0F], &[0x1F9DD, 0x1F3FF, 0x200D, 0x2642, 0xFE0F], &[0x1F9DE, 0x200D, 0x2640, 0xFE0F], &[0x1F9DE, 0x200D, 0x2642, 0xFE0F],
+        &[0x1F9DF, 0x200D, 0x2640, 0xFE0F], &[0x1F9DF, 0x200D, 0x2642, 0xFE0F], &[0x1FAF1, 0x1F3FB, 0x200D, 0x1FAF2, 0x1F3FC], &[0x1FAF1, 0x1F3FB, 0x200D, 0x1FAF2, 0x1F3FD],
+        &[0x1FAF1, 0x1F3FB, 0x200D, 0x1FAF2, 0x1F3FE], &[0x1FAF1, 0x1F3FB, 0x200D, 0x1FAF2, 0x1F3FF], &[0x1FAF1, 0x1F3FC, 0x200D, 0x1FAF2, 0x1F3FB], &[0x1FAF1, 0x1F3FC, 0x200D, 0x1FAF2, 0x1F3FD],
+        &[0x1FAF1, 0x1F3FC, 0x200D, 0x1FAF2, 0x1F3FE], &[0x1FAF1, 0x1F3FC, 0x200D, 0x1FAF2, 0x1F3FF], &[0x1FAF1, 0x1F3FD, 0x200D, 0x1FAF2, 0x1F3FB], &[0x1FAF1, 0x1F3FD, 0x200D, 0x1FAF2, 0x1F3FC],
+        &[0x1FAF1, 0x1F3FD, 0x200D, 0x1FAF2, 0x1F3FE], &[0x1FAF1, 0x1F3FD, 0x200D, 0x1FAF2, 0x1F3FF], &[0x1FAF1, 0x1F3FE, 0x200D, 0x1FAF2, 0x1F3FB], &[0x1FAF1, 0x1F3FE, 0x200D, 0x1FAF2, 0x1F3FC],
+        &[0x1FAF1, 0x1F3FE, 0x200D, 0x1FAF2, 0x1F3FD], &[0x1FAF1, 0x1F3FE, 0x200D, 0x1FAF2, 0x1F3FF], &[0x1FAF1, 0x1F3FF, 0x200D, 0x1FAF2, 0x1F3FB], &[0x1FAF1, 0x1F3FF, 0x200D, 0x1FAF2, 0x1F3FC],
+        &[0x1FAF1, 0x1F3FF, 0x200D, 0x1FAF2, 0x1F3FD], &[0x1FAF1, 0x1F3FF, 0x200D, 0x1FAF2, 0x1F3FE],
+    ]),
+];
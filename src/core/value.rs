@@ -5,7 +5,7 @@ use super::string::StringRep;
 use super::symbol::SymbolRep;
 
 /// An ES value of any type.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Value {
     /// Holds a [null](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-ecmascript-language-types-null-type) value.
     Null,
@@ -45,7 +45,7 @@ pub fn p262_null() -> Value {
     Value::Null
 }
 
-/// Creates an ES [String](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-ecmascript-language-types-string-type) value from either a [`String`] or a [`&str`](str).
+/// Creates an ES [String](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-ecmascript-language-types-string-type) value from a [`StringRep`].
 pub fn p262_str(value: StringRep) -> Value {
     Value::String(value)
 }
@@ -57,7 +57,7 @@ pub fn p262_string(value: String) -> Value {
 
 /// Creates an ES [Symbol](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-ecmascript-language-types-symbol-type) value from an [optional](Option) [`String`].
 pub fn p262_symbol(description: Option<String>) -> Value {
-    Value::Symbol(SymbolRep::new(description))
+    Value::Symbol(SymbolRep::new(description.map(StringRep::from)))
 }
 
 /// Creates an ES [undefined](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-ecmascript-language-types-undefined-type) value.
@@ -1,6 +1,12 @@
 //! A module implementing helpers for handling ES exceptions.
+//!
+//! Native code throws with the message-only variants of [`CoreError`], e.g. `CoreError::TypeError("…".to_string())`; the matching Error object is only created once script code can observe the exception, i.e. when it is [caught](CoreError::into_value). Any other thrown value, such as the `42` of `throw 42`, is carried as-is by [`CoreError::Throw`].
 
-/// Implements an error object that can be converted to an ES [NativeError](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-error-objects) object.
+use crate::builtins::error::p262_create_error_object;
+use crate::core::Value;
+use crate::realm::Intrinsic;
+
+/// Implements the value of a [throw completion](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-completion-record-specification-type), either an arbitrary value or an error object that can be converted to an ES [NativeError](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-error-objects) object.
 #[derive(Debug)]
 pub enum CoreError {
     /// Convertible to an [EvalError](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-native-error-types-used-in-this-standard-evalerror) object.
@@ -15,9 +21,53 @@ pub enum CoreError {
     TypeError(String),
     /// Convertible to an [URIError](https://tc39.es/ecma262/multipage/fundamental-objects.html#sec-native-error-types-used-in-this-standard-urierror) object.
     URIError(String),
+    /// An arbitrary thrown value, e.g. an exception that was caught and is rethrown unchanged.
+    Throw(Value),
+}
+
+impl CoreError {
+    /// Returns the thrown value, as a `catch` clause observes it.
+    ///
+    /// The message-only variants are converted into an instance of the matching NativeError in the [current realm](crate::agent::e262_current_realm). Rethrowing the result via [`Throw`](Self::Throw) preserves its identity.
+    pub fn into_value(self) -> Value {
+        let (prototype, message) = match self {
+            Self::EvalError(message) => (Intrinsic::EvalErrorPrototype, message),
+            Self::RangeError(message) => (Intrinsic::RangeErrorPrototype, message),
+            Self::ReferenceError(message) => (Intrinsic::ReferenceErrorPrototype, message),
+            Self::SyntaxError(message) => (Intrinsic::SyntaxErrorPrototype, message),
+            Self::TypeError(message) => (Intrinsic::TypeErrorPrototype, message),
+            Self::URIError(message) => (Intrinsic::URIErrorPrototype, message),
+            Self::Throw(value) => return value,
+        };
+        Value::Object(p262_create_error_object(prototype, &message))
+    }
+}
+
+impl From<Value> for CoreError {
+    fn from(value: Value) -> Self {
+        Self::Throw(value)
+    }
 }
 
 /// A [Result] wrapping either a `T` or a [CoreError].
 ///
 /// It can be converted into a [`Completion`](crate::completions::Completion) via the [`From`] trait.
 pub type CoreResult<T> = Result<T, CoreError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::e262_ordinary_object_create;
+
+    #[test]
+    fn carries_arbitrary_values() {
+        assert_eq!(
+            CoreError::from(Value::Number(42f64)).into_value(),
+            Value::Number(42f64)
+        );
+        let obj = Value::Object(e262_ordinary_object_create(None));
+        let caught = CoreError::Throw(obj.clone()).into_value();
+        let rethrown = CoreError::Throw(caught).into_value();
+        assert_eq!(rethrown, obj);
+    }
+}
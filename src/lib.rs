@@ -1,9 +1,12 @@
+#![feature(try_trait_v2, try_trait_v2_residual)]
 #![allow(dead_code)]
-#![feature(trait_upcasting)]
 #![warn(missing_docs)]
 
 //! A hacking-friendly [ECMAScript](https://tc39.es/ecma262/multipage/) engine.
 
+pub mod agent;
+pub mod builtins;
 pub mod completions;
 pub mod core;
 pub mod errors;
+pub mod realm;
@@ -0,0 +1,218 @@
+//! A module implementing ES [realms](https://tc39.es/ecma262/multipage/executable-code-and-execution-contexts.html#sec-code-realms) and their intrinsic objects.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::agent::{p262_random_bits, p262_seed_generation};
+use crate::builtins;
+use crate::core::{
+    e262_array_create, e262_create_builtin_function, e262_ordinary_object_create,
+    e262_string_create, ObjectRep, Value,
+};
+
+/// A [well-known intrinsic object](https://tc39.es/ecma262/multipage/ecmascript-data-types-and-values.html#sec-well-known-intrinsic-objects).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Intrinsic {
+    /// `%AggregateError%`.
+    AggregateError,
+    /// `%AggregateError.prototype%`.
+    AggregateErrorPrototype,
+    /// `%Array%`.
+    Array,
+    /// `%ArrayIteratorPrototype%`.
+    ArrayIteratorPrototype,
+    /// `%Array.prototype%`.
+    ArrayPrototype,
+    /// `%BigInt%`.
+    BigInt,
+    /// `%BigInt.prototype%`.
+    BigIntPrototype,
+    /// `%Boolean%`.
+    Boolean,
+    /// `%Boolean.prototype%`.
+    BooleanPrototype,
+    /// `%Error%`.
+    Error,
+    /// `%Error.prototype%`.
+    ErrorPrototype,
+    /// `%EvalError%`.
+    EvalError,
+    /// `%EvalError.prototype%`.
+    EvalErrorPrototype,
+    /// `%Function.prototype%`.
+    FunctionPrototype,
+    /// `%Iterator.prototype%`.
+    IteratorPrototype,
+    /// `%JSON%`.
+    JSON,
+    /// `%Math%`.
+    Math,
+    /// `%Number%`.
+    Number,
+    /// `%Number.prototype%`.
+    NumberPrototype,
+    /// `%Object%`.
+    Object,
+    /// `%Object.prototype%`.
+    ObjectPrototype,
+    /// `%Object.prototype.toString%`.
+    ObjectPrototypeToString,
+    /// `%parseFloat%`.
+    ParseFloat,
+    /// `%parseInt%`.
+    ParseInt,
+    /// `%RangeError%`.
+    RangeError,
+    /// `%RangeError.prototype%`.
+    RangeErrorPrototype,
+    /// `%ReferenceError%`.
+    ReferenceError,
+    /// `%ReferenceError.prototype%`.
+    ReferenceErrorPrototype,
+    /// `%RegExp%`.
+    RegExp,
+    /// `%RegExp.prototype%`.
+    RegExpPrototype,
+    /// `%RegExp.prototype.exec%`.
+    RegExpPrototypeExec,
+    /// `%RegExpStringIteratorPrototype%`.
+    RegExpStringIteratorPrototype,
+    /// `%String%`.
+    String,
+    /// `%StringIteratorPrototype%`.
+    StringIteratorPrototype,
+    /// `%String.prototype%`.
+    StringPrototype,
+    /// `%Symbol%`.
+    Symbol,
+    /// `%Symbol.prototype%`.
+    SymbolPrototype,
+    /// `%SyntaxError%`.
+    SyntaxError,
+    /// `%SyntaxError.prototype%`.
+    SyntaxErrorPrototype,
+    /// `%TypeError%`.
+    TypeError,
+    /// `%TypeError.prototype%`.
+    TypeErrorPrototype,
+    /// `%URIError%`.
+    URIError,
+    /// `%URIError.prototype%`.
+    URIErrorPrototype,
+}
+
+/// A [Realm Record](https://tc39.es/ecma262/multipage/executable-code-and-execution-contexts.html#realm-record).
+pub struct Realm {
+    intrinsics: RefCell<HashMap<Intrinsic, ObjectRep>>,
+    /// The generator behind `Math.random`, with the [seed generation](p262_seed_generation) it was derived in.
+    rng: RefCell<Option<(u64, ChaCha8Rng)>>,
+}
+
+impl Realm {
+    /// Creates a new realm, implementing [CreateRealm](https://tc39.es/ecma262/multipage/executable-code-and-execution-contexts.html#sec-createrealm) and [CreateIntrinsics](https://tc39.es/ecma262/multipage/executable-code-and-execution-contexts.html#sec-createintrinsics).
+    pub fn new() -> Rc<Self> {
+        let realm = Rc::new(Realm {
+            intrinsics: RefCell::new(HashMap::new()),
+            rng: RefCell::new(None),
+        });
+        realm.create_intrinsics();
+        realm
+    }
+
+    /// Returns an intrinsic object of this realm.
+    pub fn intrinsic(&self, intrinsic: Intrinsic) -> ObjectRep {
+        self.intrinsics
+            .borrow()
+            .get(&intrinsic)
+            .cloned()
+            .unwrap_or_else(|| panic!("Intrinsic {intrinsic:?} is not initialized"))
+    }
+
+    /// Reseeds the generator behind `Math.random` in this realm, so it yields the same sequence for the same seed.
+    ///
+    /// The generator is otherwise derived from the agent's on first use and again after each call to [`p262_set_seed`](crate::agent::p262_set_seed).
+    pub fn set_random_seed(&self, seed: u64) {
+        *self.rng.borrow_mut() = Some((p262_seed_generation(), ChaCha8Rng::seed_from_u64(seed)));
+    }
+
+    /// Returns a random number in the range `[0, 1)` from this realm's generator.
+    pub(crate) fn random(&self) -> f64 {
+        let generation = p262_seed_generation();
+        let mut rng = self.rng.borrow_mut();
+        if !matches!(*rng, Some((derived, _)) if derived == generation) {
+            *rng = Some((generation, ChaCha8Rng::seed_from_u64(p262_random_bits())));
+        }
+        let (_, rng) = rng.as_mut().expect("The generator was just derived");
+        rng.gen()
+    }
+
+    pub(crate) fn set_intrinsic(&self, intrinsic: Intrinsic, obj: ObjectRep) {
+        self.intrinsics.borrow_mut().insert(intrinsic, obj);
+    }
+
+    fn create_intrinsics(&self) {
+        let object_prototype = e262_ordinary_object_create(None);
+        self.set_intrinsic(Intrinsic::ObjectPrototype, object_prototype.clone());
+
+        let function_prototype = e262_create_builtin_function(
+            Rc::new(|_, _| Ok(Value::Undefined)),
+            0,
+            "",
+            Some(object_prototype.clone()),
+        );
+        self.set_intrinsic(Intrinsic::FunctionPrototype, function_prototype);
+
+        let boolean_prototype = e262_ordinary_object_create(Some(object_prototype.clone()));
+        boolean_prototype
+            .0
+            .clone()
+            .set_slot("BooleanData".to_string(), Rc::new(false));
+        self.set_intrinsic(Intrinsic::BooleanPrototype, boolean_prototype);
+
+        let number_prototype = e262_ordinary_object_create(Some(object_prototype.clone()));
+        number_prototype
+            .0
+            .clone()
+            .set_slot("NumberData".to_string(), Rc::new(0f64));
+        self.set_intrinsic(Intrinsic::NumberPrototype, number_prototype);
+
+        let string_prototype = e262_string_create("".into(), Some(object_prototype.clone()));
+        self.set_intrinsic(Intrinsic::StringPrototype, string_prototype);
+
+        let bigint_prototype = e262_ordinary_object_create(Some(object_prototype.clone()));
+        self.set_intrinsic(Intrinsic::BigIntPrototype, bigint_prototype);
+
+        let array_prototype = e262_array_create(0f64, Some(object_prototype.clone()))
+            .expect("Empty arrays can always be created");
+        self.set_intrinsic(Intrinsic::ArrayPrototype, array_prototype);
+
+        let symbol_prototype = e262_ordinary_object_create(Some(object_prototype));
+        self.set_intrinsic(Intrinsic::SymbolPrototype, symbol_prototype);
+
+        builtins::object::p262_create_intrinsics(self);
+        builtins::function::p262_create_intrinsics(self);
+        builtins::error::p262_create_intrinsics(self);
+        builtins::iterator::p262_create_intrinsics(self);
+        builtins::array::p262_create_intrinsics(self);
+        builtins::string::p262_create_intrinsics(self);
+        builtins::global::p262_create_intrinsics(self);
+        builtins::number::p262_create_intrinsics(self);
+        builtins::math::p262_create_intrinsics(self);
+        builtins::bigint::p262_create_intrinsics(self);
+        builtins::boolean::p262_create_intrinsics(self);
+        builtins::symbol::p262_create_intrinsics(self);
+        builtins::json::p262_create_intrinsics(self);
+        builtins::regexp::p262_create_intrinsics(self);
+    }
+}
+
+impl fmt::Debug for Realm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Realm").finish_non_exhaustive()
+    }
+}
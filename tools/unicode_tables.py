@@ -0,0 +1,419 @@
+#!/usr/bin/env python3
+"""Generates src/core/unicode/tables.rs from the files of the Unicode Character Database.
+
+Run from the repository root: python3 tools/unicode_tables.py > src/core/unicode/tables.rs
+
+The UCD files of UNICODE_VERSION, and the emoji files of the same release listing the RGI emoji sequences, are
+downloaded into target/unicode-<version>, and each file must state that version in its header.
+"""
+
+import itertools
+import os
+import re
+import sys
+import urllib.request
+
+# The version of the Unicode Standard the tables are generated from.
+UNICODE_VERSION = "17.0.0"
+
+DATA_DIR = os.path.join("target", f"unicode-{UNICODE_VERSION}")
+
+MAX_CODE_POINT = 0x10FFFF
+
+
+def ranges(values):
+    """Collapses (code point, value) pairs into sorted (start, end, value) ranges."""
+    result = []
+    for cp, value in values:
+        if result and result[-1][1] == cp - 1 and result[-1][2] == value:
+            result[-1][1] = cp
+        else:
+            result.append([cp, cp, value])
+    return result
+
+
+def emit_rows(name, element_type, rows, per_line):
+    print(f"pub(super) static {name}: &[{element_type}] = &[")
+    for i in range(0, len(rows), per_line):
+        print("    " + " ".join(f"{row}," for row in rows[i : i + per_line]))
+    print("];")
+    print()
+
+
+def emit_range_list(ranges_list, indent):
+    pad = " " * indent
+    for i in range(0, len(ranges_list), 6):
+        print(pad + " ".join(f"(0x{a:X}, 0x{b:X})," for a, b in ranges_list[i : i + 6]))
+
+
+def string_list(names):
+    return "&[" + ", ".join(f'"{name}"' for name in names) + "]"
+
+
+def code_point_list(cps):
+    return "&[" + ", ".join(f"0x{cp:X}" for cp in cps) + "]"
+
+
+# The binary properties of table-binary-unicode-properties, with their aliases.
+BINARY_PROPERTIES = [
+    ("ASCII", None), ("ASCII_Hex_Digit", "AHex"), ("Alphabetic", "Alpha"), ("Any", None),
+    ("Assigned", None), ("Bidi_Control", "Bidi_C"), ("Bidi_Mirrored", "Bidi_M"),
+    ("Case_Ignorable", "CI"), ("Cased", None), ("Changes_When_Casefolded", "CWCF"),
+    ("Changes_When_Casemapped", "CWCM"), ("Changes_When_Lowercased", "CWL"),
+    ("Changes_When_NFKC_Casefolded", "CWKCF"), ("Changes_When_Titlecased", "CWT"),
+    ("Changes_When_Uppercased", "CWU"), ("Dash", None), ("Default_Ignorable_Code_Point", "DI"),
+    ("Deprecated", "Dep"), ("Diacritic", "Dia"), ("Emoji", None), ("Emoji_Component", "EComp"),
+    ("Emoji_Modifier", "EMod"), ("Emoji_Modifier_Base", "EBase"), ("Emoji_Presentation", "EPres"),
+    ("Extended_Pictographic", "ExtPict"), ("Extender", "Ext"), ("Grapheme_Base", "Gr_Base"),
+    ("Grapheme_Extend", "Gr_Ext"), ("Hex_Digit", "Hex"), ("IDS_Binary_Operator", "IDSB"),
+    ("IDS_Trinary_Operator", "IDST"), ("ID_Continue", "IDC"), ("ID_Start", "IDS"),
+    ("Ideographic", "Ideo"), ("Join_Control", "Join_C"), ("Logical_Order_Exception", "LOE"),
+    ("Lowercase", "Lower"), ("Math", None), ("Noncharacter_Code_Point", "NChar"),
+    ("Pattern_Syntax", "Pat_Syn"), ("Pattern_White_Space", "Pat_WS"), ("Quotation_Mark", "QMark"),
+    ("Radical", None), ("Regional_Indicator", "RI"), ("Sentence_Terminal", "STerm"),
+    ("Soft_Dotted", "SD"), ("Terminal_Punctuation", "Term"), ("Unified_Ideograph", "UIdeo"),
+    ("Uppercase", "Upper"), ("Variation_Selector", "VS"), ("White_Space", "space"),
+    ("XID_Continue", "XIDC"), ("XID_Start", "XIDS"),
+]
+
+# The files listing the binary properties, as lines of the form `code points ; property`.
+PROPERTY_FILES = [
+    "ucd/PropList.txt",
+    "ucd/DerivedCoreProperties.txt",
+    "ucd/DerivedNormalizationProps.txt",
+    "ucd/extracted/DerivedBinaryProperties.txt",
+    "ucd/emoji/emoji-data.txt",
+]
+
+
+def read_lines(path):
+    """Returns the lines of a file of the Unicode release, downloading it first if needed, and checks its version."""
+    local = os.path.join(DATA_DIR, path)
+    if not os.path.exists(local):
+        url = f"https://www.unicode.org/Public/{UNICODE_VERSION}/{path}"
+        print(f"Downloading {url}", file=sys.stderr)
+        os.makedirs(os.path.dirname(local), exist_ok=True)
+        try:
+            urllib.request.urlretrieve(url, local + ".part")
+        except OSError as error:
+            sys.exit(f"Could not download {url}: {error}")
+        os.replace(local + ".part", local)
+    with open(local, encoding="utf-8") as f:
+        lines = f.read().splitlines()
+    # UnicodeData.txt has no header, so only the URL it was downloaded from pins its version.
+    if path != "ucd/UnicodeData.txt":
+        header = "\n".join(itertools.takewhile(lambda line: line.startswith("#"), lines))
+        major, minor, micro = UNICODE_VERSION.split(".")
+        # The emoji files leave out the micro version.
+        if not re.search(rf"(?<![\d.]){major}\.{minor}(\.{micro})?(?!\.?\d)", header):
+            sys.exit(f"{local} is not from Unicode {UNICODE_VERSION}, delete {DATA_DIR} to download it again")
+    return lines
+
+
+def records(path):
+    """Returns the fields of each data line of a file of the Unicode release, without the comments."""
+    for line in read_lines(path):
+        line = line.split("#", 1)[0].strip()
+        if line:
+            yield [field.strip() for field in line.split(";")]
+
+
+def parse_range(field):
+    first, _, last = field.partition("..")
+    return int(first, 16), int(last or first, 16)
+
+
+def parse_code_points(field):
+    return [int(cp, 16) for cp in field.split()]
+
+
+def merge_ranges(list_of_ranges):
+    """Sorts inclusive ranges, merging the overlapping and adjacent ones."""
+    result = []
+    for first, last in sorted(list_of_ranges):
+        if result and result[-1][1] + 1 >= first:
+            result[-1] = (result[-1][0], max(result[-1][1], last))
+        else:
+            result.append((first, last))
+    return result
+
+
+def unicode_data():
+    """Reads UnicodeData.txt, returning the General_Category of every code point, and the non-zero
+    Canonical_Combining_Class, Decomposition_Mapping and simple case mappings of the code points having them."""
+    categories = ["Cn"] * (MAX_CODE_POINT + 1)
+    combining, decompositions, uppercase, lowercase = {}, {}, {}, {}
+    first = None
+    for fields in records("ucd/UnicodeData.txt"):
+        cp = int(fields[0], 16)
+        if fields[1].endswith(", First>"):
+            first = cp
+            continue
+        # The code points of a range share their properties, and have no mappings.
+        for member in range(first if fields[1].endswith(", Last>") else cp, cp + 1):
+            categories[member] = fields[2]
+        if int(fields[3]):
+            combining[cp] = int(fields[3])
+        if fields[5]:
+            decompositions[cp] = fields[5]
+        if fields[12]:
+            uppercase[cp] = [int(fields[12], 16)]
+        if fields[13]:
+            lowercase[cp] = [int(fields[13], 16)]
+    return categories, combining, decompositions, uppercase, lowercase
+
+
+def full_case_mappings(uppercase, lowercase):
+    """Adds the unconditional mappings of SpecialCasing.txt to simple case mappings, dropping those to the code point itself."""
+    for fields in records("ucd/SpecialCasing.txt"):
+        # The fifth field lists the conditions, such as Final_Sigma or a language.
+        if fields[4]:
+            continue
+        cp = int(fields[0], 16)
+        lowercase[cp] = parse_code_points(fields[1])
+        uppercase[cp] = parse_code_points(fields[3])
+    for mappings in (uppercase, lowercase):
+        for cp in [cp for cp, mapping in mappings.items() if mapping == [cp]]:
+            del mappings[cp]
+    return sorted(uppercase.items()), sorted(lowercase.items())
+
+
+def simple_case_folding():
+    """Reads the common and simple foldings of CaseFolding.txt, as `(code point, folded)` pairs."""
+    return sorted(
+        (int(fields[0], 16), int(fields[2], 16)) for fields in records("ucd/CaseFolding.txt") if fields[1] in ("C", "S")
+    )
+
+
+def binary_properties(categories):
+    """Reads the binary properties of PROPERTY_FILES given their name or alias, and derives ASCII, Any and Assigned."""
+    names = {alias: name for name, alias in BINARY_PROPERTIES if alias}
+    names["Comp_Ex"] = "Full_Composition_Exclusion"
+    found = {}
+    for path in PROPERTY_FILES:
+        for fields in records(path):
+            # Lines with a third field give the value of a property that is not binary.
+            if len(fields) == 2:
+                found.setdefault(names.get(fields[1], fields[1]), []).append(parse_range(fields[0]))
+    binary = {name: merge_ranges(list_of_ranges) for name, list_of_ranges in found.items()}
+    binary["ASCII"] = [(0, 0x7F)]
+    binary["Any"] = [(0, MAX_CODE_POINT)]
+    binary["Assigned"] = merge_ranges((a, b) for a, b, category in ranges(enumerate(categories)) if category != "Cn")
+    missing = [name for name, _ in BINARY_PROPERTIES if name not in binary]
+    if missing:
+        sys.exit(f"The Unicode {UNICODE_VERSION} files define no {', '.join(missing)}")
+    return binary
+
+
+def scripts():
+    """Reads Scripts.txt and ScriptExtensions.txt, returning the ranges of each value of Script and Script_Extensions,
+    whose values are comma-separated lists of names."""
+    script = ["Unknown"] * (MAX_CODE_POINT + 1)
+    for fields in records("ucd/Scripts.txt"):
+        first, last = parse_range(fields[0])
+        script[first : last + 1] = [fields[1]] * (last - first + 1)
+    extensions = {}
+    for fields in records("ucd/ScriptExtensions.txt"):
+        first, last = parse_range(fields[0])
+        for cp in range(first, last + 1):
+            extensions[cp] = ",".join(fields[1].split())
+    return {
+        "sc": ranges(enumerate(script)),
+        "scx": ranges((cp, extensions.get(cp, value)) for cp, value in enumerate(script)),
+    }
+
+
+def value_aliases():
+    """Reads the aliases of the General_Category and Script values from PropertyValueAliases.txt, short name first."""
+    aliases = {"gc": [], "sc": []}
+    for property, *names in records("ucd/PropertyValueAliases.txt"):
+        if property in aliases:
+            aliases[property].append(names)
+    return aliases
+
+
+# The properties of strings of table-binary-unicode-properties-of-strings, except RGI_Emoji which is their union.
+STRING_PROPERTIES = [
+    "Basic_Emoji",
+    "Emoji_Keycap_Sequence",
+    "RGI_Emoji_Flag_Sequence",
+    "RGI_Emoji_Modifier_Sequence",
+    "RGI_Emoji_Tag_Sequence",
+    "RGI_Emoji_ZWJ_Sequence",
+]
+
+
+def emoji_sequences():
+    """Reads the sorted sequences of each property of strings from the emoji files."""
+    properties = {name: [] for name in STRING_PROPERTIES}
+    for path in ("emoji/emoji-sequences.txt", "emoji/emoji-zwj-sequences.txt"):
+        for fields in records(path):
+            if ".." in fields[0]:
+                first, last = parse_range(fields[0])
+                properties[fields[1]].extend([cp] for cp in range(first, last + 1))
+            else:
+                properties[fields[1]].append(parse_code_points(fields[0]))
+    missing = [name for name, sequences in properties.items() if not sequences]
+    if missing:
+        sys.exit(f"The Unicode {UNICODE_VERSION} emoji files list no {', '.join(missing)}")
+    return {name: sorted(sequences) for name, sequences in properties.items()}
+
+
+def main():
+    version = tuple(int(part) for part in UNICODE_VERSION.split("."))
+    categories, combining, decompositions, uppercase, lowercase = unicode_data()
+    binary = binary_properties(categories)
+    maps = scripts()
+    maps["gc"] = ranges(enumerate(categories))
+    uppercase, lowercase = full_case_mappings(uppercase, lowercase)
+    print("// Generated by tools/unicode_tables.py, do not edit by hand.")
+    print()
+    print(f"/// The version of the Unicode Character Database the tables are generated from.")
+    print(f"pub const UNICODE_VERSION: (u8, u8, u8) = {version};")
+    print()
+
+    combining = ranges(sorted(combining.items()))
+    print("/// Ranges of code points with a non-zero Canonical_Combining_Class, as `(first, last, class)`.")
+    emit_rows(
+        "COMBINING_CLASSES",
+        "(u32, u32, u8)",
+        [f"(0x{a:X}, 0x{b:X}, {c})" for a, b, c in combining],
+        4,
+    )
+
+    excluded = binary["Full_Composition_Exclusion"]
+    entries = []
+    data = []
+    compositions = []
+    for cp, mapping in sorted(decompositions.items()):
+        parts = mapping.split()
+        compat = parts[0].startswith("<")
+        if compat:
+            parts = parts[1:]
+        decomposed = [int(part, 16) for part in parts]
+        entries.append(f"(0x{cp:X}, {len(data)}, {len(decomposed)}, {str(compat).lower()})")
+        data.extend(decomposed)
+        if not compat and len(decomposed) == 2 and not any(a <= cp <= b for a, b in excluded):
+            compositions.append((decomposed[0], decomposed[1], cp))
+
+    print("/// The Decomposition_Mapping of each code point except Hangul syllables, as")
+    print("/// `(code point, offset in DECOMPOSITION_DATA, length, is compatibility mapping)`.")
+    emit_rows("DECOMPOSITIONS", "(u32, u16, u8, bool)", entries, 4)
+    emit_rows("DECOMPOSITION_DATA", "u32", [f"0x{cp:X}" for cp in data], 10)
+
+    compositions.sort()
+    print("/// The primary composites, as `(first, second, composite)` sorted by the pair they compose.")
+    emit_rows(
+        "COMPOSITIONS",
+        "(u32, u32, u32)",
+        [f"(0x{a:X}, 0x{b:X}, 0x{c:X})" for a, b, c in compositions],
+        4,
+    )
+
+    emit_regexp_tables(
+        binary,
+        maps,
+        value_aliases(),
+        simple_case_folding(),
+        uppercase,
+        lowercase,
+        emoji_sequences(),
+    )
+
+
+def emit_regexp_tables(binary, maps, aliases, folding, uppercase, lowercase, sequences):
+    print("/// Sorted inclusive ranges of code points.")
+    print("pub(super) type Ranges = &'static [(u32, u32)];")
+    print()
+    print("/// The binary properties usable in regular expressions, as `(name, alias, ranges)`.")
+    print("pub(super) static BINARY_PROPERTIES: &[(&str, &str, Ranges)] = &[")
+    for name, alias in BINARY_PROPERTIES:
+        print(f'    ("{name}", "{alias or ""}", &[')
+        emit_range_list(binary[name], 8)
+        print("    ]),")
+    print("];")
+    print()
+
+    leaves = sorted({value for _, _, value in maps["gc"]})
+    values = []
+    for names in aliases["gc"]:
+        short = names[0]
+        if short in leaves:
+            members = [short]
+        elif short == "LC":
+            members = ["Lu", "Ll", "Lt"]
+        else:
+            members = [leaf for leaf in leaves if leaf.startswith(short)]
+        mask = sum(1 << leaves.index(leaf) for leaf in members)
+        values.append((names, mask))
+    values.sort(key=lambda value: value[0][0])
+    print("/// The General_Category values, as `(aliases, mask)`, where the bits of the mask are the indices in")
+    print("/// GENERAL_CATEGORIES of the categories the value groups.")
+    emit_rows(
+        "GENERAL_CATEGORY_VALUES",
+        "(&[&str], u32)",
+        [f"({string_list(names)}, 0x{mask:08X})" for names, mask in values],
+        1,
+    )
+    print("/// The General_Category of every code point, as `(first, last, index)` where index is a bit of the value masks.")
+    emit_rows(
+        "GENERAL_CATEGORIES",
+        "(u32, u32, u8)",
+        [f"(0x{a:X}, 0x{b:X}, {leaves.index(value)})" for a, b, value in maps["gc"]],
+        4,
+    )
+
+    scripts = sorted(aliases["sc"], key=lambda names: names[1])
+    index = {names[1]: i for i, names in enumerate(scripts)}
+    print("/// The Script values, as their aliases; the first alias is the short name and the second the long one.")
+    emit_rows("SCRIPT_VALUES", "&[&str]", [string_list(names) for names in scripts], 2)
+    print("/// The Script of every code point, as `(first, last, index in SCRIPT_VALUES)`.")
+    emit_rows(
+        "SCRIPTS",
+        "(u32, u32, u8)",
+        [f"(0x{a:X}, 0x{b:X}, {index[value]})" for a, b, value in maps["sc"]],
+        4,
+    )
+    short_index = {names[0]: i for i, names in enumerate(scripts)}
+    short_index.update(index)
+    print("/// The Script_Extensions of every code point, as `(first, last, indices in SCRIPT_VALUES)`.")
+    emit_rows(
+        "SCRIPT_EXTENSIONS",
+        "(u32, u32, &[u8])",
+        [
+            f"(0x{a:X}, 0x{b:X}, &[{', '.join(str(short_index[v]) for v in value.split(','))}])"
+            for a, b, value in maps["scx"]
+        ],
+        3,
+    )
+
+    print("/// The Simple_Case_Folding of every code point that does not fold to itself, as `(code point, folded)`.")
+    emit_rows("SIMPLE_CASE_FOLDING", "(u32, u32)", [f"(0x{a:X}, 0x{b:X})" for a, b in folding], 6)
+    print("/// The full uppercase mapping of every code point that does not map to itself, as `(code point, mapping)`.")
+    emit_rows(
+        "UPPERCASE_MAPPINGS",
+        "(u32, &[u32])",
+        [f"(0x{cp:X}, {code_point_list(mapping)})" for cp, mapping in uppercase],
+        4,
+    )
+    print("/// The full lowercase mapping of every code point that does not map to itself, as `(code point, mapping)`,")
+    print("/// leaving out the contextual mapping of U+03A3 GREEK CAPITAL LETTER SIGMA at the end of a word.")
+    emit_rows(
+        "LOWERCASE_MAPPINGS",
+        "(u32, &[u32])",
+        [f"(0x{cp:X}, {code_point_list(mapping)})" for cp, mapping in lowercase],
+        4,
+    )
+
+    print("/// The properties of strings usable in regular expressions with the `v` flag, as `(name, sorted sequences)`.")
+    print("pub(super) static STRING_PROPERTIES: &[(&str, &[&[u32]])] = &[")
+    for name, members in sequences.items():
+        print(f'    ("{name}", &[')
+        for i in range(0, len(members), 4):
+            print("        " + " ".join(f"{code_point_list(m)}," for m in members[i : i + 4]))
+        print("    ]),")
+    print("];")
+
+
+if __name__ == "__main__":
+    sys.exit(main())